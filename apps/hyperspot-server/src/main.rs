@@ -5,8 +5,8 @@ use clap::{Parser, Subcommand};
 use mimalloc::MiMalloc;
 use modkit::bootstrap::{
     AppConfig, dump_effective_modules_config_json, dump_effective_modules_config_yaml,
-    host::init_logging_unified, host::init_panic_tracing, list_module_names, run_migrate,
-    run_server,
+    host::init_logging_unified, host::init_panic_tracing, list_module_names, run_emit_openapi,
+    run_migrate, run_server,
 };
 
 use std::path::PathBuf;
@@ -45,6 +45,10 @@ struct Cli {
     #[arg(long)]
     dump_modules_config_json: bool,
 
+    /// Write the `OpenAPI` document to PATH without starting the server, then exit
+    #[arg(long, value_name = "PATH")]
+    emit_openapi: Option<PathBuf>,
+
     /// Log verbosity level (-v info, -vv debug, -vvv trace)
     #[arg(short, long, action = clap::ArgAction::Count)]
     verbose: u8,
@@ -147,6 +151,11 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Build the OpenAPI document in dry-run mode and exit if requested
+    if let Some(path) = cli.emit_openapi.as_deref() {
+        return run_emit_openapi(config, path).await;
+    }
+
     // Dispatch subcommands (default: run)
     match cli.command.as_ref().unwrap_or(&Commands::Run) {
         Commands::Run => run_server(config).await,
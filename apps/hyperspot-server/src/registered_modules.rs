@@ -27,6 +27,9 @@ use static_tr_plugin as _;
 #[cfg(feature = "static-authn")]
 use static_authn_plugin as _;
 
+#[cfg(feature = "api-key-authn")]
+use api_key_authn_plugin as _;
+
 #[cfg(feature = "static-authz")]
 use static_authz_plugin as _;
 
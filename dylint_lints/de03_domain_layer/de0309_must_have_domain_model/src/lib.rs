@@ -59,6 +59,11 @@ fn check_domain_model_attribute(cx: &EarlyContext<'_>, item: &Item) {
         return;
     }
 
+    // Skip macro-generated items (e.g. builders emitted by `#[domain_model(builder)]`)
+    if item.span.from_expansion() {
+        return;
+    }
+
     // Only check items in domain path
     if !is_in_domain_path(cx.sess().source_map(), item.span) {
         return;
@@ -149,8 +149,9 @@ async fn main() {
     println!("\nType Safety Demonstrations:");
     println!("===========================");
 
-    println!("VALID: Both handler and response are provided");
+    println!("VALID: Operation ID, handler and response are provided");
     println!("   OperationBuilder::get(\"/tests/v1/example\")");
+    println!("     .operation_id(\"example.get\")");
     println!("     .json_response(StatusCode::OK, \"OK\")");
     println!("     .handler(some_handler)");
     println!("     .register(router, registry) // ← This compiles!");
@@ -167,6 +168,13 @@ async fn main() {
     println!("     .handler(some_handler)");
     println!("     .register(router, registry) // ← Compilation error!");
 
+    println!();
+    println!("INVALID: Missing operation ID (compile-time error)");
+    println!("   OperationBuilder::get(\"/tests/v1/example\")");
+    println!("     .json_response(StatusCode::OK, \"OK\")");
+    println!("     .handler(some_handler)");
+    println!("     .register(router, registry) // ← Compilation error!");
+
     println!();
     println!("FLEXIBLE: Descriptive methods can be called in any order");
     println!("   OperationBuilder::get(\"/tests/v1/example\")");
@@ -175,6 +183,7 @@ async fn main() {
     println!("     .description(\"Details\")     // ← Can be anywhere");
     println!("     .json_response(StatusCode::OK, \"OK\")   // ← Can be anywhere");
     println!("     .tag(\"example\")             // ← Can be anywhere");
+    println!("     .operation_id(\"example.get\") // ← Can be anywhere");
     println!("     .register(router, registry) // ← Always at the end");
 
     println!("\nAll operations built successfully with compile-time type safety!");
@@ -6,6 +6,7 @@
 
 use async_trait::async_trait;
 use futures_core::Stream;
+use modkit_odata::Page;
 use modkit_sdk::odata::QueryBuilder;
 use modkit_security::SecurityContext;
 use std::pin::Pin;
@@ -36,6 +37,33 @@ pub trait UsersInfoClientV1: Send + Sync {
     fn cities(&self) -> Box<dyn CitiesStreamingClientV1>;
     fn addresses(&self) -> Box<dyn AddressesStreamingClientV1>;
 
+    // ==================== Query Operations ====================
+
+    /// List one page of users matching a typed query.
+    ///
+    /// The query goes through the same `OData` validation and pagination path as
+    /// `GET /users-info/v1/users`; pass `PageInfo::next_cursor` back via
+    /// `QueryBuilder::cursor` to fetch the next page.
+    ///
+    /// ```ignore
+    /// use users_info_sdk::odata::{USER_CREATED_AT, USER_DISPLAY_NAME, USER_EMAIL};
+    ///
+    /// let page = client
+    ///     .list_users(
+    ///         ctx,
+    ///         QueryBuilder::new()
+    ///             .filter(USER_EMAIL.eq("x@y").and(USER_CREATED_AT.gt(ts)))
+    ///             .order_by(USER_DISPLAY_NAME, SortDir::Asc)
+    ///             .page_size(50),
+    ///     )
+    ///     .await?;
+    /// ```
+    async fn list_users(
+        &self,
+        ctx: SecurityContext,
+        query: QueryBuilder<UserSchema>,
+    ) -> Result<Page<User>, UsersInfoError>;
+
     // ==================== Single-Item Operations ====================
 
     /// Get a single user by ID.
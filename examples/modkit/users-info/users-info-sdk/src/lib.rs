@@ -16,8 +16,10 @@
 //! let client = hub.get::<dyn UsersInfoClientV1>()?;
 //!
 //! // Use the API
-//! let user = client.get_user(&ctx, user_id).await?;
-//! let users = client.list_users(&ctx, query).await?;
+//! let user = client.get_user(ctx.clone(), user_id).await?;
+//! let page = client
+//!     .list_users(ctx, QueryBuilder::new().filter(USER_EMAIL.eq("x@y")).page_size(50))
+//!     .await?;
 //! ```
 //!
 //! ## `OData` Support
@@ -44,6 +46,7 @@ pub use client::{
 };
 pub use errors::UsersInfoError;
 pub use models::{
-    Address, AddressPatch, City, CityPatch, NewAddress, NewCity, NewUser, UpdateAddressRequest,
-    UpdateCityRequest, UpdateUserRequest, User, UserFull, UserPatch,
+    Address, AddressPatch, AddressSummary, City, CityPatch, NewAddress, NewCity, NewUser,
+    UpdateAddressRequest, UpdateCityRequest, UpdateUserRequest, User, UserFull, UserPatch,
+    UserWithAddressSummary,
 };
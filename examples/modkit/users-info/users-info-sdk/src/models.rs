@@ -3,15 +3,23 @@
 //! These are transport-agnostic data structures that define the contract
 //! between the `user_info` module and its consumers.
 
+use modkit_security::Redactable;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
 /// A user entity.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Log it as `?Redacted(&user)`: emails are hashed and the display name masked.
+#[derive(Debug, Clone, PartialEq, Eq, Redactable)]
+#[redact(email = "hash", email_original = "hash", display_name)]
 pub struct User {
     pub id: Uuid,
     pub tenant_id: Uuid,
+    /// Normalized email (see the module's email policy).
     pub email: String,
+    /// Email as entered, before normalization. `None` for users created
+    /// before normalization was introduced.
+    pub email_original: Option<String>,
     pub display_name: String,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
@@ -41,7 +49,10 @@ pub struct UpdateUserRequest {
 }
 
 /// An address entity (1:1 with users).
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Log it as `?Redacted(&address)`: street and postal code are masked.
+#[derive(Debug, Clone, PartialEq, Eq, Redactable)]
+#[redact(street, postal_code)]
 pub struct Address {
     pub id: Uuid,
     pub tenant_id: Uuid,
@@ -120,3 +131,30 @@ pub struct UserFull {
     pub address: Option<Address>,
     pub city: Option<City>,
 }
+
+/// Address and city name of a user, as shown in user lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AddressSummary {
+    pub address_id: Uuid,
+    pub city_id: Uuid,
+    /// `None` when the city is not visible to the caller.
+    pub city_name: Option<String>,
+}
+
+/// A listed user with the summary of their address.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserWithAddressSummary {
+    pub user: User,
+    /// `None` when the user has no address visible to the caller, or when
+    /// the summary was not requested.
+    pub address: Option<AddressSummary>,
+}
+
+impl From<User> for UserWithAddressSummary {
+    fn from(user: User) -> Self {
+        Self {
+            user,
+            address: None,
+        }
+    }
+}
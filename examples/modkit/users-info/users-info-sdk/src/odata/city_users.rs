@@ -0,0 +1,58 @@
+//! `OData` filter field definitions for the users of a city.
+
+use modkit_odata_macros::ODataFilterable;
+use modkit_sdk::odata::{FieldRef, Schema};
+use time::OffsetDateTime;
+
+use modkit_odata::filter::FilterField as _;
+
+/// Filterable fields of `GET /users-info/v1/cities/{city_id}/users`.
+///
+/// User fields filter the listed users. Address fields match users with an
+/// address in the city that satisfies the comparison. `$orderby` takes
+/// [`UserFilterField`](super::UserFilterField) fields.
+#[derive(ODataFilterable)]
+pub struct CityUserQuery {
+    #[odata(filter(kind = "String"))]
+    pub email: String,
+
+    #[odata(filter(kind = "String"))]
+    pub display_name: String,
+
+    #[odata(filter(kind = "DateTimeUtc"))]
+    pub created_at: OffsetDateTime,
+
+    #[odata(filter(kind = "String"))]
+    pub street: String,
+
+    #[odata(filter(kind = "String"))]
+    pub postal_code: String,
+}
+
+/// Type alias for the generated filter field enum.
+///
+/// This enum is auto-generated by the `ODataFilterable` derive macro
+/// and represents all filterable fields for the users of a city.
+pub use CityUserQueryFilterField as CityUserFilterField;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CityUserSchema;
+
+impl Schema for CityUserSchema {
+    type Field = CityUserFilterField;
+
+    fn field_name(field: Self::Field) -> &'static str {
+        field.name()
+    }
+}
+
+pub const CITY_USER_EMAIL: FieldRef<CityUserSchema, String> =
+    FieldRef::new(CityUserFilterField::Email);
+pub const CITY_USER_DISPLAY_NAME: FieldRef<CityUserSchema, String> =
+    FieldRef::new(CityUserFilterField::DisplayName);
+pub const CITY_USER_CREATED_AT: FieldRef<CityUserSchema, OffsetDateTime> =
+    FieldRef::new(CityUserFilterField::CreatedAt);
+pub const CITY_USER_STREET: FieldRef<CityUserSchema, String> =
+    FieldRef::new(CityUserFilterField::Street);
+pub const CITY_USER_POSTAL_CODE: FieldRef<CityUserSchema, String> =
+    FieldRef::new(CityUserFilterField::PostalCode);
@@ -17,8 +17,12 @@
 
 mod addresses;
 mod cities;
+mod city_users;
 mod users;
+mod webhooks;
 
 pub use addresses::*;
 pub use cities::*;
+pub use city_users::*;
 pub use users::*;
+pub use webhooks::*;
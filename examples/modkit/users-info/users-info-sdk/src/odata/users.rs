@@ -1,4 +1,4 @@
-//! `OData` filter field definitions for User resources.
+//! `OData` filter and search field definitions for User resources.
 
 use modkit_odata_macros::ODataFilterable;
 use modkit_sdk::odata::{FieldRef, Schema};
@@ -6,6 +6,7 @@ use time::OffsetDateTime;
 use uuid::Uuid;
 
 use modkit_odata::filter::FilterField as _;
+use modkit_odata::{SearchField, SearchableFields};
 
 /// User filterable fields schema.
 ///
@@ -19,6 +20,9 @@ pub struct UserQuery {
     #[odata(filter(kind = "String"))]
     pub email: String,
 
+    #[odata(filter(kind = "String"))]
+    pub display_name: String,
+
     #[odata(filter(kind = "DateTimeUtc"))]
     pub created_at: OffsetDateTime,
 }
@@ -40,7 +44,22 @@ impl Schema for UserSchema {
     }
 }
 
+/// Fields matched by `$search` on users.
+#[derive(Debug, Clone, Copy)]
+pub struct UserSearch;
+
+impl SearchableFields for UserSearch {
+    type Field = UserFilterField;
+
+    const FIELDS: &'static [SearchField<UserFilterField>] = &[
+        SearchField::new(UserFilterField::DisplayName, 2),
+        SearchField::new(UserFilterField::Email, 1),
+    ];
+}
+
 pub const USER_ID: FieldRef<UserSchema, Uuid> = FieldRef::new(UserFilterField::Id);
 pub const USER_EMAIL: FieldRef<UserSchema, String> = FieldRef::new(UserFilterField::Email);
+pub const USER_DISPLAY_NAME: FieldRef<UserSchema, String> =
+    FieldRef::new(UserFilterField::DisplayName);
 pub const USER_CREATED_AT: FieldRef<UserSchema, OffsetDateTime> =
     FieldRef::new(UserFilterField::CreatedAt);
@@ -0,0 +1,43 @@
+//! `OData` filter field definitions for webhook resources.
+
+use modkit_odata_macros::ODataFilterable;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Webhook subscription filterable fields schema.
+#[derive(ODataFilterable)]
+pub struct WebhookQuery {
+    #[odata(filter(kind = "Uuid"))]
+    pub id: Uuid,
+
+    #[odata(filter(kind = "String"))]
+    pub url: String,
+
+    #[odata(filter(kind = "DateTimeUtc"))]
+    pub created_at: OffsetDateTime,
+}
+
+/// Type alias for the generated webhook filter field enum.
+pub use WebhookQueryFilterField as WebhookFilterField;
+
+/// Webhook delivery attempt filterable fields schema.
+#[derive(ODataFilterable)]
+pub struct WebhookDeliveryQuery {
+    #[odata(filter(kind = "Uuid"))]
+    pub id: Uuid,
+
+    #[odata(filter(kind = "Uuid"))]
+    pub event_id: Uuid,
+
+    #[odata(filter(kind = "String"))]
+    pub event_type: String,
+
+    #[odata(filter(kind = "String"))]
+    pub status: String,
+
+    #[odata(filter(kind = "DateTimeUtc"))]
+    pub attempted_at: OffsetDateTime,
+}
+
+/// Type alias for the generated webhook delivery filter field enum.
+pub use WebhookDeliveryQueryFilterField as WebhookDeliveryFilterField;
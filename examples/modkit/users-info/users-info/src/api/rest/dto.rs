@@ -1,8 +1,18 @@
 /// REST DTO for user representation with serde/utoipa
 use time::OffsetDateTime;
-use users_info_sdk::{Address, City, NewAddress, NewCity, NewUser, User, UserFull, UserPatch};
+use users_info_sdk::{
+    Address, AddressSummary, City, NewAddress, NewCity, NewUser, User, UserFull, UserPatch,
+    UserWithAddressSummary,
+};
 use uuid::Uuid;
 
+use crate::domain::city_import::{CityImportReport, CityImportRow, RejectedRow};
+use crate::domain::service::{PurgeMode, TenantPurgeReport};
+use crate::domain::tenant_settings::{TenantLimitOverrides, TenantSettings};
+use crate::domain::webhooks::{
+    DeliveryStatus, NewWebhook, Webhook, WebhookDelivery, WebhookEventType,
+};
+
 /// REST DTO for user representation with serde/utoipa
 #[derive(Debug, Clone)]
 #[modkit_macros::api_dto(request, response)]
@@ -10,6 +20,9 @@ pub struct UserDto {
     pub id: Uuid,
     pub tenant_id: Uuid,
     pub email: String,
+    /// Email as entered, before normalization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_original: Option<String>,
     pub display_name: String,
     #[serde(with = "time::serde::rfc3339")]
     pub created_at: OffsetDateTime,
@@ -18,7 +31,7 @@ pub struct UserDto {
 }
 
 /// REST DTO for creating a new user
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 #[modkit_macros::api_dto(request)]
 pub struct CreateUserReq {
     /// Optional ID for the user. If not provided, a UUID v7 will be generated
@@ -48,6 +61,28 @@ pub struct UserFullDto {
     pub city: Option<CityDto>,
 }
 
+/// REST DTO for a listed user
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct UserListItemDto {
+    #[serde(flatten)]
+    pub user: UserDto,
+    /// Included when `$select` contains `address_summary`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_summary: Option<AddressSummaryDto>,
+}
+
+/// REST DTO for the address summary of a listed user
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct AddressSummaryDto {
+    pub address_id: Uuid,
+    pub city_id: Uuid,
+    /// Omitted when the city is not visible to the caller
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub city_name: Option<String>,
+}
+
 // Conversion implementations between REST DTOs and contract models
 impl From<User> for UserDto {
     fn from(user: User) -> Self {
@@ -55,6 +90,7 @@ impl From<User> for UserDto {
             id: user.id,
             tenant_id: user.tenant_id,
             email: user.email,
+            email_original: user.email_original,
             display_name: user.display_name,
             created_at: user.created_at,
             updated_at: user.updated_at,
@@ -92,6 +128,77 @@ impl From<UserFull> for UserFullDto {
     }
 }
 
+impl From<AddressSummary> for AddressSummaryDto {
+    fn from(summary: AddressSummary) -> Self {
+        Self {
+            address_id: summary.address_id,
+            city_id: summary.city_id,
+            city_name: summary.city_name,
+        }
+    }
+}
+
+impl From<UserWithAddressSummary> for UserListItemDto {
+    fn from(item: UserWithAddressSummary) -> Self {
+        Self {
+            user: UserDto::from(item.user),
+            address_summary: item.address.map(AddressSummaryDto::from),
+        }
+    }
+}
+
+// ==================== OpenAPI Examples ====================
+
+/// Fixed instant used by the examples (2026-01-15T10:30:00Z).
+fn example_timestamp() -> OffsetDateTime {
+    OffsetDateTime::UNIX_EPOCH + time::Duration::seconds(1_768_473_000)
+}
+
+impl CreateUserReq {
+    /// Request example published in the `OpenAPI` document.
+    #[must_use]
+    pub(crate) fn example() -> Self {
+        Self {
+            id: None,
+            tenant_id: Uuid::from_u128(0x0194_62a0_7c1e_7d3b_9f2a_5c8e_1b4d_6a70),
+            email: "jane.doe@example.com".to_owned(),
+            display_name: "Jane Doe".to_owned(),
+        }
+    }
+}
+
+impl UserDto {
+    /// Response example published in the `OpenAPI` document.
+    #[must_use]
+    pub(crate) fn example() -> Self {
+        let at = example_timestamp();
+        Self {
+            id: Uuid::from_u128(0x0194_62a1_0b5f_7e21_8c4d_2a9e_6f13_b845),
+            tenant_id: Uuid::from_u128(0x0194_62a0_7c1e_7d3b_9f2a_5c8e_1b4d_6a70),
+            email: "jane.doe@example.com".to_owned(),
+            email_original: Some("Jane.Doe@Example.com".to_owned()),
+            display_name: "Jane Doe".to_owned(),
+            created_at: at,
+            updated_at: at,
+        }
+    }
+}
+
+impl UserListItemDto {
+    /// List item example published in the `OpenAPI` document.
+    #[must_use]
+    pub(crate) fn example() -> Self {
+        Self {
+            user: UserDto::example(),
+            address_summary: Some(AddressSummaryDto {
+                address_id: Uuid::from_u128(0x0194_62a2_3d71_7c05_a1e8_4b6f_9d20_c317),
+                city_id: Uuid::from_u128(0x0194_62a2_1f0c_7a94_b3d5_8e17_2c6a_f408),
+                city_name: Some("Lisbon".to_owned()),
+            }),
+        }
+    }
+}
+
 // ==================== City DTOs ====================
 
 /// REST DTO for city representation
@@ -160,6 +267,81 @@ impl From<UpdateCityReq> for users_info_sdk::CityPatch {
     }
 }
 
+/// Query parameters for the city import endpoint
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct ImportCitiesQuery {
+    /// Validate and report without writing anything
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// REST DTO for an imported CSV row
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct ImportedCityRowDto {
+    /// Line of the row in the file; the header is line 1
+    pub row: usize,
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub country: String,
+}
+
+/// REST DTO for a CSV row that was not imported
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct RejectedCityRowDto {
+    /// Line of the row in the file; the header is line 1
+    pub row: usize,
+    /// Machine-readable reason, e.g. `invalid_country`
+    pub code: String,
+    pub reason: String,
+}
+
+/// REST DTO for the city import summary
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct CityImportReportDto {
+    pub dry_run: bool,
+    /// Rows that were created (or would be, in a dry run)
+    pub created: Vec<ImportedCityRowDto>,
+    /// Rows whose name already exists in the tenant
+    pub skipped: Vec<RejectedCityRowDto>,
+    /// Rows that failed validation or authorization
+    pub failed: Vec<RejectedCityRowDto>,
+}
+
+impl From<CityImportRow> for ImportedCityRowDto {
+    fn from(row: CityImportRow) -> Self {
+        Self {
+            row: row.row,
+            tenant_id: row.tenant_id,
+            name: row.name,
+            country: row.country,
+        }
+    }
+}
+
+impl From<RejectedRow> for RejectedCityRowDto {
+    fn from(rejected: RejectedRow) -> Self {
+        Self {
+            row: rejected.row,
+            code: rejected.reason.code().to_owned(),
+            reason: rejected.reason.to_string(),
+        }
+    }
+}
+
+impl From<CityImportReport> for CityImportReportDto {
+    fn from(report: CityImportReport) -> Self {
+        Self {
+            dry_run: report.dry_run,
+            created: report.created.into_iter().map(Into::into).collect(),
+            skipped: report.skipped.into_iter().map(Into::into).collect(),
+            failed: report.failed.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 // ==================== Address DTOs ====================
 
 /// REST DTO for address representation
@@ -216,6 +398,272 @@ impl PutAddressReq {
     }
 }
 
+// ==================== Tenant Data DTOs ====================
+
+/// How a tenant purge treats existing rows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[modkit_macros::api_dto(request, response)]
+pub enum PurgeModeDto {
+    /// Delete all rows owned by the tenant
+    #[default]
+    Erase,
+    /// Keep rows but overwrite PII columns with placeholders
+    Anonymize,
+}
+
+/// Query parameters for the tenant purge endpoint
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct PurgeTenantQuery {
+    #[serde(default)]
+    pub mode: PurgeModeDto,
+}
+
+/// REST DTO for the tenant purge report (affected rows per table)
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct TenantPurgeReportDto {
+    pub tenant_id: Uuid,
+    pub mode: PurgeModeDto,
+    pub users: u64,
+    pub addresses: u64,
+    pub cities: u64,
+}
+
+impl From<PurgeModeDto> for PurgeMode {
+    fn from(mode: PurgeModeDto) -> Self {
+        match mode {
+            PurgeModeDto::Erase => Self::Erase,
+            PurgeModeDto::Anonymize => Self::Anonymize,
+        }
+    }
+}
+
+impl From<PurgeMode> for PurgeModeDto {
+    fn from(mode: PurgeMode) -> Self {
+        match mode {
+            PurgeMode::Erase => Self::Erase,
+            PurgeMode::Anonymize => Self::Anonymize,
+        }
+    }
+}
+
+impl From<TenantPurgeReport> for TenantPurgeReportDto {
+    fn from(report: TenantPurgeReport) -> Self {
+        Self {
+            tenant_id: report.tenant_id,
+            mode: report.mode.into(),
+            users: report.users,
+            addresses: report.addresses,
+            cities: report.cities,
+        }
+    }
+}
+
+// ==================== Tenant Settings DTOs ====================
+
+/// REST DTO for a tenant's limit overrides; `null` keeps the global value
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct TenantSettingsDto {
+    pub tenant_id: Uuid,
+    pub max_users: Option<u32>,
+    pub max_page_size: Option<u32>,
+    pub max_display_name_length: Option<u32>,
+    /// Absent if the tenant has no stored settings
+    #[serde(
+        default,
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub updated_at: Option<OffsetDateTime>,
+}
+
+/// REST DTO replacing a tenant's limit overrides; omitted fields are reset
+#[derive(Debug, Clone, Default)]
+#[modkit_macros::api_dto(request)]
+pub struct PutTenantSettingsReq {
+    pub max_users: Option<u32>,
+    pub max_page_size: Option<u32>,
+    pub max_display_name_length: Option<u32>,
+}
+
+impl From<TenantSettings> for TenantSettingsDto {
+    fn from(settings: TenantSettings) -> Self {
+        Self {
+            tenant_id: settings.tenant_id,
+            max_users: settings.limits.max_users,
+            max_page_size: settings.limits.max_page_size,
+            max_display_name_length: settings.limits.max_display_name_length,
+            updated_at: settings.updated_at,
+        }
+    }
+}
+
+impl From<PutTenantSettingsReq> for TenantLimitOverrides {
+    fn from(req: PutTenantSettingsReq) -> Self {
+        Self {
+            max_users: req.max_users,
+            max_page_size: req.max_page_size,
+            max_display_name_length: req.max_display_name_length,
+        }
+    }
+}
+
+// ==================== Webhook DTOs ====================
+
+/// User event types a webhook can subscribe to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[modkit_macros::api_dto(request, response)]
+pub enum WebhookEventTypeDto {
+    #[serde(rename = "user.created")]
+    UserCreated,
+    #[serde(rename = "user.updated")]
+    UserUpdated,
+    #[serde(rename = "user.deleted")]
+    UserDeleted,
+}
+
+/// REST DTO for webhook representation
+///
+/// `secret` is only present in the response to the create request.
+#[derive(Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct WebhookDto {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub url: String,
+    pub event_types: Vec<WebhookEventTypeDto>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+}
+
+/// REST DTO for registering a webhook
+#[derive(Clone)]
+#[modkit_macros::api_dto(request)]
+pub struct CreateWebhookReq {
+    pub tenant_id: Uuid,
+    /// HTTPS endpoint that receives `POST` deliveries
+    pub url: String,
+    /// Signing secret (min. 16 characters); generated when omitted
+    #[serde(default)]
+    pub secret: Option<String>,
+    pub event_types: Vec<WebhookEventTypeDto>,
+}
+
+/// Outcome of a webhook delivery attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[modkit_macros::api_dto(response)]
+pub enum WebhookDeliveryStatusDto {
+    /// The receiver answered with a 2xx status
+    Succeeded,
+    /// The attempt failed and will be retried
+    Retrying,
+    /// The attempt failed and will not be retried
+    Failed,
+}
+
+/// REST DTO for a recorded webhook delivery attempt
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub struct WebhookDeliveryDto {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventTypeDto,
+    pub attempt: u32,
+    pub status: WebhookDeliveryStatusDto,
+    pub response_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub attempted_at: OffsetDateTime,
+}
+
+impl From<WebhookEventTypeDto> for WebhookEventType {
+    fn from(event_type: WebhookEventTypeDto) -> Self {
+        match event_type {
+            WebhookEventTypeDto::UserCreated => Self::UserCreated,
+            WebhookEventTypeDto::UserUpdated => Self::UserUpdated,
+            WebhookEventTypeDto::UserDeleted => Self::UserDeleted,
+        }
+    }
+}
+
+impl From<WebhookEventType> for WebhookEventTypeDto {
+    fn from(event_type: WebhookEventType) -> Self {
+        match event_type {
+            WebhookEventType::UserCreated => Self::UserCreated,
+            WebhookEventType::UserUpdated => Self::UserUpdated,
+            WebhookEventType::UserDeleted => Self::UserDeleted,
+        }
+    }
+}
+
+impl From<DeliveryStatus> for WebhookDeliveryStatusDto {
+    fn from(status: DeliveryStatus) -> Self {
+        match status {
+            DeliveryStatus::Succeeded => Self::Succeeded,
+            DeliveryStatus::Retrying => Self::Retrying,
+            DeliveryStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<Webhook> for WebhookDto {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id,
+            tenant_id: webhook.tenant_id,
+            url: webhook.url,
+            event_types: webhook.event_types.into_iter().map(Into::into).collect(),
+            secret: None,
+            created_at: webhook.created_at,
+        }
+    }
+}
+
+impl WebhookDto {
+    /// Representation returned once, on creation, including the signing secret.
+    #[must_use]
+    pub fn with_secret(webhook: Webhook) -> Self {
+        let secret = webhook.secret.clone();
+        Self {
+            secret: Some(secret),
+            ..Self::from(webhook)
+        }
+    }
+}
+
+impl From<CreateWebhookReq> for NewWebhook {
+    fn from(req: CreateWebhookReq) -> Self {
+        Self {
+            tenant_id: req.tenant_id,
+            url: req.url,
+            secret: req.secret,
+            event_types: req.event_types.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryDto {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id,
+            webhook_id: delivery.webhook_id,
+            event_id: delivery.event_id,
+            event_type: delivery.event_type.into(),
+            attempt: delivery.attempt,
+            status: delivery.status.into(),
+            response_code: delivery.response_code,
+            latency_ms: delivery.latency_ms,
+            error: delivery.error,
+            attempted_at: delivery.attempted_at,
+        }
+    }
+}
+
 /// Transport-level SSE payload.
 #[derive(Debug, Clone)]
 #[modkit_macros::api_dto(request, response)]
@@ -232,17 +680,17 @@ impl From<&crate::domain::events::UserDomainEvent> for UserEvent {
     fn from(e: &crate::domain::events::UserDomainEvent) -> Self {
         use crate::domain::events::UserDomainEvent::{Created, Deleted, Updated};
         match e {
-            Created { id, at } => Self {
+            Created { id, at, .. } => Self {
                 kind: "created".into(),
                 id: *id,
                 at: *at,
             },
-            Updated { id, at } => Self {
+            Updated { id, at, .. } => Self {
                 kind: "updated".into(),
                 id: *id,
                 at: *at,
             },
-            Deleted { id, at } => Self {
+            Deleted { id, at, .. } => Self {
                 kind: "deleted".into(),
                 id: *id,
                 at: *at,
@@ -262,7 +710,11 @@ mod tests {
     fn maps_domain_event_to_transport() {
         let at = OffsetDateTime::from_unix_timestamp(1_699_963_200).unwrap();
         let id = Uuid::nil();
-        let de = UserDomainEvent::Created { id, at };
+        let de = UserDomainEvent::Created {
+            id,
+            tenant_id: Uuid::nil(),
+            at,
+        };
         let out = UserEvent::from(&de);
         assert_eq!(out.kind, "created");
         assert_eq!(out.id, id);
@@ -275,21 +727,33 @@ mod tests {
         let id = Uuid::nil();
 
         // Test Created event
-        let created = UserDomainEvent::Created { id, at };
+        let created = UserDomainEvent::Created {
+            id,
+            tenant_id: Uuid::nil(),
+            at,
+        };
         let created_event = UserEvent::from(&created);
         assert_eq!(created_event.kind, "created");
         assert_eq!(created_event.id, id);
         assert_eq!(created_event.at, at);
 
         // Test Updated event
-        let updated = UserDomainEvent::Updated { id, at };
+        let updated = UserDomainEvent::Updated {
+            id,
+            tenant_id: Uuid::nil(),
+            at,
+        };
         let updated_event = UserEvent::from(&updated);
         assert_eq!(updated_event.kind, "updated");
         assert_eq!(updated_event.id, id);
         assert_eq!(updated_event.at, at);
 
         // Test Deleted event
-        let deleted = UserDomainEvent::Deleted { id, at };
+        let deleted = UserDomainEvent::Deleted {
+            id,
+            tenant_id: Uuid::nil(),
+            at,
+        };
         let deleted_event = UserEvent::from(&deleted);
         assert_eq!(deleted_event.kind, "deleted");
         assert_eq!(deleted_event.id, id);
@@ -1,4 +1,4 @@
-use modkit::api::problem::Problem;
+use modkit::api::problem::{Problem, ValidationViolation};
 
 use crate::domain::error::DomainError;
 use crate::errors::ErrorCode;
@@ -30,6 +30,13 @@ pub fn domain_error_to_problem(e: &DomainError, instance: &str) -> Problem {
             ),
         DomainError::InvalidEmail { email } => ErrorCode::example1_user_invalid_email_v1()
             .with_context(format!("Email '{email}' is invalid"), instance, trace_id),
+        DomainError::EmailPolicyViolation { rule, .. } => ErrorCode::example1_user_validation_v1()
+            .with_context(format!("{e}"), instance, trace_id)
+            .with_errors(vec![ValidationViolation {
+                field: "email".to_owned(),
+                message: format!("rejected by policy rule '{rule}'"),
+                code: Some(rule.as_str().to_owned()),
+            }]),
         DomainError::EmptyDisplayName => ErrorCode::example1_user_validation_v1().with_context(
             "Display name cannot be empty",
             instance,
@@ -42,6 +49,13 @@ pub fn domain_error_to_problem(e: &DomainError, instance: &str) -> Problem {
                 trace_id,
             )
         }
+        DomainError::ReferenceNotInScope { field, .. } => ErrorCode::example1_user_validation_v1()
+            .with_context(format!("{e}"), instance, trace_id)
+            .with_errors(vec![ValidationViolation {
+                field: (*field).to_owned(),
+                message: "does not refer to an accessible entity".to_owned(),
+                code: Some("reference_not_in_scope".to_owned()),
+            }]),
         DomainError::Database { .. } => {
             // Log the internal error details but don't expose them to the client
             tracing::error!(error = ?e, "Database error occurred");
@@ -56,6 +70,41 @@ pub fn domain_error_to_problem(e: &DomainError, instance: &str) -> Problem {
             "Access denied",
             "You do not have permission to perform this action",
         ),
+        DomainError::FieldsNotEditable { fields } => Problem::new(
+            http::StatusCode::FORBIDDEN,
+            "Access denied",
+            format!("{e}"),
+        )
+        .with_errors(
+            fields
+                .iter()
+                .map(|field| ValidationViolation {
+                    field: field.clone(),
+                    message: "cannot be changed on the own profile".to_owned(),
+                    code: Some("field_not_editable".to_owned()),
+                })
+                .collect(),
+        ),
+        // Same problem as for a single entry, pointing at the failing one.
+        DomainError::BulkEntry { index, source } => {
+            let mut problem = domain_error_to_problem(source, instance);
+            problem.detail = format!("Entry {index}: {}", problem.detail);
+            problem
+        }
+        DomainError::UserLimitReached { .. } => Problem::new(
+            http::StatusCode::CONFLICT,
+            "Conflict",
+            format!("{e}"),
+        ),
+        DomainError::PayloadTooLarge { .. } => Problem::new(
+            http::StatusCode::PAYLOAD_TOO_LARGE,
+            "Payload Too Large",
+            format!("{e}"),
+        ),
+        // Disabled features are hidden: respond as if the resource does not exist.
+        DomainError::FeatureDisabled { .. } => {
+            Problem::new(http::StatusCode::NOT_FOUND, "Not Found", format!("{e}"))
+        }
         DomainError::InternalError => {
             tracing::error!(error = ?e, "Internal error occurred");
             ErrorCode::example1_user_internal_database_v1().with_context(
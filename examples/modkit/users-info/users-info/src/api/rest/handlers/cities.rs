@@ -1,13 +1,20 @@
-use axum::http::Uri;
+use axum::extract::{FromRequest, Multipart, Request};
+use axum::http::{Uri, header};
 use axum::response::{IntoResponse, Response};
+use futures_util::StreamExt;
 use uuid::Uuid;
 
 use super::{
-    ApiResult, CityDto, CreateCityReq, Json, JsonBody, JsonPage, SecurityContext, UpdateCityReq,
+    ApiResult, CityDto, CityImportReportDto, CreateCityReq, ImportCitiesQuery, Json, JsonBody,
+    JsonPage, PaginatedResponse, Problem, SecurityContext, StatusCode, UpdateCityReq, UserDto,
     apply_select, created_json, info, no_content, page_to_projected_json,
 };
+use crate::domain::error::DomainError;
 use crate::module::ConcreteAppServices;
 
+/// Multipart field that carries the CSV file.
+const IMPORT_FILE_FIELD: &str = "file";
+
 pub(super) async fn list_cities(
     ctx: SecurityContext,
     svc: std::sync::Arc<ConcreteAppServices>,
@@ -21,7 +28,10 @@ pub(super) async fn list_cities(
     let page = svc.cities.list_cities_page(&ctx, &query).await?;
     let page = page.map_items(CityDto::from);
 
-    Ok(Json(page_to_projected_json(&page, query.selected_fields())))
+    Ok(PaginatedResponse::from(page_to_projected_json(
+        &page,
+        query.selected_fields(),
+    )))
 }
 
 pub(super) async fn get_city(
@@ -44,6 +54,30 @@ pub(super) async fn get_city(
     Ok(Json(projected))
 }
 
+pub(super) async fn list_city_users(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    city_id: Uuid,
+    query: modkit::api::odata::ODataQuery,
+) -> ApiResult<JsonPage<serde_json::Value>> {
+    info!(
+        city_id = %city_id,
+        user_id = %ctx.subject_id(),
+        "Listing users of a city"
+    );
+
+    let page = svc
+        .users
+        .list_city_users_page(&ctx, city_id, &query)
+        .await?;
+    let page = page.map_items(UserDto::from);
+
+    Ok(PaginatedResponse::from(page_to_projected_json(
+        &page,
+        query.selected_fields(),
+    )))
+}
+
 pub(super) async fn create_city(
     uri: Uri,
     ctx: SecurityContext,
@@ -95,3 +129,78 @@ pub(super) async fn delete_city(
     svc.cities.delete_city(&ctx, id).await?;
     Ok(no_content().into_response())
 }
+
+pub(super) async fn import_cities(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    query: ImportCitiesQuery,
+    request: Request,
+) -> ApiResult<JsonBody<CityImportReportDto>> {
+    info!(
+        dry_run = query.dry_run,
+        creator_id = %ctx.subject_id(),
+        "Importing cities from CSV"
+    );
+
+    let csv = read_csv_upload(request, svc.cities.import_max_bytes()).await?;
+    let report = svc.cities.import_cities(&ctx, &csv, query.dry_run).await?;
+    Ok(Json(CityImportReportDto::from(report)))
+}
+
+/// Read the CSV from the multipart `file` field, or from the raw body for any
+/// other content type. The upload is consumed chunk by chunk and rejected with
+/// 413 as soon as it exceeds `max_bytes`.
+async fn read_csv_upload(request: Request, max_bytes: usize) -> Result<Vec<u8>, Problem> {
+    let is_multipart = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.starts_with("multipart/form-data"));
+
+    let mut csv = Vec::new();
+    let mut append = |chunk: &[u8]| {
+        if csv.len() + chunk.len() > max_bytes {
+            return Err(Problem::from(DomainError::payload_too_large(max_bytes)));
+        }
+        csv.extend_from_slice(chunk);
+        Ok(())
+    };
+
+    if is_multipart {
+        let mut multipart = Multipart::from_request(request, &())
+            .await
+            .map_err(|e| bad_upload(&e))?;
+        let mut found = false;
+        while let Some(mut field) = multipart.next_field().await.map_err(|e| bad_upload(&e))? {
+            if field.name() != Some(IMPORT_FILE_FIELD) {
+                continue;
+            }
+            found = true;
+            while let Some(chunk) = field.chunk().await.map_err(|e| bad_upload(&e))? {
+                append(&chunk)?;
+            }
+            break;
+        }
+        if !found {
+            return Err(DomainError::validation(
+                IMPORT_FILE_FIELD,
+                format!("missing multipart field '{IMPORT_FILE_FIELD}'"),
+            )
+            .into());
+        }
+    } else {
+        let mut body = request.into_body().into_data_stream();
+        while let Some(chunk) = body.next().await {
+            append(&chunk.map_err(|e| bad_upload(&e))?)?;
+        }
+    }
+    Ok(csv)
+}
+
+fn bad_upload(e: &impl std::fmt::Display) -> Problem {
+    Problem::new(
+        StatusCode::BAD_REQUEST,
+        "Bad Request",
+        format!("Could not read the upload: {e}"),
+    )
+}
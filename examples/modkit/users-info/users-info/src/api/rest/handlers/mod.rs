@@ -1,10 +1,16 @@
-use axum::{Extension, extract::Path, http::Uri};
+use axum::{
+    Extension,
+    extract::{Path, Query},
+    http::Uri,
+};
 use tracing::{field::Empty, info};
 use uuid::Uuid;
 
 use crate::api::rest::dto::{
-    AddressDto, CityDto, CreateCityReq, CreateUserReq, PutAddressReq, UpdateCityReq, UpdateUserReq,
-    UserDto, UserEvent, UserFullDto,
+    AddressDto, CityDto, CityImportReportDto, CreateCityReq, CreateUserReq, CreateWebhookReq,
+    ImportCitiesQuery, PurgeTenantQuery, PutAddressReq, PutTenantSettingsReq, TenantPurgeReportDto,
+    TenantSettingsDto, UpdateCityReq, UpdateUserReq, UserDto, UserEvent, UserFullDto,
+    UserListItemDto, WebhookDeliveryDto, WebhookDto,
 };
 
 use modkit::api::odata::OData;
@@ -19,7 +25,9 @@ use modkit_security::SecurityContext;
 mod addresses;
 mod cities;
 mod events;
+mod tenant_data;
 mod users;
+mod webhooks;
 
 // ==================== User Handlers ====================
 
@@ -100,6 +108,25 @@ pub(crate) async fn create_user(
     users::create_user(uri, ctx, svc, new_user).await
 }
 
+/// Create several users atomically
+#[tracing::instrument(
+    skip(svc, req_body, ctx, query),
+    fields(
+        users.count = req_body.len(),
+        request_id = Empty,
+        creator.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn create_users_bulk(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    OData(query): OData,
+    Json(req_body): Json<Vec<CreateUserReq>>,
+) -> ApiResult<impl IntoResponse> {
+    let new_users = req_body.into_iter().map(Into::into).collect();
+    users::create_users_bulk(ctx, svc, new_users, query).await
+}
+
 /// Update an existing user
 #[tracing::instrument(
     skip(svc, req_body, ctx),
@@ -135,6 +162,37 @@ pub(crate) async fn delete_user(
     users::delete_user(ctx, svc, id).await
 }
 
+/// Get the caller's own profile
+#[tracing::instrument(
+    skip(svc, ctx),
+    fields(
+        request_id = Empty,
+        subject.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn get_me(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+) -> ApiResult<JsonBody<UserDto>> {
+    users::get_me(ctx, svc).await
+}
+
+/// Update the caller's own profile
+#[tracing::instrument(
+    skip(svc, req_body, ctx),
+    fields(
+        request_id = Empty,
+        subject.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn update_me(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Json(req_body): Json<UpdateUserReq>,
+) -> ApiResult<JsonBody<UserDto>> {
+    users::update_me(ctx, svc, req_body).await
+}
+
 // ==================== Event Handlers (SSE) ====================
 
 /// SSE endpoint returning a live stream of `UserEvent`.
@@ -185,6 +243,25 @@ pub(crate) async fn get_city(
     cities::get_city(ctx, svc, id, query).await
 }
 
+/// List the users with an address in a city, with cursor-based pagination
+#[tracing::instrument(
+    skip(svc, query, ctx),
+    fields(
+        city.id = %id,
+        limit = query.limit,
+        request_id = Empty,
+        user.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn list_city_users(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(id): Path<Uuid>,
+    OData(query): OData,
+) -> ApiResult<JsonPage<serde_json::Value>> {
+    cities::list_city_users(ctx, svc, id, query).await
+}
+
 /// Create a new city
 #[tracing::instrument(
     skip(svc, req_body, ctx, uri),
@@ -293,3 +370,167 @@ pub(crate) async fn delete_user_address(
 ) -> ApiResult<impl IntoResponse> {
     addresses::delete_user_address(ctx, svc, user_id).await
 }
+
+/// Bulk-create cities from a CSV upload (multipart `file` field or raw body)
+#[tracing::instrument(
+    skip(svc, ctx, query, request),
+    fields(
+        dry_run = query.dry_run,
+        request_id = Empty,
+        creator.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn import_cities(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Query(query): Query<ImportCitiesQuery>,
+    request: axum::extract::Request,
+) -> ApiResult<JsonBody<CityImportReportDto>> {
+    cities::import_cities(ctx, svc, query, request).await
+}
+
+// ==================== Tenant Data Handlers ====================
+
+/// Erase or anonymize all data owned by a tenant
+#[tracing::instrument(
+    skip(svc, ctx, query),
+    fields(
+        tenant.id = %tenant_id,
+        request_id = Empty,
+        requester.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn purge_tenant(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(tenant_id): Path<Uuid>,
+    Query(query): Query<PurgeTenantQuery>,
+) -> ApiResult<JsonBody<TenantPurgeReportDto>> {
+    tenant_data::purge_tenant(ctx, svc, tenant_id, query).await
+}
+
+/// Get a tenant's limit overrides
+#[tracing::instrument(
+    skip(svc, ctx),
+    fields(
+        tenant.id = %tenant_id,
+        request_id = Empty,
+        requester.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn get_tenant_settings(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(tenant_id): Path<Uuid>,
+) -> ApiResult<JsonBody<TenantSettingsDto>> {
+    tenant_data::get_tenant_settings(ctx, svc, tenant_id).await
+}
+
+/// Replace a tenant's limit overrides
+#[tracing::instrument(
+    skip(svc, req_body, ctx),
+    fields(
+        tenant.id = %tenant_id,
+        request_id = Empty,
+        requester.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn put_tenant_settings(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(tenant_id): Path<Uuid>,
+    Json(req_body): Json<PutTenantSettingsReq>,
+) -> ApiResult<JsonBody<TenantSettingsDto>> {
+    tenant_data::put_tenant_settings(ctx, svc, tenant_id, req_body).await
+}
+
+// ==================== Webhook Handlers ====================
+
+/// List webhooks with cursor-based pagination
+#[tracing::instrument(
+    skip(svc, query, ctx),
+    fields(
+        limit = query.limit,
+        request_id = Empty,
+        user.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn list_webhooks(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    OData(query): OData,
+) -> ApiResult<JsonPage<serde_json::Value>> {
+    webhooks::list_webhooks(ctx, svc, query).await
+}
+
+/// Get a specific webhook by ID with optional field projection via $select
+#[tracing::instrument(
+    skip(svc, ctx, query),
+    fields(
+        webhook.id = %id,
+        request_id = Empty,
+        requester.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn get_webhook(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(id): Path<Uuid>,
+    OData(query): OData,
+) -> ApiResult<JsonBody<serde_json::Value>> {
+    webhooks::get_webhook(ctx, svc, id, query).await
+}
+
+/// Register a webhook
+#[tracing::instrument(
+    skip(svc, req_body, ctx, uri),
+    fields(
+        webhook.tenant_id = %req_body.tenant_id,
+        request_id = Empty,
+        creator.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn create_webhook(
+    uri: Uri,
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Json(req_body): Json<CreateWebhookReq>,
+) -> ApiResult<impl IntoResponse> {
+    webhooks::create_webhook(uri, ctx, svc, req_body).await
+}
+
+/// Delete a webhook by ID
+#[tracing::instrument(
+    skip(svc, ctx),
+    fields(
+        webhook.id = %id,
+        request_id = Empty,
+        deleter.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn delete_webhook(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<impl IntoResponse> {
+    webhooks::delete_webhook(ctx, svc, id).await
+}
+
+/// List delivery attempts of a webhook with cursor-based pagination
+#[tracing::instrument(
+    skip(svc, query, ctx),
+    fields(
+        webhook.id = %id,
+        limit = query.limit,
+        request_id = Empty,
+        requester.id = %ctx.subject_id()
+    )
+)]
+pub(crate) async fn list_webhook_deliveries(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(svc): Extension<std::sync::Arc<ConcreteAppServices>>,
+    Path(id): Path<Uuid>,
+    OData(query): OData,
+) -> ApiResult<JsonPage<serde_json::Value>> {
+    webhooks::list_webhook_deliveries(ctx, svc, id, query).await
+}
@@ -0,0 +1,58 @@
+use uuid::Uuid;
+
+use super::{
+    ApiResult, Json, JsonBody, PurgeTenantQuery, PutTenantSettingsReq, SecurityContext,
+    TenantPurgeReportDto, TenantSettingsDto, info,
+};
+use crate::module::ConcreteAppServices;
+
+pub(super) async fn purge_tenant(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    tenant_id: Uuid,
+    query: PurgeTenantQuery,
+) -> ApiResult<JsonBody<TenantPurgeReportDto>> {
+    info!(
+        tenant_id = %tenant_id,
+        mode = ?query.mode,
+        requester_id = %ctx.subject_id(),
+        "Purging tenant data"
+    );
+
+    let report = svc
+        .tenant_data
+        .purge_tenant(&ctx, tenant_id, query.mode.into())
+        .await?;
+
+    Ok(Json(TenantPurgeReportDto::from(report)))
+}
+
+pub(super) async fn get_tenant_settings(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    tenant_id: Uuid,
+) -> ApiResult<JsonBody<TenantSettingsDto>> {
+    let settings = svc.tenant_settings.get_settings(&ctx, tenant_id).await?;
+
+    Ok(Json(TenantSettingsDto::from(settings)))
+}
+
+pub(super) async fn put_tenant_settings(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    tenant_id: Uuid,
+    req_body: PutTenantSettingsReq,
+) -> ApiResult<JsonBody<TenantSettingsDto>> {
+    info!(
+        tenant_id = %tenant_id,
+        requester_id = %ctx.subject_id(),
+        "Updating tenant settings"
+    );
+
+    let settings = svc
+        .tenant_settings
+        .put_settings(&ctx, tenant_id, req_body.into())
+        .await?;
+
+    Ok(Json(TenantSettingsDto::from(settings)))
+}
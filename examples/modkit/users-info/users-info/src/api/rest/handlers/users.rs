@@ -3,8 +3,9 @@ use axum::response::{IntoResponse, Response};
 use uuid::Uuid;
 
 use super::{
-    ApiResult, Json, JsonBody, JsonPage, SecurityContext, UpdateUserReq, UserDto, UserFullDto,
-    apply_select, created_json, info, no_content, page_to_projected_json,
+    ApiResult, Json, JsonBody, JsonPage, PaginatedResponse, SecurityContext, StatusCode,
+    UpdateUserReq, UserDto, UserFullDto, UserListItemDto, apply_select, created_json, info,
+    no_content, page_to_projected_json,
 };
 use crate::module::ConcreteAppServices;
 
@@ -18,10 +19,16 @@ pub(super) async fn list_users(
         "Listing users with cursor pagination"
     );
 
-    let page = svc.users.list_users_page(&ctx, &query).await?;
-    let page = page.map_items(UserDto::from);
+    let page = svc
+        .users
+        .list_users_page_with_summaries(&ctx, &query)
+        .await?;
+    let page = page.map_items(UserListItemDto::from);
 
-    Ok(Json(page_to_projected_json(&page, query.selected_fields())))
+    Ok(PaginatedResponse::from(page_to_projected_json(
+        &page,
+        query.selected_fields(),
+    )))
 }
 
 pub(super) async fn get_user(
@@ -53,6 +60,26 @@ pub(super) async fn create_user(
     Ok(created_json(UserDto::from(user), &uri, &id_str).into_response())
 }
 
+pub(super) async fn create_users_bulk(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    new_users: Vec<users_info_sdk::NewUser>,
+    query: modkit::api::odata::ODataQuery,
+) -> ApiResult<(StatusCode, JsonBody<Vec<serde_json::Value>>)> {
+    info!(
+        count = new_users.len(),
+        creator_id = %ctx.subject_id(),
+        "Creating users in bulk"
+    );
+
+    let users = svc.users.create_users_bulk(&ctx, new_users).await?;
+    let projected = users
+        .into_iter()
+        .map(|user| apply_select(&UserDto::from(user), query.selected_fields()))
+        .collect();
+    Ok((StatusCode::CREATED, Json(projected)))
+}
+
 pub(super) async fn update_user(
     ctx: SecurityContext,
     svc: std::sync::Arc<ConcreteAppServices>,
@@ -70,6 +97,27 @@ pub(super) async fn update_user(
     Ok(Json(UserDto::from(user)))
 }
 
+pub(super) async fn get_me(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+) -> ApiResult<JsonBody<UserDto>> {
+    info!(subject_id = %ctx.subject_id(), "Getting own profile");
+
+    let user = svc.users.get_me(&ctx).await?;
+    Ok(Json(UserDto::from(user)))
+}
+
+pub(super) async fn update_me(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    req_body: UpdateUserReq,
+) -> ApiResult<JsonBody<UserDto>> {
+    info!(subject_id = %ctx.subject_id(), "Updating own profile");
+
+    let user = svc.users.update_me(&ctx, req_body.into()).await?;
+    Ok(Json(UserDto::from(user)))
+}
+
 pub(super) async fn delete_user(
     ctx: SecurityContext,
     svc: std::sync::Arc<ConcreteAppServices>,
@@ -0,0 +1,102 @@
+use axum::http::Uri;
+use axum::response::{IntoResponse, Response};
+use uuid::Uuid;
+
+use super::{
+    ApiResult, CreateWebhookReq, Json, JsonBody, JsonPage, PaginatedResponse, SecurityContext,
+    WebhookDeliveryDto, WebhookDto, apply_select, created_json, info, no_content,
+    page_to_projected_json,
+};
+use crate::module::ConcreteAppServices;
+
+pub(super) async fn list_webhooks(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    query: modkit::api::odata::ODataQuery,
+) -> ApiResult<JsonPage<serde_json::Value>> {
+    info!(
+        user_id = %ctx.subject_id(),
+        "Listing webhooks with cursor pagination"
+    );
+
+    let page = svc.webhooks.list_webhooks_page(&ctx, &query).await?;
+    let page = page.map_items(WebhookDto::from);
+
+    Ok(PaginatedResponse::from(page_to_projected_json(
+        &page,
+        query.selected_fields(),
+    )))
+}
+
+pub(super) async fn get_webhook(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    id: Uuid,
+    query: modkit::api::odata::ODataQuery,
+) -> ApiResult<JsonBody<serde_json::Value>> {
+    info!(
+        webhook_id = %id,
+        requester_id = %ctx.subject_id(),
+        "Getting webhook details"
+    );
+
+    let webhook = svc.webhooks.get_webhook(&ctx, id).await?;
+    let webhook_dto = WebhookDto::from(webhook);
+
+    let projected = apply_select(&webhook_dto, query.selected_fields());
+
+    Ok(Json(projected))
+}
+
+pub(super) async fn create_webhook(
+    uri: Uri,
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    req_body: CreateWebhookReq,
+) -> ApiResult<Response> {
+    info!(
+        tenant_id = %req_body.tenant_id,
+        creator_id = %ctx.subject_id(),
+        "Creating new webhook"
+    );
+
+    let webhook = svc.webhooks.create_webhook(&ctx, req_body.into()).await?;
+    let id_str = webhook.id.to_string();
+    Ok(created_json(WebhookDto::with_secret(webhook), &uri, &id_str).into_response())
+}
+
+pub(super) async fn delete_webhook(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    id: Uuid,
+) -> ApiResult<Response> {
+    info!(
+        webhook_id = %id,
+        deleter_id = %ctx.subject_id(),
+        "Deleting webhook"
+    );
+
+    svc.webhooks.delete_webhook(&ctx, id).await?;
+    Ok(no_content().into_response())
+}
+
+pub(super) async fn list_webhook_deliveries(
+    ctx: SecurityContext,
+    svc: std::sync::Arc<ConcreteAppServices>,
+    id: Uuid,
+    query: modkit::api::odata::ODataQuery,
+) -> ApiResult<JsonPage<serde_json::Value>> {
+    info!(
+        webhook_id = %id,
+        requester_id = %ctx.subject_id(),
+        "Listing webhook deliveries"
+    );
+
+    let page = svc.webhooks.list_deliveries_page(&ctx, id, &query).await?;
+    let page = page.map_items(WebhookDeliveryDto::from);
+
+    Ok(PaginatedResponse::from(page_to_projected_json(
+        &page,
+        query.selected_fields(),
+    )))
+}
@@ -1,12 +1,19 @@
 use super::{License, dto, handlers};
+use crate::domain::service::features;
 use axum::Router;
+use modkit::FeatureGate;
 use modkit::api::OpenApiRegistry;
 use modkit::api::operation_builder::OperationBuilder;
 
-pub(super) fn register_address_routes(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
+pub(super) fn register_address_routes(
+    mut router: Router,
+    openapi: &dyn OpenApiRegistry,
+    feature_gate: &FeatureGate,
+) -> Router {
     // GET /users-info/v1/users/{id}/address - Get user's address
     router = OperationBuilder::get("/users-info/v1/users/{id}/address")
         .operation_id("users_info.get_user_address")
+        .feature_gate(feature_gate, features::ADDRESSES)
         .authenticated()
         .require_license_features::<License>([])
         .summary("Get user address")
@@ -24,6 +31,7 @@ pub(super) fn register_address_routes(mut router: Router, openapi: &dyn OpenApiR
     // PUT /users-info/v1/users/{id}/address - Upsert user's address
     router = OperationBuilder::put("/users-info/v1/users/{id}/address")
         .operation_id("users_info.put_user_address")
+        .feature_gate(feature_gate, features::ADDRESSES)
         .authenticated()
         .require_license_features::<License>([])
         .summary("Upsert user address")
@@ -47,6 +55,7 @@ pub(super) fn register_address_routes(mut router: Router, openapi: &dyn OpenApiR
     // DELETE /users-info/v1/users/{id}/address - Delete user's address
     router = OperationBuilder::delete("/users-info/v1/users/{id}/address")
         .operation_id("users_info.delete_user_address")
+        .feature_gate(feature_gate, features::ADDRESSES)
         .authenticated()
         .require_license_features::<License>([])
         .summary("Delete user address")
@@ -2,7 +2,7 @@ use super::{License, dto, handlers};
 use axum::Router;
 use modkit::api::OpenApiRegistry;
 use modkit::api::operation_builder::{OperationBuilder, OperationBuilderODataExt};
-use users_info_sdk::odata::CityFilterField;
+use users_info_sdk::odata::{CityFilterField, CityUserFilterField, UserFilterField};
 
 pub(super) fn register_city_routes(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
     // GET /users-info/v1/cities - List cities with cursor-based pagination
@@ -13,15 +13,9 @@ pub(super) fn register_city_routes(mut router: Router, openapi: &dyn OpenApiRegi
         .tag("cities")
         .authenticated()
         .require_license_features::<License>([])
-        .query_param_typed(
-            "limit",
-            false,
-            "Maximum number of cities to return",
-            "integer",
-        )
-        .query_param("cursor", false, "Cursor for pagination")
+        .with_cursor_pagination()
         .handler(handlers::list_cities)
-        .json_response_with_schema::<modkit_odata::Page<dto::CityDto>>(
+        .json_response_with_schema::<modkit::api::PaginatedResponse<dto::CityDto>>(
             openapi,
             http::StatusCode::OK,
             "Paginated list of cities",
@@ -51,6 +45,36 @@ pub(super) fn register_city_routes(mut router: Router, openapi: &dyn OpenApiRegi
         .error_500(openapi)
         .register(router, openapi);
 
+    // GET /users-info/v1/cities/{id}/users - List the users of a city
+    router = OperationBuilder::get("/users-info/v1/cities/{id}/users")
+        .operation_id("users_info.list_city_users")
+        .summary("List users of a city")
+        .description(
+            "Retrieve a paginated list of the users with an address in the city. \
+             `street` and `postal_code` in `$filter` match the user's addresses in the city; \
+             `$orderby` accepts user fields only.",
+        )
+        .tag("cities")
+        .authenticated()
+        .require_license_features::<License>([])
+        .path_param("id", "City UUID")
+        .with_cursor_pagination()
+        .handler(handlers::list_city_users)
+        .json_response_with_schema::<modkit::api::PaginatedResponse<dto::UserDto>>(
+            openapi,
+            http::StatusCode::OK,
+            "Paginated list of users",
+        )
+        .with_odata_filter::<CityUserFilterField>()
+        .with_odata_select()
+        .with_odata_orderby::<UserFilterField>()
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
     // POST /users-info/v1/cities - Create a new city
     router = OperationBuilder::post("/users-info/v1/cities")
         .operation_id("users_info.create_city")
@@ -73,6 +97,51 @@ pub(super) fn register_city_routes(mut router: Router, openapi: &dyn OpenApiRegi
         .error_500(openapi)
         .register(router, openapi);
 
+    // POST /users-info/v1/cities:import - Bulk-create cities from CSV
+    router = OperationBuilder::post("/users-info/v1/cities:import")
+        .operation_id("users_info.import_cities")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Import cities from CSV")
+        .description(
+            "Create cities from a CSV file with a header row and `name`, `country` and \
+             optional `tenant_id` columns; rows without a tenant belong to the caller's \
+             tenant. Send the file as multipart field `file` or as the raw body. Names that \
+             already exist in the tenant are skipped; invalid or unauthorized rows are \
+             reported by line number.",
+        )
+        .tag("cities")
+        .query_param_typed(
+            "dry_run",
+            false,
+            "Validate and report without creating anything (default false)",
+            "boolean",
+        )
+        .multipart_file_request("file", Some("CSV file"))
+        .allow_content_types(&[
+            "multipart/form-data",
+            "text/csv",
+            "application/octet-stream",
+        ])
+        .handler(handlers::import_cities)
+        .json_response_with_schema::<dto::CityImportReportDto>(
+            openapi,
+            http::StatusCode::OK,
+            "Created, skipped and failed rows",
+        )
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .problem_response(
+            openapi,
+            http::StatusCode::PAYLOAD_TOO_LARGE,
+            "File exceeds the size limit",
+        )
+        .error_415(openapi)
+        .error_422(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
     // PATCH /users-info/v1/cities/{id} - Update a city
     router = OperationBuilder::patch("/users-info/v1/cities/{id}")
         .operation_id("users_info.update_city")
@@ -3,10 +3,13 @@
 //! ## Architecture
 //!
 //! This module defines REST routes with `OpenAPI` metadata organized by resource:
-//! - `users` - User endpoints (5: list, get, create, update, delete)
-//! - `cities` - City endpoints (5: list, get, create, update, delete)
-//! - `addresses` - Address endpoints (3: get, upsert, delete)
+//! - `users` - User endpoints (7: list, get, get own, create, bulk create, update, delete)
+//! - `profile` - Self-service endpoints on the caller's own user (2: get, update), rate-limited per subject
+//! - `cities` - City endpoints (6: list, get, create, import, update, delete)
+//! - `addresses` - Address endpoints (3: get, upsert, delete), gated by the `addresses` feature
 //! - `events` - SSE event stream (1: user events)
+//! - `tenant_data` - Tenant data lifecycle and settings (3: purge, get settings, put settings)
+//! - `webhooks` - Webhook subscriptions (5: list, get, create, delete, list deliveries)
 //!
 //! ## `OData` Integration
 //!
@@ -26,6 +29,7 @@
 use crate::api::rest::{dto, handlers};
 use crate::module::ConcreteAppServices;
 use axum::Router;
+use modkit::FeatureGate;
 use modkit::api::OpenApiRegistry;
 use modkit::api::operation_builder::LicenseFeature;
 use std::sync::Arc;
@@ -33,7 +37,10 @@ use std::sync::Arc;
 mod addresses;
 mod cities;
 mod events;
+mod profile;
+mod tenant_data;
 mod users;
+mod webhooks;
 
 pub(super) struct License;
 
@@ -50,13 +57,20 @@ impl LicenseFeature for License {}
 pub(crate) fn register_routes(
     mut router: Router,
     openapi: &dyn OpenApiRegistry,
-    services: Arc<ConcreteAppServices>,
+    features: &FeatureGate,
+    services: Option<Arc<ConcreteAppServices>>,
 ) -> Router {
     router = users::register_user_routes(router, openapi);
+    router = profile::register_profile_routes(router, openapi);
     router = cities::register_city_routes(router, openapi);
-    router = addresses::register_address_routes(router, openapi);
+    router = addresses::register_address_routes(router, openapi, features);
+    router = tenant_data::register_tenant_data_routes(router, openapi);
+    router = webhooks::register_webhook_routes(router, openapi);
 
-    router = router.layer(axum::Extension(services));
+    // `None` only in a dry run, where the router is discarded
+    if let Some(services) = services {
+        router = router.layer(axum::Extension(services));
+    }
 
     router
 }
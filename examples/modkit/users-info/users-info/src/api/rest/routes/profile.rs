@@ -0,0 +1,61 @@
+use super::{License, dto, handlers};
+use axum::Router;
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::OperationBuilder;
+
+/// Per-subject limits: a user needs only a handful of calls on their own profile.
+/// The read limits also cover the `GET /users-info/v1/users/me` alias.
+pub(super) const READ_RPS: u32 = 2;
+pub(super) const READ_BURST: u32 = 10;
+const WRITE_RPS: u32 = 1;
+const WRITE_BURST: u32 = 3;
+pub(super) const IN_FLIGHT: u32 = 64;
+
+pub(super) fn register_profile_routes(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
+    // GET /users-info/v1/me - The caller's own profile
+    router = OperationBuilder::get("/users-info/v1/me")
+        .operation_id("users_info.get_me")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Get own profile")
+        .description(
+            "Retrieve the user the authenticated subject maps to. \
+             Authorized as `get` on that user; no path parameter is involved.",
+        )
+        .tag("profile")
+        .per_subject_rate_limit(READ_RPS, READ_BURST, IN_FLIGHT)
+        .handler(handlers::get_me)
+        .json_response_with_schema::<dto::UserDto>(openapi, http::StatusCode::OK, "Own profile")
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_429(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // PATCH /users-info/v1/me - Update the caller's own profile
+    router = OperationBuilder::patch("/users-info/v1/me")
+        .operation_id("users_info.update_me")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Update own profile")
+        .description(
+            "Partially update the caller's own user. Only self-editable fields \
+             (by default `display_name`) may be set; others are rejected with 403.",
+        )
+        .tag("profile")
+        .per_subject_rate_limit(WRITE_RPS, WRITE_BURST, IN_FLIGHT)
+        .json_request::<dto::UpdateUserReq>(openapi, "Profile update data")
+        .handler(handlers::update_me)
+        .json_response_with_schema::<dto::UserDto>(openapi, http::StatusCode::OK, "Updated profile")
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_409(openapi)
+        .error_429(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    router
+}
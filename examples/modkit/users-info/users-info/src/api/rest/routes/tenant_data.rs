@@ -0,0 +1,93 @@
+use super::{License, dto, handlers};
+use axum::Router;
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::OperationBuilder;
+
+pub(super) fn register_tenant_data_routes(
+    mut router: Router,
+    openapi: &dyn OpenApiRegistry,
+) -> Router {
+    // POST /users-info/v1/tenants/{tenant_id}/purge - Erase or anonymize tenant data
+    router = OperationBuilder::post("/users-info/v1/tenants/{tenant_id}/purge")
+        .operation_id("users_info.purge_tenant")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Purge tenant data")
+        .description(
+            "Delete (mode=erase) or anonymize (mode=anonymize) all users, addresses and \
+             cities owned by the tenant. Requires a DELETE grant on `users_info.tenant_data` \
+             whose scope contains the tenant.",
+        )
+        .tag("tenants")
+        .path_param("tenant_id", "Tenant UUID")
+        .query_param(
+            "mode",
+            false,
+            "Purge mode: `erase` (default) or `anonymize`",
+        )
+        .handler(handlers::purge_tenant)
+        .json_response_with_schema::<dto::TenantPurgeReportDto>(
+            openapi,
+            http::StatusCode::OK,
+            "Affected row counts per table",
+        )
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // GET /users-info/v1/tenants/{tenant_id}/settings - Get tenant limit overrides
+    router = OperationBuilder::get("/users-info/v1/tenants/{tenant_id}/settings")
+        .operation_id("users_info.get_tenant_settings")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Get tenant settings")
+        .description(
+            "Per-tenant overrides of the user quota, page size and display name limits; \
+             `null` fields use the global configuration. Requires a GET grant on \
+             `users_info.tenant_settings` whose scope contains the tenant.",
+        )
+        .tag("tenants")
+        .path_param("tenant_id", "Tenant UUID")
+        .handler(handlers::get_tenant_settings)
+        .json_response_with_schema::<dto::TenantSettingsDto>(
+            openapi,
+            http::StatusCode::OK,
+            "Tenant limit overrides",
+        )
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // PUT /users-info/v1/tenants/{tenant_id}/settings - Replace tenant limit overrides
+    router = OperationBuilder::put("/users-info/v1/tenants/{tenant_id}/settings")
+        .operation_id("users_info.put_tenant_settings")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Replace tenant settings")
+        .description(
+            "Replace the tenant's limit overrides; omitted fields fall back to the global \
+             configuration. Page size and display name overrides may not exceed the global \
+             limits. Requires an UPDATE grant on `users_info.tenant_settings` whose scope \
+             contains the tenant.",
+        )
+        .tag("tenants")
+        .path_param("tenant_id", "Tenant UUID")
+        .json_request::<dto::PutTenantSettingsReq>(openapi, "Tenant limit overrides")
+        .handler(handlers::put_tenant_settings)
+        .json_response_with_schema::<dto::TenantSettingsDto>(
+            openapi,
+            http::StatusCode::OK,
+            "Stored tenant limit overrides",
+        )
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_422(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    router
+}
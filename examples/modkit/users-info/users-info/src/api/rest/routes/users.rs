@@ -1,38 +1,68 @@
+use super::profile::{IN_FLIGHT, READ_BURST, READ_RPS};
 use super::{License, dto, handlers};
 use axum::Router;
 use modkit::api::OpenApiRegistry;
 use modkit::api::operation_builder::{OperationBuilder, OperationBuilderODataExt};
-use users_info_sdk::odata::UserFilterField;
+use users_info_sdk::odata::{UserFilterField, UserSearch};
 
 pub(super) fn register_user_routes(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
     // GET /users-info/v1/users - List users with cursor-based pagination
     router = OperationBuilder::get("/users-info/v1/users")
         .operation_id("users_info.list_users")
         .summary("List users with cursor pagination")
-        .description("Retrieve a paginated list of users using cursor-based pagination")
+        .description(
+            "Retrieve a paginated list of users using cursor-based pagination. \
+             Add `address_summary` to `$select` to include each user's address and city name.",
+        )
         .tag("users")
         .authenticated()
-        .query_param_typed(
-            "limit",
-            false,
-            "Maximum number of users to return",
-            "integer",
-        )
         .require_license_features::<License>([])
-        .query_param("cursor", false, "Cursor for pagination")
+        .with_cursor_pagination()
         .handler(handlers::list_users)
-        .json_response_with_schema::<modkit_odata::Page<dto::UserDto>>(
+        .json_response_with_example::<modkit::api::PaginatedResponse<dto::UserListItemDto>>(
             openapi,
             http::StatusCode::OK,
             "Paginated list of users",
+            &modkit::api::PaginatedResponse::new(
+                vec![dto::UserListItemDto::example()],
+                Some("eyJrIjpbIjAxOTQ2MmExIl19".to_owned()),
+                50,
+            ),
         )
         .with_odata_filter::<UserFilterField>()
+        .with_odata_search::<UserSearch>()
         .with_odata_select()
         .with_odata_orderby::<UserFilterField>()
         .error_400(openapi)
         .error_500(openapi)
         .register(router, openapi);
 
+    // GET /users-info/v1/users/me - The caller's own user record
+    //
+    // Registered before `/users/{id}`, which it overlaps: `me` must reach this
+    // handler rather than be parsed as a user UUID (and rejected with 400). The
+    // literal segment takes precedence; the gateway lists the overlap in its
+    // route conflict report.
+    router = OperationBuilder::get("/users-info/v1/users/me")
+        .operation_id("users_info.get_own_user")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Get own user")
+        .description(
+            "Retrieve the user the authenticated subject maps to, without knowing its UUID. \
+             Same as `GET /users-info/v1/me`: authorized as `get` on that user's id.",
+        )
+        .tag("users")
+        // Same throttle as `GET /users-info/v1/me`, so the alias cannot bypass it
+        .per_subject_rate_limit(READ_RPS, READ_BURST, IN_FLIGHT)
+        .handler(handlers::get_me)
+        .json_response_with_schema::<dto::UserDto>(openapi, http::StatusCode::OK, "Own user")
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
     // GET /users-info/v1/users/{id} - Get a specific user
     router = OperationBuilder::get("/users-info/v1/users/{id}")
         .operation_id("users_info.get_user")
@@ -59,7 +89,11 @@ pub(super) fn register_user_routes(mut router: Router, openapi: &dyn OpenApiRegi
         .summary("Create a new user")
         .description("Create a new user with the provided information")
         .tag("users")
-        .json_request::<dto::CreateUserReq>(openapi, "User creation data")
+        .json_request_example::<dto::CreateUserReq>(
+            openapi,
+            "User creation data",
+            &dto::CreateUserReq::example(),
+        )
         .handler(handlers::create_user)
         .json_response_with_schema::<dto::UserDto>(
             openapi,
@@ -73,6 +107,37 @@ pub(super) fn register_user_routes(mut router: Router, openapi: &dyn OpenApiRegi
         .error_500(openapi)
         .register(router, openapi);
 
+    // POST /users-info/v1/users/bulk - Create several users atomically
+    router = OperationBuilder::post("/users-info/v1/users/bulk")
+        .operation_id("users_info.create_users_bulk")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Create users in bulk")
+        .description(
+            "Create several users in one transaction. Either every user is created or none; \
+             errors name the index of the first failing entry.",
+        )
+        .tag("users")
+        .json_request_example::<Vec<dto::CreateUserReq>>(
+            openapi,
+            "Users to create",
+            &vec![dto::CreateUserReq::example()],
+        )
+        .handler(handlers::create_users_bulk)
+        .json_response_with_schema::<Vec<dto::UserDto>>(
+            openapi,
+            http::StatusCode::CREATED,
+            "Created users, in request order",
+        )
+        .with_odata_select()
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_409(openapi)
+        .error_422(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
     // PATCH /users-info/v1/users/{id} - Partially update a user
     router = OperationBuilder::patch("/users-info/v1/users/{id}")
         .operation_id("users_info.update_user")
@@ -0,0 +1,123 @@
+use super::{License, dto, handlers};
+use axum::Router;
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::{OperationBuilder, OperationBuilderODataExt};
+use users_info_sdk::odata::{WebhookDeliveryFilterField, WebhookFilterField};
+
+pub(super) fn register_webhook_routes(mut router: Router, openapi: &dyn OpenApiRegistry) -> Router {
+    // GET /users-info/v1/webhooks - List webhooks with cursor-based pagination
+    router = OperationBuilder::get("/users-info/v1/webhooks")
+        .operation_id("users_info.list_webhooks")
+        .summary("List webhooks with cursor pagination")
+        .description(
+            "Retrieve a paginated list of webhook subscriptions (secrets are never returned)",
+        )
+        .tag("webhooks")
+        .authenticated()
+        .require_license_features::<License>([])
+        .with_cursor_pagination()
+        .handler(handlers::list_webhooks)
+        .json_response_with_schema::<modkit::api::PaginatedResponse<dto::WebhookDto>>(
+            openapi,
+            http::StatusCode::OK,
+            "Paginated list of webhooks",
+        )
+        .with_odata_filter::<WebhookFilterField>()
+        .with_odata_select()
+        .with_odata_orderby::<WebhookFilterField>()
+        .error_400(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // POST /users-info/v1/webhooks - Register a webhook
+    router = OperationBuilder::post("/users-info/v1/webhooks")
+        .operation_id("users_info.create_webhook")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Register a webhook")
+        .description(
+            "Subscribe an HTTPS endpoint to user lifecycle events. Deliveries are signed \
+             with HMAC-SHA256 in the `X-Webhook-Signature` header (`t=<unix>,v1=<hex>` over \
+             `<t>.<body>`). The signing secret is returned only in this response.",
+        )
+        .tag("webhooks")
+        .json_request::<dto::CreateWebhookReq>(openapi, "Webhook registration data")
+        .handler(handlers::create_webhook)
+        .json_response_with_schema::<dto::WebhookDto>(
+            openapi,
+            http::StatusCode::CREATED,
+            "Created webhook, including its signing secret",
+        )
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // GET /users-info/v1/webhooks/{id} - Get a specific webhook
+    router = OperationBuilder::get("/users-info/v1/webhooks/{id}")
+        .operation_id("users_info.get_webhook")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Get webhook by ID")
+        .description("Retrieve a webhook subscription by its UUID (the secret is never returned)")
+        .tag("webhooks")
+        .path_param("id", "Webhook UUID")
+        .handler(handlers::get_webhook)
+        .json_response_with_schema::<dto::WebhookDto>(
+            openapi,
+            http::StatusCode::OK,
+            "Webhook found",
+        )
+        .with_odata_select()
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // DELETE /users-info/v1/webhooks/{id} - Delete a webhook
+    router = OperationBuilder::delete("/users-info/v1/webhooks/{id}")
+        .operation_id("users_info.delete_webhook")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("Delete webhook")
+        .description("Delete a webhook subscription and its delivery log")
+        .tag("webhooks")
+        .path_param("id", "Webhook UUID")
+        .handler(handlers::delete_webhook)
+        .json_response(http::StatusCode::NO_CONTENT, "Webhook deleted successfully")
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    // GET /users-info/v1/webhooks/{id}/deliveries - List delivery attempts
+    router = OperationBuilder::get("/users-info/v1/webhooks/{id}/deliveries")
+        .operation_id("users_info.list_webhook_deliveries")
+        .authenticated()
+        .require_license_features::<License>([])
+        .summary("List webhook deliveries")
+        .description("Recorded delivery attempts (status, response code, latency), newest first")
+        .tag("webhooks")
+        .path_param("id", "Webhook UUID")
+        .with_cursor_pagination()
+        .handler(handlers::list_webhook_deliveries)
+        .json_response_with_schema::<modkit::api::PaginatedResponse<dto::WebhookDeliveryDto>>(
+            openapi,
+            http::StatusCode::OK,
+            "Paginated list of delivery attempts",
+        )
+        .with_odata_filter::<WebhookDeliveryFilterField>()
+        .with_odata_select()
+        .with_odata_orderby::<WebhookDeliveryFilterField>()
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_404(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    router
+}
@@ -0,0 +1,202 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Contract tests: every registered operation is called and its response
+//! checked against the `OpenAPI` spec the routes register.
+
+use std::collections::HashMap;
+
+use axum::http::Method;
+use modkit::FeatureGate;
+use modkit::api::OpenApiRegistryImpl;
+use modkit::testing::{ContractClient, ContractHarness, Fixture};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+use crate::api::rest::routes;
+use crate::domain::service::ServiceConfig;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db};
+
+/// Tenant of the `CreateUserReq` example, so the example body is accepted.
+const TENANT: Uuid = Uuid::from_u128(0x0194_62a0_7c1e_7d3b_9f2a_5c8e_1b4d_6a70);
+/// City referenced by the `put_user_address` body.
+const CITY: Uuid = Uuid::from_u128(0x0194_62a1_0b5f_7e21_8c4d_2a9e_6f13_c001);
+
+async fn create_user(client: &ContractClient) -> anyhow::Result<String> {
+    let user = client
+        .post_json(
+            "/users-info/v1/users",
+            &json!({
+                "tenant_id": TENANT,
+                "email": format!("{}@example.com", Uuid::new_v4()),
+                "display_name": "Contract User",
+            }),
+        )
+        .await?;
+    Ok(id_of(&user))
+}
+
+async fn create_city(client: &ContractClient, id: Option<Uuid>) -> anyhow::Result<String> {
+    let city = client
+        .post_json(
+            "/users-info/v1/cities",
+            &json!({ "id": id, "tenant_id": TENANT, "name": "Lisbon", "country": "PT" }),
+        )
+        .await?;
+    Ok(id_of(&city))
+}
+
+fn id_of(resource: &Value) -> String {
+    resource["id"].as_str().expect("resource id").to_owned()
+}
+
+fn with_user() -> Fixture {
+    Fixture::new().setup(|client| async move {
+        Ok(HashMap::from([(
+            "id".to_owned(),
+            create_user(&client).await?,
+        )]))
+    })
+}
+
+fn with_city() -> Fixture {
+    Fixture::new().setup(|client| async move {
+        Ok(HashMap::from([(
+            "id".to_owned(),
+            create_city(&client, None).await?,
+        )]))
+    })
+}
+
+fn with_webhook() -> Fixture {
+    Fixture::new().setup(|client| async move {
+        let webhook = client
+            .post_json("/users-info/v1/webhooks", &webhook_body())
+            .await?;
+        Ok(HashMap::from([("id".to_owned(), id_of(&webhook))]))
+    })
+}
+
+fn webhook_body() -> Value {
+    json!({
+        "tenant_id": TENANT,
+        "url": "https://hooks.example.com/users",
+        "event_types": ["user.created"],
+    })
+}
+
+#[tokio::test]
+async fn routes_match_registered_openapi() {
+    let api = OpenApiRegistryImpl::default();
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let router = routes::register_routes(
+        axum::Router::new(),
+        &api,
+        &FeatureGate::all_enabled("users-info"),
+        Some(services),
+    )
+    .layer(axum::Extension(ctx_allow_tenants(&[TENANT])));
+
+    let report = ContractHarness::new(&api, router)
+        .path_param("tenant_id", TENANT.to_string())
+        .fixture("users_info.list_users", Fixture::new().expect_status(200))
+        .fixture("users_info.create_user", Fixture::new().expect_status(201))
+        .fixture(
+            "users_info.create_users_bulk",
+            Fixture::new()
+                .body(json!([{
+                    "tenant_id": TENANT,
+                    "email": "bulk@example.com",
+                    "display_name": "Bulk"
+                }]))
+                .expect_status(201),
+        )
+        .fixture("users_info.get_user", with_user().expect_status(200))
+        .fixture(
+            "users_info.update_user",
+            with_user()
+                .body(json!({ "display_name": "Renamed" }))
+                .expect_status(200),
+        )
+        .fixture("users_info.delete_user", with_user().expect_status(204))
+        .fixture("users_info.list_cities", Fixture::new().expect_status(200))
+        .fixture(
+            "users_info.create_city",
+            Fixture::new()
+                .body(json!({ "tenant_id": TENANT, "name": "Porto", "country": "PT" }))
+                .expect_status(201),
+        )
+        .fixture("users_info.get_city", with_city().expect_status(200))
+        .fixture(
+            "users_info.update_city",
+            with_city()
+                .body(json!({ "name": "Braga" }))
+                .expect_status(200),
+        )
+        .fixture("users_info.delete_city", with_city().expect_status(204))
+        .fixture(
+            "users_info.import_cities",
+            Fixture::new().skip("multipart upload; covered by the service import tests"),
+        )
+        .fixture(
+            "users_info.get_user_address",
+            Fixture::new()
+                .setup(|client| async move {
+                    let user = create_user(&client).await?;
+                    let city = create_city(&client, None).await?;
+                    let (status, _, _) = client
+                        .send(
+                            Method::PUT,
+                            &format!("/users-info/v1/users/{user}/address"),
+                            Some(&json!({
+                                "city_id": city,
+                                "street": "Rua Augusta 1",
+                                "postal_code": "1100-048",
+                            })),
+                        )
+                        .await?;
+                    anyhow::ensure!(status.is_success(), "put address returned {status}");
+                    Ok(HashMap::from([("id".to_owned(), user)]))
+                })
+                .expect_status(200),
+        )
+        .fixture(
+            "users_info.put_user_address",
+            Fixture::new()
+                .setup(|client| async move {
+                    create_city(&client, Some(CITY)).await?;
+                    Ok(HashMap::from([(
+                        "id".to_owned(),
+                        create_user(&client).await?,
+                    )]))
+                })
+                .body(json!({
+                    "city_id": CITY,
+                    "street": "Rua Augusta 2",
+                    "postal_code": "1100-048",
+                })),
+        )
+        .fixture("users_info.delete_user_address", with_user())
+        .fixture(
+            "users_info.list_webhooks",
+            Fixture::new().expect_status(200),
+        )
+        .fixture(
+            "users_info.create_webhook",
+            Fixture::new().body(webhook_body()).expect_status(201),
+        )
+        .fixture("users_info.get_webhook", with_webhook().expect_status(200))
+        .fixture("users_info.list_webhook_deliveries", with_webhook())
+        .fixture(
+            "users_info.delete_webhook",
+            with_webhook().expect_status(204),
+        )
+        .run()
+        .await;
+
+    report.assert_passed();
+    assert!(
+        report.results.iter().filter(|r| r.status.is_some()).count() >= 18,
+        "expected every users-info operation to be exercised: {:#?}",
+        report.results
+    );
+}
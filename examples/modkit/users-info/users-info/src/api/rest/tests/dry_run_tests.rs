@@ -0,0 +1,152 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! The `OpenAPI` document built by a dry run (`--emit-openapi`) against the one
+//! served by a fully booted gateway.
+
+use std::sync::Arc;
+
+use authz_resolver_sdk::AuthZResolverClient;
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{Request, StatusCode},
+};
+use modkit::config::ConfigProvider;
+use modkit::contracts::{ApiGatewayCapability, RestApiCapability};
+use modkit::registry::RegistryBuilder;
+use modkit::runtime::{DbOptions, HostRuntime};
+use modkit::{ClientHub, Module, ModuleCtx};
+use modkit_db::{DBProvider, DbError};
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::module::UsersInfo;
+use crate::test_support::{MockAuthZResolver, inmem_db};
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        self.config.get(module)
+    }
+}
+
+fn config() -> Arc<TestConfigProvider> {
+    Arc::new(TestConfigProvider {
+        config: json!({
+            "api-gateway": {
+                "config": {
+                    "bind_addr": "0.0.0.0:8080",
+                    "auth_disabled": true,
+                    "enable_docs": true,
+                    "openapi": { "title": "Users Info", "version": "1.2.3" },
+                }
+            }
+        }),
+    })
+}
+
+/// Boot the gateway and users-info with a database and fetch `/openapi.json`.
+async fn served_openapi() -> serde_json::Value {
+    let config = config();
+    let hub = Arc::new(ClientHub::new());
+    hub.register::<dyn AuthZResolverClient>(Arc::new(MockAuthZResolver));
+
+    let gateway_ctx = ModuleCtx::new(
+        "api-gateway",
+        Uuid::new_v4(),
+        config.clone(),
+        hub.clone(),
+        CancellationToken::new(),
+        None,
+    );
+    let gateway = api_gateway::ApiGateway::default();
+    gateway.init(&gateway_ctx).await.unwrap();
+
+    let db: DBProvider<DbError> = DBProvider::new(inmem_db().await);
+    let users_ctx = ModuleCtx::new(
+        "users-info",
+        Uuid::new_v4(),
+        config,
+        hub,
+        CancellationToken::new(),
+        Some(db),
+    );
+    let users_info = UsersInfo::default();
+    users_info.init(&users_ctx).await.unwrap();
+
+    let router = gateway.rest_prepare(&gateway_ctx, Router::new()).unwrap();
+    let router = users_info
+        .register_rest(&users_ctx, router, &gateway)
+        .unwrap();
+    let router = gateway.rest_finalize(&gateway_ctx, router).unwrap();
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/openapi.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+/// Emit the document through the runtime's dry run: no database, no server.
+async fn emitted_openapi() -> utoipa::openapi::OpenApi {
+    let gateway = Arc::new(api_gateway::ApiGateway::default());
+    let users_info = Arc::new(UsersInfo::default());
+
+    let mut builder = RegistryBuilder::default();
+    builder.register_core_with_meta("api-gateway", &[], gateway.clone() as Arc<dyn Module>);
+    builder.register_rest_host_with_meta("api-gateway", gateway);
+    builder.register_core_with_meta(
+        "users-info",
+        &["api-gateway"],
+        users_info.clone() as Arc<dyn Module>,
+    );
+    builder.register_rest_with_meta("users-info", users_info);
+
+    HostRuntime::new(
+        builder.build_topo_sorted().unwrap(),
+        config(),
+        DbOptions::None,
+        Arc::new(ClientHub::new()),
+        CancellationToken::new(),
+        Uuid::new_v4(),
+        None,
+    )
+    .emit_openapi()
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn emitted_document_matches_served_document() {
+    let emitted = serde_json::to_value(emitted_openapi().await).unwrap();
+    let served = served_openapi().await;
+
+    assert_eq!(emitted["info"]["title"], "Users Info");
+    assert!(
+        emitted["paths"]
+            .as_object()
+            .unwrap()
+            .contains_key("/users-info/v1/users")
+    );
+    assert_eq!(emitted, served);
+}
+
+#[tokio::test]
+async fn consecutive_emissions_are_byte_identical() {
+    let first = modkit::api::openapi_to_sorted_json(&emitted_openapi().await).unwrap();
+    let second = modkit::api::openapi_to_sorted_json(&emitted_openapi().await).unwrap();
+
+    assert_eq!(first, second);
+}
@@ -0,0 +1,55 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::api::rest::routes;
+use crate::domain::service::{ServiceConfig, features};
+use crate::test_support::{build_services, inmem_db};
+use modkit::FeatureGate;
+use modkit::api::{OpenApiInfo, OpenApiRegistryImpl};
+
+// JSON pointer for `/users-info/v1/users/{id}/address` (`/` escaped as `~1`)
+const ADDRESS_PATH: &str = "/paths/~1users-info~1v1~1users~1{id}~1address";
+const USERS_PATH: &str = "/paths/~1users-info~1v1~1users";
+
+async fn openapi_with_features(features: &FeatureGate) -> serde_json::Value {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let api = OpenApiRegistryImpl::default();
+
+    let _router = routes::register_routes(axum::Router::new(), &api, features, Some(services));
+
+    let doc = api.build_openapi(&OpenApiInfo::default()).expect("openapi");
+    serde_json::to_value(&doc).expect("json")
+}
+
+#[tokio::test]
+async fn openapi_includes_address_routes_when_feature_enabled() {
+    let v = openapi_with_features(&FeatureGate::all_enabled("users-info")).await;
+
+    let address = v.pointer(ADDRESS_PATH).expect("address path missing");
+    assert!(address.get("get").is_some());
+    assert!(address.get("put").is_some());
+    assert!(address.get("delete").is_some());
+}
+
+#[tokio::test]
+async fn openapi_omits_address_routes_when_feature_disabled() {
+    let gate = FeatureGate::new("users-info", [(features::ADDRESSES.to_owned(), false)]);
+    let v = openapi_with_features(&gate).await;
+
+    assert!(v.pointer(ADDRESS_PATH).is_none());
+    // Ungated routes are unaffected
+    assert!(v.pointer(USERS_PATH).is_some());
+}
+
+#[tokio::test]
+async fn openapi_lists_own_user_route_next_to_user_by_id() {
+    let v = openapi_with_features(&FeatureGate::all_enabled("users-info")).await;
+
+    let own = v
+        .pointer("/paths/~1users-info~1v1~1users~1me/get")
+        .expect("own user route missing");
+    assert_eq!(own["operationId"], "users_info.get_own_user");
+    assert!(
+        v.pointer("/paths/~1users-info~1v1~1users~1{id}/get")
+            .is_some()
+    );
+}
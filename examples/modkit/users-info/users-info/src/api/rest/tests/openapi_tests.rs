@@ -0,0 +1,136 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use crate::api::rest::{dto, routes};
+use crate::domain::service::ServiceConfig;
+use crate::test_support::{build_services, inmem_db};
+use modkit::FeatureGate;
+use modkit::api::{OpenApiInfo, OpenApiRegistryImpl};
+
+const USERS_PATH: &str = "/paths/~1users-info~1v1~1users";
+
+async fn registry() -> OpenApiRegistryImpl {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let api = OpenApiRegistryImpl::default();
+    let features = FeatureGate::all_enabled("users-info");
+
+    let _router = routes::register_routes(axum::Router::new(), &api, &features, Some(services));
+    api
+}
+
+fn to_json(api: &OpenApiRegistryImpl) -> serde_json::Value {
+    let doc = api.build_openapi(&OpenApiInfo::default()).expect("openapi");
+    serde_json::to_value(&doc).expect("json")
+}
+
+async fn openapi() -> serde_json::Value {
+    to_json(&registry().await)
+}
+
+/// The same operations with every shared parameter and response inlined.
+fn inlined(api: &OpenApiRegistryImpl) -> OpenApiRegistryImpl {
+    let inline = OpenApiRegistryImpl::default();
+    inline
+        .components_registry
+        .store(api.components_registry.load_full());
+    for entry in &api.operation_specs {
+        let mut spec = entry.value().clone();
+        spec.params.iter_mut().for_each(|p| p.component = None);
+        spec.responses.iter_mut().for_each(|r| r.component = None);
+        inline.register_operation(&spec);
+    }
+    inline
+}
+
+fn collect_refs<'a>(value: &'a serde_json::Value, refs: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            if let Some(serde_json::Value::String(r)) = map.get("$ref") {
+                refs.push(r);
+            }
+            map.values().for_each(|v| collect_refs(v, refs));
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_refs(v, refs)),
+        _ => {}
+    }
+}
+
+#[tokio::test]
+async fn create_user_request_has_example() {
+    let v = openapi().await;
+
+    let example = v
+        .pointer(&format!(
+            "{USERS_PATH}/post/requestBody/content/application~1json/example"
+        ))
+        .expect("create_user request example missing");
+    assert_eq!(
+        example,
+        &serde_json::to_value(dto::CreateUserReq::example()).unwrap()
+    );
+    assert_eq!(example["email"], "jane.doe@example.com");
+    // Optional id is omitted rather than rendered as null
+    assert!(example.get("id").is_none());
+}
+
+#[tokio::test]
+async fn list_users_response_has_example() {
+    let v = openapi().await;
+
+    let example = v
+        .pointer(&format!(
+            "{USERS_PATH}/get/responses/200/content/application~1json/example"
+        ))
+        .expect("list_users response example missing");
+    let user = &example["items"][0];
+    assert_eq!(user["created_at"], "2026-01-15T10:30:00Z");
+    assert!(uuid::Uuid::parse_str(user["id"].as_str().unwrap()).is_ok());
+    assert_eq!(example["pageSize"], 50);
+
+    // Operations without examples are unaffected
+    assert!(
+        v.pointer(&format!(
+            "{USERS_PATH}/get/responses/500/content/application~1problem+json/example"
+        ))
+        .is_none()
+    );
+}
+
+#[tokio::test]
+async fn shared_components_shrink_document_without_dangling_refs() {
+    let api = registry().await;
+    let shared = to_json(&api);
+    let inline = to_json(&inlined(&api));
+
+    // List operations reference the shared OData and error components
+    let list_users = shared.pointer(&format!("{USERS_PATH}/get")).unwrap();
+    let params = list_users["parameters"].as_array().unwrap();
+    assert!(params.contains(&serde_json::json!({"$ref": "#/components/parameters/ODataFilter"})));
+    assert!(params.contains(&serde_json::json!({"$ref": "#/components/parameters/Cursor"})));
+    assert_eq!(
+        list_users.pointer("/responses/500/$ref"),
+        Some(&serde_json::json!(
+            "#/components/responses/InternalServerError"
+        ))
+    );
+    // Per-type filter fields moved from the parameter to the operation description
+    let description = list_users["description"].as_str().unwrap();
+    assert!(description.contains("`$filter` fields:"), "{description}");
+    assert!(description.contains("- email: "), "{description}");
+
+    let mut refs = Vec::new();
+    collect_refs(&shared, &mut refs);
+    assert!(!refs.is_empty());
+    for r in refs {
+        let pointer = r.strip_prefix('#').expect("local $ref");
+        assert!(shared.pointer(pointer).is_some(), "dangling $ref {r}");
+    }
+
+    let shared_len = serde_json::to_string(&shared).unwrap().len();
+    let inline_len = serde_json::to_string(&inline).unwrap().len();
+    assert!(
+        shared_len * 100 < inline_len * 95,
+        "shared components should save at least 5%: {shared_len} bytes shared vs \
+         {inline_len} bytes inlined ({}% smaller)",
+        100_usize.saturating_sub(shared_len * 100 / inline_len)
+    );
+}
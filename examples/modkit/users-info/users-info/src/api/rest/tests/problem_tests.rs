@@ -0,0 +1,133 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Problem responses served through the API gateway: `instance`, OTel `trace_id`
+//! and registered problem types.
+
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{Request, StatusCode},
+};
+use modkit::api::ProblemTypeRegistry;
+use modkit::config::ConfigProvider;
+use modkit::contracts::ApiGatewayCapability;
+use modkit::{ClientHub, FeatureGate, Module, ModuleCtx};
+use opentelemetry::trace::TracerProvider as _;
+use serde_json::json;
+use tower::ServiceExt;
+use tracing_subscriber::layer::SubscriberExt as _;
+use uuid::Uuid;
+
+use crate::api::rest::routes;
+use crate::domain::service::ServiceConfig;
+use crate::errors::ErrorCode;
+use crate::test_support::{build_services, inmem_db};
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        self.config.get(module)
+    }
+}
+
+/// users-info routes behind a fully wired, auth-disabled gateway.
+async fn gateway_router(expose_types: bool) -> Router {
+    let config = json!({
+        "api-gateway": {
+            "config": {
+                "bind_addr": "0.0.0.0:8080",
+                "auth_disabled": true,
+                "problems": { "expose_types": expose_types },
+            }
+        }
+    });
+    let ctx = ModuleCtx::new(
+        "api-gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config }),
+        Arc::new(ClientHub::new()),
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    );
+    let gateway = api_gateway::ApiGateway::default();
+    gateway.init(&ctx).await.unwrap();
+
+    crate::errors::register_problem_types(ctx.problem_types());
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let router = routes::register_routes(
+        Router::new(),
+        &gateway,
+        &FeatureGate::all_enabled("users-info"),
+        Some(services),
+    );
+    gateway.rest_finalize(&ctx, router).unwrap()
+}
+
+async fn get_json(router: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri(uri)
+                .header("x-request-id", "req-404")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn not_found_carries_instance_trace_id_and_registered_type() {
+    let provider = opentelemetry_sdk::trace::SdkTracerProvider::builder().build();
+    let subscriber = tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("users-info-test")));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let router = gateway_router(false).await;
+    let path = format!("/users-info/v1/users/{}", Uuid::new_v4());
+    let (status, problem) = get_json(router, &path).await;
+
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(problem["instance"], format!("{path}#req-404"));
+
+    let trace_id = problem["trace_id"].as_str().expect("trace_id");
+    assert_eq!(
+        trace_id.len(),
+        32,
+        "expected an OTel trace id, got {trace_id}"
+    );
+    assert!(trace_id.chars().all(|c| c.is_ascii_hexdigit()));
+
+    let def = ErrorCode::example1_user_not_found_v1().def();
+    assert_eq!(problem["type"], def.type_url);
+    let registered = ProblemTypeRegistry::global()
+        .get(def.type_url)
+        .expect("users-info catalog registered");
+    assert_eq!(registered.title, "User Not Found");
+    assert_eq!(problem["title"], registered.title);
+}
+
+#[tokio::test]
+async fn problem_types_endpoint_lists_catalog() {
+    let router = gateway_router(true).await;
+    let (status, types) = get_json(router, "/problem-types").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let def = ErrorCode::example1_user_not_found_v1().def();
+    let entry = types
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|t| t["type"] == def.type_url)
+        .expect("users-info type listed");
+    assert_eq!(entry["title"], "User Not Found");
+    assert_eq!(entry["status"], 404);
+}
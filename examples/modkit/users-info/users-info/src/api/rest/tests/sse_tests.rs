@@ -73,6 +73,7 @@ async fn sse_adapter_publishes_domain_events() {
     let timestamp = OffsetDateTime::now_utc();
     let domain_event = UserDomainEvent::Created {
         id: user_id,
+        tenant_id: Uuid::nil(),
         at: timestamp,
     };
 
@@ -102,6 +103,7 @@ async fn sse_adapter_handles_all_event_types() {
     // Test Created event
     adapter.publish(&UserDomainEvent::Created {
         id: user_id,
+        tenant_id: Uuid::nil(),
         at: timestamp,
     });
     let event = timeout(Duration::from_millis(100), stream.next())
@@ -113,6 +115,7 @@ async fn sse_adapter_handles_all_event_types() {
     // Test Updated event
     adapter.publish(&UserDomainEvent::Updated {
         id: user_id,
+        tenant_id: Uuid::nil(),
         at: timestamp,
     });
     let event = timeout(Duration::from_millis(100), stream.next())
@@ -124,6 +127,7 @@ async fn sse_adapter_handles_all_event_types() {
     // Test Deleted event
     adapter.publish(&UserDomainEvent::Deleted {
         id: user_id,
+        tenant_id: Uuid::nil(),
         at: timestamp,
     });
     let event = timeout(Duration::from_millis(100), stream.next())
@@ -12,6 +12,29 @@ pub struct UsersInfoConfig {
     pub audit_base_url: String,
     #[serde(default = "default_notifications_base_url")]
     pub notifications_base_url: String,
+    /// Email normalization and validation rules.
+    #[serde(default)]
+    pub email: EmailPolicyConfig,
+    /// Outbound webhook delivery settings.
+    #[serde(default)]
+    pub webhooks: WebhooksConfig,
+    /// Self-service profile endpoints (`/users-info/v1/me`).
+    #[serde(default)]
+    pub self_service: SelfServiceConfig,
+    /// City CSV import (`/users-info/v1/cities:import`).
+    #[serde(default)]
+    pub city_import: CityImportConfig,
+    /// Match `$search` with Postgres full-text search instead of `ILIKE`.
+    /// Requires the GIN indexes created by the user search migration.
+    #[serde(default)]
+    pub full_text_search: bool,
+    /// Users per tenant unless overridden in the tenant's settings.
+    /// Unlimited if unset.
+    #[serde(default)]
+    pub max_users_per_tenant: Option<u32>,
+    /// How long resolved tenant settings are cached by each instance.
+    #[serde(default = "default_tenant_settings_cache_ttl_secs")]
+    pub tenant_settings_cache_ttl_secs: u64,
 }
 
 impl Default for UsersInfoConfig {
@@ -21,6 +44,142 @@ impl Default for UsersInfoConfig {
             max_page_size: default_max_page_size(),
             audit_base_url: default_audit_base_url(),
             notifications_base_url: default_notifications_base_url(),
+            email: EmailPolicyConfig::default(),
+            webhooks: WebhooksConfig::default(),
+            self_service: SelfServiceConfig::default(),
+            city_import: CityImportConfig::default(),
+            full_text_search: false,
+            max_users_per_tenant: None,
+            tenant_settings_cache_ttl_secs: default_tenant_settings_cache_ttl_secs(),
+        }
+    }
+}
+
+/// Email policy applied on user create and update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmailPolicyConfig {
+    /// Lowercase the whole address.
+    #[serde(default = "default_true")]
+    pub lowercase: bool,
+    /// Apply Unicode NFC normalization.
+    #[serde(default = "default_true")]
+    pub unicode_nfc: bool,
+    /// Drop `+tag` from the local part.
+    #[serde(default)]
+    pub strip_plus_addressing: bool,
+    /// If non-empty, only these domains are accepted.
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+    /// Domains that are always rejected.
+    #[serde(default)]
+    pub blocked_domains: Vec<String>,
+    /// Maximum length of the normalized address.
+    #[serde(default = "default_email_max_length")]
+    pub max_length: usize,
+}
+
+impl Default for EmailPolicyConfig {
+    fn default() -> Self {
+        Self {
+            lowercase: true,
+            unicode_nfc: true,
+            strip_plus_addressing: false,
+            allowed_domains: Vec::new(),
+            blocked_domains: Vec::new(),
+            max_length: default_email_max_length(),
+        }
+    }
+}
+
+/// Outbound webhook delivery and target URL rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhooksConfig {
+    /// Attempts per delivery, including the first one.
+    #[serde(default = "default_webhook_max_attempts")]
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further retry.
+    #[serde(default = "default_webhook_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    /// Upper bound for a single retry delay.
+    #[serde(default = "default_webhook_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    /// Per-attempt request timeout.
+    #[serde(default = "default_webhook_timeout_ms")]
+    pub timeout_ms: u64,
+    /// Events buffered for delivery before new events are dropped.
+    #[serde(default = "default_webhook_queue_capacity")]
+    pub queue_capacity: usize,
+    /// Target address ranges that are rejected (SSRF protection).
+    #[serde(default = "default_webhook_denied_cidrs")]
+    pub denied_cidrs: Vec<String>,
+    /// Hosts that bypass `denied_cidrs`, e.g. an internal receiver.
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+    /// Accept plain `http://` targets. Intended for local development only.
+    #[serde(default)]
+    pub allow_insecure_http: bool,
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_webhook_max_attempts(),
+            initial_backoff_ms: default_webhook_initial_backoff_ms(),
+            max_backoff_ms: default_webhook_max_backoff_ms(),
+            timeout_ms: default_webhook_timeout_ms(),
+            queue_capacity: default_webhook_queue_capacity(),
+            denied_cidrs: default_webhook_denied_cidrs(),
+            allowed_hosts: Vec::new(),
+            allow_insecure_http: false,
+        }
+    }
+}
+
+/// How `/me` finds the caller's user and what the caller may change there.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SelfServiceConfig {
+    /// Subject ids are user ids. When disabled, or when no such user exists,
+    /// the user is looked up by its `external_subject_id`.
+    #[serde(default = "default_true")]
+    pub subject_is_user_id: bool,
+    /// Fields a user may change on their own profile: `display_name`, `email`.
+    #[serde(default = "default_self_editable_fields")]
+    pub editable_fields: Vec<String>,
+}
+
+impl Default for SelfServiceConfig {
+    fn default() -> Self {
+        Self {
+            subject_is_user_id: true,
+            editable_fields: default_self_editable_fields(),
+        }
+    }
+}
+
+/// Limits for city CSV imports.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CityImportConfig {
+    /// Largest accepted file; larger uploads are rejected with 413.
+    #[serde(default = "default_city_import_max_bytes")]
+    pub max_bytes: usize,
+    /// Largest accepted number of data rows; more are rejected with 422.
+    #[serde(default = "default_city_import_max_rows")]
+    pub max_rows: usize,
+    /// Rows inserted per transaction.
+    #[serde(default = "default_city_import_chunk_size")]
+    pub chunk_size: usize,
+}
+
+impl Default for CityImportConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: default_city_import_max_bytes(),
+            max_rows: default_city_import_max_rows(),
+            chunk_size: default_city_import_chunk_size(),
         }
     }
 }
@@ -33,6 +192,10 @@ fn default_max_page_size() -> u32 {
     1000
 }
 
+fn default_tenant_settings_cache_ttl_secs() -> u64 {
+    30
+}
+
 fn default_audit_base_url() -> String {
     "http://audit.local".to_owned()
 }
@@ -40,3 +203,54 @@ fn default_audit_base_url() -> String {
 fn default_notifications_base_url() -> String {
     "http://notifications.local".to_owned()
 }
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_email_max_length() -> usize {
+    254
+}
+
+fn default_self_editable_fields() -> Vec<String> {
+    vec!["display_name".to_owned()]
+}
+
+fn default_webhook_max_attempts() -> u32 {
+    5
+}
+
+fn default_webhook_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_webhook_max_backoff_ms() -> u64 {
+    30_000
+}
+
+fn default_webhook_timeout_ms() -> u64 {
+    10_000
+}
+
+fn default_webhook_queue_capacity() -> usize {
+    1024
+}
+
+fn default_webhook_denied_cidrs() -> Vec<String> {
+    crate::domain::webhooks::DEFAULT_DENIED_CIDRS
+        .iter()
+        .map(|cidr| (*cidr).to_owned())
+        .collect()
+}
+
+fn default_city_import_max_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_city_import_max_rows() -> usize {
+    10_000
+}
+
+fn default_city_import_chunk_size() -> usize {
+    500
+}
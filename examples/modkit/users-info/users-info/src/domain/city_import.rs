@@ -0,0 +1,373 @@
+//! Bulk city import from CSV (`POST /users-info/v1/cities:import`).
+//!
+//! The file starts with a header row naming a `name` and a `country` column
+//! and, optionally, a `tenant_id` column; rows without a tenant belong to the
+//! caller's tenant. This module parses and validates the rows; authorization
+//! and persistence happen in `CitiesService::import_cities`.
+
+use std::collections::HashMap;
+
+use modkit_macros::domain_model;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+/// Size limits and batching for city imports.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CityImportLimits {
+    /// Largest accepted upload in bytes (413 above).
+    pub max_bytes: usize,
+    /// Largest accepted number of data rows (422 above).
+    pub max_rows: usize,
+    /// Rows inserted per transaction.
+    pub chunk_size: usize,
+}
+
+impl Default for CityImportLimits {
+    fn default() -> Self {
+        Self {
+            max_bytes: 1024 * 1024,
+            max_rows: 10_000,
+            chunk_size: 500,
+        }
+    }
+}
+
+/// Why a row was not imported.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RowRejection {
+    #[error("expected {expected} fields, got {got}")]
+    FieldCount { expected: usize, got: usize },
+
+    #[error("name is empty")]
+    EmptyName,
+
+    #[error("country '{0}' is not a two-letter country code")]
+    InvalidCountry(String),
+
+    #[error("tenant_id '{0}' is not a UUID")]
+    InvalidTenantId(String),
+
+    #[error("duplicate of row {first_row}")]
+    DuplicateInFile { first_row: usize },
+
+    #[error("tenant {0} is not in the caller's access scope")]
+    TenantNotInScope(Uuid),
+
+    #[error("a city with this name already exists in the tenant")]
+    AlreadyExists,
+}
+
+impl RowRejection {
+    /// Stable machine-readable code for API responses.
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::FieldCount { .. } => "field_count",
+            Self::EmptyName => "empty_name",
+            Self::InvalidCountry(_) => "invalid_country",
+            Self::InvalidTenantId(_) => "invalid_tenant_id",
+            Self::DuplicateInFile { .. } => "duplicate_in_file",
+            Self::TenantNotInScope(_) => "tenant_not_in_scope",
+            Self::AlreadyExists => "already_exists",
+        }
+    }
+}
+
+/// A validated row.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityImportRow {
+    /// Line on which the row starts; the header is line 1.
+    pub row: usize,
+    pub tenant_id: Uuid,
+    pub name: String,
+    pub country: String,
+}
+
+/// A row that was not imported.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedRow {
+    /// Line on which the row starts; the header is line 1.
+    pub row: usize,
+    pub reason: RowRejection,
+}
+
+/// Outcome of an import. Every data row appears in exactly one list.
+#[domain_model]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CityImportReport {
+    /// Nothing was written; `created` lists the rows that would be created.
+    pub dry_run: bool,
+    pub created: Vec<CityImportRow>,
+    /// Rows whose name already exists in their tenant.
+    pub skipped: Vec<RejectedRow>,
+    /// Rows that failed validation or authorization.
+    pub failed: Vec<RejectedRow>,
+}
+
+/// Rows of a parsed file, split into valid and invalid ones.
+#[domain_model]
+#[derive(Debug, Default)]
+pub struct ParsedCityImport {
+    pub rows: Vec<CityImportRow>,
+    pub failed: Vec<RejectedRow>,
+}
+
+/// Parse and validate a CSV file.
+///
+/// Fields may be quoted (`"São Paulo, SP"`, `""` for a literal quote); blank
+/// lines are ignored. Country codes are upper-cased. A name that repeats
+/// within a tenant fails as a duplicate of its first occurrence.
+///
+/// # Errors
+/// Returns `DomainError::Validation` on field `file` if the file is not UTF-8,
+/// has no `name`/`country` header, contains an unterminated quoted field, or
+/// has more than `max_rows` data rows.
+pub fn parse_city_csv(
+    data: &[u8],
+    default_tenant: Uuid,
+    max_rows: usize,
+) -> Result<ParsedCityImport, DomainError> {
+    let text = std::str::from_utf8(data)
+        .map_err(|_| DomainError::validation("file", "CSV file is not valid UTF-8"))?;
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut records = records(text, max_rows)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Err(DomainError::validation("file", "CSV file is empty"));
+    };
+    let columns = Columns::from_header(&header)?;
+
+    let mut parsed = ParsedCityImport::default();
+    let mut first_seen: HashMap<(Uuid, String), usize> = HashMap::new();
+    for (row, fields) in records {
+        match columns.row(row, &fields, default_tenant) {
+            Ok(city) => match first_seen.get(&(city.tenant_id, city.name.clone())) {
+                Some(&first_row) => parsed.failed.push(RejectedRow {
+                    row,
+                    reason: RowRejection::DuplicateInFile { first_row },
+                }),
+                None => {
+                    first_seen.insert((city.tenant_id, city.name.clone()), row);
+                    parsed.rows.push(city);
+                }
+            },
+            Err(reason) => parsed.failed.push(RejectedRow { row, reason }),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Column positions taken from the header row.
+struct Columns {
+    count: usize,
+    name: usize,
+    country: usize,
+    tenant_id: Option<usize>,
+}
+
+impl Columns {
+    fn from_header(header: &[String]) -> Result<Self, DomainError> {
+        let position = |column: &str| {
+            header
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(column))
+        };
+        let missing =
+            |column: &str| DomainError::validation("file", format!("missing '{column}' column"));
+        Ok(Self {
+            count: header.len(),
+            name: position("name").ok_or_else(|| missing("name"))?,
+            country: position("country").ok_or_else(|| missing("country"))?,
+            tenant_id: position("tenant_id"),
+        })
+    }
+
+    fn row(
+        &self,
+        row: usize,
+        fields: &[String],
+        default_tenant: Uuid,
+    ) -> Result<CityImportRow, RowRejection> {
+        if fields.len() != self.count {
+            return Err(RowRejection::FieldCount {
+                expected: self.count,
+                got: fields.len(),
+            });
+        }
+
+        let name = fields[self.name].trim();
+        if name.is_empty() {
+            return Err(RowRejection::EmptyName);
+        }
+
+        let country = fields[self.country].trim();
+        if country.len() != 2 || !country.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return Err(RowRejection::InvalidCountry(country.to_owned()));
+        }
+
+        let tenant_id = match self.tenant_id.map(|i| fields[i].trim()) {
+            None | Some("") => default_tenant,
+            Some(raw) => {
+                Uuid::parse_str(raw).map_err(|_| RowRejection::InvalidTenantId(raw.to_owned()))?
+            }
+        };
+
+        Ok(CityImportRow {
+            row,
+            tenant_id,
+            name: name.to_owned(),
+            country: country.to_ascii_uppercase(),
+        })
+    }
+}
+
+/// Split `text` into records of fields, each tagged with its starting line.
+fn records(text: &str, max_rows: usize) -> Result<Vec<(usize, Vec<String>)>, DomainError> {
+    let mut records = Vec::new();
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut in_quotes = false;
+
+    let mut push_record = |start_line: usize, fields: Vec<String>| {
+        let blank = fields.len() == 1 && fields[0].trim().is_empty();
+        if !blank {
+            records.push((start_line, fields));
+        }
+        // The header is not a data row.
+        if records.len() > max_rows + 1 {
+            return Err(DomainError::validation(
+                "file",
+                format!("CSV file has more than {max_rows} rows"),
+            ));
+        }
+        Ok(())
+    };
+
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                }
+                '"' => in_quotes = false,
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                }
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                in_quotes = true;
+            }
+            ',' => fields.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' => {
+                fields.push(std::mem::take(&mut field));
+                push_record(start_line, std::mem::take(&mut fields))?;
+                line += 1;
+                start_line = line;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if in_quotes {
+        return Err(DomainError::validation(
+            "file",
+            format!("unterminated quoted field starting on line {start_line}"),
+        ));
+    }
+    if !field.is_empty() || !fields.is_empty() {
+        fields.push(field);
+        push_record(start_line, fields)?;
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn parse(csv: &str) -> ParsedCityImport {
+        parse_city_csv(csv.as_bytes(), Uuid::nil(), 100).unwrap()
+    }
+
+    #[test]
+    fn parses_quoted_fields_and_defaults_tenant() {
+        let tenant = Uuid::new_v4();
+        let csv = format!(
+            "\u{feff}Name,Country,tenant_id\r\n\"São Paulo, SP\",br,\r\n\"Say \"\"Hi\"\"\",PT,{tenant}\r\n\r\n"
+        );
+        let parsed = parse(&csv);
+
+        assert!(parsed.failed.is_empty(), "{:?}", parsed.failed);
+        assert_eq!(parsed.rows.len(), 2);
+        assert_eq!(parsed.rows[0].name, "São Paulo, SP");
+        assert_eq!(parsed.rows[0].country, "BR");
+        assert_eq!(parsed.rows[0].tenant_id, Uuid::nil());
+        assert_eq!(parsed.rows[1].name, "Say \"Hi\"");
+        assert_eq!(parsed.rows[1].tenant_id, tenant);
+        assert_eq!(parsed.rows[1].row, 3);
+    }
+
+    #[test]
+    fn reports_invalid_rows_with_line_numbers() {
+        let parsed = parse(
+            "name,country,tenant_id\n\
+             Lisbon,PT,\n\
+             ,PT,\n\
+             Porto,Portugal,\n\
+             Faro,PT,not-a-uuid\n\
+             Lisbon,PT,\n\
+             Braga,PT\n",
+        );
+
+        let failed: Vec<(usize, &str)> = parsed
+            .failed
+            .iter()
+            .map(|r| (r.row, r.reason.code()))
+            .collect();
+        assert_eq!(
+            failed,
+            vec![
+                (3, "empty_name"),
+                (4, "invalid_country"),
+                (5, "invalid_tenant_id"),
+                (6, "duplicate_in_file"),
+                (7, "field_count"),
+            ]
+        );
+        assert_eq!(parsed.rows.len(), 1);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        for csv in [
+            "",
+            "city,country\nLisbon,PT\n",
+            "name,country\n\"Lisbon,PT\n",
+            "name,country\na,PT\nb,PT\nc,PT\n",
+        ] {
+            let err = parse_city_csv(csv.as_bytes(), Uuid::nil(), 2).unwrap_err();
+            assert!(
+                matches!(&err, DomainError::Validation { field, .. } if field == "file"),
+                "{csv:?}: {err:?}"
+            );
+        }
+    }
+}
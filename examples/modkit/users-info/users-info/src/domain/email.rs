@@ -0,0 +1,295 @@
+//! Email normalization and validation pipeline.
+//!
+//! Every email accepted on create or update goes through [`EmailPolicy::normalize`]
+//! before the uniqueness check. The normalized form is what gets stored, compared
+//! and filtered on; the raw input is kept separately as `email_original`.
+//!
+//! Pipeline order: trim → Unicode NFC → lowercase → format check →
+//! plus-address stripping → max length → blocked domains → allowed domains.
+
+use modkit_macros::domain_model;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::domain::error::DomainError;
+
+/// Deployment-specific email rules (`modules.users_info.config.email`).
+#[domain_model]
+#[derive(Debug, Clone)]
+pub struct EmailPolicy {
+    /// Lowercase the whole address.
+    pub lowercase: bool,
+    /// Apply Unicode NFC normalization.
+    pub unicode_nfc: bool,
+    /// Drop `+tag` from the local part (`john+news@x.com` → `john@x.com`).
+    pub strip_plus_addressing: bool,
+    /// If non-empty, only these domains are accepted (exact, case-insensitive).
+    pub allowed_domains: Vec<String>,
+    /// Domains that are always rejected (exact, case-insensitive).
+    pub blocked_domains: Vec<String>,
+    /// Maximum length of the normalized address, in characters.
+    pub max_length: usize,
+}
+
+impl Default for EmailPolicy {
+    fn default() -> Self {
+        Self {
+            lowercase: true,
+            unicode_nfc: true,
+            strip_plus_addressing: false,
+            allowed_domains: Vec::new(),
+            blocked_domains: Vec::new(),
+            max_length: 254,
+        }
+    }
+}
+
+/// Policy rule that rejected an email; the name is reported to API clients.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailRule {
+    MaxLength,
+    BlockedDomain,
+    DomainNotAllowed,
+}
+
+impl EmailRule {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::MaxLength => "max_length",
+            Self::BlockedDomain => "blocked_domains",
+            Self::DomainNotAllowed => "allowed_domains",
+        }
+    }
+}
+
+impl std::fmt::Display for EmailRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl EmailPolicy {
+    /// Normalize `raw` and check it against the policy.
+    ///
+    /// # Errors
+    ///
+    /// - `DomainError::InvalidEmail` if the address is malformed
+    /// - `DomainError::EmailPolicyViolation` naming the rule that rejected it
+    pub fn normalize(&self, raw: &str) -> Result<String, DomainError> {
+        let folded = self.fold(raw);
+        let Some((local, domain)) = split_address(&folded) else {
+            return Err(DomainError::invalid_email(raw.to_owned()));
+        };
+
+        let local = if self.strip_plus_addressing {
+            match local.split_once('+') {
+                Some((base, _)) if !base.is_empty() => base,
+                _ => local,
+            }
+        } else {
+            local
+        };
+        let normalized = format!("{local}@{domain}");
+
+        if normalized.chars().count() > self.max_length {
+            return Err(DomainError::email_policy_violation(
+                raw.to_owned(),
+                EmailRule::MaxLength,
+            ));
+        }
+        if contains_domain(&self.blocked_domains, domain) {
+            return Err(DomainError::email_policy_violation(
+                raw.to_owned(),
+                EmailRule::BlockedDomain,
+            ));
+        }
+        if !self.allowed_domains.is_empty() && !contains_domain(&self.allowed_domains, domain) {
+            return Err(DomainError::email_policy_violation(
+                raw.to_owned(),
+                EmailRule::DomainNotAllowed,
+            ));
+        }
+
+        Ok(normalized)
+    }
+
+    /// Normalize a literal from an `OData` filter on `email`.
+    ///
+    /// Complete addresses get the same normalization as stored values so `eq`
+    /// matches; partial values (`contains`, `startswith`) are only case/Unicode
+    /// folded. Domain rules are not applied.
+    #[must_use]
+    pub fn normalize_filter_literal(&self, literal: &str) -> String {
+        let folded = self.fold(literal);
+        match split_address(&folded) {
+            Some((local, domain)) if self.strip_plus_addressing => {
+                let local = match local.split_once('+') {
+                    Some((base, _)) if !base.is_empty() => base,
+                    _ => local,
+                };
+                format!("{local}@{domain}")
+            }
+            _ => folded,
+        }
+    }
+
+    fn fold(&self, raw: &str) -> String {
+        let trimmed = raw.trim();
+        let nfc: String = if self.unicode_nfc {
+            trimmed.nfc().collect()
+        } else {
+            trimmed.to_owned()
+        };
+        if self.lowercase {
+            nfc.to_lowercase()
+        } else {
+            nfc
+        }
+    }
+}
+
+/// Split into `(local, domain)`; `None` unless there is exactly one `@`, a
+/// non-empty local part and a dotted domain.
+fn split_address(email: &str) -> Option<(&str, &str)> {
+    let (local, domain) = email.split_once('@')?;
+    if local.is_empty()
+        || domain.contains('@')
+        || !domain.contains('.')
+        || domain.starts_with('.')
+        || domain.ends_with('.')
+    {
+        return None;
+    }
+    Some((local, domain))
+}
+
+fn contains_domain(list: &[String], domain: &str) -> bool {
+    list.iter().any(|d| d.eq_ignore_ascii_case(domain))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy_folds_case_and_whitespace() {
+        let policy = EmailPolicy::default();
+        assert_eq!(
+            policy.normalize("  John.Doe@Example.COM ").unwrap(),
+            "john.doe@example.com"
+        );
+    }
+
+    #[test]
+    fn nfc_normalizes_decomposed_characters() {
+        let policy = EmailPolicy::default();
+        // "e" + combining acute accent vs precomposed "é"
+        assert_eq!(
+            policy.normalize("Jose\u{301}@example.com").unwrap(),
+            "jos\u{e9}@example.com"
+        );
+    }
+
+    #[test]
+    fn case_is_preserved_when_lowercase_disabled() {
+        let policy = EmailPolicy {
+            lowercase: false,
+            ..EmailPolicy::default()
+        };
+        assert_eq!(
+            policy.normalize("John@Example.com").unwrap(),
+            "John@Example.com"
+        );
+    }
+
+    #[test]
+    fn plus_addressing_is_stripped_only_when_enabled() {
+        let keep = EmailPolicy::default();
+        assert_eq!(
+            keep.normalize("john+news@example.com").unwrap(),
+            "john+news@example.com"
+        );
+
+        let strip = EmailPolicy {
+            strip_plus_addressing: true,
+            ..EmailPolicy::default()
+        };
+        assert_eq!(
+            strip.normalize("john+news@example.com").unwrap(),
+            "john@example.com"
+        );
+        // A leading '+' is not a tag
+        assert_eq!(strip.normalize("+x@example.com").unwrap(), "+x@example.com");
+    }
+
+    #[test]
+    fn malformed_email_is_invalid() {
+        let policy = EmailPolicy::default();
+        for raw in [
+            "",
+            "no-at-sign",
+            "@example.com",
+            "a@b@example.com",
+            "a@localhost",
+        ] {
+            assert!(
+                matches!(policy.normalize(raw), Err(DomainError::InvalidEmail { .. })),
+                "{raw:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn max_length_is_enforced() {
+        let policy = EmailPolicy {
+            max_length: 10,
+            ..EmailPolicy::default()
+        };
+        assert!(matches!(
+            policy.normalize("longname@example.com"),
+            Err(DomainError::EmailPolicyViolation {
+                rule: EmailRule::MaxLength,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn domain_lists_are_enforced() {
+        let policy = EmailPolicy {
+            allowed_domains: vec!["corp.example".to_owned()],
+            blocked_domains: vec!["spam.example".to_owned()],
+            ..EmailPolicy::default()
+        };
+        assert!(policy.normalize("a@Corp.Example").is_ok());
+        assert!(matches!(
+            policy.normalize("a@spam.example"),
+            Err(DomainError::EmailPolicyViolation {
+                rule: EmailRule::BlockedDomain,
+                ..
+            })
+        ));
+        assert!(matches!(
+            policy.normalize("a@other.example"),
+            Err(DomainError::EmailPolicyViolation {
+                rule: EmailRule::DomainNotAllowed,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn filter_literals_are_folded() {
+        let policy = EmailPolicy {
+            strip_plus_addressing: true,
+            ..EmailPolicy::default()
+        };
+        assert_eq!(
+            policy.normalize_filter_literal("John+x@Example.com"),
+            "john@example.com"
+        );
+        assert_eq!(policy.normalize_filter_literal("JOHN"), "john");
+    }
+}
@@ -3,6 +3,8 @@ use modkit_db::secure::InfraError;
 use modkit_db::secure::ScopeError;
 use modkit_macros::domain_model;
 use thiserror::Error;
+
+use crate::domain::email::EmailRule;
 use users_info_sdk::UsersInfoError;
 use uuid::Uuid;
 
@@ -19,6 +21,9 @@ pub enum DomainError {
     #[error("Invalid email format: '{email}'")]
     InvalidEmail { email: String },
 
+    #[error("Email '{email}' rejected by policy rule '{rule}'")]
+    EmailPolicyViolation { email: String, rule: EmailRule },
+
     #[error("Display name cannot be empty")]
     EmptyDisplayName,
 
@@ -34,9 +39,30 @@ pub enum DomainError {
     #[error("{entity_type} not found: {id}")]
     NotFound { entity_type: String, id: Uuid },
 
+    #[error("{field} does not refer to an accessible entity: {id}")]
+    ReferenceNotInScope { field: &'static str, id: Uuid },
+
     #[error("Access denied")]
     Forbidden,
 
+    #[error("Fields cannot be changed on the own profile: {}", .fields.join(", "))]
+    FieldsNotEditable { fields: Vec<String> },
+
+    #[error("Entry {index}: {source}")]
+    BulkEntry {
+        index: usize,
+        source: Box<DomainError>,
+    },
+
+    #[error("Payload exceeds the limit of {max_bytes} bytes")]
+    PayloadTooLarge { max_bytes: usize },
+
+    #[error("Tenant {tenant_id} has reached its limit of {max} users")]
+    UserLimitReached { tenant_id: Uuid, max: u32 },
+
+    #[error("Feature '{feature}' is disabled")]
+    FeatureDisabled { feature: String },
+
     #[error("Internal error")]
     InternalError,
 }
@@ -57,6 +83,11 @@ impl DomainError {
         Self::InvalidEmail { email }
     }
 
+    #[must_use]
+    pub fn email_policy_violation(email: String, rule: EmailRule) -> Self {
+        Self::EmailPolicyViolation { email, rule }
+    }
+
     #[must_use]
     pub fn empty_display_name() -> Self {
         Self::EmptyDisplayName
@@ -94,6 +125,35 @@ impl DomainError {
             id,
         }
     }
+
+    #[must_use]
+    pub fn reference_not_in_scope(field: &'static str, id: Uuid) -> Self {
+        Self::ReferenceNotInScope { field, id }
+    }
+
+    /// Attribute `source` to the entry at `index` of a bulk request.
+    #[must_use]
+    pub fn bulk_entry(index: usize, source: DomainError) -> Self {
+        Self::BulkEntry {
+            index,
+            source: Box::new(source),
+        }
+    }
+
+    #[must_use]
+    pub fn payload_too_large(max_bytes: usize) -> Self {
+        Self::PayloadTooLarge { max_bytes }
+    }
+
+    #[must_use]
+    pub fn user_limit_reached(tenant_id: Uuid, max: u32) -> Self {
+        Self::UserLimitReached { tenant_id, max }
+    }
+
+    #[must_use]
+    pub fn fields_not_editable(fields: Vec<String>) -> Self {
+        Self::FieldsNotEditable { fields }
+    }
 }
 
 /// Convert domain errors to SDK errors for public API consumption.
@@ -104,6 +164,9 @@ impl From<DomainError> for UsersInfoError {
             DomainError::InvalidEmail { email } => {
                 UsersInfoError::validation(format!("Invalid email: {email}"))
             }
+            DomainError::EmailPolicyViolation { email, rule } => UsersInfoError::validation(
+                format!("Email '{email}' rejected by policy rule '{rule}'"),
+            ),
             DomainError::EmptyDisplayName => {
                 UsersInfoError::validation("Display name cannot be empty")
             }
@@ -113,10 +176,23 @@ impl From<DomainError> for UsersInfoError {
             DomainError::Validation { field, message } => {
                 UsersInfoError::validation(format!("{field}: {message}"))
             }
+            DomainError::BulkEntry { source, .. } => UsersInfoError::from(*source),
+            DomainError::PayloadTooLarge { max_bytes } => UsersInfoError::validation(format!(
+                "Payload exceeds the limit of {max_bytes} bytes"
+            )),
+            DomainError::UserLimitReached { tenant_id, max } => UsersInfoError::validation(
+                format!("Tenant {tenant_id} has reached its limit of {max} users"),
+            ),
+            DomainError::ReferenceNotInScope { field, id } => {
+                UsersInfoError::validation(format!("{field}: no accessible entity with id {id}"))
+            }
             DomainError::UserNotFound { id } | DomainError::NotFound { id, .. } => {
                 UsersInfoError::not_found(id)
             }
-            DomainError::Forbidden => UsersInfoError::forbidden(),
+            DomainError::Forbidden | DomainError::FieldsNotEditable { .. } => {
+                UsersInfoError::forbidden()
+            }
+            DomainError::FeatureDisabled { .. } => UsersInfoError::not_implemented(),
             DomainError::Database { .. } | DomainError::InternalError => UsersInfoError::internal(),
         }
     }
@@ -132,13 +208,39 @@ impl From<Box<dyn std::error::Error>> for DomainError {
 
 impl From<DbError> for DomainError {
     fn from(e: DbError) -> Self {
-        DomainError::database(e.to_string())
+        match e {
+            // Domain errors raised inside a transaction closure round-trip unchanged.
+            DbError::Other(err) => match err.downcast::<DomainError>() {
+                Ok(domain) => domain,
+                Err(err) => DomainError::database(err.to_string()),
+            },
+            other => DomainError::database(other.to_string()),
+        }
+    }
+}
+
+/// Allows `?` on domain errors inside `DBProvider::transaction` closures.
+impl From<DomainError> for DbError {
+    fn from(e: DomainError) -> Self {
+        DbError::Other(anyhow::Error::new(e))
     }
 }
 
 impl From<ScopeError> for DomainError {
     fn from(e: ScopeError) -> Self {
-        DomainError::database(e.to_string())
+        match e {
+            ScopeError::ReadOnlyScope => {
+                tracing::error!("Write attempted with a read-only scope");
+                Self::Forbidden
+            }
+            e => DomainError::database(e.to_string()),
+        }
+    }
+}
+
+impl From<modkit::FeatureDisabled> for DomainError {
+    fn from(e: modkit::FeatureDisabled) -> Self {
+        Self::FeatureDisabled { feature: e.feature }
     }
 }
 
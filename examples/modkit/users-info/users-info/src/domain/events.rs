@@ -1,12 +1,76 @@
+use std::sync::Arc;
+
 use modkit_macros::domain_model;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
+use crate::domain::ports::EventPublisher;
+
 /// Transport-agnostic domain event.
 #[domain_model]
 #[derive(Debug, Clone)]
 pub enum UserDomainEvent {
-    Created { id: Uuid, at: OffsetDateTime },
-    Updated { id: Uuid, at: OffsetDateTime },
-    Deleted { id: Uuid, at: OffsetDateTime },
+    Created {
+        id: Uuid,
+        tenant_id: Uuid,
+        at: OffsetDateTime,
+    },
+    Updated {
+        id: Uuid,
+        tenant_id: Uuid,
+        at: OffsetDateTime,
+    },
+    Deleted {
+        id: Uuid,
+        tenant_id: Uuid,
+        at: OffsetDateTime,
+    },
+}
+
+impl UserDomainEvent {
+    /// ID of the user the event is about.
+    #[must_use]
+    pub fn user_id(&self) -> Uuid {
+        match self {
+            Self::Created { id, .. } | Self::Updated { id, .. } | Self::Deleted { id, .. } => *id,
+        }
+    }
+
+    /// Tenant that owns the user.
+    #[must_use]
+    pub fn tenant_id(&self) -> Uuid {
+        match self {
+            Self::Created { tenant_id, .. }
+            | Self::Updated { tenant_id, .. }
+            | Self::Deleted { tenant_id, .. } => *tenant_id,
+        }
+    }
+
+    /// When the change happened.
+    #[must_use]
+    pub fn at(&self) -> OffsetDateTime {
+        match self {
+            Self::Created { at, .. } | Self::Updated { at, .. } | Self::Deleted { at, .. } => *at,
+        }
+    }
+}
+
+/// Publishes every event to each of the wrapped publishers, in order.
+pub struct FanOutPublisher<E> {
+    publishers: Vec<Arc<dyn EventPublisher<E>>>,
+}
+
+impl<E> FanOutPublisher<E> {
+    #[must_use]
+    pub fn new(publishers: Vec<Arc<dyn EventPublisher<E>>>) -> Self {
+        Self { publishers }
+    }
+}
+
+impl<E: 'static> EventPublisher<E> for FanOutPublisher<E> {
+    fn publish(&self, event: &E) {
+        for publisher in &self.publishers {
+            publisher.publish(event);
+        }
+    }
 }
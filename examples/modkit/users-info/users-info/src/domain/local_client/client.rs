@@ -2,9 +2,13 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use modkit_macros::domain_model;
+use modkit_odata::Page;
+use modkit_sdk::odata::QueryBuilder;
 use modkit_security::SecurityContext;
 use uuid::Uuid;
 
+use users_info_sdk::odata::UserSchema;
+
 use users_info_sdk::{
     Address, AddressesStreamingClientV1, CitiesStreamingClientV1, City, NewAddress, NewCity,
     NewUser, UpdateAddressRequest, UpdateCityRequest, UpdateUserRequest, User, UserFull,
@@ -50,6 +54,20 @@ impl UsersInfoClientV1 for UsersInfoLocalClient {
         )))
     }
 
+    // ==================== Query Operations ====================
+
+    async fn list_users(
+        &self,
+        ctx: SecurityContext,
+        query: QueryBuilder<UserSchema>,
+    ) -> Result<Page<User>, UsersInfoError> {
+        self.services
+            .users
+            .list_users_page(&ctx, &query.build())
+            .await
+            .map_err(UsersInfoError::from)
+    }
+
     // ==================== Single-Item Operations ====================
 
     async fn get_user(&self, ctx: SecurityContext, id: Uuid) -> Result<User, UsersInfoError> {
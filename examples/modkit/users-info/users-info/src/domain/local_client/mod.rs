@@ -7,3 +7,6 @@ pub mod addresses;
 pub mod cities;
 pub mod client;
 pub mod users;
+
+#[cfg(test)]
+mod tests_list_users;
@@ -0,0 +1,102 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::Arc;
+
+use modkit::api::odata::parse_orderby;
+use modkit_odata::{CursorV1, ODataQuery, SortDir, pagination::short_filter_hash};
+use modkit_sdk::odata::QueryBuilder;
+use users_info_sdk::UsersInfoClientV1;
+use users_info_sdk::odata::{USER_DISPLAY_NAME, USER_EMAIL, UserSchema};
+use uuid::Uuid;
+
+use crate::domain::local_client::client::UsersInfoLocalClient;
+use crate::domain::service::ServiceConfig;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db, seed_user};
+
+/// Build the query the REST extractor produces for `$filter` / `$orderby` / `limit`.
+fn rest_query(filter: &str, orderby: &str, limit: u64) -> ODataQuery {
+    let expr = modkit_odata::parse_filter_string(filter)
+        .expect("filter must parse")
+        .into_expr();
+    let hash = short_filter_hash(Some(&expr)).expect("filter hash");
+    ODataQuery::new()
+        .with_filter(expr)
+        .with_filter_hash(hash)
+        .with_order(parse_orderby(orderby).expect("orderby must parse"))
+        .with_limit(limit)
+}
+
+fn builder_query() -> QueryBuilder<UserSchema> {
+    QueryBuilder::new()
+        .filter(USER_EMAIL.ne("bob@example.com"))
+        .order_by(USER_DISPLAY_NAME, SortDir::Desc)
+        .page_size(2)
+}
+
+#[test]
+fn builder_query_matches_rest_filter() {
+    let rest = rest_query("email ne 'bob@example.com'", "display_name desc", 2);
+    let built = builder_query().build();
+
+    // The filter hash is computed over the AST, so equal hashes mean equal filters.
+    assert!(built.filter_hash.is_some());
+    assert_eq!(built.filter_hash, rest.filter_hash);
+    assert_eq!(
+        built.order.to_signed_tokens(),
+        rest.order.to_signed_tokens()
+    );
+    assert_eq!(built.limit, rest.limit);
+}
+
+#[tokio::test]
+async fn list_users_returns_same_pages_as_rest_path() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    for name in ["Alice", "Bob", "Carol", "Dave"] {
+        let email = format!("{}@example.com", name.to_lowercase());
+        seed_user(&conn, Uuid::new_v4(), tenant_id, &email, name).await;
+    }
+
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let client = UsersInfoLocalClient::new(Arc::clone(&services));
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let rest_page = services
+        .users
+        .list_users_page(
+            &ctx,
+            &rest_query("email ne 'bob@example.com'", "display_name desc", 2),
+        )
+        .await
+        .unwrap();
+    let page = client
+        .list_users(ctx.clone(), builder_query())
+        .await
+        .unwrap();
+
+    let names = |items: &[users_info_sdk::User]| {
+        items
+            .iter()
+            .map(|u| u.display_name.clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&page.items), ["Dave", "Carol"]);
+    assert_eq!(names(&page.items), names(&rest_page.items));
+    assert_eq!(page.page_info.next_cursor, rest_page.page_info.next_cursor);
+
+    // Follow the cursor with the builder.
+    let cursor = CursorV1::decode(page.page_info.next_cursor.as_deref().unwrap()).unwrap();
+    let next = client
+        .list_users(
+            ctx,
+            QueryBuilder::new()
+                .filter(USER_EMAIL.ne("bob@example.com"))
+                .page_size(2)
+                .cursor(cursor),
+        )
+        .await
+        .unwrap();
+    assert_eq!(names(&next.items), ["Alice"]);
+    assert!(next.page_info.next_cursor.is_none());
+}
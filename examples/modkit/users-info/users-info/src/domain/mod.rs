@@ -3,9 +3,14 @@
 #![allow(unknown_lints)]
 #![allow(de0301_no_infra_in_domain)]
 
+pub mod city_import;
+pub mod email;
 pub mod error;
 pub mod events;
 pub mod local_client;
 pub mod ports;
+pub mod profile;
 pub mod repos;
 pub mod service;
+pub mod tenant_settings;
+pub mod webhooks;
@@ -1,6 +1,8 @@
 pub mod audit;
+pub mod webhook;
 
 pub use audit::AuditPort;
+pub use webhook::WebhookSender;
 
 /// Output port: publish domain events (no knowledge of transport).
 pub trait EventPublisher<E>: Send + Sync + 'static {
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+/// Transport port for webhook deliveries.
+///
+/// Implementations must not follow redirects or retry on their own: the
+/// delivery worker owns retries and records every attempt.
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// POST `body` (JSON) to `url` with the given headers and return the
+    /// response status code. Transport failures (connect, TLS, timeout) are
+    /// returned as errors.
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&'static str, String)],
+        body: String,
+    ) -> anyhow::Result<u16>;
+}
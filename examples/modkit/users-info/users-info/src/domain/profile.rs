@@ -0,0 +1,115 @@
+//! Self-service profile rules (`/users-info/v1/me`).
+//!
+//! A caller acting on their own profile is resolved from the subject of the
+//! security context, not from a path parameter, and may only change the
+//! fields listed in [`SelfServicePolicy::editable_fields`].
+
+use modkit_macros::domain_model;
+use users_info_sdk::UserPatch;
+
+use crate::domain::error::DomainError;
+
+/// User field a subject may be allowed to change on their own profile.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileField {
+    DisplayName,
+    Email,
+}
+
+impl ProfileField {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DisplayName => "display_name",
+            Self::Email => "email",
+        }
+    }
+
+    /// Parse a config name (`display_name`, `email`).
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "display_name" => Some(Self::DisplayName),
+            "email" => Some(Self::Email),
+            _ => None,
+        }
+    }
+}
+
+/// Deployment-specific self-service rules (`modules.users_info.config.self_service`).
+#[domain_model]
+#[derive(Debug, Clone)]
+pub struct SelfServicePolicy {
+    /// Treat the subject id as the user id before looking up `external_subject_id`.
+    pub subject_is_user_id: bool,
+    /// Fields a subject may change on their own profile.
+    pub editable_fields: Vec<ProfileField>,
+}
+
+impl Default for SelfServicePolicy {
+    fn default() -> Self {
+        Self {
+            subject_is_user_id: true,
+            editable_fields: vec![ProfileField::DisplayName],
+        }
+    }
+}
+
+impl SelfServicePolicy {
+    /// Check that `patch` only touches editable fields.
+    ///
+    /// # Errors
+    ///
+    /// `DomainError::FieldsNotEditable` listing every field set in `patch`
+    /// that is not editable.
+    pub fn check_patch(&self, patch: &UserPatch) -> Result<(), DomainError> {
+        let touched = [
+            (ProfileField::DisplayName, patch.display_name.is_some()),
+            (ProfileField::Email, patch.email.is_some()),
+        ];
+        let fields: Vec<String> = touched
+            .into_iter()
+            .filter(|(field, set)| *set && !self.editable_fields.contains(field))
+            .map(|(field, _)| field.as_str().to_owned())
+            .collect();
+        if fields.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::fields_not_editable(fields))
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy_allows_display_name_only() {
+        let policy = SelfServicePolicy::default();
+        let rename = UserPatch {
+            display_name: Some("New".to_owned()),
+            ..Default::default()
+        };
+        assert!(policy.check_patch(&rename).is_ok());
+
+        let both = UserPatch {
+            email: Some("new@example.com".to_owned()),
+            display_name: Some("New".to_owned()),
+        };
+        match policy.check_patch(&both) {
+            Err(DomainError::FieldsNotEditable { fields }) => assert_eq!(fields, ["email"]),
+            other => panic!("expected FieldsNotEditable, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn field_names_round_trip() {
+        for field in [ProfileField::DisplayName, ProfileField::Email] {
+            assert_eq!(ProfileField::parse(field.as_str()), Some(field));
+        }
+        assert_eq!(ProfileField::parse("tenant_id"), None);
+    }
+}
@@ -65,4 +65,36 @@ pub trait AddressesRepository: Send + Sync {
         scope: &AccessScope,
         user_id: Uuid,
     ) -> Result<u64, DomainError>;
+
+    /// List up to `limit` addresses ordered by ID, starting after `after`.
+    ///
+    /// Used by chunked bulk operations (tenant purge) to bound statement size.
+    async fn list_chunk<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<Address>, DomainError>;
+
+    /// Delete addresses by ID within the scope. Returns the number of deleted rows.
+    async fn delete_by_ids<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        ids: &[Uuid],
+    ) -> Result<u64, DomainError>;
+
+    /// Check that `user_id` and `city_id` refer to a user visible in
+    /// `user_scope` and a city visible in `city_scope`.
+    ///
+    /// Returns `DomainError::ReferenceNotInScope` naming the offending field.
+    async fn ensure_references_in_scope<C: DBRunner>(
+        &self,
+        runner: &C,
+        user_scope: &AccessScope,
+        user_id: Uuid,
+        city_scope: &AccessScope,
+        city_id: Uuid,
+    ) -> Result<(), DomainError>;
 }
@@ -49,4 +49,35 @@ pub trait CitiesRepository: Send + Sync {
         scope: &AccessScope,
         id: Uuid,
     ) -> Result<bool, DomainError>;
+
+    /// Check that `scope` allows inserting `city`, without touching the database.
+    fn insert_in_scope(&self, scope: &AccessScope, city: &City) -> bool;
+
+    /// Those of `names` that already exist in `tenant_id` within the scope.
+    async fn existing_names<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        tenant_id: Uuid,
+        names: &[String],
+    ) -> Result<Vec<String>, DomainError>;
+
+    /// List up to `limit` cities ordered by ID, starting after `after`.
+    ///
+    /// Used by chunked bulk operations (tenant purge) to bound statement size.
+    async fn list_chunk<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<City>, DomainError>;
+
+    /// Delete cities by ID within the scope. Returns the number of deleted rows.
+    async fn delete_by_ids<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        ids: &[Uuid],
+    ) -> Result<u64, DomainError>;
 }
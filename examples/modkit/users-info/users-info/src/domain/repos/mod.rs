@@ -1,7 +1,11 @@
 mod addresses_repo;
 mod cities_repo;
+mod tenant_settings_repo;
 mod users_repo;
+mod webhooks_repo;
 
 pub(crate) use addresses_repo::AddressesRepository;
 pub(crate) use cities_repo::CitiesRepository;
+pub(crate) use tenant_settings_repo::TenantSettingsRepository;
 pub(crate) use users_repo::UsersRepository;
+pub(crate) use webhooks_repo::WebhooksRepository;
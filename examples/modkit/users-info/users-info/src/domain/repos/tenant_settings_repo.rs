@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use modkit_db::secure::DBRunner;
+use modkit_security::AccessScope;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::tenant_settings::TenantSettings;
+
+/// Repository trait for per-tenant limit overrides.
+#[async_trait]
+pub trait TenantSettingsRepository: Send + Sync {
+    /// Find the settings of a tenant within the given security scope.
+    async fn get<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        tenant_id: Uuid,
+    ) -> Result<Option<TenantSettings>, DomainError>;
+
+    /// Insert or fully replace the settings of a tenant.
+    async fn upsert<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        settings: TenantSettings,
+    ) -> Result<TenantSettings, DomainError>;
+}
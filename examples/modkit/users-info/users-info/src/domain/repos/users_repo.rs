@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use modkit_db::secure::DBRunner;
 use modkit_odata::{ODataQuery, Page};
-use modkit_security::AccessScope;
-use users_info_sdk::User;
+use modkit_security::{AccessScope, SecurityContext};
+use users_info_sdk::{User, UserWithAddressSummary};
 use uuid::Uuid;
 
 use crate::domain::error::DomainError;
@@ -33,19 +33,55 @@ pub trait UsersRepository: Send + Sync {
         query: &ODataQuery,
     ) -> Result<Page<User>, DomainError>;
 
-    /// Create a new user.
+    /// List users like [`Self::list_page`], with the address summary of each
+    /// listed user.
+    ///
+    /// The page's addresses and their cities are loaded with one query each,
+    /// scoped by `address_scope` and `city_scope`.
+    async fn list_page_with_address_summaries<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        address_scope: &AccessScope,
+        city_scope: &AccessScope,
+        query: &ODataQuery,
+    ) -> Result<Page<UserWithAddressSummary>, DomainError>;
+
+    /// List the users with an address in `city_id`, with cursor-based
+    /// pagination and `OData` filtering on `CityUserFilterField`.
+    ///
+    /// Addresses are only seen within `address_scope`, so a user whose
+    /// address in the city is outside it is not listed.
+    async fn list_city_users_page<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        address_scope: &AccessScope,
+        city_id: Uuid,
+        query: &ODataQuery,
+    ) -> Result<Page<User>, DomainError>;
+
+    /// Create a new user, returning it as stored.
+    ///
+    /// The creation and update time and actor are stamped from `ctx`; the
+    /// timestamps of `user` are ignored.
     async fn create<C: DBRunner>(
         &self,
         runner: &C,
         scope: &AccessScope,
+        ctx: &SecurityContext,
         user: User,
     ) -> Result<User, DomainError>;
 
-    /// Update an existing user.
+    /// Update an existing user, returning it as stored.
+    ///
+    /// The update time and actor are stamped from `ctx`; the timestamps of
+    /// `user` are ignored.
     async fn update<C: DBRunner>(
         &self,
         runner: &C,
         scope: &AccessScope,
+        ctx: &SecurityContext,
         user: User,
     ) -> Result<User, DomainError>;
 
@@ -57,6 +93,9 @@ pub trait UsersRepository: Send + Sync {
         id: Uuid,
     ) -> Result<bool, DomainError>;
 
+    /// Check that `scope` allows inserting `user`, without touching the database.
+    fn insert_in_scope(&self, scope: &AccessScope, user: &User) -> bool;
+
     /// Check if a user with the given ID exists within the scope.
     async fn exists<C: DBRunner>(
         &self,
@@ -65,6 +104,10 @@ pub trait UsersRepository: Send + Sync {
         id: Uuid,
     ) -> Result<bool, DomainError>;
 
+    /// Count all users within the scope.
+    async fn count<C: DBRunner>(&self, runner: &C, scope: &AccessScope)
+    -> Result<u64, DomainError>;
+
     /// Count users matching the given email within the scope.
     async fn count_by_email<C: DBRunner>(
         &self,
@@ -72,4 +115,31 @@ pub trait UsersRepository: Send + Sync {
         scope: &AccessScope,
         email: &str,
     ) -> Result<u64, DomainError>;
+
+    /// Find the user linked to an identity-provider subject within the scope.
+    async fn find_by_external_subject<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        subject_id: Uuid,
+    ) -> Result<Option<User>, DomainError>;
+
+    /// List up to `limit` users ordered by ID, starting after `after`.
+    ///
+    /// Used by chunked bulk operations (tenant purge) to bound statement size.
+    async fn list_chunk<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<User>, DomainError>;
+
+    /// Delete users by ID within the scope. Returns the number of deleted rows.
+    async fn delete_by_ids<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        ids: &[Uuid],
+    ) -> Result<u64, DomainError>;
 }
@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+use modkit_db::secure::DBRunner;
+use modkit_odata::{ODataQuery, Page};
+use modkit_security::AccessScope;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::webhooks::{Webhook, WebhookDelivery};
+
+/// Repository trait for webhook subscriptions and their delivery log.
+#[async_trait]
+pub trait WebhooksRepository: Send + Sync {
+    /// Find a webhook by ID within the given security scope.
+    async fn get<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        id: Uuid,
+    ) -> Result<Option<Webhook>, DomainError>;
+
+    /// List webhooks with cursor-based pagination and `OData` filtering.
+    async fn list_page<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        query: &ODataQuery,
+    ) -> Result<Page<Webhook>, DomainError>;
+
+    /// List every webhook visible in the scope (used by the delivery worker).
+    async fn list_all<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+    ) -> Result<Vec<Webhook>, DomainError>;
+
+    /// Create a new webhook.
+    async fn create<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        webhook: Webhook,
+    ) -> Result<Webhook, DomainError>;
+
+    /// Delete a webhook and its delivery log.
+    async fn delete<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        id: Uuid,
+    ) -> Result<bool, DomainError>;
+
+    /// Record a delivery attempt.
+    async fn record_delivery<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        delivery: WebhookDelivery,
+    ) -> Result<(), DomainError>;
+
+    /// List delivery attempts of one webhook with cursor-based pagination.
+    async fn list_deliveries_page<C: DBRunner>(
+        &self,
+        runner: &C,
+        scope: &AccessScope,
+        webhook_id: Uuid,
+        query: &ODataQuery,
+    ) -> Result<Page<WebhookDelivery>, DomainError>;
+}
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use modkit::FeatureGate;
+use modkit_db::secure::DBRunner;
 use modkit_macros::domain_model;
 use tracing::{debug, info, instrument};
 
@@ -9,9 +11,9 @@ use crate::domain::service::DbProvider;
 use authz_resolver_sdk::PolicyEnforcer;
 use authz_resolver_sdk::pep::AccessRequest;
 
-use super::{actions, resources};
+use super::{actions, features, resources};
 use modkit_odata::{ODataQuery, Page};
-use modkit_security::{AccessScope, SecurityContext, pep_properties};
+use modkit_security::{AccessScope, ScopeIntent, SecurityContext, pep_properties};
 use resources::properties;
 use time::OffsetDateTime;
 use users_info_sdk::{Address, AddressPatch, NewAddress};
@@ -23,6 +25,7 @@ pub struct AddressesService<R: AddressesRepository, U: UsersRepository> {
     repo: Arc<R>,
     users_repo: Arc<U>,
     policy_enforcer: PolicyEnforcer,
+    features: FeatureGate,
 }
 
 impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
@@ -31,14 +34,52 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         repo: Arc<R>,
         users_repo: Arc<U>,
         policy_enforcer: PolicyEnforcer,
+        features: FeatureGate,
     ) -> Self {
         Self {
             db,
             repo,
             users_repo,
             policy_enforcer,
+            features,
         }
     }
+
+    /// Whether the `addresses` feature is enabled for this module.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.features.is_enabled(features::ADDRESSES)
+    }
+
+    fn ensure_enabled(&self) -> Result<(), DomainError> {
+        Ok(self.features.ensure_enabled(features::ADDRESSES)?)
+    }
+
+    /// Check that the address references a user and a city the caller can
+    /// read, both in the address tenant.
+    async fn ensure_references<C: DBRunner>(
+        &self,
+        conn: &C,
+        ctx: &SecurityContext,
+        tenant_id: Uuid,
+        user_id: Uuid,
+        city_id: Uuid,
+    ) -> Result<(), DomainError> {
+        let tenant_scope = AccessScope::for_tenant(tenant_id);
+        let user_scope = self
+            .policy_enforcer
+            .access_scope(ctx, &resources::USER, actions::GET, Some(user_id))
+            .await?
+            .intersect(&tenant_scope);
+        let city_scope = self
+            .policy_enforcer
+            .access_scope(ctx, &resources::CITY, actions::GET, Some(city_id))
+            .await?
+            .intersect(&tenant_scope);
+        self.repo
+            .ensure_references_in_scope(conn, &user_scope, user_id, &city_scope, city_id)
+            .await
+    }
 }
 
 // Business logic methods
@@ -49,13 +90,15 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         ctx: &SecurityContext,
         id: Uuid,
     ) -> Result<Address, DomainError> {
+        self.ensure_enabled()?;
+
         debug!("Getting address by id");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
 
         // Prefetch: load address to extract owner properties for PDP.
         // PDP returns a narrow `eq` constraint instead of expanding the subtree.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let addr = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -96,6 +139,8 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         ctx: &SecurityContext,
         query: &ODataQuery,
     ) -> Result<Page<Address>, DomainError> {
+        self.ensure_enabled()?;
+
         debug!("Listing addresses with cursor pagination");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
@@ -117,6 +162,8 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         ctx: &SecurityContext,
         user_id: Uuid,
     ) -> Result<Option<Address>, DomainError> {
+        self.ensure_enabled()?;
+
         debug!("Getting address by user_id");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
@@ -147,6 +194,8 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         user_id: Uuid,
         address: NewAddress,
     ) -> Result<Address, DomainError> {
+        self.ensure_enabled()?;
+
         info!("Upserting address for user");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
@@ -154,7 +203,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         // Prefetch: load user and existing address without authorization scope.
         // These internal reads extract tenant_id for the PDP request — no data
         // is leaked to the caller. Authorization is enforced on the mutation below.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
 
         let user = self
             .users_repo
@@ -187,6 +236,15 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
                 )
                 .await?;
 
+            self.ensure_references(
+                &conn,
+                ctx,
+                existing_model.tenant_id,
+                user_id,
+                address.city_id,
+            )
+            .await?;
+
             let mut updated: Address = existing_model;
             updated.city_id = address.city_id;
             updated.street = address.street;
@@ -212,6 +270,9 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
                 )
                 .await?;
 
+            self.ensure_references(&conn, ctx, user.tenant_id, user_id, address.city_id)
+                .await?;
+
             let id = address.id.unwrap_or_else(Uuid::now_v7);
 
             let new_address = Address {
@@ -238,12 +299,14 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         ctx: &SecurityContext,
         user_id: Uuid,
     ) -> Result<(), DomainError> {
+        self.ensure_enabled()?;
+
         info!("Deleting address for user");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
 
         // Prefetch: load existing address to extract owner properties for PDP.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let existing = self
             .repo
             .get_by_user_id(&conn, &prefetch_scope, user_id)
@@ -280,6 +343,8 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         ctx: &SecurityContext,
         new_address: NewAddress,
     ) -> Result<Address, DomainError> {
+        self.ensure_enabled()?;
+
         info!("Creating new address");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
@@ -287,7 +352,7 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         // Prefetch: load user without authorization scope. This internal read
         // extracts tenant_id for the PDP request — no data is leaked to the
         // caller. Authorization is enforced on the CREATE below.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
 
         let user = self
             .users_repo
@@ -312,6 +377,15 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
             )
             .await?;
 
+        self.ensure_references(
+            &conn,
+            ctx,
+            tenant_id,
+            new_address.user_id,
+            new_address.city_id,
+        )
+        .await?;
+
         let now = OffsetDateTime::now_utc();
         let id = new_address.id.unwrap_or_else(Uuid::now_v7);
 
@@ -339,13 +413,15 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
         id: Uuid,
         patch: AddressPatch,
     ) -> Result<Address, DomainError> {
+        self.ensure_enabled()?;
+
         info!("Updating address");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
 
         // Prefetch: load existing address to extract owner properties for PDP.
         // Authorization is enforced on the mutation below via the narrowed scope.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let mut current = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -367,6 +443,8 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
             .await?;
 
         if let Some(city_id) = patch.city_id {
+            self.ensure_references(&conn, ctx, current.tenant_id, current.user_id, city_id)
+                .await?;
             current.city_id = city_id;
         }
         if let Some(street) = patch.street {
@@ -386,13 +464,15 @@ impl<R: AddressesRepository, U: UsersRepository> AddressesService<R, U> {
 
     #[instrument(skip(self, ctx), fields(address_id = %id))]
     pub async fn delete_address(&self, ctx: &SecurityContext, id: Uuid) -> Result<(), DomainError> {
+        self.ensure_enabled()?;
+
         info!("Deleting address");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
 
         // Prefetch: load existing address to extract owner properties for PDP.
         // Authorization is enforced on the delete below via the narrowed scope.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let existing = self.repo.get(&conn, &prefetch_scope, id).await?;
         let existing_model = existing.ok_or_else(|| DomainError::not_found("Address", id))?;
 
@@ -1,8 +1,12 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use modkit_macros::domain_model;
 use tracing::{debug, info, instrument};
 
+use crate::domain::city_import::{
+    CityImportLimits, CityImportReport, CityImportRow, RejectedRow, RowRejection, parse_city_csv,
+};
 use crate::domain::error::DomainError;
 use crate::domain::repos::CitiesRepository;
 use crate::domain::service::DbProvider;
@@ -11,7 +15,7 @@ use authz_resolver_sdk::pep::AccessRequest;
 
 use super::{actions, resources};
 use modkit_odata::{ODataQuery, Page};
-use modkit_security::{AccessScope, SecurityContext, pep_properties};
+use modkit_security::{AccessScope, ScopeIntent, SecurityContext, pep_properties};
 use time::OffsetDateTime;
 use users_info_sdk::{City, CityPatch, NewCity};
 use uuid::Uuid;
@@ -32,16 +36,32 @@ pub struct CitiesService<R: CitiesRepository> {
     db: Arc<DbProvider>,
     repo: Arc<R>,
     policy_enforcer: PolicyEnforcer,
+    import_limits: CityImportLimits,
 }
 
 impl<R: CitiesRepository> CitiesService<R> {
-    pub fn new(db: Arc<DbProvider>, repo: Arc<R>, policy_enforcer: PolicyEnforcer) -> Self {
+    pub fn new(
+        db: Arc<DbProvider>,
+        repo: Arc<R>,
+        policy_enforcer: PolicyEnforcer,
+        import_limits: CityImportLimits,
+    ) -> Self {
         Self {
             db,
             repo,
             policy_enforcer,
+            import_limits: CityImportLimits {
+                chunk_size: import_limits.chunk_size.max(1),
+                ..import_limits
+            },
         }
     }
+
+    /// Largest CSV upload accepted by [`Self::import_cities`].
+    #[must_use]
+    pub fn import_max_bytes(&self) -> usize {
+        self.import_limits.max_bytes
+    }
 }
 
 // Business logic methods
@@ -54,7 +74,7 @@ impl<R: CitiesRepository> CitiesService<R> {
 
         // Prefetch: load city to extract owner_tenant_id for PDP.
         // PDP returns a narrow `eq` constraint instead of expanding the subtree.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let city = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -161,7 +181,7 @@ impl<R: CitiesRepository> CitiesService<R> {
 
         // Prefetch: load city to extract owner_tenant_id for PDP.
         // Narrow scope + WHERE constraint provides TOCTOU protection.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let mut current = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -203,7 +223,7 @@ impl<R: CitiesRepository> CitiesService<R> {
 
         // Prefetch: load city to extract owner_tenant_id for PDP.
         // Narrow scope + WHERE constraint provides TOCTOU protection.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let prefetched = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -232,3 +252,169 @@ impl<R: CitiesRepository> CitiesService<R> {
         Ok(())
     }
 }
+
+// CSV import
+impl<R: CitiesRepository + 'static> CitiesService<R> {
+    /// Import cities from a CSV file (see [`crate::domain::city_import`]).
+    ///
+    /// Every row's tenant is authorized with its own CREATE decision, and the
+    /// resulting scope is checked against the row before anything is written.
+    /// Names that already exist in the tenant are skipped, so re-uploading a
+    /// file is idempotent. Valid rows are inserted in transactions of
+    /// `chunk_size` rows; if one fails, earlier chunks stay committed.
+    ///
+    /// With `dry_run`, the report is computed but nothing is written.
+    ///
+    /// # Errors
+    /// `PayloadTooLarge` if `csv` exceeds the byte limit, `Validation` if the
+    /// file is malformed or has too many rows, `InternalError` if the PDP
+    /// cannot be reached, and `Database` if an insert fails.
+    #[instrument(skip(self, ctx, csv), fields(bytes = csv.len()))]
+    pub async fn import_cities(
+        &self,
+        ctx: &SecurityContext,
+        csv: &[u8],
+        dry_run: bool,
+    ) -> Result<CityImportReport, DomainError> {
+        info!("Importing cities");
+
+        if csv.len() > self.import_limits.max_bytes {
+            return Err(DomainError::payload_too_large(self.import_limits.max_bytes));
+        }
+        let parsed = parse_city_csv(csv, ctx.subject_tenant_id(), self.import_limits.max_rows)?;
+
+        let mut scopes: HashMap<Uuid, Option<AccessScope>> = HashMap::new();
+        for row in &parsed.rows {
+            if !scopes.contains_key(&row.tenant_id) {
+                let scope = self.create_scope(ctx, row.tenant_id).await?;
+                scopes.insert(row.tenant_id, scope);
+            }
+        }
+
+        let mut report = CityImportReport {
+            dry_run,
+            failed: parsed.failed,
+            ..Default::default()
+        };
+        let now = OffsetDateTime::now_utc();
+        let mut candidates: Vec<(CityImportRow, City, AccessScope)> = Vec::new();
+        for row in parsed.rows {
+            let city = City {
+                id: Uuid::now_v7(),
+                tenant_id: row.tenant_id,
+                name: row.name.clone(),
+                country: row.country.clone(),
+                created_at: now,
+                updated_at: now,
+            };
+            match scopes.get(&row.tenant_id).and_then(Option::as_ref) {
+                Some(scope) if self.repo.insert_in_scope(scope, &city) => {
+                    candidates.push((row, city, scope.clone()));
+                }
+                _ => report.failed.push(RejectedRow {
+                    row: row.row,
+                    reason: RowRejection::TenantNotInScope(row.tenant_id),
+                }),
+            }
+        }
+
+        let candidates = self.skip_existing(candidates, &mut report).await?;
+        report.failed.sort_by_key(|r| r.row);
+
+        if !dry_run {
+            for chunk in candidates.chunks(self.import_limits.chunk_size) {
+                let repo = Arc::clone(&self.repo);
+                let chunk: Vec<(City, AccessScope)> = chunk
+                    .iter()
+                    .map(|(_, city, scope)| (city.clone(), scope.clone()))
+                    .collect();
+                self.db
+                    .transaction(|tx| {
+                        Box::pin(async move {
+                            for (city, scope) in chunk {
+                                repo.create(tx, &scope, city).await?;
+                            }
+                            Ok(())
+                        })
+                    })
+                    .await?;
+            }
+        }
+        report.created = candidates.into_iter().map(|(row, _, _)| row).collect();
+
+        info!(
+            created = report.created.len(),
+            skipped = report.skipped.len(),
+            failed = report.failed.len(),
+            "Finished city import"
+        );
+        Ok(report)
+    }
+
+    /// CREATE scope for cities of `tenant_id`, or `None` if the PDP denies it.
+    async fn create_scope(
+        &self,
+        ctx: &SecurityContext,
+        tenant_id: Uuid,
+    ) -> Result<Option<AccessScope>, DomainError> {
+        let result = self
+            .policy_enforcer
+            .access_scope_with(
+                ctx,
+                &resources::CITY,
+                actions::CREATE,
+                None,
+                &AccessRequest::new().resource_property(pep_properties::OWNER_TENANT_ID, tenant_id),
+            )
+            .await;
+        match result.map_err(DomainError::from) {
+            Ok(scope) => Ok(Some(scope)),
+            Err(DomainError::Forbidden) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Move rows whose name already exists in their tenant to `report.skipped`.
+    async fn skip_existing(
+        &self,
+        candidates: Vec<(CityImportRow, City, AccessScope)>,
+        report: &mut CityImportReport,
+    ) -> Result<Vec<(CityImportRow, City, AccessScope)>, DomainError> {
+        let conn = self.db.conn().map_err(DomainError::from)?;
+
+        let mut by_tenant: HashMap<Uuid, (&AccessScope, Vec<String>)> = HashMap::new();
+        for (row, _, scope) in &candidates {
+            by_tenant
+                .entry(row.tenant_id)
+                .or_insert_with(|| (scope, Vec::new()))
+                .1
+                .push(row.name.clone());
+        }
+
+        let mut existing: HashMap<Uuid, HashSet<String>> = HashMap::new();
+        for (tenant_id, (scope, names)) in by_tenant {
+            let mut found = HashSet::new();
+            for names in names.chunks(self.import_limits.chunk_size) {
+                found.extend(
+                    self.repo
+                        .existing_names(&conn, scope, tenant_id, names)
+                        .await?,
+                );
+            }
+            existing.insert(tenant_id, found);
+        }
+
+        let (skipped, kept): (Vec<_>, Vec<_>) = candidates.into_iter().partition(|(row, _, _)| {
+            existing
+                .get(&row.tenant_id)
+                .is_some_and(|names| names.contains(&row.name))
+        });
+        report
+            .skipped
+            .extend(skipped.into_iter().map(|(row, _, _)| RejectedRow {
+                row: row.row,
+                reason: RowRejection::AlreadyExists,
+            }));
+        Ok(kept)
+    }
+}
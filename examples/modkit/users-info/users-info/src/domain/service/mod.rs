@@ -3,9 +3,13 @@
 //! ## Architecture
 //!
 //! This module implements the domain service pattern with per-resource submodules:
-//! - `users` - User CRUD and business rules (email/display name validation)
-//! - `cities` - City CRUD operations
+//! - `users` - User CRUD and business rules (email policy, display name validation),
+//!   plus self-service access to the caller's own profile
+//! - `cities` - City CRUD operations and CSV import
 //! - `addresses` - Address management (1-to-1 with users)
+//! - `tenant_data` - Tenant data lifecycle (GDPR-style erasure / anonymization)
+//! - `webhooks` - Webhook subscriptions and their delivery log
+//! - `tenant_settings` - Per-tenant limit overrides and their cached resolution
 //!
 //! ## Layering Rules
 //!
@@ -31,6 +35,11 @@
 //! 3. The enforcer builds the request, evaluates via PDP, and compiles to `AccessScope`
 //! 4. Pass scope to repository methods for tenant-isolated queries
 //!
+//! Call sites only set resource properties. Tenant mode, barrier mode and
+//! tenant status come from the `AuthZ` resolver's `evaluation_defaults`
+//! configuration (the enforcer is built with `with_resolver_defaults()`), so
+//! changing a convention does not touch this module.
+//!
 //! ### Subtree authorization (no closure table)
 //!
 //! Enforcers are created with empty `capabilities` (no `tenant_hierarchy`).
@@ -56,21 +65,34 @@
 //! - Maintains transaction safety via the task-local guard
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use modkit_macros::domain_model;
 
+use crate::domain::city_import::CityImportLimits;
+use crate::domain::email::EmailPolicy;
 use crate::domain::events::UserDomainEvent;
 use crate::domain::ports::{AuditPort, EventPublisher};
-use crate::domain::repos::{AddressesRepository, CitiesRepository, UsersRepository};
+use crate::domain::profile::SelfServicePolicy;
+use crate::domain::repos::{
+    AddressesRepository, CitiesRepository, TenantSettingsRepository, UsersRepository,
+    WebhooksRepository,
+};
+use crate::domain::tenant_settings::TenantLimits;
+use crate::domain::webhooks::TargetPolicy;
 use authz_resolver_sdk::AuthZResolverClient;
 use authz_resolver_sdk::PolicyEnforcer;
 use authz_resolver_sdk::pep::ResourceType;
+use modkit::FeatureGate;
 use modkit_db::DBProvider;
 use modkit_db::odata::LimitCfg;
 
 mod addresses;
 mod cities;
+mod tenant_data;
+mod tenant_settings;
 mod users;
+mod webhooks;
 
 /// Authorization resource types and their PEP-supported properties.
 ///
@@ -103,6 +125,21 @@ mod users;
 ///   restrictions, e.g., "user A may only have addresses in city 1,
 ///   user B — only in city 2". PDP returns `eq(city_id, <allowed_city>)`
 ///   or `in(city_id, [city1, city2])` predicates.
+///
+/// ## `TENANT_DATA`
+/// - **Tenant isolation**: `owner_tenant_id` — represents all data owned by a
+///   tenant. Used for bulk lifecycle operations (purge); the PDP scope must
+///   contain the target tenant explicitly.
+///
+/// ## `WEBHOOK`
+/// - **Tenant isolation**: `owner_tenant_id` — subscriptions are tenant-scoped
+///   and only receive events about users of the same tenant.
+/// - **Resource-level access**: `id` — PDP may restrict to specific webhooks.
+///   Reading a webhook also grants reading its delivery log.
+///
+/// ## `TENANT_SETTINGS`
+/// - **Tenant isolation**: `owner_tenant_id` — per-tenant limit overrides,
+///   managed by admins; the PDP scope must contain the target tenant explicitly.
 pub(crate) mod resources {
     use super::ResourceType;
     use modkit_security::pep_properties;
@@ -132,6 +169,21 @@ pub(crate) mod resources {
             properties::CITY_ID,
         ],
     };
+
+    pub const TENANT_DATA: ResourceType = ResourceType {
+        name: "users_info.tenant_data",
+        supported_properties: &[pep_properties::OWNER_TENANT_ID],
+    };
+
+    pub const WEBHOOK: ResourceType = ResourceType {
+        name: "users_info.webhook",
+        supported_properties: &[pep_properties::OWNER_TENANT_ID, pep_properties::RESOURCE_ID],
+    };
+
+    pub const TENANT_SETTINGS: ResourceType = ResourceType {
+        name: "users_info.tenant_settings",
+        supported_properties: &[pep_properties::OWNER_TENANT_ID],
+    };
 }
 
 pub(crate) mod actions {
@@ -142,28 +194,63 @@ pub(crate) mod actions {
     pub const DELETE: &str = "delete";
 }
 
+/// Module feature flags (`modules.users-info.features`).
+///
+/// Unlisted features are enabled; see [`modkit::FeatureGate`].
+pub(crate) mod features {
+    /// Address management: REST routes and `AddressesService` operations.
+    pub const ADDRESSES: &str = "addresses";
+}
+
 pub(crate) use addresses::AddressesService;
 pub(crate) use cities::CitiesService;
+pub(crate) use tenant_data::{PurgeMode, TenantDataService, TenantPurgeReport};
+pub(crate) use tenant_settings::{TenantConfigResolver, TenantSettingsService};
 pub(crate) use users::UsersService;
+pub(crate) use webhooks::WebhooksService;
 
 pub(crate) type DbProvider = DBProvider<modkit_db::DbError>;
 
 /// Configuration for the domain service
-#[domain_model]
+#[domain_model(builder)]
 #[derive(Debug, Clone)]
 pub struct ServiceConfig {
+    #[builder(default = 100)]
     pub max_display_name_length: usize,
     pub default_page_size: u32,
     pub max_page_size: u32,
+    /// Largest batch accepted by bulk user creation.
+    #[builder(default = 1000)]
+    pub max_bulk_users: usize,
+    /// Rows processed per statement during a tenant purge.
+    #[builder(default = 500)]
+    pub purge_chunk_size: u64,
+    /// Size limits and batching for city CSV imports.
+    #[builder(default)]
+    pub city_import: CityImportLimits,
+    /// Email normalization and validation rules.
+    #[builder(default)]
+    pub email_policy: EmailPolicy,
+    /// SSRF rules for webhook target URLs.
+    #[builder(default)]
+    pub webhook_targets: TargetPolicy,
+    /// Subject-to-user mapping and editable fields for `/me`.
+    #[builder(default)]
+    pub self_service: SelfServicePolicy,
+    /// Users per tenant unless the tenant overrides it; `None` is unlimited.
+    #[builder(default)]
+    pub max_users_per_tenant: Option<u32>,
+    /// How long resolved tenant settings are cached.
+    #[builder(default = Duration::from_secs(30))]
+    pub tenant_settings_ttl: Duration,
 }
 
 impl Default for ServiceConfig {
     fn default() -> Self {
-        Self {
-            max_display_name_length: 100,
-            default_page_size: 50,
-            max_page_size: 1000,
-        }
+        Self::builder()
+            .default_page_size(50)
+            .max_page_size(1000)
+            .build()
     }
 }
 
@@ -175,6 +262,16 @@ impl ServiceConfig {
             max: u64::from(self.max_page_size),
         }
     }
+
+    /// Limits of a tenant without overrides.
+    #[must_use]
+    pub fn tenant_defaults(&self) -> TenantLimits {
+        TenantLimits {
+            max_users: self.max_users_per_tenant,
+            max_page_size: self.max_page_size,
+            max_display_name_length: self.max_display_name_length,
+        }
+    }
 }
 
 // DI Container - aggregates all domain services
@@ -188,15 +285,20 @@ impl ServiceConfig {
 // **Security**: A task-local guard prevents `Db::conn()` from being called
 // inside transaction closures, eliminating the factory bypass vulnerability.
 #[domain_model]
-pub(crate) struct AppServices<UR, CR, AR>
+pub(crate) struct AppServices<UR, CR, AR, WR, TR>
 where
     UR: UsersRepository + 'static,
     CR: CitiesRepository,
     AR: AddressesRepository,
+    WR: WebhooksRepository,
+    TR: TenantSettingsRepository,
 {
-    pub(crate) users: UsersService<UR, CR, AR>,
+    pub(crate) users: UsersService<UR, CR, AR, TR>,
     pub(crate) cities: Arc<CitiesService<CR>>,
     pub(crate) addresses: Arc<AddressesService<AR, UR>>,
+    pub(crate) tenant_data: TenantDataService<UR, CR, AR>,
+    pub(crate) webhooks: WebhooksService<WR>,
+    pub(crate) tenant_settings: TenantSettingsService<TR>,
 }
 
 #[cfg(test)]
@@ -211,44 +313,123 @@ mod tests_tenant_scoping;
 #[cfg(test)]
 mod tests_entities;
 
+#[cfg(test)]
+mod tests_email_policy;
+
+#[cfg(test)]
+mod tests_name_search;
+
+#[cfg(test)]
+mod tests_user_search;
+
 #[cfg(test)]
 mod tests_cursor_pagination;
 
-impl<UR, CR, AR> AppServices<UR, CR, AR>
+#[cfg(test)]
+mod tests_features;
+
+#[cfg(test)]
+mod tests_tenant_purge;
+
+#[cfg(test)]
+mod tests_webhooks;
+
+#[cfg(test)]
+mod tests_self_service;
+
+#[cfg(test)]
+mod tests_scope_filters;
+
+#[cfg(test)]
+mod tests_city_import;
+
+#[cfg(test)]
+mod tests_bulk_users;
+
+#[cfg(test)]
+mod tests_tenant_settings;
+
+#[cfg(test)]
+mod tests_address_summaries;
+
+#[cfg(test)]
+mod tests_city_users;
+
+#[cfg(test)]
+mod tests_audit_columns;
+
+impl<UR, CR, AR, WR, TR> AppServices<UR, CR, AR, WR, TR>
 where
     UR: UsersRepository + 'static,
     CR: CitiesRepository,
     AR: AddressesRepository,
+    WR: WebhooksRepository,
+    TR: TenantSettingsRepository,
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         users_repo: UR,
         cities_repo: CR,
         addresses_repo: AR,
+        webhooks_repo: Arc<WR>,
+        tenant_settings_repo: TR,
         db: Arc<DbProvider>,
         events: Arc<dyn EventPublisher<UserDomainEvent>>,
         audit: Arc<dyn AuditPort>,
         authz: Arc<dyn AuthZResolverClient>,
         config: ServiceConfig,
+        features: FeatureGate,
     ) -> Self {
         let users_repo = Arc::new(users_repo);
         let cities_repo = Arc::new(cities_repo);
         let addresses_repo = Arc::new(addresses_repo);
 
-        let enforcer = PolicyEnforcer::new(authz);
+        let enforcer = PolicyEnforcer::new(authz).with_resolver_defaults();
+
+        let tenant_settings_repo = Arc::new(tenant_settings_repo);
+        let tenant_limits = Arc::new(TenantConfigResolver::new(
+            Arc::clone(&db),
+            Arc::clone(&tenant_settings_repo),
+            config.tenant_defaults(),
+            config.tenant_settings_ttl,
+        ));
+        let tenant_settings = TenantSettingsService::new(
+            Arc::clone(&db),
+            tenant_settings_repo,
+            Arc::clone(&tenant_limits),
+            enforcer.clone(),
+        );
 
         let cities = Arc::new(CitiesService::new(
             Arc::clone(&db),
             Arc::clone(&cities_repo),
             enforcer.clone(),
+            config.city_import,
         ));
         let addresses = Arc::new(AddressesService::new(
             Arc::clone(&db),
             Arc::clone(&addresses_repo),
             Arc::clone(&users_repo),
             enforcer.clone(),
+            features,
         ));
 
+        let tenant_data = TenantDataService::new(
+            Arc::clone(&db),
+            Arc::clone(&users_repo),
+            Arc::clone(&cities_repo),
+            Arc::clone(&addresses_repo),
+            enforcer.clone(),
+            config.purge_chunk_size,
+        );
+
+        let webhooks = WebhooksService::new(
+            Arc::clone(&db),
+            webhooks_repo,
+            enforcer.clone(),
+            config.webhook_targets.clone(),
+        );
+
         Self {
             users: UsersService::new(
                 db,
@@ -259,9 +440,13 @@ where
                 config,
                 cities.clone(),
                 addresses.clone(),
+                tenant_limits,
             ),
             cities,
             addresses,
+            tenant_data,
+            webhooks,
+            tenant_settings,
         }
     }
 }
@@ -0,0 +1,295 @@
+use std::sync::Arc;
+
+use modkit_macros::domain_model;
+use tracing::{info, instrument};
+
+use crate::domain::error::DomainError;
+use crate::domain::repos::{AddressesRepository, CitiesRepository, UsersRepository};
+use crate::domain::service::DbProvider;
+use authz_resolver_sdk::PolicyEnforcer;
+use authz_resolver_sdk::pep::AccessRequest;
+
+use super::{actions, resources};
+use modkit_db::secure::DBRunner;
+use modkit_security::{AccessScope, SecurityContext, pep_properties};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Placeholder written to `display_name` when a user is anonymized.
+pub const ANONYMIZED_DISPLAY_NAME: &str = "Anonymized user";
+
+/// Placeholder written to `street` when an address is anonymized.
+pub const ANONYMIZED_STREET: &str = "Anonymized";
+
+/// How tenant data is purged.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PurgeMode {
+    /// Delete all rows owned by the tenant.
+    #[default]
+    Erase,
+    /// Keep rows but overwrite PII columns with deterministic placeholders.
+    Anonymize,
+}
+
+/// Per-table row counts affected by a tenant purge.
+#[domain_model]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TenantPurgeReport {
+    pub tenant_id: Uuid,
+    pub mode: PurgeMode,
+    pub users: u64,
+    pub addresses: u64,
+    pub cities: u64,
+}
+
+/// Deterministic placeholder email for an anonymized user.
+///
+/// Derived from the user ID so the per-tenant unique email index stays valid.
+#[must_use]
+pub fn anonymized_email(user_id: Uuid) -> String {
+    format!("anonymized+{}@users-info.invalid", user_id.simple())
+}
+
+/// Tenant data lifecycle service (GDPR-style erasure).
+///
+/// Purges run inside a single transaction and process rows in chunks of
+/// `chunk_size`, logging progress after every chunk so large tenants can be
+/// followed in the logs.
+#[domain_model]
+pub struct TenantDataService<UR: UsersRepository, CR: CitiesRepository, AR: AddressesRepository> {
+    db: Arc<DbProvider>,
+    users_repo: Arc<UR>,
+    cities_repo: Arc<CR>,
+    addresses_repo: Arc<AR>,
+    policy_enforcer: PolicyEnforcer,
+    chunk_size: u64,
+}
+
+impl<UR: UsersRepository, CR: CitiesRepository, AR: AddressesRepository>
+    TenantDataService<UR, CR, AR>
+{
+    pub fn new(
+        db: Arc<DbProvider>,
+        users_repo: Arc<UR>,
+        cities_repo: Arc<CR>,
+        addresses_repo: Arc<AR>,
+        policy_enforcer: PolicyEnforcer,
+        chunk_size: u64,
+    ) -> Self {
+        Self {
+            db,
+            users_repo,
+            cities_repo,
+            addresses_repo,
+            policy_enforcer,
+            chunk_size: chunk_size.max(1),
+        }
+    }
+}
+
+// Business logic methods
+impl<UR, CR, AR> TenantDataService<UR, CR, AR>
+where
+    UR: UsersRepository + 'static,
+    CR: CitiesRepository + 'static,
+    AR: AddressesRepository + 'static,
+{
+    /// Delete or anonymize all data owned by `tenant_id`.
+    ///
+    /// The PDP must return a scope that explicitly contains `tenant_id`;
+    /// unconstrained or narrower scopes are rejected with `Forbidden`.
+    #[instrument(skip(self, ctx), fields(tenant_id = %tenant_id, mode = ?mode))]
+    pub async fn purge_tenant(
+        &self,
+        ctx: &SecurityContext,
+        tenant_id: Uuid,
+        mode: PurgeMode,
+    ) -> Result<TenantPurgeReport, DomainError> {
+        info!("Purging tenant data");
+
+        let pdp_scope = self
+            .policy_enforcer
+            .access_scope_with(
+                ctx,
+                &resources::TENANT_DATA,
+                actions::DELETE,
+                Some(tenant_id),
+                &AccessRequest::new().resource_property(pep_properties::OWNER_TENANT_ID, tenant_id),
+            )
+            .await?;
+
+        if !pdp_scope.contains_uuid(pep_properties::OWNER_TENANT_ID, tenant_id) {
+            return Err(DomainError::Forbidden);
+        }
+
+        // Target exactly this tenant, even if the PDP granted a wider subtree.
+        let scope = AccessScope::for_tenant(tenant_id);
+
+        let users_repo = Arc::clone(&self.users_repo);
+        let cities_repo = Arc::clone(&self.cities_repo);
+        let addresses_repo = Arc::clone(&self.addresses_repo);
+        let chunk_size = self.chunk_size;
+        let ctx = ctx.clone();
+
+        let report = self
+            .db
+            .transaction(|tx| {
+                Box::pin(async move {
+                    let mut report = TenantPurgeReport {
+                        tenant_id,
+                        mode,
+                        ..Default::default()
+                    };
+                    match mode {
+                        PurgeMode::Erase => {
+                            report.addresses =
+                                erase_addresses(&*addresses_repo, tx, &scope, chunk_size).await?;
+                            report.users =
+                                erase_users(&*users_repo, tx, &scope, chunk_size).await?;
+                            report.cities =
+                                erase_cities(&*cities_repo, tx, &scope, chunk_size).await?;
+                        }
+                        PurgeMode::Anonymize => {
+                            report.addresses =
+                                anonymize_addresses(&*addresses_repo, tx, &scope, chunk_size)
+                                    .await?;
+                            report.users =
+                                anonymize_users(&*users_repo, tx, &scope, &ctx, chunk_size).await?;
+                        }
+                    }
+                    Ok(report)
+                })
+            })
+            .await?;
+
+        info!(
+            users = report.users,
+            addresses = report.addresses,
+            cities = report.cities,
+            "Successfully purged tenant data"
+        );
+        Ok(report)
+    }
+}
+
+fn log_progress(table: &str, processed: u64) {
+    info!(table, processed, "Tenant purge progress");
+}
+
+async fn erase_addresses<R: AddressesRepository>(
+    repo: &R,
+    runner: &impl DBRunner,
+    scope: &AccessScope,
+    chunk_size: u64,
+) -> Result<u64, DomainError> {
+    let mut total = 0;
+    loop {
+        let chunk = repo.list_chunk(runner, scope, None, chunk_size).await?;
+        if chunk.is_empty() {
+            return Ok(total);
+        }
+        let ids: Vec<Uuid> = chunk.iter().map(|a| a.id).collect();
+        let deleted = repo.delete_by_ids(runner, scope, &ids).await?;
+        if deleted == 0 {
+            return Ok(total);
+        }
+        total += deleted;
+        log_progress("addresses", total);
+    }
+}
+
+async fn erase_users<R: UsersRepository>(
+    repo: &R,
+    runner: &impl DBRunner,
+    scope: &AccessScope,
+    chunk_size: u64,
+) -> Result<u64, DomainError> {
+    let mut total = 0;
+    loop {
+        let chunk = repo.list_chunk(runner, scope, None, chunk_size).await?;
+        if chunk.is_empty() {
+            return Ok(total);
+        }
+        let ids: Vec<Uuid> = chunk.iter().map(|u| u.id).collect();
+        let deleted = repo.delete_by_ids(runner, scope, &ids).await?;
+        if deleted == 0 {
+            return Ok(total);
+        }
+        total += deleted;
+        log_progress("users", total);
+    }
+}
+
+async fn erase_cities<R: CitiesRepository>(
+    repo: &R,
+    runner: &impl DBRunner,
+    scope: &AccessScope,
+    chunk_size: u64,
+) -> Result<u64, DomainError> {
+    let mut total = 0;
+    loop {
+        let chunk = repo.list_chunk(runner, scope, None, chunk_size).await?;
+        if chunk.is_empty() {
+            return Ok(total);
+        }
+        let ids: Vec<Uuid> = chunk.iter().map(|c| c.id).collect();
+        let deleted = repo.delete_by_ids(runner, scope, &ids).await?;
+        if deleted == 0 {
+            return Ok(total);
+        }
+        total += deleted;
+        log_progress("cities", total);
+    }
+}
+
+async fn anonymize_addresses<R: AddressesRepository>(
+    repo: &R,
+    runner: &impl DBRunner,
+    scope: &AccessScope,
+    chunk_size: u64,
+) -> Result<u64, DomainError> {
+    let mut total = 0;
+    let mut after = None;
+    loop {
+        let chunk = repo.list_chunk(runner, scope, after, chunk_size).await?;
+        let Some(last) = chunk.last() else {
+            return Ok(total);
+        };
+        after = Some(last.id);
+        let now = OffsetDateTime::now_utc();
+        for mut address in chunk {
+            address.street = ANONYMIZED_STREET.to_owned();
+            address.updated_at = now;
+            let _ = repo.update(runner, scope, address).await?;
+            total += 1;
+        }
+        log_progress("addresses", total);
+    }
+}
+
+async fn anonymize_users<R: UsersRepository>(
+    repo: &R,
+    runner: &impl DBRunner,
+    scope: &AccessScope,
+    ctx: &SecurityContext,
+    chunk_size: u64,
+) -> Result<u64, DomainError> {
+    let mut total = 0;
+    let mut after = None;
+    loop {
+        let chunk = repo.list_chunk(runner, scope, after, chunk_size).await?;
+        let Some(last) = chunk.last() else {
+            return Ok(total);
+        };
+        after = Some(last.id);
+        for mut user in chunk {
+            user.email = anonymized_email(user.id);
+            user.email_original = None;
+            user.display_name = ANONYMIZED_DISPLAY_NAME.to_owned();
+            let _ = repo.update(runner, scope, ctx, user).await?;
+            total += 1;
+        }
+        log_progress("users", total);
+    }
+}
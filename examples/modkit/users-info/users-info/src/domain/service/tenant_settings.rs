@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use modkit_macros::domain_model;
+use tracing::{info, instrument};
+
+use crate::domain::error::DomainError;
+use crate::domain::repos::TenantSettingsRepository;
+use crate::domain::service::DbProvider;
+use crate::domain::tenant_settings::{TenantLimitOverrides, TenantLimits, TenantSettings};
+use authz_resolver_sdk::PolicyEnforcer;
+use authz_resolver_sdk::pep::AccessRequest;
+
+use super::{actions, resources};
+use modkit_security::{AccessScope, ScopeIntent, SecurityContext, pep_properties};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Resolves the effective limits of a tenant.
+///
+/// Merges the global limits with the tenant's `tenant_settings` row. Results
+/// are cached for `ttl`; writes through [`TenantSettingsService`] drop the
+/// entry at once, other module instances pick the change up on expiry.
+#[domain_model]
+pub struct TenantConfigResolver<TR: TenantSettingsRepository> {
+    db: Arc<DbProvider>,
+    repo: Arc<TR>,
+    defaults: TenantLimits,
+    ttl: Duration,
+    cache: Mutex<HashMap<Uuid, (Instant, TenantLimits)>>,
+}
+
+impl<TR: TenantSettingsRepository> TenantConfigResolver<TR> {
+    pub fn new(db: Arc<DbProvider>, repo: Arc<TR>, defaults: TenantLimits, ttl: Duration) -> Self {
+        Self {
+            db,
+            repo,
+            defaults,
+            ttl,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Global limits, used for tenants without settings.
+    #[must_use]
+    pub fn defaults(&self) -> &TenantLimits {
+        &self.defaults
+    }
+
+    /// Effective limits of `tenant_id`.
+    ///
+    /// The nil tenant (anonymous contexts) always gets the global limits.
+    pub async fn resolve(&self, tenant_id: Uuid) -> Result<TenantLimits, DomainError> {
+        if tenant_id.is_nil() {
+            return Ok(self.defaults);
+        }
+        if let Some(limits) = self.cached(tenant_id) {
+            return Ok(limits);
+        }
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        // SAFETY(PEP bypass): limits are read on behalf of the service to
+        // enforce quotas; nothing is returned to the caller.
+        let scope = AccessScope::for_tenant(tenant_id).with_intent(ScopeIntent::Read);
+        let limits = match self.repo.get(&conn, &scope, tenant_id).await? {
+            Some(settings) => self.defaults.with_overrides(&settings.limits),
+            None => self.defaults,
+        };
+
+        self.lock_cache()
+            .insert(tenant_id, (Instant::now() + self.ttl, limits));
+        Ok(limits)
+    }
+
+    /// Drop the cached limits of `tenant_id`.
+    pub fn invalidate(&self, tenant_id: Uuid) {
+        self.lock_cache().remove(&tenant_id);
+    }
+
+    fn cached(&self, tenant_id: Uuid) -> Option<TenantLimits> {
+        let mut cache = self.lock_cache();
+        match cache.get(&tenant_id) {
+            Some((expires_at, limits)) if *expires_at > Instant::now() => Some(*limits),
+            Some(_) => {
+                cache.remove(&tenant_id);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn lock_cache(&self) -> std::sync::MutexGuard<'_, HashMap<Uuid, (Instant, TenantLimits)>> {
+        // The map stays consistent even if a holder panicked.
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Tenant settings administration.
+///
+/// Both reading and writing require a grant on `users_info.tenant_settings`
+/// whose scope contains the tenant explicitly.
+#[domain_model]
+pub struct TenantSettingsService<TR: TenantSettingsRepository> {
+    db: Arc<DbProvider>,
+    repo: Arc<TR>,
+    resolver: Arc<TenantConfigResolver<TR>>,
+    policy_enforcer: PolicyEnforcer,
+}
+
+impl<TR: TenantSettingsRepository> TenantSettingsService<TR> {
+    pub fn new(
+        db: Arc<DbProvider>,
+        repo: Arc<TR>,
+        resolver: Arc<TenantConfigResolver<TR>>,
+        policy_enforcer: PolicyEnforcer,
+    ) -> Self {
+        Self {
+            db,
+            repo,
+            resolver,
+            policy_enforcer,
+        }
+    }
+
+    /// Get the overrides of `tenant_id`; all `None` if none were stored.
+    #[instrument(skip(self, ctx), fields(tenant_id = %tenant_id))]
+    pub async fn get_settings(
+        &self,
+        ctx: &SecurityContext,
+        tenant_id: Uuid,
+    ) -> Result<TenantSettings, DomainError> {
+        self.authorize(ctx, tenant_id, actions::GET).await?;
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let scope = AccessScope::for_tenant(tenant_id).with_intent(ScopeIntent::Read);
+        let settings = self.repo.get(&conn, &scope, tenant_id).await?;
+
+        Ok(settings.unwrap_or(TenantSettings {
+            tenant_id,
+            limits: TenantLimitOverrides::default(),
+            updated_at: None,
+        }))
+    }
+
+    /// Replace the overrides of `tenant_id`.
+    ///
+    /// # Errors
+    /// `Validation` if an override is zero or exceeds the global limit.
+    #[instrument(skip(self, ctx), fields(tenant_id = %tenant_id))]
+    pub async fn put_settings(
+        &self,
+        ctx: &SecurityContext,
+        tenant_id: Uuid,
+        limits: TenantLimitOverrides,
+    ) -> Result<TenantSettings, DomainError> {
+        info!("Updating tenant settings");
+
+        limits.validate(self.resolver.defaults())?;
+        self.authorize(ctx, tenant_id, actions::UPDATE).await?;
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let scope = AccessScope::for_tenant(tenant_id);
+        let settings = self
+            .repo
+            .upsert(
+                &conn,
+                &scope,
+                TenantSettings {
+                    tenant_id,
+                    limits,
+                    updated_at: Some(OffsetDateTime::now_utc()),
+                },
+            )
+            .await?;
+
+        self.resolver.invalidate(tenant_id);
+        Ok(settings)
+    }
+
+    /// The PDP must return a scope that explicitly contains `tenant_id`;
+    /// unconstrained or narrower scopes are rejected with `Forbidden`.
+    async fn authorize(
+        &self,
+        ctx: &SecurityContext,
+        tenant_id: Uuid,
+        action: &str,
+    ) -> Result<(), DomainError> {
+        let pdp_scope = self
+            .policy_enforcer
+            .access_scope_with(
+                ctx,
+                &resources::TENANT_SETTINGS,
+                action,
+                Some(tenant_id),
+                &AccessRequest::new().resource_property(pep_properties::OWNER_TENANT_ID, tenant_id),
+            )
+            .await?;
+
+        if !pdp_scope.contains_uuid(pep_properties::OWNER_TENANT_ID, tenant_id) {
+            return Err(DomainError::Forbidden);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,234 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_db::fake::{FakeRunner, RecordedStatement, StatementKind};
+use modkit_odata::{CursorV1, ODataQuery};
+use sea_orm::Value;
+use time::OffsetDateTime;
+use users_info_sdk::{NewAddress, NewCity};
+use uuid::Uuid;
+
+use crate::domain::service::ServiceConfig;
+use crate::infra::storage::entity::{address, city, user};
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db, seed_user};
+
+fn summary_query(limit: u64) -> ODataQuery {
+    ODataQuery::default()
+        .with_limit(limit)
+        .with_select(vec!["id".to_owned(), "address_summary".to_owned()])
+}
+
+fn user_model(tenant_id: Uuid, i: usize) -> user::Model {
+    let now = OffsetDateTime::now_utc();
+    user::Model {
+        id: Uuid::new_v4(),
+        tenant_id,
+        email: format!("user{i}@example.com"),
+        email_original: None,
+        display_name: format!("User {i}"),
+        external_subject_id: None,
+        created_at: now,
+        updated_at: now,
+        created_by: None,
+        updated_by: None,
+    }
+}
+
+fn address_model(user: &user::Model, city_id: Uuid) -> address::Model {
+    let now = OffsetDateTime::now_utc();
+    address::Model {
+        id: Uuid::new_v4(),
+        tenant_id: user.tenant_id,
+        user_id: user.id,
+        city_id,
+        street: "Rua Augusta 1".to_owned(),
+        postal_code: "1100-048".to_owned(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+fn city_model(tenant_id: Uuid, name: &str) -> city::Model {
+    let now = OffsetDateTime::now_utc();
+    city::Model {
+        id: Uuid::new_v4(),
+        tenant_id,
+        name: name.to_owned(),
+        country: "Portugal".to_owned(),
+        created_at: now,
+        updated_at: now,
+    }
+}
+
+/// Statements of the listing itself; the tenant limits lookup is not counted.
+fn list_statements(fake: &FakeRunner) -> Vec<RecordedStatement> {
+    fake.statements()
+        .into_iter()
+        .filter(|s| s.table.as_deref() != Some("tenant_settings"))
+        .collect()
+}
+
+#[tokio::test]
+async fn summaries_for_a_full_page_take_three_queries() {
+    let fake = FakeRunner::new();
+    let tenant_id = Uuid::new_v4();
+    let lisbon = city_model(tenant_id, "Lisbon");
+    let users: Vec<user::Model> = (0..50).map(|i| user_model(tenant_id, i)).collect();
+    let addresses = users.iter().map(|u| address_model(u, lisbon.id)).collect();
+    fake.on_select::<user::Entity>().return_models(users);
+    fake.on_select::<address::Entity>().return_models(addresses);
+    fake.on_select::<city::Entity>().return_models(vec![lisbon]);
+
+    let services = build_services(fake.db(), ServiceConfig::default());
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let page = services
+        .users
+        .list_users_page_with_summaries(&ctx, &summary_query(50))
+        .await
+        .unwrap();
+
+    assert_eq!(page.items.len(), 50);
+    assert!(page.items.iter().all(|item| {
+        item.address
+            .as_ref()
+            .and_then(|a| a.city_name.as_deref())
+            .is_some_and(|name| name == "Lisbon")
+    }));
+
+    let statements = list_statements(&fake);
+    let tables: Vec<_> = statements.iter().map(|s| s.table.as_deref()).collect();
+    assert_eq!(
+        tables,
+        vec![Some("users"), Some("addresses"), Some("cities")],
+        "{statements:#?}"
+    );
+    // Batch loads are scoped like any other read.
+    for s in &statements {
+        assert_eq!(s.kind, StatementKind::Select);
+        let condition = s.condition.as_deref().unwrap_or_default();
+        assert!(condition.contains("\"tenant_id\""), "{condition}");
+        assert!(s.values.contains(&Value::from(tenant_id)), "{s:#?}");
+    }
+}
+
+#[tokio::test]
+async fn listing_without_summary_takes_one_query() {
+    let fake = FakeRunner::new();
+    let tenant_id = Uuid::new_v4();
+    let users: Vec<user::Model> = (0..50).map(|i| user_model(tenant_id, i)).collect();
+    fake.on_select::<user::Entity>().return_models(users);
+
+    let services = build_services(fake.db(), ServiceConfig::default());
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+    let query = ODataQuery::default()
+        .with_limit(50)
+        .with_select(vec!["id".to_owned(), "display_name".to_owned()]);
+
+    let page = services
+        .users
+        .list_users_page_with_summaries(&ctx, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(page.items.len(), 50);
+    assert!(page.items.iter().all(|item| item.address.is_none()));
+    assert_eq!(list_statements(&fake).len(), 1);
+    assert_eq!(fake.calls::<address::Entity>(StatementKind::Select), 0);
+    assert_eq!(fake.calls::<city::Entity>(StatementKind::Select), 0);
+}
+
+#[tokio::test]
+async fn summaries_follow_scope_and_keep_cursors() {
+    let db = inmem_db().await;
+    let conn = db.conn().unwrap();
+    let services = build_services(db.clone(), ServiceConfig::default());
+
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    let with_address = Uuid::new_v4();
+    let without_address = Uuid::new_v4();
+    let foreign = Uuid::new_v4();
+    seed_user(&conn, with_address, tenant_a, "a1@example.com", "A1").await;
+    seed_user(&conn, without_address, tenant_a, "a2@example.com", "A2").await;
+    seed_user(&conn, foreign, tenant_b, "b1@example.com", "B1").await;
+
+    for (tenant_id, user_id, name) in [
+        (tenant_a, with_address, "Lisbon"),
+        (tenant_b, foreign, "Porto"),
+    ] {
+        let ctx = ctx_allow_tenants(&[tenant_id]);
+        let city = services
+            .cities
+            .create_city(
+                &ctx,
+                NewCity {
+                    id: None,
+                    tenant_id,
+                    name: name.to_owned(),
+                    country: "Portugal".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+        services
+            .addresses
+            .create_address(
+                &ctx,
+                NewAddress {
+                    id: None,
+                    tenant_id,
+                    user_id,
+                    city_id: city.id,
+                    street: "Main St 1".to_owned(),
+                    postal_code: "1000-001".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let ctx = ctx_allow_tenants(&[tenant_a]);
+    let page = services
+        .users
+        .list_users_page_with_summaries(&ctx, &summary_query(10))
+        .await
+        .unwrap();
+
+    assert_eq!(page.items.len(), 2);
+    for item in &page.items {
+        let city_name = item.address.as_ref().and_then(|a| a.city_name.as_deref());
+        if item.user.id == with_address {
+            assert_eq!(city_name, Some("Lisbon"));
+        } else {
+            assert_eq!(item.user.id, without_address);
+            assert!(item.address.is_none());
+        }
+    }
+
+    // Embedding summaries does not change paging.
+    let plain = services
+        .users
+        .list_users_page(&ctx, &ODataQuery::default().with_limit(1))
+        .await
+        .unwrap();
+    let summarized = services
+        .users
+        .list_users_page_with_summaries(&ctx, &summary_query(1))
+        .await
+        .unwrap();
+    assert_eq!(summarized.items[0].user.id, plain.items[0].id);
+    let cursor = plain.page_info.next_cursor.clone().expect("second page");
+    assert_eq!(summarized.page_info.next_cursor, Some(cursor.clone()));
+
+    let next = services
+        .users
+        .list_users_page_with_summaries(
+            &ctx,
+            &summary_query(1).with_cursor(CursorV1::decode(&cursor).unwrap()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(next.items.len(), 1);
+    assert_ne!(next.items[0].user.id, plain.items[0].id);
+    assert!(next.page_info.next_cursor.is_none());
+}
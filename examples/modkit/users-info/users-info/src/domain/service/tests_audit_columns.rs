@@ -0,0 +1,152 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::time::Duration;
+
+use modkit_db::secure::{DBRunner, SecureEntityExt};
+use modkit_security::AccessScope;
+use users_info_sdk::UserPatch;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::repos::UsersRepository;
+use crate::domain::service::ServiceConfig;
+use crate::infra::storage::OrmUsersRepository;
+use crate::infra::storage::entity::user;
+use crate::test_support::{build_services, ctx_for_subject, inmem_db, new_user};
+
+async fn stored(conn: &impl DBRunner, id: Uuid) -> user::Model {
+    user::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::allow_all())
+        .and_id(id)
+        .unwrap()
+        .one(conn)
+        .await
+        .unwrap()
+        .expect("user row")
+}
+
+#[tokio::test]
+async fn create_stamps_creator_and_time() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let author = Uuid::new_v4();
+
+    let created = services
+        .users
+        .create_user(
+            &ctx_for_subject(author, tenant_id),
+            new_user(tenant_id, "a@example.com"),
+        )
+        .await
+        .unwrap();
+
+    let row = stored(&db.conn().unwrap(), created.id).await;
+    assert_eq!(row.created_by, Some(author));
+    assert_eq!(row.updated_by, Some(author));
+    assert_eq!(row.updated_at, row.created_at);
+    assert_eq!(created.created_at, row.created_at);
+    assert_eq!(created.updated_at, row.updated_at);
+}
+
+#[tokio::test]
+async fn bulk_create_stamps_every_user() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let author = Uuid::new_v4();
+
+    let created = services
+        .users
+        .create_users_bulk(
+            &ctx_for_subject(author, tenant_id),
+            vec![
+                new_user(tenant_id, "b1@example.com"),
+                new_user(tenant_id, "b2@example.com"),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let conn = db.conn().unwrap();
+    for user in created {
+        let row = stored(&conn, user.id).await;
+        assert_eq!(row.created_by, Some(author));
+        assert_eq!(row.updated_by, Some(author));
+    }
+}
+
+#[tokio::test]
+async fn update_refreshes_updated_stamps_and_keeps_created_ones() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let author = Uuid::new_v4();
+    let editor = Uuid::new_v4();
+
+    let created = services
+        .users
+        .create_user(
+            &ctx_for_subject(author, tenant_id),
+            new_user(tenant_id, "c@example.com"),
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let updated = services
+        .users
+        .update_user(
+            &ctx_for_subject(editor, tenant_id),
+            created.id,
+            UserPatch {
+                display_name: Some("Renamed".to_owned()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let row = stored(&db.conn().unwrap(), created.id).await;
+    assert_eq!(row.display_name, "Renamed");
+    assert_eq!(row.created_at, created.created_at);
+    assert_eq!(row.created_by, Some(author));
+    assert_eq!(row.updated_by, Some(editor));
+    assert!(row.updated_at > created.updated_at);
+    assert_eq!(updated.created_at, created.created_at);
+    assert_eq!(updated.updated_at, row.updated_at);
+}
+
+#[tokio::test]
+async fn audited_update_keeps_tenant_immutable() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_for_subject(Uuid::new_v4(), tenant_id);
+
+    let created = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "d@example.com"))
+        .await
+        .unwrap();
+
+    let repo = OrmUsersRepository::new(ServiceConfig::default().limit_cfg());
+    let conn = db.conn().unwrap();
+    let moved = users_info_sdk::User {
+        tenant_id: Uuid::new_v4(),
+        ..created.clone()
+    };
+    let err = repo
+        .update(&conn, &AccessScope::for_tenant(tenant_id), &ctx, moved)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::Database { ref message } if message.contains("tenant_id is immutable")),
+        "{err:?}"
+    );
+
+    let row = stored(&conn, created.id).await;
+    assert_eq!(row.tenant_id, tenant_id);
+    assert_eq!(row.updated_at, created.updated_at);
+}
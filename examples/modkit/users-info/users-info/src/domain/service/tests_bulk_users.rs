@@ -0,0 +1,177 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::ODataQuery;
+use modkit_security::SecurityContext;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::module::ConcreteAppServices;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db, new_user};
+
+async fn emails(services: &ConcreteAppServices, ctx: &SecurityContext) -> Vec<String> {
+    let page = services
+        .users
+        .list_users_page(ctx, &ODataQuery::default())
+        .await
+        .unwrap();
+    let mut emails: Vec<String> = page.items.into_iter().map(|u| u.email).collect();
+    emails.sort();
+    emails
+}
+
+/// Index of the failing entry and its underlying error.
+fn entry_error(err: DomainError) -> (usize, DomainError) {
+    match err {
+        DomainError::BulkEntry { index, source } => (index, *source),
+        other => panic!("expected BulkEntry, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn creates_all_users_in_input_order() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let created = services
+        .users
+        .create_users_bulk(
+            &ctx,
+            vec![
+                new_user(tenant_id, "b@example.com"),
+                new_user(tenant_id, "A@Example.com"),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let created: Vec<&str> = created.iter().map(|u| u.email.as_str()).collect();
+    assert_eq!(created, vec!["b@example.com", "a@example.com"]);
+    assert_eq!(
+        emails(&services, &ctx).await,
+        vec!["a@example.com", "b@example.com"]
+    );
+}
+
+#[tokio::test]
+async fn invalid_entry_creates_nothing() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+    let mut blank = new_user(tenant_id, "blank@example.com");
+    blank.display_name = "   ".to_owned();
+
+    let err = services
+        .users
+        .create_users_bulk(&ctx, vec![new_user(tenant_id, "ok@example.com"), blank])
+        .await
+        .unwrap_err();
+
+    let (index, source) = entry_error(err);
+    assert_eq!(index, 1);
+    assert!(
+        matches!(source, DomainError::EmptyDisplayName),
+        "{source:?}"
+    );
+    assert!(emails(&services, &ctx).await.is_empty());
+}
+
+#[tokio::test]
+async fn duplicate_emails_are_rejected() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "taken@example.com"))
+        .await
+        .unwrap();
+
+    let err = services
+        .users
+        .create_users_bulk(
+            &ctx,
+            vec![
+                new_user(tenant_id, "one@example.com"),
+                new_user(tenant_id, "ONE@example.com"),
+            ],
+        )
+        .await
+        .unwrap_err();
+    let (index, source) = entry_error(err);
+    assert_eq!(index, 1);
+    assert!(matches!(source, DomainError::EmailAlreadyExists { .. }));
+
+    let err = services
+        .users
+        .create_users_bulk(
+            &ctx,
+            vec![
+                new_user(tenant_id, "two@example.com"),
+                new_user(tenant_id, "taken@example.com"),
+            ],
+        )
+        .await
+        .unwrap_err();
+    let (index, source) = entry_error(err);
+    assert_eq!(index, 1);
+    assert!(matches!(source, DomainError::EmailAlreadyExists { .. }));
+
+    assert_eq!(emails(&services, &ctx).await, vec!["taken@example.com"]);
+}
+
+#[tokio::test]
+async fn foreign_tenant_entry_is_forbidden() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let own = Uuid::new_v4();
+    let foreign = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[own]);
+
+    let err = services
+        .users
+        .create_users_bulk(
+            &ctx,
+            vec![
+                new_user(own, "own@example.com"),
+                new_user(foreign, "foreign@example.com"),
+            ],
+        )
+        .await
+        .unwrap_err();
+
+    let (index, source) = entry_error(err);
+    assert_eq!(index, 1);
+    assert!(matches!(source, DomainError::Forbidden), "{source:?}");
+    assert!(emails(&services, &ctx).await.is_empty());
+}
+
+#[tokio::test]
+async fn batch_size_is_bounded() {
+    let config = ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .max_bulk_users(2)
+        .build();
+    let services = build_services(inmem_db().await, config);
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let err = services
+        .users
+        .create_users_bulk(&ctx, Vec::new())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Validation { .. }), "{err:?}");
+
+    let batch = (0..3)
+        .map(|i| new_user(tenant_id, &format!("user{i}@example.com")))
+        .collect();
+    let err = services
+        .users
+        .create_users_bulk(&ctx, batch)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Validation { .. }), "{err:?}");
+}
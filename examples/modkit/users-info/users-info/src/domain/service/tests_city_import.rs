@@ -0,0 +1,197 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::ODataQuery;
+use modkit_security::SecurityContext;
+use uuid::Uuid;
+
+use crate::domain::city_import::{CityImportLimits, CityImportReport, RowRejection};
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::module::ConcreteAppServices;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db};
+
+fn config(limits: CityImportLimits) -> ServiceConfig {
+    ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .city_import(limits)
+        .build()
+}
+
+/// Small chunks so imports span several transactions.
+fn chunked() -> CityImportLimits {
+    CityImportLimits {
+        chunk_size: 2,
+        ..CityImportLimits::default()
+    }
+}
+
+/// Lines 2 and 4 are valid; 3, 5 and 6 are not.
+const MIXED: &str = "name,country\n\
+                     Lisbon,PT\n\
+                     ,PT\n\
+                     Porto,pt\n\
+                     Faro,Portugal\n\
+                     Lisbon,PT\n";
+
+async fn city_names(services: &ConcreteAppServices, ctx: &SecurityContext) -> Vec<String> {
+    let page = services
+        .cities
+        .list_cities_page(ctx, &ODataQuery::default())
+        .await
+        .unwrap();
+    let mut names: Vec<String> = page.items.into_iter().map(|c| c.name).collect();
+    names.sort();
+    names
+}
+
+fn rows(report: &CityImportReport) -> (Vec<usize>, Vec<usize>, Vec<(usize, &str)>) {
+    (
+        report.created.iter().map(|r| r.row).collect(),
+        report.skipped.iter().map(|r| r.row).collect(),
+        report
+            .failed
+            .iter()
+            .map(|r| (r.row, r.reason.code()))
+            .collect(),
+    )
+}
+
+#[tokio::test]
+async fn dry_run_reports_without_writing() {
+    let services = build_services(inmem_db().await, config(chunked()));
+    let ctx = ctx_allow_tenants(&[Uuid::new_v4()]);
+
+    let report = services
+        .cities
+        .import_cities(&ctx, MIXED.as_bytes(), true)
+        .await
+        .unwrap();
+
+    assert!(report.dry_run);
+    assert_eq!(
+        rows(&report),
+        (
+            vec![2, 4],
+            vec![],
+            vec![
+                (3, "empty_name"),
+                (5, "invalid_country"),
+                (6, "duplicate_in_file")
+            ]
+        )
+    );
+    assert!(city_names(&services, &ctx).await.is_empty());
+}
+
+#[tokio::test]
+async fn import_creates_valid_rows_in_callers_tenant() {
+    let services = build_services(inmem_db().await, config(chunked()));
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let report = services
+        .cities
+        .import_cities(&ctx, MIXED.as_bytes(), false)
+        .await
+        .unwrap();
+
+    assert!(!report.dry_run);
+    assert_eq!(rows(&report).0, vec![2, 4]);
+    assert_eq!(report.failed.len(), 3);
+    assert!(report.created.iter().all(|r| r.tenant_id == tenant_id));
+    assert_eq!(report.created[1].country, "PT");
+    assert_eq!(city_names(&services, &ctx).await, vec!["Lisbon", "Porto"]);
+}
+
+#[tokio::test]
+async fn rows_outside_the_callers_scope_are_rejected() {
+    let services = build_services(inmem_db().await, config(chunked()));
+    let own = Uuid::new_v4();
+    let foreign = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[own]);
+    let csv = format!(
+        "name,country,tenant_id\n\
+         Lisbon,PT,{own}\n\
+         Madrid,ES,{foreign}\n\
+         Porto,PT,\n"
+    );
+
+    let report = services
+        .cities
+        .import_cities(&ctx, csv.as_bytes(), false)
+        .await
+        .unwrap();
+
+    assert_eq!(rows(&report).0, vec![2, 4]);
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].row, 3);
+    assert_eq!(
+        report.failed[0].reason,
+        RowRejection::TenantNotInScope(foreign)
+    );
+    assert!(
+        city_names(&services, &ctx_allow_tenants(&[foreign]))
+            .await
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn reupload_skips_existing_names() {
+    let services = build_services(inmem_db().await, config(chunked()));
+    let ctx = ctx_allow_tenants(&[Uuid::new_v4()]);
+
+    let first = services
+        .cities
+        .import_cities(&ctx, MIXED.as_bytes(), false)
+        .await
+        .unwrap();
+    let second = services
+        .cities
+        .import_cities(&ctx, MIXED.as_bytes(), false)
+        .await
+        .unwrap();
+
+    assert_eq!(rows(&first).0, vec![2, 4]);
+    assert!(second.created.is_empty());
+    assert_eq!(rows(&second).1, vec![2, 4]);
+    assert!(
+        second
+            .skipped
+            .iter()
+            .all(|r| r.reason == RowRejection::AlreadyExists)
+    );
+    assert_eq!(rows(&second).2, rows(&first).2);
+    assert_eq!(city_names(&services, &ctx).await, vec!["Lisbon", "Porto"]);
+}
+
+#[tokio::test]
+async fn size_and_row_limits_are_enforced() {
+    let services = build_services(
+        inmem_db().await,
+        config(CityImportLimits {
+            max_bytes: 64,
+            max_rows: 2,
+            chunk_size: 2,
+        }),
+    );
+    let ctx = ctx_allow_tenants(&[Uuid::new_v4()]);
+
+    let err = services
+        .cities
+        .import_cities(&ctx, &[b'a'; 65], false)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        DomainError::PayloadTooLarge { max_bytes: 64 }
+    ));
+
+    let err = services
+        .cities
+        .import_cities(&ctx, b"name,country\na,PT\nb,PT\nc,PT\n", false)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Validation { .. }), "{err:?}");
+}
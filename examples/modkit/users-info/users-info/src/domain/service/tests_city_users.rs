@@ -0,0 +1,237 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use authz_resolver_sdk::constraints::{EqPredicate, Predicate};
+use authz_resolver_sdk::models::{EvaluationRequest, EvaluationResponse};
+use authz_resolver_sdk::{AuthZResolverClient, AuthZResolverError};
+use modkit_odata::{CursorV1, ODataQuery, parse_filter_string};
+use modkit_security::{SecurityContext, pep_properties};
+use users_info_sdk::{City, NewAddress, NewCity};
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::module::ConcreteAppServices;
+use crate::test_support::{
+    MockAuthZResolver, build_services, build_services_with_authz, ctx_allow_tenants,
+    ctx_for_subject, inmem_db, seed_user,
+};
+
+/// [`MockAuthZResolver`] that only lets callers list their own addresses.
+struct OwnAddressesAuthZResolver;
+
+#[async_trait]
+impl AuthZResolverClient for OwnAddressesAuthZResolver {
+    async fn evaluate(
+        &self,
+        request: EvaluationRequest,
+    ) -> Result<EvaluationResponse, AuthZResolverError> {
+        let own_addresses =
+            request.resource.resource_type == "users_info.address" && request.action.name == "list";
+        let subject_id = request.subject.id;
+
+        let mut response = MockAuthZResolver.evaluate(request).await?;
+        if own_addresses {
+            for constraint in &mut response.context.constraints {
+                constraint.predicates.push(Predicate::Eq(EqPredicate::new(
+                    pep_properties::OWNER_ID,
+                    subject_id,
+                )));
+            }
+        }
+        Ok(response)
+    }
+}
+
+fn filtered(raw: &str) -> ODataQuery {
+    ODataQuery::default().with_filter(parse_filter_string(raw).unwrap().into_expr())
+}
+
+async fn create_city(services: &ConcreteAppServices, tenant_id: Uuid, name: &str) -> City {
+    services
+        .cities
+        .create_city(
+            &ctx_allow_tenants(&[tenant_id]),
+            NewCity {
+                id: None,
+                tenant_id,
+                name: name.to_owned(),
+                country: "Portugal".to_owned(),
+            },
+        )
+        .await
+        .unwrap()
+}
+
+async fn create_address(
+    services: &ConcreteAppServices,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    city_id: Uuid,
+    street: &str,
+) {
+    services
+        .addresses
+        .create_address(
+            &ctx_allow_tenants(&[tenant_id]),
+            NewAddress {
+                id: None,
+                tenant_id,
+                user_id,
+                city_id,
+                street: street.to_owned(),
+                postal_code: "1000-001".to_owned(),
+            },
+        )
+        .await
+        .unwrap();
+}
+
+/// Every page of the listing, following `next_cursor`.
+async fn list_all(
+    services: &ConcreteAppServices,
+    ctx: &SecurityContext,
+    city_id: Uuid,
+    query: ODataQuery,
+) -> Vec<Uuid> {
+    let mut query = query;
+    let mut ids = Vec::new();
+    loop {
+        let page = services
+            .users
+            .list_city_users_page(ctx, city_id, &query)
+            .await
+            .unwrap();
+        ids.extend(page.items.iter().map(|u| u.id));
+        match page.page_info.next_cursor {
+            Some(cursor) => query = query.with_cursor(CursorV1::decode(&cursor).unwrap()),
+            None => break,
+        }
+    }
+    ids
+}
+
+#[tokio::test]
+async fn city_is_checked_like_get_city() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_a = Uuid::new_v4();
+    let foreign = create_city(&services, Uuid::new_v4(), "Porto").await;
+    let ctx = ctx_allow_tenants(&[tenant_a]);
+
+    let err = services
+        .users
+        .list_city_users_page(&ctx, foreign.id, &ODataQuery::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Forbidden), "{err:?}");
+    assert!(matches!(
+        services
+            .cities
+            .get_city(&ctx, foreign.id)
+            .await
+            .unwrap_err(),
+        DomainError::Forbidden
+    ));
+
+    let err = services
+        .users
+        .list_city_users_page(&ctx, Uuid::new_v4(), &ODataQuery::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::NotFound { .. }), "{err:?}");
+}
+
+#[tokio::test]
+async fn users_are_matched_only_through_addresses_in_scope() {
+    let db = inmem_db().await;
+    let conn = db.conn().unwrap();
+    let services = build_services_with_authz(
+        db.clone(),
+        ServiceConfig::default(),
+        Arc::new(OwnAddressesAuthZResolver),
+    );
+
+    let tenant_id = Uuid::new_v4();
+    let caller = Uuid::new_v4();
+    let neighbour = Uuid::new_v4();
+    seed_user(&conn, caller, tenant_id, "me@example.com", "Me").await;
+    seed_user(&conn, neighbour, tenant_id, "them@example.com", "Them").await;
+
+    let lisbon = create_city(&services, tenant_id, "Lisbon").await;
+    create_address(&services, tenant_id, caller, lisbon.id, "Rua Augusta 1").await;
+    create_address(&services, tenant_id, neighbour, lisbon.id, "Rua do Ouro 2").await;
+
+    // Both users are in the caller's user scope; only one address is.
+    let ctx = ctx_for_subject(caller, tenant_id);
+    let page = services
+        .users
+        .list_users_page(&ctx, &ODataQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 2);
+
+    let listed = list_all(&services, &ctx, lisbon.id, ODataQuery::default()).await;
+    assert_eq!(listed, vec![caller]);
+
+    // Address filters see the same addresses.
+    let listed = list_all(
+        &services,
+        &ctx,
+        lisbon.id,
+        filtered("street eq 'Rua do Ouro 2'"),
+    )
+    .await;
+    assert!(listed.is_empty(), "{listed:?}");
+}
+
+#[tokio::test]
+async fn cursors_page_through_city_users_once() {
+    let db = inmem_db().await;
+    let conn = db.conn().unwrap();
+    let services = build_services(db.clone(), ServiceConfig::default());
+
+    let tenant_id = Uuid::new_v4();
+    let lisbon = create_city(&services, tenant_id, "Lisbon").await;
+    let porto = create_city(&services, tenant_id, "Porto").await;
+
+    let mut in_lisbon = Vec::new();
+    for i in 0..5 {
+        let id = Uuid::new_v4();
+        seed_user(&conn, id, tenant_id, &format!("l{i}@example.com"), "L").await;
+        let street = if i % 2 == 0 { "Main St 1" } else { "Side St 2" };
+        create_address(&services, tenant_id, id, lisbon.id, street).await;
+        in_lisbon.push(id);
+    }
+    let elsewhere = Uuid::new_v4();
+    seed_user(&conn, elsewhere, tenant_id, "p@example.com", "P").await;
+    create_address(&services, tenant_id, elsewhere, porto.id, "Main St 1").await;
+    seed_user(&conn, Uuid::new_v4(), tenant_id, "n@example.com", "N").await;
+
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let listed = list_all(
+        &services,
+        &ctx,
+        lisbon.id,
+        ODataQuery::default().with_limit(2),
+    )
+    .await;
+    let mut expected = in_lisbon.clone();
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(listed, expected, "ordered by id across pages");
+
+    // User and address fields combine, and the filter holds across pages.
+    let listed = list_all(
+        &services,
+        &ctx,
+        lisbon.id,
+        filtered("startswith(street, 'Main') or email eq 'l1@example.com'").with_limit(1),
+    )
+    .await;
+    let expected: HashSet<Uuid> = [in_lisbon[0], in_lisbon[1], in_lisbon[2], in_lisbon[4]].into();
+    assert_eq!(listed.len(), expected.len(), "{listed:?}");
+    assert_eq!(listed.into_iter().collect::<HashSet<_>>(), expected);
+}
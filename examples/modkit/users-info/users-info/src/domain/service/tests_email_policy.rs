@@ -0,0 +1,219 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::{ODataQuery, ast};
+use users_info_sdk::UserPatch;
+use uuid::Uuid;
+
+use crate::domain::email::{EmailPolicy, EmailRule};
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db, new_user};
+
+fn config_with_policy(email_policy: EmailPolicy) -> ServiceConfig {
+    ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .email_policy(email_policy)
+        .build()
+}
+
+#[tokio::test]
+async fn stores_normalized_email_and_original() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let user = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, " John.Doe@Example.COM"))
+        .await
+        .unwrap();
+
+    assert_eq!(user.email, "john.doe@example.com");
+    assert_eq!(
+        user.email_original.as_deref(),
+        Some(" John.Doe@Example.COM")
+    );
+
+    let fetched = services.users.get_user(&ctx, user.id).await.unwrap();
+    assert_eq!(fetched.email, "john.doe@example.com");
+    assert_eq!(fetched.email_original, user.email_original);
+}
+
+#[tokio::test]
+async fn case_collision_is_rejected_as_duplicate() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "jane@example.com"))
+        .await
+        .unwrap();
+
+    let err = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "Jane@EXAMPLE.com"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::EmailAlreadyExists { ref email } if email == "jane@example.com"),
+        "Expected EmailAlreadyExists, got: {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn update_to_case_variant_of_other_user_is_rejected() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "taken@example.com"))
+        .await
+        .unwrap();
+    let other = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "other@example.com"))
+        .await
+        .unwrap();
+
+    let err = services
+        .users
+        .update_user(
+            &ctx,
+            other.id,
+            UserPatch {
+                email: Some("TAKEN@example.com".to_owned()),
+                display_name: None,
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::EmailAlreadyExists { .. }));
+}
+
+#[tokio::test]
+async fn blocked_domain_is_rejected() {
+    let policy = EmailPolicy {
+        blocked_domains: vec!["mailinator.com".to_owned()],
+        ..EmailPolicy::default()
+    };
+    let services = build_services(inmem_db().await, config_with_policy(policy));
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let err = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "bob@Mailinator.com"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            DomainError::EmailPolicyViolation {
+                rule: EmailRule::BlockedDomain,
+                ..
+            }
+        ),
+        "Expected blocked_domains violation, got: {err:?}"
+    );
+}
+
+#[tokio::test]
+async fn plus_stripping_follows_config() {
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    // Disabled (default): tagged addresses are distinct users
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "amy@example.com"))
+        .await
+        .unwrap();
+    let tagged = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "amy+news@example.com"))
+        .await
+        .unwrap();
+    assert_eq!(tagged.email, "amy+news@example.com");
+
+    // Enabled: the tag is dropped and the address collides
+    let policy = EmailPolicy {
+        strip_plus_addressing: true,
+        ..EmailPolicy::default()
+    };
+    let services = build_services(inmem_db().await, config_with_policy(policy));
+    services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "amy@example.com"))
+        .await
+        .unwrap();
+    let err = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "amy+news@example.com"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::EmailAlreadyExists { .. }));
+}
+
+#[tokio::test]
+async fn email_filter_matches_normalized_value() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let user = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "Carol@Example.com"))
+        .await
+        .unwrap();
+
+    let query = ODataQuery::default().with_filter(ast::Expr::Compare(
+        Box::new(ast::Expr::Identifier("email".to_owned())),
+        ast::CompareOperator::Eq,
+        Box::new(ast::Expr::Value(ast::Value::String(
+            "CAROL@example.COM".to_owned(),
+        ))),
+    ));
+    let page = services.users.list_users_page(&ctx, &query).await.unwrap();
+
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].id, user.id);
+}
+
+#[tokio::test]
+async fn unique_index_race_maps_to_email_already_exists() {
+    use crate::domain::repos::UsersRepository;
+    use crate::infra::storage::OrmUsersRepository;
+    use modkit_security::AccessScope;
+
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+    let existing = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, "race@example.com"))
+        .await
+        .unwrap();
+
+    // A concurrent writer that passed the service's uniqueness check
+    let repo = OrmUsersRepository::new(ServiceConfig::default().limit_cfg());
+    let conn = db.conn().unwrap();
+    let duplicate = users_info_sdk::User {
+        id: Uuid::new_v4(),
+        ..existing
+    };
+    let err = repo
+        .create(&conn, &AccessScope::for_tenant(tenant_id), &ctx, duplicate)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::EmailAlreadyExists { ref email } if email == "race@example.com"),
+        "Expected EmailAlreadyExists, got: {err:?}"
+    );
+}
@@ -9,18 +9,20 @@ use uuid::Uuid;
 
 use users_info_sdk::{CityPatch, NewAddress, NewCity};
 
-use crate::domain::service::ServiceConfig;
+use crate::domain::service::{ServiceConfig, resources};
 use crate::infra::storage::entity::city::ActiveModel as CityAM;
 use crate::infra::storage::entity::city::Entity as CityEntity;
 use crate::test_support::{build_services, ctx_allow_tenants, inmem_db, seed_user};
-use modkit_db::secure::{AccessScope, secure_insert};
+use modkit_db::fake::{FakeRunner, StatementKind, unique_violation};
+use modkit_db::secure::secure_insert;
+use modkit_security::{AccessScopeBuilder, pep_properties};
 
 #[tokio::test]
 async fn create_city_success() {
-    let db = inmem_db().await;
+    let fake = FakeRunner::new();
     let tenant_id = Uuid::new_v4();
 
-    let services = build_services(db.clone(), ServiceConfig::default());
+    let services = build_services(fake.db(), ServiceConfig::default());
     let ctx = ctx_allow_tenants(&[tenant_id]);
 
     let new_city = NewCity {
@@ -34,6 +36,42 @@ async fn create_city_success() {
     assert_eq!(city.name, "San Francisco");
     assert_eq!(city.country, "USA");
     assert_eq!(city.tenant_id, tenant_id);
+
+    let inserts: Vec<_> = fake
+        .statements()
+        .into_iter()
+        .filter(|s| s.kind == StatementKind::Insert)
+        .collect();
+    assert_eq!(inserts.len(), 1);
+    assert_eq!(inserts[0].table.as_deref(), Some("cities"));
+}
+
+#[tokio::test]
+async fn create_city_surfaces_database_failure() {
+    let fake = FakeRunner::new();
+    fake.on_insert::<CityEntity>().fail_with(unique_violation());
+    let tenant_id = Uuid::new_v4();
+
+    let services = build_services(fake.db(), ServiceConfig::default());
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let new_city = NewCity {
+        id: None,
+        tenant_id,
+        name: "Duplicate".to_string(),
+        country: "USA".to_string(),
+    };
+
+    let err = services
+        .cities
+        .create_city(&ctx, new_city)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, crate::domain::error::DomainError::Database { .. }),
+        "Expected DomainError::Database, got: {err:?}"
+    );
+    assert_eq!(fake.calls::<CityEntity>(StatementKind::Insert), 1);
 }
 
 #[tokio::test]
@@ -52,7 +90,10 @@ async fn get_city_respects_tenant_scope() {
         created_at: Set(now),
         updated_at: Set(now),
     };
-    let scope = AccessScope::for_tenants(vec![tenant1]);
+    let scope = AccessScopeBuilder::for_resource(&resources::CITY)
+        .filter(pep_properties::OWNER_TENANT_ID, [tenant1])
+        .build()
+        .unwrap();
     let conn = db.conn().unwrap();
     let _ = secure_insert::<CityEntity>(city_am, &scope, &conn)
         .await
@@ -89,7 +130,10 @@ async fn update_city_success() {
         created_at: Set(now),
         updated_at: Set(now),
     };
-    let scope = AccessScope::for_tenants(vec![tenant_id]);
+    let scope = AccessScopeBuilder::for_resource(&resources::CITY)
+        .filter(pep_properties::OWNER_TENANT_ID, [tenant_id])
+        .build()
+        .unwrap();
     let conn = db.conn().unwrap();
     let _ = secure_insert::<CityEntity>(city_am, &scope, &conn)
         .await
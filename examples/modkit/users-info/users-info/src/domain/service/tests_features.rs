@@ -0,0 +1,128 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::Arc;
+
+use modkit::FeatureGate;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::{ServiceConfig, features};
+use crate::test_support::{
+    MockAuthZResolver, build_services, build_services_with_features, ctx_allow_tenants, inmem_db,
+    seed_user,
+};
+use users_info_sdk::{NewAddress, NewCity};
+
+// ---------------------------------------------------------------------------
+// Module feature flags (`modules.users-info.features.addresses`)
+// ---------------------------------------------------------------------------
+
+fn addresses_disabled() -> FeatureGate {
+    FeatureGate::new("users-info", [(features::ADDRESSES.to_owned(), false)])
+}
+
+/// With the feature disabled, programmatic access to the addresses service
+/// (e.g. via `ClientHub`) is rejected with `DomainError::FeatureDisabled`.
+#[tokio::test]
+async fn addresses_disabled_rejects_service_calls() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_id, tenant_id, "feat@example.com", "Feat").await;
+
+    let services = build_services_with_features(
+        db.clone(),
+        ServiceConfig::default(),
+        Arc::new(MockAuthZResolver),
+        addresses_disabled(),
+    );
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let err = services
+        .addresses
+        .create_address(
+            &ctx,
+            NewAddress {
+                id: None,
+                tenant_id,
+                user_id,
+                city_id: Uuid::new_v4(),
+                street: "Gated St".to_owned(),
+                postal_code: "00000".to_owned(),
+            },
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(&err, DomainError::FeatureDisabled { feature } if feature == features::ADDRESSES),
+        "Expected DomainError::FeatureDisabled for create_address, got: {err:?}"
+    );
+
+    let err = services
+        .addresses
+        .get_user_address(&ctx, user_id)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::FeatureDisabled { .. }),
+        "Expected DomainError::FeatureDisabled for get_user_address, got: {err:?}"
+    );
+}
+
+/// The aggregated user view keeps working and simply omits the address.
+#[tokio::test]
+async fn addresses_disabled_get_user_full_omits_address() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_id, tenant_id, "full@example.com", "Full").await;
+
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    // Create an address while the feature is enabled
+    let enabled = build_services(db.clone(), ServiceConfig::default());
+    let city = enabled
+        .cities
+        .create_city(
+            &ctx,
+            NewCity {
+                id: None,
+                tenant_id,
+                name: "Feature City".to_owned(),
+                country: "FC".to_owned(),
+            },
+        )
+        .await
+        .unwrap();
+    enabled
+        .addresses
+        .create_address(
+            &ctx,
+            NewAddress {
+                id: None,
+                tenant_id,
+                user_id,
+                city_id: city.id,
+                street: "Feature St".to_owned(),
+                postal_code: "11111".to_owned(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let full = enabled.users.get_user_full(&ctx, user_id).await.unwrap();
+    assert!(full.address.is_some());
+
+    let disabled = build_services_with_features(
+        db.clone(),
+        ServiceConfig::default(),
+        Arc::new(MockAuthZResolver),
+        addresses_disabled(),
+    );
+    let full = disabled.users.get_user_full(&ctx, user_id).await.unwrap();
+    assert_eq!(full.user.id, user_id);
+    assert!(full.address.is_none());
+    assert!(full.city.is_none());
+}
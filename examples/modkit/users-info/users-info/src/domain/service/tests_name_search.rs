@@ -0,0 +1,85 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::{ODataQuery, parse_filter_string};
+use users_info_sdk::NewUser;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db};
+
+fn query(raw: &str) -> ODataQuery {
+    ODataQuery::default().with_filter(parse_filter_string(raw).unwrap().into_expr())
+}
+
+#[tokio::test]
+async fn display_name_search_is_case_insensitive_with_tolower() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    for (email, display_name) in [
+        ("john@example.com", "John Smith"),
+        ("anna@example.com", "Anna SMITHSON"),
+        ("bob@example.com", "Bob Jones"),
+    ] {
+        services
+            .users
+            .create_user(
+                &ctx,
+                NewUser {
+                    id: None,
+                    tenant_id,
+                    email: email.to_owned(),
+                    display_name: display_name.to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let names = |page: modkit_odata::Page<users_info_sdk::User>| {
+        let mut names: Vec<String> = page.items.into_iter().map(|u| u.display_name).collect();
+        names.sort();
+        names
+    };
+
+    let page = services
+        .users
+        .list_users_page(&ctx, &query("contains(tolower(display_name),'smith')"))
+        .await
+        .unwrap();
+    assert_eq!(names(page), vec!["Anna SMITHSON", "John Smith"]);
+
+    let page = services
+        .users
+        .list_users_page(&ctx, &query("toupper(display_name) eq 'BOB JONES'"))
+        .await
+        .unwrap();
+    assert_eq!(names(page), vec!["Bob Jones"]);
+
+    let page = services
+        .users
+        .list_users_page(&ctx, &query("startswith(tolower(display_name),'anna s')"))
+        .await
+        .unwrap();
+    assert_eq!(names(page), vec!["Anna SMITHSON"]);
+}
+
+#[tokio::test]
+async fn case_fold_on_non_string_field_is_a_validation_error() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let err = services
+        .users
+        .list_users_page(&ctx, &query("tolower(created_at) eq 'x'"))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, DomainError::Validation { ref field, .. } if field == "$filter"),
+        "{err:?}"
+    );
+}
@@ -6,6 +6,8 @@ use std::sync::Arc;
 
 use crate::domain::error::DomainError;
 use crate::domain::service::ServiceConfig;
+use modkit_db::fake::FakeRunner;
+
 use crate::test_support::{
     FailingAuthZResolver, build_services, build_services_with_authz, ctx_allow_tenants, inmem_db,
     seed_user,
@@ -20,11 +22,11 @@ use users_info_sdk::{NewAddress, NewCity};
 /// must propagate it as `DomainError::InternalError`, not `Forbidden`.
 #[tokio::test]
 async fn pdp_internal_error_returns_internal_for_list_users() {
-    let db = inmem_db().await;
+    let fake = FakeRunner::new();
     let tenant_id = Uuid::new_v4();
 
     let services = build_services_with_authz(
-        db.clone(),
+        fake.db(),
         ServiceConfig::default(),
         Arc::new(FailingAuthZResolver),
     );
@@ -40,6 +42,10 @@ async fn pdp_internal_error_returns_internal_for_list_users() {
         matches!(err, DomainError::InternalError),
         "Expected DomainError::InternalError from PDP failure, got: {err:?}"
     );
+    assert!(
+        fake.statements().is_empty(),
+        "No query may run when the PDP fails"
+    );
 }
 
 /// PDP internal error on `create_address` → `DomainError::InternalError`.
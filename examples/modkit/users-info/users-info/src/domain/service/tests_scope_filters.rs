@@ -0,0 +1,137 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_db::secure::{SecureEntityExt, scope_is_top_level};
+use modkit_odata::filter::{FilterNode, FilterOp, ODataValue};
+use modkit_odata::{ODataQuery, parse_filter_string};
+use modkit_security::AccessScope;
+use sea_orm::{Condition, EntityTrait, QueryFilter, sea_query::Expr};
+use users_info_sdk::NewUser;
+use users_info_sdk::odata::UserFilterField;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::infra::storage::entity::user::{Column, Entity as UserEntity};
+use crate::infra::storage::odata_mapper::filter_to_condition;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db};
+
+// ---------------------------------------------------------------------------
+// `$filter` must not reference scope columns, and the scope must stay AND-ed
+// ---------------------------------------------------------------------------
+
+fn query(raw: &str) -> ODataQuery {
+    ODataQuery::default().with_filter(parse_filter_string(raw).unwrap().into_expr())
+}
+
+fn assert_scope_filter_rejected(err: &DomainError, dimension: &str) {
+    match err {
+        DomainError::Validation { field, message } => {
+            assert_eq!(field, "$filter");
+            assert!(message.contains(dimension), "{message}");
+        }
+        other => panic!("Expected $filter validation error, got: {other:?}"),
+    }
+}
+
+/// `id` is the users resource column.
+#[tokio::test]
+async fn filter_on_user_resource_column_is_rejected() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let ctx = ctx_allow_tenants(&[Uuid::new_v4()]);
+
+    let err = services
+        .users
+        .list_users_page(&ctx, &query(&format!("id ne {}", Uuid::new_v4())))
+        .await
+        .unwrap_err();
+
+    assert_scope_filter_rejected(&err, "resource");
+}
+
+/// `user_id` is the addresses owner column.
+#[tokio::test]
+async fn filter_on_address_owner_column_is_rejected() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let ctx = ctx_allow_tenants(&[Uuid::new_v4()]);
+
+    let err = services
+        .addresses
+        .list_addresses_page(
+            &ctx,
+            &query(&format!(
+                "street eq 'Main' or user_id ne {}",
+                Uuid::new_v4()
+            )),
+        )
+        .await
+        .unwrap_err();
+
+    assert_scope_filter_rejected(&err, "owner");
+}
+
+/// An explicit opt-in runs the filter, still inside the caller's scope.
+#[tokio::test]
+async fn opted_in_scope_filter_stays_within_scope() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+
+    let mut ids = Vec::new();
+    for (tenant_id, email) in [(tenant_a, "a@example.com"), (tenant_b, "b@example.com")] {
+        let user = services
+            .users
+            .create_user(
+                &ctx_allow_tenants(&[tenant_id]),
+                NewUser {
+                    id: None,
+                    tenant_id,
+                    email: email.to_owned(),
+                    display_name: "User".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+        ids.push(user.id);
+    }
+
+    // Matches every user except a random id, i.e. both tenants without a scope.
+    let q = query(&format!("id ne {}", Uuid::new_v4())).with_scope_filters_allowed();
+    let page = services
+        .users
+        .list_users_page(&ctx_allow_tenants(&[tenant_a]), &q)
+        .await
+        .unwrap();
+
+    let found: Vec<Uuid> = page.items.iter().map(|u| u.id).collect();
+    assert_eq!(found, vec![ids[0]]);
+}
+
+/// Regression: a repo that combines the OData condition with its own tenant
+/// check via `Condition::any()` lets the filter widen access.
+#[test]
+fn or_combined_scope_is_detected() {
+    let tenant_id = Uuid::new_v4();
+    let scope = AccessScope::for_tenant(tenant_id);
+    let odata = filter_to_condition(&FilterNode::binary(
+        UserFilterField::Email,
+        FilterOp::Eq,
+        ODataValue::String("a@example.com".to_owned()),
+    ))
+    .unwrap();
+
+    // The secure way: the scope is AND-ed, the filter only narrows.
+    let scoped = UserEntity::find()
+        .secure()
+        .scope_with(&scope)
+        .into_inner()
+        .filter(odata.clone());
+    assert!(scope_is_top_level(&scoped, &scope));
+
+    // The misuse: tenant check and filter are OR-ed.
+    let widened = UserEntity::find().filter(
+        Condition::any()
+            .add(Expr::col(Column::TenantId).is_in([tenant_id]))
+            .add(odata),
+    );
+    assert!(!scope_is_top_level(&widened, &scope));
+}
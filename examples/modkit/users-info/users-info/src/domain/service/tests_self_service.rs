@@ -0,0 +1,283 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use authz_resolver_sdk::{
+    AuthZResolverClient, AuthZResolverError,
+    constraints::{Constraint, InPredicate, Predicate},
+    models::{EvaluationRequest, EvaluationResponse, EvaluationResponseContext},
+};
+use modkit_db::secure::secure_insert;
+use modkit_security::{AccessScopeBuilder, pep_properties};
+use sea_orm::Set;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::profile::SelfServicePolicy;
+use crate::domain::service::{ServiceConfig, resources};
+use crate::test_support::{build_services_with_authz, ctx_for_subject, inmem_db, seed_user};
+use users_info_sdk::UserPatch;
+
+// ---------------------------------------------------------------------------
+// Self-service profile tests (`get_me` / `update_me`)
+// ---------------------------------------------------------------------------
+
+/// PDP that only lets a subject act on the user it is linked to.
+///
+/// Decisions are made on `resource.id`; constrained requests get
+/// `in(id, [linked user])`.
+struct SelfOnlyAuthZResolver {
+    links: HashMap<Uuid, Uuid>,
+}
+
+impl SelfOnlyAuthZResolver {
+    fn new(links: impl IntoIterator<Item = (Uuid, Uuid)>) -> Arc<Self> {
+        Arc::new(Self {
+            links: links.into_iter().collect(),
+        })
+    }
+}
+
+#[async_trait]
+impl AuthZResolverClient for SelfOnlyAuthZResolver {
+    async fn evaluate(
+        &self,
+        request: EvaluationRequest,
+    ) -> Result<EvaluationResponse, AuthZResolverError> {
+        let Some(&user_id) = self.links.get(&request.subject.id) else {
+            return Ok(EvaluationResponse {
+                decision: false,
+                context: EvaluationResponseContext::default(),
+            });
+        };
+        let decision = request.resource.id.is_none_or(|id| id == user_id);
+        let constraints = if decision && request.context.require_constraints {
+            vec![Constraint {
+                predicates: vec![Predicate::In(InPredicate::new(
+                    pep_properties::RESOURCE_ID,
+                    [user_id],
+                ))],
+            }]
+        } else {
+            vec![]
+        };
+        Ok(EvaluationResponse {
+            decision,
+            context: EvaluationResponseContext {
+                constraints,
+                ..Default::default()
+            },
+        })
+    }
+}
+
+fn config(policy: SelfServicePolicy) -> ServiceConfig {
+    ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .self_service(policy)
+        .build()
+}
+
+fn rename(display_name: &str) -> UserPatch {
+    UserPatch {
+        display_name: Some(display_name.to_owned()),
+        ..Default::default()
+    }
+}
+
+/// Insert a user linked to `subject_id` through `external_subject_id`.
+async fn seed_linked_user(
+    db: &modkit_db::Db,
+    id: Uuid,
+    tenant_id: Uuid,
+    subject_id: Uuid,
+    email: &str,
+) {
+    use crate::infra::storage::entity::user::{ActiveModel, Entity as UserEntity};
+
+    let now = OffsetDateTime::now_utc();
+    let user = ActiveModel {
+        id: Set(id),
+        tenant_id: Set(tenant_id),
+        email: Set(email.to_owned()),
+        email_original: Set(None),
+        display_name: Set("Linked".to_owned()),
+        external_subject_id: Set(Some(subject_id)),
+        created_at: Set(now),
+        updated_at: Set(now),
+        created_by: Set(None),
+        updated_by: Set(None),
+    };
+    let scope = AccessScopeBuilder::for_resource(&resources::USER)
+        .filter(pep_properties::OWNER_TENANT_ID, [tenant_id])
+        .build()
+        .expect("valid user scope");
+    let conn = db.conn().unwrap();
+    secure_insert::<UserEntity>(user, &scope, &conn)
+        .await
+        .expect("Failed to seed linked user");
+}
+
+/// A token whose subject is user A reads and renames A; user B is untouched.
+#[tokio::test]
+async fn subject_reads_and_updates_own_profile() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let user_a = Uuid::new_v4();
+    let user_b = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_a, tenant_id, "a@example.com", "User A").await;
+    seed_user(&conn, user_b, tenant_id, "b@example.com", "User B").await;
+
+    let services = build_services_with_authz(
+        db.clone(),
+        config(SelfServicePolicy::default()),
+        SelfOnlyAuthZResolver::new([(user_a, user_a), (user_b, user_b)]),
+    );
+    let ctx_a = ctx_for_subject(user_a, tenant_id);
+
+    let me = services.users.get_me(&ctx_a).await.unwrap();
+    assert_eq!(me.id, user_a);
+
+    let updated = services
+        .users
+        .update_me(&ctx_a, rename("Renamed A"))
+        .await
+        .unwrap();
+    assert_eq!(updated.id, user_a);
+    assert_eq!(updated.display_name, "Renamed A");
+
+    let ctx_b = ctx_for_subject(user_b, tenant_id);
+    let b = services.users.get_me(&ctx_b).await.unwrap();
+    assert_eq!(b.display_name, "User B", "B must be unchanged");
+}
+
+/// The same token cannot reach user B through the regular endpoints.
+#[tokio::test]
+async fn subject_cannot_touch_another_user() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let user_a = Uuid::new_v4();
+    let user_b = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_a, tenant_id, "a@example.com", "User A").await;
+    seed_user(&conn, user_b, tenant_id, "b@example.com", "User B").await;
+
+    let services = build_services_with_authz(
+        db.clone(),
+        config(SelfServicePolicy::default()),
+        SelfOnlyAuthZResolver::new([(user_a, user_a)]),
+    );
+    let ctx_a = ctx_for_subject(user_a, tenant_id);
+
+    let err = services.users.get_user(&ctx_a, user_b).await.unwrap_err();
+    assert!(
+        matches!(err, DomainError::Forbidden),
+        "Expected Forbidden reading B, got: {err:?}"
+    );
+
+    let err = services
+        .users
+        .update_user(&ctx_a, user_b, rename("Hacked"))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::Forbidden),
+        "Expected Forbidden updating B, got: {err:?}"
+    );
+}
+
+/// Fields outside the whitelist are rejected before anything is written.
+#[tokio::test]
+async fn patching_email_on_own_profile_is_rejected() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let user_a = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_a, tenant_id, "a@example.com", "User A").await;
+
+    let services = build_services_with_authz(
+        db.clone(),
+        config(SelfServicePolicy::default()),
+        SelfOnlyAuthZResolver::new([(user_a, user_a)]),
+    );
+    let ctx_a = ctx_for_subject(user_a, tenant_id);
+
+    let err = services
+        .users
+        .update_me(
+            &ctx_a,
+            UserPatch {
+                email: Some("new@example.com".to_owned()),
+                display_name: Some("Renamed".to_owned()),
+            },
+        )
+        .await
+        .unwrap_err();
+    match err {
+        DomainError::FieldsNotEditable { fields } => assert_eq!(fields, ["email"]),
+        other => panic!("Expected FieldsNotEditable, got: {other:?}"),
+    }
+
+    let me = services.users.get_me(&ctx_a).await.unwrap();
+    assert_eq!(me.email, "a@example.com");
+    assert_eq!(me.display_name, "User A");
+}
+
+/// A subject that is not a user id resolves through `external_subject_id`.
+#[tokio::test]
+async fn external_subject_mapping_resolves_profile() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let subject = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    seed_linked_user(&db, user_id, tenant_id, subject, "linked@example.com").await;
+
+    let services = build_services_with_authz(
+        db.clone(),
+        config(SelfServicePolicy::default()),
+        SelfOnlyAuthZResolver::new([(subject, user_id)]),
+    );
+    let ctx = ctx_for_subject(subject, tenant_id);
+
+    let me = services.users.get_me(&ctx).await.unwrap();
+    assert_eq!(me.id, user_id);
+
+    let updated = services
+        .users
+        .update_me(&ctx, rename("Renamed"))
+        .await
+        .unwrap();
+    assert_eq!(updated.id, user_id);
+    assert_eq!(updated.display_name, "Renamed");
+}
+
+/// With `subject_is_user_id` off, a matching user id alone is not a profile.
+#[tokio::test]
+async fn unmapped_subject_has_no_profile() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let user_a = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_a, tenant_id, "a@example.com", "User A").await;
+
+    let services = build_services_with_authz(
+        db.clone(),
+        config(SelfServicePolicy {
+            subject_is_user_id: false,
+            ..Default::default()
+        }),
+        SelfOnlyAuthZResolver::new([(user_a, user_a)]),
+    );
+    let ctx_a = ctx_for_subject(user_a, tenant_id);
+
+    let err = services.users.get_me(&ctx_a).await.unwrap_err();
+    assert!(
+        matches!(err, DomainError::NotFound { .. }),
+        "Expected NotFound, got: {err:?}"
+    );
+}
@@ -0,0 +1,202 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::Arc;
+
+use modkit_odata::ODataQuery;
+use modkit_security::SecurityContext;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::tenant_data::{
+    ANONYMIZED_DISPLAY_NAME, ANONYMIZED_STREET, anonymized_email,
+};
+use crate::domain::service::{PurgeMode, ServiceConfig};
+use crate::module::ConcreteAppServices;
+use crate::test_support::{build_services, ctx_allow_tenants, ctx_deny_all, inmem_db, seed_user};
+use users_info_sdk::{NewAddress, NewCity};
+
+/// Small chunk size so purges span several chunks.
+fn chunked_config() -> ServiceConfig {
+    ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .purge_chunk_size(1)
+        .build()
+}
+
+/// Seed two users, one city and one address per user for `tenant_id`.
+async fn seed_tenant(db: &modkit_db::Db, services: &ConcreteAppServices, tenant_id: Uuid) {
+    let conn = db.conn().unwrap();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let city = services
+        .cities
+        .create_city(
+            &ctx,
+            NewCity {
+                id: None,
+                tenant_id,
+                name: "Purgeville".to_owned(),
+                country: "PV".to_owned(),
+            },
+        )
+        .await
+        .unwrap();
+
+    for n in 0..2 {
+        let user_id = Uuid::new_v4();
+        seed_user(
+            &conn,
+            user_id,
+            tenant_id,
+            &format!("user{n}-{tenant_id}@example.com"),
+            &format!("User {n}"),
+        )
+        .await;
+        services
+            .addresses
+            .create_address(
+                &ctx,
+                NewAddress {
+                    id: None,
+                    tenant_id,
+                    user_id,
+                    city_id: city.id,
+                    street: format!("{n} Private Lane"),
+                    postal_code: "12345".to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+}
+
+async fn counts(services: &ConcreteAppServices, ctx: &SecurityContext) -> (usize, usize, usize) {
+    let q = ODataQuery::default();
+    let users = services.users.list_users_page(ctx, &q).await.unwrap();
+    let addresses = services
+        .addresses
+        .list_addresses_page(ctx, &q)
+        .await
+        .unwrap();
+    let cities = services.cities.list_cities_page(ctx, &q).await.unwrap();
+    (users.items.len(), addresses.items.len(), cities.items.len())
+}
+
+#[tokio::test]
+async fn erase_removes_only_target_tenant_rows() {
+    let db = inmem_db().await;
+    let services: Arc<ConcreteAppServices> = build_services(db.clone(), chunked_config());
+    let target = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    seed_tenant(&db, &services, target).await;
+    seed_tenant(&db, &services, other).await;
+
+    let ctx = ctx_allow_tenants(&[target]);
+    let report = services
+        .tenant_data
+        .purge_tenant(&ctx, target, PurgeMode::Erase)
+        .await
+        .unwrap();
+
+    assert_eq!(report.tenant_id, target);
+    assert_eq!(report.mode, PurgeMode::Erase);
+    assert_eq!((report.users, report.addresses, report.cities), (2, 2, 1));
+
+    assert_eq!(counts(&services, &ctx).await, (0, 0, 0));
+    assert_eq!(
+        counts(&services, &ctx_allow_tenants(&[other])).await,
+        (2, 2, 1)
+    );
+}
+
+#[tokio::test]
+async fn anonymize_preserves_rows_and_scrubs_pii() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), chunked_config());
+    let target = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    seed_tenant(&db, &services, target).await;
+    seed_tenant(&db, &services, other).await;
+
+    let ctx = ctx_allow_tenants(&[target]);
+    let report = services
+        .tenant_data
+        .purge_tenant(&ctx, target, PurgeMode::Anonymize)
+        .await
+        .unwrap();
+
+    assert_eq!((report.users, report.addresses, report.cities), (2, 2, 0));
+    assert_eq!(counts(&services, &ctx).await, (2, 2, 1));
+
+    let q = ODataQuery::default();
+    let users = services.users.list_users_page(&ctx, &q).await.unwrap();
+    for user in &users.items {
+        assert_eq!(user.email, anonymized_email(user.id));
+        assert_eq!(user.display_name, ANONYMIZED_DISPLAY_NAME);
+    }
+    let addresses = services
+        .addresses
+        .list_addresses_page(&ctx, &q)
+        .await
+        .unwrap();
+    for address in &addresses.items {
+        assert_eq!(address.street, ANONYMIZED_STREET);
+    }
+
+    // Other tenant keeps its PII.
+    let other_ctx = ctx_allow_tenants(&[other]);
+    let other_users = services
+        .users
+        .list_users_page(&other_ctx, &q)
+        .await
+        .unwrap();
+    assert!(
+        other_users
+            .items
+            .iter()
+            .all(|u| u.display_name != ANONYMIZED_DISPLAY_NAME)
+    );
+}
+
+#[tokio::test]
+async fn purge_forbidden_when_scope_lacks_tenant() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let own = Uuid::new_v4();
+    let target = Uuid::new_v4();
+    seed_tenant(&db, &services, target).await;
+
+    let ctx = ctx_allow_tenants(&[own]);
+    let err = services
+        .tenant_data
+        .purge_tenant(&ctx, target, PurgeMode::Erase)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::Forbidden),
+        "Expected DomainError::Forbidden, got: {err:?}"
+    );
+
+    assert_eq!(
+        counts(&services, &ctx_allow_tenants(&[target])).await,
+        (2, 2, 1)
+    );
+}
+
+#[tokio::test]
+async fn purge_forbidden_for_unconstrained_context() {
+    let db = inmem_db().await;
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let target = Uuid::new_v4();
+
+    let err = services
+        .tenant_data
+        .purge_tenant(&ctx_deny_all(), target, PurgeMode::Erase)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::Forbidden),
+        "Expected DomainError::Forbidden, got: {err:?}"
+    );
+}
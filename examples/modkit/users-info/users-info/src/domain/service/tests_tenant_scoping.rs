@@ -1,10 +1,16 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
+use std::sync::Arc;
+
+use authz_resolver_sdk::{EvaluationDefaults, TenantMode};
 use uuid::Uuid;
 
 use crate::domain::error::DomainError;
 use crate::domain::service::ServiceConfig;
-use crate::test_support::{build_services, ctx_allow_tenants, ctx_deny_all, inmem_db, seed_user};
+use crate::test_support::{
+    DefaultsAuthZResolver, build_services, build_services_with_authz, ctx_allow_tenants,
+    ctx_deny_all, inmem_db, seed_user,
+};
 use modkit_db::DBProvider;
 use users_info_sdk::{NewAddress, NewCity, NewUser};
 
@@ -32,6 +38,29 @@ async fn tenant_scope_only_sees_its_tenant() {
     assert_eq!(page.items[0].tenant_id, tenant1);
 }
 
+#[tokio::test]
+async fn list_users_follows_resolver_defaults() {
+    let tenant = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant]);
+
+    for mode in [TenantMode::RootOnly, TenantMode::Subtree] {
+        let authz = Arc::new(DefaultsAuthZResolver::new(EvaluationDefaults {
+            tenant_mode: Some(mode.clone()),
+            ..EvaluationDefaults::default()
+        }));
+        let services =
+            build_services_with_authz(inmem_db().await, ServiceConfig::default(), authz.clone());
+
+        services
+            .users
+            .list_users_page(&ctx, &modkit_odata::ODataQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(authz.last_tenant_context().unwrap().mode, mode);
+    }
+}
+
 #[tokio::test]
 async fn deny_all_returns_forbidden() {
     let db = inmem_db().await;
@@ -82,8 +111,10 @@ async fn create_user_with_transaction() {
 
 #[tokio::test]
 async fn dbprovider_transaction_smoke() {
+    use crate::domain::service::resources;
     use crate::infra::storage::entity::user::{ActiveModel, Entity as UserEntity};
-    use modkit_db::secure::{AccessScope, secure_insert};
+    use modkit_db::secure::secure_insert;
+    use modkit_security::{AccessScopeBuilder, pep_properties};
     use sea_orm::Set;
     use time::OffsetDateTime;
 
@@ -93,7 +124,10 @@ async fn dbprovider_transaction_smoke() {
     let tenant_id = Uuid::new_v4();
     let user_id = Uuid::new_v4();
     let now = OffsetDateTime::now_utc();
-    let scope = AccessScope::for_tenants(vec![tenant_id]);
+    let scope = AccessScopeBuilder::for_resource(&resources::USER)
+        .filter(pep_properties::OWNER_TENANT_ID, [tenant_id])
+        .build()
+        .expect("valid user scope");
 
     provider
         .transaction(|tx| {
@@ -102,9 +136,13 @@ async fn dbprovider_transaction_smoke() {
                     id: Set(user_id),
                     tenant_id: Set(tenant_id),
                     email: Set("tx@example.com".to_owned()),
+                    email_original: Set(None),
                     display_name: Set("Tx User".to_owned()),
+                    external_subject_id: Set(None),
                     created_at: Set(now),
                     updated_at: Set(now),
+                    created_by: Set(None),
+                    updated_by: Set(None),
                 };
                 let _ = secure_insert::<UserEntity>(user, &scope, tx).await?;
                 Ok(())
@@ -259,3 +297,99 @@ async fn put_user_address_creates_then_updates() {
     assert_eq!(updated.street, "Second St");
     assert_eq!(updated.postal_code, "22222");
 }
+
+#[tokio::test]
+async fn address_rejects_city_from_another_tenant() {
+    let db = inmem_db().await;
+    let tenant_id = Uuid::new_v4();
+    let other_tenant = Uuid::new_v4();
+    let user_id = Uuid::new_v4();
+    let conn = db.conn().unwrap();
+    seed_user(&conn, user_id, tenant_id, "fk@example.com", "FK User").await;
+
+    let services = build_services(db.clone(), ServiceConfig::default());
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let new_city = |tenant_id| NewCity {
+        id: None,
+        tenant_id,
+        name: "FK City".to_owned(),
+        country: "FK".to_owned(),
+    };
+    let own_city = services
+        .cities
+        .create_city(&ctx, new_city(tenant_id))
+        .await
+        .unwrap();
+    let foreign_city = services
+        .cities
+        .create_city(&ctx_allow_tenants(&[other_tenant]), new_city(other_tenant))
+        .await
+        .unwrap();
+
+    let new_address = |city_id| NewAddress {
+        id: None,
+        tenant_id,
+        user_id,
+        city_id,
+        street: "Border St".to_owned(),
+        postal_code: "33333".to_owned(),
+    };
+
+    let err = services
+        .addresses
+        .create_address(&ctx, new_address(foreign_city.id))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::ReferenceNotInScope { field: "city_id", id } if id == foreign_city.id),
+        "Expected ReferenceNotInScope for city_id, got: {err:?}"
+    );
+
+    let problem = crate::api::rest::error::domain_error_to_problem(&err, "/addresses");
+    assert_eq!(problem.status, http::StatusCode::UNPROCESSABLE_ENTITY);
+    let errors = problem.errors.expect("field violations");
+    assert_eq!(errors[0].field, "city_id");
+
+    let err = services
+        .addresses
+        .put_user_address(&ctx, user_id, new_address(Uuid::new_v4()))
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            DomainError::ReferenceNotInScope {
+                field: "city_id",
+                ..
+            }
+        ),
+        "Expected ReferenceNotInScope for an unknown city, got: {err:?}"
+    );
+
+    // Moving an existing address to a foreign city is rejected as well
+    let created = services
+        .addresses
+        .put_user_address(&ctx, user_id, new_address(own_city.id))
+        .await
+        .unwrap();
+    let err = services
+        .addresses
+        .put_user_address(&ctx, user_id, new_address(foreign_city.id))
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        DomainError::ReferenceNotInScope {
+            field: "city_id",
+            ..
+        }
+    ));
+    let stored = services
+        .addresses
+        .get_user_address(&ctx, user_id)
+        .await
+        .unwrap()
+        .expect("address kept");
+    assert_eq!(stored.city_id, created.city_id);
+}
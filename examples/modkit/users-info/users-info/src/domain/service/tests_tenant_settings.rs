@@ -0,0 +1,286 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::ODataQuery;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::domain::tenant_settings::TenantLimitOverrides;
+use crate::module::ConcreteAppServices;
+use crate::test_support::{build_services, ctx_allow_tenants, ctx_deny_all, inmem_db, new_user};
+
+fn email(tenant_id: Uuid, n: usize) -> String {
+    format!("user{n}-{tenant_id}@example.com")
+}
+
+async fn create_users(services: &ConcreteAppServices, tenant_id: Uuid, count: usize) {
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+    for n in 0..count {
+        services
+            .users
+            .create_user(&ctx, new_user(tenant_id, &email(tenant_id, n)))
+            .await
+            .unwrap();
+    }
+}
+
+async fn put_limits(services: &ConcreteAppServices, tenant_id: Uuid, limits: TenantLimitOverrides) {
+    services
+        .tenant_settings
+        .put_settings(&ctx_allow_tenants(&[tenant_id]), tenant_id, limits)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn user_cap_applies_only_to_its_tenant() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    put_limits(
+        &services,
+        tenant_a,
+        TenantLimitOverrides {
+            max_users: Some(2),
+            ..TenantLimitOverrides::default()
+        },
+    )
+    .await;
+
+    create_users(&services, tenant_a, 2).await;
+    let err = services
+        .users
+        .create_user(
+            &ctx_allow_tenants(&[tenant_a]),
+            new_user(tenant_a, &email(tenant_a, 2)),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(err, DomainError::UserLimitReached { tenant_id, max: 2 } if tenant_id == tenant_a),
+        "{err:?}"
+    );
+    let problem = crate::api::rest::error::domain_error_to_problem(&err, "/users");
+    assert_eq!(problem.status, http::StatusCode::CONFLICT);
+
+    create_users(&services, tenant_b, 3).await;
+}
+
+#[tokio::test]
+async fn global_user_cap_can_be_raised_per_tenant() {
+    let config = ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .max_users_per_tenant(Some(1))
+        .build();
+    let services = build_services(inmem_db().await, config);
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    create_users(&services, tenant_id, 1).await;
+    let err = services
+        .users
+        .create_user(&ctx, new_user(tenant_id, &email(tenant_id, 1)))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::UserLimitReached { max: 1, .. }));
+
+    put_limits(
+        &services,
+        tenant_id,
+        TenantLimitOverrides {
+            max_users: Some(3),
+            ..TenantLimitOverrides::default()
+        },
+    )
+    .await;
+    services
+        .users
+        .create_user(&ctx, new_user(tenant_id, &email(tenant_id, 1)))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn bulk_create_over_the_cap_creates_nothing() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+    put_limits(
+        &services,
+        tenant_id,
+        TenantLimitOverrides {
+            max_users: Some(2),
+            ..TenantLimitOverrides::default()
+        },
+    )
+    .await;
+
+    let err = services
+        .users
+        .create_users_bulk(
+            &ctx,
+            (0..3)
+                .map(|n| new_user(tenant_id, &email(tenant_id, n)))
+                .collect(),
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::UserLimitReached { max: 2, .. }));
+
+    let page = services
+        .users
+        .list_users_page(&ctx, &ODataQuery::default())
+        .await
+        .unwrap();
+    assert!(page.items.is_empty());
+}
+
+#[tokio::test]
+async fn page_size_override_clamps_list_limit() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    create_users(&services, tenant_a, 3).await;
+    create_users(&services, tenant_b, 3).await;
+    put_limits(
+        &services,
+        tenant_a,
+        TenantLimitOverrides {
+            max_page_size: Some(2),
+            ..TenantLimitOverrides::default()
+        },
+    )
+    .await;
+
+    let query = ODataQuery::default().with_limit(10);
+    let page_a = services
+        .users
+        .list_users_page(&ctx_allow_tenants(&[tenant_a]), &query)
+        .await
+        .unwrap();
+    assert_eq!(page_a.items.len(), 2);
+    assert_eq!(page_a.page_info.limit, 2);
+
+    // Without a limit the default page size is clamped as well
+    let page_a = services
+        .users
+        .list_users_page(&ctx_allow_tenants(&[tenant_a]), &ODataQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(page_a.items.len(), 2);
+
+    let page_b = services
+        .users
+        .list_users_page(&ctx_allow_tenants(&[tenant_b]), &query)
+        .await
+        .unwrap();
+    assert_eq!(page_b.items.len(), 3);
+}
+
+#[tokio::test]
+async fn display_name_override_is_enforced() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    put_limits(
+        &services,
+        tenant_id,
+        TenantLimitOverrides {
+            max_display_name_length: Some(5),
+            ..TenantLimitOverrides::default()
+        },
+    )
+    .await;
+
+    let mut user = new_user(tenant_id, &email(tenant_id, 0));
+    user.display_name = "Too long".to_owned();
+    let err = services
+        .users
+        .create_user(&ctx_allow_tenants(&[tenant_id]), user)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        DomainError::DisplayNameTooLong { len: 8, max: 5 }
+    ));
+}
+
+#[tokio::test]
+async fn settings_default_to_global_values_and_round_trip() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let settings = services
+        .tenant_settings
+        .get_settings(&ctx, tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(settings.limits, TenantLimitOverrides::default());
+    assert!(settings.updated_at.is_none());
+
+    let limits = TenantLimitOverrides {
+        max_users: Some(10),
+        max_page_size: Some(20),
+        max_display_name_length: None,
+    };
+    put_limits(&services, tenant_id, limits).await;
+    // A second PUT replaces the row instead of inserting another one
+    put_limits(&services, tenant_id, limits).await;
+
+    let settings = services
+        .tenant_settings
+        .get_settings(&ctx, tenant_id)
+        .await
+        .unwrap();
+    assert_eq!(settings.limits, limits);
+    assert!(settings.updated_at.is_some());
+}
+
+#[tokio::test]
+async fn overrides_above_global_limits_are_rejected() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    for limits in [
+        TenantLimitOverrides {
+            max_page_size: Some(1001),
+            ..TenantLimitOverrides::default()
+        },
+        TenantLimitOverrides {
+            max_users: Some(0),
+            ..TenantLimitOverrides::default()
+        },
+    ] {
+        let err = services
+            .tenant_settings
+            .put_settings(&ctx, tenant_id, limits)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DomainError::Validation { .. }), "{err:?}");
+    }
+}
+
+#[tokio::test]
+async fn settings_of_another_tenant_are_forbidden() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+
+    for ctx in [ctx_allow_tenants(&[tenant_b]), ctx_deny_all()] {
+        let err = services
+            .tenant_settings
+            .get_settings(&ctx, tenant_a)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DomainError::Forbidden), "{err:?}");
+
+        let err = services
+            .tenant_settings
+            .put_settings(&ctx, tenant_a, TenantLimitOverrides::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DomainError::Forbidden), "{err:?}");
+    }
+}
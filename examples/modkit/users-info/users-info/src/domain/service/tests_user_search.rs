@@ -0,0 +1,74 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::{ODataQuery, ODataSearch, parse_filter_string};
+use users_info_sdk::NewUser;
+use uuid::Uuid;
+
+use crate::domain::service::ServiceConfig;
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db};
+
+fn search(terms: &[&str]) -> ODataQuery {
+    ODataQuery::default().with_search(ODataSearch::new(terms.iter().copied()))
+}
+
+#[tokio::test]
+async fn search_matches_all_terms_across_name_and_email() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let other_tenant = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    for (tenant_id, email, display_name) in [
+        (tenant_id, "ada@example.com", "Ada Lovelace"),
+        (tenant_id, "lovelace@example.org", "Byron"),
+        (tenant_id, "grace@example.com", "Grace Hopper"),
+        (tenant_id, "promo@example.com", "50% off"),
+        (tenant_id, "promo2@example.com", "500 offers"),
+        (other_tenant, "ada@other.com", "Ada Other"),
+    ] {
+        services
+            .users
+            .create_user(
+                &ctx_allow_tenants(&[tenant_id]),
+                NewUser {
+                    id: None,
+                    tenant_id,
+                    email: email.to_owned(),
+                    display_name: display_name.to_owned(),
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    let names = |query: ODataQuery| {
+        let services = &services;
+        let ctx = &ctx;
+        async move {
+            let page = services.users.list_users_page(ctx, &query).await.unwrap();
+            let mut names: Vec<String> = page.items.into_iter().map(|u| u.display_name).collect();
+            names.sort();
+            names
+        }
+    };
+
+    // Either column may match, case-insensitively; other tenants stay hidden
+    assert_eq!(
+        names(search(&["LOVELACE"])).await,
+        vec!["Ada Lovelace", "Byron"]
+    );
+    // Every term must match
+    assert_eq!(
+        names(search(&["ada", "example.com"])).await,
+        vec!["Ada Lovelace"]
+    );
+    // Wildcards are literal
+    assert_eq!(names(search(&["0% off"])).await, vec!["50% off"]);
+    // $search narrows $filter
+    let query = search(&["example"]).with_filter(
+        parse_filter_string("startswith(email,'g')")
+            .unwrap()
+            .into_expr(),
+    );
+    assert_eq!(names(query).await, vec!["Grace Hopper"]);
+}
@@ -0,0 +1,201 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_odata::ODataQuery;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+use crate::domain::service::ServiceConfig;
+use crate::domain::webhooks::{NewWebhook, TargetPolicy, WebhookEventType};
+use crate::test_support::{build_services, ctx_allow_tenants, inmem_db};
+
+fn new_webhook(tenant_id: Uuid, url: &str) -> NewWebhook {
+    NewWebhook {
+        tenant_id,
+        url: url.to_owned(),
+        secret: None,
+        event_types: vec![WebhookEventType::UserCreated, WebhookEventType::UserDeleted],
+    }
+}
+
+fn validation_field(err: &DomainError) -> Option<&str> {
+    match err {
+        DomainError::Validation { field, .. } => Some(field),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn create_generates_secret_and_dedupes_event_types() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let mut req = new_webhook(tenant_id, "https://hooks.example.com/users");
+    req.event_types.push(WebhookEventType::UserCreated);
+    let created = services.webhooks.create_webhook(&ctx, req).await.unwrap();
+
+    assert!(created.secret.starts_with("whsec_"));
+    assert_eq!(
+        created.event_types,
+        vec![WebhookEventType::UserCreated, WebhookEventType::UserDeleted]
+    );
+
+    let fetched = services
+        .webhooks
+        .get_webhook(&ctx, created.id)
+        .await
+        .unwrap();
+    assert_eq!(fetched, created);
+}
+
+#[tokio::test]
+async fn create_rejects_internal_targets() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    for url in [
+        "http://hooks.example.com/users",
+        "https://localhost/users",
+        "https://127.0.0.1/users",
+        "https://169.254.169.254/latest/meta-data",
+        "https://[fd00::1]/users",
+    ] {
+        let err = services
+            .webhooks
+            .create_webhook(&ctx, new_webhook(tenant_id, url))
+            .await
+            .unwrap_err();
+        assert_eq!(validation_field(&err), Some("url"), "{url}");
+    }
+}
+
+#[tokio::test]
+async fn create_honours_configured_allowed_hosts() {
+    let config = ServiceConfig::builder()
+        .default_page_size(50)
+        .max_page_size(1000)
+        .webhook_targets(
+            TargetPolicy::new(&["10.0.0.0/8".to_owned()], &["10.0.0.5".to_owned()], false).unwrap(),
+        )
+        .build();
+    let services = build_services(inmem_db().await, config);
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    services
+        .webhooks
+        .create_webhook(&ctx, new_webhook(tenant_id, "https://10.0.0.5/users"))
+        .await
+        .unwrap();
+    let err = services
+        .webhooks
+        .create_webhook(&ctx, new_webhook(tenant_id, "https://10.0.0.6/users"))
+        .await
+        .unwrap_err();
+    assert_eq!(validation_field(&err), Some("url"));
+}
+
+#[tokio::test]
+async fn create_validates_event_types_and_secret() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_id = Uuid::new_v4();
+    let ctx = ctx_allow_tenants(&[tenant_id]);
+
+    let mut no_events = new_webhook(tenant_id, "https://hooks.example.com/users");
+    no_events.event_types.clear();
+    let err = services
+        .webhooks
+        .create_webhook(&ctx, no_events)
+        .await
+        .unwrap_err();
+    assert_eq!(validation_field(&err), Some("event_types"));
+
+    let mut short_secret = new_webhook(tenant_id, "https://hooks.example.com/users");
+    short_secret.secret = Some("short".to_owned());
+    let err = services
+        .webhooks
+        .create_webhook(&ctx, short_secret)
+        .await
+        .unwrap_err();
+    assert_eq!(validation_field(&err), Some("secret"));
+}
+
+#[tokio::test]
+async fn webhooks_are_tenant_scoped() {
+    let services = build_services(inmem_db().await, ServiceConfig::default());
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    let ctx_a = ctx_allow_tenants(&[tenant_a]);
+    let ctx_b = ctx_allow_tenants(&[tenant_b]);
+
+    let created = services
+        .webhooks
+        .create_webhook(&ctx_a, new_webhook(tenant_a, "https://a.example.com/hook"))
+        .await
+        .unwrap();
+
+    // Tenant B cannot register webhooks for tenant A
+    assert!(
+        services
+            .webhooks
+            .create_webhook(&ctx_b, new_webhook(tenant_a, "https://b.example.com/hook"))
+            .await
+            .is_err()
+    );
+
+    let err = services
+        .webhooks
+        .get_webhook(&ctx_b, created.id)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Forbidden));
+
+    let err = services
+        .webhooks
+        .list_deliveries_page(&ctx_b, created.id, &ODataQuery::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::Forbidden));
+
+    let page_b = services
+        .webhooks
+        .list_webhooks_page(&ctx_b, &ODataQuery::default())
+        .await
+        .unwrap();
+    assert!(page_b.items.is_empty());
+
+    assert!(
+        services
+            .webhooks
+            .delete_webhook(&ctx_b, created.id)
+            .await
+            .is_err()
+    );
+
+    let page_a = services
+        .webhooks
+        .list_webhooks_page(&ctx_a, &ODataQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(page_a.items.len(), 1);
+
+    let deliveries = services
+        .webhooks
+        .list_deliveries_page(&ctx_a, created.id, &ODataQuery::default())
+        .await
+        .unwrap();
+    assert!(deliveries.items.is_empty());
+
+    services
+        .webhooks
+        .delete_webhook(&ctx_a, created.id)
+        .await
+        .unwrap();
+    let err = services
+        .webhooks
+        .get_webhook(&ctx_a, created.id)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, DomainError::NotFound { .. }));
+}
@@ -1,22 +1,29 @@
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use modkit_macros::domain_model;
 use tracing::instrument;
 
+use crate::domain::email::EmailPolicy;
 use crate::domain::error::DomainError;
 use crate::domain::events::UserDomainEvent;
 use crate::domain::ports::{AuditPort, EventPublisher};
-use crate::domain::repos::{AddressesRepository, CitiesRepository, UsersRepository};
+use crate::domain::repos::{
+    AddressesRepository, CitiesRepository, TenantSettingsRepository, UsersRepository,
+};
 use crate::domain::service::DbProvider;
-use crate::domain::service::{AddressesService, CitiesService, ServiceConfig};
+use crate::domain::service::{
+    AddressesService, CitiesService, ServiceConfig, TenantConfigResolver,
+};
 use authz_resolver_sdk::PolicyEnforcer;
 use authz_resolver_sdk::pep::AccessRequest;
 
 use super::{actions, resources};
-use modkit_odata::{ODataQuery, Page};
-use modkit_security::{AccessScope, SecurityContext, pep_properties};
+use modkit_db::secure::DBRunner;
+use modkit_odata::{ODataQuery, Page, ast};
+use modkit_security::{AccessScope, Redacted, ScopeIntent, SecurityContext, pep_properties};
 use time::OffsetDateTime;
-use users_info_sdk::{NewUser, User, UserFull, UserPatch};
+use users_info_sdk::{NewUser, User, UserFull, UserPatch, UserWithAddressSummary};
 use uuid::Uuid;
 
 /// Users service.
@@ -31,8 +38,12 @@ use uuid::Uuid;
 /// - Centralizes DB error mapping in the domain layer
 /// - Maintains transaction safety via the task-local guard
 #[domain_model]
-pub struct UsersService<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository>
-{
+pub struct UsersService<
+    R: UsersRepository + 'static,
+    CR: CitiesRepository,
+    AR: AddressesRepository,
+    TR: TenantSettingsRepository,
+> {
     db: Arc<DbProvider>,
     repo: Arc<R>,
     events: Arc<dyn EventPublisher<UserDomainEvent>>,
@@ -41,10 +52,15 @@ pub struct UsersService<R: UsersRepository + 'static, CR: CitiesRepository, AR:
     config: ServiceConfig,
     cities: Arc<CitiesService<CR>>,
     addresses: Arc<AddressesService<AR, R>>,
+    tenant_limits: Arc<TenantConfigResolver<TR>>,
 }
 
-impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository>
-    UsersService<R, CR, AR>
+impl<
+    R: UsersRepository + 'static,
+    CR: CitiesRepository,
+    AR: AddressesRepository,
+    TR: TenantSettingsRepository,
+> UsersService<R, CR, AR, TR>
 {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -56,6 +72,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         config: ServiceConfig,
         cities: Arc<CitiesService<CR>>,
         addresses: Arc<AddressesService<AR, R>>,
+        tenant_limits: Arc<TenantConfigResolver<TR>>,
     ) -> Self {
         Self {
             db,
@@ -66,16 +83,71 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
             config,
             cities,
             addresses,
+            tenant_limits,
         }
     }
 }
 
+/// `OData` filter field holding the normalized email.
+const EMAIL_FIELD: &str = "email";
+
+/// `$select` field that embeds address summaries in listed users.
+const ADDRESS_SUMMARY_FIELD: &str = "address_summary";
+
+/// Whether `$select` asks for [`ADDRESS_SUMMARY_FIELD`].
+fn selects_address_summary(query: &ODataQuery) -> bool {
+    query.selected_fields().is_some_and(|fields| {
+        fields
+            .iter()
+            .any(|f| f.eq_ignore_ascii_case(ADDRESS_SUMMARY_FIELD))
+    })
+}
+
+/// Rewrite string literals compared against `email` to their normalized form.
+fn normalize_email_literals(expr: ast::Expr, policy: &EmailPolicy) -> ast::Expr {
+    use ast::Expr;
+
+    let is_email = |e: &Expr| matches!(e, Expr::Identifier(name) if name == EMAIL_FIELD);
+    let normalize = |e: Expr| match e {
+        Expr::Value(ast::Value::String(s)) => {
+            Expr::Value(ast::Value::String(policy.normalize_filter_literal(&s)))
+        }
+        other => other,
+    };
+
+    match expr {
+        Expr::And(a, b) => Expr::And(
+            Box::new(normalize_email_literals(*a, policy)),
+            Box::new(normalize_email_literals(*b, policy)),
+        ),
+        Expr::Or(a, b) => Expr::Or(
+            Box::new(normalize_email_literals(*a, policy)),
+            Box::new(normalize_email_literals(*b, policy)),
+        ),
+        Expr::Not(e) => Expr::Not(Box::new(normalize_email_literals(*e, policy))),
+        Expr::Compare(lhs, op, rhs) if is_email(&lhs) => {
+            Expr::Compare(lhs, op, Box::new(normalize(*rhs)))
+        }
+        Expr::Compare(lhs, op, rhs) if is_email(&rhs) => {
+            Expr::Compare(Box::new(normalize(*lhs)), op, rhs)
+        }
+        Expr::In(lhs, values) if is_email(&lhs) => {
+            Expr::In(lhs, values.into_iter().map(normalize).collect())
+        }
+        Expr::Function(name, args) if args.iter().any(is_email) => {
+            Expr::Function(name, args.into_iter().map(normalize).collect())
+        }
+        other => other,
+    }
+}
+
 async fn audit_get_user_access_best_effort<
     R: UsersRepository,
     CR: CitiesRepository,
     AR: AddressesRepository,
+    TR: TenantSettingsRepository,
 >(
-    svc: &UsersService<R, CR, AR>,
+    svc: &UsersService<R, CR, AR, TR>,
     id: Uuid,
 ) {
     let audit_result = svc.audit.get_user_access(id).await;
@@ -84,9 +156,52 @@ async fn audit_get_user_access_best_effort<
     }
 }
 
+/// `max_len` is the tenant's effective `max_display_name_length`.
+fn validate_display_name(display_name: &str, max_len: usize) -> Result<(), DomainError> {
+    if display_name.trim().is_empty() {
+        return Err(DomainError::empty_display_name());
+    }
+    if display_name.len() > max_len {
+        return Err(DomainError::display_name_too_long(
+            display_name.len(),
+            max_len,
+        ));
+    }
+    Ok(())
+}
+
+/// Fail if adding `adding` users to `tenant_id` would exceed `max`.
+async fn check_user_quota<R: UsersRepository, C: DBRunner>(
+    repo: &R,
+    conn: &C,
+    tenant_id: Uuid,
+    max: u32,
+    adding: u64,
+) -> Result<(), DomainError> {
+    // SAFETY(PEP bypass): counts the tenant's users to enforce its quota;
+    // the count is not returned to the caller.
+    let scope = AccessScope::for_tenant(tenant_id).with_intent(ScopeIntent::Read);
+    if repo.count(conn, &scope).await? + adding > u64::from(max) {
+        return Err(DomainError::user_limit_reached(tenant_id, max));
+    }
+    Ok(())
+}
+
+/// IDs, emails and CREATE scopes seen so far in a bulk request.
+#[derive(Default)]
+struct BulkBatch {
+    ids: HashSet<Uuid>,
+    emails: HashSet<String>,
+    scopes: HashMap<Uuid, AccessScope>,
+}
+
 // Business logic methods
-impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository>
-    UsersService<R, CR, AR>
+impl<
+    R: UsersRepository + 'static,
+    CR: CitiesRepository,
+    AR: AddressesRepository,
+    TR: TenantSettingsRepository,
+> UsersService<R, CR, AR, TR>
 {
     #[instrument(skip(self, ctx), fields(user_id = %id))]
     pub async fn get_user(&self, ctx: &SecurityContext, id: Uuid) -> Result<User, DomainError> {
@@ -98,7 +213,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
 
         // Prefetch: load user to extract owner_tenant_id for PDP.
         // PDP returns a narrow `eq` constraint instead of expanding the subtree.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let user = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -143,18 +258,114 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         tracing::debug!("Listing users with cursor pagination");
 
         let conn = self.db.conn().map_err(DomainError::from)?;
+        let (scope, query) = self.prepare_list(ctx, query).await?;
 
-        let scope = self
+        let page = self.repo.list_page(&conn, &scope, &query).await?;
+
+        tracing::debug!("Successfully listed {} users in page", page.items.len());
+        Ok(page)
+    }
+
+    /// List users with cursor-based pagination, embedding address summaries
+    /// when `$select` includes `address_summary`.
+    ///
+    /// The page's addresses and cities are then loaded with one scoped query
+    /// each rather than per user. Otherwise, or with the `addresses` feature
+    /// disabled, this runs the same single query as `list_users_page`.
+    #[instrument(skip(self, ctx, query))]
+    pub async fn list_users_page_with_summaries(
+        &self,
+        ctx: &SecurityContext,
+        query: &ODataQuery,
+    ) -> Result<Page<UserWithAddressSummary>, DomainError> {
+        tracing::debug!("Listing users with address summaries");
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let (scope, query) = self.prepare_list(ctx, query).await?;
+
+        if !(self.addresses.is_enabled() && selects_address_summary(&query)) {
+            let page = self.repo.list_page(&conn, &scope, &query).await?;
+            return Ok(page.map_items(Into::into));
+        }
+
+        let address_scope = self
             .policy_enforcer
-            .access_scope(ctx, &resources::USER, actions::LIST, None)
+            .access_scope(ctx, &resources::ADDRESS, actions::LIST, None)
+            .await?;
+        let city_scope = self
+            .policy_enforcer
+            .access_scope(ctx, &resources::CITY, actions::LIST, None)
             .await?;
 
-        let page = self.repo.list_page(&conn, &scope, query).await?;
+        let page = self
+            .repo
+            .list_page_with_address_summaries(&conn, &scope, &address_scope, &city_scope, &query)
+            .await?;
 
         tracing::debug!("Successfully listed {} users in page", page.items.len());
         Ok(page)
     }
 
+    /// List the users with an address in a city, with cursor-based pagination.
+    ///
+    /// The city must be readable by the caller, with the same not-found and
+    /// forbidden outcomes as [`CitiesService::get_city`]. Users are then
+    /// listed within the user LIST scope and matched only against addresses
+    /// within the address LIST scope.
+    #[instrument(skip(self, ctx, query), fields(city_id = %city_id))]
+    pub async fn list_city_users_page(
+        &self,
+        ctx: &SecurityContext,
+        city_id: Uuid,
+        query: &ODataQuery,
+    ) -> Result<Page<User>, DomainError> {
+        tracing::debug!("Listing users of a city");
+
+        self.cities.get_city(ctx, city_id).await?;
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let (scope, query) = self.prepare_list(ctx, query).await?;
+        let address_scope = self
+            .policy_enforcer
+            .access_scope(ctx, &resources::ADDRESS, actions::LIST, None)
+            .await?;
+
+        let page = self
+            .repo
+            .list_city_users_page(&conn, &scope, &address_scope, city_id, &query)
+            .await?;
+
+        tracing::debug!("Successfully listed {} users in page", page.items.len());
+        Ok(page)
+    }
+
+    /// Resolve the LIST scope and apply the tenant page limit and email
+    /// normalization to `query`.
+    async fn prepare_list(
+        &self,
+        ctx: &SecurityContext,
+        query: &ODataQuery,
+    ) -> Result<(AccessScope, ODataQuery), DomainError> {
+        let scope = self
+            .policy_enforcer
+            .access_scope(ctx, &resources::USER, actions::LIST, None)
+            .await?;
+
+        // Stored emails are normalized; normalize filter literals the same way.
+        let mut query = query.clone();
+
+        // The caller's tenant may override the page size below the global maximum.
+        let limits = self.tenant_limits.resolve(ctx.subject_tenant_id()).await?;
+        query.limit = limits.page_limit(query.limit, self.config.default_page_size);
+
+        if let Some(filter) = query.filter.take() {
+            let policy = &self.config.email_policy;
+            query.filter = Some(Box::new(normalize_email_literals(*filter, policy)));
+        }
+
+        Ok((scope, query))
+    }
+
     /// Create a new user.
     #[allow(clippy::cognitive_complexity)]
     #[instrument(
@@ -168,14 +379,14 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
     ) -> Result<User, DomainError> {
         tracing::info!("Creating new user");
 
-        self.validate_new_user(&new_user)?;
+        let email = self.config.email_policy.normalize(&new_user.email)?;
 
         let conn = self.db.conn().map_err(DomainError::from)?;
 
         let NewUser {
             id: provided_id,
             tenant_id,
-            email,
+            email: email_original,
             display_name,
         } = new_user;
 
@@ -192,12 +403,16 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
             )
             .await?;
 
+        let limits = self.tenant_limits.resolve(tenant_id).await?;
+        validate_display_name(&display_name, limits.max_display_name_length)?;
+
         let now = OffsetDateTime::now_utc();
 
         let user = User {
             id,
             tenant_id,
             email,
+            email_original: Some(email_original),
             display_name,
             created_at: now,
             updated_at: now,
@@ -207,7 +422,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         // globally across all tenants, not per-tenant. This intentionally
         // bypasses tenant isolation so that a CREATE in tenant A is rejected
         // if the same email/ID already exists in tenant B.
-        let global = AccessScope::allow_all();
+        let global = AccessScope::allow_all().with_intent(ScopeIntent::Read);
 
         if provided_id.is_some() && self.repo.exists(&conn, &global, id).await? {
             return Err(DomainError::validation(
@@ -225,7 +440,20 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
             return Err(DomainError::email_already_exists(user.email.clone()));
         }
 
-        let created_user = self.repo.create(&conn, &scope, user).await?;
+        // The quota is counted in the same transaction as the insert.
+        let repo = Arc::clone(&self.repo);
+        let ctx = ctx.clone();
+        let created_user = self
+            .db
+            .transaction(|tx| {
+                Box::pin(async move {
+                    if let Some(max) = limits.max_users {
+                        check_user_quota(&*repo, tx, tenant_id, max, 1).await?;
+                    }
+                    Ok(repo.create(tx, &scope, &ctx, user).await?)
+                })
+            })
+            .await?;
 
         let notification_result = self.audit.notify_user_created().await;
         if let Err(e) = notification_result {
@@ -234,13 +462,174 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
 
         self.events.publish(&UserDomainEvent::Created {
             id: created_user.id,
+            tenant_id: created_user.tenant_id,
             at: created_user.created_at,
         });
 
-        tracing::info!("Successfully created user with id={}", created_user.id);
+        tracing::info!(user = ?Redacted(&created_user), "Successfully created user");
         Ok(created_user)
     }
 
+    /// Create several users in one transaction.
+    ///
+    /// Every entry is validated like in [`Self::create_user`], including ID and
+    /// email uniqueness within the batch, and checked against the CREATE scope
+    /// of its tenant before anything is written. Either all users are created
+    /// or none.
+    ///
+    /// # Errors
+    /// `Validation` if the batch is empty or larger than `max_bulk_users`;
+    /// `UserLimitReached` if the batch would exceed a tenant's `max_users`;
+    /// otherwise `BulkEntry` with the index and error of the first failing entry.
+    #[instrument(skip(self, ctx, new_users), fields(count = new_users.len()))]
+    pub async fn create_users_bulk(
+        &self,
+        ctx: &SecurityContext,
+        new_users: Vec<NewUser>,
+    ) -> Result<Vec<User>, DomainError> {
+        tracing::info!("Creating users in bulk");
+
+        if new_users.is_empty() {
+            return Err(DomainError::validation(
+                "users",
+                "at least one user is required",
+            ));
+        }
+        if new_users.len() > self.config.max_bulk_users {
+            return Err(DomainError::validation(
+                "users",
+                format!("at most {} users per request", self.config.max_bulk_users),
+            ));
+        }
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let now = OffsetDateTime::now_utc();
+        let mut batch = BulkBatch::default();
+        let mut prepared = Vec::with_capacity(new_users.len());
+        for (index, new_user) in new_users.into_iter().enumerate() {
+            let entry = self
+                .prepare_bulk_entry(ctx, &conn, new_user, now, &mut batch)
+                .await
+                .map_err(|e| DomainError::bulk_entry(index, e))?;
+            prepared.push(entry);
+        }
+
+        let mut quotas: HashMap<Uuid, (u32, u64)> = HashMap::new();
+        for (user, _) in &prepared {
+            if let Some(max) = self.tenant_limits.resolve(user.tenant_id).await?.max_users {
+                quotas.entry(user.tenant_id).or_insert((max, 0)).1 += 1;
+            }
+        }
+
+        let repo = Arc::clone(&self.repo);
+        let ctx = ctx.clone();
+        let created = self
+            .db
+            .transaction(|tx| {
+                Box::pin(async move {
+                    for (tenant_id, (max, adding)) in quotas {
+                        check_user_quota(&*repo, tx, tenant_id, max, adding).await?;
+                    }
+                    let mut created = Vec::with_capacity(prepared.len());
+                    for (index, (user, scope)) in prepared.into_iter().enumerate() {
+                        let user = repo
+                            .create(tx, &scope, &ctx, user)
+                            .await
+                            .map_err(|e| DomainError::bulk_entry(index, e))?;
+                        created.push(user);
+                    }
+                    Ok(created)
+                })
+            })
+            .await?;
+
+        for user in &created {
+            if let Err(e) = self.audit.notify_user_created().await {
+                tracing::debug!("Notification service call failed (continuing): {}", e);
+            }
+            self.events.publish(&UserDomainEvent::Created {
+                id: user.id,
+                tenant_id: user.tenant_id,
+                at: user.created_at,
+            });
+        }
+
+        tracing::info!("Successfully created {} users", created.len());
+        Ok(created)
+    }
+
+    /// Validate one bulk entry and resolve the CREATE scope of its tenant.
+    async fn prepare_bulk_entry<C: DBRunner>(
+        &self,
+        ctx: &SecurityContext,
+        conn: &C,
+        new_user: NewUser,
+        now: OffsetDateTime,
+        batch: &mut BulkBatch,
+    ) -> Result<(User, AccessScope), DomainError> {
+        let email = self.config.email_policy.normalize(&new_user.email)?;
+        let id = new_user.id.unwrap_or_else(Uuid::now_v7);
+
+        if !batch.ids.insert(id) {
+            return Err(DomainError::validation(
+                "id",
+                "User with this ID appears more than once",
+            ));
+        }
+        if !batch.emails.insert(email.clone()) {
+            return Err(DomainError::email_already_exists(email));
+        }
+
+        let scope = match batch.scopes.get(&new_user.tenant_id) {
+            Some(scope) => scope.clone(),
+            None => {
+                let scope = self
+                    .policy_enforcer
+                    .access_scope_with(
+                        ctx,
+                        &resources::USER,
+                        actions::CREATE,
+                        None,
+                        &AccessRequest::new()
+                            .resource_property(pep_properties::OWNER_TENANT_ID, new_user.tenant_id),
+                    )
+                    .await?;
+                batch.scopes.insert(new_user.tenant_id, scope.clone());
+                scope
+            }
+        };
+
+        let limits = self.tenant_limits.resolve(new_user.tenant_id).await?;
+        validate_display_name(&new_user.display_name, limits.max_display_name_length)?;
+
+        let user = User {
+            id,
+            tenant_id: new_user.tenant_id,
+            email,
+            email_original: Some(new_user.email),
+            display_name: new_user.display_name,
+            created_at: now,
+            updated_at: now,
+        };
+        if !self.repo.insert_in_scope(&scope, &user) {
+            return Err(DomainError::Forbidden);
+        }
+
+        // SAFETY(multi-tenant bypass): global uniqueness, as in `create_user`.
+        let global = AccessScope::allow_all().with_intent(ScopeIntent::Read);
+        if new_user.id.is_some() && self.repo.exists(conn, &global, id).await? {
+            return Err(DomainError::validation(
+                "id",
+                "User with this ID already exists",
+            ));
+        }
+        if self.repo.count_by_email(conn, &global, &user.email).await? > 0 {
+            return Err(DomainError::email_already_exists(user.email));
+        }
+
+        Ok((user, scope))
+    }
+
     /// Update an existing user.
     #[instrument(skip(self, ctx), fields(user_id = %id))]
     pub async fn update_user(
@@ -251,13 +640,17 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
     ) -> Result<User, DomainError> {
         tracing::info!("Updating user");
 
-        self.validate_user_patch(&patch)?;
+        let new_email = patch
+            .email
+            .as_deref()
+            .map(|raw| self.config.email_policy.normalize(raw))
+            .transpose()?;
 
         let conn = self.db.conn().map_err(DomainError::from)?;
 
         // Prefetch: load user to extract owner_tenant_id for PDP.
         // Narrow scope + WHERE constraint provides TOCTOU protection.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let mut current = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -276,30 +669,37 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
             )
             .await?;
 
-        if let Some(ref new_email) = patch.email
+        if let Some(ref display_name) = patch.display_name {
+            let limits = self.tenant_limits.resolve(current.tenant_id).await?;
+            validate_display_name(display_name, limits.max_display_name_length)?;
+        }
+
+        if let Some(ref new_email) = new_email
             && new_email != &current.email
         {
             // SAFETY(multi-tenant bypass): see comment in create_user().
-            let global = AccessScope::allow_all();
+            let global = AccessScope::allow_all().with_intent(ScopeIntent::Read);
             let count = self.repo.count_by_email(&conn, &global, new_email).await?;
             if count > 0 {
                 return Err(DomainError::email_already_exists(new_email.clone()));
             }
         }
 
-        if let Some(email) = patch.email {
+        if let (Some(email), Some(original)) = (new_email, patch.email) {
             current.email = email;
+            current.email_original = Some(original);
         }
         if let Some(display_name) = patch.display_name {
             current.display_name = display_name;
         }
-        current.updated_at = OffsetDateTime::now_utc();
 
-        // repo.update applies scope constraints via WHERE clause (TOCTOU-safe).
-        let updated_user = self.repo.update(&conn, &scope, current).await?;
+        // repo.update applies scope constraints via WHERE clause (TOCTOU-safe)
+        // and stamps the update time and actor.
+        let updated_user = self.repo.update(&conn, &scope, ctx, current).await?;
 
         self.events.publish(&UserDomainEvent::Updated {
             id: updated_user.id,
+            tenant_id: updated_user.tenant_id,
             at: updated_user.updated_at,
         });
 
@@ -307,6 +707,59 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         Ok(updated_user)
     }
 
+    /// Get the caller's own profile.
+    ///
+    /// The user is resolved from the subject (see [`Self::resolve_self`]); the
+    /// PDP then evaluates `get` on that user, so a policy can grant access to
+    /// one's own profile without a general GET grant.
+    #[instrument(skip(self, ctx), fields(subject_id = %ctx.subject_id()))]
+    pub async fn get_me(&self, ctx: &SecurityContext) -> Result<User, DomainError> {
+        let id = self.resolve_self(ctx).await?;
+        self.get_user(ctx, id).await
+    }
+
+    /// Update the caller's own profile.
+    ///
+    /// Only the fields allowed by the self-service policy may be set; the
+    /// PDP evaluates `update` on the resolved user.
+    #[instrument(skip(self, ctx, patch), fields(subject_id = %ctx.subject_id()))]
+    pub async fn update_me(
+        &self,
+        ctx: &SecurityContext,
+        patch: UserPatch,
+    ) -> Result<User, DomainError> {
+        self.config.self_service.check_patch(&patch)?;
+        let id = self.resolve_self(ctx).await?;
+        self.update_user(ctx, id, patch).await
+    }
+
+    /// Find the user the subject of `ctx` is.
+    ///
+    /// With `subject_is_user_id`, a user whose id equals the subject id wins;
+    /// otherwise (or if there is none) the user linked via
+    /// `external_subject_id` is used.
+    async fn resolve_self(&self, ctx: &SecurityContext) -> Result<Uuid, DomainError> {
+        let subject_id = ctx.subject_id();
+        let conn = self.db.conn().map_err(DomainError::from)?;
+
+        // SAFETY(PEP bypass): this lookup only maps the subject to a user id in
+        // the subject's own tenant. It returns no data to the caller; access is
+        // decided by the PDP on the resolved id afterwards.
+        let lookup =
+            AccessScope::for_tenant(ctx.subject_tenant_id()).with_intent(ScopeIntent::Read);
+
+        if self.config.self_service.subject_is_user_id
+            && self.repo.exists(&conn, &lookup, subject_id).await?
+        {
+            return Ok(subject_id);
+        }
+        self.repo
+            .find_by_external_subject(&conn, &lookup, subject_id)
+            .await?
+            .map(|user| user.id)
+            .ok_or_else(|| DomainError::not_found("User profile", subject_id))
+    }
+
     #[instrument(skip(self, ctx), fields(user_id = %id))]
     pub async fn delete_user(&self, ctx: &SecurityContext, id: Uuid) -> Result<(), DomainError> {
         tracing::info!("Deleting user");
@@ -315,7 +768,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
 
         // Prefetch: load user to extract owner_tenant_id for PDP.
         // Narrow scope + WHERE constraint provides TOCTOU protection.
-        let prefetch_scope = AccessScope::allow_all();
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
         let prefetched = self
             .repo
             .get(&conn, &prefetch_scope, id)
@@ -342,6 +795,7 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
 
         self.events.publish(&UserDomainEvent::Deleted {
             id,
+            tenant_id: prefetched.tenant_id,
             at: OffsetDateTime::now_utc(),
         });
 
@@ -349,42 +803,6 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
         Ok(())
     }
 
-    fn validate_new_user(&self, new_user: &NewUser) -> Result<(), DomainError> {
-        Self::validate_email(&new_user.email)?;
-        self.validate_display_name(&new_user.display_name)?;
-        Ok(())
-    }
-
-    fn validate_user_patch(&self, patch: &UserPatch) -> Result<(), DomainError> {
-        if let Some(ref email) = patch.email {
-            Self::validate_email(email)?;
-        }
-        if let Some(ref display_name) = patch.display_name {
-            self.validate_display_name(display_name)?;
-        }
-        Ok(())
-    }
-
-    fn validate_email(email: &str) -> Result<(), DomainError> {
-        if email.is_empty() || !email.contains('@') || !email.contains('.') {
-            return Err(DomainError::invalid_email(email.to_owned()));
-        }
-        Ok(())
-    }
-
-    fn validate_display_name(&self, display_name: &str) -> Result<(), DomainError> {
-        if display_name.trim().is_empty() {
-            return Err(DomainError::empty_display_name());
-        }
-        if display_name.len() > self.config.max_display_name_length {
-            return Err(DomainError::display_name_too_long(
-                display_name.len(),
-                self.config.max_display_name_length,
-            ));
-        }
-        Ok(())
-    }
-
     #[instrument(skip(self, ctx), fields(user_id = %id))]
     pub async fn get_user_full(
         &self,
@@ -395,7 +813,12 @@ impl<R: UsersRepository + 'static, CR: CitiesRepository, AR: AddressesRepository
 
         let user = self.get_user(ctx, id).await?;
 
-        let address = self.addresses.get_address_by_user(ctx, id).await?;
+        // Aggregation degrades gracefully when the addresses feature is disabled.
+        let address = if self.addresses.is_enabled() {
+            self.addresses.get_address_by_user(ctx, id).await?
+        } else {
+            None
+        };
 
         let city = if let Some(ref addr) = address {
             Some(self.cities.get_city(ctx, addr.city_id).await?)
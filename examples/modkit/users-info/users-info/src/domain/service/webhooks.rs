@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use modkit_macros::domain_model;
+use rand::Rng as _;
+use tracing::{debug, info, instrument};
+
+use crate::domain::error::DomainError;
+use crate::domain::repos::WebhooksRepository;
+use crate::domain::service::DbProvider;
+use crate::domain::webhooks::{NewWebhook, TargetPolicy, Webhook, WebhookDelivery};
+use authz_resolver_sdk::PolicyEnforcer;
+use authz_resolver_sdk::pep::AccessRequest;
+
+use super::{actions, resources};
+use modkit_odata::{ODataQuery, Page};
+use modkit_security::{AccessScope, ScopeIntent, SecurityContext, pep_properties};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Minimum length of a client-provided signing secret.
+pub const MIN_SECRET_LEN: usize = 16;
+
+/// Webhook subscriptions service.
+///
+/// Target URLs are validated against the [`TargetPolicy`] on registration;
+/// deliveries are handled by [`crate::domain::webhooks::WebhookWorker`].
+#[domain_model]
+pub struct WebhooksService<R: WebhooksRepository> {
+    db: Arc<DbProvider>,
+    repo: Arc<R>,
+    policy_enforcer: PolicyEnforcer,
+    targets: TargetPolicy,
+}
+
+impl<R: WebhooksRepository> WebhooksService<R> {
+    pub fn new(
+        db: Arc<DbProvider>,
+        repo: Arc<R>,
+        policy_enforcer: PolicyEnforcer,
+        targets: TargetPolicy,
+    ) -> Self {
+        Self {
+            db,
+            repo,
+            policy_enforcer,
+            targets,
+        }
+    }
+}
+
+impl<R: WebhooksRepository> WebhooksService<R> {
+    #[instrument(skip(self, ctx), fields(webhook_id = %id))]
+    pub async fn get_webhook(
+        &self,
+        ctx: &SecurityContext,
+        id: Uuid,
+    ) -> Result<Webhook, DomainError> {
+        debug!("Getting webhook by id");
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+
+        // Prefetch: load webhook to extract owner_tenant_id for PDP.
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
+        let webhook = self
+            .repo
+            .get(&conn, &prefetch_scope, id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("Webhook", id))?;
+
+        let scope = self
+            .policy_enforcer
+            .access_scope_with(
+                ctx,
+                &resources::WEBHOOK,
+                actions::GET,
+                Some(id),
+                &AccessRequest::new()
+                    .resource_property(pep_properties::OWNER_TENANT_ID, webhook.tenant_id)
+                    .require_constraints(false),
+            )
+            .await?;
+
+        if scope.is_unconstrained() {
+            Ok(webhook)
+        } else {
+            self.repo
+                .get(&conn, &scope, id)
+                .await?
+                .ok_or_else(|| DomainError::not_found("Webhook", id))
+        }
+    }
+
+    #[instrument(skip(self, ctx, query))]
+    pub async fn list_webhooks_page(
+        &self,
+        ctx: &SecurityContext,
+        query: &ODataQuery,
+    ) -> Result<Page<Webhook>, DomainError> {
+        debug!("Listing webhooks with cursor pagination");
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+
+        let scope = self
+            .policy_enforcer
+            .access_scope(ctx, &resources::WEBHOOK, actions::LIST, None)
+            .await?;
+
+        self.repo.list_page(&conn, &scope, query).await
+    }
+
+    /// Register a webhook. The returned model carries the signing secret,
+    /// which is generated when the request does not provide one.
+    #[instrument(skip(self, ctx, new_webhook), fields(tenant_id = %new_webhook.tenant_id))]
+    pub async fn create_webhook(
+        &self,
+        ctx: &SecurityContext,
+        new_webhook: NewWebhook,
+    ) -> Result<Webhook, DomainError> {
+        info!("Creating new webhook");
+
+        let url = self.targets.check_url(&new_webhook.url)?;
+
+        let mut event_types = Vec::with_capacity(new_webhook.event_types.len());
+        for event_type in new_webhook.event_types {
+            if !event_types.contains(&event_type) {
+                event_types.push(event_type);
+            }
+        }
+        if event_types.is_empty() {
+            return Err(DomainError::validation(
+                "event_types",
+                "at least one event type is required",
+            ));
+        }
+
+        let secret = match new_webhook.secret {
+            Some(secret) if secret.len() < MIN_SECRET_LEN => {
+                return Err(DomainError::validation(
+                    "secret",
+                    format!("must be at least {MIN_SECRET_LEN} characters"),
+                ));
+            }
+            Some(secret) => secret,
+            None => generate_secret(),
+        };
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+
+        let tenant_id = new_webhook.tenant_id;
+        let scope = self
+            .policy_enforcer
+            .access_scope_with(
+                ctx,
+                &resources::WEBHOOK,
+                actions::CREATE,
+                None,
+                &AccessRequest::new().resource_property(pep_properties::OWNER_TENANT_ID, tenant_id),
+            )
+            .await?;
+
+        let webhook = Webhook {
+            id: Uuid::now_v7(),
+            tenant_id,
+            url: url.into(),
+            secret,
+            event_types,
+            created_at: OffsetDateTime::now_utc(),
+        };
+
+        let webhook = self.repo.create(&conn, &scope, webhook).await?;
+
+        info!("Successfully created webhook with id={}", webhook.id);
+        Ok(webhook)
+    }
+
+    #[instrument(skip(self, ctx), fields(webhook_id = %id))]
+    pub async fn delete_webhook(&self, ctx: &SecurityContext, id: Uuid) -> Result<(), DomainError> {
+        info!("Deleting webhook");
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+
+        // Prefetch: load webhook to extract owner_tenant_id for PDP.
+        // Narrow scope + WHERE constraint provides TOCTOU protection.
+        let prefetch_scope = AccessScope::allow_all().with_intent(ScopeIntent::Read);
+        let prefetched = self
+            .repo
+            .get(&conn, &prefetch_scope, id)
+            .await?
+            .ok_or_else(|| DomainError::not_found("Webhook", id))?;
+
+        let scope = self
+            .policy_enforcer
+            .access_scope_with(
+                ctx,
+                &resources::WEBHOOK,
+                actions::DELETE,
+                Some(id),
+                &AccessRequest::new()
+                    .resource_property(pep_properties::OWNER_TENANT_ID, prefetched.tenant_id),
+            )
+            .await?;
+
+        if !self.repo.delete(&conn, &scope, id).await? {
+            return Err(DomainError::not_found("Webhook", id));
+        }
+
+        info!("Successfully deleted webhook");
+        Ok(())
+    }
+
+    /// List recorded delivery attempts of a webhook, newest first.
+    ///
+    /// Access follows the webhook: a caller who may read the webhook may read
+    /// its delivery log.
+    #[instrument(skip(self, ctx, query), fields(webhook_id = %webhook_id))]
+    pub async fn list_deliveries_page(
+        &self,
+        ctx: &SecurityContext,
+        webhook_id: Uuid,
+        query: &ODataQuery,
+    ) -> Result<Page<WebhookDelivery>, DomainError> {
+        debug!("Listing webhook deliveries");
+
+        let webhook = self.get_webhook(ctx, webhook_id).await?;
+
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let scope = AccessScope::for_tenant(webhook.tenant_id).with_intent(ScopeIntent::Read);
+
+        self.repo
+            .list_deliveries_page(&conn, &scope, webhook_id, query)
+            .await
+    }
+}
+
+/// Random 256-bit signing secret, hex-encoded.
+fn generate_secret() -> String {
+    let bytes: [u8; 32] = rand::rng().random();
+    format!("whsec_{}", hex::encode(bytes))
+}
@@ -0,0 +1,120 @@
+//! Per-tenant overrides of the service limits.
+//!
+//! A tenant without a `tenant_settings` row uses the global [`ServiceConfig`]
+//! values. Overrides may tighten the page size and display name limits but
+//! never exceed the global ones; `max_users` is a quota and may be set freely.
+//!
+//! [`ServiceConfig`]: crate::domain::service::ServiceConfig
+
+use modkit_macros::domain_model;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::domain::error::DomainError;
+
+/// Limits a tenant may override. `None` falls back to the global value.
+#[domain_model]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TenantLimitOverrides {
+    /// Maximum number of users in the tenant.
+    pub max_users: Option<u32>,
+    /// Largest page returned by list endpoints.
+    pub max_page_size: Option<u32>,
+    /// Longest accepted display name.
+    pub max_display_name_length: Option<u32>,
+}
+
+/// Settings of one tenant.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TenantSettings {
+    pub tenant_id: Uuid,
+    pub limits: TenantLimitOverrides,
+    /// `None` if the settings were never written.
+    pub updated_at: Option<OffsetDateTime>,
+}
+
+/// Effective limits of a tenant: global values merged with its overrides.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TenantLimits {
+    /// `None` means unlimited.
+    pub max_users: Option<u32>,
+    pub max_page_size: u32,
+    pub max_display_name_length: usize,
+}
+
+impl TenantLimitOverrides {
+    /// Check the overrides against the global limits.
+    ///
+    /// # Errors
+    /// `Validation` if a value is zero or exceeds the global limit.
+    pub fn validate(&self, global: &TenantLimits) -> Result<(), DomainError> {
+        let checks = [
+            ("max_users", self.max_users, None),
+            (
+                "max_page_size",
+                self.max_page_size,
+                Some(u64::from(global.max_page_size)),
+            ),
+            (
+                "max_display_name_length",
+                self.max_display_name_length,
+                Some(u64::try_from(global.max_display_name_length).unwrap_or(u64::MAX)),
+            ),
+        ];
+        for (field, value, ceiling) in checks {
+            let Some(value) = value else { continue };
+            if value == 0 {
+                return Err(DomainError::validation(field, "must be greater than 0"));
+            }
+            if let Some(ceiling) = ceiling
+                && u64::from(value) > ceiling
+            {
+                return Err(DomainError::validation(
+                    field,
+                    format!("must not exceed the global limit of {ceiling}"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TenantLimits {
+    /// Apply `overrides` on top of these (global) limits.
+    ///
+    /// Page size and display name overrides are capped at the global values,
+    /// so lowering a global limit also applies to tenants that overrode it.
+    #[must_use]
+    pub fn with_overrides(self, overrides: &TenantLimitOverrides) -> Self {
+        Self {
+            max_users: overrides.max_users.or(self.max_users),
+            max_page_size: overrides
+                .max_page_size
+                .map_or(self.max_page_size, |v| v.min(self.max_page_size)),
+            max_display_name_length: overrides.max_display_name_length.map_or(
+                self.max_display_name_length,
+                |v| {
+                    usize::try_from(v)
+                        .unwrap_or(usize::MAX)
+                        .min(self.max_display_name_length)
+                },
+            ),
+        }
+    }
+
+    /// Page limit to request from the repository.
+    ///
+    /// Returns `requested` unless it, or the default page size used in its
+    /// absence, exceeds the tenant's `max_page_size`.
+    #[must_use]
+    pub fn page_limit(&self, requested: Option<u64>, default_page_size: u32) -> Option<u64> {
+        let max = u64::from(self.max_page_size);
+        match requested {
+            Some(limit) => Some(limit.min(max)),
+            None if u64::from(default_page_size) > max => Some(max),
+            None => None,
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use modkit_macros::domain_model;
+use modkit_security::{AccessScope, ScopeIntent};
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc3339;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+use url::Url;
+use uuid::Uuid;
+
+use super::signing::{self, EVENT_ID_HEADER, EVENT_TYPE_HEADER, SIGNATURE_HEADER};
+use super::{
+    DeliveryStatus, TargetPolicy, TargetRejection, Webhook, WebhookDelivery, WebhookEventType,
+};
+use crate::domain::error::DomainError;
+use crate::domain::events::UserDomainEvent;
+use crate::domain::ports::{EventPublisher, WebhookSender};
+use crate::domain::repos::WebhooksRepository;
+use crate::domain::service::DbProvider;
+
+/// Exponential backoff between delivery attempts.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts per delivery, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the second attempt; doubled for every further attempt.
+    pub initial_backoff: Duration,
+    /// Upper bound for a single delay.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay after the failed attempt number `attempt` (1-based).
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Event publisher that queues user events for webhook delivery.
+///
+/// Publishing never blocks the request path: when the queue is full the event
+/// is dropped and a warning is logged.
+#[domain_model]
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    events: mpsc::Sender<UserDomainEvent>,
+}
+
+impl WebhookDispatcher {
+    /// Create a dispatcher and the receiving end for [`WebhookWorker::run`].
+    #[must_use]
+    pub fn channel(capacity: usize) -> (Self, mpsc::Receiver<UserDomainEvent>) {
+        let (events, rx) = mpsc::channel(capacity.max(1));
+        (Self { events }, rx)
+    }
+}
+
+impl EventPublisher<UserDomainEvent> for WebhookDispatcher {
+    fn publish(&self, event: &UserDomainEvent) {
+        match self.events.try_send(event.clone()) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                warn!(user_id = %event.user_id(), "webhook queue is full; dropping event");
+            }
+            Err(TrySendError::Closed(_)) => {
+                debug!("webhook worker is stopped; dropping event");
+            }
+        }
+    }
+}
+
+/// Result of a single HTTP attempt, before it is classified and recorded.
+struct AttemptOutcome {
+    response_code: Option<u16>,
+    latency: Duration,
+    error: Option<String>,
+    retryable: bool,
+}
+
+impl AttemptOutcome {
+    fn rejected(error: String, retryable: bool) -> Self {
+        Self {
+            response_code: None,
+            latency: Duration::ZERO,
+            error: Some(error),
+            retryable,
+        }
+    }
+}
+
+/// Background worker that delivers queued events to subscribed webhooks.
+///
+/// Runs with a tenant-wide system scope derived from the event itself; the
+/// subscriptions it reads were authorized when they were created.
+#[domain_model]
+pub struct WebhookWorker<R: WebhooksRepository> {
+    db: Arc<DbProvider>,
+    repo: Arc<R>,
+    sender: Arc<dyn WebhookSender>,
+    targets: TargetPolicy,
+    retry: RetryPolicy,
+}
+
+impl<R: WebhooksRepository + 'static> WebhookWorker<R> {
+    pub fn new(
+        db: Arc<DbProvider>,
+        repo: Arc<R>,
+        sender: Arc<dyn WebhookSender>,
+        targets: TargetPolicy,
+        retry: RetryPolicy,
+    ) -> Self {
+        Self {
+            db,
+            repo,
+            sender,
+            targets,
+            retry,
+        }
+    }
+
+    /// Consume queued events until every [`WebhookDispatcher`] is dropped or
+    /// `cancel` fires. Cancellation also stops deliveries still in flight.
+    pub async fn run(
+        self: Arc<Self>,
+        mut events: mpsc::Receiver<UserDomainEvent>,
+        cancel: CancellationToken,
+    ) {
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => {
+                    info!("webhook delivery worker cancelled");
+                    return;
+                }
+                event = events.recv() => match event {
+                    Some(event) => self.dispatch(&event, &cancel).await,
+                    None => break,
+                },
+            }
+        }
+        info!("webhook queue closed; delivery worker stopped");
+    }
+
+    /// Start one delivery task per webhook of the event's tenant that
+    /// subscribes to the event type; the tasks stop when `cancel` fires.
+    pub async fn dispatch(self: &Arc<Self>, event: &UserDomainEvent, cancel: &CancellationToken) {
+        let event_type = WebhookEventType::of(event);
+        let webhooks = match self.subscriptions(event.tenant_id()).await {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                warn!(tenant_id = %event.tenant_id(), error = %e, "failed to load webhooks");
+                return;
+            }
+        };
+
+        let event_id = Uuid::now_v7();
+        let body = envelope(event_id, event);
+        for webhook in webhooks.into_iter().filter(|w| w.subscribes_to(event_type)) {
+            let worker = Arc::clone(self);
+            let body = body.clone();
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                tokio::select! {
+                    () = cancel.cancelled() => {
+                        debug!(webhook_id = %webhook.id, %event_id, "webhook delivery cancelled");
+                    }
+                    _ = worker.deliver(&webhook, event_id, event_type, &body) => {}
+                }
+            });
+        }
+    }
+
+    /// Deliver `body` to `webhook`, retrying transient failures, and record
+    /// every attempt. Returns the status of the last attempt.
+    pub async fn deliver(
+        &self,
+        webhook: &Webhook,
+        event_id: Uuid,
+        event_type: WebhookEventType,
+        body: &str,
+    ) -> DeliveryStatus {
+        let mut attempt = 1;
+        loop {
+            let attempted_at = OffsetDateTime::now_utc();
+            let outcome = self.attempt(webhook, event_id, event_type, body).await;
+
+            let status = if outcome.error.is_none() {
+                DeliveryStatus::Succeeded
+            } else if outcome.retryable && attempt < self.retry.max_attempts {
+                DeliveryStatus::Retrying
+            } else {
+                DeliveryStatus::Failed
+            };
+
+            self.record(WebhookDelivery {
+                id: Uuid::now_v7(),
+                tenant_id: webhook.tenant_id,
+                webhook_id: webhook.id,
+                event_id,
+                event_type,
+                attempt,
+                status,
+                response_code: outcome.response_code,
+                latency_ms: u64::try_from(outcome.latency.as_millis()).unwrap_or(u64::MAX),
+                error: outcome.error,
+                attempted_at,
+            })
+            .await;
+
+            if status != DeliveryStatus::Retrying {
+                debug!(webhook_id = %webhook.id, %event_id, attempt, status = status.as_str(), "webhook delivery finished");
+                return status;
+            }
+            tokio::time::sleep(self.retry.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    async fn attempt(
+        &self,
+        webhook: &Webhook,
+        event_id: Uuid,
+        event_type: WebhookEventType,
+        body: &str,
+    ) -> AttemptOutcome {
+        let url = match Url::parse(&webhook.url) {
+            Ok(url) => url,
+            Err(e) => return AttemptOutcome::rejected(format!("invalid target URL: {e}"), false),
+        };
+        match self.targets.check_resolved(&url).await {
+            Ok(()) => {}
+            Err(e @ TargetRejection::Unresolved(_)) => {
+                return AttemptOutcome::rejected(e.to_string(), true);
+            }
+            Err(e @ TargetRejection::Denied(_)) => {
+                warn!(webhook_id = %webhook.id, error = %e, "webhook target rejected");
+                return AttemptOutcome::rejected(e.to_string(), false);
+            }
+        }
+
+        let timestamp = OffsetDateTime::now_utc().unix_timestamp();
+        let headers = [
+            ("content-type", "application/json".to_owned()),
+            (
+                SIGNATURE_HEADER,
+                signing::sign(&webhook.secret, timestamp, body.as_bytes()),
+            ),
+            (EVENT_ID_HEADER, event_id.to_string()),
+            (EVENT_TYPE_HEADER, event_type.as_str().to_owned()),
+        ];
+
+        let started = Instant::now();
+        let result = self
+            .sender
+            .post(url.as_str(), &headers, body.to_owned())
+            .await;
+        let latency = started.elapsed();
+
+        match result {
+            Ok(code) if (200..300).contains(&code) => AttemptOutcome {
+                response_code: Some(code),
+                latency,
+                error: None,
+                retryable: false,
+            },
+            Ok(code) => AttemptOutcome {
+                response_code: Some(code),
+                latency,
+                error: Some(format!("HTTP {code}")),
+                retryable: code >= 500 || code == 408 || code == 429,
+            },
+            Err(e) => AttemptOutcome {
+                response_code: None,
+                latency,
+                error: Some(format!("{e:#}")),
+                retryable: true,
+            },
+        }
+    }
+
+    async fn subscriptions(&self, tenant_id: Uuid) -> Result<Vec<Webhook>, DomainError> {
+        let conn = self.db.conn().map_err(DomainError::from)?;
+        let scope = AccessScope::for_tenant(tenant_id).with_intent(ScopeIntent::Read);
+        self.repo.list_all(&conn, &scope).await
+    }
+
+    async fn record(&self, delivery: WebhookDelivery) {
+        let webhook_id = delivery.webhook_id;
+        let scope = AccessScope::for_tenant(delivery.tenant_id);
+        let result = match self.db.conn() {
+            Ok(conn) => self.repo.record_delivery(&conn, &scope, delivery).await,
+            Err(e) => Err(DomainError::from(e)),
+        };
+        if let Err(e) = result {
+            warn!(%webhook_id, error = %e, "failed to record webhook delivery");
+        }
+    }
+}
+
+/// JSON envelope POSTed to subscribers.
+fn envelope(event_id: Uuid, event: &UserDomainEvent) -> String {
+    serde_json::json!({
+        "id": event_id,
+        "type": WebhookEventType::of(event).as_str(),
+        "tenant_id": event.tenant_id(),
+        "occurred_at": event.at().format(&Rfc3339).unwrap_or_default(),
+        "data": { "user_id": event.user_id() },
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_and_is_capped() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1000),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(4), Duration::from_millis(800));
+        assert_eq!(policy.backoff(5), Duration::from_millis(1000));
+        assert_eq!(policy.backoff(64), Duration::from_millis(1000));
+    }
+}
@@ -0,0 +1,154 @@
+//! Outbound webhooks for user lifecycle events.
+//!
+//! Tenants register HTTPS endpoints and the event types they want. Every
+//! published [`UserDomainEvent`] is queued on the [`WebhookDispatcher`] and a
+//! background [`WebhookWorker`] POSTs a signed JSON envelope to each matching
+//! subscription, retrying transient failures with exponential backoff.
+//!
+//! - `signing` - HMAC-SHA256 signature header (`t=<unix>,v1=<hex>`)
+//! - `target` - SSRF guard for target URLs (denied CIDRs, allowed hosts)
+//! - `delivery` - queue, worker and retry policy
+//!
+//! Every attempt is recorded as a [`WebhookDelivery`] row so tenants can
+//! inspect status codes, latencies and errors.
+
+use modkit_macros::domain_model;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::domain::events::UserDomainEvent;
+
+mod delivery;
+pub mod signing;
+mod target;
+
+pub use delivery::{RetryPolicy, WebhookDispatcher, WebhookWorker};
+pub use target::{DEFAULT_DENIED_CIDRS, TargetPolicy, TargetRejection};
+
+#[cfg(test)]
+mod tests_delivery;
+
+/// User lifecycle event types a webhook can subscribe to.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEventType {
+    UserCreated,
+    UserUpdated,
+    UserDeleted,
+}
+
+impl WebhookEventType {
+    /// All event types, in wire order.
+    pub const ALL: [Self; 3] = [Self::UserCreated, Self::UserUpdated, Self::UserDeleted];
+
+    /// Wire name used in the envelope `type` field and in subscriptions.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::UserCreated => "user.created",
+            Self::UserUpdated => "user.updated",
+            Self::UserDeleted => "user.deleted",
+        }
+    }
+
+    /// Parse a wire name; returns `None` for unknown event types.
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.as_str() == value)
+    }
+
+    /// Event type of a domain event.
+    #[must_use]
+    pub fn of(event: &UserDomainEvent) -> Self {
+        match event {
+            UserDomainEvent::Created { .. } => Self::UserCreated,
+            UserDomainEvent::Updated { .. } => Self::UserUpdated,
+            UserDomainEvent::Deleted { .. } => Self::UserDeleted,
+        }
+    }
+}
+
+/// A tenant's webhook subscription.
+///
+/// `secret` signs every delivery; it is only returned to the client once,
+/// when the subscription is created.
+#[domain_model(redact(secret))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub created_at: OffsetDateTime,
+}
+
+impl Webhook {
+    #[must_use]
+    pub fn subscribes_to(&self, event_type: WebhookEventType) -> bool {
+        self.event_types.contains(&event_type)
+    }
+}
+
+/// Data for registering a webhook. A secret is generated when `secret` is `None`.
+#[domain_model(redact(secret))]
+#[derive(Clone)]
+pub struct NewWebhook {
+    pub tenant_id: Uuid,
+    pub url: String,
+    pub secret: Option<String>,
+    pub event_types: Vec<WebhookEventType>,
+}
+
+/// Outcome of a single delivery attempt.
+#[domain_model]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// The receiver answered with a 2xx status.
+    Succeeded,
+    /// The attempt failed and another attempt is scheduled.
+    Retrying,
+    /// The attempt failed and no further attempts will be made.
+    Failed,
+}
+
+impl DeliveryStatus {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Retrying => "retrying",
+            Self::Failed => "failed",
+        }
+    }
+
+    #[must_use]
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "succeeded" => Some(Self::Succeeded),
+            "retrying" => Some(Self::Retrying),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+}
+
+/// A recorded delivery attempt.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub webhook_id: Uuid,
+    /// Envelope ID; identical across retries of the same event.
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    /// 1-based attempt number.
+    pub attempt: u32,
+    pub status: DeliveryStatus,
+    /// HTTP status returned by the receiver, if a response was received.
+    pub response_code: Option<u16>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+    pub attempted_at: OffsetDateTime,
+}
@@ -0,0 +1,296 @@
+//! SSRF protection for webhook target URLs.
+//!
+//! Targets are checked twice: when a subscription is registered (scheme, host
+//! and literal IP addresses) and again before every delivery against the
+//! addresses the host currently resolves to, so a public DNS name cannot be
+//! pointed at an internal service after registration. The delivery client
+//! resolves names through [`TargetPolicy::resolve_host`] as well, so it only
+//! ever connects to addresses that passed the check.
+
+use std::net::IpAddr;
+
+use ipnet::IpNet;
+use modkit_macros::domain_model;
+use url::{Host, Url};
+
+use crate::domain::error::DomainError;
+
+/// Ranges denied by default: loopback, private, link-local, CGNAT,
+/// benchmarking, multicast, reserved and unspecified addresses for both IPv4
+/// and IPv6, plus the NAT64 and 6to4 prefixes that embed an IPv4 address.
+pub const DEFAULT_DENIED_CIDRS: &[&str] = &[
+    "0.0.0.0/8",
+    "10.0.0.0/8",
+    "100.64.0.0/10",
+    "127.0.0.0/8",
+    "169.254.0.0/16",
+    "172.16.0.0/12",
+    "192.168.0.0/16",
+    "198.18.0.0/15",
+    "224.0.0.0/4",
+    "240.0.0.0/4",
+    "::/128",
+    "::1/128",
+    "64:ff9b::/96",
+    "2002::/16",
+    "fc00::/7",
+    "fe80::/10",
+    "ff00::/8",
+];
+
+/// Why a delivery target was rejected right before sending.
+#[domain_model]
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum TargetRejection {
+    /// DNS resolution failed; may succeed on a later attempt.
+    #[error("failed to resolve {0}")]
+    Unresolved(String),
+    /// The target is not allowed by the policy.
+    #[error("{0}")]
+    Denied(String),
+}
+
+/// Decides which webhook target URLs may be called.
+#[domain_model]
+#[derive(Debug, Clone)]
+pub struct TargetPolicy {
+    denied: Vec<IpNet>,
+    allowed_hosts: Vec<String>,
+    allow_insecure_http: bool,
+}
+
+impl Default for TargetPolicy {
+    fn default() -> Self {
+        Self {
+            denied: DEFAULT_DENIED_CIDRS
+                .iter()
+                .filter_map(|cidr| cidr.parse().ok())
+                .collect(),
+            allowed_hosts: Vec::new(),
+            allow_insecure_http: false,
+        }
+    }
+}
+
+impl TargetPolicy {
+    /// Build a policy from configuration.
+    ///
+    /// Hosts in `allowed_hosts` bypass the address checks (compared
+    /// case-insensitively against the URL host, IPs in canonical form).
+    ///
+    /// # Errors
+    /// Returns a message naming the first entry of `denied_cidrs` that is not
+    /// a valid CIDR.
+    pub fn new(
+        denied_cidrs: &[String],
+        allowed_hosts: &[String],
+        allow_insecure_http: bool,
+    ) -> Result<Self, String> {
+        let denied = denied_cidrs
+            .iter()
+            .map(|cidr| {
+                cidr.parse::<IpNet>()
+                    .map_err(|e| format!("invalid denied CIDR '{cidr}': {e}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            denied,
+            allowed_hosts: allowed_hosts
+                .iter()
+                .map(|h| h.trim().to_ascii_lowercase())
+                .collect(),
+            allow_insecure_http,
+        })
+    }
+
+    /// Validate a target URL at registration time.
+    ///
+    /// # Errors
+    /// Returns `DomainError::Validation` on field `url` when the URL is not
+    /// absolute HTTPS (or HTTP if allowed), carries credentials, or names a
+    /// denied host or address.
+    pub fn check_url(&self, raw: &str) -> Result<Url, DomainError> {
+        let invalid = |msg: &str| DomainError::validation("url", msg);
+
+        let url = Url::parse(raw).map_err(|e| DomainError::validation("url", e.to_string()))?;
+        match url.scheme() {
+            "https" => {}
+            "http" if self.allow_insecure_http => {}
+            _ => return Err(invalid("must use https")),
+        }
+        if !url.username().is_empty() || url.password().is_some() {
+            return Err(invalid("must not contain credentials"));
+        }
+        let host = url.host().ok_or_else(|| invalid("must have a host"))?;
+        if self.is_allowed_host(&host) {
+            return Ok(url);
+        }
+        match host {
+            Host::Ipv4(ip) => self.check_ip(IpAddr::V4(ip)),
+            Host::Ipv6(ip) => self.check_ip(IpAddr::V6(ip)),
+            Host::Domain(name) => {
+                let name = name.trim_end_matches('.').to_ascii_lowercase();
+                if name == "localhost" || name.ends_with(".localhost") {
+                    Err("host is not allowed".to_owned())
+                } else {
+                    Ok(())
+                }
+            }
+        }
+        .map_err(|msg| invalid(&msg))?;
+        Ok(url)
+    }
+
+    /// Check the addresses `url` resolves to right before a delivery.
+    ///
+    /// # Errors
+    /// Returns [`TargetRejection::Unresolved`] when the host cannot be resolved
+    /// and [`TargetRejection::Denied`] when any resolved address is denied.
+    pub async fn check_resolved(&self, url: &Url) -> Result<(), TargetRejection> {
+        let host = url
+            .host()
+            .ok_or_else(|| TargetRejection::Denied("target has no host".to_owned()))?;
+        if self.is_allowed_host(&host) {
+            return Ok(());
+        }
+        match host {
+            Host::Ipv4(ip) => self
+                .check_ip(IpAddr::V4(ip))
+                .map_err(TargetRejection::Denied),
+            Host::Ipv6(ip) => self
+                .check_ip(IpAddr::V6(ip))
+                .map_err(TargetRejection::Denied),
+            Host::Domain(name) => self.resolve_host(name).await.map(drop),
+        }
+    }
+
+    /// Resolve a domain name and return its addresses if all of them are
+    /// allowed. Allowed hosts are resolved without checks.
+    ///
+    /// # Errors
+    /// Returns [`TargetRejection::Unresolved`] when the host cannot be resolved
+    /// and [`TargetRejection::Denied`] when any resolved address is denied.
+    pub async fn resolve_host(&self, name: &str) -> Result<Vec<IpAddr>, TargetRejection> {
+        let addrs: Vec<IpAddr> = tokio::net::lookup_host((name, 0))
+            .await
+            .map_err(|e| TargetRejection::Unresolved(format!("{name}: {e}")))?
+            .map(|addr| addr.ip())
+            .collect();
+        if !self.is_allowed_host(&Host::Domain(name)) {
+            addrs
+                .iter()
+                .try_for_each(|ip| self.check_ip(*ip))
+                .map_err(TargetRejection::Denied)?;
+        }
+        Ok(addrs)
+    }
+
+    fn check_ip(&self, ip: IpAddr) -> Result<(), String> {
+        let ip = ip.to_canonical();
+        if self.denied.iter().any(|net| net.contains(&ip)) {
+            return Err(format!("address {ip} is in a denied range"));
+        }
+        Ok(())
+    }
+
+    fn is_allowed_host(&self, host: &Host<&str>) -> bool {
+        let host = match host {
+            Host::Domain(name) => name.trim_end_matches('.').to_ascii_lowercase(),
+            Host::Ipv4(ip) => ip.to_string(),
+            Host::Ipv6(ip) => ip.to_string(),
+        };
+        self.allowed_hosts.contains(&host)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy_rejects_private_targets() {
+        let policy = TargetPolicy::default();
+        for url in [
+            "https://127.0.0.1/hook",
+            "https://10.1.2.3/hook",
+            "https://192.168.0.10/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://[::1]/hook",
+            "https://[::ffff:127.0.0.1]/hook",
+            "https://198.18.0.1/hook",
+            "https://224.0.0.251/hook",
+            "https://240.0.0.1/hook",
+            "https://255.255.255.255/hook",
+            "https://[64:ff9b::a00:1]/hook",
+            "https://[2002:a00:1::]/hook",
+            "https://[ff02::1]/hook",
+            "https://localhost/hook",
+            "https://api.localhost/hook",
+        ] {
+            let err = policy.check_url(url).unwrap_err();
+            assert!(
+                matches!(err, DomainError::Validation { ref field, .. } if field == "url"),
+                "{url} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn default_policy_requires_https_without_credentials() {
+        let policy = TargetPolicy::default();
+        assert!(policy.check_url("http://example.com/hook").is_err());
+        assert!(policy.check_url("ftp://example.com/hook").is_err());
+        assert!(
+            policy
+                .check_url("https://user:pw@example.com/hook")
+                .is_err()
+        );
+        assert!(policy.check_url("https://example.com/hook").is_ok());
+        assert!(policy.check_url("https://8.8.8.8/hook").is_ok());
+        assert!(
+            policy
+                .check_url("https://[2001:4860:4860::8888]/hook")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn allowed_hosts_bypass_denied_ranges() {
+        let policy =
+            TargetPolicy::new(&["127.0.0.0/8".to_owned()], &["127.0.0.1".to_owned()], true)
+                .unwrap();
+        assert!(policy.check_url("http://127.0.0.1:8080/hook").is_ok());
+        assert!(policy.check_url("http://127.0.0.2:8080/hook").is_err());
+    }
+
+    #[test]
+    fn invalid_cidr_is_reported() {
+        let err = TargetPolicy::new(&["10.0.0.0/33".to_owned()], &[], false).unwrap_err();
+        assert!(err.contains("10.0.0.0/33"));
+    }
+
+    #[tokio::test]
+    async fn resolved_addresses_are_checked() {
+        let policy = TargetPolicy::default();
+        let url = Url::parse("https://localhost/hook").unwrap();
+        assert!(matches!(
+            policy.check_resolved(&url).await,
+            Err(TargetRejection::Denied(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn resolve_host_returns_only_vetted_addresses() {
+        assert!(matches!(
+            TargetPolicy::default().resolve_host("localhost").await,
+            Err(TargetRejection::Denied(_))
+        ));
+
+        let policy =
+            TargetPolicy::new(&["127.0.0.0/8".to_owned()], &["localhost".to_owned()], true)
+                .unwrap();
+        let addrs = policy.resolve_host("localhost").await.unwrap();
+        assert!(addrs.iter().any(IpAddr::is_loopback));
+    }
+}
@@ -0,0 +1,321 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use httpmock::HttpMockRequest;
+use httpmock::prelude::*;
+use modkit_db::{DBProvider, DbError};
+use modkit_http::{HttpClient, TransportSecurity};
+use modkit_odata::ODataQuery;
+use modkit_security::AccessScope;
+use time::OffsetDateTime;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use super::signing::{self, EVENT_ID_HEADER, EVENT_TYPE_HEADER, SIGNATURE_HEADER};
+use super::{
+    DeliveryStatus, RetryPolicy, TargetPolicy, Webhook, WebhookDispatcher, WebhookEventType,
+    WebhookWorker,
+};
+use crate::domain::repos::WebhooksRepository;
+use crate::infra::storage::OrmWebhooksRepository;
+use crate::infra::webhooks::{HttpWebhookSender, TargetResolver};
+use crate::test_support::inmem_db;
+
+const SECRET: &str = "test-secret-0123456789";
+const BODY: &str = r#"{"id":"evt","type":"user.created"}"#;
+
+struct Harness {
+    db: Arc<DBProvider<DbError>>,
+    repo: Arc<OrmWebhooksRepository>,
+    worker: WebhookWorker<OrmWebhooksRepository>,
+    webhook: Webhook,
+}
+
+/// Seed one webhook pointing at `url` and build a worker that may call the
+/// local mock server (or not, with `targets = TargetPolicy::default()`).
+async fn harness(url: String, targets: TargetPolicy, max_attempts: u32) -> Harness {
+    let client = webhook_client(targets.clone());
+    harness_with_client(url, targets, max_attempts, client).await
+}
+
+/// Delivery client wired like in the module: no retries or redirects, names
+/// resolved through `resolver_policy`.
+fn webhook_client(resolver_policy: TargetPolicy) -> HttpClient {
+    HttpClient::builder()
+        .timeout(Duration::from_secs(5))
+        .retry(None)
+        .no_redirects()
+        .dns_resolver(Arc::new(TargetResolver::new(resolver_policy)))
+        .transport(TransportSecurity::AllowInsecureHttp)
+        .build()
+        .unwrap()
+}
+
+async fn harness_with_client(
+    url: String,
+    targets: TargetPolicy,
+    max_attempts: u32,
+    client: HttpClient,
+) -> Harness {
+    let db = Arc::new(DBProvider::new(inmem_db().await));
+    let repo = Arc::new(OrmWebhooksRepository::new(
+        crate::domain::service::ServiceConfig::default().limit_cfg(),
+    ));
+
+    let tenant_id = Uuid::new_v4();
+    let webhook = repo
+        .create(
+            &db.conn().unwrap(),
+            &AccessScope::for_tenant(tenant_id),
+            Webhook {
+                id: Uuid::now_v7(),
+                tenant_id,
+                url,
+                secret: SECRET.to_owned(),
+                event_types: vec![WebhookEventType::UserCreated],
+                created_at: OffsetDateTime::now_utc(),
+            },
+        )
+        .await
+        .unwrap();
+
+    let worker = WebhookWorker::new(
+        Arc::clone(&db),
+        Arc::clone(&repo),
+        Arc::new(HttpWebhookSender::new(client)),
+        targets,
+        RetryPolicy {
+            max_attempts,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(5),
+        },
+    );
+
+    Harness {
+        db,
+        repo,
+        worker,
+        webhook,
+    }
+}
+
+fn loopback_policy() -> TargetPolicy {
+    TargetPolicy::new(&["127.0.0.0/8".to_owned()], &["127.0.0.1".to_owned()], true).unwrap()
+}
+
+async fn recorded_statuses(h: &Harness) -> Vec<(u32, DeliveryStatus, Option<u16>)> {
+    let page = h
+        .repo
+        .list_deliveries_page(
+            &h.db.conn().unwrap(),
+            &AccessScope::for_tenant(h.webhook.tenant_id),
+            h.webhook.id,
+            &ODataQuery::default(),
+        )
+        .await
+        .unwrap();
+    let mut rows: Vec<_> = page
+        .items
+        .into_iter()
+        .map(|d| (d.attempt, d.status, d.response_code))
+        .collect();
+    rows.sort_by_key(|(attempt, _, _)| *attempt);
+    rows
+}
+
+fn header(req: &HttpMockRequest, name: &str) -> Option<String> {
+    req.headers_vec()
+        .into_iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v)
+}
+
+#[tokio::test]
+async fn delivery_is_signed_and_recorded() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/hook")
+                .header("content-type", "application/json")
+                .header(EVENT_TYPE_HEADER, "user.created")
+                .is_true(|req: &HttpMockRequest| {
+                    header(req, SIGNATURE_HEADER).is_some_and(|sig| {
+                        signing::verify(SECRET, &sig, &req.body().to_vec()).is_some()
+                    })
+                });
+            then.status(204);
+        })
+        .await;
+
+    let h = harness(server.url("/hook"), loopback_policy(), 3).await;
+    let event_id = Uuid::now_v7();
+    let status = h
+        .worker
+        .deliver(&h.webhook, event_id, WebhookEventType::UserCreated, BODY)
+        .await;
+
+    assert_eq!(status, DeliveryStatus::Succeeded);
+    assert_eq!(mock.calls_async().await, 1);
+    assert_eq!(
+        recorded_statuses(&h).await,
+        vec![(1, DeliveryStatus::Succeeded, Some(204))]
+    );
+}
+
+#[tokio::test]
+async fn event_id_header_is_stable_across_retries() {
+    let server = MockServer::start_async().await;
+    let event_id = Uuid::now_v7();
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST)
+                .path("/hook")
+                .header(EVENT_ID_HEADER, event_id.to_string());
+            then.status(503);
+        })
+        .await;
+
+    let h = harness(server.url("/hook"), loopback_policy(), 2).await;
+    h.worker
+        .deliver(&h.webhook, event_id, WebhookEventType::UserCreated, BODY)
+        .await;
+
+    assert_eq!(mock.calls_async().await, 2);
+}
+
+#[tokio::test]
+async fn server_errors_are_retried_until_attempts_run_out() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hook");
+            then.status(500);
+        })
+        .await;
+
+    let h = harness(server.url("/hook"), loopback_policy(), 3).await;
+    let status = h
+        .worker
+        .deliver(
+            &h.webhook,
+            Uuid::now_v7(),
+            WebhookEventType::UserCreated,
+            BODY,
+        )
+        .await;
+
+    assert_eq!(status, DeliveryStatus::Failed);
+    assert_eq!(mock.calls_async().await, 3);
+    assert_eq!(
+        recorded_statuses(&h).await,
+        vec![
+            (1, DeliveryStatus::Retrying, Some(500)),
+            (2, DeliveryStatus::Retrying, Some(500)),
+            (3, DeliveryStatus::Failed, Some(500)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hook");
+            then.status(400);
+        })
+        .await;
+
+    let h = harness(server.url("/hook"), loopback_policy(), 3).await;
+    let status = h
+        .worker
+        .deliver(
+            &h.webhook,
+            Uuid::now_v7(),
+            WebhookEventType::UserCreated,
+            BODY,
+        )
+        .await;
+
+    assert_eq!(status, DeliveryStatus::Failed);
+    assert_eq!(mock.calls_async().await, 1);
+}
+
+#[tokio::test]
+async fn denied_target_is_never_called() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hook");
+            then.status(204);
+        })
+        .await;
+
+    // Default policy denies loopback, so the stored target is rejected at
+    // delivery time even though it was accepted when seeded.
+    let h = harness(server.url("/hook"), TargetPolicy::default(), 3).await;
+    let status = h
+        .worker
+        .deliver(
+            &h.webhook,
+            Uuid::now_v7(),
+            WebhookEventType::UserCreated,
+            BODY,
+        )
+        .await;
+
+    assert_eq!(status, DeliveryStatus::Failed);
+    assert_eq!(mock.calls_async().await, 0);
+    assert_eq!(
+        recorded_statuses(&h).await,
+        vec![(1, DeliveryStatus::Failed, None)]
+    );
+}
+
+#[tokio::test]
+async fn connection_is_pinned_to_vetted_addresses() {
+    let server = MockServer::start_async().await;
+    let mock = server
+        .mock_async(|when, then| {
+            when.method(POST).path("/hook");
+            then.status(204);
+        })
+        .await;
+
+    // The pre-delivery check passes (nothing is denied), as it would if DNS
+    // answered with a public address first; the client resolves again and
+    // must refuse to connect to loopback.
+    let url = format!("http://localhost:{}/hook", server.port());
+    let permissive = TargetPolicy::new(&[], &[], true).unwrap();
+    let h = harness_with_client(url, permissive, 1, webhook_client(TargetPolicy::default())).await;
+    let status = h
+        .worker
+        .deliver(
+            &h.webhook,
+            Uuid::now_v7(),
+            WebhookEventType::UserCreated,
+            BODY,
+        )
+        .await;
+
+    assert_eq!(status, DeliveryStatus::Failed);
+    assert_eq!(mock.calls_async().await, 0);
+}
+
+#[tokio::test]
+async fn worker_stops_when_cancelled() {
+    let h = harness("https://example.com/hook".to_owned(), loopback_policy(), 1).await;
+    let (_dispatcher, events) = WebhookDispatcher::channel(1);
+    let cancel = CancellationToken::new();
+
+    let run = tokio::spawn(Arc::new(h.worker).run(events, cancel.clone()));
+    cancel.cancel();
+
+    tokio::time::timeout(Duration::from_secs(5), run)
+        .await
+        .expect("worker should stop on cancellation")
+        .unwrap();
+}
@@ -8,3 +8,9 @@ declare_errors! {
     namespace = "errors",
     vis = "pub"
 }
+
+/// Register the catalog's problem types so every `users_info` problem carries
+/// the catalog title and status, whoever builds it.
+pub fn register_problem_types(registry: &modkit::api::ProblemTypeRegistry) {
+    registry.register_catalog(ErrorCode::ALL.iter().map(ErrorCode::def));
+}
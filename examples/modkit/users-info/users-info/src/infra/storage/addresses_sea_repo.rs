@@ -2,14 +2,17 @@ use async_trait::async_trait;
 
 use crate::domain::error::DomainError;
 use crate::domain::repos::AddressesRepository;
-use crate::infra::storage::db::db_err;
+use crate::infra::storage::db::{db_err, odata_err};
 use crate::infra::storage::entity::address::{
     ActiveModel as AddressAM, Column as AddressColumn, Entity as AddressEntity,
 };
+use crate::infra::storage::entity::city::Entity as CityEntity;
+use crate::infra::storage::entity::user::Entity as UserEntity;
 use crate::infra::storage::odata_mapper::AddressODataMapper;
 use modkit_db::odata::{LimitCfg, paginate_odata};
 use modkit_db::secure::{
-    DBRunner, SecureDeleteExt, SecureEntityExt, secure_insert, secure_update_with_scope,
+    DBRunner, ScopeError, SecureDeleteExt, SecureEntityExt, assert_ref_in_scope, secure_insert,
+    secure_update_with_scope,
 };
 use modkit_odata::{ODataQuery, Page, SortDir};
 use modkit_security::AccessScope;
@@ -67,7 +70,7 @@ impl AddressesRepository for OrmAddressesRepository {
             Into::into,
         )
         .await
-        .map_err(db_err)?;
+        .map_err(odata_err)?;
 
         Ok(page)
     }
@@ -167,4 +170,76 @@ impl AddressesRepository for OrmAddressesRepository {
 
         Ok(result.rows_affected)
     }
+
+    async fn list_chunk<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<Address>, DomainError> {
+        let mut cond = sea_orm::Condition::all();
+        if let Some(after) = after {
+            cond = cond.add(Expr::col(AddressColumn::Id).gt(after));
+        }
+        let found = AddressEntity::find()
+            .filter(cond)
+            .secure()
+            .scope_with(scope)
+            .order_by(AddressColumn::Id, sea_orm::Order::Asc)
+            .limit(limit)
+            .all(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_by_ids<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        ids: &[Uuid],
+    ) -> Result<u64, DomainError> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let result = AddressEntity::delete_many()
+            .filter(
+                sea_orm::Condition::all()
+                    .add(Expr::col(AddressColumn::Id).is_in(ids.iter().copied())),
+            )
+            .secure()
+            .scope_with(scope)
+            .exec(conn)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+
+    async fn ensure_references_in_scope<C: DBRunner>(
+        &self,
+        conn: &C,
+        user_scope: &AccessScope,
+        user_id: Uuid,
+        city_scope: &AccessScope,
+        city_id: Uuid,
+    ) -> Result<(), DomainError> {
+        assert_ref_in_scope::<UserEntity>(user_id, user_scope, conn)
+            .await
+            .map_err(|e| reference_err("user_id", e))?;
+        assert_ref_in_scope::<CityEntity>(city_id, city_scope, conn)
+            .await
+            .map_err(|e| reference_err("city_id", e))
+    }
+}
+
+/// Name the request field of a reference that is not in scope.
+fn reference_err(field: &'static str, e: ScopeError) -> DomainError {
+    match e {
+        ScopeError::ReferenceNotInScope { id, .. } => {
+            DomainError::reference_not_in_scope(field, id)
+        }
+        other => db_err(other),
+    }
 }
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 
 use crate::domain::error::DomainError;
 use crate::domain::repos::CitiesRepository;
-use crate::infra::storage::db::db_err;
+use crate::infra::storage::db::{db_err, odata_err};
 use crate::infra::storage::entity::city::{
     ActiveModel as CityAM, Column as CityColumn, Entity as CityEntity,
 };
@@ -10,6 +10,7 @@ use crate::infra::storage::odata_mapper::CityODataMapper;
 use modkit_db::odata::{LimitCfg, paginate_odata};
 use modkit_db::secure::{
     DBRunner, SecureDeleteExt, SecureEntityExt, secure_insert, secure_update_with_scope,
+    validate_insert_scope,
 };
 use modkit_odata::{ODataQuery, Page, SortDir};
 use modkit_security::AccessScope;
@@ -32,6 +33,17 @@ impl OrmCitiesRepository {
     }
 }
 
+fn active_model(city: &City) -> CityAM {
+    CityAM {
+        id: Set(city.id),
+        tenant_id: Set(city.tenant_id),
+        name: Set(city.name.clone()),
+        country: Set(city.country.clone()),
+        created_at: Set(city.created_at),
+        updated_at: Set(city.updated_at),
+    }
+}
+
 #[async_trait]
 impl CitiesRepository for OrmCitiesRepository {
     async fn get<C: DBRunner>(
@@ -67,7 +79,7 @@ impl CitiesRepository for OrmCitiesRepository {
             Into::into,
         )
         .await
-        .map_err(db_err)?;
+        .map_err(odata_err)?;
 
         Ok(page)
     }
@@ -78,14 +90,7 @@ impl CitiesRepository for OrmCitiesRepository {
         scope: &AccessScope,
         city: City,
     ) -> Result<City, DomainError> {
-        let m = CityAM {
-            id: Set(city.id),
-            tenant_id: Set(city.tenant_id),
-            name: Set(city.name.clone()),
-            country: Set(city.country.clone()),
-            created_at: Set(city.created_at),
-            updated_at: Set(city.updated_at),
-        };
+        let m = active_model(&city);
 
         let _ = secure_insert::<CityEntity>(m, scope, conn)
             .await
@@ -99,14 +104,7 @@ impl CitiesRepository for OrmCitiesRepository {
         scope: &AccessScope,
         city: City,
     ) -> Result<City, DomainError> {
-        let m = CityAM {
-            id: Set(city.id),
-            tenant_id: Set(city.tenant_id),
-            name: Set(city.name.clone()),
-            country: Set(city.country.clone()),
-            created_at: Set(city.created_at),
-            updated_at: Set(city.updated_at),
-        };
+        let m = active_model(&city);
 
         let _ = secure_update_with_scope::<CityEntity>(m, scope, city.id, conn)
             .await
@@ -130,4 +128,77 @@ impl CitiesRepository for OrmCitiesRepository {
 
         Ok(result.rows_affected > 0)
     }
+
+    fn insert_in_scope(&self, scope: &AccessScope, city: &City) -> bool {
+        validate_insert_scope(&active_model(city), scope).is_ok()
+    }
+
+    async fn existing_names<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        tenant_id: Uuid,
+        names: &[String],
+    ) -> Result<Vec<String>, DomainError> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+        let found = CityEntity::find()
+            .filter(
+                sea_orm::Condition::all()
+                    .add(Expr::col(CityColumn::TenantId).eq(tenant_id))
+                    .add(Expr::col(CityColumn::Name).is_in(names.iter().cloned())),
+            )
+            .secure()
+            .scope_with(scope)
+            .all(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.into_iter().map(|city| city.name).collect())
+    }
+
+    async fn list_chunk<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<City>, DomainError> {
+        let mut cond = sea_orm::Condition::all();
+        if let Some(after) = after {
+            cond = cond.add(Expr::col(CityColumn::Id).gt(after));
+        }
+        let found = CityEntity::find()
+            .filter(cond)
+            .secure()
+            .scope_with(scope)
+            .order_by(CityColumn::Id, sea_orm::Order::Asc)
+            .limit(limit)
+            .all(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_by_ids<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        ids: &[Uuid],
+    ) -> Result<u64, DomainError> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let result = CityEntity::delete_many()
+            .filter(
+                sea_orm::Condition::all().add(Expr::col(CityColumn::Id).is_in(ids.iter().copied())),
+            )
+            .secure()
+            .scope_with(scope)
+            .exec(conn)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
 }
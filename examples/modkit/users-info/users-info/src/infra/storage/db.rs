@@ -8,3 +8,13 @@ use crate::domain::error::DomainError;
 pub fn db_err(e: impl Display) -> DomainError {
     DomainError::database(e.to_string())
 }
+
+/// Convert an `OData` pagination error: an invalid `$filter` (e.g. `tolower()` on
+/// a non-string field) is a client error, anything else is a database error.
+pub fn odata_err(e: modkit_odata::Error) -> DomainError {
+    match e {
+        modkit_odata::Error::InvalidFilter(message) => DomainError::validation("$filter", message),
+        modkit_odata::Error::InvalidSearch(message) => DomainError::validation("$search", message),
+        other => db_err(other),
+    }
+}
@@ -1,5 +1,8 @@
 pub mod address;
 pub mod city;
+pub mod tenant_settings;
 pub mod user;
+pub mod webhook;
+pub mod webhook_delivery;
 
 pub use user::{ActiveModel, Column, Entity, Model, Relation};
@@ -0,0 +1,27 @@
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Per-tenant limit overrides; one row per tenant, `NULL` keeps the global value.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "tenant_settings")]
+#[secure(
+    tenant_col = "tenant_id",
+    resource_col = "tenant_id",
+    no_owner,
+    no_type
+)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tenant_id: Uuid,
+    pub max_users: Option<i64>,
+    pub max_page_size: Option<i64>,
+    pub max_display_name_length: Option<i64>,
+    pub updated_at: OffsetDateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -5,15 +5,31 @@ use uuid::Uuid;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Scopable)]
 #[sea_orm(table_name = "users")]
-#[secure(tenant_col = "tenant_id", resource_col = "id", no_owner, no_type)]
+#[secure(
+    tenant_col = "tenant_id",
+    resource_col = "id",
+    no_owner,
+    no_type,
+    created_at_col = "created_at",
+    updated_at_col = "updated_at",
+    created_by_col = "created_by",
+    updated_by_col = "updated_by"
+)]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
     pub tenant_id: Uuid,
     pub email: String,
+    pub email_original: Option<String>,
     pub display_name: String,
+    /// Identity-provider subject linked to this user, for self-service lookups.
+    pub external_subject_id: Option<Uuid>,
     pub created_at: OffsetDateTime,
     pub updated_at: OffsetDateTime,
+    /// Subject that created the user; `None` for users created before it was recorded.
+    pub created_by: Option<Uuid>,
+    /// Subject that last updated the user.
+    pub updated_by: Option<Uuid>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -1,3 +1,5 @@
+use crate::domain::tenant_settings::{TenantLimitOverrides, TenantSettings};
+use crate::domain::webhooks::{DeliveryStatus, Webhook, WebhookDelivery, WebhookEventType};
 use crate::infra::storage::entity;
 use users_info_sdk::{Address, City, User};
 
@@ -8,6 +10,7 @@ impl From<entity::user::Model> for User {
             id: e.id,
             tenant_id: e.tenant_id,
             email: e.email,
+            email_original: e.email_original,
             display_name: e.display_name,
             created_at: e.created_at,
             updated_at: e.updated_at,
@@ -22,6 +25,7 @@ impl From<&entity::user::Model> for User {
             id: e.id,
             tenant_id: e.tenant_id,
             email: e.email.clone(),
+            email_original: e.email_original.clone(),
             display_name: e.display_name.clone(),
             created_at: e.created_at,
             updated_at: e.updated_at,
@@ -88,3 +92,71 @@ impl From<&entity::address::Model> for Address {
         }
     }
 }
+
+/// Serialize webhook event types into the comma-separated column format
+#[must_use]
+pub fn event_types_to_column(event_types: &[WebhookEventType]) -> String {
+    event_types
+        .iter()
+        .map(|t| t.as_str())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Convert a webhook database entity to a domain model.
+///
+/// Unknown event type names (e.g. written by a newer version) are skipped.
+impl From<entity::webhook::Model> for Webhook {
+    fn from(e: entity::webhook::Model) -> Self {
+        Self {
+            id: e.id,
+            tenant_id: e.tenant_id,
+            url: e.url,
+            secret: e.secret,
+            event_types: e
+                .event_types
+                .split(',')
+                .filter_map(WebhookEventType::parse)
+                .collect(),
+            created_at: e.created_at,
+        }
+    }
+}
+
+/// Convert a webhook delivery database entity to a domain model.
+impl From<entity::webhook_delivery::Model> for WebhookDelivery {
+    fn from(e: entity::webhook_delivery::Model) -> Self {
+        Self {
+            id: e.id,
+            tenant_id: e.tenant_id,
+            webhook_id: e.webhook_id,
+            event_id: e.event_id,
+            event_type: WebhookEventType::parse(&e.event_type)
+                .unwrap_or(WebhookEventType::UserUpdated),
+            attempt: u32::try_from(e.attempt).unwrap_or_default(),
+            status: DeliveryStatus::parse(&e.status).unwrap_or(DeliveryStatus::Failed),
+            response_code: e.response_code.and_then(|c| u16::try_from(c).ok()),
+            latency_ms: u64::try_from(e.latency_ms).unwrap_or_default(),
+            error: e.error,
+            attempted_at: e.attempted_at,
+        }
+    }
+}
+
+/// Convert a tenant settings database entity to a domain model.
+///
+/// Out-of-range values (never written by this service) are treated as unset.
+impl From<entity::tenant_settings::Model> for TenantSettings {
+    fn from(e: entity::tenant_settings::Model) -> Self {
+        let limit = |v: Option<i64>| v.and_then(|v| u32::try_from(v).ok());
+        Self {
+            tenant_id: e.tenant_id,
+            limits: TenantLimitOverrides {
+                max_users: limit(e.max_users),
+                max_page_size: limit(e.max_page_size),
+                max_display_name_length: limit(e.max_display_name_length),
+            },
+            updated_at: Some(e.updated_at),
+        }
+    }
+}
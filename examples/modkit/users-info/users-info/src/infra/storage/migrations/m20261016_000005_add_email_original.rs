@@ -0,0 +1,56 @@
+//! Keep the raw email input next to the normalized address.
+//!
+//! From this migration on, `users.email` holds the normalized address produced
+//! by the module's email policy, so `uk_users_tenant_email` enforces uniqueness
+//! on the normalized form. `email_original` preserves what the client sent;
+//! existing rows are backfilled with their current value.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        if !manager.has_column("users", "email_original").await? {
+            let sql = match backend {
+                sea_orm::DatabaseBackend::Postgres | sea_orm::DatabaseBackend::MySql => {
+                    "ALTER TABLE users ADD COLUMN email_original VARCHAR(255) NULL;"
+                }
+                sea_orm::DatabaseBackend::Sqlite => {
+                    "ALTER TABLE users ADD COLUMN email_original TEXT NULL;"
+                }
+            };
+            conn.execute_unprepared(sql).await?;
+        }
+
+        conn.execute_unprepared(
+            "UPDATE users SET email_original = email WHERE email_original IS NULL;",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        match backend {
+            // SQLite < 3.35 cannot drop columns; the nullable column is harmless.
+            sea_orm::DatabaseBackend::Sqlite => Ok(()),
+            sea_orm::DatabaseBackend::Postgres | sea_orm::DatabaseBackend::MySql => {
+                if manager.has_column("users", "email_original").await? {
+                    conn.execute_unprepared("ALTER TABLE users DROP COLUMN email_original;")
+                        .await?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! Link users to identity-provider subjects for self-service (`/me`) lookups.
+//!
+//! `external_subject_id` is only needed when subject ids are not user ids.
+//! It is unique across tenants; unlinked users keep it `NULL`.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        if !manager.has_column("users", "external_subject_id").await? {
+            let sql = match backend {
+                sea_orm::DatabaseBackend::Postgres => {
+                    "ALTER TABLE users ADD COLUMN external_subject_id UUID NULL;"
+                }
+                sea_orm::DatabaseBackend::MySql => {
+                    "ALTER TABLE users ADD COLUMN external_subject_id VARCHAR(36) NULL;"
+                }
+                sea_orm::DatabaseBackend::Sqlite => {
+                    "ALTER TABLE users ADD COLUMN external_subject_id TEXT NULL;"
+                }
+            };
+            conn.execute_unprepared(sql).await?;
+        }
+
+        if !manager
+            .has_index("users", "uk_users_external_subject_id")
+            .await?
+        {
+            conn.execute_unprepared(
+                "CREATE UNIQUE INDEX uk_users_external_subject_id ON users(external_subject_id);",
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        match backend {
+            // SQLite < 3.35 cannot drop columns; the nullable column is harmless.
+            sea_orm::DatabaseBackend::Sqlite => {
+                conn.execute_unprepared("DROP INDEX IF EXISTS uk_users_external_subject_id;")
+                    .await?;
+                Ok(())
+            }
+            sea_orm::DatabaseBackend::Postgres => {
+                conn.execute_unprepared(
+                    "DROP INDEX IF EXISTS uk_users_external_subject_id; \
+                     ALTER TABLE users DROP COLUMN IF EXISTS external_subject_id;",
+                )
+                .await?;
+                Ok(())
+            }
+            sea_orm::DatabaseBackend::MySql => {
+                if manager.has_column("users", "external_subject_id").await? {
+                    conn.execute_unprepared(
+                        "ALTER TABLE users DROP INDEX uk_users_external_subject_id, \
+                         DROP COLUMN external_subject_id;",
+                    )
+                    .await?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
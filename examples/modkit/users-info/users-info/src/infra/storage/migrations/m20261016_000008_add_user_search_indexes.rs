@@ -0,0 +1,41 @@
+//! Expression indexes backing `$search` in full-text mode on Postgres.
+//!
+//! Only used when `full_text_search` is enabled; `LIKE` search on the other
+//! backends cannot use an index for `%term%` patterns, so nothing is created there.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == sea_orm::DatabaseBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    "CREATE INDEX IF NOT EXISTS idx_users_display_name_fts \
+                     ON users USING GIN (to_tsvector('simple', display_name)); \
+                     CREATE INDEX IF NOT EXISTS idx_users_email_fts \
+                     ON users USING GIN (to_tsvector('simple', email));",
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() == sea_orm::DatabaseBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    "DROP INDEX IF EXISTS idx_users_display_name_fts; \
+                     DROP INDEX IF EXISTS idx_users_email_fts;",
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+//! Per-tenant overrides of the service limits.
+//!
+//! One row per tenant; a `NULL` column keeps the global value from the
+//! module config.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres => {
+                r"
+CREATE TABLE IF NOT EXISTS tenant_settings (
+    tenant_id UUID PRIMARY KEY NOT NULL,
+    max_users BIGINT NULL,
+    max_page_size BIGINT NULL,
+    max_display_name_length BIGINT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::MySql => {
+                r"
+CREATE TABLE IF NOT EXISTS tenant_settings (
+    tenant_id VARCHAR(36) PRIMARY KEY NOT NULL,
+    max_users BIGINT NULL,
+    max_page_size BIGINT NULL,
+    max_display_name_length BIGINT NULL,
+    updated_at TIMESTAMP NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                r"
+CREATE TABLE IF NOT EXISTS tenant_settings (
+    tenant_id TEXT PRIMARY KEY NOT NULL,
+    max_users INTEGER NULL,
+    max_page_size INTEGER NULL,
+    max_display_name_length INTEGER NULL,
+    updated_at TEXT NOT NULL
+);
+                "
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        conn.execute_unprepared("DROP TABLE IF EXISTS tenant_settings;")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+//! Record who created and last updated each user.
+//!
+//! `created_by`/`updated_by` hold the acting subject id, stamped by the
+//! audited secure writes. Users created before this migration keep them `NULL`.
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+const COLUMNS: [&str; 2] = ["created_by", "updated_by"];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        for column in COLUMNS {
+            if manager.has_column("users", column).await? {
+                continue;
+            }
+            let sql_type = match backend {
+                sea_orm::DatabaseBackend::Postgres => "UUID",
+                sea_orm::DatabaseBackend::MySql => "VARCHAR(36)",
+                sea_orm::DatabaseBackend::Sqlite => "TEXT",
+            };
+            conn.execute_unprepared(&format!(
+                "ALTER TABLE users ADD COLUMN {column} {sql_type} NULL;"
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        // SQLite < 3.35 cannot drop columns; the nullable columns are harmless.
+        if backend == sea_orm::DatabaseBackend::Sqlite {
+            return Ok(());
+        }
+        for column in COLUMNS {
+            if manager.has_column("users", column).await? {
+                conn.execute_unprepared(&format!("ALTER TABLE users DROP COLUMN {column};"))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -4,6 +4,12 @@ mod m20260111_000001_initial;
 mod m20260111_000002_add_tenant_support;
 mod m20260111_000003_add_relationships;
 mod m20260111_000004_add_tenant_to_all_tables;
+mod m20261016_000005_add_email_original;
+mod m20261016_000006_add_webhooks;
+mod m20261016_000007_add_external_subject_id;
+mod m20261016_000008_add_user_search_indexes;
+mod m20261016_000009_add_tenant_settings;
+mod m20261016_000010_add_user_audit_actors;
 
 pub struct Migrator;
 
@@ -15,6 +21,12 @@ impl MigratorTrait for Migrator {
             Box::new(m20260111_000002_add_tenant_support::Migration),
             Box::new(m20260111_000003_add_relationships::Migration),
             Box::new(m20260111_000004_add_tenant_to_all_tables::Migration),
+            Box::new(m20261016_000005_add_email_original::Migration),
+            Box::new(m20261016_000006_add_webhooks::Migration),
+            Box::new(m20261016_000007_add_external_subject_id::Migration),
+            Box::new(m20261016_000008_add_user_search_indexes::Migration),
+            Box::new(m20261016_000009_add_tenant_settings::Migration),
+            Box::new(m20261016_000010_add_user_audit_actors::Migration),
         ]
     }
 }
@@ -3,7 +3,8 @@
 //! ## Architecture
 //!
 //! This module contains ALL `SeaORM`-specific code and database operations:
-//! - `entity/` - `SeaORM` entity definitions (users, cities, addresses)
+//! - `entity/` - `SeaORM` entity definitions (users, cities, addresses, webhooks,
+//!   tenant settings)
 //! - `mapper.rs` - Conversions between `SeaORM` models and SDK contract types
 //! - `odata_mapper.rs` - `OData` filter → `SeaORM` column mappings
 //! - `migrations/` - Database schema migrations
@@ -32,8 +33,12 @@ pub mod odata_mapper;
 mod addresses_sea_repo;
 mod cities_sea_repo;
 mod db;
+mod tenant_settings_sea_repo;
 mod users_sea_repo;
+mod webhooks_sea_repo;
 
 pub use addresses_sea_repo::OrmAddressesRepository;
 pub use cities_sea_repo::OrmCitiesRepository;
+pub use tenant_settings_sea_repo::OrmTenantSettingsRepository;
 pub use users_sea_repo::OrmUsersRepository;
+pub use webhooks_sea_repo::OrmWebhooksRepository;
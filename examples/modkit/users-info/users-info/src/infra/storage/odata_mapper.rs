@@ -10,15 +10,22 @@
 use modkit_db::odata::sea_orm_filter::{
     FieldToColumn, ODataFieldMapping, filter_node_to_condition,
 };
-use modkit_odata::filter::FilterNode;
+use modkit_odata::filter::{FilterNode, FilterOp};
 use sea_orm::Condition;
 
 use crate::infra::storage::entity::{
     Column, Entity, Model,
     address::{Column as AddressColumn, Entity as AddressEntity, Model as AddressModel},
     city::{Column as CityColumn, Entity as CityEntity, Model as CityModel},
+    webhook::{Column as WebhookColumn, Entity as WebhookEntity, Model as WebhookModel},
+    webhook_delivery::{
+        Column as DeliveryColumn, Entity as DeliveryEntity, Model as DeliveryModel,
+    },
+};
+use users_info_sdk::odata::{
+    AddressFilterField, CityFilterField, CityUserFilterField, UserFilterField,
+    WebhookDeliveryFilterField, WebhookFilterField,
 };
-use users_info_sdk::odata::{AddressFilterField, CityFilterField, UserFilterField};
 
 /// Complete `OData` mapper for `users_info`.
 ///
@@ -33,6 +40,7 @@ impl FieldToColumn<UserFilterField> for UserODataMapper {
         match field {
             UserFilterField::Id => Column::Id,
             UserFilterField::Email => Column::Email,
+            UserFilterField::DisplayName => Column::DisplayName,
             UserFilterField::CreatedAt => Column::CreatedAt,
         }
     }
@@ -45,6 +53,9 @@ impl ODataFieldMapping<UserFilterField> for UserODataMapper {
         match field {
             UserFilterField::Id => sea_orm::Value::Uuid(Some(Box::new(model.id))),
             UserFilterField::Email => sea_orm::Value::String(Some(Box::new(model.email.clone()))),
+            UserFilterField::DisplayName => {
+                sea_orm::Value::String(Some(Box::new(model.display_name.clone())))
+            }
             UserFilterField::CreatedAt => {
                 sea_orm::Value::TimeDateTimeWithTimeZone(Some(Box::new(model.created_at)))
             }
@@ -68,6 +79,77 @@ pub fn filter_to_condition(filter: &FilterNode<UserFilterField>) -> Result<Condi
     filter_node_to_condition::<UserFilterField, UserODataMapper>(filter)
 }
 
+/// What a [`CityUserFilterField`] compares: the user or their address.
+#[derive(Debug, Clone, Copy)]
+enum CityUserField {
+    User(UserFilterField),
+    Address(AddressFilterField),
+}
+
+impl From<CityUserFilterField> for CityUserField {
+    fn from(field: CityUserFilterField) -> Self {
+        match field {
+            CityUserFilterField::Email => Self::User(UserFilterField::Email),
+            CityUserFilterField::DisplayName => Self::User(UserFilterField::DisplayName),
+            CityUserFilterField::CreatedAt => Self::User(UserFilterField::CreatedAt),
+            CityUserFilterField::Street => Self::Address(AddressFilterField::Street),
+            CityUserFilterField::PostalCode => Self::Address(AddressFilterField::PostalCode),
+        }
+    }
+}
+
+/// Map a `FilterNode`<CityUserFilterField> to a `SeaORM` Condition on users.
+///
+/// User fields map through [`UserODataMapper`]. Each comparison on an address
+/// field is turned into a condition on the user by `addresses`, which is
+/// expected to match users having such an address in the city.
+pub fn city_user_filter_to_condition(
+    filter: &FilterNode<CityUserFilterField>,
+    addresses: &impl Fn(Condition) -> Condition,
+) -> Result<Condition, String> {
+    match filter {
+        FilterNode::Binary {
+            field,
+            fold,
+            op,
+            value,
+        } => match CityUserField::from(*field) {
+            CityUserField::User(field) => {
+                let node = FilterNode::Binary {
+                    field,
+                    fold: *fold,
+                    op: *op,
+                    value: value.clone(),
+                };
+                filter_node_to_condition::<_, UserODataMapper>(&node)
+            }
+            CityUserField::Address(field) => {
+                let node = FilterNode::Binary {
+                    field,
+                    fold: *fold,
+                    op: *op,
+                    value: value.clone(),
+                };
+                filter_node_to_condition::<_, AddressODataMapper>(&node).map(addresses)
+            }
+        },
+        FilterNode::Composite { op, children } => {
+            let base = match op {
+                FilterOp::And => Condition::all(),
+                FilterOp::Or => Condition::any(),
+                _ => return Err(format!("Invalid composite operator: {op:?}")),
+            };
+            children.iter().try_fold(base, |acc, child| {
+                Ok(acc.add(city_user_filter_to_condition(child, addresses)?))
+            })
+        }
+        FilterNode::Not(inner) => Ok(Condition::all()
+            .add(city_user_filter_to_condition(inner, addresses)?)
+            .not()),
+        FilterNode::JsonPath { .. } => Err("JSON path filters are not supported here".to_owned()),
+    }
+}
+
 /// Complete `OData` mapper for cities.
 pub struct CityODataMapper;
 
@@ -139,3 +221,74 @@ impl ODataFieldMapping<AddressFilterField> for AddressODataMapper {
         }
     }
 }
+
+/// Complete `OData` mapper for webhooks.
+pub struct WebhookODataMapper;
+
+impl FieldToColumn<WebhookFilterField> for WebhookODataMapper {
+    type Column = WebhookColumn;
+
+    fn map_field(field: WebhookFilterField) -> WebhookColumn {
+        match field {
+            WebhookFilterField::Id => WebhookColumn::Id,
+            WebhookFilterField::Url => WebhookColumn::Url,
+            WebhookFilterField::CreatedAt => WebhookColumn::CreatedAt,
+        }
+    }
+}
+
+impl ODataFieldMapping<WebhookFilterField> for WebhookODataMapper {
+    type Entity = WebhookEntity;
+
+    fn extract_cursor_value(model: &WebhookModel, field: WebhookFilterField) -> sea_orm::Value {
+        match field {
+            WebhookFilterField::Id => sea_orm::Value::Uuid(Some(Box::new(model.id))),
+            WebhookFilterField::Url => sea_orm::Value::String(Some(Box::new(model.url.clone()))),
+            WebhookFilterField::CreatedAt => {
+                sea_orm::Value::TimeDateTimeWithTimeZone(Some(Box::new(model.created_at)))
+            }
+        }
+    }
+}
+
+/// Complete `OData` mapper for webhook delivery attempts.
+pub struct WebhookDeliveryODataMapper;
+
+impl FieldToColumn<WebhookDeliveryFilterField> for WebhookDeliveryODataMapper {
+    type Column = DeliveryColumn;
+
+    fn map_field(field: WebhookDeliveryFilterField) -> DeliveryColumn {
+        match field {
+            WebhookDeliveryFilterField::Id => DeliveryColumn::Id,
+            WebhookDeliveryFilterField::EventId => DeliveryColumn::EventId,
+            WebhookDeliveryFilterField::EventType => DeliveryColumn::EventType,
+            WebhookDeliveryFilterField::Status => DeliveryColumn::Status,
+            WebhookDeliveryFilterField::AttemptedAt => DeliveryColumn::AttemptedAt,
+        }
+    }
+}
+
+impl ODataFieldMapping<WebhookDeliveryFilterField> for WebhookDeliveryODataMapper {
+    type Entity = DeliveryEntity;
+
+    fn extract_cursor_value(
+        model: &DeliveryModel,
+        field: WebhookDeliveryFilterField,
+    ) -> sea_orm::Value {
+        match field {
+            WebhookDeliveryFilterField::Id => sea_orm::Value::Uuid(Some(Box::new(model.id))),
+            WebhookDeliveryFilterField::EventId => {
+                sea_orm::Value::Uuid(Some(Box::new(model.event_id)))
+            }
+            WebhookDeliveryFilterField::EventType => {
+                sea_orm::Value::String(Some(Box::new(model.event_type.clone())))
+            }
+            WebhookDeliveryFilterField::Status => {
+                sea_orm::Value::String(Some(Box::new(model.status.clone())))
+            }
+            WebhookDeliveryFilterField::AttemptedAt => {
+                sea_orm::Value::TimeDateTimeWithTimeZone(Some(Box::new(model.attempted_at)))
+            }
+        }
+    }
+}
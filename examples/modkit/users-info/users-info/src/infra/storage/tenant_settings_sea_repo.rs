@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+use crate::domain::repos::TenantSettingsRepository;
+use crate::domain::tenant_settings::TenantSettings;
+use crate::infra::storage::db::db_err;
+use crate::infra::storage::entity::tenant_settings::{
+    ActiveModel as TenantSettingsAM, Column as TenantSettingsColumn, Entity as TenantSettingsEntity,
+};
+use modkit_db::secure::{DBRunner, SecureEntityExt, SecureInsertExt, SecureOnConflict};
+use modkit_security::AccessScope;
+use sea_orm::sea_query::Expr;
+use sea_orm::{EntityTrait, QueryFilter, Set};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// ORM-based implementation of the `TenantSettingsRepository` trait.
+#[derive(Clone, Default)]
+pub struct OrmTenantSettingsRepository;
+
+impl OrmTenantSettingsRepository {
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl TenantSettingsRepository for OrmTenantSettingsRepository {
+    async fn get<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        tenant_id: Uuid,
+    ) -> Result<Option<TenantSettings>, DomainError> {
+        let found = TenantSettingsEntity::find()
+            .filter(
+                sea_orm::Condition::all()
+                    .add(Expr::col(TenantSettingsColumn::TenantId).eq(tenant_id)),
+            )
+            .secure()
+            .scope_with(scope)
+            .one(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.map(Into::into))
+    }
+
+    async fn upsert<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        settings: TenantSettings,
+    ) -> Result<TenantSettings, DomainError> {
+        let limits = settings.limits;
+        let m = TenantSettingsAM {
+            tenant_id: Set(settings.tenant_id),
+            max_users: Set(limits.max_users.map(i64::from)),
+            max_page_size: Set(limits.max_page_size.map(i64::from)),
+            max_display_name_length: Set(limits.max_display_name_length.map(i64::from)),
+            updated_at: Set(settings.updated_at.unwrap_or_else(OffsetDateTime::now_utc)),
+        };
+
+        // Full replacement: every override column is overwritten.
+        let on_conflict =
+            SecureOnConflict::<TenantSettingsEntity>::columns([TenantSettingsColumn::TenantId])
+                .update_columns([
+                    TenantSettingsColumn::MaxUsers,
+                    TenantSettingsColumn::MaxPageSize,
+                    TenantSettingsColumn::MaxDisplayNameLength,
+                    TenantSettingsColumn::UpdatedAt,
+                ])
+                .map_err(db_err)?;
+
+        let _ = TenantSettingsEntity::insert(m.clone())
+            .secure()
+            .scope_with_model(scope, &m)
+            .map_err(db_err)?
+            .on_conflict(on_conflict)
+            .exec(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(settings)
+    }
+}
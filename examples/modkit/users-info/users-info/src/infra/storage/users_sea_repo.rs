@@ -1,31 +1,65 @@
+use std::collections::{HashMap, HashSet};
+
 use async_trait::async_trait;
 
-use crate::infra::storage::db::db_err;
+use crate::infra::storage::db::{db_err, odata_err};
+use crate::infra::storage::entity::address::{Column as AddressColumn, Entity as AddressEntity};
+use crate::infra::storage::entity::city::{Column as CityColumn, Entity as CityEntity};
 use crate::infra::storage::entity::user::{ActiveModel as UserAM, Column, Entity as UserEntity};
-use crate::infra::storage::odata_mapper::UserODataMapper;
+use crate::infra::storage::odata_mapper::{UserODataMapper, city_user_filter_to_condition};
 use crate::{domain::error::DomainError, domain::repos::UsersRepository};
-use modkit_db::odata::{LimitCfg, paginate_odata};
+use modkit_db::odata::{LimitCfg, SearchMode, paginate_odata, paginate_odata_with_search};
 use modkit_db::secure::{
-    DBRunner, SecureDeleteExt, SecureEntityExt, secure_insert, secure_update_with_scope,
+    DBRunner, ScopeError, SecureDeleteExt, SecureEntityExt, scoped_in_subquery,
+    secure_insert_audited, secure_update_with_scope_audited, validate_insert_scope,
 };
+use modkit_odata::filter::convert_expr_to_filter_node;
 use modkit_odata::{ODataQuery, Page, SortDir};
-use modkit_security::AccessScope;
+use modkit_security::{AccessScope, SecurityContext};
 use sea_orm::sea_query::Expr;
-use sea_orm::{EntityTrait, QueryFilter, Set};
-use users_info_sdk::User;
-use users_info_sdk::odata::UserFilterField;
+use sea_orm::{Condition, EntityTrait, NotSet, QueryFilter, Set};
+use users_info_sdk::odata::{CityUserFilterField, UserFilterField, UserSearch};
+use users_info_sdk::{AddressSummary, User, UserWithAddressSummary};
 use uuid::Uuid;
 
 /// ORM-based implementation of the `UsersRepository` trait.
 #[derive(Clone)]
 pub struct OrmUsersRepository {
     limit_cfg: LimitCfg,
+    search_mode: SearchMode,
 }
 
 impl OrmUsersRepository {
     #[must_use]
     pub fn new(limit_cfg: LimitCfg) -> Self {
-        Self { limit_cfg }
+        Self {
+            limit_cfg,
+            search_mode: SearchMode::default(),
+        }
+    }
+
+    /// How `$search` is matched; `SearchMode::Like` by default.
+    #[must_use]
+    pub fn with_search_mode(mut self, search_mode: SearchMode) -> Self {
+        self.search_mode = search_mode;
+        self
+    }
+}
+
+fn active_model(user: &User) -> UserAM {
+    UserAM {
+        id: Set(user.id),
+        tenant_id: Set(user.tenant_id),
+        email: Set(user.email.clone()),
+        email_original: Set(user.email_original.clone()),
+        display_name: Set(user.display_name.clone()),
+        // Not part of the contract model; never overwritten from it.
+        external_subject_id: NotSet,
+        // Audit columns are stamped by the audited secure writes.
+        created_at: NotSet,
+        updated_at: NotSet,
+        created_by: NotSet,
+        updated_by: NotSet,
     }
 }
 
@@ -55,16 +89,125 @@ impl UsersRepository for OrmUsersRepository {
     ) -> Result<Page<User>, DomainError> {
         let base_query = UserEntity::find().secure().scope_with(scope);
 
+        let page =
+            paginate_odata_with_search::<UserFilterField, UserODataMapper, UserSearch, _, _, _, _>(
+                base_query,
+                conn,
+                query,
+                self.search_mode,
+                ("id", SortDir::Desc),
+                self.limit_cfg,
+                Into::into,
+            )
+            .await
+            .map_err(odata_err)?;
+
+        Ok(page)
+    }
+
+    async fn list_page_with_address_summaries<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        address_scope: &AccessScope,
+        city_scope: &AccessScope,
+        query: &ODataQuery,
+    ) -> Result<Page<UserWithAddressSummary>, DomainError> {
+        let page = self.list_page(conn, scope, query).await?;
+        if page.items.is_empty() {
+            return Ok(page.map_items(Into::into));
+        }
+
+        let user_ids: Vec<Uuid> = page.items.iter().map(|u| u.id).collect();
+        let addresses = AddressEntity::find()
+            .filter(sea_orm::Condition::all().add(Expr::col(AddressColumn::UserId).is_in(user_ids)))
+            .secure()
+            .scope_with(address_scope)
+            .all(conn)
+            .await
+            .map_err(db_err)?;
+
+        let city_ids: HashSet<Uuid> = addresses.iter().map(|a| a.city_id).collect();
+        let city_names: HashMap<Uuid, String> = if city_ids.is_empty() {
+            HashMap::new()
+        } else {
+            CityEntity::find()
+                .filter(sea_orm::Condition::all().add(Expr::col(CityColumn::Id).is_in(city_ids)))
+                .secure()
+                .scope_with(city_scope)
+                .all(conn)
+                .await
+                .map_err(db_err)?
+                .into_iter()
+                .map(|c| (c.id, c.name))
+                .collect()
+        };
+
+        let mut summaries: HashMap<Uuid, AddressSummary> = addresses
+            .into_iter()
+            .map(|a| {
+                let summary = AddressSummary {
+                    address_id: a.id,
+                    city_id: a.city_id,
+                    city_name: city_names.get(&a.city_id).cloned(),
+                };
+                (a.user_id, summary)
+            })
+            .collect();
+
+        Ok(page.map_items(|user| UserWithAddressSummary {
+            address: summaries.remove(&user.id),
+            user,
+        }))
+    }
+
+    async fn list_city_users_page<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        address_scope: &AccessScope,
+        city_id: Uuid,
+        query: &ODataQuery,
+    ) -> Result<Page<User>, DomainError> {
+        // A semi-join rather than a join: rows stay users, so the user id
+        // tiebreaker keeps cursors stable.
+        let in_city = |cond: Condition| {
+            scoped_in_subquery::<AddressEntity, _>(
+                Column::Id,
+                AddressColumn::UserId,
+                address_scope,
+                Condition::all()
+                    .add(Expr::col(AddressColumn::CityId).eq(city_id))
+                    .add(cond),
+            )
+        };
+
+        let mut base_query = UserEntity::find()
+            .secure()
+            .scope_with(scope)
+            .filter(in_city(Condition::all()));
+
+        // Address fields are not user columns: the filter is applied here and
+        // only its hash is left for the cursors.
+        let mut query = query.clone();
+        if let Some(filter) = query.filter.take() {
+            let invalid = |message: String| DomainError::validation("$filter", message);
+            let node = convert_expr_to_filter_node::<CityUserFilterField>(&filter)
+                .map_err(|e| invalid(e.to_string()))?;
+            base_query =
+                base_query.filter(city_user_filter_to_condition(&node, &in_city).map_err(invalid)?);
+        }
+
         let page = paginate_odata::<UserFilterField, UserODataMapper, _, _, _, _>(
             base_query,
             conn,
-            query,
+            &query,
             ("id", SortDir::Desc),
             self.limit_cfg,
             Into::into,
         )
         .await
-        .map_err(db_err)?;
+        .map_err(odata_err)?;
 
         Ok(page)
     }
@@ -73,42 +216,30 @@ impl UsersRepository for OrmUsersRepository {
         &self,
         conn: &C,
         scope: &AccessScope,
+        ctx: &SecurityContext,
         user: User,
     ) -> Result<User, DomainError> {
-        let m = UserAM {
-            id: Set(user.id),
-            tenant_id: Set(user.tenant_id),
-            email: Set(user.email.clone()),
-            display_name: Set(user.display_name.clone()),
-            created_at: Set(user.created_at),
-            updated_at: Set(user.updated_at),
-        };
+        let m = active_model(&user);
 
-        let _ = secure_insert::<UserEntity>(m, scope, conn)
+        let created = secure_insert_audited::<UserEntity>(m, scope, ctx, conn)
             .await
-            .map_err(db_err)?;
-        Ok(user)
+            .map_err(|e| write_err(e, &user.email))?;
+        Ok(created.into())
     }
 
     async fn update<C: DBRunner>(
         &self,
         conn: &C,
         scope: &AccessScope,
+        ctx: &SecurityContext,
         user: User,
     ) -> Result<User, DomainError> {
-        let m = UserAM {
-            id: Set(user.id),
-            tenant_id: Set(user.tenant_id),
-            email: Set(user.email.clone()),
-            display_name: Set(user.display_name.clone()),
-            created_at: Set(user.created_at),
-            updated_at: Set(user.updated_at),
-        };
+        let m = active_model(&user);
 
-        let _ = secure_update_with_scope::<UserEntity>(m, scope, user.id, conn)
+        let updated = secure_update_with_scope_audited::<UserEntity>(m, scope, user.id, ctx, conn)
             .await
-            .map_err(db_err)?;
-        Ok(user)
+            .map_err(|e| write_err(e, &user.email))?;
+        Ok(updated.into())
     }
 
     async fn delete<C: DBRunner>(
@@ -128,6 +259,10 @@ impl UsersRepository for OrmUsersRepository {
         Ok(result.rows_affected > 0)
     }
 
+    fn insert_in_scope(&self, scope: &AccessScope, user: &User) -> bool {
+        validate_insert_scope(&active_model(user), scope).is_ok()
+    }
+
     async fn exists<C: DBRunner>(
         &self,
         conn: &C,
@@ -144,6 +279,16 @@ impl UsersRepository for OrmUsersRepository {
         Ok(found.is_some())
     }
 
+    async fn count<C: DBRunner>(&self, conn: &C, scope: &AccessScope) -> Result<u64, DomainError> {
+        let count = UserEntity::find()
+            .secure()
+            .scope_with(scope)
+            .count(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(count)
+    }
+
     async fn count_by_email<C: DBRunner>(
         &self,
         conn: &C,
@@ -159,4 +304,76 @@ impl UsersRepository for OrmUsersRepository {
             .map_err(db_err)?;
         Ok(count)
     }
+
+    async fn find_by_external_subject<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        subject_id: Uuid,
+    ) -> Result<Option<User>, DomainError> {
+        let found = UserEntity::find()
+            .filter(
+                sea_orm::Condition::all().add(Expr::col(Column::ExternalSubjectId).eq(subject_id)),
+            )
+            .secure()
+            .scope_with(scope)
+            .one(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.map(Into::into))
+    }
+
+    async fn list_chunk<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        after: Option<Uuid>,
+        limit: u64,
+    ) -> Result<Vec<User>, DomainError> {
+        let mut cond = sea_orm::Condition::all();
+        if let Some(after) = after {
+            cond = cond.add(Expr::col(Column::Id).gt(after));
+        }
+        let found = UserEntity::find()
+            .filter(cond)
+            .secure()
+            .scope_with(scope)
+            .order_by(Column::Id, sea_orm::Order::Asc)
+            .limit(limit)
+            .all(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.into_iter().map(Into::into).collect())
+    }
+
+    async fn delete_by_ids<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        ids: &[Uuid],
+    ) -> Result<u64, DomainError> {
+        if ids.is_empty() {
+            return Ok(0);
+        }
+        let result = UserEntity::delete_many()
+            .filter(sea_orm::Condition::all().add(Expr::col(Column::Id).is_in(ids.iter().copied())))
+            .secure()
+            .scope_with(scope)
+            .exec(conn)
+            .await
+            .map_err(db_err)?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+/// A concurrent write can take the email between the service's uniqueness check
+/// and the write; the unique index then reports it.
+fn write_err(e: ScopeError, email: &str) -> DomainError {
+    match e {
+        ScopeError::UniqueViolation { ref constraint, .. } if constraint.contains("email") => {
+            DomainError::email_already_exists(email.to_owned())
+        }
+        other => db_err(other),
+    }
 }
@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+
+use crate::domain::error::DomainError;
+use crate::domain::repos::WebhooksRepository;
+use crate::domain::webhooks::{Webhook, WebhookDelivery};
+use crate::infra::storage::db::{db_err, odata_err};
+use crate::infra::storage::entity::webhook::{
+    ActiveModel as WebhookAM, Column as WebhookColumn, Entity as WebhookEntity,
+};
+use crate::infra::storage::entity::webhook_delivery::{
+    ActiveModel as DeliveryAM, Column as DeliveryColumn, Entity as DeliveryEntity,
+};
+use crate::infra::storage::mapper::event_types_to_column;
+use crate::infra::storage::odata_mapper::{WebhookDeliveryODataMapper, WebhookODataMapper};
+use modkit_db::odata::{LimitCfg, paginate_odata};
+use modkit_db::secure::{DBRunner, SecureDeleteExt, SecureEntityExt, secure_insert};
+use modkit_odata::{ODataQuery, Page, SortDir};
+use modkit_security::AccessScope;
+use sea_orm::sea_query::Expr;
+use sea_orm::{EntityTrait, QueryFilter, Set};
+use users_info_sdk::odata::{WebhookDeliveryFilterField, WebhookFilterField};
+use uuid::Uuid;
+
+/// ORM-based implementation of the `WebhooksRepository` trait.
+#[derive(Clone)]
+pub struct OrmWebhooksRepository {
+    limit_cfg: LimitCfg,
+}
+
+impl OrmWebhooksRepository {
+    #[must_use]
+    pub fn new(limit_cfg: LimitCfg) -> Self {
+        Self { limit_cfg }
+    }
+}
+
+#[async_trait]
+impl WebhooksRepository for OrmWebhooksRepository {
+    async fn get<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        id: Uuid,
+    ) -> Result<Option<Webhook>, DomainError> {
+        let found = WebhookEntity::find()
+            .filter(sea_orm::Condition::all().add(Expr::col(WebhookColumn::Id).eq(id)))
+            .secure()
+            .scope_with(scope)
+            .one(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.map(Into::into))
+    }
+
+    async fn list_page<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        query: &ODataQuery,
+    ) -> Result<Page<Webhook>, DomainError> {
+        let base_query = WebhookEntity::find().secure().scope_with(scope);
+
+        let page = paginate_odata::<WebhookFilterField, WebhookODataMapper, _, _, _, _>(
+            base_query,
+            conn,
+            query,
+            ("id", SortDir::Desc),
+            self.limit_cfg,
+            Into::into,
+        )
+        .await
+        .map_err(odata_err)?;
+
+        Ok(page)
+    }
+
+    async fn list_all<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+    ) -> Result<Vec<Webhook>, DomainError> {
+        let found = WebhookEntity::find()
+            .secure()
+            .scope_with(scope)
+            .all(conn)
+            .await
+            .map_err(db_err)?;
+        Ok(found.into_iter().map(Into::into).collect())
+    }
+
+    async fn create<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        webhook: Webhook,
+    ) -> Result<Webhook, DomainError> {
+        let m = WebhookAM {
+            id: Set(webhook.id),
+            tenant_id: Set(webhook.tenant_id),
+            url: Set(webhook.url.clone()),
+            secret: Set(webhook.secret.clone()),
+            event_types: Set(event_types_to_column(&webhook.event_types)),
+            created_at: Set(webhook.created_at),
+        };
+
+        let _ = secure_insert::<WebhookEntity>(m, scope, conn)
+            .await
+            .map_err(db_err)?;
+        Ok(webhook)
+    }
+
+    async fn delete<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        id: Uuid,
+    ) -> Result<bool, DomainError> {
+        let result = WebhookEntity::delete_many()
+            .filter(sea_orm::Condition::all().add(Expr::col(WebhookColumn::Id).eq(id)))
+            .secure()
+            .scope_with(scope)
+            .exec(conn)
+            .await
+            .map_err(db_err)?;
+
+        if result.rows_affected == 0 {
+            return Ok(false);
+        }
+
+        // Not every backend enforces `ON DELETE CASCADE` (SQLite needs
+        // `PRAGMA foreign_keys`), so drop the delivery log explicitly.
+        let _ = DeliveryEntity::delete_many()
+            .filter(sea_orm::Condition::all().add(Expr::col(DeliveryColumn::WebhookId).eq(id)))
+            .secure()
+            .scope_with(scope)
+            .exec(conn)
+            .await
+            .map_err(db_err)?;
+
+        Ok(true)
+    }
+
+    async fn record_delivery<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        delivery: WebhookDelivery,
+    ) -> Result<(), DomainError> {
+        let m = DeliveryAM {
+            id: Set(delivery.id),
+            tenant_id: Set(delivery.tenant_id),
+            webhook_id: Set(delivery.webhook_id),
+            event_id: Set(delivery.event_id),
+            event_type: Set(delivery.event_type.as_str().to_owned()),
+            attempt: Set(i32::try_from(delivery.attempt).unwrap_or(i32::MAX)),
+            status: Set(delivery.status.as_str().to_owned()),
+            response_code: Set(delivery.response_code.map(i32::from)),
+            latency_ms: Set(i64::try_from(delivery.latency_ms).unwrap_or(i64::MAX)),
+            error: Set(delivery.error),
+            attempted_at: Set(delivery.attempted_at),
+        };
+
+        let _ = secure_insert::<DeliveryEntity>(m, scope, conn)
+            .await
+            .map_err(db_err)?;
+        Ok(())
+    }
+
+    async fn list_deliveries_page<C: DBRunner>(
+        &self,
+        conn: &C,
+        scope: &AccessScope,
+        webhook_id: Uuid,
+        query: &ODataQuery,
+    ) -> Result<Page<WebhookDelivery>, DomainError> {
+        let base_query = DeliveryEntity::find()
+            .filter(
+                sea_orm::Condition::all().add(Expr::col(DeliveryColumn::WebhookId).eq(webhook_id)),
+            )
+            .secure()
+            .scope_with(scope);
+
+        let page =
+            paginate_odata::<WebhookDeliveryFilterField, WebhookDeliveryODataMapper, _, _, _, _>(
+                base_query,
+                conn,
+                query,
+                ("id", SortDir::Desc),
+                self.limit_cfg,
+                Into::into,
+            )
+            .await
+            .map_err(odata_err)?;
+
+        Ok(page)
+    }
+}
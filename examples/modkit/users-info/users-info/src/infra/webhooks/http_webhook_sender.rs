@@ -0,0 +1,47 @@
+use anyhow::Context;
+use async_trait::async_trait;
+use modkit_http::HttpClient;
+use tracing::instrument;
+
+use crate::domain::ports::WebhookSender;
+
+/// HTTP adapter implementing the `WebhookSender` port.
+///
+/// The client must be built with retries and redirects disabled and with a
+/// [`TargetResolver`](super::TargetResolver) as DNS resolver: the delivery
+/// worker retries on its own, and following a redirect or connecting to a
+/// freshly resolved address would bypass the target address checks.
+pub struct HttpWebhookSender {
+    client: HttpClient,
+}
+
+impl HttpWebhookSender {
+    #[must_use]
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    #[instrument(skip_all, fields(url = %url))]
+    async fn post(
+        &self,
+        url: &str,
+        headers: &[(&'static str, String)],
+        body: String,
+    ) -> anyhow::Result<u16> {
+        let mut request = self.client.post(url);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        let response = request
+            .body_string(body)
+            .send()
+            .await
+            .with_context(|| format!("POST {url}"))?;
+
+        Ok(response.status().as_u16())
+    }
+}
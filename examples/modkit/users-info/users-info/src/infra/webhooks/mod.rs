@@ -0,0 +1,5 @@
+pub mod http_webhook_sender;
+pub mod target_resolver;
+
+pub use http_webhook_sender::HttpWebhookSender;
+pub use target_resolver::TargetResolver;
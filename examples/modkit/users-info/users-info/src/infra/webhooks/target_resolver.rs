@@ -0,0 +1,32 @@
+use std::net::SocketAddr;
+
+use modkit_http::{Resolve, Resolving};
+
+use crate::domain::webhooks::TargetPolicy;
+
+/// DNS resolver for the webhook HTTP client that applies the [`TargetPolicy`].
+///
+/// The connection goes to the addresses checked here, so a target whose DNS
+/// answer changes between the pre-delivery check and the connect still cannot
+/// reach a denied address.
+pub struct TargetResolver {
+    policy: TargetPolicy,
+}
+
+impl TargetResolver {
+    #[must_use]
+    pub fn new(policy: TargetPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Resolve for TargetResolver {
+    fn resolve(&self, host: &str) -> Resolving {
+        let policy = self.policy.clone();
+        let host = host.to_owned();
+        Box::pin(async move {
+            let addrs = policy.resolve_host(&host).await?;
+            Ok(addrs.into_iter().map(|ip| SocketAddr::new(ip, 0)).collect())
+        })
+    }
+}
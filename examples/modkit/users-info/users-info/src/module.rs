@@ -1,11 +1,13 @@
 use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use async_trait::async_trait;
 use modkit::api::OpenApiRegistry;
 use modkit::{DatabaseCapability, Module, ModuleCtx, RestApiCapability, SseBroadcaster};
 use modkit_db::DBProvider;
 use modkit_db::DbError;
-use modkit_http::HttpClient;
+use modkit_db::odata::SearchMode;
+use modkit_http::{HttpClient, TransportSecurity};
 use sea_orm_migration::MigrationTrait;
 use tracing::{debug, info};
 use url::Url;
@@ -21,18 +23,31 @@ use crate::api::rest::dto::UserEvent;
 use crate::api::rest::routes;
 use crate::api::rest::sse_adapter::SseUserEventPublisher;
 use crate::config::UsersInfoConfig;
-use crate::domain::events::UserDomainEvent;
+use crate::domain::city_import::CityImportLimits;
+use crate::domain::email::EmailPolicy;
+use crate::domain::events::{FanOutPublisher, UserDomainEvent};
 use crate::domain::local_client::client::UsersInfoLocalClient;
-use crate::domain::ports::{AuditPort, EventPublisher};
+use crate::domain::ports::{AuditPort, EventPublisher, WebhookSender};
+use crate::domain::profile::{ProfileField, SelfServicePolicy};
 use crate::domain::service::{AppServices, ServiceConfig};
+use crate::domain::webhooks::{RetryPolicy, TargetPolicy, WebhookDispatcher, WebhookWorker};
 use crate::infra::audit::HttpAuditClient;
-use crate::infra::storage::{OrmAddressesRepository, OrmCitiesRepository, OrmUsersRepository};
+use crate::infra::storage::{
+    OrmAddressesRepository, OrmCitiesRepository, OrmTenantSettingsRepository, OrmUsersRepository,
+    OrmWebhooksRepository,
+};
+use crate::infra::webhooks::{HttpWebhookSender, TargetResolver};
 
 /// Type alias for the concrete `AppServices` type used with ORM repositories.
 /// This lives in the composition root (module.rs) to avoid infra dependencies in domain.
 /// May be converted to `AppState` if we need additional fields like metrics, config and etc
-pub(crate) type ConcreteAppServices =
-    AppServices<OrmUsersRepository, OrmCitiesRepository, OrmAddressesRepository>;
+pub(crate) type ConcreteAppServices = AppServices<
+    OrmUsersRepository,
+    OrmCitiesRepository,
+    OrmAddressesRepository,
+    OrmWebhooksRepository,
+    OrmTenantSettingsRepository,
+>;
 
 /// Main module struct with DDD-light layout and proper `ClientHub` integration
 #[modkit::module(
@@ -69,12 +84,24 @@ impl Module for UsersInfo {
             cfg.default_page_size, cfg.max_page_size
         );
 
+        crate::errors::register_problem_types(ctx.problem_types());
+
+        // Routes only depend on config and features; a dry run has no database
+        if ctx.is_dry_run() {
+            return Ok(());
+        }
+
         // Acquire DB capability (secure wrapper, no DbHandle exposed to modules)
         let db: Arc<DBProvider<DbError>> = Arc::new(ctx.db_required()?);
 
-        // Create event publisher adapter that bridges domain events to SSE
+        // Domain events go to SSE subscribers and to the webhook delivery queue
+        let (webhook_dispatcher, webhook_events) =
+            WebhookDispatcher::channel(cfg.webhooks.queue_capacity);
         let publisher: Arc<dyn EventPublisher<UserDomainEvent>> =
-            Arc::new(SseUserEventPublisher::new(self.sse.clone()));
+            Arc::new(FanOutPublisher::new(vec![
+                Arc::new(SseUserEventPublisher::new(self.sse.clone())),
+                Arc::new(webhook_dispatcher),
+            ]));
 
         // Build HTTP client with OTEL tracing enabled
         let http_client = HttpClient::builder()
@@ -98,28 +125,109 @@ impl Module for UsersInfo {
             .get::<dyn AuthZResolverClient>()
             .map_err(|e| anyhow::anyhow!("failed to get AuthZ resolver: {e}"))?;
 
-        let service_config = ServiceConfig {
-            max_display_name_length: 100,
-            default_page_size: cfg.default_page_size,
-            max_page_size: cfg.max_page_size,
-        };
+        let webhook_targets = TargetPolicy::new(
+            &cfg.webhooks.denied_cidrs,
+            &cfg.webhooks.allowed_hosts,
+            cfg.webhooks.allow_insecure_http,
+        )
+        .map_err(|e| anyhow::anyhow!("invalid webhooks config: {e}"))?;
+
+        let editable_fields = cfg
+            .self_service
+            .editable_fields
+            .iter()
+            .map(|name| {
+                ProfileField::parse(name).ok_or_else(|| {
+                    anyhow::anyhow!("invalid self_service.editable_fields: unknown field '{name}'")
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let service_config = ServiceConfig::builder()
+            .default_page_size(cfg.default_page_size)
+            .max_page_size(cfg.max_page_size)
+            .email_policy(EmailPolicy {
+                lowercase: cfg.email.lowercase,
+                unicode_nfc: cfg.email.unicode_nfc,
+                strip_plus_addressing: cfg.email.strip_plus_addressing,
+                allowed_domains: cfg.email.allowed_domains.clone(),
+                blocked_domains: cfg.email.blocked_domains.clone(),
+                max_length: cfg.email.max_length,
+            })
+            .webhook_targets(webhook_targets.clone())
+            .self_service(SelfServicePolicy {
+                subject_is_user_id: cfg.self_service.subject_is_user_id,
+                editable_fields,
+            })
+            .city_import(CityImportLimits {
+                max_bytes: cfg.city_import.max_bytes,
+                max_rows: cfg.city_import.max_rows,
+                chunk_size: cfg.city_import.chunk_size,
+            })
+            .max_users_per_tenant(cfg.max_users_per_tenant)
+            .tenant_settings_ttl(Duration::from_secs(cfg.tenant_settings_cache_ttl_secs))
+            .build();
 
         // Create repository implementations
         let limit_cfg = service_config.limit_cfg();
-        let users_repo = OrmUsersRepository::new(limit_cfg);
+        let mut users_repo = OrmUsersRepository::new(limit_cfg);
+        if cfg.full_text_search {
+            users_repo = users_repo.with_search_mode(SearchMode::FullText);
+        }
         let cities_repo = OrmCitiesRepository::new(limit_cfg);
         let addresses_repo = OrmAddressesRepository::new(limit_cfg);
+        let webhooks_repo = Arc::new(OrmWebhooksRepository::new(limit_cfg));
+
+        let features = ctx.features();
+        debug!(
+            "users_info addresses feature enabled: {}",
+            features.is_enabled(crate::domain::service::features::ADDRESSES)
+        );
+
+        // Webhook deliveries: dedicated client without built-in retries or
+        // redirects (the worker retries itself; redirects would bypass SSRF checks),
+        // resolving names through the target policy so it only connects to vetted addresses
+        let mut webhook_client = HttpClient::builder()
+            .with_otel()
+            .timeout(Duration::from_millis(cfg.webhooks.timeout_ms))
+            .retry(None)
+            .no_redirects()
+            .dns_resolver(Arc::new(TargetResolver::new(webhook_targets.clone())));
+        if cfg.webhooks.allow_insecure_http {
+            webhook_client = webhook_client.transport(TransportSecurity::AllowInsecureHttp);
+        }
+        let webhook_sender: Arc<dyn WebhookSender> =
+            Arc::new(HttpWebhookSender::new(webhook_client.build().map_err(
+                |e| anyhow::anyhow!("failed to build webhook HTTP client: {e}"),
+            )?));
+        let webhook_worker = Arc::new(WebhookWorker::new(
+            Arc::clone(&db),
+            Arc::clone(&webhooks_repo),
+            webhook_sender,
+            webhook_targets,
+            RetryPolicy {
+                max_attempts: cfg.webhooks.max_attempts.max(1),
+                initial_backoff: Duration::from_millis(cfg.webhooks.initial_backoff_ms),
+                max_backoff: Duration::from_millis(cfg.webhooks.max_backoff_ms),
+            },
+        ));
+        // Runs until the module is cancelled or the last dispatcher handle
+        // (held by the services) is dropped.
+        tokio::spawn(webhook_worker.run(webhook_events, ctx.cancellation_token().child_token()));
 
         // Create services with repository dependencies
         let services = Arc::new(AppServices::new(
             users_repo,
             cities_repo,
             addresses_repo,
+            webhooks_repo,
+            OrmTenantSettingsRepository::new(),
             db,
             publisher,
             audit_adapter,
             authz,
             service_config,
+            features,
         ));
 
         self.service
@@ -149,19 +257,19 @@ impl DatabaseCapability for UsersInfo {
 impl RestApiCapability for UsersInfo {
     fn register_rest(
         &self,
-        _ctx: &ModuleCtx,
+        ctx: &ModuleCtx,
         router: axum::Router,
         openapi: &dyn OpenApiRegistry,
     ) -> anyhow::Result<axum::Router> {
         info!("Registering users_info REST routes");
 
-        let service = self
-            .service
-            .get()
-            .ok_or_else(|| anyhow::anyhow!("Service not initialized"))?
-            .clone();
+        let service = match self.service.get() {
+            Some(service) => Some(service.clone()),
+            None if ctx.is_dry_run() => None,
+            None => anyhow::bail!("Service not initialized"),
+        };
 
-        let router = routes::register_routes(router, openapi, service);
+        let router = routes::register_routes(router, openapi, &ctx.features(), service);
 
         // Register SSE route with per-route Extension
         let router = routes::register_users_sse_route(router, openapi, self.sse.clone());
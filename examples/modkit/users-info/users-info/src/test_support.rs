@@ -1,26 +1,34 @@
 #![allow(clippy::unwrap_used, clippy::expect_used)]
 
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use authz_resolver_sdk::{
     AuthZResolverClient, AuthZResolverError,
     constraints::{Constraint, EqPredicate, InPredicate, Predicate},
-    models::{EvaluationRequest, EvaluationResponse, EvaluationResponseContext},
+    models::{
+        EvaluationDefaults, EvaluationRequest, EvaluationResponse, EvaluationResponseContext,
+        TenantContext,
+    },
 };
+use modkit::FeatureGate;
 use modkit_db::migration_runner::run_migrations_for_testing;
 use modkit_db::secure::DBRunner;
-use modkit_db::secure::{AccessScope, secure_insert};
+use modkit_db::secure::secure_insert;
 use modkit_db::{ConnectOpts, DBProvider, Db, DbError, connect_db};
-use modkit_security::{SecurityContext, pep_properties};
+use modkit_security::{AccessScopeBuilder, SecurityContext, pep_properties};
 use sea_orm_migration::MigratorTrait;
 use time::OffsetDateTime;
+use users_info_sdk::NewUser;
 use uuid::Uuid;
 
 use crate::domain::events::UserDomainEvent;
 use crate::domain::ports::{AuditPort, EventPublisher};
-use crate::domain::service::ServiceConfig;
-use crate::infra::storage::{OrmAddressesRepository, OrmCitiesRepository, OrmUsersRepository};
+use crate::domain::service::{ServiceConfig, resources};
+use crate::infra::storage::{
+    OrmAddressesRepository, OrmCitiesRepository, OrmTenantSettingsRepository, OrmUsersRepository,
+    OrmWebhooksRepository,
+};
 use crate::module::ConcreteAppServices;
 
 #[must_use]
@@ -71,6 +79,17 @@ pub async fn inmem_db() -> Db {
     db
 }
 
+/// Build a `NewUser` request for `tenant_id` with the given email.
+#[must_use]
+pub fn new_user(tenant_id: Uuid, email: &str) -> NewUser {
+    NewUser {
+        id: None,
+        tenant_id,
+        email: email.to_owned(),
+        display_name: "Test User".to_owned(),
+    }
+}
+
 pub async fn seed_user(
     db: &impl DBRunner,
     id: Uuid,
@@ -87,12 +106,19 @@ pub async fn seed_user(
         id: Set(id),
         tenant_id: Set(tenant_id),
         email: Set(email.to_owned()),
+        email_original: Set(None),
         display_name: Set(display_name.to_owned()),
+        external_subject_id: Set(None),
         created_at: Set(now),
         updated_at: Set(now),
+        created_by: Set(None),
+        updated_by: Set(None),
     };
 
-    let scope = AccessScope::for_tenants(vec![tenant_id]);
+    let scope = AccessScopeBuilder::for_resource(&resources::USER)
+        .filter(pep_properties::OWNER_TENANT_ID, [tenant_id])
+        .build()
+        .expect("valid user scope");
     let _ = secure_insert::<UserEntity>(user, &scope, db)
         .await
         .expect("Failed to seed user");
@@ -203,12 +229,22 @@ pub fn build_services_with_authz(
     db: Db,
     config: ServiceConfig,
     authz: Arc<dyn AuthZResolverClient>,
+) -> Arc<ConcreteAppServices> {
+    build_services_with_features(db, config, authz, FeatureGate::all_enabled("users-info"))
+}
+
+pub fn build_services_with_features(
+    db: Db,
+    config: ServiceConfig,
+    authz: Arc<dyn AuthZResolverClient>,
+    features: FeatureGate,
 ) -> Arc<ConcreteAppServices> {
     let limit_cfg = config.limit_cfg();
 
     let users_repo = OrmUsersRepository::new(limit_cfg);
     let cities_repo = OrmCitiesRepository::new(limit_cfg);
     let addresses_repo = OrmAddressesRepository::new(limit_cfg);
+    let webhooks_repo = Arc::new(OrmWebhooksRepository::new(limit_cfg));
 
     let db: Arc<DBProvider<DbError>> = Arc::new(DBProvider::new(db));
 
@@ -216,14 +252,57 @@ pub fn build_services_with_authz(
         users_repo,
         cities_repo,
         addresses_repo,
+        webhooks_repo,
+        OrmTenantSettingsRepository::new(),
         db,
         Arc::new(MockEventPublisher),
         Arc::new(MockAuditPort),
         authz,
         config,
+        features,
     ))
 }
 
+/// [`MockAuthZResolver`] serving fixed resolver `evaluation_defaults` and
+/// recording the tenant context of the last evaluation request.
+pub struct DefaultsAuthZResolver {
+    defaults: EvaluationDefaults,
+    last_tenant_context: Mutex<Option<TenantContext>>,
+}
+
+impl DefaultsAuthZResolver {
+    #[must_use]
+    pub fn new(defaults: EvaluationDefaults) -> Self {
+        Self {
+            defaults,
+            last_tenant_context: Mutex::new(None),
+        }
+    }
+
+    pub fn last_tenant_context(&self) -> Option<TenantContext> {
+        self.last_tenant_context.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl AuthZResolverClient for DefaultsAuthZResolver {
+    async fn evaluate(
+        &self,
+        request: EvaluationRequest,
+    ) -> Result<EvaluationResponse, AuthZResolverError> {
+        *self.last_tenant_context.lock().unwrap() = request.context.tenant_context.clone();
+        MockAuthZResolver.evaluate(request).await
+    }
+
+    async fn evaluation_defaults(
+        &self,
+        _resource_type: &str,
+        _action: &str,
+    ) -> Result<EvaluationDefaults, AuthZResolverError> {
+        Ok(self.defaults.clone())
+    }
+}
+
 /// Mock `AuthZ` resolver that returns `decision=false` in the response.
 ///
 /// This is the canonical PDP denial path: the PDP evaluates the request and
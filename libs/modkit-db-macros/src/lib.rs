@@ -37,7 +37,7 @@
 //! - **Resource**: `resource_col = "column_name"` OR `no_resource`
 //! - **Owner**: `owner_col = "column_name"` OR `no_owner`
 //! - **Type**: `type_col = "column_name"` OR `no_type`
-//! - **Unrestricted**: `unrestricted` (forbids all other attributes)
+//! - **Unrestricted**: `unrestricted` (forbids dimension attributes; `pep_prop` is allowed)
 //! - **Custom PEP property**: `pep_prop(property_name = "column_name")` (repeatable)
 //!
 //! ## Note on `OData` Macros
@@ -64,7 +64,7 @@ mod scopable;
 /// - `resource_col = "column_name"` OR `no_resource` - Primary resource ID column
 /// - `owner_col = "column_name"` OR `no_owner` - Owner-based filtering column
 /// - `type_col = "column_name"` OR `no_type` - Type-based filtering column
-/// - `unrestricted` - Mark as global entity (forbids all other attributes)
+/// - `unrestricted` - Mark as global entity (forbids dimension attributes; `pep_prop` is allowed)
 /// - `pep_prop(property_name = "column_name")` - Custom PEP property mapping (repeatable)
 ///
 /// The macro auto-generates `resolve_property()` from dimension columns and `pep_prop` entries:
@@ -73,6 +73,9 @@ mod scopable;
 /// - `owner_col` → `"owner_id"`
 /// - Each `pep_prop(name = "col")` → `"name"`
 ///
+/// It also generates `scope_columns()`, the names of those columns in the same
+/// order (dimensions first), each listed once.
+///
 /// # Example
 ///
 /// ```ignore
@@ -131,6 +134,20 @@ mod scopable;
 ///     pub value: String,
 /// }
 /// ```
+///
+/// A global entity can still expose properties for ABAC checks. Reserved names
+/// such as `owner_id` are allowed here because there is no `owner_col`:
+///
+/// ```ignore
+/// #[derive(DeriveEntityModel, Scopable)]
+/// #[sea_orm(table_name = "shared_templates")]
+/// #[secure(unrestricted, pep_prop(owner_id = "created_by"))]
+/// pub struct Model {
+///     #[sea_orm(primary_key)]
+///     pub id: Uuid,
+///     pub created_by: Uuid,
+/// }
+/// ```
 #[proc_macro_derive(Scopable, attributes(secure))]
 #[proc_macro_error]
 pub fn derive_scopable(input: TokenStream) -> TokenStream {
@@ -12,17 +12,20 @@ struct FilterableField {
     field_name: String,
     /// The `FieldKind` variant name (e.g., "String", "Uuid", "`DateTimeUtc`")
     kind: String,
+    /// Filterable paths of a `Json` field (e.g., "department", "labels/team")
+    paths: Vec<String>,
     /// Span for error reporting
     span: Span,
 }
 
-/// Parse #[odata(filter(kind = "..."))] attributes on struct fields
+/// Parse #[odata(filter(kind = "...", paths = ["..."]))] attributes on struct fields
 fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
     let field_ident = field.ident.as_ref()?.clone();
     let field_name = field_ident.to_string();
     let span = field.span();
 
     let mut found_kind: Option<String> = None;
+    let mut paths: Vec<String> = Vec::new();
 
     for attr in &field.attrs {
         // Look for #[odata(...)]
@@ -49,6 +52,22 @@ fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
                             );
                         }
                     }
+                    // Check for paths = ["...", ...] (Json fields)
+                    if filter_meta.path.is_ident("paths") {
+                        let value = filter_meta.value()?;
+                        let array: syn::ExprArray = value.parse()?;
+                        for elem in &array.elems {
+                            if let syn::Expr::Lit(syn::ExprLit {
+                                lit: Lit::Str(lit_str),
+                                ..
+                            }) = elem
+                            {
+                                paths.push(lit_str.value());
+                            } else {
+                                emit_error!(elem.span(), "paths must be string literals");
+                            }
+                        }
+                    }
                     Ok(())
                 })?;
             }
@@ -60,10 +79,18 @@ fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
         }
     }
 
-    found_kind.map(|kind| FilterableField {
+    let kind = found_kind?;
+    if kind == "Json" && paths.is_empty() {
+        emit_error!(span, "Json filter fields need paths = [\"...\"]");
+    } else if kind != "Json" && !paths.is_empty() {
+        emit_error!(span, "paths are only supported on Json filter fields");
+    }
+
+    Some(FilterableField {
         field_ident,
         field_name,
         kind,
+        paths,
         span,
     })
 }
@@ -138,8 +165,17 @@ pub fn expand_derive_odata_filterable(input: DeriveInput) -> TokenStream {
         .map(|(f, variant)| {
             let kind_str = &f.kind;
             let kind_ident = Ident::new(kind_str, f.span);
-            quote! {
-                #filter_enum_name::#variant => ::modkit_odata::filter::FieldKind::#kind_ident
+            if f.paths.is_empty() {
+                quote! {
+                    #filter_enum_name::#variant => ::modkit_odata::filter::FieldKind::#kind_ident
+                }
+            } else {
+                let paths = &f.paths;
+                quote! {
+                    #filter_enum_name::#variant => ::modkit_odata::filter::FieldKind::#kind_ident {
+                        allowed_paths: &[#(#paths),*],
+                    }
+                }
             }
         });
 
@@ -1,6 +1,6 @@
 use heck::ToUpperCamelCase;
 use proc_macro_error2::abort;
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Span, TokenStream, TokenTree};
 use quote::quote;
 use syn::{Data, DeriveInput, spanned::Spanned};
 
@@ -43,6 +43,12 @@ struct SecureConfig {
 
     // Custom PEP property mappings: (property_name, column_name, span)
     pep_props: Vec<(String, String, Span)>,
+
+    // Audit columns, stamped by the `_audited` write helpers
+    created_at_col: Option<(String, Span)>,
+    updated_at_col: Option<(String, Span)>,
+    created_by_col: Option<(String, Span)>,
+    updated_by_col: Option<(String, Span)>,
 }
 
 #[allow(clippy::needless_pass_by_value)] // DeriveInput is consumed by proc-macro pattern
@@ -61,10 +67,23 @@ pub fn expand_derive_scopable(input: DeriveInput) -> TokenStream {
     // Validate configuration
     validate_config(&config, &input);
 
+    // The generated impl targets the `Entity`/`Column` types emitted by `DeriveEntityModel`
+    if !has_sea_orm_table_name(&input) {
+        abort!(
+            input.ident.span(),
+            "Scopable requires DeriveEntityModel — add #[derive(Clone, Debug, PartialEq, DeriveEntityModel)] and #[sea_orm(table_name = \"...\")]"
+        );
+    }
+
     let entity_ident = syn::Ident::new("Entity", input.ident.span());
 
-    // If unrestricted, generate simple implementation with all None
+    // Audit columns are independent of the scope dimensions
+    let audit_cols_impl = generate_audit_cols(&config, input.ident.span());
+
+    // If unrestricted, all dimension columns are None; only `pep_prop` entries resolve
     if config.unrestricted.is_some() {
+        let resolve_property_impl = generate_resolve_property(&config, input.ident.span());
+        let scope_columns_impl = generate_scope_columns(&config);
         return quote! {
             impl ::modkit_db::secure::ScopableEntity for #entity_ident {
                 const IS_UNRESTRICTED: bool = true;
@@ -85,9 +104,11 @@ pub fn expand_derive_scopable(input: DeriveInput) -> TokenStream {
                     ::core::option::Option::None
                 }
 
-                fn resolve_property(_property: &str) -> ::core::option::Option<Self::Column> {
-                    ::core::option::Option::None
-                }
+                #resolve_property_impl
+
+                #scope_columns_impl
+
+                #audit_cols_impl
             }
         };
     }
@@ -113,6 +134,9 @@ pub fn expand_derive_scopable(input: DeriveInput) -> TokenStream {
     // Generate resolve_property implementation
     let resolve_property_impl = generate_resolve_property(&config, input.ident.span());
 
+    // Generate scope_columns implementation
+    let scope_columns_impl = generate_scope_columns(&config);
+
     // Generate the implementation
     quote! {
         impl ::modkit_db::secure::ScopableEntity for #entity_ident {
@@ -127,6 +151,10 @@ pub fn expand_derive_scopable(input: DeriveInput) -> TokenStream {
             #type_col_impl
 
             #resolve_property_impl
+
+            #scope_columns_impl
+
+            #audit_cols_impl
         }
     }
 }
@@ -156,6 +184,22 @@ fn generate_col_impl(
     }
 }
 
+/// Generate the audit column methods for the columns that were declared.
+///
+/// Undeclared ones keep the trait default (`None`).
+fn generate_audit_cols(config: &SecureConfig, span: Span) -> TokenStream {
+    [
+        ("created_at_col", &config.created_at_col),
+        ("updated_at_col", &config.updated_at_col),
+        ("created_by_col", &config.created_by_col),
+        ("updated_by_col", &config.updated_by_col),
+    ]
+    .into_iter()
+    .filter(|(_, col)| col.is_some())
+    .map(|(method_name, col)| generate_col_impl(method_name, col.as_ref(), span))
+    .collect()
+}
+
 /// Generate the `resolve_property` match arms from dimension columns and `pep_prop` entries.
 fn generate_resolve_property(config: &SecureConfig, span: Span) -> TokenStream {
     let mut arms = Vec::new();
@@ -204,11 +248,63 @@ fn generate_resolve_property(config: &SecureConfig, span: Span) -> TokenStream {
     }
 }
 
+/// Generate `scope_columns`: dimension columns first, then `pep_prop` columns, without duplicates.
+fn generate_scope_columns(config: &SecureConfig) -> TokenStream {
+    let dimensions = [
+        &config.tenant_col,
+        &config.resource_col,
+        &config.owner_col,
+        &config.type_col,
+    ];
+    let mut columns: Vec<&str> = Vec::new();
+    for column in dimensions
+        .into_iter()
+        .flatten()
+        .map(|(column, _)| column.as_str())
+        .chain(
+            config
+                .pep_props
+                .iter()
+                .map(|(_, column, _)| column.as_str()),
+        )
+    {
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+    }
+
+    quote! {
+        fn scope_columns() -> ::std::vec::Vec<&'static str> {
+            ::std::vec![#(#columns),*]
+        }
+    }
+}
+
+/// Whether the struct carries `#[sea_orm(table_name = "...")]`, i.e. it is a `SeaORM` entity model.
+fn has_sea_orm_table_name(input: &DeriveInput) -> bool {
+    input.attrs.iter().any(|attr| {
+        if !attr.path().is_ident("sea_orm") {
+            return false;
+        }
+        let Ok(list) = attr.meta.require_list() else {
+            return false;
+        };
+        let tokens: Vec<TokenTree> = list.tokens.clone().into_iter().collect();
+        tokens.windows(2).any(|pair| {
+            matches!(
+                pair,
+                [TokenTree::Ident(ident), TokenTree::Punct(punct)]
+                    if ident == "table_name" && punct.as_char() == '='
+            )
+        })
+    })
+}
+
 /// Validate the configuration for strict compile-time checks
 fn validate_config(config: &SecureConfig, input: &DeriveInput) {
     let struct_span = input.span();
 
-    // If unrestricted is set, no other attributes should be present
+    // If unrestricted is set, only `pep_prop` entries may accompany it
     if let Some(unrestricted_span) = config.unrestricted {
         let has_other = config.tenant_col.is_some()
             || config.no_tenant.is_some()
@@ -225,6 +321,7 @@ fn validate_config(config: &SecureConfig, input: &DeriveInput) {
                 "When using 'unrestricted', no other column attributes are allowed"
             );
         }
+        validate_pep_props(config);
         return; // Valid unrestricted config
     }
 
@@ -259,20 +356,26 @@ fn validate_config(config: &SecureConfig, input: &DeriveInput) {
 }
 
 /// Validate `pep_prop` entries for reserved names, duplicates, and empty values.
+///
+/// Unrestricted entities have no dimension columns, so the reserved names are
+/// free for them (e.g. `pep_prop(owner_id = "created_by")`).
 fn validate_pep_props(config: &SecureConfig) {
     let mut seen = std::collections::HashSet::new();
+    let check_reserved = config.unrestricted.is_none();
 
     for (property, column, span) in &config.pep_props {
         // Check for reserved property names
-        for (reserved, use_instead) in RESERVED_PROPERTIES {
-            if property == reserved {
-                abort!(
-                    *span,
-                    "pep_prop: '{}' is a reserved property name; use `{}` instead",
-                    reserved,
-                    use_instead
-                );
-            }
+        if check_reserved
+            && let Some((reserved, use_instead)) = RESERVED_PROPERTIES
+                .iter()
+                .find(|(reserved, _)| property == reserved)
+        {
+            abort!(
+                *span,
+                "pep_prop: '{}' is a reserved property name; use `{}` instead",
+                reserved,
+                use_instead
+            );
         }
 
         // Check for empty property or column
@@ -408,9 +511,6 @@ fn parse_secure_attrs(input: &DeriveInput) -> SecureConfig {
 
             // Check for pep_prop(name = "column") — nested meta with parentheses
             if meta.path.is_ident("pep_prop") {
-                if config.unrestricted.is_some() {
-                    abort!(span, "Cannot use 'pep_prop' with 'unrestricted'");
-                }
                 meta.parse_nested_meta(|pep_meta| {
                     let property = pep_meta
                         .path
@@ -521,12 +621,28 @@ fn parse_key_value_attr(config: &mut SecureConfig, meta: syn::meta::ParseNestedM
             }
             config.type_col = Some((value, span));
         }
+        "created_at_col" | "updated_at_col" | "created_by_col" | "updated_by_col" => {
+            let slot = match key.as_str() {
+                "created_at_col" => &mut config.created_at_col,
+                "updated_at_col" => &mut config.updated_at_col,
+                "created_by_col" => &mut config.created_by_col,
+                _ => &mut config.updated_by_col,
+            };
+            if slot.is_some() {
+                abort!(span, "duplicate attribute '{}'", key);
+            }
+            if value.is_empty() {
+                abort!(span, "{}: column name must not be empty", key);
+            }
+            *slot = Some((value, span));
+        }
         _ => {
             abort!(
                 span,
                 "Unknown attribute '{}'. Valid attributes: tenant_col, no_tenant, \
                  resource_col, no_resource, owner_col, no_owner, type_col, no_type, \
-                 unrestricted, pep_prop",
+                 unrestricted, pep_prop, created_at_col, updated_at_col, created_by_col, \
+                 updated_by_col",
                 key
             );
         }
@@ -16,6 +16,8 @@ fn ui() {
     t.compile_fail("tests/ui/err_unknown_attr.rs");
     t.compile_fail("tests/ui/err_non_struct.rs");
     t.compile_fail("tests/ui/err_duplicate_tenant_col.rs");
+    t.compile_fail("tests/ui/err_duplicate_audit_col.rs");
+    t.compile_fail("tests/ui/err_not_entity_model.rs");
 
     // Error cases: Missing explicit decisions
     t.compile_fail("tests/ui/err_missing_tenant_decision.rs");
@@ -36,7 +38,6 @@ fn ui() {
     t.compile_fail("tests/ui/err_pep_reserved_id.rs");
     t.compile_fail("tests/ui/err_pep_reserved_owner_id.rs");
     t.compile_fail("tests/ui/err_pep_duplicate_property.rs");
-    t.compile_fail("tests/ui/err_unrestricted_with_pep.rs");
 
     // Note: Compile-pass tests (ok_*.rs) exist on disk for documentation but are
     // not registered here — successful expansion requires the modkit-db crate which
@@ -0,0 +1,8 @@
+// Duplicate attribute: an audit column specified twice should abort.
+
+use modkit_db_macros::Scopable;
+
+#[derive(Scopable)]
+#[secure(updated_by_col = "updated_by")]
+#[secure(updated_by_col = "modified_by")]
+struct Model;
@@ -0,0 +1,12 @@
+// Scopable on a plain struct (no DeriveEntityModel / #[sea_orm(table_name = "...")])
+// should produce a helpful compile error.
+
+use modkit_db_macros::Scopable;
+
+#[derive(Scopable)]
+#[secure(no_tenant, no_resource, no_owner, no_type)]
+struct Model {
+    id: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+// Unrestricted entity with custom pep_prop mappings - macro should expand.
+// Reserved names are allowed because unrestricted entities have no dimension columns.
+// Note: This test only validates macro expansion, not the full trait implementation.
+
+use modkit_db_macros::Scopable;
+
+#[derive(Scopable)]
+#[secure(unrestricted, pep_prop(owner_id = "created_by"), pep_prop(region_id = "region_id"))]
+struct Model {
+    id: String,
+    created_by: String,
+    region_id: String,
+}
+
+fn main() {}
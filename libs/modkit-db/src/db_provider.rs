@@ -1,6 +1,6 @@
 use std::{future::Future, marker::PhantomData, pin::Pin, sync::Arc};
 
-use crate::secure::{DbConn, DbTx};
+use crate::secure::{DbConn, DbTx, TxAccessMode, TxConfig, TxError};
 use crate::{Db, DbError};
 
 /// Thin, reusable DB entrypoint for application services.
@@ -8,6 +8,7 @@ use crate::{Db, DbError};
 /// This wraps a module-scoped `Db` and provides:
 /// - `conn()` for non-transactional operations
 /// - `transaction(...)` for transactional operations without exposing `DbHandle`
+/// - `with_replica_tx(...)` for read-only transactions on a replica, if one is attached
 ///
 /// Services can store this behind an `Arc` and use:
 ///
@@ -15,8 +16,13 @@ use crate::{Db, DbError};
 /// let conn = self.db.conn()?;
 /// let out = self.db.transaction(|tx| Box::pin(async move { /* ... */ })).await?;
 /// ```
+///
+/// Runners acquired inside [`with_request_cancellation`](crate::secure::with_request_cancellation)
+/// (every API gateway request) carry the request's cancellation token, so their
+/// queries are aborted when the request is dropped.
 pub struct DBProvider<E> {
     db: Arc<Db>,
+    replica: Option<Arc<Db>>,
     _error: PhantomData<fn() -> E>,
 }
 
@@ -24,6 +30,7 @@ impl<E> Clone for DBProvider<E> {
     fn clone(&self) -> Self {
         Self {
             db: Arc::clone(&self.db),
+            replica: self.replica.clone(),
             _error: PhantomData,
         }
     }
@@ -37,10 +44,18 @@ where
     pub fn new(db: Db) -> Self {
         Self {
             db: Arc::new(db),
+            replica: None,
             _error: PhantomData,
         }
     }
 
+    /// Attach a read replica used by [`with_replica_tx`](Self::with_replica_tx).
+    #[must_use]
+    pub fn with_replica(mut self, replica: Db) -> Self {
+        self.replica = Some(Arc::new(replica));
+        self
+    }
+
     /// Create a non-transactional database runner.
     ///
     /// # Errors
@@ -66,4 +81,31 @@ where
     {
         self.db.transaction_ref_mapped(f).await
     }
+
+    /// Execute a closure inside a read-only transaction on the replica.
+    ///
+    /// Without an attached replica the transaction runs on the primary; it is
+    /// still opened read-only. The closure runs once (`retry_on_conflict` is
+    /// ignored).
+    ///
+    /// # Errors
+    ///
+    /// Returns:
+    /// - `TxError::Config` if `config.access_mode` is not `TxAccessMode::ReadOnly`
+    /// - `TxError::Infra` if starting or committing the transaction fails
+    /// - `TxError::Domain` if the closure returns an error
+    pub async fn with_replica_tx<T, F>(&self, config: TxConfig, f: F) -> Result<T, TxError<E>>
+    where
+        T: Send + 'static,
+        F: for<'a> FnOnce(&'a DbTx<'a>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'a>>
+            + Send,
+    {
+        if config.access_mode != Some(TxAccessMode::ReadOnly) {
+            return Err(TxError::Config(
+                "read-write transaction not allowed on replica".to_owned(),
+            ));
+        }
+        let db = self.replica.as_ref().unwrap_or(&self.db);
+        db.in_transaction_ref_with_config(&config, f).await
+    }
 }
@@ -0,0 +1,411 @@
+//! In-memory fake database for fast unit tests (feature `test-utils`).
+//!
+//! [`FakeRunner`] answers every statement from canned responses instead of a
+//! real database, and records what was executed:
+//!
+//! ```ignore
+//! use modkit_db::fake::{FakeRunner, StatementKind, unique_violation};
+//!
+//! let fake = FakeRunner::new();
+//! fake.on_select::<user::Entity>().return_models(vec![alice]);
+//! fake.on_insert::<user::Entity>().on_call(2).fail_with(unique_violation());
+//!
+//! // Repositories take the fake directly; services take `fake.db()`.
+//! repo.get(&fake, &scope, alice_id).await?;
+//! let services = build_services(fake.db());
+//!
+//! assert_eq!(fake.calls::<user::Entity>(StatementKind::Select), 1);
+//! ```
+//!
+//! The fake sits behind `SeaORM`'s proxy connection, so the secure layer
+//! (`SecureSelect`, `secure_insert`, `SecureUpdateMany`, `SecureDeleteMany`,
+//! transactions) runs unchanged and only the SQL execution is replaced.
+//!
+//! # Limits
+//!
+//! There are no SQL semantics: WHERE clauses, ordering, limits, joins and
+//! constraints are not evaluated. A stub returns its models for every matching
+//! statement, whatever the scope or filter. Without a stub, SELECTs return no
+//! rows, UPDATE/DELETE affect no rows and INSERTs echo the inserted values.
+//! Advisory locks are not supported. Use `SQLite` or a real database when a
+//! test depends on query behavior rather than on the code around it.
+
+mod statement;
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use sea_orm::{
+    DatabaseConnection, DbBackend, DbErr, EntityName, EntityTrait, IdenStatic, Iterable,
+    ModelTrait, ProxyDatabaseConnector, ProxyDatabaseTrait, ProxyExecResult, ProxyRow, RuntimeErr,
+    Statement, Value,
+};
+
+pub use statement::StatementKind;
+
+use crate::secure::Db;
+
+/// A statement executed against a [`FakeRunner`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedStatement {
+    pub kind: StatementKind,
+    /// Primary table (the INSERT/UPDATE target or the first FROM table).
+    pub table: Option<String>,
+    /// WHERE clause as generated, e.g. `"users"."tenant_id" IN ($1)`.
+    pub condition: Option<String>,
+    pub sql: String,
+    pub values: Vec<Value>,
+}
+
+/// Database error returned by a stub.
+///
+/// Surfaces as a driver error carrying `code`, so modkit-db classifies it like
+/// a real one (e.g. [`serialization_failure`] becomes
+/// `DbError::SerializationFailure`). `SeaORM`'s `DbErr::sql_err()` does not
+/// recognize it.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("{message}")]
+pub struct FakeDbError {
+    code: Option<String>,
+    message: String,
+}
+
+impl FakeDbError {
+    /// Error with a SQLSTATE or vendor code.
+    #[must_use]
+    pub fn new(code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            code: Some(code.into()),
+            message: message.into(),
+        }
+    }
+
+    /// Error without a code.
+    #[must_use]
+    pub fn other(message: impl Into<String>) -> Self {
+        Self {
+            code: None,
+            message: message.into(),
+        }
+    }
+
+    fn into_db_err(self, query: bool) -> DbErr {
+        let err = RuntimeErr::SqlxError(sqlx::Error::Database(Box::new(self)));
+        if query {
+            DbErr::Query(err)
+        } else {
+            DbErr::Exec(err)
+        }
+    }
+}
+
+impl sqlx::error::DatabaseError for FakeDbError {
+    fn message(&self) -> &str {
+        &self.message
+    }
+
+    fn code(&self) -> Option<Cow<'_, str>> {
+        self.code.as_deref().map(Cow::Borrowed)
+    }
+
+    fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+        self
+    }
+
+    fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+        self
+    }
+
+    fn kind(&self) -> sqlx::error::ErrorKind {
+        match self.code.as_deref() {
+            Some("23505" | "2067" | "1062") => sqlx::error::ErrorKind::UniqueViolation,
+            _ => sqlx::error::ErrorKind::Other,
+        }
+    }
+}
+
+/// Unique constraint violation (Postgres SQLSTATE `23505`).
+#[must_use]
+pub fn unique_violation() -> FakeDbError {
+    FakeDbError::new("23505", "duplicate key value violates unique constraint")
+}
+
+/// Serialization failure (Postgres SQLSTATE `40001`).
+#[must_use]
+pub fn serialization_failure() -> FakeDbError {
+    FakeDbError::new(
+        "40001",
+        "could not serialize access due to concurrent update",
+    )
+}
+
+#[derive(Debug, Clone)]
+enum Response {
+    Rows(Vec<ProxyRow>),
+    Affected(u64),
+    Fail(FakeDbError),
+}
+
+#[derive(Debug)]
+struct Rule {
+    kind: StatementKind,
+    table: String,
+    /// 1-based call number this rule is limited to; `None` = every call.
+    call: Option<usize>,
+    response: Response,
+}
+
+#[derive(Debug, Default)]
+struct FakeState {
+    statements: Vec<RecordedStatement>,
+    rules: Vec<Rule>,
+    calls: HashMap<(StatementKind, String), usize>,
+}
+
+impl FakeState {
+    fn respond(&mut self, stmt: Statement) -> Response {
+        let parsed = statement::parse(&stmt.sql);
+        let values = stmt.values.map(|v| v.0).unwrap_or_default();
+
+        let rule = parsed.table.as_ref().and_then(|table| {
+            let call = self
+                .calls
+                .entry((parsed.kind, table.clone()))
+                .and_modify(|n| *n += 1)
+                .or_insert(1);
+            let call = *call;
+            let matches = |r: &&Rule| r.kind == parsed.kind && &r.table == table;
+            self.rules
+                .iter()
+                .rev()
+                .filter(matches)
+                .find(|r| r.call == Some(call))
+                .or_else(|| {
+                    self.rules
+                        .iter()
+                        .rev()
+                        .filter(matches)
+                        .find(|r| r.call.is_none())
+                })
+                .map(|r| r.response.clone())
+        });
+
+        let response = rule.unwrap_or_else(|| match parsed.kind {
+            StatementKind::Insert => Response::Rows(statement::inserted_rows(&stmt.sql, &values)),
+            StatementKind::Update | StatementKind::Delete => Response::Affected(0),
+            StatementKind::Select | StatementKind::Other => Response::Rows(Vec::new()),
+        });
+
+        self.statements.push(RecordedStatement {
+            kind: parsed.kind,
+            table: parsed.table,
+            condition: parsed.condition,
+            sql: stmt.sql,
+            values,
+        });
+        response
+    }
+}
+
+/// Proxy backend that routes `SeaORM` statements to the shared [`FakeState`].
+#[derive(Debug)]
+struct FakeProxy {
+    state: Arc<Mutex<FakeState>>,
+}
+
+impl FakeProxy {
+    fn respond(&self, stmt: Statement) -> Response {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .respond(stmt)
+    }
+}
+
+#[async_trait::async_trait]
+impl ProxyDatabaseTrait for FakeProxy {
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        match self.respond(statement) {
+            Response::Rows(rows) => Ok(rows),
+            Response::Affected(_) => Ok(Vec::new()),
+            Response::Fail(err) => Err(err.into_db_err(true)),
+        }
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        let rows_affected = match self.respond(statement) {
+            Response::Rows(rows) => rows.len() as u64,
+            Response::Affected(n) => n,
+            Response::Fail(err) => return Err(err.into_db_err(false)),
+        };
+        Ok(ProxyExecResult::new(0, rows_affected))
+    }
+}
+
+/// Fake database runner with per-table canned responses.
+///
+/// Implements `DBRunner`, so it can be passed wherever a `DbConn` or `DbTx`
+/// is accepted. Use [`FakeRunner::db`] for code that takes a [`Db`] or a
+/// `DBProvider`.
+pub struct FakeRunner {
+    state: Arc<Mutex<FakeState>>,
+    pub(crate) conn: DatabaseConnection,
+}
+
+impl Default for FakeRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for FakeRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FakeRunner").finish_non_exhaustive()
+    }
+}
+
+impl FakeRunner {
+    /// Fake generating Postgres SQL.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_backend(DbBackend::Postgres)
+    }
+
+    /// Fake generating SQL for `backend`.
+    ///
+    /// Postgres (the default) reads inserted and updated rows back with
+    /// `RETURNING`, which the fake answers directly; MySQL issues an extra
+    /// SELECT after every INSERT.
+    #[must_use]
+    pub fn with_backend(backend: DbBackend) -> Self {
+        let state = Arc::new(Mutex::new(FakeState::default()));
+        let proxy: Box<dyn ProxyDatabaseTrait> = Box::new(FakeProxy {
+            state: Arc::clone(&state),
+        });
+        let conn = ProxyDatabaseConnector::connect(backend, Arc::new(proxy))
+            .unwrap_or_else(|_| unreachable!("proxy connections cannot fail"));
+        Self { state, conn }
+    }
+
+    /// A [`Db`] backed by this fake, for services and `DBProvider`.
+    #[must_use]
+    pub fn db(&self) -> Db {
+        Db::new(crate::DbHandle::from_proxy(self.conn.clone()))
+    }
+
+    /// Stub SELECTs from `E`'s table.
+    pub fn on_select<E: EntityTrait>(&self) -> Stub<'_, E> {
+        self.stub(StatementKind::Select)
+    }
+
+    /// Stub INSERTs into `E`'s table.
+    pub fn on_insert<E: EntityTrait>(&self) -> Stub<'_, E> {
+        self.stub(StatementKind::Insert)
+    }
+
+    /// Stub UPDATEs of `E`'s table.
+    pub fn on_update<E: EntityTrait>(&self) -> Stub<'_, E> {
+        self.stub(StatementKind::Update)
+    }
+
+    /// Stub DELETEs from `E`'s table.
+    pub fn on_delete<E: EntityTrait>(&self) -> Stub<'_, E> {
+        self.stub(StatementKind::Delete)
+    }
+
+    fn stub<E: EntityTrait>(&self, kind: StatementKind) -> Stub<'_, E> {
+        Stub {
+            state: &self.state,
+            kind,
+            call: None,
+            _entity: PhantomData,
+        }
+    }
+
+    /// All statements executed so far, in order.
+    #[must_use]
+    pub fn statements(&self) -> Vec<RecordedStatement> {
+        self.lock().statements.clone()
+    }
+
+    /// Number of `kind` statements executed against `E`'s table.
+    #[must_use]
+    pub fn calls<E: EntityTrait>(&self, kind: StatementKind) -> usize {
+        self.lock()
+            .calls
+            .get(&(kind, table_name::<E>()))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Response builder returned by `FakeRunner::on_*`.
+///
+/// A later stub for the same statement kind and table replaces an earlier one;
+/// a stub limited with [`Stub::on_call`] takes precedence for that call.
+#[must_use = "a stub has no effect until a response is set"]
+pub struct Stub<'a, E> {
+    state: &'a Mutex<FakeState>,
+    kind: StatementKind,
+    call: Option<usize>,
+    _entity: PhantomData<E>,
+}
+
+impl<E: EntityTrait> Stub<'_, E> {
+    /// Apply the response to the `n`-th matching statement only (1-based).
+    pub fn on_call(mut self, n: usize) -> Self {
+        self.call = Some(n);
+        self
+    }
+
+    /// Answer with `models`. For UPDATE/DELETE executed without `RETURNING`,
+    /// the number of models is reported as the affected row count.
+    pub fn return_models(self, models: Vec<E::Model>) {
+        let rows = models.iter().map(model_row::<E>).collect();
+        self.respond(Response::Rows(rows));
+    }
+
+    /// Report `n` affected rows (UPDATE/DELETE).
+    pub fn affect_rows(self, n: u64) {
+        self.respond(Response::Affected(n));
+    }
+
+    /// Fail the statement with `err`.
+    pub fn fail_with(self, err: FakeDbError) {
+        self.respond(Response::Fail(err));
+    }
+
+    fn respond(self, response: Response) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .rules
+            .push(Rule {
+                kind: self.kind,
+                table: table_name::<E>(),
+                call: self.call,
+                response,
+            });
+    }
+}
+
+fn table_name<E: EntityTrait>() -> String {
+    E::default().table_name().to_owned()
+}
+
+fn model_row<E: EntityTrait>(model: &E::Model) -> ProxyRow {
+    E::Column::iter()
+        .map(|col| (col.as_str().to_owned(), model.get(col)))
+        .collect::<BTreeMap<_, _>>()
+        .into()
+}
@@ -0,0 +1,202 @@
+//! Lightweight SQL inspection for the fake runner.
+//!
+//! Only what the fake needs: the statement kind, the primary table, a WHERE
+//! summary and the column list of INSERTs. This is not a SQL parser; it relies
+//! on the shape of statements generated by `SeaORM`.
+
+use std::collections::BTreeMap;
+
+use sea_orm::{ProxyRow, Value};
+
+/// Kind of an executed statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatementKind {
+    Select,
+    Insert,
+    Update,
+    Delete,
+    /// Anything else (`SET`, `SAVEPOINT`, ...).
+    Other,
+}
+
+pub(super) struct ParsedStatement {
+    pub kind: StatementKind,
+    pub table: Option<String>,
+    pub condition: Option<String>,
+}
+
+/// Keywords that end a WHERE clause.
+const CONDITION_END: &[&str] = &[
+    " GROUP BY ",
+    " ORDER BY ",
+    " LIMIT ",
+    " OFFSET ",
+    " RETURNING ",
+    " FOR UPDATE",
+    " ON CONFLICT ",
+];
+
+pub(super) fn parse(sql: &str) -> ParsedStatement {
+    let sql = sql.trim();
+    // ASCII uppercasing keeps byte offsets aligned with `sql`.
+    let upper = sql.to_ascii_uppercase();
+
+    let kind = match upper.split_whitespace().next() {
+        Some("SELECT" | "WITH") => StatementKind::Select,
+        Some("INSERT") => StatementKind::Insert,
+        Some("UPDATE") => StatementKind::Update,
+        Some("DELETE") => StatementKind::Delete,
+        _ => StatementKind::Other,
+    };
+
+    let table = match kind {
+        StatementKind::Select | StatementKind::Delete => table_after(sql, &upper, " FROM "),
+        StatementKind::Insert => table_after(sql, &upper, "INSERT INTO "),
+        StatementKind::Update => table_after(sql, &upper, "UPDATE "),
+        StatementKind::Other => None,
+    };
+
+    let condition = upper.find(" WHERE ").map(|start| {
+        let rest = start + " WHERE ".len();
+        let end = CONDITION_END
+            .iter()
+            .filter_map(|kw| upper[rest..].find(kw).map(|i| rest + i))
+            .min()
+            .unwrap_or(sql.len());
+        sql[rest..end].trim().to_owned()
+    });
+
+    ParsedStatement {
+        kind,
+        table,
+        condition,
+    }
+}
+
+/// First table name following `keyword`, skipping subqueries.
+fn table_after(sql: &str, upper: &str, keyword: &str) -> Option<String> {
+    let mut from = 0;
+    while let Some(i) = upper[from..].find(keyword) {
+        let start = from + i + keyword.len();
+        let token = sql[start..]
+            .split(|c: char| c.is_whitespace() || c == '(' || c == ',')
+            .next()
+            .unwrap_or_default();
+        if !token.is_empty() {
+            return Some(unquote(token.rsplit('.').next().unwrap_or(token)));
+        }
+        from = start;
+    }
+    None
+}
+
+fn unquote(ident: &str) -> String {
+    ident.trim_matches(|c| c == '"' || c == '`').to_owned()
+}
+
+/// Rows of an `INSERT ... (cols) VALUES (...)`, built from its bound values.
+///
+/// Returns no rows when the values do not line up with the column list
+/// (e.g. `DEFAULT VALUES` or non-parameterized expressions).
+pub(super) fn inserted_rows(sql: &str, values: &[Value]) -> Vec<ProxyRow> {
+    let upper = sql.to_ascii_uppercase();
+    let Some(into) = upper.find("INSERT INTO ") else {
+        return Vec::new();
+    };
+    let after = into + "INSERT INTO ".len();
+    let (Some(open), Some(values_kw)) = (sql[after..].find('('), upper[after..].find(" VALUES"))
+    else {
+        return Vec::new();
+    };
+    if open > values_kw {
+        return Vec::new();
+    }
+    let open = after + open;
+    let Some(close) = sql[open..].find(')') else {
+        return Vec::new();
+    };
+    let columns: Vec<String> = sql[open + 1..open + close]
+        .split(',')
+        .map(|c| unquote(c.trim()))
+        .collect();
+
+    if columns.is_empty() || values.is_empty() || values.len() % columns.len() != 0 {
+        return Vec::new();
+    }
+
+    values
+        .chunks(columns.len())
+        .map(|chunk| {
+            columns
+                .iter()
+                .cloned()
+                .zip(chunk.iter().cloned())
+                .collect::<BTreeMap<_, _>>()
+                .into()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_select_with_condition() {
+        let p = parse(
+            r#"SELECT "users"."id" FROM "users" WHERE "users"."tenant_id" IN ($1) ORDER BY "users"."id" LIMIT $2"#,
+        );
+        assert_eq!(p.kind, StatementKind::Select);
+        assert_eq!(p.table.as_deref(), Some("users"));
+        assert_eq!(
+            p.condition.as_deref(),
+            Some(r#""users"."tenant_id" IN ($1)"#)
+        );
+    }
+
+    #[test]
+    fn parses_count_subquery_table() {
+        let p = parse(
+            r#"SELECT COUNT(*) AS num_items FROM (SELECT "users"."id" FROM "users" WHERE "users"."email" = $1) AS "sub_query""#,
+        );
+        assert_eq!(p.kind, StatementKind::Select);
+        assert_eq!(p.table.as_deref(), Some("users"));
+    }
+
+    #[test]
+    fn parses_write_statements() {
+        let p = parse(r#"UPDATE "cities" SET "name" = $1 WHERE "cities"."id" = $2 RETURNING "id""#);
+        assert_eq!(
+            (p.kind, p.table.as_deref()),
+            (StatementKind::Update, Some("cities"))
+        );
+        assert_eq!(p.condition.as_deref(), Some(r#""cities"."id" = $2"#));
+
+        let p = parse(r#"DELETE FROM "cities" WHERE "cities"."id" = $1"#);
+        assert_eq!(
+            (p.kind, p.table.as_deref()),
+            (StatementKind::Delete, Some("cities"))
+        );
+
+        let p = parse("SET LOCAL statement_timeout = 1000");
+        assert_eq!((p.kind, p.table), (StatementKind::Other, None));
+    }
+
+    #[test]
+    fn inserted_rows_pairs_columns_with_values() {
+        let sql = r#"INSERT INTO "cities" ("id", "name") VALUES ($1, $2), ($3, $4) RETURNING "id", "name""#;
+        let values = vec![
+            Value::from(1i32),
+            Value::from("Paris"),
+            Value::from(2i32),
+            Value::from("Rome"),
+        ];
+        let rows = inserted_rows(sql, &values);
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1].values.get("name"), Some(&Value::from("Rome")));
+
+        assert!(inserted_rows(sql, &values[..3]).is_empty());
+        assert!(inserted_rows(r#"INSERT INTO "cities" DEFAULT VALUES"#, &[]).is_empty());
+    }
+}
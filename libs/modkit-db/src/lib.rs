@@ -82,6 +82,9 @@ pub mod options;
 
 pub mod secure;
 
+#[cfg(feature = "test-utils")]
+pub mod fake;
+
 mod db_provider;
 
 // Internal modules
@@ -192,7 +195,14 @@ pub enum DbError {
     Sqlx(#[from] sqlx::Error),
 
     #[error(transparent)]
-    Sea(#[from] sea_orm::DbErr),
+    Sea(sea_orm::DbErr),
+
+    /// The transaction was aborted by the database because it conflicted with a
+    /// concurrent transaction (SQLSTATE `40001` serialization failure or a deadlock).
+    ///
+    /// Retrying the whole transaction is safe; see `TxConfig::retry_on_conflict`.
+    #[error("transaction serialization failure: {0}")]
+    SerializationFailure(sea_orm::DbErr),
 
     #[error(transparent)]
     Io(#[from] std::io::Error),
@@ -232,8 +242,106 @@ pub enum DbError {
     ConnRequestedInsideTx,
 }
 
+impl From<sea_orm::DbErr> for DbError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        if is_seaorm_serialization_failure(&err) {
+            DbError::SerializationFailure(err)
+        } else {
+            DbError::Sea(err)
+        }
+    }
+}
+
+impl DbError {
+    /// Returns `true` if this error is a retryable transaction conflict.
+    #[must_use]
+    pub fn is_serialization_failure(&self) -> bool {
+        matches!(self, DbError::SerializationFailure(_))
+    }
+}
+
+/// SQLSTATE codes of retryable transaction conflicts: serialization failure
+/// (Postgres 40001), deadlock (Postgres 40P01) and `InnoDB` deadlock (MySQL 1213).
+pub(crate) fn is_serialization_failure_code(code: &str) -> bool {
+    matches!(code, "40001" | "40P01" | "1213")
+}
+
+/// Codes of unique constraint violations, primary keys included: Postgres 23505,
+/// `SQLite` 2067/1555 (`SQLITE_CONSTRAINT_UNIQUE`/`_PRIMARYKEY`) and `MySQL` 1062.
+pub(crate) fn is_unique_violation_code(code: &str) -> bool {
+    matches!(code, "23505" | "2067" | "1555" | "1062")
+}
+
+fn is_seaorm_serialization_failure(err: &sea_orm::DbErr) -> bool {
+    db_error_code(err).is_some_and(|c| is_serialization_failure_code(&c))
+}
+
+/// Returns `true` if the statement was cancelled by the server
+/// (Postgres `query_canceled`, 57014), e.g. on `statement_timeout`.
+pub(crate) fn is_query_canceled(err: &sea_orm::DbErr) -> bool {
+    db_error_code(err).is_some_and(|c| c == "57014")
+}
+
+/// Backend error code (SQLSTATE or vendor code) of a driver error.
+pub(crate) fn db_error_code(err: &sea_orm::DbErr) -> Option<String> {
+    #[cfg(any(feature = "pg", feature = "mysql", feature = "sqlite"))]
+    {
+        use sea_orm::{DbErr, RuntimeErr};
+
+        if let DbErr::Conn(RuntimeErr::SqlxError(sqlx::Error::Database(db)))
+        | DbErr::Exec(RuntimeErr::SqlxError(sqlx::Error::Database(db)))
+        | DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(db))) = err
+        {
+            return db.code().map(std::borrow::Cow::into_owned);
+        }
+    }
+    #[cfg(not(any(feature = "pg", feature = "mysql", feature = "sqlite")))]
+    let _ = err;
+    None
+}
+
+/// Constraint named by a driver error: the constraint name on Postgres, the
+/// offending columns (`"users.email"`) on `SQLite`, which does not name constraints.
+pub(crate) fn db_error_constraint(err: &sea_orm::DbErr) -> Option<String> {
+    #[cfg(any(feature = "pg", feature = "mysql", feature = "sqlite"))]
+    {
+        use sea_orm::{DbErr, RuntimeErr};
+
+        if let DbErr::Conn(RuntimeErr::SqlxError(sqlx::Error::Database(db)))
+        | DbErr::Exec(RuntimeErr::SqlxError(sqlx::Error::Database(db)))
+        | DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(db))) = err
+        {
+            return db.constraint().map(ToOwned::to_owned).or_else(|| {
+                db.message()
+                    .split_once("constraint failed: ")
+                    .map(|(_, columns)| columns.to_owned())
+            });
+        }
+    }
+    #[cfg(not(any(feature = "pg", feature = "mysql", feature = "sqlite")))]
+    let _ = err;
+    None
+}
+
+/// Returns `true` if any error in the chain is a retryable transaction conflict,
+/// either as [`DbError::SerializationFailure`] or as a raw `SeaORM` error.
+pub(crate) fn is_serialization_failure(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<DbError>()
+            .is_some_and(DbError::is_serialization_failure)
+            || cause
+                .downcast_ref::<sea_orm::DbErr>()
+                .is_some_and(is_seaorm_serialization_failure)
+    })
+}
+
 impl From<crate::secure::ScopeError> for DbError {
     fn from(value: crate::secure::ScopeError) -> Self {
+        // Keep conflicts recognizable so transaction retries still apply
+        if let crate::secure::ScopeError::SerializationFailure(err) = value {
+            return DbError::SerializationFailure(err);
+        }
         // Scope errors are not infra connection errors, but they still originate from the DB
         // access layer. We keep the wrapper thin and preserve the message for callers.
         DbError::Other(anyhow::Error::new(value))
@@ -432,6 +540,23 @@ impl DbHandle {
         }
     }
 
+    /// Wrap a `SeaORM` proxy connection (see [`fake`]).
+    #[cfg(feature = "test-utils")]
+    pub(crate) fn from_proxy(sea: DatabaseConnection) -> Self {
+        use sea_orm::{ConnectionTrait, DbBackend};
+
+        let engine = match sea.get_database_backend() {
+            DbBackend::Postgres => DbEngine::Postgres,
+            DbBackend::MySql => DbEngine::MySql,
+            DbBackend::Sqlite => DbEngine::Sqlite,
+        };
+        Self {
+            engine,
+            dsn: "fake://".to_owned(),
+            sea,
+        }
+    }
+
     /// Get the backend.
     #[must_use]
     pub fn engine(&self) -> DbEngine {
@@ -642,4 +767,33 @@ mod tests {
         let _raw = db.sea_internal();
         Ok(())
     }
+
+    #[test]
+    fn test_serialization_failure_codes() {
+        assert!(is_serialization_failure_code("40001"));
+        assert!(is_serialization_failure_code("40P01"));
+        assert!(is_serialization_failure_code("1213"));
+        assert!(!is_serialization_failure_code("23505"));
+    }
+
+    #[test]
+    fn test_unique_violation_codes() {
+        assert!(is_unique_violation_code("23505"));
+        assert!(is_unique_violation_code("2067"));
+        assert!(is_unique_violation_code("1555"));
+        assert!(is_unique_violation_code("1062"));
+        assert!(!is_unique_violation_code("40001"));
+    }
+
+    #[test]
+    fn test_serialization_failure_in_error_chain() {
+        let conflict = DbError::SerializationFailure(sea_orm::DbErr::Custom("conflict".into()));
+        assert!(conflict.is_serialization_failure());
+
+        let err = anyhow::Error::from(conflict).context("while saving");
+        assert!(is_serialization_failure(&err));
+
+        let other = anyhow::Error::from(DbError::Sea(sea_orm::DbErr::Custom("boom".into())));
+        assert!(!is_serialization_failure(&other));
+    }
 }
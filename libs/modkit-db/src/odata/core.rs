@@ -9,11 +9,11 @@ use modkit_odata::{CursorV1, Error as ODataError, ODataOrderBy, ODataQuery, Sort
 use rust_decimal::Decimal;
 use sea_orm::{
     ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
-    sea_query::{Expr, Order},
+    sea_query::{Expr, Func, Order},
 };
 use thiserror::Error;
 
-use modkit_odata::filter::FieldKind;
+use modkit_odata::filter::{CaseFold, FieldKind};
 
 use crate::odata::LimitCfg;
 use crate::secure::{DBRunner, DBRunnerInternal, SeaOrmRunner};
@@ -264,6 +264,44 @@ fn ensure_string_field<E: EntityTrait>(f: &Field<E>, _field_name: &str) -> OData
     Ok(())
 }
 
+/// Resolve the field side of a comparison or string function: a bare field, or
+/// a string field wrapped in `tolower`/`toupper`.
+fn resolve_operand<'a, E: EntityTrait>(
+    expr: &core::Expr,
+    fmap: &'a FieldMap<E>,
+) -> ODataBuildResult<(&'a Field<E>, Option<CaseFold>)> {
+    match expr {
+        core::Expr::Identifier(name) => {
+            let f = fmap
+                .get(name)
+                .ok_or_else(|| ODataBuildError::UnknownField(name.clone()))?;
+            Ok((f, None))
+        }
+        core::Expr::Function(fname, args) => {
+            let fold = CaseFold::from_function_name(fname)
+                .ok_or_else(|| ODataBuildError::UnsupportedFn(fname.clone()))?;
+            let [core::Expr::Identifier(name)] = args.as_slice() else {
+                return Err(ODataBuildError::UnsupportedFn(fname.clone()));
+            };
+            let f = fmap
+                .get(name)
+                .ok_or_else(|| ODataBuildError::UnknownField(name.clone()))?;
+            ensure_string_field(f, name)?;
+            Ok((f, Some(fold)))
+        }
+        _ => Err(ODataBuildError::Other("expected a field reference")),
+    }
+}
+
+/// Column expression for a (possibly case-folded) field.
+fn operand_expr<C: ColumnTrait>(col: C, fold: Option<CaseFold>) -> Expr {
+    match fold {
+        None => Expr::col(col),
+        Some(CaseFold::Lower) => Expr::expr(Func::lower(Expr::col(col))),
+        Some(CaseFold::Upper) => Expr::expr(Func::upper(Expr::col(col))),
+    }
+}
+
 /* ---------- cursor value encoding/decoding ---------- */
 
 /// Parse a cursor value from string based on field kind
@@ -320,6 +358,9 @@ pub fn parse_cursor_value(kind: FieldKind, s: &str) -> ODataBuildResult<sea_orm:
                 .map_err(|_| ODataBuildError::Other("invalid decimal in cursor"))?;
             V::Decimal(Some(Box::new(d)))
         }
+        FieldKind::Json { .. } => {
+            return Err(ODataBuildError::Other("JSON fields cannot be cursor keys"));
+        }
     };
 
     Ok(result)
@@ -484,39 +525,38 @@ where
             Condition::all().add(inner).not()
         }
 
-        // Identifier op Value
+        // Identifier op Value (the field may be wrapped in tolower/toupper)
         X::Compare(lhs, op, rhs) => {
-            let (name, rhs_val) = match (&**lhs, &**rhs) {
-                (X::Identifier(name), X::Value(val)) => (name, val),
+            let (operand, rhs_val) = match (&**lhs, &**rhs) {
                 (X::Identifier(_), X::Identifier(_)) => {
                     return Err(ODataBuildError::Other(
                         "field-to-field comparison is not supported",
                     ));
                 }
+                (operand @ (X::Identifier(_) | X::Function(..)), X::Value(val)) => (operand, val),
                 _ => return Err(ODataBuildError::Other("unsupported comparison form")),
             };
-            let field = fmap
-                .get(name)
-                .ok_or_else(|| ODataBuildError::UnknownField(name.clone()))?;
+            let (field, fold) = resolve_operand(operand, fmap)?;
             let col = field.col;
 
             // null handling
             if matches!(rhs_val, core::Value::Null) {
                 return Ok(match op {
-                    Op::Eq => Condition::all().add(Expr::col(col).is_null()),
-                    Op::Ne => Condition::all().add(Expr::col(col).is_not_null()),
+                    Op::Eq => Condition::all().add(operand_expr(col, fold).is_null()),
+                    Op::Ne => Condition::all().add(operand_expr(col, fold).is_not_null()),
                     _ => return Err(ODataBuildError::UnsupportedOp(*op)),
                 });
             }
 
             let value = coerce(field.kind, rhs_val)?;
+            let lhs = operand_expr(col, fold);
             let expr = match op {
-                Op::Eq => Expr::col(col).eq(value),
-                Op::Ne => Expr::col(col).ne(value),
-                Op::Gt => Expr::col(col).gt(value),
-                Op::Ge => Expr::col(col).gte(value),
-                Op::Lt => Expr::col(col).lt(value),
-                Op::Le => Expr::col(col).lte(value),
+                Op::Eq => lhs.eq(value),
+                Op::Ne => lhs.ne(value),
+                Op::Gt => lhs.gt(value),
+                Op::Ge => lhs.gte(value),
+                Op::Lt => lhs.lt(value),
+                Op::Le => lhs.lte(value),
             };
             Condition::all().add(expr)
         }
@@ -539,33 +579,24 @@ where
             }
         }
 
-        // Supported functions: contains/startswith/endswith
+        // Supported functions: contains/startswith/endswith, optionally on tolower/toupper(field)
         X::Function(fname, args) => {
             let n = fname.to_ascii_lowercase();
-            match (n.as_str(), args.as_slice()) {
-                ("contains", [X::Identifier(name), X::Value(core::Value::String(s))]) => {
-                    let f = fmap
-                        .get(name)
-                        .ok_or_else(|| ODataBuildError::UnknownField(name.clone()))?;
-                    ensure_string_field(f, name)?;
-                    Condition::all().add(Expr::col(f.col).like(like_contains(s)))
+            let (operand, pattern) = match (n.as_str(), args.as_slice()) {
+                ("contains", [operand, X::Value(core::Value::String(s))]) => {
+                    (operand, like_contains(s))
                 }
-                ("startswith", [X::Identifier(name), X::Value(core::Value::String(s))]) => {
-                    let f = fmap
-                        .get(name)
-                        .ok_or_else(|| ODataBuildError::UnknownField(name.clone()))?;
-                    ensure_string_field(f, name)?;
-                    Condition::all().add(Expr::col(f.col).like(like_starts(s)))
+                ("startswith", [operand, X::Value(core::Value::String(s))]) => {
+                    (operand, like_starts(s))
                 }
-                ("endswith", [X::Identifier(name), X::Value(core::Value::String(s))]) => {
-                    let f = fmap
-                        .get(name)
-                        .ok_or_else(|| ODataBuildError::UnknownField(name.clone()))?;
-                    ensure_string_field(f, name)?;
-                    Condition::all().add(Expr::col(f.col).like(like_ends(s)))
+                ("endswith", [operand, X::Value(core::Value::String(s))]) => {
+                    (operand, like_ends(s))
                 }
                 _ => return Err(ODataBuildError::UnsupportedFn(fname.clone())),
-            }
+            };
+            let (f, fold) = resolve_operand(operand, fmap)?;
+            ensure_string_field(f, fname)?;
+            Condition::all().add(operand_expr(f.col, fold).like(pattern))
         }
 
         // Leaf forms are not valid WHERE by themselves
@@ -797,6 +828,40 @@ pub async fn paginate_with_odata<E, D, F, C>(
     limit_cfg: LimitCfg,         // e.g. { default: 25, max: 1000 }
     model_to_domain: F,
 ) -> Result<Page<D>, ODataError>
+where
+    E: EntityTrait,
+    E::Column: ColumnTrait + Copy,
+    F: Fn(E::Model) -> D + Copy,
+    C: DBRunner,
+{
+    paginate_select(
+        select,
+        conn,
+        q,
+        fmap,
+        tiebreaker,
+        limit_cfg,
+        model_to_domain,
+        false,
+    )
+    .await
+}
+
+/// Shared implementation of [`paginate_with_odata`].
+///
+/// With `skip_query` set (deny-all scope) the query is built and validated but
+/// not executed, yielding an empty page.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn paginate_select<E, D, F, C>(
+    select: sea_orm::Select<E>,
+    conn: &C,
+    q: &ODataQuery,
+    fmap: &FieldMap<E>,
+    tiebreaker: (&str, SortDir),
+    limit_cfg: LimitCfg,
+    model_to_domain: F,
+    skip_query: bool,
+) -> Result<Page<D>, ODataError>
 where
     E: EntityTrait,
     E::Column: ColumnTrait + Copy,
@@ -818,6 +883,13 @@ where
             .ensure_tiebreaker(tiebreaker.0, tiebreaker.1)
     };
 
+    // FieldMap-based paging has no searchable columns
+    if q.search.as_ref().is_some_and(|t| !t.is_empty()) {
+        return Err(ODataError::InvalidSearch(
+            "not supported for this resource".to_owned(),
+        ));
+    }
+
     // Validate cursor consistency (filter hash only) if cursor present
     if let Some(cur) = &q.cursor
         && let (Some(h), Some(cf)) = (q.filter_hash.as_deref(), cur.f.as_deref())
@@ -860,11 +932,15 @@ where
     s = s.limit(fetch);
 
     #[allow(clippy::disallowed_methods)]
-    let mut rows = match DBRunnerInternal::as_seaorm(conn) {
-        SeaOrmRunner::Conn(db) => s.all(db).await,
-        SeaOrmRunner::Tx(tx) => s.all(tx).await,
-    }
-    .map_err(|e| ODataError::Db(e.to_string()))?;
+    let mut rows = if skip_query {
+        Vec::new()
+    } else {
+        match DBRunnerInternal::as_seaorm(conn) {
+            SeaOrmRunner::Conn(db) => s.all(db).await,
+            SeaOrmRunner::Tx(tx) => s.all(tx).await,
+        }
+        .map_err(|e| ODataError::Db(e.to_string()))?
+    };
 
     let has_more = (rows.len() as u64) > limit;
 
@@ -18,6 +18,7 @@
 //! - `core`: Core `OData` to `SeaORM` translation (filters, cursors, ordering) - legacy `FieldMap` based
 //! - `sea_orm_filter`: Type-safe mapping from `FilterNode<F>` to `SeaORM` conditions
 //! - `pager`: Fluent builder for secure + `OData` pagination
+//! - `search`: `$search` terms to `SeaORM` conditions
 
 // Core OData functionality (legacy FieldMap-based)
 mod core;
@@ -28,11 +29,18 @@ pub mod sea_orm_filter;
 // Fluent pagination builder
 pub mod pager;
 
+// $search translation
+pub mod search;
+
+// Rejects filters on scope columns
+mod scope_guard;
+
 // Re-export all public items from core (legacy API)
 pub use core::*;
 
 // Re-export SeaORM filter mapping and pagination
 pub use sea_orm_filter::{
     FieldToColumn, LimitCfg, ODataFieldMapping, encode_cursor_value, filter_node_to_condition,
-    paginate_odata, parse_cursor_value,
+    filter_node_to_condition_for, paginate_odata, paginate_odata_with_search, parse_cursor_value,
 };
+pub use search::{SearchMode, search_condition};
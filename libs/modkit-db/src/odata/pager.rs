@@ -3,6 +3,7 @@
 //! This module provides `OPager`, a small ergonomic builder that:
 //! - Applies security scope via `Entity::find().secure().scope_with(&scope)`
 //! - Applies `OData` filter + cursor + order + limit via `paginate_with_odata`
+//! - Skips the database entirely for deny-all scopes (empty page)
 //! - Keeps all existing types without introducing facades or macros
 //!
 //! # Quick Start
@@ -94,7 +95,7 @@
 //!
 //! `OPager` automatically enforces tenant isolation and access control:
 //! - Security scope is applied before any filters
-//! - Empty scopes result in deny-all (no data returned)
+//! - Empty scopes result in deny-all (no data returned, no query executed)
 //! - All queries are scoped by the `SecurityCtx` provided
 //!
 //! # Performance
@@ -104,11 +105,13 @@
 //! - Applies filters at the database level (not in application memory)
 //! - Supports indexed columns via field mappings for optimal query performance
 
-use crate::odata::{FieldMap, LimitCfg, paginate_with_odata};
-use crate::secure::{DBRunner, ScopableEntity, SecureEntityExt};
+use crate::odata::core::paginate_select;
+use crate::odata::scope_guard::ensure_filter_avoids_scope_columns;
+use crate::odata::{FieldMap, LimitCfg};
+use crate::secure::{DBRunner, DBRunnerInternal, ScopableEntity, SecureEntityExt};
 use modkit_odata::{Error as ODataError, ODataQuery, Page, SortDir};
 use modkit_security::AccessScope;
-use sea_orm::{ColumnTrait, EntityTrait};
+use sea_orm::{ColumnTrait, EntityTrait, IdenStatic};
 
 /// Minimal fluent builder for Secure + `OData` pagination.
 ///
@@ -272,18 +275,27 @@ where
         E: ScopableEntity,
         F: Fn(E::Model) -> D + Copy,
     {
+        ensure_filter_avoids_scope_columns::<E>(q, |name| {
+            self.fmap
+                .get(name)
+                .map(|field| field.col.as_str().to_owned())
+        })?;
+
         // Apply security scope first - this enforces tenant isolation
-        let select = E::find().secure().scope_with(self.scope).inner;
+        let select = E::find().secure().scope_with(self.scope);
+        let deny_all = select.is_deny_all();
 
-        // Now apply OData filters, cursor, order, and limits
-        paginate_with_odata::<E, D, _, _>(
-            select,
+        // Now apply OData filters, cursor, order, and limits.
+        // Deny-all scopes are validated but never hit the database.
+        paginate_select::<E, D, _, _>(
+            select.into_inner_for(DBRunnerInternal::backend(self.conn)),
             self.conn,
             q,
             self.fmap,
             self.tiebreaker,
             self.limits,
             map,
+            deny_all,
         )
         .await
     }
@@ -4,19 +4,22 @@
 //! into `SeaORM` conditions. Concrete modules only need to provide a mapping from
 //! their DTO field enum to `SeaORM` Column types via the `FieldToColumn` trait.
 
-use crate::secure::{Scoped, SecureSelect};
+use crate::odata::scope_guard::ensure_filter_avoids_scope_columns;
+use crate::odata::search::{SearchMode, search_condition};
+use crate::secure::{ScopableEntity, Scoped, SecureSelect, scope_is_top_level};
 use bigdecimal::ToPrimitive;
 use chrono::SecondsFormat;
 use modkit_odata::filter::{
-    FieldKind, FilterField, FilterNode, FilterOp, ODataValue, convert_expr_to_filter_node,
+    CaseFold, FieldKind, FilterField, FilterNode, FilterOp, ODataValue, convert_expr_to_filter_node,
 };
+use modkit_odata::search::{SearchField, SearchableFields};
 use modkit_odata::{CursorV1, Error as ODataError, ODataOrderBy, Page, PageInfo, SortDir};
 use sea_orm::{
-    Condition, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
-    sea_query::{Expr, Order},
+    Condition, DbBackend, EntityTrait, IdenStatic, QueryFilter, QueryOrder, QuerySelect,
+    sea_query::{Expr, Func, Order},
 };
 
-use crate::secure::{DBRunner, DBRunnerInternal, SeaOrmRunner};
+use crate::secure::{DBRunner, DBRunnerInternal, SeaOrmRunner, json_path_expr, run_query};
 
 /// Trait for mapping DTO filter fields to `SeaORM` columns.
 ///
@@ -142,17 +145,41 @@ pub trait ODataFieldMapping<F: FilterField>: FieldToColumn<F> {
 /// ```
 ///
 /// # Errors
-/// Returns an error string if the filter contains unsupported operations or invalid values.
+/// Returns an error string if the filter contains unsupported operations or invalid values,
+/// or a JSON path (see [`filter_node_to_condition_for`]).
 pub fn filter_node_to_condition<F, M>(filter: &FilterNode<F>) -> Result<Condition, String>
+where
+    F: FilterField,
+    M: FieldToColumn<F>,
+{
+    filter_node_to_condition_for::<F, M>(filter, None)
+}
+
+/// [`filter_node_to_condition`] for queries run on `backend`.
+///
+/// JSON-path comparisons (`metadata/department eq 'sales'`) compile to
+/// backend-specific SQL and are rejected when `backend` is `None`.
+///
+/// # Errors
+/// Returns an error string if the filter contains unsupported operations or invalid values.
+pub fn filter_node_to_condition_for<F, M>(
+    filter: &FilterNode<F>,
+    backend: Option<DbBackend>,
+) -> Result<Condition, String>
 where
     F: FilterField,
     M: FieldToColumn<F>,
 {
     match filter {
-        FilterNode::Binary { field, op, value } => {
+        FilterNode::Binary {
+            field,
+            fold,
+            op,
+            value,
+        } => {
             // Map DTO field to database column
             let column = M::map_field(*field);
-            build_binary_condition(column, *op, value)
+            build_binary_condition(column, *fold, *op, value)
         }
         FilterNode::Composite { op, children } => {
             // Combine child conditions with AND or OR
@@ -163,23 +190,79 @@ where
             };
 
             children.iter().try_fold(base, |acc, child| {
-                let child_cond = filter_node_to_condition::<F, M>(child)?;
+                let child_cond = filter_node_to_condition_for::<F, M>(child, backend)?;
                 Ok(acc.add(child_cond))
             })
         }
         FilterNode::Not(inner) => {
             // FIXED: Call .not() AFTER adding the inner condition
-            let inner_cond = filter_node_to_condition::<F, M>(inner)?;
+            let inner_cond = filter_node_to_condition_for::<F, M>(inner, backend)?;
             Ok(Condition::all().add(inner_cond).not())
         }
+        FilterNode::JsonPath {
+            field,
+            path,
+            op,
+            value,
+        } => {
+            let backend =
+                backend.ok_or_else(|| "JSON path filters need the database backend".to_owned())?;
+            build_json_path_condition(M::map_field(*field), path, *op, value, backend)
+        }
     }
 }
 
+/// Build `field/path op value` for a JSON column.
+///
+/// `Postgres` and `MySQL` extract the value as text, so numbers and booleans are
+/// compared as their JSON text; `SQLite` compares native values, with booleans
+/// as `1`/`0`. `null` matches a missing key as well as a JSON `null`.
+fn build_json_path_condition<C>(
+    column: C,
+    path: &[String],
+    op: FilterOp,
+    value: &ODataValue,
+    backend: DbBackend,
+) -> Result<Condition, String>
+where
+    C: sea_orm::ColumnTrait,
+{
+    let path: Vec<&str> = path.iter().map(String::as_str).collect();
+    let lhs = Expr::expr(json_path_expr(column, &path, backend));
+    let as_text = backend != DbBackend::Sqlite;
+
+    let rhs = match value {
+        ODataValue::Null => {
+            return Ok(Condition::all().add(match op {
+                FilterOp::Eq => lhs.is_null(),
+                FilterOp::Ne => lhs.is_not_null(),
+                _ => return Err(format!("Unsupported operator for NULL: {op:?}")),
+            }));
+        }
+        ODataValue::String(s) => sea_orm::Value::from(s.clone()),
+        ODataValue::Number(n) if as_text => sea_orm::Value::from(n.to_string()),
+        ODataValue::Bool(b) if as_text => sea_orm::Value::from(b.to_string()),
+        ODataValue::Bool(b) => sea_orm::Value::from(i64::from(*b)),
+        ODataValue::Number(_) => odata_value_to_sea_value(value)?,
+        other => return Err(format!("Unsupported value for a JSON path: {other}")),
+    };
+
+    let expr = match op {
+        FilterOp::Eq => lhs.eq(rhs),
+        FilterOp::Ne => lhs.ne(rhs),
+        _ => return Err(format!("Unsupported operator for a JSON path: {op:?}")),
+    };
+    Ok(Condition::all().add(expr))
+}
+
 /// Build a binary condition (field op value) for `SeaORM`.
 ///
-/// This handles all comparison and string function operations.
+/// This handles all comparison and string function operations. A case-folded
+/// field is compared as `LOWER(col)` / `UPPER(col)`; note that `SQLite`'s
+/// built-in `LOWER`/`UPPER` only fold ASCII letters.
 fn build_binary_condition<C>(
     column: C,
+    fold: Option<CaseFold>,
     op: FilterOp,
     value: &ODataValue,
 ) -> Result<Condition, String>
@@ -198,25 +281,31 @@ where
         });
     }
 
+    let lhs = match fold {
+        None => Expr::col(column),
+        Some(CaseFold::Lower) => Expr::expr(Func::lower(Expr::col(column))),
+        Some(CaseFold::Upper) => Expr::expr(Func::upper(Expr::col(column))),
+    };
+
     // Build the expression based on the operator
     let expr = match op {
-        FilterOp::Eq => Expr::col(column).eq(sea_value),
-        FilterOp::Ne => Expr::col(column).ne(sea_value),
-        FilterOp::Gt => Expr::col(column).gt(sea_value),
-        FilterOp::Ge => Expr::col(column).gte(sea_value),
-        FilterOp::Lt => Expr::col(column).lt(sea_value),
-        FilterOp::Le => Expr::col(column).lte(sea_value),
+        FilterOp::Eq => lhs.eq(sea_value),
+        FilterOp::Ne => lhs.ne(sea_value),
+        FilterOp::Gt => lhs.gt(sea_value),
+        FilterOp::Ge => lhs.gte(sea_value),
+        FilterOp::Lt => lhs.lt(sea_value),
+        FilterOp::Le => lhs.lte(sea_value),
         FilterOp::Contains => {
             let s = extract_string(value)?;
-            Expr::col(column).like(format!("%{}%", escape_like(&s)))
+            lhs.like(format!("%{}%", escape_like(&s)))
         }
         FilterOp::StartsWith => {
             let s = extract_string(value)?;
-            Expr::col(column).like(format!("{}%", escape_like(&s)))
+            lhs.like(format!("{}%", escape_like(&s)))
         }
         FilterOp::EndsWith => {
             let s = extract_string(value)?;
-            Expr::col(column).like(format!("%{}", escape_like(&s)))
+            lhs.like(format!("%{}", escape_like(&s)))
         }
         FilterOp::And | FilterOp::Or => {
             return Err(format!("Logical operator {op:?} in binary context"));
@@ -380,6 +469,7 @@ pub fn parse_cursor_value(kind: FieldKind, s: &str) -> Result<sea_orm::Value, St
                 .map_err(|_| "invalid decimal in cursor".to_owned())?;
             V::Decimal(Some(Box::new(d)))
         }
+        FieldKind::Json { .. } => return Err("JSON fields cannot be cursor keys".to_owned()),
     };
 
     Ok(result)
@@ -432,6 +522,15 @@ fn clamp_limit(req: Option<u64>, cfg: LimitCfg) -> u64 {
 ///
 /// A Page containing the results and pagination metadata (next/prev cursors)
 ///
+/// If the select's scope is deny-all, the query is still validated (filter,
+/// order, cursor) but never executed: an empty page without cursors is returned.
+///
+/// A `$filter` that references the entity's tenant, resource, or owner column
+/// is rejected with `InvalidFilter`, unless the query was built with
+/// `ODataQuery::with_scope_filters_allowed`. A query with `$search` terms is
+/// rejected with `InvalidSearch`; use [`paginate_odata_with_search`] for
+/// searchable resources.
+///
 /// # Example
 ///
 /// ```ignore
@@ -458,10 +557,95 @@ pub async fn paginate_odata<F, M, E, D, Mapper, C>(
 where
     F: FilterField,
     M: ODataFieldMapping<F, Entity = E>,
-    E: EntityTrait,
+    E: ScopableEntity,
+    Mapper: Fn(E::Model) -> D,
+    C: DBRunner,
+{
+    paginate_odata_impl::<F, M, E, D, Mapper, C>(
+        select,
+        conn,
+        query,
+        None,
+        tiebreaker,
+        limit_cfg,
+        model_to_domain,
+    )
+    .await
+}
+
+/// [`paginate_odata`] for resources that support `$search`.
+///
+/// Search terms are matched against the fields declared by `S` (see
+/// [`search_condition`]) and AND-ed with `$filter` inside the access scope.
+///
+/// # Example
+///
+/// ```ignore
+/// let page = paginate_odata_with_search::<UserFilterField, UserODataMapper, UserSearch, _, _, _, _>(
+///     base_query,
+///     db,
+///     &odata_query,
+///     SearchMode::Like,
+///     ("id", SortDir::Desc),
+///     LimitCfg { default: 25, max: 1000 },
+///     |model| model.into(),
+/// ).await?;
+/// ```
+///
+/// # Errors
+/// Returns `ODataError` if filter or search application, cursor validation, or
+/// database query fails.
+pub async fn paginate_odata_with_search<F, M, S, E, D, Mapper, C>(
+    select: SecureSelect<E, Scoped>,
+    conn: &C,
+    query: &modkit_odata::ODataQuery,
+    search_mode: SearchMode,
+    tiebreaker: (&str, SortDir),
+    limit_cfg: LimitCfg,
+    model_to_domain: Mapper,
+) -> Result<Page<D>, ODataError>
+where
+    F: FilterField,
+    M: ODataFieldMapping<F, Entity = E>,
+    S: SearchableFields<Field = F>,
+    E: ScopableEntity,
+    Mapper: Fn(E::Model) -> D,
+    C: DBRunner,
+{
+    paginate_odata_impl::<F, M, E, D, Mapper, C>(
+        select,
+        conn,
+        query,
+        Some((S::FIELDS, search_mode)),
+        tiebreaker,
+        limit_cfg,
+        model_to_domain,
+    )
+    .await
+}
+
+/// Shared implementation of [`paginate_odata`] and [`paginate_odata_with_search`];
+/// `search` is `None` for resources without `$search` support.
+async fn paginate_odata_impl<F, M, E, D, Mapper, C>(
+    select: SecureSelect<E, Scoped>,
+    conn: &C,
+    query: &modkit_odata::ODataQuery,
+    search: Option<(&[SearchField<F>], SearchMode)>,
+    tiebreaker: (&str, SortDir),
+    limit_cfg: LimitCfg,
+    model_to_domain: Mapper,
+) -> Result<Page<D>, ODataError>
+where
+    F: FilterField,
+    M: ODataFieldMapping<F, Entity = E>,
+    E: ScopableEntity,
     Mapper: Fn(E::Model) -> D,
     C: DBRunner,
 {
+    ensure_filter_avoids_scope_columns::<E>(query, |name| {
+        F::from_name(name).map(|field| M::map_field(field).as_str().to_owned())
+    })?;
+
     let limit = clamp_limit(query.limit, limit_cfg);
     let fetch = limit + 1;
 
@@ -483,7 +667,11 @@ where
         return Err(ODataError::FilterMismatch);
     }
 
-    let mut s = select.inner;
+    let backend = DBRunnerInternal::backend(conn);
+    let deny_all = select.is_deny_all();
+    let timeout = select.timeout;
+    let scope = select.scope_arc();
+    let mut s = select.into_inner_for(backend);
 
     // Apply filter using type-safe FilterNode
     if let Some(ast) = query.filter.as_deref() {
@@ -491,7 +679,21 @@ where
             .map_err(|e| ODataError::InvalidFilter(e.to_string()))?;
 
         s = s.filter(
-            filter_node_to_condition::<F, M>(&filter_node).map_err(ODataError::InvalidFilter)?,
+            filter_node_to_condition_for::<F, M>(&filter_node, Some(backend))
+                .map_err(ODataError::InvalidFilter)?,
+        );
+    }
+
+    // Apply search as one more AND-ed condition
+    if let Some(terms) = query.search.as_ref().filter(|t| !t.is_empty()) {
+        let Some((fields, mode)) = search else {
+            return Err(ODataError::InvalidSearch(
+                "not supported for this resource".to_owned(),
+            ));
+        };
+        s = s.filter(
+            search_condition::<F, M>(terms, fields, mode, backend)
+                .map_err(ODataError::InvalidSearch)?,
         );
     }
 
@@ -503,6 +705,13 @@ where
         s = s.filter(cursor_cond);
     }
 
+    // JSON-path scope conditions are rendered for `backend`, which the check
+    // cannot reproduce; like the rest of the scope they are AND-ed above.
+    debug_assert!(
+        scope.has_json_paths() || scope_is_top_level(&s, &scope),
+        "OData conditions must not displace the access scope condition"
+    );
+
     // Apply ordering
     let query_order = if is_backward {
         effective_order.clone().reverse_directions()
@@ -523,12 +732,20 @@ where
 
     s = s.limit(fetch);
 
+    // A deny-all scope compiles to `WHERE false`: skip the round trip.
     #[allow(clippy::disallowed_methods)]
-    let mut rows = match DBRunnerInternal::as_seaorm(conn) {
-        SeaOrmRunner::Conn(db) => s.all(db).await,
-        SeaOrmRunner::Tx(tx) => s.all(tx).await,
-    }
-    .map_err(|e| ODataError::Db(e.to_string()))?;
+    let mut rows = if deny_all {
+        Vec::new()
+    } else {
+        run_query(conn, timeout, async move {
+            match DBRunnerInternal::as_seaorm(conn) {
+                SeaOrmRunner::Conn(db) => s.all(db).await,
+                SeaOrmRunner::Tx(tx) => s.all(tx).await,
+            }
+        })
+        .await
+        .map_err(|e| ODataError::Db(e.to_string()))?
+    };
 
     let has_more = (rows.len() as u64) > limit;
 
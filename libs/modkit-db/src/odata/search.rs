@@ -0,0 +1,233 @@
+//! Translation of `$search` terms into `SeaORM` conditions.
+//!
+//! Every term must match (AND); a term matches if any searchable column
+//! matches it (OR). The resulting condition is added to the query next to
+//! `$filter`, so it never widens the access scope.
+
+use modkit_odata::ODataSearch;
+use modkit_odata::filter::{FieldKind, FilterField};
+use modkit_odata::search::SearchField;
+use sea_orm::{
+    Condition, DbBackend,
+    sea_query::{Expr, LikeExpr, SimpleExpr, extension::postgres::PgExpr},
+};
+
+use crate::odata::sea_orm_filter::{FieldToColumn, escape_like};
+
+/// How `$search` terms are matched against the searchable columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchMode {
+    /// Case-insensitive substring match: `ILIKE` on Postgres, `LIKE` elsewhere.
+    #[default]
+    Like,
+    /// Postgres full-text match:
+    /// `to_tsvector('simple', col) @@ phraseto_tsquery('simple', term)`.
+    ///
+    /// Matches whole words rather than substrings. Only worth enabling when
+    /// every searchable column has a matching expression index, e.g.
+    /// `CREATE INDEX ... USING GIN (to_tsvector('simple', email))`.
+    /// Other backends fall back to [`SearchMode::Like`].
+    FullText,
+}
+
+/// Build the condition for `search` over `fields`.
+///
+/// `%`, `_` and `\` in the terms are matched literally.
+///
+/// # Errors
+/// Returns an error string if `fields` is empty or contains a non-string field.
+pub fn search_condition<F, M>(
+    search: &ODataSearch,
+    fields: &[SearchField<F>],
+    mode: SearchMode,
+    backend: DbBackend,
+) -> Result<Condition, String>
+where
+    F: FilterField,
+    M: FieldToColumn<F>,
+{
+    if fields.is_empty() {
+        return Err("resource declares no searchable fields".to_owned());
+    }
+    if let Some(field) = fields.iter().find(|f| f.field.kind() != FieldKind::String) {
+        return Err(format!(
+            "searchable field '{}' is not a string field",
+            field.field.name()
+        ));
+    }
+
+    let full_text = mode == SearchMode::FullText && backend == DbBackend::Postgres;
+    let condition = search.terms.iter().fold(Condition::all(), |all, term| {
+        let any = fields.iter().fold(Condition::any(), |any, f| {
+            let column = M::map_field(f.field);
+            any.add(if full_text {
+                ts_match(column, term)
+            } else {
+                like_match(column, term, backend)
+            })
+        });
+        all.add(any)
+    });
+    Ok(condition)
+}
+
+fn like_match<C>(column: C, term: &str, backend: DbBackend) -> SimpleExpr
+where
+    C: sea_orm::Iden + sea_orm::ColumnTrait + 'static,
+{
+    let pattern = format!("%{}%", escape_like(term));
+    match backend {
+        // Backslash is the default LIKE escape on Postgres
+        DbBackend::Postgres => Expr::col(column).ilike(LikeExpr::new(pattern)),
+        // SQLite's LIKE is case-insensitive for ASCII, MySQL's follows the collation
+        DbBackend::MySql | DbBackend::Sqlite => {
+            Expr::col(column).like(LikeExpr::new(pattern).escape('\\'))
+        }
+    }
+}
+
+fn ts_match<C>(column: C, term: &str) -> SimpleExpr
+where
+    C: sea_orm::Iden + sea_orm::ColumnTrait + 'static,
+{
+    Expr::cust_with_exprs(
+        "to_tsvector('simple', $1) @@ phraseto_tsquery('simple', $2)",
+        [Expr::col(column).into(), Expr::val(term).into()],
+    )
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use sea_orm::{EntityTrait, QueryFilter, QueryTrait};
+
+    mod item {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "search_items")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i64,
+            pub name: String,
+            pub email: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum Field {
+        Id,
+        Name,
+        Email,
+    }
+
+    impl FilterField for Field {
+        const FIELDS: &'static [Self] = &[Field::Id, Field::Name, Field::Email];
+
+        fn name(&self) -> &'static str {
+            match self {
+                Field::Id => "id",
+                Field::Name => "name",
+                Field::Email => "email",
+            }
+        }
+
+        fn kind(&self) -> FieldKind {
+            match self {
+                Field::Id => FieldKind::I64,
+                Field::Name | Field::Email => FieldKind::String,
+            }
+        }
+    }
+
+    struct Mapper;
+
+    impl FieldToColumn<Field> for Mapper {
+        type Column = item::Column;
+
+        fn map_field(field: Field) -> item::Column {
+            match field {
+                Field::Id => item::Column::Id,
+                Field::Name => item::Column::Name,
+                Field::Email => item::Column::Email,
+            }
+        }
+    }
+
+    const FIELDS: &[SearchField<Field>] = &[
+        SearchField::new(Field::Name, 2),
+        SearchField::new(Field::Email, 1),
+    ];
+
+    fn render(terms: &[&str], mode: SearchMode, backend: DbBackend) -> String {
+        let cond = search_condition::<Field, Mapper>(
+            &ODataSearch::new(terms.iter().copied()),
+            FIELDS,
+            mode,
+            backend,
+        )
+        .unwrap();
+        item::Entity::find().filter(cond).build(backend).to_string()
+    }
+
+    #[test]
+    fn terms_are_anded_and_fields_ored() {
+        let sql = render(&["ada", "lovelace"], SearchMode::Like, DbBackend::Sqlite);
+        assert!(
+            sql.contains(
+                r#"("search_items"."name" LIKE '%ada%' ESCAPE '\' OR "search_items"."email" LIKE '%ada%' ESCAPE '\') AND ("search_items"."name" LIKE '%lovelace%' ESCAPE '\' OR "search_items"."email" LIKE '%lovelace%' ESCAPE '\')"#
+            ),
+            "{sql}"
+        );
+    }
+
+    #[test]
+    fn wildcards_in_terms_are_escaped() {
+        let sql = render(&["50%_off"], SearchMode::Like, DbBackend::Sqlite);
+        assert!(sql.contains(r"LIKE '%50\%\_off%' ESCAPE '\'"), "{sql}");
+    }
+
+    #[test]
+    fn postgres_uses_ilike_or_full_text() {
+        let sql = render(&["ada"], SearchMode::Like, DbBackend::Postgres);
+        assert!(sql.contains(r#""name" ILIKE '%ada%' OR"#), "{sql}");
+        let sql = render(&["50%_off"], SearchMode::Like, DbBackend::Postgres);
+        assert!(sql.contains(r"ILIKE E'%50\\%\\_off%'"), "{sql}");
+
+        let sql = render(&["ada lovelace"], SearchMode::FullText, DbBackend::Postgres);
+        assert!(
+            sql.contains(
+                r#"to_tsvector('simple', "search_items"."name") @@ phraseto_tsquery('simple', 'ada lovelace')"#
+            ),
+            "{sql}"
+        );
+
+        // Full-text is Postgres-only
+        let sql = render(&["ada"], SearchMode::FullText, DbBackend::Sqlite);
+        assert!(sql.contains("LIKE '%ada%'"), "{sql}");
+    }
+
+    #[test]
+    fn rejects_unusable_fields() {
+        let search = ODataSearch::new(["ada"]);
+        assert!(
+            search_condition::<Field, Mapper>(&search, &[], SearchMode::Like, DbBackend::Sqlite)
+                .is_err()
+        );
+        assert!(
+            search_condition::<Field, Mapper>(
+                &search,
+                &[SearchField::new(Field::Id, 1)],
+                SearchMode::Like,
+                DbBackend::Sqlite
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Audit column stamping for the `_audited` write helpers.
+//!
+//! The actor always comes from the caller's `SecurityContext`; the DB layer
+//! never guesses identity. One timestamp is captured per statement, so every
+//! row and column written by it carries the same instant.
+
+use sea_orm::{ActiveModelTrait, ActiveValue, EntityTrait, IdenStatic, Value, sea_query::Expr};
+use time::{OffsetDateTime, PrimitiveDateTime};
+use uuid::Uuid;
+
+use crate::secure::{ScopableEntity, ScopeError};
+use modkit_security::SecurityContext;
+
+/// The actor and the instant of one audited statement.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AuditStamp {
+    now: OffsetDateTime,
+    actor: Uuid,
+}
+
+impl AuditStamp {
+    pub(crate) fn new(ctx: &SecurityContext) -> Self {
+        Self {
+            now: OffsetDateTime::now_utc(),
+            actor: ctx.subject_id(),
+        }
+    }
+
+    /// Fill `created_*` and `updated_*` for an insert.
+    pub(crate) fn stamp_insert<A>(&self, am: &mut A) -> Result<(), ScopeError>
+    where
+        A: ActiveModelTrait,
+        A::Entity: ScopableEntity,
+    {
+        self.stamp_time(am, <A::Entity as ScopableEntity>::created_at_col())?;
+        self.stamp_actor(am, <A::Entity as ScopableEntity>::created_by_col())?;
+        self.stamp_update(am)
+    }
+
+    /// Fill `updated_*` for an update.
+    pub(crate) fn stamp_update<A>(&self, am: &mut A) -> Result<(), ScopeError>
+    where
+        A: ActiveModelTrait,
+        A::Entity: ScopableEntity,
+    {
+        self.stamp_time(am, <A::Entity as ScopableEntity>::updated_at_col())?;
+        self.stamp_actor(am, <A::Entity as ScopableEntity>::updated_by_col())
+    }
+
+    /// `updated_*` column expressions for an update-many, skipping the
+    /// columns in `explicit`.
+    pub(crate) fn update_exprs<E>(
+        &self,
+        explicit: &[&'static str],
+    ) -> Vec<(E::Column, sea_orm::sea_query::SimpleExpr)>
+    where
+        E: ScopableEntity + EntityTrait,
+    {
+        let updated_at = E::updated_at_col().map(|col| (col, Value::from(self.now)));
+        let updated_by = E::updated_by_col().map(|col| (col, Value::from(self.actor)));
+        updated_at
+            .into_iter()
+            .chain(updated_by)
+            .filter(|(col, _)| !explicit.contains(&col.as_str()))
+            .map(|(col, value)| (col, Expr::value(value)))
+            .collect()
+    }
+
+    fn stamp_time<A>(
+        &self,
+        am: &mut A,
+        col: Option<<A::Entity as EntityTrait>::Column>,
+    ) -> Result<(), ScopeError>
+    where
+        A: ActiveModelTrait,
+    {
+        let now = self.now;
+        let chrono_now = chrono::DateTime::from_timestamp(now.unix_timestamp(), now.nanosecond())
+            .ok_or(ScopeError::Invalid("audit timestamp out of range"))?;
+        stamp(
+            am,
+            col,
+            [
+                Value::from(now),
+                Value::from(PrimitiveDateTime::new(now.date(), now.time())),
+                Value::from(chrono_now),
+                Value::from(chrono_now.fixed_offset()),
+                Value::from(chrono_now.naive_utc()),
+            ],
+            "audit timestamp column must be a date-time",
+        )
+    }
+
+    fn stamp_actor<A>(
+        &self,
+        am: &mut A,
+        col: Option<<A::Entity as EntityTrait>::Column>,
+    ) -> Result<(), ScopeError>
+    where
+        A: ActiveModelTrait,
+    {
+        stamp(
+            am,
+            col,
+            [Value::from(self.actor), Value::from(self.actor.to_string())],
+            "audit actor column must be a UUID or a string",
+        )
+    }
+}
+
+/// Set `col` to the first of `candidates` its field type accepts, unless the
+/// caller already set it.
+fn stamp<A, const N: usize>(
+    am: &mut A,
+    col: Option<<A::Entity as EntityTrait>::Column>,
+    candidates: [Value; N],
+    unsupported: &'static str,
+) -> Result<(), ScopeError>
+where
+    A: ActiveModelTrait,
+{
+    let Some(col) = col else {
+        return Ok(());
+    };
+    if matches!(am.get(col), ActiveValue::Set(_)) {
+        return Ok(());
+    }
+    for value in candidates {
+        if am.try_set(col, value).is_ok() {
+            return Ok(());
+        }
+    }
+    Err(ScopeError::Invalid(unsupported))
+}
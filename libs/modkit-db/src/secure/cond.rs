@@ -1,7 +1,12 @@
-use sea_orm::{ColumnTrait, Condition, EntityTrait, sea_query::Expr};
+use std::sync::Arc;
+
+use sea_orm::{
+    ColumnTrait, Condition, DbBackend, EntityTrait, QueryFilter,
+    sea_query::{Expr, Query, SimpleExpr},
+};
 
 use crate::secure::{AccessScope, ScopableEntity};
-use modkit_security::access_scope::{ScopeConstraint, ScopeFilter, ScopeValue};
+use modkit_security::access_scope::{JsonPath, ScopeConstraint, ScopeFilter, ScopeValue};
 
 /// Convert a [`ScopeValue`] to a `sea_query::SimpleExpr` for SQL binding.
 fn scope_value_to_sea_expr(v: &ScopeValue) -> sea_orm::sea_query::SimpleExpr {
@@ -26,6 +31,90 @@ fn scope_values_to_sea_values(values: &[ScopeValue]) -> Vec<sea_orm::Value> {
         .collect()
 }
 
+/// Convert a [`ScopeValue`] to the value compared with a JSON scalar.
+///
+/// `Postgres` and `MySQL` extract JSON scalars as text, so values are compared
+/// as their JSON text (`true`, `42`). `SQLite` extracts native values, with
+/// booleans as `1`/`0`.
+fn json_scope_value(v: &ScopeValue, backend: DbBackend) -> sea_orm::Value {
+    match (v, backend) {
+        (ScopeValue::Uuid(u), _) => sea_orm::Value::from(u.to_string()),
+        (ScopeValue::String(s), _) => sea_orm::Value::from(s.clone()),
+        (ScopeValue::Int(n), DbBackend::Sqlite) => sea_orm::Value::from(*n),
+        (ScopeValue::Bool(b), DbBackend::Sqlite) => sea_orm::Value::from(i64::from(*b)),
+        (ScopeValue::Int(n), _) => sea_orm::Value::from(n.to_string()),
+        (ScopeValue::Bool(b), _) => sea_orm::Value::from(b.to_string()),
+    }
+}
+
+/// SQL expression extracting the scalar at `path` inside the JSON column `col`.
+///
+/// - `Postgres`: `("col" -> 'a' ->> 'b')`
+/// - `SQLite`: `json_extract("col", '$."a"."b"')`
+/// - `MySQL`: `json_unquote(json_extract(`col`, '$."a"."b"'))`
+///
+/// Keys are bound as values.
+pub(crate) fn json_path_expr<C>(col: C, path: &[&str], backend: DbBackend) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    let col = SimpleExpr::from(Expr::col(col));
+    match backend {
+        DbBackend::Postgres => {
+            let arrows: String = (1..=path.len())
+                .map(|i| {
+                    let op = if i == path.len() { "->>" } else { "->" };
+                    format!(" {op} ${}", i + 1)
+                })
+                .collect();
+            let exprs = std::iter::once(col).chain(path.iter().map(|key| Expr::value(*key)));
+            Expr::cust_with_exprs(format!("($1{arrows})"), exprs)
+        }
+        DbBackend::Sqlite => Expr::cust_with_exprs(
+            "json_extract(?, ?)",
+            [col, Expr::value(json_path_literal(path))],
+        ),
+        DbBackend::MySql => Expr::cust_with_exprs(
+            "json_unquote(json_extract(?, ?))",
+            [col, Expr::value(json_path_literal(path))],
+        ),
+    }
+}
+
+/// `SQLite`/`MySQL` JSON path for `path`: `$."a"."b"`.
+fn json_path_literal(path: &[&str]) -> String {
+    let keys: String = path.iter().map(|key| format!(".\"{key}\"")).collect();
+    format!("${keys}")
+}
+
+/// Compare the JSON scalar at `path` inside `col` with the filter values.
+fn json_filter_expr<C>(
+    col: C,
+    path: &JsonPath<'_>,
+    filter: &ScopeFilter,
+    backend: DbBackend,
+) -> SimpleExpr
+where
+    C: ColumnTrait,
+{
+    let target = Expr::expr(json_path_expr(col, path.path(), backend));
+    match filter {
+        ScopeFilter::Eq(eq) => target.eq(json_scope_value(eq.value(), backend)),
+        ScopeFilter::In(inf) => target.is_in(
+            inf.values()
+                .iter()
+                .map(|v| json_scope_value(v, backend))
+                .collect::<Vec<_>>(),
+        ),
+        ScopeFilter::NotIn(nif) => target.is_not_in(
+            nif.values()
+                .iter()
+                .map(|v| json_scope_value(v, backend))
+                .collect::<Vec<_>>(),
+        ),
+    }
+}
+
 /// Build a deny-all condition (`WHERE false`).
 fn deny_all() -> Condition {
     Condition::all().add(Expr::value(false))
@@ -48,7 +137,22 @@ fn deny_all() -> Condition {
 /// | unconstrained (allow-all) | No filtering (`WHERE true`) |
 /// | single constraint | AND of resolved filters |
 /// | multiple constraints | OR of ANDed filter groups |
+///
+/// JSON-path filters need a backend (see [`build_scope_condition_for`]); here
+/// they fail their constraint.
 pub fn build_scope_condition<E>(scope: &AccessScope) -> Condition
+where
+    E: ScopableEntity + EntityTrait,
+    E::Column: ColumnTrait + Copy,
+{
+    build_scope_condition_for::<E>(scope, None)
+}
+
+/// [`build_scope_condition`] for queries run on `backend`.
+///
+/// JSON-path filters (`metadata->department`) compile to backend-specific SQL,
+/// so they fail their constraint when `backend` is `None`.
+pub fn build_scope_condition_for<E>(scope: &AccessScope, backend: Option<DbBackend>) -> Condition
 where
     E: ScopableEntity + EntityTrait,
     E::Column: ColumnTrait + Copy,
@@ -63,7 +167,7 @@ where
     let compiled: Vec<Condition> = scope
         .constraints()
         .iter()
-        .filter_map(build_constraint_condition::<E>)
+        .filter_map(|c| build_constraint_condition::<E>(c, backend))
         .collect();
 
     match compiled.len() {
@@ -79,10 +183,41 @@ where
     }
 }
 
+/// `col IN (SELECT via FROM J WHERE <scope> AND cond)`.
+///
+/// A semi-join on `J` that only sees the `J` rows `scope` allows. Each outer
+/// row matches at most once however many `J` rows it joins, so ordering and
+/// cursors on the outer query are unaffected. As with
+/// [`build_scope_condition`], JSON-path filters in `scope` fail their
+/// constraint.
+pub fn scoped_in_subquery<J, C>(
+    col: C,
+    via: J::Column,
+    scope: &AccessScope,
+    cond: Condition,
+) -> Condition
+where
+    J: ScopableEntity + EntityTrait,
+    J::Column: ColumnTrait + Copy,
+    C: ColumnTrait,
+{
+    let mut sub = Query::select();
+    sub.column(via).from(J::default()).cond_where(
+        Condition::all()
+            .add(build_scope_condition::<J>(scope))
+            .add(cond),
+    );
+    Condition::all().add(col.in_subquery(sub))
+}
+
 /// Build SQL for a single constraint (AND of filters).
 ///
-/// Returns `None` if any filter references an unknown property (fail-closed).
-fn build_constraint_condition<E>(constraint: &ScopeConstraint) -> Option<Condition>
+/// Returns `None` if any filter references an unknown property, or a JSON path
+/// without a backend (fail-closed).
+fn build_constraint_condition<E>(
+    constraint: &ScopeConstraint,
+    backend: Option<DbBackend>,
+) -> Option<Condition>
 where
     E: ScopableEntity + EntityTrait,
     E::Column: ColumnTrait + Copy,
@@ -92,6 +227,11 @@ where
     }
     let mut and_cond = Condition::all();
     for filter in constraint.filters() {
+        if let Some(path) = filter.json_path() {
+            let col = E::resolve_property(path.column_property())?;
+            and_cond = and_cond.add(json_filter_expr(col, &path, filter, backend?));
+            continue;
+        }
         let col = E::resolve_property(filter.property())?;
         match filter {
             ScopeFilter::Eq(eq) => {
@@ -102,11 +242,61 @@ where
                 let sea_values = scope_values_to_sea_values(inf.values());
                 and_cond = and_cond.add(Expr::col(col).is_in(sea_values));
             }
+            ScopeFilter::NotIn(nif) => {
+                let sea_values = scope_values_to_sea_values(nif.values());
+                and_cond = and_cond.add(Expr::col(col).is_not_in(sea_values));
+            }
         }
     }
     Some(and_cond)
 }
 
+/// Builds a scope condition for a given backend.
+pub(crate) type ScopeConditionFn = fn(&AccessScope, Option<DbBackend>) -> Condition;
+
+/// A scope whose condition is built when the query runs.
+///
+/// Scopes with JSON-path filters compile to backend-specific SQL, so the
+/// condition can only be built once the runner, and with it the backend, is
+/// known. Without a backend the JSON constraints fail closed.
+#[derive(Clone, Debug)]
+pub(crate) struct DeferredScope {
+    scope: Arc<AccessScope>,
+    build: ScopeConditionFn,
+}
+
+impl DeferredScope {
+    /// Defer `scope` on entity `E` if it has JSON-path filters.
+    pub(crate) fn for_scope<E>(scope: &Arc<AccessScope>) -> Option<Self>
+    where
+        E: ScopableEntity + EntityTrait,
+        E::Column: ColumnTrait + Copy,
+    {
+        scope.has_json_paths().then(|| Self {
+            scope: Arc::clone(scope),
+            build: build_scope_condition_for::<E>,
+        })
+    }
+
+    pub(crate) fn condition(&self, backend: Option<DbBackend>) -> Condition {
+        (self.build)(&self.scope, backend)
+    }
+}
+
+/// AND the `deferred` scope conditions, built for `backend`, into `query`.
+pub(crate) fn apply_deferred<Q>(
+    query: Q,
+    deferred: &[DeferredScope],
+    backend: Option<DbBackend>,
+) -> Q
+where
+    Q: QueryFilter,
+{
+    deferred
+        .iter()
+        .fold(query, |q, d| q.filter(d.condition(backend)))
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -167,6 +357,7 @@ mod tests {
             pub id: Uuid,
             pub tenant_id: Uuid,
             pub department_id: Uuid,
+            pub metadata: Json,
         }
 
         #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -192,6 +383,7 @@ mod tests {
                     p if p == pep_properties::OWNER_TENANT_ID => Some(Column::TenantId),
                     p if p == pep_properties::RESOURCE_ID => Some(Column::Id),
                     "department_id" => Some(Column::DepartmentId),
+                    "metadata" => Some(Column::Metadata),
                     _ => None,
                 }
             }
@@ -266,4 +458,149 @@ mod tests {
             "Expected a real condition, got deny-all: {cond_str}"
         );
     }
+
+    #[test]
+    fn test_not_in_filter_produces_not_in_condition() {
+        use sea_orm::QueryTrait;
+
+        let tid = uuid::Uuid::new_v4();
+        let excluded = uuid::Uuid::new_v4();
+        let scope = AccessScope::from_constraints(vec![ScopeConstraint::new(vec![
+            ScopeFilter::eq(pep_properties::OWNER_TENANT_ID, tid),
+            ScopeFilter::not_in_uuids("department_id", [excluded]),
+        ])]);
+        let cond = build_scope_condition::<custom_prop_entity::Entity>(&scope);
+        let sql = custom_prop_entity::Entity::find()
+            .filter(cond)
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert!(
+            sql.contains(&format!(r#""department_id" NOT IN ('{excluded}')"#)),
+            "{sql}"
+        );
+    }
+
+    // --- JSON paths ---
+
+    fn render(scope: &AccessScope, backend: DbBackend) -> String {
+        use sea_orm::QueryTrait;
+
+        let cond = build_scope_condition_for::<custom_prop_entity::Entity>(scope, Some(backend));
+        custom_prop_entity::Entity::find()
+            .filter(cond)
+            .build(backend)
+            .to_string()
+    }
+
+    fn department_scope() -> AccessScope {
+        AccessScope::from_constraints(vec![ScopeConstraint::new(vec![
+            ScopeFilter::eq(pep_properties::OWNER_TENANT_ID, uuid::Uuid::nil()),
+            ScopeFilter::eq("metadata->department", "sales"),
+        ])])
+    }
+
+    #[test]
+    fn json_path_filter_renders_per_backend() {
+        let scope = department_scope();
+
+        let pg = render(&scope, DbBackend::Postgres);
+        assert!(
+            pg.contains(r#"("metadata" ->> 'department') = 'sales'"#),
+            "{pg}"
+        );
+        assert!(pg.contains(r#""tenant_id" = "#), "{pg}");
+
+        let sqlite = render(&scope, DbBackend::Sqlite);
+        assert!(
+            sqlite.contains(r#"json_extract("metadata", '$."department"') = 'sales'"#),
+            "{sqlite}"
+        );
+    }
+
+    #[test]
+    fn nested_json_path_compares_native_values_on_sqlite() {
+        let scope =
+            AccessScope::from_constraints(vec![ScopeConstraint::new(vec![ScopeFilter::r#in(
+                "metadata->labels->level",
+                vec![ScopeValue::Int(1), ScopeValue::Int(2)],
+            )])]);
+
+        let pg = render(&scope, DbBackend::Postgres);
+        assert!(
+            pg.contains(r#"("metadata" -> 'labels' ->> 'level') IN ('1', '2')"#),
+            "{pg}"
+        );
+
+        let sqlite = render(&scope, DbBackend::Sqlite);
+        assert!(
+            sqlite.contains(r#"json_extract("metadata", '$."labels"."level"') IN (1, 2)"#),
+            "{sqlite}"
+        );
+    }
+
+    #[test]
+    fn scoped_subquery_carries_the_joined_scope() {
+        use custom_prop_entity::{Column, Entity};
+        use sea_orm::QueryTrait;
+
+        let tid = uuid::Uuid::new_v4();
+        let dept = uuid::Uuid::new_v4();
+        let cond = scoped_in_subquery::<Entity, _>(
+            Column::Id,
+            Column::Id,
+            &AccessScope::for_tenant(tid),
+            Condition::all().add(Expr::col(Column::DepartmentId).eq(dept)),
+        );
+        let sql = Entity::find()
+            .filter(cond)
+            .build(DbBackend::Postgres)
+            .to_string();
+
+        assert!(
+            sql.contains(r#"WHERE "custom_prop_test"."id" IN (SELECT "id" FROM "custom_prop_test" WHERE "tenant_id" IN ("#),
+            "{sql}"
+        );
+        assert!(sql.contains(&tid.to_string()), "{sql}");
+        assert!(
+            sql.contains(&format!(r#""department_id" = '{dept}'"#)),
+            "{sql}"
+        );
+
+        let denied = scoped_in_subquery::<Entity, _>(
+            Column::Id,
+            Column::Id,
+            &AccessScope::default(),
+            Condition::all(),
+        );
+        let denied = Entity::find()
+            .filter(denied)
+            .build(DbBackend::Postgres)
+            .to_string();
+        assert!(denied.contains("WHERE FALSE"), "{denied}");
+    }
+
+    #[test]
+    fn json_path_without_backend_denies() {
+        let cond = build_scope_condition::<custom_prop_entity::Entity>(&department_scope());
+        let cond_str = format!("{cond:?}");
+        assert!(
+            cond_str.contains("Value(Bool(Some(false)))"),
+            "Expected deny-all, got: {cond_str}"
+        );
+
+        let deferred =
+            DeferredScope::for_scope::<custom_prop_entity::Entity>(&Arc::new(department_scope()))
+                .expect("JSON scopes are deferred");
+        assert_eq!(
+            format!("{:?}", deferred.condition(None)),
+            cond_str,
+            "deferred scope without a backend fails closed too"
+        );
+        assert!(
+            DeferredScope::for_scope::<custom_prop_entity::Entity>(&Arc::new(
+                AccessScope::for_tenant(uuid::Uuid::nil())
+            ))
+            .is_none()
+        );
+    }
 }
@@ -41,10 +41,12 @@
 //! let user_id = result?;
 //! ```
 
-use std::{cell::Cell, future::Future, pin::Pin, sync::Arc};
+use std::{cell::Cell, future::Future, pin::Pin, sync::Arc, time::Duration};
 
 use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use tokio_util::sync::CancellationToken;
 
+use super::query_guard::current_cancellation;
 use super::tx_config::TxConfig;
 use super::tx_error::TxError;
 use crate::{DbError, DbHandle};
@@ -175,6 +177,7 @@ impl Db {
         }
         Ok(DbConn {
             conn: self.handle.sea_internal_ref(),
+            cancel: current_cancellation(),
         })
     }
 
@@ -227,7 +230,7 @@ impl Db {
         T: Send + 'static,
     {
         let txn = self.handle.sea_internal_ref().begin().await?;
-        let tx = DbTx { tx: &txn };
+        let tx = DbTx::new(&txn);
 
         // Run the closure with the transaction guard set
         let res = with_tx_guard(f(&tx)).await;
@@ -276,7 +279,7 @@ impl Db {
             .await
             .map_err(DbError::from)
             .map_err(E::from)?;
-        let tx = DbTx { tx: &txn };
+        let tx = DbTx::new(&txn);
 
         // Run the closure with the transaction guard set
         let res = with_tx_guard(f(&tx)).await;
@@ -338,7 +341,7 @@ impl Db {
             Ok(t) => t,
             Err(e) => return (self, Err(e.into())),
         };
-        let tx = DbTx { tx: &txn };
+        let tx = DbTx::new(&txn);
 
         // Run the closure with the transaction guard set
         let res = with_tx_guard(f(&tx)).await;
@@ -389,7 +392,7 @@ impl Db {
             Err(e) => return (self, Err(TxError::Infra(InfraError::new(e.to_string())))),
         };
 
-        let tx = DbTx { tx: &txn };
+        let tx = DbTx::new(&txn);
 
         // Run the closure with the transaction guard set
         let res = with_tx_guard(f(&tx)).await;
@@ -408,6 +411,11 @@ impl Db {
 
     /// Execute a transaction with custom configuration (isolation level, access mode).
     ///
+    /// When [`TxConfig::retry_on_conflict`] is set, the closure is re-run in a fresh
+    /// transaction if the attempt fails with a serialization failure or deadlock,
+    /// up to [`TxConfig::max_retries`] times with exponential backoff. The closure
+    /// must therefore be safe to execute more than once.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -415,7 +423,8 @@ impl Db {
     ///
     /// let config = TxConfig {
     ///     isolation: Some(TxIsolationLevel::Serializable),
-    ///     access_mode: None,
+    ///     retry_on_conflict: true,
+    ///     ..Default::default()
     /// };
     ///
     /// let (db, result) = db.transaction_with_config(config, |tx| {
@@ -432,39 +441,120 @@ impl Db {
     ) -> (Self, anyhow::Result<T>)
     where
         T: Send + 'static,
-        F: for<'a> FnOnce(
-                &'a DbTx<'a>,
-            )
-                -> Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>
+        F: for<'a> Fn(&'a DbTx<'a>) -> Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>
+            + Send
+            + Sync,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.run_with_config(&config, &f).await {
+                Err(e) if config.should_retry(attempt, &e) => {
+                    let delay = config.backoff_delay(attempt);
+                    attempt += 1;
+                    tracing::warn!(
+                        attempt,
+                        max_retries = config.max_retries,
+                        delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        error = %e,
+                        "Transaction conflict, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                res => return (self, res),
+            }
+        }
+    }
+
+    /// Execute a single configured transaction with typed domain errors (borrowed form).
+    ///
+    /// This is the building block for [`DBProvider::with_replica_tx`](crate::DBProvider::with_replica_tx):
+    /// isolation level, access mode and statement timeout from `config` are applied,
+    /// but the closure runs at most once, so `retry_on_conflict` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TxError::Infra` if starting or committing the transaction fails,
+    /// and `TxError::Domain` if the closure returns an error (the transaction is
+    /// rolled back).
+    pub async fn in_transaction_ref_with_config<T, E, F>(
+        &self,
+        config: &TxConfig,
+        f: F,
+    ) -> Result<T, TxError<E>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+        F: for<'a> FnOnce(&'a DbTx<'a>) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'a>>
             + Send,
     {
+        use super::tx_error::InfraError;
         use sea_orm::{AccessMode, IsolationLevel};
 
+        let infra = |e: DbError| TxError::Infra(InfraError::new(e.to_string()));
+
         let isolation: Option<IsolationLevel> = config.isolation.map(Into::into);
         let access_mode: Option<AccessMode> = config.access_mode.map(Into::into);
 
-        let txn = match self
+        let txn = self
             .handle
             .sea_internal_ref()
             .begin_with_config(isolation, access_mode)
             .await
-        {
-            Ok(t) => t,
-            Err(e) => return (self, Err(e.into())),
+            .map_err(|e| infra(e.into()))?;
+        config.apply_statement_timeout(&txn).await.map_err(infra)?;
+        let tx = DbTx {
+            statement_timeout: config.statement_timeout,
+            ..DbTx::new(&txn)
         };
-        let tx = DbTx { tx: &txn };
 
         // Run the closure with the transaction guard set
         let res = with_tx_guard(f(&tx)).await;
 
         match res {
-            Ok(v) => match txn.commit().await {
-                Ok(()) => (self, Ok(v)),
-                Err(e) => (self, Err(e.into())),
-            },
+            Ok(v) => {
+                txn.commit().await.map_err(|e| infra(e.into()))?;
+                Ok(v)
+            }
             Err(e) => {
                 _ = txn.rollback().await;
-                (self, Err(e))
+                Err(TxError::Domain(e))
+            }
+        }
+    }
+
+    /// Run a single configured transaction attempt.
+    async fn run_with_config<T, F>(&self, config: &TxConfig, f: &F) -> anyhow::Result<T>
+    where
+        F: for<'a> Fn(&'a DbTx<'a>) -> Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>,
+    {
+        use sea_orm::{AccessMode, IsolationLevel};
+
+        let isolation: Option<IsolationLevel> = config.isolation.map(Into::into);
+        let access_mode: Option<AccessMode> = config.access_mode.map(Into::into);
+
+        let txn = self
+            .handle
+            .sea_internal_ref()
+            .begin_with_config(isolation, access_mode)
+            .await
+            .map_err(DbError::from)?;
+        config.apply_statement_timeout(&txn).await?;
+        let tx = DbTx {
+            statement_timeout: config.statement_timeout,
+            ..DbTx::new(&txn)
+        };
+
+        // Run the closure with the transaction guard set
+        let res = with_tx_guard(f(&tx)).await;
+
+        match res {
+            Ok(v) => {
+                txn.commit().await.map_err(DbError::from)?;
+                Ok(v)
+            }
+            Err(e) => {
+                _ = txn.rollback().await;
+                Err(e)
             }
         }
     }
@@ -506,6 +596,23 @@ impl Db {
 /// ```
 pub struct DbConn<'a> {
     pub(crate) conn: &'a DatabaseConnection,
+    pub(crate) cancel: Option<CancellationToken>,
+}
+
+impl DbConn<'_> {
+    /// Abort queries run on this connection with [`ScopeError::Cancelled`] once
+    /// `token` is cancelled.
+    ///
+    /// Inside [`with_request_cancellation`] the request's token is attached
+    /// automatically; use this for other long-running callers (jobs, workers).
+    ///
+    /// [`ScopeError::Cancelled`]: super::ScopeError::Cancelled
+    /// [`with_request_cancellation`]: super::with_request_cancellation
+    #[must_use]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
 }
 
 impl std::fmt::Debug for DbConn<'_> {
@@ -541,6 +648,18 @@ impl std::fmt::Debug for DbConn<'_> {
 /// ```
 pub struct DbTx<'a> {
     pub(crate) tx: &'a DatabaseTransaction,
+    pub(crate) cancel: Option<CancellationToken>,
+    pub(crate) statement_timeout: Option<Duration>,
+}
+
+impl<'a> DbTx<'a> {
+    fn new(tx: &'a DatabaseTransaction) -> Self {
+        Self {
+            tx,
+            cancel: current_cancellation(),
+            statement_timeout: None,
+        }
+    }
 }
 
 impl std::fmt::Debug for DbTx<'_> {
@@ -93,6 +93,10 @@
 //!     .all(conn);  // ERROR: method not found in `SecureSelect<E, Unscoped>`
 //! ```
 //!
+//! `ScopableEntity::find_scoped()` is shorthand for `Entity::find().secure()`.
+//! Executing a plain `Entity::find()` does not compile either: `DbConn` and
+//! `DbTx` do not implement `SeaORM`'s `ConnectionTrait`.
+//!
 //! ## Implicit Security Policy
 //!
 //! The layer enforces these rules automatically:
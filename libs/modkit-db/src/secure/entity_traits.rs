@@ -1,5 +1,7 @@
 use sea_orm::EntityTrait;
 
+use crate::secure::{SecureEntityExt, SecureSelect, Unscoped};
+
 /// Defines the contract for entities that can be scoped by tenant, resource, owner, and type.
 ///
 /// Each entity implementing this trait must explicitly declare all four scope dimensions:
@@ -152,4 +154,66 @@ pub trait ScopableEntity: EntityTrait {
     /// Manual implementors must provide all property arms explicitly.
     #[must_use]
     fn resolve_property(property: &str) -> Option<Self::Column>;
+
+    /// Composite unique constraints that involve scope columns.
+    ///
+    /// Each group lists the columns of one composite key, e.g.
+    /// `&[&[Column::TenantId, Column::Email]]`. Secure inserts reject an
+    /// `ActiveModel` that sets only part of a group, since a partial key would
+    /// be completed by database defaults outside of scope validation.
+    ///
+    /// Default: no composite keys.
+    #[must_use]
+    fn unique_scope_columns() -> &'static [&'static [Self::Column]] {
+        &[]
+    }
+
+    /// Names of every column scoping reads: the dimension columns (tenant,
+    /// resource, owner, type) followed by the `pep_prop(...)` columns.
+    ///
+    /// For tooling that must not hardcode them, such as a migration
+    /// backfilling `tenant_id` or a data access audit. `#[derive(Scopable)]`
+    /// generates it; manual implementors override the default, which lists none.
+    #[must_use]
+    fn scope_columns() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Column stamped with the insert time by the `_audited` write helpers.
+    ///
+    /// Set via `created_at_col = "..."`. Default: none.
+    fn created_at_col() -> Option<Self::Column> {
+        None
+    }
+
+    /// Column stamped with the write time on inserts and updates by the
+    /// `_audited` write helpers.
+    ///
+    /// Set via `updated_at_col = "..."`. Default: none.
+    fn updated_at_col() -> Option<Self::Column> {
+        None
+    }
+
+    /// Column stamped with the inserting subject by the `_audited` write helpers.
+    ///
+    /// Set via `created_by_col = "..."`. Default: none.
+    fn created_by_col() -> Option<Self::Column> {
+        None
+    }
+
+    /// Column stamped with the writing subject on inserts and updates by the
+    /// `_audited` write helpers.
+    ///
+    /// Set via `updated_by_col = "..."`. Default: none.
+    fn updated_by_col() -> Option<Self::Column> {
+        None
+    }
+
+    /// Start a secure `SELECT`: shorthand for `Entity::find().secure()`.
+    ///
+    /// The query must still be scoped with `scope_with` before it can run.
+    #[must_use]
+    fn find_scoped() -> SecureSelect<Self, Unscoped> {
+        Self::find().secure()
+    }
 }
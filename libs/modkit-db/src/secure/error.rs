@@ -1,11 +1,32 @@
+use std::time::Duration;
+
 use uuid::Uuid;
 
 /// Errors that can occur during scoped query execution.
 #[derive(thiserror::Error, Debug)]
 pub enum ScopeError {
     /// Database error occurred during query execution.
+    ///
+    /// Errors with a recognized SQLSTATE become [`Self::UniqueViolation`] or
+    /// [`Self::SerializationFailure`] instead; see [`Self::sql_state`].
     #[error("database error: {0}")]
-    Db(#[from] sea_orm::DbErr),
+    Db(sea_orm::DbErr),
+
+    /// A unique or primary key constraint was violated (Postgres `23505`,
+    /// `SQLite` 2067/1555, `MySQL` 1062).
+    ///
+    /// `constraint` is the constraint name reported by the backend, or the
+    /// offending columns on `SQLite`; empty if the backend reports neither.
+    #[error("unique constraint violated: {constraint}")]
+    UniqueViolation {
+        constraint: String,
+        source: sea_orm::DbErr,
+    },
+
+    /// The transaction conflicted with a concurrent one (serialization failure
+    /// or deadlock). Retrying the whole transaction is safe.
+    #[error("transaction serialization failure: {0}")]
+    SerializationFailure(#[source] sea_orm::DbErr),
 
     /// Invalid scope configuration.
     #[error("invalid scope: {0}")]
@@ -15,7 +36,80 @@ pub enum ScopeError {
     #[error("access denied: tenant_id not present in security scope ({tenant_id})")]
     TenantNotInScope { tenant_id: Uuid },
 
+    /// Entity not found in the current security scope.
+    ///
+    /// Distinct from `Denied` so callers can map it to HTTP 404 rather than 403.
+    #[error("entity not found{}", .id.map(|id| format!(": {id}")).unwrap_or_default())]
+    NotFound { id: Option<Uuid> },
+
+    /// A foreign-key value does not refer to an entity visible in the scope.
+    ///
+    /// Returned by [`assert_ref_in_scope`](super::assert_ref_in_scope); `entity`
+    /// is the table of the referenced entity.
+    #[error("referenced {entity} not found in security scope: {id}")]
+    ReferenceNotInScope { entity: &'static str, id: Uuid },
+
+    /// Several foreign-key values do not refer to entities visible in the scope.
+    ///
+    /// Returned by [`assert_refs_in_scope`](super::assert_refs_in_scope) with
+    /// every missing id.
+    #[error("referenced {entity} not found in security scope: {}", format_ids(.ids))]
+    ReferencesNotInScope {
+        entity: &'static str,
+        ids: Vec<Uuid>,
+    },
+
     /// Operation denied - entity not accessible in current security scope.
     #[error("access denied: {0}")]
     Denied(&'static str),
+
+    /// A write was attempted with a scope issued for a read-only action.
+    #[error("access denied: scope is read-only")]
+    ReadOnlyScope,
+
+    /// The query exceeded its timeout and was aborted.
+    #[error("query timed out after {0:?}")]
+    QueryTimeout(Duration),
+
+    /// The query was aborted because the request was cancelled (e.g. client disconnect).
+    #[error("query cancelled")]
+    Cancelled,
+}
+
+impl From<sea_orm::DbErr> for ScopeError {
+    fn from(err: sea_orm::DbErr) -> Self {
+        let Some(code) = crate::db_error_code(&err) else {
+            return Self::Db(err);
+        };
+        if crate::is_unique_violation_code(&code) {
+            Self::UniqueViolation {
+                constraint: crate::db_error_constraint(&err).unwrap_or_default(),
+                source: err,
+            }
+        } else if crate::is_serialization_failure_code(&code) {
+            Self::SerializationFailure(err)
+        } else {
+            Self::Db(err)
+        }
+    }
+}
+
+impl ScopeError {
+    /// SQLSTATE (or vendor code) of the underlying database error, if any.
+    #[must_use]
+    pub fn sql_state(&self) -> Option<String> {
+        match self {
+            Self::Db(err)
+            | Self::UniqueViolation { source: err, .. }
+            | Self::SerializationFailure(err) => crate::db_error_code(err),
+            _ => None,
+        }
+    }
+}
+
+fn format_ids(ids: &[Uuid]) -> String {
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
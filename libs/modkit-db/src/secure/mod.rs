@@ -83,6 +83,30 @@
 //! }
 //! ```
 //!
+//! # Scopes from a PDP response
+//!
+//! Services normally get their scope from `PolicyEnforcer::access_scope`. Code
+//! that calls the PDP itself compiles the response with
+//! `EvaluationResponse::to_access_scope` from `authz-resolver-sdk` (this crate
+//! cannot re-export it, as the SDK depends on `modkit`, which depends on us):
+//!
+//! ```rust,ignore
+//! let response = authz.evaluate(request).await?;
+//! if !response.decision {
+//!     return Err(forbidden(response.context.deny_reason));
+//! }
+//! let scope = response.to_access_scope(true, &[pep_properties::OWNER_TENANT_ID])?;
+//! let users = Entity::find().secure().scope_with(&scope).all(conn).await?;
+//! ```
+//!
+//! # JSON-path filters
+//!
+//! A scope filter property of the form `metadata->department` compares the
+//! value at that path inside the JSON column `resolve_property("metadata")`
+//! maps to. The SQL differs per backend (`->>` on Postgres, `json_extract` on
+//! `SQLite`), so these conditions are added when the query runs. `into_inner()`
+//! cannot know the backend and denies the JSON constraints instead.
+//!
 //! # Features
 //!
 //! - **Typestate enforcement**: Prevents unscoped queries at compile time
@@ -103,6 +127,7 @@
 //! See the [docs module](docs) for comprehensive examples and usage patterns.
 
 // Module declarations
+mod audit;
 mod cond;
 mod db;
 mod db_ops;
@@ -111,7 +136,10 @@ pub mod docs;
 mod entity_traits;
 mod error;
 pub mod provider;
+mod query_guard;
+mod refs;
 mod runner;
+mod scope_check;
 mod secure_conn;
 mod select;
 mod tests;
@@ -126,8 +154,8 @@ pub use error::ScopeError;
 
 // Security types from modkit-security
 pub use modkit_security::{
-    AccessScope, EqScopeFilter, InScopeFilter, ScopeConstraint, ScopeFilter, ScopeValue,
-    pep_properties,
+    AccessScope, EqScopeFilter, InScopeFilter, JsonPath, NotInScopeFilter, ScopeConstraint,
+    ScopeFilter, ScopeIntent, ScopeValue, pep_properties,
 };
 
 // Ergonomic secure connection API (no raw SeaORM types leaked)
@@ -145,22 +173,39 @@ pub use db::{Db, DbConn, DbTx};
 // Transaction error types (no SeaORM types leaked)
 pub use tx_error::{InfraError, TxError};
 
+// Query timeouts and request cancellation
+pub(crate) use query_guard::run_query;
+pub use query_guard::with_request_cancellation;
+
 // Transaction configuration (no SeaORM types leaked)
 pub use tx_config::{TxAccessMode, TxConfig, TxIsolationLevel};
 
+// Backend-specific JSON-path SQL, shared with the OData filters
+pub(crate) use cond::json_path_expr;
+
+// Scoped semi-joins on related entities
+pub use cond::scoped_in_subquery;
+
 // Select operations
 pub use select::{
-    Scoped, SecureEntityExt, SecureFindRelatedExt, SecureSelect, SecureSelectTwo,
-    SecureSelectTwoMany, Unscoped,
+    PartialSecureSelect, Scoped, SecureEntityExt, SecureFindRelatedExt, SecureSelect,
+    SecureSelectTwo, SecureSelectTwoMany, Unscoped,
 };
 
 // Update/Delete/Insert operations
 pub use db_ops::{
     SecureDeleteExt, SecureDeleteMany, SecureInsertExt, SecureInsertOne, SecureOnConflict,
-    SecureUpdateExt, SecureUpdateMany, secure_insert, secure_update_with_scope,
-    validate_tenant_in_scope,
+    SecureUpdateExt, SecureUpdateMany, secure_delete_one, secure_insert, secure_insert_audited,
+    secure_insert_many, secure_insert_many_audited, secure_update_with_scope,
+    secure_update_with_scope_audited, validate_insert_scope, validate_tenant_in_scope,
 };
 
+// Foreign-key scope checks
+pub use refs::{assert_ref_in_scope, assert_refs_in_scope};
+
+// Structural scope check for tests and debug assertions
+pub use scope_check::scope_is_top_level;
+
 // Provider pattern for advanced tenant filtering
 pub use provider::{SimpleTenantFilter, TenantFilterProvider};
 
@@ -0,0 +1,100 @@
+//! Query timeouts and cancellation for secure query execution.
+//!
+//! Every execution method of the secure wrappers runs through [`run_query`], which
+//! bounds the query by its timeout (per-query `.timeout()` or the transaction's
+//! `TxConfig::statement_timeout`) and aborts it when the runner's cancellation
+//! token fires.
+//!
+//! Aborting drops the in-flight driver future, which returns the connection to the
+//! pool: Postgres and MySQL discard the pending result, `SQLite` stops stepping the
+//! statement at the next row. For a server-side guarantee on Postgres use
+//! `TxConfig::statement_timeout`, which also sets `statement_timeout` for the
+//! transaction.
+//!
+//! # Request cancellation
+//!
+//! The API gateway runs every request inside [`with_request_cancellation`]. Runners
+//! created in that task (`Db::conn()`, transactions, `DBProvider`) pick up the token,
+//! so a client disconnect or gateway timeout aborts the request's queries instead of
+//! leaving them running.
+
+use std::future::Future;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::secure::{DBRunnerInternal, ScopeError};
+
+tokio::task_local! {
+    static REQUEST_CANCELLATION: CancellationToken;
+}
+
+/// Run `fut` with `token` as the cancellation token for database runners
+/// acquired inside it.
+pub async fn with_request_cancellation<F>(token: CancellationToken, fut: F) -> F::Output
+where
+    F: Future,
+{
+    REQUEST_CANCELLATION.scope(token, fut).await
+}
+
+/// Cancellation token of the current request, if any.
+pub(crate) fn current_cancellation() -> Option<CancellationToken> {
+    REQUEST_CANCELLATION.try_with(Clone::clone).ok()
+}
+
+/// Execute `query` on behalf of `runner`, applying timeout and cancellation.
+///
+/// `timeout` overrides the runner's default statement timeout.
+pub(crate) async fn run_query<R, T, F>(
+    runner: &R,
+    timeout: Option<Duration>,
+    query: F,
+) -> Result<T, ScopeError>
+where
+    R: DBRunnerInternal + ?Sized,
+    F: Future<Output = Result<T, sea_orm::DbErr>>,
+{
+    let timeout = timeout.or_else(|| runner.statement_timeout());
+    let bounded = async {
+        let res = match timeout {
+            Some(limit) => tokio::time::timeout(limit, query)
+                .await
+                .map_err(|_| ScopeError::QueryTimeout(limit))?,
+            None => query.await,
+        };
+        res.map_err(|err| match timeout {
+            // Server-side statement timeout (Postgres `statement_timeout`)
+            Some(limit) if crate::is_query_canceled(&err) => ScopeError::QueryTimeout(limit),
+            _ => ScopeError::from(err),
+        })
+    };
+
+    match runner.cancellation() {
+        Some(token) => tokio::select! {
+            biased;
+            () = token.cancelled() => Err(ScopeError::Cancelled),
+            res = bounded => res,
+        },
+        None => bounded.await,
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn current_cancellation_is_scoped() {
+        assert!(current_cancellation().is_none());
+
+        let token = CancellationToken::new();
+        let inner =
+            with_request_cancellation(token.clone(), async { current_cancellation() }).await;
+
+        token.cancel();
+        assert!(inner.is_some_and(|t| t.is_cancelled()));
+        assert!(current_cancellation().is_none());
+    }
+}
@@ -0,0 +1,101 @@
+//! Scope checks for foreign-key values supplied by callers.
+//!
+//! Before inserting a row that references another entity (an address pointing
+//! at a city), the referenced id must be checked against the caller's scope on
+//! the *referenced* entity; otherwise any UUID, including one from another
+//! tenant, would be accepted. Both helpers take any [`DBRunner`], so the check
+//! can run in the same [`SecureTx`](super::SecureTx) as the insert.
+
+use std::collections::BTreeSet;
+
+use sea_orm::sea_query::Expr;
+use sea_orm::{ColumnTrait, EntityTrait, IdenStatic, ModelTrait};
+use uuid::Uuid;
+
+use crate::secure::{AccessScope, DBRunner, ScopableEntity, ScopeError, SecureEntityExt};
+
+/// Check that `fk_value` is the id of an `E` row visible in `scope`.
+///
+/// Runs a single scoped `exists()` on `E`'s resource column.
+///
+/// # Errors
+/// - `ScopeError::ReferenceNotInScope` if no such row is visible
+/// - `ScopeError::Invalid` if `E` has no resource column
+/// - `ScopeError::Db` if the query fails
+pub async fn assert_ref_in_scope<E>(
+    fk_value: Uuid,
+    scope: &AccessScope,
+    runner: &impl DBRunner,
+) -> Result<(), ScopeError>
+where
+    E: ScopableEntity + EntityTrait,
+    E::Column: ColumnTrait + Copy,
+{
+    let found = E::find()
+        .secure()
+        .scope_with(scope)
+        .and_id(fk_value)?
+        .exists(runner)
+        .await?;
+    if found {
+        Ok(())
+    } else {
+        Err(ScopeError::ReferenceNotInScope {
+            entity: entity_name::<E>(),
+            id: fk_value,
+        })
+    }
+}
+
+/// Check that every id in `fk_values` is an `E` row visible in `scope`.
+///
+/// Runs a single scoped `IN` query; duplicates are checked once.
+///
+/// # Errors
+/// - `ScopeError::ReferencesNotInScope` listing every id that is not visible
+/// - `ScopeError::Invalid` if `E` has no resource column
+/// - `ScopeError::Db` if the query fails
+pub async fn assert_refs_in_scope<E>(
+    fk_values: impl IntoIterator<Item = Uuid>,
+    scope: &AccessScope,
+    runner: &impl DBRunner,
+) -> Result<(), ScopeError>
+where
+    E: ScopableEntity + EntityTrait,
+    E::Column: ColumnTrait + Copy,
+{
+    let mut missing: BTreeSet<Uuid> = fk_values.into_iter().collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    let resource_col = E::resource_col().ok_or(ScopeError::Invalid(
+        "Entity must have a resource_col to check references",
+    ))?;
+
+    let found = E::find()
+        .secure()
+        .scope_with(scope)
+        .filter(
+            sea_orm::Condition::all().add(Expr::col(resource_col).is_in(missing.iter().copied())),
+        )
+        .all(runner)
+        .await?;
+    for model in &found {
+        if let sea_orm::Value::Uuid(Some(id)) = model.get(resource_col) {
+            missing.remove(&*id);
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(ScopeError::ReferencesNotInScope {
+            entity: entity_name::<E>(),
+            ids: missing.into_iter().collect(),
+        })
+    }
+}
+
+fn entity_name<E: EntityTrait>() -> &'static str {
+    E::default().as_str()
+}
@@ -9,6 +9,12 @@
 //! The `DBRunner` trait is **sealed** - it cannot be implemented outside this crate.
 //! This ensures that only `DbConn` and `DbTx` can be used as database runners,
 //! preventing user code from creating custom runners that could bypass transaction isolation.
+//! The only exception is the in-memory `fake::FakeRunner`, compiled with the `test-utils`
+//! feature, which runs the same secure wrappers against canned responses.
+
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
 
 use super::db::{DbConn, DbTx};
 use super::secure_conn::{SecureConn, SecureTx};
@@ -29,6 +35,26 @@ pub enum SeaOrmRunner<'a> {
 /// Internal-only bridge to `SeaORM`'s executor types.
 pub trait DBRunnerInternal: sealed::Sealed + Send + Sync {
     fn as_seaorm(&self) -> SeaOrmRunner<'_>;
+
+    /// Backend the queries are run on, for backend-specific SQL.
+    fn backend(&self) -> sea_orm::DbBackend {
+        use sea_orm::ConnectionTrait;
+
+        match self.as_seaorm() {
+            SeaOrmRunner::Conn(db) => db.get_database_backend(),
+            SeaOrmRunner::Tx(tx) => tx.get_database_backend(),
+        }
+    }
+
+    /// Token that aborts queries run through this runner when cancelled.
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        None
+    }
+
+    /// Timeout applied to queries that don't set their own.
+    fn statement_timeout(&self) -> Option<Duration> {
+        None
+    }
 }
 
 /// Hidden capability marker used by repositories and services.
@@ -47,6 +73,10 @@ impl DBRunnerInternal for DbConn<'_> {
     fn as_seaorm(&self) -> SeaOrmRunner<'_> {
         SeaOrmRunner::Conn(self.conn)
     }
+
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancel.as_ref()
+    }
 }
 impl DBRunner for DbConn<'_> {}
 
@@ -55,6 +85,14 @@ impl DBRunnerInternal for DbTx<'_> {
     fn as_seaorm(&self) -> SeaOrmRunner<'_> {
         SeaOrmRunner::Tx(self.tx)
     }
+
+    fn cancellation(&self) -> Option<&CancellationToken> {
+        self.cancel.as_ref()
+    }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
 }
 impl DBRunner for DbTx<'_> {}
 
@@ -73,5 +111,22 @@ impl DBRunnerInternal for SecureTx<'_> {
     fn as_seaorm(&self) -> SeaOrmRunner<'_> {
         SeaOrmRunner::Tx(self.tx)
     }
+
+    fn statement_timeout(&self) -> Option<Duration> {
+        self.statement_timeout
+    }
 }
 impl DBRunner for SecureTx<'_> {}
+
+// --- Test fake ---
+
+#[cfg(feature = "test-utils")]
+impl sealed::Sealed for crate::fake::FakeRunner {}
+#[cfg(feature = "test-utils")]
+impl DBRunnerInternal for crate::fake::FakeRunner {
+    fn as_seaorm(&self) -> SeaOrmRunner<'_> {
+        SeaOrmRunner::Conn(&self.conn)
+    }
+}
+#[cfg(feature = "test-utils")]
+impl DBRunner for crate::fake::FakeRunner {}
@@ -0,0 +1,302 @@
+//! Structural check that a query still enforces its access scope.
+//!
+//! `SecureSelect` AND-s the scope condition into the `WHERE` clause, so later
+//! filters can only narrow the result set. Code that builds conditions by hand
+//! (e.g. `Condition::any().add(scope).add(filter)`) can silently turn that into
+//! an `OR`. [`scope_is_top_level`] renders the query and verifies that every
+//! term of the scope condition is a top-level conjunct of the `WHERE` clause.
+
+use sea_orm::{
+    ColumnTrait, EntityName, EntityTrait, QueryTrait,
+    sea_query::{Query, SelectStatement, SqliteQueryBuilder},
+};
+
+use crate::secure::cond::build_scope_condition;
+use crate::secure::{AccessScope, ScopableEntity};
+
+/// Returns `true` if `select` enforces `scope` at the top level of its `WHERE`
+/// clause, i.e. no `OR` can bypass the scope condition.
+///
+/// Meant for tests and debug assertions: it renders SQL, so it is not free.
+/// Unconstrained scopes add no condition and always pass.
+#[must_use]
+pub fn scope_is_top_level<E>(select: &sea_orm::Select<E>, scope: &AccessScope) -> bool
+where
+    E: ScopableEntity + EntityTrait,
+    E::Column: ColumnTrait + Copy,
+{
+    if scope.is_unconstrained() {
+        return true;
+    }
+
+    let table = E::default().table_name().to_owned();
+    let mut scope_stmt = Query::select();
+    scope_stmt.cond_where(build_scope_condition::<E>(scope));
+
+    let (Some(scope_sql), Some(where_sql)) = (
+        where_clause(&scope_stmt, &table),
+        where_clause(select.as_query(), &table),
+    ) else {
+        return false;
+    };
+
+    let where_terms = conjuncts(&where_sql);
+    conjuncts(&scope_sql)
+        .iter()
+        .all(|term| where_terms.contains(term))
+}
+
+/// Top-level `AND` terms of `sql`, flattening parenthesized conjunctions:
+/// `(a AND b) AND c` yields `[a, b, c]`.
+fn conjuncts(sql: &str) -> Vec<&str> {
+    let mut terms = Vec::new();
+    for part in split_top_level(sql, " AND ") {
+        let inner = strip_outer_parens(part);
+        if inner.len() < part.len() && split_top_level(inner, " AND ").len() > 1 {
+            terms.extend(conjuncts(inner));
+        } else {
+            terms.push(inner);
+        }
+    }
+    terms
+}
+
+/// Render `stmt` and return its `WHERE` clause with `"table".` qualifiers
+/// removed, so `Expr::col(c)` and `c.eq(..)` render the same.
+fn where_clause(stmt: &SelectStatement, table: &str) -> Option<String> {
+    let sql = stmt
+        .to_string(SqliteQueryBuilder)
+        .replace(&format!("\"{table}\"."), "");
+    let start = find_top_level(&sql, " WHERE ")? + " WHERE ".len();
+    let rest = &sql[start..];
+    let end = [
+        " GROUP BY ",
+        " HAVING ",
+        " ORDER BY ",
+        " LIMIT ",
+        " OFFSET ",
+    ]
+    .iter()
+    .filter_map(|kw| find_top_level(rest, kw))
+    .min()
+    .unwrap_or(rest.len());
+    Some(rest[..end].to_owned())
+}
+
+/// Byte offsets of `needle` outside parentheses and quoted literals.
+fn top_level_matches(sql: &str, needle: &str) -> Vec<usize> {
+    let bytes = sql.as_bytes();
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match quote {
+            // Doubled quotes (`''`) toggle twice and stay inside the literal.
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None => match b {
+                b'\'' | b'"' => quote = Some(b),
+                b'(' => depth += 1,
+                b')' => depth = depth.saturating_sub(1),
+                _ if depth == 0 && bytes[i..].starts_with(needle.as_bytes()) => {
+                    found.push(i);
+                    i += needle.len();
+                    continue;
+                }
+                _ => {}
+            },
+        }
+        i += 1;
+    }
+    found
+}
+
+fn find_top_level(sql: &str, needle: &str) -> Option<usize> {
+    top_level_matches(sql, needle).first().copied()
+}
+
+fn split_top_level<'a>(sql: &'a str, sep: &str) -> Vec<&'a str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    for pos in top_level_matches(sql, sep) {
+        parts.push(sql[start..pos].trim());
+        start = pos + sep.len();
+    }
+    parts.push(sql[start..].trim());
+    parts
+}
+
+/// Strip parentheses that wrap the whole expression, e.g. `((a OR b))`.
+fn strip_outer_parens(mut sql: &str) -> &str {
+    while wraps_whole(sql) {
+        sql = sql[1..sql.len() - 1].trim();
+    }
+    sql
+}
+
+/// `true` if `sql` starts with a paren that closes at its last byte.
+/// `(a) OR (b)` starts and ends with parens that do not pair up.
+fn wraps_whole(sql: &str) -> bool {
+    if !sql.starts_with('(') {
+        return false;
+    }
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    for (i, b) in sql.bytes().enumerate() {
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None => match b {
+                b'\'' | b'"' => quote = Some(b),
+                b'(' => depth += 1,
+                b')' => {
+                    depth = depth.saturating_sub(1);
+                    if depth == 0 {
+                        return i == sql.len() - 1;
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::secure::SecureEntityExt;
+    use modkit_security::access_scope::{ScopeConstraint, ScopeFilter, pep_properties};
+    use sea_orm::{Condition, QueryFilter, sea_query::Expr};
+    use uuid::Uuid;
+
+    mod item {
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+        #[sea_orm(table_name = "scope_check_items")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: Uuid,
+            pub tenant_id: Uuid,
+            pub name: String,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    impl ScopableEntity for item::Entity {
+        fn tenant_col() -> Option<item::Column> {
+            Some(item::Column::TenantId)
+        }
+        fn resource_col() -> Option<item::Column> {
+            Some(item::Column::Id)
+        }
+        fn owner_col() -> Option<item::Column> {
+            None
+        }
+        fn type_col() -> Option<item::Column> {
+            None
+        }
+        fn resolve_property(property: &str) -> Option<item::Column> {
+            match property {
+                p if p == pep_properties::OWNER_TENANT_ID => Some(item::Column::TenantId),
+                p if p == pep_properties::RESOURCE_ID => Some(item::Column::Id),
+                _ => None,
+            }
+        }
+    }
+
+    fn name_is(name: &str) -> Condition {
+        Condition::all().add(Expr::col(item::Column::Name).eq(name))
+    }
+
+    #[test]
+    fn secure_select_keeps_scope_at_top_level() {
+        let scope = AccessScope::for_tenant(Uuid::new_v4());
+        let select = item::Entity::find()
+            .secure()
+            .scope_with(&scope)
+            .into_inner()
+            .filter(Condition::any().add(name_is("a")).add(name_is("b")));
+
+        assert!(scope_is_top_level(&select, &scope));
+    }
+
+    #[test]
+    fn find_scoped_is_secure_find() {
+        let scope = AccessScope::for_tenant(Uuid::new_v4());
+        let select = item::Entity::find_scoped().scope_with(&scope).into_inner();
+
+        assert!(scope_is_top_level(&select, &scope));
+        assert_eq!(
+            select.build(sea_orm::DbBackend::Sqlite).to_string(),
+            item::Entity::find()
+                .secure()
+                .scope_with(&scope)
+                .into_inner()
+                .build(sea_orm::DbBackend::Sqlite)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn multi_constraint_scope_is_checked_as_a_whole() {
+        let scope = AccessScope::from_constraints(vec![
+            ScopeConstraint::new(vec![ScopeFilter::in_uuids(
+                pep_properties::OWNER_TENANT_ID,
+                vec![Uuid::new_v4()],
+            )]),
+            ScopeConstraint::new(vec![
+                ScopeFilter::in_uuids(pep_properties::OWNER_TENANT_ID, vec![Uuid::new_v4()]),
+                ScopeFilter::in_uuids(pep_properties::RESOURCE_ID, vec![Uuid::new_v4()]),
+            ]),
+        ]);
+        let select = item::Entity::find()
+            .secure()
+            .scope_with(&scope)
+            .into_inner()
+            .filter(name_is("it's AND (quoted)"));
+
+        assert!(scope_is_top_level(&select, &scope));
+    }
+
+    #[test]
+    fn scope_inside_or_is_detected() {
+        let scope = AccessScope::for_tenant(Uuid::new_v4());
+        let select = item::Entity::find().filter(
+            Condition::any()
+                .add(build_scope_condition::<item::Entity>(&scope))
+                .add(name_is("a")),
+        );
+
+        assert!(!scope_is_top_level(&select, &scope));
+    }
+
+    #[test]
+    fn missing_scope_is_detected() {
+        let scope = AccessScope::for_tenant(Uuid::new_v4());
+        let other = AccessScope::for_tenant(Uuid::new_v4());
+        let select = item::Entity::find()
+            .secure()
+            .scope_with(&other)
+            .into_inner();
+
+        assert!(!scope_is_top_level(&select, &scope));
+        assert!(!scope_is_top_level(&item::Entity::find(), &scope));
+    }
+
+    #[test]
+    fn unconstrained_scope_always_passes() {
+        assert!(scope_is_top_level(
+            &item::Entity::find(),
+            &AccessScope::allow_all()
+        ));
+    }
+}
@@ -70,12 +70,16 @@ use crate::secure::db_ops::{SecureDeleteExt, SecureDeleteMany, SecureUpdateExt,
 /// This type intentionally does not expose any raw transaction or executor API.
 pub struct SecureTx<'a> {
     pub(crate) tx: &'a DatabaseTransaction,
+    pub(crate) statement_timeout: Option<std::time::Duration>,
 }
 
 impl<'a> SecureTx<'a> {
     #[must_use]
     pub(crate) fn new(tx: &'a DatabaseTransaction) -> Self {
-        Self { tx }
+        Self {
+            tx,
+            statement_timeout: None,
+        }
     }
 }
 
@@ -340,7 +344,7 @@ impl SecureConn {
     /// # Security
     ///
     /// - Validates the entity exists and is accessible in the security scope
-    /// - Returns `ScopeError::Denied` if the entity is not in scope
+    /// - Returns `ScopeError::NotFound` if the entity is not in scope
     /// - Ensures updates cannot affect entities outside the security boundary
     ///
     /// # Example
@@ -364,7 +368,7 @@ impl SecureConn {
     ///
     /// # Errors
     ///
-    /// - `ScopeError::Denied` if the entity is not accessible in the current scope
+    /// - `ScopeError::NotFound` if the entity is not accessible in the current scope
     /// - `ScopeError::Db` if the database operation fails
     pub async fn update_with_ctx<E>(
         &self,
@@ -596,9 +600,17 @@ impl SecureConn {
     /// let cfg = TxConfig {
     ///     isolation: Some(TxIsolationLevel::Serializable),
     ///     access_mode: Some(TxAccessMode::ReadWrite),
+    ///     ..Default::default()
     /// };
     /// ```
     ///
+    /// # Conflict Retry
+    ///
+    /// When [`TxConfig::retry_on_conflict`] is set, the closure is re-run in a fresh
+    /// transaction if the attempt fails with a serialization failure or deadlock,
+    /// up to [`TxConfig::max_retries`] times with exponential backoff. The closure
+    /// must therefore be safe to execute more than once.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -653,35 +665,61 @@ impl SecureConn {
     ) -> (Self, anyhow::Result<T>)
     where
         T: Send + 'static,
-        F: for<'a> FnOnce(
+        F: for<'a> Fn(
                 &'a SecureTx<'a>,
-            )
-                -> Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>
-            + Send,
+            ) -> Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>
+            + Send
+            + Sync,
+    {
+        let mut attempt = 0;
+        loop {
+            match self.run_with_config(&cfg, &f).await {
+                Err(e) if cfg.should_retry(attempt, &e) => {
+                    let delay = cfg.backoff_delay(attempt);
+                    attempt += 1;
+                    tracing::warn!(
+                        attempt,
+                        max_retries = cfg.max_retries,
+                        delay_ms = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX),
+                        error = %e,
+                        "Transaction conflict, retrying"
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                res => return (self, res),
+            }
+        }
+    }
+
+    /// Run a single configured transaction attempt.
+    async fn run_with_config<T, F>(&self, cfg: &TxConfig, f: &F) -> anyhow::Result<T>
+    where
+        F: for<'a> Fn(
+            &'a SecureTx<'a>,
+        ) -> Pin<Box<dyn Future<Output = anyhow::Result<T>> + Send + 'a>>,
     {
         let isolation: Option<IsolationLevel> = cfg.isolation.map(Into::into);
         let access_mode: Option<AccessMode> = cfg.access_mode.map(Into::into);
 
-        let txn = match self
+        let txn = self
             .conn_internal()
             .begin_with_config(isolation, access_mode)
             .await
-        {
-            Ok(t) => t,
-            Err(e) => return (self, Err(e.into())),
+            .map_err(crate::DbError::from)?;
+        cfg.apply_statement_timeout(&txn).await?;
+        let tx = SecureTx {
+            statement_timeout: cfg.statement_timeout,
+            ..SecureTx::new(&txn)
         };
-        let tx = SecureTx::new(&txn);
 
-        let res = f(&tx).await;
-
-        match res {
-            Ok(v) => match txn.commit().await {
-                Ok(()) => (self, Ok(v)),
-                Err(e) => (self, Err(e.into())),
-            },
+        match f(&tx).await {
+            Ok(v) => {
+                txn.commit().await.map_err(crate::DbError::from)?;
+                Ok(v)
+            }
             Err(e) => {
                 _ = txn.rollback().await;
-                (self, Err(e))
+                Err(e)
             }
         }
     }
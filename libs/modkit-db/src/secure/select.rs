@@ -1,12 +1,19 @@
+use futures_core::Stream;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use sea_orm::{
-    ColumnTrait, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
-    Related, sea_query::Expr,
+    ColumnTrait, DbBackend, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Related, sea_query::Expr,
 };
 use std::sync::Arc;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
 
-use crate::secure::cond::build_scope_condition;
+use crate::secure::cond::{DeferredScope, apply_deferred, build_scope_condition};
 use crate::secure::error::ScopeError;
-use crate::secure::{AccessScope, DBRunner, DBRunnerInternal, ScopableEntity, SeaOrmRunner};
+use crate::secure::{
+    AccessScope, DBRunner, DBRunnerInternal, ScopableEntity, SeaOrmRunner, run_query,
+};
 
 /// Typestate marker: query has not yet been scoped.
 /// Cannot execute queries in this state.
@@ -22,6 +29,16 @@ pub struct Unscoped;
 #[derive(Debug, Clone)]
 pub struct Scoped {
     scope: Arc<AccessScope>,
+    /// Scope conditions added when the query runs, once the backend is known
+    /// (scopes with JSON-path filters).
+    deferred: Vec<DeferredScope>,
+}
+
+impl Scoped {
+    /// AND the deferred scope conditions, built for `backend`, into `query`.
+    fn apply_deferred<Q: QueryFilter>(&self, query: Q, backend: Option<DbBackend>) -> Q {
+        apply_deferred(query, &self.deferred, backend)
+    }
 }
 
 /// A type-safe wrapper around `SeaORM`'s `Select` that enforces scoping.
@@ -62,6 +79,7 @@ pub struct Scoped {
 pub struct SecureSelect<E: EntityTrait, S> {
     pub(crate) inner: sea_orm::Select<E>,
     pub(crate) state: S,
+    pub(crate) timeout: Option<Duration>,
 }
 
 /// A type-safe wrapper around `SeaORM`'s `SelectTwo` that enforces scoping.
@@ -128,6 +146,29 @@ pub struct SecureSelectTwoMany<E: EntityTrait, F: EntityTrait, S> {
     pub(crate) state: S,
 }
 
+/// A scoped query restricted to a subset of columns (`SELECT col1, col2, ...`).
+///
+/// Created by [`SecureSelect::select_columns`]. Rows are returned as JSON objects
+/// keyed by column name, because a typed `E::Model` needs every column.
+///
+/// # Example
+/// ```rust,ignore
+/// let rows: Vec<serde_json::Value> = user::Entity::find()
+///     .secure()
+///     .scope_with(&scope)
+///     .select_columns([user::Column::Id, user::Column::Email])
+///     .all(conn)
+///     .await?;
+/// ```
+#[must_use]
+#[derive(Clone, Debug)]
+pub struct PartialSecureSelect<E: EntityTrait> {
+    pub(crate) inner: sea_orm::Select<E>,
+    pub(crate) state: Scoped,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) has_columns: bool,
+}
+
 /// Extension trait to convert a regular `SeaORM` `Select` into a `SecureSelect`.
 pub trait SecureEntityExt<E: EntityTrait>: Sized {
     /// Convert this select query into a secure (unscoped) select.
@@ -143,6 +184,7 @@ where
         SecureSelect {
             inner: self,
             state: Unscoped,
+            timeout: None,
         }
     }
 }
@@ -164,14 +206,13 @@ where
     /// - Resources only → filter by resource IDs
     /// - Both → AND them together
     ///
+    /// A deny-all scope still adds `WHERE false` (so `into_inner()` stays safe),
+    /// but the execution methods return empty results without querying the database.
+    ///
+    /// Scopes with JSON-path filters are applied when the query runs, as their
+    /// SQL depends on the backend; `into_inner()` denies those constraints.
     pub fn scope_with(self, scope: &AccessScope) -> SecureSelect<E, Scoped> {
-        let cond = build_scope_condition::<E>(scope);
-        SecureSelect {
-            inner: self.inner.filter(cond),
-            state: Scoped {
-                scope: Arc::new(scope.clone()),
-            },
-        }
+        self.scope_with_arc(Arc::new(scope.clone()))
     }
 
     /// Apply access control scope using an `Arc<AccessScope>`.
@@ -179,10 +220,19 @@ where
     /// This is useful when you already have the scope in an `Arc` and want to
     /// avoid an extra clone.
     pub fn scope_with_arc(self, scope: Arc<AccessScope>) -> SecureSelect<E, Scoped> {
-        let cond = build_scope_condition::<E>(&scope);
+        // Unconstrained scopes need no condition at all, and JSON-path scopes
+        // get theirs when the query runs.
+        let deferred: Vec<DeferredScope> =
+            DeferredScope::for_scope::<E>(&scope).into_iter().collect();
+        let inner = if scope.is_unconstrained() || !deferred.is_empty() {
+            self.inner
+        } else {
+            self.inner.filter(build_scope_condition::<E>(&scope))
+        };
         SecureSelect {
-            inner: self.inner.filter(cond),
-            state: Scoped { scope },
+            inner,
+            state: Scoped { scope, deferred },
+            timeout: self.timeout,
         }
     }
 }
@@ -195,44 +245,140 @@ where
     /// Execute the query and return all matching results.
     ///
     /// # Errors
-    /// Returns `ScopeError::Db` if the database query fails.
+    /// - `ScopeError::Db` if the database query fails
+    /// - `ScopeError::QueryTimeout` if the query exceeds its timeout
+    /// - `ScopeError::Cancelled` if the runner's cancellation token fires
     #[allow(clippy::disallowed_methods)]
     pub async fn all(self, runner: &impl DBRunner) -> Result<Vec<E::Model>, ScopeError> {
-        match DBRunnerInternal::as_seaorm(runner) {
-            SeaOrmRunner::Conn(db) => Ok(self.inner.all(db).await?),
-            SeaOrmRunner::Tx(tx) => Ok(self.inner.all(tx).await?),
+        if self.is_deny_all() {
+            return Ok(Vec::new());
         }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
+        run_query(runner, self.timeout, async move {
+            match DBRunnerInternal::as_seaorm(runner) {
+                SeaOrmRunner::Conn(db) => inner.all(db).await,
+                SeaOrmRunner::Tx(tx) => inner.all(tx).await,
+            }
+        })
+        .await
     }
 
     /// Execute the query and return at most one result.
     ///
     /// # Errors
-    /// Returns `ScopeError::Db` if the database query fails.
+    /// - `ScopeError::Db` if the database query fails
+    /// - `ScopeError::QueryTimeout` if the query exceeds its timeout
+    /// - `ScopeError::Cancelled` if the runner's cancellation token fires
     #[allow(clippy::disallowed_methods)]
     pub async fn one(self, runner: &impl DBRunner) -> Result<Option<E::Model>, ScopeError> {
-        match DBRunnerInternal::as_seaorm(runner) {
-            SeaOrmRunner::Conn(db) => Ok(self.inner.one(db).await?),
-            SeaOrmRunner::Tx(tx) => Ok(self.inner.one(tx).await?),
+        if self.is_deny_all() {
+            return Ok(None);
         }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
+        run_query(runner, self.timeout, async move {
+            match DBRunnerInternal::as_seaorm(runner) {
+                SeaOrmRunner::Conn(db) => inner.one(db).await,
+                SeaOrmRunner::Tx(tx) => inner.one(tx).await,
+            }
+        })
+        .await
     }
 
     /// Execute the query and return the number of matching results.
     ///
     /// # Errors
-    /// Returns `ScopeError::Db` if the database query fails.
+    /// - `ScopeError::Db` if the database query fails
+    /// - `ScopeError::QueryTimeout` if the query exceeds its timeout
+    /// - `ScopeError::Cancelled` if the runner's cancellation token fires
     #[allow(clippy::disallowed_methods)]
     pub async fn count(self, runner: &impl DBRunner) -> Result<u64, ScopeError>
     where
         E::Model: sea_orm::FromQueryResult + Send + Sync,
     {
-        match DBRunnerInternal::as_seaorm(runner) {
-            SeaOrmRunner::Conn(db) => Ok(self.inner.count(db).await?),
-            SeaOrmRunner::Tx(tx) => Ok(self.inner.count(tx).await?),
+        if self.is_deny_all() {
+            return Ok(0);
         }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
+        run_query(runner, self.timeout, async move {
+            match DBRunnerInternal::as_seaorm(runner) {
+                SeaOrmRunner::Conn(db) => inner.count(db).await,
+                SeaOrmRunner::Tx(tx) => inner.count(tx).await,
+            }
+        })
+        .await
     }
 
     // Note: count() uses SeaORM's `PaginatorTrait::count` internally.
 
+    /// Check whether at least one row matches the query.
+    ///
+    /// # Errors
+    /// Returns `ScopeError::Db` if the database query fails.
+    pub async fn exists(self, runner: &impl DBRunner) -> Result<bool, ScopeError> {
+        Ok(self.limit(1).one(runner).await?.is_some())
+    }
+
+    /// Execute the query and return the matching rows as a stream.
+    ///
+    /// Rows are decoded as they are consumed instead of being collected into a
+    /// `Vec`, for exports and batch jobs over large result sets.
+    ///
+    /// The stream holds a database connection while it is alive: for a
+    /// [`DbConn`](crate::secure::DbConn) one connection is taken from the pool
+    /// until the stream is dropped. Consume it fully or drop it promptly; never
+    /// keep it across unrelated awaits.
+    ///
+    /// The query timeout bounds opening the stream, not consuming it. If the
+    /// runner's cancellation token fires mid-stream, the stream yields
+    /// `ScopeError::Cancelled` and ends.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let mut rows = user::Entity::find()
+    ///     .secure()
+    ///     .scope_with(&scope)
+    ///     .stream(&conn)
+    ///     .await?;
+    /// while let Some(user) = rows.try_next().await? {
+    ///     export.write(&user)?;
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// - `ScopeError::Db` if the query fails to start, or per row if decoding fails
+    /// - `ScopeError::QueryTimeout` if opening the stream exceeds the query timeout
+    /// - `ScopeError::Cancelled` if the runner's cancellation token fires
+    #[allow(clippy::disallowed_methods)]
+    pub async fn stream<'r>(
+        self,
+        runner: &'r impl DBRunner,
+    ) -> Result<impl Stream<Item = Result<E::Model, ScopeError>> + Send + 'r, ScopeError>
+    where
+        E: 'r,
+    {
+        if self.is_deny_all() {
+            return Ok(futures_util::stream::empty::<Result<E::Model, ScopeError>>().boxed());
+        }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
+        let rows: BoxStream<'r, Result<E::Model, sea_orm::DbErr>> =
+            run_query(runner, self.timeout, async move {
+                Ok(match DBRunnerInternal::as_seaorm(runner) {
+                    SeaOrmRunner::Conn(db) => inner.stream(db).await?.boxed(),
+                    SeaOrmRunner::Tx(tx) => inner.stream(tx).await?.boxed(),
+                })
+            })
+            .await?;
+        Ok(guard_stream(rows, runner.cancellation().cloned()))
+    }
+
     // Note: For pagination, use `into_inner().paginate()` due to complex lifetime bounds
 
     /// Add an additional filter for a specific resource ID.
@@ -298,11 +444,20 @@ where
         self
     }
 
+    /// Abort the query with `ScopeError::QueryTimeout` if it runs longer than `timeout`.
+    ///
+    /// Overrides the transaction's `TxConfig::statement_timeout` for this query.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Apply scoping for a joined entity.
     ///
     /// This delegates to `build_scope_condition::<J>()` which handles all
     /// property types (tenant, resource, owner, custom PEP properties) with
-    /// proper OR/AND constraint semantics.
+    /// proper OR/AND constraint semantics. A scope with JSON-path filters is
+    /// applied when the query runs, like the query's own scope.
     ///
     /// # Example
     /// ```ignore
@@ -319,6 +474,10 @@ where
         J: ScopableEntity + EntityTrait,
         J::Column: ColumnTrait + Copy,
     {
+        if let Some(deferred) = DeferredScope::for_scope::<J>(&Arc::new(scope.clone())) {
+            self.state.deferred.push(deferred);
+            return self;
+        }
         let cond = build_scope_condition::<J>(scope);
         self.inner = QueryFilter::filter(self.inner, cond);
         self
@@ -335,7 +494,8 @@ where
     /// # Note
     /// This is a simplified EXISTS check (no join predicate linking back to the
     /// primary entity). For complex join predicates, use `into_inner()` and build
-    /// custom EXISTS clauses.
+    /// custom EXISTS clauses. JSON-path filters are not supported here; their
+    /// constraints deny.
     ///
     /// # Example
     /// ```ignore
@@ -364,14 +524,56 @@ where
         self
     }
 
+    /// Fetch only `cols` instead of every column of the entity.
+    ///
+    /// Emits `SELECT col1, col2, ... FROM ...` with the same scope, filters and
+    /// ordering. An empty column list is rejected when the query runs.
+    pub fn select_columns(
+        self,
+        cols: impl IntoIterator<Item = E::Column>,
+    ) -> PartialSecureSelect<E> {
+        let cols: Vec<E::Column> = cols.into_iter().collect();
+        let has_columns = !cols.is_empty();
+        let inner = QuerySelect::columns(QuerySelect::select_only(self.inner), cols);
+        PartialSecureSelect {
+            inner,
+            state: self.state,
+            timeout: self.timeout,
+            has_columns,
+        }
+    }
+
     /// Unwrap the inner `SeaORM` `Select` for advanced use cases.
     ///
     /// # Safety
     /// The caller must ensure they don't remove or bypass the security
-    /// conditions that were applied during `.scope_with()`.
+    /// conditions that were applied during `.scope_with()`. JSON-path scope
+    /// filters need the backend, so their constraints deny here.
     #[must_use]
     pub fn into_inner(self) -> sea_orm::Select<E> {
-        self.inner
+        self.state.apply_deferred(self.inner, None)
+    }
+
+    /// [`into_inner`](Self::into_inner) for a query run on `backend`, with
+    /// every scope condition in place.
+    pub(crate) fn into_inner_for(self, backend: DbBackend) -> sea_orm::Select<E> {
+        self.state.apply_deferred(self.inner, Some(backend))
+    }
+}
+
+impl<E> SecureSelect<E, Scoped>
+where
+    E: ScopableEntity + EntityTrait,
+    E::Column: ColumnTrait + Copy,
+{
+    /// Returns `true` if the stored scope is still AND-ed at the top level of
+    /// the `WHERE` clause. See [`scope_is_top_level`](super::scope_is_top_level).
+    ///
+    /// Deferred (JSON-path) scopes are AND-ed when the query runs, so they
+    /// always are.
+    #[must_use]
+    pub fn scope_is_top_level(&self) -> bool {
+        !self.state.deferred.is_empty() || super::scope_is_top_level(&self.inner, &self.state.scope)
     }
 }
 
@@ -417,6 +619,14 @@ where
         Arc::clone(&self.state.scope)
     }
 
+    /// Returns `true` if the stored scope denies all access.
+    ///
+    /// Such a query can never return rows, so execution methods skip the database.
+    #[must_use]
+    pub fn is_deny_all(&self) -> bool {
+        self.state.scope.is_deny_all()
+    }
+
     /// Find related entities using `find_also_related` with automatic scoping.
     ///
     /// This executes a LEFT JOIN to fetch the primary entity along with an
@@ -466,8 +676,13 @@ where
     {
         let select_two = self.inner.find_also_related(r);
 
-        // Auto-apply scope to the related entity R (no-op if R has no tenant_col)
-        let select_two = if let Some(cond) = apply_related_scope::<R>(&self.state.scope) {
+        // Auto-apply scope to the related entity R (no-op if R has no tenant_col);
+        // JSON-path scopes are applied when the query runs.
+        let mut state = self.state;
+        let select_two = if let Some(deferred) = DeferredScope::for_scope::<R>(&state.scope) {
+            state.deferred.push(deferred);
+            select_two
+        } else if let Some(cond) = apply_related_scope::<R>(&state.scope) {
             QueryFilter::filter(select_two, cond)
         } else {
             select_two
@@ -475,7 +690,7 @@ where
 
         SecureSelectTwo {
             inner: select_two,
-            state: self.state,
+            state,
         }
     }
 
@@ -527,8 +742,13 @@ where
     {
         let select_two_many = self.inner.find_with_related(r);
 
-        // Auto-apply scope to the related entity R (no-op if R has no tenant_col)
-        let select_two_many = if let Some(cond) = apply_related_scope::<R>(&self.state.scope) {
+        // Auto-apply scope to the related entity R (no-op if R has no tenant_col);
+        // JSON-path scopes are applied when the query runs.
+        let mut state = self.state;
+        let select_two_many = if let Some(deferred) = DeferredScope::for_scope::<R>(&state.scope) {
+            state.deferred.push(deferred);
+            select_two_many
+        } else if let Some(cond) = apply_related_scope::<R>(&state.scope) {
             QueryFilter::filter(select_two_many, cond)
         } else {
             select_two_many
@@ -536,7 +756,7 @@ where
 
         SecureSelectTwoMany {
             inner: select_two_many,
-            state: self.state,
+            state,
         }
     }
 }
@@ -573,9 +793,15 @@ where
         self,
         runner: &impl DBRunner,
     ) -> Result<Vec<(E::Model, Option<F::Model>)>, ScopeError> {
+        if self.state.scope.is_deny_all() {
+            return Ok(Vec::new());
+        }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
         match DBRunnerInternal::as_seaorm(runner) {
-            SeaOrmRunner::Conn(db) => Ok(self.inner.all(db).await?),
-            SeaOrmRunner::Tx(tx) => Ok(self.inner.all(tx).await?),
+            SeaOrmRunner::Conn(db) => Ok(inner.all(db).await?),
+            SeaOrmRunner::Tx(tx) => Ok(inner.all(tx).await?),
         }
     }
 
@@ -588,9 +814,15 @@ where
         self,
         runner: &impl DBRunner,
     ) -> Result<Option<(E::Model, Option<F::Model>)>, ScopeError> {
+        if self.state.scope.is_deny_all() {
+            return Ok(None);
+        }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
         match DBRunnerInternal::as_seaorm(runner) {
-            SeaOrmRunner::Conn(db) => Ok(self.inner.one(db).await?),
-            SeaOrmRunner::Tx(tx) => Ok(self.inner.one(tx).await?),
+            SeaOrmRunner::Conn(db) => Ok(inner.one(db).await?),
+            SeaOrmRunner::Tx(tx) => Ok(inner.one(tx).await?),
         }
     }
 
@@ -616,9 +848,14 @@ where
     }
 
     /// Unwrap the inner `SeaORM` `SelectTwo` for advanced use cases.
+    ///
+    /// # Safety
+    /// The caller must ensure they don't remove or bypass the security
+    /// conditions that were applied during `.scope_with()`. JSON-path scope
+    /// filters need the backend, so their constraints deny here.
     #[must_use]
     pub fn into_inner(self) -> sea_orm::SelectTwo<E, F> {
-        self.inner
+        self.state.apply_deferred(self.inner, None)
     }
 }
 
@@ -654,9 +891,15 @@ where
         self,
         runner: &impl DBRunner,
     ) -> Result<Vec<(E::Model, Vec<F::Model>)>, ScopeError> {
+        if self.state.scope.is_deny_all() {
+            return Ok(Vec::new());
+        }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)));
         match DBRunnerInternal::as_seaorm(runner) {
-            SeaOrmRunner::Conn(db) => Ok(self.inner.all(db).await?),
-            SeaOrmRunner::Tx(tx) => Ok(self.inner.all(tx).await?),
+            SeaOrmRunner::Conn(db) => Ok(inner.all(db).await?),
+            SeaOrmRunner::Tx(tx) => Ok(inner.all(tx).await?),
         }
     }
 
@@ -676,9 +919,124 @@ where
     }
 
     /// Unwrap the inner `SeaORM` `SelectTwoMany` for advanced use cases.
+    ///
+    /// # Safety
+    /// The caller must ensure they don't remove or bypass the security
+    /// conditions that were applied during `.scope_with()`. JSON-path scope
+    /// filters need the backend, so their constraints deny here.
     #[must_use]
     pub fn into_inner(self) -> sea_orm::SelectTwoMany<E, F> {
-        self.inner
+        self.state.apply_deferred(self.inner, None)
+    }
+}
+
+// =============================================================================
+// PartialSecureSelect<E> - Execution methods
+// =============================================================================
+
+impl<E> PartialSecureSelect<E>
+where
+    E: EntityTrait,
+{
+    /// Get a reference to the stored scope.
+    #[must_use]
+    pub fn scope(&self) -> &AccessScope {
+        &self.state.scope
+    }
+
+    /// Execute the query and return all matching rows as JSON objects.
+    ///
+    /// # Errors
+    /// - `ScopeError::Invalid` if no columns were selected
+    /// - `ScopeError::Db` if the database query fails
+    /// - `ScopeError::QueryTimeout` if the query exceeds its timeout
+    /// - `ScopeError::Cancelled` if the runner's cancellation token fires
+    #[allow(clippy::disallowed_methods)]
+    pub async fn all(self, runner: &impl DBRunner) -> Result<Vec<serde_json::Value>, ScopeError> {
+        if !self.has_columns {
+            return Err(ScopeError::Invalid(
+                "select_columns requires at least one column",
+            ));
+        }
+        if self.state.scope.is_deny_all() {
+            return Ok(Vec::new());
+        }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)))
+            .into_json();
+        run_query(runner, self.timeout, async move {
+            match DBRunnerInternal::as_seaorm(runner) {
+                SeaOrmRunner::Conn(db) => inner.all(db).await,
+                SeaOrmRunner::Tx(tx) => inner.all(tx).await,
+            }
+        })
+        .await
+    }
+
+    /// Execute the query and return at most one row as a JSON object.
+    ///
+    /// # Errors
+    /// - `ScopeError::Invalid` if no columns were selected
+    /// - `ScopeError::Db` if the database query fails
+    /// - `ScopeError::QueryTimeout` if the query exceeds its timeout
+    /// - `ScopeError::Cancelled` if the runner's cancellation token fires
+    #[allow(clippy::disallowed_methods)]
+    pub async fn one(
+        self,
+        runner: &impl DBRunner,
+    ) -> Result<Option<serde_json::Value>, ScopeError> {
+        if !self.has_columns {
+            return Err(ScopeError::Invalid(
+                "select_columns requires at least one column",
+            ));
+        }
+        if self.state.scope.is_deny_all() {
+            return Ok(None);
+        }
+        let inner = self
+            .state
+            .apply_deferred(self.inner, Some(DBRunnerInternal::backend(runner)))
+            .into_json();
+        run_query(runner, self.timeout, async move {
+            match DBRunnerInternal::as_seaorm(runner) {
+                SeaOrmRunner::Conn(db) => inner.one(db).await,
+                SeaOrmRunner::Tx(tx) => inner.one(tx).await,
+            }
+        })
+        .await
+    }
+
+    /// Add additional filters to the query.
+    pub fn filter(mut self, filter: sea_orm::Condition) -> Self {
+        self.inner = QueryFilter::filter(self.inner, filter);
+        self
+    }
+
+    /// Add ordering to the query.
+    pub fn order_by<C>(mut self, col: C, order: sea_orm::Order) -> Self
+    where
+        C: sea_orm::IntoSimpleExpr,
+    {
+        self.inner = QueryOrder::order_by(self.inner, col, order);
+        self
+    }
+
+    /// Add a limit to the query.
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.inner = QuerySelect::limit(self.inner, limit);
+        self
+    }
+
+    /// Unwrap the inner `SeaORM` `Select` for advanced use cases.
+    ///
+    /// # Safety
+    /// The caller must ensure they don't remove or bypass the security
+    /// conditions that were applied during `.scope_with()`. JSON-path scope
+    /// filters need the backend, so their constraints deny here.
+    #[must_use]
+    pub fn into_inner(self) -> sea_orm::Select<E> {
+        self.state.apply_deferred(self.inner, None)
     }
 }
 
@@ -691,6 +1049,11 @@ where
 /// This trait provides a way to find entities related to an already-loaded model
 /// while maintaining security scope constraints.
 ///
+/// The scope is resolved against the **related** entity `R` (its `tenant_col`,
+/// `resource_col`, ...), never the parent. It is shorthand for
+/// `model.find_related(r).secure().scope_with(scope)`; a plain
+/// `model.find_related(r)` is unscoped and must not be executed directly.
+///
 /// # Example
 /// ```rust,ignore
 /// use modkit_db::secure::{AccessScope, SecureFindRelatedExt};
@@ -744,6 +1107,30 @@ where
     }
 }
 
+/// Map row errors to `ScopeError` and end the stream with
+/// `ScopeError::Cancelled` once `cancel` fires.
+fn guard_stream<'r, T: Send + 'r>(
+    rows: BoxStream<'r, Result<T, sea_orm::DbErr>>,
+    cancel: Option<CancellationToken>,
+) -> BoxStream<'r, Result<T, ScopeError>> {
+    futures_util::stream::unfold(Some(rows), move |state| {
+        let cancel = cancel.clone();
+        async move {
+            let mut rows = state?;
+            let next = match cancel {
+                Some(token) => tokio::select! {
+                    biased;
+                    () = token.cancelled() => return Some((Err(ScopeError::Cancelled), None)),
+                    next = rows.next() => next,
+                },
+                None => rows.next().await,
+            };
+            next.map(|row| (row.map_err(ScopeError::from), Some(rows)))
+        }
+    })
+    .boxed()
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -767,6 +1154,7 @@ mod tests {
         let scope = AccessScope::default();
         let scoped = Scoped {
             scope: Arc::new(scope),
+            deferred: Vec::new(),
         };
         assert!(!scoped.scope.has_property(pep_properties::OWNER_TENANT_ID)); // default scope has no tenants
     }
@@ -777,6 +1165,7 @@ mod tests {
         let scope = AccessScope::for_tenants(vec![tenant_id]);
         let scoped = Scoped {
             scope: Arc::new(scope),
+            deferred: Vec::new(),
         };
 
         // Verify the scope is accessible
@@ -801,6 +1190,7 @@ mod tests {
         let scope = AccessScope::for_tenants(vec![uuid::Uuid::new_v4()]);
         let scoped = Scoped {
             scope: Arc::new(scope),
+            deferred: Vec::new(),
         };
 
         // Cloning should share the Arc
@@ -12,6 +12,19 @@
 //!   boundaries belong in application/domain services.
 //! - **Domain services** may use `TxConfig` to specify transaction requirements.
 //!
+//! # Conflict Retry
+//!
+//! With `retry_on_conflict` enabled, `transaction_with_config` re-runs the
+//! closure when the transaction fails with a serialization failure or deadlock
+//! (`DbError::SerializationFailure`). Retries use exponential backoff starting
+//! at `retry_delay`. The closure must therefore be safe to run more than once.
+//!
+//! # Statement Timeout
+//!
+//! `statement_timeout` bounds every statement run through the secure wrappers in
+//! the transaction (`ScopeError::QueryTimeout` on expiry). On Postgres it is also
+//! enforced server-side via `SET LOCAL statement_timeout`.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -27,7 +40,9 @@
 //!     let cfg = TxConfig {
 //!         isolation: Some(TxIsolationLevel::Serializable),
 //!         access_mode: Some(TxAccessMode::ReadWrite),
-//!     };
+//!         ..Default::default()
+//!     }
+//!     .with_conflict_retry(3, Duration::from_millis(50));
 //!
 //!     db.transaction_with_config(cfg, |tx| async move {
 //!         accounts_repo.debit(from, amount, tx).await?;
@@ -37,6 +52,8 @@
 //! }
 //! ```
 
+use std::time::Duration;
+
 /// Transaction isolation level.
 ///
 /// Controls how transaction integrity is maintained when multiple transactions
@@ -94,8 +111,8 @@ pub enum TxAccessMode {
 
 /// Configuration for database transactions.
 ///
-/// Use this struct to specify transaction isolation level and access mode
-/// without importing `SeaORM` types.
+/// Use this struct to specify transaction isolation level, access mode and
+/// conflict retry policy without importing `SeaORM` types.
 ///
 /// # Example
 ///
@@ -109,23 +126,59 @@ pub enum TxAccessMode {
 /// let cfg = TxConfig {
 ///     isolation: Some(TxIsolationLevel::RepeatableRead),
 ///     access_mode: Some(TxAccessMode::ReadOnly),
+///     ..Default::default()
 /// };
+///
+/// // Serializable with up to 5 retries on serialization failures
+/// let cfg = TxConfig::serializable().with_conflict_retry(5, Duration::from_millis(20));
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct TxConfig {
     /// Transaction isolation level. If `None`, uses database default.
     pub isolation: Option<TxIsolationLevel>,
     /// Transaction access mode. If `None`, uses database default (usually `ReadWrite`).
     pub access_mode: Option<TxAccessMode>,
+    /// Re-run the transaction when it fails with a serialization failure
+    /// or deadlock. Disabled by default.
+    pub retry_on_conflict: bool,
+    /// Maximum number of retries after the first attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled on every subsequent retry.
+    pub retry_delay: Duration,
+    /// Timeout for every statement run in the transaction. Expired statements
+    /// fail with `ScopeError::QueryTimeout`.
+    ///
+    /// On Postgres this also sets `statement_timeout` for the transaction, so the
+    /// server aborts the statement even if the client is gone.
+    pub statement_timeout: Option<Duration>,
+}
+
+impl Default for TxConfig {
+    fn default() -> Self {
+        Self {
+            isolation: None,
+            access_mode: None,
+            retry_on_conflict: false,
+            max_retries: DEFAULT_MAX_RETRIES,
+            retry_delay: DEFAULT_RETRY_DELAY,
+            statement_timeout: None,
+        }
+    }
 }
 
+/// Default number of conflict retries when `retry_on_conflict` is enabled.
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default delay before the first conflict retry.
+const DEFAULT_RETRY_DELAY: Duration = Duration::from_millis(50);
+
 impl TxConfig {
     /// Create a new configuration with the specified isolation level.
     #[must_use]
     pub fn with_isolation(isolation: TxIsolationLevel) -> Self {
         Self {
             isolation: Some(isolation),
-            access_mode: None,
+            ..Self::default()
         }
     }
 
@@ -133,8 +186,8 @@ impl TxConfig {
     #[must_use]
     pub fn read_only() -> Self {
         Self {
-            isolation: None,
             access_mode: Some(TxAccessMode::ReadOnly),
+            ..Self::default()
         }
     }
 
@@ -146,9 +199,60 @@ impl TxConfig {
     pub fn serializable() -> Self {
         Self {
             isolation: Some(TxIsolationLevel::Serializable),
-            access_mode: None,
+            ..Self::default()
         }
     }
+
+    /// Enable automatic retry on serialization failures and deadlocks.
+    ///
+    /// The transaction is re-run up to `max_retries` times, waiting
+    /// `retry_delay`, `2 * retry_delay`, `4 * retry_delay`, ... between attempts.
+    #[must_use]
+    pub fn with_conflict_retry(mut self, max_retries: u32, retry_delay: Duration) -> Self {
+        self.retry_on_conflict = true;
+        self.max_retries = max_retries;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Abort statements that run longer than `timeout`.
+    #[must_use]
+    pub fn with_statement_timeout(mut self, timeout: Duration) -> Self {
+        self.statement_timeout = Some(timeout);
+        self
+    }
+
+    /// Apply the server-side statement timeout to a freshly started transaction.
+    ///
+    /// Only Postgres supports a per-transaction statement timeout; other backends
+    /// rely on the client-side timeout applied by the secure query wrappers.
+    pub(crate) async fn apply_statement_timeout(
+        &self,
+        txn: &sea_orm::DatabaseTransaction,
+    ) -> Result<(), crate::DbError> {
+        use sea_orm::{ConnectionTrait, DbBackend};
+
+        let Some(timeout) = self.statement_timeout else {
+            return Ok(());
+        };
+        if txn.get_database_backend() == DbBackend::Postgres {
+            let millis = timeout.as_millis().max(1);
+            txn.execute_unprepared(&format!("SET LOCAL statement_timeout = {millis}"))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if a failed attempt number `attempt` (0-based) may be retried.
+    pub(crate) fn should_retry(&self, attempt: u32, err: &anyhow::Error) -> bool {
+        self.retry_on_conflict && attempt < self.max_retries && crate::is_serialization_failure(err)
+    }
+
+    /// Backoff delay before retrying after failed attempt number `attempt` (0-based).
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        self.retry_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+    }
 }
 
 // ============================================================================
@@ -186,6 +290,56 @@ mod tests {
         let cfg = TxConfig::default();
         assert!(cfg.isolation.is_none());
         assert!(cfg.access_mode.is_none());
+        assert!(!cfg.retry_on_conflict);
+        assert_eq!(cfg.max_retries, DEFAULT_MAX_RETRIES);
+        assert_eq!(cfg.retry_delay, DEFAULT_RETRY_DELAY);
+        assert!(cfg.statement_timeout.is_none());
+    }
+
+    #[test]
+    fn test_tx_config_with_statement_timeout() {
+        let cfg = TxConfig::read_only().with_statement_timeout(Duration::from_secs(2));
+        assert_eq!(cfg.access_mode, Some(TxAccessMode::ReadOnly));
+        assert_eq!(cfg.statement_timeout, Some(Duration::from_secs(2)));
+        assert!(!cfg.retry_on_conflict);
+    }
+
+    #[test]
+    fn test_tx_config_with_conflict_retry() {
+        let cfg = TxConfig::serializable().with_conflict_retry(5, Duration::from_millis(10));
+        assert_eq!(cfg.isolation, Some(TxIsolationLevel::Serializable));
+        assert!(cfg.retry_on_conflict);
+        assert_eq!(cfg.max_retries, 5);
+        assert_eq!(cfg.retry_delay, Duration::from_millis(10));
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles() {
+        let cfg = TxConfig::default().with_conflict_retry(3, Duration::from_millis(10));
+        assert_eq!(cfg.backoff_delay(0), Duration::from_millis(10));
+        assert_eq!(cfg.backoff_delay(1), Duration::from_millis(20));
+        assert_eq!(cfg.backoff_delay(2), Duration::from_millis(40));
+        // Saturates instead of overflowing on large attempt numbers.
+        assert_eq!(cfg.backoff_delay(40), Duration::from_millis(10) * u32::MAX);
+    }
+
+    #[test]
+    fn test_should_retry_only_serialization_failures() {
+        let conflict = || {
+            anyhow::Error::from(crate::DbError::SerializationFailure(
+                sea_orm::DbErr::Custom("could not serialize access".to_owned()),
+            ))
+        };
+        let other = anyhow::anyhow!("boom");
+
+        let disabled = TxConfig::default();
+        assert!(!disabled.should_retry(0, &conflict()));
+
+        let cfg = TxConfig::default().with_conflict_retry(2, Duration::ZERO);
+        assert!(cfg.should_retry(0, &conflict()));
+        assert!(cfg.should_retry(1, &conflict()));
+        assert!(!cfg.should_retry(2, &conflict()));
+        assert!(!cfg.should_retry(0, &other));
     }
 
     #[test]
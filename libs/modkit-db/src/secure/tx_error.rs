@@ -60,14 +60,17 @@ pub enum TxError<E> {
     Domain(E),
     /// An infrastructure error from the database layer.
     Infra(InfraError),
+    /// The transaction was rejected before it started because its
+    /// configuration is not valid for the target connection.
+    Config(String),
 }
 
 impl<E> TxError<E> {
     /// Convert this transaction error into a domain error.
     ///
     /// If this is already a domain error, returns it directly.
-    /// If this is an infrastructure error, uses the provided mapping function
-    /// to convert it into a domain error.
+    /// If this is an infrastructure or configuration error, uses the provided
+    /// mapping function to convert it into a domain error.
     pub fn into_domain<F>(self, map_infra: F) -> E
     where
         F: FnOnce(InfraError) -> E,
@@ -75,6 +78,7 @@ impl<E> TxError<E> {
         match self {
             TxError::Domain(e) => e,
             TxError::Infra(infra) => map_infra(infra),
+            TxError::Config(msg) => map_infra(InfraError::new(msg)),
         }
     }
 }
@@ -84,6 +88,7 @@ impl<E: fmt::Display> fmt::Display for TxError<E> {
         match self {
             TxError::Domain(e) => write!(f, "{e}"),
             TxError::Infra(e) => write!(f, "infrastructure error: {e}"),
+            TxError::Config(msg) => write!(f, "transaction config error: {msg}"),
         }
     }
 }
@@ -0,0 +1,209 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+#![cfg(feature = "test-utils")]
+
+//! Tests for the in-memory fake runner (`modkit_db::fake`).
+//!
+//! Queries go through the secure wrappers exactly as in production code; only
+//! the SQL execution is answered by the fake.
+
+use modkit_db::DbError;
+use modkit_db::fake::{FakeRunner, StatementKind, serialization_failure, unique_violation};
+use modkit_db::secure::{Scopable, ScopeError, SecureDeleteExt, SecureEntityExt, secure_insert};
+use modkit_security::AccessScope;
+use sea_orm::Set;
+use uuid::Uuid;
+
+mod ent {
+    use super::Scopable;
+    use sea_orm::entity::prelude::*;
+    use uuid::Uuid;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel, Scopable)]
+    #[sea_orm(table_name = "fake_items")]
+    #[secure(tenant_col = "tenant_id", resource_col = "id", no_owner, no_type)]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        pub tenant_id: Uuid,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+fn item(tenant_id: Uuid, name: &str) -> ent::Model {
+    ent::Model {
+        id: Uuid::new_v4(),
+        tenant_id,
+        name: name.to_owned(),
+    }
+}
+
+fn active(model: &ent::Model) -> ent::ActiveModel {
+    ent::ActiveModel {
+        id: Set(model.id),
+        tenant_id: Set(model.tenant_id),
+        name: Set(model.name.clone()),
+    }
+}
+
+#[tokio::test]
+async fn select_returns_stubbed_models_and_records_scope() {
+    let tenant = Uuid::new_v4();
+    let fake = FakeRunner::new();
+    let rows = vec![item(tenant, "a"), item(tenant, "b")];
+    fake.on_select::<ent::Entity>().return_models(rows.clone());
+
+    let found = ent::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant))
+        .all(&fake)
+        .await
+        .unwrap();
+
+    assert_eq!(found, rows);
+    assert_eq!(fake.calls::<ent::Entity>(StatementKind::Select), 1);
+    let stmt = &fake.statements()[0];
+    assert_eq!(stmt.table.as_deref(), Some("fake_items"));
+    assert!(
+        stmt.condition
+            .as_deref()
+            .is_some_and(|c| c.contains("tenant_id")),
+        "scope filter missing: {stmt:?}"
+    );
+}
+
+#[tokio::test]
+async fn deny_all_scope_is_not_executed() {
+    let fake = FakeRunner::new();
+
+    let found = ent::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::deny_all())
+        .all(&fake)
+        .await
+        .unwrap();
+
+    assert!(found.is_empty());
+    assert!(fake.statements().is_empty());
+}
+
+#[tokio::test]
+async fn insert_echoes_values_by_default() {
+    let tenant = Uuid::new_v4();
+    let fake = FakeRunner::new();
+    let model = item(tenant, "new");
+
+    let inserted =
+        secure_insert::<ent::Entity>(active(&model), &AccessScope::for_tenant(tenant), &fake)
+            .await
+            .unwrap();
+
+    assert_eq!(inserted, model);
+    assert_eq!(fake.calls::<ent::Entity>(StatementKind::Insert), 1);
+}
+
+#[tokio::test]
+async fn failure_can_target_the_nth_call() {
+    let tenant = Uuid::new_v4();
+    let scope = AccessScope::for_tenant(tenant);
+    let fake = FakeRunner::new();
+    fake.on_insert::<ent::Entity>()
+        .on_call(2)
+        .fail_with(unique_violation());
+
+    secure_insert::<ent::Entity>(active(&item(tenant, "first")), &scope, &fake)
+        .await
+        .unwrap();
+    let err = secure_insert::<ent::Entity>(active(&item(tenant, "second")), &scope, &fake)
+        .await
+        .unwrap_err();
+    secure_insert::<ent::Entity>(active(&item(tenant, "third")), &scope, &fake)
+        .await
+        .unwrap();
+
+    assert!(
+        matches!(err, ScopeError::UniqueViolation { .. }),
+        "unexpected error: {err:?}"
+    );
+    assert_eq!(err.sql_state().as_deref(), Some("23505"));
+    assert!(
+        std::error::Error::source(&err)
+            .unwrap()
+            .to_string()
+            .contains("duplicate key")
+    );
+    assert_eq!(fake.calls::<ent::Entity>(StatementKind::Insert), 3);
+}
+
+#[tokio::test]
+async fn serialization_failure_is_classified() {
+    let tenant = Uuid::new_v4();
+    let fake = FakeRunner::new();
+    fake.on_select::<ent::Entity>()
+        .fail_with(serialization_failure());
+
+    let err = ent::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant))
+        .one(&fake)
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(err, ScopeError::SerializationFailure(_)),
+        "expected ScopeError::SerializationFailure, got {err:?}"
+    );
+    assert_eq!(err.sql_state().as_deref(), Some("40001"));
+    assert!(DbError::from(err).is_serialization_failure());
+}
+
+#[tokio::test]
+async fn delete_reports_stubbed_row_count() {
+    let tenant = Uuid::new_v4();
+    let fake = FakeRunner::new();
+    fake.on_delete::<ent::Entity>().affect_rows(3);
+
+    let res = ent::Entity::delete_many()
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant))
+        .exec(&fake)
+        .await
+        .unwrap();
+
+    assert_eq!(res.rows_affected, 3);
+    assert_eq!(fake.calls::<ent::Entity>(StatementKind::Delete), 1);
+}
+
+#[tokio::test]
+async fn db_handle_runs_against_the_fake() {
+    let tenant = Uuid::new_v4();
+    let fake = FakeRunner::new();
+    let model = item(tenant, "tx");
+    fake.on_select::<ent::Entity>()
+        .return_models(vec![model.clone()]);
+
+    let db = fake.db();
+    let (_db, res) = db
+        .transaction(|tx| {
+            let model = model.clone();
+            Box::pin(async move {
+                let scope = AccessScope::for_tenant(model.tenant_id);
+                secure_insert::<ent::Entity>(active(&model), &scope, tx).await?;
+                let found = ent::Entity::find()
+                    .secure()
+                    .scope_with(&scope)
+                    .one(tx)
+                    .await?;
+                Ok(found)
+            })
+        })
+        .await;
+
+    assert_eq!(res.unwrap(), Some(model));
+    assert_eq!(fake.calls::<ent::Entity>(StatementKind::Insert), 1);
+    assert_eq!(fake.calls::<ent::Entity>(StatementKind::Select), 1);
+}
@@ -125,4 +125,91 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
+
+    fn sql_for(ast: &Expr) -> String {
+        use sea_orm::{DbBackend, QueryTrait};
+
+        let condition = expr_to_condition::<Entity>(ast, &setup_field_map()).unwrap();
+        Entity::find()
+            .filter(condition)
+            .build(DbBackend::Postgres)
+            .to_string()
+    }
+
+    fn fold(name: &str, field: &str) -> Expr {
+        Expr::Function(name.to_owned(), vec![Expr::Identifier(field.to_owned())])
+    }
+
+    #[test]
+    fn test_tolower_contains_sql() {
+        let ast = Expr::Function(
+            "contains".to_owned(),
+            vec![
+                fold("tolower", "name"),
+                Expr::Value(Value::String("smith".to_owned())),
+            ],
+        );
+
+        let sql = sql_for(&ast);
+        assert!(sql.contains(r#"LOWER("name") LIKE '%smith%'"#), "{sql}");
+    }
+
+    #[test]
+    fn test_toupper_startswith_and_endswith_sql() {
+        let starts = Expr::Function(
+            "startswith".to_owned(),
+            vec![
+                fold("toupper", "email"),
+                Expr::Value(Value::String("JO".to_owned())),
+            ],
+        );
+        let ends = Expr::Function(
+            "endswith".to_owned(),
+            vec![
+                fold("tolower", "email"),
+                Expr::Value(Value::String("@acme.com".to_owned())),
+            ],
+        );
+
+        let sql = sql_for(&starts);
+        assert!(sql.contains(r#"UPPER("email") LIKE 'JO%'"#), "{sql}");
+        let sql = sql_for(&ends);
+        assert!(sql.contains(r#"LOWER("email") LIKE '%@acme.com'"#), "{sql}");
+    }
+
+    #[test]
+    fn test_tolower_eq_sql() {
+        let ast = Expr::Compare(
+            Box::new(fold("tolower", "name")),
+            CompareOperator::Eq,
+            Box::new(Expr::Value(Value::String("smith".to_owned()))),
+        );
+
+        let sql = sql_for(&ast);
+        assert!(sql.contains(r#"LOWER("name") = 'smith'"#), "{sql}");
+    }
+
+    #[test]
+    fn test_case_fold_on_non_string_field_error() {
+        let ast = Expr::Compare(
+            Box::new(fold("tolower", "score")),
+            CompareOperator::Eq,
+            Box::new(Expr::Value(Value::String("1".to_owned()))),
+        );
+
+        let result = expr_to_condition::<Entity>(&ast, &setup_field_map());
+        assert!(result.unwrap_err().to_string().contains("type mismatch"));
+    }
+
+    #[test]
+    fn test_unknown_case_fold_function_error() {
+        let ast = Expr::Compare(
+            Box::new(fold("trim", "name")),
+            CompareOperator::Eq,
+            Box::new(Expr::Value(Value::String("x".to_owned()))),
+        );
+
+        let result = expr_to_condition::<Entity>(&ast, &setup_field_map());
+        assert!(result.unwrap_err().to_string().contains("trim()"));
+    }
 }
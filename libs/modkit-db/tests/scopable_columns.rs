@@ -0,0 +1,36 @@
+//! `scope_columns()` generated by `#[derive(Scopable)]`.
+
+use modkit_db::secure::{Scopable, ScopableEntity};
+use sea_orm::entity::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "documents")]
+#[secure(
+    tenant_col = "tenant_id",
+    resource_col = "id",
+    owner_col = "created_by",
+    no_type,
+    pep_prop(department_id = "department_id"),
+    pep_prop(billing_tenant_id = "tenant_id")
+)]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub tenant_id: Uuid,
+    pub created_by: Uuid,
+    pub department_id: Uuid,
+    pub title: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[test]
+fn dimension_columns_come_before_pep_prop_columns() {
+    assert_eq!(
+        Entity::scope_columns(),
+        vec!["tenant_id", "id", "created_by", "department_id"]
+    );
+}
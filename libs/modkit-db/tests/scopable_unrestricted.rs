@@ -0,0 +1,94 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! `#[secure(unrestricted, pep_prop(...))]`: a global entity with no dimension
+//! columns that still resolves its custom ABAC properties.
+
+use modkit_db::secure::{
+    Scopable, ScopableEntity, ScopeError, SecureEntityExt, validate_insert_scope,
+};
+use modkit_security::{AccessScope, ScopeConstraint, ScopeFilter, pep_properties};
+use sea_orm::entity::prelude::*;
+use sea_orm::{DbBackend, QueryTrait, Set};
+
+#[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "shared_templates")]
+#[secure(unrestricted, pep_prop(owner_id = "created_by"))]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: Uuid,
+    pub created_by: Uuid,
+    pub name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+#[test]
+fn dimension_columns_stay_empty() {
+    assert!(Entity::IS_UNRESTRICTED);
+    assert!(Entity::tenant_col().is_none());
+    assert!(Entity::resource_col().is_none());
+    assert!(Entity::owner_col().is_none());
+    assert!(Entity::type_col().is_none());
+}
+
+#[test]
+fn pep_prop_mappings_resolve() {
+    assert_eq!(
+        Entity::resolve_property(pep_properties::OWNER_ID),
+        Some(Column::CreatedBy)
+    );
+    assert_eq!(
+        Entity::resolve_property(pep_properties::OWNER_TENANT_ID),
+        None
+    );
+    assert_eq!(Entity::resolve_property("name"), None);
+}
+
+#[test]
+fn scope_columns_lists_pep_prop_columns_only() {
+    assert_eq!(Entity::scope_columns(), vec!["created_by"]);
+}
+
+#[test]
+fn owner_constraint_filters_on_mapped_column() {
+    let owner = Uuid::new_v4();
+    let scope = AccessScope::single(ScopeConstraint::new(vec![ScopeFilter::eq(
+        pep_properties::OWNER_ID,
+        owner,
+    )]));
+
+    let sql = Entity::find()
+        .secure()
+        .scope_with(&scope)
+        .into_inner()
+        .build(DbBackend::Postgres)
+        .to_string();
+
+    assert!(
+        sql.contains(&format!(r#""created_by" = '{owner}'"#)),
+        "{sql}"
+    );
+}
+
+#[test]
+fn inserts_are_checked_against_pep_prop_columns() {
+    let owner = Uuid::new_v4();
+    let scope = AccessScope::single(ScopeConstraint::new(vec![ScopeFilter::eq(
+        pep_properties::OWNER_ID,
+        owner,
+    )]));
+    let row = |created_by| ActiveModel {
+        id: Set(Uuid::new_v4()),
+        created_by: Set(created_by),
+        name: Set("template".to_owned()),
+    };
+
+    assert!(validate_insert_scope(&row(owner), &scope).is_ok());
+    assert!(matches!(
+        validate_insert_scope(&row(Uuid::new_v4()), &scope),
+        Err(ScopeError::Denied(_))
+    ));
+}
@@ -9,12 +9,18 @@
 
 use anyhow::anyhow;
 use modkit_db::migration_runner::run_migrations_for_testing;
-use modkit_db::odata::FieldMap;
 use modkit_db::odata::pager::OPager;
-use modkit_db::secure::{Db, DbConn, ScopableEntity, secure_insert};
+use modkit_db::odata::{
+    FieldMap, FieldToColumn, LimitCfg, ODataFieldMapping, SearchMode, paginate_odata,
+    paginate_odata_with_search,
+};
+use modkit_db::secure::{Db, DbConn, ScopableEntity, SecureEntityExt, secure_insert};
 use modkit_db::{ConnectOpts, connect_db};
-use modkit_odata::ODataQuery;
-use modkit_odata::filter::FieldKind;
+use modkit_odata::filter::{FieldKind, FilterField};
+use modkit_odata::{
+    CursorV1, ODataOrderBy, ODataQuery, ODataSearch, OrderKey, SearchField, SearchableFields,
+    SortDir,
+};
 use modkit_security::{AccessScope, pep_properties};
 use sea_orm::Set;
 use sea_orm::entity::prelude::*;
@@ -190,3 +196,565 @@ async fn paginate_odata_works_with_secure_conn() {
 
     assert_eq!(page.items.len(), 2, "page size");
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum TestField {
+    Id,
+    Name,
+}
+
+impl FilterField for TestField {
+    const FIELDS: &'static [Self] = &[Self::Id, Self::Name];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+        }
+    }
+
+    fn kind(&self) -> FieldKind {
+        match self {
+            Self::Id => FieldKind::I64,
+            Self::Name => FieldKind::String,
+        }
+    }
+}
+
+struct TestMapper;
+
+impl FieldToColumn<TestField> for TestMapper {
+    type Column = ent::Column;
+
+    fn map_field(field: TestField) -> ent::Column {
+        match field {
+            TestField::Id => ent::Column::Id,
+            TestField::Name => ent::Column::Name,
+        }
+    }
+}
+
+impl ODataFieldMapping<TestField> for TestMapper {
+    type Entity = ent::Entity;
+
+    fn extract_cursor_value(model: &ent::Model, field: TestField) -> sea_orm::Value {
+        match field {
+            TestField::Id => model.id.into(),
+            TestField::Name => model.name.clone().into(),
+        }
+    }
+}
+
+/// Connect without running migrations: any statement touching the table fails,
+/// so an `Ok` result proves no query reached the database.
+async fn db_without_tables() -> Db {
+    connect_db("sqlite::memory:", ConnectOpts::default())
+        .await
+        .expect("db connect")
+}
+
+fn forward_cursor() -> CursorV1 {
+    let order = ODataOrderBy(vec![OrderKey {
+        field: "id".to_owned(),
+        dir: SortDir::Desc,
+    }]);
+    CursorV1 {
+        k: vec!["5".to_owned()],
+        o: SortDir::Desc,
+        s: order.to_signed_tokens(),
+        f: None,
+        d: "fwd".to_owned(),
+    }
+}
+
+#[tokio::test]
+async fn paginate_odata_deny_all_executes_no_queries() {
+    let db = db_without_tables().await;
+    let conn = db.conn().expect("conn");
+    let limits = LimitCfg {
+        default: 10,
+        max: 100,
+    };
+
+    for q in [
+        ODataQuery::new().with_limit(5),
+        ODataQuery::new()
+            .with_limit(5)
+            .with_cursor(forward_cursor()),
+    ] {
+        let select = ent::Entity::find()
+            .secure()
+            .scope_with(&AccessScope::deny_all());
+        let page = paginate_odata::<TestField, TestMapper, _, _, _, _>(
+            select,
+            &conn,
+            &q,
+            ("id", SortDir::Desc),
+            limits,
+            |m| m.name,
+        )
+        .await
+        .expect("deny-all must not hit the database");
+
+        assert!(page.items.is_empty());
+        assert_eq!(page.page_info.next_cursor, None);
+        assert_eq!(page.page_info.prev_cursor, None);
+        assert_eq!(page.page_info.limit, 5);
+    }
+
+    // Sanity check: a real scope does query (and fails on the missing table).
+    let select = ent::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::for_tenants(vec![Uuid::new_v4()]));
+    let res = paginate_odata::<TestField, TestMapper, _, _, _, _>(
+        select,
+        &conn,
+        &ODataQuery::new(),
+        ("id", SortDir::Desc),
+        limits,
+        |m| m.name,
+    )
+    .await;
+    assert!(res.is_err(), "scoped query should reach the database");
+}
+
+#[tokio::test]
+async fn paginate_odata_deny_all_still_validates_query() {
+    let db = db_without_tables().await;
+    let conn = db.conn().expect("conn");
+
+    let q = ODataQuery::new().with_order(ODataOrderBy(vec![OrderKey {
+        field: "unknown".to_owned(),
+        dir: SortDir::Asc,
+    }]));
+    let select = ent::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::deny_all());
+    let res = paginate_odata::<TestField, TestMapper, _, _, _, _>(
+        select,
+        &conn,
+        &q,
+        ("id", SortDir::Desc),
+        LimitCfg {
+            default: 10,
+            max: 100,
+        },
+        |m| m.name,
+    )
+    .await;
+
+    assert!(matches!(
+        res,
+        Err(modkit_odata::Error::InvalidOrderByField(_))
+    ));
+}
+
+#[tokio::test]
+async fn opager_deny_all_executes_no_queries() {
+    let db = db_without_tables().await;
+    let conn = db.conn().expect("conn");
+    let fmap: FieldMap<ent::Entity> = FieldMap::new()
+        .insert_with_extractor("id", ent::Column::Id, FieldKind::I64, |m: &ent::Model| {
+            m.id.to_string()
+        })
+        .insert("name", ent::Column::Name, FieldKind::String);
+
+    let scope = AccessScope::deny_all();
+    let page = OPager::<ent::Entity, _>::new(&scope, &conn, &fmap)
+        .fetch(&ODataQuery::new().with_cursor(forward_cursor()), |m| m.name)
+        .await
+        .expect("deny-all must not hit the database");
+
+    assert!(page.items.is_empty());
+    assert_eq!(page.page_info.next_cursor, None);
+    assert_eq!(page.page_info.prev_cursor, None);
+}
+
+#[tokio::test]
+async fn secure_select_helpers_deny_all_execute_no_queries() {
+    let db = db_without_tables().await;
+    let conn = db.conn().expect("conn");
+    let scope = AccessScope::deny_all();
+
+    let select = || ent::Entity::find().secure().scope_with(&scope);
+
+    assert!(select().all(&conn).await.expect("all").is_empty());
+    assert!(select().one(&conn).await.expect("one").is_none());
+    assert_eq!(select().count(&conn).await.expect("count"), 0);
+    assert!(!select().exists(&conn).await.expect("exists"));
+    let projected = || select().select_columns([ent::Column::Name]);
+    assert!(projected().all(&conn).await.expect("all").is_empty());
+    assert!(projected().one(&conn).await.expect("one").is_none());
+}
+
+async fn names_matching(test_db: &TestDb, filter: modkit_odata::ast::Expr) -> Vec<String> {
+    let conn = test_db.conn();
+    let select = ent::Entity::find().secure().scope_with(&test_db.scope);
+    let mut names = paginate_odata::<TestField, TestMapper, _, _, _, _>(
+        select,
+        &conn,
+        &ODataQuery::new().with_filter(filter),
+        ("id", SortDir::Desc),
+        LimitCfg {
+            default: 10,
+            max: 100,
+        },
+        |m| m.name,
+    )
+    .await
+    .expect("paginate")
+    .items;
+    names.sort();
+    names
+}
+
+/// `tolower`/`toupper` compile to `LOWER()`/`UPPER()`. `SQLite`'s built-in
+/// functions only fold ASCII letters, so non-ASCII characters keep their case.
+#[tokio::test]
+async fn case_fold_filters_on_sqlite() {
+    use modkit_odata::ast::{CompareOperator, Expr, Value};
+
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    for name in ["Smith", "SMITHERS", "\u{c9}mile", "jones"] {
+        let am = ent::ActiveModel {
+            tenant_id: Set(test_db.tenant_id),
+            name: Set(name.to_owned()),
+            score: Set(0),
+            ..Default::default()
+        };
+        secure_insert::<ent::Entity>(am, &test_db.scope, &conn)
+            .await
+            .expect("insert");
+    }
+
+    let folded = |f: &str| Expr::Function(f.to_owned(), vec![Expr::Identifier("name".to_owned())]);
+    let string = |s: &str| Box::new(Expr::Value(Value::String(s.to_owned())));
+
+    let contains = Expr::Function(
+        "contains".to_owned(),
+        vec![
+            folded("tolower"),
+            Expr::Value(Value::String("smith".to_owned())),
+        ],
+    );
+    assert_eq!(
+        names_matching(&test_db, contains).await,
+        vec!["SMITHERS".to_owned(), "Smith".to_owned()]
+    );
+
+    let eq_upper = Expr::Compare(
+        Box::new(folded("toupper")),
+        CompareOperator::Eq,
+        string("JONES"),
+    );
+    assert_eq!(names_matching(&test_db, eq_upper).await, vec!["jones"]);
+
+    // ASCII-only folding: 'É' is left as-is by LOWER(), so the lowercase literal misses...
+    let eq_lower_non_ascii = Expr::Compare(
+        Box::new(folded("tolower")),
+        CompareOperator::Eq,
+        string("\u{e9}mile"),
+    );
+    assert!(
+        names_matching(&test_db, eq_lower_non_ascii)
+            .await
+            .is_empty()
+    );
+
+    // ...while the ASCII tail is still folded.
+    let eq_lower_ascii_tail = Expr::Compare(
+        Box::new(folded("tolower")),
+        CompareOperator::Eq,
+        string("\u{c9}mile"),
+    );
+    assert_eq!(
+        names_matching(&test_db, eq_lower_ascii_tail).await,
+        vec!["\u{c9}mile"]
+    );
+}
+
+struct TestSearch;
+
+impl SearchableFields for TestSearch {
+    type Field = TestField;
+
+    const FIELDS: &'static [SearchField<TestField>] = &[SearchField::new(TestField::Name, 1)];
+}
+
+async fn names_searching(test_db: &TestDb, query: &ODataQuery) -> Vec<String> {
+    let conn = test_db.conn();
+    let select = ent::Entity::find().secure().scope_with(&test_db.scope);
+    let mut names = paginate_odata_with_search::<TestField, TestMapper, TestSearch, _, _, _, _>(
+        select,
+        &conn,
+        query,
+        SearchMode::Like,
+        ("id", SortDir::Desc),
+        LimitCfg {
+            default: 10,
+            max: 100,
+        },
+        |m| m.name,
+    )
+    .await
+    .expect("paginate")
+    .items;
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn search_matches_terms_literally_within_scope_and_filter() {
+    use modkit_odata::ast::{CompareOperator, Expr, Value};
+
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    for name in ["50% off", "500 off", "a_b", "axb", "Alice Smith"] {
+        let am = ent::ActiveModel {
+            tenant_id: Set(test_db.tenant_id),
+            name: Set(name.to_owned()),
+            score: Set(0),
+            ..Default::default()
+        };
+        secure_insert::<ent::Entity>(am, &test_db.scope, &conn)
+            .await
+            .expect("insert");
+    }
+    let other_tenant = Uuid::new_v4();
+    let am = ent::ActiveModel {
+        tenant_id: Set(other_tenant),
+        name: Set("Alice Jones".to_owned()),
+        score: Set(0),
+        ..Default::default()
+    };
+    secure_insert::<ent::Entity>(am, &AccessScope::for_tenant(other_tenant), &conn)
+        .await
+        .expect("insert");
+
+    let search = |terms: &[&str]| ODataQuery::new().with_search(ODataSearch::new(terms.to_vec()));
+
+    // `%` and `_` are not wildcards
+    assert_eq!(
+        names_searching(&test_db, &search(&["50%"])).await,
+        vec!["50% off"]
+    );
+    assert_eq!(
+        names_searching(&test_db, &search(&["a_b"])).await,
+        vec!["a_b"]
+    );
+
+    // Every term must match; other tenants stay invisible
+    assert_eq!(
+        names_searching(&test_db, &search(&["ALICE", "smith"])).await,
+        vec!["Alice Smith"]
+    );
+    assert!(
+        names_searching(&test_db, &search(&["jones"]))
+            .await
+            .is_empty()
+    );
+
+    // Search and $filter narrow each other
+    let not_500 = Expr::Compare(
+        Box::new(Expr::Identifier("name".to_owned())),
+        CompareOperator::Ne,
+        Box::new(Expr::Value(Value::String("500 off".to_owned()))),
+    );
+    assert_eq!(
+        names_searching(&test_db, &search(&["off"]).with_filter(not_500)).await,
+        vec!["50% off"]
+    );
+
+    // Resources without searchable fields reject $search
+    let select = ent::Entity::find().secure().scope_with(&test_db.scope);
+    let res = paginate_odata::<TestField, TestMapper, _, _, _, _>(
+        select,
+        &conn,
+        &search(&["off"]),
+        ("id", SortDir::Desc),
+        LimitCfg {
+            default: 10,
+            max: 100,
+        },
+        |m| m.name,
+    )
+    .await;
+    assert!(matches!(res, Err(modkit_odata::Error::InvalidSearch(_))));
+}
+
+#[tokio::test]
+async fn select_columns_fetches_only_requested_columns() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    seed(&conn, test_db.tenant_id, &test_db.scope).await;
+
+    let select = || ent::Entity::find().secure().scope_with(&test_db.scope);
+
+    let rows = select()
+        .select_columns([ent::Column::Name, ent::Column::Score])
+        .filter(sea_orm::Condition::all().add(ent::Column::Score.gte(20)))
+        .order_by(ent::Column::Score, sea_orm::Order::Asc)
+        .all(&conn)
+        .await
+        .expect("projected all");
+    assert_eq!(
+        rows,
+        vec![
+            serde_json::json!({ "name": "bob", "score": 20 }),
+            serde_json::json!({ "name": "charlie", "score": 30 }),
+            serde_json::json!({ "name": "dave", "score": 40 }),
+        ]
+    );
+
+    let row = select()
+        .select_columns([ent::Column::Name])
+        .order_by(ent::Column::Score, sea_orm::Order::Desc)
+        .one(&conn)
+        .await
+        .expect("projected one");
+    assert_eq!(row, Some(serde_json::json!({ "name": "dave" })));
+
+    // Rows of other tenants stay invisible
+    let other = AccessScope::for_tenants(vec![Uuid::new_v4()]);
+    let rows = ent::Entity::find()
+        .secure()
+        .scope_with(&other)
+        .select_columns([ent::Column::Name])
+        .all(&conn)
+        .await
+        .expect("other tenant");
+    assert!(rows.is_empty());
+
+    let err = select()
+        .select_columns(std::iter::empty())
+        .all(&conn)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, modkit_db::secure::ScopeError::Invalid(_)));
+}
+
+/// A filterable field that exposes the tenant column under another name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum AliasField {
+    Name,
+    Owner,
+}
+
+impl FilterField for AliasField {
+    const FIELDS: &'static [Self] = &[Self::Name, Self::Owner];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Owner => "owner",
+        }
+    }
+
+    fn kind(&self) -> FieldKind {
+        match self {
+            Self::Name => FieldKind::String,
+            Self::Owner => FieldKind::Uuid,
+        }
+    }
+}
+
+struct AliasMapper;
+
+impl FieldToColumn<AliasField> for AliasMapper {
+    type Column = ent::Column;
+
+    fn map_field(field: AliasField) -> ent::Column {
+        match field {
+            AliasField::Name => ent::Column::Name,
+            AliasField::Owner => ent::Column::TenantId,
+        }
+    }
+}
+
+impl ODataFieldMapping<AliasField> for AliasMapper {
+    type Entity = ent::Entity;
+
+    fn extract_cursor_value(model: &ent::Model, field: AliasField) -> sea_orm::Value {
+        match field {
+            AliasField::Name => model.name.clone().into(),
+            AliasField::Owner => model.tenant_id.into(),
+        }
+    }
+}
+
+fn owner_ne(tenant_id: Uuid) -> modkit_odata::ast::Expr {
+    use modkit_odata::ast::{CompareOperator, Expr, Value};
+
+    let name_is_alice = Expr::Compare(
+        Box::new(Expr::Identifier("name".to_owned())),
+        CompareOperator::Eq,
+        Box::new(Expr::Value(Value::String("alice".to_owned()))),
+    );
+    let owner_ne = Expr::Compare(
+        Box::new(Expr::Identifier("owner".to_owned())),
+        CompareOperator::Ne,
+        Box::new(Expr::Value(Value::Uuid(tenant_id))),
+    );
+    name_is_alice.or(owner_ne)
+}
+
+#[tokio::test]
+async fn paginate_odata_rejects_filters_on_scope_columns() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    seed(&conn, test_db.tenant_id, &test_db.scope).await;
+    let limits = LimitCfg {
+        default: 10,
+        max: 100,
+    };
+
+    let run = |q: ODataQuery| {
+        let select = ent::Entity::find().secure().scope_with(&test_db.scope);
+        let conn = &conn;
+        async move {
+            paginate_odata::<AliasField, AliasMapper, _, _, _, _>(
+                select,
+                conn,
+                &q,
+                ("name", SortDir::Asc),
+                limits,
+                |m| m.name,
+            )
+            .await
+        }
+    };
+
+    let q = ODataQuery::new().with_filter(owner_ne(test_db.tenant_id));
+    match run(q.clone()).await {
+        Err(modkit_odata::Error::InvalidFilter(msg)) => {
+            assert!(msg.contains("'owner'"), "{msg}");
+            assert!(msg.contains("tenant"), "{msg}");
+        }
+        other => panic!("expected InvalidFilter, got {other:?}"),
+    }
+
+    // Opting in runs the filter, still AND-ed with the scope.
+    let page = run(q.with_scope_filters_allowed())
+        .await
+        .expect("opted-in filter");
+    assert_eq!(page.items, vec!["alice"]);
+}
+
+#[tokio::test]
+async fn opager_rejects_filters_on_scope_columns() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let fmap: FieldMap<ent::Entity> = FieldMap::new()
+        .insert("name", ent::Column::Name, FieldKind::String)
+        .insert("owner", ent::Column::TenantId, FieldKind::Uuid);
+
+    let res = OPager::<ent::Entity, _>::new(&test_db.scope, &conn, &fmap)
+        .fetch(
+            &ODataQuery::new().with_filter(owner_ne(test_db.tenant_id)),
+            |m| m.name,
+        )
+        .await;
+
+    assert!(matches!(res, Err(modkit_odata::Error::InvalidFilter(_))));
+}
@@ -0,0 +1,224 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+#![cfg(all(feature = "sqlite", target_os = "linux"))]
+
+//! `SecureSelect::stream()` returns the same rows as `all()` without
+//! materializing the whole result set.
+//!
+//! Memory is measured as peak RSS growth (`VmHWM`, reset through
+//! `/proc/self/clear_refs`), so the test is Linux-only and lives in its own
+//! binary where no concurrent test can skew the peak.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use futures_util::TryStreamExt;
+use modkit_db::migration_runner::run_migrations_for_testing;
+use modkit_db::secure::{ScopableEntity, SecureEntityExt, secure_insert};
+use modkit_db::{ConnectOpts, connect_db};
+use modkit_security::{AccessScope, pep_properties};
+use sea_orm::entity::prelude::*;
+use sea_orm::{Order, Set};
+use sea_orm_migration::prelude as mig;
+use uuid::Uuid;
+
+fn proc_status_kib(field: &str) -> usize {
+    let status = std::fs::read_to_string("/proc/self/status").expect("read /proc/self/status");
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix(field))
+        .and_then(|rest| rest.trim().trim_end_matches("kB").trim().parse().ok())
+        .expect("status field")
+}
+
+/// Run `f` and return its output plus the peak RSS growth (bytes) while it ran.
+async fn peak_growth<T>(f: impl Future<Output = T>) -> (T, usize) {
+    // "5" resets VmHWM to the current RSS.
+    std::fs::write("/proc/self/clear_refs", "5").expect("reset peak RSS");
+    let base = proc_status_kib("VmRSS:");
+    let out = f.await;
+    let peak = proc_status_kib("VmHWM:");
+    (out, peak.saturating_sub(base) * 1024)
+}
+
+fn digest(row: &ent::Model) -> (i64, u64) {
+    let mut h = DefaultHasher::new();
+    row.tenant_id.hash(&mut h);
+    row.payload.hash(&mut h);
+    (row.id, h.finish())
+}
+
+mod ent {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "stream_test")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub tenant_id: Uuid,
+        pub payload: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+impl ScopableEntity for ent::Entity {
+    fn tenant_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(ent::Column::TenantId)
+    }
+
+    fn resource_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+
+    fn owner_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+
+    fn type_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+
+    fn resolve_property(property: &str) -> Option<<Self as EntityTrait>::Column> {
+        match property {
+            p if p == pep_properties::OWNER_TENANT_ID => Self::tenant_col(),
+            _ => None,
+        }
+    }
+}
+
+struct CreateStreamTest;
+
+impl mig::MigrationName for CreateStreamTest {
+    fn name(&self) -> &'static str {
+        "m001_create_stream_test"
+    }
+}
+
+#[async_trait::async_trait]
+impl mig::MigrationTrait for CreateStreamTest {
+    async fn up(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .create_table(
+                mig::Table::create()
+                    .table(mig::Alias::new("stream_test"))
+                    .if_not_exists()
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("id"))
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("tenant_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("payload"))
+                            .text()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .drop_table(
+                mig::Table::drop()
+                    .table(mig::Alias::new("stream_test"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+const ROWS: usize = 1000;
+const PAYLOAD: usize = 16 * 1024;
+
+#[tokio::test]
+async fn stream_matches_all_with_bounded_memory() {
+    let db = connect_db(
+        "sqlite:file:memdb_secure_stream?mode=memory&cache=shared",
+        ConnectOpts {
+            max_conns: Some(1),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("connect");
+    run_migrations_for_testing(&db, vec![Box::new(CreateStreamTest)])
+        .await
+        .expect("migrate");
+
+    let tenant = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    let conn = db.conn().expect("conn");
+    for n in 0..ROWS {
+        let am = ent::ActiveModel {
+            tenant_id: Set(tenant),
+            payload: Set(format!("{n:0>PAYLOAD$}")),
+            ..Default::default()
+        };
+        secure_insert::<ent::Entity>(am, &AccessScope::for_tenant(tenant), &conn)
+            .await
+            .expect("seed");
+    }
+    let am = ent::ActiveModel {
+        tenant_id: Set(other),
+        payload: Set("other".to_owned()),
+        ..Default::default()
+    };
+    secure_insert::<ent::Entity>(am, &AccessScope::for_tenant(other), &conn)
+        .await
+        .expect("seed other tenant");
+
+    let scope = AccessScope::for_tenant(tenant);
+    let query = || {
+        ent::Entity::find()
+            .secure()
+            .scope_with(&scope)
+            .order_by(ent::Column::Id, Order::Asc)
+    };
+
+    // Stream first: memory freed by `all()` would otherwise be reused and
+    // hide the stream's own growth.
+    let (streamed, stream_peak) = peak_growth(async {
+        let mut rows = query().stream(&conn).await.expect("open stream");
+        let mut digests = Vec::with_capacity(ROWS);
+        while let Some(row) = rows.try_next().await.expect("row") {
+            digests.push(digest(&row));
+        }
+        digests
+    })
+    .await;
+
+    let (all, all_peak) = peak_growth(query().all(&conn)).await;
+    let all = all.expect("all");
+    assert_eq!(all.len(), ROWS);
+    assert_eq!(streamed, all.iter().map(digest).collect::<Vec<_>>());
+
+    // `all()` holds every payload at once; the stream only holds the rows the
+    // driver buffers ahead of the consumer.
+    assert!(
+        all_peak > ROWS * PAYLOAD / 2,
+        "all() peak growth {all_peak} bytes"
+    );
+    assert!(
+        stream_peak < all_peak / 4,
+        "stream() peak growth {stream_peak} bytes vs all() {all_peak} bytes"
+    );
+
+    let denied = ent::Entity::find()
+        .secure()
+        .scope_with(&AccessScope::deny_all())
+        .stream(&conn)
+        .await
+        .expect("deny-all stream");
+    assert_eq!(denied.try_collect::<Vec<_>>().await.expect("rows").len(), 0);
+}
@@ -9,7 +9,11 @@ mod concurrency_tests;
 mod manager;
 mod options;
 mod pooling_tests;
+mod query_timeout;
+mod secure_audit_columns;
+mod secure_find_related;
 mod secure_insert_tenant_validation;
+mod secure_refs;
 mod secure_update_tenant_safety;
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod sqlite_tests;
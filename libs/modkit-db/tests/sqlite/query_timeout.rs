@@ -0,0 +1,279 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Query timeout and cancellation tests for the secure query wrappers.
+//!
+//! The slow query is a per-row busy loop expressed as a filter condition, so every
+//! row takes milliseconds to evaluate and the full scan takes seconds.
+
+use modkit_db::migration_runner::run_migrations_for_testing;
+use modkit_db::secure::{
+    Db, ScopableEntity, ScopeError, SecureEntityExt, TxConfig, secure_insert,
+    with_request_cancellation,
+};
+use modkit_db::{ConnectOpts, connect_db};
+use modkit_security::{AccessScope, pep_properties};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{Condition, Set};
+use sea_orm_migration::prelude as mig;
+use std::time::{Duration, Instant};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+mod ent {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "query_timeout_test")]
+    pub struct Model {
+        #[sea_orm(primary_key)]
+        pub id: i64,
+        pub tenant_id: Uuid,
+        pub val: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+impl ScopableEntity for ent::Entity {
+    fn tenant_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(ent::Column::TenantId)
+    }
+
+    fn resource_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+
+    fn owner_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+
+    fn type_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+
+    fn resolve_property(property: &str) -> Option<<Self as EntityTrait>::Column> {
+        match property {
+            p if p == pep_properties::OWNER_TENANT_ID => Self::tenant_col(),
+            _ => None,
+        }
+    }
+}
+
+struct CreateQueryTimeoutTest;
+
+impl mig::MigrationName for CreateQueryTimeoutTest {
+    fn name(&self) -> &'static str {
+        "m001_create_query_timeout_test"
+    }
+}
+
+#[async_trait::async_trait]
+impl mig::MigrationTrait for CreateQueryTimeoutTest {
+    async fn up(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .create_table(
+                mig::Table::create()
+                    .table(mig::Alias::new("query_timeout_test"))
+                    .if_not_exists()
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("id"))
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("tenant_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("val"))
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .drop_table(
+                mig::Table::drop()
+                    .table(mig::Alias::new("query_timeout_test"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+const ROWS: usize = 300;
+
+/// Connect a single-connection pool and seed `ROWS` rows for one tenant.
+async fn setup(name: &str) -> (Db, AccessScope) {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db(
+        &format!("sqlite:file:{name}?mode=memory&cache=shared"),
+        opts,
+    )
+    .await
+    .expect("Failed to connect to database");
+    run_migrations_for_testing(&db, vec![Box::new(CreateQueryTimeoutTest)])
+        .await
+        .expect("migrate");
+
+    let tenant_id = Uuid::new_v4();
+    let scope = AccessScope::for_tenant(tenant_id);
+    let conn = db.conn().expect("conn");
+    for n in 0..ROWS {
+        let am = ent::ActiveModel {
+            tenant_id: Set(tenant_id),
+            val: Set(format!("row-{n}")),
+            ..Default::default()
+        };
+        let _ = secure_insert::<ent::Entity>(am, &scope, &conn)
+            .await
+            .expect("insert");
+    }
+    (db, scope)
+}
+
+/// Filter that burns several milliseconds of CPU per row and matches every row.
+fn slow_filter() -> Condition {
+    Condition::all().add(Expr::cust(
+        "length(replace(hex(zeroblob(4000000)), '0', 'x') || val) > 0",
+    ))
+}
+
+/// The single pooled connection must be usable again right away.
+async fn assert_connection_released(db: &Db, scope: &AccessScope) {
+    let conn = db.conn().expect("conn");
+    let count = tokio::time::timeout(
+        Duration::from_secs(2),
+        ent::Entity::find().secure().scope_with(scope).count(&conn),
+    )
+    .await
+    .expect("pool connection was not released")
+    .expect("count");
+    assert_eq!(count, ROWS as u64);
+}
+
+#[tokio::test]
+async fn select_timeout_returns_query_timeout() {
+    let (db, scope) = setup("memdb_query_timeout").await;
+    let conn = db.conn().expect("conn");
+
+    let started = Instant::now();
+    let err = ent::Entity::find()
+        .secure()
+        .scope_with(&scope)
+        .filter(slow_filter())
+        .timeout(Duration::from_millis(100))
+        .all(&conn)
+        .await
+        .expect_err("slow query must time out");
+
+    assert!(
+        matches!(err, ScopeError::QueryTimeout(t) if t == Duration::from_millis(100)),
+        "Expected QueryTimeout, got: {err:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_connection_released(&db, &scope).await;
+}
+
+#[tokio::test]
+async fn cancelled_connection_aborts_query() {
+    let (db, scope) = setup("memdb_query_cancel").await;
+    let token = CancellationToken::new();
+    let conn = db.conn().expect("conn").with_cancellation(token.clone());
+
+    let canceller = {
+        let token = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            token.cancel();
+        })
+    };
+
+    let started = Instant::now();
+    let err = ent::Entity::find()
+        .secure()
+        .scope_with(&scope)
+        .filter(slow_filter())
+        .all(&conn)
+        .await
+        .expect_err("query must be cancelled");
+    canceller.await.unwrap();
+
+    assert!(
+        matches!(err, ScopeError::Cancelled),
+        "Expected Cancelled, got: {err:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(1));
+    drop(conn);
+    assert_connection_released(&db, &scope).await;
+}
+
+#[tokio::test]
+async fn request_cancellation_applies_to_acquired_runners() {
+    let (db, scope) = setup("memdb_request_cancel").await;
+    let token = CancellationToken::new();
+    token.cancel();
+
+    let err = with_request_cancellation(token, async {
+        let conn = db.conn().expect("conn");
+        ent::Entity::find()
+            .secure()
+            .scope_with(&scope)
+            .count(&conn)
+            .await
+            .expect_err("cancelled request must not run queries")
+    })
+    .await;
+
+    assert!(
+        matches!(err, ScopeError::Cancelled),
+        "Expected Cancelled, got: {err:?}"
+    );
+    assert_connection_released(&db, &scope).await;
+}
+
+#[tokio::test]
+async fn tx_config_statement_timeout_bounds_queries() {
+    let (db, scope) = setup("memdb_tx_statement_timeout").await;
+    let scope_for_tx = scope.clone();
+
+    let cfg = TxConfig::default().with_statement_timeout(Duration::from_millis(100));
+    let (db, result): (_, anyhow::Result<()>) = db
+        .transaction_with_config(cfg, move |tx| {
+            let scope = scope_for_tx.clone();
+            Box::pin(async move {
+                ent::Entity::find()
+                    .secure()
+                    .scope_with(&scope)
+                    .filter(slow_filter())
+                    .all(tx)
+                    .await?;
+                Ok(())
+            })
+        })
+        .await;
+
+    let err = result.expect_err("slow query in transaction must time out");
+    assert!(
+        matches!(
+            err.downcast_ref::<ScopeError>(),
+            Some(ScopeError::QueryTimeout(_))
+        ),
+        "Expected QueryTimeout, got: {err:?}"
+    );
+    assert_connection_released(&db, &scope).await;
+}
@@ -0,0 +1,414 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for audit column stamping by the `_audited` write helpers.
+//!
+//! Security contract:
+//! - No raw SQL in tests.
+//! - Schema is created via `sea-orm-migration` definitions executed by the migration runner.
+
+use std::time::Duration;
+
+use modkit_db::migration_runner::run_migrations_for_testing;
+use modkit_db::secure::{
+    Db, DbConn, Scopable, ScopeError, SecureEntityExt, SecureUpdateExt, secure_insert_audited,
+    secure_insert_many_audited, secure_update_with_scope_audited,
+};
+use modkit_db::{ConnectOpts, connect_db};
+use modkit_security::{AccessScope, SecurityContext};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{Condition, Set};
+use sea_orm_migration::prelude as mig;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+mod audited_ent {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel, Scopable)]
+    #[sea_orm(table_name = "audited_test")]
+    #[secure(
+        tenant_col = "tenant_id",
+        resource_col = "id",
+        no_owner,
+        no_type,
+        created_at_col = "created_at",
+        updated_at_col = "updated_at",
+        created_by_col = "created_by",
+        updated_by_col = "updated_by"
+    )]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        pub tenant_id: Uuid,
+        pub name: String,
+        pub created_at: OffsetDateTime,
+        pub updated_at: OffsetDateTime,
+        pub created_by: Uuid,
+        pub updated_by: Option<Uuid>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+use audited_ent::{ActiveModel, Column, Entity};
+
+struct CreateAuditedTable;
+
+impl mig::MigrationName for CreateAuditedTable {
+    fn name(&self) -> &'static str {
+        "m001_create_audited_test"
+    }
+}
+
+#[async_trait::async_trait]
+impl mig::MigrationTrait for CreateAuditedTable {
+    async fn up(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .create_table(
+                mig::Table::create()
+                    .table(mig::Alias::new("audited_test"))
+                    .if_not_exists()
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("tenant_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("name"))
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("created_at"))
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("updated_at"))
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("created_by"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(mig::ColumnDef::new(mig::Alias::new("updated_by")).uuid())
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .drop_table(
+                mig::Table::drop()
+                    .table(mig::Alias::new("audited_test"))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await?;
+        Ok(())
+    }
+}
+
+// Helper struct to manage test database lifecycle
+struct TestDb {
+    db: Db,
+}
+
+impl TestDb {
+    async fn new() -> Self {
+        let test_id = Uuid::new_v4();
+        let dsn =
+            format!("sqlite:file:memdb_secure_audit_columns_{test_id}?mode=memory&cache=shared");
+
+        let opts = ConnectOpts {
+            max_conns: Some(1),
+            min_conns: Some(1),
+            ..Default::default()
+        };
+
+        let db = connect_db(&dsn, opts).await.expect("connect");
+
+        run_migrations_for_testing(&db, vec![Box::new(CreateAuditedTable)])
+            .await
+            .expect("migrate");
+
+        Self { db }
+    }
+
+    fn conn(&self) -> DbConn<'_> {
+        self.db.conn().expect("conn")
+    }
+}
+
+fn ctx(subject_id: Uuid, tenant_id: Uuid) -> SecurityContext {
+    SecurityContext::builder()
+        .subject_id(subject_id)
+        .subject_tenant_id(tenant_id)
+        .build()
+        .unwrap()
+}
+
+fn new_row(id: Uuid, tenant_id: Uuid, name: &str) -> ActiveModel {
+    ActiveModel {
+        id: Set(id),
+        tenant_id: Set(tenant_id),
+        name: Set(name.to_owned()),
+        ..Default::default()
+    }
+}
+
+async fn insert(conn: &DbConn<'_>, ctx: &SecurityContext, tenant_id: Uuid) -> audited_ent::Model {
+    secure_insert_audited::<Entity>(
+        new_row(Uuid::new_v4(), tenant_id, "before"),
+        &AccessScope::for_tenant(tenant_id),
+        ctx,
+        conn,
+    )
+    .await
+    .expect("insert")
+}
+
+async fn reload(conn: &DbConn<'_>, tenant_id: Uuid, id: Uuid) -> audited_ent::Model {
+    Entity::find()
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant_id))
+        .and_id(id)
+        .unwrap()
+        .one(conn)
+        .await
+        .unwrap()
+        .expect("row")
+}
+
+#[tokio::test]
+async fn insert_stamps_created_and_updated_columns() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let actor = Uuid::new_v4();
+
+    let before = OffsetDateTime::now_utc();
+    let created = insert(&conn, &ctx(actor, tenant_id), tenant_id).await;
+
+    assert_eq!(created.created_by, actor);
+    assert_eq!(created.updated_by, Some(actor));
+    assert!(created.created_at >= before);
+    assert_eq!(created.updated_at, created.created_at);
+    assert_eq!(reload(&conn, tenant_id, created.id).await, created);
+}
+
+#[tokio::test]
+async fn explicitly_set_audit_columns_win() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let importer = Uuid::new_v4();
+    let original_author = Uuid::new_v4();
+    let original_time = OffsetDateTime::UNIX_EPOCH;
+
+    let created = secure_insert_audited::<Entity>(
+        ActiveModel {
+            created_at: Set(original_time),
+            created_by: Set(original_author),
+            ..new_row(Uuid::new_v4(), tenant_id, "imported")
+        },
+        &AccessScope::for_tenant(tenant_id),
+        &ctx(importer, tenant_id),
+        &conn,
+    )
+    .await
+    .expect("insert");
+
+    assert_eq!(created.created_at, original_time);
+    assert_eq!(created.created_by, original_author);
+    assert_eq!(created.updated_by, Some(importer));
+    assert!(created.updated_at > original_time);
+}
+
+#[tokio::test]
+async fn update_refreshes_updated_columns_and_keeps_created_ones() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let author = Uuid::new_v4();
+    let editor = Uuid::new_v4();
+
+    let created = insert(&conn, &ctx(author, tenant_id), tenant_id).await;
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let updated = secure_update_with_scope_audited::<Entity>(
+        ActiveModel {
+            id: Set(created.id),
+            name: Set("after".to_owned()),
+            ..Default::default()
+        },
+        &AccessScope::for_tenant(tenant_id),
+        created.id,
+        &ctx(editor, tenant_id),
+        &conn,
+    )
+    .await
+    .expect("update");
+
+    assert_eq!(updated.name, "after");
+    assert_eq!(updated.created_at, created.created_at);
+    assert_eq!(updated.created_by, author);
+    assert_eq!(updated.updated_by, Some(editor));
+    assert!(updated.updated_at > created.updated_at);
+}
+
+#[tokio::test]
+async fn audited_update_still_rejects_tenant_change() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_a = Uuid::new_v4();
+    let actor = Uuid::new_v4();
+
+    let created = insert(&conn, &ctx(actor, tenant_a), tenant_a).await;
+
+    let err = secure_update_with_scope_audited::<Entity>(
+        ActiveModel {
+            id: Set(created.id),
+            tenant_id: Set(Uuid::new_v4()),
+            ..Default::default()
+        },
+        &AccessScope::for_tenant(tenant_a),
+        created.id,
+        &ctx(actor, tenant_a),
+        &conn,
+    )
+    .await
+    .expect_err("must reject tenant change");
+
+    assert!(matches!(err, ScopeError::Denied("tenant_id is immutable")));
+    assert_eq!(reload(&conn, tenant_a, created.id).await, created);
+}
+
+#[tokio::test]
+async fn insert_many_stamps_every_row_with_one_time() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let actor = Uuid::new_v4();
+    let ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+
+    let inserted = secure_insert_many_audited::<Entity>(
+        ids.iter()
+            .map(|id| new_row(*id, tenant_id, "bulk"))
+            .collect(),
+        &AccessScope::for_tenant(tenant_id),
+        &ctx(actor, tenant_id),
+        &conn,
+    )
+    .await
+    .expect("insert many");
+    assert_eq!(inserted, 3);
+
+    let first = reload(&conn, tenant_id, ids[0]).await;
+    for id in ids {
+        let row = reload(&conn, tenant_id, id).await;
+        assert_eq!(row.created_by, actor);
+        assert_eq!(row.updated_by, Some(actor));
+        assert_eq!(row.created_at, first.created_at);
+        assert_eq!(row.updated_at, first.created_at);
+    }
+}
+
+#[tokio::test]
+async fn insert_many_writes_nothing_if_a_row_is_out_of_scope() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let in_scope = Uuid::new_v4();
+
+    let err = secure_insert_many_audited::<Entity>(
+        vec![
+            new_row(in_scope, tenant_id, "ok"),
+            new_row(Uuid::new_v4(), Uuid::new_v4(), "foreign"),
+        ],
+        &AccessScope::for_tenant(tenant_id),
+        &ctx(Uuid::new_v4(), tenant_id),
+        &conn,
+    )
+    .await
+    .expect_err("must deny");
+
+    assert!(matches!(err, ScopeError::Denied(_)), "{err:?}");
+    let found = Entity::find()
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant_id))
+        .and_id(in_scope)
+        .unwrap()
+        .one(&conn)
+        .await
+        .unwrap();
+    assert!(found.is_none());
+}
+
+#[tokio::test]
+async fn audited_update_many_stamps_updated_columns() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let author = Uuid::new_v4();
+    let editor = Uuid::new_v4();
+
+    let created = insert(&conn, &ctx(author, tenant_id), tenant_id).await;
+    tokio::time::sleep(Duration::from_millis(5)).await;
+
+    let result = Entity::update_many()
+        .col_expr(Column::Name, Expr::value("renamed"))
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant_id))
+        .filter(Condition::all().add(Column::Id.eq(created.id)))
+        .audited(&ctx(editor, tenant_id))
+        .exec(&conn)
+        .await
+        .expect("update many");
+    assert_eq!(result.rows_affected, 1);
+
+    let row = reload(&conn, tenant_id, created.id).await;
+    assert_eq!(row.name, "renamed");
+    assert_eq!(row.created_at, created.created_at);
+    assert_eq!(row.created_by, author);
+    assert_eq!(row.updated_by, Some(editor));
+    assert!(row.updated_at > created.updated_at);
+}
+
+#[tokio::test]
+async fn update_many_col_expr_wins_over_stamp() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_id = Uuid::new_v4();
+    let author = Uuid::new_v4();
+
+    let created = insert(&conn, &ctx(author, tenant_id), tenant_id).await;
+
+    Entity::update_many()
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant_id))
+        .col_expr(Column::UpdatedBy, Expr::value(Option::<Uuid>::None))
+        .audited(&ctx(Uuid::new_v4(), tenant_id))
+        .exec(&conn)
+        .await
+        .expect("update many");
+
+    let row = reload(&conn, tenant_id, created.id).await;
+    assert_eq!(row.updated_by, None);
+    assert!(row.updated_at >= created.updated_at);
+}
@@ -0,0 +1,331 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for scoping `find_related` queries.
+//!
+//! The scope must be resolved against the *related* entity, so a related row
+//! outside the scope stays hidden even when the parent model is visible.
+
+use modkit_db::migration_runner::run_migrations_for_testing;
+use modkit_db::secure::{
+    Db, DbConn, ScopableEntity, SecureEntityExt, SecureFindRelatedExt, secure_insert,
+};
+use modkit_db::{ConnectOpts, connect_db};
+use modkit_security::{AccessScope, pep_properties};
+use sea_orm::Set;
+use sea_orm::entity::prelude::*;
+use sea_orm_migration::prelude as mig;
+use uuid::Uuid;
+
+mod user {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "related_user")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        pub tenant_id: Uuid,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(has_many = "super::address::Entity")]
+        Address,
+    }
+
+    impl Related<super::address::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Address.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod address {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "related_address")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        pub tenant_id: Uuid,
+        pub user_id: Uuid,
+        pub street: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[sea_orm(
+            belongs_to = "super::user::Entity",
+            from = "Column::UserId",
+            to = "super::user::Column::Id"
+        )]
+        User,
+    }
+
+    impl Related<super::user::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::User.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+impl ScopableEntity for user::Entity {
+    fn tenant_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(user::Column::TenantId)
+    }
+    fn resource_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(user::Column::Id)
+    }
+    fn owner_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+    fn type_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+    fn resolve_property(property: &str) -> Option<<Self as EntityTrait>::Column> {
+        match property {
+            p if p == pep_properties::OWNER_TENANT_ID => Self::tenant_col(),
+            p if p == pep_properties::RESOURCE_ID => Self::resource_col(),
+            _ => None,
+        }
+    }
+}
+
+impl ScopableEntity for address::Entity {
+    fn tenant_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(address::Column::TenantId)
+    }
+    fn resource_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(address::Column::Id)
+    }
+    fn owner_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+    fn type_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+    fn resolve_property(property: &str) -> Option<<Self as EntityTrait>::Column> {
+        match property {
+            p if p == pep_properties::OWNER_TENANT_ID => Self::tenant_col(),
+            p if p == pep_properties::RESOURCE_ID => Self::resource_col(),
+            _ => None,
+        }
+    }
+}
+
+struct CreateFindRelatedTables;
+
+impl mig::MigrationName for CreateFindRelatedTables {
+    fn name(&self) -> &'static str {
+        "m001_create_find_related_tables"
+    }
+}
+
+#[async_trait::async_trait]
+impl mig::MigrationTrait for CreateFindRelatedTables {
+    async fn up(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .create_table(
+                mig::Table::create()
+                    .table(mig::Alias::new("related_user"))
+                    .if_not_exists()
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("tenant_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("name"))
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                mig::Table::create()
+                    .table(mig::Alias::new("related_address"))
+                    .if_not_exists()
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("tenant_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("user_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("street"))
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        for table in ["related_address", "related_user"] {
+            manager
+                .drop_table(
+                    mig::Table::drop()
+                        .table(mig::Alias::new(table))
+                        .if_exists()
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+struct TestDb {
+    db: Db,
+}
+
+impl TestDb {
+    async fn new() -> Self {
+        let test_id = Uuid::new_v4();
+        let dsn =
+            format!("sqlite:file:memdb_secure_find_related_{test_id}?mode=memory&cache=shared");
+
+        let opts = ConnectOpts {
+            max_conns: Some(1),
+            min_conns: Some(1),
+            ..Default::default()
+        };
+
+        let db = connect_db(&dsn, opts).await.expect("connect");
+
+        run_migrations_for_testing(&db, vec![Box::new(CreateFindRelatedTables)])
+            .await
+            .expect("migrate");
+
+        Self { db }
+    }
+
+    fn conn(&self) -> DbConn<'_> {
+        self.db.conn().expect("conn")
+    }
+}
+
+async fn insert_user(conn: &DbConn<'_>, tenant_id: Uuid) -> user::Model {
+    secure_insert::<user::Entity>(
+        user::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(tenant_id),
+            name: Set("alice".to_owned()),
+        },
+        &AccessScope::for_tenant(tenant_id),
+        conn,
+    )
+    .await
+    .expect("insert user")
+}
+
+async fn insert_address(
+    conn: &DbConn<'_>,
+    tenant_id: Uuid,
+    user_id: Uuid,
+    street: &str,
+) -> address::Model {
+    secure_insert::<address::Entity>(
+        address::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(tenant_id),
+            user_id: Set(user_id),
+            street: Set(street.to_owned()),
+        },
+        &AccessScope::for_tenant(tenant_id),
+        conn,
+    )
+    .await
+    .expect("insert address")
+}
+
+#[tokio::test]
+async fn find_related_in_wrong_tenant_scope_returns_nothing() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+
+    let alice = insert_user(&conn, tenant_a).await;
+    let home = insert_address(&conn, tenant_a, alice.id, "Main St").await;
+
+    let own = alice
+        .secure_find_related(address::Entity, &AccessScope::for_tenant(tenant_a))
+        .all(&conn)
+        .await
+        .expect("own tenant");
+    assert_eq!(own, vec![home]);
+
+    let foreign = alice
+        .secure_find_related(address::Entity, &AccessScope::for_tenant(tenant_b))
+        .all(&conn)
+        .await
+        .expect("foreign tenant");
+    assert!(foreign.is_empty());
+
+    // Equivalent form: scope a plain `find_related` select.
+    let foreign = alice
+        .find_related(address::Entity)
+        .secure()
+        .scope_with(&AccessScope::for_tenant(tenant_b))
+        .all(&conn)
+        .await
+        .expect("foreign tenant");
+    assert!(foreign.is_empty());
+}
+
+#[tokio::test]
+async fn find_related_scope_applies_to_related_rows() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+
+    let alice = insert_user(&conn, tenant_a).await;
+    let home = insert_address(&conn, tenant_a, alice.id, "Main St").await;
+    // A related row that lives in another tenant must not leak through the join.
+    let _stray = insert_address(&conn, tenant_b, alice.id, "Side St").await;
+
+    let visible = alice
+        .secure_find_related(address::Entity, &AccessScope::for_tenant(tenant_a))
+        .all(&conn)
+        .await
+        .expect("scoped related");
+    assert_eq!(visible, vec![home]);
+
+    // Scoped by resource id of the related entity, not the parent.
+    let by_parent_id = alice
+        .secure_find_related(address::Entity, &AccessScope::for_resource(alice.id))
+        .all(&conn)
+        .await
+        .expect("scoped related");
+    assert!(by_parent_id.is_empty());
+}
@@ -308,3 +308,32 @@ async fn unrestricted_entity_insert_does_not_require_tenant_scope_or_tenant_id()
         .await
         .expect("insert ok");
 }
+
+#[tokio::test]
+async fn duplicate_key_insert_is_a_unique_violation() {
+    let test_db = setup().await;
+    let conn = test_db.conn();
+    let tenant_a = Uuid::new_v4();
+    let scope = AccessScope::for_tenants(vec![tenant_a]);
+
+    let am = || tenant_ent::ActiveModel {
+        id: Set(7),
+        tenant_id: Set(tenant_a),
+        name: Set("dup".to_owned()),
+    };
+    let _ = secure_insert::<tenant_ent::Entity>(am(), &scope, &conn)
+        .await
+        .expect("first insert ok");
+
+    let err = secure_insert::<tenant_ent::Entity>(am(), &scope, &conn)
+        .await
+        .expect_err("duplicate id must be rejected");
+
+    match &err {
+        ScopeError::UniqueViolation { constraint, .. } => {
+            assert_eq!(constraint, "tenant_insert_test.id");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+    assert_eq!(err.sql_state().as_deref(), Some("1555"));
+}
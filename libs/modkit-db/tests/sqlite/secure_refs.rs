@@ -0,0 +1,215 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for foreign-key scope checks (`assert_ref_in_scope`,
+//! `assert_refs_in_scope`).
+
+use modkit_db::migration_runner::run_migrations_for_testing;
+use modkit_db::secure::{
+    Db, DbConn, ScopableEntity, ScopeError, assert_ref_in_scope, assert_refs_in_scope,
+    secure_insert,
+};
+use modkit_db::{ConnectOpts, connect_db};
+use modkit_security::{AccessScope, pep_properties};
+use sea_orm::Set;
+use sea_orm::entity::prelude::*;
+use sea_orm_migration::prelude as mig;
+use uuid::Uuid;
+
+mod city {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, DeriveEntityModel)]
+    #[sea_orm(table_name = "ref_city")]
+    pub struct Model {
+        #[sea_orm(primary_key, auto_increment = false)]
+        pub id: Uuid,
+        pub tenant_id: Uuid,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+impl ScopableEntity for city::Entity {
+    fn tenant_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(city::Column::TenantId)
+    }
+    fn resource_col() -> Option<<Self as EntityTrait>::Column> {
+        Some(city::Column::Id)
+    }
+    fn owner_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+    fn type_col() -> Option<<Self as EntityTrait>::Column> {
+        None
+    }
+    fn resolve_property(property: &str) -> Option<<Self as EntityTrait>::Column> {
+        match property {
+            p if p == pep_properties::OWNER_TENANT_ID => Self::tenant_col(),
+            p if p == pep_properties::RESOURCE_ID => Self::resource_col(),
+            _ => None,
+        }
+    }
+}
+
+struct CreateRefTables;
+
+impl mig::MigrationName for CreateRefTables {
+    fn name(&self) -> &'static str {
+        "m001_create_ref_tables"
+    }
+}
+
+#[async_trait::async_trait]
+impl mig::MigrationTrait for CreateRefTables {
+    async fn up(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .create_table(
+                mig::Table::create()
+                    .table(mig::Alias::new("ref_city"))
+                    .if_not_exists()
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("id"))
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        mig::ColumnDef::new(mig::Alias::new("tenant_id"))
+                            .uuid()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &mig::SchemaManager) -> Result<(), mig::DbErr> {
+        manager
+            .drop_table(
+                mig::Table::drop()
+                    .table(mig::Alias::new("ref_city"))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+async fn setup() -> Db {
+    let dsn = format!(
+        "sqlite:file:memdb_secure_refs_{}?mode=memory&cache=shared",
+        Uuid::new_v4()
+    );
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        min_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db(&dsn, opts).await.expect("connect");
+    run_migrations_for_testing(&db, vec![Box::new(CreateRefTables)])
+        .await
+        .expect("migrate");
+    db
+}
+
+async fn insert_city(conn: &DbConn<'_>, tenant_id: Uuid) -> Uuid {
+    secure_insert::<city::Entity>(
+        city::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(tenant_id),
+        },
+        &AccessScope::for_tenant(tenant_id),
+        conn,
+    )
+    .await
+    .expect("insert city")
+    .id
+}
+
+#[tokio::test]
+async fn reference_in_another_tenant_is_rejected() {
+    let db = setup().await;
+    let conn = db.conn().expect("conn");
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    let own_city = insert_city(&conn, tenant_a).await;
+    let foreign_city = insert_city(&conn, tenant_b).await;
+    let scope = AccessScope::for_tenant(tenant_a);
+
+    assert_ref_in_scope::<city::Entity>(own_city, &scope, &conn)
+        .await
+        .expect("own city");
+
+    let err = assert_ref_in_scope::<city::Entity>(foreign_city, &scope, &conn)
+        .await
+        .unwrap_err();
+    assert!(
+        matches!(
+            err,
+            ScopeError::ReferenceNotInScope { entity: "ref_city", id } if id == foreign_city
+        ),
+        "unexpected error: {err:?}"
+    );
+
+    // A made-up id is indistinguishable from a foreign one.
+    let err = assert_ref_in_scope::<city::Entity>(Uuid::new_v4(), &scope, &conn)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ScopeError::ReferenceNotInScope { .. }));
+}
+
+#[tokio::test]
+async fn batched_check_reports_every_missing_id() {
+    let db = setup().await;
+    let conn = db.conn().expect("conn");
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    let own = [
+        insert_city(&conn, tenant_a).await,
+        insert_city(&conn, tenant_a).await,
+    ];
+    let foreign = insert_city(&conn, tenant_b).await;
+    let unknown = Uuid::new_v4();
+    let scope = AccessScope::for_tenant(tenant_a);
+
+    assert_refs_in_scope::<city::Entity>([own[0], own[1], own[0]], &scope, &conn)
+        .await
+        .expect("own cities");
+    assert_refs_in_scope::<city::Entity>([], &scope, &conn)
+        .await
+        .expect("nothing to check");
+
+    let err = assert_refs_in_scope::<city::Entity>([own[0], foreign, unknown], &scope, &conn)
+        .await
+        .unwrap_err();
+    let ScopeError::ReferencesNotInScope { entity, mut ids } = err else {
+        panic!("unexpected error: {err:?}");
+    };
+    assert_eq!(entity, "ref_city");
+    let mut expected = vec![foreign, unknown];
+    ids.sort();
+    expected.sort();
+    assert_eq!(ids, expected);
+}
+
+#[tokio::test]
+async fn check_runs_inside_transaction() {
+    let db = setup().await;
+    let tenant_id = Uuid::new_v4();
+    let city_id = insert_city(&db.conn().expect("conn"), tenant_id).await;
+
+    let (_db, result) = db
+        .transaction(move |tx| {
+            Box::pin(async move {
+                let scope = AccessScope::for_tenant(tenant_id);
+                assert_ref_in_scope::<city::Entity>(city_id, &scope, tx).await?;
+                assert_refs_in_scope::<city::Entity>([city_id], &scope, tx).await?;
+                Ok::<(), anyhow::Error>(())
+            })
+        })
+        .await;
+    result.expect("transaction");
+}
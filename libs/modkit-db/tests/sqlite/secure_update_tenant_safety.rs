@@ -8,11 +8,11 @@
 
 use modkit_db::migration_runner::run_migrations_for_testing;
 use modkit_db::secure::{
-    Db, DbConn, ScopableEntity, ScopeError, SecureUpdateExt, secure_insert,
-    secure_update_with_scope,
+    Db, DbConn, ScopableEntity, ScopeError, SecureDeleteExt, SecureEntityExt, SecureUpdateExt,
+    secure_delete_one, secure_insert, secure_update_with_scope,
 };
 use modkit_db::{ConnectOpts, connect_db};
-use modkit_security::{AccessScope, pep_properties};
+use modkit_security::{AccessScope, ScopeIntent, pep_properties};
 use sea_orm::Set;
 use sea_orm::entity::prelude::*;
 use sea_orm_migration::prelude as mig;
@@ -216,7 +216,7 @@ async fn tenant_scoped_update_rejects_cross_tenant_update_by_id() {
     .await
     .expect_err("must deny");
 
-    assert!(matches!(err, ScopeError::Denied(_)));
+    assert!(matches!(err, ScopeError::NotFound { id: Some(id) } if id == id_b));
 }
 
 #[tokio::test]
@@ -291,3 +291,186 @@ async fn update_many_rejects_setting_tenant_id() {
 
     assert!(matches!(err, ScopeError::Denied("tenant_id is immutable")));
 }
+
+#[tokio::test]
+async fn tenant_scoped_update_returns_not_found_for_missing_id() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let scope = AccessScope::for_tenant(Uuid::new_v4());
+    let missing = Uuid::new_v4();
+
+    let err = secure_update_with_scope::<tenant_ent::Entity>(
+        tenant_ent::ActiveModel {
+            id: Set(missing),
+            name: Set("nope".to_owned()),
+            ..Default::default()
+        },
+        &scope,
+        missing,
+        &conn,
+    )
+    .await
+    .expect_err("must report not found");
+
+    assert!(matches!(err, ScopeError::NotFound { id: Some(id) } if id == missing));
+}
+
+#[tokio::test]
+async fn secure_delete_one_deletes_row_in_scope() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_a = Uuid::new_v4();
+    let scope_a = AccessScope::for_tenant(tenant_a);
+
+    let id = Uuid::new_v4();
+    let _ = secure_insert::<tenant_ent::Entity>(
+        tenant_ent::ActiveModel {
+            id: Set(id),
+            tenant_id: Set(tenant_a),
+            name: Set("to-delete".to_owned()),
+        },
+        &scope_a,
+        &conn,
+    )
+    .await
+    .expect("insert");
+
+    secure_delete_one::<tenant_ent::Entity>(&scope_a, id, &conn)
+        .await
+        .expect("delete");
+
+    let found = tenant_ent::Entity::find()
+        .secure()
+        .scope_with(&scope_a)
+        .and_id(id)
+        .expect("and_id")
+        .one(&conn)
+        .await
+        .expect("select");
+    assert!(found.is_none());
+}
+
+#[tokio::test]
+async fn secure_delete_one_returns_not_found_for_cross_tenant_id() {
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant_a = Uuid::new_v4();
+    let tenant_b = Uuid::new_v4();
+    let scope_a = AccessScope::for_tenant(tenant_a);
+    let scope_b = AccessScope::for_tenant(tenant_b);
+
+    let id_b = Uuid::new_v4();
+    let _ = secure_insert::<tenant_ent::Entity>(
+        tenant_ent::ActiveModel {
+            id: Set(id_b),
+            tenant_id: Set(tenant_b),
+            name: Set("row-b".to_owned()),
+        },
+        &scope_b,
+        &conn,
+    )
+    .await
+    .expect("insert");
+
+    let err = secure_delete_one::<tenant_ent::Entity>(&scope_a, id_b, &conn)
+        .await
+        .expect_err("must not delete across tenants");
+    assert!(matches!(err, ScopeError::NotFound { id: Some(id) } if id == id_b));
+
+    // Row in tenant B must still exist.
+    let found = tenant_ent::Entity::find()
+        .secure()
+        .scope_with(&scope_b)
+        .and_id(id_b)
+        .expect("and_id")
+        .one(&conn)
+        .await
+        .expect("select");
+    assert!(found.is_some());
+}
+
+#[tokio::test]
+async fn read_scope_cannot_write_but_can_select() {
+    use sea_orm::sea_query::Expr;
+
+    let test_db = TestDb::new().await;
+    let conn = test_db.conn();
+    let tenant = Uuid::new_v4();
+    let write_scope = AccessScope::for_tenant(tenant);
+    // What the PEP hands out for a "get" action.
+    let get_scope = AccessScope::for_tenant(tenant).with_intent(ScopeIntent::Read);
+
+    let id = Uuid::new_v4();
+    let _ = secure_insert::<tenant_ent::Entity>(
+        tenant_ent::ActiveModel {
+            id: Set(id),
+            tenant_id: Set(tenant),
+            name: Set("before".to_owned()),
+        },
+        &write_scope,
+        &conn,
+    )
+    .await
+    .expect("insert");
+
+    let err = secure_update_with_scope::<tenant_ent::Entity>(
+        tenant_ent::ActiveModel {
+            id: Set(id),
+            name: Set("after".to_owned()),
+            ..Default::default()
+        },
+        &get_scope,
+        id,
+        &conn,
+    )
+    .await
+    .expect_err("read scope must not update");
+    assert!(matches!(err, ScopeError::ReadOnlyScope));
+
+    let err = tenant_ent::Entity::update_many()
+        .secure()
+        .scope_with(&get_scope)
+        .col_expr(tenant_ent::Column::Name, Expr::value("after"))
+        .exec(&conn)
+        .await
+        .expect_err("read scope must not update_many");
+    assert!(matches!(err, ScopeError::ReadOnlyScope));
+
+    let err = tenant_ent::Entity::delete_many()
+        .secure()
+        .scope_with(&get_scope)
+        .exec(&conn)
+        .await
+        .expect_err("read scope must not delete_many");
+    assert!(matches!(err, ScopeError::ReadOnlyScope));
+
+    let err = secure_delete_one::<tenant_ent::Entity>(&get_scope, id, &conn)
+        .await
+        .expect_err("read scope must not delete");
+    assert!(matches!(err, ScopeError::ReadOnlyScope));
+
+    let err = secure_insert::<tenant_ent::Entity>(
+        tenant_ent::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(tenant),
+            name: Set("other".to_owned()),
+        },
+        &get_scope,
+        &conn,
+    )
+    .await
+    .expect_err("read scope must not insert");
+    assert!(matches!(err, ScopeError::ReadOnlyScope));
+
+    // The row is untouched and still readable with the read scope.
+    let row = tenant_ent::Entity::find()
+        .secure()
+        .scope_with(&get_scope)
+        .and_id(id)
+        .expect("and_id")
+        .one(&conn)
+        .await
+        .expect("select")
+        .expect("row in scope");
+    assert_eq!(row.name, "before");
+}
@@ -10,13 +10,18 @@
 //! the factory-based bypass vulnerability.
 
 use modkit_db::migration_runner::run_migrations_for_testing;
-use modkit_db::secure::{Db, ScopableEntity, SecureEntityExt, secure_insert};
-use modkit_db::{ConnectOpts, DbError, connect_db};
+use modkit_db::secure::{
+    Db, ScopableEntity, SecureEntityExt, TxAccessMode, TxConfig, TxError, secure_insert,
+};
+use modkit_db::{ConnectOpts, DBProvider, DbError, connect_db};
 use modkit_security::access_scope::{ScopeConstraint, ScopeFilter};
 use modkit_security::{AccessScope, pep_properties};
 use sea_orm::Set;
 use sea_orm::entity::prelude::*;
 use sea_orm_migration::prelude as mig;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
 use uuid::Uuid;
 
 mod ent {
@@ -321,3 +326,218 @@ async fn sqlite_conn_succeeds_outside_transaction() {
     let conn = db.conn();
     assert!(conn.is_ok(), "conn() should succeed outside transaction");
 }
+
+fn serialization_failure() -> anyhow::Error {
+    DbError::SerializationFailure(DbErr::Custom("could not serialize access".to_owned())).into()
+}
+
+/// Test: a transaction failing with a serialization conflict is re-run and
+/// only the successful attempt is committed.
+#[tokio::test]
+async fn sqlite_tx_config_retries_on_conflict() {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db("sqlite:file:memdb_retry?mode=memory&cache=shared", opts)
+        .await
+        .expect("Failed to connect to database");
+    let db = setup(db).await;
+
+    let tenant_id = Uuid::new_v4();
+    let scope = AccessScope::for_tenants(vec![tenant_id]);
+    let scope_for_tx = scope.clone();
+    let attempts = Arc::new(AtomicU32::new(0));
+    let attempts_for_tx = Arc::clone(&attempts);
+    let cfg = TxConfig::serializable().with_conflict_retry(3, Duration::from_millis(1));
+
+    let (db, result) = db
+        .transaction_with_config(cfg, move |tx| {
+            let scope = scope_for_tx.clone();
+            let attempt = attempts_for_tx.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move {
+                let am = ent::ActiveModel {
+                    tenant_id: Set(tenant_id),
+                    resource_id: Set(Uuid::new_v4()),
+                    val: Set(format!("attempt-{attempt}")),
+                    ..Default::default()
+                };
+                let _ = secure_insert::<ent::Entity>(am, &scope, tx).await?;
+                if attempt < 2 {
+                    return Err(serialization_failure());
+                }
+                Ok(attempt)
+            })
+        })
+        .await;
+
+    assert_eq!(result.expect("Transaction should succeed after retries"), 2);
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    // Failed attempts were rolled back.
+    let conn = db.conn().expect("conn");
+    let rows = ent::Entity::find()
+        .secure()
+        .scope_with(&scope)
+        .all(&conn)
+        .await
+        .expect("select");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].val, "attempt-2");
+}
+
+/// Test: retries stop after `max_retries` and the conflict error is returned.
+#[tokio::test]
+async fn sqlite_tx_config_gives_up_after_max_retries() {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db("sqlite:file:memdb_retry_max?mode=memory&cache=shared", opts)
+        .await
+        .expect("Failed to connect to database");
+
+    let attempts = Arc::new(AtomicU32::new(0));
+    let attempts_for_tx = Arc::clone(&attempts);
+    let cfg = TxConfig::default().with_conflict_retry(2, Duration::from_millis(1));
+
+    let (_db, result): (_, anyhow::Result<()>) = db
+        .transaction_with_config(cfg, move |_tx| {
+            attempts_for_tx.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Err(serialization_failure()) })
+        })
+        .await;
+
+    let err = result.expect_err("Transaction should fail");
+    assert!(
+        err.downcast_ref::<DbError>()
+            .is_some_and(DbError::is_serialization_failure),
+        "Expected SerializationFailure, got: {err:?}"
+    );
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+}
+
+/// Test: without `retry_on_conflict` the closure runs exactly once.
+#[tokio::test]
+async fn sqlite_tx_config_does_not_retry_by_default() {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db("sqlite:file:memdb_no_retry?mode=memory&cache=shared", opts)
+        .await
+        .expect("Failed to connect to database");
+
+    let attempts = Arc::new(AtomicU32::new(0));
+    let attempts_for_tx = Arc::clone(&attempts);
+
+    let (_db, result): (_, anyhow::Result<()>) = db
+        .transaction_with_config(TxConfig::default(), move |_tx| {
+            attempts_for_tx.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Err(serialization_failure()) })
+        })
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+/// Test: `with_replica_tx` rejects a read-write configuration before touching the database.
+#[tokio::test]
+async fn sqlite_replica_tx_rejects_read_write() {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db(
+        "sqlite:file:memdb_replica_rw?mode=memory&cache=shared",
+        opts,
+    )
+    .await
+    .expect("Failed to connect to database");
+    let provider = DBProvider::<DbError>::new(db);
+
+    for cfg in [
+        TxConfig::default(),
+        TxConfig {
+            access_mode: Some(TxAccessMode::ReadWrite),
+            ..Default::default()
+        },
+    ] {
+        let err = provider
+            .with_replica_tx(cfg, |_tx| Box::pin(async { Ok(()) }))
+            .await
+            .expect_err("read-write replica transaction must be rejected");
+        assert!(
+            matches!(&err, TxError::Config(msg) if msg == "read-write transaction not allowed on replica"),
+            "Expected TxError::Config, got: {err:?}"
+        );
+    }
+}
+
+/// Test: `with_replica_tx` reads from the attached replica, not from the primary.
+#[tokio::test]
+async fn sqlite_replica_tx_reads_from_replica() {
+    let opts = || ConnectOpts {
+        max_conns: Some(1),
+        ..Default::default()
+    };
+    let primary = connect_db(
+        "sqlite:file:memdb_replica_primary?mode=memory&cache=shared",
+        opts(),
+    )
+    .await
+    .expect("Failed to connect to primary");
+    let replica = connect_db(
+        "sqlite:file:memdb_replica_replica?mode=memory&cache=shared",
+        opts(),
+    )
+    .await
+    .expect("Failed to connect to replica");
+    let primary = setup(primary).await;
+    let replica = setup(replica).await;
+
+    let tenant_id = Uuid::new_v4();
+    let scope = AccessScope::for_tenants(vec![tenant_id]);
+    let scope_for_tx = scope.clone();
+    let (replica, result) = replica
+        .transaction(move |tx| {
+            Box::pin(async move {
+                let am = ent::ActiveModel {
+                    tenant_id: Set(tenant_id),
+                    resource_id: Set(Uuid::new_v4()),
+                    val: Set("replicated".to_owned()),
+                    ..Default::default()
+                };
+                let _ = secure_insert::<ent::Entity>(am, &scope_for_tx, tx).await?;
+                Ok::<(), anyhow::Error>(())
+            })
+        })
+        .await;
+    result.expect("seed replica");
+
+    let count_rows = |provider: DBProvider<DbError>| {
+        let scope = scope.clone();
+        async move {
+            provider
+                .with_replica_tx(TxConfig::read_only(), move |tx| {
+                    Box::pin(async move {
+                        ent::Entity::find()
+                            .secure()
+                            .scope_with(&scope)
+                            .count(tx)
+                            .await
+                            .map_err(|e| DbError::Other(e.into()))
+                    })
+                })
+                .await
+                .expect("read-only transaction")
+        }
+    };
+
+    let without_replica = DBProvider::<DbError>::new(primary.clone());
+    assert_eq!(count_rows(without_replica).await, 0);
+
+    let with_replica = DBProvider::<DbError>::new(primary).with_replica(replica);
+    assert_eq!(count_rows(with_replica).await, 1);
+}
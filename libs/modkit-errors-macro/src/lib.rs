@@ -134,6 +134,7 @@ fn generate_errors(input: &DeclareErrorsInput) -> syn::Result<TokenStream2> {
     let json_file_path = &input.path;
 
     let enum_variants = generate_enum_variants(&entries);
+    let all_variants: Vec<_> = entries.iter().map(|e| code_to_ident(&e.code)).collect();
     let const_defs = generate_const_defs(&entries);
     let impl_methods = generate_impl_methods(&entries);
     let short_accessors = generate_short_accessors(&entries, &short_names);
@@ -159,6 +160,9 @@ fn generate_errors(input: &DeclareErrorsInput) -> syn::Result<TokenStream2> {
         }
 
         impl ErrorCode {
+            /// All error codes of the catalog, in declaration order
+            pub const ALL: &'static [ErrorCode] = &[#(ErrorCode::#all_variants),*];
+
             /// Get the HTTP status code for this error
             pub const fn status(&self) -> u16 {
                 match self {
@@ -4,10 +4,12 @@
 //! on HTTP frameworks. It includes:
 //! - RFC 9457 Problem Details (`Problem`)
 //! - Error catalog support (`ErrDef`)
+//! - Process-wide problem type registry (`ProblemTypeRegistry`)
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 pub mod catalog;
 pub mod problem;
+pub mod registry;
 
 // Re-export commonly used types
 pub use catalog::ErrDef;
@@ -15,6 +17,7 @@ pub use problem::{
     APPLICATION_PROBLEM_JSON, Problem, ValidationError, ValidationErrorResponse,
     ValidationViolation,
 };
+pub use registry::{ProblemType, ProblemTypeRegistry};
 
 /// Helper to attach instance and `trace_id` to a Problem
 ///
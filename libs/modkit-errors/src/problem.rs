@@ -116,11 +116,34 @@ impl Problem {
         }
     }
 
+    /// Create a Problem of a registered type, taking title and status from the
+    /// [`ProblemTypeRegistry`](crate::ProblemTypeRegistry).
+    ///
+    /// An unregistered URI yields a 500 "Internal Server Error" problem with the
+    /// given type and logs a warning (once per URI).
+    pub fn from_type(type_url: impl Into<String>) -> Self {
+        let type_url = type_url.into();
+        let problem = match crate::ProblemTypeRegistry::global().lookup(&type_url) {
+            Some(ty) => Self::new(ty.status, ty.title, String::new()),
+            None => Self::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Internal Server Error",
+                String::new(),
+            ),
+        };
+        problem.with_type(type_url)
+    }
+
     pub fn with_type(mut self, type_url: impl Into<String>) -> Self {
         self.type_url = type_url.into();
         self
     }
 
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = detail.into();
+        self
+    }
+
     pub fn with_instance(mut self, uri: impl Into<String>) -> Self {
         self.instance = uri.into();
         self
@@ -201,6 +224,21 @@ mod tests {
         assert_eq!(p.errors.as_ref().unwrap().len(), 1);
     }
 
+    #[test]
+    fn from_type_uses_registered_title_and_status() {
+        let url = "https://errors.example.com/PROBLEM_TEST_GONE";
+        crate::ProblemTypeRegistry::global().register(url, "Widget Gone", StatusCode::GONE);
+
+        let p = Problem::from_type(url).with_detail("widget 7 was removed");
+        assert_eq!(p.status, StatusCode::GONE);
+        assert_eq!(p.title, "Widget Gone");
+        assert_eq!(p.type_url, url);
+        assert_eq!(p.detail, "widget 7 was removed");
+
+        let p = Problem::from_type("https://errors.example.com/PROBLEM_TEST_UNKNOWN");
+        assert_eq!(p.status, StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
     #[test]
     fn problem_serializes_status_as_u16() {
         let p = Problem::new(StatusCode::NOT_FOUND, "Not Found", "Resource not found");
@@ -0,0 +1,206 @@
+//! Process-wide registry of problem type URIs
+//!
+//! Modules register the `type` URIs of their error catalog together with a
+//! title and a default status. [`Problem::from_type`](crate::Problem::from_type)
+//! and the HTTP error layer consult the registry so the same type URI always
+//! carries the same title and status, whoever builds the `Problem`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::{Mutex, OnceLock, PoisonError, RwLock};
+
+use http::StatusCode;
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "utoipa")]
+use utoipa::ToSchema;
+
+use crate::catalog::ErrDef;
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde requires &T signature
+fn serialize_status_code<S>(status: &StatusCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u16(status.as_u16())
+}
+
+/// A registered problem type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(ToSchema))]
+#[cfg_attr(feature = "utoipa", schema(title = "ProblemType"))]
+pub struct ProblemType {
+    /// Problem type URI (the `type` member of a Problem).
+    #[serde(rename = "type")]
+    pub type_url: String,
+    /// Title used for every problem of this type.
+    pub title: String,
+    /// Default HTTP status for problems of this type.
+    #[serde(serialize_with = "serialize_status_code")]
+    #[cfg_attr(feature = "utoipa", schema(value_type = u16))]
+    pub status: StatusCode,
+}
+
+/// Registry of problem type URIs, shared by the whole process.
+#[derive(Debug, Default)]
+pub struct ProblemTypeRegistry {
+    types: RwLock<BTreeMap<String, ProblemType>>,
+    /// Unregistered URIs that were already reported, so each is logged once.
+    warned: Mutex<HashSet<String>>,
+}
+
+impl ProblemTypeRegistry {
+    /// Create an empty registry.
+    ///
+    /// Production code uses [`ProblemTypeRegistry::global`]; separate instances
+    /// are meant for tests.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The process-wide registry.
+    #[must_use]
+    pub fn global() -> &'static Self {
+        static GLOBAL: OnceLock<ProblemTypeRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(Self::new)
+    }
+
+    /// Register a problem type.
+    ///
+    /// Registering the same URI again with the same title and status is a
+    /// no-op; a different title or status replaces the entry and logs a warning.
+    pub fn register(
+        &self,
+        type_url: impl Into<String>,
+        title: impl Into<String>,
+        status: StatusCode,
+    ) {
+        let entry = ProblemType {
+            type_url: type_url.into(),
+            title: title.into(),
+            status,
+        };
+        let mut types = self.types.write().unwrap_or_else(PoisonError::into_inner);
+        if let Some(previous) = types.get(&entry.type_url)
+            && previous != &entry
+        {
+            tracing::warn!(
+                type_url = %entry.type_url,
+                previous_title = %previous.title,
+                previous_status = previous.status.as_u16(),
+                title = %entry.title,
+                status = entry.status.as_u16(),
+                "Problem type registered again with a different title or status"
+            );
+        }
+        types.insert(entry.type_url.clone(), entry);
+    }
+
+    /// Register every entry of an error catalog.
+    ///
+    /// With a catalog generated by `declare_errors!`:
+    /// `registry.register_catalog(ErrorCode::ALL.iter().map(ErrorCode::def))`.
+    pub fn register_catalog(&self, defs: impl IntoIterator<Item = ErrDef>) {
+        for def in defs {
+            let status =
+                StatusCode::from_u16(def.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+            self.register(def.type_url, def.title, status);
+        }
+    }
+
+    /// Look up a registered problem type.
+    #[must_use]
+    pub fn get(&self, type_url: &str) -> Option<ProblemType> {
+        self.types
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(type_url)
+            .cloned()
+    }
+
+    /// Look up a problem type, logging a warning the first time an
+    /// unregistered URI is seen. `about:blank` is never reported.
+    pub fn lookup(&self, type_url: &str) -> Option<ProblemType> {
+        let found = self.get(type_url);
+        if found.is_none()
+            && type_url != "about:blank"
+            && self
+                .warned
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .insert(type_url.to_owned())
+        {
+            tracing::warn!(type_url, "Problem type URI is not registered");
+        }
+        found
+    }
+
+    /// All registered problem types, ordered by URI.
+    #[must_use]
+    pub fn all(&self) -> Vec<ProblemType> {
+        self.types
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    const NOT_FOUND: ErrDef = ErrDef {
+        status: 404,
+        title: "Widget Not Found",
+        code: "WIDGET_NOT_FOUND",
+        type_url: "https://errors.example.com/WIDGET_NOT_FOUND",
+    };
+
+    #[test]
+    fn registers_catalog_entries() {
+        let registry = ProblemTypeRegistry::new();
+        registry.register_catalog([NOT_FOUND]);
+
+        let ty = registry.get(NOT_FOUND.type_url).unwrap();
+        assert_eq!(ty.title, "Widget Not Found");
+        assert_eq!(ty.status, StatusCode::NOT_FOUND);
+        assert_eq!(registry.all(), vec![ty]);
+    }
+
+    #[test]
+    fn unregistered_lookup_is_reported_once() {
+        let registry = ProblemTypeRegistry::new();
+        assert!(
+            registry
+                .lookup("https://errors.example.com/UNKNOWN")
+                .is_none()
+        );
+        assert!(
+            registry
+                .lookup("https://errors.example.com/UNKNOWN")
+                .is_none()
+        );
+        assert_eq!(registry.warned.lock().unwrap().len(), 1);
+
+        registry.lookup("about:blank");
+        assert_eq!(registry.warned.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn serializes_status_as_u16() {
+        let registry = ProblemTypeRegistry::new();
+        registry.register_catalog([NOT_FOUND]);
+        let json = serde_json::to_value(registry.all()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "type": "https://errors.example.com/WIDGET_NOT_FOUND",
+                "title": "Widget Not Found",
+                "status": 404,
+            }])
+        );
+    }
+}
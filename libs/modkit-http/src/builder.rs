@@ -1,6 +1,7 @@
 use crate::config::{
     HttpClientConfig, RedirectConfig, RetryConfig, TlsRootConfig, TransportSecurity,
 };
+use crate::dns::{DynResolver, Resolve};
 use crate::error::HttpError;
 use crate::layers::{OtelLayer, RetryLayer, SecureRedirectPolicy, UserAgentLayer};
 use crate::response::ResponseBody;
@@ -12,6 +13,7 @@ use hyper_rustls::HttpsConnector;
 use hyper_util::client::legacy::Client;
 use hyper_util::client::legacy::connect::HttpConnector;
 use hyper_util::rt::{TokioExecutor, TokioTimer};
+use std::sync::Arc;
 use std::time::Duration;
 use tower::buffer::Buffer;
 use tower::limit::ConcurrencyLimitLayer;
@@ -30,6 +32,7 @@ type InnerService =
 pub struct HttpClientBuilder {
     config: HttpClientConfig,
     auth_layer: Option<Box<dyn FnOnce(InnerService) -> InnerService + Send>>,
+    resolver: Option<Arc<dyn Resolve>>,
 }
 
 impl HttpClientBuilder {
@@ -39,6 +42,7 @@ impl HttpClientBuilder {
         Self {
             config: HttpClientConfig::default(),
             auth_layer: None,
+            resolver: None,
         }
     }
 
@@ -48,6 +52,7 @@ impl HttpClientBuilder {
         Self {
             config,
             auth_layer: None,
+            resolver: None,
         }
     }
 
@@ -156,6 +161,17 @@ impl HttpClientBuilder {
         self
     }
 
+    /// Resolve host names with `resolver` instead of the system resolver
+    ///
+    /// Connections are made only to the addresses the resolver returns, which
+    /// lets callers vet them (e.g. reject private ranges for user-supplied
+    /// URLs) without a separate lookup that could answer differently.
+    #[must_use]
+    pub fn dns_resolver(mut self, resolver: Arc<dyn Resolve>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
     /// Set the buffer capacity for concurrent request handling
     ///
     /// The HTTP client uses an internal buffer to allow concurrent requests
@@ -253,7 +269,11 @@ impl HttpClientBuilder {
         let total_timeout = self.config.total_timeout;
 
         // Build the HTTPS connector (may fail for Native roots if no valid certs)
-        let https = build_https_connector(self.config.tls_roots, self.config.transport)?;
+        let https = build_https_connector(
+            self.config.tls_roots,
+            self.config.transport,
+            DynResolver::new(self.resolver),
+        )?;
 
         // Create the base hyper client with HTTP/2 support and connection pool settings
         let mut client_builder = Client::builder(TokioExecutor::new());
@@ -465,8 +485,12 @@ where
 fn build_https_connector(
     tls_roots: TlsRootConfig,
     transport: TransportSecurity,
-) -> Result<HttpsConnector<HttpConnector>, HttpError> {
+    resolver: DynResolver,
+) -> Result<HttpsConnector<HttpConnector<DynResolver>>, HttpError> {
     let allow_http = transport == TransportSecurity::AllowInsecureHttp;
+    let mut http = HttpConnector::new_with_resolver(resolver);
+    // HttpConnector won't enforce the scheme; HttpsConnector does
+    http.enforce_http(false);
 
     match tls_roots {
         TlsRootConfig::WebPki => {
@@ -477,9 +501,15 @@ fn build_https_connector(
                 // rustls::Error implements Error + Send + Sync
                 .map_err(|e| HttpError::Tls(Box::new(e)))?;
             let connector = if allow_http {
-                builder.https_or_http().enable_all_versions().build()
+                builder
+                    .https_or_http()
+                    .enable_all_versions()
+                    .wrap_connector(http)
             } else {
-                builder.https_only().enable_all_versions().build()
+                builder
+                    .https_only()
+                    .enable_all_versions()
+                    .wrap_connector(http)
             };
             Ok(connector)
         }
@@ -489,9 +519,15 @@ fn build_https_connector(
                 .map_err(|e| HttpError::Tls(e.into()))?;
             let builder = hyper_rustls::HttpsConnectorBuilder::new().with_tls_config(client_config);
             let connector = if allow_http {
-                builder.https_or_http().enable_all_versions().build()
+                builder
+                    .https_or_http()
+                    .enable_all_versions()
+                    .wrap_connector(http)
             } else {
-                builder.https_only().enable_all_versions().build()
+                builder
+                    .https_only()
+                    .enable_all_versions()
+                    .wrap_connector(http)
             };
             Ok(connector)
         }
@@ -2007,4 +2007,51 @@ mod tests {
             Ok(_) => panic!("Expected InvalidUri error, but request succeeded"),
         }
     }
+
+    /// Resolver that answers every name with loopback, or refuses everything.
+    struct StaticResolver(Option<std::net::IpAddr>);
+
+    impl crate::Resolve for StaticResolver {
+        fn resolve(&self, host: &str) -> crate::Resolving {
+            let answer = match self.0 {
+                Some(ip) => Ok(vec![std::net::SocketAddr::new(ip, 0)]),
+                None => Err(format!("{host} is not allowed").into()),
+            };
+            Box::pin(async move { answer })
+        }
+    }
+
+    /// Test: host names are resolved with the custom resolver only
+    #[tokio::test]
+    async fn test_custom_dns_resolver_is_used() {
+        let server = MockServer::start();
+        let _m = server.mock(|when, then| {
+            when.method(Method::GET).path("/test");
+            then.status(200);
+        });
+        let url = format!("http://resolver.test:{}/test", server.port());
+
+        let client = HttpClientBuilder::new()
+            .allow_insecure_http()
+            .retry(None)
+            .dns_resolver(std::sync::Arc::new(StaticResolver(Some(
+                std::net::Ipv4Addr::LOCALHOST.into(),
+            ))))
+            .build()
+            .unwrap();
+        let resp = client.get(&url).send().await.unwrap();
+        assert_eq!(resp.status(), hyper::StatusCode::OK);
+
+        let client = HttpClientBuilder::new()
+            .allow_insecure_http()
+            .retry(None)
+            .dns_resolver(std::sync::Arc::new(StaticResolver(None)))
+            .build()
+            .unwrap();
+        let result = client.get(&url).send().await;
+        assert!(
+            matches!(result, Err(HttpError::Transport(_))),
+            "expected a transport error, got: {result:?}"
+        );
+    }
 }
@@ -0,0 +1,65 @@
+//! Pluggable host name resolution for outbound connections.
+
+use std::future::Future;
+use std::net::SocketAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use hyper_util::client::legacy::connect::dns::{GaiResolver, Name};
+use tower::Service;
+
+/// Error returned by a [`Resolve`] implementation.
+pub type ResolveError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Future returned by [`Resolve::resolve`].
+pub type Resolving = Pin<Box<dyn Future<Output = Result<Vec<SocketAddr>, ResolveError>> + Send>>;
+
+/// Custom host name resolution, installed with
+/// [`HttpClientBuilder::dns_resolver`](crate::HttpClientBuilder::dns_resolver).
+///
+/// The client connects only to the addresses returned here, so a resolver
+/// that filters them (e.g. to block private ranges) is not subject to a
+/// second lookup returning something else. Return addresses with port `0`;
+/// the port is taken from the request URL.
+pub trait Resolve: Send + Sync {
+    /// Resolve a domain name. IP literals in URLs never reach the resolver.
+    fn resolve(&self, host: &str) -> Resolving;
+}
+
+/// Adapter between [`Resolve`] and hyper's connector.
+///
+/// Falls back to the system resolver when no custom resolver is set.
+#[derive(Clone)]
+pub(crate) struct DynResolver {
+    custom: Option<Arc<dyn Resolve>>,
+    system: GaiResolver,
+}
+
+impl DynResolver {
+    pub(crate) fn new(custom: Option<Arc<dyn Resolve>>) -> Self {
+        Self {
+            custom,
+            system: GaiResolver::new(),
+        }
+    }
+}
+
+impl Service<Name> for DynResolver {
+    type Response = std::vec::IntoIter<SocketAddr>;
+    type Error = ResolveError;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        if let Some(custom) = &self.custom {
+            let resolving = custom.resolve(name.as_str());
+            return Box::pin(async move { Ok(resolving.await?.into_iter()) });
+        }
+        let resolving = self.system.call(name);
+        Box::pin(async move { Ok(resolving.await?.collect::<Vec<_>>().into_iter()) })
+    }
+}
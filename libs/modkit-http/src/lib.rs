@@ -46,6 +46,7 @@
 mod builder;
 mod client;
 mod config;
+mod dns;
 mod error;
 mod layers;
 pub mod otel;
@@ -61,6 +62,7 @@ pub use config::{
     RateLimitConfig, RedirectConfig, RetryConfig, RetryTrigger, TlsRootConfig, TransportSecurity,
     is_idempotent_method,
 };
+pub use dns::{Resolve, ResolveError, Resolving};
 pub use error::{HttpError, InvalidUriKind};
 pub use layers::{
     OtelLayer, OtelService, RETRY_ATTEMPT_HEADER, RetryLayer, RetryService, SecureRedirectPolicy,
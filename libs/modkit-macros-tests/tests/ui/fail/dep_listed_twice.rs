@@ -0,0 +1,6 @@
+use modkit_macros::module;
+
+#[module(name="x", deps=["db", optional("db")])]
+pub struct X;
+
+fn main() {}
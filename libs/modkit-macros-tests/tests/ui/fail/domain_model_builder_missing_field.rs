@@ -0,0 +1,11 @@
+use modkit_macros::domain_model;
+
+#[domain_model(builder)]
+pub struct Config {
+    pub name: String,
+    pub size: u32,
+}
+
+fn main() {
+    let _ = Config::builder().name("x".to_owned()).build();
+}
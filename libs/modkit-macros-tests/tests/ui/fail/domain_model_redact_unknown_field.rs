@@ -0,0 +1,9 @@
+use modkit_macros::domain_model;
+
+#[domain_model(redact(pasword))]
+pub struct Credentials {
+    pub user: String,
+    pub password: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use modkit_macros::module;
+
+#[module(name="x", deps=["db"], capabilities=[stateful], lifecycle(entry="serve", await_ready_of=["authn"]))]
+pub struct X;
+
+fn main() {}
@@ -0,0 +1,33 @@
+// Builder and redacted Debug compose with other derives and generics
+use modkit_macros::domain_model;
+
+#[domain_model(builder, redact(password))]
+#[derive(Clone, PartialEq)]
+pub struct DbSettings<T: Clone + PartialEq> {
+    pub url: String,
+    pub password: String,
+    pub extra: T,
+    pub label: Option<String>,
+    #[builder(default = 10)]
+    pub max_connections: u32,
+    #[builder(default)]
+    pub tags: Vec<String>,
+}
+
+fn main() {
+    let settings = DbSettings::builder()
+        .password("hunter2".to_owned())
+        .url("postgres://db".to_owned())
+        .extra(7_u8)
+        .label("primary".to_owned())
+        .build();
+
+    assert_eq!(settings.max_connections, 10);
+    assert!(settings.tags.is_empty());
+    assert_eq!(settings.clone(), settings);
+
+    let debug = format!("{settings:?}");
+    assert!(debug.contains("password: \"<redacted>\""));
+    assert!(debug.contains("url: \"postgres://db\""));
+    assert!(!debug.contains("hunter2"));
+}
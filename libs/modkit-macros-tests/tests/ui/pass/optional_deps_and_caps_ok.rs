@@ -0,0 +1,38 @@
+// Optional dependencies, and a capability registered only after opting in
+use anyhow::Result;
+use modkit_macros::module;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Default)]
+#[module(
+    name = "demo-optional",
+    deps = ["demo-required", optional("demo-extra")],
+    capabilities = [optional(stateful)],
+    lifecycle(entry = "serve", await_ready, await_ready_of = ["demo-extra"])
+)]
+pub struct DemoOptional;
+
+impl DemoOptional {
+    async fn serve(
+        &self,
+        _cancel: CancellationToken,
+        ready: modkit::lifecycle::ReadySignal,
+    ) -> Result<()> {
+        ready.notify();
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl modkit::Module for DemoOptional {
+    async fn init(&self, ctx: &modkit::ModuleCtx) -> anyhow::Result<()> {
+        if ctx.dependency_available("demo-extra") {
+            // use the extra module's client
+        }
+        Ok(())
+    }
+}
+
+fn main() {
+    DemoOptional::enable_stateful_capability();
+}
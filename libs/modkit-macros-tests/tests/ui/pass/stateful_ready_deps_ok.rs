@@ -0,0 +1,33 @@
+// Lifecycle can wait for dependencies to be ready before reporting ready
+use anyhow::Result;
+use modkit_macros::module;
+use tokio_util::sync::CancellationToken;
+
+#[derive(Default)]
+#[module(
+    name = "demo-gated",
+    deps = ["demo-ready"],
+    capabilities = [stateful],
+    lifecycle(entry = "serve", await_ready, await_ready_of = ["demo-ready"], ready_timeout = "5s")
+)]
+pub struct DemoGated;
+
+impl DemoGated {
+    async fn serve(
+        &self,
+        _cancel: CancellationToken,
+        ready: modkit::lifecycle::ReadySignal,
+    ) -> Result<()> {
+        ready.notify_degraded("warming up");
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl modkit::Module for DemoGated {
+    async fn init(&self, _ctx: &modkit::ModuleCtx) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+fn main() {}
@@ -4,9 +4,13 @@
 //! contain infrastructure types. Validation is performed at macro expansion time by
 //! checking field type paths against forbidden crates and type names.
 
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{DeriveInput, Fields, Type, TypePath};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{DeriveInput, Fields, GenericParam, Ident, Type, TypePath, parse_quote};
+
+use crate::utils::did_you_mean;
 
 /// Forbidden crate names for domain models.
 ///
@@ -43,17 +47,55 @@ const FORBIDDEN_PATH_PREFIXES: &[(&str, &str)] = &[
 /// `http::StatusCode` etc.
 const FORBIDDEN_TYPE_NAMES: &[&str] = &["PgPool", "MySqlPool", "SqlitePool", "DatabaseConnection"];
 
+/// Placeholder printed by the generated `Debug` impl for redacted fields.
+const REDACTED: &str = "<redacted>";
+
+/// Options accepted by `#[domain_model(...)]`.
+#[derive(Default)]
+pub struct DomainModelArgs {
+    /// `builder`: generate a type-state builder (span of the keyword).
+    builder: Option<Span>,
+    /// `redact(a, b)`: fields printed as `"<redacted>"` by a generated `Debug` impl.
+    redact: Vec<Ident>,
+    /// Span of the `redact` keyword, used for struct-level errors.
+    redact_span: Option<Span>,
+}
+
+impl DomainModelArgs {
+    /// Parses `builder` and `redact(field, ...)` from the attribute arguments.
+    pub fn parse(attr: TokenStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("builder") {
+                args.builder = Some(meta.path.require_ident()?.span());
+                Ok(())
+            } else if meta.path.is_ident("redact") {
+                args.redact_span = Some(meta.path.require_ident()?.span());
+                meta.parse_nested_meta(|field| {
+                    args.redact.push(field.path.require_ident()?.clone());
+                    Ok(())
+                })
+            } else {
+                Err(meta.error("unknown domain_model option; expected `builder` or `redact(...)`"))
+            }
+        });
+        syn::parse::Parser::parse2(parser, attr)?;
+        Ok(args)
+    }
+}
+
 /// Expands the `#[domain_model]` attribute macro.
 ///
 /// This function:
 /// 1. Validates that all field types are free of infrastructure dependencies
 /// 2. Returns clear error messages if forbidden types are found
 /// 3. Generates `impl DomainModel for T {}` if validation passes
+/// 4. Generates a builder and/or a redacting `Debug` impl when requested
 ///
 /// Unlike the previous implementation that used trait bounds (which produced
 /// generic "trait not satisfied" errors), this validates type names directly
 /// during macro expansion, providing clear, actionable error messages.
-pub fn expand_domain_model(input: &DeriveInput) -> TokenStream {
+pub fn expand_domain_model(args: &DomainModelArgs, input: &DeriveInput) -> TokenStream {
     let name = &input.ident;
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
@@ -74,12 +116,383 @@ pub fn expand_domain_model(input: &DeriveInput) -> TokenStream {
         }
     }
 
+    let debug_impl = if args.redact_span.is_some() {
+        match expand_redacted_debug(args, input) {
+            Ok(tokens) => tokens,
+            Err(err) => return err.to_compile_error(),
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // `#[builder(...)]` field attributes are consumed here and stripped from the output.
+    let (input, builder) = if let Some(span) = args.builder {
+        match expand_builder(input, span) {
+            Ok(expanded) => expanded,
+            Err(err) => return err.to_compile_error(),
+        }
+    } else {
+        (input.clone(), TokenStream::new())
+    };
+
     // If validation passed, generate the struct/enum and implement DomainModel trait
     quote! {
         #input
 
         impl #impl_generics ::modkit::domain::DomainModel for #name #ty_generics #where_clause {}
+
+        #debug_impl
+
+        #builder
+    }
+}
+
+/// Returns the named fields of a struct, or an error spanned at `span` naming `option`.
+fn named_fields<'a>(
+    input: &'a DeriveInput,
+    option: &str,
+    span: Span,
+) -> syn::Result<&'a syn::FieldsNamed> {
+    match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => Ok(fields),
+        _ => Err(syn::Error::new(
+            span,
+            format!("domain_model `{option}` is only supported on structs with named fields"),
+        )),
+    }
+}
+
+/// Generates a `Debug` impl that prints `"<redacted>"` for the fields listed in `redact(...)`.
+fn expand_redacted_debug(args: &DomainModelArgs, input: &DeriveInput) -> syn::Result<TokenStream> {
+    let span = args.redact_span.unwrap_or_else(Span::call_site);
+    let fields = named_fields(input, "redact", span)?;
+
+    if let Some(debug) = derived_debug(input) {
+        return Err(syn::Error::new_spanned(
+            debug,
+            "remove `Debug` from #[derive(...)]: domain_model `redact(...)` generates the Debug impl",
+        ));
+    }
+
+    let names: Vec<String> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(|i| i.unraw().to_string()))
+        .collect();
+    for ident in &args.redact {
+        let requested = ident.unraw().to_string();
+        if names.contains(&requested) {
+            continue;
+        }
+        let help = did_you_mean(&requested, &names);
+        return Err(syn::Error::new(
+            ident.span(),
+            format!("unknown field '{requested}' in domain_model redact(...){help}"),
+        ));
+    }
+
+    let name = &input.ident;
+    let name_str = name.unraw().to_string();
+    let mut generics = input.generics.clone();
+    let type_params: Vec<Ident> = generics.type_params().map(|t| t.ident.clone()).collect();
+    {
+        let where_clause = generics.make_where_clause();
+        for ident in &type_params {
+            where_clause
+                .predicates
+                .push(parse_quote!(#ident: ::core::fmt::Debug));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let entries = fields.named.iter().filter_map(|f| {
+        let ident = f.ident.as_ref()?;
+        let label = ident.unraw().to_string();
+        Some(if args.redact.iter().any(|r| r == ident) {
+            quote! { .field(#label, &#REDACTED) }
+        } else {
+            quote! { .field(#label, &self.#ident) }
+        })
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#name_str)
+                    #(#entries)*
+                    .finish()
+            }
+        }
+    })
+}
+
+/// Finds `Debug` inside a `#[derive(...)]` on the item, if any.
+fn derived_debug(input: &DeriveInput) -> Option<syn::Path> {
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .find(|path| path.segments.last().is_some_and(|s| s.ident == "Debug"))
+}
+
+/// How a field is filled in by the generated builder.
+enum BuilderFieldKind {
+    /// Must be set before `build()` is available; tracked in the builder's type.
+    Required,
+    /// `Option<T>` field; the setter takes `T` and the default is `None`.
+    Optional(Type),
+    /// `#[builder(default)]` or `#[builder(default = expr)]`.
+    Default(Option<syn::Expr>),
+}
+
+struct BuilderField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    kind: BuilderFieldKind,
+}
+
+/// Parses `#[builder(default)]` / `#[builder(default = expr)]` on a field.
+fn builder_field_kind(field: &syn::Field) -> syn::Result<BuilderFieldKind> {
+    let mut kind = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+                let expr = if meta.input.peek(syn::Token![=]) {
+                    Some(meta.value()?.parse()?)
+                } else {
+                    None
+                };
+                kind = Some(BuilderFieldKind::Default(expr));
+                Ok(())
+            } else {
+                Err(meta.error("unknown builder option; expected `default` or `default = ...`"))
+            }
+        })?;
+    }
+    if let Some(kind) = kind {
+        return Ok(kind);
+    }
+    Ok(match option_inner(&field.ty) {
+        Some(inner) => BuilderFieldKind::Optional(inner.clone()),
+        None => BuilderFieldKind::Required,
+    })
+}
+
+/// Returns `T` for `Option<T>` (including `std::option::Option<T>`).
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let last = path.segments.last()?;
+    if last.ident != "Option" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(inner)) if args.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Generates `<Name>Builder` with compile-time tracking of required fields.
+///
+/// Each required field gets a type parameter that is either
+/// `modkit::domain::builder::Missing` or `modkit::domain::builder::Present<T>`;
+/// `build()` is only implemented once every required field is `Present`, mirroring
+/// the type-state approach of `OperationBuilder`.
+///
+/// Returns the input with `#[builder(...)]` field attributes stripped, plus the builder.
+fn expand_builder(input: &DeriveInput, span: Span) -> syn::Result<(DeriveInput, TokenStream)> {
+    let fields = named_fields(input, "builder", span)?;
+    let fields = fields
+        .named
+        .iter()
+        .map(|f| {
+            Ok(BuilderField {
+                // Named fields always have an ident by syn's definition
+                #[allow(clippy::unwrap_used)]
+                ident: f.ident.as_ref().unwrap(),
+                ty: &f.ty,
+                kind: builder_field_kind(f)?,
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let builder_name = format_ident!("{}Builder", name);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let missing = quote!(::modkit::domain::builder::Missing);
+    let present = |ty: &Type| quote!(::modkit::domain::builder::Present<#ty>);
+
+    let required: Vec<&BuilderField> = fields
+        .iter()
+        .filter(|f| matches!(f.kind, BuilderFieldKind::Required))
+        .collect();
+    let optional: Vec<&BuilderField> = fields
+        .iter()
+        .filter(|f| !matches!(f.kind, BuilderFieldKind::Required))
+        .collect();
+    let states: Vec<Ident> = required
+        .iter()
+        .map(|f| format_ident!("__{}", f.ident.unraw().to_string().to_upper_camel_case()))
+        .collect();
+
+    // Builder generics: the struct's own parameters (without defaults) plus one per required field.
+    let mut builder_generics = input.generics.clone();
+    for param in &mut builder_generics.params {
+        match param {
+            GenericParam::Type(t) => t.default = None,
+            GenericParam::Const(c) => c.default = None,
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    for state in &states {
+        builder_generics.params.push(parse_quote!(#state));
     }
+    let (builder_impl_generics, _, _) = builder_generics.split_for_impl();
+
+    let struct_args: Vec<TokenStream> = input
+        .generics
+        .params
+        .iter()
+        .map(|p| match p {
+            GenericParam::Lifetime(l) => {
+                let lt = &l.lifetime;
+                quote!(#lt)
+            }
+            GenericParam::Type(t) => {
+                let ident = &t.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(c) => {
+                let ident = &c.ident;
+                quote!(#ident)
+            }
+        })
+        .collect();
+    let builder_ty =
+        |state_args: &[TokenStream]| quote!(#builder_name<#(#struct_args,)* #(#state_args),*>);
+    let generic_states: Vec<TokenStream> = states.iter().map(|s| quote!(#s)).collect();
+    let generic_builder = builder_ty(&generic_states);
+
+    let req_idents: Vec<&Ident> = required.iter().map(|f| f.ident).collect();
+    let opt_idents: Vec<&Ident> = optional.iter().map(|f| f.ident).collect();
+    let opt_tys: Vec<&Type> = optional.iter().map(|f| f.ty).collect();
+    let opt_defaults = optional.iter().map(|f| match &f.kind {
+        BuilderFieldKind::Default(Some(expr)) => quote!(#expr),
+        BuilderFieldKind::Optional(_) => quote!(::core::option::Option::None),
+        _ => quote!(::core::default::Default::default()),
+    });
+
+    let all_missing = builder_ty(&vec![missing.clone(); required.len()]);
+    let all_present = builder_ty(&required.iter().map(|f| present(f.ty)).collect::<Vec<_>>());
+
+    let required_setters = required.iter().enumerate().map(|(idx, field)| {
+        let ident = field.ident;
+        let ty = field.ty;
+        let mut next_states = generic_states.clone();
+        next_states[idx] = present(ty);
+        let next = builder_ty(&next_states);
+        let others: Vec<&Ident> = req_idents
+            .iter()
+            .copied()
+            .filter(|other| *other != ident)
+            .collect();
+        quote! {
+            #vis fn #ident(self, value: #ty) -> #next {
+                #builder_name {
+                    #ident: ::modkit::domain::builder::Present(value),
+                    #(#others: self.#others,)*
+                    #(#opt_idents: self.#opt_idents,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    });
+
+    let optional_setters = optional.iter().map(|field| {
+        let ident = field.ident;
+        match &field.kind {
+            BuilderFieldKind::Optional(inner) => quote! {
+                #vis fn #ident(mut self, value: #inner) -> Self {
+                    self.#ident = ::core::option::Option::Some(value);
+                    self
+                }
+            },
+            _ => {
+                let ty = field.ty;
+                quote! {
+                    #vis fn #ident(mut self, value: #ty) -> Self {
+                        self.#ident = value;
+                        self
+                    }
+                }
+            }
+        }
+    });
+
+    let builder_doc =
+        format!("Builder for [`{name}`]; `build()` is available once all required fields are set.");
+
+    let builder = quote! {
+        #[doc = #builder_doc]
+        #[must_use]
+        #vis struct #builder_name #builder_impl_generics #where_clause {
+            #(#req_idents: #states,)*
+            #(#opt_idents: #opt_tys,)*
+            __marker: ::core::marker::PhantomData<fn() -> #name #ty_generics>,
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Starts building this value; required fields must be set before `build()`.
+            #vis fn builder() -> #all_missing {
+                #builder_name {
+                    #(#req_idents: #missing,)*
+                    #(#opt_idents: #opt_defaults,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #builder_impl_generics #generic_builder #where_clause {
+            #(#required_setters)*
+            #(#optional_setters)*
+        }
+
+        impl #impl_generics #all_present #where_clause {
+            /// Builds the value; only available once all required fields are set.
+            #[must_use]
+            #vis fn build(self) -> #name #ty_generics {
+                #name {
+                    #(#req_idents: self.#req_idents.0,)*
+                    #(#opt_idents: self.#opt_idents,)*
+                }
+            }
+        }
+    };
+
+    let mut stripped = input.clone();
+    if let syn::Data::Struct(data) = &mut stripped.data {
+        for field in &mut data.fields {
+            field.attrs.retain(|a| !a.path().is_ident("builder"));
+        }
+    }
+
+    Ok((stripped, builder))
 }
 
 /// Context information about a field for error reporting.
@@ -321,7 +734,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("DomainModel"));
@@ -334,7 +747,7 @@ mod tests {
             pub struct Marker;
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("DomainModel"));
@@ -351,7 +764,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("DomainModel"));
@@ -366,7 +779,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("DomainModel"));
@@ -382,7 +795,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -399,7 +812,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -414,7 +827,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -429,7 +842,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -444,7 +857,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -460,7 +873,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -477,7 +890,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -492,7 +905,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -508,7 +921,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -524,7 +937,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -540,7 +953,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -557,7 +970,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -572,7 +985,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(output_str.contains("compile_error"));
@@ -593,7 +1006,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -610,7 +1023,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -627,7 +1040,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -644,7 +1057,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -660,7 +1073,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -676,7 +1089,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -694,7 +1107,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -711,7 +1124,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -727,7 +1140,7 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
@@ -743,10 +1156,126 @@ mod tests {
             }
         };
 
-        let output = expand_domain_model(&input);
+        let output = expand_domain_model(&DomainModelArgs::default(), &input);
         let output_str = output.to_string();
 
         assert!(!output_str.contains("compile_error"));
         assert!(output_str.contains("DomainModel"));
     }
+
+    // ==================== OPTIONS ====================
+
+    fn expand_with(args: TokenStream, input: &DeriveInput) -> String {
+        let args = DomainModelArgs::parse(args).unwrap();
+        expand_domain_model(&args, input).to_string()
+    }
+
+    #[test]
+    fn test_unknown_option_rejected() {
+        let err = DomainModelArgs::parse(quote!(bulder)).err().unwrap();
+        assert!(err.to_string().contains("unknown domain_model option"));
+    }
+
+    #[test]
+    fn test_builder_generates_typestate_builder() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Config<T> {
+                pub name: String,
+                pub value: T,
+                pub note: Option<String>,
+                #[builder(default = 5)]
+                pub retries: u32,
+            }
+        };
+
+        let output = expand_with(quote!(builder), &input);
+
+        assert!(!output.contains("compile_error"));
+        assert!(output.contains("pub struct ConfigBuilder"));
+        assert!(output.contains("fn builder ()"));
+        assert!(output.contains("fn build (self) -> Config < T >"));
+        // One state parameter per required field only.
+        assert!(output.contains("__Name"));
+        assert!(output.contains("__Value"));
+        assert!(!output.contains("__Note"));
+        assert!(!output.contains("__Retries"));
+        assert!(output.contains(":: modkit :: domain :: builder :: Present < String >"));
+        // Helper attributes are stripped from the emitted struct.
+        assert!(!output.contains("# [builder"));
+    }
+
+    #[test]
+    fn test_builder_rejects_enum() {
+        let input: DeriveInput = parse_quote! {
+            pub enum Mode { A, B }
+        };
+
+        let output = expand_with(quote!(builder), &input);
+
+        assert!(output.contains("compile_error"));
+        assert!(output.contains("only supported on structs with named fields"));
+    }
+
+    #[test]
+    fn test_redact_generates_debug() {
+        let input: DeriveInput = parse_quote! {
+            #[derive(Clone)]
+            pub struct Creds {
+                pub user: String,
+                pub password: String,
+            }
+        };
+
+        let output = expand_with(quote!(redact(password)), &input);
+
+        assert!(!output.contains("compile_error"));
+        assert!(output.contains(":: core :: fmt :: Debug for Creds"));
+        assert!(output.contains(". field (\"user\" , & self . user)"));
+        assert!(output.contains(". field (\"password\" , & \"<redacted>\")"));
+        assert!(!output.contains("self . password"));
+    }
+
+    #[test]
+    fn test_redact_adds_debug_bounds_for_generics() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Wrapper<T> {
+                pub inner: T,
+                pub token: String,
+            }
+        };
+
+        let output = expand_with(quote!(redact(token)), &input);
+
+        assert!(output.contains("T : :: core :: fmt :: Debug"));
+    }
+
+    #[test]
+    fn test_redact_unknown_field_suggests_name() {
+        let input: DeriveInput = parse_quote! {
+            pub struct Creds {
+                pub password: String,
+            }
+        };
+
+        let output = expand_with(quote!(redact(pasword)), &input);
+
+        assert!(output.contains("compile_error"));
+        assert!(output.contains("unknown field 'pasword'"));
+        assert!(output.contains("did you mean 'password'"));
+    }
+
+    #[test]
+    fn test_redact_conflicts_with_derived_debug() {
+        let input: DeriveInput = parse_quote! {
+            #[derive(Debug, Clone)]
+            pub struct Creds {
+                pub password: String,
+            }
+        };
+
+        let output = expand_with(quote!(redact(password)), &input);
+
+        assert!(output.contains("compile_error"));
+        assert!(output.contains("remove `Debug`"));
+    }
 }
@@ -12,13 +12,16 @@ use syn::{
 mod api_dto;
 mod domain_model;
 mod grpc_client;
+mod redactable;
 mod utils;
 
 /// Configuration parsed from #[module(...)] attribute
 struct ModuleConfig {
     name: String,
     deps: Vec<String>,
+    optional_deps: Vec<String>, // `optional("...")` entries of `deps`
     caps: Vec<Capability>,
+    optional_caps: Vec<Capability>, // `optional(...)` entries of `capabilities`
     ctor: Option<Expr>,             // arbitrary constructor expression
     client: Option<Path>,           // trait path for client DX helpers
     lifecycle: Option<LcModuleCfg>, // optional lifecycle config (on type)
@@ -36,6 +39,52 @@ enum Capability {
 }
 
 impl Capability {
+    /// Name as written in `capabilities = [...]`.
+    fn label(&self) -> &'static str {
+        match self {
+            Capability::Db => "db",
+            Capability::Rest => "rest",
+            Capability::RestHost => "rest_host",
+            Capability::Stateful => "stateful",
+            Capability::System => "system",
+            Capability::GrpcHub => "grpc_hub",
+            Capability::Grpc => "grpc",
+        }
+    }
+
+    /// Parses `db`, `"db"` or `optional(db)`; the flag is `true` for `optional(...)`.
+    fn from_expr(expr: &Expr) -> syn::Result<(Self, bool)> {
+        match expr {
+            Expr::Path(path) => {
+                if let Some(ident) = path.path.get_ident() {
+                    Ok((Capability::from_ident(ident)?, false))
+                } else {
+                    Err(syn::Error::new_spanned(
+                        path,
+                        "capability must be a simple identifier (db, rest, rest_host, stateful)",
+                    ))
+                }
+            }
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Ok((Capability::from_str_lit(s)?, false)),
+            Expr::Call(call) if call_is_optional(call) => {
+                let inner = optional_arg(call)?;
+                if matches!(inner, Expr::Call(_)) {
+                    return Err(syn::Error::new_spanned(
+                        inner,
+                        "optional(...) takes a single capability, e.g. optional(grpc)",
+                    ));
+                }
+                Ok((Capability::from_expr(inner)?.0, true))
+            }
+            other => Err(syn::Error::new_spanned(
+                other,
+                "capability must be an identifier or string literal (\"db\", \"rest\", \"rest_host\", \"stateful\"), or optional(...) of one",
+            )),
+        }
+    }
+
     const VALID_CAPABILITIES: &'static [&'static str] = &[
         "db",
         "rest",
@@ -116,6 +165,23 @@ impl Capability {
     }
 }
 
+/// Whether `call` is `optional(...)`.
+fn call_is_optional(call: &syn::ExprCall) -> bool {
+    matches!(&*call.func, Expr::Path(p) if p.path.is_ident("optional"))
+}
+
+/// The single argument of `optional(...)`.
+fn optional_arg(call: &syn::ExprCall) -> syn::Result<&Expr> {
+    let mut args = call.args.iter();
+    match (args.next(), args.next()) {
+        (Some(arg), None) => Ok(arg),
+        _ => Err(syn::Error::new_spanned(
+            call,
+            "optional(...) takes exactly one argument",
+        )),
+    }
+}
+
 /// Validates that a module name follows kebab-case naming convention.
 ///
 /// # Rules
@@ -180,9 +246,11 @@ fn validate_kebab_case(name: &str) -> Result<(), String> {
 
 #[derive(Debug, Clone)]
 struct LcModuleCfg {
-    entry: String,        // entry method name (e.g., "serve")
-    stop_timeout: String, // human duration (e.g., "30s")
-    await_ready: bool,    // require ReadySignal gating
+    entry: String,               // entry method name (e.g., "serve")
+    stop_timeout: String,        // human duration (e.g., "30s")
+    await_ready: bool,           // require ReadySignal gating
+    await_ready_of: Vec<LitStr>, // modules that must be ready before this one reports ready
+    ready_timeout: String,       // human duration; dependencies not ready by then -> degraded
 }
 
 impl Default for LcModuleCfg {
@@ -191,6 +259,8 @@ impl Default for LcModuleCfg {
             entry: "serve".to_owned(),
             stop_timeout: "30s".to_owned(),
             await_ready: false,
+            await_ready_of: Vec::new(),
+            ready_timeout: "30s".to_owned(),
         }
     }
 }
@@ -199,7 +269,9 @@ impl Parse for ModuleConfig {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut name: Option<String> = None;
         let mut deps: Vec<String> = Vec::new();
+        let mut optional_deps: Vec<String> = Vec::new();
         let mut caps: Vec<Capability> = Vec::new();
+        let mut optional_caps: Vec<Capability> = Vec::new();
         let mut ctor: Option<Expr> = None;
         let mut client: Option<Path> = None;
         let mut lifecycle: Option<LcModuleCfg> = None;
@@ -302,18 +374,41 @@ impl Parse for ModuleConfig {
                     match value {
                         Expr::Array(arr) => {
                             for elem in arr.elems {
-                                match elem {
+                                let (dep, optional) = match &elem {
                                     Expr::Lit(syn::ExprLit {
                                         lit: Lit::Str(s), ..
-                                    }) => {
-                                        deps.push(s.value());
+                                    }) => (s.clone(), false),
+                                    Expr::Call(call) if call_is_optional(call) => {
+                                        match optional_arg(call)? {
+                                            Expr::Lit(syn::ExprLit {
+                                                lit: Lit::Str(s), ..
+                                            }) => (s.clone(), true),
+                                            other => {
+                                                return Err(syn::Error::new_spanned(
+                                                    other,
+                                                    "optional(...) takes a module name string literal, e.g. optional(\"auth\")",
+                                                ));
+                                            }
+                                        }
                                     }
                                     other => {
                                         return Err(syn::Error::new_spanned(
                                             other,
-                                            "deps must be an array of string literals, e.g. deps = [\"db\", \"auth\"]",
+                                            "deps must be an array of string literals or optional(...) of one, e.g. deps = [\"db\", optional(\"auth\")]",
                                         ));
                                     }
+                                };
+                                let value = dep.value();
+                                if deps.contains(&value) || optional_deps.contains(&value) {
+                                    return Err(syn::Error::new_spanned(
+                                        dep,
+                                        format!("dependency '{value}' is listed more than once"),
+                                    ));
+                                }
+                                if optional {
+                                    optional_deps.push(value);
+                                } else {
+                                    deps.push(value);
                                 }
                             }
                         }
@@ -337,28 +432,20 @@ impl Parse for ModuleConfig {
                     match value {
                         Expr::Array(arr) => {
                             for elem in arr.elems {
-                                match elem {
-                                    Expr::Path(ref path) => {
-                                        if let Some(ident) = path.path.get_ident() {
-                                            caps.push(Capability::from_ident(ident)?);
-                                        } else {
-                                            return Err(syn::Error::new_spanned(
-                                                path,
-                                                "capability must be a simple identifier (db, rest, rest_host, stateful)",
-                                            ));
-                                        }
-                                    }
-                                    Expr::Lit(syn::ExprLit {
-                                        lit: Lit::Str(s), ..
-                                    }) => {
-                                        caps.push(Capability::from_str_lit(&s)?);
-                                    }
-                                    other => {
-                                        return Err(syn::Error::new_spanned(
-                                            other,
-                                            "capability must be an identifier or string literal (\"db\", \"rest\", \"rest_host\", \"stateful\")",
-                                        ));
-                                    }
+                                let (cap, optional) = Capability::from_expr(&elem)?;
+                                if caps.contains(&cap) || optional_caps.contains(&cap) {
+                                    return Err(syn::Error::new_spanned(
+                                        elem,
+                                        format!(
+                                            "capability '{}' is listed more than once",
+                                            cap.label()
+                                        ),
+                                    ));
+                                }
+                                if optional {
+                                    optional_caps.push(cap);
+                                } else {
+                                    caps.push(cap);
                                 }
                             }
                         }
@@ -397,10 +484,27 @@ impl Parse for ModuleConfig {
             )
         })?;
 
+        if let Some(lc) = &lifecycle
+            && let Some(undeclared) = lc
+                .await_ready_of
+                .iter()
+                .find(|m| !deps.contains(&m.value()) && !optional_deps.contains(&m.value()))
+        {
+            return Err(syn::Error::new_spanned(
+                undeclared,
+                format!(
+                    "module '{}' in await_ready_of must also be listed in deps",
+                    undeclared.value()
+                ),
+            ));
+        }
+
         Ok(ModuleConfig {
             name,
             deps,
+            optional_deps,
             caps,
+            optional_caps,
             ctor,
             client,
             lifecycle,
@@ -442,6 +546,26 @@ fn parse_lifecycle_list(list: &MetaList) -> syn::Result<LcModuleCfg> {
                     ));
                 }
             }
+            Meta::NameValue(MetaNameValue { path, value, .. })
+                if path.is_ident("await_ready_of") =>
+            {
+                cfg.await_ready_of = parse_string_array(&value, "await_ready_of")?;
+            }
+            Meta::NameValue(MetaNameValue { path, value, .. })
+                if path.is_ident("ready_timeout") =>
+            {
+                if let Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(s), ..
+                }) = value
+                {
+                    cfg.ready_timeout = s.value();
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        value,
+                        "ready_timeout must be a string literal like \"10s\"",
+                    ));
+                }
+            }
             Meta::Path(p) if p.is_ident("await_ready") => {
                 cfg.await_ready = true;
             }
@@ -462,7 +586,7 @@ fn parse_lifecycle_list(list: &MetaList) -> syn::Result<LcModuleCfg> {
             other => {
                 return Err(syn::Error::new_spanned(
                     other,
-                    "expected lifecycle args: entry=\"...\", stop_timeout=\"...\", await_ready[=true|false]",
+                    "expected lifecycle args: entry=\"...\", stop_timeout=\"...\", await_ready[=true|false], await_ready_of=[...], ready_timeout=\"...\"",
                 ));
             }
         }
@@ -471,6 +595,41 @@ fn parse_lifecycle_list(list: &MetaList) -> syn::Result<LcModuleCfg> {
     Ok(cfg)
 }
 
+/// Parses `["a", "b"]` into its string literals.
+fn parse_string_array(value: &Expr, param: &str) -> syn::Result<Vec<LitStr>> {
+    let Expr::Array(arr) = value else {
+        return Err(syn::Error::new_spanned(
+            value,
+            format!("{param} must be an array of string literals, e.g. [\"authn-resolver\"]"),
+        ));
+    };
+    arr.elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(syn::ExprLit {
+                lit: Lit::Str(s), ..
+            }) => Ok(s.clone()),
+            other => Err(syn::Error::new_spanned(
+                other,
+                format!("{param} entries must be string literals"),
+            )),
+        })
+        .collect()
+}
+
+impl LcModuleCfg {
+    /// `.with_ready_dependencies(...)` for the generated `WithLifecycle`, if any.
+    fn ready_dependencies_tokens(&self) -> proc_macro2::TokenStream {
+        if self.await_ready_of.is_empty() {
+            return proc_macro2::TokenStream::new();
+        }
+        let modules = &self.await_ready_of;
+        let timeout_ts =
+            parse_duration_tokens(&self.ready_timeout).unwrap_or_else(|e| e.to_compile_error());
+        quote! { .with_ready_dependencies(&[#(#modules),*], #timeout_ts) }
+    }
+}
+
 /// Main #[module] attribute macro
 ///
 /// `ctor` must be a Rust expression that evaluates to the module instance,
@@ -488,8 +647,15 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     let name_owned: String = config.name.clone();
     let deps_owned: Vec<String> = config.deps.clone();
-    let caps_for_asserts: Vec<Capability> = config.caps.clone();
+    let optional_deps_owned: Vec<String> = config.optional_deps.clone();
+    let caps_for_asserts: Vec<Capability> = config
+        .caps
+        .iter()
+        .chain(&config.optional_caps)
+        .cloned()
+        .collect();
     let caps_for_regs: Vec<Capability> = config.caps.clone();
+    let optional_caps_for_regs: Vec<Capability> = config.optional_caps.clone();
     let ctor_expr_opt: Option<Expr> = config.ctor.clone();
     let client_trait_opt: Option<Path> = config.client.clone();
     let lifecycle_cfg_opt: Option<LcModuleCfg> = config.lifecycle;
@@ -500,6 +666,16 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
         .iter()
         .map(|s| LitStr::new(s, Span::call_site()))
         .collect();
+    let optional_deps_registration = if optional_deps_owned.is_empty() {
+        proc_macro2::TokenStream::new()
+    } else {
+        let optional_deps_lits = optional_deps_owned
+            .iter()
+            .map(|s| LitStr::new(s, Span::call_site()));
+        quote! {
+            b.register_optional_deps_with_meta(#name_lit, &[#(#optional_deps_lits),*]);
+        }
+    };
 
     // Constructor expression (provided or Default::default())
     let constructor = if let Some(expr) = &ctor_expr_opt {
@@ -607,6 +783,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
         let timeout_ts =
             parse_duration_tokens(&lc.stop_timeout).unwrap_or_else(|e| e.to_compile_error());
         let await_ready_bool = lc.await_ready;
+        let ready_deps = lc.ready_dependencies_tokens();
 
         if await_ready_bool {
             let ready_shim_ident =
@@ -642,6 +819,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                         ::modkit::lifecycle::WithLifecycle::new_with_name(self, #name_lit)
                             .with_stop_timeout(#timeout_ts)
                             .with_ready_mode(true, true, Some(#ready_shim_ident))
+                            #ready_deps
                     }
                 }
             });
@@ -661,6 +839,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                         ::modkit::lifecycle::WithLifecycle::new_with_name(self, #name_lit)
                             .with_stop_timeout(#timeout_ts)
                             .with_ready_mode(false, false, None)
+                            #ready_deps
                     }
                 }
             });
@@ -668,7 +847,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
     }
 
     // Capability registrations (builder API), with special handling for stateful + lifecycle
-    let capability_registrations = caps_for_regs.iter().map(|cap| {
+    let capability_registration = |cap: &Capability| {
         match cap {
             Capability::Db => quote! {
                 b.register_db_with_meta(#name_lit,
@@ -687,6 +866,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                     let timeout_ts = parse_duration_tokens(&lc.stop_timeout)
                         .unwrap_or_else(|e| e.to_compile_error());
                     let await_ready_bool = lc.await_ready;
+                    let ready_deps = lc.ready_dependencies_tokens();
                     let ready_shim_ident =
                         format_ident!("__modkit_run_ready_shim_for_{}", struct_name_snake);
 
@@ -697,7 +877,8 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                                     #name_lit,
                                 )
                                 .with_stop_timeout(#timeout_ts)
-                                .with_ready_mode(true, true, Some(#ready_shim_ident));
+                                .with_ready_mode(true, true, Some(#ready_shim_ident))
+                                #ready_deps;
 
                             b.register_stateful_with_meta(
                                 #name_lit,
@@ -711,7 +892,8 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                                     #name_lit,
                                 )
                                 .with_stop_timeout(#timeout_ts)
-                                .with_ready_mode(false, false, None);
+                                .with_ready_mode(false, false, None)
+                                #ready_deps;
 
                             b.register_stateful_with_meta(
                                 #name_lit,
@@ -726,7 +908,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                             module.clone() as ::std::sync::Arc<dyn ::modkit::contracts::RunnableCapability>);
                     }
                 }
-            },
+            }
             Capability::System => quote! {
                 b.register_system_with_meta(#name_lit,
                     module.clone() as ::std::sync::Arc<dyn ::modkit::contracts::SystemCapability>);
@@ -740,7 +922,45 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                     module.clone() as ::std::sync::Arc<dyn ::modkit::contracts::GrpcServiceCapability>);
             },
         }
-    });
+    };
+    let capability_registrations: Vec<_> =
+        caps_for_regs.iter().map(&capability_registration).collect();
+
+    // Optional capabilities: registered only once the module has opted in
+    let mut optional_capability_registrations = Vec::new();
+    for cap in &optional_caps_for_regs {
+        let label = cap.label();
+        let flag_ident = format_ident!(
+            "__MODKIT_{}_OPT_IN_{}",
+            struct_name_snake.to_uppercase(),
+            label.to_uppercase()
+        );
+        let enable_ident = format_ident!("enable_{}_capability", label);
+        let registration = capability_registration(cap);
+        let doc = format!(
+            "Opt in to the optional `{label}` capability of `{name_owned}`.\n\n\
+             Takes effect when the module registry is built, so call this before \
+             that, e.g. from `main` or from the module's `ctor`."
+        );
+
+        extra_top_level.extend(quote! {
+            #[doc(hidden)]
+            static #flag_ident: ::core::sync::atomic::AtomicBool =
+                ::core::sync::atomic::AtomicBool::new(false);
+
+            impl #impl_generics #struct_ident #ty_generics #where_clause {
+                #[doc = #doc]
+                pub fn #enable_ident() {
+                    #flag_ident.store(true, ::core::sync::atomic::Ordering::Release);
+                }
+            }
+        });
+        optional_capability_registrations.push(quote! {
+            if #flag_ident.load(::core::sync::atomic::Ordering::Acquire) {
+                #registration
+            }
+        });
+    }
 
     // ClientHub DX helpers (optional)
     // Note: The `client` parameter now only triggers compile-time trait checks.
@@ -787,9 +1007,11 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                 &[#(#deps_lits),*],
                 module.clone() as Arc<dyn ::modkit::contracts::Module>
             );
+            #optional_deps_registration
 
             // capabilities
             #(#capability_registrations)*
+            #(#optional_capability_registrations)*
         }
 
         ::modkit::inventory::submit! {
@@ -1232,8 +1454,75 @@ pub fn api_dto(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// - External clients: `reqwest::*`, `tonic::*`
 /// - File system: `std::fs::*`, `tokio::fs::*`
 /// - Database-specific names: `PgPool`, `MySqlPool`, `SqlitePool`, `DatabaseConnection`
+///
+/// # Options
+///
+/// - `builder` — generates `<Name>Builder` and `Name::builder()`. Fields of type
+///   `Option<T>` and fields marked `#[builder(default)]` / `#[builder(default = expr)]`
+///   are optional; every other field is required, and `build()` only exists once all
+///   required fields are set (type-state, like `OperationBuilder`).
+/// - `redact(field, ...)` — generates a `Debug` impl that prints `"<redacted>"` for the
+///   listed fields. Do not also `#[derive(Debug)]`.
+///
+/// ```ignore
+/// #[domain_model(builder, redact(password))]
+/// #[derive(Clone)]
+/// pub struct DbSettings {
+///     pub url: String,
+///     pub password: String,
+///     #[builder(default = 10)]
+///     pub max_connections: u32,
+/// }
+///
+/// let settings = DbSettings::builder()
+///     .url("postgres://db".to_owned())
+///     .password("secret".to_owned())
+///     .build();
+/// // DbSettings { url: "postgres://db", password: "<redacted>", max_connections: 10 }
+/// println!("{settings:?}");
+/// ```
 #[proc_macro_attribute]
-pub fn domain_model(_attr: TokenStream, item: TokenStream) -> TokenStream {
+pub fn domain_model(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = match domain_model::DomainModelArgs::parse(attr.into()) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error().into(),
+    };
     let input = parse_macro_input!(item as DeriveInput);
-    TokenStream::from(domain_model::expand_domain_model(&input))
+    TokenStream::from(domain_model::expand_domain_model(&args, &input))
+}
+
+/// Derives `modkit_security::Redactable`, generating `<Name>Redacted<'a>`: a
+/// serializable view of the struct for logging.
+///
+/// Fields listed in `#[redact(...)]` are replaced in the view; the others are
+/// borrowed as is and must implement `Serialize` and `Debug`.
+///
+/// - `field` or `field = "mask"` — a shape hint such as `<redacted len=12>` or
+///   `<redacted uuid>` (`modkit_security::RedactField::mask`)
+/// - `field = "hash"` — a short stable hash such as `<hash 1a2b3c4d>`, so the
+///   same value can be correlated across log lines
+/// - `field = "nested"` — the field's own redacted view (the field type, or the
+///   `T` of an `Option<T>` / `Vec<T>`, must be `Redactable`)
+///
+/// Only non-generic structs with named fields are supported. Use it through the
+/// `modkit_security` re-export, which the generated code refers to.
+///
+/// ```ignore
+/// use modkit_security::{Redactable, Redacted};
+///
+/// #[derive(Debug, Redactable)]
+/// #[redact(email = "hash", display_name, address = "nested")]
+/// pub struct Profile {
+///     pub id: Uuid,
+///     pub email: String,
+///     pub display_name: String,
+///     pub address: Option<Address>,
+/// }
+///
+/// tracing::debug!(profile = ?Redacted(&profile), "loaded profile");
+/// ```
+#[proc_macro_derive(Redactable, attributes(redact))]
+pub fn derive_redactable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    TokenStream::from(redactable::expand_redactable(&input))
 }
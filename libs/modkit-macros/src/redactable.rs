@@ -0,0 +1,284 @@
+//! Proc-macro implementation for `#[derive(Redactable)]`.
+//!
+//! Generates `<Name>Redacted<'a>`, a serializable view of the struct in which the
+//! fields listed in `#[redact(...)]` are masked, hashed or replaced by their own
+//! redacted view, and implements `modkit_security::Redactable` for the struct.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{DeriveInput, Fields, Ident, LitStr};
+
+use crate::utils::did_you_mean;
+
+/// How a field listed in `#[redact(...)]` appears in the view.
+enum RedactMode {
+    /// `field` / `field = "mask"`: `RedactField::mask`.
+    Mask,
+    /// `field = "hash"`: `RedactField::hash`.
+    Hash,
+    /// `field = "nested"`: the field's own `Redactable` view.
+    Nested,
+}
+
+/// Parses every `#[redact(field, field = "mode", ...)]` attribute on the struct.
+fn parse_redact_attrs(input: &DeriveInput) -> syn::Result<Vec<(Ident, RedactMode)>> {
+    let mut fields: Vec<(Ident, RedactMode)> = Vec::new();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("redact")) {
+        attr.parse_nested_meta(|meta| {
+            let ident = meta.path.require_ident()?.clone();
+            let mode = if meta.input.peek(syn::Token![=]) {
+                let lit: LitStr = meta.value()?.parse()?;
+                match lit.value().as_str() {
+                    "mask" => RedactMode::Mask,
+                    "hash" => RedactMode::Hash,
+                    "nested" => RedactMode::Nested,
+                    other => {
+                        return Err(syn::Error::new_spanned(
+                            &lit,
+                            format!(
+                                "unknown redact mode '{other}', expected \"mask\", \"hash\" or \"nested\""
+                            ),
+                        ));
+                    }
+                }
+            } else {
+                RedactMode::Mask
+            };
+            if fields.iter().any(|(seen, _)| *seen == ident) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!("field '{}' is listed twice in redact(...)", ident.unraw()),
+                ));
+            }
+            fields.push((ident, mode));
+            Ok(())
+        })?;
+    }
+    Ok(fields)
+}
+
+/// Expands `#[derive(Redactable)]`.
+pub fn expand_redactable(input: &DeriveInput) -> TokenStream {
+    expand(input).unwrap_or_else(syn::Error::into_compile_error)
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let syn::Data::Struct(syn::DataStruct {
+        fields: Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(syn::Error::new_spanned(
+            name,
+            "Redactable can only be derived for structs with named fields",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Redactable cannot be derived for generic types",
+        ));
+    }
+
+    let redact = parse_redact_attrs(input)?;
+    let names: Vec<String> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(|i| i.unraw().to_string()))
+        .collect();
+    for (ident, _) in &redact {
+        let requested = ident.unraw().to_string();
+        if !names.contains(&requested) {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "unknown field '{requested}' in redact(...){}",
+                    did_you_mean(&requested, &names)
+                ),
+            ));
+        }
+    }
+
+    let krate = quote!(::modkit_security::redact);
+    let vis = &input.vis;
+    let view = format_ident!("{}Redacted", name);
+    let doc = format!("Redacted view of [`{name}`] for logging.");
+    let serde_crate = "::modkit_security::redact::__private::serde";
+
+    let mut view_fields = Vec::new();
+    let mut view_values = Vec::new();
+    let mut debug_entries = Vec::new();
+    let mut borrows = false;
+    for field in &fields.named {
+        // Named fields always have an ident by syn's definition
+        #[allow(clippy::unwrap_used)]
+        let ident = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+        let mode = redact.iter().find(|(r, _)| r == ident).map(|(_, m)| m);
+        borrows |= matches!(mode, None | Some(RedactMode::Nested));
+        let (field_ty, value) = match mode {
+            None => (quote!(&'__r #ty), quote!(&self.#ident)),
+            Some(RedactMode::Mask) => (
+                quote!(<#ty as #krate::RedactField>::Output),
+                quote!(#krate::RedactField::mask(&self.#ident)),
+            ),
+            Some(RedactMode::Hash) => (
+                quote!(<#ty as #krate::RedactField>::Output),
+                quote!(#krate::RedactField::hash(&self.#ident)),
+            ),
+            Some(RedactMode::Nested) => (
+                quote!(<#ty as #krate::Redactable>::RedactedView<'__r>),
+                quote!(#krate::Redactable::redacted(&self.#ident)),
+            ),
+        };
+        let field_vis = &field.vis;
+        let label = ident.unraw().to_string();
+        view_fields.push(quote!(#field_vis #ident: #field_ty));
+        view_values.push(quote!(#ident: #value));
+        debug_entries.push(quote!(.field(#label, &self.#ident)));
+    }
+
+    // Keeps `'__r` used when every field is masked or hashed.
+    let (phantom_field, phantom_value) = if borrows {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        (
+            quote!(#[serde(skip)] __lifetime: ::core::marker::PhantomData<&'__r ()>,),
+            quote!(__lifetime: ::core::marker::PhantomData,),
+        )
+    };
+    let view_str = view.to_string();
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(#krate::__private::serde::Serialize)]
+        #[serde(crate = #serde_crate)]
+        #vis struct #view<'__r> {
+            #(#view_fields,)*
+            #phantom_field
+        }
+
+        impl ::core::fmt::Debug for #view<'_> {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(#view_str)
+                    #(#debug_entries)*
+                    .finish()
+            }
+        }
+
+        impl #krate::Redactable for #name {
+            type RedactedView<'__r>
+                = #view<'__r>
+            where
+                Self: '__r;
+
+            fn redacted(&self) -> Self::RedactedView<'_> {
+                #view {
+                    #(#view_values,)*
+                    #phantom_value
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_modes_select_view_field_types() {
+        let input: DeriveInput = parse_quote! {
+            #[redact(email = "hash", street, address = "nested")]
+            pub struct User {
+                pub id: Uuid,
+                pub email: String,
+                pub street: Option<String>,
+                pub address: Option<Address>,
+            }
+        };
+
+        let output = expand_redactable(&input).to_string();
+
+        assert!(!output.contains("compile_error"));
+        assert!(output.contains("pub struct UserRedacted < '__r >"));
+        assert!(output.contains("pub id : & '__r Uuid"));
+        assert!(output.contains("RedactField :: hash (& self . email)"));
+        assert!(output.contains("RedactField :: mask (& self . street)"));
+        assert!(output.contains("Redactable :: redacted (& self . address)"));
+        assert!(!output.contains("__lifetime"));
+    }
+
+    #[test]
+    fn test_fully_masked_struct_keeps_lifetime() {
+        let input: DeriveInput = parse_quote! {
+            #[redact(token)]
+            struct Secret {
+                token: String,
+            }
+        };
+
+        let output = expand_redactable(&input).to_string();
+
+        assert!(!output.contains("compile_error"));
+        assert!(output.contains("__lifetime"));
+    }
+
+    #[test]
+    fn test_unknown_field_suggests_name() {
+        let input: DeriveInput = parse_quote! {
+            #[redact(emial)]
+            pub struct User {
+                pub email: String,
+            }
+        };
+
+        let output = expand_redactable(&input).to_string();
+
+        assert!(output.contains("compile_error"));
+        assert!(output.contains("unknown field 'emial'"));
+        assert!(output.contains("did you mean 'email'"));
+    }
+
+    #[test]
+    fn test_unknown_mode_is_rejected() {
+        let input: DeriveInput = parse_quote! {
+            #[redact(email = "sha256")]
+            pub struct User {
+                pub email: String,
+            }
+        };
+
+        let output = expand_redactable(&input).to_string();
+
+        assert!(output.contains("unknown redact mode 'sha256'"));
+    }
+
+    #[test]
+    fn test_enums_and_generics_are_rejected() {
+        let enum_input: DeriveInput = parse_quote! {
+            pub enum Contact {
+                Email(String),
+            }
+        };
+        let generic_input: DeriveInput = parse_quote! {
+            pub struct Wrapper<T> {
+                pub inner: T,
+            }
+        };
+
+        assert!(
+            expand_redactable(&enum_input)
+                .to_string()
+                .contains("structs with named fields")
+        );
+        assert!(
+            expand_redactable(&generic_input)
+                .to_string()
+                .contains("generic types")
+        );
+    }
+}
@@ -102,3 +102,15 @@ pub fn extract_impl_methods(impl_block: &syn::ItemImpl) -> Vec<syn::Ident> {
 pub fn parse_type_from_string(s: &str) -> syn::Result<Type> {
     syn::parse_str(s)
 }
+
+/// Returns a `help: did you mean '...'?` line for the candidate closest to
+/// `requested`, or an empty string if none is similar enough.
+pub fn did_you_mean(requested: &str, candidates: &[String]) -> String {
+    candidates
+        .iter()
+        .map(|c| (c, strsim::jaro_winkler(requested, c)))
+        .filter(|(_, score)| *score > 0.8)
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(best, _)| format!("\n       = help: did you mean '{best}'?"))
+        .unwrap_or_default()
+}
@@ -34,6 +34,9 @@ mod odata_schema;
 ///     pub id: uuid::Uuid,
 ///     #[odata(filter(kind = "String"))]
 ///     pub email: String,
+///     // JSON column: only the listed paths are filterable, e.g. `metadata/department eq 'sales'`
+///     #[odata(filter(kind = "Json", paths = ["department", "labels/team"]))]
+///     pub metadata: serde_json::Value,
 /// }
 /// ```
 #[proc_macro_derive(ODataFilterable, attributes(odata))]
@@ -12,17 +12,30 @@ struct FilterableField {
     field_name: String,
     /// The `FieldKind` variant name (e.g., "String", "Uuid", "`DateTimeUtc`")
     kind: String,
+    /// Filterable paths of a `Json` field (e.g., "department", "labels/team")
+    paths: Vec<String>,
     /// Span for error reporting
     span: Span,
 }
 
-/// Parse #[odata(filter(kind = "..."))] attributes on struct fields
-fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
+/// Parsed `#[odata(...)]` attributes of one struct field
+struct FieldAttrs {
+    /// Present when the field is filterable
+    filter: Option<FilterableField>,
+    /// Whether the field may appear in `$orderby`
+    sortable: bool,
+}
+
+/// Parse #[odata(filter(kind = "...", paths = ["..."]))] and #[odata(not_sortable)]
+/// attributes on struct fields
+fn parse_field_attrs(field: &syn::Field) -> Option<FieldAttrs> {
     let field_ident = field.ident.as_ref()?.clone();
     let field_name = field_ident.to_string();
     let span = field.span();
 
     let mut found_kind: Option<String> = None;
+    let mut paths: Vec<String> = Vec::new();
+    let mut sortable = true;
 
     for attr in &field.attrs {
         // Look for #[odata(...)]
@@ -32,6 +45,10 @@ fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
 
         // Parse using syn v2 API
         let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("not_sortable") {
+                sortable = false;
+                return Ok(());
+            }
             // Check for filter(...) nested group
             if meta.path.is_ident("filter") {
                 // Parse the contents of filter(...)
@@ -49,6 +66,22 @@ fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
                             );
                         }
                     }
+                    // Check for paths = ["...", ...] (Json fields)
+                    if filter_meta.path.is_ident("paths") {
+                        let value = filter_meta.value()?;
+                        let array: syn::ExprArray = value.parse()?;
+                        for elem in &array.elems {
+                            if let syn::Expr::Lit(syn::ExprLit {
+                                lit: Lit::Str(lit_str),
+                                ..
+                            }) = elem
+                            {
+                                paths.push(lit_str.value());
+                            } else {
+                                emit_error!(elem.span(), "paths must be string literals");
+                            }
+                        }
+                    }
                     Ok(())
                 })?;
             }
@@ -60,11 +93,28 @@ fn parse_field_attrs(field: &syn::Field) -> Option<FilterableField> {
         }
     }
 
-    found_kind.map(|kind| FilterableField {
-        field_ident,
-        field_name,
-        kind,
-        span,
+    let Some(kind) = found_kind else {
+        return Some(FieldAttrs {
+            filter: None,
+            sortable,
+        });
+    };
+    if kind == "Json" && paths.is_empty() {
+        emit_error!(span, "Json filter fields need paths = [\"...\"]");
+    } else if kind != "Json" && !paths.is_empty() {
+        emit_error!(span, "paths are only supported on Json filter fields");
+    }
+
+    Some(FieldAttrs {
+        // A whole JSON document has no meaningful sort order.
+        sortable: sortable && kind != "Json",
+        filter: Some(FilterableField {
+            field_ident,
+            field_name,
+            kind,
+            paths,
+            span,
+        }),
     })
 }
 
@@ -89,10 +139,19 @@ pub fn expand_derive_odata_filterable(input: DeriveInput) -> TokenStream {
         }
     };
 
-    // Extract filterable fields
+    // Extract filterable and sortable fields
     let mut filterable_fields = Vec::new();
+    let mut sortable_fields = Vec::new();
     for field in fields {
-        if let Some(filterable) = parse_field_attrs(field) {
+        let Some(attrs) = parse_field_attrs(field) else {
+            continue;
+        };
+        if attrs.sortable
+            && let Some(ident) = &field.ident
+        {
+            sortable_fields.push(ident.clone());
+        }
+        if let Some(filterable) = attrs.filter {
             filterable_fields.push(filterable);
         }
     }
@@ -138,11 +197,22 @@ pub fn expand_derive_odata_filterable(input: DeriveInput) -> TokenStream {
         .map(|(f, variant)| {
             let kind_str = &f.kind;
             let kind_ident = Ident::new(kind_str, f.span);
-            quote! {
-                #filter_enum_name::#variant => ::modkit_odata::filter::FieldKind::#kind_ident
+            if f.paths.is_empty() {
+                quote! {
+                    #filter_enum_name::#variant => ::modkit_odata::filter::FieldKind::#kind_ident
+                }
+            } else {
+                let paths = &f.paths;
+                quote! {
+                    #filter_enum_name::#variant => ::modkit_odata::filter::FieldKind::#kind_ident {
+                        allowed_paths: &[#(#paths),*],
+                    }
+                }
             }
         });
 
+    let sort_impl = expand_sort_field(dto_name, &sortable_fields);
+
     // Generate the full implementation
     quote! {
         #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
@@ -168,5 +238,60 @@ pub fn expand_derive_odata_filterable(input: DeriveInput) -> TokenStream {
                 }
             }
         }
+
+        #sort_impl
+    }
+}
+
+/// Generate the `{Dto}SortField` enum for fields not marked `#[odata(not_sortable)]`.
+fn expand_sort_field(dto_name: &Ident, sortable_fields: &[Ident]) -> TokenStream {
+    if sortable_fields.is_empty() {
+        return quote! {};
+    }
+
+    let sort_enum_name = Ident::new(&format!("{dto_name}SortField"), dto_name.span());
+    let variants: Vec<_> = sortable_fields
+        .iter()
+        .map(|ident| Ident::new(&ident.to_string().to_upper_camel_case(), ident.span()))
+        .collect();
+    let name_match_arms = sortable_fields
+        .iter()
+        .zip(&variants)
+        .map(|(ident, variant)| {
+            let name = ident.to_string();
+            quote! {
+                #sort_enum_name::#variant => #name
+            }
+        });
+    let fields_array = variants.iter().map(|variant| {
+        quote! { &#sort_enum_name::#variant }
+    });
+
+    quote! {
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+        #[allow(non_camel_case_types)]
+        pub enum #sort_enum_name {
+            #(#variants),*
+        }
+
+        impl ::modkit_odata::sort::SortableFieldSpec for #sort_enum_name {
+            fn name(&self) -> &'static str {
+                match self {
+                    #(#name_match_arms),*
+                }
+            }
+        }
+
+        impl ::modkit_odata::sort::SortableField for #sort_enum_name {
+            const FIELDS: &'static [&'static dyn ::modkit_odata::sort::SortableFieldSpec] = &[
+                #(#fields_array),*
+            ];
+        }
+
+        impl ::modkit_odata::sort::IntoSortableFields for #sort_enum_name {
+            fn sortable_field_names() -> ::std::vec::Vec<&'static str> {
+                ::modkit_odata::sort::sortable_field_names::<Self>()
+            }
+        }
     }
 }
@@ -0,0 +1,24 @@
+use modkit_odata::IntoSortableFields;
+use modkit_odata::filter::FilterField;
+use modkit_odata_macros::ODataFilterable;
+
+#[derive(ODataFilterable)]
+struct UserQuery {
+    #[odata(filter(kind = "Uuid"))]
+    id: uuid::Uuid,
+    #[odata(filter(kind = "String"), not_sortable)]
+    email: String,
+    created_at: String,
+}
+
+fn main() {
+    assert_eq!(UserQueryFilterField::FIELDS.len(), 2);
+    assert_eq!(
+        UserQuerySortField::sortable_field_names(),
+        ["id", "created_at"]
+    );
+    assert_eq!(
+        UserQueryFilterField::sortable_field_names(),
+        ["id", "email"]
+    );
+}
@@ -49,9 +49,9 @@
 //!     .build();
 //! ```
 
-use crate::schema::{AsFieldKey, AsFieldName, FieldRef, Schema};
+use crate::schema::{AsFieldKey, FieldRef, Schema};
 use crate::{
-    ODataOrderBy, ODataQuery, OrderKey, SortDir, ast::Expr, pagination::short_filter_hash,
+    CursorV1, ODataOrderBy, ODataQuery, OrderKey, SortDir, ast::Expr, pagination::short_filter_hash,
 };
 use std::marker::PhantomData;
 
@@ -75,6 +75,7 @@ pub struct QueryBuilder<S: Schema> {
     order: Vec<OrderKey>,
     select: Option<Vec<S::Field>>,
     limit: Option<u64>,
+    cursor: Option<CursorV1>,
     _phantom: PhantomData<S>,
 }
 
@@ -87,6 +88,7 @@ impl<S: Schema> QueryBuilder<S> {
             order: Vec::new(),
             select: None,
             limit: None,
+            cursor: None,
             _phantom: PhantomData,
         }
     }
@@ -106,7 +108,9 @@ impl<S: Schema> QueryBuilder<S> {
 
     /// Add an order-by clause.
     ///
-    /// Can be called multiple times to add multiple sort keys.
+    /// Can be called multiple times to add multiple sort keys. Only fields of
+    /// this builder's schema are accepted, so ordering by a foreign field is a
+    /// compile error.
     ///
     /// # Example
     ///
@@ -118,10 +122,10 @@ impl<S: Schema> QueryBuilder<S> {
     #[must_use]
     pub fn order_by<F>(mut self, field: F, dir: SortDir) -> Self
     where
-        F: AsFieldName,
+        F: AsFieldKey<S>,
     {
         self.order.push(OrderKey {
-            field: field.as_field_name().to_owned(),
+            field: S::field_name(field.as_field_key()).to_owned(),
             dir,
         });
         self
@@ -167,6 +171,23 @@ impl<S: Schema> QueryBuilder<S> {
         self
     }
 
+    /// Resume from a cursor returned in a previous page's `PageInfo::next_cursor`.
+    ///
+    /// The cursor carries its own sort order, so the filter must stay the same
+    /// as on the page that produced it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let cursor = CursorV1::decode(&page.page_info.next_cursor.unwrap())?;
+    /// builder.cursor(cursor)
+    /// ```
+    #[must_use]
+    pub fn cursor(mut self, cursor: CursorV1) -> Self {
+        self.cursor = Some(cursor);
+        self
+    }
+
     /// Build the final `ODataQuery` with computed filter hash.
     ///
     /// The filter hash is computed using the stable hashing algorithm from
@@ -188,6 +209,10 @@ impl<S: Schema> QueryBuilder<S> {
             query = query.with_limit(limit);
         }
 
+        if let Some(cursor) = self.cursor {
+            query = query.with_cursor(cursor);
+        }
+
         if let Some(hash) = filter_hash {
             query = query.with_filter_hash(hash);
         }
@@ -458,6 +483,25 @@ mod tests {
         assert_eq!(query.limit, Some(50));
     }
 
+    #[test]
+    fn test_cursor() {
+        let cursor = CursorV1 {
+            k: vec!["alice".to_owned()],
+            o: SortDir::Asc,
+            s: "+name".to_owned(),
+            f: None,
+            d: "fwd".to_owned(),
+        };
+        let query = QueryBuilder::<UserSchema>::new()
+            .order_by(NAME, SortDir::Asc)
+            .cursor(cursor)
+            .build();
+
+        let cursor = query.cursor.expect("cursor must be set");
+        assert_eq!(cursor.k, vec!["alice".to_owned()]);
+        assert_eq!(cursor.s, "+name");
+    }
+
     #[test]
     fn test_full_query_build() {
         let user_id = uuid::Uuid::new_v4();
@@ -17,6 +17,11 @@ pub enum FieldKind {
     Date,
     Time,
     Decimal,
+    /// JSON column; only the listed paths (`department`, `labels/team`) can be
+    /// filtered, as `field/path eq value`.
+    Json {
+        allowed_paths: &'static [&'static str],
+    },
 }
 
 impl fmt::Display for FieldKind {
@@ -31,6 +36,7 @@ impl fmt::Display for FieldKind {
             FieldKind::Date => write!(f, "Date"),
             FieldKind::Time => write!(f, "Time"),
             FieldKind::Decimal => write!(f, "Decimal"),
+            FieldKind::Json { .. } => write!(f, "Json"),
         }
     }
 }
@@ -83,10 +89,51 @@ impl fmt::Display for FilterOp {
     }
 }
 
+/// Case-folding function wrapping a string field: `tolower(field)` / `toupper(field)`.
+///
+/// The folding is done by the database (`LOWER()` / `UPPER()`); the literal on
+/// the other side is compared as-is, as in standard `OData`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaseFold {
+    Lower,
+    Upper,
+}
+
+impl CaseFold {
+    /// All supported case-folding functions.
+    pub const ALL: &'static [CaseFold] = &[CaseFold::Lower, CaseFold::Upper];
+
+    /// Look up a case-folding function by its `OData` name (case-insensitive).
+    #[must_use]
+    pub fn from_function_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|f| f.function_name().eq_ignore_ascii_case(name))
+    }
+
+    /// The `OData` function name.
+    #[must_use]
+    pub fn function_name(self) -> &'static str {
+        match self {
+            CaseFold::Lower => "tolower",
+            CaseFold::Upper => "toupper",
+        }
+    }
+}
+
+impl fmt::Display for CaseFold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.function_name())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FilterNode<F: FilterField> {
     Binary {
         field: F,
+        /// Case folding applied to the field before the comparison.
+        fold: Option<CaseFold>,
         op: FilterOp,
         value: ODataValue,
     },
@@ -95,11 +142,35 @@ pub enum FilterNode<F: FilterField> {
         children: Vec<FilterNode<F>>,
     },
     Not(Box<FilterNode<F>>),
+    /// Comparison of the scalar at `path` inside a [`FieldKind::Json`] field,
+    /// e.g. `metadata/labels/team eq 'payments'`.
+    JsonPath {
+        field: F,
+        path: Vec<String>,
+        op: FilterOp,
+        value: ODataValue,
+    },
 }
 
 impl<F: FilterField> FilterNode<F> {
     pub fn binary(field: F, op: FilterOp, value: ODataValue) -> Self {
-        FilterNode::Binary { field, op, value }
+        FilterNode::Binary {
+            field,
+            fold: None,
+            op,
+            value,
+        }
+    }
+
+    /// Binary node on a case-folded field, e.g. `tolower(name) eq 'smith'`.
+    #[must_use]
+    pub fn folded(fold: CaseFold, field: F, op: FilterOp, value: ODataValue) -> Self {
+        FilterNode::Binary {
+            field,
+            fold: Some(fold),
+            op,
+            value,
+        }
     }
 
     #[must_use]
@@ -208,11 +279,13 @@ pub fn convert_expr_to_filter_node<F: FilterField>(
         }
 
         E::Compare(left, op, right) => {
-            let (field_name, value) = match (&**left, &**right) {
-                (E::Identifier(name), E::Value(val)) => (name.as_str(), val.clone()),
+            let (operand, value) = match (&**left, &**right) {
                 (E::Identifier(_), E::Identifier(_)) => {
                     return Err(FilterError::FieldToFieldComparison);
                 }
+                (operand @ (E::Identifier(_) | E::Function(..)), E::Value(val)) => {
+                    (operand, val.clone())
+                }
                 _ => {
                     return Err(FilterError::InvalidExpression(
                         "Comparison must be between field and value".to_owned(),
@@ -220,11 +293,6 @@ pub fn convert_expr_to_filter_node<F: FilterField>(
                 }
             };
 
-            let field = F::from_name(field_name)
-                .ok_or_else(|| FilterError::UnknownField(field_name.to_owned()))?;
-
-            validate_value_type(field, &value)?;
-
             let filter_op = match op {
                 odata_ast::CompareOperator::Eq => FilterOp::Eq,
                 odata_ast::CompareOperator::Ne => FilterOp::Ne,
@@ -234,88 +302,50 @@ pub fn convert_expr_to_filter_node<F: FilterField>(
                 odata_ast::CompareOperator::Le => FilterOp::Le,
             };
 
-            Ok(FilterNode::binary(field, filter_op, value))
+            if let E::Identifier(name) = operand
+                && let Some((field, path)) = resolve_json_path::<F>(name)?
+            {
+                return json_path_node(field, path, filter_op, value);
+            }
+
+            let (field, fold) = resolve_operand::<F>(operand)?;
+
+            validate_value_type(field, &value)?;
+
+            Ok(FilterNode::Binary {
+                field,
+                fold,
+                op: filter_op,
+                value,
+            })
         }
 
         E::Function(func_name, args) => {
-            let name_lower = func_name.to_ascii_lowercase();
-            match (name_lower.as_str(), args.as_slice()) {
-                (
-                    "contains",
-                    [
-                        E::Identifier(field_name),
-                        E::Value(odata_ast::Value::String(s)),
-                    ],
-                ) => {
-                    let field = F::from_name(field_name)
-                        .ok_or_else(|| FilterError::UnknownField(field_name.clone()))?;
-
-                    if field.kind() != FieldKind::String {
-                        return Err(FilterError::TypeMismatch {
-                            field: field_name.clone(),
-                            expected: FieldKind::String,
-                            got: "non-string".to_owned(),
-                        });
-                    }
-
-                    Ok(FilterNode::binary(
-                        field,
-                        FilterOp::Contains,
-                        odata_ast::Value::String(s.clone()),
-                    ))
-                }
-                (
-                    "startswith",
-                    [
-                        E::Identifier(field_name),
-                        E::Value(odata_ast::Value::String(s)),
-                    ],
-                ) => {
-                    let field = F::from_name(field_name)
-                        .ok_or_else(|| FilterError::UnknownField(field_name.clone()))?;
-
-                    if field.kind() != FieldKind::String {
-                        return Err(FilterError::TypeMismatch {
-                            field: field_name.clone(),
-                            expected: FieldKind::String,
-                            got: "non-string".to_owned(),
-                        });
-                    }
-
-                    Ok(FilterNode::binary(
-                        field,
-                        FilterOp::StartsWith,
-                        odata_ast::Value::String(s.clone()),
-                    ))
-                }
-                (
-                    "endswith",
-                    [
-                        E::Identifier(field_name),
-                        E::Value(odata_ast::Value::String(s)),
-                    ],
-                ) => {
-                    let field = F::from_name(field_name)
-                        .ok_or_else(|| FilterError::UnknownField(field_name.clone()))?;
-
-                    if field.kind() != FieldKind::String {
-                        return Err(FilterError::TypeMismatch {
-                            field: field_name.clone(),
-                            expected: FieldKind::String,
-                            got: "non-string".to_owned(),
-                        });
-                    }
-
-                    Ok(FilterNode::binary(
-                        field,
-                        FilterOp::EndsWith,
-                        odata_ast::Value::String(s.clone()),
-                    ))
+            let filter_op = match func_name.to_ascii_lowercase().as_str() {
+                "contains" => FilterOp::Contains,
+                "startswith" => FilterOp::StartsWith,
+                "endswith" => FilterOp::EndsWith,
+                _ => {
+                    return Err(FilterError::UnsupportedOperation(format!(
+                        "Function '{func_name}'"
+                    )));
                 }
-                _ => Err(FilterError::UnsupportedOperation(format!(
+            };
+            let [operand, E::Value(odata_ast::Value::String(s))] = args.as_slice() else {
+                return Err(FilterError::UnsupportedOperation(format!(
                     "Function '{func_name}'"
-                ))),
-            }
+                )));
+            };
+
+            let (field, fold) = resolve_operand::<F>(operand)?;
+            ensure_string_field(field)?;
+
+            Ok(FilterNode::Binary {
+                field,
+                fold,
+                op: filter_op,
+                value: odata_ast::Value::String(s.clone()),
+            })
         }
 
         E::In(_left, _list) => Err(FilterError::UnsupportedOperation(
@@ -327,6 +357,96 @@ pub fn convert_expr_to_filter_node<F: FilterField>(
     }
 }
 
+/// Resolve the field side of a comparison or string function: a bare field, or
+/// a string field wrapped in `tolower`/`toupper`.
+fn resolve_operand<F: FilterField>(expr: &odata_ast::Expr) -> FilterResult<(F, Option<CaseFold>)> {
+    use odata_ast::Expr as E;
+
+    match expr {
+        E::Identifier(name) => {
+            let field =
+                F::from_name(name).ok_or_else(|| FilterError::UnknownField(name.clone()))?;
+            Ok((field, None))
+        }
+        E::Function(func_name, args) => {
+            let fold = CaseFold::from_function_name(func_name).ok_or_else(|| {
+                FilterError::UnsupportedOperation(format!("Function '{func_name}'"))
+            })?;
+            let [E::Identifier(name)] = args.as_slice() else {
+                return Err(FilterError::InvalidExpression(format!(
+                    "{fold}() takes a single field argument"
+                )));
+            };
+            let field =
+                F::from_name(name).ok_or_else(|| FilterError::UnknownField(name.clone()))?;
+            ensure_string_field(field)?;
+            Ok((field, Some(fold)))
+        }
+        _ => Err(FilterError::InvalidExpression(
+            "Expected a field reference".to_owned(),
+        )),
+    }
+}
+
+/// Resolve `field/key/...` to a [`FieldKind::Json`] field and one of its allowed
+/// paths. Plain identifiers resolve to `None`.
+fn resolve_json_path<F: FilterField>(name: &str) -> FilterResult<Option<(F, Vec<String>)>> {
+    let Some((field_name, path)) = name.split_once('/') else {
+        return Ok(None);
+    };
+    let field =
+        F::from_name(field_name).ok_or_else(|| FilterError::UnknownField(name.to_owned()))?;
+    match field.kind() {
+        FieldKind::Json { allowed_paths } if allowed_paths.contains(&path) => {
+            Ok(Some((field, path.split('/').map(str::to_owned).collect())))
+        }
+        _ => Err(FilterError::UnknownField(name.to_owned())),
+    }
+}
+
+/// JSON paths support `eq`/`ne` against a string, number, boolean or `null`.
+fn json_path_node<F: FilterField>(
+    field: F,
+    path: Vec<String>,
+    op: FilterOp,
+    value: odata_ast::Value,
+) -> FilterResult<FilterNode<F>> {
+    use odata_ast::Value as V;
+
+    if !matches!(op, FilterOp::Eq | FilterOp::Ne) {
+        return Err(FilterError::UnsupportedOperation(format!(
+            "'{op}' on JSON path {}/{}",
+            field.name(),
+            path.join("/")
+        )));
+    }
+    if !matches!(value, V::String(_) | V::Number(_) | V::Bool(_) | V::Null) {
+        return Err(FilterError::TypeMismatch {
+            field: format!("{}/{}", field.name(), path.join("/")),
+            expected: field.kind(),
+            got: value.to_string(),
+        });
+    }
+    Ok(FilterNode::JsonPath {
+        field,
+        path,
+        op,
+        value,
+    })
+}
+
+fn ensure_string_field<F: FilterField>(field: F) -> FilterResult<()> {
+    if field.kind() == FieldKind::String {
+        Ok(())
+    } else {
+        Err(FilterError::TypeMismatch {
+            field: field.name().to_owned(),
+            expected: FieldKind::String,
+            got: "non-string".to_owned(),
+        })
+    }
+}
+
 fn validate_value_type<F: FilterField>(field: F, value: &odata_ast::Value) -> FilterResult<()> {
     use odata_ast::Value as V;
 
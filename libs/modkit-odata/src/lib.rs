@@ -7,12 +7,16 @@ pub mod page;
 pub mod pagination;
 pub mod problem_mapping;
 pub mod schema;
+pub mod search;
+pub mod sort;
 
 pub use builder::QueryBuilder;
 pub use limits::ODataLimits;
 pub use page::{Page, PageInfo};
-pub use pagination::{normalize_filter_for_hash, short_filter_hash};
+pub use pagination::{normalize_filter_for_hash, short_filter_hash, short_query_hash};
 pub use schema::{FieldRef, Schema};
+pub use search::{ODataSearch, SearchField, SearchableFields, parse_search};
+pub use sort::{IntoSortableFields, SortableField, SortableFieldSpec};
 
 pub mod ast {
     use bigdecimal::BigDecimal;
@@ -283,6 +287,10 @@ pub enum Error {
     #[error("unsupported $orderby field: {0}")]
     InvalidOrderByField(String),
 
+    // Search parsing and support errors
+    #[error("invalid $search: {0}")]
+    InvalidSearch(String),
+
     // Pagination and cursor errors
     #[error("ORDER_MISMATCH")]
     OrderMismatch,
@@ -466,6 +474,11 @@ pub struct ODataQuery {
     pub cursor: Option<CursorV1>,
     pub filter_hash: Option<String>,
     pub select: Option<Vec<String>>,
+    /// Free-text `$search` terms; `None` when the parameter is absent or blank.
+    pub search: Option<ODataSearch>,
+    /// Allow `$filter` to reference the entity's scope columns (tenant, owner,
+    /// resource). Off by default; set by routes that deliberately expose them.
+    pub allow_scope_filters: bool,
 }
 
 impl ODataQuery {
@@ -503,6 +516,17 @@ impl ODataQuery {
         self
     }
 
+    pub fn with_search(mut self, search: ODataSearch) -> Self {
+        self.search = Some(search);
+        self
+    }
+
+    /// Opt in to filtering on scope columns (see [`ODataQuery::allow_scope_filters`]).
+    pub fn with_scope_filters_allowed(mut self) -> Self {
+        self.allow_scope_filters = true;
+        self
+    }
+
     /// Get filter as AST
     #[must_use]
     pub fn filter(&self) -> Option<&ast::Expr> {
@@ -521,6 +545,12 @@ impl ODataQuery {
         self.filter.map(|b| *b)
     }
 
+    /// Get `$search` terms
+    #[must_use]
+    pub fn search(&self) -> Option<&ODataSearch> {
+        self.search.as_ref()
+    }
+
     /// Check if field selection is present
     #[must_use]
     pub fn has_select(&self) -> bool {
@@ -1,6 +1,7 @@
 //! Filter hashing utilities for `OData` pagination
 
 use crate::ast;
+use crate::search::ODataSearch;
 use chrono::SecondsFormat;
 use sha2::{Digest, Sha256};
 
@@ -90,6 +91,29 @@ pub fn short_filter_hash(expr: Option<&ast::Expr>) -> Option<String> {
     })
 }
 
+/// Like [`short_filter_hash`], but also covers `$search` terms so that a cursor
+/// cannot be replayed against a different search.
+///
+/// Without search terms this is exactly `short_filter_hash(expr)`.
+#[must_use]
+pub fn short_query_hash(expr: Option<&ast::Expr>, search: Option<&ODataSearch>) -> Option<String> {
+    let Some(search) = search.filter(|s| !s.is_empty()) else {
+        return short_filter_hash(expr);
+    };
+
+    let mut hasher = Sha256::new();
+    if let Some(e) = expr {
+        hasher.update(normalize_filter_for_hash(e).as_bytes());
+    }
+    for term in &search.terms {
+        hasher.update(b"\x1fSEARCH(");
+        hasher.update(term.as_bytes());
+        hasher.update(b")");
+    }
+    let bytes = hasher.finalize();
+    Some(hex::encode(&bytes[..8]))
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -137,4 +161,28 @@ mod tests {
     fn test_short_filter_hash_none() {
         assert_eq!(short_filter_hash(None), None);
     }
+
+    #[test]
+    fn test_short_query_hash_covers_search() {
+        let expr = Expr::Identifier("active".to_owned());
+        let search = ODataSearch::new(["ada"]);
+
+        assert_eq!(
+            short_query_hash(Some(&expr), None),
+            short_filter_hash(Some(&expr))
+        );
+        assert_eq!(short_query_hash(None, Some(&ODataSearch::default())), None);
+
+        let with_search = short_query_hash(Some(&expr), Some(&search));
+        assert!(with_search.is_some());
+        assert_ne!(with_search, short_filter_hash(Some(&expr)));
+        assert_ne!(
+            with_search,
+            short_query_hash(Some(&expr), Some(&ODataSearch::new(["grace"])))
+        );
+        assert_ne!(
+            short_query_hash(None, Some(&ODataSearch::new(["a b"]))),
+            short_query_hash(None, Some(&ODataSearch::new(["a", "b"])))
+        );
+    }
 }
@@ -13,8 +13,8 @@ impl From<Error> for Problem {
         use Error::{
             CursorInvalidBase64, CursorInvalidDirection, CursorInvalidFields, CursorInvalidJson,
             CursorInvalidKeys, CursorInvalidVersion, Db, FilterMismatch, InvalidCursor,
-            InvalidFilter, InvalidLimit, InvalidOrderByField, OrderMismatch, OrderWithCursor,
-            ParsingUnavailable,
+            InvalidFilter, InvalidLimit, InvalidOrderByField, InvalidSearch, OrderMismatch,
+            OrderWithCursor, ParsingUnavailable,
         };
 
         match err {
@@ -26,6 +26,10 @@ impl From<Error> for Problem {
             InvalidOrderByField(field) => ErrorCode::odata_errors_invalid_orderby_v1()
                 .as_problem(format!("Unsupported $orderby field: {field}")),
 
+            // Search parsing errors → 422 (reported as a filter problem)
+            InvalidSearch(msg) => ErrorCode::odata_errors_invalid_filter_v1()
+                .as_problem(format!("Invalid $search: {msg}")),
+
             // All cursor-related errors → 422
             InvalidCursor
             | CursorInvalidBase64
@@ -97,6 +101,18 @@ mod tests {
         assert!(problem.code.contains("invalid_orderby"));
     }
 
+    #[test]
+    fn test_search_error_converts_to_problem() {
+        use http::StatusCode;
+
+        let err = Error::InvalidSearch("unterminated quoted phrase".to_owned());
+        let problem: Problem = err.into();
+
+        assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(problem.detail.starts_with("Invalid $search:"));
+        assert!(problem.code.contains("invalid_filter"));
+    }
+
     #[test]
     fn test_cursor_error_converts_to_problem() {
         use http::StatusCode;
@@ -0,0 +1,205 @@
+//! `$search` free-text queries.
+//!
+//! A search is a list of terms that must all match (implicit AND). A term is a
+//! bare word or a double-quoted phrase; inside a phrase `\"` is a literal quote
+//! and `\\` a literal backslash:
+//!
+//! ```text
+//! $search=ada "lovelace, countess" example.com
+//! ```
+//!
+//! yields the terms `ada`, `lovelace, countess` and `example.com`. The `AND`
+//! keyword is accepted and ignored; `OR` and `NOT` are not supported.
+//!
+//! Which columns a term is matched against is declared per resource with
+//! [`SearchableFields`].
+
+use thiserror::Error;
+
+use crate::filter::FilterField;
+
+/// Parsed `$search` parameter; a row matches if every term matches.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ODataSearch {
+    pub terms: Vec<String>,
+}
+
+impl ODataSearch {
+    /// A search matching all of `terms`.
+    #[must_use]
+    pub fn new<I, T>(terms: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self {
+            terms: terms.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+/// A field matched by `$search`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SearchField<F> {
+    pub field: F,
+    /// Relative importance of a match in this field. Reserved for ranking;
+    /// matching currently ignores it.
+    pub weight: u8,
+}
+
+impl<F> SearchField<F> {
+    #[must_use]
+    pub const fn new(field: F, weight: u8) -> Self {
+        Self { field, weight }
+    }
+}
+
+/// Declares the fields a resource's `$search` is matched against.
+///
+/// The fields are a subset of the resource's filter fields, so the storage
+/// layer maps them to columns the same way it maps `$filter` fields. Only
+/// string fields make sense here.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// pub struct UserSearch;
+///
+/// impl SearchableFields for UserSearch {
+///     type Field = UserFilterField;
+///
+///     const FIELDS: &'static [SearchField<UserFilterField>] = &[
+///         SearchField::new(UserFilterField::DisplayName, 2),
+///         SearchField::new(UserFilterField::Email, 1),
+///     ];
+/// }
+/// ```
+pub trait SearchableFields {
+    type Field: FilterField;
+
+    const FIELDS: &'static [SearchField<Self::Field>];
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum SearchError {
+    #[error("unterminated quoted phrase")]
+    UnterminatedPhrase,
+
+    #[error("'{0}' is not supported; terms are always combined with AND")]
+    UnsupportedOperator(String),
+}
+
+/// Split a raw `$search` value into terms.
+///
+/// Blank input and empty phrases yield no terms.
+///
+/// # Errors
+/// Returns `SearchError::UnterminatedPhrase` if a quote is never closed and
+/// `SearchError::UnsupportedOperator` for a bare `OR` or `NOT`.
+pub fn parse_search(raw: &str) -> Result<ODataSearch, SearchError> {
+    let mut terms = Vec::new();
+    let mut chars = raw.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c == '"' {
+            chars.next();
+            let mut phrase = String::new();
+            loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') if matches!(chars.peek(), Some(&('"' | '\\'))) => {
+                        phrase.extend(chars.next());
+                    }
+                    Some(c) => phrase.push(c),
+                    None => return Err(SearchError::UnterminatedPhrase),
+                }
+            }
+            let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+            if !phrase.is_empty() {
+                terms.push(phrase);
+            }
+            continue;
+        }
+
+        let mut word = String::new();
+        while let Some(&c) = chars.peek() {
+            if c.is_whitespace() || c == '"' {
+                break;
+            }
+            word.push(c);
+            chars.next();
+        }
+        match word.as_str() {
+            "AND" => {}
+            "OR" | "NOT" => return Err(SearchError::UnsupportedOperator(word)),
+            _ => terms.push(word),
+        }
+    }
+
+    Ok(ODataSearch { terms })
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn terms(raw: &str) -> Vec<String> {
+        parse_search(raw).unwrap().terms
+    }
+
+    #[test]
+    fn splits_words_and_phrases() {
+        assert_eq!(
+            terms(r#"  ada "lovelace,   countess" example.com "#),
+            vec!["ada", "lovelace, countess", "example.com"]
+        );
+        assert_eq!(terms(r#"a"b c"d"#), vec!["a", "b c", "d"]);
+    }
+
+    #[test]
+    fn and_keyword_is_implicit() {
+        assert_eq!(terms("ada AND lovelace"), vec!["ada", "lovelace"]);
+        assert_eq!(terms(r#""AND" and"#), vec!["AND", "and"]);
+    }
+
+    #[test]
+    fn phrases_support_escapes() {
+        assert_eq!(
+            terms(r#""say \"hi\"" "a\\b" "c\d""#),
+            vec![r#"say "hi""#, r"a\b", r"c\d"]
+        );
+    }
+
+    #[test]
+    fn blank_input_has_no_terms() {
+        assert!(parse_search("").unwrap().is_empty());
+        assert!(parse_search(r#"  ""  "   " "#).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert_eq!(
+            parse_search(r#"ada "lovelace"#),
+            Err(SearchError::UnterminatedPhrase)
+        );
+        assert_eq!(
+            parse_search("ada OR grace"),
+            Err(SearchError::UnsupportedOperator("OR".to_owned()))
+        );
+        assert_eq!(
+            parse_search("NOT ada"),
+            Err(SearchError::UnsupportedOperator("NOT".to_owned()))
+        );
+    }
+}
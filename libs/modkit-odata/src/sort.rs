@@ -0,0 +1,103 @@
+//! Fields that `$orderby` may sort on.
+//!
+//! Sorting does not require filtering: a field such as `created_at` can be
+//! offered to `$orderby` without being usable in `$filter`. `#[derive(ODataFilterable)]`
+//! generates a `*SortField` enum implementing [`SortableField`] next to the
+//! `*FilterField` enum; `with_odata_orderby` accepts either through
+//! [`IntoSortableFields`].
+
+use crate::filter::{FieldKind, FilterField};
+
+/// A single field that `$orderby` may sort on.
+pub trait SortableFieldSpec: Sync {
+    /// Field name on the wire.
+    fn name(&self) -> &'static str;
+}
+
+/// The set of fields `$orderby` may sort on.
+pub trait SortableField: 'static {
+    const FIELDS: &'static [&'static dyn SortableFieldSpec];
+}
+
+/// Types whose fields can be offered to `$orderby`.
+///
+/// Implemented for every [`FilterField`] (filterable fields are sortable,
+/// except `Json` ones) and by the derive for the generated `*SortField` enums. A hand-written
+/// [`SortableField`] implements it with [`sortable_field_names`].
+pub trait IntoSortableFields {
+    /// Wire names of the sortable fields, in declaration order.
+    fn sortable_field_names() -> Vec<&'static str>;
+}
+
+impl<T: FilterField> IntoSortableFields for T {
+    fn sortable_field_names() -> Vec<&'static str> {
+        T::FIELDS
+            .iter()
+            .filter(|field| !matches!(field.kind(), FieldKind::Json { .. }))
+            .map(FilterField::name)
+            .collect()
+    }
+}
+
+/// Wire names of `T`'s sortable fields.
+#[must_use]
+pub fn sortable_field_names<T: SortableField>() -> Vec<&'static str> {
+    T::FIELDS.iter().map(|field| field.name()).collect()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    enum UserFilter {
+        Email,
+    }
+
+    impl FilterField for UserFilter {
+        const FIELDS: &'static [Self] = &[Self::Email];
+
+        fn name(&self) -> &'static str {
+            "email"
+        }
+
+        fn kind(&self) -> FieldKind {
+            FieldKind::String
+        }
+    }
+
+    enum UserSort {
+        Email,
+        CreatedAt,
+    }
+
+    impl SortableFieldSpec for UserSort {
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Email => "email",
+                Self::CreatedAt => "created_at",
+            }
+        }
+    }
+
+    impl SortableField for UserSort {
+        const FIELDS: &'static [&'static dyn SortableFieldSpec] = &[&Self::Email, &Self::CreatedAt];
+    }
+
+    impl IntoSortableFields for UserSort {
+        fn sortable_field_names() -> Vec<&'static str> {
+            sortable_field_names::<Self>()
+        }
+    }
+
+    #[test]
+    fn filter_fields_are_sortable() {
+        assert_eq!(UserFilter::sortable_field_names(), ["email"]);
+    }
+
+    #[test]
+    fn sortable_fields_need_not_be_filterable() {
+        assert_eq!(UserSort::sortable_field_names(), ["email", "created_at"]);
+    }
+}
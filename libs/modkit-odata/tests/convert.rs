@@ -47,4 +47,278 @@ mod tests {
             panic!("expected In()");
         }
     }
+
+    #[test]
+    fn converts_tolower_inside_contains() {
+        let src = od::parse_str("contains(tolower(display_name),'smith')").unwrap();
+        let dst: Expr = src.into();
+
+        let Expr::Function(name, args) = dst else {
+            panic!("expected Function()");
+        };
+        assert_eq!(name.to_lowercase(), "contains");
+        match &args[..] {
+            [
+                Expr::Function(inner, inner_args),
+                Expr::Value(Value::String(s)),
+            ] => {
+                assert_eq!(inner.to_lowercase(), "tolower");
+                assert!(matches!(&inner_args[..], [Expr::Identifier(f)] if f == "display_name"));
+                assert_eq!(s, "smith");
+            }
+            other => panic!("unexpected args: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn converts_toupper_on_left_of_comparison() {
+        let src = od::parse_str("toupper(name) eq 'SMITH'").unwrap();
+        let dst: Expr = src.into();
+
+        let Expr::Compare(lhs, op, rhs) = dst else {
+            panic!("expected Compare()");
+        };
+        assert!(matches!(op, CompareOperator::Eq));
+        assert!(matches!(*lhs, Expr::Function(ref n, _) if n.eq_ignore_ascii_case("toupper")));
+        assert!(matches!(*rhs, Expr::Value(Value::String(ref s)) if s == "SMITH"));
+    }
+}
+
+#[cfg(feature = "with-odata-params")]
+mod typed {
+    use modkit_odata::filter::{
+        CaseFold, FieldKind, FilterError, FilterField, FilterNode, FilterOp, parse_odata_filter,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum UserField {
+        DisplayName,
+        Age,
+    }
+
+    impl FilterField for UserField {
+        const FIELDS: &'static [Self] = &[Self::DisplayName, Self::Age];
+
+        fn name(&self) -> &'static str {
+            match self {
+                Self::DisplayName => "display_name",
+                Self::Age => "age",
+            }
+        }
+
+        fn kind(&self) -> FieldKind {
+            match self {
+                Self::DisplayName => FieldKind::String,
+                Self::Age => FieldKind::I64,
+            }
+        }
+    }
+
+    fn binary(node: FilterNode<UserField>) -> (UserField, Option<CaseFold>, FilterOp) {
+        match node {
+            FilterNode::Binary {
+                field, fold, op, ..
+            } => (field, fold, op),
+            other => panic!("expected Binary, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn case_fold_functions_are_recognized() {
+        for (raw, fold, op) in [
+            (
+                "contains(tolower(display_name),'smith')",
+                CaseFold::Lower,
+                FilterOp::Contains,
+            ),
+            (
+                "startswith(TOUPPER(display_name),'SM')",
+                CaseFold::Upper,
+                FilterOp::StartsWith,
+            ),
+            (
+                "endswith(tolower(display_name),'th')",
+                CaseFold::Lower,
+                FilterOp::EndsWith,
+            ),
+            (
+                "tolower(display_name) eq 'smith'",
+                CaseFold::Lower,
+                FilterOp::Eq,
+            ),
+            (
+                "toupper(display_name) ne 'SMITH'",
+                CaseFold::Upper,
+                FilterOp::Ne,
+            ),
+        ] {
+            let node = parse_odata_filter::<UserField>(raw).unwrap();
+            assert_eq!(
+                binary(node),
+                (UserField::DisplayName, Some(fold), op),
+                "{raw}"
+            );
+        }
+    }
+
+    #[test]
+    fn plain_field_has_no_fold() {
+        let node = parse_odata_filter::<UserField>("contains(display_name,'x')").unwrap();
+        assert_eq!(
+            binary(node),
+            (UserField::DisplayName, None, FilterOp::Contains)
+        );
+    }
+
+    #[test]
+    fn case_fold_on_non_string_field_is_rejected() {
+        for raw in ["tolower(age) eq 'x'", "contains(toupper(age),'1')"] {
+            let err = parse_odata_filter::<UserField>(raw).unwrap_err();
+            assert!(
+                matches!(err, FilterError::TypeMismatch { ref field, .. } if field == "age"),
+                "{raw}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_case_fold_is_rejected() {
+        assert!(matches!(
+            parse_odata_filter::<UserField>("tolower(display_name) eq 1").unwrap_err(),
+            FilterError::TypeMismatch { .. }
+        ));
+        assert!(matches!(
+            parse_odata_filter::<UserField>("tolower(nope) eq 'x'").unwrap_err(),
+            FilterError::UnknownField(_)
+        ));
+        assert!(matches!(
+            parse_odata_filter::<UserField>("trim(display_name) eq 'x'").unwrap_err(),
+            FilterError::UnsupportedOperation(_)
+        ));
+    }
+}
+
+mod json_paths {
+    use modkit_odata::ast::{CompareOperator, Expr, Value};
+    use modkit_odata::filter::{
+        FieldKind, FilterError, FilterField, FilterNode, FilterOp, convert_expr_to_filter_node,
+    };
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum DocField {
+        Title,
+        Metadata,
+    }
+
+    impl FilterField for DocField {
+        const FIELDS: &'static [Self] = &[Self::Title, Self::Metadata];
+
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Title => "title",
+                Self::Metadata => "metadata",
+            }
+        }
+
+        fn kind(&self) -> FieldKind {
+            match self {
+                Self::Title => FieldKind::String,
+                Self::Metadata => FieldKind::Json {
+                    allowed_paths: &["department", "labels/team"],
+                },
+            }
+        }
+    }
+
+    fn compare(field: &str, op: CompareOperator, value: Value) -> Expr {
+        Expr::Compare(
+            Box::new(Expr::Identifier(field.to_owned())),
+            op,
+            Box::new(Expr::Value(value)),
+        )
+    }
+
+    fn convert(expr: &Expr) -> Result<FilterNode<DocField>, FilterError> {
+        convert_expr_to_filter_node::<DocField>(expr)
+    }
+
+    #[test]
+    fn allowed_paths_convert_to_json_path_nodes() {
+        let node = convert(&compare(
+            "metadata/labels/team",
+            CompareOperator::Ne,
+            Value::String("payments".to_owned()),
+        ))
+        .unwrap();
+        let FilterNode::JsonPath {
+            field, path, op, ..
+        } = node
+        else {
+            panic!("expected JsonPath, got {node:?}");
+        };
+        assert_eq!(field, DocField::Metadata);
+        assert_eq!(path, ["labels", "team"]);
+        assert_eq!(op, FilterOp::Ne);
+
+        assert!(matches!(
+            convert(&compare(
+                "metadata/department",
+                CompareOperator::Eq,
+                Value::Null
+            )),
+            Ok(FilterNode::JsonPath { .. })
+        ));
+    }
+
+    #[test]
+    fn paths_outside_the_whitelist_are_unknown_fields() {
+        for field in [
+            "metadata/salary",
+            "metadata/labels",
+            "title/x",
+            "nope/department",
+        ] {
+            let err = convert(&compare(
+                field,
+                CompareOperator::Eq,
+                Value::String("x".to_owned()),
+            ))
+            .unwrap_err();
+            assert!(
+                matches!(err, FilterError::UnknownField(ref name) if name == field),
+                "{field}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn json_paths_only_support_equality_on_scalars() {
+        let err = convert(&compare(
+            "metadata/department",
+            CompareOperator::Gt,
+            Value::String("a".to_owned()),
+        ))
+        .unwrap_err();
+        assert!(
+            matches!(err, FilterError::UnsupportedOperation(_)),
+            "{err:?}"
+        );
+
+        let err = convert(&compare(
+            "metadata/department",
+            CompareOperator::Eq,
+            Value::Uuid(uuid::Uuid::nil()),
+        ))
+        .unwrap_err();
+        assert!(matches!(err, FilterError::TypeMismatch { .. }), "{err:?}");
+
+        // The JSON field itself is not comparable.
+        let err = convert(&compare(
+            "metadata",
+            CompareOperator::Eq,
+            Value::String("x".to_owned()),
+        ))
+        .unwrap_err();
+        assert!(matches!(err, FilterError::TypeMismatch { .. }), "{err:?}");
+    }
 }
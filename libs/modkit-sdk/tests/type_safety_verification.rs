@@ -100,6 +100,26 @@ fn test_no_manual_odata_query_construction() {
     // - Touch internal OData structures
 }
 
+#[test]
+fn test_order_by_requires_schema_field() {
+    // ✅ Valid: Field of the builder's schema
+    let query = QueryBuilder::<TestSchema>::new()
+        .order_by(AGE, SortDir::Desc)
+        .build();
+    assert_eq!(query.order.0[0].field, "age");
+
+    // ❌ COMPILE ERROR: Uncomment to verify type safety
+    // struct OtherSchema;
+    // impl Schema for OtherSchema {
+    //     type Field = TestField;
+    //     fn field_name(_: TestField) -> &'static str { "other" }
+    // }
+    // const OTHER: FieldRef<OtherSchema, i32> = FieldRef::new(TestField::Age);
+    // let _query = QueryBuilder::<TestSchema>::new()
+    //     .order_by(OTHER, SortDir::Asc)  // ERROR: OtherSchema field on TestSchema builder
+    //     .build();
+}
+
 #[test]
 fn test_filter_hash_determinism() {
     let user_id = 123;
@@ -110,6 +110,10 @@ pub mod pep_properties {
 /// Variants mirror the predicate types from the PDP response:
 /// - [`ScopeFilter::Eq`] — equality (`property = value`)
 /// - [`ScopeFilter::In`] — set membership (`property IN (values)`)
+/// - [`ScopeFilter::NotIn`] — exclusion (`property NOT IN (values)`)
+///
+/// A property may also address a value inside a JSON column, e.g.
+/// `"metadata->department"` (see [`JsonPath`]).
 ///
 /// ## Future extensions
 ///
@@ -122,6 +126,8 @@ pub enum ScopeFilter {
     Eq(EqScopeFilter),
     /// Set membership: `property IN (values)`.
     In(InScopeFilter),
+    /// Exclusion: `property NOT IN (values)`.
+    NotIn(NotInScopeFilter),
 }
 
 /// Equality scope filter: `property = value`.
@@ -142,6 +148,17 @@ pub struct InScopeFilter {
     values: Vec<ScopeValue>,
 }
 
+/// Exclusion scope filter: `property NOT IN (values)`.
+///
+/// Rows whose property is NULL (or, for a JSON path, missing) do not match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotInScopeFilter {
+    /// Authorization property name (e.g., `pep_properties::OWNER_ID`).
+    property: String,
+    /// The set of values to exclude.
+    values: Vec<ScopeValue>,
+}
+
 impl EqScopeFilter {
     /// Create an equality scope filter.
     #[must_use]
@@ -204,6 +221,31 @@ impl InScopeFilter {
     }
 }
 
+impl NotInScopeFilter {
+    /// Create an exclusion scope filter.
+    #[must_use]
+    pub fn new(property: impl Into<String>, values: Vec<ScopeValue>) -> Self {
+        Self {
+            property: property.into(),
+            values,
+        }
+    }
+
+    /// The authorization property name.
+    #[inline]
+    #[must_use]
+    pub fn property(&self) -> &str {
+        &self.property
+    }
+
+    /// The excluded values.
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &[ScopeValue] {
+        &self.values
+    }
+}
+
 impl ScopeFilter {
     /// Create an equality filter (`property = value`).
     #[must_use]
@@ -226,26 +268,67 @@ impl ScopeFilter {
         ))
     }
 
+    /// Create an exclusion filter (`property NOT IN (values)`).
+    #[must_use]
+    pub fn not_in(property: impl Into<String>, values: Vec<ScopeValue>) -> Self {
+        Self::NotIn(NotInScopeFilter::new(property, values))
+    }
+
+    /// Create an exclusion filter from UUID values (convenience).
+    #[must_use]
+    pub fn not_in_uuids(
+        property: impl Into<String>,
+        values: impl IntoIterator<Item = Uuid>,
+    ) -> Self {
+        Self::NotIn(NotInScopeFilter::new(
+            property,
+            values.into_iter().map(ScopeValue::Uuid).collect(),
+        ))
+    }
+
     /// The authorization property name.
     #[must_use]
     pub fn property(&self) -> &str {
         match self {
             Self::Eq(f) => f.property(),
             Self::In(f) => f.property(),
+            Self::NotIn(f) => f.property(),
         }
     }
 
     /// Collect all values as a slice-like view for iteration.
     ///
-    /// For `Eq`, returns a single-element slice; for `In`, returns the values slice.
+    /// For `Eq`, returns a single-element slice; for `In` and `NotIn`, returns
+    /// the values slice. Check [`is_exclusion`](Self::is_exclusion) before
+    /// treating them as granted values.
     #[must_use]
     pub fn values(&self) -> ScopeFilterValues<'_> {
         match self {
             Self::Eq(f) => ScopeFilterValues::Single(&f.value),
             Self::In(f) => ScopeFilterValues::Multiple(&f.values),
+            Self::NotIn(f) => ScopeFilterValues::Multiple(&f.values),
         }
     }
 
+    /// Returns `true` if this filter's values are excluded rather than granted.
+    #[inline]
+    #[must_use]
+    pub fn is_exclusion(&self) -> bool {
+        matches!(self, Self::NotIn(_))
+    }
+
+    /// Returns `true` if a row whose property holds `value` passes this filter.
+    #[must_use]
+    pub fn matches(&self, value: &ScopeValue) -> bool {
+        self.values().contains(value) != self.is_exclusion()
+    }
+
+    /// The JSON path this filter's property addresses, if it is one.
+    #[must_use]
+    pub fn json_path(&self) -> Option<JsonPath<'_>> {
+        JsonPath::parse(self.property())
+    }
+
     /// Extract filter values as UUIDs, skipping non-UUID entries.
     ///
     /// Useful when the caller knows the property holds UUID values
@@ -259,15 +342,86 @@ impl ScopeFilter {
     }
 }
 
+/// Separator between a JSON column property and the keys inside the column,
+/// as in `"metadata->labels->team"`.
+pub const JSON_PATH_SEPARATOR: &str = "->";
+
+/// A filter property addressing a value inside a JSON column.
+///
+/// Written as the column's property followed by object keys, joined with
+/// [`JSON_PATH_SEPARATOR`]: `metadata->department` filters on the `department`
+/// key of the column that `ScopableEntity::resolve_property("metadata")` maps
+/// to. Keys may contain ASCII letters, digits, `_` and `-`; any other property
+/// is treated as a plain property name.
+///
+/// Filter values are compared with the JSON scalar at the path: strings with
+/// [`ScopeValue::String`] or [`ScopeValue::Uuid`], numbers with
+/// [`ScopeValue::Int`] and booleans with [`ScopeValue::Bool`]. A missing key
+/// matches nothing.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonPath<'a> {
+    column_property: &'a str,
+    path: Vec<&'a str>,
+}
+
+impl<'a> JsonPath<'a> {
+    /// Parse `property` as a JSON path; `None` if it is a plain property.
+    #[must_use]
+    pub fn parse(property: &'a str) -> Option<Self> {
+        let mut parts = property.split(JSON_PATH_SEPARATOR);
+        let column_property = parts.next().filter(|p| !p.is_empty())?;
+        let path: Vec<&str> = parts.collect();
+        if path.is_empty() || !path.iter().all(|key| is_json_key(key)) {
+            return None;
+        }
+        Some(Self {
+            column_property,
+            path,
+        })
+    }
+
+    /// Build the property for `path` inside the JSON column `column_property`.
+    #[must_use]
+    pub fn property(column_property: &str, path: &[&str]) -> String {
+        let mut property = column_property.to_owned();
+        for key in path {
+            property.push_str(JSON_PATH_SEPARATOR);
+            property.push_str(key);
+        }
+        property
+    }
+
+    /// Property of the JSON column itself.
+    #[inline]
+    #[must_use]
+    pub fn column_property(&self) -> &'a str {
+        self.column_property
+    }
+
+    /// Object keys from the column root to the filtered value.
+    #[inline]
+    #[must_use]
+    pub fn path(&self) -> &[&'a str] {
+        &self.path
+    }
+}
+
+fn is_json_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'_' || b == b'-')
+}
+
 /// Iterator adapter for [`ScopeFilter::values()`].
 ///
 /// Provides a uniform way to iterate over filter values regardless of
-/// whether the filter is `Eq` (single value) or `In` (multiple values).
+/// whether the filter is `Eq` (single value) or `In`/`NotIn` (multiple values).
 #[derive(Clone, Debug)]
 pub enum ScopeFilterValues<'a> {
     /// Single value from an `Eq` filter.
     Single(&'a ScopeValue),
-    /// Multiple values from an `In` filter.
+    /// Multiple values from an `In` or `NotIn` filter.
     Multiple(&'a [ScopeValue]),
 }
 
@@ -356,6 +510,35 @@ impl ScopeConstraint {
     }
 }
 
+/// What a scope may be used for.
+///
+/// Set by the PEP from the action the scope was obtained for, so a scope
+/// authorized for a read cannot be reused for a write. Scopes built directly
+/// with the constructors default to [`ScopeIntent::ReadWrite`].
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ScopeIntent {
+    /// Only reads (selects) may use this scope.
+    Read,
+    /// Reads and writes may use this scope.
+    #[default]
+    ReadWrite,
+}
+
+impl ScopeIntent {
+    /// The more restrictive of the two intents.
+    #[must_use]
+    pub fn stricter(self, other: Self) -> Self {
+        if self == Self::Read || other == Self::Read {
+            Self::Read
+        } else {
+            Self::ReadWrite
+        }
+    }
+}
+
 /// A disjunction (OR) of scope constraints defining what data is accessible.
 ///
 /// Each constraint is an independent access path (OR-ed). Filters within a
@@ -381,6 +564,7 @@ impl ScopeConstraint {
 pub struct AccessScope {
     constraints: Vec<ScopeConstraint>,
     unconstrained: bool,
+    intent: ScopeIntent,
 }
 
 impl Default for AccessScope {
@@ -399,6 +583,7 @@ impl AccessScope {
         Self {
             constraints,
             unconstrained: false,
+            intent: ScopeIntent::ReadWrite,
         }
     }
 
@@ -417,6 +602,7 @@ impl AccessScope {
         Self {
             constraints: Vec::new(),
             unconstrained: true,
+            intent: ScopeIntent::ReadWrite,
         }
     }
 
@@ -426,6 +612,7 @@ impl AccessScope {
         Self {
             constraints: Vec::new(),
             unconstrained: false,
+            intent: ScopeIntent::ReadWrite,
         }
     }
 
@@ -461,6 +648,40 @@ impl AccessScope {
         Self::for_resources(vec![id])
     }
 
+    /// Return this scope with the given intent.
+    #[must_use]
+    pub fn with_intent(mut self, intent: ScopeIntent) -> Self {
+        self.intent = intent;
+        self
+    }
+
+    // ── Combination ─────────────────────────────────────────────────
+
+    /// Intersect two scopes: a row must be accessible through both.
+    ///
+    /// Constraints are combined pairwise (filters AND-ed) and the result
+    /// carries the stricter of the two intents.
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        let intent = self.intent.stricter(other.intent);
+        if self.unconstrained {
+            return other.clone().with_intent(intent);
+        }
+        if other.unconstrained {
+            return self.clone().with_intent(intent);
+        }
+        let constraints = self
+            .constraints
+            .iter()
+            .flat_map(|a| {
+                other.constraints.iter().map(move |b| {
+                    ScopeConstraint::new(a.filters().iter().chain(b.filters()).cloned().collect())
+                })
+            })
+            .collect();
+        Self::from_constraints(constraints).with_intent(intent)
+    }
+
     // ── Accessors ───────────────────────────────────────────────────
 
     /// The constraints in this scope (OR-ed).
@@ -477,6 +698,20 @@ impl AccessScope {
         self.unconstrained
     }
 
+    /// The intent this scope was issued for.
+    #[inline]
+    #[must_use]
+    pub fn intent(&self) -> ScopeIntent {
+        self.intent
+    }
+
+    /// Returns `true` if this scope may only be used for reads.
+    #[inline]
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        self.intent == ScopeIntent::Read
+    }
+
     /// Returns `true` if this scope denies all access.
     ///
     /// A scope is deny-all when it is not unconstrained and has no constraints.
@@ -486,12 +721,14 @@ impl AccessScope {
     }
 
     /// Collect all values for a given property across all constraints.
+    ///
+    /// Only granted values are collected; `NotIn` filters are skipped.
     #[must_use]
     pub fn all_values_for(&self, property: &str) -> Vec<&ScopeValue> {
         let mut result = Vec::new();
         for constraint in &self.constraints {
             for filter in constraint.filters() {
-                if filter.property() == property {
+                if filter.property() == property && !filter.is_exclusion() {
                     result.extend(filter.values());
                 }
             }
@@ -501,13 +738,13 @@ impl AccessScope {
 
     /// Collect all UUID values for a given property across all constraints.
     ///
-    /// Convenience wrapper — skips non-UUID values.
+    /// Convenience wrapper — skips non-UUID values and `NotIn` filters.
     #[must_use]
     pub fn all_uuid_values_for(&self, property: &str) -> Vec<Uuid> {
         let mut result = Vec::new();
         for constraint in &self.constraints {
             for filter in constraint.filters() {
-                if filter.property() == property {
+                if filter.property() == property && !filter.is_exclusion() {
                     result.extend(filter.uuid_values());
                 }
             }
@@ -515,13 +752,15 @@ impl AccessScope {
         result
     }
 
-    /// Check if any constraint has a filter matching the given property and value.
+    /// Check if any constraint has a filter granting the given property and value.
+    ///
+    /// Values listed by a `NotIn` filter are not granted.
     #[must_use]
     pub fn contains_value(&self, property: &str, value: &ScopeValue) -> bool {
         self.constraints.iter().any(|c| {
-            c.filters()
-                .iter()
-                .any(|f| f.property() == property && f.values().contains(value))
+            c.filters().iter().any(|f| {
+                f.property() == property && !f.is_exclusion() && f.values().contains(value)
+            })
         })
     }
 
@@ -531,6 +770,14 @@ impl AccessScope {
         self.contains_value(property, &ScopeValue::Uuid(id))
     }
 
+    /// Returns `true` if any filter addresses a value inside a JSON column.
+    #[must_use]
+    pub fn has_json_paths(&self) -> bool {
+        self.constraints
+            .iter()
+            .any(|c| c.filters().iter().any(|f| f.json_path().is_some()))
+    }
+
     /// Check if any constraint references the given property.
     #[must_use]
     pub fn has_property(&self, property: &str) -> bool {
@@ -540,6 +787,254 @@ impl AccessScope {
     }
 }
 
+// ── Validation against a resource ──────────────────────────────────
+
+/// A resource type with a declared set of scope properties.
+///
+/// Implemented by the authz SDK's `ResourceType`; lets scopes built by hand be
+/// checked against the properties the PEP and the secure ORM understand.
+pub trait ScopeResource {
+    /// Resource type name, used in error messages.
+    fn resource_name(&self) -> &str;
+
+    /// Properties a scope for this resource may filter on.
+    fn supported_properties(&self) -> &[&str];
+}
+
+/// A scope does not fit the resource it is meant for.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ScopeValidationError {
+    /// A filter names a property the resource does not support.
+    #[error(
+        "unknown scope property '{property}'{}; supported: [{}]",
+        for_resource(.resource),
+        .supported.join(", ")
+    )]
+    UnknownProperty {
+        resource: String,
+        property: String,
+        supported: Vec<String>,
+    },
+
+    /// A filter has no values and would match nothing.
+    #[error("scope property '{property}'{} has no values", for_resource(.resource))]
+    EmptyValues { resource: String, property: String },
+}
+
+fn for_resource(resource: &str) -> String {
+    if resource.is_empty() {
+        String::new()
+    } else {
+        format!(" for resource {resource}")
+    }
+}
+
+/// Check that `property` is one of `supported`.
+///
+/// A [`JsonPath`] is accepted when its column property is supported.
+/// `resource` only names the resource in the error; it may be empty.
+///
+/// # Errors
+/// Returns [`ScopeValidationError::UnknownProperty`] listing the supported set.
+pub fn check_property(
+    resource: &str,
+    supported: &[&str],
+    property: &str,
+) -> Result<(), ScopeValidationError> {
+    let checked = JsonPath::parse(property).map_or(property, |p| p.column_property());
+    if supported.contains(&checked) {
+        Ok(())
+    } else {
+        Err(ScopeValidationError::UnknownProperty {
+            resource: resource.to_owned(),
+            property: property.to_owned(),
+            supported: supported.iter().map(|p| (*p).to_owned()).collect(),
+        })
+    }
+}
+
+fn check_filter(
+    resource: &impl ScopeResource,
+    filter: &ScopeFilter,
+) -> Result<(), ScopeValidationError> {
+    check_property(
+        resource.resource_name(),
+        resource.supported_properties(),
+        filter.property(),
+    )?;
+    if let ScopeFilter::In(f) = filter
+        && f.values().is_empty()
+    {
+        return Err(ScopeValidationError::EmptyValues {
+            resource: resource.resource_name().to_owned(),
+            property: f.property().to_owned(),
+        });
+    }
+    Ok(())
+}
+
+/// An [`AccessScope`] whose filters were checked against a resource's
+/// supported properties.
+///
+/// Derefs to [`AccessScope`], so it can be passed wherever `&AccessScope` is expected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidatedScope(AccessScope);
+
+impl ValidatedScope {
+    /// The checked scope.
+    #[must_use]
+    pub fn into_inner(self) -> AccessScope {
+        self.0
+    }
+}
+
+impl std::ops::Deref for ValidatedScope {
+    type Target = AccessScope;
+
+    fn deref(&self) -> &AccessScope {
+        &self.0
+    }
+}
+
+impl AsRef<AccessScope> for ValidatedScope {
+    fn as_ref(&self) -> &AccessScope {
+        &self.0
+    }
+}
+
+impl From<ValidatedScope> for AccessScope {
+    fn from(scope: ValidatedScope) -> Self {
+        scope.0
+    }
+}
+
+impl AccessScope {
+    /// Check every filter against `resource`'s supported properties.
+    ///
+    /// `allow_all()` and `deny_all()` have no filters and always pass.
+    ///
+    /// # Errors
+    /// - [`ScopeValidationError::UnknownProperty`] for a property the resource does not support
+    /// - [`ScopeValidationError::EmptyValues`] for an `In` filter without values
+    pub fn validated_for(
+        self,
+        resource: &impl ScopeResource,
+    ) -> Result<ValidatedScope, ScopeValidationError> {
+        for filter in self.constraints.iter().flat_map(ScopeConstraint::filters) {
+            check_filter(resource, filter)?;
+        }
+        Ok(ValidatedScope(self))
+    }
+}
+
+/// Builds an [`AccessScope`] for a resource, rejecting unknown properties.
+///
+/// Filters added with [`filter`](Self::filter) are AND-ed into the current
+/// constraint; [`or`](Self::or) starts a new, OR-ed constraint. The first
+/// invalid filter is reported by [`build`](Self::build).
+///
+/// ```
+/// use modkit_security::access_scope::{AccessScopeBuilder, ScopeResource, pep_properties};
+/// use uuid::Uuid;
+///
+/// struct Users;
+/// impl ScopeResource for Users {
+///     fn resource_name(&self) -> &str { "users" }
+///     fn supported_properties(&self) -> &[&str] { &[pep_properties::OWNER_TENANT_ID] }
+/// }
+///
+/// let tenant = Uuid::new_v4();
+/// let scope = AccessScopeBuilder::for_resource(&Users)
+///     .filter(pep_properties::OWNER_TENANT_ID, [tenant])
+///     .build()
+///     .unwrap();
+/// assert!(scope.contains_uuid(pep_properties::OWNER_TENANT_ID, tenant));
+///
+/// let err = AccessScopeBuilder::for_resource(&Users)
+///     .filter("owner_tenant", [tenant])
+///     .build()
+///     .unwrap_err();
+/// assert!(err.to_string().contains("supported: [owner_tenant_id]"));
+/// ```
+pub struct AccessScopeBuilder<'a, R: ScopeResource> {
+    resource: &'a R,
+    constraints: Vec<ScopeConstraint>,
+    filters: Vec<ScopeFilter>,
+    intent: ScopeIntent,
+    error: Option<ScopeValidationError>,
+}
+
+impl<'a, R: ScopeResource> AccessScopeBuilder<'a, R> {
+    /// Start an empty scope for `resource`.
+    #[must_use]
+    pub fn for_resource(resource: &'a R) -> Self {
+        Self {
+            resource,
+            constraints: Vec::new(),
+            filters: Vec::new(),
+            intent: ScopeIntent::ReadWrite,
+            error: None,
+        }
+    }
+
+    /// Restrict `property` to `values` within the current constraint.
+    #[must_use]
+    pub fn filter<V: Into<ScopeValue>>(
+        mut self,
+        property: &str,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        let filter = ScopeFilter::r#in(property, values.into_iter().map(Into::into).collect());
+        if self.error.is_none()
+            && let Err(e) = check_filter(self.resource, &filter)
+        {
+            self.error = Some(e);
+        }
+        self.filters.push(filter);
+        self
+    }
+
+    /// Close the current constraint; following filters form an alternative one.
+    #[must_use]
+    pub fn or(mut self) -> Self {
+        if !self.filters.is_empty() {
+            self.constraints
+                .push(ScopeConstraint::new(std::mem::take(&mut self.filters)));
+        }
+        self
+    }
+
+    /// Set the intent of the built scope (default: read-write).
+    #[must_use]
+    pub fn intent(mut self, intent: ScopeIntent) -> Self {
+        self.intent = intent;
+        self
+    }
+
+    /// Finish the scope. Without any filter the scope is deny-all.
+    ///
+    /// # Errors
+    /// Returns the first invalid filter's [`ScopeValidationError`].
+    pub fn build(self) -> Result<ValidatedScope, ScopeValidationError> {
+        let Self {
+            mut constraints,
+            filters,
+            intent,
+            error,
+            ..
+        } = self;
+        if let Some(e) = error {
+            return Err(e);
+        }
+        if !filters.is_empty() {
+            constraints.push(ScopeConstraint::new(filters));
+        }
+        Ok(ValidatedScope(
+            AccessScope::from_constraints(constraints).with_intent(intent),
+        ))
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -600,4 +1095,198 @@ mod tests {
         assert!(scope.contains_uuid(pep_properties::OWNER_TENANT_ID, uid(T1)));
         assert!(!scope.contains_uuid(pep_properties::OWNER_TENANT_ID, uid(T2)));
     }
+
+    // --- ScopeFilter::NotIn ---
+
+    #[test]
+    fn scope_filter_not_in_matches_everything_but_its_values() {
+        let f = ScopeFilter::not_in_uuids(pep_properties::OWNER_ID, [uid(T1)]);
+        assert_eq!(f.property(), pep_properties::OWNER_ID);
+        assert!(f.is_exclusion());
+        assert!(!f.matches(&ScopeValue::Uuid(uid(T1))));
+        assert!(f.matches(&ScopeValue::Uuid(uid(T2))));
+
+        let in_filter = ScopeFilter::in_uuids(pep_properties::OWNER_ID, vec![uid(T1)]);
+        assert!(!in_filter.is_exclusion());
+        assert!(in_filter.matches(&ScopeValue::Uuid(uid(T1))));
+    }
+
+    #[test]
+    fn not_in_values_are_not_granted() {
+        let scope = AccessScope::single(ScopeConstraint::new(vec![
+            ScopeFilter::in_uuids(pep_properties::OWNER_TENANT_ID, vec![uid(T1)]),
+            ScopeFilter::not_in_uuids(pep_properties::OWNER_TENANT_ID, [uid(T2)]),
+        ]));
+        assert_eq!(
+            scope.all_uuid_values_for(pep_properties::OWNER_TENANT_ID),
+            &[uid(T1)]
+        );
+        assert_eq!(
+            scope.all_values_for(pep_properties::OWNER_TENANT_ID),
+            vec![&ScopeValue::Uuid(uid(T1))]
+        );
+        assert!(!scope.contains_uuid(pep_properties::OWNER_TENANT_ID, uid(T2)));
+        assert!(scope.has_property(pep_properties::OWNER_TENANT_ID));
+    }
+
+    // --- JSON paths ---
+
+    #[test]
+    fn json_path_parses_column_and_keys() {
+        let path = JsonPath::parse("metadata->labels->team").unwrap();
+        assert_eq!(path.column_property(), "metadata");
+        assert_eq!(path.path(), &["labels", "team"]);
+        assert_eq!(
+            JsonPath::property("metadata", &["labels", "team"]),
+            "metadata->labels->team"
+        );
+
+        for plain in [
+            pep_properties::OWNER_TENANT_ID,
+            "metadata->",
+            "->department",
+            "metadata->a b",
+            "metadata->'x'",
+        ] {
+            assert_eq!(JsonPath::parse(plain), None, "{plain}");
+        }
+
+        let scope = AccessScope::single(ScopeConstraint::new(vec![ScopeFilter::eq(
+            "metadata->department",
+            "sales",
+        )]));
+        assert!(scope.has_json_paths());
+        assert!(!AccessScope::for_tenant(uid(T1)).has_json_paths());
+    }
+
+    #[test]
+    fn json_path_is_checked_against_its_column_property() {
+        let supported = [pep_properties::OWNER_TENANT_ID, "metadata"];
+        assert!(check_property("", &supported, "metadata->department").is_ok());
+        assert!(check_property("", &supported, "labels->department").is_err());
+        assert!(check_property("", &supported, "metadata->a b").is_err());
+    }
+
+    // --- ScopeIntent ---
+
+    #[test]
+    fn constructors_default_to_read_write() {
+        assert_eq!(
+            AccessScope::for_tenant(uid(T1)).intent(),
+            ScopeIntent::ReadWrite
+        );
+        assert_eq!(AccessScope::allow_all().intent(), ScopeIntent::ReadWrite);
+        assert!(!AccessScope::from_constraints(vec![]).is_read_only());
+        assert!(
+            AccessScope::for_tenant(uid(T1))
+                .with_intent(ScopeIntent::Read)
+                .is_read_only()
+        );
+    }
+
+    #[test]
+    fn intent_survives_serde() {
+        let json = serde_json::to_string(&ScopeIntent::Read).unwrap();
+        assert_eq!(json, "\"read\"");
+        let back: ScopeIntent = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, ScopeIntent::Read);
+    }
+
+    #[test]
+    fn intersect_ands_constraints_and_takes_stricter_intent() {
+        let tenants = AccessScope::for_tenant(uid(T1)).with_intent(ScopeIntent::Read);
+        let resources = AccessScope::for_resource(uid(T2));
+
+        let both = tenants.intersect(&resources);
+        assert!(both.is_read_only());
+        assert_eq!(both.constraints().len(), 1);
+        assert!(both.contains_uuid(pep_properties::OWNER_TENANT_ID, uid(T1)));
+        assert!(both.contains_uuid(pep_properties::RESOURCE_ID, uid(T2)));
+
+        let open = AccessScope::allow_all().intersect(&resources);
+        assert_eq!(open, resources);
+        assert!(AccessScope::deny_all().intersect(&resources).is_deny_all());
+        assert!(AccessScope::allow_all().intersect(&tenants).is_read_only());
+    }
+
+    struct Users;
+
+    impl ScopeResource for Users {
+        fn resource_name(&self) -> &str {
+            "users"
+        }
+        fn supported_properties(&self) -> &[&str] {
+            &[pep_properties::OWNER_TENANT_ID, pep_properties::RESOURCE_ID]
+        }
+    }
+
+    #[test]
+    fn builder_rejects_unknown_property() {
+        let err = AccessScopeBuilder::for_resource(&Users)
+            .filter(pep_properties::OWNER_TENANT_ID, [uid(T1)])
+            .filter("tenant_id", [uid(T2)])
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ScopeValidationError::UnknownProperty {
+                resource: "users".to_owned(),
+                property: "tenant_id".to_owned(),
+                supported: vec!["owner_tenant_id".to_owned(), "id".to_owned()],
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unknown scope property 'tenant_id' for resource users; supported: [owner_tenant_id, id]"
+        );
+    }
+
+    #[test]
+    fn builder_rejects_empty_values() {
+        let err = AccessScopeBuilder::for_resource(&Users)
+            .filter(pep_properties::RESOURCE_ID, Vec::<Uuid>::new())
+            .build()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ScopeValidationError::EmptyValues { ref property, .. } if property == "id"
+        ));
+    }
+
+    #[test]
+    fn builder_ors_constraints_and_sets_intent() {
+        let scope = AccessScopeBuilder::for_resource(&Users)
+            .filter(pep_properties::OWNER_TENANT_ID, [uid(T1)])
+            .or()
+            .filter(pep_properties::RESOURCE_ID, [uid(T2)])
+            .intent(ScopeIntent::Read)
+            .build()
+            .unwrap();
+        assert_eq!(scope.constraints().len(), 2);
+        assert!(scope.is_read_only());
+        assert!(
+            AccessScopeBuilder::for_resource(&Users)
+                .build()
+                .unwrap()
+                .is_deny_all()
+        );
+    }
+
+    #[test]
+    fn validated_for_checks_existing_scope() {
+        let scope = AccessScope::for_tenant(uid(T1))
+            .validated_for(&Users)
+            .unwrap();
+        assert_eq!(*scope, AccessScope::for_tenant(uid(T1)));
+        assert!(AccessScope::allow_all().validated_for(&Users).is_ok());
+
+        let typo = AccessScope::single(ScopeConstraint::new(vec![ScopeFilter::eq(
+            "owner_tenant",
+            uid(T1),
+        )]));
+        assert!(matches!(
+            typo.validated_for(&Users),
+            Err(ScopeValidationError::UnknownProperty { .. })
+        ));
+    }
 }
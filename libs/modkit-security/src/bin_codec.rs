@@ -1,8 +1,14 @@
 use crate::SecurityContext;
+use crate::context::SecurityContextV1;
 use postcard::Error as PostcardError;
 use thiserror::Error;
 
-pub const SECCTX_BIN_VERSION: u8 = 1;
+/// Current wire version. Version 2 adds the narrowed-context marker
+/// (see [`SecurityContext::narrowed`]).
+pub const SECCTX_BIN_VERSION: u8 = 2;
+
+/// Legacy wire version without the narrowed-context marker; still accepted by `decode_bin()`.
+const SECCTX_BIN_VERSION_V1: u8 = 1;
 
 #[derive(Debug, Error)]
 pub enum SecCtxEncodeError {
@@ -20,6 +26,9 @@ pub enum SecCtxDecodeError {
 
     #[error("security context deserialization failed: {0:?}")]
     Postcard(#[from] PostcardError),
+
+    #[error("invalid propagation token: {0}")]
+    Base64(#[from] base64::DecodeError),
 }
 
 /// Encode `SecurityContext` into a versioned binary blob using `postcard`.
@@ -39,6 +48,9 @@ pub fn encode_bin(ctx: &SecurityContext) -> Result<Vec<u8>, SecCtxEncodeError> {
 
 /// Decode `SecurityContext` from a versioned binary blob produced by `encode_bin()`.
 ///
+/// Version 1 blobs (no narrowed marker) are still accepted and decode as
+/// non-narrowed contexts.
+///
 /// # Errors
 /// Returns `SecCtxDecodeError::Empty` if the input is empty.
 /// Returns `SecCtxDecodeError::UnsupportedVersion` if the version byte is not supported.
@@ -49,13 +61,14 @@ pub fn decode_bin(bytes: &[u8]) -> Result<SecurityContext, SecCtxDecodeError> {
     }
 
     let version = bytes[0];
-    if version != SECCTX_BIN_VERSION {
-        return Err(SecCtxDecodeError::UnsupportedVersion(version));
-    }
-
     let payload = &bytes[1..];
 
-    let ctx: SecurityContext = postcard::from_bytes(payload)?;
-
-    Ok(ctx)
+    match version {
+        SECCTX_BIN_VERSION => Ok(postcard::from_bytes(payload)?),
+        SECCTX_BIN_VERSION_V1 => {
+            let legacy: SecurityContextV1 = postcard::from_bytes(payload)?;
+            Ok(legacy.into())
+        }
+        other => Err(SecCtxDecodeError::UnsupportedVersion(other)),
+    }
 }
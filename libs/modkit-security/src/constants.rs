@@ -4,7 +4,7 @@ use uuid::uuid;
 /// Default tenant ID for single-tenant or auth-disabled deployments.
 ///
 /// Used when:
-/// - Auth is disabled (on-premises single-user mode)
+/// - Auth is disabled and `auth_disabled_identity.tenant_id` is not configured
 /// - Default/fallback tenant ID is needed (e.g., migrations, examples)
 ///
 /// In multi-tenant production deployments, tenant IDs come from
@@ -14,7 +14,7 @@ pub const DEFAULT_TENANT_ID: Uuid = uuid!("00000000-df51-5b42-9538-d2b56b7ee953"
 /// Default subject ID for single-tenant or auth-disabled deployments.
 ///
 /// Used when:
-/// - Auth is disabled (on-premises single-user mode)
+/// - Auth is disabled and `auth_disabled_identity.subject_id` is not configured
 /// - Default/fallback subject ID is needed
 ///
 /// In production deployments, subject IDs come from the authentication layer.
@@ -36,6 +36,33 @@ pub struct SecurityContext {
     /// Wrapped in `SecretString` so `Debug` redacts the value automatically.
     #[serde(skip)]
     bearer_token: Option<SecretString>,
+    /// Set on contexts derived via [`SecurityContext::narrowed`]: the number of
+    /// token scopes held by the original (un-narrowed) context, kept for audit.
+    #[serde(default)]
+    narrowed_from: Option<usize>,
+}
+
+/// Wire layout of `SecurityContext` before the `narrowed_from` marker was added
+/// (`bin_codec` version 1). Only used to decode legacy blobs.
+#[derive(serde::Deserialize)]
+pub(crate) struct SecurityContextV1 {
+    subject_id: Uuid,
+    subject_type: Option<String>,
+    subject_tenant_id: Uuid,
+    token_scopes: Vec<String>,
+}
+
+impl From<SecurityContextV1> for SecurityContext {
+    fn from(v1: SecurityContextV1) -> Self {
+        Self {
+            subject_id: v1.subject_id,
+            subject_type: v1.subject_type,
+            subject_tenant_id: v1.subject_tenant_id,
+            token_scopes: v1.token_scopes,
+            bearer_token: None,
+            narrowed_from: None,
+        }
+    }
 }
 
 impl SecurityContext {
@@ -48,7 +75,8 @@ impl SecurityContext {
     /// Create an anonymous `SecurityContext` with no tenant, subject, or permissions.
     ///
     /// Use this for unauthenticated / dev / auth-disabled contexts where no
-    /// authenticated subject exists.
+    /// authenticated subject exists. It carries no token scopes, so it passes
+    /// every [`Self::has_all_scopes`] / [`Self::has_any_scope`] check.
     #[must_use]
     pub fn anonymous() -> Self {
         Self {
@@ -57,6 +85,7 @@ impl SecurityContext {
             subject_tenant_id: Uuid::default(),
             token_scopes: Vec::new(),
             bearer_token: None,
+            narrowed_from: None,
         }
     }
 
@@ -89,6 +118,80 @@ impl SecurityContext {
     pub fn bearer_token(&self) -> Option<&SecretString> {
         self.bearer_token.as_ref()
     }
+
+    /// Returns `true` if this context was derived via [`Self::narrowed`].
+    #[must_use]
+    pub fn is_narrowed(&self) -> bool {
+        self.narrowed_from.is_some()
+    }
+
+    /// Number of token scopes on the original context this one was narrowed
+    /// from, or `None` if the context is not narrowed.
+    #[must_use]
+    pub fn narrowed_from_scope_count(&self) -> Option<usize> {
+        self.narrowed_from
+    }
+
+    /// Derive a context for a delegated internal call that only carries `scopes`.
+    ///
+    /// The resulting `token_scopes` are the requested scopes this context
+    /// actually grants (see [`Self::has_all_scopes`] for matching rules), so
+    /// narrowing never widens access: requesting a scope the original lacks
+    /// simply leaves it out. The bearer token is dropped so the derived context
+    /// cannot be replayed upstream, and the original scope count is recorded.
+    ///
+    /// Unlike a regular context, a narrowed context with no scopes left is
+    /// *not* treated as unrestricted.
+    #[must_use]
+    pub fn narrowed(&self, scopes: &[&str]) -> Self {
+        let mut token_scopes: Vec<String> = Vec::with_capacity(scopes.len());
+        for scope in scopes {
+            if self.has_scope(scope) && !token_scopes.iter().any(|s| s == scope) {
+                token_scopes.push((*scope).to_owned());
+            }
+        }
+
+        Self {
+            subject_id: self.subject_id,
+            subject_type: self.subject_type.clone(),
+            subject_tenant_id: self.subject_tenant_id,
+            token_scopes,
+            bearer_token: None,
+            narrowed_from: Some(self.narrowed_from.unwrap_or(self.token_scopes.len())),
+        }
+    }
+
+    /// Returns `true` if the token grants every scope in `required`.
+    ///
+    /// A `"*"` token scope matches any requested scope. An empty token scope
+    /// list is treated as unrestricted (see `token_scopes` field docs), unless
+    /// the context was narrowed.
+    /// An empty `required` slice is always satisfied.
+    ///
+    /// Scopes restrict what a token may do; they do not prove authentication.
+    /// [`Self::anonymous`] and other contexts without scopes pass every check,
+    /// so guards that need an authenticated caller must check the subject too.
+    #[must_use]
+    pub fn has_all_scopes(&self, required: &[&str]) -> bool {
+        required.iter().all(|scope| self.has_scope(scope))
+    }
+
+    /// Returns `true` if the token grants at least one scope in `required`.
+    ///
+    /// Uses the same matching rules as [`Self::has_all_scopes`]. An empty
+    /// `required` slice is never satisfied.
+    #[must_use]
+    pub fn has_any_scope(&self, required: &[&str]) -> bool {
+        required.iter().any(|scope| self.has_scope(scope))
+    }
+
+    fn has_scope(&self, scope: &str) -> bool {
+        (self.token_scopes.is_empty() && !self.is_narrowed())
+            || self
+                .token_scopes
+                .iter()
+                .any(|granted| granted == "*" || granted == scope)
+    }
 }
 
 #[derive(Default)]
@@ -151,6 +254,7 @@ impl SecurityContextBuilder {
             subject_tenant_id,
             token_scopes: self.token_scopes,
             bearer_token: self.bearer_token,
+            narrowed_from: None,
         })
     }
 }
@@ -309,4 +413,169 @@ mod tests {
 
         assert!(ctx.token_scopes().is_empty());
     }
+
+    fn ctx_with_scopes(scopes: &[&str]) -> SecurityContext {
+        SecurityContext::builder()
+            .subject_id(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap())
+            .subject_tenant_id(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002").unwrap())
+            .token_scopes(scopes.iter().map(|s| (*s).to_owned()).collect())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_has_all_scopes_exact_match() {
+        let ctx = ctx_with_scopes(&["read:events", "write:events"]);
+
+        assert!(ctx.has_all_scopes(&["read:events"]));
+        assert!(ctx.has_all_scopes(&["read:events", "write:events"]));
+    }
+
+    #[test]
+    fn test_has_all_scopes_partial_match() {
+        let ctx = ctx_with_scopes(&["read:events"]);
+
+        assert!(!ctx.has_all_scopes(&["read:events", "write:events"]));
+        assert!(!ctx.has_all_scopes(&["write:events"]));
+    }
+
+    #[test]
+    fn test_has_any_scope_partial_match() {
+        let ctx = ctx_with_scopes(&["read:events"]);
+
+        assert!(ctx.has_any_scope(&["read:events", "write:events"]));
+        assert!(!ctx.has_any_scope(&["write:events", "admin"]));
+    }
+
+    #[test]
+    fn test_scope_checks_wildcard() {
+        let ctx = ctx_with_scopes(&["*"]);
+
+        assert!(ctx.has_all_scopes(&["read:events", "write:events", "admin"]));
+        assert!(ctx.has_any_scope(&["anything"]));
+    }
+
+    #[test]
+    fn test_scope_checks_wildcard_among_other_scopes() {
+        let ctx = ctx_with_scopes(&["read:events", "*"]);
+
+        assert!(ctx.has_all_scopes(&["write:events"]));
+        assert!(ctx.has_any_scope(&["admin"]));
+    }
+
+    #[test]
+    fn test_scope_checks_empty_token_scopes_unrestricted() {
+        let ctx = ctx_with_scopes(&[]);
+
+        assert!(ctx.has_all_scopes(&["read:events", "write:events"]));
+        assert!(ctx.has_any_scope(&["read:events"]));
+    }
+
+    #[test]
+    fn test_scope_checks_anonymous_context_unrestricted() {
+        let ctx = SecurityContext::anonymous();
+
+        assert!(ctx.token_scopes().is_empty());
+        assert!(ctx.has_all_scopes(&["admin", "write:events"]));
+        assert!(ctx.has_any_scope(&["admin"]));
+        assert!(!ctx.narrowed(&["admin"]).has_any_scope(&["write:events"]));
+    }
+
+    #[test]
+    fn test_scope_checks_empty_required() {
+        let ctx = ctx_with_scopes(&["read:events"]);
+
+        assert!(ctx.has_all_scopes(&[]));
+        assert!(!ctx.has_any_scope(&[]));
+    }
+
+    #[test]
+    fn test_scope_checks_no_prefix_matching() {
+        let ctx = ctx_with_scopes(&["read"]);
+
+        assert!(!ctx.has_all_scopes(&["read:events"]));
+        assert!(!ctx.has_any_scope(&["read:events"]));
+    }
+
+    #[test]
+    fn test_narrowed_intersects_scopes() {
+        let ctx = ctx_with_scopes(&["read:events", "write:events", "admin"]);
+
+        let narrowed = ctx.narrowed(&["read:events", "admin"]);
+
+        assert_eq!(narrowed.token_scopes(), &["read:events", "admin"]);
+        assert_eq!(narrowed.subject_id(), ctx.subject_id());
+        assert_eq!(narrowed.subject_tenant_id(), ctx.subject_tenant_id());
+        assert!(narrowed.is_narrowed());
+        assert_eq!(narrowed.narrowed_from_scope_count(), Some(3));
+        assert!(!ctx.is_narrowed());
+    }
+
+    #[test]
+    fn test_narrowed_drops_scopes_original_lacks() {
+        let ctx = ctx_with_scopes(&["read:events"]);
+
+        let narrowed = ctx.narrowed(&["read:events", "write:events", "*"]);
+
+        assert_eq!(narrowed.token_scopes(), &["read:events"]);
+        assert!(!narrowed.has_all_scopes(&["write:events"]));
+    }
+
+    #[test]
+    fn test_narrowed_from_wildcard_yields_requested_scopes() {
+        let ctx = ctx_with_scopes(&["*"]);
+
+        let narrowed = ctx.narrowed(&["read:events", "read:events"]);
+
+        assert_eq!(narrowed.token_scopes(), &["read:events"]);
+        assert!(!narrowed.has_any_scope(&["admin"]));
+    }
+
+    #[test]
+    fn test_narrowed_to_nothing_is_not_unrestricted() {
+        let ctx = ctx_with_scopes(&["read:events"]);
+
+        let narrowed = ctx.narrowed(&["admin"]);
+
+        assert!(narrowed.token_scopes().is_empty());
+        assert!(!narrowed.has_any_scope(&["read:events"]));
+        assert!(!narrowed.has_all_scopes(&["admin"]));
+    }
+
+    #[test]
+    fn test_narrowed_strips_bearer_token() {
+        let ctx = SecurityContext::builder()
+            .subject_id(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap())
+            .subject_tenant_id(Uuid::parse_str("550e8400-e29b-41d4-a716-446655440002").unwrap())
+            .token_scopes(vec!["*".to_owned()])
+            .bearer_token("secret".to_owned())
+            .build()
+            .unwrap();
+
+        let narrowed = ctx.narrowed(&["read:events"]);
+
+        assert!(narrowed.bearer_token().is_none());
+        assert!(ctx.bearer_token().is_some());
+    }
+
+    #[test]
+    fn test_narrowed_twice_keeps_original_count() {
+        let ctx = ctx_with_scopes(&["a", "b", "c", "d"]);
+
+        let narrowed = ctx.narrowed(&["a", "b"]).narrowed(&["a"]);
+
+        assert_eq!(narrowed.token_scopes(), &["a"]);
+        assert_eq!(narrowed.narrowed_from_scope_count(), Some(4));
+    }
+
+    #[test]
+    fn test_narrowed_marker_survives_json_round_trip() {
+        let narrowed = ctx_with_scopes(&["read:events"]).narrowed(&["admin"]);
+
+        let json = serde_json::to_string(&narrowed).unwrap();
+        let restored: SecurityContext = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.narrowed_from_scope_count(), Some(1));
+        assert!(!restored.has_any_scope(&["admin"]));
+    }
 }
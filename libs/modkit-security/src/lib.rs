@@ -4,13 +4,21 @@ pub mod bin_codec;
 pub mod constants;
 pub mod context;
 pub mod prelude;
+pub mod propagation;
+pub mod redact;
 
 pub use access_scope::{
-    AccessScope, EqScopeFilter, InScopeFilter, ScopeConstraint, ScopeFilter, ScopeValue,
-    pep_properties,
+    AccessScope, AccessScopeBuilder, EqScopeFilter, InScopeFilter, JsonPath, NotInScopeFilter,
+    ScopeConstraint, ScopeFilter, ScopeIntent, ScopeResource, ScopeValidationError, ScopeValue,
+    ValidatedScope, pep_properties,
 };
 pub use context::{SecurityContext, SecurityContextBuildError};
 
 pub use bin_codec::{
     SECCTX_BIN_VERSION, SecCtxDecodeError, SecCtxEncodeError, decode_bin, encode_bin,
 };
+pub use propagation::{PropagationToken, SecurityContextPropagator};
+pub use redact::{Masked, RedactField, Redactable, Redacted};
+
+/// Derives [`Redactable`](trait@Redactable); see [`redact`].
+pub use modkit_macros::Redactable;
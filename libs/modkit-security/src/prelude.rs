@@ -1,4 +1,4 @@
 pub use crate::{
-    AccessScope, EqScopeFilter, InScopeFilter, ScopeConstraint, ScopeFilter, ScopeValue,
-    SecurityContext, access_scope::pep_properties,
+    AccessScope, EqScopeFilter, InScopeFilter, NotInScopeFilter, ScopeConstraint, ScopeFilter,
+    ScopeIntent, ScopeValue, SecurityContext, access_scope::pep_properties,
 };
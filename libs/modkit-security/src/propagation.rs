@@ -0,0 +1,92 @@
+//! Carrying a [`SecurityContext`] across Tokio task boundaries.
+//!
+//! `tokio::spawn` does not inherit task-local state, so a background task that
+//! needs the caller's context has to receive it explicitly. A
+//! [`PropagationToken`] is an owned, `Send + 'static` string built from
+//! [`encode_bin`] that can be moved into the task and turned back into a
+//! context there:
+//!
+//! ```
+//! use modkit_security::{SecurityContext, SecurityContextPropagator};
+//!
+//! # fn demo(ctx: &SecurityContext) -> anyhow::Result<()> {
+//! let token = SecurityContextPropagator::capture(ctx)?;
+//! std::thread::spawn(move || {
+//!     let ctx = SecurityContextPropagator::restore(token.as_str()).expect("valid token");
+//!     // ... emit an audit event as `ctx.subject_id()`
+//! #   let _ = ctx;
+//! });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The token is an encoding, not a credential: it is neither signed nor
+//! encrypted. Only restore tokens produced inside the same process, never
+//! ones received from a client or another service. The bearer token is not
+//! carried (see [`SecurityContext::bearer_token`]).
+
+use std::fmt;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+use crate::SecurityContext;
+use crate::bin_codec::{SecCtxDecodeError, SecCtxEncodeError, decode_bin, encode_bin};
+
+/// Opaque, base64-encoded [`SecurityContext`] produced by
+/// [`SecurityContextPropagator::capture`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct PropagationToken(String);
+
+impl PropagationToken {
+    /// The encoded token.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Reconstruct the captured context.
+    ///
+    /// # Errors
+    /// Same as [`SecurityContextPropagator::restore`].
+    pub fn restore(&self) -> Result<SecurityContext, SecCtxDecodeError> {
+        SecurityContextPropagator::restore(&self.0)
+    }
+}
+
+impl fmt::Display for PropagationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// Keeps identities out of logs that print the surrounding value.
+impl fmt::Debug for PropagationToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PropagationToken")
+            .field(&"<opaque>")
+            .finish()
+    }
+}
+
+/// Converts a [`SecurityContext`] to and from a [`PropagationToken`].
+pub struct SecurityContextPropagator;
+
+impl SecurityContextPropagator {
+    /// Encode `ctx` into a token that can be moved into a spawned task.
+    ///
+    /// # Errors
+    /// Returns `SecCtxEncodeError` if the context cannot be serialized.
+    pub fn capture(ctx: &SecurityContext) -> Result<PropagationToken, SecCtxEncodeError> {
+        Ok(PropagationToken(URL_SAFE_NO_PAD.encode(encode_bin(ctx)?)))
+    }
+
+    /// Reconstruct a context from a token produced by [`Self::capture`].
+    ///
+    /// # Errors
+    /// Returns `SecCtxDecodeError::Base64` if `token` is not valid base64, or
+    /// any error of [`decode_bin`] for the decoded bytes.
+    pub fn restore(token: &str) -> Result<SecurityContext, SecCtxDecodeError> {
+        decode_bin(&URL_SAFE_NO_PAD.decode(token)?)
+    }
+}
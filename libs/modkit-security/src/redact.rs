@@ -0,0 +1,249 @@
+//! Redacted views of entity models for logging.
+//!
+//! Logging a model with `?user` dumps emails and addresses into the logs.
+//! Types deriving [`Redactable`](macro@crate::Redactable) get a generated view
+//! type in which the listed fields are masked, and [`Redacted`] formats that
+//! view so call sites stay one-liners:
+//!
+//! ```
+//! use modkit_security::{Redactable, Redacted};
+//!
+//! #[derive(Redactable)]
+//! #[redact(email = "hash", street)]
+//! struct Contact {
+//!     name: String,
+//!     email: String,
+//!     street: Option<String>,
+//! }
+//!
+//! let contact = Contact {
+//!     name: "Ada".to_owned(),
+//!     email: "ada@example.com".to_owned(),
+//!     street: Some("Main St 1".to_owned()),
+//! };
+//! let line = format!("{:?}", Redacted(&contact));
+//! assert!(line.contains("<redacted len=9>"));
+//! assert!(!line.contains("ada@example.com"));
+//! ```
+//!
+//! Field modes in `#[redact(...)]`:
+//! - `field` or `field = "mask"`: a shape hint ([`Masked`]) instead of the value
+//! - `field = "hash"`: a short stable hash, so log lines can be correlated
+//! - `field = "nested"`: the field's own [`Redactable`] view
+//!
+//! Fields not listed are logged as is and must implement `Serialize` and `Debug`.
+//! Masking and hashing are provided through [`RedactField`].
+
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+use uuid::Uuid;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
+
+/// A type with a serializable view in which sensitive fields are masked.
+///
+/// Usually derived with `#[derive(Redactable)]`.
+pub trait Redactable {
+    /// The redacted view, borrowing the fields that are logged as is.
+    type RedactedView<'a>: Serialize + fmt::Debug
+    where
+        Self: 'a;
+
+    /// Returns the redacted view of `self`.
+    fn redacted(&self) -> Self::RedactedView<'_>;
+}
+
+impl<T: Redactable> Redactable for Option<T> {
+    type RedactedView<'a>
+        = Option<T::RedactedView<'a>>
+    where
+        T: 'a;
+
+    fn redacted(&self) -> Self::RedactedView<'_> {
+        self.as_ref().map(Redactable::redacted)
+    }
+}
+
+impl<T: Redactable> Redactable for Vec<T> {
+    type RedactedView<'a>
+        = Vec<T::RedactedView<'a>>
+    where
+        T: 'a;
+
+    fn redacted(&self) -> Self::RedactedView<'_> {
+        self.iter().map(Redactable::redacted).collect()
+    }
+}
+
+/// Placeholder logged in place of a redacted value.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Masked {
+    /// A string of `len` characters.
+    Str { len: usize },
+    /// A UUID.
+    Uuid,
+    /// A value without a shape hint.
+    Opaque,
+    /// Short stable hash of the value (see [`RedactField::hash`]).
+    Hash(String),
+}
+
+impl fmt::Display for Masked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Masked::Str { len } => write!(f, "<redacted len={len}>"),
+            Masked::Uuid => f.write_str("<redacted uuid>"),
+            Masked::Opaque => f.write_str("<redacted>"),
+            Masked::Hash(hash) => write!(f, "<hash {hash}>"),
+        }
+    }
+}
+
+impl fmt::Debug for Masked {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Serialize for Masked {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl Masked {
+    /// Hashes `bytes` into [`Masked::Hash`].
+    ///
+    /// FNV-1a, so the hash is the same across processes and releases; the first
+    /// 8 hex digits are kept. This is for correlating log lines, not a secure
+    /// pseudonym: low-entropy values can be recovered by brute force.
+    #[must_use]
+    pub fn hash_of(bytes: &[u8]) -> Self {
+        const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let hash = bytes
+            .iter()
+            .fold(OFFSET, |h, b| (h ^ u64::from(*b)).wrapping_mul(PRIME));
+        let mut hex = format!("{hash:016x}");
+        hex.truncate(8);
+        Masked::Hash(hex)
+    }
+}
+
+/// A field value that can be masked or hashed in a redacted view.
+pub trait RedactField {
+    /// What the field becomes in the view.
+    type Output: Serialize + fmt::Debug;
+
+    /// Replaces the value with a shape hint.
+    fn mask(&self) -> Self::Output;
+
+    /// Replaces the value with a short stable hash.
+    fn hash(&self) -> Self::Output;
+}
+
+impl RedactField for String {
+    type Output = Masked;
+
+    fn mask(&self) -> Masked {
+        Masked::Str {
+            len: self.chars().count(),
+        }
+    }
+
+    fn hash(&self) -> Masked {
+        Masked::hash_of(self.as_bytes())
+    }
+}
+
+impl RedactField for Uuid {
+    type Output = Masked;
+
+    fn mask(&self) -> Masked {
+        Masked::Uuid
+    }
+
+    fn hash(&self) -> Masked {
+        Masked::hash_of(self.as_bytes())
+    }
+}
+
+impl<T: RedactField> RedactField for Option<T> {
+    type Output = Option<T::Output>;
+
+    fn mask(&self) -> Self::Output {
+        self.as_ref().map(RedactField::mask)
+    }
+
+    fn hash(&self) -> Self::Output {
+        self.as_ref().map(RedactField::hash)
+    }
+}
+
+impl<T: RedactField> RedactField for Vec<T> {
+    type Output = Vec<T::Output>;
+
+    fn mask(&self) -> Self::Output {
+        self.iter().map(RedactField::mask).collect()
+    }
+
+    fn hash(&self) -> Self::Output {
+        self.iter().map(RedactField::hash).collect()
+    }
+}
+
+/// Formats a value through its redacted view.
+///
+/// `Debug` prints the view's `Debug` output and `Display` prints it as JSON,
+/// so either `tracing` sigil works: `tracing::debug!(user = ?Redacted(&user))`.
+pub struct Redacted<'a, T>(pub &'a T);
+
+impl<T: Redactable> fmt::Debug for Redacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0.redacted(), f)
+    }
+}
+
+impl<T: Redactable> fmt::Display for Redacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let json = serde_json::to_string(&self.0.redacted()).map_err(|_| fmt::Error)?;
+        f.write_str(&json)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn masks_carry_shape_hints() {
+        assert_eq!("jürgen".to_owned().mask(), Masked::Str { len: 6 });
+        assert_eq!(Uuid::new_v4().mask(), Masked::Uuid);
+        assert_eq!(None::<String>.mask(), None);
+        assert_eq!(Masked::Str { len: 6 }.to_string(), "<redacted len=6>");
+        assert_eq!(
+            serde_json::to_value(Masked::Uuid).unwrap(),
+            serde_json::json!("<redacted uuid>")
+        );
+    }
+
+    #[test]
+    fn hash_is_short_and_stable() {
+        // FNV-1a test vector: "a" -> af63dc4c8601ec8c
+        assert_eq!(Masked::hash_of(b"a"), Masked::Hash("af63dc4c".to_owned()));
+        assert_eq!(
+            "a@example.com".to_owned().hash(),
+            "a@example.com".to_owned().hash()
+        );
+        assert_ne!(
+            "a@example.com".to_owned().hash(),
+            "b@example.com".to_owned().hash()
+        );
+    }
+}
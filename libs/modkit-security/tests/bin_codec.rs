@@ -42,3 +42,54 @@ fn decode_rejects_unknown_version() {
         "expected version error, got: {message}"
     );
 }
+
+#[test]
+#[allow(clippy::unreadable_literal)] // UUID hex patterns are intentionally repeating
+fn round_trips_narrowed_marker() {
+    let ctx = SecurityContext::builder()
+        .subject_id(Uuid::from_u128(0xdeadbeefdeadbeefdeadbeefdeadbeef))
+        .subject_tenant_id(Uuid::from_u128(0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb))
+        .token_scopes(vec!["admin".to_owned(), "read:events".to_owned()])
+        .bearer_token("secret".to_owned())
+        .build()
+        .unwrap();
+
+    let narrowed = ctx.narrowed(&["read:events"]);
+    let decoded = decode_bin(&encode_bin(&narrowed).unwrap()).unwrap();
+
+    assert!(decoded.is_narrowed());
+    assert_eq!(decoded.narrowed_from_scope_count(), Some(2));
+    assert_eq!(decoded.token_scopes(), &["read:events"]);
+    assert!(decoded.bearer_token().is_none());
+
+    let plain = decode_bin(&encode_bin(&ctx).unwrap()).unwrap();
+    assert!(!plain.is_narrowed());
+}
+
+#[test]
+#[allow(clippy::unreadable_literal)] // UUID hex patterns are intentionally repeating
+fn decodes_legacy_v1_payload_as_not_narrowed() {
+    #[derive(serde::Serialize)]
+    struct LegacyCtx {
+        subject_id: Uuid,
+        subject_type: Option<String>,
+        subject_tenant_id: Uuid,
+        token_scopes: Vec<String>,
+    }
+
+    let legacy = LegacyCtx {
+        subject_id: Uuid::from_u128(0xdeadbeefdeadbeefdeadbeefdeadbeef),
+        subject_type: Some("user".to_owned()),
+        subject_tenant_id: Uuid::from_u128(0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb),
+        token_scopes: vec!["read:events".to_owned()],
+    };
+    let mut blob = vec![1_u8];
+    blob.extend(postcard::to_allocvec(&legacy).unwrap());
+
+    let decoded = decode_bin(&blob).expect("v1 blob decodes");
+
+    assert_eq!(decoded.subject_id(), legacy.subject_id);
+    assert_eq!(decoded.subject_type(), Some("user"));
+    assert_eq!(decoded.token_scopes(), &["read:events"]);
+    assert!(!decoded.is_narrowed());
+}
@@ -0,0 +1,54 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_security::{SecCtxDecodeError, SecurityContext, SecurityContextPropagator};
+use uuid::Uuid;
+
+fn context() -> SecurityContext {
+    SecurityContext::builder()
+        .subject_id(Uuid::new_v4())
+        .subject_tenant_id(Uuid::new_v4())
+        .token_scopes(vec!["audit:write".to_owned()])
+        .bearer_token("secret-token".to_owned())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn context_survives_a_spawned_task() {
+    let ctx = context();
+    let token = SecurityContextPropagator::capture(&ctx).unwrap();
+
+    let restored = tokio::spawn(async move { token.restore().unwrap() })
+        .await
+        .unwrap();
+
+    assert_eq!(restored.subject_id(), ctx.subject_id());
+    assert_eq!(restored.subject_tenant_id(), ctx.subject_tenant_id());
+    assert_eq!(restored.token_scopes(), ctx.token_scopes());
+    assert!(restored.bearer_token().is_none());
+}
+
+#[test]
+fn narrowed_marker_is_preserved() {
+    let ctx = context().narrowed(&["audit:write"]);
+    let token = SecurityContextPropagator::capture(&ctx).unwrap();
+
+    let restored = SecurityContextPropagator::restore(token.as_str()).unwrap();
+    assert!(restored.is_narrowed());
+    assert_eq!(restored.narrowed_from_scope_count(), Some(1));
+}
+
+#[test]
+fn restore_rejects_invalid_tokens() {
+    let err = SecurityContextPropagator::restore("not base64!").unwrap_err();
+    assert!(matches!(err, SecCtxDecodeError::Base64(_)), "{err:?}");
+
+    let err = SecurityContextPropagator::restore("").unwrap_err();
+    assert!(matches!(err, SecCtxDecodeError::Empty), "{err:?}");
+}
+
+#[test]
+fn debug_does_not_expose_the_token() {
+    let token = SecurityContextPropagator::capture(&context()).unwrap();
+    assert_eq!(format!("{token:?}"), r#"PropagationToken("<opaque>")"#);
+}
@@ -0,0 +1,109 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use modkit_security::{Masked, Redactable, Redacted};
+use serde_json::json;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Redactable)]
+#[redact(street, postal_code = "hash")]
+struct Address {
+    city: String,
+    street: String,
+    postal_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Redactable)]
+#[redact(id, email = "hash", nickname, address = "nested", previous = "nested")]
+struct Person {
+    id: Uuid,
+    email: String,
+    nickname: Option<String>,
+    age: u32,
+    address: Option<Address>,
+    previous: Vec<Address>,
+}
+
+fn address() -> Address {
+    Address {
+        city: "Berlin".to_owned(),
+        street: "Unter den Linden 1".to_owned(),
+        postal_code: Some("10117".to_owned()),
+    }
+}
+
+fn person() -> Person {
+    Person {
+        id: Uuid::new_v4(),
+        email: "ada@example.com".to_owned(),
+        nickname: None,
+        age: 36,
+        address: Some(address()),
+        previous: vec![address()],
+    }
+}
+
+#[test]
+fn masked_fields_keep_shape_hints() {
+    let person = person();
+    let view = serde_json::to_value(person.redacted()).unwrap();
+
+    assert_eq!(view["id"], json!("<redacted uuid>"));
+    assert_eq!(view["age"], json!(36));
+    assert_eq!(view["address"]["street"], json!("<redacted len=18>"));
+    assert_eq!(view["address"]["city"], json!("Berlin"));
+    assert!(view.get("__lifetime").is_none());
+}
+
+#[test]
+fn option_fields_stay_optional() {
+    let mut person = person();
+    let view = serde_json::to_value(person.redacted()).unwrap();
+    assert_eq!(view["nickname"], json!(null));
+
+    person.nickname = Some("countess".to_owned());
+    person.address = None;
+    let view = serde_json::to_value(person.redacted()).unwrap();
+    assert_eq!(view["nickname"], json!("<redacted len=8>"));
+    assert_eq!(view["address"], json!(null));
+}
+
+#[test]
+fn nested_views_are_redacted() {
+    let person = person();
+    let debug = format!("{:?}", Redacted(&person));
+
+    assert!(debug.starts_with("PersonRedacted {"));
+    assert!(debug.contains("AddressRedacted {"));
+    assert!(!debug.contains("Unter den Linden"));
+    assert!(!debug.contains("10117"));
+    assert!(!debug.contains("ada@example.com"));
+
+    let json: serde_json::Value = serde_json::from_str(&Redacted(&person).to_string()).unwrap();
+    assert_eq!(json["previous"][0]["street"], json!("<redacted len=18>"));
+}
+
+#[test]
+fn hash_mode_is_deterministic() {
+    let a = person();
+    let b = Person {
+        id: Uuid::new_v4(),
+        ..a.clone()
+    };
+    let other = Person {
+        email: "grace@example.com".to_owned(),
+        ..a.clone()
+    };
+
+    let hash = |p: &Person| serde_json::to_value(p.redacted()).unwrap()["email"].clone();
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&other));
+
+    let Masked::Hash(expected) = Masked::hash_of(b"ada@example.com") else {
+        panic!("expected a hash");
+    };
+    assert_eq!(hash(&a), json!(format!("<hash {expected}>")));
+    assert_eq!(
+        a.redacted().address.unwrap().postal_code,
+        Some(Masked::hash_of(b"10117"))
+    );
+}
@@ -0,0 +1,67 @@
+//! Minimal admin channel client, used by tests and operator tooling.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+
+use super::{AdminError, AdminRequest, AdminResponse};
+
+/// Error returned by [`call`].
+#[derive(Debug, thiserror::Error)]
+pub enum AdminClientError {
+    /// Could not connect to or talk over the socket.
+    #[error("admin socket I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The server sent something that is not a valid response.
+    #[error("invalid admin response: {0}")]
+    Protocol(String),
+
+    /// The command ran and returned an error envelope.
+    #[error(transparent)]
+    Command(AdminError),
+}
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Run a single admin command against the socket at `socket` and return its result.
+///
+/// # Errors
+/// - [`AdminClientError::Io`] if the socket is unreachable
+/// - [`AdminClientError::Protocol`] if the response cannot be decoded
+/// - [`AdminClientError::Command`] if the command returned an error
+pub async fn call(socket: &Path, command: &str, args: Value) -> Result<Value, AdminClientError> {
+    let stream = UnixStream::connect(socket).await?;
+    let (reader, mut writer) = stream.into_split();
+
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let request = AdminRequest {
+        jsonrpc: Some("2.0".to_owned()),
+        id: Value::from(id),
+        method: command.to_owned(),
+        params: args,
+    };
+    let mut line =
+        serde_json::to_vec(&request).map_err(|e| AdminClientError::Protocol(e.to_string()))?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+
+    let mut response = String::new();
+    BufReader::new(reader).read_line(&mut response).await?;
+    if response.is_empty() {
+        return Err(AdminClientError::Protocol(
+            "connection closed before a response was received".to_owned(),
+        ));
+    }
+    let response: AdminResponse =
+        serde_json::from_str(&response).map_err(|e| AdminClientError::Protocol(e.to_string()))?;
+
+    // `"result": null` deserializes as `None`
+    match response.error {
+        Some(error) => Err(AdminClientError::Command(error)),
+        None => Ok(response.result.unwrap_or(Value::Null)),
+    }
+}
@@ -0,0 +1,45 @@
+//! Resolved module dependency graph of a running process.
+
+use serde::{Deserialize, Serialize};
+
+use super::ModuleState;
+
+/// A module in the [`ModuleGraph`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleNode {
+    pub name: String,
+    /// Modules this one declares in `deps = [...]`.
+    pub deps: Vec<String>,
+    pub capabilities: Vec<String>,
+    pub state: ModuleState,
+    /// Wall time spent in `init()`; absent until init has run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub init_duration_ms: Option<u64>,
+}
+
+/// Every registered module with its declared dependencies, in init order.
+///
+/// Built by [`RuntimeIntrospection::graph`](super::RuntimeIntrospection::graph)
+/// and served by the `modules.graph` admin command.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleGraph {
+    pub modules: Vec<ModuleNode>,
+}
+
+impl ModuleGraph {
+    /// The node for `name`, if registered.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ModuleNode> {
+        self.modules.iter().find(|m| m.name == name)
+    }
+
+    /// Names of the modules that declare a dependency on `name`.
+    #[must_use]
+    pub fn dependents(&self, name: &str) -> Vec<&str> {
+        self.modules
+            .iter()
+            .filter(|m| m.deps.iter().any(|d| d == name))
+            .map(|m| m.name.as_str())
+            .collect()
+    }
+}
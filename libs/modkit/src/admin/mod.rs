@@ -0,0 +1,544 @@
+//! Operator administration channel.
+//!
+//! A line-delimited JSON-RPC server on a local unix domain socket lets operators
+//! act on a running process (reset a plugin selector, dump runtime state)
+//! without exposing those actions over HTTP. The channel is disabled unless
+//! `server.admin_socket` is configured.
+//!
+//! Modules contribute commands from `init()`:
+//!
+//! ```rust,ignore
+//! let svc = Arc::clone(&svc);
+//! ctx.register_admin_command("authn.reset-selector", move |_args| {
+//!     let svc = Arc::clone(&svc);
+//!     async move { Ok(serde_json::json!({ "reset": svc.reset_selector().await })) }
+//! })?;
+//! ```
+//!
+//! The runtime provides built-in commands:
+//! - `modules.list` — registered modules with their dependencies and capabilities
+//! - `modules.states` — lifecycle state of every module
+//! - `modules.graph` — dependency graph with states and init durations ([`ModuleGraph`])
+//! - `routes.list` — REST operations registered by each module
+//!
+//! # Wire format
+//!
+//! One JSON object per line in each direction:
+//!
+//! ```text
+//! -> {"jsonrpc":"2.0","id":1,"method":"modules.states","params":{}}
+//! <- {"jsonrpc":"2.0","id":1,"result":{"authn-resolver":"started"}}
+//! <- {"jsonrpc":"2.0","id":2,"error":{"code":-32601,"message":"unknown admin command 'nope'"}}
+//! ```
+
+#[cfg(unix)]
+pub mod client;
+mod graph;
+#[cfg(unix)]
+mod server;
+
+pub use graph::{ModuleGraph, ModuleNode};
+#[cfg(unix)]
+pub use server::{MAX_REQUEST_LINE, serve};
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::openapi::RefOr;
+use utoipa::openapi::schema::Schema;
+
+use crate::api::OpenApiRegistry;
+use crate::api::operation_builder::{OperationSpec, ParamSpec, ResponseSpec};
+use crate::registry::ModuleRegistry;
+
+/// Result of an admin command.
+pub type AdminResult = Result<Value, AdminError>;
+
+/// Type-erased admin command handler.
+pub type AdminHandler =
+    Arc<dyn Fn(Value) -> Pin<Box<dyn Future<Output = AdminResult> + Send>> + Send + Sync>;
+
+/// Standard error envelope returned for failed admin requests.
+///
+/// Codes follow JSON-RPC 2.0; handlers normally use [`AdminError::invalid_args`]
+/// or [`AdminError::internal`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, thiserror::Error)]
+#[error("admin error {code}: {message}")]
+pub struct AdminError {
+    pub code: i64,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl AdminError {
+    /// The request line is not valid JSON.
+    pub const PARSE_ERROR: i64 = -32700;
+    /// The request is JSON but not a valid request object.
+    pub const INVALID_REQUEST: i64 = -32600;
+    /// No command is registered under the requested name.
+    pub const UNKNOWN_COMMAND: i64 = -32601;
+    /// The command rejected its arguments.
+    pub const INVALID_ARGS: i64 = -32602;
+    /// The command failed while executing.
+    pub const INTERNAL: i64 = -32603;
+
+    #[must_use]
+    pub fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    #[must_use]
+    pub fn unknown_command(command: &str) -> Self {
+        Self::new(
+            Self::UNKNOWN_COMMAND,
+            format!("unknown admin command '{command}'"),
+        )
+    }
+
+    #[must_use]
+    pub fn invalid_args(message: impl Into<String>) -> Self {
+        Self::new(Self::INVALID_ARGS, message)
+    }
+
+    #[must_use]
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(Self::INTERNAL, message)
+    }
+}
+
+impl From<anyhow::Error> for AdminError {
+    fn from(e: anyhow::Error) -> Self {
+        Self::internal(format!("{e:#}"))
+    }
+}
+
+/// Commands available on the admin channel, keyed by name.
+///
+/// Shared by every `ModuleCtx` built by the runtime; see
+/// [`ModuleCtx::register_admin_command`](crate::context::ModuleCtx::register_admin_command).
+#[derive(Default)]
+pub struct AdminCommandRegistry {
+    commands: RwLock<BTreeMap<String, AdminHandler>>,
+}
+
+impl AdminCommandRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a command handler.
+    ///
+    /// # Errors
+    /// Returns an error if a command with the same name is already registered.
+    pub fn register<F, Fut>(&self, name: &str, handler: F) -> anyhow::Result<()>
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AdminResult> + Send + 'static,
+    {
+        let mut commands = self.commands.write();
+        if commands.contains_key(name) {
+            anyhow::bail!("admin command '{name}' is already registered");
+        }
+        commands.insert(name.to_owned(), Self::erase(handler));
+        tracing::debug!(command = name, "Registered admin command");
+        Ok(())
+    }
+
+    /// Insert or replace a command without the duplicate check.
+    fn insert<F, Fut>(&self, name: &str, handler: F)
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AdminResult> + Send + 'static,
+    {
+        self.commands
+            .write()
+            .insert(name.to_owned(), Self::erase(handler));
+    }
+
+    fn erase<F, Fut>(handler: F) -> AdminHandler
+    where
+        F: Fn(Value) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AdminResult> + Send + 'static,
+    {
+        Arc::new(move |args| Box::pin(handler(args)))
+    }
+
+    /// Names of all registered commands, sorted.
+    #[must_use]
+    pub fn command_names(&self) -> Vec<String> {
+        self.commands.read().keys().cloned().collect()
+    }
+
+    /// Run the named command with `args`.
+    ///
+    /// # Errors
+    /// Returns [`AdminError::UNKNOWN_COMMAND`] if the command is not registered,
+    /// or the handler's own error.
+    pub async fn dispatch(&self, command: &str, args: Value) -> AdminResult {
+        let handler = self
+            .commands
+            .read()
+            .get(command)
+            .cloned()
+            .ok_or_else(|| AdminError::unknown_command(command))?;
+        handler(args).await
+    }
+}
+
+/// Lifecycle state of a module as tracked by the runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModuleState {
+    Registered,
+    Initialized,
+    Started,
+    Stopped,
+    Failed,
+}
+
+/// A registered module as reported by `modules.list`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleSummary {
+    pub name: String,
+    pub deps: Vec<String>,
+    pub capabilities: Vec<String>,
+}
+
+/// A REST operation as reported by `routes.list`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteSummary {
+    pub module: String,
+    pub method: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation_id: Option<String>,
+}
+
+/// Runtime state exposed through the built-in admin commands.
+///
+/// Owned by `HostRuntime`, which records lifecycle transitions and REST
+/// registrations as phases run. System modules receive it through
+/// [`SystemContext`](crate::runtime::SystemContext).
+#[derive(Default)]
+pub struct RuntimeIntrospection {
+    modules: RwLock<Vec<ModuleSummary>>,
+    states: RwLock<BTreeMap<String, ModuleState>>,
+    init_durations: RwLock<BTreeMap<String, Duration>>,
+    routes: RwLock<Vec<RouteSummary>>,
+}
+
+impl RuntimeIntrospection {
+    /// Introspection for the modules of `registry`, all in `Registered` state.
+    #[must_use]
+    pub fn from_registry(registry: &ModuleRegistry) -> Self {
+        let this = Self::default();
+        this.set_modules(
+            registry
+                .modules()
+                .iter()
+                .map(|e| ModuleSummary {
+                    name: e.name().to_owned(),
+                    deps: e.deps().iter().map(|d| (*d).to_owned()).collect(),
+                    capabilities: e.caps().labels().into_iter().map(str::to_owned).collect(),
+                })
+                .collect(),
+        );
+        this
+    }
+
+    pub(crate) fn set_modules(&self, modules: Vec<ModuleSummary>) {
+        let mut states = self.states.write();
+        for m in &modules {
+            states.insert(m.name.clone(), ModuleState::Registered);
+        }
+        *self.modules.write() = modules;
+    }
+
+    /// Record a lifecycle transition of `module`.
+    pub fn set_state(&self, module: &str, state: ModuleState) {
+        self.states.write().insert(module.to_owned(), state);
+    }
+
+    /// Record how long `init()` of `module` took.
+    pub fn record_init_duration(&self, module: &str, duration: Duration) {
+        self.init_durations
+            .write()
+            .insert(module.to_owned(), duration);
+    }
+
+    pub(crate) fn add_route(&self, route: RouteSummary) {
+        self.routes.write().push(route);
+    }
+
+    #[must_use]
+    pub fn modules(&self) -> Vec<ModuleSummary> {
+        self.modules.read().clone()
+    }
+
+    #[must_use]
+    pub fn states(&self) -> BTreeMap<String, ModuleState> {
+        self.states.read().clone()
+    }
+
+    #[must_use]
+    pub fn routes(&self) -> Vec<RouteSummary> {
+        self.routes.read().clone()
+    }
+
+    /// Snapshot of the dependency graph, in init order.
+    #[must_use]
+    pub fn graph(&self) -> ModuleGraph {
+        let states = self.states.read();
+        let durations = self.init_durations.read();
+        let modules = self
+            .modules
+            .read()
+            .iter()
+            .map(|m| ModuleNode {
+                name: m.name.clone(),
+                deps: m.deps.clone(),
+                capabilities: m.capabilities.clone(),
+                state: states
+                    .get(&m.name)
+                    .copied()
+                    .unwrap_or(ModuleState::Registered),
+                init_duration_ms: durations
+                    .get(&m.name)
+                    .map(|d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX)),
+            })
+            .collect();
+        ModuleGraph { modules }
+    }
+
+    /// Register `modules.list`, `modules.states`, `modules.graph` and `routes.list`.
+    pub(crate) fn register_builtins(self: &Arc<Self>, registry: &AdminCommandRegistry) {
+        let this = Arc::clone(self);
+        registry.insert("modules.list", move |_args| {
+            let modules = this.modules();
+            async move { to_result(&modules) }
+        });
+        let this = Arc::clone(self);
+        registry.insert("modules.states", move |_args| {
+            let states = this.states();
+            async move { to_result(&states) }
+        });
+        let this = Arc::clone(self);
+        registry.insert("modules.graph", move |_args| {
+            let graph = this.graph();
+            async move { to_result(&graph) }
+        });
+        let this = Arc::clone(self);
+        registry.insert("routes.list", move |_args| {
+            let routes = this.routes();
+            async move { to_result(&routes) }
+        });
+    }
+}
+
+/// [`OpenApiRegistry`] wrapper that records each operation for `routes.list`.
+pub(crate) struct RouteRecorder<'a> {
+    pub(crate) inner: &'a dyn OpenApiRegistry,
+    pub(crate) module: &'static str,
+    pub(crate) introspection: &'a RuntimeIntrospection,
+}
+
+impl OpenApiRegistry for RouteRecorder<'_> {
+    fn register_operation(&self, spec: &OperationSpec) {
+        self.introspection.add_route(RouteSummary {
+            module: self.module.to_owned(),
+            method: spec.method.as_str().to_owned(),
+            path: spec.path.clone(),
+            operation_id: spec.operation_id.clone(),
+        });
+        self.inner.register_operation(spec);
+    }
+
+    fn ensure_schema_raw(&self, name: &str, schemas: Vec<(String, RefOr<Schema>)>) -> String {
+        self.inner.ensure_schema_raw(name, schemas)
+    }
+
+    fn ensure_parameter_component(&self, name: &str, spec: ParamSpec) -> String {
+        self.inner.ensure_parameter_component(name, spec)
+    }
+
+    fn ensure_response_component(&self, name: &str, spec: ResponseSpec) -> String {
+        self.inner.ensure_response_component(name, spec)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self.inner.as_any()
+    }
+}
+
+fn to_result<T: Serialize>(value: &T) -> AdminResult {
+    serde_json::to_value(value).map_err(|e| AdminError::internal(e.to_string()))
+}
+
+/// Request line sent to the admin socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jsonrpc: Option<String>,
+    #[serde(default)]
+    pub id: Value,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+}
+
+/// Response line written by the admin socket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdminResponse {
+    pub jsonrpc: String,
+    #[serde(default)]
+    pub id: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<AdminError>,
+}
+
+impl AdminResponse {
+    pub(crate) fn from_result(id: Value, result: AdminResult) -> Self {
+        match result {
+            Ok(value) => Self {
+                jsonrpc: "2.0".to_owned(),
+                id,
+                result: Some(value),
+                error: None,
+            },
+            Err(error) => Self {
+                jsonrpc: "2.0".to_owned(),
+                id,
+                result: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn dispatch_runs_registered_command() {
+        let registry = AdminCommandRegistry::new();
+        registry
+            .register("echo", |args| async move { Ok(json!({ "echo": args })) })
+            .unwrap();
+
+        let out = registry.dispatch("echo", json!({"x": 1})).await.unwrap();
+        assert_eq!(out, json!({"echo": {"x": 1}}));
+    }
+
+    #[tokio::test]
+    async fn unknown_command_is_reported() {
+        let registry = AdminCommandRegistry::new();
+        let err = registry.dispatch("missing", Value::Null).await.unwrap_err();
+        assert_eq!(err.code, AdminError::UNKNOWN_COMMAND);
+    }
+
+    #[test]
+    fn duplicate_registration_is_rejected() {
+        let registry = AdminCommandRegistry::new();
+        registry
+            .register("a", |_| async { Ok(Value::Null) })
+            .unwrap();
+        assert!(
+            registry
+                .register("a", |_| async { Ok(Value::Null) })
+                .is_err()
+        );
+        assert_eq!(registry.command_names(), vec!["a".to_owned()]);
+    }
+
+    #[tokio::test]
+    async fn builtins_report_runtime_state() {
+        let registry = AdminCommandRegistry::new();
+        let introspection = Arc::new(RuntimeIntrospection::default());
+        introspection.register_builtins(&registry);
+        introspection.set_modules(vec![ModuleSummary {
+            name: "users".to_owned(),
+            deps: vec![],
+            capabilities: vec!["rest".to_owned()],
+        }]);
+        introspection.set_state("users", ModuleState::Started);
+        introspection.add_route(RouteSummary {
+            module: "users".to_owned(),
+            method: "GET".to_owned(),
+            path: "/users".to_owned(),
+            operation_id: None,
+        });
+
+        let states = registry
+            .dispatch("modules.states", Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(states, json!({"users": "started"}));
+
+        let routes = registry.dispatch("routes.list", Value::Null).await.unwrap();
+        assert_eq!(
+            routes,
+            json!([{"module": "users", "method": "GET", "path": "/users"}])
+        );
+    }
+
+    #[tokio::test]
+    async fn graph_combines_deps_states_and_init_durations() {
+        let registry = AdminCommandRegistry::new();
+        let introspection = Arc::new(RuntimeIntrospection::default());
+        introspection.register_builtins(&registry);
+        introspection.set_modules(vec![
+            ModuleSummary {
+                name: "db".to_owned(),
+                deps: vec![],
+                capabilities: vec![],
+            },
+            ModuleSummary {
+                name: "users".to_owned(),
+                deps: vec!["db".to_owned()],
+                capabilities: vec!["rest".to_owned()],
+            },
+        ]);
+        introspection.set_state("db", ModuleState::Initialized);
+        introspection.record_init_duration("db", Duration::from_millis(12));
+
+        let graph = introspection.graph();
+        assert_eq!(graph.dependents("db"), vec!["users"]);
+        assert_eq!(graph.get("users").unwrap().state, ModuleState::Registered);
+
+        let out = registry
+            .dispatch("modules.graph", Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(
+            out,
+            json!({"modules": [
+                {"name": "db", "deps": [], "capabilities": [], "state": "initialized", "init_duration_ms": 12},
+                {"name": "users", "deps": ["db"], "capabilities": ["rest"], "state": "registered"},
+            ]})
+        );
+        assert_eq!(serde_json::from_value::<ModuleGraph>(out).unwrap(), graph);
+    }
+}
@@ -0,0 +1,189 @@
+//! Unix socket listener for the admin channel.
+
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::Path;
+use std::sync::Arc;
+
+use serde_json::Value;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio_util::sync::CancellationToken;
+
+use super::{AdminCommandRegistry, AdminError, AdminRequest, AdminResponse};
+
+/// Longest request line accepted, excluding the newline. Longer requests get
+/// an `INVALID_REQUEST` error and the connection is closed.
+pub const MAX_REQUEST_LINE: usize = 64 * 1024;
+
+/// Serve admin commands on `path` until `cancel` fires.
+///
+/// A stale socket file left by a previous process is replaced. The socket is
+/// bound inside a private `0700` directory, set to `0600` and only then moved
+/// to `path`, so it is never reachable with umask-derived permissions. It is
+/// removed on shutdown.
+///
+/// # Errors
+/// Returns an error if the socket cannot be bound.
+pub async fn serve(
+    path: &Path,
+    registry: Arc<AdminCommandRegistry>,
+    cancel: CancellationToken,
+) -> anyhow::Result<()> {
+    let listener = bind(path)?;
+    tracing::info!(socket = %path.display(), "Admin channel listening");
+
+    loop {
+        tokio::select! {
+            () = cancel.cancelled() => break,
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    let registry = Arc::clone(&registry);
+                    let cancel = cancel.clone();
+                    tokio::spawn(async move {
+                        if let Err(e) = handle_connection(stream, &registry, &cancel).await {
+                            tracing::debug!(error = %e, "Admin connection closed with error");
+                        }
+                    });
+                }
+                Err(e) => tracing::warn!(error = %e, "Failed to accept admin connection"),
+            },
+        }
+    }
+
+    drop(listener);
+    remove_socket(path);
+    tracing::info!(socket = %path.display(), "Admin channel stopped");
+    Ok(())
+}
+
+fn bind(path: &Path) -> anyhow::Result<UnixListener> {
+    if let Ok(meta) = std::fs::symlink_metadata(path) {
+        if !meta.file_type().is_socket() {
+            anyhow::bail!(
+                "admin socket path '{}' exists and is not a socket",
+                path.display()
+            );
+        }
+        std::fs::remove_file(path)?;
+    }
+    if let Some(parent) = path.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let file_name = path.file_name().ok_or_else(|| {
+        anyhow::anyhow!("admin socket path '{}' has no file name", path.display())
+    })?;
+    let mut staging_name = std::ffi::OsString::from(".");
+    staging_name.push(file_name);
+    staging_name.push(".staging");
+    let staging = path.with_file_name(staging_name);
+    if std::fs::symlink_metadata(&staging).is_ok() {
+        std::fs::remove_dir_all(&staging)?;
+    }
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+
+    let staged = staging.join("admin.sock");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(0o600))?;
+        std::fs::rename(&staged, path)?;
+        Ok(listener)
+    });
+    if let Err(e) = std::fs::remove_dir_all(&staging) {
+        tracing::warn!(dir = %staging.display(), error = %e, "Failed to remove admin socket staging directory");
+    }
+    Ok(bound?)
+}
+
+fn remove_socket(path: &Path) {
+    if let Err(e) = std::fs::remove_file(path)
+        && e.kind() != std::io::ErrorKind::NotFound
+    {
+        tracing::warn!(socket = %path.display(), error = %e, "Failed to remove admin socket");
+    }
+}
+
+async fn handle_connection(
+    stream: UnixStream,
+    registry: &AdminCommandRegistry,
+    cancel: &CancellationToken,
+) -> std::io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let mut buf = Vec::new();
+    let limit = u64::try_from(MAX_REQUEST_LINE + 1).unwrap_or(u64::MAX);
+
+    loop {
+        buf.clear();
+        let read = tokio::select! {
+            () = cancel.cancelled() => return Ok(()),
+            read = (&mut reader).take(limit).read_until(b'\n', &mut buf) => read?,
+        };
+        if read == 0 {
+            return Ok(());
+        }
+        if buf.len() > MAX_REQUEST_LINE && !buf.ends_with(b"\n") {
+            let response = AdminResponse::from_result(
+                Value::Null,
+                Err(AdminError::new(
+                    AdminError::INVALID_REQUEST,
+                    format!("request line exceeds {MAX_REQUEST_LINE} bytes"),
+                )),
+            );
+            write_response(&mut writer, &response).await?;
+            return Ok(());
+        }
+        let line = std::str::from_utf8(&buf)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = handle_line(line, registry).await;
+        write_response(&mut writer, &response).await?;
+    }
+}
+
+async fn write_response(
+    writer: &mut (impl AsyncWriteExt + Unpin),
+    response: &AdminResponse,
+) -> std::io::Result<()> {
+    let mut out = serde_json::to_vec(response).map_err(std::io::Error::other)?;
+    out.push(b'\n');
+    writer.write_all(&out).await
+}
+
+async fn handle_line(line: &str, registry: &AdminCommandRegistry) -> AdminResponse {
+    let value: Value = match serde_json::from_str(line) {
+        Ok(v) => v,
+        Err(e) => {
+            return AdminResponse::from_result(
+                Value::Null,
+                Err(AdminError::new(AdminError::PARSE_ERROR, e.to_string())),
+            );
+        }
+    };
+    let id = value.get("id").cloned().unwrap_or(Value::Null);
+    let request: AdminRequest = match serde_json::from_value(value) {
+        Ok(r) => r,
+        Err(e) => {
+            return AdminResponse::from_result(
+                id,
+                Err(AdminError::new(AdminError::INVALID_REQUEST, e.to_string())),
+            );
+        }
+    };
+
+    let result = registry.dispatch(&request.method, request.params).await;
+    match &result {
+        Ok(_) => tracing::info!(command = %request.method, "Admin command executed"),
+        Err(e) => tracing::warn!(
+            command = %request.method,
+            code = e.code,
+            error = %e.message,
+            "Admin command failed"
+        ),
+    }
+    AdminResponse::from_result(request.id, result)
+}
@@ -32,6 +32,7 @@ impl_api_dto_trait!(
     Option<T>,
     Box<T>,
     modkit_odata::Page<T>,
+    super::response::PaginatedResponse<T>,
     Result<T, anyhow::Error>,
     Result<T, modkit_errors::Problem>,
 );
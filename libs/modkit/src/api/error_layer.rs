@@ -4,36 +4,241 @@
 //! and module errors into consistent RFC 9457 Problem+JSON responses, eliminating
 //! per-route boilerplate.
 
-use axum::{extract::Request, http::HeaderMap, middleware::Next, response::Response};
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request},
+    http::{HeaderMap, header::CONTENT_LENGTH},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use futures_util::FutureExt as _;
 use http::StatusCode;
 use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Once};
 
-use crate::api::problem::Problem;
+use crate::api::problem::{Problem, ProblemTypeRegistry};
 use crate::config::ConfigError;
 use modkit_odata::Error as ODataError;
 
+/// Default template for the `instance` member of problem responses.
+///
+/// `{path}` is the request path and `{request_id}` the `x-request-id` of the request.
+pub const DEFAULT_INSTANCE_TEMPLATE: &str = "{path}#{request_id}";
+
+/// Largest problem body the error layer rewrites; bigger bodies pass through untouched.
+const MAX_PROBLEM_BODY_BYTES: usize = 64 * 1024;
+
 /// Middleware function that provides centralized error mapping
 ///
-/// This middleware can be applied to routes to automatically extract request context
-/// and provide it to error handlers. The actual error conversion happens in the
-/// `IntoProblem` trait implementations and `map_error_to_problem` function.
+/// Equivalent to [`problem_context_middleware`] with [`DEFAULT_INSTANCE_TEMPLATE`].
 pub async fn error_mapping_middleware(request: Request, next: Next) -> Response {
-    let _uri = request.uri().clone();
-    let _headers = request.headers().clone();
+    problem_context_middleware(Arc::from(DEFAULT_INSTANCE_TEMPLATE), request, next).await
+}
+
+/// Middleware that completes Problem+JSON responses with request context.
+///
+/// For every `application/problem+json` response:
+/// - `instance` is set from `instance_template` when it is empty or just the request
+///   path; without a request id it is the bare path
+/// - `trace_id` is set to the OpenTelemetry trace id when the current span is sampled
+/// - a missing `title` is taken from the [`ProblemTypeRegistry`] entry of `type`;
+///   an unregistered type URI is logged once
+///
+/// The error conversion itself happens in handlers (`IntoProblemWithContext`,
+/// [`map_error_to_problem`] or the module's catalog).
+pub async fn problem_context_middleware(
+    instance_template: Arc<str>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path().to_owned();
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(ToOwned::to_owned);
 
     let response = next.run(request).await;
 
-    // If the response is already successful or is already a Problem response, pass it through
-    if response.status().is_success() || is_problem_response(&response) {
+    if response.status().is_success() || !is_problem_response(&response) {
         return response;
     }
 
-    // For error responses, the actual error conversion should happen in the handlers
-    // using the IntoProblem trait or map_error_to_problem function
-    // This middleware provides the infrastructure for extracting request context
-    response
+    let instance = match request_id {
+        Some(rid) => instance_template
+            .replace("{path}", &path)
+            .replace("{request_id}", &rid),
+        None => path.clone(),
+    };
+    enrich_problem_response(response, &path, &instance).await
 }
 
+async fn enrich_problem_response(response: Response, path: &str, instance: &str) -> Response {
+    let (mut parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, MAX_PROBLEM_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to read problem response body");
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+    let Ok(serde_json::Value::Object(mut problem)) = serde_json::from_slice(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let current_instance = problem.get("instance").and_then(|v| v.as_str());
+    if current_instance.is_none_or(|v| v.is_empty() || v == path) {
+        problem.insert("instance".to_owned(), instance.into());
+    }
+
+    if let Some(trace_id) = current_trace_id() {
+        problem.insert("trace_id".to_owned(), trace_id.into());
+    }
+
+    if let Some(type_url) = problem.get("type").and_then(|v| v.as_str())
+        && let Some(ty) = ProblemTypeRegistry::global().lookup(type_url)
+        && problem
+            .get("title")
+            .and_then(|v| v.as_str())
+            .is_none_or(str::is_empty)
+    {
+        problem.insert("title".to_owned(), ty.title.into());
+    }
+
+    match serde_json::to_vec(&problem) {
+        Ok(body) => {
+            parts.headers.remove(CONTENT_LENGTH);
+            Response::from_parts(parts, Body::from(body))
+        }
+        Err(_) => Response::from_parts(parts, Body::from(bytes)),
+    }
+}
+
+/// Trace id of the current OpenTelemetry span, when the span is sampled.
+#[cfg(feature = "otel")]
+fn current_trace_id() -> Option<String> {
+    use opentelemetry::trace::TraceContextExt as _;
+    use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+
+    let cx = tracing::Span::current().context();
+    let span = cx.span();
+    let sc = span.span_context();
+    (sc.is_valid() && sc.is_sampled()).then(|| sc.trace_id().to_string())
+}
+
+#[cfg(not(feature = "otel"))]
+fn current_trace_id() -> Option<String> {
+    None
+}
+
+/// Header carrying the request id assigned by the gateway.
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+static HANDLER_PANICS: AtomicU64 = AtomicU64::new(0);
+static BACKTRACE_HOOK_INIT: Once = Once::new();
+
+thread_local! {
+    /// Backtrace of the last panic on this thread, taken by [`catch_panic_middleware`].
+    static LAST_PANIC_BACKTRACE: RefCell<Option<Backtrace>> = const { RefCell::new(None) };
+}
+
+/// Middleware that turns a panic in a handler into a 500 Problem response.
+///
+/// Place it just inside [`error_mapping_middleware`]. The panic payload and backtrace
+/// are logged at error level together with the request id and matched route; the
+/// client gets a generic `detail` and the request id in `trace_id` for support
+/// correlation. The connection stays usable for subsequent requests.
+pub async fn catch_panic_middleware(request: Request, next: Next) -> Response {
+    install_backtrace_hook();
+
+    let request_id = request.headers().get(REQUEST_ID_HEADER).cloned();
+    let route = request.extensions().get::<MatchedPath>().cloned();
+    let uri = request.uri().clone();
+
+    // `Ok` is the handler's own response (including early-return errors): pass it through
+    let payload = match AssertUnwindSafe(next.run(request)).catch_unwind().await {
+        Ok(response) => return response,
+        Err(payload) => payload,
+    };
+
+    HANDLER_PANICS.fetch_add(1, Ordering::Relaxed);
+    let request_id = request_id.and_then(|v| v.to_str().ok().map(ToOwned::to_owned));
+    let route = route.as_ref().map_or("<unmatched>", MatchedPath::as_str);
+    record_panic_metric(route);
+
+    let backtrace = LAST_PANIC_BACKTRACE.with(|bt| bt.borrow_mut().take());
+    tracing::error!(
+        request_id = request_id.as_deref().unwrap_or("n/a"),
+        route,
+        panic = panic_payload(payload.as_ref()),
+        backtrace = %backtrace.map_or_else(|| "<not captured>".to_owned(), |bt| bt.to_string()),
+        "Handler panicked"
+    );
+
+    let mut problem = Problem::new(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Internal Server Error",
+        "An internal error occurred",
+    )
+    .with_code("INTERNAL_ERROR")
+    .with_type("https://errors.example.com/INTERNAL_ERROR")
+    .with_instance(uri.path());
+    if let Some(rid) = request_id {
+        problem = problem.with_trace_id(rid);
+    }
+    problem.into_response()
+}
+
+/// Number of handler panics caught by [`catch_panic_middleware`] since process start.
+#[must_use]
+pub fn handler_panic_count() -> u64 {
+    HANDLER_PANICS.load(Ordering::Relaxed)
+}
+
+/// Chain a panic hook that records the backtrace for the middleware to log.
+fn install_backtrace_hook() {
+    BACKTRACE_HOOK_INIT.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            LAST_PANIC_BACKTRACE.with(|bt| *bt.borrow_mut() = Some(Backtrace::force_capture()));
+            previous(info);
+        }));
+    });
+}
+
+fn panic_payload(payload: &(dyn Any + Send)) -> &str {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s
+    } else {
+        "non-string panic payload"
+    }
+}
+
+#[cfg(feature = "otel")]
+fn record_panic_metric(route: &str) {
+    use std::sync::LazyLock;
+
+    static PANICS: LazyLock<opentelemetry::metrics::Counter<u64>> = LazyLock::new(|| {
+        opentelemetry::global::meter("modkit")
+            .u64_counter("http.server.handler_panics")
+            .with_description("Panics caught in HTTP handlers")
+            .build()
+    });
+    PANICS.add(
+        1,
+        &[opentelemetry::KeyValue::new("http.route", route.to_owned())],
+    );
+}
+
+#[cfg(not(feature = "otel"))]
+fn record_panic_metric(_route: &str) {}
+
 /// Check if a response is already a Problem+JSON response
 fn is_problem_response(response: &Response) -> bool {
     response
@@ -103,6 +308,14 @@ pub fn map_error_to_problem(error: &dyn Any, instance: &str, trace_id: Option<St
             )
             .with_code("CONFIG_INVALID")
             .with_type("https://errors.example.com/CONFIG_INVALID"),
+
+            ConfigError::InvalidFile { .. } => Problem::new(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Configuration Error",
+                "Configuration file could not be loaded",
+            )
+            .with_code("CONFIG_INVALID_FILE")
+            .with_type("https://errors.example.com/CONFIG_INVALID_FILE"),
         };
 
         problem = problem.with_instance(instance);
@@ -150,24 +363,26 @@ pub fn map_error_to_problem(error: &dyn Any, instance: &str, trace_id: Option<St
     problem
 }
 
-/// Helper trait for converting errors to Problem responses with context
-pub trait IntoProblem {
+/// Helper trait for converting errors to Problem responses with request context.
+///
+/// For handler return types, see [`crate::result::IntoProblem`].
+pub trait IntoProblemWithContext {
     fn into_problem(self, instance: &str, trace_id: Option<String>) -> Problem;
 }
 
-impl IntoProblem for ODataError {
+impl IntoProblemWithContext for ODataError {
     fn into_problem(self, instance: &str, trace_id: Option<String>) -> Problem {
         crate::api::odata::error::odata_error_to_problem(&self, instance, trace_id)
     }
 }
 
-impl IntoProblem for ConfigError {
+impl IntoProblemWithContext for ConfigError {
     fn into_problem(self, instance: &str, trace_id: Option<String>) -> Problem {
         map_error_to_problem(&self as &dyn Any, instance, trace_id)
     }
 }
 
-impl IntoProblem for anyhow::Error {
+impl IntoProblemWithContext for anyhow::Error {
     fn into_problem(self, instance: &str, trace_id: Option<String>) -> Problem {
         map_error_to_problem(&self as &dyn Any, instance, trace_id)
     }
@@ -0,0 +1,447 @@
+//! RFC 6902 JSON Patch request bodies.
+//!
+//! [`JsonPatch<T>`] is an Axum extractor for `PATCH` handlers that accept a
+//! JSON Patch document instead of a per-resource partial-update DTO:
+//!
+//! ```json
+//! [{ "op": "replace", "path": "/email", "value": "new@example.com" }]
+//! ```
+//!
+//! The extractor only parses the operations; the handler loads the current
+//! resource and calls [`JsonPatch::apply`] to get the patched value. Patches
+//! are atomic: if any operation fails, nothing is applied.
+//!
+//! Use `OperationBuilder::json_patch_request` to document the body in `OpenAPI`.
+
+use std::marker::PhantomData;
+
+use axum::{
+    Json,
+    extract::{FromRequest, Request},
+};
+use http::StatusCode;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+use crate::api::problem::Problem;
+
+/// Media type registered for JSON Patch documents (RFC 6902, section 6).
+pub const APPLICATION_JSON_PATCH: &str = "application/json-patch+json";
+
+/// A single RFC 6902 operation. Paths are JSON Pointers (RFC 6901).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOperation {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+/// `OpenAPI` schema of a JSON Patch request body: an array of operations.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct JsonPatchDocument(pub Vec<PatchOperation>);
+
+/// Errors raised while applying a patch.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum JsonPatchError {
+    #[error("invalid JSON pointer '{0}'")]
+    InvalidPointer(String),
+    #[error("path '{0}' does not exist")]
+    PathNotFound(String),
+    #[error("cannot move '{from}' into its own child '{path}'")]
+    MoveIntoChild { from: String, path: String },
+    #[error("test failed at '{0}'")]
+    TestFailed(String),
+    #[error("patched document is not valid: {0}")]
+    InvalidResult(String),
+    #[error("failed to serialize patch target: {0}")]
+    Serialize(String),
+}
+
+impl From<JsonPatchError> for Problem {
+    fn from(e: JsonPatchError) -> Self {
+        let status = match e {
+            // RFC 5789: the request conflicts with the current resource state.
+            JsonPatchError::TestFailed(_) => StatusCode::CONFLICT,
+            JsonPatchError::Serialize(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            _ => StatusCode::UNPROCESSABLE_ENTITY,
+        };
+        Problem::new(
+            status,
+            status.canonical_reason().unwrap_or("Invalid Patch"),
+            e.to_string(),
+        )
+    }
+}
+
+/// Extractor for an RFC 6902 JSON Patch body targeting a `T`.
+///
+/// Accepts `application/json-patch+json` and `application/json`. Malformed
+/// bodies are rejected with a `Problem` before the handler runs.
+#[derive(Clone, Debug)]
+pub struct JsonPatch<T> {
+    operations: Vec<PatchOperation>,
+    _target: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonPatch<T> {
+    #[must_use]
+    pub fn new(operations: Vec<PatchOperation>) -> Self {
+        Self {
+            operations,
+            _target: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn operations(&self) -> &[PatchOperation] {
+        &self.operations
+    }
+
+    #[must_use]
+    pub fn into_operations(self) -> Vec<PatchOperation> {
+        self.operations
+    }
+
+    /// Apply the patch to `target` and return the patched value.
+    ///
+    /// # Errors
+    /// Returns [`JsonPatchError`] if an operation fails or the patched
+    /// document no longer deserializes into `T`.
+    pub fn apply(&self, target: &T) -> Result<T, JsonPatchError>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut doc =
+            serde_json::to_value(target).map_err(|e| JsonPatchError::Serialize(e.to_string()))?;
+        apply_patch(&mut doc, &self.operations)?;
+        serde_json::from_value(doc).map_err(|e| JsonPatchError::InvalidResult(e.to_string()))
+    }
+}
+
+impl<S, T> FromRequest<S> for JsonPatch<T>
+where
+    S: Send + Sync,
+{
+    type Rejection = Problem;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        // `Json` accepts any `application/*+json` media type, which covers
+        // `application/json-patch+json`.
+        let Json(operations) = Json::<Vec<PatchOperation>>::from_request(req, state)
+            .await
+            .map_err(|rejection| {
+                let status = rejection.status();
+                Problem::new(
+                    status,
+                    status.canonical_reason().unwrap_or("Bad Request"),
+                    rejection.body_text(),
+                )
+            })?;
+        Ok(Self::new(operations))
+    }
+}
+
+/// Apply `operations` to `doc` in order.
+///
+/// The patch is atomic: on error `doc` is left unchanged.
+///
+/// # Errors
+/// Returns [`JsonPatchError`] for the first operation that fails.
+pub fn apply_patch(doc: &mut Value, operations: &[PatchOperation]) -> Result<(), JsonPatchError> {
+    let mut patched = doc.clone();
+    for op in operations {
+        apply_operation(&mut patched, op)?;
+    }
+    *doc = patched;
+    Ok(())
+}
+
+fn apply_operation(doc: &mut Value, op: &PatchOperation) -> Result<(), JsonPatchError> {
+    match op {
+        PatchOperation::Add { path, value } => add(doc, path, value.clone()),
+        PatchOperation::Remove { path } => remove(doc, path).map(drop),
+        PatchOperation::Replace { path, value } => {
+            let target = pointer_mut(doc, path)?;
+            *target = value.clone();
+            Ok(())
+        }
+        PatchOperation::Move { from, path } => {
+            if from == path {
+                // Still required to exist.
+                return pointer(doc, from).map(drop);
+            }
+            if path.starts_with(&format!("{from}/")) {
+                return Err(JsonPatchError::MoveIntoChild {
+                    from: from.clone(),
+                    path: path.clone(),
+                });
+            }
+            let value = remove(doc, from)?;
+            add(doc, path, value)
+        }
+        PatchOperation::Copy { from, path } => {
+            let value = pointer(doc, from)?.clone();
+            add(doc, path, value)
+        }
+        PatchOperation::Test { path, value } => {
+            if pointer(doc, path)? == value {
+                Ok(())
+            } else {
+                Err(JsonPatchError::TestFailed(path.clone()))
+            }
+        }
+    }
+}
+
+fn add(doc: &mut Value, path: &str, value: Value) -> Result<(), JsonPatchError> {
+    let Some((parent_path, key)) = split_last(path)? else {
+        *doc = value;
+        return Ok(());
+    };
+    match pointer_mut(doc, parent_path)? {
+        Value::Object(map) => {
+            map.insert(key, value);
+            Ok(())
+        }
+        Value::Array(items) => {
+            let index = if key == "-" {
+                items.len()
+            } else {
+                array_index(&key, path)?
+            };
+            if index > items.len() {
+                return Err(JsonPatchError::PathNotFound(path.to_owned()));
+            }
+            items.insert(index, value);
+            Ok(())
+        }
+        _ => Err(JsonPatchError::PathNotFound(path.to_owned())),
+    }
+}
+
+fn remove(doc: &mut Value, path: &str) -> Result<Value, JsonPatchError> {
+    let Some((parent_path, key)) = split_last(path)? else {
+        return Err(JsonPatchError::InvalidPointer(path.to_owned()));
+    };
+    let removed = match pointer_mut(doc, parent_path)? {
+        Value::Object(map) => map.remove(&key),
+        Value::Array(items) => {
+            let index = array_index(&key, path)?;
+            (index < items.len()).then(|| items.remove(index))
+        }
+        _ => None,
+    };
+    removed.ok_or_else(|| JsonPatchError::PathNotFound(path.to_owned()))
+}
+
+fn pointer<'a>(doc: &'a Value, path: &str) -> Result<&'a Value, JsonPatchError> {
+    validate_pointer(path)?;
+    doc.pointer(path)
+        .ok_or_else(|| JsonPatchError::PathNotFound(path.to_owned()))
+}
+
+fn pointer_mut<'a>(doc: &'a mut Value, path: &str) -> Result<&'a mut Value, JsonPatchError> {
+    validate_pointer(path)?;
+    doc.pointer_mut(path)
+        .ok_or_else(|| JsonPatchError::PathNotFound(path.to_owned()))
+}
+
+fn validate_pointer(path: &str) -> Result<(), JsonPatchError> {
+    if path.is_empty() || path.starts_with('/') {
+        Ok(())
+    } else {
+        Err(JsonPatchError::InvalidPointer(path.to_owned()))
+    }
+}
+
+/// Split a pointer into its parent pointer and unescaped last token.
+/// Returns `None` for the root pointer.
+fn split_last(path: &str) -> Result<Option<(&str, String)>, JsonPatchError> {
+    validate_pointer(path)?;
+    Ok(path.rfind('/').map(|pos| {
+        let token = path[pos + 1..].replace("~1", "/").replace("~0", "~");
+        (&path[..pos], token)
+    }))
+}
+
+fn array_index(token: &str, path: &str) -> Result<usize, JsonPatchError> {
+    let valid = !token.is_empty()
+        && token.bytes().all(|b| b.is_ascii_digit())
+        && (token == "0" || !token.starts_with('0'));
+    if !valid {
+        return Err(JsonPatchError::InvalidPointer(path.to_owned()));
+    }
+    token
+        .parse()
+        .map_err(|_| JsonPatchError::InvalidPointer(path.to_owned()))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use serde_json::json;
+
+    fn patch(ops: Value) -> Vec<PatchOperation> {
+        serde_json::from_value(ops).unwrap()
+    }
+
+    #[test]
+    fn applies_all_operation_kinds() {
+        let mut doc = json!({ "a": 1, "list": [1, 2], "nested": { "x": "y" } });
+        let ops = patch(json!([
+            { "op": "test", "path": "/a", "value": 1 },
+            { "op": "replace", "path": "/a", "value": 2 },
+            { "op": "add", "path": "/list/-", "value": 3 },
+            { "op": "add", "path": "/list/0", "value": 0 },
+            { "op": "remove", "path": "/list/1" },
+            { "op": "copy", "from": "/nested/x", "path": "/copied" },
+            { "op": "move", "from": "/nested", "path": "/moved" },
+        ]));
+
+        apply_patch(&mut doc, &ops).unwrap();
+
+        assert_eq!(
+            doc,
+            json!({ "a": 2, "list": [0, 2, 3], "copied": "y", "moved": { "x": "y" } })
+        );
+    }
+
+    #[test]
+    fn pointer_tokens_are_unescaped() {
+        let mut doc = json!({});
+        let ops = patch(json!([
+            { "op": "add", "path": "/a~1b", "value": 1 },
+            { "op": "add", "path": "/c~0d", "value": 2 },
+        ]));
+
+        apply_patch(&mut doc, &ops).unwrap();
+
+        assert_eq!(doc, json!({ "a/b": 1, "c~d": 2 }));
+    }
+
+    #[test]
+    fn failed_patch_leaves_document_unchanged() {
+        let mut doc = json!({ "a": 1 });
+        let ops = patch(json!([
+            { "op": "replace", "path": "/a", "value": 2 },
+            { "op": "test", "path": "/a", "value": 1 },
+        ]));
+
+        let err = apply_patch(&mut doc, &ops).unwrap_err();
+
+        assert_eq!(err, JsonPatchError::TestFailed("/a".to_owned()));
+        assert_eq!(doc, json!({ "a": 1 }));
+    }
+
+    #[test]
+    fn rejects_invalid_targets() {
+        let cases = [
+            (
+                json!({ "op": "replace", "path": "/missing", "value": 1 }),
+                JsonPatchError::PathNotFound("/missing".to_owned()),
+            ),
+            (
+                json!({ "op": "add", "path": "/list/5", "value": 1 }),
+                JsonPatchError::PathNotFound("/list/5".to_owned()),
+            ),
+            (
+                json!({ "op": "remove", "path": "/list/01" }),
+                JsonPatchError::InvalidPointer("/list/01".to_owned()),
+            ),
+            (
+                json!({ "op": "add", "path": "list", "value": 1 }),
+                JsonPatchError::InvalidPointer("list".to_owned()),
+            ),
+            (
+                json!({ "op": "move", "from": "/obj", "path": "/obj/inner" }),
+                JsonPatchError::MoveIntoChild {
+                    from: "/obj".to_owned(),
+                    path: "/obj/inner".to_owned(),
+                },
+            ),
+        ];
+
+        for (op, expected) in cases {
+            let mut doc = json!({ "list": [1], "obj": {} });
+            let err = apply_patch(&mut doc, &patch(json!([op]))).unwrap_err();
+            assert_eq!(err, expected);
+        }
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct User {
+        email: String,
+        display_name: String,
+    }
+
+    #[test]
+    fn apply_returns_typed_value() {
+        let user = User {
+            email: "old@example.com".to_owned(),
+            display_name: "Old".to_owned(),
+        };
+        let ops = patch(json!([
+            { "op": "replace", "path": "/email", "value": "new@example.com" }
+        ]));
+
+        let patched = JsonPatch::<User>::new(ops).apply(&user).unwrap();
+
+        assert_eq!(patched.email, "new@example.com");
+        assert_eq!(patched.display_name, "Old");
+    }
+
+    #[test]
+    fn apply_rejects_result_that_no_longer_fits_target() {
+        let user = User {
+            email: "a@example.com".to_owned(),
+            display_name: "A".to_owned(),
+        };
+        let ops = patch(json!([{ "op": "remove", "path": "/email" }]));
+
+        let err = JsonPatch::<User>::new(ops).apply(&user).unwrap_err();
+
+        assert!(matches!(err, JsonPatchError::InvalidResult(_)));
+        assert_eq!(Problem::from(err).status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    fn request(content_type: &str, body: &str) -> Request {
+        Request::builder()
+            .method("PATCH")
+            .header(http::header::CONTENT_TYPE, content_type)
+            .body(Body::from(body.to_owned()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn extractor_accepts_json_patch_media_type() {
+        let req = request(
+            APPLICATION_JSON_PATCH,
+            r#"[{"op":"replace","path":"/email","value":"new@example.com"}]"#,
+        );
+
+        let extracted = JsonPatch::<User>::from_request(req, &()).await.unwrap();
+
+        assert_eq!(
+            extracted.operations(),
+            [PatchOperation::Replace {
+                path: "/email".to_owned(),
+                value: json!("new@example.com"),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn extractor_rejects_unknown_operation() {
+        let req = request("application/json", r#"[{"op":"merge","path":"/a"}]"#);
+
+        let problem = JsonPatch::<User>::from_request(req, &()).await.unwrap_err();
+
+        assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
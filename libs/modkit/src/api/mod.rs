@@ -6,6 +6,7 @@
 
 pub mod api_dto;
 pub mod error_layer;
+pub mod json_patch;
 pub mod odata;
 pub mod openapi_registry;
 pub mod operation_builder;
@@ -19,30 +20,39 @@ pub mod trace_layer;
 mod odata_policy_tests;
 
 pub use error_layer::{
-    IntoProblem, error_mapping_middleware, extract_trace_id, map_error_to_problem,
+    DEFAULT_INSTANCE_TEMPLATE, IntoProblemWithContext, catch_panic_middleware,
+    error_mapping_middleware, extract_trace_id, handler_panic_count, map_error_to_problem,
+    problem_context_middleware,
+};
+pub use json_patch::{JsonPatch, JsonPatchError, PatchOperation, apply_patch};
+pub use openapi_registry::{
+    OpenApiInfo, OpenApiRegistry, OpenApiRegistryImpl, OpenApiServer, ensure_schema,
+    openapi_to_sorted_json,
 };
-pub use openapi_registry::{OpenApiInfo, OpenApiRegistry, OpenApiRegistryImpl, ensure_schema};
 pub use operation_builder::{
-    Missing, OperationBuilder, OperationSpec, ParamLocation, ParamSpec, Present, RateLimitSpec,
-    ResponseSpec, state,
+    Missing, OperationBuilder, OperationSpec, ParamLocation, ParamSpec, Present, RateLimitKeyBy,
+    RateLimitSpec, ResponseSpec, state,
 };
 pub use problem::{
-    APPLICATION_PROBLEM_JSON, Problem, ValidationError, bad_request, conflict, internal_error,
-    not_found,
+    APPLICATION_PROBLEM_JSON, Problem, ProblemType, ProblemTypeRegistry, ValidationError,
+    bad_request, conflict, internal_error, not_found,
 };
-pub use select::{apply_select, page_to_projected_json, project_json};
+pub use response::PaginatedResponse;
+pub use select::{apply_select, columns_for_select, page_to_projected_json, project_json};
 pub use trace_layer::{WithRequestContext, WithTraceContext};
 
 /// Prelude module that re-exports common API types and utilities for module authors
 pub mod prelude {
-    // Result type (Problem-only)
-    pub use crate::result::ApiResult;
+    // Result type (Problem-only) and typed domain-error responses
+    pub use crate::result::{ApiResult, IntoProblem, ProblemResponse};
 
     // Problem type for error construction
     pub use super::problem::Problem;
 
     // Response sugar
-    pub use super::response::{JsonBody, JsonPage, created_json, no_content, ok_json};
+    pub use super::response::{
+        JsonBody, JsonPage, PaginatedResponse, created_json, no_content, ok_json,
+    };
 
     // OData and field projection
     pub use super::select::apply_select;
@@ -1,6 +1,6 @@
 use axum::extract::{FromRequestParts, Query};
 use axum::http::request::Parts;
-use modkit_odata::{CursorV1, Error as ODataError, ODataOrderBy, OrderKey, SortDir};
+use modkit_odata::{CursorV1, Error as ODataError, ODataOrderBy, ODataSearch, OrderKey, SortDir};
 use serde::Deserialize;
 
 // Re-export types from modkit-odata for convenience and better DX
@@ -19,6 +19,8 @@ pub struct ODataParams {
     pub orderby: Option<String>,
     #[serde(rename = "$select")]
     pub select: Option<String>,
+    #[serde(rename = "$search")]
+    pub search: Option<String>,
     pub limit: Option<u64>,
     pub cursor: Option<String>,
 }
@@ -29,6 +31,8 @@ pub const MAX_ORDERBY_LEN: usize = 1024;
 pub const MAX_ORDER_FIELDS: usize = 10;
 pub const MAX_SELECT_LEN: usize = 2048;
 pub const MAX_SELECT_FIELDS: usize = 100;
+pub const MAX_SEARCH_LEN: usize = 256;
+pub const MAX_SEARCH_TERMS: usize = 10;
 
 /// Parse $select string into a list of field names.
 /// Format: "field1, field2, field3, ..."
@@ -134,8 +138,32 @@ pub fn parse_orderby(raw: &str) -> Result<ODataOrderBy, modkit_odata::Error> {
     Ok(ODataOrderBy(keys))
 }
 
+/// Parse $search string into `ODataSearch`.
+/// Format: space-separated terms and double-quoted phrases, all of which must match.
+/// Blank input yields `None`.
+///
+/// # Errors
+/// Returns `modkit_odata::Error::InvalidSearch` if the search string is malformed
+/// or exceeds the length/term budgets.
+pub fn parse_search(raw: &str) -> Result<Option<ODataSearch>, modkit_odata::Error> {
+    if raw.len() > MAX_SEARCH_LEN {
+        return Err(modkit_odata::Error::InvalidSearch("search too long".into()));
+    }
+
+    let search = modkit_odata::parse_search(raw)
+        .map_err(|e| modkit_odata::Error::InvalidSearch(e.to_string()))?;
+
+    if search.terms.len() > MAX_SEARCH_TERMS {
+        return Err(modkit_odata::Error::InvalidSearch(format!(
+            "too many terms (max {MAX_SEARCH_TERMS})"
+        )));
+    }
+
+    Ok((!search.is_empty()).then_some(search))
+}
+
 /// Extract and validate full `OData` query from request parts.
-/// - Parses $filter, $orderby, limit, cursor
+/// - Parses $filter, $search, $orderby, limit, cursor
 /// - Enforces budgets and validates formats
 /// - Returns unified `ODataQuery`
 ///
@@ -195,6 +223,19 @@ where
         }
     }
 
+    // Parse search; the cursor hash then covers filter and search together
+    if let Some(raw_search) = params.search.as_ref()
+        && let Some(search) = parse_search(raw_search)
+            .map_err(|e| crate::api::odata::odata_error_to_problem(&e, parts.uri.path(), None))?
+    {
+        if let Some(hash) =
+            modkit_odata::pagination::short_query_hash(query.filter(), Some(&search))
+        {
+            query = query.with_filter_hash(hash);
+        }
+        query = query.with_search(search);
+    }
+
     // Check for cursor+orderby conflict before parsing either
     if params.cursor.is_some() && params.orderby.is_some() {
         return Err(crate::api::odata::odata_error_to_problem(
@@ -243,7 +284,7 @@ where
 use std::ops::Deref;
 
 /// Simple Axum extractor for full `OData` query parameters.
-/// Parses $filter, $orderby, limit, and cursor parameters.
+/// Parses $filter, $search, $orderby, limit, and cursor parameters.
 /// Usage in handlers:
 ///   async fn `list_users(OData(query)`: `OData`, /* ... */) { /* use `query` */ }
 #[derive(Debug, Clone)]
@@ -227,6 +227,63 @@ mod tests {
         let _problem_response = result.unwrap_err();
     }
 
+    #[tokio::test]
+    async fn test_extract_odata_query_search() {
+        let uri = format!(
+            "/?%24search={}&%24filter={}",
+            urlencoding::encode(r#"ada "lovelace, countess""#),
+            urlencoding::encode("email eq 'ada@example.com'")
+        );
+
+        let request = Request::builder().uri(uri).body(()).unwrap();
+
+        let (mut parts, _body) = request.into_parts();
+
+        let query = extract_odata_query(&mut parts, &()).await.unwrap();
+
+        let search = query.search.as_ref().unwrap();
+        assert_eq!(search.terms, vec!["ada", "lovelace, countess"]);
+        assert!(query.filter.is_some());
+        // The cursor hash covers the search, not only the filter
+        assert_ne!(
+            query.filter_hash,
+            modkit_odata::short_filter_hash(query.filter())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_odata_query_blank_search() {
+        let request = Request::builder()
+            .uri("/?%24search=%20%20")
+            .body(())
+            .unwrap();
+
+        let (mut parts, _body) = request.into_parts();
+
+        let query = extract_odata_query(&mut parts, &()).await.unwrap();
+
+        assert!(query.search.is_none());
+        assert!(query.filter_hash.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_extract_odata_query_invalid_search() {
+        let too_many = vec!["term"; MAX_SEARCH_TERMS + 1].join(" ");
+        for raw in [r#"ada "lovelace"#, "ada OR grace", too_many.as_str()] {
+            let uri = format!("/?%24search={}", urlencoding::encode(raw));
+            let request = Request::builder().uri(uri).body(()).unwrap();
+
+            let (mut parts, _body) = request.into_parts();
+
+            let problem = extract_odata_query(&mut parts, &()).await.unwrap_err();
+            assert_eq!(
+                problem.status,
+                http::StatusCode::UNPROCESSABLE_ENTITY,
+                "{raw}"
+            );
+        }
+    }
+
     #[tokio::test]
     async fn test_odata_extractor() {
         let uri = "/?%24filter=email%20eq%20%27test%40example.com%27&limit=10";
@@ -6,20 +6,22 @@
 use anyhow::Result;
 use arc_swap::ArcSwap;
 use dashmap::DashMap;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 use utoipa::openapi::{
     OpenApi, OpenApiBuilder, Ref, RefOr, Required,
     content::ContentBuilder,
     info::InfoBuilder,
     path::{
-        HttpMethod, OperationBuilder as UOperationBuilder, ParameterBuilder, ParameterIn,
-        PathItemBuilder, PathsBuilder,
+        HttpMethod, OperationBuilder as UOperationBuilder, Parameter, ParameterBuilder,
+        ParameterIn, PathItemBuilder, PathsBuilder,
     },
     request_body::RequestBodyBuilder,
-    response::{ResponseBuilder, ResponsesBuilder},
+    response::{Response, ResponseBuilder, ResponsesBuilder},
     schema::{ComponentsBuilder, ObjectBuilder, Schema, SchemaFormat, SchemaType},
     security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
+    server::ServerBuilder,
 };
 
 use crate::api::{operation_builder, problem};
@@ -27,12 +29,14 @@ use crate::api::{operation_builder, problem};
 /// Type alias for schema collections used in API operations.
 type SchemaCollection = Vec<(String, RefOr<Schema>)>;
 
-/// `OpenAPI` document metadata (title, version, description)
+/// `OpenAPI` document metadata (title, version, description, servers)
 #[derive(Debug, Clone)]
 pub struct OpenApiInfo {
     pub title: String,
     pub version: String,
     pub description: Option<String>,
+    /// Emitted as `servers` in this order; omitted when empty.
+    pub servers: Vec<OpenApiServer>,
 }
 
 impl Default for OpenApiInfo {
@@ -41,10 +45,19 @@ impl Default for OpenApiInfo {
             title: "API Documentation".to_owned(),
             version: "0.1.0".to_owned(),
             description: None,
+            servers: Vec::new(),
         }
     }
 }
 
+/// A base URL the API is served at (e.g. staging, production).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpenApiServer {
+    /// Absolute URL, or a path relative to the document's location.
+    pub url: String,
+    pub description: Option<String>,
+}
+
 /// `OpenAPI` registry trait for operation and schema registration
 pub trait OpenApiRegistry: Send + Sync {
     /// Register an API operation specification
@@ -55,6 +68,28 @@ pub trait OpenApiRegistry: Send + Sync {
     /// This is a type-erased version for dyn compatibility.
     fn ensure_schema_raw(&self, name: &str, schemas: SchemaCollection) -> String;
 
+    /// Ensure `spec` is registered under `components.parameters` and return the
+    /// canonical component name for `$ref`.
+    ///
+    /// The default only echoes `name`; registries that build a document override it.
+    fn ensure_parameter_component(&self, name: &str, spec: operation_builder::ParamSpec) -> String {
+        let _ = spec;
+        name.to_owned()
+    }
+
+    /// Ensure `spec` is registered under `components.responses` and return the
+    /// canonical component name for `$ref`.
+    ///
+    /// The default only echoes `name`; registries that build a document override it.
+    fn ensure_response_component(
+        &self,
+        name: &str,
+        spec: operation_builder::ResponseSpec,
+    ) -> String {
+        let _ = spec;
+        name.to_owned()
+    }
+
     /// Downcast support for accessing the concrete implementation if needed.
     fn as_any(&self) -> &dyn std::any::Any;
 }
@@ -85,6 +120,10 @@ pub struct OpenApiRegistryImpl {
     pub operation_specs: DashMap<String, operation_builder::OperationSpec>,
     /// Store schema components using arc-swap for lock-free reads
     pub components_registry: ArcSwap<HashMap<String, RefOr<Schema>>>,
+    /// Shared parameters emitted under `components.parameters`
+    pub parameter_components: ArcSwap<HashMap<String, Parameter>>,
+    /// Shared responses emitted under `components.responses`
+    pub response_components: ArcSwap<HashMap<String, Response>>,
 }
 
 impl OpenApiRegistryImpl {
@@ -94,7 +133,80 @@ impl OpenApiRegistryImpl {
         Self {
             operation_specs: DashMap::new(),
             components_registry: ArcSwap::from_pointee(HashMap::new()),
+            parameter_components: ArcSwap::from_pointee(HashMap::new()),
+            response_components: ArcSwap::from_pointee(HashMap::new()),
+        }
+    }
+
+    /// Combine this registry with `other` into a new registry.
+    ///
+    /// Lets separately owned registries (e.g. one per team) be assembled into a
+    /// single document before [`build_openapi`](Self::build_openapi). Neither
+    /// input is modified. Component schemas registered identically in both are
+    /// kept once; the same applies to shared parameters and responses.
+    ///
+    /// # Errors
+    /// Returns an error if both registries contain an operation with the same
+    /// `handler_id` or the same method and path, or a component schema, parameter
+    /// or response with the same name but different content.
+    pub fn merge(&self, other: &Self) -> Result<Self> {
+        let merged = Self::new();
+
+        for entry in &self.operation_specs {
+            merged
+                .operation_specs
+                .insert(entry.key().clone(), entry.value().clone());
         }
+        for entry in &other.operation_specs {
+            let spec = entry.value();
+            if let Some(existing) = merged
+                .operation_specs
+                .iter()
+                .find(|e| e.value().handler_id == spec.handler_id)
+            {
+                anyhow::bail!(
+                    "duplicate handler_id '{}' ({} and {})",
+                    spec.handler_id,
+                    existing.key(),
+                    entry.key()
+                );
+            }
+            if let Some(existing) = merged.operation_specs.get(entry.key()) {
+                anyhow::bail!(
+                    "operation {} registered twice (handler_ids '{}' and '{}')",
+                    entry.key(),
+                    existing.handler_id,
+                    spec.handler_id
+                );
+            }
+            merged
+                .operation_specs
+                .insert(entry.key().clone(), spec.clone());
+        }
+
+        let mut components = (**self.components_registry.load()).clone();
+        for (name, schema) in other.components_registry.load().iter() {
+            if let Some(existing) = components.get(name) {
+                if serde_json::to_value(existing).ok() != serde_json::to_value(schema).ok() {
+                    anyhow::bail!("component schema '{name}' differs between registries");
+                }
+                continue;
+            }
+            components.insert(name.clone(), schema.clone());
+        }
+        merged.components_registry.store(Arc::new(components));
+        merged.parameter_components.store(Arc::new(merge_components(
+            "parameter",
+            &self.parameter_components,
+            &other.parameter_components,
+        )?));
+        merged.response_components.store(Arc::new(merge_components(
+            "response",
+            &self.response_components,
+            &other.response_components,
+        )?));
+
+        Ok(merged)
     }
 
     /// Build `OpenAPI` specification from registered operations and components.
@@ -118,7 +230,7 @@ impl OpenApiRegistryImpl {
             let mut op = UOperationBuilder::new()
                 .operation_id(spec.operation_id.clone().or(Some(spec.handler_id.clone())))
                 .summary(spec.summary.clone())
-                .description(spec.description.clone());
+                .description(operation_description(&spec));
 
             for tag in &spec.tags {
                 op = op.tag(tag.clone());
@@ -148,43 +260,36 @@ impl OpenApiRegistryImpl {
             {
                 ext.insert("x-odata-orderby".to_owned(), value);
             }
-
-            if !ext.is_empty() {
-                op = op.extensions(Some(ext));
+            if let Some(pagination) = spec.vendor_extensions.x_odata_search.as_ref()
+                && let Ok(value) = serde_json::to_value(pagination)
+            {
+                ext.insert("x-odata-search".to_owned(), value);
             }
 
             // Parameters
-            for p in &spec.params {
-                let in_ = match p.location {
-                    operation_builder::ParamLocation::Path => ParameterIn::Path,
-                    operation_builder::ParamLocation::Query => ParameterIn::Query,
-                    operation_builder::ParamLocation::Header => ParameterIn::Header,
-                    operation_builder::ParamLocation::Cookie => ParameterIn::Cookie,
-                };
-                let required =
-                    if matches!(p.location, operation_builder::ParamLocation::Path) || p.required {
-                        Required::True
-                    } else {
-                        Required::False
-                    };
-
-                let schema_type = match p.param_type.as_str() {
-                    "integer" => SchemaType::Type(utoipa::openapi::schema::Type::Integer),
-                    "number" => SchemaType::Type(utoipa::openapi::schema::Type::Number),
-                    "boolean" => SchemaType::Type(utoipa::openapi::schema::Type::Boolean),
-                    _ => SchemaType::Type(utoipa::openapi::schema::Type::String),
-                };
-                let schema = Schema::Object(ObjectBuilder::new().schema_type(schema_type).build());
-
-                let param = ParameterBuilder::new()
-                    .name(&p.name)
-                    .parameter_in(in_)
-                    .required(required)
-                    .description(p.description.clone())
-                    .schema(Some(schema))
-                    .build();
+            // utoipa 5 has no `$ref` parameters: when any parameter is shared, the whole
+            // list goes through the flattened extension map, which serializes as a plain
+            // `parameters` key.
+            if spec.params.iter().any(|p| p.component.is_some()) {
+                let params = spec
+                    .params
+                    .iter()
+                    .map(|p| match &p.component {
+                        Some(name) => serde_json::json!({
+                            "$ref": format!("#/components/parameters/{name}")
+                        }),
+                        None => serde_json::to_value(build_parameter(p)).unwrap_or_default(),
+                    })
+                    .collect();
+                ext.insert("parameters".to_owned(), serde_json::Value::Array(params));
+            } else {
+                for p in &spec.params {
+                    op = op.parameter(build_parameter(p));
+                }
+            }
 
-                op = op.parameter(param);
+            if !ext.is_empty() {
+                op = op.extensions(Some(ext));
             }
 
             // Request body
@@ -236,6 +341,7 @@ impl OpenApiRegistryImpl {
                             .build()
                     }
                 };
+                let content = with_example(content, rb.example.as_ref());
                 let mut rbld = RequestBodyBuilder::new()
                     .description(rb.description.clone())
                     .content(rb.content_type.to_owned(), content);
@@ -246,44 +352,15 @@ impl OpenApiRegistryImpl {
             }
 
             // Responses
+            let examples = response_examples(spec);
             let mut responses = ResponsesBuilder::new();
             for r in &spec.responses {
-                let is_json_like = r.content_type == "application/json"
-                    || r.content_type == problem::APPLICATION_PROBLEM_JSON
-                    || r.content_type == "text/event-stream";
-                let resp = if is_json_like {
-                    if let Some(name) = &r.schema_name {
-                        // Manually build content to preserve the correct content type
-                        let content = ContentBuilder::new()
-                            .schema(Some(RefOr::Ref(Ref::new(format!(
-                                "#/components/schemas/{name}"
-                            )))))
-                            .build();
-                        ResponseBuilder::new()
-                            .description(&r.description)
-                            .content(r.content_type, content)
-                            .build()
-                    } else {
-                        let content = ContentBuilder::new()
-                            .schema(Some(Schema::Object(ObjectBuilder::new().build())))
-                            .build();
-                        ResponseBuilder::new()
-                            .description(&r.description)
-                            .content(r.content_type, content)
-                            .build()
+                let resp = match &r.component {
+                    Some(name) => RefOr::Ref(Ref::from_response_name(name.clone())),
+                    None => {
+                        let example = examples.get(&(r.status, r.content_type)).copied();
+                        RefOr::T(build_response(r, example))
                     }
-                } else {
-                    let schema = Schema::Object(
-                        ObjectBuilder::new()
-                            .schema_type(SchemaType::Type(utoipa::openapi::schema::Type::String))
-                            .format(Some(SchemaFormat::Custom(r.content_type.into())))
-                            .build(),
-                    );
-                    let content = ContentBuilder::new().schema(Some(schema)).build();
-                    ResponseBuilder::new()
-                        .description(&r.description)
-                        .content(r.content_type, content)
-                        .build()
                 };
                 responses = responses.response(r.status.to_string(), resp);
             }
@@ -318,6 +395,20 @@ impl OpenApiRegistryImpl {
         for (name, schema) in self.components_registry.load().iter() {
             components = components.schema(name.clone(), schema.clone());
         }
+        for (name, response) in self.response_components.load().iter() {
+            components = components.response(name.clone(), response.clone());
+        }
+        // No `components.parameters` in utoipa 5 either; see the operation parameters above.
+        let parameters = self.parameter_components.load();
+        if !parameters.is_empty() {
+            let parameters: BTreeMap<_, _> = parameters
+                .iter()
+                .map(|(name, p)| (name.clone(), serde_json::to_value(p).unwrap_or_default()))
+                .collect();
+            let mut ext = utoipa::openapi::extensions::Extensions::default();
+            ext.insert("parameters".to_owned(), serde_json::json!(parameters));
+            components = components.extensions(Some(ext));
+        }
 
         // Add bearer auth security scheme
         components = components.security_scheme(
@@ -337,8 +428,21 @@ impl OpenApiRegistryImpl {
             .description(info.description.clone())
             .build();
 
+        let servers = (!info.servers.is_empty()).then(|| {
+            info.servers
+                .iter()
+                .map(|s| {
+                    ServerBuilder::new()
+                        .url(&s.url)
+                        .description(s.description.clone())
+                        .build()
+                })
+                .collect::<Vec<_>>()
+        });
+
         let openapi = OpenApiBuilder::new()
             .info(openapi_info)
+            .servers(servers)
             .paths(paths.build())
             .components(Some(components.build()))
             .build();
@@ -356,6 +460,17 @@ impl Default for OpenApiRegistryImpl {
 impl OpenApiRegistry for OpenApiRegistryImpl {
     fn register_operation(&self, spec: &operation_builder::OperationSpec) {
         let operation_key = format!("{}:{}", spec.method.as_str(), spec.path);
+        let mut spec = spec.clone();
+        for p in &mut spec.params {
+            if let Some(name) = p.component.take() {
+                p.component = Some(self.ensure_parameter_component(&name, p.clone()));
+            }
+        }
+        for r in &mut spec.responses {
+            if let Some(name) = r.component.take() {
+                r.component = Some(self.ensure_response_component(&name, r.clone()));
+            }
+        }
         self.operation_specs
             .insert(operation_key.clone(), spec.clone());
 
@@ -391,11 +506,251 @@ impl OpenApiRegistry for OpenApiRegistryImpl {
         root_name.to_owned()
     }
 
+    fn ensure_parameter_component(&self, name: &str, spec: operation_builder::ParamSpec) -> String {
+        ensure_component(
+            &self.parameter_components,
+            "parameter",
+            name,
+            build_parameter(&spec),
+        )
+    }
+
+    fn ensure_response_component(
+        &self,
+        name: &str,
+        spec: operation_builder::ResponseSpec,
+    ) -> String {
+        let response = build_response(&spec, spec.example.as_ref());
+        ensure_component(&self.response_components, "response", name, response)
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 }
 
+/// Serialize `doc` as pretty-printed JSON with all object keys sorted.
+///
+/// Parts of the document (e.g. vendor extensions) are hash maps, so plain
+/// serialization is not stable; sorted output is byte-identical across runs
+/// and can be diffed.
+///
+/// # Errors
+/// Returns an error if the document cannot be serialized.
+pub fn openapi_to_sorted_json(doc: &OpenApi) -> Result<String> {
+    fn sort_keys(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Object(map) => {
+                let sorted: BTreeMap<String, serde_json::Value> =
+                    map.into_iter().map(|(k, v)| (k, sort_keys(v))).collect();
+                serde_json::Value::Object(sorted.into_iter().collect())
+            }
+            serde_json::Value::Array(items) => {
+                serde_json::Value::Array(items.into_iter().map(sort_keys).collect())
+            }
+            other => other,
+        }
+    }
+
+    let mut json = serde_json::to_string_pretty(&sort_keys(serde_json::to_value(doc)?))?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Register `value` as component `name`, or reuse it if already registered.
+///
+/// Identical content is deduplicated by name. Different content under a taken
+/// name is registered as `{name}_{content hash}` so existing `$ref`s keep their
+/// meaning; the returned name is the one to reference.
+fn ensure_component<T: serde::Serialize + Clone>(
+    registry: &ArcSwap<HashMap<String, T>>,
+    kind: &str,
+    name: &str,
+    value: T,
+) -> String {
+    let current = registry.load();
+    let content = serde_json::to_value(&value).unwrap_or_default();
+    let same = |existing: &T| serde_json::to_value(existing).ok().as_ref() == Some(&content);
+
+    let name = match current.get(name) {
+        None => name.to_owned(),
+        Some(existing) if same(existing) => return name.to_owned(),
+        Some(_) => {
+            let mut hasher = DefaultHasher::new();
+            content.to_string().hash(&mut hasher);
+            let hashed = format!("{name}_{:08x}", hasher.finish() & 0xffff_ffff);
+            if current.get(&hashed).is_some_and(same) {
+                return hashed;
+            }
+            tracing::warn!(
+                %name,
+                %hashed,
+                "Shared {kind} content conflict; registering under a content-derived name"
+            );
+            hashed
+        }
+    };
+
+    let mut reg = (**current).clone();
+    reg.insert(name.clone(), value);
+    registry.store(Arc::new(reg));
+    name
+}
+
+/// Union of two component maps; a name with different content is an error.
+fn merge_components<T: serde::Serialize + Clone>(
+    kind: &str,
+    a: &ArcSwap<HashMap<String, T>>,
+    b: &ArcSwap<HashMap<String, T>>,
+) -> Result<HashMap<String, T>> {
+    let mut merged = (**a.load()).clone();
+    for (name, value) in b.load().iter() {
+        if let Some(existing) = merged.get(name) {
+            if serde_json::to_value(existing).ok() != serde_json::to_value(value).ok() {
+                anyhow::bail!("{kind} component '{name}' differs between registries");
+            }
+            continue;
+        }
+        merged.insert(name.clone(), value.clone());
+    }
+    Ok(merged)
+}
+
+/// Operation description, followed by the fields accepted by `$filter`,
+/// `$orderby` and `$search` (their parameters are shared, so the per-operation
+/// details live here).
+fn operation_description(spec: &operation_builder::OperationSpec) -> Option<String> {
+    use std::fmt::Write as _;
+
+    let mut text = spec.description.clone().unwrap_or_default();
+    if let Some(filter) = &spec.vendor_extensions.x_odata_filter
+        && !filter.allowed_fields.is_empty()
+    {
+        text.push_str("\n\n`$filter` fields:");
+        for (field, ops) in &filter.allowed_fields {
+            _ = write!(text, "\n- {field}: {}", ops.join("|"));
+        }
+    }
+    if let Some(order_by) = &spec.vendor_extensions.x_odata_orderby
+        && !order_by.allowed_fields.is_empty()
+    {
+        text.push_str("\n\n`$orderby` values:");
+        for value in &order_by.allowed_fields {
+            _ = write!(text, "\n- {value}");
+        }
+    }
+    if let Some(search) = &spec.vendor_extensions.x_odata_search
+        && !search.allowed_fields.is_empty()
+    {
+        text.push_str("\n\n`$search` fields:");
+        for field in &search.allowed_fields {
+            _ = write!(text, "\n- {field}");
+        }
+    }
+    let text = text.trim_start();
+    (!text.is_empty()).then(|| text.to_owned())
+}
+
+fn build_parameter(p: &operation_builder::ParamSpec) -> Parameter {
+    let in_ = match p.location {
+        operation_builder::ParamLocation::Path => ParameterIn::Path,
+        operation_builder::ParamLocation::Query => ParameterIn::Query,
+        operation_builder::ParamLocation::Header => ParameterIn::Header,
+        operation_builder::ParamLocation::Cookie => ParameterIn::Cookie,
+    };
+    let required = if matches!(p.location, operation_builder::ParamLocation::Path) || p.required {
+        Required::True
+    } else {
+        Required::False
+    };
+
+    let schema_type = match p.param_type.as_str() {
+        "integer" => SchemaType::Type(utoipa::openapi::schema::Type::Integer),
+        "number" => SchemaType::Type(utoipa::openapi::schema::Type::Number),
+        "boolean" => SchemaType::Type(utoipa::openapi::schema::Type::Boolean),
+        _ => SchemaType::Type(utoipa::openapi::schema::Type::String),
+    };
+    let schema = Schema::Object(ObjectBuilder::new().schema_type(schema_type).build());
+
+    ParameterBuilder::new()
+        .name(&p.name)
+        .parameter_in(in_)
+        .required(required)
+        .description(p.description.clone())
+        .schema(Some(schema))
+        .build()
+}
+
+fn build_response(
+    r: &operation_builder::ResponseSpec,
+    example: Option<&serde_json::Value>,
+) -> Response {
+    let is_json_like = r.content_type == "application/json"
+        || r.content_type == problem::APPLICATION_PROBLEM_JSON
+        || r.content_type == "text/event-stream";
+    let schema = if is_json_like {
+        match &r.schema_name {
+            Some(name) => RefOr::Ref(Ref::new(format!("#/components/schemas/{name}"))),
+            None => RefOr::T(Schema::Object(ObjectBuilder::new().build())),
+        }
+    } else {
+        RefOr::T(Schema::Object(
+            ObjectBuilder::new()
+                .schema_type(SchemaType::Type(utoipa::openapi::schema::Type::String))
+                .format(Some(SchemaFormat::Custom(r.content_type.into())))
+                .build(),
+        ))
+    };
+    // Manually build content to preserve the correct content type
+    let content = ContentBuilder::new().schema(Some(schema)).build();
+    ResponseBuilder::new()
+        .description(&r.description)
+        .content(r.content_type, with_example(content, example))
+        .build()
+}
+
+/// Attach an example to a media type entry, leaving it untouched when there is none.
+fn with_example(
+    mut content: utoipa::openapi::Content,
+    example: Option<&serde_json::Value>,
+) -> utoipa::openapi::Content {
+    if let Some(example) = example {
+        content.example = Some(example.clone());
+    }
+    content
+}
+
+/// Collect response examples per `(status, content type)`.
+///
+/// The first example declared for a pair wins; a different example declared
+/// later for the same pair is dropped with a warning.
+fn response_examples(
+    spec: &operation_builder::OperationSpec,
+) -> HashMap<(u16, &'static str), &serde_json::Value> {
+    let mut examples = HashMap::new();
+    for r in &spec.responses {
+        let Some(example) = &r.example else {
+            continue;
+        };
+        match examples.entry((r.status, r.content_type)) {
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(example);
+            }
+            std::collections::hash_map::Entry::Occupied(first) => {
+                if *first.get() != example {
+                    tracing::warn!(
+                        path = %spec.path,
+                        status = r.status,
+                        content_type = r.content_type,
+                        "Conflicting response examples; keeping the first one"
+                    );
+                }
+            }
+        }
+    }
+    examples
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -429,6 +784,8 @@ mod tests {
                 content_type: "application/json",
                 description: "Success".to_owned(),
                 schema_name: None,
+                example: None,
+                component: None,
             }],
             handler_id: "get_test".to_owned(),
             authenticated: false,
@@ -437,6 +794,8 @@ mod tests {
             allowed_request_content_types: None,
             vendor_extensions: VendorExtensions::default(),
             license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
         };
 
         registry.register_operation(&spec);
@@ -450,6 +809,7 @@ mod tests {
             title: "Test API".to_owned(),
             version: "1.0.0".to_owned(),
             description: Some("Test API Description".to_owned()),
+            servers: Vec::new(),
         };
         let doc = registry.build_openapi(&info).unwrap();
         let json = serde_json::to_value(&doc).unwrap();
@@ -469,6 +829,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_openapi_with_servers() {
+        let registry = OpenApiRegistryImpl::new();
+        let info = OpenApiInfo {
+            servers: vec![
+                OpenApiServer {
+                    url: "https://api.example.com".to_owned(),
+                    description: Some("Production".to_owned()),
+                },
+                OpenApiServer {
+                    url: "/api".to_owned(),
+                    description: None,
+                },
+            ],
+            ..OpenApiInfo::default()
+        };
+        let json = serde_json::to_value(registry.build_openapi(&info).unwrap()).unwrap();
+
+        assert_eq!(
+            json["servers"],
+            serde_json::json!([
+                { "url": "https://api.example.com", "description": "Production" },
+                { "url": "/api" },
+            ])
+        );
+
+        // No servers configured: the key is omitted
+        let doc = registry.build_openapi(&OpenApiInfo::default()).unwrap();
+        assert!(serde_json::to_value(doc).unwrap().get("servers").is_none());
+    }
+
+    #[test]
+    fn test_sorted_json_is_stable() {
+        let registry = OpenApiRegistryImpl::new();
+        let doc = registry.build_openapi(&OpenApiInfo::default()).unwrap();
+
+        let json = openapi_to_sorted_json(&doc).unwrap();
+        assert_eq!(json, openapi_to_sorted_json(&doc).unwrap());
+        assert!(json.ends_with('\n'));
+
+        // Top-level keys come out in lexicographic order
+        let keys: Vec<usize> = ["\"components\"", "\"info\"", "\"openapi\"", "\"paths\""]
+            .iter()
+            .map(|k| json.find(k).unwrap())
+            .collect();
+        assert!(keys.is_sorted(), "{json}");
+    }
+
     #[test]
     fn test_build_openapi_with_operation() {
         let registry = OpenApiRegistryImpl::new();
@@ -485,6 +893,7 @@ mod tests {
                 required: true,
                 description: Some("User ID".to_owned()),
                 param_type: "string".to_owned(),
+                component: None,
             }],
             request_body: None,
             responses: vec![ResponseSpec {
@@ -492,6 +901,8 @@ mod tests {
                 content_type: "application/json",
                 description: "User found".to_owned(),
                 schema_name: None,
+                example: None,
+                component: None,
             }],
             handler_id: "get_users_id".to_owned(),
             authenticated: false,
@@ -500,6 +911,8 @@ mod tests {
             allowed_request_content_types: None,
             vendor_extensions: VendorExtensions::default(),
             license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
         };
 
         registry.register_operation(&spec);
@@ -546,12 +959,15 @@ mod tests {
                 description: Some("Raw file bytes".to_owned()),
                 schema: RequestBodySchema::Binary,
                 required: true,
+                example: None,
             }),
             responses: vec![ResponseSpec {
                 status: 200,
                 content_type: "application/json",
                 description: "Upload successful".to_owned(),
                 schema_name: None,
+                example: None,
+                component: None,
             }],
             handler_id: "post_upload".to_owned(),
             authenticated: false,
@@ -560,6 +976,8 @@ mod tests {
             allowed_request_content_types: Some(vec!["application/octet-stream"]),
             vendor_extensions: VendorExtensions::default(),
             license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
         };
 
         registry.register_operation(&spec);
@@ -597,10 +1015,19 @@ mod tests {
         > = operation_builder::ODataPagination::default();
         filter.allowed_fields.insert(
             "name".to_owned(),
-            vec!["eq", "ne", "contains", "startswith", "endswith", "in"]
-                .into_iter()
-                .map(String::from)
-                .collect(),
+            vec![
+                "eq",
+                "ne",
+                "contains",
+                "startswith",
+                "endswith",
+                "in",
+                "tolower",
+                "toupper",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
         );
         filter.allowed_fields.insert(
             "age".to_owned(),
@@ -631,6 +1058,8 @@ mod tests {
                 content_type: "application/json",
                 description: "OK".to_owned(),
                 schema_name: None,
+                example: None,
+                component: None,
             }],
             handler_id: "get_test".to_owned(),
             authenticated: false,
@@ -639,6 +1068,8 @@ mod tests {
             allowed_request_content_types: None,
             vendor_extensions: VendorExtensions::default(),
             license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
         };
         spec.vendor_extensions.x_odata_filter = Some(filter);
         spec.vendor_extensions.x_odata_orderby = Some(order_by);
@@ -667,4 +1098,305 @@ mod tests {
         assert!(allowed_order.iter().any(|v| v.as_str() == Some("name asc")));
         assert!(allowed_order.iter().any(|v| v.as_str() == Some("age desc")));
     }
+
+    #[test]
+    fn test_build_openapi_with_examples() {
+        use crate::api::operation_builder::{RequestBodySchema, RequestBodySpec};
+
+        let request_example = serde_json::json!({
+            "id": "0190a1b2-c3d4-7e5f-8a9b-0c1d2e3f4a5b",
+            "created_at": "2026-01-15T10:30:00Z",
+            "tags": ["a", "b"],
+            "count": 3
+        });
+        let first = serde_json::json!({"id": 1});
+        let conflicting = serde_json::json!({"id": 2});
+
+        let registry = OpenApiRegistryImpl::new();
+        let spec = OperationSpec {
+            method: Method::POST,
+            path: "/items".to_owned(),
+            operation_id: Some("create_item".to_owned()),
+            summary: None,
+            description: None,
+            tags: vec![],
+            params: vec![],
+            request_body: Some(RequestBodySpec {
+                content_type: "application/json",
+                description: None,
+                schema: RequestBodySchema::InlineObject,
+                required: true,
+                example: Some(request_example.clone()),
+            }),
+            responses: vec![
+                ResponseSpec {
+                    status: 201,
+                    content_type: "application/json",
+                    description: "Created".to_owned(),
+                    schema_name: None,
+                    example: Some(first.clone()),
+                    component: None,
+                },
+                ResponseSpec {
+                    status: 201,
+                    content_type: "application/json",
+                    description: "Created".to_owned(),
+                    schema_name: None,
+                    example: Some(conflicting),
+                    component: None,
+                },
+                ResponseSpec {
+                    status: 500,
+                    content_type: problem::APPLICATION_PROBLEM_JSON,
+                    description: "Error".to_owned(),
+                    schema_name: None,
+                    example: None,
+                    component: None,
+                },
+            ],
+            handler_id: "post_items".to_owned(),
+            authenticated: false,
+            is_public: false,
+            rate_limit: None,
+            allowed_request_content_types: None,
+            vendor_extensions: VendorExtensions::default(),
+            license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
+        };
+
+        registry.register_operation(&spec);
+        let doc = registry.build_openapi(&OpenApiInfo::default()).unwrap();
+        let json = serde_json::to_value(&doc).unwrap();
+        let op = json.pointer("/paths/~1items/post").unwrap();
+
+        // Examples round-trip unchanged
+        assert_eq!(
+            op.pointer("/requestBody/content/application~1json/example"),
+            Some(&request_example)
+        );
+        // The first example for (201, application/json) wins
+        assert_eq!(
+            op.pointer("/responses/201/content/application~1json/example"),
+            Some(&first)
+        );
+        // Responses without an example have no `example` key
+        assert!(
+            op.pointer("/responses/500/content/application~1problem+json/example")
+                .is_none()
+        );
+    }
+
+    fn spec(method: Method, path: &str, handler_id: &str) -> OperationSpec {
+        OperationSpec {
+            method,
+            path: path.to_owned(),
+            operation_id: None,
+            summary: None,
+            description: None,
+            tags: vec![],
+            params: vec![],
+            request_body: None,
+            responses: vec![],
+            handler_id: handler_id.to_owned(),
+            authenticated: false,
+            is_public: false,
+            rate_limit: None,
+            allowed_request_content_types: None,
+            vendor_extensions: VendorExtensions::default(),
+            license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_combines_operations_and_components() {
+        let users = OpenApiRegistryImpl::new();
+        users.register_operation(&spec(Method::GET, "/users", "list_users"));
+        users.ensure_schema_raw(
+            "Shared",
+            vec![(
+                "Shared".to_owned(),
+                RefOr::T(Schema::Object(ObjectBuilder::new().build())),
+            )],
+        );
+
+        let billing = OpenApiRegistryImpl::new();
+        billing.register_operation(&spec(Method::GET, "/invoices", "list_invoices"));
+        billing.ensure_schema_raw(
+            "Shared",
+            vec![
+                (
+                    "Shared".to_owned(),
+                    RefOr::T(Schema::Object(ObjectBuilder::new().build())),
+                ),
+                (
+                    "Invoice".to_owned(),
+                    RefOr::T(Schema::Object(ObjectBuilder::new().build())),
+                ),
+            ],
+        );
+
+        let merged = users.merge(&billing).unwrap();
+        assert_eq!(merged.operation_specs.len(), 2);
+        assert_eq!(merged.components_registry.load().len(), 2);
+        assert_eq!(users.operation_specs.len(), 1);
+
+        let json =
+            serde_json::to_value(merged.build_openapi(&OpenApiInfo::default()).unwrap()).unwrap();
+        assert!(json["paths"].get("/users").is_some());
+        assert!(json["paths"].get("/invoices").is_some());
+    }
+
+    #[test]
+    fn test_merge_rejects_duplicates() {
+        let a = OpenApiRegistryImpl::new();
+        a.register_operation(&spec(Method::GET, "/users", "list_users"));
+
+        let same_handler = OpenApiRegistryImpl::new();
+        same_handler.register_operation(&spec(Method::GET, "/people", "list_users"));
+        let err = a.merge(&same_handler).unwrap_err().to_string();
+        assert!(err.contains("duplicate handler_id 'list_users'"), "{err}");
+
+        let same_route = OpenApiRegistryImpl::new();
+        same_route.register_operation(&spec(Method::GET, "/users", "list_people"));
+        let err = a.merge(&same_route).unwrap_err().to_string();
+        assert!(err.contains("GET:/users registered twice"), "{err}");
+
+        let conflicting_schema = OpenApiRegistryImpl::new();
+        a.ensure_schema_raw(
+            "User",
+            vec![(
+                "User".to_owned(),
+                RefOr::T(Schema::Object(ObjectBuilder::new().build())),
+            )],
+        );
+        conflicting_schema.ensure_schema_raw(
+            "User",
+            vec![(
+                "User".to_owned(),
+                RefOr::T(Schema::Object(
+                    ObjectBuilder::new().description(Some("other")).build(),
+                )),
+            )],
+        );
+        let err = a.merge(&conflicting_schema).unwrap_err().to_string();
+        assert!(err.contains("component schema 'User'"), "{err}");
+    }
+
+    fn query_param(description: &str, component: Option<&str>) -> ParamSpec {
+        ParamSpec {
+            name: "cursor".to_owned(),
+            location: ParamLocation::Query,
+            required: false,
+            description: Some(description.to_owned()),
+            param_type: "string".to_owned(),
+            component: component.map(str::to_owned),
+        }
+    }
+
+    fn problem_response(description: &str, component: Option<&str>) -> ResponseSpec {
+        ResponseSpec {
+            status: 400,
+            content_type: problem::APPLICATION_PROBLEM_JSON,
+            description: description.to_owned(),
+            schema_name: Some("Problem".to_owned()),
+            example: None,
+            component: component.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn test_shared_components_are_deduplicated_by_content() {
+        let registry = OpenApiRegistryImpl::new();
+
+        let first = registry.ensure_parameter_component("Cursor", query_param("Cursor", None));
+        let same = registry.ensure_parameter_component("Cursor", query_param("Cursor", None));
+        let other = registry.ensure_parameter_component("Cursor", query_param("Other", None));
+        assert_eq!(first, "Cursor");
+        assert_eq!(same, "Cursor");
+        assert!(other.starts_with("Cursor_"), "{other}");
+        assert_eq!(
+            registry.ensure_parameter_component("Cursor", query_param("Other", None)),
+            other
+        );
+        assert_eq!(registry.parameter_components.load().len(), 2);
+
+        let bad =
+            registry.ensure_response_component("BadRequest", problem_response("Bad Request", None));
+        assert_eq!(bad, "BadRequest");
+        assert_eq!(
+            registry.ensure_response_component("BadRequest", problem_response("Bad Request", None)),
+            "BadRequest"
+        );
+        assert_eq!(registry.response_components.load().len(), 1);
+    }
+
+    #[test]
+    fn test_build_openapi_with_shared_components() {
+        let registry = OpenApiRegistryImpl::new();
+        for (path, handler_id) in [("/a", "list_a"), ("/b", "list_b")] {
+            let mut op = spec(Method::GET, path, handler_id);
+            op.params = vec![
+                query_param("Cursor for pagination", Some("Cursor")),
+                ParamSpec {
+                    name: "kind".to_owned(),
+                    ..query_param("Inline", None)
+                },
+            ];
+            op.responses = vec![problem_response("Bad Request", Some("BadRequest"))];
+            registry.register_operation(&op);
+        }
+        // Same name, different content: referenced under a content-derived name
+        let mut odd = spec(Method::GET, "/c", "list_c");
+        odd.params = vec![query_param("Opaque cursor", Some("Cursor"))];
+        registry.register_operation(&odd);
+
+        let json =
+            serde_json::to_value(registry.build_openapi(&OpenApiInfo::default()).unwrap()).unwrap();
+
+        for path in ["/paths/~1a/get", "/paths/~1b/get"] {
+            let op = json.pointer(path).unwrap();
+            assert_eq!(
+                op["parameters"][0],
+                serde_json::json!({"$ref": "#/components/parameters/Cursor"})
+            );
+            assert_eq!(op["parameters"][1]["name"], "kind");
+            assert_eq!(
+                op.pointer("/responses/400/$ref"),
+                Some(&serde_json::json!("#/components/responses/BadRequest"))
+            );
+        }
+        assert_eq!(
+            json.pointer("/components/parameters/Cursor/description"),
+            Some(&serde_json::json!("Cursor for pagination"))
+        );
+        assert_eq!(
+            json.pointer("/components/responses/BadRequest/description"),
+            Some(&serde_json::json!("Bad Request"))
+        );
+
+        let odd_ref = json.pointer("/paths/~1c/get/parameters/0/$ref").unwrap();
+        let odd_ref = odd_ref.as_str().unwrap();
+        assert_ne!(odd_ref, "#/components/parameters/Cursor");
+        assert_eq!(
+            json.pointer(&format!("{}/description", &odd_ref[1..])),
+            Some(&serde_json::json!("Opaque cursor"))
+        );
+    }
+
+    #[test]
+    fn test_merge_rejects_conflicting_shared_components() {
+        let a = OpenApiRegistryImpl::new();
+        a.ensure_response_component("BadRequest", problem_response("Bad Request", None));
+        let same = OpenApiRegistryImpl::new();
+        same.ensure_response_component("BadRequest", problem_response("Bad Request", None));
+        assert_eq!(a.merge(&same).unwrap().response_components.load().len(), 1);
+
+        let other = OpenApiRegistryImpl::new();
+        other.ensure_response_component("BadRequest", problem_response("Malformed", None));
+        let err = a.merge(&other).unwrap_err().to_string();
+        assert!(err.contains("response component 'BadRequest'"), "{err}");
+    }
 }
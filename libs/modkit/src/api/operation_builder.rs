@@ -3,6 +3,7 @@
 //! This module implements a type-state builder pattern that ensures:
 //! - `register()` cannot be called unless a handler is set
 //! - `register()` cannot be called unless at least one response is declared
+//! - `register()` cannot be called unless an operation ID is set
 //! - Descriptive methods remain available at any stage
 //! - No panics or unwraps in production hot paths
 //! - Request body support (`json_request`, `json_request_schema`) so POST/PUT calls are invokable in UI
@@ -11,7 +12,8 @@
 //!   then use plain function handlers (no per-route closures that capture/clones).
 //! - Optional `method_router(...)` for advanced use (layers/middleware on route level).
 
-use crate::api::{api_dto, problem};
+use crate::api::{api_dto, json_patch, problem};
+use crate::features::FeatureGate;
 use axum::{Router, handler::Handler, routing::MethodRouter};
 use http::Method;
 use serde::{Deserialize, Serialize};
@@ -84,6 +86,14 @@ pub mod state {
     /// Marker for license requirement set
     #[derive(Debug, Clone, Copy)]
     pub struct LicenseSet;
+
+    /// Marker for operation ID not yet set
+    #[derive(Debug, Clone, Copy)]
+    pub struct IdNotSet;
+
+    /// Marker for operation ID set (explicitly or derived from method + path)
+    #[derive(Debug, Clone, Copy)]
+    pub struct IdSet;
 }
 
 /// Internal trait mapping handler state to the concrete router slot type.
@@ -93,6 +103,7 @@ mod sealed {
     pub trait Sealed {}
     pub trait SealedAuth {}
     pub trait SealedLicenseReq {}
+    pub trait SealedId {}
 }
 
 pub trait HandlerSlot<S>: sealed::Sealed {
@@ -119,6 +130,15 @@ impl sealed::SealedLicenseReq for state::LicenseSet {}
 impl LicenseState for state::LicenseNotSet {}
 impl LicenseState for state::LicenseSet {}
 
+/// Sealed trait for operation ID state markers
+pub trait IdState: sealed::SealedId {}
+
+impl sealed::SealedId for state::IdNotSet {}
+impl sealed::SealedId for state::IdSet {}
+
+impl IdState for state::IdNotSet {}
+impl IdState for state::IdSet {}
+
 impl<S> HandlerSlot<S> for Missing {
     type Slot = ();
 }
@@ -126,7 +146,9 @@ impl<S> HandlerSlot<S> for Present {
     type Slot = MethodRouter<S>;
 }
 
-pub use state::{AuthNotSet, AuthSet, LicenseNotSet, LicenseSet, Missing, Present};
+pub use state::{
+    AuthNotSet, AuthSet, IdNotSet, IdSet, LicenseNotSet, LicenseSet, Missing, Present,
+};
 
 /// Parameter specification for API operations
 #[derive(Clone, Debug)]
@@ -136,6 +158,9 @@ pub struct ParamSpec {
     pub required: bool,
     pub description: Option<String>,
     pub param_type: String, // JSON Schema type (string, integer, etc.)
+    /// Name under `components.parameters`; the operation then emits a `$ref`
+    /// instead of the inline parameter.
+    pub component: Option<String>,
 }
 
 pub trait LicenseFeature: AsRef<str> {}
@@ -173,6 +198,8 @@ pub struct RequestBodySpec {
     pub schema: RequestBodySchema,
     /// Whether request body is required (`OpenAPI` default is `false`).
     pub required: bool,
+    /// Example emitted under the media type's `example` key.
+    pub example: Option<serde_json::Value>,
 }
 
 /// Response specification for API operations
@@ -183,6 +210,11 @@ pub struct ResponseSpec {
     pub description: String,
     /// Name of a registered component schema (if any).
     pub schema_name: Option<String>,
+    /// Example emitted under the media type's `example` key.
+    pub example: Option<serde_json::Value>,
+    /// Name under `components.responses`; the operation then emits a `$ref`
+    /// instead of the inline response.
+    pub component: Option<String>,
 }
 
 /// License requirement specification for an operation
@@ -191,6 +223,16 @@ pub struct LicenseReqSpec {
     pub license_names: Vec<String>,
 }
 
+/// Feature gate recorded on an operation.
+///
+/// The enabled state is resolved from the module's `FeatureGate` when the builder
+/// method is called, so registries can tell why an operation was skipped.
+#[derive(Clone, Debug)]
+pub struct FeatureGateSpec {
+    pub feature: String,
+    pub enabled: bool,
+}
+
 /// Simplified operation specification for the type-safe builder
 #[derive(Clone, Debug)]
 pub struct OperationSpec {
@@ -221,6 +263,20 @@ pub struct OperationSpec {
     /// `OpenAPI` vendor extensions (x-*)
     pub vendor_extensions: VendorExtensions,
     pub license_requirement: Option<LicenseReqSpec>,
+    /// Optional module feature gating this operation.
+    /// Disabled operations are neither routed nor published in `OpenAPI`.
+    pub feature_gate: Option<FeatureGateSpec>,
+    /// Optional named execution group (bulkhead) defined in the gateway config.
+    /// Operations of a group share its concurrency limit and queue.
+    pub bulkhead: Option<String>,
+}
+
+impl OperationSpec {
+    /// Returns `false` if the operation is gated behind a disabled feature.
+    #[must_use]
+    pub fn is_feature_enabled(&self) -> bool {
+        self.feature_gate.as_ref().is_none_or(|g| g.enabled)
+    }
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -229,6 +285,8 @@ pub struct VendorExtensions {
     pub x_odata_filter: Option<ODataPagination<BTreeMap<String, Vec<String>>>>,
     #[serde(rename = "x-odata-orderby", skip_serializing_if = "Option::is_none")]
     pub x_odata_orderby: Option<ODataPagination<Vec<String>>>,
+    #[serde(rename = "x-odata-search", skip_serializing_if = "Option::is_none")]
+    pub x_odata_search: Option<ODataPagination<Vec<String>>>,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -246,6 +304,24 @@ pub struct RateLimitSpec {
     pub burst: u32,
     /// Maximum number of in-flight requests for this route
     pub in_flight: u32,
+    /// What each token bucket is keyed by.
+    pub key_by: RateLimitKeyBy,
+}
+
+/// Key of the per-route token buckets.
+///
+/// Subject-keyed buckets are checked after authentication. Requests without an
+/// authenticated subject (public routes, anonymous callers) fall back to the
+/// client IP.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RateLimitKeyBy {
+    /// One bucket per client IP.
+    #[default]
+    Ip,
+    /// One bucket per authenticated subject, whatever its client IP.
+    SubjectId,
+    /// One bucket per subject and client IP pair.
+    IpAndSubjectId,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Default)]
@@ -258,34 +334,60 @@ pub struct XPagination {
 //
 pub trait OperationBuilderODataExt<S, H, R> {
     /// Adds optional `$filter` query parameter to `OpenAPI`.
+    ///
+    /// The parameter is the shared `ODataFilter` component; the filterable
+    /// fields of `T` are listed in the operation description. String fields
+    /// also list the `tolower`/`toupper` functions, and JSON fields list each
+    /// allowed path as `field/path`.
     #[must_use]
     fn with_odata_filter<T>(self) -> Self
     where
         T: modkit_odata::filter::FilterField;
 
-    /// Adds optional `$select` query parameter to `OpenAPI`.
+    /// Adds optional `$select` query parameter (shared `ODataSelect` component) to `OpenAPI`.
     #[must_use]
     fn with_odata_select(self) -> Self;
 
     /// Adds optional `$orderby` query parameter to `OpenAPI`.
+    ///
+    /// The parameter is the shared `ODataOrderBy` component; the sortable
+    /// fields of `T` are listed in the operation description. `T` is either a
+    /// `FilterField` enum (its non-JSON fields are sortable) or a
+    /// `SortableField` enum such as the `*SortField` generated by
+    /// `#[derive(ODataFilterable)]`.
     #[must_use]
     fn with_odata_orderby<T>(self) -> Self
     where
-        T: modkit_odata::filter::FilterField;
+        T: modkit_odata::sort::IntoSortableFields;
+
+    /// Adds optional `$search` query parameter to `OpenAPI`.
+    ///
+    /// The parameter is the shared `ODataSearch` component; the fields of `T`
+    /// that search terms are matched against are listed in the operation
+    /// description.
+    #[must_use]
+    fn with_odata_search<T>(self) -> Self
+    where
+        T: modkit_odata::SearchableFields;
+
+    /// Adds optional `limit` and `cursor` query parameters (shared `Limit` and
+    /// `Cursor` components) to `OpenAPI`.
+    #[must_use]
+    fn with_cursor_pagination(self) -> Self;
 }
 
-impl<S, H, R, A, L> OperationBuilderODataExt<S, H, R> for OperationBuilder<H, R, S, A, L>
+impl<S, H, R, A, L, I> OperationBuilderODataExt<S, H, R> for OperationBuilder<H, R, S, A, L, I>
 where
     H: HandlerSlot<S>,
     A: AuthState,
     L: LicenseState,
+    I: IdState,
 {
     fn with_odata_filter<T>(mut self) -> Self
     where
         T: modkit_odata::filter::FilterField,
     {
-        use modkit_odata::filter::FieldKind;
-        use std::fmt::Write as _;
+        use modkit_odata::filter::{CaseFold, FieldKind};
 
         let mut filter = self
             .spec
@@ -293,15 +395,19 @@ where
             .x_odata_filter
             .unwrap_or_default();
 
-        let mut description = "OData v4 filter expression".to_owned();
         for field in T::FIELDS {
             let name = field.name().to_owned();
             let kind = field.kind();
 
+            // String fields also accept the case-folding functions, e.g. `tolower(name) eq 'x'`
             let ops: Vec<String> = match kind {
-                FieldKind::String => vec!["eq", "ne", "contains", "startswith", "endswith", "in"],
+                FieldKind::String => {
+                    let mut ops = vec!["eq", "ne", "contains", "startswith", "endswith", "in"];
+                    ops.extend(CaseFold::ALL.iter().map(|f| f.function_name()));
+                    ops
+                }
                 FieldKind::Uuid => vec!["eq", "ne", "in"],
-                FieldKind::Bool => vec!["eq", "ne"],
+                FieldKind::Bool | FieldKind::Json { .. } => vec!["eq", "ne"],
                 FieldKind::I64
                 | FieldKind::F64
                 | FieldKind::Decimal
@@ -315,50 +421,51 @@ where
             .map(String::from)
             .collect();
 
-            _ = write!(description, "\n- {}: {}", name, ops.join("|"));
-            filter.allowed_fields.insert(name.clone(), ops);
+            // JSON fields are only filterable through their allowed paths
+            if let FieldKind::Json { allowed_paths } = kind {
+                for path in allowed_paths {
+                    filter
+                        .allowed_fields
+                        .insert(format!("{name}/{path}"), ops.clone());
+                }
+            } else {
+                filter.allowed_fields.insert(name, ops);
+            }
         }
-        self.spec.params.push(ParamSpec {
-            name: "$filter".to_owned(),
-            location: ParamLocation::Query,
-            required: false,
-            description: Some(description),
-            param_type: "string".to_owned(),
-        });
+        self.spec.params.push(shared_query_param(
+            "ODataFilter",
+            "$filter",
+            "OData v4 filter expression",
+            "string",
+        ));
         self.spec.vendor_extensions.x_odata_filter = Some(filter);
         self
     }
 
     fn with_odata_select(mut self) -> Self {
-        self.spec.params.push(ParamSpec {
-            name: "$select".to_owned(),
-            location: ParamLocation::Query,
-            required: false,
-            description: Some("OData v4 select expression".to_owned()),
-            param_type: "string".to_owned(),
-        });
+        self.spec.params.push(shared_query_param(
+            "ODataSelect",
+            "$select",
+            "OData v4 select expression",
+            "string",
+        ));
         self
     }
 
     fn with_odata_orderby<T>(mut self) -> Self
     where
-        T: modkit_odata::filter::FilterField,
+        T: modkit_odata::sort::IntoSortableFields,
     {
-        use std::fmt::Write as _;
         let mut order_by = self
             .spec
             .vendor_extensions
             .x_odata_orderby
             .unwrap_or_default();
-        let mut description = "OData v4 orderby expression".to_owned();
-        for field in T::FIELDS {
-            let name = field.name().to_owned();
-
+        for name in T::sortable_field_names() {
             // Add sort options (asc/desc)
             let asc = format!("{name} asc");
             let desc = format!("{name} desc");
 
-            _ = write!(description, "\n- {asc}\n- {desc}");
             if !order_by.allowed_fields.contains(&asc) {
                 order_by.allowed_fields.push(asc);
             }
@@ -366,21 +473,88 @@ where
                 order_by.allowed_fields.push(desc);
             }
         }
-        self.spec.params.push(ParamSpec {
-            name: "$orderby".to_owned(),
-            location: ParamLocation::Query,
-            required: false,
-            description: Some(description),
-            param_type: "string".to_owned(),
-        });
+        self.spec.params.push(shared_query_param(
+            "ODataOrderBy",
+            "$orderby",
+            "OData v4 orderby expression",
+            "string",
+        ));
         self.spec.vendor_extensions.x_odata_orderby = Some(order_by);
         self
     }
+
+    fn with_odata_search<T>(mut self) -> Self
+    where
+        T: modkit_odata::SearchableFields,
+    {
+        use modkit_odata::filter::FilterField as _;
+
+        let mut search = self
+            .spec
+            .vendor_extensions
+            .x_odata_search
+            .unwrap_or_default();
+        for field in T::FIELDS {
+            let name = field.field.name().to_owned();
+            if !search.allowed_fields.contains(&name) {
+                search.allowed_fields.push(name);
+            }
+        }
+        self.spec.params.push(shared_query_param(
+            "ODataSearch",
+            "$search",
+            "OData v4 search expression: terms and \"quoted phrases\", all of which must match",
+            "string",
+        ));
+        self.spec.vendor_extensions.x_odata_search = Some(search);
+        self
+    }
+
+    fn with_cursor_pagination(mut self) -> Self {
+        self.spec.params.push(shared_query_param(
+            "Limit",
+            "limit",
+            "Maximum number of items to return",
+            "integer",
+        ));
+        self.spec.params.push(shared_query_param(
+            "Cursor",
+            "cursor",
+            "Cursor for pagination",
+            "string",
+        ));
+        self
+    }
+}
+
+/// Optional query parameter published once under `components.parameters.{component}`.
+fn shared_query_param(
+    component: &str,
+    name: &str,
+    description: &str,
+    param_type: &str,
+) -> ParamSpec {
+    ParamSpec {
+        name: name.to_owned(),
+        location: ParamLocation::Query,
+        required: false,
+        description: Some(description.to_owned()),
+        param_type: param_type.to_owned(),
+        component: Some(component.to_owned()),
+    }
 }
 
 // Re-export from openapi_registry for backward compatibility
 pub use crate::api::openapi_registry::{OpenApiRegistry, ensure_schema};
 
+/// Serialize an `OpenAPI` example; a value that fails to serialize is dropped
+/// with a warning rather than failing route registration.
+fn example_value<T: Serialize>(example: &T) -> Option<serde_json::Value> {
+    serde_json::to_value(example)
+        .inspect_err(|e| tracing::warn!(error = %e, "Failed to serialize OpenAPI example"))
+        .ok()
+}
+
 /// Type-safe operation builder with compile-time guarantees.
 ///
 /// Generic parameters:
@@ -389,12 +563,20 @@ pub use crate::api::openapi_registry::{OpenApiRegistry, ensure_schema};
 /// - `S`: Router state type (what you put into `Router::with_state(S)`).
 /// - `A`: Auth state (`AuthNotSet` | `AuthSet`)
 /// - `L`: License requirement state (`LicenseNotSet` | `LicenseSet`)
+/// - `I`: Operation ID state (`IdNotSet` | `IdSet`)
 #[must_use]
-pub struct OperationBuilder<H = Missing, R = Missing, S = (), A = AuthNotSet, L = LicenseNotSet>
-where
+pub struct OperationBuilder<
+    H = Missing,
+    R = Missing,
+    S = (),
+    A = AuthNotSet,
+    L = LicenseNotSet,
+    I = IdNotSet,
+> where
     H: HandlerSlot<S>,
     A: AuthState,
     L: LicenseState,
+    I: IdState,
 {
     spec: OperationSpec,
     method_router: <H as HandlerSlot<S>>::Slot,
@@ -404,6 +586,7 @@ where
     _state: PhantomData<fn() -> S>, // Zero-sized marker for type-state pattern
     _auth_state: PhantomData<A>,
     _license_state: PhantomData<L>,
+    _id_state: PhantomData<I>,
 }
 
 // -------------------------------------------------------------------------------------------------
@@ -437,6 +620,8 @@ impl<S> OperationBuilder<Missing, Missing, S, AuthNotSet> {
                 allowed_request_content_types: None,
                 vendor_extensions: VendorExtensions::default(),
                 license_requirement: None,
+                feature_gate: None,
+                bulkhead: None,
             },
             method_router: (), // no router in Missing state
             _has_handler: PhantomData,
@@ -444,6 +629,7 @@ impl<S> OperationBuilder<Missing, Missing, S, AuthNotSet> {
             _state: PhantomData,
             _auth_state: PhantomData,
             _license_state: PhantomData,
+            _id_state: PhantomData,
         }
     }
 
@@ -481,23 +667,18 @@ impl<S> OperationBuilder<Missing, Missing, S, AuthNotSet> {
 // -------------------------------------------------------------------------------------------------
 // Descriptive methods — available at any stage
 // -------------------------------------------------------------------------------------------------
-impl<H, R, S, A, L> OperationBuilder<H, R, S, A, L>
+impl<H, R, S, A, L, I> OperationBuilder<H, R, S, A, L, I>
 where
     H: HandlerSlot<S>,
     A: AuthState,
     L: LicenseState,
+    I: IdState,
 {
     /// Inspect the spec (primarily for tests)
     pub fn spec(&self) -> &OperationSpec {
         &self.spec
     }
 
-    /// Set the operation ID
-    pub fn operation_id(mut self, id: impl Into<String>) -> Self {
-        self.spec.operation_id = Some(id.into());
-        self
-    }
-
     /// Require per-route rate and concurrency limits.
     /// Stores metadata for the gateway to enforce.
     pub fn require_rate_limit(&mut self, rps: u32, burst: u32, in_flight: u32) -> &mut Self {
@@ -505,6 +686,32 @@ where
             rps,
             burst,
             in_flight,
+            key_by: RateLimitKeyBy::Ip,
+        });
+        self
+    }
+
+    /// Require per-route limits with one token bucket per authenticated subject.
+    ///
+    /// For endpoints acting on the caller's own data (e.g. `/me`), where many
+    /// users may share a client IP but each should get its own budget.
+    pub fn per_subject_rate_limit(self, rps: u32, burst: u32, in_flight: u32) -> Self {
+        self.rate_limit_keyed_by(RateLimitKeyBy::SubjectId, rps, burst, in_flight)
+    }
+
+    /// Require per-route limits with token buckets keyed by `key_by`.
+    pub fn rate_limit_keyed_by(
+        mut self,
+        key_by: RateLimitKeyBy,
+        rps: u32,
+        burst: u32,
+        in_flight: u32,
+    ) -> Self {
+        self.spec.rate_limit = Some(RateLimitSpec {
+            rps,
+            burst,
+            in_flight,
+            key_by,
         });
         self
     }
@@ -541,6 +748,7 @@ where
             required: true,
             description: Some(description.into()),
             param_type: "string".to_owned(),
+            component: None,
         });
         self
     }
@@ -558,6 +766,7 @@ where
             required,
             description: Some(description.into()),
             param_type: "string".to_owned(),
+            component: None,
         });
         self
     }
@@ -576,6 +785,7 @@ where
             required,
             description: Some(description.into()),
             param_type: param_type.into(),
+            component: None,
         });
         self
     }
@@ -594,6 +804,7 @@ where
                 schema_name: schema_name.into(),
             },
             required: true,
+            example: None,
         });
         self
     }
@@ -608,6 +819,7 @@ where
                 schema_name: schema_name.into(),
             },
             required: true,
+            example: None,
         });
         self
     }
@@ -628,6 +840,7 @@ where
             description: Some(desc.into()),
             schema: RequestBodySchema::Ref { schema_name: name },
             required: true,
+            example: None,
         });
         self
     }
@@ -644,7 +857,52 @@ where
             description: None,
             schema: RequestBodySchema::Ref { schema_name: name },
             required: true,
+            example: None,
+        });
+        self
+    }
+
+    /// Attach a JSON request body (auto-register schema) together with an example value.
+    ///
+    /// The example is serialized once, here, and emitted under the media type's
+    /// `example` key. Marks the body as **required**.
+    pub fn json_request_example<T>(
+        self,
+        registry: &dyn OpenApiRegistry,
+        desc: impl Into<String>,
+        example: &T,
+    ) -> Self
+    where
+        T: utoipa::ToSchema + utoipa::PartialSchema + api_dto::RequestApiDto + Serialize + 'static,
+    {
+        let mut this = self.json_request::<T>(registry, desc);
+        if let Some(rb) = &mut this.spec.request_body {
+            rb.example = example_value(example);
+        }
+        this
+    }
+
+    /// Attach an RFC 6902 JSON Patch request body (`application/json-patch+json`).
+    ///
+    /// Registers the `JsonPatchDocument` schema and restricts the request to the
+    /// JSON Patch and plain JSON media types. Pair it with the
+    /// [`JsonPatch`](crate::api::json_patch::JsonPatch) extractor in the handler.
+    /// Marks the body as **required**.
+    pub fn json_patch_request(
+        mut self,
+        registry: &dyn OpenApiRegistry,
+        desc: impl Into<String>,
+    ) -> Self {
+        let name = ensure_schema::<json_patch::JsonPatchDocument>(registry);
+        self.spec.request_body = Some(RequestBodySpec {
+            content_type: json_patch::APPLICATION_JSON_PATCH,
+            description: Some(desc.into()),
+            schema: RequestBodySchema::Ref { schema_name: name },
+            required: true,
+            example: None,
         });
+        self.spec.allowed_request_content_types =
+            Some(vec![json_patch::APPLICATION_JSON_PATCH, "application/json"]);
         self
     }
 
@@ -703,6 +961,7 @@ where
                 field_name: field_name.to_owned(),
             },
             required: true,
+            example: None,
         });
 
         // Also configure MIME type validation
@@ -760,6 +1019,7 @@ where
             description: description.map(ToString::to_string),
             schema: RequestBodySchema::Binary,
             required: true,
+            example: None,
         });
 
         // Also configure MIME type validation
@@ -801,12 +1061,140 @@ where
         self.spec.allowed_request_content_types = Some(types.to_vec());
         self
     }
+
+    /// Gate this operation behind a module feature flag.
+    ///
+    /// When the feature is disabled in the module config, `register()` becomes a no-op:
+    /// the route is not added to the router and the operation is omitted from `OpenAPI`.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use axum::Router;
+    /// # use http::StatusCode;
+    /// # use modkit::api::{
+    /// #     openapi_registry::OpenApiRegistryImpl,
+    /// #     operation_builder::OperationBuilder,
+    /// # };
+    /// # use modkit::FeatureGate;
+    /// # async fn handler() -> &'static str { "ok" }
+    /// # let registry = OpenApiRegistryImpl::new();
+    /// # let router: Router<()> = Router::new();
+    /// let features = FeatureGate::new("users-info", [("addresses".to_owned(), false)]);
+    /// let router = OperationBuilder::get("/users-info/v1/users/{id}/address")
+    ///     .operation_id("users_info.get_user_address")
+    ///     .feature_gate(&features, "addresses")
+    ///     .public()
+    ///     .handler(handler)
+    ///     .json_response(StatusCode::OK, "Address")
+    ///     .register(router, &registry);
+    /// # let _ = router;
+    /// ```
+    pub fn feature_gate(mut self, features: &FeatureGate, feature: impl Into<String>) -> Self {
+        let feature = feature.into();
+        let enabled = features.is_enabled(&feature);
+        self.spec.feature_gate = Some(FeatureGateSpec { feature, enabled });
+        self
+    }
+
+    /// Run this operation in a named execution group (bulkhead).
+    ///
+    /// Groups are defined in the gateway config (`bulkheads.<name>`) with their own
+    /// concurrency limit, queue depth and, for CPU-heavy endpoints, a dedicated
+    /// runtime, so a saturated group does not starve unrelated routes.
+    /// The gateway refuses to start if the group is not configured.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use axum::Router;
+    /// # use http::StatusCode;
+    /// # use modkit::api::{
+    /// #     openapi_registry::OpenApiRegistryImpl,
+    /// #     operation_builder::OperationBuilder,
+    /// # };
+    /// # async fn export_handler() -> &'static str { "ok" }
+    /// # let registry = OpenApiRegistryImpl::new();
+    /// # let router: Router<()> = Router::new();
+    /// let router = OperationBuilder::get("/reports/v1/export")
+    ///     .operation_id("reports.export")
+    ///     .bulkhead("exports")
+    ///     .public()
+    ///     .handler(export_handler)
+    ///     .json_response(StatusCode::OK, "Export")
+    ///     .register(router, &registry);
+    /// # let _ = router;
+    /// ```
+    pub fn bulkhead(mut self, name: impl Into<String>) -> Self {
+        self.spec.bulkhead = Some(name.into());
+        self
+    }
+}
+
+// -------------------------------------------------------------------------------------------------
+// Operation ID setting — transitions IdNotSet -> IdSet
+// -------------------------------------------------------------------------------------------------
+impl<H, R, S, A, L> OperationBuilder<H, R, S, A, L, IdNotSet>
+where
+    H: HandlerSlot<S>,
+    A: AuthState,
+    L: LicenseState,
+{
+    /// Set the operation ID.
+    ///
+    /// Required before `register()`; use [`Self::auto_operation_id`] to derive
+    /// one from the method and path instead.
+    pub fn operation_id(mut self, id: impl Into<String>) -> OperationBuilder<H, R, S, A, L, IdSet> {
+        self.spec.operation_id = Some(id.into());
+        self.with_id_set()
+    }
+
+    /// Derive the operation ID from the HTTP method and path,
+    /// e.g. `GET /users-info/v1/users/{id}` becomes `get_users_info_v1_users_id`.
+    ///
+    /// Intended for internal or test routes; public APIs should prefer a stable,
+    /// hand-picked ID set via [`Self::operation_id`].
+    pub fn auto_operation_id(mut self) -> OperationBuilder<H, R, S, A, L, IdSet> {
+        self.spec.operation_id = Some(derive_operation_id(&self.spec.method, &self.spec.path));
+        self.with_id_set()
+    }
+
+    fn with_id_set(self) -> OperationBuilder<H, R, S, A, L, IdSet> {
+        OperationBuilder {
+            spec: self.spec,
+            method_router: self.method_router,
+            _has_handler: self._has_handler,
+            _has_response: self._has_response,
+            _state: self._state,
+            _auth_state: self._auth_state,
+            _license_state: self._license_state,
+            _id_state: PhantomData,
+        }
+    }
+}
+
+/// Build an operation ID from method and path: lowercase, with every
+/// non-alphanumeric run collapsed into a single `_`.
+fn derive_operation_id(method: &Method, path: &str) -> String {
+    let mut id = method.as_str().to_lowercase();
+    let mut pending_sep = true;
+    for c in path.chars() {
+        if c.is_ascii_alphanumeric() {
+            if pending_sep {
+                id.push('_');
+                pending_sep = false;
+            }
+            id.push(c.to_ascii_lowercase());
+        } else {
+            pending_sep = true;
+        }
+    }
+    id
 }
 
 /// License requirement setting — transitions `LicenseNotSet` -> `LicenseSet`
-impl<H, R, S> OperationBuilder<H, R, S, AuthSet, LicenseNotSet>
+impl<H, R, S, I> OperationBuilder<H, R, S, AuthSet, LicenseNotSet, I>
 where
     H: HandlerSlot<S>,
+    I: IdState,
 {
     /// Set (or explicitly clear) the license feature requirement for this operation.
     ///
@@ -823,7 +1211,7 @@ where
     pub fn require_license_features<F>(
         mut self,
         licenses: impl IntoIterator<Item = F>,
-    ) -> OperationBuilder<H, R, S, AuthSet, LicenseSet>
+    ) -> OperationBuilder<H, R, S, AuthSet, LicenseSet, I>
     where
         F: LicenseFeature,
     {
@@ -843,6 +1231,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: PhantomData,
+            _id_state: self._id_state,
         }
     }
 
@@ -853,7 +1242,7 @@ where
     ///
     /// This transitions from `LicenseNotSet` to `LicenseSet` without
     /// attaching any license requirement.
-    pub fn no_license_required(self) -> OperationBuilder<H, R, S, AuthSet, LicenseSet> {
+    pub fn no_license_required(self) -> OperationBuilder<H, R, S, AuthSet, LicenseSet, I> {
         OperationBuilder {
             spec: self.spec,
             method_router: self.method_router,
@@ -862,6 +1251,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: PhantomData,
+            _id_state: self._id_state,
         }
     }
 }
@@ -869,10 +1259,11 @@ where
 // -------------------------------------------------------------------------------------------------
 // Auth requirement setting — transitions AuthNotSet -> AuthSet
 // -------------------------------------------------------------------------------------------------
-impl<H, R, S, L> OperationBuilder<H, R, S, AuthNotSet, L>
+impl<H, R, S, L, I> OperationBuilder<H, R, S, AuthNotSet, L, I>
 where
     H: HandlerSlot<S>,
     L: LicenseState,
+    I: IdState,
 {
     /// Mark this route as requiring authentication.
     ///
@@ -911,6 +1302,7 @@ where
     /// #   api: &dyn modkit::api::OpenApiRegistry,
     /// # ) -> anyhow::Result<axum::Router> {
     /// let router = OperationBuilder::get("/users-info/v1/users")
+    ///     .operation_id("users_info.list_users")
     ///     .authenticated()
     ///     .require_license_features::<License>([])
     ///     .handler(list_users_handler)
@@ -923,7 +1315,7 @@ where
     /// #   unimplemented!()
     /// # }
     /// ```
-    pub fn authenticated(mut self) -> OperationBuilder<H, R, S, AuthSet, L> {
+    pub fn authenticated(mut self) -> OperationBuilder<H, R, S, AuthSet, L, I> {
         self.spec.authenticated = true;
         self.spec.is_public = false;
         OperationBuilder {
@@ -934,6 +1326,7 @@ where
             _state: self._state,
             _auth_state: PhantomData,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
@@ -954,13 +1347,14 @@ where
     /// # let registry = OpenApiRegistryImpl::new();
     /// # let router: Router<()> = Router::new();
     /// let router = OperationBuilder::get("/users-info/v1/health")
+    ///     .operation_id("users_info.health")
     ///     .public()
     ///     .handler(health_check)
     ///     .json_response(StatusCode::OK, "OK")
     ///     .register(router, &registry);
     /// # let _ = router;
     /// ```
-    pub fn public(mut self) -> OperationBuilder<H, R, S, AuthSet, LicenseSet> {
+    pub fn public(mut self) -> OperationBuilder<H, R, S, AuthSet, LicenseSet, I> {
         self.spec.is_public = true;
         self.spec.authenticated = false;
         OperationBuilder {
@@ -971,6 +1365,7 @@ where
             _state: self._state,
             _auth_state: PhantomData,
             _license_state: PhantomData,
+            _id_state: self._id_state,
         }
     }
 }
@@ -978,16 +1373,17 @@ where
 // -------------------------------------------------------------------------------------------------
 // Handler setting — transitions Missing -> Present for handler
 // -------------------------------------------------------------------------------------------------
-impl<R, S, A, L> OperationBuilder<Missing, R, S, A, L>
+impl<R, S, A, L, I> OperationBuilder<Missing, R, S, A, L, I>
 where
     S: Clone + Send + Sync + 'static,
     A: AuthState,
     L: LicenseState,
+    I: IdState,
 {
     /// Set the handler for this operation (function handlers are recommended).
     ///
     /// This transitions the builder from `Missing` to `Present` handler state.
-    pub fn handler<F, T>(self, h: F) -> OperationBuilder<Present, R, S, A, L>
+    pub fn handler<F, T>(self, h: F) -> OperationBuilder<Present, R, S, A, L, I>
     where
         F: Handler<T, S> + Clone + Send + 'static,
         T: 'static,
@@ -1009,12 +1405,13 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
     /// Alternative path: provide a pre-composed `MethodRouter<S>` yourself
     /// (useful to attach per-route middleware/layers).
-    pub fn method_router(self, mr: MethodRouter<S>) -> OperationBuilder<Present, R, S, A, L> {
+    pub fn method_router(self, mr: MethodRouter<S>) -> OperationBuilder<Present, R, S, A, L, I> {
         OperationBuilder {
             spec: self.spec,
             method_router: mr, // concrete MethodRouter<S> in Present state
@@ -1023,6 +1420,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 }
@@ -1030,14 +1428,15 @@ where
 // -------------------------------------------------------------------------------------------------
 // Response setting — transitions Missing -> Present for response (first response)
 // -------------------------------------------------------------------------------------------------
-impl<H, S, A, L> OperationBuilder<H, Missing, S, A, L>
+impl<H, S, A, L, I> OperationBuilder<H, Missing, S, A, L, I>
 where
     H: HandlerSlot<S>,
     A: AuthState,
     L: LicenseState,
+    I: IdState,
 {
     /// Add a raw response spec (transitions from Missing to Present).
-    pub fn response(mut self, resp: ResponseSpec) -> OperationBuilder<H, Present, S, A, L> {
+    pub fn response(mut self, resp: ResponseSpec) -> OperationBuilder<H, Present, S, A, L, I> {
         self.spec.responses.push(resp);
         OperationBuilder {
             spec: self.spec,
@@ -1047,6 +1446,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
@@ -1055,12 +1455,14 @@ where
         mut self,
         status: http::StatusCode,
         description: impl Into<String>,
-    ) -> OperationBuilder<H, Present, S, A, L> {
+    ) -> OperationBuilder<H, Present, S, A, L, I> {
         self.spec.responses.push(ResponseSpec {
             status: status.as_u16(),
             content_type: "application/json",
             description: description.into(),
             schema_name: None,
+            example: None,
+            component: None,
         });
         OperationBuilder {
             spec: self.spec,
@@ -1070,6 +1472,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
@@ -1079,7 +1482,7 @@ where
         registry: &dyn OpenApiRegistry,
         status: http::StatusCode,
         description: impl Into<String>,
-    ) -> OperationBuilder<H, Present, S, A, L>
+    ) -> OperationBuilder<H, Present, S, A, L, I>
     where
         T: utoipa::ToSchema + utoipa::PartialSchema + api_dto::ResponseApiDto + 'static,
     {
@@ -1089,6 +1492,8 @@ where
             content_type: "application/json",
             description: description.into(),
             schema_name: Some(name),
+            example: None,
+            component: None,
         });
         OperationBuilder {
             spec: self.spec,
@@ -1098,9 +1503,32 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
+    /// Add a JSON response with a registered schema and an example value
+    /// (transitions from Missing to Present).
+    ///
+    /// The example is serialized once, here, and emitted under the media type's
+    /// `example` key.
+    pub fn json_response_with_example<T>(
+        self,
+        registry: &dyn OpenApiRegistry,
+        status: http::StatusCode,
+        description: impl Into<String>,
+        example: &T,
+    ) -> OperationBuilder<H, Present, S, A, L, I>
+    where
+        T: utoipa::ToSchema + utoipa::PartialSchema + api_dto::ResponseApiDto + Serialize + 'static,
+    {
+        let mut this = self.json_response_with_schema::<T>(registry, status, description);
+        if let Some(resp) = this.spec.responses.last_mut() {
+            resp.example = example_value(example);
+        }
+        this
+    }
+
     /// Add a text response with a custom content type (transitions from Missing to Present).
     ///
     /// # Arguments
@@ -1118,12 +1546,14 @@ where
         status: http::StatusCode,
         description: impl Into<String>,
         content_type: &'static str,
-    ) -> OperationBuilder<H, Present, S, A, L> {
+    ) -> OperationBuilder<H, Present, S, A, L, I> {
         self.spec.responses.push(ResponseSpec {
             status: status.as_u16(),
             content_type,
             description: description.into(),
             schema_name: None,
+            example: None,
+            component: None,
         });
         OperationBuilder {
             spec: self.spec,
@@ -1133,6 +1563,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
@@ -1141,12 +1572,14 @@ where
         mut self,
         status: http::StatusCode,
         description: impl Into<String>,
-    ) -> OperationBuilder<H, Present, S, A, L> {
+    ) -> OperationBuilder<H, Present, S, A, L, I> {
         self.spec.responses.push(ResponseSpec {
             status: status.as_u16(),
             content_type: "text/html",
             description: description.into(),
             schema_name: None,
+            example: None,
+            component: None,
         });
         OperationBuilder {
             spec: self.spec,
@@ -1156,6 +1589,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
@@ -1165,7 +1599,7 @@ where
         registry: &dyn OpenApiRegistry,
         status: http::StatusCode,
         description: impl Into<String>,
-    ) -> OperationBuilder<H, Present, S, A, L> {
+    ) -> OperationBuilder<H, Present, S, A, L, I> {
         // Ensure `Problem` schema is registered in components
         let problem_name = ensure_schema::<crate::api::problem::Problem>(registry);
         self.spec.responses.push(ResponseSpec {
@@ -1173,6 +1607,8 @@ where
             content_type: problem::APPLICATION_PROBLEM_JSON,
             description: description.into(),
             schema_name: Some(problem_name),
+            example: None,
+            component: None,
         });
         OperationBuilder {
             spec: self.spec,
@@ -1182,6 +1618,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 
@@ -1190,7 +1627,7 @@ where
         mut self,
         openapi: &dyn OpenApiRegistry,
         description: impl Into<String>,
-    ) -> OperationBuilder<H, Present, S, A, L>
+    ) -> OperationBuilder<H, Present, S, A, L, I>
     where
         T: utoipa::ToSchema + utoipa::PartialSchema + api_dto::ResponseApiDto + 'static,
     {
@@ -1200,6 +1637,8 @@ where
             content_type: "text/event-stream",
             description: description.into(),
             schema_name: Some(name),
+            example: None,
+            component: None,
         });
         OperationBuilder {
             spec: self.spec,
@@ -1209,6 +1648,7 @@ where
             _state: self._state,
             _auth_state: self._auth_state,
             _license_state: self._license_state,
+            _id_state: self._id_state,
         }
     }
 }
@@ -1216,11 +1656,12 @@ where
 // -------------------------------------------------------------------------------------------------
 // Additional responses — for Present response state (additional responses)
 // -------------------------------------------------------------------------------------------------
-impl<H, S, A, L> OperationBuilder<H, Present, S, A, L>
+impl<H, S, A, L, I> OperationBuilder<H, Present, S, A, L, I>
 where
     H: HandlerSlot<S>,
     A: AuthState,
     L: LicenseState,
+    I: IdState,
 {
     /// Add a JSON response (additional).
     pub fn json_response(
@@ -1233,6 +1674,8 @@ where
             content_type: "application/json",
             description: description.into(),
             schema_name: None,
+            example: None,
+            component: None,
         });
         self
     }
@@ -1253,10 +1696,65 @@ where
             content_type: "application/json",
             description: description.into(),
             schema_name: Some(name),
+            example: None,
+            component: None,
         });
         self
     }
 
+    /// Add a JSON response with a registered schema and an example value (additional).
+    pub fn json_response_with_example<T>(
+        self,
+        registry: &dyn OpenApiRegistry,
+        status: http::StatusCode,
+        description: impl Into<String>,
+        example: &T,
+    ) -> Self
+    where
+        T: utoipa::ToSchema + utoipa::PartialSchema + api_dto::ResponseApiDto + Serialize + 'static,
+    {
+        let mut this = self.json_response_with_schema::<T>(registry, status, description);
+        if let Some(resp) = this.spec.responses.last_mut() {
+            resp.example = example_value(example);
+        }
+        this
+    }
+
+    /// Attach an example to an already declared response.
+    ///
+    /// The example goes to the first response declared for `status`. A response
+    /// that already has a different example keeps it, and a warning is logged;
+    /// an unknown `status` is ignored with a warning as well.
+    pub fn response_example(
+        mut self,
+        status: http::StatusCode,
+        example: serde_json::Value,
+    ) -> Self {
+        let path = &self.spec.path;
+        match self
+            .spec
+            .responses
+            .iter_mut()
+            .find(|r| r.status == status.as_u16())
+        {
+            Some(resp) => match &resp.example {
+                None => resp.example = Some(example),
+                Some(existing) if *existing == example => {}
+                Some(_) => tracing::warn!(
+                    path = %path,
+                    status = status.as_u16(),
+                    "Conflicting response example; keeping the first one"
+                ),
+            },
+            None => tracing::warn!(
+                path = %path,
+                status = status.as_u16(),
+                "Example for an undeclared response status ignored"
+            ),
+        }
+        self
+    }
+
     /// Add a text response with a custom content type (additional).
     ///
     /// # Arguments
@@ -1280,6 +1778,8 @@ where
             content_type,
             description: description.into(),
             schema_name: None,
+            example: None,
+            component: None,
         });
         self
     }
@@ -1295,6 +1795,8 @@ where
             content_type: "text/html",
             description: description.into(),
             schema_name: None,
+            example: None,
+            component: None,
         });
         self
     }
@@ -1312,6 +1814,8 @@ where
             content_type: problem::APPLICATION_PROBLEM_JSON,
             description: description.into(),
             schema_name: Some(problem_name),
+            example: None,
+            component: None,
         });
         self
     }
@@ -1331,6 +1835,8 @@ where
             content_type: "text/event-stream",
             description: description.into(),
             schema_name: Some(name),
+            example: None,
+            component: None,
         });
         self
     }
@@ -1338,8 +1844,9 @@ where
     /// Add standard error responses (400, 401, 403, 404, 409, 422, 429, 500).
     ///
     /// All responses reference the shared Problem schema (RFC 9457) for consistent
-    /// error handling across your API. This is the recommended way to declare
-    /// common error responses without repeating boilerplate.
+    /// error handling across your API. Each response is published once under
+    /// `components.responses` and referenced from the operation, so repeating it
+    /// on every route does not grow the document.
     ///
     /// # Example
     ///
@@ -1354,6 +1861,7 @@ where
     /// # let registry = OpenApiRegistryImpl::new();
     /// # let router: Router<()> = Router::new();
     /// let op = OperationBuilder::get("/user-info/v1/users")
+    ///     .operation_id("users_info.list_users")
     ///     .public()
     ///     .handler(list_users)
     ///     .json_response(StatusCode::OK, "List of users")
@@ -1374,7 +1882,6 @@ where
     /// - 500 Internal Server Error
     pub fn standard_errors(mut self, registry: &dyn OpenApiRegistry) -> Self {
         use http::StatusCode;
-        let problem_name = ensure_schema::<crate::api::problem::Problem>(registry);
 
         let standard_errors = [
             (StatusCode::BAD_REQUEST, "Bad Request"),
@@ -1388,17 +1895,32 @@ where
         ];
 
         for (status, description) in standard_errors {
-            self.spec.responses.push(ResponseSpec {
-                status: status.as_u16(),
-                content_type: problem::APPLICATION_PROBLEM_JSON,
-                description: description.to_owned(),
-                schema_name: Some(problem_name.clone()),
-            });
+            self = self.shared_problem_response(registry, status, description);
         }
 
         self
     }
 
+    /// Add a `Problem` response published once under `components.responses`,
+    /// named after `description` without spaces (`Bad Request` → `BadRequest`).
+    fn shared_problem_response(
+        mut self,
+        registry: &dyn OpenApiRegistry,
+        status: http::StatusCode,
+        description: &str,
+    ) -> Self {
+        let problem_name = ensure_schema::<crate::api::problem::Problem>(registry);
+        self.spec.responses.push(ResponseSpec {
+            status: status.as_u16(),
+            content_type: problem::APPLICATION_PROBLEM_JSON,
+            description: description.to_owned(),
+            schema_name: Some(problem_name),
+            example: None,
+            component: Some(description.replace(' ', "")),
+        });
+        self
+    }
+
     /// Add 422 validation error response using `ValidationError` schema.
     ///
     /// This method adds a specific 422 Unprocessable Entity response that uses
@@ -1426,6 +1948,7 @@ where
     /// # let registry = OpenApiRegistryImpl::new();
     /// # let router: Router<()> = Router::new();
     /// let op = OperationBuilder::post("/users-info/v1/users")
+    ///     .operation_id("users_info.create_user")
     ///     .public()
     ///     .handler(create_user)
     ///     .json_request::<CreateUserRequest>(&registry, "User data")
@@ -1444,6 +1967,8 @@ where
             content_type: problem::APPLICATION_PROBLEM_JSON,
             description: "Validation Error".to_owned(),
             schema_name: Some(validation_error_name),
+            example: None,
+            component: Some("ValidationError".to_owned()),
         });
 
         self
@@ -1451,44 +1976,44 @@ where
 
     /// Add a 400 Bad Request error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_400(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(registry, http::StatusCode::BAD_REQUEST, "Bad Request")
+        self.shared_problem_response(registry, http::StatusCode::BAD_REQUEST, "Bad Request")
     }
 
     /// Add a 401 Unauthorized error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_401(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(registry, http::StatusCode::UNAUTHORIZED, "Unauthorized")
+        self.shared_problem_response(registry, http::StatusCode::UNAUTHORIZED, "Unauthorized")
     }
 
     /// Add a 403 Forbidden error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_403(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(registry, http::StatusCode::FORBIDDEN, "Forbidden")
+        self.shared_problem_response(registry, http::StatusCode::FORBIDDEN, "Forbidden")
     }
 
     /// Add a 404 Not Found error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_404(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(registry, http::StatusCode::NOT_FOUND, "Not Found")
+        self.shared_problem_response(registry, http::StatusCode::NOT_FOUND, "Not Found")
     }
 
     /// Add a 409 Conflict error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_409(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(registry, http::StatusCode::CONFLICT, "Conflict")
+        self.shared_problem_response(registry, http::StatusCode::CONFLICT, "Conflict")
     }
 
     /// Add a 415 Unsupported Media Type error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_415(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(
+        self.shared_problem_response(
             registry,
             http::StatusCode::UNSUPPORTED_MEDIA_TYPE,
             "Unsupported Media Type",
@@ -1497,9 +2022,9 @@ where
 
     /// Add a 422 Unprocessable Entity error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_422(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(
+        self.shared_problem_response(
             registry,
             http::StatusCode::UNPROCESSABLE_ENTITY,
             "Unprocessable Entity",
@@ -1508,9 +2033,9 @@ where
 
     /// Add a 429 Too Many Requests error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_429(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(
+        self.shared_problem_response(
             registry,
             http::StatusCode::TOO_MANY_REQUESTS,
             "Too Many Requests",
@@ -1519,9 +2044,9 @@ where
 
     /// Add a 500 Internal Server Error response.
     ///
-    /// This is a convenience wrapper around `problem_response`.
+    /// The response is shared through `components.responses`.
     pub fn error_500(self, registry: &dyn OpenApiRegistry) -> Self {
-        self.problem_response(
+        self.shared_problem_response(
             registry,
             http::StatusCode::INTERNAL_SERVER_ERROR,
             "Internal Server Error",
@@ -1530,9 +2055,9 @@ where
 }
 
 // -------------------------------------------------------------------------------------------------
-// Registration — only available when handler, response, AND auth are all set
+// Registration — only available when handler, response, auth, license AND operation ID are set
 // -------------------------------------------------------------------------------------------------
-impl<S> OperationBuilder<Present, Present, S, AuthSet, LicenseSet>
+impl<S> OperationBuilder<Present, Present, S, AuthSet, LicenseSet, IdSet>
 where
     S: Clone + Send + Sync + 'static,
 {
@@ -1542,9 +2067,21 @@ where
     /// - Handler is present
     /// - Response is present
     /// - Auth requirement is set (either `authenticated` or `public`)
+    /// - License requirement is set
+    /// - Operation ID is set (`operation_id` or `auto_operation_id`)
     ///
     /// All conditions are enforced at compile time by the type system.
     pub fn register(self, router: Router<S>, openapi: &dyn OpenApiRegistry) -> Router<S> {
+        if let Some(gate) = self.spec.feature_gate.as_ref().filter(|g| !g.enabled) {
+            tracing::debug!(
+                method = %self.spec.method,
+                path = %self.spec.path,
+                feature = %gate.feature,
+                "Skipping operation gated by disabled feature"
+            );
+            return router;
+        }
+
         // Inform the OpenAPI registry (the implementation will translate OperationSpec
         // into an OpenAPI Operation + RequestBody + Responses with component refs).
         openapi.register_operation(&self.spec);
@@ -1629,6 +2166,11 @@ mod tests {
     #[modkit_macros::api_dto(response)]
     struct SampleDtoResponse;
 
+    #[modkit_macros::api_dto(request, response)]
+    struct SampleExampleDto {
+        id: u32,
+    }
+
     #[test]
     fn builder_descriptive_methods() {
         let builder = OperationBuilder::<Missing, Missing, (), AuthNotSet>::get("/tests/v1/test")
@@ -1656,6 +2198,7 @@ mod tests {
         let router = Router::new();
 
         let _router = OperationBuilder::<Missing, Missing, ()>::post("/tests/v1/test")
+            .operation_id("test.post")
             .summary("Test endpoint")
             .json_request::<SampleDtoRequest>(&registry, "optional body") // registers schema
             .public()
@@ -1683,6 +2226,54 @@ mod tests {
         assert!(!schemas.is_empty());
     }
 
+    #[tokio::test]
+    async fn builder_with_examples() {
+        let registry = MockRegistry::new();
+        let router = Router::new();
+
+        let _router = OperationBuilder::<Missing, Missing, ()>::post("/tests/v1/test")
+            .operation_id("test.post")
+            .json_request_example::<SampleExampleDto>(
+                &registry,
+                "body",
+                &SampleExampleDto { id: 1 },
+            )
+            .public()
+            .handler(test_handler)
+            .json_response_with_example::<SampleExampleDto>(
+                &registry,
+                http::StatusCode::OK,
+                "Success response",
+                &SampleExampleDto { id: 2 },
+            )
+            .json_response(http::StatusCode::ACCEPTED, "Accepted")
+            .response_example(
+                http::StatusCode::ACCEPTED,
+                serde_json::json!({"queued": true}),
+            )
+            // Conflicting example for an already-exampled response is ignored
+            .response_example(
+                http::StatusCode::ACCEPTED,
+                serde_json::json!({"queued": false}),
+            )
+            // Unknown status is ignored
+            .response_example(http::StatusCode::CONFLICT, serde_json::json!({}))
+            .register(router, &registry);
+
+        let ops = registry.operations.lock().unwrap();
+        let op = &ops[0];
+        assert_eq!(
+            op.request_body.as_ref().unwrap().example,
+            Some(serde_json::json!({"id": 1}))
+        );
+        assert_eq!(op.responses.len(), 2);
+        assert_eq!(op.responses[0].example, Some(serde_json::json!({"id": 2})));
+        assert_eq!(
+            op.responses[1].example,
+            Some(serde_json::json!({"queued": true}))
+        );
+    }
+
     #[test]
     fn convenience_constructors() {
         let get_builder =
@@ -1815,6 +2406,151 @@ mod tests {
         }
     }
 
+    #[test]
+    fn shared_params_and_errors_name_their_components() {
+        let registry = MockRegistry::new();
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/test")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "Success")
+            .with_odata_select()
+            .with_cursor_pagination()
+            .error_404(&registry)
+            .problem_response(&registry, http::StatusCode::CONFLICT, "Name taken");
+
+        let params: Vec<_> = builder
+            .spec
+            .params
+            .iter()
+            .map(|p| (p.name.as_str(), p.component.as_deref()))
+            .collect();
+        assert_eq!(
+            params,
+            [
+                ("$select", Some("ODataSelect")),
+                ("limit", Some("Limit")),
+                ("cursor", Some("Cursor")),
+            ]
+        );
+
+        let components: Vec<_> = builder
+            .spec
+            .responses
+            .iter()
+            .map(|r| (r.status, r.component.as_deref()))
+            .collect();
+        // Custom problem descriptions stay inline
+        assert_eq!(
+            components,
+            [(200, None), (404, Some("NotFound")), (409, None)]
+        );
+    }
+
+    #[test]
+    fn odata_filter_lists_json_paths() {
+        use modkit_odata::filter::{FieldKind, FilterField};
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        enum Field {
+            Name,
+            Metadata,
+        }
+
+        impl FilterField for Field {
+            const FIELDS: &'static [Self] = &[Field::Name, Field::Metadata];
+
+            fn name(&self) -> &'static str {
+                match self {
+                    Field::Name => "name",
+                    Field::Metadata => "metadata",
+                }
+            }
+
+            fn kind(&self) -> FieldKind {
+                match self {
+                    Field::Name => FieldKind::String,
+                    Field::Metadata => FieldKind::Json {
+                        allowed_paths: &["department", "labels/team"],
+                    },
+                }
+            }
+        }
+
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/test")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "Success")
+            .with_odata_filter::<Field>();
+
+        let fields = builder
+            .spec
+            .vendor_extensions
+            .x_odata_filter
+            .expect("filter fields")
+            .allowed_fields;
+        let eq_ne = vec!["eq".to_owned(), "ne".to_owned()];
+        assert_eq!(fields.get("metadata/department"), Some(&eq_ne));
+        assert_eq!(fields.get("metadata/labels/team"), Some(&eq_ne));
+        assert!(!fields.contains_key("metadata"));
+        assert!(fields.contains_key("name"));
+    }
+
+    #[test]
+    fn odata_search_lists_searchable_fields() {
+        use modkit_odata::filter::{FieldKind, FilterField};
+        use modkit_odata::{SearchField, SearchableFields};
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        enum Field {
+            Name,
+            Email,
+        }
+
+        impl FilterField for Field {
+            const FIELDS: &'static [Self] = &[Field::Name, Field::Email];
+
+            fn name(&self) -> &'static str {
+                match self {
+                    Field::Name => "name",
+                    Field::Email => "email",
+                }
+            }
+
+            fn kind(&self) -> FieldKind {
+                FieldKind::String
+            }
+        }
+
+        struct Search;
+
+        impl SearchableFields for Search {
+            type Field = Field;
+
+            const FIELDS: &'static [SearchField<Field>] = &[
+                SearchField::new(Field::Name, 2),
+                SearchField::new(Field::Email, 1),
+            ];
+        }
+
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/test")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "Success")
+            .with_odata_search::<Search>();
+
+        let param = &builder.spec.params[0];
+        assert_eq!(param.name, "$search");
+        assert_eq!(param.component.as_deref(), Some("ODataSearch"));
+        assert_eq!(
+            builder
+                .spec
+                .vendor_extensions
+                .x_odata_search
+                .map(|s| s.allowed_fields),
+            Some(vec!["name".to_owned(), "email".to_owned()])
+        );
+    }
+
     #[test]
     fn authenticated() {
         let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/test")
@@ -1895,6 +2631,7 @@ mod tests {
         let router = Router::new();
 
         let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/test")
+            .operation_id("test.get")
             .public()
             .handler(test_handler)
             .json_response(http::StatusCode::OK, "Success")
@@ -2107,6 +2844,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn json_patch_request_registers_patch_schema() {
+        let registry = MockRegistry::new();
+        let builder = OperationBuilder::<Missing, Missing, ()>::patch("/tests/v1/test/{id}")
+            .path_param("id", "Resource ID")
+            .json_patch_request(&registry, "Changes to apply")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "Success");
+
+        let rb = builder.spec.request_body.as_ref().unwrap();
+        assert_eq!(rb.content_type, "application/json-patch+json");
+        assert!(rb.required);
+        assert_eq!(
+            rb.schema,
+            RequestBodySchema::Ref {
+                schema_name: "JsonPatchDocument".to_owned()
+            }
+        );
+        assert_eq!(
+            builder.spec.allowed_request_content_types.as_deref(),
+            Some(&["application/json-patch+json", "application/json"][..])
+        );
+    }
+
     #[test]
     fn response_content_types_must_not_contain_parameters() {
         // This test ensures OpenAPI correctness: media type keys cannot include
@@ -2135,4 +2897,97 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn feature_gate_disabled_skips_registration() {
+        let registry = MockRegistry::new();
+        let features = FeatureGate::new("tests", [("beta".to_owned(), false)]);
+
+        let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/beta")
+            .operation_id("test.beta")
+            .feature_gate(&features, "beta")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "OK")
+            .register(Router::new(), &registry);
+
+        assert!(registry.operations.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn feature_gate_enabled_registers_operation() {
+        let registry = MockRegistry::new();
+        let features = FeatureGate::all_enabled("tests");
+
+        let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/beta")
+            .operation_id("test.beta")
+            .feature_gate(&features, "beta")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "OK")
+            .register(Router::new(), &registry);
+
+        let ops = registry.operations.lock().unwrap();
+        assert_eq!(ops.len(), 1);
+        let gate = ops[0].feature_gate.as_ref().unwrap();
+        assert_eq!(gate.feature, "beta");
+        assert!(gate.enabled);
+    }
+
+    #[test]
+    fn bulkhead_is_recorded_on_spec() {
+        let builder =
+            OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/export").bulkhead("exports");
+        assert_eq!(builder.spec().bulkhead.as_deref(), Some("exports"));
+    }
+
+    #[test]
+    fn per_subject_rate_limit_is_recorded_on_spec() {
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/me")
+            .per_subject_rate_limit(2, 5, 4);
+        let limit = builder.spec().rate_limit.as_ref().unwrap();
+        assert_eq!((limit.rps, limit.burst, limit.in_flight), (2, 5, 4));
+        assert_eq!(limit.key_by, RateLimitKeyBy::SubjectId);
+
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/me")
+            .rate_limit_keyed_by(RateLimitKeyBy::IpAndSubjectId, 2, 5, 4);
+        let limit = builder.spec().rate_limit.as_ref().unwrap();
+        assert_eq!(limit.key_by, RateLimitKeyBy::IpAndSubjectId);
+    }
+
+    #[test]
+    fn auto_operation_id_derives_from_method_and_path() {
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/users-info/v1/users/{id}")
+            .auto_operation_id();
+        assert_eq!(
+            builder.spec.operation_id.as_deref(),
+            Some("get_users_info_v1_users_id")
+        );
+
+        let builder = OperationBuilder::<Missing, Missing, ()>::post("/tests/v1/files/{*path}")
+            .auto_operation_id();
+        assert_eq!(
+            builder.spec.operation_id.as_deref(),
+            Some("post_tests_v1_files_path")
+        );
+    }
+
+    #[test]
+    fn auto_operation_id_allows_registration() {
+        let registry = MockRegistry::new();
+
+        let _router = OperationBuilder::<Missing, Missing, ()>::delete("/tests/v1/items/{id}")
+            .public()
+            .handler(test_handler)
+            .json_response(http::StatusCode::OK, "OK")
+            .auto_operation_id()
+            .register(Router::new(), &registry);
+
+        let ops = registry.operations.lock().unwrap();
+        assert_eq!(ops.len(), 1);
+        assert_eq!(
+            ops[0].operation_id.as_deref(),
+            Some("delete_tests_v1_items_id")
+        );
+    }
 }
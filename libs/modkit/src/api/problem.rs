@@ -6,6 +6,7 @@ pub use modkit_errors::problem::{
     APPLICATION_PROBLEM_JSON, Problem, ValidationError, ValidationErrorResponse,
     ValidationViolation,
 };
+pub use modkit_errors::registry::{ProblemType, ProblemTypeRegistry};
 
 // Optional convenience constructors that return `Problem` directly
 pub fn bad_request(detail: impl Into<String>) -> Problem {
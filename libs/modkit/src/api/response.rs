@@ -1,12 +1,68 @@
 use axum::{
     Json,
     http::{StatusCode, Uri, header},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
+use serde::{Deserialize, Serialize};
 
 /// Short aliases for JSON responses
 pub type JsonBody<T> = Json<T>;
-pub type JsonPage<T> = Json<modkit_odata::Page<T>>;
+pub type JsonPage<T> = PaginatedResponse<T>;
+
+/// Standard envelope for paginated list endpoints.
+///
+/// Serializes as `{ "items": [...], "nextCursor": "...", "totalCount": null, "pageSize": 50 }`.
+/// `totalCount` is only populated when the endpoint can compute it cheaply.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PaginatedResponse<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub total_count: Option<u64>,
+    pub page_size: u32,
+}
+
+impl<T> PaginatedResponse<T> {
+    #[must_use]
+    pub fn new(items: Vec<T>, next_cursor: Option<String>, page_size: u32) -> Self {
+        Self {
+            items,
+            next_cursor,
+            total_count: None,
+            page_size,
+        }
+    }
+
+    #[must_use]
+    pub fn with_total_count(mut self, total_count: u64) -> Self {
+        self.total_count = Some(total_count);
+        self
+    }
+
+    /// Map items while preserving pagination metadata.
+    #[must_use]
+    pub fn map_items<U>(self, f: impl FnMut(T) -> U) -> PaginatedResponse<U> {
+        PaginatedResponse {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+            total_count: self.total_count,
+            page_size: self.page_size,
+        }
+    }
+}
+
+impl<T> From<modkit_odata::Page<T>> for PaginatedResponse<T> {
+    fn from(page: modkit_odata::Page<T>) -> Self {
+        let page_size = u32::try_from(page.page_info.limit).unwrap_or(u32::MAX);
+        Self::new(page.items, page.page_info.next_cursor, page_size)
+    }
+}
+
+impl<T: Serialize> IntoResponse for PaginatedResponse<T> {
+    fn into_response(self) -> Response {
+        Json(self).into_response()
+    }
+}
 
 /// 200 OK + JSON
 pub fn ok_json<T: serde::Serialize>(value: T) -> impl IntoResponse {
@@ -32,3 +88,52 @@ pub fn created_json<T: serde::Serialize>(
 pub fn no_content() -> impl IntoResponse {
     StatusCode::NO_CONTENT
 }
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use modkit_odata::{Page, PageInfo};
+
+    #[test]
+    fn paginated_response_serializes_camel_case() {
+        let resp = PaginatedResponse::new(vec![1, 2], Some("c1".to_owned()), 50);
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "items": [1, 2],
+                "nextCursor": "c1",
+                "totalCount": null,
+                "pageSize": 50
+            })
+        );
+    }
+
+    #[test]
+    fn paginated_response_from_page_keeps_cursor_and_limit() {
+        let page = Page::new(
+            vec!["a"],
+            PageInfo {
+                next_cursor: Some("next".to_owned()),
+                prev_cursor: Some("prev".to_owned()),
+                limit: 25,
+            },
+        );
+        let resp = PaginatedResponse::from(page).with_total_count(7);
+        assert_eq!(resp.items, vec!["a"]);
+        assert_eq!(resp.next_cursor.as_deref(), Some("next"));
+        assert_eq!(resp.total_count, Some(7));
+        assert_eq!(resp.page_size, 25);
+    }
+
+    #[test]
+    fn paginated_response_into_response_is_json() {
+        let resp = PaginatedResponse::new(Vec::<u8>::new(), None, 10).into_response();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+    }
+}
@@ -90,6 +90,10 @@ fn extract_nested_fields(parent_key: &str, selected_fields: &HashSet<String>) ->
 /// # Returns
 ///
 /// The projected JSON value, or the original value if no fields are selected
+///
+/// Projection happens after the row was loaded. To also avoid fetching unused
+/// columns, resolve them with [`columns_for_select`] and pass them to
+/// `SecureSelect::select_columns` before running the query.
 pub fn apply_select<T: serde::Serialize>(value: T, selected_fields: Option<&[String]>) -> Value {
     match selected_fields {
         Some(fields) if !fields.is_empty() => {
@@ -103,6 +107,42 @@ pub fn apply_select<T: serde::Serialize>(value: T, selected_fields: Option<&[Str
     }
 }
 
+/// Resolve `$select` fields to database columns for a projected query.
+///
+/// `column_for` maps a lowercased field name to its column. Returns `None` when
+/// there is no `$select`, or when a field cannot be served from a single column
+/// (unknown or dotted); the caller should then fetch full rows and rely on
+/// [`apply_select`] alone. Duplicate columns are dropped.
+///
+/// # Example
+///
+/// ```ignore
+/// let rows = match columns_for_select(query.selected_fields(), user_column) {
+///     Some(cols) => select.select_columns(cols).all(conn).await?,
+///     None => select.all(conn).await?.into_iter().map(to_json).collect(),
+/// };
+/// let body: Vec<Value> = rows.iter().map(|r| apply_select(r, query.selected_fields())).collect();
+/// ```
+#[must_use]
+pub fn columns_for_select<C: PartialEq>(
+    selected_fields: Option<&[String]>,
+    column_for: impl Fn(&str) -> Option<C>,
+) -> Option<Vec<C>> {
+    let fields = selected_fields.filter(|f| !f.is_empty())?;
+    let mut columns = Vec::with_capacity(fields.len());
+    for field in fields {
+        let field = field.to_lowercase();
+        if field.contains('.') {
+            return None;
+        }
+        let column = column_for(&field)?;
+        if !columns.contains(&column) {
+            columns.push(column);
+        }
+    }
+    Some(columns)
+}
+
 /// Convert a page of items to a page of projected JSON values.
 ///
 /// This is a convenience function that combines serialization and projection
@@ -30,6 +30,9 @@ pub struct ModuleConfig {
     pub database: Option<DbConnConfig>,
     #[serde(default)]
     pub config: serde_json::Value,
+    /// Feature flags read via `ModuleCtx::features()`; unlisted features are enabled.
+    #[serde(default)]
+    pub features: HashMap<String, bool>,
     #[serde(default)]
     pub runtime: Option<ModuleRuntime>,
     #[serde(default)] // Used by the CLI
@@ -119,17 +122,37 @@ impl ConfigProvider for AppConfig {
 #[serde(deny_unknown_fields)]
 pub struct ServerConfig {
     pub home_dir: PathBuf, // will be normalized to absolute path
+    /// Unix socket for the operator admin channel (relative to `home_dir`).
+    /// The channel is disabled when unset.
+    #[serde(default)]
+    pub admin_socket: Option<PathBuf>,
+    /// Seconds allowed for module shutdown hooks and stopping stateful
+    /// modules, combined.
+    #[serde(default = "default_stop_timeout_secs")]
+    pub stop_timeout_secs: u64,
+}
+
+fn default_stop_timeout_secs() -> u64 {
+    crate::runtime::DEFAULT_STOP_TIMEOUT.as_secs()
 }
 
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
             home_dir: super::host::paths::default_home_dir().join(".cyberfabric"),
+            admin_socket: None,
+            stop_timeout_secs: default_stop_timeout_secs(),
         }
     }
 }
 
 impl ServerConfig {
+    /// Bound on module shutdown hooks and the stop phase combined.
+    #[must_use]
+    pub fn stop_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.stop_timeout_secs)
+    }
+
     fn normalize_home_dir_inplace(&mut self) -> Result<()> {
         self.home_dir = super::host::normalize_path(
             self.home_dir
@@ -58,8 +58,8 @@ use super::config::{
 };
 use crate::bootstrap::host::{init_logging_unified, init_panic_tracing};
 use crate::runtime::{
-    ClientRegistration, DbOptions, MODKIT_DIRECTORY_ENDPOINT_ENV, RunOptions, ShutdownOptions, run,
-    shutdown,
+    ClientRegistration, DEFAULT_STOP_TIMEOUT, DbOptions, MODKIT_DIRECTORY_ENDPOINT_ENV, RunOptions,
+    ShutdownOptions, run, shutdown,
 };
 use cf_system_sdks::directory::{DirectoryClient, DirectoryGrpcClient};
 
@@ -575,6 +575,8 @@ pub async fn run_oop_with_options(opts: OopRunOptions) -> Result<()> {
         )],
         instance_id,
         oop: None, // OoP modules don't spawn other OoP modules
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = run(run_options).await;
@@ -20,6 +20,8 @@ fn minimal_app_config() -> AppConfig {
     AppConfig {
         server: ServerConfig {
             home_dir: std::env::temp_dir().join("modkit_test"),
+            admin_socket: None,
+            stop_timeout_secs: 30,
         },
         database: None,
         logging: default_logging_config(),
@@ -67,6 +67,13 @@ pub async fn run_server(config: AppConfig) -> anyhow::Result<()> {
     // Build OoP spawn configuration
     let oop_options = build_oop_spawn_options(&config, oop_backend)?;
 
+    // Admin channel socket; relative paths are resolved against home_dir
+    let admin_socket = config
+        .server
+        .admin_socket
+        .as_ref()
+        .map(|socket| config.server.home_dir.join(socket));
+
     // Run the ModKit runtime with the root cancellation token.
     // Shutdown is driven by the signal handler spawned above, not by ShutdownOptions::Signals.
     // OoP modules are spawned after the start phase (once grpc-hub has bound its port).
@@ -77,6 +84,8 @@ pub async fn run_server(config: AppConfig) -> anyhow::Result<()> {
         clients: vec![],
         instance_id,
         oop: oop_options,
+        admin_socket,
+        stop_timeout: config.server.stop_timeout(),
     };
 
     let result = run(run_options).await;
@@ -161,6 +170,56 @@ pub async fn run_migrate(config: AppConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Build the `OpenAPI` document of all discovered modules without serving it.
+///
+/// Modules are initialized in dry-run mode (no database, no plugin discovery,
+/// see [`crate::context::ModuleCtx::is_dry_run`]) and only register their
+/// routes; see [`crate::runtime::HostRuntime::emit_openapi`].
+///
+/// # Errors
+///
+/// Returns an error if module discovery, initialization or route registration fails.
+pub async fn emit_openapi(config: AppConfig) -> anyhow::Result<utoipa::openapi::OpenApi> {
+    let registry = crate::registry::ModuleRegistry::discover_and_build()?;
+    tracing::info!(
+        module_count = registry.modules().len(),
+        "Discovered modules for OpenAPI emission"
+    );
+
+    let host = crate::runtime::HostRuntime::new(
+        registry,
+        Arc::new(config),
+        DbOptions::None,
+        Arc::new(crate::client_hub::ClientHub::new()),
+        CancellationToken::new(),
+        uuid::Uuid::new_v4(),
+        None, // No OoP spawning in a dry run
+    );
+
+    host.emit_openapi().await
+}
+
+/// Write the `OpenAPI` document to `path` and exit.
+///
+/// The output has sorted keys, so two runs over the same build and
+/// configuration produce byte-identical files.
+///
+/// # Errors
+///
+/// Returns an error if the document cannot be built or written.
+#[allow(unknown_lints, de1301_no_print_macros)]
+pub async fn run_emit_openapi(config: AppConfig, path: &Path) -> anyhow::Result<()> {
+    tracing::info!(path = %path.display(), "Starting OpenAPI emission mode...");
+
+    let doc = emit_openapi(config).await?;
+    let json = crate::api::openapi_to_sorted_json(&doc)?;
+    std::fs::write(path, json)
+        .map_err(|e| anyhow::anyhow!("failed to write OpenAPI to '{}': {e}", path.display()))?;
+
+    println!("[OK] OpenAPI written to {}", path.display());
+    Ok(())
+}
+
 fn resolve_db_options(config: &AppConfig) -> anyhow::Result<DbOptions> {
     if config.database.is_none() {
         tracing::warn!("No global database section found; running without databases");
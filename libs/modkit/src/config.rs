@@ -9,8 +9,16 @@
 //! 2. **Strict loading**: Requires configuration to be present and valid.
 //!    - Used by `module_config_required`
 //!    - Returns errors when configuration is missing or invalid
+//!
+//! A [`ConfigWatcher`] additionally polls a config file and publishes changes to
+//! [`ConfigHandle`]s, for modules that pick up new settings without a restart.
 
 use serde::de::DeserializeOwned;
+use std::path::PathBuf;
+
+mod watch;
+
+pub use watch::{ConfigHandle, ConfigWatcher};
 
 /// Configuration error for typed config operations
 #[derive(thiserror::Error, Debug)]
@@ -27,6 +35,8 @@ pub enum ConfigError {
         #[source]
         source: serde_json::Error,
     },
+    #[error("cannot load config file '{}': {message}", path.display())]
+    InvalidFile { path: PathBuf, message: String },
 }
 
 /// Provider of module-specific configuration (raw JSON sections only).
@@ -0,0 +1,351 @@
+//! Config file watching.
+//!
+//! [`ConfigWatcher`] polls a YAML (or JSON) config file and publishes every
+//! successfully parsed change on a `tokio::sync::watch` channel. Modules read
+//! the latest value through a [`ConfigHandle`], usually obtained from
+//! [`ModuleCtx::config_handle`](crate::context::ModuleCtx::config_handle).
+//!
+//! Only the file itself is re-read: `APP__*` environment overrides and
+//! `modules_dir` fragments are not re-applied on reload.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use figment::Figment;
+use figment::providers::{Format, Yaml};
+use serde::de::DeserializeOwned;
+use tokio::sync::watch;
+use tokio::time::{Instant, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+
+use super::{ConfigError, ConfigProvider, module_config_or_default};
+
+type FileStamp = (SystemTime, u64);
+type Parser<T> = Box<dyn Fn(serde_json::Value) -> Result<T, ConfigError> + Send + Sync>;
+
+/// Read access to a config value that may change at runtime.
+///
+/// Clones share the same channel and see the same updates.
+pub struct ConfigHandle<T> {
+    rx: watch::Receiver<Arc<T>>,
+}
+
+impl<T> Clone for ConfigHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rx: self.rx.clone(),
+        }
+    }
+}
+
+impl<T> ConfigHandle<T> {
+    /// A handle whose value never changes.
+    #[must_use]
+    pub fn fixed(value: T) -> Self {
+        let (_tx, rx) = watch::channel(Arc::new(value));
+        Self { rx }
+    }
+
+    /// The most recently loaded config.
+    #[must_use]
+    pub fn latest(&self) -> Arc<T> {
+        Arc::clone(&self.rx.borrow())
+    }
+
+    /// Wait for the next reload and return the new value.
+    ///
+    /// Returns `None` once the watcher has stopped (or for a [`fixed`](Self::fixed)
+    /// handle); [`latest`](Self::latest) keeps returning the last value.
+    pub async fn changed(&mut self) -> Option<Arc<T>> {
+        self.rx.changed().await.ok()?;
+        Some(Arc::clone(&self.rx.borrow_and_update()))
+    }
+}
+
+/// Polls a config file and publishes the parsed value whenever it changes.
+///
+/// Changes are detected by modification time and size. A file that fails to
+/// read or parse is logged and skipped; handles keep the previous value.
+pub struct ConfigWatcher<T> {
+    path: PathBuf,
+    interval: Duration,
+    parse: Parser<T>,
+    stamp: Option<FileStamp>,
+    tx: watch::Sender<Arc<T>>,
+}
+
+impl<T: Send + Sync + 'static> ConfigWatcher<T> {
+    /// Load the whole file at `path` into `T`; [`watch`](Self::watch) then
+    /// polls it every `interval` (0 disables polling).
+    ///
+    /// # Errors
+    /// Returns `ConfigError::InvalidFile` if the file cannot be read or parsed.
+    pub fn new(path: &Path, interval: Duration) -> Result<Self, ConfigError>
+    where
+        T: DeserializeOwned,
+    {
+        let file = path.to_owned();
+        Self::with_parser(
+            path,
+            interval,
+            Box::new(move |value| {
+                serde_json::from_value(value).map_err(|e| invalid_file(&file, e))
+            }),
+        )
+    }
+
+    /// Load the `modules.<module>.config` section of an application config
+    /// file into `T`, with the lenient rules of [`module_config_or_default`].
+    ///
+    /// # Errors
+    /// Returns `ConfigError::InvalidFile` if the file cannot be read or parsed,
+    /// or `ConfigError::InvalidConfig` if the module's section is invalid.
+    pub fn for_module(path: &Path, interval: Duration, module: &str) -> Result<Self, ConfigError>
+    where
+        T: DeserializeOwned + Default,
+    {
+        let module = module.to_owned();
+        Self::with_parser(
+            path,
+            interval,
+            Box::new(move |value| module_config_or_default(&FileModules(value), &module)),
+        )
+    }
+
+    fn with_parser(path: &Path, interval: Duration, parse: Parser<T>) -> Result<Self, ConfigError> {
+        let stamp = file_stamp(path);
+        let value = parse(read_file(path)?)?;
+        let (tx, _rx) = watch::channel(Arc::new(value));
+        Ok(Self {
+            path: path.to_owned(),
+            interval,
+            parse,
+            stamp,
+            tx,
+        })
+    }
+
+    /// A handle following this watcher's updates.
+    #[must_use]
+    pub fn handle(&self) -> ConfigHandle<T> {
+        ConfigHandle {
+            rx: self.tx.subscribe(),
+        }
+    }
+
+    /// Publish `value` without touching the file, e.g. a config merged from
+    /// more sources than the file alone.
+    pub(crate) fn publish(&self, value: T) {
+        self.tx.send_replace(Arc::new(value));
+    }
+
+    /// Reload the file if it changed since the last (attempted) load.
+    ///
+    /// Returns `true` if a new value was published.
+    pub fn poll(&mut self) -> bool {
+        let stamp = file_stamp(&self.path);
+        if stamp == self.stamp {
+            return false;
+        }
+        self.stamp = stamp;
+        match read_file(&self.path).and_then(|value| (self.parse)(value)) {
+            Ok(value) => {
+                self.publish(value);
+                tracing::info!(path = %self.path.display(), "Config file reloaded");
+                true
+            }
+            Err(e) => {
+                tracing::warn!(
+                    path = %self.path.display(),
+                    error = %e,
+                    "Config file reload failed; keeping the previous config"
+                );
+                false
+            }
+        }
+    }
+
+    /// Poll the file every `interval` until `cancel` fires or every handle is dropped.
+    pub async fn watch(mut self, cancel: CancellationToken) {
+        let mut ticks = (!self.interval.is_zero()).then(|| {
+            let mut ticks = tokio::time::interval_at(Instant::now() + self.interval, self.interval);
+            ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            ticks
+        });
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => return,
+                () = self.tx.closed() => return,
+                () = tick(ticks.as_mut()) => {}
+            }
+            self.poll();
+        }
+    }
+}
+
+async fn tick(interval: Option<&mut tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// Module sections of a parsed application config file.
+struct FileModules(serde_json::Value);
+
+impl ConfigProvider for FileModules {
+    fn get_module_config(&self, module_name: &str) -> Option<&serde_json::Value> {
+        self.0.get("modules")?.get(module_name)
+    }
+}
+
+fn file_stamp(path: &Path) -> Option<FileStamp> {
+    let meta = std::fs::metadata(path).ok()?;
+    Some((meta.modified().ok()?, meta.len()))
+}
+
+fn read_file(path: &Path) -> Result<serde_json::Value, ConfigError> {
+    let text = std::fs::read_to_string(path).map_err(|e| invalid_file(path, e))?;
+    Figment::from(Yaml::string(&text))
+        .extract()
+        .map_err(|e| invalid_file(path, e))
+}
+
+fn invalid_file(path: &Path, error: impl std::fmt::Display) -> ConfigError {
+    ConfigError::InvalidFile {
+        path: path.to_owned(),
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize, Default)]
+    struct GatewayConfig {
+        #[serde(default)]
+        port: u16,
+        #[serde(default)]
+        cors_enabled: bool,
+    }
+
+    fn write(path: &Path, contents: &str) {
+        std::fs::write(path, contents).unwrap();
+    }
+
+    #[test]
+    fn loads_the_whole_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        write(&path, "port: 8080\ncors_enabled: true\n");
+
+        let watcher = ConfigWatcher::<GatewayConfig>::new(&path, Duration::ZERO).unwrap();
+
+        assert_eq!(
+            *watcher.handle().latest(),
+            GatewayConfig {
+                port: 8080,
+                cors_enabled: true
+            }
+        );
+    }
+
+    #[test]
+    fn loads_a_module_section_or_its_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.yaml");
+        write(
+            &path,
+            "modules:\n  api-gateway:\n    config:\n      port: 8080\n",
+        );
+
+        let gateway =
+            ConfigWatcher::<GatewayConfig>::for_module(&path, Duration::ZERO, "api-gateway")
+                .unwrap();
+        let other =
+            ConfigWatcher::<GatewayConfig>::for_module(&path, Duration::ZERO, "other").unwrap();
+
+        assert_eq!(gateway.handle().latest().port, 8080);
+        assert_eq!(*other.handle().latest(), GatewayConfig::default());
+    }
+
+    #[test]
+    fn unreadable_files_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing.yaml");
+
+        let err = ConfigWatcher::<GatewayConfig>::new(&path, Duration::ZERO)
+            .err()
+            .unwrap();
+
+        assert!(matches!(err, ConfigError::InvalidFile { path: p, .. } if p == path));
+    }
+
+    #[tokio::test]
+    async fn changes_are_published_to_handles() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        write(&path, "port: 8080\n");
+        let mut watcher = ConfigWatcher::<GatewayConfig>::new(&path, Duration::ZERO).unwrap();
+        let mut handle = watcher.handle();
+
+        assert!(!watcher.poll(), "unchanged file must not be republished");
+
+        write(&path, "port: 9090\ncors_enabled: true\n");
+        assert!(watcher.poll());
+
+        let changed = handle.changed().await.unwrap();
+        assert_eq!(changed.port, 9090);
+        assert_eq!(handle.latest().port, 9090);
+    }
+
+    #[test]
+    fn invalid_changes_keep_the_previous_value() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        write(&path, "port: 8080\n");
+        let mut watcher = ConfigWatcher::<GatewayConfig>::new(&path, Duration::ZERO).unwrap();
+
+        write(&path, "port: not-a-number\n");
+
+        assert!(!watcher.poll());
+        assert_eq!(watcher.handle().latest().port, 8080);
+    }
+
+    #[tokio::test]
+    async fn watch_polls_until_cancelled() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("gateway.yaml");
+        write(&path, "port: 8080\n");
+        let watcher =
+            ConfigWatcher::<GatewayConfig>::new(&path, Duration::from_millis(10)).unwrap();
+        let mut handle = watcher.handle();
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(watcher.watch(cancel.clone()));
+
+        write(&path, "port: 9090\ncors_enabled: true\n");
+        let changed = tokio::time::timeout(Duration::from_secs(5), handle.changed())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(changed.port, 9090);
+
+        cancel.cancel();
+        task.await.unwrap();
+        assert!(handle.changed().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn fixed_handles_never_change() {
+        let mut handle = ConfigHandle::fixed(GatewayConfig::default());
+
+        assert!(handle.changed().await.is_none());
+        assert_eq!(*handle.latest(), GatewayConfig::default());
+    }
+}
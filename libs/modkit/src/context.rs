@@ -1,10 +1,17 @@
 use serde::de::DeserializeOwned;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
+use crate::admin::{AdminCommandRegistry, AdminResult};
 // Import configuration types from the config module
-use crate::config::{ConfigError, ConfigProvider, module_config_or_default};
+use crate::config::{
+    ConfigError, ConfigHandle, ConfigProvider, ConfigWatcher, module_config_or_default,
+};
+use crate::features::FeatureGate;
+use crate::readiness::{ModuleReadiness, ReadinessRegistry};
 
 // Note: runtime-dependent features are conditionally compiled
 
@@ -32,6 +39,11 @@ pub struct ModuleCtx {
     client_hub: Arc<crate::client_hub::ClientHub>,
     cancellation_token: CancellationToken,
     db: Option<DbProvider>,
+    admin_commands: Arc<AdminCommandRegistry>,
+    readiness: Arc<ReadinessRegistry>,
+    dry_run: bool,
+    /// Config file followed by [`ModuleCtx::config_handle`], with its poll interval
+    config_file: Option<Arc<(PathBuf, Duration)>>,
 }
 
 /// Builder for creating module-scoped contexts with resolved database handles.
@@ -44,6 +56,11 @@ pub struct ModuleContextBuilder {
     client_hub: Arc<crate::client_hub::ClientHub>,
     root_token: CancellationToken,
     db_manager: Option<Arc<DbManager>>, // internal only, never exposed to modules
+    admin_commands: Arc<AdminCommandRegistry>,
+    readiness: Arc<ReadinessRegistry>,
+    dry_run: bool,
+    /// Config file followed by [`ModuleCtx::config_handle`], with its poll interval
+    config_file: Option<Arc<(PathBuf, Duration)>>,
 }
 
 impl ModuleContextBuilder {
@@ -60,9 +77,44 @@ impl ModuleContextBuilder {
             client_hub,
             root_token,
             db_manager,
+            admin_commands: Arc::new(AdminCommandRegistry::new()),
+            readiness: Arc::new(ReadinessRegistry::new()),
+            dry_run: false,
+            config_file: None,
         }
     }
 
+    /// Share `registry` with every context built from now on, so commands
+    /// registered by modules are visible to the admin channel.
+    #[must_use]
+    pub fn with_admin_commands(mut self, registry: Arc<AdminCommandRegistry>) -> Self {
+        self.admin_commands = registry;
+        self
+    }
+
+    /// Share `registry` with every context built from now on, so readiness
+    /// reported by modules is visible to health probes.
+    #[must_use]
+    pub fn with_readiness(mut self, registry: Arc<ReadinessRegistry>) -> Self {
+        self.readiness = registry;
+        self
+    }
+
+    /// Build dry-run contexts (see [`ModuleCtx::is_dry_run`]); no database is resolved.
+    #[must_use]
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Let modules follow changes of the config file at `path`, polled every
+    /// `interval` (see [`ModuleCtx::config_handle`]).
+    #[must_use]
+    pub fn with_config_file(mut self, path: PathBuf, interval: Duration) -> Self {
+        self.config_file = Some(Arc::new((path, interval)));
+        self
+    }
+
     /// Returns the process-level instance ID.
     #[must_use]
     pub fn instance_id(&self) -> Uuid {
@@ -77,7 +129,9 @@ impl ModuleContextBuilder {
         let db: Option<DbProvider> = {
             #[cfg(feature = "db")]
             {
-                if let Some(mgr) = &self.db_manager {
+                if let Some(mgr) = &self.db_manager
+                    && !self.dry_run
+                {
                     mgr.get(module_name).await?.map(modkit_db::DBProvider::new)
                 } else {
                     None
@@ -97,7 +151,11 @@ impl ModuleContextBuilder {
             self.client_hub.clone(),
             self.root_token.child_token(),
             db,
-        ))
+        )
+        .with_admin_commands(Arc::clone(&self.admin_commands))
+        .with_readiness(Arc::clone(&self.readiness))
+        .with_dry_run(self.dry_run)
+        .with_config_file_of(self.config_file.clone()))
     }
 }
 
@@ -118,9 +176,42 @@ impl ModuleCtx {
             client_hub,
             cancellation_token,
             db,
+            admin_commands: Arc::new(AdminCommandRegistry::new()),
+            readiness: Arc::new(ReadinessRegistry::new()),
+            dry_run: false,
+            config_file: None,
         }
     }
 
+    /// Replace the admin command registry (the runtime shares one across modules).
+    pub fn with_admin_commands(mut self, registry: Arc<AdminCommandRegistry>) -> Self {
+        self.admin_commands = registry;
+        self
+    }
+
+    /// Replace the readiness registry (the runtime shares one across modules).
+    pub fn with_readiness(mut self, registry: Arc<ReadinessRegistry>) -> Self {
+        self.readiness = registry;
+        self
+    }
+
+    /// Mark this context as a dry run (see [`Self::is_dry_run`]).
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Follow changes of the config file at `path`, polled every `interval`
+    /// (see [`Self::config_handle`]).
+    pub fn with_config_file(self, path: PathBuf, interval: Duration) -> Self {
+        self.with_config_file_of(Some(Arc::new((path, interval))))
+    }
+
+    fn with_config_file_of(mut self, config_file: Option<Arc<(PathBuf, Duration)>>) -> Self {
+        self.config_file = config_file;
+        self
+    }
+
     // ---- public read-only API for modules ----
 
     #[inline]
@@ -139,6 +230,18 @@ impl ModuleCtx {
         self.instance_id
     }
 
+    /// True when the runtime only builds the `OpenAPI` document and exits.
+    ///
+    /// No database is provided and `post_init`, start and serving are skipped.
+    /// In `init`, skip anything that needs external resources (connections,
+    /// plugin discovery, background workers); in `register_rest`, register all
+    /// routes even if init-time state is missing. The router is discarded.
+    #[inline]
+    #[must_use]
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
     #[inline]
     #[must_use]
     pub fn config_provider(&self) -> &dyn ConfigProvider {
@@ -208,6 +311,62 @@ impl ModuleCtx {
         })
     }
 
+    /// Register a command on the operator admin channel (see [`crate::admin`]).
+    ///
+    /// Command names are global; prefix them with the module's short name,
+    /// e.g. `"authn.reset-selector"`. The handler receives the request params
+    /// and returns a JSON result.
+    ///
+    /// # Errors
+    /// Returns an error if a command with the same name is already registered.
+    pub fn register_admin_command<F, Fut>(&self, name: &str, handler: F) -> anyhow::Result<()>
+    where
+        F: Fn(serde_json::Value) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = AdminResult> + Send + 'static,
+    {
+        self.admin_commands.register(name, handler)
+    }
+
+    /// The admin command registry this context registers into.
+    #[must_use]
+    pub fn admin_commands(&self) -> Arc<AdminCommandRegistry> {
+        Arc::clone(&self.admin_commands)
+    }
+
+    /// Reporter for this module's readiness (see [`crate::readiness`]).
+    ///
+    /// Modules without a lifecycle `ReadySignal` use it to report that they
+    /// run degraded, and to recover later.
+    #[must_use]
+    pub fn readiness(&self) -> ModuleReadiness {
+        self.readiness.module(&self.module_name)
+    }
+
+    /// Readiness of every module in the process, e.g. for a health endpoint.
+    #[must_use]
+    pub fn readiness_registry(&self) -> Arc<ReadinessRegistry> {
+        Arc::clone(&self.readiness)
+    }
+
+    /// Whether module `name` is loaded in this process, so that `init` can
+    /// branch on an optional dependency (`deps = [optional("...")]`).
+    ///
+    /// Answered from the readiness registry, which the runtime fills with every
+    /// module it loads; a context not built by the runtime knows no modules.
+    #[must_use]
+    pub fn dependency_available(&self, name: &str) -> bool {
+        self.readiness.tracks(name)
+    }
+
+    /// Process-wide registry of problem type URIs.
+    ///
+    /// Register the module's error catalog here during `init` so problems of
+    /// these types get consistent titles and statuses.
+    #[must_use]
+    pub fn problem_types(&self) -> &'static crate::api::problem::ProblemTypeRegistry {
+        crate::api::problem::ProblemTypeRegistry::global()
+    }
+
     #[must_use]
     pub fn current_module(&self) -> Option<&str> {
         Some(&self.module_name)
@@ -239,6 +398,31 @@ impl ModuleCtx {
         module_config_or_default(self.config_provider.as_ref(), &self.module_name)
     }
 
+    /// The module's config section behind a [`ConfigHandle`].
+    ///
+    /// When the host follows a config file (see
+    /// [`HostRuntime::with_config_file`](crate::runtime::HostRuntime::with_config_file)),
+    /// the handle starts at [`Self::config`] and picks up every valid change of
+    /// the module's section in that file until the module is cancelled. Otherwise
+    /// it holds [`Self::config`] and never changes.
+    ///
+    /// # Errors
+    /// Returns `ConfigError` if the module's config or the followed file is invalid.
+    pub fn config_handle<T>(&self) -> Result<ConfigHandle<T>, ConfigError>
+    where
+        T: DeserializeOwned + Default + Send + Sync + 'static,
+    {
+        let config = self.config()?;
+        let Some((path, interval)) = self.config_file.as_deref() else {
+            return Ok(ConfigHandle::fixed(config));
+        };
+        let watcher = ConfigWatcher::for_module(path, *interval, &self.module_name)?;
+        watcher.publish(config);
+        let handle = watcher.handle();
+        drop(tokio::spawn(watcher.watch(self.cancellation_token.clone())));
+        Ok(handle)
+    }
+
     /// Get the raw JSON value of the module's config section.
     /// Returns the 'config' field from: modules.<name> = { database: ..., config: ... }
     #[must_use]
@@ -259,6 +443,23 @@ impl ModuleCtx {
         &EMPTY
     }
 
+    /// Get the module's feature flags.
+    /// Reads the 'features' field from: modules.<name> = { features: { ... }, config: ... }
+    ///
+    /// Features not listed in config are enabled.
+    pub fn features(&self) -> FeatureGate {
+        FeatureGate::from_module_entry(
+            self.module_name.clone(),
+            self.config_provider.get_module_config(&self.module_name),
+        )
+    }
+
+    /// Shorthand for `self.features().is_enabled(feature)`.
+    #[must_use]
+    pub fn feature_enabled(&self, feature: &str) -> bool {
+        self.features().is_enabled(feature)
+    }
+
     /// Create a derivative context with the same references but no DB handle.
     /// Useful for modules that don't require database access.
     pub fn without_db(&self) -> ModuleCtx {
@@ -269,6 +470,10 @@ impl ModuleCtx {
             client_hub: self.client_hub.clone(),
             cancellation_token: self.cancellation_token.clone(),
             db: None,
+            admin_commands: Arc::clone(&self.admin_commands),
+            readiness: Arc::clone(&self.readiness),
+            dry_run: self.dry_run,
+            config_file: self.config_file.clone(),
         }
     }
 }
@@ -314,6 +519,18 @@ mod tests {
                 }),
             );
 
+            // Module with feature flags
+            modules.insert(
+                "featured_module".to_owned(),
+                json!({
+                    "features": {
+                        "addresses": false,
+                        "cities": true
+                    },
+                    "config": {}
+                }),
+            );
+
             Self { modules }
         }
     }
@@ -379,4 +596,101 @@ mod tests {
 
         assert_eq!(ctx.instance_id(), instance_id);
     }
+
+    #[test]
+    fn test_module_ctx_feature_flags() {
+        let provider = Arc::new(MockConfigProvider::new());
+        let ctx = ModuleCtx::new(
+            "featured_module",
+            Uuid::new_v4(),
+            provider,
+            Arc::new(crate::client_hub::ClientHub::default()),
+            CancellationToken::new(),
+            None,
+        );
+
+        assert!(!ctx.feature_enabled("addresses"));
+        assert!(ctx.feature_enabled("cities"));
+        assert!(ctx.feature_enabled("unlisted"));
+        assert_eq!(ctx.features().module_name(), "featured_module");
+    }
+
+    #[test]
+    fn test_module_ctx_features_default_to_enabled() {
+        let provider = Arc::new(MockConfigProvider::new());
+        let ctx = ModuleCtx::new(
+            "test_module",
+            Uuid::new_v4(),
+            provider,
+            Arc::new(crate::client_hub::ClientHub::default()),
+            CancellationToken::new(),
+            None,
+        );
+
+        assert!(ctx.feature_enabled("addresses"));
+    }
+
+    #[test]
+    fn dependency_available_follows_loaded_modules() {
+        let readiness = Arc::new(ReadinessRegistry::new());
+        readiness.register("api-gateway");
+        readiness.register("grpc-hub");
+        let ctx = ModuleCtx::new(
+            "api-gateway",
+            Uuid::new_v4(),
+            Arc::new(MockConfigProvider::new()),
+            Arc::new(crate::client_hub::ClientHub::default()),
+            CancellationToken::new(),
+            None,
+        )
+        .with_readiness(readiness);
+
+        assert!(ctx.dependency_available("grpc-hub"));
+        assert!(!ctx.dependency_available("authn-resolver"));
+    }
+
+    fn test_module_ctx() -> ModuleCtx {
+        ModuleCtx::new(
+            "test_module",
+            Uuid::new_v4(),
+            Arc::new(MockConfigProvider::new()),
+            Arc::new(crate::client_hub::ClientHub::default()),
+            CancellationToken::new(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn config_handle_without_config_file_is_fixed() {
+        let mut handle = test_module_ctx().config_handle::<TestConfig>().unwrap();
+
+        assert_eq!(handle.latest().api_key, "secret123");
+        assert!(handle.changed().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn config_handle_follows_the_config_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.yaml");
+        std::fs::write(&path, "modules: {}\n").unwrap();
+        let ctx = test_module_ctx().with_config_file(path.clone(), Duration::from_millis(10));
+
+        // Starts from the provider's config, not the file's
+        let mut handle = ctx.config_handle::<TestConfig>().unwrap();
+        assert_eq!(handle.latest().api_key, "secret123");
+
+        std::fs::write(
+            &path,
+            "modules:\n  test_module:\n    config:\n      api_key: rotated\n",
+        )
+        .unwrap();
+        let changed = tokio::time::timeout(Duration::from_secs(5), handle.changed())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(changed.api_key, "rotated");
+
+        ctx.cancellation_token().cancel();
+        assert!(handle.changed().await.is_none());
+    }
 }
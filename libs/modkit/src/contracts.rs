@@ -36,6 +36,20 @@ pub trait SystemCapability: Send + Sync {
 #[async_trait]
 pub trait Module: Send + Sync + 'static {
     async fn init(&self, ctx: &crate::context::ModuleCtx) -> anyhow::Result<()>;
+
+    /// Optional cleanup hook (close connections, flush buffers, ...).
+    ///
+    /// Called once the runtime's cancellation token fires, in reverse startup
+    /// order and before stateful modules are stopped. Hooks and the stop phase
+    /// share the runtime's stop timeout.
+    ///
+    /// Default implementation is a no-op so most modules don't need to implement it.
+    ///
+    /// # Errors
+    /// Errors are logged and do not interrupt the shutdown of other modules.
+    async fn on_shutdown(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
 /// Database capability: modules provide migrations, runtime executes them.
@@ -90,12 +104,27 @@ pub trait ApiGatewayCapability: Send + Sync + 'static {
 
     // Return OpenAPI registry of the module, e.g., to register endpoints
     fn as_registry(&self) -> &dyn OpenApiRegistry;
+
+    /// Title, version and description of the served `OpenAPI` document.
+    ///
+    /// Used when the document is built outside the host's own registry (dry run).
+    fn openapi_info(&self) -> crate::api::OpenApiInfo {
+        crate::api::OpenApiInfo::default()
+    }
 }
 
 #[async_trait]
 pub trait RunnableCapability: Send + Sync {
     async fn start(&self, cancel: CancellationToken) -> anyhow::Result<()>;
     async fn stop(&self, cancel: CancellationToken) -> anyhow::Result<()>;
+
+    /// Hand the module its readiness reporter; called by the runtime before `start()`.
+    ///
+    /// Return `true` if the module reports its own readiness. Otherwise the
+    /// runtime marks it ready once `start()` returns.
+    fn bind_readiness(&self, _readiness: crate::readiness::ModuleReadiness) -> bool {
+        false
+    }
 }
 
 /// Represents a gRPC service registration callback used by the gRPC hub.
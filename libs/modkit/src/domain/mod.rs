@@ -83,6 +83,21 @@ pub trait DomainModel {}
 /// contain infrastructure-specific error types.
 pub trait DomainErrorMarker: std::error::Error + Send + Sync {}
 
+/// Type-state markers used by builders generated with `#[domain_model(builder)]`.
+///
+/// Each required field of the model is tracked by one builder type parameter that
+/// starts as [`Missing`] and becomes [`Present`] once its setter is called; `build()`
+/// is only implemented when every required field is `Present`.
+pub mod builder {
+    /// Marker for a required field that has not been set yet.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Missing;
+
+    /// Holds the value of a required field that has been set.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Present<T>(pub T);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
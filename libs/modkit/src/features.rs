@@ -0,0 +1,189 @@
+//! Declarative per-module feature flags.
+//!
+//! Feature flags are read from the module entry in the application config,
+//! next to `database` and `config`:
+//!
+//! ```yaml
+//! modules:
+//!   users-info:
+//!     features:
+//!       addresses: false
+//!     config: { ... }
+//! ```
+//!
+//! Features that are not listed are **enabled**, so adding a gate to existing
+//! code never changes behavior for deployments that don't configure it.
+//!
+//! A [`FeatureGate`] is obtained from [`ModuleCtx::features`](crate::ModuleCtx::features)
+//! and serves two purposes:
+//! - REST wiring: `OperationBuilder::feature_gate` records the gate on the
+//!   `OperationSpec`; `register()` skips disabled operations entirely, so they
+//!   are neither routed nor present in the `OpenAPI` document.
+//! - Domain services: [`FeatureGate::ensure_enabled`] rejects programmatic
+//!   access (e.g. via `ClientHub`) with a [`FeatureDisabled`] error, which maps
+//!   to HTTP 404.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::api::problem::{Problem, not_found};
+
+/// Key of the feature map inside a module config entry.
+pub const FEATURES_KEY: &str = "features";
+
+/// Error returned when a disabled feature is accessed.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("feature '{feature}' is disabled for module '{module}'")]
+pub struct FeatureDisabled {
+    pub module: String,
+    pub feature: String,
+}
+
+impl From<FeatureDisabled> for Problem {
+    fn from(e: FeatureDisabled) -> Self {
+        // Disabled features behave as if the resource does not exist.
+        not_found(e.to_string())
+    }
+}
+
+/// Cheap-to-clone, read-only view over a module's feature flags.
+#[derive(Clone, Debug)]
+pub struct FeatureGate {
+    module: Arc<str>,
+    flags: Arc<BTreeMap<String, bool>>,
+}
+
+impl FeatureGate {
+    /// Create a gate from explicit flags.
+    pub fn new(
+        module: impl Into<Arc<str>>,
+        flags: impl IntoIterator<Item = (String, bool)>,
+    ) -> Self {
+        Self {
+            module: module.into(),
+            flags: Arc::new(flags.into_iter().collect()),
+        }
+    }
+
+    /// Create a gate with no configured flags (every feature enabled).
+    pub fn all_enabled(module: impl Into<Arc<str>>) -> Self {
+        Self::new(module, std::iter::empty())
+    }
+
+    /// Build a gate from a raw module config entry
+    /// (`modules.<name> = { features: { ... }, config: ... }`).
+    ///
+    /// Missing or malformed `features` sections yield an all-enabled gate;
+    /// non-boolean values are ignored with a warning.
+    pub fn from_module_entry(
+        module: impl Into<Arc<str>>,
+        entry: Option<&serde_json::Value>,
+    ) -> Self {
+        let module = module.into();
+        let Some(map) = entry
+            .and_then(|v| v.get(FEATURES_KEY))
+            .and_then(serde_json::Value::as_object)
+        else {
+            return Self::all_enabled(module);
+        };
+
+        let flags = map.iter().filter_map(|(name, value)| {
+            if let Some(enabled) = value.as_bool() {
+                Some((name.clone(), enabled))
+            } else {
+                tracing::warn!(
+                    module = %module,
+                    feature = %name,
+                    "Ignoring non-boolean feature flag value"
+                );
+                None
+            }
+        });
+
+        let flags: Vec<_> = flags.collect();
+        Self::new(module, flags)
+    }
+
+    /// Name of the module these flags belong to.
+    #[must_use]
+    pub fn module_name(&self) -> &str {
+        &self.module
+    }
+
+    /// Returns `true` unless the feature is explicitly disabled.
+    #[must_use]
+    pub fn is_enabled(&self, feature: &str) -> bool {
+        self.flags.get(feature).copied().unwrap_or(true)
+    }
+
+    /// Guard for domain code.
+    ///
+    /// # Errors
+    /// Returns [`FeatureDisabled`] if the feature is explicitly disabled.
+    pub fn ensure_enabled(&self, feature: &str) -> Result<(), FeatureDisabled> {
+        if self.is_enabled(feature) {
+            Ok(())
+        } else {
+            Err(FeatureDisabled {
+                module: self.module.to_string(),
+                feature: feature.to_owned(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use http::StatusCode;
+    use serde_json::json;
+
+    #[test]
+    fn unlisted_features_are_enabled() {
+        let gate = FeatureGate::all_enabled("m");
+        assert!(gate.is_enabled("anything"));
+        assert!(gate.ensure_enabled("anything").is_ok());
+    }
+
+    #[test]
+    fn from_module_entry_reads_flags() {
+        let entry = json!({
+            "features": { "addresses": false, "cities": true },
+            "config": {}
+        });
+        let gate = FeatureGate::from_module_entry("users-info", Some(&entry));
+
+        assert!(!gate.is_enabled("addresses"));
+        assert!(gate.is_enabled("cities"));
+        assert!(gate.is_enabled("unlisted"));
+    }
+
+    #[test]
+    fn from_module_entry_ignores_non_bool_values() {
+        let entry = json!({ "features": { "addresses": "off" } });
+        let gate = FeatureGate::from_module_entry("m", Some(&entry));
+        assert!(gate.is_enabled("addresses"));
+    }
+
+    #[test]
+    fn from_module_entry_without_section_is_all_enabled() {
+        let gate = FeatureGate::from_module_entry("m", Some(&json!({ "config": {} })));
+        assert!(gate.is_enabled("addresses"));
+
+        let gate = FeatureGate::from_module_entry("m", None);
+        assert!(gate.is_enabled("addresses"));
+    }
+
+    #[test]
+    fn ensure_enabled_returns_error_mapped_to_404() {
+        let gate = FeatureGate::new("users-info", [("addresses".to_owned(), false)]);
+        let err = gate.ensure_enabled("addresses").unwrap_err();
+
+        assert_eq!(err.module, "users-info");
+        assert_eq!(err.feature, "addresses");
+
+        let problem: Problem = err.into();
+        assert_eq!(problem.status, StatusCode::NOT_FOUND);
+    }
+}
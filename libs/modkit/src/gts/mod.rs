@@ -4,3 +4,9 @@ pub use plugin::BaseModkitPluginV1;
 
 pub mod schemas;
 pub use schemas::get_core_gts_schemas;
+
+pub mod validation;
+pub use validation::{
+    PluginHealth, PluginIssueKind, PluginValidationIssue, format_plugin_issues,
+    validate_plugin_instances,
+};
@@ -0,0 +1,216 @@
+//! Proactive validation of registered plugin instances.
+//!
+//! [`choose_plugin_instance`](crate::plugins::choose_plugin_instance) stops at the
+//! first malformed instance, and only when a plugin is first selected. The helpers
+//! here check every candidate up front and collect all problems, so a resolver can
+//! report misconfigured registry entries at startup.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::BaseModkitPluginV1;
+
+/// Lowest accepted plugin priority (lower value = preferred).
+pub const MIN_PLUGIN_PRIORITY: i16 = 0;
+
+/// What is wrong with a plugin instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginIssueKind {
+    /// Content is not a plugin instance (not an object, missing or mistyped base fields).
+    Malformed,
+    /// `content.id` differs from the registered GTS ID.
+    IdMismatch,
+    /// `vendor` is empty or whitespace.
+    EmptyVendor,
+    /// `priority` is outside `MIN_PLUGIN_PRIORITY..=i16::MAX`.
+    PriorityOutOfRange,
+    /// `properties` does not match the plugin-specific schema.
+    InvalidProperties,
+}
+
+impl PluginIssueKind {
+    /// Stable name used in logs and health output.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Malformed => "malformed",
+            Self::IdMismatch => "id_mismatch",
+            Self::EmptyVendor => "empty_vendor",
+            Self::PriorityOutOfRange => "priority_out_of_range",
+            Self::InvalidProperties => "invalid_properties",
+        }
+    }
+}
+
+impl fmt::Display for PluginIssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single problem found in a plugin instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PluginValidationIssue {
+    /// GTS ID the instance is registered under.
+    pub gts_id: String,
+    /// Category of the problem.
+    pub kind: PluginIssueKind,
+    /// Human-readable details.
+    pub message: String,
+}
+
+impl fmt::Display for PluginValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} [{}]: {}", self.gts_id, self.kind, self.message)
+    }
+}
+
+/// Base plugin fields, read loosely so every field can be checked independently.
+#[derive(Deserialize)]
+struct RawPluginInstance {
+    id: String,
+    vendor: String,
+    priority: i64,
+}
+
+/// Check every plugin instance and return all problems found.
+///
+/// Accepts the same `(gts_id, content)` pairs as
+/// [`choose_plugin_instance`](crate::plugins::choose_plugin_instance). Each entry is
+/// checked for a matching `content.id`, a non-empty `vendor`, a priority in
+/// `MIN_PLUGIN_PRIORITY..=i16::MAX` and `properties` that deserialize as `P`. An
+/// instance can yield several issues; an empty result means all instances are valid.
+pub fn validate_plugin_instances<'a, P>(
+    instances: impl IntoIterator<Item = (&'a str, &'a serde_json::Value)>,
+) -> Vec<PluginValidationIssue>
+where
+    P: for<'de> gts::GtsDeserialize<'de> + gts::GtsSchema,
+{
+    let mut issues = Vec::new();
+
+    for (gts_id, content) in instances {
+        let issue = |kind, message: String| PluginValidationIssue {
+            gts_id: gts_id.to_owned(),
+            kind,
+            message,
+        };
+
+        let raw: RawPluginInstance = match serde_json::from_value(content.clone()) {
+            Ok(raw) => raw,
+            Err(e) => {
+                issues.push(issue(PluginIssueKind::Malformed, e.to_string()));
+                continue;
+            }
+        };
+
+        if raw.id != gts_id {
+            issues.push(issue(
+                PluginIssueKind::IdMismatch,
+                format!("content.id is {:?}", raw.id),
+            ));
+        }
+        if raw.vendor.trim().is_empty() {
+            issues.push(issue(
+                PluginIssueKind::EmptyVendor,
+                "vendor must not be empty".to_owned(),
+            ));
+        }
+        if !i16::try_from(raw.priority).is_ok_and(|p| p >= MIN_PLUGIN_PRIORITY) {
+            issues.push(issue(
+                PluginIssueKind::PriorityOutOfRange,
+                format!(
+                    "priority {} is outside {MIN_PLUGIN_PRIORITY}..={}",
+                    raw.priority,
+                    i16::MAX
+                ),
+            ));
+            // The typed check below would only repeat the range error
+            continue;
+        }
+
+        if let Err(e) = serde_json::from_value::<BaseModkitPluginV1<P>>(content.clone()) {
+            issues.push(issue(PluginIssueKind::InvalidProperties, e.to_string()));
+        }
+    }
+
+    issues
+}
+
+/// Render issues as a fixed-width table for a single consolidated log line.
+#[must_use]
+pub fn format_plugin_issues(issues: &[PluginValidationIssue]) -> String {
+    let id_width = issues
+        .iter()
+        .map(|i| i.gts_id.len())
+        .chain(std::iter::once("GTS ID".len()))
+        .max()
+        .unwrap_or_default();
+    let kind_width = issues
+        .iter()
+        .map(|i| i.kind.as_str().len())
+        .chain(std::iter::once("ISSUE".len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut out = format!("{:id_width$}  {:kind_width$}  DETAILS", "GTS ID", "ISSUE");
+    for issue in issues {
+        out.push('\n');
+        out.push_str(&format!(
+            "{:id_width$}  {:kind_width$}  {}",
+            issue.gts_id,
+            issue.kind.as_str(),
+            issue.message
+        ));
+    }
+    out
+}
+
+/// Outcome of the last plugin validation run, suitable for health output.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PluginHealth {
+    /// Number of instances that were checked.
+    pub checked: usize,
+    /// Problems found, possibly several per instance.
+    pub issues: Vec<PluginValidationIssue>,
+}
+
+impl PluginHealth {
+    /// Number of distinct instances with at least one issue.
+    #[must_use]
+    pub fn invalid_instances(&self) -> usize {
+        let mut ids: Vec<&str> = self.issues.iter().map(|i| i.gts_id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.len()
+    }
+
+    /// `true` when no issues were found.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// One-line summary, e.g. `"2 invalid plugin instances"`.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        match self.invalid_instances() {
+            0 => format!("{} plugin instances valid", self.checked),
+            1 => "1 invalid plugin instance".to_owned(),
+            n => format!("{n} invalid plugin instances"),
+        }
+    }
+
+    /// Health probe payload: status, counts, summary and the issue list.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "status": if self.is_healthy() { "ok" } else { "degraded" },
+            "checked": self.checked,
+            "invalid_instances": self.invalid_instances(),
+            "summary": self.summary(),
+            "issues": self.issues,
+        })
+    }
+}
@@ -0,0 +1,318 @@
+//! Context-propagating HTTP client for inter-module calls.
+//!
+//! [`ContextHttpClient`] wraps a [`modkit_http::HttpClient`] and stamps every
+//! request made on behalf of a caller with:
+//! - `Authorization: Bearer <token>` — the bearer token the caller
+//!   authenticated with, so the receiving module validates it exactly like a
+//!   request coming through the gateway;
+//! - `X-Request-Id` — the id of the inbound request being served, so logs and
+//!   traces of both modules can be correlated.
+//!
+//! The client is bound to the hosts it may call: a request to any other host
+//! is refused before the token is attached, so a caller-supplied URL (a
+//! webhook target, a redirect) cannot receive an end user's credentials.
+//!
+//! The binary `SecurityContext` encoding (`modkit_security::bin_codec`) is not
+//! used as a credential: it is neither signed nor encrypted, so a receiver
+//! trusting it would accept whatever identity the sender chose to claim.
+//!
+//! ```ignore
+//! let client = ContextHttpClient::new(
+//!     HttpClient::builder().build()?,
+//!     ["users-info.internal"],
+//! );
+//! let resp = client
+//!     .with_context(&ctx)
+//!     .request_id(request_id)
+//!     .get("https://users-info.internal/users-info/v1/users")?
+//!     .send()
+//!     .await?;
+//! ```
+
+use std::sync::Arc;
+
+use modkit_http::{HttpClient, RequestBuilder};
+use modkit_security::SecurityContext;
+use secrecy::ExposeSecret;
+
+/// Header carrying the request id across module boundaries.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Errors raised while preparing a propagated request.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum PropagationError {
+    /// The context has no bearer token to forward, e.g. because it was
+    /// narrowed or built for a background job.
+    #[error("security context has no bearer token to forward")]
+    MissingBearerToken,
+
+    /// The URL is not on a host the client is bound to (or has no host).
+    #[error("refusing to forward credentials to '{url}': host is not allowed")]
+    HostNotAllowed { url: String },
+}
+
+/// HTTP client that forwards the caller's credentials and request id.
+///
+/// Cloning is cheap; store it directly in services.
+#[derive(Clone)]
+pub struct ContextHttpClient {
+    inner: HttpClient,
+    allowed_hosts: Arc<[String]>,
+}
+
+impl ContextHttpClient {
+    /// Wrap a configured transport client (timeouts, retries, TLS) that may
+    /// only call `allowed_hosts`.
+    ///
+    /// Hosts are compared case-insensitively and without the port; an empty
+    /// list rejects every request.
+    #[must_use]
+    pub fn new<H: Into<String>>(
+        inner: HttpClient,
+        allowed_hosts: impl IntoIterator<Item = H>,
+    ) -> Self {
+        Self {
+            inner,
+            allowed_hosts: allowed_hosts
+                .into_iter()
+                .map(|host| host.into().to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Issue requests on behalf of `ctx`.
+    #[must_use]
+    pub fn with_context<'a>(&'a self, ctx: &'a SecurityContext) -> ContextRequests<'a> {
+        ContextRequests {
+            client: &self.inner,
+            allowed_hosts: &self.allowed_hosts,
+            ctx,
+            request_id: None,
+        }
+    }
+
+    /// The underlying transport client, for calls that must not carry the
+    /// caller's identity.
+    #[must_use]
+    pub fn inner(&self) -> &HttpClient {
+        &self.inner
+    }
+}
+
+/// Request factory bound to a caller's [`SecurityContext`].
+pub struct ContextRequests<'a> {
+    client: &'a HttpClient,
+    allowed_hosts: &'a [String],
+    ctx: &'a SecurityContext,
+    request_id: Option<String>,
+}
+
+impl ContextRequests<'_> {
+    /// Forward `request_id` as `X-Request-Id` on every request.
+    #[must_use]
+    pub fn request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// # Errors
+    /// Returns [`PropagationError`] if the host is not allowed or the context
+    /// has no token.
+    pub fn get(&self, url: &str) -> Result<RequestBuilder, PropagationError> {
+        self.check_host(url)?;
+        self.propagate(self.client.get(url))
+    }
+
+    /// # Errors
+    /// Returns [`PropagationError`] if the host is not allowed or the context
+    /// has no token.
+    pub fn post(&self, url: &str) -> Result<RequestBuilder, PropagationError> {
+        self.check_host(url)?;
+        self.propagate(self.client.post(url))
+    }
+
+    /// # Errors
+    /// Returns [`PropagationError`] if the host is not allowed or the context
+    /// has no token.
+    pub fn put(&self, url: &str) -> Result<RequestBuilder, PropagationError> {
+        self.check_host(url)?;
+        self.propagate(self.client.put(url))
+    }
+
+    /// # Errors
+    /// Returns [`PropagationError`] if the host is not allowed or the context
+    /// has no token.
+    pub fn patch(&self, url: &str) -> Result<RequestBuilder, PropagationError> {
+        self.check_host(url)?;
+        self.propagate(self.client.patch(url))
+    }
+
+    /// # Errors
+    /// Returns [`PropagationError`] if the host is not allowed or the context
+    /// has no token.
+    pub fn delete(&self, url: &str) -> Result<RequestBuilder, PropagationError> {
+        self.check_host(url)?;
+        self.propagate(self.client.delete(url))
+    }
+
+    fn check_host(&self, url: &str) -> Result<(), PropagationError> {
+        let allowed = url.parse::<http::Uri>().ok().is_some_and(|uri| {
+            uri.host().is_some_and(|host| {
+                let host = host.trim_end_matches('.');
+                self.allowed_hosts
+                    .iter()
+                    .any(|allowed| host.eq_ignore_ascii_case(allowed))
+            })
+        });
+        if allowed {
+            Ok(())
+        } else {
+            Err(PropagationError::HostNotAllowed {
+                url: url.to_owned(),
+            })
+        }
+    }
+
+    fn propagate(&self, request: RequestBuilder) -> Result<RequestBuilder, PropagationError> {
+        let token = self
+            .ctx
+            .bearer_token()
+            .ok_or(PropagationError::MissingBearerToken)?;
+        let request = request.header(
+            http::header::AUTHORIZATION.as_str(),
+            &format!("Bearer {}", token.expose_secret()),
+        );
+        Ok(match &self.request_id {
+            Some(request_id) => request.header(REQUEST_ID_HEADER, request_id),
+            None => request,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use httpmock::prelude::*;
+    use modkit_http::TransportSecurity;
+    use uuid::Uuid;
+
+    fn client() -> ContextHttpClient {
+        ContextHttpClient::new(
+            HttpClient::builder()
+                .retry(None)
+                .transport(TransportSecurity::AllowInsecureHttp)
+                .build()
+                .unwrap(),
+            ["127.0.0.1", "localhost"],
+        )
+    }
+
+    fn ctx_with_token(token: &str) -> SecurityContext {
+        SecurityContext::builder()
+            .subject_id(Uuid::new_v4())
+            .subject_tenant_id(Uuid::new_v4())
+            .bearer_token(token.to_owned())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn forwards_bearer_token_and_request_id() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(GET)
+                    .path("/v1/things")
+                    .header("authorization", "Bearer caller-token")
+                    .header("x-request-id", "req-42");
+                then.status(200);
+            })
+            .await;
+
+        let client = client();
+        let ctx = ctx_with_token("caller-token");
+        let resp = client
+            .with_context(&ctx)
+            .request_id("req-42")
+            .get(&server.url("/v1/things"))
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status().as_u16(), 200);
+        assert_eq!(mock.calls_async().await, 1);
+    }
+
+    #[tokio::test]
+    async fn request_id_is_optional() {
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method(POST)
+                    .path("/v1/things")
+                    .header("authorization", "Bearer caller-token")
+                    .header_missing("x-request-id");
+                then.status(201);
+            })
+            .await;
+
+        let client = client();
+        let ctx = ctx_with_token("caller-token");
+        client
+            .with_context(&ctx)
+            .post(&server.url("/v1/things"))
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(mock.calls_async().await, 1);
+    }
+
+    #[test]
+    fn contexts_without_token_are_rejected() {
+        let client = client();
+
+        let anonymous = SecurityContext::anonymous();
+        assert_eq!(
+            client
+                .with_context(&anonymous)
+                .get("http://localhost/")
+                .err(),
+            Some(PropagationError::MissingBearerToken)
+        );
+
+        // Narrowing drops the token so a reduced context cannot be re-expanded downstream
+        let narrowed = ctx_with_token("caller-token").narrowed(&["read"]);
+        assert!(
+            client
+                .with_context(&narrowed)
+                .delete("http://localhost/")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn other_hosts_are_rejected() {
+        let client = client();
+        let ctx = ctx_with_token("caller-token");
+        let requests = client.with_context(&ctx);
+
+        for url in [
+            "https://hooks.example.com/collect",
+            "http://127.0.0.2/",
+            "/relative/path",
+        ] {
+            assert_eq!(
+                requests.post(url).err(),
+                Some(PropagationError::HostNotAllowed {
+                    url: url.to_owned()
+                }),
+                "{url}"
+            );
+        }
+        assert!(requests.get("http://LOCALHOST:8080/v1/things").is_ok());
+    }
+}
@@ -0,0 +1,87 @@
+//! Request id propagation for outgoing gRPC calls.
+//!
+//! ```ignore
+//! let channel = Endpoint::from_static("http://users-info:50051").connect().await?;
+//! let client = UsersClient::with_interceptor(channel, request_id_interceptor());
+//! ```
+
+use tonic::metadata::MetadataValue;
+use tonic::service::Interceptor;
+use tonic::{Request, Status};
+
+use super::REQUEST_ID_HEADER;
+use super::request_id;
+
+/// Adds the current request id (see [`request_id::current`]) to each outgoing
+/// call as `x-request-id` metadata.
+///
+/// Calls that already carry the key keep their value; calls made outside a
+/// request scope are left untouched.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RequestIdInterceptor;
+
+impl Interceptor for RequestIdInterceptor {
+    fn call(&mut self, mut request: Request<()>) -> Result<Request<()>, Status> {
+        if request.metadata().contains_key(REQUEST_ID_HEADER) {
+            return Ok(request);
+        }
+        let Some(request_id) = request_id::current() else {
+            return Ok(request);
+        };
+        match MetadataValue::try_from(request_id.as_str()) {
+            Ok(value) => {
+                request.metadata_mut().insert(REQUEST_ID_HEADER, value);
+            }
+            Err(_) => tracing::debug!("Request id is not valid gRPC metadata; not propagated"),
+        }
+        Ok(request)
+    }
+}
+
+/// Interceptor that propagates the current request id; see [`RequestIdInterceptor`].
+#[must_use]
+pub fn request_id_interceptor() -> RequestIdInterceptor {
+    RequestIdInterceptor
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    async fn intercept(request: Request<()>) -> Request<()> {
+        request_id_interceptor().call(request).unwrap()
+    }
+
+    fn header(request: &Request<()>) -> Option<&str> {
+        request
+            .metadata()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+    }
+
+    #[tokio::test]
+    async fn adds_current_request_id() {
+        let request = request_id::scope("req-42".to_owned(), intercept(Request::new(()))).await;
+        assert_eq!(header(&request), Some("req-42"));
+    }
+
+    #[tokio::test]
+    async fn leaves_requests_outside_a_scope_and_explicit_ids_alone() {
+        let request = intercept(Request::new(())).await;
+        assert_eq!(header(&request), None);
+
+        let mut explicit = Request::new(());
+        explicit
+            .metadata_mut()
+            .insert(REQUEST_ID_HEADER, MetadataValue::from_static("caller-set"));
+        let request = request_id::scope("req-42".to_owned(), intercept(explicit)).await;
+        assert_eq!(header(&request), Some("caller-set"));
+    }
+
+    #[tokio::test]
+    async fn skips_ids_that_are_not_valid_metadata() {
+        let request = request_id::scope("bad\nid".to_owned(), intercept(Request::new(()))).await;
+        assert_eq!(header(&request), None);
+    }
+}
@@ -3,4 +3,10 @@
 //! This module provides shared HTTP types and utilities for building
 //! modular web applications.
 
+pub mod client;
+#[cfg(feature = "otel")]
+pub mod grpc;
+pub mod request_id;
 pub mod sse;
+
+pub use client::{ContextHttpClient, ContextRequests, PropagationError, REQUEST_ID_HEADER};
@@ -0,0 +1,38 @@
+//! Request id of the inbound HTTP request being served.
+//!
+//! The API gateway runs each handler inside [`scope`], so code further down
+//! the call chain (outgoing HTTP or gRPC clients, background logging) can read
+//! the id with [`current`] without threading it through every signature.
+
+use std::future::Future;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Run `fut` with `request_id` as the current request id.
+pub async fn scope<F: Future>(request_id: String, fut: F) -> F::Output {
+    REQUEST_ID.scope(request_id, fut).await
+}
+
+/// The request id of the enclosing [`scope`], if any.
+///
+/// Tasks spawned with `tokio::spawn` do not inherit the scope.
+#[must_use]
+pub fn current() -> Option<String> {
+    REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn current_is_set_only_inside_scope() {
+        assert_eq!(current(), None);
+        let inside = scope("req-1".to_owned(), async { current() }).await;
+        assert_eq!(inside.as_deref(), Some("req-1"));
+        assert_eq!(current(), None);
+    }
+}
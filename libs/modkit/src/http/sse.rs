@@ -7,12 +7,30 @@ use std::{borrow::Cow, convert::Infallible, time::Duration};
 use tokio::sync::broadcast;
 use tokio_stream::wrappers::BroadcastStream;
 
+/// A broadcast payload with optional per-message SSE `event:` and `id:` fields.
+#[derive(Clone)]
+struct SseMessage<T> {
+    event: Option<&'static str>,
+    id: Option<String>,
+    data: T,
+}
+
+impl<T> SseMessage<T> {
+    fn plain(data: T) -> Self {
+        Self {
+            event: None,
+            id: None,
+            data,
+        }
+    }
+}
+
 /// Small typed SSE broadcaster built on `tokio::sync::broadcast`.
 /// - T must be `Clone` so multiple subscribers can receive the same payload.
 /// - Bounded channel drops oldest events when subscribers lag (by design).
 #[derive(Clone)]
 pub struct SseBroadcaster<T> {
-    tx: broadcast::Sender<T>,
+    tx: broadcast::Sender<SseMessage<T>>,
 }
 
 impl<T: Clone + Send + 'static> SseBroadcaster<T> {
@@ -26,49 +44,79 @@ impl<T: Clone + Send + 'static> SseBroadcaster<T> {
     /// Broadcast a single message to current subscribers.
     /// Errors are ignored to keep the hot path cheap (e.g., no active subscribers).
     pub fn send(&self, value: T) {
-        _ = self.tx.send(value);
+        _ = self.tx.send(SseMessage::plain(value));
+    }
+
+    /// Broadcast a message with a named SSE `event:` field (e.g. `"user.created"`).
+    ///
+    /// Lets clients discriminate event kinds on a single stream; overrides the
+    /// constant name used by [`Self::sse_response_named`] for this message.
+    pub fn broadcast_typed(&self, event_type: &'static str, data: T) {
+        _ = self.tx.send(SseMessage {
+            event: Some(event_type),
+            id: None,
+            data,
+        });
+    }
+
+    /// Broadcast a message with an SSE `id:` field.
+    ///
+    /// Clients echo the last seen id in `Last-Event-ID` when reconnecting,
+    /// which lets handlers resume from a cursor.
+    pub fn broadcast_id(&self, id: impl Into<String>, data: T) {
+        _ = self.tx.send(SseMessage {
+            event: None,
+            id: Some(id.into()),
+            data,
+        });
     }
 
     /// Subscribe to a typed stream of messages; lag/drop errors are filtered out.
     pub fn subscribe_stream(&self) -> impl Stream<Item = T> + use<T> {
+        self.subscribe_messages().map(|msg| msg.data)
+    }
+
+    fn subscribe_messages(&self) -> impl Stream<Item = SseMessage<T>> + use<T> {
         BroadcastStream::new(self.tx.subscribe()).filter_map(|res| async move { res.ok() })
     }
 
-    /// Convert a typed stream into an SSE stream with JSON payloads (no event name).
+    /// Build an SSE event with a JSON payload, falling back to a tiny text
+    /// marker instead of breaking the stream when serialization fails.
+    fn to_event(msg: &SseMessage<T>, default_event: Option<&str>) -> Event
+    where
+        T: Serialize,
+    {
+        let mut ev = Event::default();
+        if let Some(name) = msg.event.or(default_event) {
+            ev = ev.event(name);
+        }
+        if let Some(id) = &msg.id {
+            ev = ev.id(id);
+        }
+        ev.clone()
+            .json_data(&msg.data)
+            .unwrap_or_else(|_| ev.data("serialization_error"))
+    }
+
+    /// Convert a message stream into an SSE stream with JSON payloads (no default event name).
     fn wrap_stream_as_sse<U>(stream: U) -> impl Stream<Item = Result<Event, Infallible>>
     where
-        U: Stream<Item = T>,
+        U: Stream<Item = SseMessage<T>>,
         T: Serialize,
     {
-        stream.map(|msg| {
-            let ev = Event::default().json_data(&msg).unwrap_or_else(|_| {
-                // Fallback to a tiny text marker instead of breaking the stream.
-                Event::default().data("serialization_error")
-            });
-            Ok(ev)
-        })
+        stream.map(|msg| Ok(Self::to_event(&msg, None)))
     }
 
-    /// Convert a typed stream into an SSE stream with JSON payloads and a constant `event:` name.
+    /// Convert a message stream into an SSE stream with JSON payloads and a default `event:` name.
     fn wrap_stream_as_sse_named<U>(
         stream: U,
         event_name: Cow<'static, str>,
     ) -> impl Stream<Item = Result<Event, Infallible>>
     where
-        U: Stream<Item = T>,
+        U: Stream<Item = SseMessage<T>>,
         T: Serialize,
     {
-        stream.map(move |msg| {
-            let ev = Event::default()
-                .event(&event_name) // <-- set event name
-                .json_data(&msg)
-                .unwrap_or_else(|_| {
-                    Event::default()
-                        .event(&event_name)
-                        .data("serialization_error")
-                });
-            Ok(ev)
-        })
+        stream.map(move |msg| Ok(Self::to_event(&msg, Some(&event_name))))
     }
 
     // -------------------------
@@ -81,7 +129,7 @@ impl<T: Clone + Send + 'static> SseBroadcaster<T> {
     where
         T: Serialize,
     {
-        let stream = Self::wrap_stream_as_sse(self.subscribe_stream());
+        let stream = Self::wrap_stream_as_sse(self.subscribe_messages());
         Sse::new(stream).keep_alive(
             KeepAlive::new()
                 .interval(Duration::from_secs(15))
@@ -116,7 +164,7 @@ impl<T: Clone + Send + 'static> SseBroadcaster<T> {
         T: Serialize,
         N: Into<Cow<'static, str>> + 'static,
     {
-        let stream = Self::wrap_stream_as_sse_named(self.subscribe_stream(), event_name.into());
+        let stream = Self::wrap_stream_as_sse_named(self.subscribe_messages(), event_name.into());
         Sse::new(stream).keep_alive(
             KeepAlive::new()
                 .interval(Duration::from_secs(15))
@@ -339,4 +387,66 @@ mod tests {
             "Send operations took too long: {elapsed:?}"
         );
     }
+
+    #[tokio::test]
+    async fn broadcast_typed_sets_event_type() {
+        let b = SseBroadcaster::<u32>::new(16);
+        let mut sub = Box::pin(b.subscribe_messages());
+        b.broadcast_typed("user.created", 7);
+        let msg = timeout(Duration::from_millis(200), sub.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.event, Some("user.created"));
+        assert_eq!(msg.id, None);
+        assert_eq!(msg.data, 7);
+    }
+
+    #[tokio::test]
+    async fn broadcast_id_sets_event_id() {
+        let b = SseBroadcaster::<u32>::new(16);
+        let mut sub = Box::pin(b.subscribe_messages());
+        b.broadcast_id("cursor-1", 8);
+        let msg = timeout(Duration::from_millis(200), sub.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(msg.event, None);
+        assert_eq!(msg.id.as_deref(), Some("cursor-1"));
+        assert_eq!(msg.data, 8);
+    }
+
+    #[tokio::test]
+    async fn subscribe_stream_yields_data_of_typed_messages() {
+        let b = SseBroadcaster::<u32>::new(16);
+        let mut sub = Box::pin(b.subscribe_stream());
+        b.broadcast_typed("user.deleted", 9);
+        let v = timeout(Duration::from_millis(200), sub.next())
+            .await
+            .unwrap();
+        assert_eq!(v, Some(9));
+    }
+
+    #[test]
+    fn per_message_event_overrides_default_name() {
+        let typed = SseMessage {
+            event: Some("user.created"),
+            id: Some("42".to_owned()),
+            data: 1u32,
+        };
+        let rendered = format!(
+            "{:?}",
+            SseBroadcaster::<u32>::to_event(&typed, Some("users"))
+        );
+        assert!(rendered.contains("event: user.created"), "{rendered}");
+        assert!(rendered.contains("id: 42"), "{rendered}");
+
+        let plain = SseMessage::plain(1u32);
+        let rendered = format!(
+            "{:?}",
+            SseBroadcaster::<u32>::to_event(&plain, Some("users"))
+        );
+        assert!(rendered.contains("event: users"), "{rendered}");
+        assert!(!rendered.contains("id:"), "{rendered}");
+    }
 }
@@ -79,12 +79,19 @@ pub use crate::contracts::{GrpcServiceCapability, RegisterGrpcServiceFn};
 
 // Configuration module
 pub mod config;
-pub use config::{ConfigError, ConfigProvider, module_config_or_default, module_config_required};
+pub use config::{
+    ConfigError, ConfigHandle, ConfigProvider, ConfigWatcher, module_config_or_default,
+    module_config_required,
+};
 
 // Context module
 pub mod context;
 pub use context::{ModuleContextBuilder, ModuleCtx};
 
+// Declarative per-module feature flags
+pub mod features;
+pub use features::{FeatureDisabled, FeatureGate};
+
 // Module system implementations for macro code
 pub mod client_hub;
 pub mod registry;
@@ -101,8 +108,8 @@ pub mod contracts;
 // Type-safe API operation builder
 pub mod api;
 pub use api::{
-    IntoProblem, OpenApiInfo, OpenApiRegistry, OpenApiRegistryImpl, OperationBuilder,
-    error_mapping_middleware,
+    IntoProblemWithContext, OpenApiInfo, OpenApiRegistry, OpenApiRegistryImpl, OpenApiServer,
+    OperationBuilder, error_mapping_middleware,
 };
 pub use modkit_odata::{Page, PageInfo};
 
@@ -116,9 +123,13 @@ pub use http::sse::SseBroadcaster;
 // Telemetry utilities
 pub mod telemetry;
 
+// Operator admin channel over a local unix socket
+pub mod admin;
+
 pub mod backends;
 pub mod lifecycle;
 pub mod plugins;
+pub mod readiness;
 pub mod runtime;
 
 // Error catalog runtime support
@@ -126,7 +137,7 @@ pub mod errors;
 
 // Ergonomic result types
 pub mod result;
-pub use result::ApiResult;
+pub use result::{ApiResult, IntoProblem, ProblemResponse};
 
 // Domain layer marker traits for DDD enforcement
 pub mod domain;
@@ -150,11 +161,15 @@ pub use backends::{
     OopModuleConfig, OopSpawnConfig,
 };
 pub use lifecycle::{Lifecycle, Runnable, Status, StopReason, WithLifecycle};
-pub use plugins::GtsPluginSelector;
+pub use plugins::{GtsPluginSelector, SelectorStatus};
 pub use runtime::{
     DbOptions, Endpoint, ModuleInstance, ModuleManager, OopModuleSpawnConfig, OopSpawnOptions,
     RunOptions, ShutdownOptions, run,
 };
 
+// Contract-testing helpers for module test suites
+#[cfg(feature = "test-utils")]
+pub mod testing;
+
 #[cfg(feature = "bootstrap")]
 pub mod bootstrap;
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use parking_lot::Mutex;
 use std::sync::{
-    Arc,
+    Arc, OnceLock,
     atomic::{AtomicBool, AtomicU8, Ordering},
 };
 use std::time::Duration;
@@ -9,6 +9,8 @@ use tokio::sync::{Notify, oneshot};
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
+use crate::readiness::ModuleReadiness;
+
 // ----- Results & aliases -----------------------------------------------------
 
 /// Public result for lifecycle-level operations.
@@ -71,18 +73,81 @@ pub enum StopReason {
 // ----- Ready signal ----------------------------------------------------------
 
 /// Ready signal used by `start_with_ready*` to flip Starting -> Running.
-pub struct ReadySignal(oneshot::Sender<()>);
+///
+/// When the module runs under the runtime, the signal also reports the module's
+/// readiness (see [`crate::readiness`]).
+pub struct ReadySignal {
+    tx: oneshot::Sender<()>,
+    gate: Option<ReadyGate>,
+}
 
 impl ReadySignal {
+    /// Report the module ready.
+    ///
+    /// With `lifecycle(await_ready_of = [...])`, the module stays pending until
+    /// those modules are ready, and becomes degraded if they are not ready
+    /// within `ready_timeout`.
     #[inline]
     pub fn notify(self) {
-        _ = self.0.send(());
+        _ = self.tx.send(());
+        if let Some(gate) = self.gate {
+            gate.report_ready();
+        }
     }
+
+    /// Report the module running but degraded, e.g. when an optional backend is
+    /// unreachable. The status flips to `Running` as with [`Self::notify`].
+    pub fn notify_degraded(self, reason: impl Into<String>) {
+        let reason = reason.into();
+        _ = self.tx.send(());
+        match self.gate {
+            Some(gate) => gate.readiness.degraded(reason),
+            None => tracing::warn!(reason = %reason, "Module started degraded"),
+        }
+    }
+
     /// Construct a `ReadySignal` from a oneshot sender (used by macro-generated shims).
     #[inline]
     #[must_use]
     pub fn from_sender(sender: tokio::sync::oneshot::Sender<()>) -> Self {
-        ReadySignal(sender)
+        ReadySignal {
+            tx: sender,
+            gate: None,
+        }
+    }
+
+    fn with_gate(mut self, gate: Option<ReadyGate>) -> Self {
+        self.gate = gate;
+        self
+    }
+}
+
+/// Where a started module reports readiness, and what it waits for first.
+#[derive(Clone)]
+struct ReadyGate {
+    readiness: ModuleReadiness,
+    await_ready_of: &'static [&'static str],
+    timeout: Duration,
+}
+
+impl ReadyGate {
+    fn report_ready(self) {
+        // Optional dependencies that are not loaded are not waited for.
+        let await_ready_of: Vec<&str> = self
+            .await_ready_of
+            .iter()
+            .copied()
+            .filter(|module| self.readiness.tracks(module))
+            .collect();
+        if await_ready_of.is_empty() {
+            self.readiness.ready();
+            return;
+        }
+        tokio::spawn(async move {
+            self.readiness
+                .ready_after(&await_ready_of, self.timeout)
+                .await;
+        });
     }
 }
 
@@ -303,7 +368,11 @@ impl Lifecycle {
         let task_id = format!("{module_name}-{self:p}");
         let handle = tokio::spawn(async move {
             tracing::debug!(task_id = %task_id, module = %module_name, "lifecycle task starting");
-            let res = make(token, ready_mode.then(|| ReadySignal(ready_tx))).await;
+            let res = make(
+                token,
+                ready_mode.then(|| ReadySignal::from_sender(ready_tx)),
+            )
+            .await;
             if let Err(e) = res {
                 tracing::error!(error=%e, task_id=%task_id, module = %module_name, "lifecycle task error");
             }
@@ -484,6 +553,10 @@ pub struct WithLifecycle<T: Runnable> {
     await_ready: bool,
     has_ready_handler: bool,
     run_ready_fn: Option<ReadyFn<T>>,
+    // readiness reporting (bound by the runtime before start)
+    readiness: OnceLock<ModuleReadiness>,
+    await_ready_of: &'static [&'static str],
+    ready_timeout: Duration,
 }
 
 impl<T: Runnable> WithLifecycle<T> {
@@ -495,6 +568,9 @@ impl<T: Runnable> WithLifecycle<T> {
             await_ready: false,
             has_ready_handler: false,
             run_ready_fn: None,
+            readiness: OnceLock::new(),
+            await_ready_of: &[],
+            ready_timeout: Duration::from_secs(30),
         }
     }
 
@@ -506,6 +582,9 @@ impl<T: Runnable> WithLifecycle<T> {
             await_ready: false,
             has_ready_handler: false,
             run_ready_fn: None,
+            readiness: OnceLock::new(),
+            await_ready_of: &[],
+            ready_timeout: Duration::from_secs(30),
         }
     }
 
@@ -517,6 +596,9 @@ impl<T: Runnable> WithLifecycle<T> {
             await_ready: false,
             has_ready_handler: false,
             run_ready_fn: None,
+            readiness: OnceLock::new(),
+            await_ready_of: &[],
+            ready_timeout: Duration::from_secs(30),
         }
     }
 
@@ -528,6 +610,9 @@ impl<T: Runnable> WithLifecycle<T> {
             await_ready: false,
             has_ready_handler: false,
             run_ready_fn: None,
+            readiness: OnceLock::new(),
+            await_ready_of: &[],
+            ready_timeout: Duration::from_secs(30),
         }
     }
 
@@ -567,6 +652,26 @@ impl<T: Runnable> WithLifecycle<T> {
         self.run_ready_fn = run_ready_fn;
         self
     }
+
+    /// Report ready only once `modules` are ready, or degraded after `timeout`
+    /// (`#[modkit::module(..., lifecycle(await_ready_of = [...], ready_timeout = "..."))]`).
+    pub fn with_ready_dependencies(
+        mut self,
+        modules: &'static [&'static str],
+        timeout: Duration,
+    ) -> Self {
+        self.await_ready_of = modules;
+        self.ready_timeout = timeout;
+        self
+    }
+
+    fn ready_gate(&self) -> Option<ReadyGate> {
+        self.readiness.get().map(|readiness| ReadyGate {
+            readiness: readiness.clone(),
+            await_ready_of: self.await_ready_of,
+            timeout: self.ready_timeout,
+        })
+    }
 }
 
 impl<T: Runnable + Default> Default for WithLifecycle<T> {
@@ -581,29 +686,41 @@ impl<T: Runnable> crate::contracts::RunnableCapability for WithLifecycle<T> {
     async fn start(&self, external_cancel: CancellationToken) -> TaskResult<()> {
         let inner = self.inner.clone();
         let composed = external_cancel.child_token();
+        let gate = self.ready_gate();
 
         if !self.await_ready {
             self.lc
                 .start_with_token(composed, move |cancel| inner.run(cancel))
-                .map_err(anyhow::Error::from)
+                .map_err(anyhow::Error::from)?;
+            if let Some(gate) = gate {
+                gate.report_ready();
+            }
+            Ok(())
         } else if self.has_ready_handler {
             let f = self.run_ready_fn.ok_or_else(|| {
                 anyhow::anyhow!("run_ready_fn must be set when has_ready_handler")
             })?;
             self.lc
-                .start_with_ready_and_token(composed, move |cancel, ready| f(inner, cancel, ready))
+                .start_with_ready_and_token(composed, move |cancel, ready| {
+                    f(inner, cancel, ready.with_gate(gate))
+                })
                 .map_err(anyhow::Error::from)
         } else {
             self.lc
                 .start_with_ready_and_token(composed, move |cancel, ready| async move {
                     // Auto-notify readiness and continue with normal run()
-                    ready.notify();
+                    ready.with_gate(gate).notify();
                     inner.run(cancel).await
                 })
                 .map_err(anyhow::Error::from)
         }
     }
 
+    fn bind_readiness(&self, readiness: ModuleReadiness) -> bool {
+        _ = self.readiness.set(readiness);
+        true
+    }
+
     #[tracing::instrument(skip(self, external_cancel), level = "debug")]
     async fn stop(&self, external_cancel: CancellationToken) -> TaskResult<()> {
         tokio::select! {
@@ -842,6 +959,96 @@ mod tests {
         assert_eq!(wrapper.status(), Status::Stopped);
     }
 
+    #[tokio::test]
+    async fn ready_dependencies_keep_module_pending_until_timeout() {
+        use crate::contracts::RunnableCapability;
+        use crate::readiness::{Readiness, ReadinessRegistry};
+
+        let registry = Arc::new(ReadinessRegistry::new());
+        registry.register("api-gateway");
+        registry
+            .module("authn-resolver")
+            .degraded("no plugin selected");
+
+        let wrapper = WithLifecycle::new_with_name(TestRunnable::new(), "api-gateway")
+            .with_ready_mode(true, false, None)
+            .with_ready_dependencies(&["authn-resolver"], Duration::from_millis(100));
+        assert!(wrapper.bind_readiness(registry.module("api-gateway")));
+
+        wrapper.start(CancellationToken::new()).await.unwrap();
+        sleep(Duration::from_millis(30)).await;
+        // The task runs, but the module is not ready while its dependency is degraded.
+        assert_eq!(wrapper.status(), Status::Running);
+        assert_eq!(registry.get("api-gateway"), Some(Readiness::Pending));
+
+        sleep(Duration::from_millis(150)).await;
+        let Some(Readiness::Degraded { reason }) = registry.get("api-gateway") else {
+            panic!("expected degraded after the ready timeout");
+        };
+        assert!(reason.contains("authn-resolver"), "{reason}");
+
+        wrapper.stop(CancellationToken::new()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn ready_dependencies_that_are_not_loaded_are_not_awaited() {
+        use crate::contracts::RunnableCapability;
+        use crate::readiness::{Readiness, ReadinessRegistry};
+
+        let registry = Arc::new(ReadinessRegistry::new());
+        registry.register("api-gateway");
+
+        let wrapper = WithLifecycle::new_with_name(TestRunnable::new(), "api-gateway")
+            .with_ready_mode(true, false, None)
+            .with_ready_dependencies(&["authn-resolver"], Duration::from_secs(5));
+        assert!(wrapper.bind_readiness(registry.module("api-gateway")));
+
+        wrapper.start(CancellationToken::new()).await.unwrap();
+        sleep(Duration::from_millis(30)).await;
+        assert_eq!(registry.get("api-gateway"), Some(Readiness::Ready));
+
+        wrapper.stop(CancellationToken::new()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn notify_degraded_runs_and_reports_reason() {
+        use crate::contracts::RunnableCapability;
+        use crate::readiness::{Readiness, ReadinessRegistry};
+
+        fn degraded_entry(
+            _this: Arc<TestRunnable>,
+            cancel: CancellationToken,
+            ready: ReadySignal,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = TaskResult<()>> + Send>> {
+            Box::pin(async move {
+                ready.notify_degraded("cache unreachable");
+                cancel.cancelled().await;
+                Ok(())
+            })
+        }
+
+        let registry = Arc::new(ReadinessRegistry::new());
+        let wrapper = WithLifecycle::new_with_name(TestRunnable::new(), "worker").with_ready_mode(
+            true,
+            true,
+            Some(degraded_entry),
+        );
+        wrapper.bind_readiness(registry.module("worker"));
+
+        wrapper.start(CancellationToken::new()).await.unwrap();
+        sleep(Duration::from_millis(20)).await;
+
+        assert_eq!(wrapper.status(), Status::Running);
+        assert_eq!(
+            registry.get("worker"),
+            Some(Readiness::Degraded {
+                reason: "cache unreachable".to_owned()
+            })
+        );
+
+        wrapper.stop(CancellationToken::new()).await.unwrap();
+    }
+
     #[tokio::test]
     async fn with_lifecycle_double_start_fails() {
         use crate::contracts::RunnableCapability;
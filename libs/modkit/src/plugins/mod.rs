@@ -1,21 +1,101 @@
+use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use parking_lot::RwLock;
 use tokio::sync::Mutex;
 
 use crate::gts::BaseModkitPluginV1;
 
+/// Snapshot of a [`GtsPluginSelector`], for health probes and diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SelectorStatus {
+    /// No resolution attempted since startup or the last reset.
+    Unresolved,
+    /// A plugin instance is selected.
+    Resolved {
+        instance_id: Arc<str>,
+        resolved_at: SystemTime,
+        /// Successful resolutions since startup, including reselections.
+        resolution_count: u64,
+    },
+    /// The last resolution failed; the next call tries again.
+    Failed {
+        error_message: String,
+        failed_at: SystemTime,
+        /// Consecutive failed attempts.
+        attempts: u64,
+    },
+}
+
+impl SelectorStatus {
+    /// Health probe payload; timestamps are Unix seconds.
+    #[must_use]
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Unresolved => serde_json::json!({ "state": "unresolved" }),
+            Self::Resolved {
+                instance_id,
+                resolved_at,
+                resolution_count,
+            } => serde_json::json!({
+                "state": "resolved",
+                "instance_id": instance_id.as_ref(),
+                "resolved_at": unix_secs(*resolved_at),
+                "resolution_count": resolution_count,
+            }),
+            Self::Failed {
+                error_message,
+                failed_at,
+                attempts,
+            } => serde_json::json!({
+                "state": "failed",
+                "error_message": error_message,
+                "failed_at": unix_secs(*failed_at),
+                "attempts": attempts,
+            }),
+        }
+    }
+}
+
+fn unix_secs(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+/// Returned by [`GtsPluginSelector::get_or_init_with_backoff`] while the
+/// cool-down after a failed resolution is running.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("plugin resolution cooling down for {retry_in:?} after failure: {last_error}")]
+pub struct SelectionCoolingDown {
+    /// Message of the failure that started the cool-down.
+    pub last_error: String,
+    /// Time left before the next attempt.
+    pub retry_in: Duration,
+}
+
+struct SelectorState {
+    status: SelectorStatus,
+    /// Monotonic time of the last failure, for the backoff window.
+    last_failure: Option<Instant>,
+}
+
 /// A resettable, allocation-friendly selector for GTS plugin instance IDs.
 ///
 /// Uses a single-flight pattern to ensure that the resolve function is called
 /// at most once even under concurrent callers. The selected instance ID is
-/// cached as `Arc<str>` to avoid allocations on the happy path.
+/// cached as `Arc<str>` to avoid allocations on the happy path. Failures are
+/// recorded in [`status`](Self::status) but not cached.
 pub struct GtsPluginSelector {
-    /// Cached selected instance ID (sync lock for fast access and sync reset).
-    cached: RwLock<Option<Arc<str>>>,
+    /// Selection state (sync lock for fast access and sync reset).
+    state: RwLock<SelectorState>,
     /// Mutex to ensure single-flight resolution.
     resolve_lock: Mutex<()>,
+    /// Number of `reset()` calls; non-zero means resolutions are reselections.
+    reset_count: AtomicU64,
+    /// Number of successful resolutions.
+    resolution_count: AtomicU64,
 }
 
 impl Default for GtsPluginSelector {
@@ -28,8 +108,13 @@ impl GtsPluginSelector {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            cached: RwLock::new(None),
+            state: RwLock::new(SelectorState {
+                status: SelectorStatus::Unresolved,
+                last_failure: None,
+            }),
             resolve_lock: Mutex::new(()),
+            reset_count: AtomicU64::new(0),
+            resolution_count: AtomicU64::new(0),
         }
     }
 
@@ -45,33 +130,131 @@ impl GtsPluginSelector {
     where
         F: FnOnce() -> Fut,
         Fut: Future<Output = Result<String, E>>,
+        E: fmt::Display,
     {
         // Fast path: check if already cached (sync lock, no await)
-        {
-            let guard = self.cached.read();
-            if let Some(ref id) = *guard {
-                return Ok(Arc::clone(id));
-            }
+        if let Some(id) = self.cached() {
+            return Ok(id);
         }
 
         // Slow path: acquire resolve lock for single-flight
         let _resolve_guard = self.resolve_lock.lock().await;
 
         // Re-check after acquiring resolve lock (another caller may have resolved)
+        if let Some(id) = self.cached() {
+            return Ok(id);
+        }
+
+        self.resolve_locked(resolve).await
+    }
+
+    /// Like [`get_or_init`](Self::get_or_init), but after a failed resolution
+    /// further calls fail fast with [`SelectionCoolingDown`] until `cool_down`
+    /// has elapsed, instead of calling `resolve` again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(E)` if the provided `resolve` future fails, or
+    /// `E::from(SelectionCoolingDown)` within the cool-down window.
+    pub async fn get_or_init_with_backoff<F, Fut, E>(
+        &self,
+        cool_down: Duration,
+        resolve: F,
+    ) -> Result<Arc<str>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, E>>,
+        E: fmt::Display + From<SelectionCoolingDown>,
+    {
+        if let Some(id) = self.cached() {
+            return Ok(id);
+        }
+        self.check_cool_down(cool_down)?;
+
+        let _resolve_guard = self.resolve_lock.lock().await;
+
+        // Re-check: another caller may have resolved, or failed, meanwhile
+        if let Some(id) = self.cached() {
+            return Ok(id);
+        }
+        self.check_cool_down(cool_down)?;
+
+        self.resolve_locked(resolve).await
+    }
+
+    /// Current selection state.
+    #[must_use]
+    pub fn status(&self) -> SelectorStatus {
+        self.state.read().status.clone()
+    }
+
+    fn cached(&self) -> Option<Arc<str>> {
+        match &self.state.read().status {
+            SelectorStatus::Resolved { instance_id, .. } => Some(Arc::clone(instance_id)),
+            SelectorStatus::Unresolved | SelectorStatus::Failed { .. } => None,
+        }
+    }
+
+    fn check_cool_down(&self, cool_down: Duration) -> Result<(), SelectionCoolingDown> {
+        let state = self.state.read();
+        if let (SelectorStatus::Failed { error_message, .. }, Some(last_failure)) =
+            (&state.status, state.last_failure)
         {
-            let guard = self.cached.read();
-            if let Some(ref id) = *guard {
-                return Ok(Arc::clone(id));
+            let elapsed = last_failure.elapsed();
+            if elapsed < cool_down {
+                return Err(SelectionCoolingDown {
+                    last_error: error_message.clone(),
+                    retry_in: cool_down - elapsed,
+                });
             }
         }
+        Ok(())
+    }
 
-        // Resolve and cache
-        let id_string = resolve().await?;
-        let id: Arc<str> = id_string.into();
+    /// Runs `resolve` and records the outcome. Caller holds `resolve_lock`.
+    async fn resolve_locked<F, Fut, E>(&self, resolve: F) -> Result<Arc<str>, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<String, E>>,
+        E: fmt::Display,
+    {
+        let id: Arc<str> = match resolve().await {
+            Ok(id) => id.into(),
+            Err(e) => {
+                let mut state = self.state.write();
+                let attempts = match &state.status {
+                    SelectorStatus::Failed { attempts, .. } => attempts + 1,
+                    SelectorStatus::Unresolved | SelectorStatus::Resolved { .. } => 1,
+                };
+                tracing::warn!(attempts, error = %e, "GTS plugin resolution failed");
+                state.status = SelectorStatus::Failed {
+                    error_message: e.to_string(),
+                    failed_at: SystemTime::now(),
+                    attempts,
+                };
+                state.last_failure = Some(Instant::now());
+                return Err(e);
+            }
+        };
 
+        let resolution_count = self.resolution_count.fetch_add(1, Ordering::Relaxed) + 1;
         {
-            let mut guard = self.cached.write();
-            *guard = Some(Arc::clone(&id));
+            let mut state = self.state.write();
+            state.status = SelectorStatus::Resolved {
+                instance_id: Arc::clone(&id),
+                resolved_at: SystemTime::now(),
+                resolution_count,
+            };
+            state.last_failure = None;
+        }
+
+        let reset_count = self.reset_count.load(Ordering::Relaxed);
+        if reset_count > 0 {
+            tracing::warn!(
+                reset_count,
+                resolved_id = %id,
+                "GTS plugin re-resolved after selector reset"
+            );
         }
 
         Ok(id)
@@ -80,10 +263,21 @@ impl GtsPluginSelector {
     /// Clears the cached selected instance ID.
     ///
     /// Returns `true` if there was a cached value, `false` otherwise.
+    /// Every call is counted; the next resolution logs a warning with the count.
+    /// The status goes back to [`SelectorStatus::Unresolved`].
     pub async fn reset(&self) -> bool {
         let _resolve_guard = self.resolve_lock.lock().await;
-        let mut guard = self.cached.write();
-        guard.take().is_some()
+        self.reset_count.fetch_add(1, Ordering::Relaxed);
+        let mut state = self.state.write();
+        state.last_failure = None;
+        let previous = std::mem::replace(&mut state.status, SelectorStatus::Unresolved);
+        matches!(previous, SelectorStatus::Resolved { .. })
+    }
+
+    /// Number of times [`reset`](Self::reset) has been called.
+    #[must_use]
+    pub fn reset_count(&self) -> u64 {
+        self.reset_count.load(Ordering::Relaxed)
     }
 }
 
@@ -184,10 +378,23 @@ where
 
     tracing::debug!(vendor, instance_count = count, "choose_plugin_instance");
 
-    best.map(|(gts_id, _)| gts_id.to_owned())
-        .ok_or_else(|| ChoosePluginError::PluginNotFound {
+    let Some((gts_id, priority)) = best else {
+        if count == 0 {
+            // Distinguishes "nothing registered" from "nothing for this vendor".
+            tracing::warn!(vendor, "No plugin instances registered");
+        }
+        return Err(ChoosePluginError::PluginNotFound {
             vendor: vendor.to_owned(),
-        })
+        });
+    };
+
+    tracing::debug!(
+        vendor,
+        selected_gts_id = %gts_id,
+        priority = %priority,
+        "Plugin selected"
+    );
+    Ok(gts_id.to_owned())
 }
 
 #[cfg(test)]
@@ -250,7 +457,9 @@ mod tests {
             "gts.x.core.modkit.plugin.v1~x.core.test.plugin.v1~a.test._.plugin.v1"
         );
         assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(selector.reset_count(), 0);
         assert!(selector.reset().await);
+        assert_eq!(selector.reset_count(), 1);
 
         let calls_b = calls.clone();
         let id_b = selector
@@ -267,6 +476,11 @@ mod tests {
             "gts.x.core.modkit.plugin.v1~x.core.test.plugin.v1~b.test._.plugin.v1"
         );
         assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+        // Resetting an empty selector is still counted
+        assert!(selector.reset().await);
+        assert!(!selector.reset().await);
+        assert_eq!(selector.reset_count(), 3);
     }
 
     #[tokio::test]
@@ -310,4 +524,105 @@ mod tests {
         // Resolve should have been called exactly once
         assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
+
+    #[derive(Debug)]
+    struct ResolveError(&'static str);
+
+    impl fmt::Display for ResolveError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl From<SelectionCoolingDown> for ResolveError {
+        fn from(_: SelectionCoolingDown) -> Self {
+            Self("cooling down")
+        }
+    }
+
+    #[tokio::test]
+    async fn failure_is_recorded_then_retry_resolves() {
+        let selector = GtsPluginSelector::new();
+        assert_eq!(selector.status(), SelectorStatus::Unresolved);
+
+        for expected_attempts in 1..=2 {
+            let err = selector
+                .get_or_init(|| async { Err::<String, _>(ResolveError("no plugin for vendor")) })
+                .await
+                .unwrap_err();
+            assert_eq!(err.0, "no plugin for vendor");
+            match selector.status() {
+                SelectorStatus::Failed {
+                    error_message,
+                    attempts,
+                    ..
+                } => {
+                    assert_eq!(error_message, "no plugin for vendor");
+                    assert_eq!(attempts, expected_attempts);
+                }
+                other => panic!("expected Failed, got {other:?}"),
+            }
+        }
+
+        let id = selector
+            .get_or_init(|| async {
+                Ok::<_, ResolveError>(
+                    "gts.x.core.modkit.plugin.v1~x.core.test.plugin.v1~a.test._.plugin.v1"
+                        .to_owned(),
+                )
+            })
+            .await
+            .unwrap();
+        match selector.status() {
+            SelectorStatus::Resolved {
+                instance_id,
+                resolution_count,
+                ..
+            } => {
+                assert_eq!(instance_id, id);
+                assert_eq!(resolution_count, 1);
+            }
+            other => panic!("expected Resolved, got {other:?}"),
+        }
+        assert_eq!(selector.status().to_json()["state"], "resolved");
+
+        assert!(selector.reset().await);
+        assert_eq!(selector.status(), SelectorStatus::Unresolved);
+    }
+
+    #[tokio::test]
+    async fn backoff_window_suppresses_resolution() {
+        let selector = GtsPluginSelector::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cool_down = Duration::from_secs(60);
+
+        for _ in 0..3 {
+            let calls = Arc::clone(&calls);
+            let result = selector
+                .get_or_init_with_backoff(cool_down, || async move {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err::<String, _>(ResolveError("types-registry unavailable"))
+                })
+                .await;
+            assert!(result.is_err());
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Once the window has passed the resolver is called again
+        let id = selector
+            .get_or_init_with_backoff(Duration::ZERO, || async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, ResolveError>(
+                    "gts.x.core.modkit.plugin.v1~x.core.test.plugin.v1~a.test._.plugin.v1"
+                        .to_owned(),
+                )
+            })
+            .await
+            .unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert!(matches!(
+            selector.status(),
+            SelectorStatus::Resolved { instance_id, .. } if instance_id == id
+        ));
+    }
 }
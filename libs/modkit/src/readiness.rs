@@ -0,0 +1,377 @@
+//! Module readiness, as reported to health probes.
+//!
+//! Every module starts `Pending`. Once a module has started, the runtime marks it
+//! `Ready`, unless the module reports its own readiness. Lifecycle modules with
+//! `await_ready` report it through
+//! [`ReadySignal`](crate::lifecycle::ReadySignal), and other modules through
+//! [`ModuleCtx::readiness`](crate::context::ModuleCtx::readiness). A module that
+//! runs but cannot serve everything reports `Degraded` with a reason. It may
+//! recover to `Ready` later.
+//!
+//! The process is ready only when every module is `Ready` (see [`ReadinessReport`]).
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+/// Readiness of a single module.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "state", rename_all = "snake_case")]
+pub enum Readiness {
+    /// Starting, or waiting for the modules it depends on.
+    Pending,
+    /// Fully able to serve.
+    Ready,
+    /// Running, but some requests will fail.
+    Degraded { reason: String },
+}
+
+impl Readiness {
+    #[inline]
+    #[must_use]
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Readiness::Ready)
+    }
+}
+
+impl fmt::Display for Readiness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Readiness::Pending => f.write_str("pending"),
+            Readiness::Ready => f.write_str("ready"),
+            Readiness::Degraded { reason } => write!(f, "degraded: {reason}"),
+        }
+    }
+}
+
+/// Aggregate readiness of every module in the process.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadinessReport {
+    /// `true` only when every module is [`Readiness::Ready`].
+    pub ready: bool,
+    pub modules: BTreeMap<String, Readiness>,
+}
+
+impl ReadinessReport {
+    /// `(module, reason)` of every degraded module.
+    #[must_use]
+    pub fn degraded(&self) -> Vec<(&str, &str)> {
+        self.modules
+            .iter()
+            .filter_map(|(name, readiness)| match readiness {
+                Readiness::Degraded { reason } => Some((name.as_str(), reason.as_str())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+/// Returned by [`ReadinessRegistry::wait_ready`] when modules are still not
+/// ready at the timeout.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("not ready after {timeout:?}: {}", describe(.modules))]
+pub struct DependenciesNotReady {
+    pub timeout: Duration,
+    /// The modules that were not ready, with their readiness at the timeout.
+    pub modules: Vec<(String, Readiness)>,
+}
+
+fn describe(modules: &[(String, Readiness)]) -> String {
+    modules
+        .iter()
+        .map(|(name, readiness)| format!("{name} ({readiness})"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Readiness of every module, shared by the runtime and every `ModuleCtx`.
+pub struct ReadinessRegistry {
+    states: watch::Sender<BTreeMap<String, Readiness>>,
+}
+
+impl Default for ReadinessRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReadinessRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        let (states, _) = watch::channel(BTreeMap::new());
+        Self { states }
+    }
+
+    /// Track `module` as [`Readiness::Pending`] unless it is already tracked.
+    pub fn register(&self, module: &str) {
+        self.states.send_if_modified(|states| {
+            if states.contains_key(module) {
+                return false;
+            }
+            states.insert(module.to_owned(), Readiness::Pending);
+            true
+        });
+    }
+
+    /// Record the readiness of `module`.
+    pub fn set(&self, module: &str, readiness: Readiness) {
+        self.states.send_if_modified(|states| {
+            if states.get(module) == Some(&readiness) {
+                return false;
+            }
+            match &readiness {
+                Readiness::Degraded { reason } => {
+                    tracing::warn!(module, reason = %reason, "Module is degraded");
+                }
+                other => tracing::info!(module, readiness = %other, "Module readiness changed"),
+            }
+            states.insert(module.to_owned(), readiness);
+            true
+        });
+    }
+
+    /// Mark `module` ready if nothing else was reported for it.
+    pub(crate) fn mark_ready_if_pending(&self, module: &str) {
+        self.states
+            .send_if_modified(|states| match states.get(module) {
+                None | Some(Readiness::Pending) => {
+                    states.insert(module.to_owned(), Readiness::Ready);
+                    true
+                }
+                Some(_) => false,
+            });
+    }
+
+    /// Whether `module` is tracked. The runtime tracks every module it loads,
+    /// so this is also whether `module` is loaded in this process.
+    #[must_use]
+    pub fn tracks(&self, module: &str) -> bool {
+        self.states.borrow().contains_key(module)
+    }
+
+    /// Current readiness of `module`, if tracked.
+    #[must_use]
+    pub fn get(&self, module: &str) -> Option<Readiness> {
+        self.states.borrow().get(module).cloned()
+    }
+
+    /// Snapshot of every module's readiness.
+    #[must_use]
+    pub fn report(&self) -> ReadinessReport {
+        let modules = self.states.borrow().clone();
+        ReadinessReport {
+            ready: modules.values().all(Readiness::is_ready),
+            modules,
+        }
+    }
+
+    /// Reporting handle for `module`.
+    #[must_use]
+    pub fn module(self: &Arc<Self>, module: &str) -> ModuleReadiness {
+        ModuleReadiness {
+            module: Arc::from(module),
+            registry: Arc::clone(self),
+        }
+    }
+
+    /// Wait until every module in `modules` is ready.
+    ///
+    /// # Errors
+    /// Returns [`DependenciesNotReady`] listing the modules that are still not
+    /// ready after `timeout`.
+    pub async fn wait_ready(
+        &self,
+        modules: &[&str],
+        timeout: Duration,
+    ) -> Result<(), DependenciesNotReady> {
+        let all_ready = |states: &BTreeMap<String, Readiness>| {
+            modules
+                .iter()
+                .all(|m| states.get(*m).is_some_and(Readiness::is_ready))
+        };
+
+        let mut rx = self.states.subscribe();
+        if let Ok(Ok(_)) = tokio::time::timeout(timeout, rx.wait_for(all_ready)).await {
+            return Ok(());
+        }
+
+        let states = self.states.borrow();
+        Err(DependenciesNotReady {
+            timeout,
+            modules: modules
+                .iter()
+                .filter_map(|m| {
+                    let readiness = states.get(*m).cloned().unwrap_or(Readiness::Pending);
+                    (!readiness.is_ready()).then(|| ((*m).to_owned(), readiness))
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Handle a module uses to report its own readiness.
+#[derive(Clone)]
+pub struct ModuleReadiness {
+    module: Arc<str>,
+    registry: Arc<ReadinessRegistry>,
+}
+
+impl ModuleReadiness {
+    #[inline]
+    #[must_use]
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Report the module ready.
+    pub fn ready(&self) {
+        self.registry.set(&self.module, Readiness::Ready);
+    }
+
+    /// Report the module running but degraded; `reason` is shown by health probes.
+    pub fn degraded(&self, reason: impl Into<String>) {
+        self.registry.set(
+            &self.module,
+            Readiness::Degraded {
+                reason: reason.into(),
+            },
+        );
+    }
+
+    /// See [`ReadinessRegistry::tracks`].
+    pub(crate) fn tracks(&self, module: &str) -> bool {
+        self.registry.tracks(module)
+    }
+
+    /// Current readiness of the module.
+    #[must_use]
+    pub fn get(&self) -> Readiness {
+        self.registry
+            .get(&self.module)
+            .unwrap_or(Readiness::Pending)
+    }
+
+    /// Report ready once every module in `deps` is ready.
+    ///
+    /// The module stays pending while waiting and is reported degraded if
+    /// `deps` are still not ready after `timeout`.
+    pub async fn ready_after(&self, deps: &[&str], timeout: Duration) {
+        match self.registry.wait_ready(deps, timeout).await {
+            Ok(()) => self.ready(),
+            Err(e) => self.degraded(format!("dependencies {e}")),
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_is_ready_only_when_every_module_is() {
+        let registry = Arc::new(ReadinessRegistry::new());
+        registry.register("authn-resolver");
+        registry.register("api-gateway");
+        assert!(!registry.report().ready);
+
+        registry.module("api-gateway").ready();
+        registry
+            .module("authn-resolver")
+            .degraded("no plugin selected");
+
+        let report = registry.report();
+        assert!(!report.ready);
+        assert_eq!(
+            report.degraded(),
+            vec![("authn-resolver", "no plugin selected")]
+        );
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "ready": false,
+                "modules": {
+                    "api-gateway": {"state": "ready"},
+                    "authn-resolver": {"state": "degraded", "reason": "no plugin selected"},
+                },
+            })
+        );
+
+        registry.module("authn-resolver").ready();
+        assert!(registry.report().ready);
+    }
+
+    #[test]
+    fn runtime_default_keeps_reported_state() {
+        let registry = Arc::new(ReadinessRegistry::new());
+        registry.register("a");
+        registry.register("b");
+        registry.module("b").degraded("cache unreachable");
+        // Registering again does not reset a reported state.
+        registry.register("b");
+
+        registry.mark_ready_if_pending("a");
+        registry.mark_ready_if_pending("b");
+
+        assert_eq!(registry.get("a"), Some(Readiness::Ready));
+        assert!(matches!(
+            registry.get("b"),
+            Some(Readiness::Degraded { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn dependent_waits_while_dependency_is_degraded() {
+        let registry = Arc::new(ReadinessRegistry::new());
+        registry.register("gateway");
+        registry.module("authn").degraded("no plugin selected");
+
+        let gateway = registry.module("gateway");
+        let waiting = tokio::spawn({
+            let gateway = gateway.clone();
+            async move {
+                gateway
+                    .ready_after(&["authn"], Duration::from_millis(100))
+                    .await;
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        assert_eq!(gateway.get(), Readiness::Pending);
+
+        waiting.await.unwrap();
+        let Readiness::Degraded { reason } = gateway.get() else {
+            panic!("expected degraded, got {:?}", gateway.get());
+        };
+        assert!(
+            reason.contains("authn (degraded: no plugin selected)"),
+            "{reason}"
+        );
+    }
+
+    #[tokio::test]
+    async fn dependent_becomes_ready_when_dependency_recovers() {
+        let registry = Arc::new(ReadinessRegistry::new());
+        registry.module("authn").degraded("no plugin selected");
+
+        let gateway = registry.module("gateway");
+        let waiting = tokio::spawn({
+            let gateway = gateway.clone();
+            async move {
+                gateway
+                    .ready_after(&["authn"], Duration::from_secs(5))
+                    .await;
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        registry.module("authn").ready();
+
+        waiting.await.unwrap();
+        assert_eq!(gateway.get(), Readiness::Ready);
+    }
+}
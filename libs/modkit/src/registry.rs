@@ -213,6 +213,7 @@ impl Default for CapabilitySet {
 pub struct ModuleEntry {
     pub(crate) name: &'static str,
     pub(crate) deps: &'static [&'static str],
+    pub(crate) optional_deps: &'static [&'static str],
     pub(crate) core: Arc<dyn contracts::Module>,
     pub(crate) caps: CapabilitySet,
 }
@@ -230,6 +231,14 @@ impl ModuleEntry {
         self.deps
     }
 
+    /// Returns the names of the dependencies the module can run without.
+    ///
+    /// Those that are loaded are initialized before the module, like [`deps`](Self::deps).
+    #[must_use]
+    pub fn optional_deps(&self) -> &'static [&'static str] {
+        self.optional_deps
+    }
+
     /// Returns the capability set.
     #[must_use]
     pub fn caps(&self) -> &CapabilitySet {
@@ -242,6 +251,7 @@ impl std::fmt::Debug for ModuleEntry {
         f.debug_struct("ModuleEntry")
             .field("name", &self.name)
             .field("deps", &self.deps)
+            .field("optional_deps", &self.optional_deps)
             .field("has_rest", &self.caps.has::<RestApiCap>())
             .field("is_rest_host", &self.caps.has::<ApiGatewayCap>())
             .field("has_db", &self.caps.has_db())
@@ -334,6 +344,7 @@ type GrpcHubEntry = (&'static str, Arc<dyn contracts::GrpcHubCapability>);
 pub struct RegistryBuilder {
     core: HashMap<&'static str, Arc<dyn contracts::Module>>,
     deps: HashMap<&'static str, &'static [&'static str]>,
+    optional_deps: HashMap<&'static str, &'static [&'static str]>,
     capabilities: HashMap<&'static str, Vec<Capability>>,
     rest_host: Option<RestHostEntry>,
     grpc_hub: Option<GrpcHubEntry>,
@@ -363,6 +374,16 @@ impl RegistryBuilder {
         self.deps.insert(name, deps);
     }
 
+    /// Dependencies of `name` that may be absent: the registry builds without
+    /// them, and orders them before `name` only when they are registered.
+    pub fn register_optional_deps_with_meta(
+        &mut self,
+        name: &'static str,
+        optional_deps: &'static [&'static str],
+    ) {
+        self.optional_deps.insert(name, optional_deps);
+    }
+
     pub fn register_rest_with_meta(
         &mut self,
         name: &'static str,
@@ -449,6 +470,9 @@ impl RegistryBuilder {
 
     /// Detect cycles in the dependency graph using DFS with path tracking.
     /// Returns the cycle path if found, None otherwise.
+    ///
+    /// `adj` points from a dependency to its dependents; the returned path is
+    /// reversed so that each step reads "depends on" (`a -> b -> a`).
     fn detect_cycle_with_path(
         names: &[&'static str],
         adj: &[Vec<usize>],
@@ -481,6 +505,7 @@ impl RegistryBuilder {
                                 cycle_indices.iter().map(|&i| names[i]).collect();
                             // Close the cycle by adding the first node again
                             cycle_path.push(names[neighbor]);
+                            cycle_path.reverse();
                             return Some(cycle_path);
                         }
                     }
@@ -549,7 +574,10 @@ impl RegistryBuilder {
 
     /// Build dependency graph and return module names, adjacency list, and index mapping.
     fn build_dependency_graph(&self) -> Result<DependencyGraph, RegistryError> {
-        let names: Vec<&'static str> = self.core.keys().copied().collect();
+        // Sorted so the topo order of independent modules and reported cycle
+        // paths do not depend on hash order.
+        let mut names: Vec<&'static str> = self.core.keys().copied().collect();
+        names.sort_unstable();
         let mut idx: HashMap<&'static str, usize> = HashMap::new();
         for (i, &n) in names.iter().enumerate() {
             idx.insert(n, i);
@@ -565,12 +593,30 @@ impl RegistryBuilder {
                 let v = *idx.get(d).ok_or_else(|| RegistryError::UnknownDependency {
                     module: n.to_owned(),
                     depends_on: d.to_owned(),
+                    suggestion: closest_name(d, &names).map(str::to_owned),
                 })?;
                 // edge d -> n (dep before module)
                 adj[v].push(u);
             }
         }
 
+        for (&n, &optional_deps) in &self.optional_deps {
+            let u = *idx
+                .get(n)
+                .ok_or_else(|| RegistryError::UnknownModule(n.to_owned()))?;
+            for &d in optional_deps {
+                if let Some(&v) = idx.get(d) {
+                    adj[v].push(u);
+                } else {
+                    tracing::info!(
+                        module = n,
+                        dependency = d,
+                        "Optional dependency is not loaded"
+                    );
+                }
+            }
+        }
+
         Ok((names, adj, idx))
     }
 
@@ -588,6 +634,8 @@ impl RegistryBuilder {
                 .get(name)
                 .ok_or_else(|| RegistryError::MissingDeps(name.to_owned()))?;
 
+            let optional_deps = self.optional_deps.get(name).copied().unwrap_or_default();
+
             let core = self
                 .core
                 .get(name)
@@ -621,6 +669,7 @@ impl RegistryBuilder {
             let entry = ModuleEntry {
                 name,
                 deps,
+                optional_deps,
                 core,
                 caps,
             };
@@ -794,8 +843,16 @@ pub enum RegistryError {
     // Build/topo-sort errors
     #[error("unknown module '{0}'")]
     UnknownModule(String),
-    #[error("module '{module}' depends on unknown '{depends_on}'")]
-    UnknownDependency { module: String, depends_on: String },
+    #[error(
+        "module '{module}' depends on unknown '{depends_on}'{}",
+        suggestion.as_ref().map(|s| format!(" (did you mean '{s}'?)")).unwrap_or_default()
+    )]
+    UnknownDependency {
+        module: String,
+        depends_on: String,
+        /// Closest registered module name, if one is a likely typo fix.
+        suggestion: Option<String>,
+    },
     #[error("cyclic dependency detected: {}", path.join(" -> "))]
     CycleDetected { path: Vec<&'static str> },
     #[error("missing deps for '{0}'")]
@@ -806,6 +863,34 @@ pub enum RegistryError {
     InvalidRegistryConfiguration { errors: Vec<String> },
 }
 
+/// The registered name closest to `unknown`, if it is within a typo's reach:
+/// an edit distance of at most a third of the name's length (minimum 1).
+fn closest_name<'a>(unknown: &str, names: &[&'a str]) -> Option<&'a str> {
+    let max_distance = (unknown.chars().count() / 3).max(1);
+    names
+        .iter()
+        .map(|&name| (edit_distance(unknown, name), name))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min()
+        .map(|(_, name)| name)
+}
+
+/// Levenshtein distance, treating `-` and `_` as equal.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let norm = |c: char| if c == '-' { '_' } else { c };
+    let b: Vec<char> = b.chars().map(norm).collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().map(norm).enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -847,7 +932,9 @@ mod tests {
 
         let err = b.build_topo_sorted().unwrap_err();
         match err {
-            RegistryError::UnknownDependency { module, depends_on } => {
+            RegistryError::UnknownDependency {
+                module, depends_on, ..
+            } => {
                 assert_eq!(module, "core_a");
                 assert_eq!(depends_on, "missing_dep");
             }
@@ -855,6 +942,76 @@ mod tests {
         }
     }
 
+    #[test]
+    fn misspelled_dependency_suggests_registered_name() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("authn-resolver", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("api-gateway", &["authn_resolvr"], Arc::new(DummyCore));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "module 'api-gateway' depends on unknown 'authn_resolvr' (did you mean 'authn-resolver'?)"
+        );
+
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("users", &["billing"], Arc::new(DummyCore));
+        let err = b.build_topo_sorted().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "module 'users' depends on unknown 'billing'"
+        );
+    }
+
+    #[test]
+    fn loaded_optional_dependency_is_ordered_first() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("api-gateway", &[], Arc::new(DummyCore));
+        b.register_optional_deps_with_meta("api-gateway", &["authn-resolver"]);
+        b.register_core_with_meta("authn-resolver", &[], Arc::new(DummyCore));
+
+        let reg = b.build_topo_sorted().unwrap();
+        let order: Vec<_> = reg.modules().iter().map(|m| m.name).collect();
+        assert_eq!(order, vec!["authn-resolver", "api-gateway"]);
+        assert_eq!(reg.modules()[1].optional_deps(), &["authn-resolver"]);
+    }
+
+    #[test]
+    fn absent_optional_dependency_is_skipped_but_required_one_is_not() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("grpc-hub", &[], Arc::new(DummyCore));
+        b.register_core_with_meta("api-gateway", &["grpc-hub"], Arc::new(DummyCore));
+        b.register_optional_deps_with_meta("api-gateway", &["authn-resolver"]);
+
+        let reg = b.build_topo_sorted().unwrap();
+        let order: Vec<_> = reg.modules().iter().map(|m| m.name).collect();
+        assert_eq!(order, vec!["grpc-hub", "api-gateway"]);
+
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("api-gateway", &["grpc-hub"], Arc::new(DummyCore));
+        b.register_optional_deps_with_meta("api-gateway", &["authn-resolver"]);
+        let err = b.build_topo_sorted().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "module 'api-gateway' depends on unknown 'grpc-hub'"
+        );
+    }
+
+    #[test]
+    fn cycle_error_reads_in_dependency_order() {
+        let mut b = RegistryBuilder::default();
+        b.register_core_with_meta("a", &["b"], Arc::new(DummyCore));
+        b.register_core_with_meta("b", &["c"], Arc::new(DummyCore));
+        b.register_core_with_meta("c", &["a"], Arc::new(DummyCore));
+        b.register_core_with_meta("d", &["a"], Arc::new(DummyCore));
+
+        let err = b.build_topo_sorted().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "cyclic dependency detected: a -> b -> c -> a"
+        );
+    }
+
     #[test]
     fn cyclic_dependency_detected() {
         let mut b = RegistryBuilder::default();
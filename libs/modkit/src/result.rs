@@ -3,6 +3,8 @@
 //! This module provides type aliases and conversions to make error handling
 //! in HTTP handlers more concise and uniform.
 
+use axum::response::{IntoResponse, Response};
+
 use crate::api::problem::Problem;
 
 /// Standard result type for API operations
@@ -22,10 +24,74 @@ use crate::api::problem::Problem;
 /// to an HTTP response when returned from a handler.
 pub type ApiResult<T = ()> = Result<T, Problem>;
 
+/// Conversion of an error into an RFC 9457 [`Problem`].
+///
+/// Implement this once for a module's domain error and return
+/// [`ProblemResponse`] from handlers instead of writing `From<DomainError> for Problem`
+/// and a manual `IntoResponse`:
+///
+/// ```ignore
+/// impl IntoProblem for DomainError {
+///     fn into_problem(self) -> Problem {
+///         domain_error_to_problem(&self, "/")
+///     }
+/// }
+///
+/// pub type ApiResult<T = ()> = Result<T, ProblemResponse<DomainError>>;
+///
+/// async fn handler() -> ApiResult<Json<User>> {
+///     let user = svc.get_user(id).await?;  // DomainError -> ProblemResponse
+///     Ok(Json(user))
+/// }
+/// ```
+pub trait IntoProblem {
+    fn into_problem(self) -> Problem;
+}
+
+impl IntoProblem for Problem {
+    fn into_problem(self) -> Problem {
+        self
+    }
+}
+
+/// Axum response wrapper for any [`IntoProblem`] error.
+///
+/// The error is converted to a [`Problem`] only when the response is rendered,
+/// so handlers and tests can still inspect the typed error.
+#[derive(Debug)]
+pub struct ProblemResponse<E: IntoProblem>(pub E);
+
+impl<E: IntoProblem> ProblemResponse<E> {
+    /// Unwrap the typed error.
+    #[must_use]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+
+    /// Convert to a [`Problem`] without rendering it.
+    #[must_use]
+    pub fn into_problem(self) -> Problem {
+        self.0.into_problem()
+    }
+}
+
+impl<E: IntoProblem> From<E> for ProblemResponse<E> {
+    fn from(e: E) -> Self {
+        Self(e)
+    }
+}
+
+impl<E: IntoProblem> IntoResponse for ProblemResponse<E> {
+    fn into_response(self) -> Response {
+        self.0.into_problem().into_response()
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
     use super::*;
+    use http::StatusCode;
 
     #[test]
     fn test_api_result_ok() {
@@ -35,8 +101,6 @@ mod tests {
 
     #[test]
     fn test_api_result_err() {
-        use http::StatusCode;
-
         let result: ApiResult<i32> = Err(Problem::new(
             StatusCode::BAD_REQUEST,
             "Bad Request",
@@ -44,4 +108,46 @@ mod tests {
         ));
         assert!(result.is_err());
     }
+
+    #[derive(Debug)]
+    enum TestError {
+        Missing,
+    }
+
+    impl IntoProblem for TestError {
+        fn into_problem(self) -> Problem {
+            match self {
+                Self::Missing => Problem::new(StatusCode::NOT_FOUND, "Not Found", "missing"),
+            }
+        }
+    }
+
+    fn lookup() -> Result<(), TestError> {
+        Err(TestError::Missing)
+    }
+
+    fn handler() -> Result<(), ProblemResponse<TestError>> {
+        lookup()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_question_mark_wraps_domain_error() {
+        let err = handler().unwrap_err();
+        assert!(matches!(err.0, TestError::Missing));
+    }
+
+    #[test]
+    fn test_problem_response_renders_problem() {
+        let response = ProblemResponse(TestError::Missing).into_response();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(
+            response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|v| v.to_str().ok()),
+            Some(crate::api::problem::APPLICATION_PROBLEM_JSON)
+        );
+    }
 }
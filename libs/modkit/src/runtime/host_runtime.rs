@@ -10,20 +10,33 @@
 //! - `post_init` (system modules only; runs after *all* `init` complete)
 //! - REST wiring (modules with REST capability; requires a single REST host)
 //! - gRPC registration (modules with gRPC capability; requires a single gRPC hub)
-//! - start/stop (stateful modules)
+//! - start (stateful modules); modules are reported ready as they start unless
+//!   they report readiness themselves (see [`crate::readiness`])
+//! - shutdown hooks (all modules, reverse order) and stop (stateful modules),
+//!   bounded together by the stop timeout
 //! - `OoP` spawn / wait / stop (host-only orchestration)
+//! - admin channel (optional, after start; see [`crate::admin`])
+//!
+//! [`HostRuntime::emit_openapi`] runs only `pre_init`, `init` and REST
+//! registration to build the `OpenAPI` document without serving it.
 
 use axum::Router;
 use std::collections::HashSet;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 use tokio_util::sync::CancellationToken;
+use utoipa::openapi::OpenApi;
 use uuid::Uuid;
 
+use crate::admin::{AdminCommandRegistry, ModuleState, RouteRecorder, RuntimeIntrospection};
+use crate::api::{OpenApiInfo, OpenApiRegistryImpl};
 use crate::backends::OopSpawnConfig;
 use crate::client_hub::ClientHub;
 use crate::config::ConfigProvider;
 use crate::context::ModuleContextBuilder;
+use crate::readiness::ReadinessRegistry;
 use crate::registry::{
     ApiGatewayCap, GrpcHubCap, ModuleEntry, ModuleRegistry, RegistryError, RestApiCap, RunnableCap,
     SystemCap,
@@ -52,6 +65,9 @@ pub enum RunMode {
     MigrateOnly,
 }
 
+/// Default bound on the shutdown hooks and stop phase combined.
+pub const DEFAULT_STOP_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// Environment variable name for passing directory endpoint to `OoP` modules.
 pub const MODKIT_DIRECTORY_ENDPOINT_ENV: &str = "MODKIT_DIRECTORY_ENDPOINT";
 
@@ -74,6 +90,16 @@ pub struct HostRuntime {
     db_options: DbOptions,
     /// `OoP` module spawn configuration and backend
     oop_options: Option<OopSpawnOptions>,
+    /// Commands exposed on the admin channel (shared with every `ModuleCtx`)
+    admin_commands: Arc<AdminCommandRegistry>,
+    /// Module/route/state snapshot backing the built-in admin commands
+    introspection: Arc<RuntimeIntrospection>,
+    /// Readiness of every module (shared with every `ModuleCtx`)
+    readiness: Arc<ReadinessRegistry>,
+    /// Unix socket for the admin channel; `None` disables it
+    admin_socket: Option<PathBuf>,
+    /// Bound on the shutdown hooks and stop phase combined
+    stop_timeout: Duration,
 }
 
 impl HostRuntime {
@@ -100,13 +126,24 @@ impl HostRuntime {
             DbOptions::None => None,
         };
 
+        let admin_commands = Arc::new(AdminCommandRegistry::new());
+        let introspection = Arc::new(RuntimeIntrospection::from_registry(&registry));
+        introspection.register_builtins(&admin_commands);
+
+        let readiness = Arc::new(ReadinessRegistry::new());
+        for e in registry.modules() {
+            readiness.register(e.name);
+        }
+
         let ctx_builder = ModuleContextBuilder::new(
             instance_id,
             modules_cfg,
             client_hub.clone(),
             cancel.clone(),
             db_manager,
-        );
+        )
+        .with_admin_commands(Arc::clone(&admin_commands))
+        .with_readiness(Arc::clone(&readiness));
 
         Self {
             registry,
@@ -118,9 +155,51 @@ impl HostRuntime {
             cancel,
             db_options,
             oop_options,
+            admin_commands,
+            introspection,
+            readiness,
+            admin_socket: None,
+            stop_timeout: DEFAULT_STOP_TIMEOUT,
         }
     }
 
+    /// Serve the admin channel on `socket` once modules have started.
+    #[must_use]
+    pub fn with_admin_socket(mut self, socket: Option<PathBuf>) -> Self {
+        self.admin_socket = socket;
+        self
+    }
+
+    /// Let modules follow changes of the config file at `path`, polled every
+    /// `interval`, through [`ModuleCtx::config_handle`](crate::context::ModuleCtx::config_handle).
+    #[must_use]
+    pub fn with_config_file(mut self, path: PathBuf, interval: Duration) -> Self {
+        self.ctx_builder = self.ctx_builder.with_config_file(path, interval);
+        self
+    }
+
+    /// Bound the shutdown hooks and stop phase to `timeout` in total.
+    ///
+    /// Defaults to [`DEFAULT_STOP_TIMEOUT`]. When it elapses, the remaining
+    /// hooks and stops are abandoned and shutdown proceeds.
+    #[must_use]
+    pub fn with_stop_timeout(mut self, timeout: Duration) -> Self {
+        self.stop_timeout = timeout;
+        self
+    }
+
+    /// Commands exposed on the admin channel.
+    #[must_use]
+    pub fn admin_commands(&self) -> Arc<AdminCommandRegistry> {
+        Arc::clone(&self.admin_commands)
+    }
+
+    /// Readiness of every module.
+    #[must_use]
+    pub fn readiness(&self) -> Arc<ReadinessRegistry> {
+        Arc::clone(&self.readiness)
+    }
+
     /// `PRE_INIT` phase: wire runtime internals into system modules.
     ///
     /// This phase runs before init and only for modules with the "system" capability.
@@ -134,7 +213,8 @@ impl HostRuntime {
             self.instance_id,
             Arc::clone(&self.module_manager),
             Arc::clone(&self.grpc_installers),
-        );
+        )
+        .with_introspection(Arc::clone(&self.introspection));
 
         for entry in self.registry.modules() {
             // Check for cancellation before processing each module
@@ -304,14 +384,20 @@ impl HostRuntime {
                         module: entry.name,
                         source: e,
                     })?;
-            entry
-                .core
-                .init(&ctx)
-                .await
-                .map_err(|e| RegistryError::Init {
+            let started = std::time::Instant::now();
+            let result = entry.core.init(&ctx).await;
+            self.introspection
+                .record_init_duration(entry.name, started.elapsed());
+            result.map_err(|e| {
+                self.introspection
+                    .set_state(entry.name, ModuleState::Failed);
+                RegistryError::Init {
                     module: entry.name,
                     source: e,
-                })?;
+                }
+            })?;
+            self.introspection
+                .set_state(entry.name, ModuleState::Initialized);
         }
 
         Ok(())
@@ -330,7 +416,8 @@ impl HostRuntime {
             self.instance_id,
             Arc::clone(&self.module_manager),
             Arc::clone(&self.grpc_installers),
-        );
+        )
+        .with_introspection(Arc::clone(&self.introspection));
 
         for entry in self.registry.modules_by_system_priority() {
             if let Some(sys_mod) = entry.caps.query::<SystemCap>() {
@@ -423,8 +510,13 @@ impl HostRuntime {
                         source: err,
                     }
                 })?;
+                let recorder = RouteRecorder {
+                    inner: registry,
+                    module: e.name,
+                    introspection: &self.introspection,
+                };
                 router = rest
-                    .register_rest(&ctx, router, registry)
+                    .register_rest(&ctx, router, &recorder)
                     .map_err(|source| RegistryError::RestRegister {
                         module: e.name,
                         source,
@@ -443,6 +535,58 @@ impl HostRuntime {
         Ok(router)
     }
 
+    /// REST phase of a dry run: collect every module's operations into `openapi`.
+    ///
+    /// Only `register_rest` runs; the host's prepare/finalize steps are skipped
+    /// and the composed router is discarded. Returns the host's document metadata.
+    async fn run_dry_rest_phase(
+        &self,
+        openapi: &OpenApiRegistryImpl,
+    ) -> Result<OpenApiInfo, RegistryError> {
+        tracing::info!("Phase: rest (dry run)");
+
+        let mut hosts = self
+            .registry
+            .modules()
+            .iter()
+            .filter_map(|e| e.caps.query::<ApiGatewayCap>());
+        let info = match (hosts.next(), hosts.next()) {
+            (Some(host), None) => host.openapi_info(),
+            (Some(_), Some(_)) => return Err(RegistryError::MultipleRestHosts),
+            (None, _) => {
+                if self
+                    .registry
+                    .modules()
+                    .iter()
+                    .any(|e| e.caps.has::<RestApiCap>())
+                {
+                    return Err(RegistryError::RestRequiresHost);
+                }
+                OpenApiInfo::default()
+            }
+        };
+
+        let mut router = Router::new();
+        for e in self.registry.modules() {
+            if let Some(rest) = e.caps.query::<RestApiCap>() {
+                let ctx = self.ctx_builder.for_module(e.name).await.map_err(|err| {
+                    RegistryError::RestRegister {
+                        module: e.name,
+                        source: err,
+                    }
+                })?;
+                router = rest
+                    .register_rest(&ctx, router, openapi)
+                    .map_err(|source| RegistryError::RestRegister {
+                        module: e.name,
+                        source,
+                    })?;
+            }
+        }
+
+        Ok(info)
+    }
+
     /// gRPC registration phase: collect services from all grpc modules.
     ///
     /// Services are stored in the installer store for the `grpc-hub` to consume during start.
@@ -517,44 +661,89 @@ impl HostRuntime {
 
     /// START phase: start all stateful modules.
     ///
-    /// System modules start first, followed by user modules.
+    /// System modules start first, followed by user modules. Each module is
+    /// marked ready once started, unless it reports its own readiness.
     async fn run_start_phase(&self) -> Result<(), RegistryError> {
         tracing::info!("Phase: start");
 
         for e in self.registry.modules_by_system_priority() {
+            let mut reports_readiness = false;
             if let Some(s) = e.caps.query::<RunnableCap>() {
                 tracing::debug!(
                     module = e.name,
                     is_system = e.caps.has::<SystemCap>(),
                     "Starting stateful module"
                 );
-                s.start(self.cancel.clone())
-                    .await
-                    .map_err(|source| RegistryError::Start {
+                reports_readiness = s.bind_readiness(self.readiness.module(e.name));
+                s.start(self.cancel.clone()).await.map_err(|source| {
+                    self.introspection.set_state(e.name, ModuleState::Failed);
+                    RegistryError::Start {
                         module: e.name,
                         source,
-                    })?;
+                    }
+                })?;
+                self.introspection.set_state(e.name, ModuleState::Started);
                 tracing::info!(module = e.name, "Started module");
             }
+            if !reports_readiness {
+                self.readiness.mark_ready_if_pending(e.name);
+            }
         }
 
         Ok(())
     }
 
     /// Stop a single module, logging errors but continuing execution.
-    async fn stop_one_module(entry: &ModuleEntry, cancel: CancellationToken) {
+    async fn stop_one_module(
+        entry: &ModuleEntry,
+        cancel: CancellationToken,
+        introspection: &RuntimeIntrospection,
+    ) {
         if let Some(s) = entry.caps.query::<RunnableCap>() {
             match s.stop(cancel).await {
                 Err(err) => {
+                    introspection.set_state(entry.name, ModuleState::Failed);
                     tracing::warn!(module = entry.name, error = %err, "Failed to stop module");
                 }
                 _ => {
+                    introspection.set_state(entry.name, ModuleState::Stopped);
                     tracing::info!(module = entry.name, "Stopped module");
                 }
             }
         }
     }
 
+    /// SHUTDOWN phase: call every module's `on_shutdown()` in reverse startup order.
+    ///
+    /// Errors are logged but do not fail the shutdown process.
+    async fn run_shutdown_phase(&self) {
+        tracing::info!("Phase: shutdown");
+
+        for e in self.registry.modules_by_system_priority().into_iter().rev() {
+            if let Err(err) = e.core.on_shutdown().await {
+                tracing::warn!(module = e.name, error = %err, "Shutdown hook failed");
+            }
+        }
+    }
+
+    /// Run the shutdown hooks, then the stop phase, within the stop timeout.
+    async fn run_shutdown_and_stop_phases(&self) -> Result<(), RegistryError> {
+        let phases = async {
+            self.run_shutdown_phase().await;
+            self.run_stop_phase().await
+        };
+        match tokio::time::timeout(self.stop_timeout, phases).await {
+            Ok(result) => result,
+            Err(_elapsed) => {
+                tracing::warn!(
+                    timeout = ?self.stop_timeout,
+                    "Shutdown did not complete within the stop timeout"
+                );
+                Ok(())
+            }
+        }
+    }
+
     /// STOP phase: stop all stateful modules in reverse order.
     ///
     /// Errors are logged but do not fail the shutdown process.
@@ -564,7 +753,7 @@ impl HostRuntime {
         tracing::info!("Phase: stop");
 
         for e in self.registry.modules().iter().rev() {
-            Self::stop_one_module(e, self.cancel.clone()).await;
+            Self::stop_one_module(e, self.cancel.clone(), &self.introspection).await;
         }
 
         Ok(())
@@ -627,6 +816,38 @@ impl HostRuntime {
         Ok(())
     }
 
+    /// Serve the admin channel in the background if a socket is configured.
+    ///
+    /// Bind failures are logged and do not stop the process; the channel is an
+    /// operator convenience, not part of the serving path.
+    fn spawn_admin_channel(&self) {
+        let Some(socket) = self.admin_socket.clone() else {
+            return;
+        };
+
+        #[cfg(unix)]
+        {
+            let registry = Arc::clone(&self.admin_commands);
+            let cancel = self.cancel.clone();
+            tokio::spawn(async move {
+                if let Err(e) = crate::admin::serve(&socket, registry, cancel).await {
+                    tracing::error!(
+                        socket = %socket.display(),
+                        error = %e,
+                        "Admin channel failed to start"
+                    );
+                }
+            });
+        }
+        #[cfg(not(unix))]
+        {
+            tracing::warn!(
+                socket = %socket.display(),
+                "Admin channel requires unix domain sockets; not started"
+            );
+        }
+    }
+
     /// Wait for `grpc-hub` to publish its bound endpoint.
     ///
     /// Polls the `GrpcHubModule::bound_endpoint()` with a short interval until available or timeout.
@@ -692,6 +913,33 @@ impl HostRuntime {
         self.run_phases_internal(RunMode::MigrateOnly).await
     }
 
+    /// Build the `OpenAPI` document without starting anything (dry run).
+    ///
+    /// Runs `pre_init` and `init` with [`ModuleCtx::is_dry_run`] set and no
+    /// database, then the REST registration of every module against a
+    /// standalone registry. DB migrations, `post_init` (plugin discovery),
+    /// gRPC, start and `OoP` spawning are skipped; nothing binds a port.
+    ///
+    /// Route conflicts are not reported here; the host does that on a normal boot.
+    ///
+    /// [`ModuleCtx::is_dry_run`]: crate::context::ModuleCtx::is_dry_run
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a module fails `pre_init`, `init` or route
+    /// registration, or if the document cannot be built.
+    pub async fn emit_openapi(mut self) -> anyhow::Result<OpenApi> {
+        tracing::info!("Running in OpenAPI emission mode (dry run)");
+        self.ctx_builder = self.ctx_builder.with_dry_run(true);
+
+        self.run_pre_init_phase()?;
+        self.run_init_phase().await?;
+
+        let openapi = OpenApiRegistryImpl::new();
+        let info = self.run_dry_rest_phase(&openapi).await?;
+        openapi.build_openapi(&info)
+    }
+
     /// Internal implementation that runs module phases based on the mode.
     ///
     /// This private method contains the actual phase execution logic and is called
@@ -711,9 +959,12 @@ impl HostRuntime {
     /// 5. REST (modules with REST capability)
     /// 6. gRPC (modules with gRPC capability)
     /// 7. Start (runnable modules)
-    /// 8. `OoP` spawn (out-of-process modules)
+    /// 8. `OoP` spawn (out-of-process modules), then the admin channel if configured
     /// 9. Wait for cancellation
-    /// 10. Stop (runnable modules in reverse order)
+    /// 10. Shutdown hooks (all modules in reverse order)
+    /// 11. Stop (runnable modules in reverse order)
+    ///
+    /// Steps 10 and 11 share the stop timeout.
     async fn run_phases_internal(self, mode: RunMode) -> anyhow::Result<()> {
         // Log execution mode
         match mode {
@@ -762,11 +1013,14 @@ impl HostRuntime {
         // 8. OoP spawn phase (after grpc_hub is running)
         self.run_oop_spawn_phase().await?;
 
+        // 8b. Admin channel (optional)
+        self.spawn_admin_channel();
+
         // 9. Wait for cancellation
         self.cancel.cancelled().await;
 
-        // 10. Stop phase
-        self.run_stop_phase().await?;
+        // 10-11. Shutdown hooks, then stop phase
+        self.run_shutdown_and_stop_phases().await?;
 
         Ok(())
     }
@@ -952,6 +1206,271 @@ mod tests {
         assert_eq!(stopped.load(Ordering::SeqCst), 3);
     }
 
+    struct ShutdownTracker {
+        name: &'static str,
+        events: Arc<Mutex<Vec<String>>>,
+        hook: ShutdownBehavior,
+    }
+
+    #[derive(Clone, Copy)]
+    enum ShutdownBehavior {
+        Succeed,
+        Fail,
+        Hang,
+    }
+
+    #[async_trait::async_trait]
+    impl Module for ShutdownTracker {
+        async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+            Ok(())
+        }
+
+        async fn on_shutdown(&self) -> anyhow::Result<()> {
+            self.events
+                .lock()
+                .await
+                .push(format!("shutdown:{}", self.name));
+            match self.hook {
+                ShutdownBehavior::Succeed => Ok(()),
+                ShutdownBehavior::Fail => anyhow::bail!("Intentional failure"),
+                ShutdownBehavior::Hang => {
+                    std::future::pending::<()>().await;
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RunnableCapability for ShutdownTracker {
+        async fn start(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+            Ok(())
+        }
+        async fn stop(&self, _cancel: CancellationToken) -> anyhow::Result<()> {
+            self.events.lock().await.push(format!("stop:{}", self.name));
+            Ok(())
+        }
+    }
+
+    fn shutdown_runtime(
+        hooks: &[(&'static str, ShutdownBehavior)],
+        events: &Arc<Mutex<Vec<String>>>,
+    ) -> HostRuntime {
+        // Each module depends on the previous one, so startup order is a -> b -> c.
+        const DEPS: [&[&str]; 3] = [&[], &["a"], &["b"]];
+
+        let mut builder = RegistryBuilder::default();
+        for (&(name, hook), deps) in hooks.iter().zip(DEPS) {
+            let module = Arc::new(ShutdownTracker {
+                name,
+                events: events.clone(),
+                hook,
+            });
+            builder.register_core_with_meta(name, deps, module.clone() as Arc<dyn Module>);
+            builder.register_stateful_with_meta(name, module as Arc<dyn RunnableCapability>);
+        }
+        let registry = builder.build_topo_sorted().unwrap();
+
+        HostRuntime::new(
+            registry,
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hooks_run_in_reverse_order_before_stop() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let runtime = shutdown_runtime(
+            &[
+                ("a", ShutdownBehavior::Succeed),
+                ("b", ShutdownBehavior::Succeed),
+                ("c", ShutdownBehavior::Succeed),
+            ],
+            &events,
+        );
+
+        runtime.run_shutdown_and_stop_phases().await.unwrap();
+
+        assert_eq!(
+            *events.lock().await,
+            vec![
+                "shutdown:c",
+                "shutdown:b",
+                "shutdown:a",
+                "stop:c",
+                "stop:b",
+                "stop:a"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_hook_error_does_not_skip_others() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let runtime = shutdown_runtime(
+            &[
+                ("a", ShutdownBehavior::Succeed),
+                ("b", ShutdownBehavior::Fail),
+                ("c", ShutdownBehavior::Succeed),
+            ],
+            &events,
+        );
+
+        runtime.run_shutdown_and_stop_phases().await.unwrap();
+
+        let events = events.lock().await;
+        assert!(events.contains(&"shutdown:a".to_owned()));
+        assert_eq!(events.iter().filter(|e| e.starts_with("stop:")).count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_stop_timeout_bounds_hanging_shutdown_hook() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let runtime = shutdown_runtime(
+            &[
+                ("a", ShutdownBehavior::Succeed),
+                ("b", ShutdownBehavior::Hang),
+            ],
+            &events,
+        )
+        .with_stop_timeout(Duration::from_millis(50));
+
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            runtime.run_shutdown_and_stop_phases(),
+        )
+        .await
+        .expect("stop timeout should bound the shutdown")
+        .unwrap();
+
+        // The hook for `b` never returns, so nothing after it runs.
+        assert_eq!(*events.lock().await, vec!["shutdown:b"]);
+    }
+
+    #[tokio::test]
+    async fn test_start_phase_marks_modules_ready_unless_reported() {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let runtime = shutdown_runtime(
+            &[
+                ("a", ShutdownBehavior::Succeed),
+                ("b", ShutdownBehavior::Succeed),
+            ],
+            &events,
+        );
+        let readiness = runtime.readiness();
+        assert!(!readiness.report().ready);
+
+        // Reported during init, e.g. a plugin could not be selected.
+        readiness.module("b").degraded("no plugin selected");
+        runtime.run_start_phase().await.unwrap();
+
+        let report = readiness.report();
+        assert!(!report.ready);
+        assert_eq!(report.modules["a"], crate::readiness::Readiness::Ready);
+        assert_eq!(report.degraded(), vec![("b", "no plugin selected")]);
+    }
+
+    struct DocsHost;
+    impl crate::contracts::ApiGatewayCapability for DocsHost {
+        fn rest_prepare(&self, _ctx: &ModuleCtx, _router: Router) -> anyhow::Result<Router> {
+            panic!("a dry run must not prepare the host router")
+        }
+        fn rest_finalize(&self, _ctx: &ModuleCtx, _router: Router) -> anyhow::Result<Router> {
+            panic!("a dry run must not finalize the host router")
+        }
+        fn as_registry(&self) -> &dyn crate::contracts::OpenApiRegistry {
+            panic!("a dry run registers into its own registry")
+        }
+        fn openapi_info(&self) -> OpenApiInfo {
+            OpenApiInfo {
+                title: "Dry Run API".to_owned(),
+                version: "2.0.0".to_owned(),
+                ..OpenApiInfo::default()
+            }
+        }
+    }
+
+    struct PingModule {
+        dry_run_inits: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Module for PingModule {
+        async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
+            if ctx.is_dry_run() {
+                self.dry_run_inits.fetch_add(1, Ordering::SeqCst);
+            }
+            Ok(())
+        }
+    }
+
+    impl crate::contracts::RestApiCapability for PingModule {
+        fn register_rest(
+            &self,
+            _ctx: &ModuleCtx,
+            router: Router,
+            openapi: &dyn crate::contracts::OpenApiRegistry,
+        ) -> anyhow::Result<Router> {
+            Ok(crate::api::OperationBuilder::get("/tests/v1/ping")
+                .operation_id("tests.ping")
+                .public()
+                .handler(|| async { "pong" })
+                .json_response(http::StatusCode::OK, "Pong")
+                .register(router, openapi))
+        }
+    }
+
+    fn dry_run_runtime(module: &Arc<PingModule>) -> HostRuntime {
+        let mut builder = RegistryBuilder::default();
+        builder.register_core_with_meta("host", &[], Arc::new(DummyCore) as Arc<dyn Module>);
+        builder.register_rest_host_with_meta("host", Arc::new(DocsHost));
+        builder.register_core_with_meta("ping", &["host"], module.clone() as Arc<dyn Module>);
+        builder.register_rest_with_meta(
+            "ping",
+            module.clone() as Arc<dyn crate::contracts::RestApiCapability>,
+        );
+
+        HostRuntime::new(
+            builder.build_topo_sorted().unwrap(),
+            Arc::new(EmptyConfigProvider),
+            DbOptions::None,
+            Arc::new(ClientHub::new()),
+            CancellationToken::new(),
+            Uuid::new_v4(),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_emit_openapi_dry_run() {
+        let module = Arc::new(PingModule {
+            dry_run_inits: Arc::new(AtomicUsize::new(0)),
+        });
+
+        let doc = dry_run_runtime(&module).emit_openapi().await.unwrap();
+        assert_eq!(module.dry_run_inits.load(Ordering::SeqCst), 1);
+
+        let json = serde_json::to_value(&doc).unwrap();
+        assert_eq!(json["info"]["title"], "Dry Run API");
+        assert_eq!(json["info"]["version"], "2.0.0");
+        assert_eq!(
+            json["paths"]["/tests/v1/ping"]["get"]["operationId"],
+            "tests.ping"
+        );
+
+        // A second emission is byte-identical
+        let again = dry_run_runtime(&module).emit_openapi().await.unwrap();
+        assert_eq!(
+            crate::api::openapi_to_sorted_json(&doc).unwrap(),
+            crate::api::openapi_to_sorted_json(&again).unwrap()
+        );
+    }
+
     struct EmptyConfigProvider;
     impl ConfigProvider for EmptyConfigProvider {
         fn get_module_config(&self, _module_name: &str) -> Option<&serde_json::Value> {
@@ -22,6 +22,7 @@ use crate::runtime::shutdown;
 use crate::runtime::{DbOptions, HostRuntime};
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::time::Duration;
 use std::{future::Future, pin::Pin, sync::Arc};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
@@ -119,6 +120,11 @@ pub struct RunOptions {
     /// These modules are spawned after the start phase, once `grpc-hub` is running
     /// and the real directory endpoint is known.
     pub oop: Option<OopSpawnOptions>,
+    /// Unix socket for the operator admin channel; `None` disables it.
+    pub admin_socket: Option<PathBuf>,
+    /// Bound on module shutdown hooks and the stop phase combined
+    /// (see [`DEFAULT_STOP_TIMEOUT`](crate::runtime::DEFAULT_STOP_TIMEOUT)).
+    pub stop_timeout: Duration,
 }
 
 /// Full cycle is orchestrated by `HostRuntime` (see `runtime/host_runtime.rs` docs).
@@ -189,7 +195,9 @@ pub async fn run(opts: RunOptions) -> anyhow::Result<()> {
         cancel.clone(),
         opts.instance_id,
         opts.oop,
-    );
+    )
+    .with_admin_socket(opts.admin_socket)
+    .with_stop_timeout(opts.stop_timeout);
 
     // 6. Run full lifecycle
     host.run_module_phases().await
@@ -3,6 +3,7 @@
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::admin::RuntimeIntrospection;
 use crate::runtime::{GrpcInstallerStore, ModuleManager};
 
 /// System-level context provided to system modules during the wiring phase.
@@ -20,6 +21,9 @@ pub struct SystemContext {
 
     /// gRPC service installer store
     pub grpc_installers: Arc<GrpcInstallerStore>,
+
+    /// Module graph, lifecycle states and init durations tracked by the runtime
+    pub introspection: Arc<RuntimeIntrospection>,
 }
 
 impl SystemContext {
@@ -33,9 +37,17 @@ impl SystemContext {
             instance_id,
             module_manager,
             grpc_installers,
+            introspection: Arc::new(RuntimeIntrospection::default()),
         }
     }
 
+    /// Use the runtime's introspection instead of an empty one.
+    #[must_use]
+    pub fn with_introspection(mut self, introspection: Arc<RuntimeIntrospection>) -> Self {
+        self.introspection = introspection;
+        self
+    }
+
     /// Returns the process-level instance ID.
     ///
     /// This is a unique identifier for this process instance, shared by all modules
@@ -0,0 +1,794 @@
+//! `OpenAPI` contract tests for a module's REST routes.
+//!
+//! [`ContractHarness`] walks every operation registered in an
+//! [`OpenApiRegistryImpl`], sends a minimal valid request to the in-process
+//! router and checks the response against the registered [`ResponseSpec`]s:
+//!
+//! - the status must be one of the declared statuses (or the fixture's
+//!   expected status),
+//! - the `Content-Type` must match the declared one,
+//! - the body must validate against the declared component schema.
+//!
+//! Requests are synthesized from the spec: path and required query parameters
+//! get placeholder values, JSON bodies come from the request example or a
+//! minimal instance of the schema. Operations that need state (get after
+//! create) use a [`Fixture`] with a setup step.
+//!
+//! ```ignore
+//! let report = ContractHarness::new(&registry, router)
+//!     .fixture(
+//!         "users_info.get_user",
+//!         Fixture::new().expect_status(200).setup(|client| async move {
+//!             let user = client.post_json("/users-info/v1/users", &body).await?;
+//!             Ok(HashMap::from([("id".to_owned(), user["id"].as_str().unwrap().to_owned())]))
+//!         }),
+//!     )
+//!     .run()
+//!     .await;
+//! report.assert_passed();
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use axum::Router;
+use axum::body::{Body, to_bytes};
+use http::{Method, Request, StatusCode, header};
+use serde_json::{Map, Value, json};
+use tower::ServiceExt;
+
+use crate::api::OpenApiRegistryImpl;
+use crate::api::operation_builder::{
+    OperationSpec, ParamLocation, ParamSpec, RequestBodySchema, ResponseSpec,
+};
+
+/// Longest body excerpt printed in failure messages.
+const BODY_EXCERPT_LEN: usize = 500;
+
+/// Recursion limit when synthesizing instances of recursive schemas.
+const MAX_SCHEMA_DEPTH: usize = 8;
+
+type SetupFuture = Pin<Box<dyn Future<Output = anyhow::Result<HashMap<String, String>>> + Send>>;
+type SetupFn = Box<dyn Fn(ContractClient) -> SetupFuture + Send + Sync>;
+
+/// Minimal client over the router under test, handed to fixture setups.
+#[derive(Clone)]
+pub struct ContractClient {
+    router: Router,
+}
+
+impl ContractClient {
+    /// Send a request and return the status, `Content-Type` and raw body.
+    ///
+    /// # Errors
+    /// Returns an error if the request cannot be built or the body cannot be read.
+    pub async fn send(
+        &self,
+        method: Method,
+        uri: &str,
+        body: Option<&Value>,
+    ) -> anyhow::Result<(StatusCode, Option<String>, Vec<u8>)> {
+        let mut request = Request::builder().method(method).uri(uri);
+        let body = match body {
+            Some(value) => {
+                request = request.header(header::CONTENT_TYPE, "application/json");
+                Body::from(serde_json::to_vec(value)?)
+            }
+            None => Body::empty(),
+        };
+
+        let response = self.router.clone().oneshot(request.body(body)?).await?;
+        let status = response.status();
+        let content_type = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(ToOwned::to_owned);
+        let bytes = to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, content_type, bytes.to_vec()))
+    }
+
+    /// POST a JSON body and return the JSON response.
+    ///
+    /// # Errors
+    /// Returns an error unless the response is a 2xx with a JSON body.
+    pub async fn post_json(&self, uri: &str, body: &Value) -> anyhow::Result<Value> {
+        let (status, _, bytes) = self.send(Method::POST, uri, Some(body)).await?;
+        if !status.is_success() {
+            anyhow::bail!("POST {uri} returned {status}: {}", excerpt(&bytes));
+        }
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// Per-operation overrides, keyed by operation id in [`ContractHarness::fixture`].
+#[derive(Default)]
+pub struct Fixture {
+    path_params: HashMap<String, String>,
+    query: Vec<(String, String)>,
+    body: Option<Value>,
+    expect_status: Option<u16>,
+    skip: Option<String>,
+    setup: Option<SetupFn>,
+}
+
+impl Fixture {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Value of a path parameter for this operation.
+    #[must_use]
+    pub fn path_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.path_params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Query parameter added to the request.
+    #[must_use]
+    pub fn query(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((name.into(), value.into()));
+        self
+    }
+
+    /// Request body to send instead of a synthesized one.
+    #[must_use]
+    pub fn body(mut self, body: Value) -> Self {
+        self.body = Some(body);
+        self
+    }
+
+    /// Require this exact status rather than any declared one.
+    #[must_use]
+    pub fn expect_status(mut self, status: u16) -> Self {
+        self.expect_status = Some(status);
+        self
+    }
+
+    /// Do not exercise this operation.
+    #[must_use]
+    pub fn skip(mut self, reason: impl Into<String>) -> Self {
+        self.skip = Some(reason.into());
+        self
+    }
+
+    /// Run `setup` before the request; the returned values are used as path
+    /// parameters (e.g. the id of a resource created by the setup).
+    #[must_use]
+    pub fn setup<F, Fut>(mut self, setup: F) -> Self
+    where
+        F: Fn(ContractClient) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = anyhow::Result<HashMap<String, String>>> + Send + 'static,
+    {
+        self.setup = Some(Box::new(move |client| Box::pin(setup(client))));
+        self
+    }
+}
+
+/// Result of one operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    Skipped(String),
+    Failed(Vec<String>),
+}
+
+/// Outcome of one operation, with what was sent and received.
+#[derive(Debug, Clone)]
+pub struct OperationResult {
+    pub operation_id: String,
+    pub method: Method,
+    /// Path template, e.g. `/users/{id}`.
+    pub path: String,
+    /// Status of the response, if a request was sent.
+    pub status: Option<u16>,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for OperationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} {})", self.operation_id, self.method, self.path)?;
+        match &self.outcome {
+            Outcome::Passed => write!(f, ": ok"),
+            Outcome::Skipped(reason) => write!(f, ": skipped ({reason})"),
+            Outcome::Failed(problems) => {
+                for problem in problems {
+                    write!(f, "\n    - {}", problem.replace('\n', "\n      "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Results of a [`ContractHarness::run`].
+#[derive(Debug, Clone)]
+pub struct ContractReport {
+    pub results: Vec<OperationResult>,
+}
+
+impl ContractReport {
+    /// Operations that violated their contract.
+    pub fn failures(&self) -> impl Iterator<Item = &OperationResult> {
+        self.results
+            .iter()
+            .filter(|r| matches!(r.outcome, Outcome::Failed(_)))
+    }
+
+    /// Look up the result of an operation.
+    #[must_use]
+    pub fn get(&self, operation_id: &str) -> Option<&OperationResult> {
+        self.results.iter().find(|r| r.operation_id == operation_id)
+    }
+
+    /// Panic with one readable entry per failing operation.
+    ///
+    /// # Panics
+    /// Panics if any operation failed.
+    pub fn assert_passed(&self) {
+        let failures: Vec<String> = self.failures().map(ToString::to_string).collect();
+        assert!(
+            failures.is_empty(),
+            "contract violations in {} of {} operations:\n  {}",
+            failures.len(),
+            self.results.len(),
+            failures.join("\n  ")
+        );
+    }
+}
+
+/// Contract test runner, see the [module docs](self).
+pub struct ContractHarness<'a> {
+    registry: &'a OpenApiRegistryImpl,
+    client: ContractClient,
+    path_params: HashMap<String, String>,
+    fixtures: HashMap<String, Fixture>,
+    strict_objects: bool,
+}
+
+impl<'a> ContractHarness<'a> {
+    /// Harness over the operations of `registry`, served by `router`.
+    ///
+    /// `router` is called as is, so it must carry whatever the handlers expect
+    /// from the gateway (e.g. a `SecurityContext` extension).
+    #[must_use]
+    pub fn new(registry: &'a OpenApiRegistryImpl, router: Router) -> Self {
+        Self {
+            registry,
+            client: ContractClient { router },
+            path_params: HashMap::new(),
+            fixtures: HashMap::new(),
+            strict_objects: true,
+        }
+    }
+
+    /// Default value of a path parameter for every operation.
+    #[must_use]
+    pub fn path_param(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.path_params.insert(name.into(), value.into());
+        self
+    }
+
+    /// Overrides for the operation with id `operation_id`.
+    #[must_use]
+    pub fn fixture(mut self, operation_id: impl Into<String>, fixture: Fixture) -> Self {
+        self.fixtures.insert(operation_id.into(), fixture);
+        self
+    }
+
+    /// Accept response properties the schema does not declare.
+    ///
+    /// By default object schemas are validated as if they had
+    /// `additionalProperties: false`, so a DTO field missing from the
+    /// registered schema is reported.
+    #[must_use]
+    pub fn allow_additional_properties(mut self) -> Self {
+        self.strict_objects = false;
+        self
+    }
+
+    /// Exercise every enabled operation, ordered by path and method.
+    pub async fn run(&self) -> ContractReport {
+        let components = self.components();
+        let mut validators = HashMap::new();
+
+        let mut specs: Vec<OperationSpec> = self
+            .registry
+            .operation_specs
+            .iter()
+            .map(|e| e.value().clone())
+            .filter(OperationSpec::is_feature_enabled)
+            .collect();
+        specs.sort_by(|a, b| (&a.path, a.method.as_str()).cmp(&(&b.path, b.method.as_str())));
+
+        let mut results = Vec::with_capacity(specs.len());
+        for spec in specs {
+            let (status, outcome) = self.check(&spec, &components, &mut validators).await;
+            results.push(OperationResult {
+                operation_id: spec
+                    .operation_id
+                    .clone()
+                    .unwrap_or_else(|| spec.handler_id.clone()),
+                method: spec.method.clone(),
+                path: spec.path.clone(),
+                status,
+                outcome,
+            });
+        }
+        ContractReport { results }
+    }
+
+    async fn check(
+        &self,
+        spec: &OperationSpec,
+        components: &Map<String, Value>,
+        validators: &mut HashMap<String, Result<jsonschema::Validator, String>>,
+    ) -> (Option<u16>, Outcome) {
+        let operation_id = spec.operation_id.as_deref().unwrap_or(&spec.handler_id);
+        let fixture = self.fixtures.get(operation_id);
+
+        if let Some(reason) = fixture.and_then(|f| f.skip.as_ref()) {
+            return (None, Outcome::Skipped(reason.clone()));
+        }
+        if fixture.is_none()
+            && spec
+                .responses
+                .iter()
+                .any(|r| r.content_type == "text/event-stream")
+        {
+            return (None, Outcome::Skipped("streaming response".to_owned()));
+        }
+
+        let mut path_params = self.path_params.clone();
+        if let Some(setup) = fixture.and_then(|f| f.setup.as_ref()) {
+            match setup(self.client.clone()).await {
+                Ok(params) => path_params.extend(params),
+                Err(e) => return (None, Outcome::Failed(vec![format!("setup failed: {e:#}")])),
+            }
+        }
+        if let Some(f) = fixture {
+            path_params.extend(f.path_params.clone());
+        }
+
+        let body = match request_body(spec, fixture, components) {
+            Ok(body) => body,
+            Err(reason) => return (None, Outcome::Skipped(reason)),
+        };
+        let uri = request_uri(spec, &path_params, fixture);
+
+        let (status, content_type, bytes) = match self
+            .client
+            .send(spec.method.clone(), &uri, body.as_ref())
+            .await
+        {
+            Ok(response) => response,
+            Err(e) => {
+                return (
+                    None,
+                    Outcome::Failed(vec![format!("request failed: {e:#}")]),
+                );
+            }
+        };
+
+        let problems = check_response(
+            spec,
+            fixture.and_then(|f| f.expect_status),
+            (status, content_type.as_deref(), &bytes),
+            components,
+            validators,
+        );
+        let outcome = if problems.is_empty() {
+            Outcome::Passed
+        } else {
+            Outcome::Failed(problems)
+        };
+        (Some(status.as_u16()), outcome)
+    }
+
+    /// Registered component schemas as JSON, tightened if `strict_objects`.
+    fn components(&self) -> Map<String, Value> {
+        self.registry
+            .components_registry
+            .load()
+            .iter()
+            .map(|(name, schema)| {
+                let mut schema = serde_json::to_value(schema).unwrap_or(Value::Null);
+                if self.strict_objects {
+                    close_objects(&mut schema, false);
+                }
+                (name.clone(), schema)
+            })
+            .collect()
+    }
+}
+
+fn check_response(
+    spec: &OperationSpec,
+    expect_status: Option<u16>,
+    (status, content_type, bytes): (StatusCode, Option<&str>, &[u8]),
+    components: &Map<String, Value>,
+    validators: &mut HashMap<String, Result<jsonschema::Validator, String>>,
+) -> Vec<String> {
+    let declared: Vec<String> = spec
+        .responses
+        .iter()
+        .map(|r| r.status.to_string())
+        .collect();
+
+    if let Some(expected) = expect_status
+        && status.as_u16() != expected
+    {
+        return vec![format!(
+            "expected status {expected}, got {status}\n{}",
+            excerpt(bytes)
+        )];
+    }
+    let Some(response) = spec.responses.iter().find(|r| r.status == status.as_u16()) else {
+        return vec![format!(
+            "unexpected status {status} (declared: {})\n{}",
+            declared.join(", "),
+            excerpt(bytes)
+        )];
+    };
+    if bytes.is_empty() {
+        return Vec::new();
+    }
+
+    let mut problems = Vec::new();
+    let actual_type = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(str::trim)
+        .unwrap_or_default();
+    if !actual_type.eq_ignore_ascii_case(response.content_type) {
+        problems.push(format!(
+            "{status} content type `{actual_type}` does not match declared `{}`",
+            response.content_type
+        ));
+    }
+
+    if let Some(schema_name) = &response.schema_name {
+        problems.extend(validate_body(
+            response,
+            schema_name,
+            bytes,
+            components,
+            validators,
+        ));
+    }
+    problems
+}
+
+fn validate_body(
+    response: &ResponseSpec,
+    schema_name: &str,
+    bytes: &[u8],
+    components: &Map<String, Value>,
+    validators: &mut HashMap<String, Result<jsonschema::Validator, String>>,
+) -> Vec<String> {
+    let instance: Value = match serde_json::from_slice(bytes) {
+        Ok(v) => v,
+        Err(e) => {
+            return vec![format!(
+                "{} body is not valid JSON: {e}\n{}",
+                response.status,
+                excerpt(bytes)
+            )];
+        }
+    };
+
+    let validator = validators
+        .entry(schema_name.to_owned())
+        .or_insert_with(|| compile_validator(schema_name, components));
+    let validator = match validator {
+        Ok(v) => v,
+        Err(e) => return vec![format!("schema `{schema_name}` does not compile: {e}")],
+    };
+
+    let errors: Vec<String> = validator
+        .iter_errors(&instance)
+        .map(|e| e.to_string())
+        .collect();
+    if errors.is_empty() {
+        return Vec::new();
+    }
+    vec![format!(
+        "{} body does not match schema `{schema_name}`:\n  {}\n{}",
+        response.status,
+        errors.join("\n  "),
+        excerpt(bytes)
+    )]
+}
+
+fn compile_validator(
+    schema_name: &str,
+    components: &Map<String, Value>,
+) -> Result<jsonschema::Validator, String> {
+    let root = json!({
+        "$ref": format!("#/components/schemas/{}", escape_pointer(schema_name)),
+        "components": { "schemas": components },
+    });
+    jsonschema::validator_for(&root).map_err(|e| e.to_string())
+}
+
+fn escape_pointer(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// Set `additionalProperties: false` on object schemas that do not say
+/// otherwise. Members of `allOf` are left open, since each only sees part of
+/// the properties.
+fn close_objects(schema: &mut Value, in_all_of: bool) {
+    match schema {
+        Value::Object(obj) => {
+            if !in_all_of
+                && obj.contains_key("properties")
+                && !obj.contains_key("additionalProperties")
+            {
+                obj.insert("additionalProperties".to_owned(), Value::Bool(false));
+            }
+            for (key, value) in obj.iter_mut() {
+                if key == "allOf" {
+                    if let Value::Array(members) = value {
+                        for member in members {
+                            close_objects(member, true);
+                        }
+                    }
+                } else {
+                    close_objects(value, false);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                close_objects(item, false);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn request_uri(
+    spec: &OperationSpec,
+    path_params: &HashMap<String, String>,
+    fixture: Option<&Fixture>,
+) -> String {
+    let mut path = spec.path.clone();
+    for param in spec
+        .params
+        .iter()
+        .filter(|p| p.location == ParamLocation::Path)
+    {
+        let value = path_params
+            .get(&param.name)
+            .cloned()
+            .unwrap_or_else(|| placeholder(param));
+        let value = urlencoding::encode(&value);
+        path = path
+            .replace(&format!("{{{}}}", param.name), &value)
+            .replace(&format!("{{*{}}}", param.name), &value);
+    }
+
+    let mut query: BTreeMap<String, String> = spec
+        .params
+        .iter()
+        .filter(|p| p.location == ParamLocation::Query && p.required)
+        .map(|p| (p.name.clone(), placeholder(p)))
+        .collect();
+    if let Some(f) = fixture {
+        query.extend(f.query.iter().cloned());
+    }
+    if query.is_empty() {
+        return path;
+    }
+    let query: Vec<String> = query
+        .iter()
+        .map(|(k, v)| format!("{}={}", urlencoding::encode(k), urlencoding::encode(v)))
+        .collect();
+    format!("{path}?{}", query.join("&"))
+}
+
+fn placeholder(param: &ParamSpec) -> String {
+    match param.param_type.as_str() {
+        "integer" | "number" => "1".to_owned(),
+        "boolean" => "false".to_owned(),
+        _ => uuid::Uuid::new_v4().to_string(),
+    }
+}
+
+fn request_body(
+    spec: &OperationSpec,
+    fixture: Option<&Fixture>,
+    components: &Map<String, Value>,
+) -> Result<Option<Value>, String> {
+    if let Some(body) = fixture.and_then(|f| f.body.clone()) {
+        return Ok(Some(body));
+    }
+    let Some(body) = &spec.request_body else {
+        return Ok(None);
+    };
+    if let Some(example) = &body.example {
+        return Ok(Some(example.clone()));
+    }
+    match &body.schema {
+        RequestBodySchema::Ref { schema_name } => {
+            let schema = json!({ "$ref": format!("#/components/schemas/{schema_name}") });
+            Ok(Some(minimal_instance(&schema, components, 0)))
+        }
+        RequestBodySchema::InlineObject => Ok(Some(json!({}))),
+        RequestBodySchema::MultipartFile { .. } | RequestBodySchema::Binary => Err(format!(
+            "`{}` request body needs a fixture",
+            body.content_type
+        )),
+    }
+}
+
+/// Smallest instance satisfying the common JSON-schema keywords of `schema`.
+fn minimal_instance(schema: &Value, components: &Map<String, Value>, depth: usize) -> Value {
+    if depth > MAX_SCHEMA_DEPTH {
+        return Value::Null;
+    }
+    if let Some(target) = schema.get("$ref").and_then(Value::as_str) {
+        let name = target.rsplit('/').next().unwrap_or_default();
+        return components
+            .get(name)
+            .map_or(Value::Null, |s| minimal_instance(s, components, depth + 1));
+    }
+    for key in ["example", "default", "const"] {
+        if let Some(value) = schema.get(key) {
+            return value.clone();
+        }
+    }
+    if let Some(first) = schema
+        .get("enum")
+        .and_then(Value::as_array)
+        .and_then(|v| v.first())
+    {
+        return first.clone();
+    }
+    for key in ["oneOf", "anyOf"] {
+        if let Some(first) = schema
+            .get(key)
+            .and_then(Value::as_array)
+            .and_then(|v| v.iter().find(|s| s.get("type") != Some(&json!("null"))))
+        {
+            return minimal_instance(first, components, depth + 1);
+        }
+    }
+    if let Some(members) = schema.get("allOf").and_then(Value::as_array) {
+        let mut merged = Map::new();
+        for member in members {
+            match minimal_instance(member, components, depth + 1) {
+                Value::Object(obj) => merged.extend(obj),
+                other => return other,
+            }
+        }
+        return Value::Object(merged);
+    }
+
+    let ty = match schema.get("type") {
+        Some(Value::String(ty)) => ty.as_str(),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null")
+            .unwrap_or("null"),
+        _ if schema.get("properties").is_some() => "object",
+        _ => "null",
+    };
+    match ty {
+        "object" => {
+            let mut obj = Map::new();
+            let properties = schema.get("properties").and_then(Value::as_object);
+            let required = schema.get("required").and_then(Value::as_array);
+            for name in required.into_iter().flatten().filter_map(Value::as_str) {
+                let property = properties.and_then(|p| p.get(name)).unwrap_or(&Value::Null);
+                obj.insert(
+                    name.to_owned(),
+                    minimal_instance(property, components, depth + 1),
+                );
+            }
+            Value::Object(obj)
+        }
+        "array" => {
+            let count = schema.get("minItems").and_then(Value::as_u64).unwrap_or(0);
+            let item = schema.get("items").unwrap_or(&Value::Null);
+            Value::Array(
+                (0..count)
+                    .map(|_| minimal_instance(item, components, depth + 1))
+                    .collect(),
+            )
+        }
+        "string" => minimal_string(schema),
+        "integer" => json!(schema.get("minimum").and_then(Value::as_i64).unwrap_or(0)),
+        "number" => json!(schema.get("minimum").and_then(Value::as_f64).unwrap_or(0.0)),
+        "boolean" => Value::Bool(false),
+        _ => Value::Null,
+    }
+}
+
+fn minimal_string(schema: &Value) -> Value {
+    let value = match schema.get("format").and_then(Value::as_str) {
+        Some("uuid") => uuid::Uuid::new_v4().to_string(),
+        Some("date-time") => "2026-01-01T00:00:00Z".to_owned(),
+        Some("date") => "2026-01-01".to_owned(),
+        Some("email") => "contract@example.com".to_owned(),
+        Some("uri" | "url") => "https://example.com/".to_owned(),
+        _ => {
+            let len = schema.get("minLength").and_then(Value::as_u64).unwrap_or(1);
+            "x".repeat(usize::try_from(len.max(1)).unwrap_or(1))
+        }
+    };
+    Value::String(value)
+}
+
+fn excerpt(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "    body: <empty>".to_owned();
+    }
+    let text = String::from_utf8_lossy(bytes);
+    let mut end = text.len().min(BODY_EXCERPT_LEN);
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    let ellipsis = if end < text.len() { "..." } else { "" };
+    format!("    body: {}{ellipsis}", &text[..end])
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimal_instance_fills_required_properties() {
+        let components: Map<String, Value> = serde_json::from_value(json!({
+            "City": {
+                "type": "object",
+                "required": ["id", "name", "tags", "kind"],
+                "properties": {
+                    "id": { "type": "string", "format": "uuid" },
+                    "name": { "type": "string", "minLength": 3 },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "kind": { "$ref": "#/components/schemas/Kind" },
+                    "note": { "type": ["string", "null"] }
+                }
+            },
+            "Kind": { "type": "string", "enum": ["capital", "town"] }
+        }))
+        .unwrap();
+
+        let city = minimal_instance(
+            &json!({ "$ref": "#/components/schemas/City" }),
+            &components,
+            0,
+        );
+        assert!(uuid::Uuid::parse_str(city["id"].as_str().unwrap()).is_ok());
+        assert_eq!(city["name"], "xxx");
+        assert_eq!(city["tags"], json!([]));
+        assert_eq!(city["kind"], "capital");
+        assert!(city.get("note").is_none());
+    }
+
+    #[test]
+    fn strict_objects_reject_undeclared_properties() {
+        let mut components: Map<String, Value> = serde_json::from_value(json!({
+            "Dto": {
+                "type": "object",
+                "required": ["id"],
+                "properties": { "id": { "type": "string" } }
+            }
+        }))
+        .unwrap();
+        for schema in components.values_mut() {
+            close_objects(schema, false);
+        }
+
+        let validator = compile_validator("Dto", &components).unwrap();
+        assert!(validator.is_valid(&json!({ "id": "a" })));
+        assert!(!validator.is_valid(&json!({ "id": "a", "extra": 1 })));
+        assert!(!validator.is_valid(&json!({})));
+    }
+}
@@ -0,0 +1,14 @@
+//! Test utilities for modules built on modkit.
+//!
+//! Enabled by the `test-utils` feature; add it to a module's
+//! `[dev-dependencies]`:
+//!
+//! ```toml
+//! modkit = { workspace = true, features = ["test-utils"] }
+//! ```
+
+pub mod contract;
+
+pub use contract::{
+    ContractClient, ContractHarness, ContractReport, Fixture, OperationResult, Outcome,
+};
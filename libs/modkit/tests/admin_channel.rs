@@ -0,0 +1,224 @@
+#![cfg(unix)]
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! End-to-end tests for the admin channel: a module registers a command in
+//! `init()`, the runtime serves it on a unix socket and the client helper
+//! drives it.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+use modkit::admin::AdminError;
+use modkit::admin::client::{AdminClientError, call};
+use modkit::{
+    ModuleCtx,
+    config::ConfigProvider,
+    contracts::Module,
+    registry::RegistryBuilder,
+    runtime::{DbOptions, HostRuntime},
+};
+
+struct EmptyConfig;
+
+impl ConfigProvider for EmptyConfig {
+    fn get_module_config(&self, _module_name: &str) -> Option<&serde_json::Value> {
+        None
+    }
+}
+
+/// Module that exposes a counter reset command.
+#[derive(Default)]
+struct CounterModule {
+    resets: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl Module for CounterModule {
+    async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
+        let resets = Arc::clone(&self.resets);
+        ctx.register_admin_command("counter.reset", move |args| {
+            let resets = Arc::clone(&resets);
+            async move {
+                let by = args
+                    .get("by")
+                    .and_then(serde_json::Value::as_u64)
+                    .unwrap_or(1);
+                let by =
+                    usize::try_from(by).map_err(|e| AdminError::invalid_args(e.to_string()))?;
+                let total = resets.fetch_add(by, Ordering::SeqCst) + by;
+                Ok(json!({ "resets": total }))
+            }
+        })
+    }
+}
+
+async fn wait_for_socket(path: &Path) {
+    for _ in 0..200 {
+        if path.exists() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!("admin socket {} was not created", path.display());
+}
+
+#[tokio::test]
+async fn module_command_runs_over_socket() {
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("admin.sock");
+
+    let module = Arc::new(CounterModule::default());
+    let resets = Arc::clone(&module.resets);
+    let mut builder = RegistryBuilder::default();
+    builder.register_core_with_meta("counter", &[], module as Arc<dyn Module>);
+    let registry = builder.build_topo_sorted().unwrap();
+
+    let cancel = CancellationToken::new();
+    let runtime = HostRuntime::new(
+        registry,
+        Arc::new(EmptyConfig),
+        DbOptions::None,
+        Arc::new(modkit::client_hub::ClientHub::default()),
+        cancel.clone(),
+        Uuid::new_v4(),
+        None,
+    )
+    .with_admin_socket(Some(socket.clone()));
+    let run = tokio::spawn(runtime.run_module_phases());
+
+    wait_for_socket(&socket).await;
+
+    let out = call(&socket, "counter.reset", json!({ "by": 2 }))
+        .await
+        .unwrap();
+    assert_eq!(out, json!({ "resets": 2 }));
+    assert_eq!(resets.load(Ordering::SeqCst), 2);
+
+    let states = call(&socket, "modules.states", json!({})).await.unwrap();
+    assert_eq!(states, json!({ "counter": "initialized" }));
+
+    let modules = call(&socket, "modules.list", json!({})).await.unwrap();
+    assert_eq!(modules[0]["name"], "counter");
+
+    let routes = call(&socket, "routes.list", json!({})).await.unwrap();
+    assert_eq!(routes, json!([]));
+
+    let err = call(&socket, "counter.nope", json!({})).await.unwrap_err();
+    match err {
+        AdminClientError::Command(e) => {
+            assert_eq!(e.code, AdminError::UNKNOWN_COMMAND);
+            assert!(e.message.contains("counter.nope"), "{}", e.message);
+        }
+        other => panic!("expected command error, got: {other:?}"),
+    }
+
+    cancel.cancel();
+    tokio::time::timeout(Duration::from_secs(5), run)
+        .await
+        .expect("runtime did not stop")
+        .unwrap()
+        .unwrap();
+
+    // The socket is removed on shutdown
+    for _ in 0..100 {
+        if !socket.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert!(!socket.exists());
+}
+
+#[tokio::test]
+async fn malformed_request_gets_parse_error() {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("admin.sock");
+    let registry = Arc::new(modkit::admin::AdminCommandRegistry::new());
+    let cancel = CancellationToken::new();
+
+    let server = {
+        let socket = socket.clone();
+        let cancel = cancel.clone();
+        tokio::spawn(async move { modkit::admin::serve(&socket, registry, cancel).await })
+    };
+    wait_for_socket(&socket).await;
+
+    let stream = tokio::net::UnixStream::connect(&socket).await.unwrap();
+    let (reader, mut writer) = stream.into_split();
+    writer.write_all(b"not json\n").await.unwrap();
+    let mut line = String::new();
+    BufReader::new(reader).read_line(&mut line).await.unwrap();
+
+    let response: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(response["error"]["code"], AdminError::PARSE_ERROR);
+
+    cancel.cancel();
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn socket_is_private_and_staging_is_removed() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("admin.sock");
+    let registry = Arc::new(modkit::admin::AdminCommandRegistry::new());
+    let cancel = CancellationToken::new();
+
+    let server = {
+        let socket = socket.clone();
+        let cancel = cancel.clone();
+        tokio::spawn(async move { modkit::admin::serve(&socket, registry, cancel).await })
+    };
+    wait_for_socket(&socket).await;
+
+    let mode = std::fs::metadata(&socket).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    let entries: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name())
+        .collect();
+    assert_eq!(entries, vec![std::ffi::OsString::from("admin.sock")]);
+
+    cancel.cancel();
+    server.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn oversized_request_line_is_rejected() {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let dir = tempfile::tempdir().unwrap();
+    let socket = dir.path().join("admin.sock");
+    let registry = Arc::new(modkit::admin::AdminCommandRegistry::new());
+    let cancel = CancellationToken::new();
+
+    let server = {
+        let socket = socket.clone();
+        let cancel = cancel.clone();
+        tokio::spawn(async move { modkit::admin::serve(&socket, registry, cancel).await })
+    };
+    wait_for_socket(&socket).await;
+
+    let stream = tokio::net::UnixStream::connect(&socket).await.unwrap();
+    let (mut reader, mut writer) = stream.into_split();
+    let oversized = vec![b'x'; modkit::admin::MAX_REQUEST_LINE + 1];
+    writer.write_all(&oversized).await.unwrap();
+
+    // The server answers once and closes the connection.
+    let mut out = String::new();
+    reader.read_to_string(&mut out).await.unwrap();
+    let response: serde_json::Value = serde_json::from_str(out.trim_end()).unwrap();
+    assert_eq!(response["error"]["code"], AdminError::INVALID_REQUEST);
+
+    cancel.cancel();
+    server.await.unwrap().unwrap();
+}
@@ -0,0 +1,134 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! `catch_panic_middleware`: a panicking handler yields a 500 Problem carrying the
+//! request id, and the keep-alive connection keeps serving requests.
+
+use std::net::SocketAddr;
+
+use axum::{Router, http::StatusCode, middleware::from_fn, routing::get};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+use modkit::api::error_layer::{catch_panic_middleware, error_mapping_middleware};
+use modkit::api::handler_panic_count;
+
+async fn panics() -> &'static str {
+    panic!("secret internal state");
+}
+
+async fn ok() -> &'static str {
+    "ok"
+}
+
+async fn early_return() -> (StatusCode, &'static str) {
+    (StatusCode::CONFLICT, "taken")
+}
+
+async fn serve() -> SocketAddr {
+    let router = Router::new()
+        .route("/users/{id}/panic", get(panics))
+        .route("/ok", get(ok))
+        .route("/conflict", get(early_return))
+        .layer(from_fn(catch_panic_middleware))
+        .layer(from_fn(error_mapping_middleware));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    addr
+}
+
+struct RawResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl RawResponse {
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// Send one HTTP/1.1 request on an existing connection and read the full response.
+async fn request(conn: &mut BufReader<TcpStream>, path: &str, request_id: &str) -> RawResponse {
+    let req = format!("GET {path} HTTP/1.1\r\nHost: test\r\nx-request-id: {request_id}\r\n\r\n");
+    conn.get_mut().write_all(req.as_bytes()).await.unwrap();
+
+    let mut status_line = String::new();
+    conn.read_line(&mut status_line).await.unwrap();
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    let mut headers = Vec::new();
+    loop {
+        let mut line = String::new();
+        conn.read_line(&mut line).await.unwrap();
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (k, v) = line.split_once(':').unwrap();
+        headers.push((k.trim().to_owned(), v.trim().to_owned()));
+    }
+
+    let len: usize = headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .map(|(_, v)| v.parse().unwrap())
+        .expect("content-length");
+    let mut body = vec![0; len];
+    conn.read_exact(&mut body).await.unwrap();
+
+    RawResponse {
+        status,
+        headers,
+        body: String::from_utf8(body).unwrap(),
+    }
+}
+
+#[tokio::test]
+async fn panicking_handler_returns_problem_and_keeps_connection() {
+    let addr = serve().await;
+    let mut conn = BufReader::new(TcpStream::connect(addr).await.unwrap());
+    let before = handler_panic_count();
+
+    let resp = request(&mut conn, "/users/42/panic", "req-panic-1").await;
+    assert_eq!(resp.status, 500);
+    assert_eq!(
+        resp.header("content-type"),
+        Some("application/problem+json")
+    );
+    let problem: serde_json::Value = serde_json::from_str(&resp.body).unwrap();
+    assert_eq!(problem["status"], 500);
+    assert_eq!(problem["trace_id"], "req-panic-1");
+    assert_eq!(problem["instance"], "/users/42/panic#req-panic-1");
+    assert!(
+        !resp.body.contains("secret internal state"),
+        "panic message leaked: {}",
+        resp.body
+    );
+    assert!(handler_panic_count() > before);
+
+    // Same connection still serves requests
+    let resp = request(&mut conn, "/ok", "req-ok-2").await;
+    assert_eq!(resp.status, 200);
+    assert_eq!(resp.body, "ok");
+}
+
+#[tokio::test]
+async fn early_returns_pass_through_untouched() {
+    let addr = serve().await;
+    let mut conn = BufReader::new(TcpStream::connect(addr).await.unwrap());
+
+    let resp = request(&mut conn, "/conflict", "req-conflict").await;
+    assert_eq!(resp.status, 409);
+    assert_eq!(resp.body, "taken");
+}
@@ -15,7 +15,7 @@ use tokio_util::sync::CancellationToken;
 
 use modkit::{
     config::ConfigProvider,
-    runtime::{DbOptions, RunOptions, ShutdownOptions, run},
+    runtime::{DEFAULT_STOP_TIMEOUT, DbOptions, RunOptions, ShutdownOptions, run},
 };
 use uuid::Uuid;
 
@@ -93,6 +93,8 @@ async fn test_db_phase_with_manager_succeeds() {
         clients: Vec::new(),
         instance_id: Uuid::new_v4(),
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = timeout(Duration::from_millis(500), run(opts)).await;
@@ -123,6 +125,8 @@ async fn test_db_phase_without_config_skips_migration() {
         clients: Vec::new(),
         instance_id: Uuid::new_v4(),
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = timeout(Duration::from_millis(500), run(opts)).await;
@@ -153,6 +157,8 @@ async fn test_db_phase_with_none_option() {
         clients: Vec::new(),
         instance_id: Uuid::new_v4(),
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = timeout(Duration::from_millis(500), run(opts)).await;
@@ -205,6 +211,8 @@ async fn test_db_phase_error_propagation() {
         clients: Vec::new(),
         instance_id: Uuid::new_v4(),
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     // Run should either succeed (if no modules try to use bad config)
@@ -231,6 +239,8 @@ async fn test_db_phase_completes_before_init() {
         clients: Vec::new(),
         instance_id: Uuid::new_v4(),
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let start = std::time::Instant::now();
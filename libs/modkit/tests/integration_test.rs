@@ -209,6 +209,7 @@ fn test_response_types() {
     let router = Router::new();
 
     let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/text")
+        .operation_id("test.text")
         .authenticated()
         .require_license_features::<TestLicenseFeatures>([])
         .text_response(http::StatusCode::OK, "Plain text response", "text/plain")
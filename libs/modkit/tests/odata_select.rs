@@ -1,6 +1,6 @@
 use modkit::api::{
     odata::{ODataParams, parse_select},
-    select::{apply_select, page_to_projected_json, project_json},
+    select::{apply_select, columns_for_select, page_to_projected_json, project_json},
 };
 use modkit_odata::Page;
 use serde_json::json;
@@ -231,6 +231,41 @@ fn test_apply_select_empty_fields() {
     assert_eq!(result.get("name").and_then(|v| v.as_str()), Some("John"));
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum UserColumn {
+    Id,
+    Name,
+}
+
+fn user_column(field: &str) -> Option<UserColumn> {
+    match field {
+        "id" => Some(UserColumn::Id),
+        "name" => Some(UserColumn::Name),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_columns_for_select_maps_fields() {
+    let selected = vec!["Name".to_owned(), "id".to_owned(), "NAME".to_owned()];
+    assert_eq!(
+        columns_for_select(Some(&selected), user_column),
+        Some(vec![UserColumn::Name, UserColumn::Id])
+    );
+}
+
+#[test]
+fn test_columns_for_select_falls_back_to_full_rows() {
+    assert_eq!(columns_for_select(None, user_column), None);
+    assert_eq!(columns_for_select(Some(&[]), user_column), None);
+
+    let unknown = vec!["id".to_owned(), "email".to_owned()];
+    assert_eq!(columns_for_select(Some(&unknown), user_column), None);
+
+    let nested = vec!["id".to_owned(), "name.first".to_owned()];
+    assert_eq!(columns_for_select(Some(&nested), user_column), None);
+}
+
 #[test]
 fn test_project_json_dot_notation_entire_nested_object() {
     let value = json!({
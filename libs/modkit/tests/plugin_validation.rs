@@ -0,0 +1,138 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! `validate_plugin_instances`: every candidate is checked and all problems are
+//! reported, instead of failing on the first malformed instance.
+
+use gts_macros::struct_to_gts_schema;
+use modkit::gts::{
+    BaseModkitPluginV1, PluginHealth, PluginIssueKind, format_plugin_issues,
+    validate_plugin_instances,
+};
+use serde_json::{Value, json};
+
+#[struct_to_gts_schema(
+    dir_path = "schemas",
+    base = BaseModkitPluginV1,
+    schema_id = "gts.x.core.modkit.plugin.v1~x.core.modkit_test.plugin.v1~",
+    description = "Test plugin specification",
+    properties = "endpoint"
+)]
+pub struct TestPluginSpecV1 {
+    pub endpoint: String,
+}
+
+const TYPE_ID: &str = "gts.x.core.modkit.plugin.v1~x.core.modkit_test.plugin.v1~";
+
+fn instance_id(name: &str) -> String {
+    format!("{TYPE_ID}acme.test.{name}.plugin.v1")
+}
+
+fn instance(id: &str, vendor: &str, priority: i64, properties: Value) -> Value {
+    json!({
+        "id": id,
+        "vendor": vendor,
+        "priority": priority,
+        "properties": properties,
+    })
+}
+
+fn validate(entries: &[(String, Value)]) -> Vec<modkit::gts::PluginValidationIssue> {
+    validate_plugin_instances::<TestPluginSpecV1>(entries.iter().map(|(id, c)| (id.as_str(), c)))
+}
+
+#[test]
+fn valid_instance_has_no_issues() {
+    let id = instance_id("good");
+    let entries = vec![(
+        id.clone(),
+        instance(&id, "acme", 100, json!({ "endpoint": "http://localhost" })),
+    )];
+    assert!(validate(&entries).is_empty());
+}
+
+#[test]
+fn reports_every_broken_instance() {
+    let good = instance_id("good");
+    let not_object = instance_id("not_object");
+    let mismatch = instance_id("mismatch");
+    let no_vendor = instance_id("no_vendor");
+    let negative = instance_id("negative");
+    let too_big = instance_id("too_big");
+    let bad_props = instance_id("bad_props");
+    let props = json!({ "endpoint": "http://localhost" });
+
+    let entries = vec![
+        (good.clone(), instance(&good, "acme", 10, props.clone())),
+        (not_object.clone(), json!("just a string")),
+        (
+            mismatch.clone(),
+            instance(&instance_id("other"), "acme", 10, props.clone()),
+        ),
+        (
+            no_vendor.clone(),
+            instance(&no_vendor, "  ", 10, props.clone()),
+        ),
+        (
+            negative.clone(),
+            instance(&negative, "acme", -1, props.clone()),
+        ),
+        (too_big.clone(), instance(&too_big, "acme", 40_000, props)),
+        (
+            bad_props.clone(),
+            instance(&bad_props, "acme", 10, json!({ "endpoint": 42 })),
+        ),
+    ];
+
+    let issues = validate(&entries);
+    let kinds: Vec<(&str, PluginIssueKind)> =
+        issues.iter().map(|i| (i.gts_id.as_str(), i.kind)).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            (not_object.as_str(), PluginIssueKind::Malformed),
+            (mismatch.as_str(), PluginIssueKind::IdMismatch),
+            (no_vendor.as_str(), PluginIssueKind::EmptyVendor),
+            (negative.as_str(), PluginIssueKind::PriorityOutOfRange),
+            (too_big.as_str(), PluginIssueKind::PriorityOutOfRange),
+            (bad_props.as_str(), PluginIssueKind::InvalidProperties),
+        ]
+    );
+
+    let health = PluginHealth {
+        checked: entries.len(),
+        issues,
+    };
+    assert!(!health.is_healthy());
+    assert_eq!(health.invalid_instances(), 6);
+    assert_eq!(health.summary(), "6 invalid plugin instances");
+    let probe = health.to_json();
+    assert_eq!(probe["status"], "degraded");
+    assert_eq!(probe["issues"][0]["kind"], "malformed");
+
+    let table = format_plugin_issues(&health.issues);
+    assert!(table.starts_with("GTS ID"), "{table}");
+    assert_eq!(table.lines().count(), 7);
+    assert!(
+        table
+            .lines()
+            .any(|l| l.starts_with(no_vendor.as_str()) && l.contains("empty_vendor")),
+        "{table}"
+    );
+}
+
+#[test]
+fn one_instance_can_have_several_issues() {
+    let id = instance_id("double");
+    let entries = vec![(
+        id.clone(),
+        instance(&instance_id("elsewhere"), "", 5, json!({ "endpoint": "x" })),
+    )];
+
+    let health = PluginHealth {
+        checked: 1,
+        issues: validate(&entries),
+    };
+    assert_eq!(health.issues.len(), 2);
+    assert_eq!(health.invalid_instances(), 1);
+    assert_eq!(health.summary(), "1 invalid plugin instance");
+}
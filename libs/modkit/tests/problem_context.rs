@@ -0,0 +1,78 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! `problem_context_middleware`: Problem+JSON responses get an `instance` built from
+//! the request path and id, and a title from the problem type registry.
+
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{Request, StatusCode},
+    middleware::from_fn,
+    response::IntoResponse,
+    routing::get,
+};
+use modkit::api::error_layer::problem_context_middleware;
+use modkit::api::{Problem, ProblemTypeRegistry};
+use tower::ServiceExt;
+
+const GONE_TYPE: &str = "https://errors.example.com/PROBLEM_CONTEXT_GONE";
+
+async fn gone() -> axum::response::Response {
+    Problem::new(StatusCode::GONE, "", "widget was removed")
+        .with_type(GONE_TYPE)
+        .with_instance("/widgets/7")
+        .into_response()
+}
+
+async fn custom_instance() -> axum::response::Response {
+    Problem::new(StatusCode::CONFLICT, "Conflict", "already taken")
+        .with_instance("urn:widgets:7")
+        .into_response()
+}
+
+fn app(template: &str) -> Router {
+    let template: Arc<str> = Arc::from(template);
+    Router::new()
+        .route("/widgets/7", get(gone))
+        .route("/custom", get(custom_instance))
+        .layer(from_fn(
+            move |req: axum::extract::Request, next: axum::middleware::Next| {
+                problem_context_middleware(template.clone(), req, next)
+            },
+        ))
+}
+
+async fn get_problem(app: Router, path: &str, request_id: Option<&str>) -> serde_json::Value {
+    let mut req = Request::builder().uri(path);
+    if let Some(rid) = request_id {
+        req = req.header("x-request-id", rid);
+    }
+    let resp = app.oneshot(req.body(Body::empty()).unwrap()).await.unwrap();
+    assert!(resp.status().is_client_error());
+    let body = to_bytes(resp.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn instance_follows_template_and_title_comes_from_registry() {
+    ProblemTypeRegistry::global().register(GONE_TYPE, "Widget Gone", StatusCode::GONE);
+
+    let problem = get_problem(app("{path}?rid={request_id}"), "/widgets/7", Some("req-1")).await;
+    assert_eq!(problem["instance"], "/widgets/7?rid=req-1");
+    assert_eq!(problem["title"], "Widget Gone");
+    assert_eq!(problem["detail"], "widget was removed");
+}
+
+#[tokio::test]
+async fn instance_is_bare_path_without_request_id() {
+    let problem = get_problem(app("{path}#{request_id}"), "/widgets/7", None).await;
+    assert_eq!(problem["instance"], "/widgets/7");
+}
+
+#[tokio::test]
+async fn handler_specific_instance_is_kept() {
+    let problem = get_problem(app("{path}#{request_id}"), "/custom", Some("req-2")).await;
+    assert_eq!(problem["instance"], "urn:widgets:7");
+}
@@ -21,7 +21,7 @@ use modkit::{
         DatabaseCapability, Module, OpenApiRegistry, RestApiCapability, RunnableCapability,
     },
     registry::{ModuleRegistry, RegistryBuilder},
-    runtime::{DbOptions, RunOptions, ShutdownOptions, run},
+    runtime::{DEFAULT_STOP_TIMEOUT, DbOptions, RunOptions, ShutdownOptions, run},
 };
 
 // Test tracking infrastructure
@@ -371,6 +371,8 @@ async fn test_db_options_none() {
         shutdown: ShutdownOptions::Token(cancel),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     // This test requires registry discovery to work, which won't work in isolation
@@ -407,6 +409,8 @@ async fn test_db_options_manager() {
         shutdown: ShutdownOptions::Token(cancel),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = timeout(Duration::from_millis(1000), run(opts)).await;
@@ -427,6 +431,8 @@ async fn test_shutdown_options_token() {
         shutdown: ShutdownOptions::Token(cancel.clone()),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     // Start the runner in a background task
@@ -458,6 +464,8 @@ async fn test_shutdown_options_future() {
         })),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     // Start the runner in a background task
@@ -496,6 +504,8 @@ async fn test_runner_with_config_provider() {
         shutdown: ShutdownOptions::Token(cancel),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = timeout(Duration::from_millis(100), run(opts)).await;
@@ -517,6 +527,8 @@ async fn test_complete_lifecycle_success() {
         shutdown: ShutdownOptions::Token(cancel),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = run(opts).await;
@@ -534,6 +546,8 @@ fn test_run_options_construction() {
         shutdown: ShutdownOptions::Token(cancel),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     // Test that we can construct RunOptions with all variants
@@ -559,6 +573,8 @@ async fn test_cancellation_during_startup() {
         shutdown: ShutdownOptions::Token(cancel.clone()),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     // Start the runner in a background task
@@ -595,6 +611,8 @@ async fn test_multiple_config_provider_scenarios() {
         shutdown: ShutdownOptions::Token(cancel.clone()),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result = run(opts).await;
@@ -630,6 +648,8 @@ async fn test_multiple_config_provider_scenarios() {
         shutdown: ShutdownOptions::Token(cancel2),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let result2 = run(opts2).await;
@@ -648,6 +668,8 @@ async fn test_runner_timeout_scenarios() {
         shutdown: ShutdownOptions::Token(cancel.clone()),
         clients: vec![],
         oop: None,
+        admin_socket: None,
+        stop_timeout: DEFAULT_STOP_TIMEOUT,
     };
 
     let runner_handle = tokio::spawn(run(opts));
@@ -20,4 +20,5 @@ fn compile_fail_tests() {
     t.compile_fail("tests/ui/no_handler.rs");
     t.compile_fail("tests/ui/no_response.rs");
     t.compile_fail("tests/ui/no_handler_no_response.rs");
+    t.compile_fail("tests/ui/no_operation_id.rs");
 }
@@ -0,0 +1,28 @@
+//! This test should fail to compile because register() is called without an operation ID
+
+use modkit::api::OperationBuilder;
+use axum::Router;
+
+async fn test_handler() -> &'static str { "ok" }
+
+struct DummyRegistry;
+impl modkit::api::OpenApiRegistry for DummyRegistry {
+    fn register_operation(&self, _: &modkit::api::OperationSpec) {}
+    fn ensure_schema_raw(&self, root_name: &str, _: Vec<(String, utoipa::openapi::RefOr<utoipa::openapi::schema::Schema>)>) -> String {
+        root_name.to_string()
+    }
+    fn as_any(&self) -> &dyn std::any::Any { self }
+}
+
+fn main() {
+    let registry = DummyRegistry;
+    let router = Router::new();
+
+    // This should fail to compile - missing operation_id
+    let _ = OperationBuilder::<_, _, ()>::get("/tests/v1/test")
+        .summary("Test endpoint")
+        .public()
+        .handler(test_handler)
+        .json_response(http::StatusCode::OK, "OK")
+        .register(router, &registry);
+}
@@ -1,4 +1,5 @@
 use modkit::api::problem::Problem;
+use modkit::{IntoProblem, ProblemResponse};
 
 use crate::domain::error::DomainError;
 use crate::errors::ErrorCode;
@@ -80,9 +81,11 @@ fn build_database_problem(e: &DomainError, instance: &str, trace_id: Option<Stri
     )
 }
 
-/// Implement From<DomainError> for Problem so `?` works in handlers
-impl From<DomainError> for Problem {
-    fn from(e: DomainError) -> Self {
-        domain_error_to_problem(&e, "/")
+/// Handler result: `?` on `DomainError` wraps it in a `ProblemResponse`
+pub type ApiResult<T = ()> = Result<T, ProblemResponse<DomainError>>;
+
+impl IntoProblem for DomainError {
+    fn into_problem(self) -> Problem {
+        domain_error_to_problem(&self, "/")
     }
 }
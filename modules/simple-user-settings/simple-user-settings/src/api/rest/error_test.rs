@@ -3,7 +3,7 @@ mod tests {
     use super::super::error::domain_error_to_problem;
     use crate::domain::error::DomainError;
     use axum::http::StatusCode;
-    use modkit::api::problem::Problem;
+    use modkit::IntoProblem;
 
     #[test]
     fn test_not_found_error_to_problem() {
@@ -42,30 +42,30 @@ mod tests {
     }
 
     #[test]
-    fn test_from_domain_error_for_problem_not_found() {
+    fn test_into_problem_not_found() {
         let error = DomainError::NotFound;
-        let problem: Problem = error.into();
+        let problem = error.into_problem();
 
         assert_eq!(problem.status, StatusCode::NOT_FOUND);
         assert_eq!(problem.instance, "/");
     }
 
     #[test]
-    fn test_from_domain_error_for_problem_validation() {
+    fn test_into_problem_validation() {
         let error = DomainError::Validation {
             field: "language".to_owned(),
             message: "invalid format".to_owned(),
         };
-        let problem: Problem = error.into();
+        let problem = error.into_problem();
 
         assert_eq!(problem.status, StatusCode::UNPROCESSABLE_ENTITY);
         assert!(problem.detail.contains("language"));
     }
 
     #[test]
-    fn test_from_domain_error_for_problem_database() {
+    fn test_into_problem_database() {
         let error = DomainError::Database(modkit_db::DbError::InvalidConfig("db error".to_owned()));
-        let problem: Problem = error.into();
+        let problem = error.into_problem();
 
         assert_eq!(problem.status, StatusCode::INTERNAL_SERVER_ERROR);
     }
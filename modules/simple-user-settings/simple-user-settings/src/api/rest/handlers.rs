@@ -10,6 +10,7 @@ use crate::api::rest::routes::ConcreteService;
 use super::dto::{
     PatchSimpleUserSettingsRequest, SimpleUserSettingsDto, UpdateSimpleUserSettingsRequest,
 };
+use super::error::ApiResult;
 
 pub async fn get_settings(
     Extension(ctx): Extension<SecurityContext>,
@@ -23,7 +23,7 @@ impl LicenseFeature for License {}
 pub fn register_routes(
     mut router: Router,
     openapi: &dyn OpenApiRegistry,
-    service: Arc<ConcreteService>,
+    service: Option<Arc<ConcreteService>>,
 ) -> Router {
     router = OperationBuilder::get("/simple-user-settings/v1/settings")
         .operation_id("simple_user_settings.get_settings")
@@ -85,7 +85,10 @@ pub fn register_routes(
         .error_500(openapi)
         .register(router, openapi);
 
-    router = router.layer(Extension(service));
+    // `None` only in a dry run, where the router is discarded
+    if let Some(service) = service {
+        router = router.layer(Extension(service));
+    }
 
     router
 }
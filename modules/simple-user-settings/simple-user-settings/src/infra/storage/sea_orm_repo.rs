@@ -29,11 +29,24 @@ impl Default for SeaOrmSettingsRepository {
 fn map_scope_error(e: ScopeError) -> DomainError {
     match e {
         ScopeError::Denied(msg) => DomainError::forbidden(msg),
+        ScopeError::NotFound { .. } => DomainError::NotFound,
         ScopeError::Invalid(msg) => DomainError::internal(format!("scope invalid: {msg}")),
-        ScopeError::Db(e) => DomainError::internal(format!("database error: {e}")),
+        ScopeError::Db(e)
+        | ScopeError::UniqueViolation { source: e, .. }
+        | ScopeError::SerializationFailure(e) => {
+            DomainError::internal(format!("database error: {e}"))
+        }
+        err @ ScopeError::ReadOnlyScope => DomainError::forbidden(err.to_string()),
         ScopeError::TenantNotInScope { tenant_id } => {
             DomainError::forbidden(format!("tenant {tenant_id} not in scope"))
         }
+        err
+        @ (ScopeError::ReferenceNotInScope { .. } | ScopeError::ReferencesNotInScope { .. }) => {
+            DomainError::validation("reference", err.to_string())
+        }
+        err @ (ScopeError::QueryTimeout(_) | ScopeError::Cancelled) => {
+            DomainError::internal(err.to_string())
+        }
     }
 }
 
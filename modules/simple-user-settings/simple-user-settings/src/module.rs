@@ -53,6 +53,11 @@ impl Module for SettingsModule {
 
         let cfg: SettingsConfig = ctx.config()?;
 
+        // Routes don't depend on the service; a dry run has no database
+        if ctx.is_dry_run() {
+            return Ok(());
+        }
+
         let db: Arc<DBProvider<DbError>> = Arc::new(ctx.db_required()?);
 
         // Repository no longer stores connection - uses &impl DBRunner per-method
@@ -86,16 +91,16 @@ impl Module for SettingsModule {
 impl modkit::contracts::RestApiCapability for SettingsModule {
     fn register_rest(
         &self,
-        _ctx: &ModuleCtx,
+        ctx: &ModuleCtx,
         router: Router,
         openapi: &dyn OpenApiRegistry,
     ) -> anyhow::Result<Router> {
         info!("Settings module: register_rest called");
-        let service = self
-            .service
-            .get()
-            .ok_or_else(|| anyhow::anyhow!("Service not initialized"))?
-            .clone();
+        let service = match self.service.get() {
+            Some(service) => Some(service.clone()),
+            None if ctx.is_dry_run() => None,
+            None => anyhow::bail!("Service not initialized"),
+        };
 
         let router = routes::register_routes(router, openapi, service);
         info!("Settings module: REST routes registered successfully");
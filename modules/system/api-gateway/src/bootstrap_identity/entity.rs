@@ -0,0 +1,21 @@
+use modkit_db_macros::Scopable;
+use sea_orm::entity::prelude::*;
+use uuid::Uuid;
+
+/// Identity the gateway was first started with in auth-disabled mode.
+///
+/// Global (not tenant-scoped): the row describes the installation itself.
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Scopable)]
+#[sea_orm(table_name = "api_gateway_bootstrap_identity")]
+#[secure(unrestricted)]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub key: String,
+    pub subject_id: Uuid,
+    pub tenant_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,53 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::ConnectionTrait;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let backend = manager.get_database_backend();
+        let conn = manager.get_connection();
+
+        let sql = match backend {
+            sea_orm::DatabaseBackend::Postgres => {
+                r"
+CREATE TABLE IF NOT EXISTS api_gateway_bootstrap_identity (
+    key VARCHAR(64) PRIMARY KEY,
+    subject_id UUID NOT NULL,
+    tenant_id UUID NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::MySql => {
+                r"
+CREATE TABLE IF NOT EXISTS api_gateway_bootstrap_identity (
+    `key` VARCHAR(64) PRIMARY KEY,
+    subject_id VARCHAR(36) NOT NULL,
+    tenant_id VARCHAR(36) NOT NULL
+);
+                "
+            }
+            sea_orm::DatabaseBackend::Sqlite => {
+                r"
+CREATE TABLE IF NOT EXISTS api_gateway_bootstrap_identity (
+    key TEXT PRIMARY KEY,
+    subject_id TEXT NOT NULL,
+    tenant_id TEXT NOT NULL
+);
+                "
+            }
+        };
+
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let conn = manager.get_connection();
+        let sql = "DROP TABLE IF EXISTS api_gateway_bootstrap_identity;";
+        conn.execute_unprepared(sql).await?;
+        Ok(())
+    }
+}
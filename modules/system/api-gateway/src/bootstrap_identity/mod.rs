@@ -0,0 +1,134 @@
+//! Bootstrap identity for auth-disabled mode.
+//!
+//! With `auth_disabled` every request runs as the subject and tenant from
+//! `auth_disabled_identity`, so all data written in that mode belongs to that
+//! tenant. Changing the identity later would silently move the installation to
+//! a new, empty tenant. When the gateway has a database, the identity is
+//! recorded on first start and a mismatch refuses startup unless `force` is set.
+
+pub(crate) mod entity;
+pub(crate) mod migrations;
+
+use anyhow::{Context as _, bail};
+use modkit_db::secure::{SecureEntityExt, SecureUpdateExt, secure_insert};
+use modkit_db::{DBProvider, DbError};
+use modkit_security::{AccessScope, SecurityContext};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveValue, ColumnTrait, Condition, EntityTrait};
+
+use crate::config::AuthDisabledIdentityConfig;
+use entity::{Column, Entity as BootstrapIdentityEntity};
+
+/// Primary key of the single fingerprint row.
+const FINGERPRINT_KEY: &str = "auth_disabled_identity";
+
+/// Check the configured identity before it is used.
+///
+/// # Errors
+/// Returns an error if the subject or tenant is the nil UUID.
+pub(crate) fn validate(cfg: &AuthDisabledIdentityConfig) -> anyhow::Result<()> {
+    if cfg.subject_id.is_nil() {
+        bail!("auth_disabled_identity.subject_id must not be the nil UUID");
+    }
+    if cfg.tenant_id.is_nil() {
+        bail!("auth_disabled_identity.tenant_id must not be the nil UUID");
+    }
+    if cfg.is_legacy_default() {
+        tracing::warn!(
+            subject_id = %cfg.subject_id,
+            tenant_id = %cfg.tenant_id,
+            "auth_disabled_identity is left at the well-known legacy defaults shared by every \
+             install; set a unique subject_id and tenant_id to keep this instance's data apart"
+        );
+    }
+    Ok(())
+}
+
+/// `SecurityContext` injected for every request in auth-disabled mode.
+///
+/// # Errors
+/// Returns an error if the context cannot be built.
+pub(crate) fn security_context(
+    cfg: &AuthDisabledIdentityConfig,
+) -> anyhow::Result<SecurityContext> {
+    Ok(SecurityContext::builder()
+        .subject_id(cfg.subject_id)
+        .subject_tenant_id(cfg.tenant_id)
+        .token_scopes(cfg.token_scopes.clone())
+        .build()?)
+}
+
+/// Compare the configured identity with the one recorded in the database,
+/// recording it on first start.
+///
+/// # Errors
+/// Returns an error if the database cannot be read or written, or if the
+/// recorded identity differs and `force` is not set.
+pub(crate) async fn verify_fingerprint(
+    db: &DBProvider<DbError>,
+    cfg: &AuthDisabledIdentityConfig,
+) -> anyhow::Result<()> {
+    let conn = db.conn()?;
+    // The fingerprint row is installation-wide, not tenant data.
+    let scope = AccessScope::allow_all();
+
+    let stored = BootstrapIdentityEntity::find()
+        .secure()
+        .scope_with(&scope)
+        .filter(Condition::all().add(Column::Key.eq(FINGERPRINT_KEY)))
+        .one(&conn)
+        .await
+        .context("failed to read auth_disabled_identity fingerprint")?;
+
+    let Some(stored) = stored else {
+        let row = entity::ActiveModel {
+            key: ActiveValue::Set(FINGERPRINT_KEY.to_owned()),
+            subject_id: ActiveValue::Set(cfg.subject_id),
+            tenant_id: ActiveValue::Set(cfg.tenant_id),
+        };
+        secure_insert::<BootstrapIdentityEntity>(row, &scope, &conn)
+            .await
+            .context("failed to record auth_disabled_identity fingerprint")?;
+        tracing::info!(
+            subject_id = %cfg.subject_id,
+            tenant_id = %cfg.tenant_id,
+            "Recorded auth-disabled bootstrap identity"
+        );
+        return Ok(());
+    };
+
+    if stored.subject_id == cfg.subject_id && stored.tenant_id == cfg.tenant_id {
+        return Ok(());
+    }
+
+    if !cfg.force {
+        bail!(
+            "auth_disabled_identity (subject_id={}, tenant_id={}) does not match the identity this \
+             database was bootstrapped with (subject_id={}, tenant_id={}); existing data would no \
+             longer be visible. Restore the previous identity or set auth_disabled_identity.force \
+             to record the new one",
+            cfg.subject_id,
+            cfg.tenant_id,
+            stored.subject_id,
+            stored.tenant_id
+        );
+    }
+
+    BootstrapIdentityEntity::update_many()
+        .secure()
+        .scope_with(&scope)
+        .col_expr(Column::SubjectId, Expr::value(cfg.subject_id))
+        .col_expr(Column::TenantId, Expr::value(cfg.tenant_id))
+        .filter(Condition::all().add(Column::Key.eq(FINGERPRINT_KEY)))
+        .exec(&conn)
+        .await
+        .context("failed to update auth_disabled_identity fingerprint")?;
+    tracing::warn!(
+        previous_subject_id = %stored.subject_id,
+        previous_tenant_id = %stored.tenant_id,
+        subject_id = %cfg.subject_id,
+        tenant_id = %cfg.tenant_id,
+        "auth_disabled_identity changed with force set; data of the previous tenant is no longer visible"
+    );
+    Ok(())
+}
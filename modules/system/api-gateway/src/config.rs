@@ -1,4 +1,9 @@
+use modkit_security::constants::{DEFAULT_SUBJECT_ID, DEFAULT_TENANT_ID};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use uuid::Uuid;
 
 fn default_require_auth_by_default() -> bool {
     true
@@ -8,6 +13,34 @@ fn default_body_limit_bytes() -> usize {
     16 * 1024 * 1024
 }
 
+fn default_client_cert_required() -> bool {
+    true
+}
+
+fn default_tls_reload_interval_secs() -> u64 {
+    30
+}
+
+fn default_bulkhead_retry_after_secs() -> u64 {
+    1
+}
+
+fn default_max_context_bytes() -> usize {
+    4096
+}
+
+fn default_instance_template() -> String {
+    modkit::api::DEFAULT_INSTANCE_TEMPLATE.to_owned()
+}
+
+fn default_identity_subject_id() -> Uuid {
+    DEFAULT_SUBJECT_ID
+}
+
+fn default_identity_tenant_id() -> Uuid {
+    DEFAULT_TENANT_ID
+}
+
 /// API gateway configuration - reused from `api_gateway` module
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 #[serde(deny_unknown_fields)]
@@ -26,10 +59,20 @@ pub struct ApiGatewayConfig {
     #[serde(default)]
     pub openapi: OpenApiConfig,
 
+    /// Interactive docs UI (`/docs`) helpers
+    #[serde(default)]
+    pub docs: DocsConfig,
+
     /// Global defaults
     #[serde(default)]
     pub defaults: Defaults,
 
+    /// Named execution groups (bulkheads), keyed by the name operations pass to
+    /// `OperationBuilder::bulkhead`. Operations without a group are not limited
+    /// beyond the per-route rate limits.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub bulkheads: BTreeMap<String, BulkheadConfig>,
+
     /// Disable authentication and authorization completely.
     /// When true, middleware automatically injects a default `SecurityContext` for all requests,
     /// providing access with no tenant filtering.
@@ -38,9 +81,252 @@ pub struct ApiGatewayConfig {
     #[serde(default)]
     pub auth_disabled: bool,
 
+    /// Identity injected for every request when `auth_disabled` is true.
+    /// Defaults to the well-known `DEFAULT_SUBJECT_ID`/`DEFAULT_TENANT_ID`.
+    #[serde(default)]
+    pub auth_disabled_identity: AuthDisabledIdentityConfig,
+
+    /// Where the gateway reads the credential passed to the `AuthN` resolver.
+    /// Default: `bearer` (`Authorization: Bearer <token>`).
+    #[serde(default)]
+    pub auth_mode: AuthMode,
+
+    /// Accept a forwarded `SecurityContext` (`X-Security-Context-Bin`) from trusted
+    /// internal callers instead of a bearer token. Absent = the header is always stripped.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub internal_auth: Option<InternalAuthConfig>,
+
     /// If true, routes without explicit security requirement still require authentication (AuthN-only).
     #[serde(default = "default_require_auth_by_default")]
     pub require_auth_by_default: bool,
+
+    /// Reverse proxies whose `X-Forwarded-For` (or `X-Real-IP`) header is trusted
+    /// when resolving the client IP. The client IP feeds the request context
+    /// attributes and keys the per-route rate limit buckets. Empty = use the peer address.
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
+
+    /// Fail startup (`rest_finalize`) when the route conflict report is not empty:
+    /// duplicate routes, overlapping path templates or operations without a 2xx response.
+    /// Default: false (conflicts are only logged).
+    #[serde(default)]
+    pub strict_routes: bool,
+
+    /// Problem+JSON error response settings.
+    #[serde(default)]
+    pub problems: ProblemsConfig,
+
+    /// Native TLS termination. When absent the gateway serves plain HTTP.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+
+    /// Security headers added to every response.
+    #[serde(default)]
+    pub security_headers: SecurityHeadersConfig,
+}
+
+fn default_api_key_header() -> String {
+    "X-API-Key".to_owned()
+}
+
+/// Credential source for authenticated requests.
+///
+/// ```yaml
+/// auth_mode:
+///   api_key:
+///     header: "X-API-Key"
+/// ```
+///
+/// In `api_key` mode the header value is passed to the `AuthN` resolver as is,
+/// so the selected plugin must understand API keys (see `api-key-authn-plugin`).
+/// Client certificates are still accepted when TLS client auth is configured.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum AuthMode {
+    /// `Authorization: Bearer <token>`.
+    #[default]
+    Bearer,
+    /// API key read from `header`.
+    ApiKey {
+        #[serde(default = "default_api_key_header")]
+        header: String,
+    },
+}
+
+/// Bootstrap identity used in auth-disabled mode.
+///
+/// Every install that keeps the defaults shares the same subject and tenant
+/// UUIDs; set unique values to keep data of separate instances apart. When the
+/// gateway has a database, the identity is recorded on first start and a later
+/// change is refused unless `force` is set.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthDisabledIdentityConfig {
+    /// Subject of the injected `SecurityContext`. Must not be the nil UUID.
+    #[serde(default = "default_identity_subject_id")]
+    pub subject_id: Uuid,
+    /// Tenant of the injected `SecurityContext`. Must not be the nil UUID.
+    #[serde(default = "default_identity_tenant_id")]
+    pub tenant_id: Uuid,
+    /// Token scopes of the injected `SecurityContext`. Empty = no scope restriction.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub token_scopes: Vec<String>,
+    /// Accept an identity that differs from the one recorded in the database and
+    /// record the new one. Existing data stays with the previous tenant.
+    #[serde(default)]
+    pub force: bool,
+}
+
+impl Default for AuthDisabledIdentityConfig {
+    fn default() -> Self {
+        Self {
+            subject_id: DEFAULT_SUBJECT_ID,
+            tenant_id: DEFAULT_TENANT_ID,
+            token_scopes: Vec::new(),
+            force: false,
+        }
+    }
+}
+
+impl AuthDisabledIdentityConfig {
+    /// Whether the identity is still the legacy well-known default.
+    #[must_use]
+    pub fn is_legacy_default(&self) -> bool {
+        self.subject_id == DEFAULT_SUBJECT_ID && self.tenant_id == DEFAULT_TENANT_ID
+    }
+}
+
+/// Trusted peers allowed to forward an encoded `SecurityContext`.
+///
+/// A request is trusted when its peer address is in `trusted_cidrs` (if any are
+/// listed) and it carries `shared_secret` in `shared_secret_header` (if set).
+/// At least one of the two must be configured. The peer address is the direct
+/// TCP peer; `X-Forwarded-For` is not consulted.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct InternalAuthConfig {
+    /// Peer networks in CIDR notation, e.g. `10.0.0.0/8` or `fd00::/8`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trusted_cidrs: Vec<String>,
+    /// Header that must carry `shared_secret`, e.g. `x-internal-auth`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_secret_header: Option<String>,
+    /// Expected value of `shared_secret_header`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_secret: Option<String>,
+    /// Maximum size of the decoded context in bytes; larger headers are rejected.
+    #[serde(default = "default_max_context_bytes")]
+    pub max_context_bytes: usize,
+}
+
+// The effective configuration is logged at startup: keep the secret out of it.
+impl std::fmt::Debug for InternalAuthConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InternalAuthConfig")
+            .field("trusted_cidrs", &self.trusted_cidrs)
+            .field("shared_secret_header", &self.shared_secret_header)
+            .field(
+                "shared_secret",
+                &self.shared_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .field("max_context_bytes", &self.max_context_bytes)
+            .finish()
+    }
+}
+
+/// Security headers added to every response, including error responses.
+///
+/// A header a handler already set is left untouched.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct SecurityHeadersConfig {
+    /// `Strict-Transport-Security: max-age=<seconds>`. Off by default: the gateway
+    /// often sits behind a TLS-terminating proxy that owns this header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hsts_max_age: Option<u32>,
+    /// `X-Content-Type-Options: nosniff`
+    pub x_content_type_options: bool,
+    /// `X-Frame-Options` value, e.g. `DENY` or `SAMEORIGIN`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_frame_options: Option<String>,
+    /// `Referrer-Policy` value, e.g. `no-referrer`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referrer_policy: Option<String>,
+}
+
+impl Default for SecurityHeadersConfig {
+    fn default() -> Self {
+        Self {
+            hsts_max_age: None,
+            x_content_type_options: true,
+            x_frame_options: None,
+            referrer_policy: None,
+        }
+    }
+}
+
+/// Problem+JSON (RFC 9457) error response settings.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProblemsConfig {
+    /// Template for the `instance` member. `{path}` is the request path and
+    /// `{request_id}` the `x-request-id`; without a request id the bare path is used.
+    #[serde(default = "default_instance_template")]
+    pub instance_template: String,
+    /// Serve the registered problem types at public `GET /problem-types`.
+    #[serde(default)]
+    pub expose_types: bool,
+}
+
+impl Default for ProblemsConfig {
+    fn default() -> Self {
+        Self {
+            instance_template: default_instance_template(),
+            expose_types: false,
+        }
+    }
+}
+
+/// TLS termination settings.
+///
+/// Certificates are loaded and validated during `init`, so a missing file or a
+/// key that does not match the certificate fails startup. They are reloaded on
+/// `SIGHUP` and when the files change; existing connections keep the old ones.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// PEM certificate chain, leaf certificate first
+    pub cert_path: PathBuf,
+    /// PEM private key (PKCS#8, PKCS#1 or SEC1)
+    pub key_path: PathBuf,
+    /// PEM bundle of CAs trusted to issue client certificates. Enables mTLS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_ca_path: Option<PathBuf>,
+    /// With mTLS enabled, reject handshakes without a client certificate.
+    /// When false, clients may connect anonymously but presented certificates are still verified.
+    #[serde(default = "default_client_cert_required")]
+    pub client_cert_required: bool,
+    /// Pass the verified client certificate to the `AuthN` Resolver as an `mtls:` credential
+    /// when a request carries no bearer token.
+    #[serde(default)]
+    pub client_cert_authn: bool,
+    /// Minimum accepted protocol version
+    #[serde(default)]
+    pub min_version: TlsVersion,
+    /// How often to check the certificate files for changes, in seconds. 0 disables polling
+    /// (`SIGHUP` still triggers a reload).
+    #[serde(default = "default_tls_reload_interval_secs")]
+    pub reload_interval_secs: u64,
+}
+
+/// TLS protocol version.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TlsVersion {
+    #[default]
+    #[serde(rename = "1.2")]
+    Tls12,
+    #[serde(rename = "1.3")]
+    Tls13,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -67,6 +353,8 @@ pub struct RateLimitDefaults {
     pub rps: u32,
     pub burst: u32,
     pub in_flight: u32,
+    /// What to do with requests when the rate limit store fails
+    pub on_store_error: RateLimitFailMode,
 }
 
 impl Default for RateLimitDefaults {
@@ -75,10 +363,47 @@ impl Default for RateLimitDefaults {
             rps: 50,
             burst: 100,
             in_flight: 64,
+            on_store_error: RateLimitFailMode::default(),
         }
     }
 }
 
+/// Handling of requests whose rate limit bucket cannot be checked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitFailMode {
+    /// Let the request through and log a warning
+    #[default]
+    Open,
+    /// Reject the request with 503
+    Closed,
+}
+
+/// An execution group isolating heavy endpoints from the rest of the gateway.
+///
+/// Requests beyond `max_concurrent` wait in a queue of `queue_depth` entries;
+/// once the queue is full the gateway answers 503 with `Retry-After`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BulkheadConfig {
+    /// Maximum number of requests of the group executing at once. Must be > 0.
+    pub max_concurrent: u32,
+    /// Maximum number of requests waiting for a slot. 0 rejects immediately.
+    #[serde(default)]
+    pub queue_depth: u32,
+    /// Run the group's handlers on a dedicated runtime so long or blocking work
+    /// cannot monopolize the main runtime's workers.
+    #[serde(default)]
+    pub cpu_bound: bool,
+    /// Worker threads of the dedicated runtime (`cpu_bound` only).
+    /// Defaults to `max_concurrent`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worker_threads: Option<usize>,
+    /// `Retry-After` value, in seconds, sent when the queue is full.
+    #[serde(default = "default_bulkhead_retry_after_secs")]
+    pub retry_after_secs: u64,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields, default)]
 pub struct CorsConfig {
@@ -124,6 +449,9 @@ pub struct OpenApiConfig {
     /// API description (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Server URLs listed in the document (e.g. staging and production), in order
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub servers: Vec<OpenApiServerConfig>,
 }
 
 impl Default for OpenApiConfig {
@@ -132,6 +460,59 @@ impl Default for OpenApiConfig {
             title: "API Documentation".to_owned(),
             version: "0.1.0".to_owned(),
             description: None,
+            servers: Vec::new(),
+        }
+    }
+}
+
+/// An entry of the `OpenAPI` `servers` list.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OpenApiServerConfig {
+    /// Absolute URL, or a path relative to where the document is served
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl From<&OpenApiServerConfig> for modkit::api::OpenApiServer {
+    fn from(server: &OpenApiServerConfig) -> Self {
+        Self {
+            url: server.url.clone(),
+            description: server.description.clone(),
         }
     }
 }
+
+/// Interactive docs UI (`/docs`) configuration.
+///
+/// Everything here is rendered into the served page at startup; with the
+/// defaults the page is served unmodified. Keep both helpers off in production.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct DocsConfig {
+    /// Target environments offered in an environment dropdown. The selected
+    /// `base_url` replaces the `servers` list of the `OpenAPI` document client-side.
+    pub environments: Vec<DocsEnvironment>,
+    /// Show a bearer token field; the token is kept in `sessionStorage` and
+    /// attached to "try it" requests sent to the gateway or a configured environment.
+    pub enable_token_helper: bool,
+}
+
+impl DocsConfig {
+    /// Returns `true` if the docs page needs the bootstrap script.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.enable_token_helper || !self.environments.is_empty()
+    }
+}
+
+/// A named target environment for the docs UI.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DocsEnvironment {
+    /// Label shown in the dropdown
+    pub name: String,
+    /// Absolute `http(s)://` URL or a path starting with `/`
+    pub base_url: String,
+}
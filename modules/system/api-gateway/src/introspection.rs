@@ -0,0 +1,202 @@
+//! Runtime view of the gateway: the middleware stack as actually built and a
+//! digest of the effective configuration.
+//!
+//! The stack report is recorded by [`MiddlewareStack`] while the layers are
+//! added, so it always matches what serves requests.
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt;
+
+use axum::Router;
+use axum::extract::Request;
+use axum::response::IntoResponse;
+use axum::routing::Route;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use tower::{Layer, Service};
+
+use crate::config::ApiGatewayConfig;
+
+/// Authenticated endpoint serving [`GatewayIntrospection`].
+pub const INTROSPECTION_PATH: &str = "/gateway/v1/introspection";
+
+/// A middleware layer and its key parameters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LayerReport {
+    pub name: &'static str,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<&'static str, Value>,
+}
+
+/// The middleware stack in request order: the outermost layer, which runs
+/// first, comes first.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MiddlewareStackReport {
+    pub layers: Vec<LayerReport>,
+}
+
+impl MiddlewareStackReport {
+    /// Layer names in request order.
+    #[must_use]
+    pub fn names(&self) -> Vec<&'static str> {
+        self.layers.iter().map(|layer| layer.name).collect()
+    }
+
+    /// The outermost layer called `name`.
+    #[must_use]
+    pub fn layer(&self, name: &str) -> Option<&LayerReport> {
+        self.layers.iter().find(|layer| layer.name == name)
+    }
+}
+
+impl fmt::Display for MiddlewareStackReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, layer) in self.layers.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" -> ")?;
+            }
+            f.write_str(layer.name)?;
+            if !layer.params.is_empty() {
+                let params: Vec<String> = layer
+                    .params
+                    .iter()
+                    .map(|(key, value)| format!("{key}={value}"))
+                    .collect();
+                write!(f, "[{}]", params.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What `GET /gateway/v1/introspection` returns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GatewayIntrospection {
+    pub middleware: MiddlewareStackReport,
+    /// See [`config_digest`].
+    pub config_digest: String,
+}
+
+/// Adds layers to a router and records each of them.
+///
+/// As with `Router::layer`, the last layer added is the outermost one.
+pub(crate) struct MiddlewareStack {
+    router: Router,
+    layers: Vec<LayerReport>,
+}
+
+impl MiddlewareStack {
+    pub(crate) fn new(router: Router) -> Self {
+        Self {
+            router,
+            layers: Vec::new(),
+        }
+    }
+
+    /// Wrap everything added so far in `layer`.
+    pub(crate) fn layer<L>(self, name: &'static str, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        self.layer_with(name, [], layer)
+    }
+
+    /// Like [`layer`](Self::layer), reporting `params` with it.
+    pub(crate) fn layer_with<L>(
+        mut self,
+        name: &'static str,
+        params: impl IntoIterator<Item = (&'static str, Value)>,
+        layer: L,
+    ) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        self.router = self.router.layer(layer);
+        self.layers.push(LayerReport {
+            name,
+            params: params.into_iter().collect(),
+        });
+        self
+    }
+
+    /// The wrapped router and the report of its layers.
+    pub(crate) fn finish(self) -> (Router, MiddlewareStackReport) {
+        let mut layers = self.layers;
+        layers.reverse();
+        (self.router, MiddlewareStackReport { layers })
+    }
+}
+
+/// Hex SHA-256 of the effective configuration.
+///
+/// Secrets are redacted first: the digest tells two deployments' settings
+/// apart, but does not change when only a secret is rotated.
+#[must_use]
+pub fn config_digest(config: &ApiGatewayConfig) -> String {
+    let mut config = config.clone();
+    if let Some(internal) = &mut config.internal_auth
+        && internal.shared_secret.is_some()
+    {
+        internal.shared_secret = Some("<redacted>".to_owned());
+    }
+    // Cannot fail: every map in the config is keyed by strings.
+    let json = serde_json::to_vec(&config).unwrap_or_default();
+    hex::encode(Sha256::digest(json))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::config::InternalAuthConfig;
+
+    #[test]
+    fn digest_follows_config_but_not_secrets() {
+        let mut config = ApiGatewayConfig {
+            bind_addr: "127.0.0.1:8080".to_owned(),
+            internal_auth: Some(InternalAuthConfig {
+                trusted_cidrs: vec!["10.0.0.0/8".to_owned()],
+                shared_secret_header: Some("x-internal-auth".to_owned()),
+                shared_secret: Some("first".to_owned()),
+                max_context_bytes: 4096,
+            }),
+            ..Default::default()
+        };
+        let digest = config_digest(&config);
+        assert_eq!(digest.len(), 64);
+        assert_eq!(config_digest(&config), digest);
+
+        if let Some(internal) = &mut config.internal_auth {
+            internal.shared_secret = Some("rotated".to_owned());
+        }
+        assert_eq!(config_digest(&config), digest);
+
+        config.defaults.body_limit_bytes = 1024;
+        assert_ne!(config_digest(&config), digest);
+    }
+
+    #[test]
+    fn report_displays_layers_in_request_order() {
+        let (_, report) = MiddlewareStack::new(Router::new())
+            .layer("inner", tower::layer::util::Identity::new())
+            .layer_with(
+                "outer",
+                [("secs", Value::from(30))],
+                tower::layer::util::Identity::new(),
+            )
+            .finish();
+
+        assert_eq!(report.names(), vec!["outer", "inner"]);
+        assert_eq!(report.to_string(), "outer[secs=30] -> inner");
+    }
+}
@@ -10,12 +10,22 @@ pub use module::ApiGateway;
 
 // === INTERNAL MODULES ===
 mod assets;
+mod bootstrap_identity;
 mod config;
 mod cors;
 pub mod error;
+pub mod introspection;
 pub mod middleware;
+pub mod route_conflicts;
 mod router_cache;
+mod security_headers;
+mod tls;
 mod web;
 
 // === RE-EXPORTS ===
-pub use config::{ApiGatewayConfig, CorsConfig};
+pub use config::{
+    ApiGatewayConfig, AuthDisabledIdentityConfig, CorsConfig, InternalAuthConfig, ProblemsConfig,
+    SecurityHeadersConfig, TlsConfig, TlsVersion,
+};
+pub use middleware::context_attributes::ClientIp;
+pub use tls::ClientCertificate;
@@ -2,14 +2,17 @@ use axum::http::Method;
 use axum::response::IntoResponse;
 use std::{collections::HashMap, sync::Arc};
 
-use authn_resolver_sdk::{AuthNResolverClient, AuthNResolverError};
+use authn_resolver_sdk::{AuthNResolverClient, AuthNResolverError, MTLS_CREDENTIAL_PREFIX};
 use modkit::api::Problem;
 use modkit_security::SecurityContext;
 
+use super::internal_auth::{self, InternalAuth};
+use crate::route_conflicts::compare_templates;
+
 /// Route matcher for a specific HTTP method (authenticated routes).
 #[derive(Clone)]
 pub struct RouteMatcher {
-    matcher: matchit::Router<()>,
+    matcher: matchit::Router<String>,
 }
 
 impl RouteMatcher {
@@ -20,18 +23,19 @@ impl RouteMatcher {
     }
 
     fn insert(&mut self, path: &str) -> Result<(), matchit::InsertError> {
-        self.matcher.insert(path, ())
+        self.matcher.insert(path, path.to_owned())
     }
 
-    fn find(&self, path: &str) -> bool {
-        self.matcher.at(path).is_ok()
+    /// Template of the route matching `path`, if any.
+    fn find(&self, path: &str) -> Option<&str> {
+        self.matcher.at(path).ok().map(|m| m.value.as_str())
     }
 }
 
 /// Public route matcher for explicitly public routes
 #[derive(Clone)]
 pub struct PublicRouteMatcher {
-    matcher: matchit::Router<()>,
+    matcher: matchit::Router<String>,
 }
 
 impl PublicRouteMatcher {
@@ -42,11 +46,12 @@ impl PublicRouteMatcher {
     }
 
     fn insert(&mut self, path: &str) -> Result<(), matchit::InsertError> {
-        self.matcher.insert(path, ())
+        self.matcher.insert(path, path.to_owned())
     }
 
-    fn find(&self, path: &str) -> bool {
-        self.matcher.at(path).is_ok()
+    /// Template of the route matching `path`, if any.
+    fn find(&self, path: &str) -> Option<&str> {
+        self.matcher.at(path).ok().map(|m| m.value.as_str())
     }
 }
 
@@ -109,22 +114,41 @@ impl GatewayRoutePolicy {
     }
 
     /// Resolve the authentication requirement for a given (method, path).
+    ///
+    /// `HEAD` is resolved as `GET`: axum serves it with the `GET` handler, so
+    /// it must follow the same policy.
     #[must_use]
     pub fn resolve(&self, method: &Method, path: &str) -> AuthRequirement {
-        // Check if route is explicitly authenticated
-        let is_authenticated = self
+        let method = if method == Method::HEAD {
+            &Method::GET
+        } else {
+            method
+        };
+
+        let authenticated = self
             .route_matchers
             .get(method)
-            .is_some_and(|matcher| matcher.find(path));
-
-        // Check if route is explicitly public using pattern matching
-        let is_public = self
+            .and_then(|matcher| matcher.find(path));
+        let public = self
             .public_matchers
             .get(method)
-            .is_some_and(|matcher| matcher.find(path));
-
-        // Public routes should not be forced to auth by default
-        let needs_authn = is_authenticated || (self.require_auth_by_default && !is_public);
+            .and_then(|matcher| matcher.find(path));
+
+        // The route's own flag always wins over `require_auth_by_default`. When
+        // both matchers match, the template axum dispatches to decides, with
+        // the router's precedence (literal, then parameter, then catch-all at
+        // the first differing segment): `/users/me` public vs `/users/{id}`
+        // authenticated, `/x/{a}/{b}` authenticated vs `/x/{*rest}` public. For
+        // the same template, public wins; if precedence cannot be decided,
+        // authentication is required.
+        let needs_authn = match (authenticated, public) {
+            (Some(auth), Some(public)) => {
+                auth != public && compare_templates(auth, public).unwrap_or(true)
+            }
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => self.require_auth_by_default,
+        };
 
         if needs_authn {
             AuthRequirement::Required
@@ -139,6 +163,29 @@ impl GatewayRoutePolicy {
 pub struct AuthState {
     pub authn_client: Arc<dyn AuthNResolverClient>,
     pub route_policy: GatewayRoutePolicy,
+    /// Authenticate with the verified mTLS client certificate when no bearer token is sent.
+    pub client_cert_authn: bool,
+    /// Read the credential from this header instead of `Authorization: Bearer`
+    /// (`auth_mode: api_key`).
+    pub api_key_header: Option<axum::http::HeaderName>,
+    /// Trusted internal callers that may forward an encoded `SecurityContext`.
+    pub internal_auth: Option<Arc<InternalAuth>>,
+}
+
+/// Header carrying the API key in `auth_mode: api_key`; `None` in bearer mode.
+///
+/// # Errors
+///
+/// Returns an error if the configured header is not a valid header name.
+pub fn api_key_header(
+    mode: &crate::config::AuthMode,
+) -> Result<Option<axum::http::HeaderName>, anyhow::Error> {
+    match mode {
+        crate::config::AuthMode::Bearer => Ok(None),
+        crate::config::AuthMode::ApiKey { header } => axum::http::HeaderName::try_from(header)
+            .map(Some)
+            .map_err(|e| anyhow::anyhow!("invalid auth_mode.api_key.header '{header}': {e}")),
+    }
 }
 
 /// Helper to build `GatewayRoutePolicy` from operation requirements.
@@ -194,16 +241,46 @@ pub fn build_route_policy(
 /// 2. Resolves the route's auth requirement via `GatewayRoutePolicy`
 /// 3. For public routes: inserts anonymous `SecurityContext`
 /// 4. For required routes: extracts bearer token, calls `AuthN` Resolver, inserts `SecurityContext`
+///
+/// With `api_key_header` set, the credential is the value of that header and
+/// 401 responses carry no `Bearer` challenge.
+///
+/// With `client_cert_authn` enabled, a request without a bearer token is
+/// authenticated with its mTLS client certificate (`mtls:<subject>` credential).
+/// A bearer token or API key that itself starts with `mtls:` is rejected with
+/// 401, so a client cannot claim a certificate identity it did not present.
+///
+/// With `internal_auth` configured, a trusted peer's `X-Security-Context-Bin`
+/// header replaces all of the above. The header is stripped from every request.
 pub async fn authn_middleware(
     axum::extract::State(state): axum::extract::State<AuthState>,
     mut req: axum::extract::Request,
     next: axum::middleware::Next,
 ) -> axum::response::Response {
+    let forwarded = match &state.internal_auth {
+        Some(internal) => internal.take_forwarded_context(&mut req),
+        None => {
+            internal_auth::strip_forwarded_context(req.headers_mut());
+            None
+        }
+    };
+
     // Skip CORS preflight
     if is_preflight_request(req.method(), req.headers()) {
         return next.run(req).await;
     }
 
+    if let Some(forwarded) = forwarded {
+        record_auth_method("header");
+        return match forwarded {
+            Ok(ctx) => {
+                req.extensions_mut().insert(ctx);
+                next.run(req).await
+            }
+            Err(err) => internal_auth::forwarded_context_error_response(&err),
+        };
+    }
+
     let requirement = state.route_policy.resolve(req.method(), req.uri().path());
 
     match requirement {
@@ -212,31 +289,97 @@ pub async fn authn_middleware(
             next.run(req).await
         }
         AuthRequirement::Required => {
-            let Some(token) = extract_bearer_token(req.headers()) else {
-                return Problem::new(
-                    axum::http::StatusCode::UNAUTHORIZED,
-                    "Unauthorized",
-                    "Missing or invalid Authorization header",
-                )
-                .into_response();
+            let token = match &state.api_key_header {
+                Some(header) => extract_api_key(req.headers(), header).map(|k| ("api_key", k)),
+                None => extract_bearer_token(req.headers()).map(|t| ("bearer", t)),
+            };
+            let credential = match token {
+                Some((method, token)) => {
+                    record_auth_method(method);
+                    if is_mtls_credential(token) {
+                        return authn_error_to_response(
+                            &AuthNResolverError::InvalidToken(format!(
+                                "{method} credential uses the reserved {MTLS_CREDENTIAL_PREFIX} scheme"
+                            )),
+                            state.api_key_header.is_none(),
+                        );
+                    }
+                    token.to_owned()
+                }
+                None => match client_cert_credential(&state, &req) {
+                    Some(credential) => {
+                        record_auth_method("client_cert");
+                        credential
+                    }
+                    None => return missing_credential_response(&state),
+                },
             };
 
-            match state.authn_client.authenticate(token).await {
+            match state.authn_client.authenticate(&credential).await {
                 Ok(result) => {
                     req.extensions_mut().insert(result.security_context);
                     next.run(req).await
                 }
-                Err(err) => authn_error_to_response(&err),
+                Err(err) => authn_error_to_response(&err, state.api_key_header.is_none()),
             }
         }
     }
 }
 
+/// 401 for a request that carries no credential.
+fn missing_credential_response(state: &AuthState) -> axum::response::Response {
+    match &state.api_key_header {
+        Some(header) => Problem::new(
+            axum::http::StatusCode::UNAUTHORIZED,
+            "Unauthorized",
+            format!("Missing or invalid {header} header"),
+        )
+        .into_response(),
+        None => with_www_authenticate(
+            Problem::new(
+                axum::http::StatusCode::UNAUTHORIZED,
+                "Unauthorized",
+                "Missing or invalid Authorization header",
+            )
+            .into_response(),
+            "Bearer",
+        ),
+    }
+}
+
+/// Count an authentication attempt by method (`header`, `bearer`, `api_key`, `client_cert`).
+#[cfg(feature = "otel")]
+fn record_auth_method(method: &'static str) {
+    use opentelemetry::{KeyValue, metrics::Counter};
+    use std::sync::OnceLock;
+
+    static REQUESTS: OnceLock<Counter<u64>> = OnceLock::new();
+    REQUESTS
+        .get_or_init(|| {
+            opentelemetry::global::meter("api-gateway")
+                .u64_counter("http.server.auth.requests")
+                .with_description("Authentication attempts by method")
+                .build()
+        })
+        .add(1, &[KeyValue::new("auth.method", method)]);
+}
+
+#[cfg(not(feature = "otel"))]
+fn record_auth_method(_method: &'static str) {}
+
 /// Convert `AuthNResolverError` to an RFC-9457 Problem Details response.
-fn authn_error_to_response(err: &AuthNResolverError) -> axum::response::Response {
+///
+/// `bearer_challenge` adds the error's `WWW-Authenticate` hint (bearer mode only).
+fn authn_error_to_response(
+    err: &AuthNResolverError,
+    bearer_challenge: bool,
+) -> axum::response::Response {
     log_authn_error(err);
     let (status, title, detail) = match err {
-        AuthNResolverError::Unauthorized(_) => (
+        AuthNResolverError::Unauthorized(_)
+        | AuthNResolverError::InvalidToken(_)
+        | AuthNResolverError::TokenExpired(_)
+        | AuthNResolverError::Revoked(_) => (
             axum::http::StatusCode::UNAUTHORIZED,
             "Unauthorized",
             "Authentication failed",
@@ -252,7 +395,24 @@ fn authn_error_to_response(err: &AuthNResolverError) -> axum::response::Response
             "Internal authentication error",
         ),
     };
-    Problem::new(status, title, detail).into_response()
+    let response = Problem::new(status, title, detail).into_response();
+    match err.www_authenticate_hint() {
+        Some(challenge) if bearer_challenge => with_www_authenticate(response, &challenge),
+        _ => response,
+    }
+}
+
+/// Set the `WWW-Authenticate` challenge on a 401 response (RFC 6750 §3).
+fn with_www_authenticate(
+    mut response: axum::response::Response,
+    challenge: &str,
+) -> axum::response::Response {
+    if let Ok(value) = axum::http::HeaderValue::from_str(challenge) {
+        response
+            .headers_mut()
+            .insert(axum::http::header::WWW_AUTHENTICATE, value);
+    }
+    response
 }
 
 /// Log authentication errors at appropriate levels.
@@ -262,6 +422,9 @@ fn authn_error_to_response(err: &AuthNResolverError) -> axum::response::Response
 fn log_authn_error(err: &AuthNResolverError) {
     match err {
         AuthNResolverError::Unauthorized(msg) => tracing::debug!("AuthN rejected: {msg}"),
+        AuthNResolverError::InvalidToken(msg) => tracing::debug!("AuthN invalid token: {msg}"),
+        AuthNResolverError::TokenExpired(msg) => tracing::debug!("AuthN token expired: {msg}"),
+        AuthNResolverError::Revoked(msg) => tracing::info!("AuthN credential revoked: {msg}"),
         AuthNResolverError::NoPluginAvailable => tracing::error!("No AuthN plugin available"),
         AuthNResolverError::ServiceUnavailable(msg) => {
             tracing::error!("AuthN service unavailable: {msg}");
@@ -270,6 +433,24 @@ fn log_authn_error(err: &AuthNResolverError) {
     }
 }
 
+/// `AuthN` credential for the request's verified mTLS client certificate, if enabled.
+fn client_cert_credential(state: &AuthState, req: &axum::extract::Request) -> Option<String> {
+    if !state.client_cert_authn {
+        return None;
+    }
+    req.extensions()
+        .get::<crate::tls::ClientCertificate>()
+        .map(crate::tls::ClientCertificate::credential)
+}
+
+/// Whether an in-band credential claims the scheme reserved for verified client
+/// certificates.
+fn is_mtls_credential(token: &str) -> bool {
+    token
+        .get(..MTLS_CREDENTIAL_PREFIX.len())
+        .is_some_and(|scheme| scheme.eq_ignore_ascii_case(MTLS_CREDENTIAL_PREFIX))
+}
+
 /// Extract Bearer token from Authorization header
 fn extract_bearer_token(headers: &axum::http::HeaderMap) -> Option<&str> {
     headers
@@ -278,6 +459,18 @@ fn extract_bearer_token(headers: &axum::http::HeaderMap) -> Option<&str> {
         .and_then(|s| s.strip_prefix("Bearer ").map(str::trim))
 }
 
+/// Extract a non-empty API key from the configured header
+fn extract_api_key<'a>(
+    headers: &'a axum::http::HeaderMap,
+    header: &axum::http::HeaderName,
+) -> Option<&'a str> {
+    headers
+        .get(header)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+}
+
 /// Check if this is a CORS preflight request
 ///
 /// Preflight requests are OPTIONS requests with:
@@ -423,6 +616,30 @@ mod tests {
         assert_eq!(result, AuthRequirement::None);
     }
 
+    #[test]
+    fn head_follows_get_policy() {
+        let mut route_matchers = HashMap::new();
+        let mut matcher = RouteMatcher::new();
+        matcher.insert("/users/{id}").unwrap();
+        route_matchers.insert(Method::GET, matcher);
+
+        let mut public_matchers = HashMap::new();
+        let mut matcher = PublicRouteMatcher::new();
+        matcher.insert("/healthz").unwrap();
+        public_matchers.insert(Method::GET, matcher);
+
+        let policy = build_test_policy(route_matchers, public_matchers, true);
+
+        assert_eq!(
+            policy.resolve(&Method::HEAD, "/healthz"),
+            AuthRequirement::None
+        );
+        assert_eq!(
+            policy.resolve(&Method::HEAD, "/users/123"),
+            AuthRequirement::Required
+        );
+    }
+
     #[test]
     fn authenticated_route_has_priority_over_default() {
         let mut route_matchers = HashMap::new();
@@ -437,6 +654,102 @@ mod tests {
         assert_eq!(result, AuthRequirement::Required);
     }
 
+    #[test]
+    fn public_literal_route_wins_over_authenticated_template() {
+        let mut route_matchers = HashMap::new();
+        let mut matcher = RouteMatcher::new();
+        matcher.insert("/users/{id}").unwrap();
+        route_matchers.insert(Method::GET, matcher);
+
+        let mut public_matchers = HashMap::new();
+        let mut matcher = PublicRouteMatcher::new();
+        matcher.insert("/users/me").unwrap();
+        public_matchers.insert(Method::GET, matcher);
+
+        let policy = build_test_policy(route_matchers, public_matchers, true);
+
+        assert_eq!(
+            policy.resolve(&Method::GET, "/users/me"),
+            AuthRequirement::None
+        );
+        assert_eq!(
+            policy.resolve(&Method::GET, "/users/42"),
+            AuthRequirement::Required
+        );
+    }
+
+    #[test]
+    fn authenticated_literal_route_wins_over_public_template() {
+        let mut route_matchers = HashMap::new();
+        let mut matcher = RouteMatcher::new();
+        matcher.insert("/docs/private").unwrap();
+        route_matchers.insert(Method::GET, matcher);
+
+        let mut public_matchers = HashMap::new();
+        let mut matcher = PublicRouteMatcher::new();
+        matcher.insert("/docs/{page}").unwrap();
+        public_matchers.insert(Method::GET, matcher);
+
+        let policy = build_test_policy(route_matchers, public_matchers, false);
+
+        assert_eq!(
+            policy.resolve(&Method::GET, "/docs/private"),
+            AuthRequirement::Required
+        );
+        assert_eq!(
+            policy.resolve(&Method::GET, "/docs/intro"),
+            AuthRequirement::None
+        );
+    }
+
+    #[test]
+    fn public_flag_wins_for_same_template() {
+        let mut route_matchers = HashMap::new();
+        let mut matcher = RouteMatcher::new();
+        matcher.insert("/status/{id}").unwrap();
+        route_matchers.insert(Method::GET, matcher);
+
+        let mut public_matchers = HashMap::new();
+        let mut matcher = PublicRouteMatcher::new();
+        matcher.insert("/status/{id}").unwrap();
+        public_matchers.insert(Method::GET, matcher);
+
+        let policy = build_test_policy(route_matchers, public_matchers, true);
+
+        assert_eq!(
+            policy.resolve(&Method::GET, "/status/1"),
+            AuthRequirement::None
+        );
+    }
+
+    #[test]
+    fn authenticated_params_win_over_public_catch_all() {
+        let mut route_matchers = HashMap::new();
+        let mut matcher = RouteMatcher::new();
+        matcher.insert("/files/{bucket}/{key}").unwrap();
+        route_matchers.insert(Method::GET, matcher);
+
+        let mut public_matchers = HashMap::new();
+        let mut matcher = PublicRouteMatcher::new();
+        matcher.insert("/files/{*rest}").unwrap();
+        public_matchers.insert(Method::GET, matcher);
+
+        let policy = build_test_policy(route_matchers, public_matchers, false);
+
+        assert_eq!(
+            policy.resolve(&Method::GET, "/files/reports/q1.pdf"),
+            AuthRequirement::Required
+        );
+        assert_eq!(
+            policy.resolve(&Method::GET, "/files/readme"),
+            AuthRequirement::None
+        );
+        assert_eq!(
+            policy.resolve(&Method::GET, "/files/a/b/c"),
+            AuthRequirement::None
+        );
+    }
+
     #[test]
     fn different_methods_resolve_independently() {
         let mut route_matchers = HashMap::new();
@@ -0,0 +1,465 @@
+//! Bulkheads: named execution groups isolating heavy endpoints.
+//!
+//! Operations assigned to a group with `OperationBuilder::bulkhead` share the
+//! group's concurrency limit. Requests over the limit wait in a bounded queue;
+//! once the queue is full the gateway answers 503 with `Retry-After`. Handlers of
+//! `cpu_bound` groups run on a dedicated runtime started with the gateway, so
+//! long or blocking work cannot monopolize the main runtime's workers.
+
+use std::collections::{BTreeMap, HashMap, btree_map::Entry};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use anyhow::{Context, Result, anyhow, bail};
+use axum::http::{HeaderValue, Method, StatusCode, header};
+use axum::{
+    extract::Request,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tokio::runtime::{Handle, Runtime};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
+
+use crate::config::{ApiGatewayConfig, BulkheadConfig};
+
+type RouteKey = (Method, String);
+
+/// Current load of a bulkhead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BulkheadStats {
+    /// Requests holding a slot
+    pub in_use: usize,
+    /// Requests waiting for a slot
+    pub queued: usize,
+}
+
+/// Per-route bulkhead assignment, built once from the operation specs.
+#[derive(Default, Clone)]
+pub struct BulkheadMap {
+    routes: Arc<HashMap<RouteKey, Arc<Bulkhead>>>,
+    groups: Arc<BTreeMap<String, Arc<Bulkhead>>>,
+}
+
+impl BulkheadMap {
+    /// Create the groups used by `specs`. Configured groups no operation uses are skipped.
+    ///
+    /// # Errors
+    /// Returns an error if an operation names a group missing from the config, a group
+    /// has `max_concurrent` 0 or a `cpu_bound` group's runtime cannot be started.
+    pub fn from_specs(
+        specs: &[modkit::api::OperationSpec],
+        cfg: &ApiGatewayConfig,
+    ) -> Result<Self> {
+        let mut groups = BTreeMap::new();
+        let mut routes = HashMap::new();
+        for spec in specs {
+            let Some(name) = spec.bulkhead.as_deref() else {
+                continue;
+            };
+            let group = match groups.entry(name.to_owned()) {
+                Entry::Occupied(e) => Arc::clone(e.get()),
+                Entry::Vacant(e) => {
+                    let group_cfg = cfg.bulkheads.get(name).with_context(|| {
+                        anyhow!(
+                            "operation {} {} uses unknown bulkhead '{name}'",
+                            spec.method,
+                            spec.path
+                        )
+                    })?;
+                    let group = Arc::new(Bulkhead::new(name, group_cfg)?);
+                    register_metrics(&group);
+                    Arc::clone(e.insert(group))
+                }
+            };
+            routes.insert((spec.method.clone(), spec.path.clone()), group);
+        }
+        Ok(Self {
+            routes: Arc::new(routes),
+            groups: Arc::new(groups),
+        })
+    }
+
+    /// Current load of every group in use.
+    #[must_use]
+    pub fn stats(&self) -> BTreeMap<String, BulkheadStats> {
+        self.groups
+            .iter()
+            .map(|(name, group)| (name.clone(), group.stats()))
+            .collect()
+    }
+}
+
+struct Bulkhead {
+    name: String,
+    max_concurrent: usize,
+    queue_depth: usize,
+    permits: Arc<Semaphore>,
+    queued: AtomicUsize,
+    retry_after: HeaderValue,
+    offload: Option<OffloadRuntime>,
+}
+
+impl Bulkhead {
+    fn new(name: &str, cfg: &BulkheadConfig) -> Result<Self> {
+        if cfg.max_concurrent == 0 {
+            bail!("bulkhead '{name}': max_concurrent must be > 0");
+        }
+        let max_concurrent = cfg.max_concurrent as usize;
+        let offload = if cfg.cpu_bound {
+            Some(OffloadRuntime::new(
+                name,
+                cfg.worker_threads.unwrap_or(max_concurrent),
+            )?)
+        } else {
+            None
+        };
+        Ok(Self {
+            name: name.to_owned(),
+            max_concurrent,
+            queue_depth: cfg.queue_depth as usize,
+            permits: Arc::new(Semaphore::new(max_concurrent)),
+            queued: AtomicUsize::new(0),
+            retry_after: cfg.retry_after_secs.into(),
+            offload,
+        })
+    }
+
+    fn stats(&self) -> BulkheadStats {
+        BulkheadStats {
+            in_use: self.max_concurrent - self.permits.available_permits(),
+            queued: self.queued.load(Ordering::Acquire),
+        }
+    }
+
+    /// Take a slot, waiting in the queue if it has room. `None` if the queue is full.
+    async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        if let Ok(permit) = Arc::clone(&self.permits).try_acquire_owned() {
+            return Some(permit);
+        }
+        self.queued
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |queued| {
+                (queued < self.queue_depth).then_some(queued + 1)
+            })
+            .ok()?;
+        let _slot = QueueSlot(&self.queued);
+        Arc::clone(&self.permits).acquire_owned().await.ok()
+    }
+}
+
+/// Leaves the queue when the waiting request gets a slot or is dropped.
+struct QueueSlot<'a>(&'a AtomicUsize);
+
+impl Drop for QueueSlot<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Dedicated runtime of a `cpu_bound` group.
+struct OffloadRuntime(Option<Runtime>);
+
+impl OffloadRuntime {
+    fn new(name: &str, worker_threads: usize) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(worker_threads.max(1))
+            .thread_name(format!("bulkhead-{name}"))
+            .enable_all()
+            .build()
+            .with_context(|| anyhow!("bulkhead '{name}': failed to start runtime"))?;
+        Ok(Self(Some(runtime)))
+    }
+
+    fn handle(&self) -> Option<&Handle> {
+        self.0.as_ref().map(Runtime::handle)
+    }
+}
+
+impl Drop for OffloadRuntime {
+    fn drop(&mut self) {
+        // Routers are dropped from async context, where a blocking shutdown panics.
+        if let Some(runtime) = self.0.take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// Aborts the offloaded handler when the request is abandoned (client gone, gateway timeout).
+struct AbortOnDrop(JoinHandle<Response>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+pub async fn bulkhead_middleware(map: BulkheadMap, req: Request, next: Next) -> Response {
+    // Use MatchedPath extension (set by Axum router) for accurate route matching
+    let path = req
+        .extensions()
+        .get::<axum::extract::MatchedPath>()
+        .map_or_else(|| req.uri().path().to_owned(), |p| p.as_str().to_owned());
+    let Some(group) = map.routes.get(&(req.method().clone(), path)).cloned() else {
+        return next.run(req).await;
+    };
+
+    let Some(permit) = group.acquire().await else {
+        tracing::debug!(bulkhead = %group.name, "bulkhead queue full, rejecting request");
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, group.retry_after.clone())],
+        )
+            .into_response();
+    };
+
+    match group.offload.as_ref().and_then(OffloadRuntime::handle) {
+        Some(handle) => run_offloaded(handle, permit, req, next).await,
+        None => {
+            let response = next.run(req).await;
+            drop(permit);
+            response
+        }
+    }
+}
+
+/// Run the rest of the stack on the group's runtime.
+///
+/// The permit moves into the task, so the slot stays taken until the handler
+/// actually stops, even if the caller gave up earlier.
+async fn run_offloaded(
+    handle: &Handle,
+    permit: OwnedSemaphorePermit,
+    req: Request,
+    next: Next,
+) -> Response {
+    let cancel = req.extensions().get::<CancellationToken>().cloned();
+    let task = async move {
+        let _permit = permit;
+        match cancel {
+            // Task-locals do not follow the spawned task: re-scope the request's token
+            Some(token) => modkit_db::secure::with_request_cancellation(token, next.run(req)).await,
+            None => next.run(req).await,
+        }
+    }
+    .instrument(tracing::Span::current());
+
+    let mut task = AbortOnDrop(handle.spawn(task));
+    match (&mut task.0).await {
+        Ok(response) => response,
+        // Re-raise so the panic middleware answers as for any other handler
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        // Only happens while the gateway is shutting down
+        Err(_) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    }
+}
+
+#[cfg(feature = "otel")]
+fn register_metrics(group: &Arc<Bulkhead>) {
+    use opentelemetry::KeyValue;
+
+    let meter = opentelemetry::global::meter("api-gateway");
+    let attrs = [KeyValue::new("bulkhead", group.name.clone())];
+
+    let weak = Arc::downgrade(group);
+    let labels = attrs.clone();
+    meter
+        .u64_observable_gauge("http.server.bulkhead.in_use")
+        .with_description("Requests executing in the bulkhead")
+        .with_callback(move |observer| {
+            if let Some(group) = weak.upgrade() {
+                observer.observe(group.stats().in_use as u64, &labels);
+            }
+        })
+        .build();
+
+    let weak = Arc::downgrade(group);
+    meter
+        .u64_observable_gauge("http.server.bulkhead.queued")
+        .with_description("Requests waiting for a bulkhead slot")
+        .with_callback(move |observer| {
+            if let Some(group) = weak.upgrade() {
+                observer.observe(group.stats().queued as u64, &attrs);
+            }
+        })
+        .build();
+}
+
+#[cfg(not(feature = "otel"))]
+fn register_metrics(_group: &Arc<Bulkhead>) {}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::routing::get;
+    use modkit::api::{Missing, OperationBuilder};
+    use std::time::{Duration, Instant};
+    use tokio::sync::Notify;
+    use tower::ServiceExt;
+
+    fn config(name: &str, group: BulkheadConfig) -> ApiGatewayConfig {
+        let mut cfg = ApiGatewayConfig::default();
+        cfg.bulkheads.insert(name.to_owned(), group);
+        cfg
+    }
+
+    fn group(max_concurrent: u32, queue_depth: u32, cpu_bound: bool) -> BulkheadConfig {
+        BulkheadConfig {
+            max_concurrent,
+            queue_depth,
+            cpu_bound,
+            worker_threads: None,
+            retry_after_secs: 2,
+        }
+    }
+
+    fn heavy_spec() -> modkit::api::OperationSpec {
+        OperationBuilder::<Missing, Missing, ()>::get("/heavy")
+            .bulkhead("heavy")
+            .spec()
+            .clone()
+    }
+
+    fn request(uri: &str) -> axum::http::Request<Body> {
+        axum::http::Request::builder()
+            .uri(uri)
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    async fn wait_for(map: &BulkheadMap, expected: BulkheadStats) {
+        for _ in 0..200 {
+            if map.stats()["heavy"] == expected {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        panic!("bulkhead never reached {expected:?}: {:?}", map.stats());
+    }
+
+    #[test]
+    fn unknown_group_fails_startup() {
+        let err = BulkheadMap::from_specs(&[heavy_spec()], &ApiGatewayConfig::default())
+            .err()
+            .expect("unknown bulkhead must be rejected");
+        assert!(err.to_string().contains("unknown bulkhead 'heavy'"));
+    }
+
+    #[tokio::test]
+    async fn queue_overflow_returns_503() {
+        let map =
+            BulkheadMap::from_specs(&[heavy_spec()], &config("heavy", group(1, 1, false))).unwrap();
+        let release = Arc::new(Notify::new());
+        let gate = Arc::clone(&release);
+        let layer_map = map.clone();
+        let app = Router::new()
+            .route(
+                "/heavy",
+                get(move || {
+                    let gate = Arc::clone(&gate);
+                    async move {
+                        gate.notified().await;
+                        "done"
+                    }
+                }),
+            )
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| bulkhead_middleware(layer_map.clone(), req, next),
+            ));
+
+        let running = tokio::spawn(app.clone().oneshot(request("/heavy")));
+        wait_for(
+            &map,
+            BulkheadStats {
+                in_use: 1,
+                queued: 0,
+            },
+        )
+        .await;
+        let waiting = tokio::spawn(app.clone().oneshot(request("/heavy")));
+        wait_for(
+            &map,
+            BulkheadStats {
+                in_use: 1,
+                queued: 1,
+            },
+        )
+        .await;
+
+        let rejected = app.clone().oneshot(request("/heavy")).await.unwrap();
+        assert_eq!(rejected.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(rejected.headers()[header::RETRY_AFTER], "2");
+
+        release.notify_one();
+        assert_eq!(running.await.unwrap().unwrap().status(), StatusCode::OK);
+        wait_for(
+            &map,
+            BulkheadStats {
+                in_use: 1,
+                queued: 0,
+            },
+        )
+        .await;
+        release.notify_one();
+        assert_eq!(waiting.await.unwrap().unwrap().status(), StatusCode::OK);
+        assert_eq!(
+            map.stats()["heavy"],
+            BulkheadStats {
+                in_use: 0,
+                queued: 0
+            }
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn saturated_cpu_bound_group_does_not_slow_other_routes() {
+        const HEAVY_WORK: Duration = Duration::from_millis(500);
+
+        let map =
+            BulkheadMap::from_specs(&[heavy_spec()], &config("heavy", group(2, 8, true))).unwrap();
+        let layer_map = map.clone();
+        let app = Router::new()
+            .route(
+                "/heavy",
+                get(|| async {
+                    // Blocks its worker thread, like CPU-bound work would
+                    std::thread::sleep(HEAVY_WORK);
+                    "heavy"
+                }),
+            )
+            .route("/fast", get(|| async { "fast" }))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| bulkhead_middleware(layer_map.clone(), req, next),
+            ));
+
+        // More heavy requests than the main runtime has workers
+        let heavy: Vec<_> = (0..6)
+            .map(|_| tokio::spawn(app.clone().oneshot(request("/heavy"))))
+            .collect();
+        wait_for(
+            &map,
+            BulkheadStats {
+                in_use: 2,
+                queued: 4,
+            },
+        )
+        .await;
+
+        let started = Instant::now();
+        let fast = app.clone().oneshot(request("/fast")).await.unwrap();
+        let latency = started.elapsed();
+        assert_eq!(fast.status(), StatusCode::OK);
+        assert!(
+            latency < HEAVY_WORK / 2,
+            "fast route waited {latency:?} behind the heavy group"
+        );
+
+        for response in heavy {
+            assert_eq!(response.await.unwrap().unwrap().status(), StatusCode::OK);
+        }
+    }
+}
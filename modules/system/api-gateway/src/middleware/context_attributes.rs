@@ -0,0 +1,225 @@
+//! Request context attributes for ABAC policies.
+//!
+//! Builds a standard [`ContextAttributes`] set (client IP, request time,
+//! user agent) for every request and stores it in the request extensions.
+//! Handlers extract it with `Extension<ContextAttributes>` and pass it to the
+//! domain layer, which forwards it to the PDP via
+//! `AccessRequest::context_attributes` — services never touch HTTP details.
+//!
+//! The resolved client IP is also stored on its own as [`ClientIp`], so later
+//! middleware (rate limiting) reads it without re-parsing forwarding headers.
+
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use authz_resolver_sdk::ctx_attrs::{self, ContextAttributes};
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, Request};
+use axum::{body::Body, middleware::Next, response::Response};
+use chrono::{DateTime, SecondsFormat, Utc};
+
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+const X_REAL_IP: &str = "x-real-ip";
+
+/// Originating client IP of a request, resolved once by
+/// [`context_attributes_middleware`]. Absent when the peer address is unknown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClientIp(pub IpAddr);
+
+/// Resolve the originating client IP.
+///
+/// `X-Forwarded-For` is only honored when the direct peer is a trusted proxy.
+/// The header is then walked right-to-left, skipping trusted proxies; the first
+/// untrusted hop is the client. If every hop is trusted, the leftmost one wins.
+/// A malformed hop stops the walk and the peer address is used instead.
+/// Without `X-Forwarded-For`, a trusted proxy's `X-Real-IP` is used if it parses.
+#[must_use]
+pub fn resolve_client_ip(
+    peer: Option<IpAddr>,
+    headers: &HeaderMap,
+    trusted_proxies: &[IpAddr],
+) -> Option<IpAddr> {
+    let peer = peer?;
+    if !trusted_proxies.contains(&peer) {
+        return Some(peer);
+    }
+
+    let hops: Vec<&str> = headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    if hops.is_empty() {
+        let real_ip = headers
+            .get(X_REAL_IP)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<IpAddr>().ok());
+        return Some(real_ip.unwrap_or(peer));
+    }
+
+    let mut client = peer;
+    for hop in hops.iter().rev() {
+        let Ok(ip) = hop.parse::<IpAddr>() else {
+            return Some(peer);
+        };
+        client = ip;
+        if !trusted_proxies.contains(&ip) {
+            break;
+        }
+    }
+    Some(client)
+}
+
+/// Build the standard attribute set for a request.
+#[must_use]
+pub fn build_context_attributes(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trusted_proxies: &[IpAddr],
+    now: DateTime<Utc>,
+) -> ContextAttributes {
+    let mut attrs = ContextAttributes::new().with(
+        ctx_attrs::REQUEST_TIME,
+        now.to_rfc3339_opts(SecondsFormat::Millis, true),
+    );
+
+    if let Some(ip) = resolve_client_ip(peer, headers, trusted_proxies) {
+        attrs.insert(ctx_attrs::REQUEST_IP, ip.to_string());
+    }
+
+    if let Some(ua) = headers
+        .get(axum::http::header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+    {
+        attrs.insert(ctx_attrs::USER_AGENT, ua);
+    }
+
+    attrs
+}
+
+/// Middleware that stores [`ContextAttributes`] in `Request.extensions`.
+///
+/// The peer address comes from `ConnectInfo<SocketAddr>`; when the server is not
+/// started with connect info (e.g. in tests) the client IP is omitted.
+pub async fn context_attributes_middleware(
+    trusted_proxies: Arc<[IpAddr]>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let peer = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    let client_ip = resolve_client_ip(peer, req.headers(), &trusted_proxies);
+    let attrs = build_context_attributes(req.headers(), peer, &trusted_proxies, Utc::now());
+    req.extensions_mut().insert(attrs);
+    if let Some(ip) = client_ip {
+        req.extensions_mut().insert(ClientIp(ip));
+    }
+
+    next.run(req).await
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn xff(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(X_FORWARDED_FOR, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn untrusted_peer_ignores_forwarded_header() {
+        let headers = xff("203.0.113.1");
+        let client = resolve_client_ip(Some(ip("198.51.100.9")), &headers, &[ip("10.0.0.1")]);
+        assert_eq!(client, Some(ip("198.51.100.9")));
+    }
+
+    #[test]
+    fn trusted_peer_uses_first_untrusted_hop_from_right() {
+        let trusted = [ip("10.0.0.1"), ip("10.0.0.2")];
+        // Spoofed leftmost entry must be ignored
+        let headers = xff("1.1.1.1, 203.0.113.5, 10.0.0.2");
+        let client = resolve_client_ip(Some(ip("10.0.0.1")), &headers, &trusted);
+        assert_eq!(client, Some(ip("203.0.113.5")));
+    }
+
+    #[test]
+    fn all_trusted_hops_resolve_to_leftmost() {
+        let trusted = [ip("10.0.0.1"), ip("10.0.0.2")];
+        let headers = xff("10.0.0.2");
+        let client = resolve_client_ip(Some(ip("10.0.0.1")), &headers, &trusted);
+        assert_eq!(client, Some(ip("10.0.0.2")));
+    }
+
+    #[test]
+    fn malformed_hop_falls_back_to_peer() {
+        let headers = xff("not-an-ip");
+        let client = resolve_client_ip(Some(ip("10.0.0.1")), &headers, &[ip("10.0.0.1")]);
+        assert_eq!(client, Some(ip("10.0.0.1")));
+    }
+
+    #[test]
+    fn trusted_peer_falls_back_to_real_ip_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(X_REAL_IP, HeaderValue::from_static("203.0.113.7"));
+        let trusted = [ip("10.0.0.1")];
+
+        let client = resolve_client_ip(Some(ip("10.0.0.1")), &headers, &trusted);
+        assert_eq!(client, Some(ip("203.0.113.7")));
+
+        // Ignored from untrusted peers, and when X-Forwarded-For is present
+        let client = resolve_client_ip(Some(ip("198.51.100.9")), &headers, &trusted);
+        assert_eq!(client, Some(ip("198.51.100.9")));
+        headers.insert(X_FORWARDED_FOR, HeaderValue::from_static("203.0.113.5"));
+        let client = resolve_client_ip(Some(ip("10.0.0.1")), &headers, &trusted);
+        assert_eq!(client, Some(ip("203.0.113.5")));
+    }
+
+    #[test]
+    fn missing_peer_yields_no_ip() {
+        assert_eq!(resolve_client_ip(None, &xff("203.0.113.1"), &[]), None);
+    }
+
+    #[test]
+    fn builds_standard_attribute_set() {
+        let mut headers = xff("203.0.113.5");
+        headers.insert(
+            axum::http::header::USER_AGENT,
+            HeaderValue::from_static("curl/8.0"),
+        );
+        let now = DateTime::parse_from_rfc3339("2026-01-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let attrs =
+            build_context_attributes(&headers, Some(ip("10.0.0.1")), &[ip("10.0.0.1")], now);
+
+        assert_eq!(
+            attrs.get(ctx_attrs::REQUEST_IP),
+            Some(&serde_json::json!("203.0.113.5"))
+        );
+        assert_eq!(
+            attrs.get(ctx_attrs::REQUEST_TIME),
+            Some(&serde_json::json!("2026-01-01T12:00:00.000Z"))
+        );
+        assert_eq!(
+            attrs.get(ctx_attrs::USER_AGENT),
+            Some(&serde_json::json!("curl/8.0"))
+        );
+        assert!(attrs.get(ctx_attrs::MFA_PRESENT).is_none());
+    }
+}
@@ -0,0 +1,246 @@
+//! Forwarded `SecurityContext` for trusted internal callers.
+//!
+//! Services inside the mesh already hold a verified identity and may forward
+//! it as `X-Security-Context-Bin`: the base64-encoded output of
+//! [`modkit_security::encode_bin`]. The gateway honors the header only for
+//! requests from a trusted peer (see [`InternalAuthConfig`]) and strips it from
+//! every request, so handlers and downstream modules can never see a spoofed
+//! context.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::ConnectInfo;
+use axum::http::{HeaderMap, HeaderName, StatusCode};
+use axum::response::{IntoResponse, Response};
+use base64::Engine;
+use base64::alphabet;
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use ipnet::IpNet;
+use modkit::api::Problem;
+use modkit_security::{SecCtxDecodeError, SecurityContext, decode_bin};
+use thiserror::Error;
+
+use crate::config::InternalAuthConfig;
+
+/// Header carrying the forwarded, base64-encoded `SecurityContext`.
+pub const SECURITY_CONTEXT_HEADER: &str = "x-security-context-bin";
+
+/// Problem type of a forwarded context that cannot be decoded.
+pub const INVALID_FORWARDED_CONTEXT_TYPE: &str =
+    "https://errors.hyperspot.com/INVALID_FORWARDED_SECURITY_CONTEXT";
+
+/// Standard alphabet, as for gRPC `-bin` metadata; padding is optional.
+const HEADER_ENCODING: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Why a forwarded context from a trusted peer was rejected.
+#[derive(Debug, Error)]
+pub enum ForwardedContextError {
+    #[error("forwarded security context exceeds {max} bytes")]
+    TooLarge { max: usize },
+
+    #[error("forwarded security context is not valid base64")]
+    Encoding,
+
+    #[error(transparent)]
+    Decode(#[from] SecCtxDecodeError),
+}
+
+/// Validated [`InternalAuthConfig`].
+#[derive(Debug, Clone)]
+pub struct InternalAuth {
+    trusted_networks: Vec<IpNet>,
+    shared_secret: Option<(HeaderName, String)>,
+    max_context_bytes: usize,
+}
+
+impl InternalAuth {
+    /// Parse and validate the configuration.
+    ///
+    /// # Errors
+    /// Returns an error if a CIDR or header name does not parse, if only one of
+    /// `shared_secret_header`/`shared_secret` is set, or if neither trusted
+    /// networks nor a shared secret are configured.
+    pub fn from_config(cfg: &InternalAuthConfig) -> anyhow::Result<Self> {
+        let trusted_networks = cfg
+            .trusted_cidrs
+            .iter()
+            .map(|cidr| {
+                cidr.parse::<IpNet>().map_err(|e| {
+                    anyhow::anyhow!("internal_auth.trusted_cidrs: invalid CIDR '{cidr}': {e}")
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let shared_secret = match (&cfg.shared_secret_header, &cfg.shared_secret) {
+            (Some(header), Some(secret)) if !secret.is_empty() => {
+                let name = HeaderName::try_from(header.as_str()).map_err(|e| {
+                    anyhow::anyhow!(
+                        "internal_auth.shared_secret_header: invalid header name '{header}': {e}"
+                    )
+                })?;
+                Some((name, secret.clone()))
+            }
+            (None, None) => None,
+            _ => anyhow::bail!(
+                "internal_auth: shared_secret_header and a non-empty shared_secret must be set together"
+            ),
+        };
+
+        if trusted_networks.is_empty() && shared_secret.is_none() {
+            anyhow::bail!(
+                "internal_auth: set trusted_cidrs and/or shared_secret_header, otherwise every peer is trusted"
+            );
+        }
+        if cfg.max_context_bytes == 0 {
+            anyhow::bail!("internal_auth.max_context_bytes must be > 0");
+        }
+
+        Ok(Self {
+            trusted_networks,
+            shared_secret,
+            max_context_bytes: cfg.max_context_bytes,
+        })
+    }
+
+    /// Strip the forwarded context (and the shared secret) from `req` and
+    /// decode the context if the request comes from a trusted peer.
+    ///
+    /// Returns `None` when the header is absent or the peer is not trusted; the
+    /// request then goes through regular authentication.
+    pub fn take_forwarded_context(
+        &self,
+        req: &mut axum::extract::Request,
+    ) -> Option<Result<SecurityContext, ForwardedContextError>> {
+        let peer = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let headers = req.headers_mut();
+        let value = headers.remove(SECURITY_CONTEXT_HEADER);
+        let trusted = self.is_trusted(peer, headers);
+        if let Some((name, _)) = &self.shared_secret {
+            headers.remove(name);
+        }
+
+        let value = value?;
+        if !trusted {
+            tracing::debug!(
+                peer = ?peer,
+                "Ignoring {SECURITY_CONTEXT_HEADER} from an untrusted peer"
+            );
+            return None;
+        }
+        Some(self.decode(value.as_bytes()))
+    }
+
+    fn is_trusted(&self, peer: Option<IpAddr>, headers: &HeaderMap) -> bool {
+        let network_ok = self.trusted_networks.is_empty()
+            || peer.is_some_and(|ip| {
+                let ip = ip.to_canonical();
+                self.trusted_networks.iter().any(|net| net.contains(&ip))
+            });
+        let secret_ok = self.shared_secret.as_ref().is_none_or(|(name, secret)| {
+            headers
+                .get(name)
+                .is_some_and(|v| constant_time_eq(v.as_bytes(), secret.as_bytes()))
+        });
+        network_ok && secret_ok
+    }
+
+    fn decode(&self, encoded: &[u8]) -> Result<SecurityContext, ForwardedContextError> {
+        let too_large = ForwardedContextError::TooLarge {
+            max: self.max_context_bytes,
+        };
+        // Refuse before allocating: 4 base64 chars per 3 bytes
+        if encoded.len() / 4 * 3 > self.max_context_bytes {
+            return Err(too_large);
+        }
+        let bytes = HEADER_ENCODING
+            .decode(encoded)
+            .map_err(|_| ForwardedContextError::Encoding)?;
+        if bytes.len() > self.max_context_bytes {
+            return Err(too_large);
+        }
+        Ok(decode_bin(&bytes)?)
+    }
+}
+
+/// Remove the forwarded context header when internal auth is not configured.
+pub fn strip_forwarded_context(headers: &mut HeaderMap) {
+    headers.remove(SECURITY_CONTEXT_HEADER);
+}
+
+/// RFC-9457 response for a trusted request whose forwarded context is invalid.
+#[must_use]
+pub fn forwarded_context_error_response(err: &ForwardedContextError) -> Response {
+    tracing::warn!(error = %err, "Rejected forwarded security context");
+    Problem::new(
+        StatusCode::UNAUTHORIZED,
+        "Invalid Forwarded Security Context",
+        err.to_string(),
+    )
+    .with_type(INVALID_FORWARDED_CONTEXT_TYPE)
+    .into_response()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn config(cidrs: &[&str], secret: Option<&str>) -> InternalAuthConfig {
+        InternalAuthConfig {
+            trusted_cidrs: cidrs.iter().map(|c| (*c).to_owned()).collect(),
+            shared_secret_header: secret.map(|_| "x-internal-auth".to_owned()),
+            shared_secret: secret.map(str::to_owned),
+            max_context_bytes: 4096,
+        }
+    }
+
+    #[test]
+    fn rejects_configs_that_trust_everyone() {
+        assert!(InternalAuth::from_config(&config(&[], None)).is_err());
+        assert!(InternalAuth::from_config(&config(&["10.0.0.0/33"], None)).is_err());
+
+        let mut half = config(&[], Some("s3cret"));
+        half.shared_secret = None;
+        assert!(InternalAuth::from_config(&half).is_err());
+    }
+
+    #[test]
+    fn trust_requires_network_and_secret() {
+        let auth = InternalAuth::from_config(&config(&["10.0.0.0/8"], Some("s3cret"))).unwrap();
+        let inside: IpAddr = "10.1.2.3".parse().unwrap();
+        let mapped: IpAddr = "::ffff:10.1.2.3".parse().unwrap();
+        let outside: IpAddr = "192.168.1.1".parse().unwrap();
+
+        let mut headers = HeaderMap::new();
+        assert!(!auth.is_trusted(Some(inside), &headers));
+
+        headers.insert("x-internal-auth", "s3cret".parse().unwrap());
+        assert!(auth.is_trusted(Some(inside), &headers));
+        assert!(auth.is_trusted(Some(mapped), &headers));
+        assert!(!auth.is_trusted(Some(outside), &headers));
+        assert!(!auth.is_trusted(None, &headers));
+
+        headers.insert("x-internal-auth", "s3cres".parse().unwrap());
+        assert!(!auth.is_trusted(Some(inside), &headers));
+    }
+
+    #[test]
+    fn oversized_context_is_rejected_before_decoding() {
+        let mut cfg = config(&["10.0.0.0/8"], None);
+        cfg.max_context_bytes = 8;
+        let auth = InternalAuth::from_config(&cfg).unwrap();
+
+        let err = auth.decode(&[b'A'; 64]).unwrap_err();
+        assert!(matches!(err, ForwardedContextError::TooLarge { max: 8 }));
+    }
+}
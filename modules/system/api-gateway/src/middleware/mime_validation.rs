@@ -153,12 +153,15 @@ mod tests {
                     field_name: "file".to_owned(),
                 },
                 required: true,
+                example: None,
             }),
             responses: vec![],
             handler_id: "test".to_owned(),
             authenticated: false,
             is_public: false,
             license_requirement: None,
+            feature_gate: None,
+            bulkhead: None,
             rate_limit: None,
             allowed_request_content_types: Some(vec!["multipart/form-data", "application/pdf"]),
             vendor_extensions: VendorExtensions::default(),
@@ -1,5 +1,9 @@
 pub mod auth;
+pub mod bulkhead;
+pub mod context_attributes;
+pub mod internal_auth;
 pub mod license_validation;
 pub mod mime_validation;
 pub mod rate_limit;
+pub mod request_cancellation;
 pub mod request_id;
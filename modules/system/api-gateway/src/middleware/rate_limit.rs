@@ -1,133 +1,932 @@
-use crate::config::ApiGatewayConfig;
+//! Per-route rate limits and in-flight limits.
+//!
+//! Token buckets live behind [`RateLimitStore`], consulted exactly once per
+//! rate-limited request. Every response of a rate-limited route carries
+//! `RateLimit-Limit`, `RateLimit-Remaining` and `RateLimit-Reset` computed from
+//! the bucket state at decision time; 429s add `Retry-After`.
+//!
+//! Routes keyed by subject are checked after authentication. Their failed
+//! authentications (401) are charged to the client IP's bucket in front of
+//! authentication, so credential guessing is throttled too.
+
+use crate::config::{ApiGatewayConfig, RateLimitFailMode};
+use crate::middleware::context_attributes::ClientIp;
 use anyhow::{Context, Result, anyhow};
-use axum::http::{HeaderValue, Method, StatusCode, header};
+use async_trait::async_trait;
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header};
 use axum::{
     extract::Request,
     middleware::Next,
     response::{IntoResponse, Response},
 };
+use dashmap::DashMap;
 use governor::clock::Clock;
 use governor::middleware::StateInformationMiddleware;
-use governor::{DefaultDirectRateLimiter, Quota, RateLimiter};
+use governor::{DefaultKeyedRateLimiter, InsufficientCapacity, Quota, RateLimiter};
+use modkit::api::RateLimitKeyBy;
+use modkit_security::SecurityContext;
 use std::collections::HashMap;
+use std::net::IpAddr;
 use std::num::NonZeroU32;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use thiserror::Error;
 use tokio::sync::Semaphore;
+use uuid::Uuid;
 
 type RateLimitKey = (Method, String);
-type BucketMap = Arc<HashMap<RateLimitKey, Arc<BucketMapEntry>>>;
 type InflightMap = Arc<HashMap<RateLimitKey, Arc<Semaphore>>>;
 
-#[derive(Default, Clone)]
-pub struct RateLimiterMap {
-    buckets: BucketMap,
-    inflight: InflightMap,
+const RATELIMIT_POLICY: HeaderName = HeaderName::from_static("ratelimit-policy");
+const RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+const RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+const RATELIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+
+/// Requests per second and burst size of a route's buckets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BucketQuota {
+    pub rps: u32,
+    pub burst: u32,
+}
+
+/// Whose bucket a request draws from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ClientKey {
+    /// The client IP; requests without a resolved IP share the `None` bucket.
+    /// Also used by routes keyed by subject when there is no authenticated
+    /// subject, and for their failed authentications.
+    Ip(Option<IpAddr>),
+    Subject(Uuid),
+    IpAndSubject(Option<IpAddr>, Uuid),
+}
+
+impl ClientKey {
+    fn of(key_by: RateLimitKeyBy, req: &Request) -> Self {
+        let client = client_ip(req);
+        let subject = req
+            .extensions()
+            .get::<SecurityContext>()
+            .map(SecurityContext::subject_id)
+            .filter(|id| !id.is_nil());
+        match (key_by, subject) {
+            (RateLimitKeyBy::Ip, _) | (_, None) => Self::Ip(client),
+            (RateLimitKeyBy::SubjectId, Some(subject)) => Self::Subject(subject),
+            (RateLimitKeyBy::IpAndSubjectId, Some(subject)) => Self::IpAndSubject(client, subject),
+        }
+    }
+}
+
+/// One token bucket: a client of a route, under the route's quota.
+///
+/// The key carries the quota so a store can create the bucket on first use
+/// without knowing the registered routes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BucketKey {
+    pub method: Method,
+    /// Route template, e.g. `/users/{id}`
+    pub path: String,
+    pub client: ClientKey,
+    pub quota: BucketQuota,
+}
+
+/// Outcome of [`RateLimitStore::check_and_consume`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Decision {
+    /// Whether the tokens were taken
+    pub allowed: bool,
+    /// Whole tokens left in the bucket after the call
+    pub remaining: u32,
+    /// Time until the bucket is full again
+    pub reset: Duration,
+    /// Time until the denied cost fits; zero when allowed
+    pub retry_after: Duration,
+}
+
+/// A rate limit store could not reach a decision.
+#[derive(Debug, Error)]
+#[error("rate limit store failed: {0}")]
+pub struct RateLimitStoreError(pub String);
+
+/// Storage of the gateway's token buckets.
+///
+/// The default [`InMemoryRateLimitStore`] keeps the buckets in the gateway
+/// process, so each replica enforces its own budget. A shared backend (e.g.
+/// Redis) lets replicas enforce one budget; install it with
+/// [`ApiGateway::set_rate_limit_store`](crate::ApiGateway::set_rate_limit_store).
+///
+/// Implementations must:
+/// - create a missing bucket full, holding `key.quota.burst` tokens;
+/// - refill it at `key.quota.rps` tokens per second, up to `burst`;
+/// - take `cost` tokens atomically, or none when fewer are left.
+///
+/// When a store fails, requests are let through with a warning, or rejected
+/// with 503 when `defaults.rate_limit.on_store_error` is `closed`.
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Take `cost` tokens from the bucket of `key`.
+    ///
+    /// # Errors
+    /// Returns an error if the backend is unavailable or `cost` is invalid for
+    /// the quota.
+    async fn check_and_consume(
+        &self,
+        key: &BucketKey,
+        cost: u32,
+    ) -> Result<Decision, RateLimitStoreError>;
+}
+
+/// How many checks pass between evictions of idle client buckets.
+const RETAIN_EVERY: u64 = 4096;
+
+/// Process-local token buckets, the default [`RateLimitStore`].
+#[derive(Default)]
+pub struct InMemoryRateLimitStore {
+    routes: DashMap<(Method, String, BucketQuota), Arc<RouteBuckets>>,
+}
+
+/// Token buckets of one route, one per client.
+struct RouteBuckets {
+    limiter: DefaultKeyedRateLimiter<ClientKey, StateInformationMiddleware>,
+    /// Time to refill one token
+    interval: Duration,
+    checks: AtomicU64,
+}
+
+impl RouteBuckets {
+    fn new(quota: BucketQuota) -> Result<Self, RateLimitStoreError> {
+        let (Some(rps), Some(burst)) = (NonZeroU32::new(quota.rps), NonZeroU32::new(quota.burst))
+        else {
+            return Err(RateLimitStoreError(format!("invalid quota {quota:?}")));
+        };
+        let quota = Quota::per_second(rps).allow_burst(burst);
+        Ok(Self {
+            limiter: RateLimiter::keyed(quota).with_middleware::<StateInformationMiddleware>(),
+            interval: quota.replenish_interval(),
+            checks: AtomicU64::new(0),
+        })
+    }
+
+    /// Drop buckets of clients that are back at full capacity, now and then.
+    fn maybe_retain_recent(&self) {
+        if self.checks.fetch_add(1, Ordering::Relaxed) % RETAIN_EVERY == RETAIN_EVERY - 1 {
+            self.limiter.retain_recent();
+        }
+    }
+}
+
+impl InMemoryRateLimitStore {
+    fn route(&self, key: &BucketKey) -> Result<Arc<RouteBuckets>, RateLimitStoreError> {
+        let route = (key.method.clone(), key.path.clone(), key.quota);
+        if let Some(buckets) = self.routes.get(&route) {
+            return Ok(Arc::clone(buckets.value()));
+        }
+        let buckets = Arc::new(RouteBuckets::new(key.quota)?);
+        Ok(Arc::clone(
+            self.routes.entry(route).or_insert(buckets).value(),
+        ))
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for InMemoryRateLimitStore {
+    async fn check_and_consume(
+        &self,
+        key: &BucketKey,
+        cost: u32,
+    ) -> Result<Decision, RateLimitStoreError> {
+        let n =
+            NonZeroU32::new(cost).ok_or_else(|| RateLimitStoreError("cost is zero".to_owned()))?;
+        let buckets = self.route(key)?;
+        buckets.maybe_retain_recent();
+        let burst = key.quota.burst;
+        match buckets.limiter.check_key_n(&key.client, n) {
+            Ok(Ok(state)) => {
+                let remaining = state.remaining_burst_capacity();
+                Ok(Decision {
+                    allowed: true,
+                    remaining,
+                    reset: buckets.interval * burst.saturating_sub(remaining),
+                    retry_after: Duration::ZERO,
+                })
+            }
+            Ok(Err(not_until)) => {
+                let wait = not_until.wait_time_from(buckets.limiter.clock().now());
+                let missing = u32::try_from(wait.as_nanos().div_ceil(buckets.interval.as_nanos()))
+                    .unwrap_or(u32::MAX);
+                Ok(Decision {
+                    allowed: false,
+                    remaining: cost.saturating_sub(missing),
+                    reset: wait + buckets.interval * burst.saturating_sub(cost),
+                    retry_after: wait,
+                })
+            }
+            Err(InsufficientCapacity(_)) => Err(RateLimitStoreError(format!(
+                "cost {cost} exceeds the burst of {burst}"
+            ))),
+        }
+    }
+}
+
+/// A client IP whose failed authentications on a route used up its bucket.
+#[derive(Clone, Copy)]
+struct Blocked {
+    retry_at: Instant,
+    reset_at: Instant,
 }
 
-struct BucketMapEntry {
-    bucket: DefaultDirectRateLimiter<StateInformationMiddleware>,
+/// Quota of a rate-limited route.
+struct RouteLimit {
+    quota: BucketQuota,
     policy: HeaderValue,
-    burst: HeaderValue,
 }
 
-impl BucketMapEntry {
-    pub fn new(rps: u32, burst: u32) -> Result<Self> {
-        let bucket = RateLimiter::direct(
-            Quota::per_second(NonZeroU32::new(rps).with_context(|| anyhow!("rps is zero"))?)
-                .allow_burst(NonZeroU32::new(burst).with_context(|| anyhow!("burst is zero"))?),
-        )
-        .with_middleware::<StateInformationMiddleware>();
+impl RouteLimit {
+    fn new(rps: u32, burst: u32) -> Result<Self> {
+        if rps == 0 || burst == 0 {
+            return Err(anyhow!("rps and burst must be positive"));
+        }
         let policy = HeaderValue::from_str(&format!("\"burst\";q={burst};w={rps}"))
             .context("Failed to create rate limit policy")?;
         Ok(Self {
-            bucket,
+            quota: BucketQuota { rps, burst },
             policy,
-            burst: burst.into(),
         })
     }
 }
 
+#[derive(Clone)]
+pub struct RateLimiterMap {
+    buckets: Arc<HashMap<RateLimitKey, RouteLimit>>,
+    /// Routes keyed by subject; checked after authentication.
+    subject_buckets: Arc<HashMap<RateLimitKey, (RateLimitKeyBy, RouteLimit)>>,
+    /// Client IPs rejected before authentication on routes keyed by subject,
+    /// until their failed-authentication bucket has a token again. Kept per
+    /// gateway replica; the bucket itself lives in the store.
+    auth_failures: Arc<DashMap<(RateLimitKey, Option<IpAddr>), Blocked>>,
+    auth_failure_blocks: Arc<AtomicU64>,
+    inflight: InflightMap,
+    store: Arc<dyn RateLimitStore>,
+    on_store_error: RateLimitFailMode,
+}
+
+impl Default for RateLimiterMap {
+    fn default() -> Self {
+        Self {
+            buckets: Arc::default(),
+            subject_buckets: Arc::default(),
+            auth_failures: Arc::default(),
+            auth_failure_blocks: Arc::default(),
+            inflight: Arc::default(),
+            store: Arc::new(InMemoryRateLimitStore::default()),
+            on_store_error: RateLimitFailMode::default(),
+        }
+    }
+}
+
 impl RateLimiterMap {
+    /// Limits of `specs`, with buckets in a fresh [`InMemoryRateLimitStore`].
+    ///
     /// # Errors
     /// Returns an error if any rate limit spec is 0.
     pub fn from_specs(
         specs: &Vec<modkit::api::OperationSpec>,
         cfg: &ApiGatewayConfig,
+    ) -> Result<Self> {
+        Self::from_specs_with_store(specs, cfg, Arc::new(InMemoryRateLimitStore::default()))
+    }
+
+    /// Limits of `specs`, with buckets in `store`.
+    ///
+    /// # Errors
+    /// Returns an error if any rate limit spec is 0.
+    pub fn from_specs_with_store(
+        specs: &[modkit::api::OperationSpec],
+        cfg: &ApiGatewayConfig,
+        store: Arc<dyn RateLimitStore>,
     ) -> Result<Self> {
         let mut buckets = HashMap::new();
+        let mut subject_buckets = HashMap::new();
         let mut inflight = HashMap::new();
         // TODO: Add support for per-route rate limiting
         for spec in specs {
-            let (rps, burst, max_in_flight) = spec.rate_limit.as_ref().map_or(
+            let (rps, burst, max_in_flight, key_by) = spec.rate_limit.as_ref().map_or(
                 (
                     cfg.defaults.rate_limit.rps,
                     cfg.defaults.rate_limit.burst,
                     cfg.defaults.rate_limit.in_flight,
+                    RateLimitKeyBy::Ip,
                 ),
-                |r| (r.rps, r.burst, r.in_flight),
+                |r| (r.rps, r.burst, r.in_flight, r.key_by),
             );
             let key = (spec.method.clone(), spec.path.clone());
-            buckets.insert(
-                key.clone(),
-                Arc::new(
-                    BucketMapEntry::new(rps, burst)
-                        .with_context(|| anyhow!("RateLimit spec invalid {spec:?} invalid"))?,
-                ),
-            );
+            let invalid = || anyhow!("RateLimit spec invalid {spec:?} invalid");
+            let limit = RouteLimit::new(rps, burst).with_context(invalid)?;
+            if key_by == RateLimitKeyBy::Ip {
+                buckets.insert(key.clone(), limit);
+            } else {
+                subject_buckets.insert(key.clone(), (key_by, limit));
+            }
             inflight.insert(key, Arc::new(Semaphore::new(max_in_flight as usize)));
         }
         Ok(Self {
             buckets: Arc::new(buckets),
+            subject_buckets: Arc::new(subject_buckets),
+            auth_failures: Arc::default(),
+            auth_failure_blocks: Arc::default(),
             inflight: Arc::new(inflight),
+            store,
+            on_store_error: cfg.defaults.rate_limit.on_store_error,
         })
     }
+
+    /// Number of routes limited per client IP.
+    #[must_use]
+    pub fn route_count(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Number of routes keyed by subject.
+    #[must_use]
+    pub fn subject_route_count(&self) -> usize {
+        self.subject_buckets.len()
+    }
+
+    /// Take a token from `client`'s bucket of `route`.
+    ///
+    /// Returns the rate limit headers for the response, or the response to
+    /// send instead of running the request.
+    async fn check(
+        &self,
+        route: &RateLimitKey,
+        limit: &RouteLimit,
+        client: ClientKey,
+    ) -> Result<HeaderMap, Response> {
+        let key = BucketKey {
+            method: route.0.clone(),
+            path: route.1.clone(),
+            client,
+            quota: limit.quota,
+        };
+        let decision = match self.store.check_and_consume(&key, 1).await {
+            Ok(decision) => decision,
+            Err(err) => {
+                return match self.on_store_error {
+                    RateLimitFailMode::Open => {
+                        tracing::warn!(
+                            method = %key.method,
+                            path = %key.path,
+                            error = %err,
+                            "Rate limit store failed; letting the request through"
+                        );
+                        Ok(HeaderMap::new())
+                    }
+                    RateLimitFailMode::Closed => {
+                        tracing::warn!(
+                            method = %key.method,
+                            path = %key.path,
+                            error = %err,
+                            "Rate limit store failed; rejecting the request"
+                        );
+                        Err(StatusCode::SERVICE_UNAVAILABLE.into_response())
+                    }
+                };
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RATELIMIT_POLICY, limit.policy.clone());
+        headers.insert(RATELIMIT_LIMIT, limit.quota.burst.into());
+        headers.insert(RATELIMIT_REMAINING, decision.remaining.into());
+        headers.insert(RATELIMIT_RESET, ceil_secs(decision.reset).into());
+        if decision.allowed {
+            Ok(headers)
+        } else {
+            headers.insert(header::RETRY_AFTER, ceil_secs(decision.retry_after).into());
+            Err((StatusCode::TOO_MANY_REQUESTS, headers).into_response())
+        }
+    }
 }
 
-// TODO: Use tower-governor instead of own implementation (upd: https://github.com/benwis/tower-governor/issues/59 )
-pub async fn rate_limit_middleware(map: RateLimiterMap, mut req: Request, next: Next) -> Response {
-    let method = req.method().clone();
+impl RateLimiterMap {
+    /// 429 for a client IP still blocked for failed authentications on `route`.
+    fn blocked_response(
+        &self,
+        route: &RateLimitKey,
+        limit: &RouteLimit,
+        client: Option<IpAddr>,
+    ) -> Option<Response> {
+        let key = (route.clone(), client);
+        let blocked = *self.auth_failures.get(&key)?;
+        let now = Instant::now();
+        if blocked.retry_at <= now {
+            self.auth_failures.remove_if(&key, |_, b| b.retry_at <= now);
+            return None;
+        }
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RATELIMIT_POLICY, limit.policy.clone());
+        headers.insert(RATELIMIT_LIMIT, limit.quota.burst.into());
+        headers.insert(RATELIMIT_REMAINING, HeaderValue::from(0_u32));
+        headers.insert(
+            RATELIMIT_RESET,
+            ceil_secs(blocked.reset_at.saturating_duration_since(now)).into(),
+        );
+        headers.insert(
+            header::RETRY_AFTER,
+            ceil_secs(blocked.retry_at - now).into(),
+        );
+        Some((StatusCode::TOO_MANY_REQUESTS, headers).into_response())
+    }
+
+    /// Take a token from the client IP's bucket of `route` for a failed
+    /// authentication; block the IP once the bucket is empty.
+    async fn charge_auth_failure(
+        &self,
+        route: &RateLimitKey,
+        limit: &RouteLimit,
+        client: Option<IpAddr>,
+    ) {
+        let key = BucketKey {
+            method: route.0.clone(),
+            path: route.1.clone(),
+            client: ClientKey::Ip(client),
+            quota: limit.quota,
+        };
+        let decision = match self.store.check_and_consume(&key, 1).await {
+            Ok(decision) => decision,
+            Err(err) => {
+                tracing::warn!(
+                    method = %key.method,
+                    path = %key.path,
+                    error = %err,
+                    "Rate limit store failed; failed authentication not charged"
+                );
+                return;
+            }
+        };
+        if decision.allowed {
+            return;
+        }
+
+        let now = Instant::now();
+        if self.auth_failure_blocks.fetch_add(1, Ordering::Relaxed) % RETAIN_EVERY
+            == RETAIN_EVERY - 1
+        {
+            self.auth_failures.retain(|_, b| b.retry_at > now);
+        }
+        self.auth_failures.insert(
+            (route.clone(), client),
+            Blocked {
+                retry_at: now + decision.retry_after,
+                reset_at: now + decision.reset,
+            },
+        );
+    }
+}
+
+/// Whole seconds, rounded up so clients never come back too early.
+fn ceil_secs(duration: Duration) -> u64 {
+    duration.as_secs() + u64::from(duration.subsec_nanos() > 0)
+}
+
+fn with_headers(mut resp: Response, headers: HeaderMap) -> Response {
+    resp.headers_mut().extend(headers);
+    resp
+}
+
+fn route_key(req: &Request) -> RateLimitKey {
     // Use MatchedPath extension (set by Axum router) for accurate route matching
     let path = req
         .extensions()
         .get::<axum::extract::MatchedPath>()
         .map_or_else(|| req.uri().path().to_owned(), |p| p.as_str().to_owned());
-    let key = (method, path);
-
-    if let Some(bucker_map_entry) = map.buckets.get(&key) {
-        let headers = req.headers_mut();
-        headers.insert("RateLimit-Policy", bucker_map_entry.policy.clone());
-        match bucker_map_entry.bucket.check() {
-            Ok(state) => {
-                headers.insert("RateLimit-Limit", bucker_map_entry.burst.clone());
-                headers.insert(
-                    "RateLimit-Limit-Remaining",
-                    state.remaining_burst_capacity().into(),
-                );
-                headers.insert("X-RateLimit-Limit", bucker_map_entry.burst.clone());
-                headers.insert(
-                    "X-RateLimit-Remaining",
-                    state.remaining_burst_capacity().into(),
-                );
-            }
-            Err(not_until) => {
-                let wait = not_until.wait_time_from(bucker_map_entry.bucket.clock().now());
-                headers.insert(header::RETRY_AFTER, wait.as_secs().into());
-                return StatusCode::TOO_MANY_REQUESTS.into_response();
-            }
+    (req.method().clone(), path)
+}
+
+/// Resolved from the peer address and trusted proxy headers by the context attributes middleware
+fn client_ip(req: &Request) -> Option<IpAddr> {
+    req.extensions().get::<ClientIp>().map(|ClientIp(ip)| *ip)
+}
+
+// TODO: Use tower-governor instead of own implementation (upd: https://github.com/benwis/tower-governor/issues/59 )
+pub async fn rate_limit_middleware(map: RateLimiterMap, req: Request, next: Next) -> Response {
+    let key = route_key(&req);
+    let client = client_ip(&req);
+
+    let headers = match map.buckets.get(&key) {
+        Some(limit) => match map.check(&key, limit, ClientKey::Ip(client)).await {
+            Ok(headers) => headers,
+            Err(resp) => return resp,
+        },
+        None => HeaderMap::new(),
+    };
+
+    // Routes keyed by subject: clients that keep failing authentication are
+    // stopped here, before reaching the AuthN resolver.
+    let subject_limit = map.subject_buckets.get(&key).map(|(_, limit)| limit);
+    if let Some(limit) = subject_limit
+        && let Some(resp) = map.blocked_response(&key, limit, client)
+    {
+        return resp;
+    }
+
+    let resp = match map.inflight.get(&key) {
+        Some(sem) => match sem.clone().try_acquire_owned() {
+            // Allow request; permit is dropped when response future completes
+            Ok(_permit) => next.run(req).await,
+            Err(_) => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+        },
+        None => next.run(req).await,
+    };
+
+    if let Some(limit) = subject_limit
+        && resp.status() == StatusCode::UNAUTHORIZED
+    {
+        map.charge_auth_failure(&key, limit, client).await;
+    }
+
+    with_headers(resp, headers)
+}
+
+/// Token buckets of routes keyed by subject.
+///
+/// Runs inside authentication: the key is built from the subject of the
+/// request's `SecurityContext`, or the client IP when there is no
+/// authenticated subject. In-flight limits and failed authentications of
+/// these routes stay in [`rate_limit_middleware`].
+pub async fn subject_rate_limit_middleware(
+    map: RateLimiterMap,
+    req: Request,
+    next: Next,
+) -> Response {
+    let key = route_key(&req);
+    let Some((key_by, limit)) = map.subject_buckets.get(&key) else {
+        return next.run(req).await;
+    };
+    match map.check(&key, limit, ClientKey::of(*key_by, &req)).await {
+        Ok(headers) => with_headers(next.run(req).await, headers),
+        Err(resp) => resp,
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::routing::get;
+    use modkit::api::{Missing, OperationBuilder};
+    use tower::ServiceExt;
+
+    fn limited_router() -> Router {
+        let mut builder = OperationBuilder::<Missing, Missing, ()>::get("/limited");
+        builder.require_rate_limit(1, 1, 8);
+        let map =
+            RateLimiterMap::from_specs(&vec![builder.spec().clone()], &ApiGatewayConfig::default())
+                .unwrap();
+        Router::new()
+            .route("/limited", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| rate_limit_middleware(map.clone(), req, next),
+            ))
+    }
+
+    async fn status(app: &Router, client: Option<&str>) -> StatusCode {
+        let mut req = axum::http::Request::builder().uri("/limited");
+        if let Some(ip) = client {
+            req = req.extension(ClientIp(ip.parse().unwrap()));
         }
+        app.clone()
+            .oneshot(req.body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+            .status()
     }
 
-    if let Some(sem) = map.inflight.get(&key) {
-        match sem.clone().try_acquire_owned() {
-            Ok(_permit) => {
-                // Allow request; permit is dropped when response future completes
-                return next.run(req).await;
-            }
-            Err(_) => {
-                return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    #[tokio::test]
+    async fn buckets_are_per_client_ip() {
+        let app = limited_router();
+
+        assert_eq!(status(&app, Some("203.0.113.1")).await, StatusCode::OK);
+        assert_eq!(
+            status(&app, Some("203.0.113.1")).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // Another client behind the same proxy keeps its own budget
+        assert_eq!(status(&app, Some("203.0.113.2")).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn requests_without_client_ip_share_a_bucket() {
+        let app = limited_router();
+
+        assert_eq!(status(&app, None).await, StatusCode::OK);
+        assert_eq!(status(&app, None).await, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(status(&app, Some("203.0.113.1")).await, StatusCode::OK);
+    }
+
+    fn subject_limited_router(key_by: RateLimitKeyBy) -> Router {
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/me")
+            .rate_limit_keyed_by(key_by, 1, 1, 8);
+        let map =
+            RateLimiterMap::from_specs(&vec![builder.spec().clone()], &ApiGatewayConfig::default())
+                .unwrap();
+        let ip_map = map.clone();
+        Router::new()
+            .route("/me", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| {
+                    subject_rate_limit_middleware(map.clone(), req, next)
+                },
+            ))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| rate_limit_middleware(ip_map.clone(), req, next),
+            ))
+    }
+
+    /// Call `/me` from `client`, as `subject` or anonymously.
+    async fn call_as(app: &Router, client: &str, subject: Option<Uuid>) -> StatusCode {
+        let ctx = subject.map_or_else(SecurityContext::anonymous, |subject| {
+            SecurityContext::builder()
+                .subject_id(subject)
+                .subject_tenant_id(Uuid::new_v4())
+                .build()
+                .unwrap()
+        });
+        let req = axum::http::Request::builder()
+            .uri("/me")
+            .extension(ClientIp(client.parse().unwrap()))
+            .extension(ctx)
+            .body(Body::empty())
+            .unwrap();
+        app.clone().oneshot(req).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn per_subject_routes_are_keyed_by_subject() {
+        let app = subject_limited_router(RateLimitKeyBy::SubjectId);
+        let client = "203.0.113.1";
+
+        let alice = Some(Uuid::new_v4());
+        assert_eq!(call_as(&app, client, alice).await, StatusCode::OK);
+        assert_eq!(
+            call_as(&app, client, alice).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        // Same client IP, different subject: own budget, no IP bucket in the way
+        assert_eq!(
+            call_as(&app, client, Some(Uuid::new_v4())).await,
+            StatusCode::OK
+        );
+        // Same subject from another IP still shares the subject's budget
+        assert_eq!(
+            call_as(&app, "203.0.113.2", alice).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn anonymous_requests_fall_back_to_client_ip() {
+        let app = subject_limited_router(RateLimitKeyBy::SubjectId);
+
+        assert_eq!(call_as(&app, "203.0.113.1", None).await, StatusCode::OK);
+        assert_eq!(
+            call_as(&app, "203.0.113.1", None).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(call_as(&app, "203.0.113.2", None).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn ip_and_subject_routes_are_keyed_by_both() {
+        let app = subject_limited_router(RateLimitKeyBy::IpAndSubjectId);
+        let alice = Some(Uuid::new_v4());
+
+        assert_eq!(call_as(&app, "203.0.113.1", alice).await, StatusCode::OK);
+        assert_eq!(
+            call_as(&app, "203.0.113.1", alice).await,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        assert_eq!(call_as(&app, "203.0.113.2", alice).await, StatusCode::OK);
+        assert_eq!(
+            call_as(&app, "203.0.113.1", Some(Uuid::new_v4())).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_authentications_are_limited_per_client_ip() {
+        let builder = OperationBuilder::<Missing, Missing, ()>::get("/me").rate_limit_keyed_by(
+            RateLimitKeyBy::SubjectId,
+            1,
+            2,
+            8,
+        );
+        let map =
+            RateLimiterMap::from_specs(&vec![builder.spec().clone()], &ApiGatewayConfig::default())
+                .unwrap();
+        let ip_map = map.clone();
+        let authn_calls = Arc::new(AtomicU64::new(0));
+        let calls = authn_calls.clone();
+        let app = Router::new()
+            .route("/me", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| {
+                    subject_rate_limit_middleware(map.clone(), req, next)
+                },
+            ))
+            // Stand-in for the AuthN middleware: every token is invalid
+            .layer(axum::middleware::from_fn(
+                move |_req: Request, _next: Next| {
+                    calls.fetch_add(1, Ordering::Relaxed);
+                    async { StatusCode::UNAUTHORIZED.into_response() }
+                },
+            ))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| rate_limit_middleware(ip_map.clone(), req, next),
+            ));
+        let attempt = |client: &'static str| {
+            let req = axum::http::Request::builder()
+                .uri("/me")
+                .header(header::AUTHORIZATION, "Bearer guess")
+                .extension(ClientIp(client.parse().unwrap()))
+                .body(Body::empty())
+                .unwrap();
+            app.clone().oneshot(req)
+        };
+
+        let mut statuses = Vec::new();
+        for _ in 0..10 {
+            statuses.push(attempt("203.0.113.1").await.unwrap().status());
+        }
+
+        // The burst of 2 plus the attempt that found the bucket empty reach AuthN
+        assert_eq!(statuses[..3], [StatusCode::UNAUTHORIZED; 3]);
+        assert!(
+            statuses[3..]
+                .iter()
+                .all(|s| *s == StatusCode::TOO_MANY_REQUESTS),
+            "{statuses:?}"
+        );
+        assert_eq!(authn_calls.load(Ordering::Relaxed), 3);
+
+        let blocked = attempt("203.0.113.1").await.unwrap();
+        assert_eq!(header(&blocked, "Retry-After"), Some(1));
+        assert_eq!(header(&blocked, "RateLimit-Remaining"), Some(0));
+        // Other clients keep their own budget
+        assert_eq!(
+            attempt("203.0.113.2").await.unwrap().status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    fn router_with(map: RateLimiterMap) -> Router {
+        let ip_map = map.clone();
+        Router::new()
+            .route("/limited", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| {
+                    subject_rate_limit_middleware(map.clone(), req, next)
+                },
+            ))
+            .layer(axum::middleware::from_fn(
+                move |req: Request, next: Next| rate_limit_middleware(ip_map.clone(), req, next),
+            ))
+    }
+
+    fn limited_spec(rps: u32, burst: u32) -> Vec<modkit::api::OperationSpec> {
+        let mut builder = OperationBuilder::<Missing, Missing, ()>::get("/limited");
+        builder.require_rate_limit(rps, burst, 8);
+        vec![builder.spec().clone()]
+    }
+
+    async fn call(app: &Router) -> Response {
+        let req = axum::http::Request::builder()
+            .uri("/limited")
+            .extension(ClientIp("203.0.113.1".parse().unwrap()))
+            .body(Body::empty())
+            .unwrap();
+        app.clone().oneshot(req).await.unwrap()
+    }
+
+    fn header(resp: &Response, name: &str) -> Option<u64> {
+        resp.headers()
+            .get(name)
+            .map(|v| v.to_str().unwrap().parse().unwrap())
+    }
+
+    #[tokio::test]
+    async fn responses_carry_rate_limit_headers() {
+        let map =
+            RateLimiterMap::from_specs(&limited_spec(1, 3), &ApiGatewayConfig::default()).unwrap();
+        let app = router_with(map);
+
+        let mut resets = Vec::new();
+        for expected_remaining in [2, 1, 0] {
+            let resp = call(&app).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(header(&resp, "RateLimit-Limit"), Some(3));
+            assert_eq!(
+                header(&resp, "RateLimit-Remaining"),
+                Some(expected_remaining)
+            );
+            assert_eq!(header(&resp, "Retry-After"), None);
+            resets.push(header(&resp, "RateLimit-Reset").unwrap());
+        }
+        // Each token taken pushes the full refill one interval further out
+        assert_eq!(resets, [1, 2, 3]);
+
+        let resp = call(&app).await;
+        assert_eq!(resp.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(header(&resp, "RateLimit-Limit"), Some(3));
+        assert_eq!(header(&resp, "RateLimit-Remaining"), Some(0));
+        assert_eq!(header(&resp, "RateLimit-Reset"), Some(3));
+        assert_eq!(header(&resp, "Retry-After"), Some(1));
+    }
+
+    /// Counts calls and delegates to an in-memory store, or fails every call.
+    #[derive(Default)]
+    struct TestStore {
+        calls: AtomicU64,
+        failing: bool,
+        inner: InMemoryRateLimitStore,
+    }
+
+    #[async_trait]
+    impl RateLimitStore for TestStore {
+        async fn check_and_consume(
+            &self,
+            key: &BucketKey,
+            cost: u32,
+        ) -> Result<Decision, RateLimitStoreError> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            if self.failing {
+                return Err(RateLimitStoreError("connection refused".to_owned()));
             }
+            self.inner.check_and_consume(key, cost).await
         }
     }
 
-    next.run(req).await
+    #[tokio::test]
+    async fn store_is_called_once_per_request() {
+        let subject_spec = OperationBuilder::<Missing, Missing, ()>::get("/limited")
+            .rate_limit_keyed_by(RateLimitKeyBy::SubjectId, 1, 5, 8)
+            .spec()
+            .clone();
+        for specs in [limited_spec(1, 5), vec![subject_spec]] {
+            let store = Arc::new(TestStore::default());
+            let map = RateLimiterMap::from_specs_with_store(
+                &specs,
+                &ApiGatewayConfig::default(),
+                store.clone(),
+            )
+            .unwrap();
+            let app = router_with(map);
+
+            let resp = call(&app).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(header(&resp, "RateLimit-Remaining"), Some(4));
+            assert_eq!(store.calls.load(Ordering::Relaxed), 1);
+        }
+    }
+
+    fn failing_router(on_store_error: RateLimitFailMode) -> Router {
+        let mut cfg = ApiGatewayConfig::default();
+        cfg.defaults.rate_limit.on_store_error = on_store_error;
+        let store = Arc::new(TestStore {
+            failing: true,
+            ..TestStore::default()
+        });
+        router_with(
+            RateLimiterMap::from_specs_with_store(&limited_spec(1, 1), &cfg, store).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn store_errors_let_requests_through_by_default() {
+        let app = failing_router(RateLimitFailMode::default());
+
+        for _ in 0..3 {
+            let resp = call(&app).await;
+            assert_eq!(resp.status(), StatusCode::OK);
+            assert_eq!(header(&resp, "RateLimit-Remaining"), None);
+        }
+    }
+
+    #[tokio::test]
+    async fn store_errors_reject_requests_when_failing_closed() {
+        let app = failing_router(RateLimitFailMode::Closed);
+
+        assert_eq!(call(&app).await.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[test]
+    fn fail_mode_is_configured_in_snake_case() {
+        let cfg: crate::config::RateLimitDefaults =
+            serde_json::from_value(serde_json::json!({ "on_store_error": "closed" })).unwrap();
+        assert_eq!(cfg.on_store_error, RateLimitFailMode::Closed);
+        assert_eq!(cfg.burst, 100);
+    }
 }
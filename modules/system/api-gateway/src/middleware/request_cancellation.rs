@@ -0,0 +1,96 @@
+//! Request cancellation for downstream work.
+//!
+//! Every request gets a [`CancellationToken`] that is cancelled when the request
+//! future is dropped before completion — the client disconnected or the gateway
+//! timeout fired. The token is stored in `Request.extensions` for handlers and
+//! scoped for database runners via
+//! [`modkit_db::secure::with_request_cancellation`], so in-flight queries are
+//! aborted instead of holding pool connections after nobody waits for the result.
+
+use axum::http::Request;
+use axum::{body::Body, middleware::Next, response::Response};
+use tokio_util::sync::CancellationToken;
+
+/// Middleware that cancels the request's token if the request is abandoned.
+pub async fn request_cancellation_middleware(mut req: Request<Body>, next: Next) -> Response {
+    let token = CancellationToken::new();
+    req.extensions_mut().insert(token.clone());
+
+    // Fires on drop; disarmed once the response is produced.
+    let guard = token.clone().drop_guard();
+    let response = modkit_db::secure::with_request_cancellation(token, next.run(req)).await;
+    guard.disarm();
+
+    response
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::routing::get;
+    use std::time::Duration;
+    use tokio::sync::oneshot;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn dropped_request_cancels_token() {
+        let (token_tx, token_rx) = oneshot::channel::<CancellationToken>();
+        let token_tx = std::sync::Arc::new(std::sync::Mutex::new(Some(token_tx)));
+
+        let app = Router::new()
+            .route(
+                "/slow",
+                get(
+                    move |axum::Extension(token): axum::Extension<CancellationToken>| {
+                        if let Some(tx) = token_tx.lock().unwrap().take() {
+                            let _ = tx.send(token);
+                        }
+                        async {
+                            tokio::time::sleep(Duration::from_secs(60)).await;
+                            "done"
+                        }
+                    },
+                ),
+            )
+            .layer(axum::middleware::from_fn(request_cancellation_middleware));
+
+        let req = Request::builder().uri("/slow").body(Body::empty()).unwrap();
+        let call = tokio::spawn(app.oneshot(req));
+        let token = token_rx.await.unwrap();
+        assert!(!token.is_cancelled());
+
+        // Client disconnect: the request future is dropped
+        call.abort();
+        tokio::time::timeout(Duration::from_secs(1), token.cancelled())
+            .await
+            .expect("token must be cancelled when the request is dropped");
+    }
+
+    #[tokio::test]
+    async fn completed_request_keeps_token_alive() {
+        let (token_tx, token_rx) = oneshot::channel::<CancellationToken>();
+        let token_tx = std::sync::Arc::new(std::sync::Mutex::new(Some(token_tx)));
+
+        let app = Router::new()
+            .route(
+                "/fast",
+                get(
+                    move |axum::Extension(token): axum::Extension<CancellationToken>| {
+                        if let Some(tx) = token_tx.lock().unwrap().take() {
+                            let _ = tx.send(token);
+                        }
+                        async { "ok" }
+                    },
+                ),
+            )
+            .layer(axum::middleware::from_fn(request_cancellation_middleware));
+
+        let req = Request::builder().uri("/fast").body(Body::empty()).unwrap();
+        let response = app.oneshot(req).await.unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert!(!token_rx.await.unwrap().is_cancelled());
+    }
+}
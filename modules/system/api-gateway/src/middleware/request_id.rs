@@ -21,7 +21,9 @@ impl MakeRequestId for MakeReqId {
     }
 }
 
-/// Middleware that stores `request_id` in Request.extensions and records it in the current span
+/// Middleware that stores `request_id` in Request.extensions, records it in the current span,
+/// and runs the rest of the request inside [`modkit::http::request_id::scope`] so outgoing
+/// calls (e.g. gRPC via `request_id_interceptor`) can forward it
 pub async fn push_req_id_to_extensions(mut req: Request<Body>, next: Next) -> Response {
     let hdr = header();
     if let Some(rid) = req
@@ -34,6 +36,7 @@ pub async fn push_req_id_to_extensions(mut req: Request<Body>, next: Next) -> Re
         req.extensions_mut().insert(XRequestId(rid.clone()));
         // Record into the current http span (created by TraceLayer)
         tracing::Span::current().record("request_id", rid.as_str());
+        return modkit::http::request_id::scope(rid, next.run(req)).await;
     }
 
     next.run(req).await
@@ -5,16 +5,18 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
-use arc_swap::ArcSwap;
+use arc_swap::{ArcSwap, ArcSwapOption};
 use dashmap::DashMap;
 
-use anyhow::Result;
+use anyhow::{Context as _, Result};
 use axum::http::Method;
 use axum::middleware::from_fn_with_state;
 use axum::{Router, extract::DefaultBodyLimit, middleware::from_fn, routing::get};
 use modkit::api::{OpenApiRegistry, OpenApiRegistryImpl};
 use modkit::lifecycle::ReadySignal;
+use modkit::readiness::ReadinessRegistry;
 use parking_lot::Mutex;
+use serde_json::json;
 use std::net::SocketAddr;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -29,24 +31,39 @@ use authn_resolver_sdk::AuthNResolverClient;
 
 use crate::config::ApiGatewayConfig;
 use crate::middleware::auth;
-use modkit_security::SecurityContext;
-use modkit_security::constants::{DEFAULT_SUBJECT_ID, DEFAULT_TENANT_ID};
+use crate::middleware::rate_limit::{RateLimitStore, RateLimiterMap};
 
+use crate::bootstrap_identity;
+use crate::introspection::{self, GatewayIntrospection, MiddlewareStack};
 use crate::middleware;
+use crate::route_conflicts::{self, RouteConflictReport};
 use crate::router_cache::RouterCache;
 use crate::web;
 
+/// Requests running longer than this are answered with `504 Gateway Timeout`.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Optional dependency providing authentication; not loaded when auth is disabled.
+const AUTHN_RESOLVER: &str = "authn-resolver";
+
 /// Main API Gateway module — owns the HTTP server (`rest_host`) and collects
 /// typed operation specs to emit a single `OpenAPI` document.
 #[modkit::module(
 	name = "api-gateway",
-	capabilities = [rest_host, rest, stateful],
-    deps = ["grpc-hub", "authn-resolver"],
-	lifecycle(entry = "serve", stop_timeout = "30s", await_ready)
+	capabilities = [rest_host, rest, stateful, db],
+    deps = ["grpc-hub", optional("authn-resolver")],
+	lifecycle(
+		entry = "serve",
+		stop_timeout = "30s",
+		await_ready,
+		await_ready_of = ["authn-resolver"],
+		ready_timeout = "30s"
+	)
 )]
 pub struct ApiGateway {
-    // Lock-free config using arc-swap for read-mostly access
-    pub(crate) config: ArcSwap<ApiGatewayConfig>,
+    // Lock-free config using arc-swap for read-mostly access, kept up to date
+    // with the config file by the module's `ConfigHandle`
+    pub(crate) config: Arc<ArcSwap<ApiGatewayConfig>>,
     // OpenAPI registry for operations and schemas
     pub(crate) openapi_registry: Arc<OpenApiRegistryImpl>,
     // Built router cache for zero-lock hot path access
@@ -59,19 +76,34 @@ pub struct ApiGateway {
     // Duplicate detection (per (method, path) and per handler id)
     pub(crate) registered_routes: DashMap<(Method, String), ()>,
     pub(crate) registered_handlers: DashMap<String, ()>,
+    // Registrations rejected as duplicates, kept for the startup conflict report
+    pub(crate) rejected_operations: Mutex<Vec<modkit::api::OperationSpec>>,
+    // TLS certificates loaded during init (None when serving plain HTTP)
+    pub(crate) tls: Mutex<Option<Arc<crate::tls::TlsState>>>,
+    // Module readiness reported by /health (None until init)
+    pub(crate) readiness: Mutex<Option<Arc<ReadinessRegistry>>>,
+    // Middleware stack and config digest of the last built router
+    pub(crate) introspection: Arc<ArcSwapOption<GatewayIntrospection>>,
+    // Shared rate limit buckets (None: a fresh in-memory store per router build)
+    pub(crate) rate_limit_store: Mutex<Option<Arc<dyn RateLimitStore>>>,
 }
 
 impl Default for ApiGateway {
     fn default() -> Self {
         let default_router = Router::new();
         Self {
-            config: ArcSwap::from_pointee(ApiGatewayConfig::default()),
+            config: Arc::new(ArcSwap::from_pointee(ApiGatewayConfig::default())),
             openapi_registry: Arc::new(OpenApiRegistryImpl::new()),
             router_cache: RouterCache::new(default_router),
             final_router: Mutex::new(None),
             authn_client: Mutex::new(None),
             registered_routes: DashMap::new(),
             registered_handlers: DashMap::new(),
+            rejected_operations: Mutex::new(Vec::new()),
+            tls: Mutex::new(None),
+            readiness: Mutex::new(None),
+            introspection: Arc::new(ArcSwapOption::empty()),
+            rate_limit_store: Mutex::new(None),
         }
     }
 }
@@ -82,16 +114,45 @@ impl ApiGateway {
     pub fn new(config: ApiGatewayConfig) -> Self {
         let default_router = Router::new();
         Self {
-            config: ArcSwap::from_pointee(config),
+            config: Arc::new(ArcSwap::from_pointee(config)),
             openapi_registry: Arc::new(OpenApiRegistryImpl::new()),
             router_cache: RouterCache::new(default_router),
             final_router: Mutex::new(None),
             authn_client: Mutex::new(None),
             registered_routes: DashMap::new(),
             registered_handlers: DashMap::new(),
+            rejected_operations: Mutex::new(Vec::new()),
+            tls: Mutex::new(None),
+            readiness: Mutex::new(None),
+            introspection: Arc::new(ArcSwapOption::empty()),
+            rate_limit_store: Mutex::new(None),
         }
     }
 
+    /// `GET /health`, reporting the readiness of every module.
+    fn health_route(&self) -> axum::routing::MethodRouter {
+        let readiness = self.readiness.lock().clone();
+        get(move || web::health_check(readiness.clone()))
+    }
+
+    /// `GET /gateway/v1/introspection`, the middleware stack and config digest.
+    fn introspection_route(&self) -> axum::routing::MethodRouter {
+        let introspection = Arc::clone(&self.introspection);
+        get(move || web::introspection(introspection.load_full()))
+    }
+
+    /// Middleware stack and config digest of the last built router, if any.
+    #[must_use]
+    pub fn introspection(&self) -> Option<Arc<GatewayIntrospection>> {
+        self.introspection.load_full()
+    }
+
+    /// Keep rate limit buckets in `store` instead of the gateway process, e.g. to
+    /// share budgets between replicas. Takes effect on the next router build.
+    pub fn set_rate_limit_store(&self, store: Arc<dyn RateLimitStore>) {
+        *self.rate_limit_store.lock() = Some(store);
+    }
+
     /// Get the current configuration (cheap clone from `ArcSwap`)
     pub fn get_config(&self) -> ApiGatewayConfig {
         (**self.config.load()).clone()
@@ -122,22 +183,26 @@ impl ApiGateway {
         let mut authenticated_routes = std::collections::HashSet::new();
         let mut public_routes = std::collections::HashSet::new();
 
-        // Always mark built-in health check routes as public
+        // Always mark built-in health check routes as public (HEAD resolves as GET)
         public_routes.insert((Method::GET, "/health".to_owned()));
         public_routes.insert((Method::GET, "/healthz".to_owned()));
         public_routes.insert((Method::GET, "/docs".to_owned()));
         public_routes.insert((Method::GET, "/openapi.json".to_owned()));
+        if self.get_cached_config().problems.expose_types {
+            public_routes.insert((Method::GET, "/problem-types".to_owned()));
+        }
+        // Introspection needs a caller identity even without require_auth_by_default
+        authenticated_routes.insert((Method::GET, introspection::INTROSPECTION_PATH.to_owned()));
 
         for spec in &self.openapi_registry.operation_specs {
             let spec = spec.value();
             let route_key = (spec.method.clone(), spec.path.clone());
 
-            if spec.authenticated {
-                authenticated_routes.insert(route_key.clone());
-            }
-
+            // `is_public` always wins: such a route is never added as authenticated.
             if spec.is_public {
                 public_routes.insert(route_key);
+            } else if spec.authenticated {
+                authenticated_routes.insert(route_key);
             }
         }
 
@@ -159,9 +224,12 @@ impl ApiGateway {
     }
 
     /// Apply all middleware layers to a router (request ID, tracing, timeout, body limit, CORS, rate limiting, error mapping, auth)
+    ///
+    /// The layers are recorded as they are added; the resulting report is logged
+    /// and served at `GET /gateway/v1/introspection`.
     pub(crate) fn apply_middleware_stack(
         &self,
-        mut router: Router,
+        router: Router,
         authn_client: Option<Arc<dyn AuthNResolverClient>>,
     ) -> Result<Router> {
         // Build route policy once
@@ -171,11 +239,13 @@ impl ApiGateway {
         // becomes the **outermost** layer and therefore runs **first** on the request path.
         //
         // Desired request execution order (outermost -> innermost):
-        // SetRequestId -> PropagateRequestId -> Trace -> push_req_id_to_extensions
-        // -> Timeout -> BodyLimit -> CORS -> MIME validation -> RateLimit -> ErrorMapping -> Auth -> Router
+        // SecurityHeaders -> SetRequestId -> PropagateRequestId -> Trace -> push_req_id_to_extensions
+        // -> ContextAttributes -> Timeout -> RequestCancellation -> BodyLimit -> CORS -> MIME validation -> RateLimit
+        // -> ErrorMapping -> CatchPanic -> Auth -> LicenseValidation -> Bulkhead -> Router
         //
         // Therefore we must add layers in the reverse order (innermost -> outermost) below.
         // Due future refactoring, this order must be maintained.
+        let mut stack = MiddlewareStack::new(router);
 
         let config = self.get_cached_config();
 
@@ -187,43 +257,110 @@ impl ApiGateway {
             .map(|e| e.value().clone())
             .collect();
 
+        // Shared by the per-route limiter (8) and the per-subject limiter (10b)
+        let rate_store = self.rate_limit_store.lock().clone();
+        let rate_map = match rate_store {
+            Some(store) => RateLimiterMap::from_specs_with_store(&specs, &config, store)?,
+            None => RateLimiterMap::from_specs(&specs, &config)?,
+        };
+
+        // 12) Bulkheads (innermost: only authenticated, licensed requests take a slot,
+        // and offloading to a group runtime covers the handler alone)
+        let bulkhead_map = middleware::bulkhead::BulkheadMap::from_specs(&specs, &config)?;
+        let groups: Vec<String> = bulkhead_map.stats().into_keys().collect();
+        stack = stack.layer_with(
+            "bulkhead",
+            [("groups", json!(groups))],
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = bulkhead_map.clone();
+                    middleware::bulkhead::bulkhead_middleware(map, req, next)
+                },
+            ),
+        );
+
         // 11) License validation
         let license_map = middleware::license_validation::LicenseRequirementMap::from_specs(&specs);
-        router = router.layer(from_fn(
-            move |req: axum::extract::Request, next: axum::middleware::Next| {
-                let map = license_map.clone();
-                middleware::license_validation::license_validation_middleware(map, req, next)
-            },
-        ));
+        stack = stack.layer(
+            "license_validation",
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = license_map.clone();
+                    middleware::license_validation::license_validation_middleware(map, req, next)
+                },
+            ),
+        );
+
+        // 10b) Per-subject rate limits (inner to auth: buckets are keyed by subject)
+        let subject_rate_map = rate_map.clone();
+        stack = stack.layer_with(
+            "subject_rate_limit",
+            [("routes", json!(rate_map.subject_route_count()))],
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = subject_rate_map.clone();
+                    middleware::rate_limit::subject_rate_limit_middleware(map, req, next)
+                },
+            ),
+        );
 
         // 10) Auth
         if config.auth_disabled {
-            // Build security contexts for compatibility during migration
-            let default_security_context = SecurityContext::builder()
-                .subject_id(DEFAULT_SUBJECT_ID)
-                .subject_tenant_id(DEFAULT_TENANT_ID)
-                .build()?;
+            let default_security_context =
+                bootstrap_identity::security_context(&config.auth_disabled_identity)?;
 
             tracing::warn!(
                 "API Gateway auth is DISABLED: all requests will run with default tenant SecurityCtx. \
                  This mode bypasses authentication and is intended ONLY for single-user on-premises deployments without an IdP. \
                  Permission checks and secure ORM still apply. DO NOT use this mode in multi-tenant or production environments."
             );
-            router = router.layer(from_fn(
-                move |mut req: axum::extract::Request, next: axum::middleware::Next| {
-                    let sec_context = default_security_context.clone();
-                    async move {
-                        req.extensions_mut().insert(sec_context);
-                        next.run(req).await
-                    }
-                },
-            ));
+            stack = stack.layer_with(
+                "auth",
+                [("mode", json!("disabled"))],
+                from_fn(
+                    move |mut req: axum::extract::Request, next: axum::middleware::Next| {
+                        let sec_context = default_security_context.clone();
+                        async move {
+                            middleware::internal_auth::strip_forwarded_context(req.headers_mut());
+                            req.extensions_mut().insert(sec_context);
+                            next.run(req).await
+                        }
+                    },
+                ),
+            );
         } else if let Some(client) = authn_client {
+            let api_key_header = auth::api_key_header(&config.auth_mode)?;
             let auth_state = auth::AuthState {
                 authn_client: client,
                 route_policy,
+                client_cert_authn: config
+                    .tls
+                    .as_ref()
+                    .is_some_and(|tls| tls.client_cert_authn && tls.client_ca_path.is_some()),
+                api_key_header,
+                internal_auth: config
+                    .internal_auth
+                    .as_ref()
+                    .map(middleware::internal_auth::InternalAuth::from_config)
+                    .transpose()?
+                    .map(Arc::new),
             };
-            router = router.layer(from_fn_with_state(auth_state, auth::authn_middleware));
+            let mut params = vec![
+                ("client_cert_authn", json!(auth_state.client_cert_authn)),
+                ("internal_auth", json!(auth_state.internal_auth.is_some())),
+            ];
+            match &auth_state.api_key_header {
+                Some(header) => {
+                    params.push(("mode", json!("api_key")));
+                    params.push(("header", json!(header.as_str())));
+                }
+                None => params.push(("mode", json!("bearer"))),
+            }
+            stack = stack.layer_with(
+                "auth",
+                params,
+                from_fn_with_state(auth_state, auth::authn_middleware),
+            );
         } else {
             return Err(anyhow::anyhow!(
                 "auth is enabled but no AuthN Resolver client is available; \
@@ -231,47 +368,113 @@ impl ApiGateway {
             ));
         }
 
-        // 9) Error mapping (outer to auth so it can translate auth/handler errors)
-        router = router.layer(from_fn(modkit::api::error_layer::error_mapping_middleware));
+        // 9b) Panic catching (just inside error mapping: handler panics become 500 Problems)
+        stack = stack.layer(
+            "catch_panic",
+            from_fn(modkit::api::error_layer::catch_panic_middleware),
+        );
+
+        // 9) Error mapping (outer to auth so it can translate auth/handler errors):
+        // completes Problem responses with instance, trace_id and registered titles
+        let instance_template: Arc<str> = Arc::from(config.problems.instance_template.as_str());
+        stack = stack.layer_with(
+            "error_mapping",
+            [("instance_template", json!(&*instance_template))],
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let template = instance_template.clone();
+                    modkit::api::error_layer::problem_context_middleware(template, req, next)
+                },
+            ),
+        );
 
         // 8) Per-route rate limiting & in-flight limits
-        let rate_map = middleware::rate_limit::RateLimiterMap::from_specs(&specs, &config)?;
-        router = router.layer(from_fn(
-            move |req: axum::extract::Request, next: axum::middleware::Next| {
-                let map = rate_map.clone();
-                middleware::rate_limit::rate_limit_middleware(map, req, next)
-            },
-        ));
+        stack = stack.layer_with(
+            "rate_limit",
+            [("routes", json!(rate_map.route_count()))],
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = rate_map.clone();
+                    middleware::rate_limit::rate_limit_middleware(map, req, next)
+                },
+            ),
+        );
 
         // 7) MIME type validation
         let mime_map = middleware::mime_validation::build_mime_validation_map(&specs);
-        router = router.layer(from_fn(
-            move |req: axum::extract::Request, next: axum::middleware::Next| {
-                let map = mime_map.clone();
-                middleware::mime_validation::mime_validation_middleware(map, req, next)
-            },
-        ));
+        stack = stack.layer_with(
+            "mime_validation",
+            [("routes", json!(mime_map.len()))],
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let map = mime_map.clone();
+                    middleware::mime_validation::mime_validation_middleware(map, req, next)
+                },
+            ),
+        );
 
         // 6) CORS (must be outer to auth/limits so OPTIONS preflight short-circuits)
         if config.cors_enabled {
-            router = router.layer(crate::cors::build_cors_layer(&config));
+            let origins = config.cors.clone().unwrap_or_default().allowed_origins;
+            stack = stack.layer_with(
+                "cors",
+                [("allowed_origins", json!(origins))],
+                crate::cors::build_cors_layer(&config),
+            );
         }
 
         // 5) Body limit
-        router = router.layer(RequestBodyLimitLayer::new(config.defaults.body_limit_bytes));
-        router = router.layer(DefaultBodyLimit::max(config.defaults.body_limit_bytes));
+        let body_limit = [("bytes", json!(config.defaults.body_limit_bytes))];
+        stack = stack.layer_with(
+            "request_body_limit",
+            body_limit.clone(),
+            RequestBodyLimitLayer::new(config.defaults.body_limit_bytes),
+        );
+        stack = stack.layer_with(
+            "default_body_limit",
+            body_limit,
+            DefaultBodyLimit::max(config.defaults.body_limit_bytes),
+        );
+
+        // 4b) Request cancellation (inner to Timeout so a gateway timeout also cancels DB work)
+        stack = stack.layer(
+            "request_cancellation",
+            from_fn(middleware::request_cancellation::request_cancellation_middleware),
+        );
 
         // 4) Timeout
-        router = router.layer(TimeoutLayer::with_status_code(
-            axum::http::StatusCode::GATEWAY_TIMEOUT,
-            Duration::from_secs(30),
-        ));
+        stack = stack.layer_with(
+            "timeout",
+            [("secs", json!(REQUEST_TIMEOUT.as_secs()))],
+            TimeoutLayer::with_status_code(
+                axum::http::StatusCode::GATEWAY_TIMEOUT,
+                REQUEST_TIMEOUT,
+            ),
+        );
+
+        // 3b) Request context attributes (client IP, time, user agent) for ABAC policies
+        let trusted_proxies: Arc<[std::net::IpAddr]> = config.trusted_proxies.clone().into();
+        stack = stack.layer_with(
+            "context_attributes",
+            [("trusted_proxies", json!(trusted_proxies.len()))],
+            from_fn(
+                move |req: axum::extract::Request, next: axum::middleware::Next| {
+                    let trusted = trusted_proxies.clone();
+                    middleware::context_attributes::context_attributes_middleware(
+                        trusted, req, next,
+                    )
+                },
+            ),
+        );
 
         // 3) Record request_id into span + extensions (requires span to exist first => must be inner to Trace)
-        router = router.layer(from_fn(middleware::request_id::push_req_id_to_extensions));
+        stack = stack.layer(
+            "request_id_extensions",
+            from_fn(middleware::request_id::push_req_id_to_extensions),
+        );
 
         // 2) Trace (outer to push_req_id_to_extensions)
-        router = router.layer({
+        stack = stack.layer("trace", {
             use modkit_http::otel;
             use tower_http::trace::TraceLayer;
             use tracing::field::Empty;
@@ -330,11 +533,37 @@ impl ApiGateway {
         // 1) Request ID handling
         let x_request_id = crate::middleware::request_id::header();
         // If missing, generate x-request-id first; then propagate it to the response.
-        router = router.layer(PropagateRequestIdLayer::new(x_request_id.clone()));
-        router = router.layer(SetRequestIdLayer::new(
-            x_request_id,
-            crate::middleware::request_id::MakeReqId,
-        ));
+        stack = stack.layer(
+            "propagate_request_id",
+            PropagateRequestIdLayer::new(x_request_id.clone()),
+        );
+        stack = stack.layer(
+            "set_request_id",
+            SetRequestIdLayer::new(x_request_id, crate::middleware::request_id::MakeReqId),
+        );
+
+        // 0) Security headers (outermost: also covers timeouts, body limit and CORS responses)
+        for (header, layer) in
+            crate::security_headers::build_security_header_layers(&config.security_headers)?
+        {
+            stack = stack.layer_with(
+                "security_header",
+                [("header", json!(header.as_str()))],
+                layer,
+            );
+        }
+
+        let (router, report) = stack.finish();
+        let introspection = GatewayIntrospection {
+            middleware: report,
+            config_digest: introspection::config_digest(&config),
+        };
+        tracing::info!(
+            middleware = %introspection.middleware,
+            config_digest = %introspection.config_digest,
+            "API gateway middleware stack"
+        );
+        self.introspection.store(Some(Arc::new(introspection)));
 
         Ok(router)
     }
@@ -356,8 +585,12 @@ impl ApiGateway {
         // In standalone mode (no REST pipeline), register both health endpoints here.
         // In normal operation, rest_prepare() registers these instead.
         let mut router = Router::new()
-            .route("/health", get(web::health_check))
-            .route("/healthz", get(|| async { "ok" }));
+            .route("/health", self.health_route())
+            .route("/healthz", get(|| async { "ok" }))
+            .route(
+                introspection::INTROSPECTION_PATH,
+                self.introspection_route(),
+            );
 
         // Apply all middleware layers including auth, above the router
         let authn_client = self.authn_client.lock().clone();
@@ -374,12 +607,7 @@ impl ApiGateway {
     /// # Errors
     /// Returns an error if `OpenAPI` specification building fails.
     pub fn build_openapi(&self) -> Result<utoipa::openapi::OpenApi> {
-        let config = self.get_cached_config();
-        let info = modkit::api::OpenApiInfo {
-            title: config.openapi.title.clone(),
-            version: config.openapi.version.clone(),
-            description: config.openapi.description,
-        };
+        let info = modkit::contracts::ApiGatewayCapability::openapi_info(self);
         self.openapi_registry.build_openapi(&info)
     }
 
@@ -418,6 +646,14 @@ impl ApiGateway {
 
         // Bind the socket, only now consider the service "ready"
         let listener = tokio::net::TcpListener::bind(addr).await?;
+
+        let tls = self.tls.lock().clone();
+        if let Some(tls) = tls {
+            tracing::info!("HTTPS server bound on {}", addr);
+            ready.notify(); // Starting -> Running
+            return crate::tls::serve(listener, router, tls, cancel).await;
+        }
+
         tracing::info!("HTTP server bound on {}", addr);
         ready.notify(); // Starting -> Running
 
@@ -430,10 +666,14 @@ impl ApiGateway {
             }
         };
 
-        axum::serve(listener, router)
-            .with_graceful_shutdown(shutdown)
-            .await
-            .map_err(|e| anyhow::anyhow!(e))
+        // Connect info provides the peer address for client IP resolution
+        axum::serve(
+            listener,
+            router.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .with_graceful_shutdown(shutdown)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))
     }
 
     /// Check if `handler_id` is already registered (returns true if duplicate)
@@ -468,6 +708,22 @@ impl ApiGateway {
         false
     }
 
+    /// Compile the route conflict report: duplicates rejected at registration,
+    /// overlapping path templates and operations without a 2xx response.
+    pub fn route_conflict_report(&self) -> RouteConflictReport {
+        let mut accepted: Vec<modkit::api::OperationSpec> = self
+            .openapi_registry
+            .operation_specs
+            .iter()
+            .map(|e| e.value().clone())
+            .collect();
+        accepted.sort_by(|a, b| {
+            (a.path.as_str(), a.method.as_str()).cmp(&(b.path.as_str(), b.method.as_str()))
+        });
+        let rejected = self.rejected_operations.lock().clone();
+        route_conflicts::build_report(&accepted, &rejected)
+    }
+
     /// Log successful operation registration
     fn log_operation_registration(&self, spec: &modkit::api::OperationSpec) {
         let current_count = self.openapi_registry.operation_specs.len();
@@ -517,7 +773,7 @@ impl ApiGateway {
                     }
                 }),
             )
-            .route("/docs", get(web::serve_docs));
+            .route("/docs", web::serve_docs(&self.get_cached_config().docs));
 
         #[cfg(feature = "embed_elements")]
         {
@@ -536,22 +792,96 @@ impl ApiGateway {
 impl modkit::Module for ApiGateway {
     async fn init(&self, ctx: &modkit::context::ModuleCtx) -> anyhow::Result<()> {
         debug!("Module initialized with context");
-        let cfg = ctx.config::<crate::config::ApiGatewayConfig>()?;
-        self.config.store(Arc::new(cfg.clone()));
+        let mut config_handle = ctx.config_handle::<crate::config::ApiGatewayConfig>()?;
+        let cfg = (*config_handle.latest()).clone();
+        self.config.store(config_handle.latest());
+        *self.readiness.lock() = Some(ctx.readiness_registry());
 
         debug!(
             "Effective api_gateway configuration:\n{:#?}",
             self.config.load()
         );
 
+        // Nothing below affects the OpenAPI document; a dry run only needs the config
+        if ctx.is_dry_run() {
+            return Ok(());
+        }
+
+        // Settings baked into the router or the listener still need a restart
+        let config = Arc::clone(&self.config);
+        drop(tokio::spawn(async move {
+            while let Some(cfg) = config_handle.changed().await {
+                config.store(cfg);
+                tracing::info!("api-gateway configuration reloaded");
+            }
+        }));
+
+        // Load certificates eagerly so a bad cert/key pair fails startup
+        if let Some(tls_cfg) = &cfg.tls {
+            let tls = crate::tls::TlsState::load(tls_cfg)
+                .context("failed to load api-gateway TLS configuration")?;
+            if tls_cfg.client_cert_authn && tls_cfg.client_ca_path.is_none() {
+                tracing::warn!(
+                    "tls.client_cert_authn is set but tls.client_ca_path is not; client certificates are not requested"
+                );
+            }
+            *self.tls.lock() = Some(Arc::new(tls));
+            tracing::info!(
+                cert_path = %tls_cfg.cert_path.display(),
+                mtls = tls_cfg.client_ca_path.is_some(),
+                "TLS termination enabled"
+            );
+        }
+
+        crate::security_headers::build_security_header_layers(&cfg.security_headers)
+            .context("invalid api-gateway security_headers configuration")?;
+
         if cfg.auth_disabled {
+            let identity = &cfg.auth_disabled_identity;
+            bootstrap_identity::validate(identity)?;
+            if let Some(db) = ctx.db() {
+                bootstrap_identity::verify_fingerprint(&db, identity).await?;
+            } else {
+                tracing::warn!(
+                    "api-gateway has no database; auth_disabled_identity changes cannot be detected"
+                );
+            }
             tracing::info!(
-                tenant_id = %DEFAULT_TENANT_ID,
-                "Auth-disabled mode enabled with default tenant"
+                subject_id = %identity.subject_id,
+                tenant_id = %identity.tenant_id,
+                "Auth-disabled mode enabled with bootstrap identity"
             );
+            if cfg.internal_auth.is_some() {
+                tracing::warn!("internal_auth is ignored while auth is disabled");
+            }
         } else {
+            if let Some(internal) = &cfg.internal_auth {
+                middleware::internal_auth::InternalAuth::from_config(internal)?;
+                ctx.problem_types().register(
+                    middleware::internal_auth::INVALID_FORWARDED_CONTEXT_TYPE,
+                    "Invalid Forwarded Security Context",
+                    axum::http::StatusCode::UNAUTHORIZED,
+                );
+                tracing::info!(
+                    trusted_cidrs = ?internal.trusted_cidrs,
+                    shared_secret = internal.shared_secret_header.is_some(),
+                    "Forwarded SecurityContext accepted from trusted internal callers"
+                );
+            }
+            if let Some(header) = auth::api_key_header(&cfg.auth_mode)? {
+                tracing::info!(header = %header, "API key authentication enabled");
+            }
             // Resolve AuthN Resolver client from ClientHub
-            let authn_client = ctx.client_hub().get::<dyn AuthNResolverClient>()?;
+            let authn_client = match ctx.client_hub().get::<dyn AuthNResolverClient>() {
+                Ok(client) => client,
+                Err(_) if !ctx.dependency_available(AUTHN_RESOLVER) => {
+                    return Err(anyhow::anyhow!(
+                        "auth is enabled but the `{AUTHN_RESOLVER}` module is not loaded; \
+                         add it to the deployment or set `auth_disabled: true`"
+                    ));
+                }
+                Err(e) => return Err(e.into()),
+            };
             *self.authn_client.lock() = Some(authn_client);
             tracing::info!("AuthN Resolver client resolved from ClientHub");
         }
@@ -560,6 +890,13 @@ impl modkit::Module for ApiGateway {
     }
 }
 
+impl modkit::contracts::DatabaseCapability for ApiGateway {
+    fn migrations(&self) -> Vec<Box<dyn sea_orm_migration::MigrationTrait>> {
+        use sea_orm_migration::MigratorTrait;
+        bootstrap_identity::migrations::Migrator::migrations()
+    }
+}
+
 // REST host role: prepare/finalize the router, but do not start the server here.
 impl modkit::contracts::ApiGatewayCapability for ApiGateway {
     fn rest_prepare(
@@ -568,10 +905,11 @@ impl modkit::contracts::ApiGatewayCapability for ApiGateway {
         router: axum::Router,
     ) -> anyhow::Result<axum::Router> {
         // Add health check endpoints:
-        // - /health: detailed JSON response with status and timestamp
+        // - /health: readiness of every module; 503 until all of them are ready
         // - /healthz: simple "ok" liveness probe (Kubernetes-style)
+        // Both answer HEAD too: `get()` serves it with an empty body and the GET headers.
         let router = router
-            .route("/health", get(web::health_check))
+            .route("/health", self.health_route())
             .route("/healthz", get(|| async { "ok" }));
 
         // You may attach global middlewares here (trace, compression, cors), but do not start server.
@@ -586,10 +924,43 @@ impl modkit::contracts::ApiGatewayCapability for ApiGateway {
     ) -> anyhow::Result<axum::Router> {
         let config = self.get_cached_config();
 
+        // All modules have registered by now; report conflicts before serving.
+        let report = self.route_conflict_report();
+        report.log();
+        if config.strict_routes && !report.is_empty() {
+            anyhow::bail!(
+                "strict_routes is enabled; refusing to serve a partially wired API: {report}"
+            );
+        }
+        // A duplicate means some module's route was silently dropped: fail debug
+        // builds outright, and log it as an error in release builds.
+        let duplicates: Vec<String> = report
+            .conflicts
+            .iter()
+            .filter(|c| c.is_duplicate())
+            .map(ToString::to_string)
+            .collect();
+        if !duplicates.is_empty() {
+            let message = format!("Route conflicts detected: {}", duplicates.join("; "));
+            #[cfg(debug_assertions)]
+            anyhow::bail!(message);
+            #[cfg(not(debug_assertions))]
+            tracing::error!("{message}");
+        }
+
         if config.enable_docs {
             router = self.add_openapi_routes(router)?;
         }
 
+        if config.problems.expose_types {
+            router = router.route("/problem-types", get(web::problem_types));
+        }
+
+        router = router.route(
+            introspection::INTROSPECTION_PATH,
+            self.introspection_route(),
+        );
+
         // Apply middleware stack (including auth) to the final router
         tracing::debug!("Applying middleware stack to finalized router");
         let authn_client = self.authn_client.lock().clone();
@@ -605,6 +976,16 @@ impl modkit::contracts::ApiGatewayCapability for ApiGateway {
     fn as_registry(&self) -> &dyn modkit::contracts::OpenApiRegistry {
         self
     }
+
+    fn openapi_info(&self) -> modkit::api::OpenApiInfo {
+        let config = self.get_cached_config();
+        modkit::api::OpenApiInfo {
+            title: config.openapi.title.clone(),
+            version: config.openapi.version.clone(),
+            description: config.openapi.description.clone(),
+            servers: config.openapi.servers.iter().map(Into::into).collect(),
+        }
+    }
 }
 
 impl modkit::contracts::RestApiCapability for ApiGateway {
@@ -623,11 +1004,9 @@ impl modkit::contracts::RestApiCapability for ApiGateway {
 impl OpenApiRegistry for ApiGateway {
     fn register_operation(&self, spec: &modkit::api::OperationSpec) {
         // Reject duplicates with "first wins" policy (second registration = programmer error).
-        if self.check_duplicate_handler(spec) {
-            return;
-        }
-
-        if self.check_duplicate_route(spec) {
+        // Rejected specs are kept for the conflict report emitted in `rest_finalize`.
+        if self.check_duplicate_handler(spec) || self.check_duplicate_route(spec) {
+            self.rejected_operations.lock().push(spec.clone());
             return;
         }
 
@@ -648,6 +1027,14 @@ impl OpenApiRegistry for ApiGateway {
         self.openapi_registry.ensure_schema_raw(root_name, schemas)
     }
 
+    fn ensure_parameter_component(&self, name: &str, spec: modkit::api::ParamSpec) -> String {
+        self.openapi_registry.ensure_parameter_component(name, spec)
+    }
+
+    fn ensure_response_component(&self, name: &str, spec: modkit::api::ResponseSpec) -> String {
+        self.openapi_registry.ensure_response_component(name, spec)
+    }
+
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -680,6 +1067,176 @@ mod tests {
         assert_eq!(info.get("title").unwrap(), "Test API");
         assert_eq!(info.get("version").unwrap(), "1.0.0");
         assert_eq!(info.get("description").unwrap(), "Test Description");
+        assert!(json.get("servers").is_none());
+    }
+
+    #[test]
+    fn test_openapi_servers_from_config() {
+        let config: ApiGatewayConfig = serde_json::from_value(serde_json::json!({
+            "bind_addr": "127.0.0.1:8080",
+            "openapi": {
+                "title": "Test API",
+                "version": "1.0.0",
+                "servers": [
+                    { "url": "https://staging.example.com", "description": "Staging" },
+                    { "url": "https://api.example.com" },
+                ],
+            }
+        }))
+        .unwrap();
+        let api = ApiGateway::new(config);
+
+        let json = serde_json::to_value(api.build_openapi().unwrap()).unwrap();
+        assert_eq!(
+            json["servers"],
+            serde_json::json!([
+                { "url": "https://staging.example.com", "description": "Staging" },
+                { "url": "https://api.example.com" },
+            ])
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod introspection_tests {
+    use super::*;
+    use authn_resolver_sdk::{AuthNResolverError, AuthenticationResult};
+    use axum::body::Body;
+    use tower::ServiceExt;
+
+    struct RejectingAuthN;
+
+    #[async_trait]
+    impl AuthNResolverClient for RejectingAuthN {
+        async fn authenticate(
+            &self,
+            _bearer_token: &str,
+        ) -> Result<AuthenticationResult, AuthNResolverError> {
+            Err(AuthNResolverError::Unauthorized("test".to_owned()))
+        }
+    }
+
+    fn gateway(cors_enabled: bool, auth_disabled: bool) -> ApiGateway {
+        ApiGateway::new(ApiGatewayConfig {
+            bind_addr: "127.0.0.1:0".to_owned(),
+            cors_enabled,
+            auth_disabled,
+            ..Default::default()
+        })
+    }
+
+    fn report(api: &ApiGateway) -> introspection::MiddlewareStackReport {
+        let client: Arc<dyn AuthNResolverClient> = Arc::new(RejectingAuthN);
+        api.apply_middleware_stack(Router::new(), Some(client))
+            .unwrap();
+        api.introspection().unwrap().middleware.clone()
+    }
+
+    #[test]
+    fn report_lists_layers_in_request_order() {
+        let report = report(&gateway(true, false));
+
+        assert_eq!(
+            report.names(),
+            vec![
+                "security_header",
+                "set_request_id",
+                "propagate_request_id",
+                "trace",
+                "request_id_extensions",
+                "context_attributes",
+                "timeout",
+                "request_cancellation",
+                "default_body_limit",
+                "request_body_limit",
+                "cors",
+                "mime_validation",
+                "rate_limit",
+                "error_mapping",
+                "catch_panic",
+                "auth",
+                "subject_rate_limit",
+                "license_validation",
+                "bulkhead",
+            ]
+        );
+        let timeout = report.layer("timeout").unwrap();
+        assert_eq!(timeout.params["secs"], json!(30));
+        let body_limit = report.layer("request_body_limit").unwrap();
+        assert_eq!(body_limit.params["bytes"], json!(16 * 1024 * 1024));
+        assert_eq!(
+            report.layer("auth").unwrap().params["mode"],
+            json!("bearer")
+        );
+    }
+
+    #[test]
+    fn report_follows_cors_and_auth_settings() {
+        let report = report(&gateway(false, true));
+
+        assert!(report.layer("cors").is_none());
+        assert_eq!(
+            report.layer("auth").unwrap().params["mode"],
+            json!("disabled")
+        );
+    }
+
+    #[test]
+    fn digest_changes_with_config() {
+        let api = gateway(false, true);
+        report(&api);
+        let digest = api.introspection().unwrap().config_digest.clone();
+
+        let mut config = api.get_config();
+        config.defaults.body_limit_bytes = 1024;
+        let other = ApiGateway::new(config);
+        report(&other);
+
+        assert_ne!(other.introspection().unwrap().config_digest, digest);
+        assert_eq!(
+            other
+                .introspection()
+                .unwrap()
+                .middleware
+                .layer("request_body_limit")
+                .unwrap()
+                .params["bytes"],
+            json!(1024)
+        );
+    }
+
+    #[tokio::test]
+    async fn endpoint_serves_the_report() {
+        let api = gateway(false, true);
+        let router =
+            Router::new().route(introspection::INTROSPECTION_PATH, api.introspection_route());
+        let router = api.apply_middleware_stack(router, None).unwrap();
+
+        let response = router
+            .oneshot(
+                axum::http::Request::get(introspection::INTROSPECTION_PATH)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), http::StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let expected = api.introspection().unwrap();
+        assert_eq!(json["config_digest"], json!(expected.config_digest));
+        assert_eq!(
+            json["middleware"]["layers"][0]["name"],
+            json!("security_header")
+        );
+        assert_eq!(
+            json["middleware"]["layers"].as_array().map(Vec::len),
+            Some(expected.middleware.layers.len())
+        );
     }
 }
 
@@ -702,6 +1259,7 @@ mod problem_openapi_tests {
 
         // Build a route with a problem+json response
         let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/problem-demo")
+            .operation_id("test.problem_demo")
             .public()
             .summary("Problem demo")
             .problem_response(&api, http::StatusCode::BAD_REQUEST, "Bad Request") // <-- registers Problem + sets content type
@@ -773,6 +1331,7 @@ mod sse_openapi_tests {
         let router = axum::Router::new();
 
         let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/demo/sse")
+            .operation_id("test.demo_sse")
             .summary("Demo SSE")
             .handler(sse_handler)
             .public()
@@ -806,6 +1365,7 @@ mod sse_openapi_tests {
         let router = axum::Router::new();
 
         let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/demo/mixed")
+            .operation_id("test.demo_mixed")
             .summary("Mixed responses")
             .public()
             .handler(mixed_handler)
@@ -846,6 +1406,7 @@ mod sse_openapi_tests {
         let router = axum::Router::new();
 
         let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/users/{id}")
+            .operation_id("test.get_user")
             .summary("Get user by ID")
             .public()
             .path_param("id", "User ID")
@@ -886,6 +1447,7 @@ mod sse_openapi_tests {
         let _router = OperationBuilder::<Missing, Missing, ()>::get(
             "/tests/v1/projects/{project_id}/items/{item_id}",
         )
+        .operation_id("test.get_project_item")
         .summary("Get project item")
         .public()
         .path_param("project_id", "Project ID")
@@ -927,6 +1489,7 @@ mod sse_openapi_tests {
 
         // Axum 0.8 uses {*path} for wildcards
         let _router = OperationBuilder::<Missing, Missing, ()>::get("/tests/v1/static/{*path}")
+            .operation_id("test.static_files")
             .summary("Serve static files")
             .public()
             .handler(static_handler)
@@ -0,0 +1,407 @@
+//! Startup route conflict analysis.
+//!
+//! After all modules have registered their REST operations, the gateway compiles a
+//! [`RouteConflictReport`] listing:
+//! - duplicate `(method, path)` registrations and duplicate handler ids (rejected with
+//!   "first wins" at registration time),
+//! - path templates that overlap for the same method (e.g. `/users/{id}` vs `/users/me`),
+//! - operations that declare no 2xx response.
+//!
+//! With `strict_routes: true` a non-empty report fails `rest_finalize`.
+
+use std::fmt;
+
+use axum::http::Method;
+use modkit::api::OperationSpec;
+
+/// A single problem found in the registered route table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RouteConflict {
+    /// The same `(method, path)` was registered more than once; only the first was kept.
+    DuplicateRoute {
+        method: Method,
+        path: String,
+        /// Handler ids of every registration, in registration order.
+        handler_ids: Vec<String>,
+        /// Operation ids of every registration, in registration order (when set).
+        operation_ids: Vec<String>,
+    },
+    /// Two different routes produced the same handler id; only the first was kept.
+    DuplicateHandler {
+        handler_id: String,
+        /// `(method, path)` of the kept registration.
+        kept: (Method, String),
+        /// `(method, path)` of the rejected registration.
+        rejected: (Method, String),
+    },
+    /// Two templates for the same method can match the same request path.
+    Overlap {
+        method: Method,
+        /// Template that wins: the one with a literal segment at the first position
+        /// where the other has a parameter.
+        preferred: String,
+        /// Template that is shadowed for the overlapping paths.
+        shadowed: String,
+    },
+    /// The operation declares no 2xx response.
+    MissingSuccessResponse {
+        method: Method,
+        path: String,
+        handler_id: String,
+    },
+}
+
+impl fmt::Display for RouteConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateRoute {
+                method,
+                path,
+                handler_ids,
+                operation_ids,
+            } => {
+                write!(
+                    f,
+                    "duplicate route {method} {path} (handler_ids: [{}]",
+                    handler_ids.join(", ")
+                )?;
+                if !operation_ids.is_empty() {
+                    write!(f, ", operation_ids: [{}]", operation_ids.join(", "))?;
+                }
+                write!(f, ")")
+            }
+            Self::DuplicateHandler {
+                handler_id,
+                kept,
+                rejected,
+            } => write!(
+                f,
+                "duplicate handler_id {handler_id}: {} {} kept, {} {} rejected",
+                kept.0, kept.1, rejected.0, rejected.1
+            ),
+            Self::Overlap {
+                method,
+                preferred,
+                shadowed,
+            } => write!(
+                f,
+                "overlapping templates for {method}: {preferred} takes precedence over {shadowed}"
+            ),
+            Self::MissingSuccessResponse {
+                method,
+                path,
+                handler_id,
+            } => write!(
+                f,
+                "operation {method} {path} ({handler_id}) declares no 2xx response"
+            ),
+        }
+    }
+}
+
+impl RouteConflict {
+    /// Returns `true` for a registration that was rejected as a duplicate.
+    #[must_use]
+    pub fn is_duplicate(&self) -> bool {
+        matches!(
+            self,
+            Self::DuplicateRoute { .. } | Self::DuplicateHandler { .. }
+        )
+    }
+}
+
+/// All route conflicts found at startup.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RouteConflictReport {
+    pub conflicts: Vec<RouteConflict>,
+}
+
+impl RouteConflictReport {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+
+    #[must_use]
+    pub fn duplicates(&self) -> usize {
+        self.count(RouteConflict::is_duplicate)
+    }
+
+    #[must_use]
+    pub fn overlaps(&self) -> usize {
+        self.count(|c| matches!(c, RouteConflict::Overlap { .. }))
+    }
+
+    #[must_use]
+    pub fn missing_success_responses(&self) -> usize {
+        self.count(|c| matches!(c, RouteConflict::MissingSuccessResponse { .. }))
+    }
+
+    fn count(&self, pred: impl Fn(&RouteConflict) -> bool) -> usize {
+        self.conflicts.iter().filter(|c| pred(c)).count()
+    }
+
+    /// Log the report as a structured summary plus one line per conflict.
+    pub fn log(&self) {
+        if self.is_empty() {
+            tracing::debug!("Route conflict report: no conflicts");
+            return;
+        }
+        tracing::warn!(
+            total = self.conflicts.len(),
+            duplicates = self.duplicates(),
+            overlaps = self.overlaps(),
+            missing_success_responses = self.missing_success_responses(),
+            "Route conflict report"
+        );
+        for conflict in &self.conflicts {
+            tracing::warn!(conflict = %conflict, "Route conflict");
+        }
+    }
+}
+
+impl fmt::Display for RouteConflictReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} route conflict(s):", self.conflicts.len())?;
+        for conflict in &self.conflicts {
+            write!(f, "\n  - {conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Build the report from the accepted operations and the registrations rejected as duplicates.
+///
+/// Conflicts are reported in the order of the input slices.
+#[must_use]
+pub fn build_report(accepted: &[OperationSpec], rejected: &[OperationSpec]) -> RouteConflictReport {
+    // Duplicates: group rejected registrations under the accepted one they collided with.
+    let mut duplicate_routes: Vec<RouteConflict> = Vec::new();
+    let mut duplicate_handlers: Vec<RouteConflict> = Vec::new();
+    for dup in rejected {
+        if let Some(kept) = accepted
+            .iter()
+            .find(|s| s.method == dup.method && s.path == dup.path)
+        {
+            let existing = duplicate_routes.iter_mut().find(|c| {
+                matches!(c, RouteConflict::DuplicateRoute { method, path, .. }
+                    if *method == dup.method && *path == dup.path)
+            });
+            if let Some(RouteConflict::DuplicateRoute {
+                handler_ids,
+                operation_ids,
+                ..
+            }) = existing
+            {
+                handler_ids.push(dup.handler_id.clone());
+                operation_ids.extend(dup.operation_id.clone());
+            } else {
+                duplicate_routes.push(RouteConflict::DuplicateRoute {
+                    method: dup.method.clone(),
+                    path: dup.path.clone(),
+                    handler_ids: vec![kept.handler_id.clone(), dup.handler_id.clone()],
+                    operation_ids: kept
+                        .operation_id
+                        .iter()
+                        .chain(dup.operation_id.iter())
+                        .cloned()
+                        .collect(),
+                });
+            }
+        } else if let Some(kept) = accepted.iter().find(|s| s.handler_id == dup.handler_id) {
+            duplicate_handlers.push(RouteConflict::DuplicateHandler {
+                handler_id: dup.handler_id.clone(),
+                kept: (kept.method.clone(), kept.path.clone()),
+                rejected: (dup.method.clone(), dup.path.clone()),
+            });
+        }
+    }
+    let mut conflicts = duplicate_routes;
+    conflicts.append(&mut duplicate_handlers);
+
+    // Overlaps between accepted templates of the same method.
+    for (i, a) in accepted.iter().enumerate() {
+        for b in &accepted[i + 1..] {
+            if a.method != b.method || a.path == b.path {
+                continue;
+            }
+            if let Some(a_wins) = compare_templates(&a.path, &b.path) {
+                let (preferred, shadowed) = if a_wins { (a, b) } else { (b, a) };
+                conflicts.push(RouteConflict::Overlap {
+                    method: a.method.clone(),
+                    preferred: preferred.path.clone(),
+                    shadowed: shadowed.path.clone(),
+                });
+            }
+        }
+    }
+
+    // Operations without any success response.
+    for spec in accepted {
+        if !spec
+            .responses
+            .iter()
+            .any(|r| (200..300).contains(&r.status))
+        {
+            conflicts.push(RouteConflict::MissingSuccessResponse {
+                method: spec.method.clone(),
+                path: spec.path.clone(),
+                handler_id: spec.handler_id.clone(),
+            });
+        }
+    }
+
+    RouteConflictReport { conflicts }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment<'a> {
+    Literal(&'a str),
+    Param,
+    CatchAll,
+}
+
+fn parse_template(path: &str) -> Vec<Segment<'_>> {
+    path.split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if s.starts_with("{*") && s.ends_with('}') {
+                Segment::CatchAll
+            } else if s.starts_with('{') && s.ends_with('}') {
+                Segment::Param
+            } else {
+                Segment::Literal(s)
+            }
+        })
+        .collect()
+}
+
+/// Compare two distinct path templates.
+///
+/// Returns `None` if no request path can match both, otherwise `Some(true)` if `a`
+/// takes precedence and `Some(false)` if `b` does. A literal segment beats a
+/// parameter, and a parameter beats a catch-all, at the first position where the
+/// templates differ.
+pub(crate) fn compare_templates(a: &str, b: &str) -> Option<bool> {
+    let a = parse_template(a);
+    let b = parse_template(b);
+    let mut preferred: Option<bool> = None;
+
+    for idx in 0..=a.len().max(b.len()) {
+        match (a.get(idx), b.get(idx)) {
+            (None, None) => break,
+            // Catch-all matches one or more remaining segments.
+            (Some(Segment::CatchAll), Some(_)) => return Some(preferred.unwrap_or(false)),
+            (Some(_), Some(Segment::CatchAll)) => return Some(preferred.unwrap_or(true)),
+            // Different lengths without a catch-all never match the same path.
+            (None, Some(_)) | (Some(_), None) => return None,
+            (Some(Segment::Literal(x)), Some(Segment::Literal(y))) => {
+                if x != y {
+                    return None;
+                }
+            }
+            (Some(Segment::Literal(_)), Some(_)) => {
+                preferred.get_or_insert(true);
+            }
+            (Some(_), Some(Segment::Literal(_))) => {
+                preferred.get_or_insert(false);
+            }
+            (Some(_), Some(_)) => {}
+        }
+    }
+
+    // Same shape with parameters only at identical positions (e.g. `{id}` vs `{user_id}`):
+    // the router keeps whichever was registered first.
+    Some(preferred.unwrap_or(true))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use modkit::api::{Missing, OperationBuilder, ResponseSpec};
+
+    fn spec(method: Method, path: &str, success: bool) -> OperationSpec {
+        let mut spec = OperationBuilder::<Missing, Missing, ()>::new(method, path)
+            .spec()
+            .clone();
+        if success {
+            spec.responses.push(ResponseSpec {
+                status: 200,
+                content_type: "application/json",
+                description: "ok".to_owned(),
+                schema_name: None,
+                example: None,
+                component: None,
+            });
+        }
+        spec
+    }
+
+    #[test]
+    fn literal_beats_parameter() {
+        assert_eq!(compare_templates("/users/{id}", "/users/me"), Some(false));
+        assert_eq!(compare_templates("/users/me", "/users/{id}"), Some(true));
+    }
+
+    #[test]
+    fn first_divergence_decides_precedence() {
+        assert_eq!(compare_templates("/a/{x}/c", "/a/b/{y}"), Some(false));
+    }
+
+    #[test]
+    fn disjoint_templates_do_not_overlap() {
+        assert_eq!(
+            compare_templates("/users/{id}", "/users/{id}/address"),
+            None
+        );
+        assert_eq!(compare_templates("/users/me", "/cities/me"), None);
+    }
+
+    #[test]
+    fn catch_all_overlaps_longer_paths() {
+        assert_eq!(
+            compare_templates("/static/{*path}", "/static/app.js"),
+            Some(false)
+        );
+        assert_eq!(compare_templates("/static/{*path}", "/assets/app.js"), None);
+    }
+
+    #[test]
+    fn report_lists_duplicates_overlaps_and_missing_success() {
+        let accepted = vec![
+            spec(Method::GET, "/users/{id}", true),
+            spec(Method::GET, "/users/me", true),
+            spec(Method::POST, "/users/{id}", false),
+        ];
+        let rejected = vec![spec(Method::GET, "/users/{id}", true)];
+
+        let report = build_report(&accepted, &rejected);
+
+        assert_eq!(report.duplicates(), 1);
+        assert_eq!(report.overlaps(), 1);
+        assert_eq!(report.missing_success_responses(), 1);
+        assert!(report.conflicts.contains(&RouteConflict::Overlap {
+            method: Method::GET,
+            preferred: "/users/me".to_owned(),
+            shadowed: "/users/{id}".to_owned(),
+        }));
+        let rendered = report.to_string();
+        assert!(rendered.starts_with("3 route conflict(s):"), "{rendered}");
+        assert!(
+            rendered.contains("duplicate route GET /users/{id}"),
+            "{rendered}"
+        );
+    }
+
+    #[test]
+    fn clean_table_has_empty_report() {
+        let accepted = vec![
+            spec(Method::GET, "/users", true),
+            spec(Method::POST, "/users", true),
+            spec(Method::GET, "/users/{id}", true),
+        ];
+
+        assert!(build_report(&accepted, &[]).is_empty());
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::{Context as _, Result};
+use axum::http::{HeaderName, HeaderValue, header};
+use tower_http::set_header::SetResponseHeaderLayer;
+
+use crate::config::SecurityHeadersConfig;
+
+fn security_headers(cfg: &SecurityHeadersConfig) -> Result<Vec<(HeaderName, HeaderValue)>> {
+    let mut headers = Vec::new();
+
+    if let Some(max_age) = cfg.hsts_max_age {
+        headers.push((
+            header::STRICT_TRANSPORT_SECURITY,
+            HeaderValue::from_str(&format!("max-age={max_age}"))?,
+        ));
+    }
+    if cfg.x_content_type_options {
+        headers.push((
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        ));
+    }
+    if let Some(value) = &cfg.x_frame_options {
+        headers.push((
+            header::X_FRAME_OPTIONS,
+            HeaderValue::from_str(value)
+                .with_context(|| format!("invalid security_headers.x_frame_options: {value:?}"))?,
+        ));
+    }
+    if let Some(value) = &cfg.referrer_policy {
+        headers.push((
+            header::REFERRER_POLICY,
+            HeaderValue::from_str(value)
+                .with_context(|| format!("invalid security_headers.referrer_policy: {value:?}"))?,
+        ));
+    }
+
+    Ok(headers)
+}
+
+/// One layer per configured header, with the header it sets; values set by
+/// handlers take precedence.
+///
+/// # Errors
+/// Returns an error if a configured value is not a valid header value.
+pub fn build_security_header_layers(
+    cfg: &SecurityHeadersConfig,
+) -> Result<Vec<(HeaderName, SetResponseHeaderLayer<HeaderValue>)>> {
+    Ok(security_headers(cfg)?
+        .into_iter()
+        .map(|(name, value)| {
+            let layer = SetResponseHeaderLayer::if_not_present(name.clone(), value);
+            (name, layer)
+        })
+        .collect())
+}
@@ -0,0 +1,652 @@
+//! Native TLS termination for the gateway HTTP server.
+//!
+//! - Certificates are loaded and validated eagerly ([`TlsState::load`]) so that a
+//!   bad cert/key pair fails startup instead of the first handshake.
+//! - The active `rustls::ServerConfig` lives in an `ArcSwap`; every accepted
+//!   connection takes the current one, so a reload (on `SIGHUP` or when the files
+//!   change) only affects new connections.
+//! - With mTLS enabled, the verified client certificate is exposed to handlers as
+//!   a [`ClientCertificate`] request extension.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context as _;
+use arc_swap::ArcSwap;
+use axum::Router;
+use axum::extract::ConnectInfo;
+use hyper::body::Incoming;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto;
+use hyper_util::server::graceful::GracefulShutdown;
+use parking_lot::Mutex;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::sign::{CertifiedKey, SingleCertAndKey};
+use rustls::{RootCertStore, ServerConfig};
+use tokio::net::TcpListener;
+use tokio_rustls::TlsAcceptor;
+use tokio_util::sync::CancellationToken;
+use tower::Service as _;
+
+use authn_resolver_sdk::MTLS_CREDENTIAL_PREFIX;
+
+use crate::config::{TlsConfig, TlsVersion};
+
+/// Upper bound for a client to complete the TLS handshake.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Verified TLS client certificate of the current connection.
+///
+/// Inserted as a request extension when the gateway terminates mTLS and the
+/// client presented a certificate trusted by `tls.client_ca_path`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCertificate {
+    /// Subject distinguished name, e.g. `CN=billing-service, O=Example`
+    pub subject: String,
+    /// Subject alternative names, prefixed by kind: `DNS:`, `IP:`, `URI:` or `email:`
+    pub subject_alt_names: Vec<String>,
+}
+
+impl ClientCertificate {
+    /// Parse subject and SANs from a DER-encoded certificate.
+    ///
+    /// # Errors
+    /// Returns an error if the certificate cannot be parsed.
+    pub fn from_der(der: &[u8]) -> anyhow::Result<Self> {
+        use x509_parser::extensions::GeneralName;
+
+        let (_, cert) = x509_parser::parse_x509_certificate(der)
+            .map_err(|e| anyhow::anyhow!("invalid client certificate: {e}"))?;
+
+        let subject_alt_names = cert
+            .subject_alternative_name()
+            .map_err(|e| anyhow::anyhow!("invalid subjectAltName extension: {e}"))?
+            .map(|ext| {
+                ext.value
+                    .general_names
+                    .iter()
+                    .filter_map(|name| match name {
+                        GeneralName::DNSName(dns) => Some(format!("DNS:{dns}")),
+                        GeneralName::URI(uri) => Some(format!("URI:{uri}")),
+                        GeneralName::RFC822Name(email) => Some(format!("email:{email}")),
+                        GeneralName::IPAddress(bytes) => {
+                            ip_from_bytes(bytes).map(|ip| format!("IP:{ip}"))
+                        }
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            subject: cert.subject().to_string(),
+            subject_alt_names,
+        })
+    }
+
+    /// Credential passed to the `AuthN` Resolver for this certificate (`mtls:<subject>`).
+    #[must_use]
+    pub fn credential(&self) -> String {
+        format!("{MTLS_CREDENTIAL_PREFIX}{}", self.subject)
+    }
+}
+
+fn ip_from_bytes(bytes: &[u8]) -> Option<IpAddr> {
+    match bytes.len() {
+        4 => <[u8; 4]>::try_from(bytes).ok().map(IpAddr::from),
+        16 => <[u8; 16]>::try_from(bytes).ok().map(IpAddr::from),
+        _ => None,
+    }
+}
+
+/// Loaded TLS configuration with hot reload support.
+pub(crate) struct TlsState {
+    config: TlsConfig,
+    server_config: ArcSwap<ServerConfig>,
+    // Modification stamps of the loaded files, used to detect changes
+    stamps: Mutex<Vec<Option<FileStamp>>>,
+}
+
+type FileStamp = (SystemTime, u64);
+
+impl TlsState {
+    /// Load and validate certificates, key and client CA bundle.
+    ///
+    /// # Errors
+    /// Returns an error if a file cannot be read or parsed, or the key does not
+    /// match the certificate.
+    pub(crate) fn load(config: &TlsConfig) -> anyhow::Result<Self> {
+        let stamps = file_stamps(config);
+        let server_config = build_server_config(config)?;
+        Ok(Self {
+            config: config.clone(),
+            server_config: ArcSwap::from_pointee(server_config),
+            stamps: Mutex::new(stamps),
+        })
+    }
+
+    /// Acceptor for a new connection, using the currently loaded certificates.
+    pub(crate) fn acceptor(&self) -> TlsAcceptor {
+        TlsAcceptor::from(self.server_config.load_full())
+    }
+
+    /// Whether the verified client certificate should be used for authentication.
+    pub(crate) fn client_cert_authn(&self) -> bool {
+        self.config.client_cert_authn && self.config.client_ca_path.is_some()
+    }
+
+    /// Reload certificates from disk. On error the previous certificates stay active.
+    ///
+    /// # Errors
+    /// Returns an error if the new files are invalid.
+    pub(crate) fn reload(&self) -> anyhow::Result<()> {
+        *self.stamps.lock() = file_stamps(&self.config);
+        let server_config = build_server_config(&self.config)?;
+        self.server_config.store(Arc::new(server_config));
+        tracing::info!(
+            cert_path = %self.config.cert_path.display(),
+            "TLS certificates reloaded"
+        );
+        Ok(())
+    }
+
+    /// Reload if any of the configured files changed since the last (attempted) load.
+    ///
+    /// Returns `true` if a reload was attempted.
+    pub(crate) fn reload_if_changed(&self) -> bool {
+        if *self.stamps.lock() == file_stamps(&self.config) {
+            return false;
+        }
+        self.reload_and_log();
+        true
+    }
+
+    fn reload_and_log(&self) {
+        if let Err(e) = self.reload() {
+            tracing::error!(error = %format!("{e:#}"), "TLS reload failed; keeping previous certificates");
+        }
+    }
+
+    /// Reload on `SIGHUP` and, if enabled, when the files change, until cancelled.
+    pub(crate) async fn watch(self: Arc<Self>, cancel: CancellationToken) {
+        let mut hangup = HangupSignal::new();
+        let mut poll = (self.config.reload_interval_secs > 0).then(|| {
+            let period = Duration::from_secs(self.config.reload_interval_secs);
+            tokio::time::interval_at(tokio::time::Instant::now() + period, period)
+        });
+
+        loop {
+            tokio::select! {
+                () = cancel.cancelled() => return,
+                () = hangup.recv() => {
+                    tracing::info!("SIGHUP received, reloading TLS certificates");
+                    self.reload_and_log();
+                }
+                () = tick(poll.as_mut()) => {
+                    self.reload_if_changed();
+                }
+            }
+        }
+    }
+}
+
+async fn tick(interval: Option<&mut tokio::time::Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+/// `SIGHUP` listener; never fires on non-Unix platforms.
+struct HangupSignal {
+    #[cfg(unix)]
+    inner: Option<tokio::signal::unix::Signal>,
+}
+
+impl HangupSignal {
+    fn new() -> Self {
+        #[cfg(unix)]
+        {
+            let inner = tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup())
+                .inspect_err(|e| tracing::warn!(error = %e, "Cannot listen for SIGHUP; TLS reload on signal disabled"))
+                .ok();
+            Self { inner }
+        }
+        #[cfg(not(unix))]
+        {
+            Self {}
+        }
+    }
+
+    async fn recv(&mut self) {
+        #[cfg(unix)]
+        if let Some(signal) = self.inner.as_mut() {
+            if signal.recv().await.is_some() {
+                return;
+            }
+            self.inner = None;
+        }
+        std::future::pending::<()>().await;
+    }
+}
+
+fn file_stamps(config: &TlsConfig) -> Vec<Option<FileStamp>> {
+    let stamp = |path: &Path| {
+        let meta = std::fs::metadata(path).ok()?;
+        Some((meta.modified().ok()?, meta.len()))
+    };
+    [
+        Some(config.cert_path.as_path()),
+        Some(config.key_path.as_path()),
+        config.client_ca_path.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(stamp)
+    .collect()
+}
+
+/// Build a `rustls` server configuration from the TLS settings.
+///
+/// # Errors
+/// Returns an error naming the offending file if anything cannot be loaded.
+pub(crate) fn build_server_config(config: &TlsConfig) -> anyhow::Result<ServerConfig> {
+    let provider = Arc::new(rustls::crypto::aws_lc_rs::default_provider());
+
+    let certs = load_certs(&config.cert_path)?;
+    let key = PrivateKeyDer::from_pem_file(&config.key_path).with_context(|| {
+        format!(
+            "failed to read TLS private key from '{}'",
+            config.key_path.display()
+        )
+    })?;
+    let certified_key = CertifiedKey::from_der(certs, key, &provider).with_context(|| {
+        format!(
+            "TLS private key '{}' does not match certificate '{}'",
+            config.key_path.display(),
+            config.cert_path.display()
+        )
+    })?;
+
+    let versions: &[&'static rustls::SupportedProtocolVersion] = match config.min_version {
+        TlsVersion::Tls12 => &[&rustls::version::TLS13, &rustls::version::TLS12],
+        TlsVersion::Tls13 => &[&rustls::version::TLS13],
+    };
+    let builder = ServerConfig::builder_with_provider(Arc::clone(&provider))
+        .with_protocol_versions(versions)
+        .context("unsupported TLS protocol versions")?;
+
+    let builder = if let Some(ca_path) = &config.client_ca_path {
+        let mut roots = RootCertStore::empty();
+        for cert in load_certs(ca_path)? {
+            roots.add(cert).with_context(|| {
+                format!("invalid client CA certificate in '{}'", ca_path.display())
+            })?;
+        }
+        let verifier = WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider);
+        let verifier = if config.client_cert_required {
+            verifier
+        } else {
+            verifier.allow_unauthenticated()
+        };
+        let verifier = verifier
+            .build()
+            .context("failed to build TLS client certificate verifier")?;
+        builder.with_client_cert_verifier(verifier)
+    } else {
+        builder.with_no_client_auth()
+    };
+
+    let mut server_config =
+        builder.with_cert_resolver(Arc::new(SingleCertAndKey::from(certified_key)));
+    server_config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    Ok(server_config)
+}
+
+fn load_certs(path: &Path) -> anyhow::Result<Vec<CertificateDer<'static>>> {
+    let certs = CertificateDer::pem_file_iter(path)
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .with_context(|| format!("failed to read certificates from '{}'", path.display()))?;
+    if certs.is_empty() {
+        anyhow::bail!("no certificates found in '{}'", path.display());
+    }
+    Ok(certs)
+}
+
+/// Serve `router` over TLS until `cancel` fires, then drain open connections.
+///
+/// Each request carries `ConnectInfo<SocketAddr>` like `axum::serve` with connect
+/// info, plus [`ClientCertificate`] when the client authenticated with mTLS.
+pub(crate) async fn serve(
+    listener: TcpListener,
+    router: Router,
+    tls: Arc<TlsState>,
+    cancel: CancellationToken,
+) -> anyhow::Result<()> {
+    let graceful = GracefulShutdown::new();
+    let builder = auto::Builder::new(TokioExecutor::new());
+
+    tokio::spawn(Arc::clone(&tls).watch(cancel.clone()));
+
+    loop {
+        let (tcp, peer) = tokio::select! {
+            () = cancel.cancelled() => break,
+            accepted = listener.accept() => match accepted {
+                Ok(conn) => conn,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to accept TCP connection");
+                    continue;
+                }
+            },
+        };
+
+        let acceptor = tls.acceptor();
+        let router = router.clone();
+        let builder = builder.clone();
+        let watcher = graceful.watcher();
+
+        tokio::spawn(async move {
+            let stream = match tokio::time::timeout(HANDSHAKE_TIMEOUT, acceptor.accept(tcp)).await {
+                Ok(Ok(stream)) => stream,
+                Ok(Err(e)) => {
+                    tracing::debug!(%peer, error = %e, "TLS handshake failed");
+                    return;
+                }
+                Err(_) => {
+                    tracing::debug!(%peer, "TLS handshake timed out");
+                    return;
+                }
+            };
+
+            let client_cert = stream
+                .get_ref()
+                .1
+                .peer_certificates()
+                .and_then(<[_]>::first)
+                .and_then(|der| {
+                    ClientCertificate::from_der(der)
+                        .inspect_err(
+                            |e| tracing::warn!(%peer, error = %e, "Unreadable client certificate"),
+                        )
+                        .ok()
+                });
+
+            let service = hyper::service::service_fn(move |mut req: http::Request<Incoming>| {
+                req.extensions_mut().insert(ConnectInfo(peer));
+                if let Some(cert) = &client_cert {
+                    req.extensions_mut().insert(cert.clone());
+                }
+                router.clone().call(req)
+            });
+
+            let conn = builder.serve_connection_with_upgrades(TokioIo::new(stream), service);
+            if let Err(e) = watcher.watch(conn.into_owned()).await {
+                tracing::debug!(%peer, error = %e, "TLS connection closed with error");
+            }
+        });
+    }
+
+    tracing::info!("HTTPS server shutting down gracefully (cancellation)");
+    drop(listener);
+    graceful.shutdown().await;
+    Ok(())
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio_rustls::TlsConnector;
+    use tokio_rustls::client::TlsStream;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/tls")
+            .join(name)
+    }
+
+    fn tls_config(client_ca: bool) -> TlsConfig {
+        TlsConfig {
+            cert_path: fixture("server.pem"),
+            key_path: fixture("server.key"),
+            client_ca_path: client_ca.then(|| fixture("ca.pem")),
+            client_cert_required: true,
+            client_cert_authn: false,
+            min_version: TlsVersion::Tls12,
+            reload_interval_secs: 0,
+        }
+    }
+
+    fn client_connector(with_client_cert: bool) -> TlsConnector {
+        let mut roots = RootCertStore::empty();
+        for cert in load_certs(&fixture("ca.pem")).unwrap() {
+            roots.add(cert).unwrap();
+        }
+        let builder = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::aws_lc_rs::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()
+        .unwrap()
+        .with_root_certificates(roots);
+        let config = if with_client_cert {
+            builder
+                .with_client_auth_cert(
+                    load_certs(&fixture("client.pem")).unwrap(),
+                    PrivateKeyDer::from_pem_file(fixture("client.key")).unwrap(),
+                )
+                .unwrap()
+        } else {
+            builder.with_no_client_auth()
+        };
+        TlsConnector::from(Arc::new(config))
+    }
+
+    fn test_router() -> Router {
+        Router::new()
+            .route("/healthz", axum::routing::get(|| async { "ok" }))
+            .route(
+                "/whoami",
+                axum::routing::get(
+                    |cert: Option<axum::Extension<ClientCertificate>>| async move {
+                        cert.map_or_else(|| "anonymous".to_owned(), |c| c.0.subject)
+                    },
+                ),
+            )
+    }
+
+    async fn start(tls: Arc<TlsState>) -> (SocketAddr, CancellationToken) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let cancel = CancellationToken::new();
+        tokio::spawn(serve(listener, test_router(), tls, cancel.clone()));
+        (addr, cancel)
+    }
+
+    async fn connect(
+        addr: SocketAddr,
+        connector: &TlsConnector,
+    ) -> std::io::Result<TlsStream<tokio::net::TcpStream>> {
+        let tcp = tokio::net::TcpStream::connect(addr).await?;
+        let name = rustls::pki_types::ServerName::try_from("localhost").unwrap();
+        connector.connect(name, tcp).await
+    }
+
+    async fn get(
+        stream: &mut TlsStream<tokio::net::TcpStream>,
+        path: &str,
+    ) -> std::io::Result<String> {
+        let request =
+            format!("GET {path} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n");
+        stream.write_all(request.as_bytes()).await?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+        Ok(response)
+    }
+
+    fn peer_subject(stream: &TlsStream<tokio::net::TcpStream>) -> String {
+        let der = &stream.get_ref().1.peer_certificates().unwrap()[0];
+        ClientCertificate::from_der(der).unwrap().subject
+    }
+
+    #[tokio::test]
+    async fn https_handshake_serves_requests() {
+        let tls = Arc::new(TlsState::load(&tls_config(false)).unwrap());
+        let (addr, cancel) = start(tls).await;
+
+        let mut stream = connect(addr, &client_connector(false)).await.unwrap();
+        let response = get(&mut stream, "/healthz").await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.ends_with("ok"), "{response}");
+        cancel.cancel();
+    }
+
+    #[tokio::test]
+    async fn mtls_exposes_client_certificate() {
+        let tls = Arc::new(TlsState::load(&tls_config(true)).unwrap());
+        let (addr, cancel) = start(tls).await;
+
+        let mut stream = connect(addr, &client_connector(true)).await.unwrap();
+        let response = get(&mut stream, "/whoami").await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(
+            response.ends_with("CN=test-client, O=Hyperspot"),
+            "{response}"
+        );
+        cancel.cancel();
+    }
+
+    #[tokio::test]
+    async fn mtls_required_rejects_missing_client_certificate() {
+        let tls = Arc::new(TlsState::load(&tls_config(true)).unwrap());
+        let (addr, cancel) = start(tls).await;
+
+        // With TLS 1.3 the server rejects the missing certificate after the client
+        // finished its side of the handshake, so the alert may surface on first read.
+        let result = match connect(addr, &client_connector(false)).await {
+            Ok(mut stream) => get(&mut stream, "/healthz").await,
+            Err(e) => Err(e),
+        };
+
+        let err = result.expect_err("handshake without client certificate must fail");
+        assert!(
+            err.to_string().contains("CertificateRequired")
+                || err.to_string().contains("HandshakeFailure"),
+            "unexpected error: {err}"
+        );
+        cancel.cancel();
+    }
+
+    #[tokio::test]
+    async fn reload_swaps_certificate_for_new_connections() {
+        let dir = std::env::temp_dir().join(format!("api-gateway-tls-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let cert_path = dir.join("cert.pem");
+        let key_path = dir.join("key.pem");
+        std::fs::copy(fixture("server.pem"), &cert_path).unwrap();
+        std::fs::copy(fixture("server.key"), &key_path).unwrap();
+
+        let config = TlsConfig {
+            cert_path: cert_path.clone(),
+            key_path: key_path.clone(),
+            ..tls_config(false)
+        };
+        let tls = Arc::new(TlsState::load(&config).unwrap());
+        let (addr, cancel) = start(Arc::clone(&tls)).await;
+        let connector = client_connector(false);
+
+        let mut before = connect(addr, &connector).await.unwrap();
+        assert_eq!(peer_subject(&before), "CN=localhost");
+        assert!(!tls.reload_if_changed(), "files are unchanged");
+
+        std::fs::copy(fixture("server-rotated.pem"), &cert_path).unwrap();
+        std::fs::copy(fixture("server-rotated.key"), &key_path).unwrap();
+        assert!(tls.reload_if_changed());
+
+        let after = connect(addr, &connector).await.unwrap();
+        assert_eq!(peer_subject(&after), "CN=localhost-rotated");
+
+        // The connection opened before the reload keeps working.
+        let response = get(&mut before, "/healthz").await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+
+        cancel.cancel();
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn failed_reload_keeps_previous_certificate() {
+        let dir = std::env::temp_dir().join(format!("api-gateway-tls-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let key_path = dir.join("key.pem");
+        std::fs::copy(fixture("server.key"), &key_path).unwrap();
+
+        let config = TlsConfig {
+            key_path: key_path.clone(),
+            ..tls_config(false)
+        };
+        let tls = TlsState::load(&config).unwrap();
+        let before = tls.server_config.load_full();
+
+        std::fs::copy(fixture("client.key"), &key_path).unwrap();
+        let err = tls.reload().unwrap_err();
+
+        assert!(format!("{err:#}").contains("does not match"), "{err:#}");
+        assert!(Arc::ptr_eq(&before, &tls.server_config.load_full()));
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn mismatched_key_fails_with_clear_error() {
+        let config = TlsConfig {
+            key_path: fixture("client.key"),
+            ..tls_config(false)
+        };
+
+        let err = TlsState::load(&config)
+            .err()
+            .expect("mismatched key must fail");
+        let message = format!("{err:#}");
+
+        assert!(message.contains("does not match certificate"), "{message}");
+        assert!(message.contains("client.key"), "{message}");
+    }
+
+    #[test]
+    fn missing_certificate_file_fails() {
+        let config = TlsConfig {
+            cert_path: fixture("missing.pem"),
+            ..tls_config(false)
+        };
+
+        let err = TlsState::load(&config)
+            .err()
+            .expect("missing file must fail");
+        assert!(format!("{err:#}").contains("missing.pem"), "{err:#}");
+    }
+
+    #[test]
+    fn client_certificate_parses_subject_and_sans() {
+        let der = load_certs(&fixture("client.pem")).unwrap().remove(0);
+        let cert = ClientCertificate::from_der(&der).unwrap();
+
+        assert_eq!(cert.subject, "CN=test-client, O=Hyperspot");
+        assert_eq!(
+            cert.subject_alt_names,
+            [
+                "DNS:client.example.com",
+                "URI:spiffe://hyperspot/test-client"
+            ]
+        );
+        assert_eq!(cert.credential(), "mtls:CN=test-client, O=Hyperspot");
+    }
+}
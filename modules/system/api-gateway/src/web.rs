@@ -1,10 +1,16 @@
 use axum::{
+    body::Bytes,
     http::StatusCode,
     response::{Html, Json},
     routing::{MethodRouter, get},
 };
 use chrono::{SecondsFormat, Utc};
+use modkit::readiness::ReadinessRegistry;
 use serde_json::{Value, json};
+use std::sync::Arc;
+
+use crate::config::{DocsConfig, DocsEnvironment};
+use crate::introspection::GatewayIntrospection;
 
 /// Returns a 501 Not Implemented handler for operations without implementations
 #[allow(dead_code)]
@@ -20,18 +26,53 @@ pub fn placeholder_handler_501() -> MethodRouter {
     })
 }
 
-pub async fn health_check() -> Json<Value> {
-    Json(json!({
-        "status": "healthy",
-        "timestamp": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
-    }))
+/// Readiness probe: `200` once every module is ready, `503` before that or
+/// while any module is degraded, with each module's readiness in the body.
+pub async fn health_check(readiness: Option<Arc<ReadinessRegistry>>) -> (StatusCode, Json<Value>) {
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+    let Some(readiness) = readiness else {
+        return (
+            StatusCode::OK,
+            Json(json!({ "status": "healthy", "timestamp": timestamp })),
+        );
+    };
+
+    let report = readiness.report();
+    let (code, status) = if report.ready {
+        (StatusCode::OK, "healthy")
+    } else if report.degraded().is_empty() {
+        (StatusCode::SERVICE_UNAVAILABLE, "starting")
+    } else {
+        (StatusCode::SERVICE_UNAVAILABLE, "degraded")
+    };
+    (
+        code,
+        Json(json!({
+            "status": status,
+            "ready": report.ready,
+            "modules": report.modules,
+            "timestamp": timestamp,
+        })),
+    )
+}
+
+/// Effective middleware stack and config digest; `503` until the stack is built.
+pub async fn introspection(
+    introspection: Option<Arc<GatewayIntrospection>>,
+) -> Result<Json<GatewayIntrospection>, StatusCode> {
+    introspection
+        .map(|i| Json((*i).clone()))
+        .ok_or(StatusCode::SERVICE_UNAVAILABLE)
+}
+
+/// Problem types registered by modules, for client tooling.
+pub async fn problem_types() -> Json<Vec<modkit::api::ProblemType>> {
+    Json(modkit::api::ProblemTypeRegistry::global().all())
 }
 
 #[cfg(not(feature = "embed_elements"))]
-pub async fn serve_docs() -> Html<&'static str> {
-    // External mode: load from CDN @latest
-    Html(
-        r#"<!DOCTYPE html>
+// External mode: load from CDN @latest
+const DOCS_HTML: &str = r#"<!DOCTYPE html>
 <html>
 <head>
   <meta charset="utf-8"/>
@@ -42,15 +83,11 @@ pub async fn serve_docs() -> Html<&'static str> {
 <body>
   <elements-api apiDescriptionUrl="/openapi.json" router="hash" layout="sidebar"></elements-api>
 </body>
-</html>"#,
-    )
-}
+</html>"#;
 
 #[cfg(feature = "embed_elements")]
-pub async fn serve_docs() -> Html<&'static str> {
-    // Embedded mode: reference local embedded assets
-    Html(
-        r#"<!DOCTYPE html>
+// Embedded mode: reference local embedded assets
+const DOCS_HTML: &str = r#"<!DOCTYPE html>
 <html>
 <head>
   <meta charset="utf-8"/>
@@ -61,6 +98,295 @@ pub async fn serve_docs() -> Html<&'static str> {
 <body>
   <elements-api apiDescriptionUrl="/openapi.json" router="hash" layout="sidebar"></elements-api>
 </body>
-</html>"#,
-    )
+</html>"#;
+
+/// Id of the `<script type="application/json">` element carrying the docs config.
+const DOCS_CONFIG_ID: &str = "docs-config";
+
+/// Static bootstrap script. It only reads the JSON config element; no
+/// configuration value is ever interpolated into script source.
+const DOCS_BOOTSTRAP_JS: &str = r#"(function () {
+  var cfg = JSON.parse(document.getElementById('docs-config').textContent);
+  var api = document.querySelector('elements-api');
+  var ENV_KEY = 'docs.environment';
+  var TOKEN_KEY = 'docs.token';
+  var origins = [window.location.origin];
+  var bar = document.createElement('div');
+  bar.style.cssText = 'display:flex;gap:12px;align-items:center;padding:6px 12px;' +
+    'border-bottom:1px solid #ddd;font:13px sans-serif';
+
+  function field(label, input) {
+    var el = document.createElement('label');
+    el.textContent = label + ' ';
+    el.appendChild(input);
+    bar.appendChild(el);
+  }
+
+  if (cfg.environments.length > 0) {
+    var select = document.createElement('select');
+    cfg.environments.forEach(function (env, i) {
+      var opt = document.createElement('option');
+      opt.value = String(i);
+      opt.textContent = env.name;
+      select.appendChild(opt);
+      origins.push(new URL(env.base_url, window.location.href).origin);
+    });
+    var saved = sessionStorage.getItem(ENV_KEY);
+    if (saved !== null && cfg.environments[Number(saved)]) {
+      select.value = saved;
+    }
+    var load = function () {
+      var env = cfg.environments[Number(select.value)];
+      fetch(cfg.openapi_url)
+        .then(function (r) { return r.json(); })
+        .then(function (doc) {
+          doc.servers = [{ url: env.base_url, description: env.name }];
+          api.removeAttribute('apiDescriptionUrl');
+          api.apiDescriptionDocument = doc;
+        });
+    };
+    select.addEventListener('change', function () {
+      sessionStorage.setItem(ENV_KEY, select.value);
+      load();
+    });
+    field('Environment', select);
+    load();
+  }
+
+  if (cfg.token_helper) {
+    var input = document.createElement('input');
+    input.type = 'password';
+    input.placeholder = 'Bearer token';
+    input.autocomplete = 'off';
+    input.value = sessionStorage.getItem(TOKEN_KEY) || '';
+    input.addEventListener('input', function () {
+      var token = input.value.trim();
+      if (token) {
+        sessionStorage.setItem(TOKEN_KEY, token);
+      } else {
+        sessionStorage.removeItem(TOKEN_KEY);
+      }
+    });
+    field('Token', input);
+
+    // Attach the token only to the gateway and configured environments.
+    var originalFetch = window.fetch.bind(window);
+    window.fetch = function (resource, init) {
+      var token = sessionStorage.getItem(TOKEN_KEY);
+      var url = new URL(resource instanceof Request ? resource.url : String(resource),
+        window.location.href);
+      if (!token || origins.indexOf(url.origin) === -1) {
+        return originalFetch(resource, init);
+      }
+      var request = new Request(resource, init);
+      if (!request.headers.has('Authorization')) {
+        request.headers.set('Authorization', 'Bearer ' + token);
+      }
+      return originalFetch(request);
+    };
+  }
+
+  document.body.insertBefore(bar, document.body.firstChild);
+})();"#;
+
+/// Build the `/docs` handler. The page is rendered once from config.
+pub fn serve_docs(cfg: &DocsConfig) -> MethodRouter {
+    let page = Bytes::from(render_docs_page(cfg));
+    get(move || {
+        let page = page.clone();
+        async move { Html(page) }
+    })
+}
+
+/// Render the docs page.
+///
+/// With no helpers configured the stock page is returned unchanged. Otherwise
+/// the config is embedded as JSON (escaped for HTML) next to a static bootstrap
+/// script, so config values can never be interpreted as markup or code.
+#[must_use]
+pub fn render_docs_page(cfg: &DocsConfig) -> String {
+    if !cfg.is_enabled() {
+        return DOCS_HTML.to_owned();
+    }
+
+    let environments: Vec<Value> = cfg
+        .environments
+        .iter()
+        .filter(|env| {
+            let ok = is_safe_base_url(&env.base_url);
+            if !ok {
+                tracing::warn!(
+                    environment = %env.name,
+                    base_url = %env.base_url,
+                    "Ignoring docs environment with unsupported base_url"
+                );
+            }
+            ok
+        })
+        .map(|DocsEnvironment { name, base_url }| json!({ "name": name, "base_url": base_url }))
+        .collect();
+
+    let bootstrap = json!({
+        "openapi_url": "/openapi.json",
+        "environments": environments,
+        "token_helper": cfg.enable_token_helper,
+    });
+
+    let injected = format!(
+        "  <script id=\"{DOCS_CONFIG_ID}\" type=\"application/json\">{}</script>\n  <script>{DOCS_BOOTSTRAP_JS}</script>\n</body>",
+        escape_json_for_html(&bootstrap.to_string())
+    );
+    DOCS_HTML.replacen("</body>", &injected, 1)
+}
+
+/// Only absolute `http(s)` URLs and same-origin paths are accepted.
+fn is_safe_base_url(url: &str) -> bool {
+    url.starts_with("https://")
+        || url.starts_with("http://")
+        || (url.starts_with('/') && !url.starts_with("//"))
+}
+
+/// Escape characters that could close the `<script>` element. The replacements
+/// are valid JSON string escapes, so `JSON.parse` yields the original values.
+fn escape_json_for_html(json: &str) -> String {
+    json.replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::config::ApiGatewayConfig;
+
+    #[tokio::test]
+    async fn health_lists_degraded_modules_with_reasons() {
+        let readiness = Arc::new(ReadinessRegistry::new());
+        readiness.module("api-gateway").ready();
+        readiness
+            .module("authn-resolver")
+            .degraded("plugin selection failed: no instances");
+
+        let (code, Json(body)) = health_check(Some(readiness.clone())).await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "degraded");
+        assert_eq!(body["ready"], false);
+        assert_eq!(
+            body["modules"],
+            json!({
+                "api-gateway": { "state": "ready" },
+                "authn-resolver": {
+                    "state": "degraded",
+                    "reason": "plugin selection failed: no instances",
+                },
+            })
+        );
+
+        readiness.module("authn-resolver").ready();
+        let (code, Json(body)) = health_check(Some(readiness)).await;
+        assert_eq!(code, StatusCode::OK);
+        assert_eq!(body["status"], "healthy");
+    }
+
+    #[tokio::test]
+    async fn health_is_starting_while_modules_are_pending() {
+        let readiness = Arc::new(ReadinessRegistry::new());
+        readiness.register("users-info");
+
+        let (code, Json(body)) = health_check(Some(readiness)).await;
+        assert_eq!(code, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["status"], "starting");
+        assert_eq!(body["modules"]["users-info"]["state"], "pending");
+    }
+
+    fn injected_config(page: &str) -> Value {
+        let open = format!("<script id=\"{DOCS_CONFIG_ID}\" type=\"application/json\">");
+        let start = page.find(&open).expect("config script") + open.len();
+        let end = start + page[start..].find("</script>").expect("closing tag");
+        serde_json::from_str(&page[start..end]).expect("valid JSON")
+    }
+
+    #[test]
+    fn disabled_helpers_serve_unmodified_page() {
+        let page = render_docs_page(&DocsConfig::default());
+        assert_eq!(page, DOCS_HTML);
+        assert!(!page.contains(DOCS_CONFIG_ID));
+    }
+
+    #[test]
+    fn injected_config_matches_yaml() {
+        let yaml = r#"
+bind_addr: "127.0.0.1:8087"
+enable_docs: true
+docs:
+  enable_token_helper: true
+  environments:
+    - name: local
+      base_url: "http://127.0.0.1:8087"
+    - name: staging
+      base_url: "https://staging.example.com/api"
+"#;
+        let cfg: ApiGatewayConfig = serde_saphyr::from_str(yaml).unwrap();
+        let page = render_docs_page(&cfg.docs);
+
+        assert_eq!(
+            injected_config(&page),
+            json!({
+                "openapi_url": "/openapi.json",
+                "token_helper": true,
+                "environments": [
+                    { "name": "local", "base_url": "http://127.0.0.1:8087" },
+                    { "name": "staging", "base_url": "https://staging.example.com/api" },
+                ],
+            })
+        );
+        assert!(page.contains(DOCS_BOOTSTRAP_JS));
+    }
+
+    #[test]
+    fn config_values_cannot_break_out_of_script() {
+        let cfg = DocsConfig {
+            environments: vec![DocsEnvironment {
+                name: "</script><script>alert(1)</script>".to_owned(),
+                base_url: "/api".to_owned(),
+            }],
+            enable_token_helper: false,
+        };
+        let page = render_docs_page(&cfg);
+
+        assert!(!page.contains("<script>alert(1)"));
+        assert_eq!(
+            injected_config(&page)["environments"][0]["name"],
+            "</script><script>alert(1)</script>"
+        );
+    }
+
+    #[test]
+    fn unsafe_base_urls_are_dropped() {
+        let cfg = DocsConfig {
+            environments: vec![
+                DocsEnvironment {
+                    name: "bad".to_owned(),
+                    base_url: "javascript:alert(1)".to_owned(),
+                },
+                DocsEnvironment {
+                    name: "protocol-relative".to_owned(),
+                    base_url: "//evil.example.com".to_owned(),
+                },
+                DocsEnvironment {
+                    name: "ok".to_owned(),
+                    base_url: "/api".to_owned(),
+                },
+            ],
+            enable_token_helper: false,
+        };
+        let config = injected_config(&render_docs_page(&cfg));
+
+        assert_eq!(
+            config["environments"],
+            json!([{ "name": "ok", "base_url": "/api" }])
+        );
+        assert_eq!(config["token_helper"], false);
+    }
 }
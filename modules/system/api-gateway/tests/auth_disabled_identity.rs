@@ -0,0 +1,233 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Tests for the configurable auth-disabled bootstrap identity
+//!
+//! These tests verify that:
+//! 1. The configured subject, tenant and scopes reach handlers' `SecurityContext`
+//! 2. Nil UUIDs are rejected at init
+//! 3. A changed identity is refused against a database bootstrapped with another one
+
+use anyhow::Result;
+use async_trait::async_trait;
+use axum::{
+    Extension, Json, Router,
+    body::Body,
+    http::{Request, StatusCode},
+};
+use modkit::{
+    ClientHub, Module,
+    api::{OperationBuilder, operation_builder::LicenseFeature},
+    config::ConfigProvider,
+    context::ModuleCtx,
+    contracts::{ApiGatewayCapability, DatabaseCapability, OpenApiRegistry, RestApiCapability},
+};
+use modkit_db::migration_runner::run_migrations_for_testing;
+use modkit_db::{ConnectOpts, DBProvider, DbError, connect_db};
+use modkit_security::SecurityContext;
+use serde_json::json;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::{Uuid, uuid};
+
+const SUBJECT_A: Uuid = uuid!("6f0c2a43-3e0b-4d55-9a0e-0c1b1a2f4e01");
+const TENANT_A: Uuid = uuid!("6f0c2a43-3e0b-4d55-9a0e-0c1b1a2f4e02");
+const TENANT_B: Uuid = uuid!("6f0c2a43-3e0b-4d55-9a0e-0c1b1a2f4e03");
+
+/// Test configuration provider
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        self.config.get(module)
+    }
+}
+
+fn gateway_config(identity: &serde_json::Value) -> serde_json::Value {
+    json!({
+        "api-gateway": {
+            "config": {
+                "bind_addr": "0.0.0.0:8080",
+                "auth_disabled": true,
+                "auth_disabled_identity": identity,
+            }
+        }
+    })
+}
+
+fn create_api_gateway_ctx(config: serde_json::Value, db: Option<DBProvider<DbError>>) -> ModuleCtx {
+    ModuleCtx::new(
+        "api-gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config }),
+        Arc::new(ClientHub::new()),
+        tokio_util::sync::CancellationToken::new(),
+        db,
+    )
+}
+
+/// In-memory database with the api-gateway migrations applied.
+async fn gateway_db() -> DBProvider<DbError> {
+    let opts = ConnectOpts {
+        max_conns: Some(1),
+        min_conns: Some(1),
+        ..Default::default()
+    };
+    let db = connect_db("sqlite::memory:", opts).await.unwrap();
+    run_migrations_for_testing(&db, api_gateway::ApiGateway::default().migrations())
+        .await
+        .unwrap();
+    DBProvider::new(db)
+}
+
+async fn init_gateway(
+    identity: &serde_json::Value,
+    db: Option<DBProvider<DbError>>,
+) -> Result<(api_gateway::ApiGateway, ModuleCtx)> {
+    let ctx = create_api_gateway_ctx(gateway_config(identity), db);
+    let gateway = api_gateway::ApiGateway::default();
+    gateway.init(&ctx).await?;
+    Ok((gateway, ctx))
+}
+
+#[derive(Clone)]
+#[modkit_macros::api_dto(response)]
+struct WhoAmI {
+    subject_id: String,
+    tenant_id: String,
+    token_scopes: Vec<String>,
+}
+
+async fn whoami(Extension(ctx): Extension<SecurityContext>) -> Json<WhoAmI> {
+    Json(WhoAmI {
+        subject_id: ctx.subject_id().to_string(),
+        tenant_id: ctx.subject_tenant_id().to_string(),
+        token_scopes: ctx.token_scopes().to_vec(),
+    })
+}
+
+struct License;
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &'static str {
+        "gts.x.core.lic.feat.v1~x.core.global.base.v1"
+    }
+}
+
+impl LicenseFeature for License {}
+
+struct WhoAmIModule;
+
+#[async_trait]
+impl Module for WhoAmIModule {
+    async fn init(&self, _ctx: &ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl RestApiCapability for WhoAmIModule {
+    fn register_rest(
+        &self,
+        _ctx: &ModuleCtx,
+        router: Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> Result<Router> {
+        let router = OperationBuilder::get("/tests/v1/whoami")
+            .operation_id("test.whoami")
+            .authenticated()
+            .require_license_features::<License>([])
+            .summary("Current security context")
+            .handler(whoami)
+            .json_response_with_schema::<WhoAmI>(openapi, http::StatusCode::OK, "Success")
+            .register(router, openapi);
+        Ok(router)
+    }
+}
+
+#[tokio::test]
+async fn custom_identity_reaches_handler_context() {
+    let identity = json!({
+        "subject_id": SUBJECT_A,
+        "tenant_id": TENANT_A,
+        "token_scopes": ["users:read"],
+    });
+    let (gateway, api_ctx) = init_gateway(&identity, None).await.unwrap();
+
+    let test_ctx = create_api_gateway_ctx(json!({}), None);
+    let router = WhoAmIModule
+        .register_rest(&test_ctx, Router::new(), &gateway)
+        .unwrap();
+    let router = gateway.rest_finalize(&api_ctx, router).unwrap();
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/whoami")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["subject_id"], SUBJECT_A.to_string());
+    assert_eq!(json["tenant_id"], TENANT_A.to_string());
+    assert_eq!(json["token_scopes"], json!(["users:read"]));
+}
+
+#[tokio::test]
+async fn nil_identity_is_rejected() {
+    let identity = json!({ "tenant_id": Uuid::nil() });
+    let err = init_gateway(&identity, None).await.err().unwrap();
+    assert!(
+        err.to_string().contains("auth_disabled_identity.tenant_id"),
+        "unexpected error: {err}"
+    );
+}
+
+#[tokio::test]
+async fn fingerprint_mismatch_aborts_startup() {
+    let db = gateway_db().await;
+
+    let first = json!({ "subject_id": SUBJECT_A, "tenant_id": TENANT_A });
+    init_gateway(&first, Some(db.clone())).await.unwrap();
+    // Same identity on restart is accepted.
+    init_gateway(&first, Some(db.clone())).await.unwrap();
+
+    let changed = json!({ "subject_id": SUBJECT_A, "tenant_id": TENANT_B });
+    let err = init_gateway(&changed, Some(db.clone()))
+        .await
+        .err()
+        .unwrap();
+    let msg = err.to_string();
+    assert!(msg.contains("does not match"), "unexpected error: {msg}");
+    assert!(
+        msg.contains(&TENANT_A.to_string()),
+        "unexpected error: {msg}"
+    );
+    assert!(
+        msg.contains(&TENANT_B.to_string()),
+        "unexpected error: {msg}"
+    );
+}
+
+#[tokio::test]
+async fn force_records_new_identity() {
+    let db = gateway_db().await;
+
+    let first = json!({ "subject_id": SUBJECT_A, "tenant_id": TENANT_A });
+    init_gateway(&first, Some(db.clone())).await.unwrap();
+
+    let forced = json!({ "subject_id": SUBJECT_A, "tenant_id": TENANT_B, "force": true });
+    init_gateway(&forced, Some(db.clone())).await.unwrap();
+
+    // The new identity is now the recorded one; the old one is refused.
+    let changed = json!({ "subject_id": SUBJECT_A, "tenant_id": TENANT_B });
+    init_gateway(&changed, Some(db.clone())).await.unwrap();
+    assert!(init_gateway(&first, Some(db)).await.is_err());
+}
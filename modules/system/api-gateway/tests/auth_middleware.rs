@@ -543,16 +543,24 @@ impl RestApiCapability for TestAuthEnabledModule {
 
 /// Create a finalized router with auth **enabled** and the given mock `AuthN` client.
 async fn create_auth_enabled_router(mock: MockAuthNResolverClient, cors_enabled: bool) -> Router {
-    let config = json!({
-        "api-gateway": {
-            "config": {
-                "bind_addr": "0.0.0.0:8080",
-                "enable_docs": false,
-                "cors_enabled": cors_enabled,
-                "auth_disabled": false,
-            }
-        }
-    });
+    create_auth_enabled_router_with_config(
+        mock,
+        json!({
+            "bind_addr": "0.0.0.0:8080",
+            "enable_docs": false,
+            "cors_enabled": cors_enabled,
+            "auth_disabled": false,
+        }),
+    )
+    .await
+}
+
+/// Same as `create_auth_enabled_router` with a custom gateway config.
+async fn create_auth_enabled_router_with_config(
+    mock: MockAuthNResolverClient,
+    gateway_config: serde_json::Value,
+) -> Router {
+    let config = json!({ "api-gateway": { "config": gateway_config } });
 
     let hub = Arc::new(ClientHub::new());
     hub.register::<dyn AuthNResolverClient>(Arc::new(mock));
@@ -598,7 +606,7 @@ fn mock_accepting_token(
                         .unwrap(),
                 })
             } else {
-                Err(AuthNResolverError::Unauthorized("invalid token".to_owned()))
+                Err(AuthNResolverError::InvalidToken("invalid token".to_owned()))
             }
         }),
     }
@@ -662,6 +670,7 @@ async fn test_missing_token_returns_401() {
         StatusCode::UNAUTHORIZED,
         "Missing token should yield 401"
     );
+    assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
 }
 
 #[tokio::test]
@@ -685,6 +694,88 @@ async fn test_invalid_token_returns_401() {
         StatusCode::UNAUTHORIZED,
         "Invalid token should yield 401"
     );
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer error="invalid_token""#
+    );
+}
+
+#[tokio::test]
+async fn test_bearer_token_with_mtls_scheme_returns_401() {
+    let mock = mock_accepting_token(
+        "mtls:CN=billing-service, O=Example",
+        Uuid::new_v4(),
+        Uuid::new_v4(),
+    );
+    let router = create_auth_enabled_router(mock, false).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header(
+                    header::AUTHORIZATION,
+                    "Bearer mtls:CN=billing-service, O=Example",
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(
+        response.status(),
+        StatusCode::UNAUTHORIZED,
+        "a bearer token must not impersonate a client certificate"
+    );
+}
+
+#[tokio::test]
+async fn test_expired_token_returns_401_with_challenge() {
+    let mock =
+        mock_returning_error(|| AuthNResolverError::TokenExpired("exp in the past".to_owned()));
+    let router = create_auth_enabled_router(mock, false).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header(header::AUTHORIZATION, "Bearer stale-token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer error="invalid_token", error_description="The access token expired""#
+    );
+}
+
+#[tokio::test]
+async fn test_revoked_token_returns_401_with_challenge() {
+    let mock =
+        mock_returning_error(|| AuthNResolverError::Revoked("token has been revoked".to_owned()));
+    let router = create_auth_enabled_router(mock, false).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header(header::AUTHORIZATION, "Bearer stolen-token")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer error="invalid_token", error_description="The access token has been revoked""#
+    );
 }
 
 #[tokio::test]
@@ -708,6 +799,7 @@ async fn test_no_plugin_available_returns_503() {
         StatusCode::SERVICE_UNAVAILABLE,
         "NoPluginAvailable should yield 503"
     );
+    assert!(!response.headers().contains_key(header::WWW_AUTHENTICATE));
 }
 
 #[tokio::test]
@@ -827,3 +919,200 @@ async fn test_cors_preflight_skips_auth() {
         "CORS preflight must not be blocked by auth"
     );
 }
+
+#[tokio::test]
+async fn test_head_health_endpoints_with_auth_enabled() {
+    let config = json!({
+        "api-gateway": {
+            "config": {
+                "bind_addr": "0.0.0.0:8080",
+                "enable_docs": false,
+                "cors_enabled": false,
+                "auth_disabled": false,
+            }
+        }
+    });
+
+    let hub = Arc::new(ClientHub::new());
+    let mock = mock_returning_error(|| AuthNResolverError::Unauthorized("no".to_owned()));
+    hub.register::<dyn AuthNResolverClient>(Arc::new(mock));
+    let api_ctx = ModuleCtx::new(
+        "api-gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config }),
+        hub,
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    );
+
+    let api_gateway = api_gateway::ApiGateway::default();
+    api_gateway.init(&api_ctx).await.expect("Failed to init");
+    let router = api_gateway
+        .rest_prepare(&api_ctx, Router::new())
+        .expect("Failed to prepare");
+    let router = api_gateway
+        .rest_finalize(&api_ctx, router)
+        .expect("Failed to finalize");
+
+    // Health routes are public, so no token is needed for either method
+    for (uri, content_type) in [
+        ("/healthz", "text/plain; charset=utf-8"),
+        ("/health", "application/json"),
+    ] {
+        let get = router
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .expect("Request failed");
+        assert_eq!(get.status(), StatusCode::OK, "GET {uri}");
+        let get_body = axum::body::to_bytes(get.into_body(), usize::MAX)
+            .await
+            .unwrap();
+
+        let head = router
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(Method::HEAD)
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .expect("Request failed");
+
+        assert_eq!(head.status(), StatusCode::OK, "HEAD {uri}");
+        assert_eq!(head.headers()[header::CONTENT_TYPE], content_type);
+        // Same length as the GET body (the /health timestamp has a fixed width)
+        assert_eq!(
+            head.headers()[header::CONTENT_LENGTH],
+            get_body.len().to_string().as_str()
+        );
+        let body = axum::body::to_bytes(head.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(body.is_empty(), "HEAD {uri} must not return a body");
+    }
+}
+
+// --- API key mode ---
+
+async fn create_api_key_router(mock: MockAuthNResolverClient) -> Router {
+    create_auth_enabled_router_with_config(
+        mock,
+        json!({
+            "bind_addr": "0.0.0.0:8080",
+            "auth_disabled": false,
+            "auth_mode": { "api_key": { "header": "X-API-Key" } },
+        }),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_api_key_mode_passes_header_value_to_resolver() {
+    let subject_id = Uuid::new_v4();
+    let mock = mock_accepting_token("k-billing", subject_id, Uuid::new_v4());
+    let router = create_api_key_router(mock).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header("x-api-key", " k-billing ")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["user_id"], subject_id.to_string());
+}
+
+#[tokio::test]
+async fn test_api_key_mode_ignores_bearer_token() {
+    let mock = mock_accepting_token("k-billing", Uuid::new_v4(), Uuid::new_v4());
+    let router = create_api_key_router(mock).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header(header::AUTHORIZATION, "Bearer k-billing")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(response.headers().get(header::WWW_AUTHENTICATE).is_none());
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["detail"], "Missing or invalid x-api-key header");
+}
+
+#[tokio::test]
+async fn test_api_key_mode_unknown_key_returns_401_without_challenge() {
+    let mock = mock_accepting_token("k-billing", Uuid::new_v4(), Uuid::new_v4());
+    let router = create_api_key_router(mock).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header("x-api-key", "k-unknown")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert!(response.headers().get(header::WWW_AUTHENTICATE).is_none());
+}
+
+#[tokio::test]
+async fn test_api_key_mode_rejects_mtls_scheme() {
+    let mock = mock_accepting_token("MTLS:CN=billing-service", Uuid::new_v4(), Uuid::new_v4());
+    let router = create_api_key_router(mock).await;
+
+    let response = router
+        .oneshot(
+            Request::builder()
+                .uri("/tests/v1/api/protected")
+                .header("x-api-key", "MTLS:CN=billing-service")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .expect("Request failed");
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_api_key_mode_rejects_invalid_header_name() {
+    let api_ctx = create_api_gateway_ctx(json!({
+        "api-gateway": {
+            "config": {
+                "bind_addr": "0.0.0.0:8080",
+                "auth_disabled": false,
+                "auth_mode": { "api_key": { "header": "X API Key" } },
+            }
+        }
+    }));
+
+    let err = api_gateway::ApiGateway::default()
+        .init(&api_ctx)
+        .await
+        .expect_err("invalid header name must be rejected");
+    assert!(err.to_string().contains("auth_mode.api_key.header"));
+}
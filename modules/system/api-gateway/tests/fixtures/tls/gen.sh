@@ -0,0 +1,29 @@
+#!/usr/bin/env sh
+# Regenerates the self-signed certificates used by the TLS tests.
+# The CA key is discarded; certificates are valid for 100 years.
+set -eu
+cd "$(dirname "$0")"
+
+DAYS=36500
+tmp=$(mktemp -d)
+trap 'rm -rf "$tmp"' EXIT
+
+openssl ecparam -name prime256v1 -genkey -noout -out "$tmp/ca.key"
+openssl req -x509 -new -key "$tmp/ca.key" -sha256 -days "$DAYS" \
+    -subj "/CN=Hyperspot Test CA" -out ca.pem
+
+issue() {
+    name=$1 subj=$2 san=$3 usage=$4
+    openssl ecparam -name prime256v1 -genkey -noout -out "$tmp/$name.ec"
+    openssl pkcs8 -topk8 -nocrypt -in "$tmp/$name.ec" -out "$name.key"
+    openssl req -new -key "$name.key" -subj "$subj" -out "$tmp/$name.csr"
+    printf 'subjectAltName=%s\nextendedKeyUsage=%s\nbasicConstraints=CA:FALSE\n' \
+        "$san" "$usage" > "$tmp/$name.ext"
+    openssl x509 -req -in "$tmp/$name.csr" -CA ca.pem -CAkey "$tmp/ca.key" \
+        -CAcreateserial -CAserial "$tmp/ca.srl" -sha256 -days "$DAYS" \
+        -extfile "$tmp/$name.ext" -out "$name.pem"
+}
+
+issue server "/CN=localhost" "DNS:localhost,IP:127.0.0.1" serverAuth
+issue server-rotated "/CN=localhost-rotated" "DNS:localhost,IP:127.0.0.1" serverAuth
+issue client "/CN=test-client/O=Hyperspot" "DNS:client.example.com,URI:spiffe://hyperspot/test-client" clientAuth
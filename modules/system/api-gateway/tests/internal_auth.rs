@@ -0,0 +1,252 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Tests for forwarded `SecurityContext` authentication (`internal_auth`)
+//!
+//! These tests verify that:
+//! 1. A trusted peer's `X-Security-Context-Bin` header becomes the request's context
+//! 2. The same header from an untrusted peer is stripped and normal auth applies
+//! 3. A malformed header from a trusted peer is rejected with a distinct problem type
+
+use anyhow::Result;
+use async_trait::async_trait;
+use authn_resolver_sdk::{AuthNResolverClient, AuthNResolverError, AuthenticationResult};
+use axum::{
+    Extension, Json, Router,
+    body::Body,
+    extract::ConnectInfo,
+    http::{HeaderMap, Request, StatusCode, header},
+};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use modkit::{
+    ClientHub, Module,
+    api::{OperationBuilder, operation_builder::LicenseFeature},
+    config::ConfigProvider,
+    context::ModuleCtx,
+    contracts::{ApiGatewayCapability, OpenApiRegistry, RestApiCapability},
+};
+use modkit_security::{SecurityContext, encode_bin};
+use serde_json::json;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+const SECRET: &str = "mesh-secret";
+const TOKEN: &str = "valid-token";
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        self.config.get(module)
+    }
+}
+
+/// Accepts `TOKEN` and authenticates it as `subject_id`.
+struct MockAuthN {
+    subject_id: Uuid,
+}
+
+#[async_trait]
+impl AuthNResolverClient for MockAuthN {
+    async fn authenticate(
+        &self,
+        bearer_token: &str,
+    ) -> Result<AuthenticationResult, AuthNResolverError> {
+        if bearer_token != TOKEN {
+            return Err(AuthNResolverError::InvalidToken("invalid token".to_owned()));
+        }
+        Ok(AuthenticationResult {
+            security_context: SecurityContext::builder()
+                .subject_id(self.subject_id)
+                .subject_tenant_id(Uuid::new_v4())
+                .build()
+                .unwrap(),
+        })
+    }
+}
+
+struct License;
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &'static str {
+        "gts.x.core.lic.feat.v1~x.core.global.base.v1"
+    }
+}
+
+impl LicenseFeature for License {}
+
+/// Echoes the subject of the request's context and whether the forwarded
+/// header reached the handler.
+async fn whoami(
+    Extension(ctx): Extension<SecurityContext>,
+    headers: HeaderMap,
+) -> Json<serde_json::Value> {
+    Json(json!({
+        "subject_id": ctx.subject_id().to_string(),
+        "tenant_id": ctx.subject_tenant_id().to_string(),
+        "header_seen": headers.contains_key("x-security-context-bin"),
+    }))
+}
+
+struct TestModule;
+
+#[async_trait]
+impl Module for TestModule {
+    async fn init(&self, _ctx: &ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl RestApiCapability for TestModule {
+    fn register_rest(
+        &self,
+        _ctx: &ModuleCtx,
+        router: Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> Result<Router> {
+        Ok(OperationBuilder::get("/tests/v1/whoami")
+            .operation_id("test_internal_auth.whoami")
+            .authenticated()
+            .require_license_features::<License>([])
+            .handler(whoami)
+            .json_response(http::StatusCode::OK, "Caller identity")
+            .register(router, openapi))
+    }
+}
+
+async fn router(bearer_subject: Uuid) -> Router {
+    let config = json!({
+        "api-gateway": {
+            "config": {
+                "bind_addr": "0.0.0.0:8080",
+                "internal_auth": {
+                    "trusted_cidrs": ["10.0.0.0/8"],
+                    "shared_secret_header": "x-internal-auth",
+                    "shared_secret": SECRET,
+                },
+            }
+        }
+    });
+
+    let hub = Arc::new(ClientHub::new());
+    hub.register::<dyn AuthNResolverClient>(Arc::new(MockAuthN {
+        subject_id: bearer_subject,
+    }));
+    let api_ctx = ModuleCtx::new(
+        "api-gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config }),
+        hub,
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    );
+    let test_ctx = ModuleCtx::new(
+        "test_module",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config: json!({}) }),
+        Arc::new(ClientHub::new()),
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    );
+
+    let api_gateway = api_gateway::ApiGateway::default();
+    api_gateway.init(&api_ctx).await.expect("Failed to init");
+
+    let router = TestModule
+        .register_rest(&test_ctx, Router::new(), &api_gateway)
+        .expect("Failed to register routes");
+    api_gateway
+        .rest_finalize(&api_ctx, router)
+        .expect("Failed to finalize")
+}
+
+fn encoded(ctx: &SecurityContext) -> String {
+    STANDARD.encode(encode_bin(ctx).unwrap())
+}
+
+fn request(peer: &str, context: &str) -> Request<Body> {
+    let mut req = Request::builder()
+        .uri("/tests/v1/whoami")
+        .header("x-internal-auth", SECRET)
+        .header("x-security-context-bin", context)
+        .header(header::AUTHORIZATION, format!("Bearer {TOKEN}"))
+        .body(Body::empty())
+        .unwrap();
+    req.extensions_mut()
+        .insert(ConnectInfo(peer.parse::<SocketAddr>().unwrap()));
+    req
+}
+
+async fn json_body(response: axum::response::Response) -> serde_json::Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn trusted_peer_gets_forwarded_context() {
+    let forwarded = SecurityContext::builder()
+        .subject_id(Uuid::new_v4())
+        .subject_tenant_id(Uuid::new_v4())
+        .build()
+        .unwrap();
+    let router = router(Uuid::new_v4()).await;
+
+    let response = router
+        .oneshot(request("10.1.2.3:40000", &encoded(&forwarded)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["subject_id"], forwarded.subject_id().to_string());
+    assert_eq!(json["tenant_id"], forwarded.subject_tenant_id().to_string());
+    assert_eq!(json["header_seen"], false);
+}
+
+#[tokio::test]
+async fn untrusted_peer_falls_through_to_bearer_auth() {
+    let bearer_subject = Uuid::new_v4();
+    let forwarded = SecurityContext::builder()
+        .subject_id(Uuid::new_v4())
+        .subject_tenant_id(Uuid::new_v4())
+        .build()
+        .unwrap();
+    let router = router(bearer_subject).await;
+
+    let response = router
+        .oneshot(request("192.168.1.10:40000", &encoded(&forwarded)))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let json = json_body(response).await;
+    assert_eq!(json["subject_id"], bearer_subject.to_string());
+    assert_eq!(json["header_seen"], false);
+}
+
+#[tokio::test]
+async fn malformed_context_from_trusted_peer_is_rejected() {
+    let router = router(Uuid::new_v4()).await;
+
+    let bad_version = STANDARD.encode([0xFF, 0x00, 0x01]);
+    for bad in ["not base64!", bad_version.as_str()] {
+        let response = router
+            .clone()
+            .oneshot(request("10.1.2.3:40000", bad))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{bad}");
+        let json = json_body(response).await;
+        assert_eq!(
+            json["type"],
+            api_gateway::middleware::internal_auth::INVALID_FORWARDED_CONTEXT_TYPE
+        );
+    }
+}
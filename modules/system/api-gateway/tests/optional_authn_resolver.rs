@@ -0,0 +1,111 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Booting the gateway through the module runtime without the optional
+//! `authn-resolver` module.
+//!
+//! These tests verify that:
+//! 1. With auth disabled, the gateway starts and reports ready
+//! 2. With auth enabled, startup fails with an error naming the missing module
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use modkit::{
+    ClientHub, ModuleRegistry,
+    config::ConfigProvider,
+    context::ModuleCtx,
+    readiness::Readiness,
+    runtime::{DbOptions, HostRuntime},
+};
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// Stands in for the gateway's required `grpc-hub` dependency.
+#[derive(Default)]
+#[modkit::module(name = "grpc-hub")]
+pub struct StubGrpcHub;
+
+#[async_trait]
+impl modkit::Module for StubGrpcHub {
+    async fn init(&self, _ctx: &ModuleCtx) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        self.config.get(module)
+    }
+}
+
+fn runtime(auth_disabled: bool, cancel: &CancellationToken) -> HostRuntime {
+    let registry = ModuleRegistry::discover_and_build().unwrap();
+    let gateway = registry
+        .modules()
+        .iter()
+        .find(|m| m.name() == "api-gateway")
+        .unwrap();
+    assert_eq!(gateway.deps(), &["grpc-hub"]);
+    assert_eq!(gateway.optional_deps(), &["authn-resolver"]);
+    assert!(registry.get_module("authn-resolver").is_none());
+
+    let config = json!({
+        "api-gateway": {
+            "config": {
+                "bind_addr": "127.0.0.1:0",
+                "auth_disabled": auth_disabled,
+            }
+        }
+    });
+    HostRuntime::new(
+        registry,
+        Arc::new(TestConfigProvider { config }),
+        DbOptions::None,
+        Arc::new(ClientHub::new()),
+        cancel.clone(),
+        Uuid::new_v4(),
+        None,
+    )
+}
+
+#[tokio::test]
+async fn boots_without_authn_resolver_when_auth_is_disabled() {
+    let cancel = CancellationToken::new();
+    let runtime = runtime(true, &cancel);
+    let readiness = runtime.readiness();
+    let running = tokio::spawn(runtime.run_module_phases());
+
+    // `await_ready_of = ["authn-resolver"]` does not hold the gateway back.
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while readiness.get("api-gateway") != Some(Readiness::Ready) {
+            assert!(!running.is_finished(), "runtime stopped before ready");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("api-gateway did not report ready");
+
+    cancel.cancel();
+    running.await.unwrap().unwrap();
+}
+
+#[tokio::test]
+async fn auth_enabled_without_authn_resolver_fails_startup() {
+    let cancel = CancellationToken::new();
+    let err = runtime(false, &cancel)
+        .run_module_phases()
+        .await
+        .unwrap_err();
+
+    let message = format!("{err:#}");
+    assert!(
+        message.contains("auth is enabled but the `authn-resolver` module is not loaded"),
+        "{message}"
+    );
+}
@@ -93,6 +93,29 @@ async fn includes_request_id_in_error_json() {
     assert_eq!(json["request_id"], "error-test-123");
 }
 
+#[tokio::test]
+async fn handler_runs_in_request_id_scope() {
+    let app = test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/scoped")
+                .header("x-request-id", "scoped-456")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["current"], "scoped-456");
+}
+
 // Test app with success and error routes
 fn test_app() -> Router {
     use axum::middleware::from_fn;
@@ -102,7 +125,8 @@ fn test_app() -> Router {
 
     let routes = Router::new()
         .route("/test", get(success_handler))
-        .route("/error", get(error_handler));
+        .route("/error", get(error_handler))
+        .route("/scoped", get(scoped_handler));
 
     Router::new()
         .merge(routes)
@@ -131,3 +155,7 @@ async fn error_handler(
         })),
     )
 }
+
+async fn scoped_handler() -> Json<serde_json::Value> {
+    Json(json!({ "current": modkit::http::request_id::current() }))
+}
@@ -0,0 +1,276 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Integration tests for the startup route conflict report and `strict_routes` mode
+
+use anyhow::Result;
+use async_trait::async_trait;
+use axum::{Router, extract::Json};
+use modkit::{
+    Module, ModuleCtx, RestApiCapability,
+    api::OperationBuilder,
+    config::ConfigProvider,
+    contracts::{ApiGatewayCapability, OpenApiRegistry},
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use api_gateway::route_conflicts::RouteConflict;
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        if module == "api-gateway" {
+            Some(&self.config)
+        } else {
+            None
+        }
+    }
+}
+
+fn create_test_module_ctx(strict_routes: bool) -> ModuleCtx {
+    let config = serde_json::json!({
+        "config": {
+            "bind_addr": "127.0.0.1:0",
+            "auth_disabled": true,
+            "strict_routes": strict_routes,
+        }
+    });
+
+    ModuleCtx::new(
+        "api-gateway",
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config }),
+        Arc::new(modkit::ClientHub::new()),
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    )
+}
+
+/// Test module that registers `GET /tests/v1/items` under its own operation id.
+struct ItemsModule {
+    operation_id: &'static str,
+}
+
+#[async_trait]
+impl Module for ItemsModule {
+    async fn init(&self, _ctx: &modkit::ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl RestApiCapability for ItemsModule {
+    fn register_rest(
+        &self,
+        _ctx: &modkit::ModuleCtx,
+        router: axum::Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> Result<axum::Router> {
+        let router = OperationBuilder::get("/tests/v1/items")
+            .operation_id(self.operation_id)
+            .public()
+            .handler(items_handler)
+            .json_response(http::StatusCode::OK, "Items")
+            .register(router, openapi);
+
+        Ok(router)
+    }
+}
+
+async fn items_handler() -> Json<serde_json::Value> {
+    Json(serde_json::json!([]))
+}
+
+/// Test module whose item routes overlap (`/items/latest` vs `/items/{id}`)
+/// without duplicating each other.
+struct OverlappingItemsModule;
+
+#[async_trait]
+impl Module for OverlappingItemsModule {
+    async fn init(&self, _ctx: &modkit::ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl RestApiCapability for OverlappingItemsModule {
+    fn register_rest(
+        &self,
+        _ctx: &modkit::ModuleCtx,
+        router: axum::Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> Result<axum::Router> {
+        let router = OperationBuilder::get("/tests/v1/items/{id}")
+            .operation_id("items.get_item")
+            .public()
+            .handler(items_handler)
+            .json_response(http::StatusCode::OK, "Item")
+            .register(router, openapi);
+        let router = OperationBuilder::get("/tests/v1/items/latest")
+            .operation_id("items.latest_item")
+            .public()
+            .handler(items_handler)
+            .json_response(http::StatusCode::OK, "Latest item")
+            .register(router, openapi);
+
+        Ok(router)
+    }
+}
+
+/// Register the same `(method, path)` from two modules.
+///
+/// Each module gets its own router so Axum does not panic on the overlapping
+/// method route; only the first router is finalized, as the registry keeps the
+/// first registration.
+async fn register_conflicting(api: &api_gateway::ApiGateway, ctx: &ModuleCtx) -> Router {
+    api.init(ctx).await.expect("Failed to init");
+
+    let first = ItemsModule {
+        operation_id: "first.list_items",
+    };
+    let second = ItemsModule {
+        operation_id: "second.list_items",
+    };
+
+    let router = first.register_rest(ctx, Router::new(), api).unwrap();
+    let _ignored = second.register_rest(ctx, Router::new(), api).unwrap();
+    router
+}
+
+#[tokio::test]
+async fn conflicting_registrations_produce_report() {
+    let api = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(false);
+    let _router = register_conflicting(&api, &ctx).await;
+
+    let report = api.route_conflict_report();
+
+    assert_eq!(report.duplicates(), 1);
+    let Some(RouteConflict::DuplicateRoute {
+        method,
+        path,
+        handler_ids,
+        operation_ids,
+    }) = report.conflicts.first()
+    else {
+        panic!("expected duplicate route conflict, got: {report}");
+    };
+    assert_eq!(*method, http::Method::GET);
+    assert_eq!(path, "/tests/v1/items");
+    assert_eq!(handler_ids.len(), 2);
+    assert_eq!(operation_ids, &["first.list_items", "second.list_items"]);
+}
+
+#[tokio::test]
+async fn strict_routes_fails_finalize_on_conflicts() {
+    let api = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(true);
+    let router = register_conflicting(&api, &ctx).await;
+
+    let err = api
+        .rest_finalize(&ctx, router)
+        .expect_err("strict mode must reject conflicting routes");
+
+    let message = err.to_string();
+    assert!(message.contains("strict_routes"), "{message}");
+    assert!(
+        message.contains("duplicate route GET /tests/v1/items"),
+        "{message}"
+    );
+}
+
+#[cfg(debug_assertions)]
+#[tokio::test]
+async fn duplicate_routes_fail_finalize_in_debug_builds() {
+    let api = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(false);
+    let router = register_conflicting(&api, &ctx).await;
+
+    let err = api
+        .rest_finalize(&ctx, router)
+        .expect_err("duplicates must fail debug builds even without strict_routes");
+
+    let message = err.to_string();
+    assert!(
+        message.starts_with("Route conflicts detected: duplicate route GET /tests/v1/items"),
+        "{message}"
+    );
+}
+
+#[tokio::test]
+async fn non_strict_finalize_serves_overlapping_routes() {
+    let api = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(false);
+    api.init(&ctx).await.expect("Failed to init");
+    let router = OverlappingItemsModule
+        .register_rest(&ctx, Router::new(), &api)
+        .unwrap();
+
+    let report = api.route_conflict_report();
+    assert_eq!(report.duplicates(), 0, "{report}");
+    assert!(
+        matches!(
+            report.conflicts.as_slice(),
+            [RouteConflict::Overlap { preferred, shadowed, .. }]
+                if preferred == "/tests/v1/items/latest" && shadowed == "/tests/v1/items/{id}"
+        ),
+        "{report}"
+    );
+
+    api.rest_finalize(&ctx, router)
+        .expect("overlaps are only logged without strict_routes");
+
+    let doc = api.build_openapi().expect("openapi");
+    let v = serde_json::to_value(&doc).expect("json");
+    for (pointer, operation_id) in [
+        (
+            "/paths/~1tests~1v1~1items~1{id}/get/operationId",
+            "items.get_item",
+        ),
+        (
+            "/paths/~1tests~1v1~1items~1latest/get/operationId",
+            "items.latest_item",
+        ),
+    ] {
+        assert_eq!(
+            v.pointer(pointer).and_then(|id| id.as_str()),
+            Some(operation_id)
+        );
+    }
+}
+
+#[cfg(not(debug_assertions))]
+#[tokio::test]
+async fn non_strict_finalize_keeps_first_registration() {
+    let api = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(false);
+    let router = register_conflicting(&api, &ctx).await;
+
+    api.rest_finalize(&ctx, router)
+        .expect("release builds only log duplicate routes");
+
+    let doc = api.build_openapi().expect("openapi");
+    let v = serde_json::to_value(&doc).expect("json");
+    assert_eq!(
+        v.pointer("/paths/~1tests~1v1~1items/get/operationId")
+            .and_then(|id| id.as_str()),
+        Some("first.list_items"),
+    );
+}
+
+#[tokio::test]
+async fn strict_routes_accepts_clean_route_table() {
+    let api = api_gateway::ApiGateway::default();
+    let ctx = create_test_module_ctx(true);
+    api.init(&ctx).await.expect("Failed to init");
+
+    let module = ItemsModule {
+        operation_id: "items.list_items",
+    };
+    let router = module.register_rest(&ctx, Router::new(), &api).unwrap();
+
+    assert!(api.route_conflict_report().is_empty());
+    api.rest_finalize(&ctx, router)
+        .expect("clean route table passes strict mode");
+}
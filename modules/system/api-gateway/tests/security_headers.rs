@@ -0,0 +1,215 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! Security response headers added by the gateway
+
+use anyhow::Result;
+use async_trait::async_trait;
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, StatusCode, header},
+    response::IntoResponse,
+};
+use modkit::{
+    ClientHub, Module,
+    api::OperationBuilder,
+    config::ConfigProvider,
+    context::ModuleCtx,
+    contracts::{ApiGatewayCapability, OpenApiRegistry, RestApiCapability},
+};
+use serde_json::json;
+use std::sync::Arc;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+struct TestConfigProvider {
+    config: serde_json::Value,
+}
+
+impl ConfigProvider for TestConfigProvider {
+    fn get_module_config(&self, module: &str) -> Option<&serde_json::Value> {
+        self.config.get(module)
+    }
+}
+
+fn create_ctx(name: &str, config: serde_json::Value) -> ModuleCtx {
+    ModuleCtx::new(
+        name,
+        Uuid::new_v4(),
+        Arc::new(TestConfigProvider { config }),
+        Arc::new(ClientHub::new()),
+        tokio_util::sync::CancellationToken::new(),
+        None,
+    )
+}
+
+fn gateway_config(security_headers: Option<serde_json::Value>) -> serde_json::Value {
+    let mut config = json!({
+        "bind_addr": "0.0.0.0:8080",
+        "auth_disabled": true
+    });
+    if let Some(headers) = security_headers {
+        config["security_headers"] = headers;
+    }
+    json!({ "api-gateway": { "config": config } })
+}
+
+pub struct TestModule;
+
+#[async_trait]
+impl Module for TestModule {
+    async fn init(&self, _ctx: &ModuleCtx) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl RestApiCapability for TestModule {
+    fn register_rest(
+        &self,
+        _ctx: &ModuleCtx,
+        router: Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> Result<Router> {
+        let router = OperationBuilder::get("/tests/v1/headers/plain")
+            .operation_id("test.headers.plain")
+            .public()
+            .handler(|| async { StatusCode::OK })
+            .json_response(http::StatusCode::OK, "OK")
+            .register(router, openapi);
+
+        let router = OperationBuilder::get("/tests/v1/headers/framed")
+            .operation_id("test.headers.framed")
+            .public()
+            .handler(|| async {
+                ([(header::X_FRAME_OPTIONS, "SAMEORIGIN")], StatusCode::OK).into_response()
+            })
+            .json_response(http::StatusCode::OK, "OK")
+            .register(router, openapi);
+
+        Ok(router)
+    }
+}
+
+async fn finalized_router(config: serde_json::Value) -> Router {
+    let api_ctx = create_ctx("api-gateway", config);
+    let test_ctx = create_ctx("test_module", json!({}));
+
+    let api_gateway = api_gateway::ApiGateway::default();
+    api_gateway.init(&api_ctx).await.expect("Failed to init");
+
+    let router = TestModule
+        .register_rest(&test_ctx, Router::new(), &api_gateway)
+        .expect("Failed to register routes");
+
+    api_gateway
+        .rest_finalize(&api_ctx, router)
+        .expect("Failed to finalize")
+}
+
+async fn get(router: Router, uri: &str) -> axum::response::Response {
+    router
+        .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+        .await
+        .expect("Request failed")
+}
+
+fn header_value<'a>(
+    response: &'a axum::response::Response,
+    name: header::HeaderName,
+) -> Option<&'a str> {
+    response.headers().get(name).and_then(|v| v.to_str().ok())
+}
+
+#[tokio::test]
+async fn defaults_to_nosniff_without_hsts() {
+    let router = finalized_router(gateway_config(None)).await;
+
+    let response = get(router, "/tests/v1/headers/plain").await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        header_value(&response, header::X_CONTENT_TYPE_OPTIONS),
+        Some("nosniff")
+    );
+    assert!(
+        response
+            .headers()
+            .get(header::STRICT_TRANSPORT_SECURITY)
+            .is_none()
+    );
+    assert!(response.headers().get(header::X_FRAME_OPTIONS).is_none());
+    assert!(response.headers().get(header::REFERRER_POLICY).is_none());
+}
+
+#[tokio::test]
+async fn configured_headers_are_added_to_every_response() {
+    let config = gateway_config(Some(json!({
+        "hsts_max_age": 31_536_000,
+        "x_frame_options": "DENY",
+        "referrer_policy": "no-referrer"
+    })));
+
+    for uri in ["/tests/v1/headers/plain", "/tests/v1/headers/missing"] {
+        let response = get(finalized_router(config.clone()).await, uri).await;
+
+        assert_eq!(
+            header_value(&response, header::STRICT_TRANSPORT_SECURITY),
+            Some("max-age=31536000"),
+            "{uri}"
+        );
+        assert_eq!(
+            header_value(&response, header::X_CONTENT_TYPE_OPTIONS),
+            Some("nosniff"),
+            "{uri}"
+        );
+        assert_eq!(
+            header_value(&response, header::X_FRAME_OPTIONS),
+            Some("DENY"),
+            "{uri}"
+        );
+        assert_eq!(
+            header_value(&response, header::REFERRER_POLICY),
+            Some("no-referrer"),
+            "{uri}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn handler_headers_take_precedence() {
+    let config = gateway_config(Some(json!({ "x_frame_options": "DENY" })));
+    let router = finalized_router(config).await;
+
+    let response = get(router, "/tests/v1/headers/framed").await;
+
+    assert_eq!(
+        header_value(&response, header::X_FRAME_OPTIONS),
+        Some("SAMEORIGIN")
+    );
+}
+
+#[tokio::test]
+async fn nosniff_can_be_disabled() {
+    let config = gateway_config(Some(json!({ "x_content_type_options": false })));
+    let router = finalized_router(config).await;
+
+    let response = get(router, "/tests/v1/headers/plain").await;
+
+    assert!(
+        response
+            .headers()
+            .get(header::X_CONTENT_TYPE_OPTIONS)
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn invalid_header_value_fails_init() {
+    let config = gateway_config(Some(json!({ "referrer_policy": "no-referrer\n" })));
+    let api_ctx = create_ctx("api-gateway", config);
+
+    let api_gateway = api_gateway::ApiGateway::default();
+    let err = api_gateway.init(&api_ctx).await.unwrap_err();
+
+    assert!(format!("{err:#}").contains("referrer_policy"), "{err:#}");
+}
@@ -32,11 +32,14 @@ pub trait AuthNResolverClient: Send + Sync {
     ///
     /// # Arguments
     ///
-    /// * `bearer_token` - The raw bearer token string (without "Bearer " prefix)
+    /// * `bearer_token` - The raw bearer token string (without "Bearer " prefix), or an
+    ///   `mtls:`-prefixed client certificate credential (see [`crate::MTLS_CREDENTIAL_PREFIX`])
     ///
     /// # Errors
     ///
-    /// - `Unauthorized` if the token is invalid, expired, or malformed
+    /// - `InvalidToken` if the token is malformed or fails verification
+    /// - `TokenExpired` if the token has expired
+    /// - `Unauthorized` if the credential is rejected for any other reason
     /// - `NoPluginAvailable` if no `AuthN` plugin is registered
     /// - `ServiceUnavailable` if the plugin is not ready
     /// - `Internal` for unexpected errors
@@ -5,10 +5,22 @@ use thiserror::Error;
 /// Errors that can occur when using the `AuthN` resolver API.
 #[derive(Debug, Error)]
 pub enum AuthNResolverError {
-    /// The token is invalid, expired, or malformed.
+    /// The request could not be authenticated.
     #[error("unauthorized: {0}")]
     Unauthorized(String),
 
+    /// The token is malformed, revoked, or its signature does not verify.
+    #[error("invalid token: {0}")]
+    InvalidToken(String),
+
+    /// The token, or its subject, has been revoked.
+    #[error("revoked: {0}")]
+    Revoked(String),
+
+    /// The token was valid but has expired.
+    #[error("token expired: {0}")]
+    TokenExpired(String),
+
     /// No `AuthN` plugin is available to handle the request.
     #[error("no plugin available")]
     NoPluginAvailable,
@@ -21,3 +33,80 @@ pub enum AuthNResolverError {
     #[error("internal error: {0}")]
     Internal(String),
 }
+
+impl AuthNResolverError {
+    /// `WWW-Authenticate` header value for a 401 response (RFC 6750 §3).
+    ///
+    /// Returns `None` for errors that do not map to 401.
+    #[must_use]
+    pub fn www_authenticate_hint(&self) -> Option<String> {
+        match self {
+            Self::Unauthorized(_) => Some("Bearer".to_owned()),
+            Self::InvalidToken(_) => Some(r#"Bearer error="invalid_token""#.to_owned()),
+            Self::TokenExpired(_) => Some(
+                r#"Bearer error="invalid_token", error_description="The access token expired""#
+                    .to_owned(),
+            ),
+            Self::Revoked(_) => Some(
+                r#"Bearer error="invalid_token", error_description="The access token has been revoked""#
+                    .to_owned(),
+            ),
+            Self::NoPluginAvailable | Self::ServiceUnavailable(_) | Self::Internal(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn www_authenticate_hint_for_token_errors() {
+        assert_eq!(
+            AuthNResolverError::InvalidToken("bad signature".to_owned())
+                .www_authenticate_hint()
+                .as_deref(),
+            Some(r#"Bearer error="invalid_token""#)
+        );
+        assert_eq!(
+            AuthNResolverError::TokenExpired("exp in the past".to_owned())
+                .www_authenticate_hint()
+                .as_deref(),
+            Some(r#"Bearer error="invalid_token", error_description="The access token expired""#)
+        );
+        assert_eq!(
+            AuthNResolverError::Unauthorized("no credential".to_owned())
+                .www_authenticate_hint()
+                .as_deref(),
+            Some("Bearer")
+        );
+        assert_eq!(
+            AuthNResolverError::Revoked("subject revoked".to_owned())
+                .www_authenticate_hint()
+                .as_deref(),
+            Some(
+                r#"Bearer error="invalid_token", error_description="The access token has been revoked""#
+            )
+        );
+    }
+
+    #[test]
+    fn www_authenticate_hint_absent_for_non_401_errors() {
+        assert!(
+            AuthNResolverError::NoPluginAvailable
+                .www_authenticate_hint()
+                .is_none()
+        );
+        assert!(
+            AuthNResolverError::ServiceUnavailable("down".to_owned())
+                .www_authenticate_hint()
+                .is_none()
+        );
+        assert!(
+            AuthNResolverError::Internal("boom".to_owned())
+                .www_authenticate_hint()
+                .is_none()
+        );
+    }
+}
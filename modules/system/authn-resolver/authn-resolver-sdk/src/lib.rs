@@ -7,6 +7,7 @@
 //! - [`AuthenticationResult`] - Authentication result model
 //! - [`AuthNResolverError`] - Error types
 //! - [`AuthNResolverPluginSpecV1`] - GTS schema for plugin discovery
+//! - [`AuthNRevocationClient`] / [`RevocationSource`] - Token and subject revocation
 //!
 //! ## Usage
 //!
@@ -28,10 +29,14 @@ pub mod error;
 pub mod gts;
 pub mod models;
 pub mod plugin_api;
+pub mod revocation;
 
 // Re-export main types at crate root
 pub use api::AuthNResolverClient;
 pub use error::AuthNResolverError;
 pub use gts::AuthNResolverPluginSpecV1;
-pub use models::AuthenticationResult;
+pub use models::{AuthenticationResult, MTLS_CREDENTIAL_PREFIX};
 pub use plugin_api::AuthNResolverPluginClient;
+pub use revocation::{
+    AuthNRevocationClient, Revocation, RevocationSource, RevocationTarget, token_hash,
+};
@@ -2,6 +2,16 @@
 
 use modkit_security::SecurityContext;
 
+/// Credential scheme for identities proven by a TLS client certificate.
+///
+/// When the gateway terminates mTLS and a request has no bearer token, it passes
+/// `mtls:<subject DN>` to [`crate::AuthNResolverClient::authenticate`] instead,
+/// e.g. `mtls:CN=billing-service, O=Example`. Plugins that do not recognize the
+/// scheme reject it like any other unknown token. The gateway rejects bearer
+/// tokens and API keys that start with this prefix, so plugins only see it for
+/// certificates the TLS layer verified.
+pub const MTLS_CREDENTIAL_PREFIX: &str = "mtls:";
+
 /// Result of a successful authentication.
 ///
 /// Contains the validated `SecurityContext` with identity information
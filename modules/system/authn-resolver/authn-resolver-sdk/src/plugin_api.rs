@@ -26,7 +26,9 @@ pub trait AuthNResolverPluginClient: Send + Sync {
     ///
     /// # Errors
     ///
-    /// - `Unauthorized` if the token is invalid, expired, or malformed
+    /// - `InvalidToken` if the token is malformed or fails verification
+    /// - `TokenExpired` if the token has expired
+    /// - `Unauthorized` if the credential is rejected for any other reason
     /// - `Internal` for unexpected errors
     async fn authenticate(
         &self,
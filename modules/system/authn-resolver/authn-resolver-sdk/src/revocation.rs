@@ -0,0 +1,140 @@
+//! Revocation of tokens and subjects.
+//!
+//! Revocations reject credentials that would otherwise still authenticate
+//! (stolen tokens, disabled users) until they expire. The resolver keeps a
+//! local revocation list fed by [`RevocationSource`]s and consulted on every
+//! `authenticate` call; other modules push revocations through
+//! [`AuthNRevocationClient`].
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_core::stream::BoxStream;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::error::AuthNResolverError;
+
+/// What a revocation applies to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RevocationTarget {
+    /// A single token, identified by [`token_hash`].
+    TokenHash(String),
+    /// Every token of a subject authenticated in `tenant_id`.
+    ///
+    /// Scoped by tenant so a revocation issued for one tenant cannot lock out
+    /// a subject of another.
+    Subject { tenant_id: Uuid, subject_id: Uuid },
+}
+
+/// A revocation pushed to the resolver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Revocation {
+    pub target: RevocationTarget,
+    /// How long the revocation is kept.
+    ///
+    /// Should cover the remaining lifetime of the revoked tokens. `None` uses
+    /// the resolver's maximum; longer values are capped to it.
+    pub ttl: Option<Duration>,
+}
+
+impl Revocation {
+    /// Revoke a single token by its hash (see [`token_hash`]).
+    #[must_use]
+    pub fn token_hash(hash: impl Into<String>) -> Self {
+        Self {
+            target: RevocationTarget::TokenHash(hash.into()),
+            ttl: None,
+        }
+    }
+
+    /// Revoke every token of a subject whose home tenant is `tenant_id`.
+    #[must_use]
+    pub fn subject(tenant_id: Uuid, subject_id: Uuid) -> Self {
+        Self {
+            target: RevocationTarget::Subject {
+                tenant_id,
+                subject_id,
+            },
+            ttl: None,
+        }
+    }
+
+    /// Keep the revocation for `ttl` instead of the resolver's maximum.
+    #[must_use]
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// Hash identifying a token in revocations: lowercase hex SHA-256 of the raw
+/// credential, exactly as passed to `authenticate`.
+///
+/// Revocations never carry the token itself.
+#[must_use]
+pub fn token_hash(bearer_token: &str) -> String {
+    hex::encode(Sha256::digest(bearer_token.as_bytes()))
+}
+
+/// A source of revocations the resolver subscribes to.
+///
+/// Everything published on [`changes`](Self::changes) is kept in the resolver's
+/// local list. [`check`](Self::check) is consulted after every successful plugin
+/// call, so sources that publish all their revocations as changes should answer
+/// it from memory (or just return `Ok(false)`).
+#[async_trait]
+pub trait RevocationSource: Send + Sync {
+    /// Whether `target` is revoked according to this source.
+    ///
+    /// # Errors
+    ///
+    /// Any error rejects the authentication (revocation checks fail closed).
+    async fn check(&self, target: &RevocationTarget) -> Result<bool, AuthNResolverError>;
+
+    /// Revocations published from now on. The stream may end when the source
+    /// shuts down.
+    fn changes(&self) -> BoxStream<'static, Revocation>;
+}
+
+/// Client for pushing revocations to the `AuthN` resolver.
+///
+/// Registered in `ClientHub` next to [`crate::AuthNResolverClient`]:
+///
+/// ```ignore
+/// let revocations = hub.get::<dyn AuthNRevocationClient>()?;
+/// revocations.revoke(Revocation::subject(tenant_id, user_id)).await?;
+/// ```
+#[async_trait]
+pub trait AuthNRevocationClient: Send + Sync {
+    /// Revoke a token or a subject. Takes effect for the next `authenticate`
+    /// call, regardless of any cached authentication.
+    ///
+    /// # Errors
+    ///
+    /// - `Internal` if the revocation cannot be recorded
+    async fn revoke(&self, revocation: Revocation) -> Result<(), AuthNResolverError>;
+
+    /// Subscribe the resolver to an additional revocation source.
+    ///
+    /// # Errors
+    ///
+    /// - `Internal` if the source cannot be subscribed
+    async fn add_source(&self, source: Arc<dyn RevocationSource>)
+    -> Result<(), AuthNResolverError>;
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_hash_is_hex_sha256() {
+        assert_eq!(
+            token_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
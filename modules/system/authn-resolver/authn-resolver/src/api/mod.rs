@@ -0,0 +1,3 @@
+//! API layer for the `AuthN` resolver.
+
+pub mod rest;
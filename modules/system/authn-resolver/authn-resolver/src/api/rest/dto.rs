@@ -0,0 +1,53 @@
+//! REST DTOs for the `AuthN` resolver.
+
+use std::time::Duration;
+
+use authn_resolver_sdk::Revocation;
+use uuid::Uuid;
+
+/// Revoke a token or every token of a subject.
+///
+/// Exactly one of `token_hash` and `subject_id` must be set. Subject
+/// revocations apply to the subject in `tenant_id`, which must be the caller's
+/// own tenant.
+#[derive(Debug)]
+#[modkit_macros::api_dto(request)]
+pub struct RevocationRequest {
+    /// Lowercase hex SHA-256 of the raw token.
+    #[serde(default)]
+    pub token_hash: Option<String>,
+    /// Subject whose tokens are all revoked.
+    #[serde(default)]
+    pub subject_id: Option<Uuid>,
+    /// Home tenant of `subject_id`. Defaults to the caller's tenant.
+    #[serde(default)]
+    pub tenant_id: Option<Uuid>,
+    /// How long to keep the revocation, in seconds. Defaults to (and is
+    /// capped at) the configured maximum.
+    #[serde(default)]
+    pub ttl_secs: Option<u64>,
+}
+
+impl RevocationRequest {
+    /// Convert to a [`Revocation`], or describe why the request is invalid.
+    ///
+    /// `caller_tenant_id` is used when a subject revocation names no tenant.
+    pub fn into_revocation(self, caller_tenant_id: Uuid) -> Result<Revocation, &'static str> {
+        let revocation = match (self.token_hash, self.subject_id) {
+            (Some(hash), None) => {
+                if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err("token_hash must be a hex-encoded SHA-256 digest");
+                }
+                Revocation::token_hash(hash.to_ascii_lowercase())
+            }
+            (None, Some(subject_id)) => {
+                Revocation::subject(self.tenant_id.unwrap_or(caller_tenant_id), subject_id)
+            }
+            _ => return Err("exactly one of token_hash and subject_id must be set"),
+        };
+        Ok(match self.ttl_secs {
+            Some(secs) => revocation.with_ttl(Duration::from_secs(secs)),
+            None => revocation,
+        })
+    }
+}
@@ -0,0 +1,180 @@
+//! REST handlers for the `AuthN` resolver.
+
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::Extension;
+use modkit::api::prelude::*;
+use modkit_security::SecurityContext;
+
+use super::dto::RevocationRequest;
+use super::routes::RevocationsState;
+
+/// POST /authn/v1/revocations
+///
+/// Revoke a token or a subject. Requires the configured admin scope, granted
+/// literally: `*` and unrestricted (empty) scopes do not count. Subjects can
+/// only be revoked in the caller's own tenant.
+pub async fn create_revocation(
+    Extension(ctx): Extension<SecurityContext>,
+    Extension(state): Extension<Arc<RevocationsState>>,
+    Json(req): Json<RevocationRequest>,
+) -> ApiResult<impl IntoResponse> {
+    if !ctx
+        .token_scopes()
+        .iter()
+        .any(|scope| *scope == state.admin_scope)
+    {
+        return Err(Problem::new(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            format!("Token scope '{}' is required", state.admin_scope),
+        ));
+    }
+    if req
+        .tenant_id
+        .is_some_and(|tenant_id| tenant_id != ctx.subject_tenant_id())
+    {
+        return Err(Problem::new(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "Subjects can only be revoked in the caller's tenant",
+        ));
+    }
+
+    let revocation = req
+        .into_revocation(ctx.subject_tenant_id())
+        .map_err(|msg| Problem::new(StatusCode::BAD_REQUEST, "Bad Request", msg))?;
+    tracing::info!(
+        requester_id = %ctx.subject_id(),
+        target = ?revocation.target,
+        ttl = ?revocation.ttl,
+        "Revocation recorded via REST"
+    );
+    state.revocations.revoke(revocation);
+
+    Ok(no_content())
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::config::RevocationConfig;
+    use crate::domain::RevocationList;
+    use authn_resolver_sdk::{RevocationTarget, token_hash};
+    use uuid::Uuid;
+
+    const ADMIN_SCOPE: &str = "authn:revocations:write";
+
+    fn state() -> Arc<RevocationsState> {
+        Arc::new(RevocationsState {
+            revocations: Arc::new(RevocationList::new(&RevocationConfig::default())),
+            admin_scope: ADMIN_SCOPE.to_owned(),
+        })
+    }
+
+    fn caller(tenant_id: Uuid, scopes: &[&str]) -> SecurityContext {
+        SecurityContext::builder()
+            .subject_id(Uuid::new_v4())
+            .subject_tenant_id(tenant_id)
+            .token_scopes(scopes.iter().map(|s| (*s).to_owned()).collect())
+            .build()
+            .unwrap()
+    }
+
+    fn revoke_subject(subject_id: Uuid, tenant_id: Option<Uuid>) -> RevocationRequest {
+        RevocationRequest {
+            token_hash: None,
+            subject_id: Some(subject_id),
+            tenant_id,
+            ttl_secs: None,
+        }
+    }
+
+    async fn status(
+        ctx: SecurityContext,
+        state: &Arc<RevocationsState>,
+        req: RevocationRequest,
+    ) -> StatusCode {
+        match create_revocation(Extension(ctx), Extension(state.clone()), Json(req)).await {
+            Ok(response) => response.into_response().status(),
+            Err(problem) => problem.status,
+        }
+    }
+
+    #[tokio::test]
+    async fn admin_scope_revokes_a_subject_of_its_tenant() {
+        let state = state();
+        let tenant_id = Uuid::new_v4();
+        let subject_id = Uuid::new_v4();
+
+        let status = status(
+            caller(tenant_id, &[ADMIN_SCOPE]),
+            &state,
+            revoke_subject(subject_id, None),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(state.revocations.is_revoked(&RevocationTarget::Subject {
+            tenant_id,
+            subject_id,
+        }));
+    }
+
+    #[tokio::test]
+    async fn wildcard_and_unrestricted_scopes_are_forbidden() {
+        let state = state();
+        for scopes in [&["*"][..], &[]] {
+            let status = status(
+                caller(Uuid::new_v4(), scopes),
+                &state,
+                revoke_subject(Uuid::new_v4(), None),
+            )
+            .await;
+            assert_eq!(status, StatusCode::FORBIDDEN, "scopes {scopes:?}");
+        }
+        assert!(state.revocations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn subjects_of_another_tenant_are_forbidden() {
+        let state = state();
+
+        let status = status(
+            caller(Uuid::new_v4(), &[ADMIN_SCOPE]),
+            &state,
+            revoke_subject(Uuid::new_v4(), Some(Uuid::new_v4())),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(state.revocations.is_empty());
+    }
+
+    #[tokio::test]
+    async fn token_hashes_are_revoked() {
+        let state = state();
+        let hash = token_hash("stolen");
+
+        let status = status(
+            caller(Uuid::new_v4(), &[ADMIN_SCOPE]),
+            &state,
+            RevocationRequest {
+                token_hash: Some(hash.clone()),
+                subject_id: None,
+                tenant_id: None,
+                ttl_secs: None,
+            },
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(
+            state
+                .revocations
+                .is_revoked(&RevocationTarget::TokenHash(hash))
+        );
+    }
+}
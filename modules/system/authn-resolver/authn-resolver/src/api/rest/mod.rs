@@ -0,0 +1,5 @@
+//! REST API layer for the `AuthN` resolver.
+
+pub mod dto;
+pub mod handlers;
+pub mod routes;
@@ -0,0 +1,59 @@
+//! REST route registration for the `AuthN` resolver.
+
+use std::sync::Arc;
+
+use axum::{Extension, Router};
+use modkit::api::OpenApiRegistry;
+use modkit::api::operation_builder::{LicenseFeature, OperationBuilder};
+use modkit::api::prelude::StatusCode;
+
+use super::dto::RevocationRequest;
+use super::handlers;
+use crate::domain::RevocationList;
+
+const TAG: &str = "authn";
+
+struct License;
+
+impl AsRef<str> for License {
+    fn as_ref(&self) -> &'static str {
+        "gts.x.core.lic.feat.v1~x.core.global.base.v1"
+    }
+}
+
+impl LicenseFeature for License {}
+
+/// State shared by the revocation handlers.
+pub struct RevocationsState {
+    pub revocations: Arc<RevocationList>,
+    /// Token scope required to push revocations.
+    pub admin_scope: String,
+}
+
+/// Registers all REST routes for the `AuthN` resolver.
+pub fn register_routes(
+    mut router: Router,
+    openapi: &dyn OpenApiRegistry,
+    state: Arc<RevocationsState>,
+) -> Router {
+    // POST /authn/v1/revocations - Revoke a token or a subject
+    router = OperationBuilder::post("/authn/v1/revocations")
+        .operation_id("authn_resolver.create_revocation")
+        .summary("Revoke a token or a subject")
+        .description(
+            "Reject a token (by SHA-256 hash) or every token of a subject until the revocation expires. Requires the configured admin scope.",
+        )
+        .tag(TAG)
+        .authenticated()
+        .require_license_features::<License>([])
+        .json_request::<RevocationRequest>(openapi, "Token hash or subject to revoke")
+        .handler(handlers::create_revocation)
+        .json_response(StatusCode::NO_CONTENT, "Revocation recorded")
+        .error_400(openapi)
+        .error_401(openapi)
+        .error_403(openapi)
+        .error_500(openapi)
+        .register(router, openapi);
+
+    router.layer(Extension(state))
+}
@@ -11,12 +11,53 @@ pub struct AuthNResolverConfig {
     /// The resolver queries types-registry for plugin instances matching
     /// this vendor and selects the one with lowest priority.
     pub vendor: String,
+
+    /// Fail startup when any registered plugin instance is invalid.
+    ///
+    /// When `false`, invalid instances are only logged and reported through
+    /// the `authn.plugins.health` admin command.
+    pub strict_plugins: bool,
+
+    /// Token and subject revocation list.
+    pub revocation: RevocationConfig,
 }
 
 impl Default for AuthNResolverConfig {
     fn default() -> Self {
         Self {
             vendor: "hyperspot".to_owned(),
+            strict_plugins: false,
+            revocation: RevocationConfig::default(),
+        }
+    }
+}
+
+/// Revocation list configuration.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RevocationConfig {
+    /// Maximum time a revocation is kept, in seconds. Should be at least the
+    /// lifetime of the longest-lived token accepted by the plugins.
+    pub max_ttl_secs: u64,
+
+    /// Maximum number of revocations kept in memory. When full, the entries
+    /// closest to expiry are dropped first.
+    pub max_entries: usize,
+
+    /// Size of the bloom filter in bits.
+    pub bloom_bits: usize,
+
+    /// Token scope required to call `POST /authn/v1/revocations`.
+    pub admin_scope: String,
+}
+
+impl Default for RevocationConfig {
+    fn default() -> Self {
+        Self {
+            max_ttl_secs: 24 * 60 * 60,
+            max_entries: 100_000,
+            bloom_bits: 1 << 20,
+            admin_scope: "authn:revocations:write".to_owned(),
         }
     }
 }
@@ -22,6 +22,15 @@ pub enum DomainError {
     #[error("unauthorized: {0}")]
     Unauthorized(String),
 
+    #[error("invalid token: {0}")]
+    InvalidToken(String),
+
+    #[error("token expired: {0}")]
+    TokenExpired(String),
+
+    #[error("revoked: {0}")]
+    Revoked(String),
+
     #[error("internal error: {0}")]
     Internal(String),
 }
@@ -61,6 +70,9 @@ impl From<AuthNResolverError> for DomainError {
     fn from(e: AuthNResolverError) -> Self {
         match e {
             AuthNResolverError::Unauthorized(msg) => Self::Unauthorized(msg),
+            AuthNResolverError::InvalidToken(msg) => Self::InvalidToken(msg),
+            AuthNResolverError::TokenExpired(msg) => Self::TokenExpired(msg),
+            AuthNResolverError::Revoked(msg) => Self::Revoked(msg),
             AuthNResolverError::NoPluginAvailable => Self::PluginNotFound {
                 vendor: "unknown".to_owned(),
             },
@@ -84,6 +96,9 @@ impl From<DomainError> for AuthNResolverError {
                 Self::ServiceUnavailable(format!("plugin not available for '{gts_id}': {reason}"))
             }
             DomainError::Unauthorized(msg) => Self::Unauthorized(msg),
+            DomainError::InvalidToken(msg) => Self::InvalidToken(msg),
+            DomainError::TokenExpired(msg) => Self::TokenExpired(msg),
+            DomainError::Revoked(msg) => Self::Revoked(msg),
             DomainError::TypesRegistryUnavailable(reason) | DomainError::Internal(reason) => {
                 Self::Internal(reason)
             }
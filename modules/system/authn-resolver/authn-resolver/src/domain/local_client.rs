@@ -3,10 +3,13 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use authn_resolver_sdk::{AuthNResolverClient, AuthNResolverError, AuthenticationResult};
+use authn_resolver_sdk::{
+    AuthNResolverClient, AuthNResolverError, AuthNRevocationClient, AuthenticationResult,
+    Revocation, RevocationSource,
+};
 use modkit_macros::domain_model;
 
-use super::{DomainError, Service};
+use super::{DomainError, RevocationList, Service};
 
 /// Local client wrapping the service.
 ///
@@ -40,3 +43,34 @@ impl AuthNResolverClient for AuthNResolverLocalClient {
             .map_err(|e| log_and_convert("authenticate", e))
     }
 }
+
+/// Local client for pushing revocations.
+///
+/// Registered in `ClientHub` by the module during `init()`.
+#[domain_model]
+pub struct AuthNRevocationLocalClient {
+    revocations: Arc<RevocationList>,
+}
+
+impl AuthNRevocationLocalClient {
+    #[must_use]
+    pub fn new(revocations: Arc<RevocationList>) -> Self {
+        Self { revocations }
+    }
+}
+
+#[async_trait]
+impl AuthNRevocationClient for AuthNRevocationLocalClient {
+    async fn revoke(&self, revocation: Revocation) -> Result<(), AuthNResolverError> {
+        self.revocations.revoke(revocation);
+        Ok(())
+    }
+
+    async fn add_source(
+        &self,
+        source: Arc<dyn RevocationSource>,
+    ) -> Result<(), AuthNResolverError> {
+        self.revocations.add_source(source);
+        Ok(())
+    }
+}
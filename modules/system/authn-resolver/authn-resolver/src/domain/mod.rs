@@ -2,8 +2,10 @@
 
 pub mod error;
 pub mod local_client;
+pub mod revocation;
 pub mod service;
 
 pub use error::DomainError;
-pub use local_client::AuthNResolverLocalClient;
+pub use local_client::{AuthNResolverLocalClient, AuthNRevocationLocalClient};
+pub use revocation::RevocationList;
 pub use service::Service;
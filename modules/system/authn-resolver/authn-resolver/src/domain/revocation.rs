@@ -0,0 +1,417 @@
+//! Local revocation list.
+//!
+//! Revoked token hashes and subject ids are kept in an exact map with a
+//! per-entry expiry, fronted by a bloom filter so the common case (nothing
+//! revoked) is answered without touching the map. A bloom filter cannot drop
+//! entries, so it is rebuilt whenever expired entries are purged.
+//!
+//! The list is also the resolver's default [`RevocationSource`]: revocations
+//! pushed through the admin endpoint or [`authn_resolver_sdk::AuthNRevocationClient`]
+//! are published on its [`changes`](RevocationSource::changes) stream.
+
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use authn_resolver_sdk::{AuthNResolverError, Revocation, RevocationSource, RevocationTarget};
+use futures_core::stream::BoxStream;
+use futures_util::StreamExt;
+use modkit_macros::domain_model;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::BroadcastStream;
+use uuid::Uuid;
+
+use super::error::DomainError;
+use crate::config::RevocationConfig;
+
+/// Number of bit positions set per entry.
+const BLOOM_HASHES: usize = 4;
+
+/// Buffer of the change stream; lagging subscribers miss older revocations.
+const CHANGES_CAPACITY: usize = 1024;
+
+struct BloomFilter {
+    bits: Vec<u64>,
+    hasher: RandomState,
+}
+
+impl BloomFilter {
+    fn new(bits: usize) -> Self {
+        Self {
+            bits: vec![0; bits.div_ceil(64).max(1)],
+            hasher: RandomState::new(),
+        }
+    }
+
+    // Positions are reduced modulo the bit count, which fits in `usize`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn positions(&self, target: &RevocationTarget) -> [usize; BLOOM_HASHES] {
+        let len = (self.bits.len() * 64) as u64;
+        let h1 = self.hasher.hash_one((0u8, target));
+        let h2 = self.hasher.hash_one((1u8, target)) | 1;
+        let mut positions = [0; BLOOM_HASHES];
+        for (i, pos) in (0u64..).zip(positions.iter_mut()) {
+            *pos = (h1.wrapping_add(i.wrapping_mul(h2)) % len) as usize;
+        }
+        positions
+    }
+
+    fn insert(&mut self, target: &RevocationTarget) {
+        for pos in self.positions(target) {
+            self.bits[pos / 64] |= 1 << (pos % 64);
+        }
+    }
+
+    fn may_contain(&self, target: &RevocationTarget) -> bool {
+        self.positions(target)
+            .into_iter()
+            .all(|pos| self.bits[pos / 64] & (1 << (pos % 64)) != 0)
+    }
+
+    fn clear(&mut self) {
+        self.bits.fill(0);
+    }
+}
+
+struct State {
+    entries: HashMap<RevocationTarget, Instant>,
+    bloom: BloomFilter,
+    /// Earliest expiry among `entries`.
+    next_expiry: Option<Instant>,
+}
+
+impl State {
+    fn purge_expired(&mut self, now: Instant) {
+        if self.next_expiry.is_none_or(|at| at > now) {
+            return;
+        }
+        self.entries.retain(|_, expires_at| *expires_at > now);
+        self.bloom.clear();
+        for target in self.entries.keys() {
+            self.bloom.insert(target);
+        }
+        self.next_expiry = self.entries.values().min().copied();
+    }
+
+    fn evict_closest_to_expiry(&mut self) {
+        let Some(target) = self
+            .entries
+            .iter()
+            .min_by_key(|(_, expires_at)| **expires_at)
+            .map(|(target, _)| target.clone())
+        else {
+            return;
+        };
+        // The bloom filter keeps the evicted bits until the next rebuild,
+        // which only costs an extra map lookup.
+        self.entries.remove(&target);
+        tracing::warn!(
+            entries = self.entries.len(),
+            "Revocation list is full, dropped the entry closest to expiry"
+        );
+    }
+}
+
+/// Revoked token hashes and subjects, with TTL-bounded memory.
+#[domain_model]
+pub struct RevocationList {
+    state: RwLock<State>,
+    max_ttl: Duration,
+    max_entries: usize,
+    changes: broadcast::Sender<Revocation>,
+    sources: RwLock<Vec<Arc<dyn RevocationSource>>>,
+}
+
+impl RevocationList {
+    #[must_use]
+    pub fn new(cfg: &RevocationConfig) -> Self {
+        let (changes, _) = broadcast::channel(CHANGES_CAPACITY);
+        Self {
+            state: RwLock::new(State {
+                entries: HashMap::new(),
+                bloom: BloomFilter::new(cfg.bloom_bits),
+                next_expiry: None,
+            }),
+            max_ttl: Duration::from_secs(cfg.max_ttl_secs),
+            max_entries: cfg.max_entries.max(1),
+            changes,
+            sources: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Record a revocation and publish it to subscribers of this list.
+    pub fn revoke(&self, revocation: Revocation) {
+        self.record(&revocation, Instant::now());
+        // No subscribers is fine.
+        _ = self.changes.send(revocation);
+    }
+
+    /// Whether `target` is currently revoked in the local list.
+    #[must_use]
+    pub fn is_revoked(&self, target: &RevocationTarget) -> bool {
+        self.is_revoked_at(target, Instant::now())
+    }
+
+    /// Number of revocations currently held, expired ones included until the
+    /// next purge.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.state
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entries
+            .len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Subscribe to `source`: its changes are recorded locally and its
+    /// [`check`](RevocationSource::check) is consulted by
+    /// [`ensure_not_revoked`](Self::ensure_not_revoked).
+    ///
+    /// Must be called from within a Tokio runtime. The subscription ends when
+    /// the source's stream ends or the list is dropped.
+    pub fn add_source(self: &Arc<Self>, source: Arc<dyn RevocationSource>) {
+        let mut changes = source.changes();
+        self.sources
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(source);
+
+        let list = Arc::downgrade(self);
+        tokio::spawn(async move {
+            while let Some(revocation) = changes.next().await {
+                let Some(list) = list.upgrade() else {
+                    break;
+                };
+                list.record(&revocation, Instant::now());
+            }
+        });
+    }
+
+    /// Local check of a token before the plugin is called.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Revoked` if the token is revoked.
+    pub fn ensure_token_not_revoked(&self, token_hash: &str) -> Result<(), DomainError> {
+        if self.is_revoked(&RevocationTarget::TokenHash(token_hash.to_owned())) {
+            return Err(DomainError::Revoked("token has been revoked".to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Full check of an authenticated token: the local list, then every
+    /// subscribed source.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Revoked` if the token or its subject (in the
+    /// subject's home tenant) is revoked, or the source's error if a source
+    /// cannot be checked.
+    pub async fn ensure_not_revoked(
+        &self,
+        token_hash: &str,
+        tenant_id: Uuid,
+        subject_id: Uuid,
+    ) -> Result<(), DomainError> {
+        let token = RevocationTarget::TokenHash(token_hash.to_owned());
+        let subject = RevocationTarget::Subject {
+            tenant_id,
+            subject_id,
+        };
+        if self.is_revoked(&token) {
+            return Err(DomainError::Revoked("token has been revoked".to_owned()));
+        }
+        if self.is_revoked(&subject) {
+            return Err(DomainError::Revoked("subject has been revoked".to_owned()));
+        }
+
+        let sources = self
+            .sources
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone();
+        for source in sources {
+            for (target, what) in [(&token, "token"), (&subject, "subject")] {
+                if source.check(target).await? {
+                    return Err(DomainError::Revoked(format!("{what} has been revoked")));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn record(&self, revocation: &Revocation, now: Instant) {
+        let ttl = revocation
+            .ttl
+            .map_or(self.max_ttl, |ttl| ttl.min(self.max_ttl));
+        let expires_at = now + ttl;
+
+        let mut state = self.state.write().unwrap_or_else(PoisonError::into_inner);
+        state.purge_expired(now);
+        if state.entries.len() >= self.max_entries
+            && !state.entries.contains_key(&revocation.target)
+        {
+            state.evict_closest_to_expiry();
+        }
+
+        let entry = state
+            .entries
+            .entry(revocation.target.clone())
+            .or_insert(expires_at);
+        *entry = (*entry).max(expires_at);
+        state.bloom.insert(&revocation.target);
+        state.next_expiry = Some(
+            state
+                .next_expiry
+                .map_or(expires_at, |at| at.min(expires_at)),
+        );
+    }
+
+    fn is_revoked_at(&self, target: &RevocationTarget, now: Instant) -> bool {
+        let state = self.state.read().unwrap_or_else(PoisonError::into_inner);
+        state.bloom.may_contain(target)
+            && state
+                .entries
+                .get(target)
+                .is_some_and(|expires_at| *expires_at > now)
+    }
+}
+
+#[async_trait]
+impl RevocationSource for RevocationList {
+    async fn check(&self, target: &RevocationTarget) -> Result<bool, AuthNResolverError> {
+        Ok(self.is_revoked(target))
+    }
+
+    fn changes(&self) -> BoxStream<'static, Revocation> {
+        BroadcastStream::new(self.changes.subscribe())
+            .filter_map(|res| async move { res.ok() })
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    fn list(max_entries: usize) -> Arc<RevocationList> {
+        Arc::new(RevocationList::new(&RevocationConfig {
+            max_ttl_secs: 60,
+            max_entries,
+            bloom_bits: 1024,
+            ..RevocationConfig::default()
+        }))
+    }
+
+    #[test]
+    fn entries_expire_after_their_ttl() {
+        let list = list(10);
+        let now = Instant::now();
+        let token = RevocationTarget::TokenHash("abc".to_owned());
+        list.record(
+            &Revocation::token_hash("abc").with_ttl(Duration::from_secs(5)),
+            now,
+        );
+
+        assert!(list.is_revoked_at(&token, now + Duration::from_secs(4)));
+        assert!(!list.is_revoked_at(&token, now + Duration::from_secs(5)));
+
+        // The next write purges the expired entry.
+        list.record(
+            &Revocation::subject(Uuid::new_v4(), Uuid::new_v4()),
+            now + Duration::from_secs(6),
+        );
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn ttl_is_capped_to_the_maximum() {
+        let list = list(10);
+        let now = Instant::now();
+        let revocation = Revocation::subject(Uuid::new_v4(), Uuid::new_v4());
+        list.record(&revocation.clone().with_ttl(Duration::from_secs(3600)), now);
+
+        let target = revocation.target;
+        assert!(list.is_revoked_at(&target, now + Duration::from_secs(59)));
+        assert!(!list.is_revoked_at(&target, now + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn full_list_drops_the_entry_closest_to_expiry() {
+        let list = list(2);
+        let now = Instant::now();
+        let tenant = Uuid::new_v4();
+        let [a, b, c] = [10, 20, 30].map(|secs| {
+            let revocation = Revocation::subject(tenant, Uuid::new_v4());
+            list.record(&revocation.clone().with_ttl(Duration::from_secs(secs)), now);
+            revocation.target
+        });
+
+        assert_eq!(list.len(), 2);
+        assert!(!list.is_revoked_at(&a, now));
+        assert!(list.is_revoked_at(&b, now));
+        assert!(list.is_revoked_at(&c, now));
+    }
+
+    #[tokio::test]
+    async fn subject_revocation_covers_every_token() {
+        let list = list(10);
+        let (tenant, subject) = (Uuid::new_v4(), Uuid::new_v4());
+        list.revoke(Revocation::subject(tenant, subject));
+
+        for token in ["token-a", "token-b"] {
+            let hash = authn_resolver_sdk::token_hash(token);
+            assert!(list.ensure_token_not_revoked(&hash).is_ok());
+            let err = list
+                .ensure_not_revoked(&hash, tenant, subject)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, DomainError::Revoked(_)), "{err:?}");
+        }
+        assert!(
+            list.ensure_not_revoked("other", tenant, Uuid::new_v4())
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn subject_revocation_is_scoped_to_its_tenant() {
+        let list = list(10);
+        let subject = Uuid::new_v4();
+        list.revoke(Revocation::subject(Uuid::new_v4(), subject));
+
+        assert!(
+            list.ensure_not_revoked("token-a", Uuid::new_v4(), subject)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn changes_of_a_source_are_recorded() {
+        let upstream = list(10);
+        let local = list(10);
+        local.add_source(upstream.clone());
+
+        let revocation = Revocation::subject(Uuid::new_v4(), Uuid::new_v4());
+        upstream.revoke(revocation.clone());
+
+        let target = revocation.target;
+        for _ in 0..100 {
+            if local.is_revoked(&target) {
+                break;
+            }
+            tokio::task::yield_now().await;
+        }
+        assert!(local.is_revoked(&target));
+    }
+}
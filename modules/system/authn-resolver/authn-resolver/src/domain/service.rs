@@ -4,19 +4,24 @@
 //! types-registry is ready.
 
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use authn_resolver_sdk::{
-    AuthNResolverPluginClient, AuthNResolverPluginSpecV1, AuthenticationResult,
+    AuthNResolverPluginClient, AuthNResolverPluginSpecV1, AuthenticationResult, token_hash,
 };
 use modkit::client_hub::{ClientHub, ClientScope};
-use modkit::plugins::{GtsPluginSelector, choose_plugin_instance};
+use modkit::gts::{PluginHealth, validate_plugin_instances};
+use modkit::plugins::{GtsPluginSelector, SelectorStatus, choose_plugin_instance};
+use modkit::readiness::ModuleReadiness;
 use modkit::telemetry::ThrottledLog;
 use modkit_macros::domain_model;
+use tokio::sync::RwLock;
 use tracing::info;
-use types_registry_sdk::{ListQuery, TypesRegistryClient};
+use types_registry_sdk::{GtsEntity, ListQuery, TypesRegistryClient};
 
 use super::error::DomainError;
+use super::revocation::RevocationList;
 
 /// Throttle interval for unavailable plugin warnings.
 const UNAVAILABLE_LOG_THROTTLE: Duration = Duration::from_secs(10);
@@ -30,23 +35,66 @@ pub struct Service {
     vendor: String,
     selector: GtsPluginSelector,
     unavailable_log_throttle: ThrottledLog,
+    plugin_health: RwLock<PluginHealth>,
+    revocations: Arc<RevocationList>,
+    /// Degraded while plugin selection fails, ready once it succeeds.
+    readiness: Option<ModuleReadiness>,
+    /// Whether the last selection succeeded, so readiness is only reported on changes.
+    selection_ok: AtomicBool,
 }
 
 impl Service {
     /// Creates a new service with lazy plugin resolution.
     #[must_use]
-    pub fn new(hub: Arc<ClientHub>, vendor: String) -> Self {
+    pub fn new(hub: Arc<ClientHub>, vendor: String, revocations: Arc<RevocationList>) -> Self {
         Self {
             hub,
             vendor,
             selector: GtsPluginSelector::new(),
             unavailable_log_throttle: ThrottledLog::new(UNAVAILABLE_LOG_THROTTLE),
+            plugin_health: RwLock::new(PluginHealth::default()),
+            revocations,
+            readiness: None,
+            selection_ok: AtomicBool::new(false),
         }
     }
 
+    /// Report plugin selection failures as module readiness.
+    #[must_use]
+    pub fn with_readiness(mut self, readiness: ModuleReadiness) -> Self {
+        self.readiness = Some(readiness);
+        self
+    }
+
+    /// Selects the plugin instance, resolving it if none is selected.
+    ///
+    /// The module is reported degraded while selection fails and ready again
+    /// once it succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns plugin resolution errors.
+    pub async fn select_plugin(&self) -> Result<Arc<str>, DomainError> {
+        let result = self.selector.get_or_init(|| self.resolve_plugin()).await;
+        if let Some(readiness) = &self.readiness {
+            match &result {
+                Ok(_) => {
+                    if !self.selection_ok.swap(true, Ordering::Relaxed) {
+                        readiness.ready();
+                    }
+                }
+                Err(e) => {
+                    self.selection_ok.store(false, Ordering::Relaxed);
+                    readiness.degraded(format!("plugin selection failed: {e}"));
+                }
+            }
+        }
+        result
+    }
+
     /// Lazily resolves and returns the plugin client.
     async fn get_plugin(&self) -> Result<Arc<dyn AuthNResolverPluginClient>, DomainError> {
-        let instance_id = self.selector.get_or_init(|| self.resolve_plugin()).await?;
+        let instance_id = self.select_plugin().await?;
         let scope = ClientScope::gts_id(instance_id.as_ref());
 
         if let Some(client) = self
@@ -69,11 +117,29 @@ impl Service {
         }
     }
 
-    /// Resolves the plugin instance from types-registry.
-    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
-    async fn resolve_plugin(&self) -> Result<String, DomainError> {
-        info!("Resolving authn_resolver plugin");
+    /// Drop the selected plugin so the next call resolves it again.
+    ///
+    /// Returns `true` if a plugin was selected.
+    pub async fn reset_selector(&self) -> bool {
+        let reset = self.selector.reset().await;
+        info!(reset, vendor = %self.vendor, "Plugin selector reset");
+        reset
+    }
+
+    /// Number of selector resets since startup.
+    #[must_use]
+    pub fn selector_reset_count(&self) -> u64 {
+        self.selector.reset_count()
+    }
+
+    /// Plugin selection state, including the last resolution error.
+    #[must_use]
+    pub fn selector_status(&self) -> SelectorStatus {
+        self.selector.status()
+    }
 
+    /// List all registered plugin instances from types-registry.
+    async fn list_plugin_instances(&self) -> Result<Vec<GtsEntity>, DomainError> {
         let registry = self
             .hub
             .get::<dyn TypesRegistryClient>()
@@ -81,13 +147,21 @@ impl Service {
 
         let plugin_type_id = AuthNResolverPluginSpecV1::gts_schema_id().clone();
 
-        let instances = registry
+        Ok(registry
             .list(
                 ListQuery::new()
                     .with_pattern(format!("{plugin_type_id}*"))
                     .with_is_type(false),
             )
-            .await?;
+            .await?)
+    }
+
+    /// Resolves the plugin instance from types-registry.
+    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
+    async fn resolve_plugin(&self) -> Result<String, DomainError> {
+        info!("Resolving authn_resolver plugin");
+
+        let instances = self.list_plugin_instances().await?;
 
         let gts_id = choose_plugin_instance::<AuthNResolverPluginSpecV1>(
             &self.vendor,
@@ -98,21 +172,227 @@ impl Service {
         Ok(gts_id)
     }
 
+    /// Validates every registered plugin instance and stores the result for
+    /// [`plugin_health`](Self::plugin_health).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if types-registry cannot be queried.
+    pub async fn validate_plugins(&self) -> Result<PluginHealth, DomainError> {
+        let instances = self.list_plugin_instances().await?;
+        let health = PluginHealth {
+            checked: instances.len(),
+            issues: validate_plugin_instances::<AuthNResolverPluginSpecV1>(
+                instances.iter().map(|e| (e.gts_id.as_str(), &e.content)),
+            ),
+        };
+        *self.plugin_health.write().await = health.clone();
+        Ok(health)
+    }
+
+    /// Result of the last [`validate_plugins`](Self::validate_plugins) run.
+    pub async fn plugin_health(&self) -> PluginHealth {
+        self.plugin_health.read().await.clone()
+    }
+
     /// Authenticate a bearer token via the selected plugin.
     ///
+    /// The revocation list is checked before the plugin call (token) and after
+    /// it (token and subject), so revocations apply even when the plugin
+    /// answers from a cache.
+    ///
     /// # Errors
     ///
-    /// - `Unauthorized` if the token is invalid
+    /// - `InvalidToken`, `TokenExpired` or `Unauthorized` if the token is rejected
+    /// - `Revoked` if the token or its subject has been revoked
     /// - Plugin resolution errors
     #[tracing::instrument(skip_all)]
     pub async fn authenticate(
         &self,
         bearer_token: &str,
     ) -> Result<AuthenticationResult, DomainError> {
+        let token_hash = token_hash(bearer_token);
+        self.revocations.ensure_token_not_revoked(&token_hash)?;
+
         let plugin = self.get_plugin().await?;
-        plugin
+        let result = plugin
             .authenticate(bearer_token)
             .await
-            .map_err(DomainError::from)
+            .map_err(DomainError::from)?;
+
+        self.revocations
+            .ensure_not_revoked(
+                &token_hash,
+                result.security_context.subject_tenant_id(),
+                result.security_context.subject_id(),
+            )
+            .await?;
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use authn_resolver_sdk::{AuthNResolverError, Revocation};
+    use modkit::gts::BaseModkitPluginV1;
+    use modkit::readiness::{Readiness, ReadinessRegistry};
+    use modkit_security::SecurityContext;
+    use types_registry_sdk::{RegisterResult, TypesRegistryError};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::config::RevocationConfig;
+
+    struct SinglePluginRegistry {
+        instance: GtsEntity,
+        /// Until set, the instance is not registered yet.
+        available: AtomicBool,
+    }
+
+    #[async_trait]
+    impl TypesRegistryClient for SinglePluginRegistry {
+        async fn register(
+            &self,
+            _entities: Vec<serde_json::Value>,
+        ) -> Result<Vec<RegisterResult>, TypesRegistryError> {
+            unimplemented!()
+        }
+
+        async fn list(&self, _query: ListQuery) -> Result<Vec<GtsEntity>, TypesRegistryError> {
+            if !self.available.load(Ordering::Relaxed) {
+                return Ok(vec![]);
+            }
+            Ok(vec![self.instance.clone()])
+        }
+
+        async fn get(&self, _gts_id: &str) -> Result<GtsEntity, TypesRegistryError> {
+            unimplemented!()
+        }
+    }
+
+    /// Accepts every token for a fixed subject, like a plugin answering from
+    /// a warm cache.
+    struct CachingPlugin {
+        subject_id: Uuid,
+        tenant_id: Uuid,
+        calls: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl AuthNResolverPluginClient for CachingPlugin {
+        async fn authenticate(
+            &self,
+            _bearer_token: &str,
+        ) -> Result<AuthenticationResult, AuthNResolverError> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            Ok(AuthenticationResult {
+                security_context: SecurityContext::builder()
+                    .subject_id(self.subject_id)
+                    .subject_tenant_id(self.tenant_id)
+                    .build()
+                    .map_err(|e| AuthNResolverError::Internal(e.to_string()))?,
+            })
+        }
+    }
+
+    fn service_with_plugin(subject_id: Uuid) -> (Service, Arc<RevocationList>, Arc<CachingPlugin>) {
+        let (svc, revocations, plugin, _registry) = service_with_registry(subject_id, true);
+        (svc, revocations, plugin)
+    }
+
+    fn service_with_registry(
+        subject_id: Uuid,
+        available: bool,
+    ) -> (
+        Service,
+        Arc<RevocationList>,
+        Arc<CachingPlugin>,
+        Arc<SinglePluginRegistry>,
+    ) {
+        let instance_id =
+            AuthNResolverPluginSpecV1::gts_make_instance_id("test.caching_authn.plugin.v1");
+        let instance = BaseModkitPluginV1::<AuthNResolverPluginSpecV1> {
+            id: instance_id.clone(),
+            vendor: "test".to_owned(),
+            priority: 0,
+            properties: AuthNResolverPluginSpecV1,
+        };
+
+        let hub = Arc::new(ClientHub::new());
+        let registry = Arc::new(SinglePluginRegistry {
+            instance: GtsEntity::new(
+                Uuid::new_v4(),
+                &instance_id.to_string(),
+                vec![],
+                false,
+                serde_json::to_value(&instance).unwrap(),
+                None,
+            ),
+            available: AtomicBool::new(available),
+        });
+        hub.register::<dyn TypesRegistryClient>(registry.clone());
+
+        let plugin = Arc::new(CachingPlugin {
+            subject_id,
+            tenant_id: Uuid::new_v4(),
+            calls: AtomicUsize::new(0),
+        });
+        let api: Arc<dyn AuthNResolverPluginClient> = plugin.clone();
+        hub.register_scoped::<dyn AuthNResolverPluginClient>(
+            ClientScope::gts_id(&instance_id),
+            api,
+        );
+
+        let revocations = Arc::new(RevocationList::new(&RevocationConfig::default()));
+        let svc = Service::new(hub, "test".to_owned(), revocations.clone());
+        (svc, revocations, plugin, registry)
+    }
+
+    #[tokio::test]
+    async fn failed_selection_degrades_until_a_later_selection_succeeds() {
+        let (svc, _revocations, _plugin, registry) = service_with_registry(Uuid::new_v4(), false);
+        let readiness = Arc::new(ReadinessRegistry::new());
+        let svc = svc.with_readiness(readiness.module("authn-resolver"));
+
+        svc.select_plugin().await.unwrap_err();
+        let Some(Readiness::Degraded { reason }) = readiness.get("authn-resolver") else {
+            panic!("expected degraded after a failed selection");
+        };
+        assert!(reason.starts_with("plugin selection failed"), "{reason}");
+
+        registry.available.store(true, Ordering::Relaxed);
+        svc.authenticate("token-a").await.unwrap();
+        assert_eq!(readiness.get("authn-resolver"), Some(Readiness::Ready));
+    }
+
+    #[tokio::test]
+    async fn revoked_token_is_rejected_before_the_plugin() {
+        let (svc, revocations, plugin) = service_with_plugin(Uuid::new_v4());
+        svc.authenticate("token-a").await.unwrap();
+
+        revocations.revoke(Revocation::token_hash(token_hash("token-a")));
+
+        let err = svc.authenticate("token-a").await.unwrap_err();
+        assert!(matches!(err, DomainError::Revoked(_)), "{err:?}");
+        assert_eq!(plugin.calls.load(Ordering::Relaxed), 1);
+        svc.authenticate("token-b").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn revoked_subject_rejects_all_its_tokens() {
+        let subject_id = Uuid::new_v4();
+        let (svc, revocations, plugin) = service_with_plugin(subject_id);
+        svc.authenticate("token-a").await.unwrap();
+
+        revocations.revoke(Revocation::subject(plugin.tenant_id, subject_id));
+
+        for token in ["token-a", "token-b"] {
+            let err = svc.authenticate(token).await.unwrap_err();
+            assert!(matches!(err, DomainError::Revoked(_)), "{err:?}");
+        }
     }
 }
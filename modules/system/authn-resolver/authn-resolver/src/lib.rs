@@ -3,10 +3,12 @@
 //! This module discovers `AuthN` resolver plugins via types-registry
 //! and routes authentication calls to the selected plugin based on vendor configuration.
 //!
-//! Provides the `AuthNResolverClient` trait registered
-//! in `ClientHub` for consumption by other modules.
+//! Provides the `AuthNResolverClient` and `AuthNRevocationClient` traits
+//! registered in `ClientHub` for consumption by other modules, and the
+//! `POST /authn/v1/revocations` admin endpoint.
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
+pub mod api;
 pub mod config;
 pub mod domain;
 pub mod module;
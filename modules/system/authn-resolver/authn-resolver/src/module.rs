@@ -1,17 +1,24 @@
 //! `AuthN` resolver module.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock};
 
 use async_trait::async_trait;
-use authn_resolver_sdk::{AuthNResolverClient, AuthNResolverPluginSpecV1};
-use modkit::Module;
+use authn_resolver_sdk::{AuthNResolverClient, AuthNResolverPluginSpecV1, AuthNRevocationClient};
+use modkit::api::OpenApiRegistry;
 use modkit::context::ModuleCtx;
 use modkit::contracts::SystemCapability;
-use tracing::info;
+use modkit::gts::format_plugin_issues;
+use modkit::runtime::SystemContext;
+use modkit::{Module, RestApiCapability};
+use tracing::{info, warn};
 use types_registry_sdk::{RegisterResult, TypesRegistryClient};
 
+use crate::api::rest::routes::RevocationsState;
 use crate::config::AuthNResolverConfig;
-use crate::domain::{AuthNResolverLocalClient, Service};
+use crate::domain::{
+    AuthNResolverLocalClient, AuthNRevocationLocalClient, RevocationList, Service,
+};
 
 /// `AuthN` Resolver module.
 ///
@@ -19,22 +26,30 @@ use crate::domain::{AuthNResolverLocalClient, Service};
 /// 1. Registers the plugin schema in types-registry
 /// 2. Discovers plugin instances via types-registry
 /// 3. Routes requests to the selected plugin based on vendor configuration
+/// 4. Rejects revoked tokens and subjects (`POST /authn/v1/revocations` or
+///    `AuthNRevocationClient`)
 ///
 /// Plugin discovery is lazy: happens on first API call after types-registry
-/// is ready.
+/// is ready. All registered instances are validated once in `post_init`, after
+/// plugins have registered them, and a first selection is attempted there: the
+/// module reports degraded while selection fails and ready once it succeeds.
 #[modkit::module(
     name = "authn-resolver",
     deps = ["types-registry"],
-    capabilities = [system]
+    capabilities = [system, rest]
 )]
 pub(crate) struct AuthNResolver {
     service: OnceLock<Arc<Service>>,
+    revocations: OnceLock<Arc<RevocationsState>>,
+    strict_plugins: AtomicBool,
 }
 
 impl Default for AuthNResolver {
     fn default() -> Self {
         Self {
             service: OnceLock::new(),
+            revocations: OnceLock::new(),
+            strict_plugins: AtomicBool::new(false),
         }
     }
 }
@@ -42,7 +57,55 @@ impl Default for AuthNResolver {
 // Marked as `system` so that init() runs in the system-module phase.
 // This ensures the AuthNResolver client is available in ClientHub before
 // other system modules that depend on it.
-impl SystemCapability for AuthNResolver {}
+#[async_trait]
+impl SystemCapability for AuthNResolver {
+    /// Selects the plugin and validates plugin instances once every module has
+    /// registered its own.
+    ///
+    /// Plugins register their instances in `init()`, which runs after this
+    /// system module's `init()`, so the check has to wait for this phase.
+    async fn post_init(&self, _sys: &SystemContext) -> anyhow::Result<()> {
+        let svc = self
+            .service
+            .get()
+            .ok_or_else(|| anyhow::anyhow!("{} module not initialized", Self::MODULE_NAME))?;
+
+        // A failed selection is retried on the next call; until then the module is degraded
+        if let Err(e) = svc.select_plugin().await {
+            warn!(error = %e, "authn_resolver plugin selection failed; module is degraded");
+        }
+
+        let health = svc.validate_plugins().await?;
+        if health.is_healthy() {
+            info!(
+                checked = health.checked,
+                "All authn_resolver plugin instances are valid"
+            );
+            return Ok(());
+        }
+
+        warn!(
+            checked = health.checked,
+            invalid = health.invalid_instances(),
+            "{}:\n{}",
+            health.summary(),
+            format_plugin_issues(&health.issues)
+        );
+        if self.strict_plugins.load(Ordering::Relaxed) {
+            anyhow::bail!(
+                "{} (strict_plugins is enabled): {}",
+                health.summary(),
+                health
+                    .issues
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            );
+        }
+        Ok(())
+    }
+}
 
 #[async_trait]
 impl Module for AuthNResolver {
@@ -73,19 +136,80 @@ impl Module for AuthNResolver {
             "Registered plugin schema in types-registry"
         );
 
+        self.strict_plugins
+            .store(cfg.strict_plugins, Ordering::Relaxed);
+
         // Create service
+        let revocations = Arc::new(RevocationList::new(&cfg.revocation));
         let hub = ctx.client_hub();
-        let svc = Arc::new(Service::new(hub, cfg.vendor));
+        let svc = Arc::new(
+            Service::new(hub, cfg.vendor, revocations.clone()).with_readiness(ctx.readiness()),
+        );
         self.service
             .set(svc.clone())
             .map_err(|_| anyhow::anyhow!("{} module already initialized", Self::MODULE_NAME))?;
 
         // Register client in ClientHub
-        let api: Arc<dyn AuthNResolverClient> = Arc::new(AuthNResolverLocalClient::new(svc));
+        let api: Arc<dyn AuthNResolverClient> =
+            Arc::new(AuthNResolverLocalClient::new(svc.clone()));
         ctx.client_hub().register::<dyn AuthNResolverClient>(api);
 
+        let revocation_api: Arc<dyn AuthNRevocationClient> =
+            Arc::new(AuthNRevocationLocalClient::new(revocations.clone()));
+        ctx.client_hub()
+            .register::<dyn AuthNRevocationClient>(revocation_api);
+        self.revocations
+            .set(Arc::new(RevocationsState {
+                revocations,
+                admin_scope: cfg.revocation.admin_scope,
+            }))
+            .map_err(|_| anyhow::anyhow!("{} module already initialized", Self::MODULE_NAME))?;
+
+        // Health probe: result of the plugin instance validation in post_init
+        // and the current plugin selection state
+        let health_svc = Arc::clone(&svc);
+        ctx.register_admin_command("authn.plugins.health", move |_args| {
+            let svc = Arc::clone(&health_svc);
+            async move {
+                let mut health = svc.plugin_health().await.to_json();
+                health["selector"] = svc.selector_status().to_json();
+                Ok(health)
+            }
+        })?;
+
+        // Operator command: force plugin reselection without a restart
+        ctx.register_admin_command("authn.reset-selector", move |_args| {
+            let svc = Arc::clone(&svc);
+            async move {
+                let reset = svc.reset_selector().await;
+                Ok(serde_json::json!({
+                    "reset": reset,
+                    "reset_count": svc.selector_reset_count(),
+                }))
+            }
+        })?;
+
         info!("{} module initialized successfully", Self::MODULE_NAME);
 
         Ok(())
     }
 }
+
+impl RestApiCapability for AuthNResolver {
+    fn register_rest(
+        &self,
+        _ctx: &ModuleCtx,
+        router: axum::Router,
+        openapi: &dyn OpenApiRegistry,
+    ) -> anyhow::Result<axum::Router> {
+        let state = self
+            .revocations
+            .get()
+            .ok_or_else(|| anyhow::anyhow!("{} module not initialized", Self::MODULE_NAME))?
+            .clone();
+
+        Ok(crate::api::rest::routes::register_routes(
+            router, openapi, state,
+        ))
+    }
+}
@@ -0,0 +1,131 @@
+//! Configuration for the API key `AuthN` resolver plugin.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Deserializer};
+use uuid::Uuid;
+
+/// Plugin configuration.
+///
+/// `Debug` output lists the number of keys, never the keys themselves.
+#[derive(Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ApiKeyAuthNPluginConfig {
+    /// Vendor name for GTS instance registration.
+    pub vendor: String,
+
+    /// Plugin priority (lower = higher priority).
+    pub priority: i16,
+
+    /// API key to identity mappings.
+    pub keys: HashMap<String, ApiKeyIdentity>,
+}
+
+impl Default for ApiKeyAuthNPluginConfig {
+    fn default() -> Self {
+        Self {
+            vendor: "hyperspot".to_owned(),
+            priority: 100,
+            keys: HashMap::new(),
+        }
+    }
+}
+
+impl fmt::Debug for ApiKeyAuthNPluginConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiKeyAuthNPluginConfig")
+            .field("vendor", &self.vendor)
+            .field("priority", &self.priority)
+            .field("keys", &format_args!("<{} redacted>", self.keys.len()))
+            .finish()
+    }
+}
+
+/// Identity returned for an API key.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApiKeyIdentity {
+    /// Subject ID (usually a service account).
+    pub subject_id: Uuid,
+
+    /// Subject's home tenant.
+    pub subject_tenant_id: Uuid,
+
+    /// Subject type passed to the PDP, e.g. `"service"`.
+    #[serde(default)]
+    pub subject_type: Option<String>,
+
+    /// Token scopes. Required and non-empty: `["*"]` grants first-party /
+    /// unrestricted access, an empty list is rejected because the security
+    /// context would treat it as unrestricted too.
+    #[serde(deserialize_with = "non_empty_scopes")]
+    pub token_scopes: Vec<String>,
+}
+
+fn non_empty_scopes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    let scopes = Vec::<String>::deserialize(deserializer)?;
+    if scopes.is_empty() {
+        return Err(serde::de::Error::custom(
+            "token_scopes must not be empty; use [\"*\"] for unrestricted access",
+        ));
+    }
+    Ok(scopes)
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_redacts_keys() {
+        let cfg: ApiKeyAuthNPluginConfig = serde_json::from_value(serde_json::json!({
+            "keys": {
+                "k-secret": {
+                    "subject_id": "5f1c0d2e-8f7a-4b6c-9d3e-2a1b0c9d8e7f",
+                    "subject_tenant_id": "00000000-df51-5b42-9538-d2b56b7ee953",
+                    "token_scopes": ["billing:read"],
+                }
+            }
+        }))
+        .unwrap();
+
+        let debug = format!("{cfg:?}");
+
+        assert!(debug.contains("keys: <1 redacted>"), "{debug}");
+        assert!(!debug.contains("k-secret"), "{debug}");
+    }
+
+    fn identity(token_scopes: Option<serde_json::Value>) -> Result<ApiKeyIdentity, String> {
+        let mut identity = serde_json::json!({
+            "subject_id": "5f1c0d2e-8f7a-4b6c-9d3e-2a1b0c9d8e7f",
+            "subject_tenant_id": "00000000-df51-5b42-9538-d2b56b7ee953",
+        });
+        if let Some(scopes) = token_scopes {
+            identity["token_scopes"] = scopes;
+        }
+        serde_json::from_value(identity).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn missing_token_scopes_are_rejected() {
+        let err = identity(None).unwrap_err();
+
+        assert!(err.contains("missing field `token_scopes`"), "{err}");
+    }
+
+    #[test]
+    fn empty_token_scopes_are_rejected() {
+        let err = identity(Some(serde_json::json!([]))).unwrap_err();
+
+        assert!(err.contains("token_scopes must not be empty"), "{err}");
+    }
+
+    #[test]
+    fn wildcard_token_scopes_are_explicit() {
+        let identity = identity(Some(serde_json::json!(["*"]))).unwrap();
+
+        assert_eq!(identity.token_scopes, ["*"]);
+    }
+}
@@ -0,0 +1,37 @@
+//! Client implementation for the API key `AuthN` resolver plugin.
+//!
+//! Implements `AuthNResolverPluginClient` using the domain service.
+
+use async_trait::async_trait;
+use authn_resolver_sdk::{AuthNResolverError, AuthNResolverPluginClient, AuthenticationResult};
+
+use super::service::Service;
+
+#[async_trait]
+impl AuthNResolverPluginClient for Service {
+    async fn authenticate(
+        &self,
+        bearer_token: &str,
+    ) -> Result<AuthenticationResult, AuthNResolverError> {
+        self.authenticate(bearer_token)
+            .ok_or_else(|| AuthNResolverError::InvalidToken("invalid API key".to_owned()))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use crate::config::ApiKeyAuthNPluginConfig;
+
+    #[tokio::test]
+    async fn plugin_trait_unknown_key_invalid() {
+        let service = Service::from_config(&ApiKeyAuthNPluginConfig::default());
+        let plugin: &dyn AuthNResolverPluginClient = &service;
+
+        match plugin.authenticate("k-unknown").await.unwrap_err() {
+            AuthNResolverError::InvalidToken(_) => {}
+            other => panic!("Expected InvalidToken, got: {other:?}"),
+        }
+    }
+}
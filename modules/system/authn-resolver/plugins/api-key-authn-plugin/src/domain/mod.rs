@@ -0,0 +1,6 @@
+//! Domain layer for the API key `AuthN` resolver plugin.
+
+mod client;
+pub mod service;
+
+pub use service::Service;
@@ -0,0 +1,105 @@
+//! Service implementation for the API key `AuthN` resolver plugin.
+
+use std::collections::HashMap;
+
+use modkit_macros::domain_model;
+use modkit_security::SecurityContext;
+
+use crate::config::{ApiKeyAuthNPluginConfig, ApiKeyIdentity};
+use authn_resolver_sdk::AuthenticationResult;
+
+/// API key `AuthN` resolver service.
+///
+/// Maps configured API keys to identities. The key itself is not kept in the
+/// returned `SecurityContext`, so it is never forwarded to the PDP.
+#[domain_model]
+pub struct Service {
+    keys: HashMap<String, ApiKeyIdentity>,
+}
+
+impl Service {
+    /// Create a service from plugin configuration.
+    #[must_use]
+    pub fn from_config(cfg: &ApiKeyAuthNPluginConfig) -> Self {
+        Self {
+            keys: cfg.keys.clone(),
+        }
+    }
+
+    /// Authenticate an API key and return the identity.
+    ///
+    /// Returns `None` if the key is empty or not configured.
+    #[must_use]
+    pub fn authenticate(&self, api_key: &str) -> Option<AuthenticationResult> {
+        if api_key.is_empty() {
+            return None;
+        }
+        let identity = self.keys.get(api_key)?;
+
+        let mut builder = SecurityContext::builder()
+            .subject_id(identity.subject_id)
+            .subject_tenant_id(identity.subject_tenant_id)
+            .token_scopes(identity.token_scopes.clone());
+        if let Some(subject_type) = &identity.subject_type {
+            builder = builder.subject_type(subject_type);
+        }
+        let ctx = builder
+            .build()
+            .map_err(|e| tracing::error!("Failed to build SecurityContext from config: {e}"))
+            .ok()?;
+
+        Some(AuthenticationResult {
+            security_context: ctx,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn service_with_key(key: &str, identity: ApiKeyIdentity) -> Service {
+        Service::from_config(&ApiKeyAuthNPluginConfig {
+            keys: HashMap::from([(key.to_owned(), identity)]),
+            ..ApiKeyAuthNPluginConfig::default()
+        })
+    }
+
+    fn identity() -> ApiKeyIdentity {
+        ApiKeyIdentity {
+            subject_id: Uuid::new_v4(),
+            subject_tenant_id: Uuid::new_v4(),
+            subject_type: Some("service".to_owned()),
+            token_scopes: vec!["billing:read".to_owned()],
+        }
+    }
+
+    #[test]
+    fn known_key_returns_mapped_identity() {
+        let identity = identity();
+        let service = service_with_key("k-billing", identity.clone());
+
+        let auth = service.authenticate("k-billing").unwrap();
+        let ctx = &auth.security_context;
+        assert_eq!(ctx.subject_id(), identity.subject_id);
+        assert_eq!(ctx.subject_tenant_id(), identity.subject_tenant_id);
+        assert_eq!(ctx.subject_type(), Some("service"));
+        assert_eq!(ctx.token_scopes(), &["billing:read"]);
+        assert!(ctx.bearer_token().is_none());
+    }
+
+    #[test]
+    fn unknown_or_empty_key_is_rejected() {
+        let service = service_with_key("k-billing", identity());
+
+        assert!(service.authenticate("k-other").is_none());
+        assert!(service.authenticate("").is_none());
+        assert!(
+            Service::from_config(&ApiKeyAuthNPluginConfig::default())
+                .authenticate("k-billing")
+                .is_none()
+        );
+    }
+}
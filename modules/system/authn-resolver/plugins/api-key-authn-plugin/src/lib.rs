@@ -0,0 +1,33 @@
+//! API Key `AuthN` Resolver Plugin
+//!
+//! Authenticates machine-to-machine callers by API key. The API gateway, with
+//! `auth_mode: { api_key: { header: "X-API-Key" } }`, passes the value of the
+//! configured header to the resolver; this plugin looks it up in a static
+//! key-to-identity map.
+//!
+//! The resolver picks a single plugin by vendor. To run this plugin next to a
+//! bearer-token plugin, give each its own vendor and point `authn-resolver.vendor`
+//! at the one to use.
+//!
+//! ## Configuration
+//!
+//! ```yaml
+//! modules:
+//!   api-key-authn-plugin:
+//!     config:
+//!       vendor: "hyperspot"
+//!       priority: 100
+//!       keys:
+//!         "k-3f9a1c...":
+//!           subject_id: "5f1c0d2e-8f7a-4b6c-9d3e-2a1b0c9d8e7f"
+//!           subject_tenant_id: "00000000-df51-5b42-9538-d2b56b7ee953"
+//!           subject_type: "service"
+//!           token_scopes: ["billing:read"]
+//! ```
+#![cfg_attr(coverage_nightly, feature(coverage_attribute))]
+
+pub mod config;
+pub mod domain;
+pub mod module;
+
+pub use module::ApiKeyAuthNPlugin;
@@ -0,0 +1,96 @@
+//! API key `AuthN` resolver plugin module.
+
+use std::sync::{Arc, OnceLock};
+
+use async_trait::async_trait;
+use authn_resolver_sdk::{AuthNResolverPluginClient, AuthNResolverPluginSpecV1};
+use modkit::Module;
+use modkit::client_hub::ClientScope;
+use modkit::context::ModuleCtx;
+use modkit::gts::BaseModkitPluginV1;
+use tracing::info;
+use types_registry_sdk::{RegisterResult, TypesRegistryClient};
+
+use crate::config::ApiKeyAuthNPluginConfig;
+use crate::domain::Service;
+
+/// API key `AuthN` resolver plugin module.
+///
+/// Provides API key to identity mapping from configuration.
+///
+/// **Plugin registration pattern:**
+/// - Gateway registers the plugin schema (GTS type definition)
+/// - This plugin registers its instance (implementation metadata)
+/// - This plugin registers its scoped client (implementation in `ClientHub`)
+#[modkit::module(
+    name = "api-key-authn-plugin",
+    deps = ["types-registry"]
+)]
+pub struct ApiKeyAuthNPlugin {
+    service: OnceLock<Arc<Service>>,
+}
+
+impl Default for ApiKeyAuthNPlugin {
+    fn default() -> Self {
+        Self {
+            service: OnceLock::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl Module for ApiKeyAuthNPlugin {
+    async fn init(&self, ctx: &ModuleCtx) -> anyhow::Result<()> {
+        info!("Initializing {} module", Self::MODULE_NAME);
+
+        // Load configuration
+        let cfg: ApiKeyAuthNPluginConfig = ctx.config()?;
+        if cfg.keys.is_empty() {
+            tracing::warn!(
+                "API key AuthN plugin has no keys configured; every request will be rejected"
+            );
+        }
+
+        info!(
+            vendor = %cfg.vendor,
+            priority = cfg.priority,
+            key_count = cfg.keys.len(),
+            "Loaded plugin configuration"
+        );
+
+        // Generate plugin instance ID
+        let instance_id = AuthNResolverPluginSpecV1::gts_make_instance_id(
+            "hyperspot.builtin.api_key_authn_resolver.plugin.v1",
+        );
+
+        // Register plugin instance in types-registry
+        let registry = ctx.client_hub().get::<dyn TypesRegistryClient>()?;
+        let instance = BaseModkitPluginV1::<AuthNResolverPluginSpecV1> {
+            id: instance_id.clone(),
+            vendor: cfg.vendor.clone(),
+            priority: cfg.priority,
+            properties: AuthNResolverPluginSpecV1,
+        };
+        let instance_json = serde_json::to_value(&instance)?;
+
+        let results = registry.register(vec![instance_json]).await?;
+        RegisterResult::ensure_all_ok(&results)?;
+
+        // Create service from config
+        let service = Arc::new(Service::from_config(&cfg));
+        self.service
+            .set(service.clone())
+            .map_err(|_| anyhow::anyhow!("{} module already initialized", Self::MODULE_NAME))?;
+
+        // Register scoped client in ClientHub
+        let api: Arc<dyn AuthNResolverPluginClient> = service;
+        ctx.client_hub()
+            .register_scoped::<dyn AuthNResolverPluginClient>(
+                ClientScope::gts_id(&instance_id),
+                api,
+            );
+
+        info!(instance_id = %instance_id, "{} module initialized successfully", Self::MODULE_NAME);
+        Ok(())
+    }
+}
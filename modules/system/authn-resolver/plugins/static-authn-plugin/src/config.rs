@@ -1,5 +1,6 @@
 //! Configuration for the static `AuthN` resolver plugin.
 
+use modkit_macros::domain_model;
 use serde::Deserialize;
 use uuid::Uuid;
 
@@ -23,6 +24,16 @@ pub struct StaticAuthNPluginConfig {
 
     /// Static token-to-identity mappings for `static_tokens` mode.
     pub tokens: Vec<TokenMapping>,
+
+    /// Reject tokens that do not start with `"Bearer "`.
+    ///
+    /// The API gateway strips the prefix before calling the resolver, so only
+    /// enable this when callers pass the raw `Authorization` value.
+    pub require_bearer_prefix: bool,
+
+    /// Trim a leading `"Bearer "` before the token is matched and stored in
+    /// the security context.
+    pub strip_bearer_prefix: bool,
 }
 
 impl Default for StaticAuthNPluginConfig {
@@ -33,6 +44,8 @@ impl Default for StaticAuthNPluginConfig {
             mode: AuthNMode::AcceptAll,
             default_identity: IdentityConfig::default(),
             tokens: Vec::new(),
+            require_bearer_prefix: false,
+            strip_bearer_prefix: false,
         }
     }
 }
@@ -73,7 +86,10 @@ impl Default for IdentityConfig {
 }
 
 /// Maps a static token to a specific identity.
-#[derive(Debug, Clone, Deserialize)]
+///
+/// `Debug` output redacts the token so configs can be logged safely.
+#[domain_model(redact(token))]
+#[derive(Clone, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct TokenMapping {
     /// The bearer token value to match.
@@ -81,3 +97,23 @@ pub struct TokenMapping {
     /// The identity to return when this token is presented.
     pub identity: IdentityConfig,
 }
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_mapping_debug_redacts_token() {
+        let mapping = TokenMapping {
+            token: "s3cr3t-bearer".to_owned(),
+            identity: IdentityConfig::default(),
+        };
+
+        let debug = format!("{mapping:?}");
+
+        assert!(debug.contains("token: \"<redacted>\""), "{debug}");
+        assert!(debug.contains("identity: IdentityConfig"), "{debug}");
+        assert!(!debug.contains("s3cr3t-bearer"), "{debug}");
+    }
+}
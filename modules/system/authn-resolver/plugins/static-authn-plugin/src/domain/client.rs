@@ -14,7 +14,7 @@ impl AuthNResolverPluginClient for Service {
         bearer_token: &str,
     ) -> Result<AuthenticationResult, AuthNResolverError> {
         self.authenticate(bearer_token)
-            .ok_or_else(|| AuthNResolverError::Unauthorized("invalid token".to_owned()))
+            .ok_or_else(|| AuthNResolverError::InvalidToken("invalid token".to_owned()))
     }
 }
 
@@ -34,15 +34,29 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn plugin_trait_empty_token_unauthorized() {
+    async fn plugin_trait_empty_token_invalid() {
         let service = Service::from_config(&StaticAuthNPluginConfig::default());
         let plugin: &dyn AuthNResolverPluginClient = &service;
 
         let result = plugin.authenticate("").await;
         assert!(result.is_err());
         match result.unwrap_err() {
-            AuthNResolverError::Unauthorized(_) => {}
-            other => panic!("Expected Unauthorized, got: {other:?}"),
+            AuthNResolverError::InvalidToken(_) => {}
+            other => panic!("Expected InvalidToken, got: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn plugin_trait_missing_bearer_prefix_invalid() {
+        let service = Service::from_config(&StaticAuthNPluginConfig {
+            require_bearer_prefix: true,
+            ..Default::default()
+        });
+        let plugin: &dyn AuthNResolverPluginClient = &service;
+
+        match plugin.authenticate("any-token").await.unwrap_err() {
+            AuthNResolverError::InvalidToken(_) => {}
+            other => panic!("Expected InvalidToken, got: {other:?}"),
         }
     }
 }
@@ -8,6 +8,8 @@ use modkit_security::SecurityContext;
 use crate::config::{AuthNMode, IdentityConfig, StaticAuthNPluginConfig};
 use authn_resolver_sdk::AuthenticationResult;
 
+const BEARER_PREFIX: &str = "Bearer ";
+
 /// Static `AuthN` resolver service.
 ///
 /// Provides token-to-identity mapping based on configuration mode:
@@ -18,6 +20,8 @@ pub struct Service {
     mode: AuthNMode,
     default_identity: IdentityConfig,
     token_map: HashMap<String, IdentityConfig>,
+    require_bearer_prefix: bool,
+    strip_bearer_prefix: bool,
 }
 
 impl Service {
@@ -34,15 +38,23 @@ impl Service {
             mode: cfg.mode.clone(),
             default_identity: cfg.default_identity.clone(),
             token_map,
+            require_bearer_prefix: cfg.require_bearer_prefix,
+            strip_bearer_prefix: cfg.strip_bearer_prefix,
         }
     }
 
     /// Authenticate a bearer token and return the identity.
     ///
-    /// Returns `None` if the token is not recognized (in `static_tokens` mode)
-    /// or empty.
+    /// Returns `None` if the token is not recognized (in `static_tokens` mode),
+    /// empty, or lacks the `"Bearer "` prefix while `require_bearer_prefix` is set.
     #[must_use]
     pub fn authenticate(&self, bearer_token: &str) -> Option<AuthenticationResult> {
+        let bearer_token = match bearer_token.strip_prefix(BEARER_PREFIX) {
+            Some(stripped) if self.strip_bearer_prefix => stripped,
+            Some(_) => bearer_token,
+            None if self.require_bearer_prefix => return None,
+            None => bearer_token,
+        };
         if bearer_token.is_empty() {
             return None;
         }
@@ -116,6 +128,50 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn require_bearer_prefix_rejects_bare_token() {
+        let cfg = StaticAuthNPluginConfig {
+            require_bearer_prefix: true,
+            ..default_config()
+        };
+        let service = Service::from_config(&cfg);
+
+        assert!(service.authenticate("any-token-value").is_none());
+
+        let auth = service.authenticate("Bearer any-token-value").unwrap();
+        assert_eq!(
+            auth.security_context
+                .bearer_token()
+                .map(ExposeSecret::expose_secret),
+            Some("Bearer any-token-value"),
+        );
+    }
+
+    #[test]
+    fn strip_bearer_prefix_trims_before_matching() {
+        let cfg = StaticAuthNPluginConfig {
+            mode: AuthNMode::StaticTokens,
+            tokens: vec![TokenMapping {
+                token: "known-token".to_owned(),
+                identity: IdentityConfig::default(),
+            }],
+            require_bearer_prefix: true,
+            strip_bearer_prefix: true,
+            ..default_config()
+        };
+        let service = Service::from_config(&cfg);
+
+        let auth = service.authenticate("Bearer known-token").unwrap();
+        assert_eq!(
+            auth.security_context
+                .bearer_token()
+                .map(ExposeSecret::expose_secret),
+            Some("known-token"),
+        );
+        assert!(service.authenticate("known-token").is_none());
+        assert!(service.authenticate("Bearer ").is_none());
+    }
+
     #[test]
     fn static_tokens_mode_returns_mapped_identity() {
         let user_a_id = Uuid::parse_str("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa").unwrap();
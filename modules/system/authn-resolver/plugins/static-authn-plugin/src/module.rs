@@ -58,6 +58,8 @@ impl Module for StaticAuthNPlugin {
             priority = cfg.priority,
             mode = ?cfg.mode,
             token_count = cfg.tokens.len(),
+            require_bearer_prefix = cfg.require_bearer_prefix,
+            strip_bearer_prefix = cfg.strip_bearer_prefix,
             "Loaded plugin configuration"
         );
 
@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 
 use crate::error::AuthZResolverError;
-use crate::models::{EvaluationRequest, EvaluationResponse};
+use crate::models::{EvaluationDefaults, EvaluationRequest, EvaluationResponse};
 
 /// Public API trait for the `AuthZ` resolver gateway.
 ///
@@ -31,4 +31,35 @@ pub trait AuthZResolverClient: Send + Sync {
         &self,
         request: EvaluationRequest,
     ) -> Result<EvaluationResponse, AuthZResolverError>;
+
+    /// Notify the resolver that authorization policies have changed.
+    ///
+    /// The resolver forwards the hint to every registered plugin via
+    /// `AuthZResolverPluginClient::on_policy_reload_hint`, so policy stores can
+    /// be updated without a service restart. The default implementation is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// - `Internal` if any plugin fails to reload its policies
+    async fn reload_policies(&self) -> Result<(), AuthZResolverError> {
+        Ok(())
+    }
+
+    /// Default tenant context values configured at the resolver for
+    /// `resource_type` and `action`.
+    ///
+    /// Applied by [`PolicyEnforcer::with_resolver_defaults`](crate::PolicyEnforcer::with_resolver_defaults)
+    /// to the fields a call site did not override. The default implementation
+    /// returns no defaults.
+    ///
+    /// # Errors
+    ///
+    /// - `Internal` if the defaults cannot be resolved
+    async fn evaluation_defaults(
+        &self,
+        _resource_type: &str,
+        _action: &str,
+    ) -> Result<EvaluationDefaults, AuthZResolverError> {
+        Ok(EvaluationDefaults::default())
+    }
 }
@@ -0,0 +1,471 @@
+//! Conformance suite for [`AuthZResolverPluginClient`] implementations.
+//!
+//! Runs a fixed battery of evaluation requests against a plugin and checks
+//! the response invariants the PEP compiler relies on:
+//!
+//! - a deny carries a `deny_reason` with a non-empty `error_code` and no constraints;
+//! - an allow for a request with `require_constraints=true` carries constraints;
+//! - constraints only use properties listed in `supported_properties`;
+//! - every constraint has predicates and every `IN` predicate has values;
+//! - the same request always yields the same response, also under concurrency.
+//!
+//! The fixture tells the suite which requests the plugin is expected to allow
+//! (and optionally deny), so it works for permissive and restrictive plugins.
+//!
+//! ```ignore
+//! use authz_resolver_sdk::conformance::{ConformanceFixture, run_conformance_suite};
+//!
+//! #[tokio::test]
+//! async fn conformance() {
+//!     let fixture = ConformanceFixture::new(allowed_request()).with_denied(denied_request());
+//!     run_conformance_suite(Arc::new(MyPlugin::new()), fixture)
+//!         .await
+//!         .assert_passed();
+//! }
+//! ```
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::join_all;
+
+use crate::constraints::Predicate;
+use crate::models::{EvaluationRequest, EvaluationResponse};
+use crate::plugin_api::AuthZResolverPluginClient;
+
+/// Resource type no plugin is expected to know.
+pub const UNKNOWN_RESOURCE_TYPE: &str = "gts.x.core.authz.conformance_unknown.v1~";
+
+/// Number of unrelated properties added in the `large_supported_properties` case.
+const EXTRA_SUPPORTED_PROPERTIES: usize = 256;
+
+/// What the plugin under test is expected to do.
+#[derive(Debug, Clone)]
+pub struct ConformanceFixture {
+    /// A request the plugin must allow. Its `require_constraints` flag is
+    /// overridden per case.
+    pub allowed: EvaluationRequest,
+    /// A request the plugin must deny; `None` for plugins that never deny.
+    pub denied: Option<EvaluationRequest>,
+    /// Evaluations issued at once in the `concurrent` case.
+    pub concurrency: usize,
+}
+
+impl ConformanceFixture {
+    #[must_use]
+    pub fn new(allowed: EvaluationRequest) -> Self {
+        Self {
+            allowed,
+            denied: None,
+            concurrency: 32,
+        }
+    }
+
+    #[must_use]
+    pub fn with_denied(mut self, denied: EvaluationRequest) -> Self {
+        self.denied = Some(denied);
+        self
+    }
+
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+}
+
+/// A broken invariant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Name of the case that found it, e.g. `"deny"`.
+    pub case: &'static str,
+    pub message: String,
+}
+
+/// Outcome of [`run_conformance_suite`].
+#[derive(Debug, Clone, Default)]
+pub struct ConformanceReport {
+    /// Cases that ran, in order.
+    pub cases: Vec<&'static str>,
+    pub violations: Vec<Violation>,
+}
+
+impl ConformanceReport {
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Panic with the full report if any invariant was violated.
+    ///
+    /// # Panics
+    /// If the report contains violations.
+    pub fn assert_passed(&self) {
+        assert!(self.passed(), "{self}");
+    }
+
+    fn violation(&mut self, case: &'static str, message: impl Into<String>) {
+        self.violations.push(Violation {
+            case,
+            message: message.into(),
+        });
+    }
+}
+
+impl fmt::Display for ConformanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.passed() {
+            return write!(
+                f,
+                "authz plugin conformance: {} cases passed",
+                self.cases.len()
+            );
+        }
+        writeln!(
+            f,
+            "authz plugin conformance: {} violation(s) in {} cases",
+            self.violations.len(),
+            self.cases.len()
+        )?;
+        for v in &self.violations {
+            writeln!(f, "  [{}] {}", v.case, v.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Run every conformance case against `client`.
+///
+/// Never panics on plugin misbehaviour; inspect the returned report or call
+/// [`ConformanceReport::assert_passed`].
+pub async fn run_conformance_suite(
+    client: Arc<dyn AuthZResolverPluginClient>,
+    fixture: ConformanceFixture,
+) -> ConformanceReport {
+    let mut report = ConformanceReport::default();
+    let client = client.as_ref();
+
+    let mut allowed = fixture.allowed.clone();
+    allowed.context.require_constraints = true;
+    expect_decision(client, &mut report, "allow", &allowed, Some(true)).await;
+
+    let mut unconstrained = fixture.allowed.clone();
+    unconstrained.context.require_constraints = false;
+    expect_decision(
+        client,
+        &mut report,
+        "allow_without_required_constraints",
+        &unconstrained,
+        Some(true),
+    )
+    .await;
+
+    if let Some(denied) = &fixture.denied {
+        expect_decision(client, &mut report, "deny", denied, Some(false)).await;
+    }
+
+    let mut large = allowed.clone();
+    large
+        .context
+        .supported_properties
+        .extend((0..EXTRA_SUPPORTED_PROPERTIES).map(|i| format!("conformance_extra_property_{i}")));
+    expect_decision(
+        client,
+        &mut report,
+        "large_supported_properties",
+        &large,
+        Some(true),
+    )
+    .await;
+
+    // Either decision is fine, but it must be a well-formed response
+    let mut unknown = allowed.clone();
+    UNKNOWN_RESOURCE_TYPE.clone_into(&mut unknown.resource.resource_type);
+    expect_decision(client, &mut report, "unknown_resource_type", &unknown, None).await;
+
+    check_deterministic(client, &mut report, &allowed).await;
+    check_concurrent(client, &mut report, &allowed, fixture.concurrency).await;
+
+    report
+}
+
+/// Evaluate `request`, check the invariants and, if given, the decision.
+async fn expect_decision(
+    client: &dyn AuthZResolverPluginClient,
+    report: &mut ConformanceReport,
+    case: &'static str,
+    request: &EvaluationRequest,
+    decision: Option<bool>,
+) {
+    report.cases.push(case);
+    let response = match client.evaluate(request.clone()).await {
+        Ok(response) => response,
+        Err(e) => {
+            report.violation(case, format!("evaluate returned an error: {e}"));
+            return;
+        }
+    };
+
+    if let Some(expected) = decision
+        && response.decision != expected
+    {
+        report.violation(
+            case,
+            format!(
+                "expected decision {expected}, got {} (deny_reason: {:?})",
+                response.decision, response.context.deny_reason
+            ),
+        );
+    }
+    for message in invariant_violations(request, &response) {
+        report.violation(case, message);
+    }
+}
+
+async fn check_deterministic(
+    client: &dyn AuthZResolverPluginClient,
+    report: &mut ConformanceReport,
+    request: &EvaluationRequest,
+) {
+    const CASE: &str = "deterministic";
+    report.cases.push(CASE);
+    let first = client.evaluate(request.clone()).await;
+    let second = client.evaluate(request.clone()).await;
+    match (first, second) {
+        (Ok(first), Ok(second)) => {
+            if canonical(&first) != canonical(&second) {
+                report.violation(
+                    CASE,
+                    format!(
+                        "repeated evaluation changed the response: {} vs {}",
+                        canonical(&first),
+                        canonical(&second)
+                    ),
+                );
+            }
+        }
+        (Err(e), _) | (_, Err(e)) => {
+            report.violation(CASE, format!("evaluate returned an error: {e}"));
+        }
+    }
+}
+
+async fn check_concurrent(
+    client: &dyn AuthZResolverPluginClient,
+    report: &mut ConformanceReport,
+    request: &EvaluationRequest,
+    concurrency: usize,
+) {
+    const CASE: &str = "concurrent";
+    report.cases.push(CASE);
+    let Ok(baseline) = client.evaluate(request.clone()).await else {
+        // Already reported by the sequential cases
+        return;
+    };
+    let baseline = canonical(&baseline);
+
+    let results = join_all((0..concurrency).map(|_| client.evaluate(request.clone()))).await;
+    let mut errors = 0;
+    let mut mismatches = 0;
+    for result in results {
+        match result {
+            Ok(response) if canonical(&response) == baseline => {}
+            Ok(_) => mismatches += 1,
+            Err(_) => errors += 1,
+        }
+    }
+    if errors > 0 {
+        report.violation(
+            CASE,
+            format!("{errors} of {concurrency} concurrent evaluations failed"),
+        );
+    }
+    if mismatches > 0 {
+        report.violation(
+            CASE,
+            format!(
+                "{mismatches} of {concurrency} concurrent evaluations differ from the sequential response"
+            ),
+        );
+    }
+}
+
+/// Invariants every response must satisfy, regardless of the decision.
+fn invariant_violations(request: &EvaluationRequest, response: &EvaluationResponse) -> Vec<String> {
+    let mut out = Vec::new();
+    let ctx = &response.context;
+
+    if response.decision {
+        if request.context.require_constraints && ctx.constraints.is_empty() {
+            out.push(
+                "allow without constraints although require_constraints=true \
+                 (the PEP rejects this as ConstraintsRequiredButAbsent)"
+                    .to_owned(),
+            );
+        }
+    } else {
+        match &ctx.deny_reason {
+            None => out.push("deny without deny_reason".to_owned()),
+            Some(reason) if reason.error_code.trim().is_empty() => {
+                out.push("deny_reason has an empty error_code".to_owned());
+            }
+            Some(_) => {}
+        }
+        if !ctx.constraints.is_empty() {
+            out.push(format!(
+                "deny carries {} constraint(s); constraints belong to allow decisions only",
+                ctx.constraints.len()
+            ));
+        }
+    }
+
+    let supported = &request.context.supported_properties;
+    for (index, constraint) in ctx.constraints.iter().enumerate() {
+        if constraint.predicates.is_empty() {
+            out.push(format!(
+                "constraint #{index} has no predicates (matches every row)"
+            ));
+        }
+        for predicate in &constraint.predicates {
+            let property = predicate.property();
+            if !supported.iter().any(|p| p == property) {
+                out.push(format!(
+                    "constraint #{index} uses property '{property}' not in supported_properties {supported:?}"
+                ));
+            }
+            if let Predicate::In(p) = predicate
+                && p.values.is_empty()
+            {
+                out.push(format!(
+                    "constraint #{index} has an empty IN list for '{property}'"
+                ));
+            }
+        }
+    }
+    out
+}
+
+/// Serialized form used to compare responses (`Constraint` has no `PartialEq`).
+fn canonical(response: &EvaluationResponse) -> String {
+    serde_json::to_string(response).unwrap_or_else(|e| format!("<unserializable: {e}>"))
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use modkit_security::pep_properties;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::constraints::{ConstraintSet, respond_allow};
+    use crate::error::AuthZResolverError;
+    use crate::models::{
+        Action, EvaluationRequestContext, EvaluationResponseContext, Resource, Subject,
+    };
+
+    fn request(supported: &[&str]) -> EvaluationRequest {
+        EvaluationRequest {
+            subject: Subject {
+                id: Uuid::nil(),
+                subject_type: None,
+                properties: HashMap::new(),
+            },
+            action: Action {
+                name: "list".to_owned(),
+            },
+            resource: Resource {
+                resource_type: "gts.x.core.users.user.v1~".to_owned(),
+                id: None,
+                properties: HashMap::new(),
+            },
+            context: EvaluationRequestContext {
+                tenant_context: None,
+                token_scopes: vec![],
+                token_scopes_narrowed: false,
+                require_constraints: true,
+                capabilities: vec![],
+                supported_properties: supported.iter().map(|p| (*p).to_owned()).collect(),
+                attributes: HashMap::new(),
+                bearer_token: None,
+            },
+        }
+    }
+
+    /// Allows with a tenant constraint, ignoring `supported_properties` and
+    /// `require_constraints`; denies without a reason on odd calls when `flaky`.
+    struct Sloppy {
+        calls: AtomicUsize,
+        flaky: bool,
+    }
+
+    #[async_trait]
+    impl AuthZResolverPluginClient for Sloppy {
+        async fn evaluate(
+            &self,
+            _request: EvaluationRequest,
+        ) -> Result<EvaluationResponse, AuthZResolverError> {
+            let call = self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.flaky && call % 2 == 1 {
+                return Ok(EvaluationResponse {
+                    decision: false,
+                    context: EvaluationResponseContext::default(),
+                });
+            }
+            Ok(respond_allow(
+                ConstraintSet::allow()
+                    .tenant_in([Uuid::nil()])
+                    .build()
+                    .unwrap(),
+            ))
+        }
+    }
+
+    fn sloppy(flaky: bool) -> Arc<dyn AuthZResolverPluginClient> {
+        Arc::new(Sloppy {
+            calls: AtomicUsize::new(0),
+            flaky,
+        })
+    }
+
+    #[tokio::test]
+    async fn well_behaved_plugin_passes() {
+        let fixture = ConformanceFixture::new(request(&[pep_properties::OWNER_TENANT_ID]));
+        let report = run_conformance_suite(sloppy(false), fixture).await;
+
+        report.assert_passed();
+        assert!(report.cases.contains(&"concurrent"));
+        assert!(!report.cases.contains(&"deny"));
+    }
+
+    #[tokio::test]
+    async fn unadvertised_property_is_reported() {
+        let fixture = ConformanceFixture::new(request(&[pep_properties::RESOURCE_ID]));
+        let report = run_conformance_suite(sloppy(false), fixture).await;
+
+        assert!(!report.passed());
+        assert!(
+            report
+                .violations
+                .iter()
+                .any(|v| v.case == "allow" && v.message.contains("not in supported_properties")),
+            "{report}"
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_deny_and_nondeterminism_are_reported() {
+        let fixture = ConformanceFixture::new(request(&[pep_properties::OWNER_TENANT_ID]))
+            .with_denied(request(&[pep_properties::OWNER_TENANT_ID]))
+            .with_concurrency(4);
+        let report = run_conformance_suite(sloppy(true), fixture).await;
+
+        let rendered = report.to_string();
+        assert!(rendered.contains("deny without deny_reason"), "{rendered}");
+        assert!(rendered.contains("[deterministic]"), "{rendered}");
+        assert!(rendered.contains("[concurrent]"), "{rendered}");
+    }
+}
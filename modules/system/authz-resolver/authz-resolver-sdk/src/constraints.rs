@@ -5,7 +5,31 @@
 //!
 //! ## Supported predicates
 //!
-//! Only `Eq` and `In` predicates are supported in the first iteration.
+//! `Eq`, `In` and `NotIn` predicates are supported. `NotIn` narrows a grant
+//! (e.g. "the tenant's rows except these owners"); it never grants on its own,
+//! so it is combined with `Eq`/`In` predicates in the same constraint.
+//!
+//! ## Building constraints
+//!
+//! PDP plugins should build constraints with [`ConstraintSet`] rather than
+//! assembling predicate trees by hand:
+//!
+//! ```ignore
+//! let (set, dropped) = ConstraintSet::allow()
+//!     .tenant_in([tenant_id])
+//!     .owner_eq(subject_id)
+//!     .or()
+//!     .tenant_in([tenant_id])
+//!     .prop_in("city_id", city_ids)
+//!     .filtered_to_supported(&request.context.supported_properties);
+//! if !dropped.is_empty() {
+//!     tracing::debug!(?dropped, "dropped constraints the PEP cannot compile");
+//! }
+//! match set.build() {
+//!     Ok(constraints) => respond_allow(constraints),
+//!     Err(e) => ConstraintSet::deny("no_applicable_constraints", Some(e.to_string())),
+//! }
+//! ```
 //!
 //! ## Future extensions
 //!
@@ -13,7 +37,11 @@
 //! `in_group_subtree`) are planned. See the authorization design document
 //! (`docs/arch/authorization/DESIGN.md`) for the full predicate taxonomy.
 
+use std::collections::HashSet;
+
+use crate::models::{DenyReason, EvaluationResponse, EvaluationResponseContext};
 use crate::pep::IntoPropertyValue;
+use modkit_security::pep_properties;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -36,6 +64,8 @@ pub enum Predicate {
     Eq(EqPredicate),
     /// Set membership: `resource_property IN (values)`
     In(InPredicate),
+    /// Exclusion: `resource_property NOT IN (values)`
+    NotIn(NotInPredicate),
 }
 
 /// Equality predicate: `property = value`.
@@ -84,6 +114,245 @@ impl InPredicate {
     }
 }
 
+/// Exclusion predicate: `property NOT IN (values)`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotInPredicate {
+    /// Resource property name (e.g., `pep_properties::OWNER_ID`).
+    pub property: String,
+    /// The set of values to exclude.
+    pub values: Vec<Value>,
+}
+
+impl NotInPredicate {
+    /// Create a `NOT IN` predicate from an iterator of convertible values.
+    #[must_use]
+    pub fn new<V: IntoPropertyValue>(
+        property: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        Self {
+            property: property.into(),
+            values: values
+                .into_iter()
+                .map(IntoPropertyValue::into_filter_value)
+                .collect(),
+        }
+    }
+}
+
+impl Predicate {
+    /// Resource property this predicate applies to.
+    #[must_use]
+    pub fn property(&self) -> &str {
+        match self {
+            Predicate::Eq(p) => &p.property,
+            Predicate::In(p) => &p.property,
+            Predicate::NotIn(p) => &p.property,
+        }
+    }
+}
+
+/// Error returned by [`ConstraintSet::build`] for malformed constraints.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConstraintBuildError {
+    /// The set contains no constraints. An allow decision without row-level
+    /// filtering must be expressed with `respond_allow(vec![])` explicitly.
+    #[error("constraint set is empty")]
+    NoConstraints,
+
+    /// A constraint has no predicates (it would match every row).
+    #[error("constraint #{index} has no predicates")]
+    EmptyConstraint { index: usize },
+
+    /// The same property appears more than once within one constraint.
+    #[error("constraint #{index} has duplicate property '{property}'")]
+    DuplicateProperty { index: usize, property: String },
+
+    /// An `IN` predicate has an empty value list (it would match nothing).
+    #[error("constraint #{index} has an empty value list for property '{property}'")]
+    EmptyValues { index: usize, property: String },
+
+    /// A constraint has only `NOT IN` predicates (it would match every row
+    /// except the listed values).
+    #[error("constraint #{index} has no eq/in predicate to narrow")]
+    ExclusionOnly { index: usize },
+}
+
+/// Builder for the constraints of an allow decision.
+///
+/// Predicates added in sequence form one constraint (`ANDed`); [`or`](Self::or)
+/// starts the next constraint (`ORed` with the previous ones). Validation runs
+/// in [`build`](Self::build).
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ConstraintSet {
+    constraints: Vec<Vec<Predicate>>,
+}
+
+impl ConstraintSet {
+    /// Start an allow decision with one empty constraint.
+    pub fn allow() -> Self {
+        Self {
+            constraints: vec![Vec::new()],
+        }
+    }
+
+    /// Deny response shell with the given reason.
+    #[must_use]
+    pub fn deny(error_code: impl Into<String>, details: Option<String>) -> EvaluationResponse {
+        respond_deny(DenyReason {
+            error_code: error_code.into(),
+            details,
+        })
+    }
+
+    /// `owner_tenant_id IN (ids)` on the current constraint.
+    pub fn tenant_in<V: IntoPropertyValue>(self, ids: impl IntoIterator<Item = V>) -> Self {
+        self.prop_in(pep_properties::OWNER_TENANT_ID, ids)
+    }
+
+    /// `owner_id = id` on the current constraint.
+    pub fn owner_eq(self, id: impl IntoPropertyValue) -> Self {
+        self.prop_eq(pep_properties::OWNER_ID, id)
+    }
+
+    /// `id = id` on the current constraint.
+    pub fn resource_eq(self, id: impl IntoPropertyValue) -> Self {
+        self.prop_eq(pep_properties::RESOURCE_ID, id)
+    }
+
+    /// `property = value` on the current constraint.
+    pub fn prop_eq(self, property: impl Into<String>, value: impl IntoPropertyValue) -> Self {
+        self.push(Predicate::Eq(EqPredicate::new(property, value)))
+    }
+
+    /// `property IN (values)` on the current constraint.
+    pub fn prop_in<V: IntoPropertyValue>(
+        self,
+        property: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.push(Predicate::In(InPredicate::new(property, values)))
+    }
+
+    /// `property NOT IN (values)` on the current constraint.
+    ///
+    /// An empty `values` excludes nothing.
+    pub fn prop_not_in<V: IntoPropertyValue>(
+        self,
+        property: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.push(Predicate::NotIn(NotInPredicate::new(property, values)))
+    }
+
+    /// Close the current constraint and start a new one.
+    pub fn or(mut self) -> Self {
+        self.constraints.push(Vec::new());
+        self
+    }
+
+    /// Keep only constraints the PEP can compile.
+    ///
+    /// Predicates within a constraint are `ANDed`, so removing a single
+    /// predicate would widen the grant. A constraint with any unsupported
+    /// property is dropped as a whole; the unsupported property names are
+    /// returned alongside the filtered set.
+    pub fn filtered_to_supported(mut self, supported: &[String]) -> (Self, Vec<String>) {
+        let mut dropped = Vec::new();
+        self.constraints.retain(|predicates| {
+            let unsupported: Vec<String> = predicates
+                .iter()
+                .map(Predicate::property)
+                .filter(|p| !supported.iter().any(|s| s == p))
+                .map(str::to_owned)
+                .collect();
+            let keep = unsupported.is_empty();
+            dropped.extend(unsupported);
+            keep
+        });
+        (self, dropped)
+    }
+
+    /// Validate and produce the constraints.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConstraintBuildError`] if the set is empty, a constraint has
+    /// no predicates or only `NOT IN` predicates, a property repeats within a
+    /// constraint, or an `IN` predicate has no values.
+    pub fn build(self) -> Result<Vec<Constraint>, ConstraintBuildError> {
+        if self.constraints.is_empty() {
+            return Err(ConstraintBuildError::NoConstraints);
+        }
+
+        self.constraints
+            .into_iter()
+            .enumerate()
+            .map(|(index, predicates)| {
+                if predicates.is_empty() {
+                    return Err(ConstraintBuildError::EmptyConstraint { index });
+                }
+                let mut seen = HashSet::new();
+                for predicate in &predicates {
+                    let property = predicate.property();
+                    if !seen.insert(property) {
+                        return Err(ConstraintBuildError::DuplicateProperty {
+                            index,
+                            property: property.to_owned(),
+                        });
+                    }
+                    if let Predicate::In(p) = predicate
+                        && p.values.is_empty()
+                    {
+                        return Err(ConstraintBuildError::EmptyValues {
+                            index,
+                            property: property.to_owned(),
+                        });
+                    }
+                }
+                if predicates.iter().all(|p| matches!(p, Predicate::NotIn(_))) {
+                    return Err(ConstraintBuildError::ExclusionOnly { index });
+                }
+                Ok(Constraint { predicates })
+            })
+            .collect()
+    }
+
+    fn push(mut self, predicate: Predicate) -> Self {
+        if let Some(current) = self.constraints.last_mut() {
+            current.push(predicate);
+        } else {
+            self.constraints.push(vec![predicate]);
+        }
+        self
+    }
+}
+
+/// Allow response carrying `constraints`.
+#[must_use]
+pub fn respond_allow(constraints: Vec<Constraint>) -> EvaluationResponse {
+    EvaluationResponse {
+        decision: true,
+        context: EvaluationResponseContext {
+            constraints,
+            ..Default::default()
+        },
+    }
+}
+
+/// Deny response carrying `reason`.
+#[must_use]
+pub fn respond_deny(reason: DenyReason) -> EvaluationResponse {
+    EvaluationResponse {
+        decision: false,
+        context: EvaluationResponseContext {
+            deny_reason: Some(reason),
+            ..Default::default()
+        },
+    }
+}
+
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
@@ -132,4 +401,187 @@ mod tests {
         let json_str = serde_json::to_string(&in_pred).unwrap();
         assert!(json_str.contains(r#""op":"in""#));
     }
+
+    // ── ConstraintSet DSL ───────────────────────────────────────────
+
+    fn supported(props: &[&str]) -> Vec<String> {
+        props.iter().map(|p| (*p).to_owned()).collect()
+    }
+
+    #[test]
+    fn dsl_builds_ored_constraints() {
+        let tenant = uuid::Uuid::from_u128(1);
+        let owner = uuid::Uuid::from_u128(2);
+
+        let constraints = ConstraintSet::allow()
+            .tenant_in([tenant])
+            .owner_eq(owner)
+            .or()
+            .tenant_in([tenant])
+            .prop_in("city_id", ["a", "b"])
+            .build()
+            .unwrap();
+
+        assert_eq!(constraints.len(), 2);
+        let props: Vec<&str> = constraints[0]
+            .predicates
+            .iter()
+            .map(Predicate::property)
+            .collect();
+        assert_eq!(
+            props,
+            [pep_properties::OWNER_TENANT_ID, pep_properties::OWNER_ID]
+        );
+        match &constraints[1].predicates[1] {
+            Predicate::In(p) => assert_eq!(p.values, vec![json!("a"), json!("b")]),
+            other @ (Predicate::Eq(_) | Predicate::NotIn(_)) => {
+                panic!("Expected In predicate, got: {other:?}")
+            }
+        }
+    }
+
+    #[test]
+    fn dsl_builds_not_in_predicates() {
+        let blocked = uuid::Uuid::from_u128(3);
+
+        let constraints = ConstraintSet::allow()
+            .tenant_in(["t1"])
+            .prop_not_in(pep_properties::OWNER_ID, [blocked])
+            .build()
+            .unwrap();
+
+        let json_str = serde_json::to_string(&constraints[0].predicates[1]).unwrap();
+        assert!(json_str.contains(r#""op":"not_in""#), "{json_str}");
+        match serde_json::from_str::<Predicate>(&json_str).unwrap() {
+            Predicate::NotIn(p) => {
+                assert_eq!(p.property, pep_properties::OWNER_ID);
+                assert_eq!(p.values, vec![json!(blocked.to_string())]);
+            }
+            other @ (Predicate::Eq(_) | Predicate::In(_)) => {
+                panic!("Expected NotIn predicate, got: {other:?}")
+            }
+        }
+
+        let excludes_nothing = ConstraintSet::allow()
+            .tenant_in(["t1"])
+            .prop_not_in(pep_properties::OWNER_ID, Vec::<uuid::Uuid>::new())
+            .build();
+        assert!(excludes_nothing.is_ok());
+    }
+
+    #[test]
+    fn dsl_rejects_exclusion_only_constraint() {
+        let err = ConstraintSet::allow()
+            .tenant_in(["t1"])
+            .or()
+            .prop_not_in(pep_properties::OWNER_TENANT_ID, ["t2"])
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ConstraintBuildError::ExclusionOnly { index: 1 });
+    }
+
+    #[test]
+    fn dsl_rejects_empty_constraint() {
+        let err = ConstraintSet::allow().build().unwrap_err();
+        assert_eq!(err, ConstraintBuildError::EmptyConstraint { index: 0 });
+
+        let err = ConstraintSet::allow()
+            .resource_eq("r1")
+            .or()
+            .build()
+            .unwrap_err();
+        assert_eq!(err, ConstraintBuildError::EmptyConstraint { index: 1 });
+    }
+
+    #[test]
+    fn dsl_rejects_duplicate_property() {
+        let err = ConstraintSet::allow()
+            .tenant_in(["t1"])
+            .prop_eq(pep_properties::OWNER_TENANT_ID, "t2")
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConstraintBuildError::DuplicateProperty {
+                index: 0,
+                property: pep_properties::OWNER_TENANT_ID.to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn dsl_allows_same_property_across_constraints() {
+        let constraints = ConstraintSet::allow()
+            .tenant_in(["t1"])
+            .or()
+            .tenant_in(["t2"])
+            .build()
+            .unwrap();
+        assert_eq!(constraints.len(), 2);
+    }
+
+    #[test]
+    fn dsl_rejects_empty_values() {
+        let err = ConstraintSet::allow()
+            .tenant_in(Vec::<uuid::Uuid>::new())
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ConstraintBuildError::EmptyValues {
+                index: 0,
+                property: pep_properties::OWNER_TENANT_ID.to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn filtered_to_supported_drops_whole_constraint() {
+        let (set, dropped) = ConstraintSet::allow()
+            .tenant_in(["t1"])
+            .prop_eq("city_id", "c1")
+            .or()
+            .tenant_in(["t1"])
+            .owner_eq("u1")
+            .filtered_to_supported(&supported(&[
+                pep_properties::OWNER_TENANT_ID,
+                pep_properties::OWNER_ID,
+            ]));
+
+        assert_eq!(dropped, vec!["city_id".to_owned()]);
+        let constraints = set.build().unwrap();
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(
+            constraints[0].predicates[1].property(),
+            pep_properties::OWNER_ID
+        );
+    }
+
+    #[test]
+    fn filtered_to_supported_can_empty_the_set() {
+        let (set, dropped) = ConstraintSet::allow()
+            .prop_eq("city_id", "c1")
+            .filtered_to_supported(&supported(&[pep_properties::OWNER_TENANT_ID]));
+
+        assert_eq!(dropped, vec!["city_id".to_owned()]);
+        assert_eq!(
+            set.build().unwrap_err(),
+            ConstraintBuildError::NoConstraints
+        );
+    }
+
+    #[test]
+    fn respond_helpers_build_decisions() {
+        let allow = respond_allow(ConstraintSet::allow().tenant_in(["t1"]).build().unwrap());
+        assert!(allow.decision);
+        assert_eq!(allow.context.constraints.len(), 1);
+        assert!(allow.context.deny_reason.is_none());
+
+        let deny = ConstraintSet::deny("no_tenant", Some("tenant missing".to_owned()));
+        assert!(!deny.decision);
+        assert!(deny.context.constraints.is_empty());
+        let reason = deny.context.deny_reason.unwrap();
+        assert_eq!(reason.error_code, "no_tenant");
+        assert_eq!(reason.details.as_deref(), Some("tenant missing"));
+    }
 }
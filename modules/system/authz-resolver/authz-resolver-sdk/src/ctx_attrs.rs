@@ -0,0 +1,114 @@
+//! Well-known request context attributes for ABAC policies.
+//!
+//! Context attributes describe the circumstances of a request (when, from where,
+//! how strongly authenticated) rather than the resource itself. They are sent to
+//! the PDP under `context.attributes` of the evaluation request.
+//!
+//! The API gateway builds a standard [`ContextAttributes`] set for every HTTP
+//! request and stores it in the request extensions. Handlers pass it to the
+//! domain layer, which forwards it via [`AccessRequest::context_attributes`]
+//! without knowing anything about HTTP.
+//!
+//! [`AccessRequest::context_attributes`]: crate::pep::AccessRequest::context_attributes
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::pep::IntoPropertyValue;
+
+/// Client IP address as seen by the gateway (trusted proxies resolved).
+pub const REQUEST_IP: &str = "request_ip";
+
+/// Request timestamp (RFC 3339, UTC).
+pub const REQUEST_TIME: &str = "request_time";
+
+/// Raw `User-Agent` header value.
+pub const USER_AGENT: &str = "user_agent";
+
+/// Whether the subject authenticated with MFA / from a trusted device (boolean).
+pub const MFA_PRESENT: &str = "mfa_present";
+
+/// A set of context attributes attached to a request.
+///
+/// Cheap to clone and safe to store in HTTP request extensions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ContextAttributes(HashMap<String, Value>);
+
+impl ContextAttributes {
+    /// Create an empty attribute set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add (or replace) an attribute.
+    #[must_use]
+    pub fn with(mut self, key: impl Into<String>, value: impl IntoPropertyValue) -> Self {
+        self.insert(key, value);
+        self
+    }
+
+    /// Add (or replace) an attribute in place.
+    pub fn insert(&mut self, key: impl Into<String>, value: impl IntoPropertyValue) {
+        self.0.insert(key.into(), value.into_filter_value());
+    }
+
+    /// Get an attribute value by key.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// Returns `true` if no attributes are set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over all attributes.
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.0.iter()
+    }
+
+    /// Consume into the underlying map.
+    #[must_use]
+    pub fn into_inner(self) -> HashMap<String, Value> {
+        self.0
+    }
+}
+
+impl From<HashMap<String, Value>> for ContextAttributes {
+    fn from(map: HashMap<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_inserts_typed_values() {
+        let attrs = ContextAttributes::new()
+            .with(REQUEST_IP, "10.0.0.1")
+            .with(MFA_PRESENT, true);
+
+        assert_eq!(attrs.get(REQUEST_IP), Some(&Value::from("10.0.0.1")));
+        assert_eq!(attrs.get(MFA_PRESENT), Some(&Value::Bool(true)));
+        assert!(attrs.get(USER_AGENT).is_none());
+    }
+
+    #[test]
+    fn serializes_as_flat_object() {
+        let attrs = ContextAttributes::new().with(REQUEST_TIME, "2026-01-01T00:00:00Z");
+        let json = serde_json::to_value(&attrs).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "request_time": "2026-01-01T00:00:00Z" })
+        );
+    }
+}
@@ -6,9 +6,12 @@
 //! - [`AuthZResolverPluginClient`] - Plugin API trait for implementations
 //! - [`EvaluationRequest`], [`EvaluationResponse`] - Evaluation models
 //! - [`Constraint`], [`Predicate`] - Constraint types
+//! - [`ConstraintSet`] - Constraint builder for plugin authors
 //! - [`AuthZResolverError`] - Error types
 //! - [`AuthZResolverPluginSpecV1`] - GTS schema for plugin discovery
 //! - [`pep`] - PEP helpers ([`PolicyEnforcer`], [`ResourceType`], compiler)
+//! - [`ctx_attrs`] - Well-known request context attributes ([`ContextAttributes`])
+//! - `conformance` - Plugin conformance suite (`test-utils` feature)
 //!
 //! ## Usage
 //!
@@ -43,7 +46,10 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
 pub mod api;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod conformance;
 pub mod constraints;
+pub mod ctx_attrs;
 pub mod error;
 pub mod gts;
 pub mod models;
@@ -52,12 +58,20 @@ pub mod plugin_api;
 
 // Re-export main types at crate root
 pub use api::AuthZResolverClient;
-pub use constraints::{Constraint, EqPredicate, InPredicate, Predicate};
+pub use constraints::{
+    Constraint, ConstraintBuildError, ConstraintSet, EqPredicate, InPredicate, NotInPredicate,
+    Predicate, respond_allow, respond_deny,
+};
+pub use ctx_attrs::ContextAttributes;
 pub use error::AuthZResolverError;
 pub use gts::AuthZResolverPluginSpecV1;
 pub use models::{
-    Action, BarrierMode, Capability, DenyReason, EvaluationRequest, EvaluationRequestContext,
-    EvaluationResponse, EvaluationResponseContext, Resource, Subject, TenantContext, TenantMode,
+    Action, BarrierMode, Capability, DenyReason, EvaluationDefaults, EvaluationRequest,
+    EvaluationRequestContext, EvaluationResponse, EvaluationResponseContext, Resource, Subject,
+    TenantContext, TenantMode,
+};
+pub use pep::{
+    AccessRequest, ConstraintCompileError, ContextEnricher, EnforcerError, IntoPropertyValue,
+    PolicyEnforcer, ResourceType, compile_to_access_scope,
 };
-pub use pep::{AccessRequest, EnforcerError, IntoPropertyValue, PolicyEnforcer, ResourceType};
 pub use plugin_api::AuthZResolverPluginClient;
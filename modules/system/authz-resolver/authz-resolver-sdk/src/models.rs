@@ -129,6 +129,33 @@ pub struct TenantContext {
     pub tenant_status: Option<Vec<String>>,
 }
 
+/// Default tenant context values for a resource type and action.
+///
+/// Configured at the resolver and served through
+/// [`AuthZResolverClient::evaluation_defaults`](crate::AuthZResolverClient::evaluation_defaults).
+/// PEPs apply them to the fields a call site did not set; `None` leaves the
+/// field as the call site built it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EvaluationDefaults {
+    /// Default tenant hierarchy mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_mode: Option<TenantMode>,
+    /// Default barrier enforcement mode.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub barrier_mode: Option<BarrierMode>,
+    /// Default tenant status filter (e.g., `["active"]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_status: Option<Vec<String>>,
+}
+
+impl EvaluationDefaults {
+    /// Whether no default is set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tenant_mode.is_none() && self.barrier_mode.is_none() && self.tenant_status.is_none()
+    }
+}
+
 /// Additional evaluation request context.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(clippy::struct_field_names)] // field names follow design doc
@@ -139,6 +166,11 @@ pub struct EvaluationRequestContext {
     /// Token scopes from the `AuthN` result.
     #[serde(default)]
     pub token_scopes: Vec<String>,
+    /// `token_scopes` come from a narrowed `SecurityContext` or a scope
+    /// override. An empty list then grants nothing; it must not be read as
+    /// unrestricted.
+    #[serde(default)]
+    pub token_scopes_narrowed: bool,
     /// Whether the PDP should return row-level constraints.
     /// - `true` for LIST/GET/UPDATE/DELETE (need scope filtering)
     /// - `false` for CREATE (just need decision)
@@ -150,6 +182,10 @@ pub struct EvaluationRequestContext {
     /// Supported constraint properties (tells PDP which properties the PEP understands).
     #[serde(default)]
     pub supported_properties: Vec<String>,
+    /// Request context attributes for ABAC policies (time, client IP, MFA, ...).
+    /// Well-known keys are defined in [`crate::ctx_attrs`].
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub attributes: HashMap<String, serde_json::Value>,
     /// Original bearer token for PDP forwarding. Wrapped in `SecretString` to prevent
     /// accidental logging. Skipped during serialization — the PDP receives the token
     /// through a separate channel if needed.
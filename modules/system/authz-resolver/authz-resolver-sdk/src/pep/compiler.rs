@@ -11,14 +11,16 @@
 //! | true              | empty       | `ConstraintsRequiredButAbsent` |
 //! | true              | present     | Compile constraints → `AccessScope` |
 //!
-//! Unknown/unsupported properties fail that constraint (fail-closed).
+//! Unknown/unsupported properties fail that constraint (fail-closed), and so
+//! does a constraint made only of `NotIn` predicates: an exclusion narrows a
+//! grant but never grants on its own.
 //!
 //! When `require_constraints=false`, empty constraints are treated as
 //! `allow_all()` (legitimate PDP "yes, no row-level filtering"). When
 //! `require_constraints=true`, empty constraints are an error (fail-closed).
 //! If the PDP returns constraints regardless of the flag, they are compiled.
 
-use modkit_security::{AccessScope, ScopeConstraint, ScopeFilter, ScopeValue};
+use modkit_security::{AccessScope, ScopeConstraint, ScopeFilter, ScopeValue, access_scope};
 
 use crate::constraints::{Constraint, Predicate};
 use crate::models::EvaluationResponse;
@@ -33,6 +35,10 @@ pub enum ConstraintCompileError {
     #[error("constraints required but PDP returned none (fail-closed)")]
     ConstraintsRequiredButAbsent,
 
+    /// The response is a deny decision; there is no scope to compile.
+    #[error("PDP denied access")]
+    Denied,
+
     /// All constraints contained unknown predicates (fail-closed).
     #[error("all constraints failed compilation (fail-closed): {reason}")]
     AllConstraintsFailed { reason: String },
@@ -104,14 +110,46 @@ pub fn compile_to_access_scope(
     Ok(AccessScope::from_constraints(constraints))
 }
 
+impl EvaluationResponse {
+    /// Compile this response's constraints into an `AccessScope`.
+    ///
+    /// Shortcut for [`compile_to_access_scope`] when calling the PDP without
+    /// a `PolicyEnforcer`. Unlike that function, it checks `decision` itself.
+    ///
+    /// # Errors
+    ///
+    /// - `Denied` if the response is a deny decision
+    /// - otherwise the same as [`compile_to_access_scope`]
+    pub fn to_access_scope(
+        &self,
+        require_constraints: bool,
+        supported_properties: &[&str],
+    ) -> Result<AccessScope, ConstraintCompileError> {
+        if !self.decision {
+            return Err(ConstraintCompileError::Denied);
+        }
+        compile_to_access_scope(self, require_constraints, supported_properties)
+    }
+}
+
 /// Compile a single PDP constraint into a `ScopeConstraint`.
 ///
 /// Each predicate becomes a `ScopeFilter`. If any predicate's property
-/// is not in `supported_properties`, the entire constraint fails (fail-closed).
+/// is not in `supported_properties`, or the constraint has only `NotIn`
+/// predicates, the entire constraint fails (fail-closed).
 fn compile_constraint(
     constraint: &Constraint,
     supported_properties: &[&str],
 ) -> Result<ScopeConstraint, String> {
+    if !constraint.predicates.is_empty()
+        && constraint
+            .predicates
+            .iter()
+            .all(|p| matches!(p, Predicate::NotIn(_)))
+    {
+        return Err("constraint has only not_in predicates".to_owned());
+    }
+
     let mut filters = Vec::new();
 
     for predicate in &constraint.predicates {
@@ -128,11 +166,21 @@ fn compile_constraint(
                     .collect::<Result<_, _>>()?;
                 (p.property.as_str(), ScopeFilter::r#in(&p.property, values))
             }
+            Predicate::NotIn(p) => {
+                let values: Vec<ScopeValue> = p
+                    .values
+                    .iter()
+                    .map(json_to_scope_value)
+                    .collect::<Result<_, _>>()?;
+                (
+                    p.property.as_str(),
+                    ScopeFilter::not_in(&p.property, values),
+                )
+            }
         };
 
-        if !supported_properties.contains(&property) {
-            return Err(format!("unsupported property: {property}"));
-        }
+        access_scope::check_property("", supported_properties, property)
+            .map_err(|e| e.to_string())?;
 
         filters.push(filter);
     }
@@ -167,7 +215,7 @@ fn json_to_scope_value(v: &serde_json::Value) -> Result<ScopeValue, String> {
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
     use super::*;
-    use crate::constraints::{EqPredicate, InPredicate};
+    use crate::constraints::{EqPredicate, InPredicate, NotInPredicate};
     use crate::models::EvaluationResponseContext;
     use modkit_security::pep_properties;
     use serde_json::json;
@@ -238,6 +286,49 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn to_access_scope_matches_compiler() {
+        let response = EvaluationResponse {
+            decision: true,
+            context: EvaluationResponseContext {
+                constraints: vec![Constraint {
+                    predicates: vec![Predicate::In(InPredicate {
+                        property: pep_properties::OWNER_TENANT_ID.to_owned(),
+                        values: vec![jid(T1), jid(T2)],
+                    })],
+                }],
+                ..Default::default()
+            },
+        };
+
+        let scope = response.to_access_scope(true, DEFAULT_PROPS).unwrap();
+        assert_eq!(
+            scope.all_uuid_values_for(pep_properties::OWNER_TENANT_ID),
+            &[uuid(T1), uuid(T2)]
+        );
+
+        let empty = EvaluationResponse {
+            decision: true,
+            context: EvaluationResponseContext::default(),
+        };
+        assert!(matches!(
+            empty.to_access_scope(true, DEFAULT_PROPS),
+            Err(ConstraintCompileError::ConstraintsRequiredButAbsent)
+        ));
+    }
+
+    #[test]
+    fn to_access_scope_rejects_deny_decision() {
+        let denied = EvaluationResponse {
+            decision: false,
+            context: EvaluationResponseContext::default(),
+        };
+        assert!(matches!(
+            denied.to_access_scope(false, DEFAULT_PROPS),
+            Err(ConstraintCompileError::Denied)
+        ));
+    }
+
     // === Constraint Compilation Tests ===
 
     #[test]
@@ -293,6 +384,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn not_in_predicate_compiles_to_exclusion_filter() {
+        let response = EvaluationResponse {
+            decision: true,
+            context: EvaluationResponseContext {
+                constraints: vec![Constraint {
+                    predicates: vec![
+                        Predicate::In(InPredicate {
+                            property: pep_properties::OWNER_TENANT_ID.to_owned(),
+                            values: vec![jid(T1)],
+                        }),
+                        Predicate::NotIn(NotInPredicate {
+                            property: pep_properties::RESOURCE_ID.to_owned(),
+                            values: vec![jid(R1)],
+                        }),
+                    ],
+                }],
+                ..Default::default()
+            },
+        };
+
+        let scope = compile_to_access_scope(&response, true, DEFAULT_PROPS).unwrap();
+        let filter = &scope.constraints()[0].filters()[1];
+        assert!(matches!(filter, ScopeFilter::NotIn(_)));
+        assert_eq!(filter.uuid_values(), &[uuid(R1)]);
+        assert!(!scope.contains_uuid(pep_properties::RESOURCE_ID, uuid(R1)));
+    }
+
+    #[test]
+    fn not_in_only_constraint_fails_closed() {
+        let not_in_only = Constraint {
+            predicates: vec![Predicate::NotIn(NotInPredicate {
+                property: pep_properties::OWNER_TENANT_ID.to_owned(),
+                values: vec![jid(T1)],
+            })],
+        };
+        let response = EvaluationResponse {
+            decision: true,
+            context: EvaluationResponseContext {
+                constraints: vec![not_in_only.clone()],
+                ..Default::default()
+            },
+        };
+
+        let err = compile_to_access_scope(&response, false, DEFAULT_PROPS).unwrap_err();
+        assert!(matches!(
+            err,
+            ConstraintCompileError::AllConstraintsFailed { .. }
+        ));
+
+        // Alongside a narrowing constraint, only the narrowing one survives.
+        let response = EvaluationResponse {
+            decision: true,
+            context: EvaluationResponseContext {
+                constraints: vec![
+                    not_in_only,
+                    Constraint {
+                        predicates: vec![Predicate::In(InPredicate {
+                            property: pep_properties::OWNER_TENANT_ID.to_owned(),
+                            values: vec![jid(T2)],
+                        })],
+                    },
+                ],
+                ..Default::default()
+            },
+        };
+        let scope = compile_to_access_scope(&response, false, DEFAULT_PROPS).unwrap();
+        assert_eq!(scope.constraints().len(), 1);
+        assert!(!scope.contains_uuid(pep_properties::OWNER_TENANT_ID, uuid(T1)));
+        assert!(scope.contains_uuid(pep_properties::OWNER_TENANT_ID, uuid(T2)));
+    }
+
     #[test]
     fn resource_id_eq_constraint() {
         let response = EvaluationResponse {
@@ -500,9 +663,12 @@ mod tests {
         };
 
         let result = compile_to_access_scope(&response, true, limited_props);
-        assert!(matches!(
-            result,
-            Err(ConstraintCompileError::AllConstraintsFailed { .. })
-        ));
+        match result {
+            Err(ConstraintCompileError::AllConstraintsFailed { reason }) => {
+                assert!(reason.contains("'id'"), "{reason}");
+                assert!(reason.contains("supported: [owner_tenant_id]"), "{reason}");
+            }
+            other => panic!("expected AllConstraintsFailed, got {other:?}"),
+        }
     }
 }
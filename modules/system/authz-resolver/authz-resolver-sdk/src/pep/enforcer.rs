@@ -8,18 +8,21 @@
 //! so a single enforcer can serve all resource types in a service.
 
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
-use modkit_security::{AccessScope, SecurityContext};
+use async_trait::async_trait;
+use modkit_security::{AccessScope, ScopeIntent, ScopeResource, SecurityContext};
 
 use super::IntoPropertyValue;
 use uuid::Uuid;
 
 use crate::api::AuthZResolverClient;
+use crate::ctx_attrs::ContextAttributes;
 use crate::error::AuthZResolverError;
 use crate::models::{
-    Action, BarrierMode, Capability, EvaluationRequest, EvaluationRequestContext, Resource,
-    Subject, TenantContext, TenantMode,
+    Action, BarrierMode, Capability, EvaluationDefaults, EvaluationRequest,
+    EvaluationRequestContext, Resource, Subject, TenantContext, TenantMode,
 };
 use crate::pep::compiler::{ConstraintCompileError, compile_to_access_scope};
 
@@ -69,12 +72,42 @@ pub enum EnforcerError {
 ///     &ctx, &RESOURCE, "list", None,
 ///     &AccessRequest::new().barrier_mode(BarrierMode::Ignore),
 /// ).await?;
+///
+/// // Forward gateway-provided context attributes (time, client IP, ...)
+/// let scope = enforcer.access_scope_with(
+///     &ctx, &RESOURCE, "delete", Some(id),
+///     &AccessRequest::new()
+///         .context_attributes(&attrs)
+///         .context_attribute(ctx_attrs::MFA_PRESENT, true),
+/// ).await?;
+///
+/// // Everything, the resource ID included, on the request
+/// let scope = enforcer.access_scope_with_request(
+///     &ctx, &RESOURCE, "get",
+///     &AccessRequest::new()
+///         .resource_id(Some(id))
+///         .resource_property(pep_properties::OWNER_TENANT_ID, tenant_id),
+/// ).await?;
 /// ```
 #[derive(Debug, Clone, Default)]
 pub struct AccessRequest {
+    resource_id: Option<Uuid>,
     resource_properties: HashMap<String, serde_json::Value>,
+    context_attributes: HashMap<String, serde_json::Value>,
     tenant_context: Option<TenantContext>,
+    overridden: TenantOverrides,
     require_constraints: Option<bool>,
+    token_scopes: Option<Vec<String>>,
+}
+
+/// Tenant context fields set explicitly on an [`AccessRequest`]; resolver
+/// defaults only fill the others.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(clippy::struct_excessive_bools)]
+struct TenantOverrides {
+    mode: bool,
+    barrier_mode: bool,
+    tenant_status: bool,
 }
 
 impl AccessRequest {
@@ -84,6 +117,16 @@ impl AccessRequest {
         Self::default()
     }
 
+    /// Set the ID of the resource being accessed (default: none).
+    ///
+    /// A `resource_id` passed to [`PolicyEnforcer::access_scope_with`] or
+    /// [`PolicyEnforcer::build_request_with`] takes precedence over this one.
+    #[must_use]
+    pub fn resource_id(mut self, id: Option<Uuid>) -> Self {
+        self.resource_id = id;
+        self
+    }
+
     /// Add a single resource property for ABAC evaluation.
     #[must_use]
     pub fn resource_property(
@@ -103,6 +146,31 @@ impl AccessRequest {
         self
     }
 
+    /// Add a single request context attribute for ABAC evaluation.
+    ///
+    /// Sent to the PDP under `context.attributes`. See [`crate::ctx_attrs`]
+    /// for well-known keys.
+    #[must_use]
+    pub fn context_attribute(
+        mut self,
+        key: impl Into<String>,
+        value: impl IntoPropertyValue,
+    ) -> Self {
+        self.context_attributes
+            .insert(key.into(), value.into_filter_value());
+        self
+    }
+
+    /// Merge a set of context attributes (e.g. the one built by the API gateway).
+    ///
+    /// Existing keys are overwritten.
+    #[must_use]
+    pub fn context_attributes(mut self, attrs: &ContextAttributes) -> Self {
+        self.context_attributes
+            .extend(attrs.iter().map(|(k, v)| (k.clone(), v.clone())));
+        self
+    }
+
     /// Override the context tenant ID (default: subject's tenant).
     #[must_use]
     pub fn context_tenant_id(mut self, id: Uuid) -> Self {
@@ -114,6 +182,7 @@ impl AccessRequest {
     #[must_use]
     pub fn tenant_mode(mut self, mode: TenantMode) -> Self {
         self.tenant_context.get_or_insert_default().mode = mode;
+        self.overridden.mode = true;
         self
     }
 
@@ -121,6 +190,7 @@ impl AccessRequest {
     #[must_use]
     pub fn barrier_mode(mut self, mode: BarrierMode) -> Self {
         self.tenant_context.get_or_insert_default().barrier_mode = mode;
+        self.overridden.barrier_mode = true;
         self
     }
 
@@ -128,13 +198,47 @@ impl AccessRequest {
     #[must_use]
     pub fn tenant_status(mut self, statuses: Vec<String>) -> Self {
         self.tenant_context.get_or_insert_default().tenant_status = Some(statuses);
+        self.overridden.tenant_status = true;
         self
     }
 
     /// Set the entire tenant context at once.
+    ///
+    /// Counts as an override of every tenant context field, so no resolver
+    /// default is applied on top of it.
     #[must_use]
     pub fn tenant_context(mut self, tc: TenantContext) -> Self {
         self.tenant_context = Some(tc);
+        self.overridden = TenantOverrides {
+            mode: true,
+            barrier_mode: true,
+            tenant_status: true,
+        };
+        self
+    }
+
+    /// Fill the tenant context fields this request does not override from `defaults`.
+    ///
+    /// Fields set with [`tenant_mode`](Self::tenant_mode),
+    /// [`barrier_mode`](Self::barrier_mode), [`tenant_status`](Self::tenant_status)
+    /// or [`tenant_context`](Self::tenant_context) are kept as is.
+    #[must_use]
+    pub fn with_defaults(mut self, defaults: &EvaluationDefaults) -> Self {
+        if let Some(mode) = &defaults.tenant_mode
+            && !self.overridden.mode
+        {
+            self.tenant_context.get_or_insert_default().mode = mode.clone();
+        }
+        if let Some(mode) = defaults.barrier_mode
+            && !self.overridden.barrier_mode
+        {
+            self.tenant_context.get_or_insert_default().barrier_mode = mode;
+        }
+        if let Some(statuses) = &defaults.tenant_status
+            && !self.overridden.tenant_status
+        {
+            self.tenant_context.get_or_insert_default().tenant_status = Some(statuses.clone());
+        }
         self
     }
 
@@ -155,6 +259,19 @@ impl AccessRequest {
         self.require_constraints = Some(require);
         self
     }
+
+    /// Override the token scopes sent to the PDP (default: `ctx.token_scopes()`).
+    ///
+    /// For service-to-service calls where the actor uses a machine token with
+    /// narrower scopes than the interactive user token (e.g. `["write:users"]`
+    /// for a delegated CREATE). The override is intersected with the context's
+    /// scopes as in [`SecurityContext::narrowed`], so it can only reduce them,
+    /// and the request is marked `token_scopes_narrowed`.
+    #[must_use]
+    pub fn token_scopes(mut self, scopes: Vec<String>) -> Self {
+        self.token_scopes = Some(scopes);
+        self
+    }
 }
 
 /// Static descriptor for a resource type and its supported constraint properties.
@@ -169,11 +286,104 @@ pub struct ResourceType {
     pub supported_properties: &'static [&'static str],
 }
 
+/// Lets scopes built by hand be checked with
+/// [`AccessScopeBuilder`](modkit_security::AccessScopeBuilder) against the
+/// same properties the PEP compiles.
+impl ScopeResource for ResourceType {
+    fn resource_name(&self) -> &str {
+        self.name
+    }
+
+    fn supported_properties(&self) -> &[&str] {
+        self.supported_properties
+    }
+}
+
+/// Hook that enriches an evaluation request right before it is sent to the PDP.
+///
+/// Use it for context the call site does not have at hand — e.g. the subject's
+/// department from a cache or the resource's classification from a lookup table.
+/// Registered once via [`PolicyEnforcer::with_context_enricher()`] and applied to
+/// every `access_scope*` call.
+///
+/// # Example
+///
+/// ```ignore
+/// struct Classification { lookup: Arc<ClassificationCache> }
+///
+/// #[async_trait]
+/// impl ContextEnricher for Classification {
+///     async fn enrich(&self, _ctx: &SecurityContext, request: &mut EvaluationRequest) {
+///         if let Some(id) = request.resource.id {
+///             let level = self.lookup.get(id).await;
+///             request.resource.properties.insert("classification".to_owned(), level.into());
+///         }
+///     }
+/// }
+///
+/// let enforcer = PolicyEnforcer::new(authz).with_context_enricher(Arc::new(classification));
+/// ```
+#[async_trait]
+pub trait ContextEnricher: Send + Sync {
+    /// Add resource properties, context attributes or tenant context to `request`.
+    async fn enrich(&self, ctx: &SecurityContext, request: &mut EvaluationRequest);
+}
+
+/// How long [`PolicyEnforcer::with_resolver_defaults()`] caches the defaults
+/// of a resource type and action.
+pub const DEFAULT_RESOLVER_DEFAULTS_TTL: Duration = Duration::from_secs(60);
+
+/// Resolver evaluation defaults cached per (resource type, action).
+struct ResolverDefaults {
+    ttl: Duration,
+    entries: Mutex<HashMap<(String, String), (Instant, EvaluationDefaults)>>,
+}
+
+impl ResolverDefaults {
+    fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    async fn get(
+        &self,
+        authz: &dyn AuthZResolverClient,
+        resource_type: &str,
+        action: &str,
+    ) -> Result<EvaluationDefaults, AuthZResolverError> {
+        let key = (resource_type.to_owned(), action.to_owned());
+        let cached = self
+            .entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, defaults)| defaults.clone());
+        if let Some(defaults) = cached {
+            return Ok(defaults);
+        }
+
+        let defaults = authz.evaluation_defaults(resource_type, action).await?;
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, (Instant::now(), defaults.clone()));
+        Ok(defaults)
+    }
+}
+
+/// Actions whose scopes are read-only unless overridden with
+/// [`PolicyEnforcer::with_action_intent()`].
+pub const DEFAULT_READ_ACTIONS: &[&str] = &["get", "list"];
+
 /// Policy Enforcement Point.
 ///
 /// Holds the `AuthZ` client and optional PEP capabilities.
 /// Constructed once during service init; cloneable and cheap to pass
-/// around (`Arc` inside). The resource type is supplied per call via
+/// around (`Arc` inside). Clones share the same underlying client and
+/// context enricher. The resource type is supplied per call via
 /// [`ResourceType`].
 ///
 /// # Example
@@ -193,21 +403,48 @@ pub struct ResourceType {
 /// let scope = enforcer.access_scope(&ctx, &USER, "get", Some(id)).await?;
 /// let scope = enforcer.access_scope(&ctx, &USER, "create", None).await?;
 /// ```
+///
+/// Scopes are tagged with a [`ScopeIntent`] derived from the action:
+/// [`DEFAULT_READ_ACTIONS`] yield read-only scopes that the secure ORM refuses
+/// to write with, every other action yields a read-write scope.
+///
+/// By default the enforcer fails closed: a PDP outage surfaces as
+/// [`EnforcerError::EvaluationFailed`]. See [`with_fail_open`](Self::with_fail_open).
 #[derive(Clone)]
 pub struct PolicyEnforcer {
     authz: Arc<dyn AuthZResolverClient>,
     capabilities: Vec<Capability>,
+    context_enricher: Option<Arc<dyn ContextEnricher>>,
+    action_intents: HashMap<String, ScopeIntent>,
+    fail_open: bool,
+    resolver_defaults: Option<Arc<ResolverDefaults>>,
 }
 
 impl PolicyEnforcer {
     /// Create a new enforcer.
+    ///
+    /// Use [`from_client`](Self::from_client) when the enforcer is the sole
+    /// owner of the client.
     pub fn new(authz: Arc<dyn AuthZResolverClient>) -> Self {
         Self {
             authz,
             capabilities: Vec::new(),
+            context_enricher: None,
+            action_intents: DEFAULT_READ_ACTIONS
+                .iter()
+                .map(|a| ((*a).to_owned(), ScopeIntent::Read))
+                .collect(),
+            fail_open: false,
+            resolver_defaults: None,
         }
     }
 
+    /// Create a new enforcer that takes ownership of `authz`.
+    #[must_use]
+    pub fn from_client(authz: impl AuthZResolverClient + 'static) -> Self {
+        Self::new(Arc::new(authz))
+    }
+
     /// Set PEP capabilities advertised to the PDP.
     #[must_use]
     pub fn with_capabilities(mut self, capabilities: Vec<Capability>) -> Self {
@@ -215,6 +452,72 @@ impl PolicyEnforcer {
         self
     }
 
+    /// Enrich every evaluation request with `enricher` before it reaches the PDP.
+    ///
+    /// Only applies to the full PEP flow (`access_scope*`); `build_request*`
+    /// return the request as built from the call site.
+    #[must_use]
+    pub fn with_context_enricher(mut self, enricher: Arc<dyn ContextEnricher>) -> Self {
+        self.context_enricher = Some(enricher);
+        self
+    }
+
+    /// Tag scopes obtained for `action` with `intent`.
+    ///
+    /// Overrides the default mapping, e.g. to make a custom `"export"` action
+    /// read-only or to allow writes under `"get"`.
+    #[must_use]
+    pub fn with_action_intent(mut self, action: impl Into<String>, intent: ScopeIntent) -> Self {
+        self.action_intents.insert(action.into(), intent);
+        self
+    }
+
+    /// Keep serving requests when the PDP cannot be reached.
+    ///
+    /// With `fail_open = true`, an [`EnforcerError::EvaluationFailed`] is
+    /// logged at error level and replaced by a scope limited to the subject's
+    /// home tenant (tagged with the action's intent). The fallback is never
+    /// wider than that: a context without a home tenant still gets the error,
+    /// and explicit PDP denials are always honoured.
+    ///
+    /// Only enable this for data where availability outweighs fine-grained
+    /// access control during an outage.
+    #[must_use]
+    pub fn with_fail_open(mut self, fail_open: bool) -> Self {
+        self.fail_open = fail_open;
+        self
+    }
+
+    /// Apply the evaluation defaults configured at the resolver
+    /// ([`AuthZResolverClient::evaluation_defaults`]) to every `access_scope*` call.
+    ///
+    /// Defaults only fill the tenant context fields the call site did not set
+    /// on its [`AccessRequest`]; explicit overrides always win. They are cached
+    /// per resource type and action for [`DEFAULT_RESOLVER_DEFAULTS_TTL`]. A
+    /// failure to fetch them is handled like a failed evaluation (see
+    /// [`with_fail_open`](Self::with_fail_open)).
+    #[must_use]
+    pub fn with_resolver_defaults(self) -> Self {
+        self.with_resolver_defaults_ttl(DEFAULT_RESOLVER_DEFAULTS_TTL)
+    }
+
+    /// Same as [`with_resolver_defaults`](Self::with_resolver_defaults) with a
+    /// custom cache TTL.
+    #[must_use]
+    pub fn with_resolver_defaults_ttl(mut self, ttl: Duration) -> Self {
+        self.resolver_defaults = Some(Arc::new(ResolverDefaults::new(ttl)));
+        self
+    }
+
+    /// The intent scopes for `action` are tagged with (`ReadWrite` if unmapped).
+    #[must_use]
+    pub fn intent_for(&self, action: &str) -> ScopeIntent {
+        self.action_intents
+            .get(action)
+            .copied()
+            .unwrap_or(ScopeIntent::ReadWrite)
+    }
+
     // ── Low-level: build request only ────────────────────────────────
 
     /// Build an evaluation request using the subject's tenant as context tenant
@@ -239,6 +542,9 @@ impl PolicyEnforcer {
     }
 
     /// Build an evaluation request with per-request overrides from [`AccessRequest`].
+    ///
+    /// `resource_id` takes precedence over the request's
+    /// [`resource_id`](AccessRequest::resource_id).
     #[must_use]
     pub fn build_request_with(
         &self,
@@ -262,6 +568,16 @@ impl PolicyEnforcer {
 
         let bearer_token = ctx.bearer_token().cloned();
 
+        // Narrowed contexts already carry the reduced set. An override is
+        // intersected with the context's scopes so it can never widen them.
+        let (token_scopes, token_scopes_narrowed) = match &request.token_scopes {
+            Some(scopes) => {
+                let scopes: Vec<&str> = scopes.iter().map(String::as_str).collect();
+                (ctx.narrowed(&scopes).token_scopes().to_vec(), true)
+            }
+            None => (ctx.token_scopes().to_vec(), ctx.is_narrowed()),
+        };
+
         EvaluationRequest {
             subject: Subject {
                 id: ctx.subject_id(),
@@ -273,12 +589,13 @@ impl PolicyEnforcer {
             },
             resource: Resource {
                 resource_type: resource.name.to_owned(),
-                id: resource_id,
+                id: resource_id.or(request.resource_id),
                 properties: request.resource_properties.clone(),
             },
             context: EvaluationRequestContext {
                 tenant_context,
-                token_scopes: ctx.token_scopes().to_vec(),
+                token_scopes,
+                token_scopes_narrowed,
                 require_constraints,
                 capabilities: self.capabilities.clone(),
                 supported_properties: resource
@@ -286,6 +603,7 @@ impl PolicyEnforcer {
                     .iter()
                     .map(|s| (*s).to_owned())
                     .collect(),
+                attributes: request.context_attributes.clone(),
                 bearer_token,
             },
         }
@@ -326,6 +644,12 @@ impl PolicyEnforcer {
     /// When `false`, the PDP may return no constraints; the resulting scope
     /// is `allow_all()`. When `true`, empty constraints trigger a compile error.
     ///
+    /// The scope's intent comes from [`intent_for(action)`](Self::intent_for).
+    /// With [`with_resolver_defaults`](Self::with_resolver_defaults), the
+    /// resolver's defaults fill the tenant context fields `request` leaves unset.
+    /// `resource_id` takes precedence over the request's
+    /// [`resource_id`](AccessRequest::resource_id).
+    ///
     /// # Errors
     ///
     /// - [`EnforcerError::EvaluationFailed`] if the PDP call fails
@@ -338,10 +662,28 @@ impl PolicyEnforcer {
         resource_id: Option<Uuid>,
         request: &AccessRequest,
     ) -> Result<AccessScope, EnforcerError> {
+        let defaulted;
+        let request = match &self.resolver_defaults {
+            Some(cache) => match cache.get(self.authz.as_ref(), resource.name, action).await {
+                Ok(defaults) => {
+                    defaulted = request.clone().with_defaults(&defaults);
+                    &defaulted
+                }
+                Err(e) => return self.on_evaluation_failed(ctx, resource, action, e),
+            },
+            None => request,
+        };
+
         let require = request.require_constraints.unwrap_or(true);
-        let eval_request =
+        let mut eval_request =
             self.build_request_with(ctx, resource, action, resource_id, require, request);
-        let response = self.authz.evaluate(eval_request).await?;
+        if let Some(enricher) = &self.context_enricher {
+            enricher.enrich(ctx, &mut eval_request).await;
+        }
+        let response = match self.authz.evaluate(eval_request).await {
+            Ok(response) => response,
+            Err(e) => return self.on_evaluation_failed(ctx, resource, action, e),
+        };
 
         // Check decision first: if denied, return error immediately
         // without attempting constraint compilation.
@@ -351,11 +693,48 @@ impl PolicyEnforcer {
             });
         }
 
-        Ok(compile_to_access_scope(
-            &response,
-            require,
-            resource.supported_properties,
-        )?)
+        let scope = compile_to_access_scope(&response, require, resource.supported_properties)?;
+        Ok(scope.with_intent(self.intent_for(action)))
+    }
+
+    /// [`access_scope_with`](Self::access_scope_with) taking the resource ID
+    /// from `request` only.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`access_scope_with`](Self::access_scope_with).
+    pub async fn access_scope_with_request(
+        &self,
+        ctx: &SecurityContext,
+        resource: &ResourceType,
+        action: &str,
+        request: &AccessRequest,
+    ) -> Result<AccessScope, EnforcerError> {
+        self.access_scope_with(ctx, resource, action, None, request)
+            .await
+    }
+
+    /// Fail closed, or fall back to the subject's home tenant in fail-open mode.
+    fn on_evaluation_failed(
+        &self,
+        ctx: &SecurityContext,
+        resource: &ResourceType,
+        action: &str,
+        error: AuthZResolverError,
+    ) -> Result<AccessScope, EnforcerError> {
+        let tenant_id = ctx.subject_tenant_id();
+        if !self.fail_open || tenant_id.is_nil() {
+            return Err(EnforcerError::EvaluationFailed(error));
+        }
+        tracing::error!(
+            error = %error,
+            resource_type = resource.name,
+            action,
+            subject_id = %ctx.subject_id(),
+            tenant_id = %tenant_id,
+            "PDP evaluation failed; failing open to the subject's home tenant"
+        );
+        Ok(AccessScope::for_tenant(tenant_id).with_intent(self.intent_for(action)))
     }
 }
 
@@ -363,6 +742,13 @@ impl std::fmt::Debug for PolicyEnforcer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("PolicyEnforcer")
             .field("capabilities", &self.capabilities)
+            .field("context_enricher", &self.context_enricher.is_some())
+            .field("action_intents", &self.action_intents)
+            .field("fail_open", &self.fail_open)
+            .field(
+                "resolver_defaults_ttl",
+                &self.resolver_defaults.as_ref().map(|d| d.ttl),
+            )
             .finish_non_exhaustive()
     }
 }
@@ -483,7 +869,7 @@ mod tests {
     };
 
     fn enforcer(mock: impl AuthZResolverClient + 'static) -> PolicyEnforcer {
-        PolicyEnforcer::new(Arc::new(mock))
+        PolicyEnforcer::from_client(mock)
     }
 
     // ── build_request ────────────────────────────────────────────────
@@ -502,6 +888,103 @@ mod tests {
         assert!(req.context.tenant_context.is_none());
     }
 
+    #[test]
+    fn build_request_passes_narrowed_scopes_as_is() {
+        let e = enforcer(AllowAllMock);
+        let ctx = SecurityContext::builder()
+            .subject_id(uuid(SUBJECT))
+            .subject_tenant_id(uuid(TENANT))
+            .token_scopes(vec!["read:users".to_owned(), "write:users".to_owned()])
+            .bearer_token("original-token".to_owned())
+            .build()
+            .unwrap()
+            .narrowed(&["read:users", "admin"]);
+
+        let req = e.build_request(&ctx, &TEST_RESOURCE, "get", None, true);
+
+        assert_eq!(req.context.token_scopes, vec!["read:users".to_owned()]);
+        assert!(req.context.token_scopes_narrowed);
+        assert!(req.context.bearer_token.is_none());
+    }
+
+    #[test]
+    fn build_request_marks_scopes_narrowed_to_nothing() {
+        let e = enforcer(AllowAllMock);
+        let ctx = SecurityContext::builder()
+            .subject_id(uuid(SUBJECT))
+            .subject_tenant_id(uuid(TENANT))
+            .token_scopes(vec!["read:users".to_owned()])
+            .build()
+            .unwrap()
+            .narrowed(&["admin"]);
+
+        let req = e.build_request(&ctx, &TEST_RESOURCE, "get", None, true);
+
+        assert!(req.context.token_scopes.is_empty());
+        assert!(req.context.token_scopes_narrowed);
+        let wire = serde_json::to_value(&req.context).unwrap();
+        assert_eq!(wire["token_scopes_narrowed"], true);
+    }
+
+    #[test]
+    fn build_request_with_overrides_token_scopes() {
+        let e = enforcer(AllowAllMock);
+        let ctx = SecurityContext::builder()
+            .subject_id(uuid(SUBJECT))
+            .subject_tenant_id(uuid(TENANT))
+            .token_scopes(vec!["*".to_owned()])
+            .build()
+            .unwrap();
+
+        let req = e.build_request_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "create",
+            None,
+            false,
+            &AccessRequest::new().token_scopes(vec!["write:users".to_owned()]),
+        );
+        assert_eq!(req.context.token_scopes, vec!["write:users".to_owned()]);
+        assert!(req.context.token_scopes_narrowed);
+
+        let req = e.build_request(&ctx, &TEST_RESOURCE, "create", None, false);
+        assert_eq!(req.context.token_scopes, vec!["*".to_owned()]);
+        assert!(!req.context.token_scopes_narrowed);
+    }
+
+    #[test]
+    fn build_request_override_cannot_widen_narrowed_scopes() {
+        let e = enforcer(AllowAllMock);
+        let ctx = SecurityContext::builder()
+            .subject_id(uuid(SUBJECT))
+            .subject_tenant_id(uuid(TENANT))
+            .token_scopes(vec!["*".to_owned()])
+            .build()
+            .unwrap()
+            .narrowed(&["read:users"]);
+
+        let req = e.build_request_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "create",
+            None,
+            false,
+            &AccessRequest::new().token_scopes(vec!["*".to_owned(), "write:users".to_owned()]),
+        );
+        assert!(req.context.token_scopes.is_empty());
+        assert!(req.context.token_scopes_narrowed);
+
+        let req = e.build_request_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "get",
+            None,
+            false,
+            &AccessRequest::new().token_scopes(vec!["read:users".to_owned()]),
+        );
+        assert_eq!(req.context.token_scopes, vec!["read:users".to_owned()]);
+    }
+
     #[test]
     fn build_request_with_overrides_tenant() {
         let custom_tenant = uuid("aaaaaaaa-aaaa-aaaa-aaaa-aaaaaaaaaaaa");
@@ -625,6 +1108,47 @@ mod tests {
         assert!(matches!(result, Err(EnforcerError::EvaluationFailed(_))));
     }
 
+    #[tokio::test]
+    async fn fail_open_falls_back_to_home_tenant() {
+        let e = enforcer(FailMock).with_fail_open(true);
+        let ctx = test_ctx();
+
+        let scope = e
+            .access_scope(&ctx, &TEST_RESOURCE, "list", None)
+            .await
+            .unwrap();
+        assert!(!scope.is_unconstrained());
+        assert_eq!(
+            scope.all_uuid_values_for(pep_properties::OWNER_TENANT_ID),
+            vec![uuid(TENANT)]
+        );
+        assert_eq!(scope.intent(), ScopeIntent::Read);
+
+        let scope = e
+            .access_scope(&ctx, &TEST_RESOURCE, "update", Some(uuid(RESOURCE)))
+            .await
+            .unwrap();
+        assert_eq!(scope.intent(), ScopeIntent::ReadWrite);
+    }
+
+    #[tokio::test]
+    async fn fail_open_without_home_tenant_still_fails() {
+        let e = enforcer(FailMock).with_fail_open(true);
+        let ctx = SecurityContext::anonymous();
+        let result = e.access_scope(&ctx, &TEST_RESOURCE, "get", None).await;
+
+        assert!(matches!(result, Err(EnforcerError::EvaluationFailed(_))));
+    }
+
+    #[tokio::test]
+    async fn fail_open_honours_explicit_denial() {
+        let e = enforcer(DenyMock::new()).with_fail_open(true);
+        let ctx = test_ctx();
+        let result = e.access_scope(&ctx, &TEST_RESOURCE, "get", None).await;
+
+        assert!(matches!(result, Err(EnforcerError::Denied { .. })));
+    }
+
     #[tokio::test]
     async fn access_scope_anonymous_no_tenant_returns_compile_error() {
         let e = enforcer(AllowAllMock);
@@ -635,6 +1159,160 @@ mod tests {
         assert!(matches!(result, Err(EnforcerError::CompileFailed(_))));
     }
 
+    // ── resolver defaults ────────────────────────────────────────────
+
+    fn list_defaults() -> EvaluationDefaults {
+        EvaluationDefaults {
+            tenant_mode: Some(TenantMode::RootOnly),
+            barrier_mode: Some(BarrierMode::Ignore),
+            tenant_status: Some(vec!["active".to_owned()]),
+        }
+    }
+
+    /// Allows everything without constraints, serves fixed defaults and
+    /// records the last evaluation request.
+    struct DefaultsMock {
+        defaults: EvaluationDefaults,
+        fetches: std::sync::atomic::AtomicUsize,
+        last_request: Mutex<Option<EvaluationRequest>>,
+    }
+
+    impl DefaultsMock {
+        fn new(defaults: EvaluationDefaults) -> Arc<Self> {
+            Arc::new(Self {
+                defaults,
+                fetches: std::sync::atomic::AtomicUsize::new(0),
+                last_request: Mutex::new(None),
+            })
+        }
+
+        fn last_tenant_context(&self) -> Option<TenantContext> {
+            self.last_request
+                .lock()
+                .unwrap()
+                .as_ref()
+                .and_then(|r| r.context.tenant_context.clone())
+        }
+    }
+
+    #[async_trait]
+    impl AuthZResolverClient for DefaultsMock {
+        async fn evaluate(
+            &self,
+            req: EvaluationRequest,
+        ) -> Result<EvaluationResponse, AuthZResolverError> {
+            *self.last_request.lock().unwrap() = Some(req);
+            Ok(EvaluationResponse {
+                decision: true,
+                context: EvaluationResponseContext::default(),
+            })
+        }
+
+        async fn evaluation_defaults(
+            &self,
+            _resource_type: &str,
+            _action: &str,
+        ) -> Result<EvaluationDefaults, AuthZResolverError> {
+            self.fetches
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            Ok(self.defaults.clone())
+        }
+    }
+
+    #[test]
+    fn with_defaults_fills_only_unset_fields() {
+        let tc = AccessRequest::new()
+            .context_tenant_id(uuid(TENANT))
+            .barrier_mode(BarrierMode::Respect)
+            .with_defaults(&list_defaults())
+            .tenant_context
+            .unwrap();
+
+        assert_eq!(tc.root_id, Some(uuid(TENANT)));
+        assert_eq!(tc.mode, TenantMode::RootOnly);
+        assert_eq!(tc.barrier_mode, BarrierMode::Respect);
+        assert_eq!(tc.tenant_status, Some(vec!["active".to_owned()]));
+
+        let explicit = AccessRequest::new()
+            .tenant_context(TenantContext::default())
+            .with_defaults(&list_defaults())
+            .tenant_context
+            .unwrap();
+        assert_eq!(explicit.mode, TenantMode::Subtree);
+        assert_eq!(explicit.barrier_mode, BarrierMode::Respect);
+        assert!(explicit.tenant_status.is_none());
+
+        let untouched = AccessRequest::new().with_defaults(&EvaluationDefaults::default());
+        assert!(untouched.tenant_context.is_none());
+    }
+
+    #[tokio::test]
+    async fn resolver_defaults_reach_the_pdp_and_are_cached() {
+        let mock = DefaultsMock::new(list_defaults());
+        let e = PolicyEnforcer::new(mock.clone()).with_resolver_defaults();
+        let ctx = test_ctx();
+        let request = AccessRequest::new().require_constraints(false);
+
+        for _ in 0..2 {
+            e.access_scope_with(&ctx, &TEST_RESOURCE, "list", None, &request)
+                .await
+                .unwrap();
+        }
+
+        let tc = mock.last_tenant_context().unwrap();
+        assert_eq!(tc.mode, TenantMode::RootOnly);
+        assert_eq!(tc.barrier_mode, BarrierMode::Ignore);
+        assert_eq!(tc.tenant_status, Some(vec!["active".to_owned()]));
+        assert_eq!(
+            mock.fetches.load(std::sync::atomic::Ordering::Relaxed),
+            1,
+            "defaults are cached per resource type and action"
+        );
+    }
+
+    #[tokio::test]
+    async fn explicit_overrides_beat_resolver_defaults() {
+        let mock = DefaultsMock::new(list_defaults());
+        let e = PolicyEnforcer::new(mock.clone()).with_resolver_defaults();
+        let ctx = test_ctx();
+
+        e.access_scope_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "list",
+            None,
+            &AccessRequest::new()
+                .require_constraints(false)
+                .tenant_mode(TenantMode::Subtree),
+        )
+        .await
+        .unwrap();
+
+        let tc = mock.last_tenant_context().unwrap();
+        assert_eq!(tc.mode, TenantMode::Subtree);
+        assert_eq!(tc.barrier_mode, BarrierMode::Ignore);
+    }
+
+    #[tokio::test]
+    async fn resolver_defaults_are_opt_in() {
+        let mock = DefaultsMock::new(list_defaults());
+        let e = PolicyEnforcer::new(mock.clone());
+        let ctx = test_ctx();
+
+        e.access_scope_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "list",
+            None,
+            &AccessRequest::new().require_constraints(false),
+        )
+        .await
+        .unwrap();
+
+        assert!(mock.last_tenant_context().is_none());
+        assert_eq!(mock.fetches.load(std::sync::atomic::Ordering::Relaxed), 0);
+    }
+
     // ── builder methods ──────────────────────────────────────────────
 
     #[test]
@@ -644,6 +1322,129 @@ mod tests {
         assert_eq!(e.capabilities, vec![Capability::TenantHierarchy]);
     }
 
+    #[test]
+    fn clones_share_client() {
+        let e = enforcer(AllowAllMock);
+        let clone = e.clone();
+
+        assert!(Arc::ptr_eq(&e.authz, &clone.authz));
+    }
+
+    #[test]
+    fn default_action_intents() {
+        let e = enforcer(AllowAllMock);
+        assert_eq!(e.intent_for("get"), ScopeIntent::Read);
+        assert_eq!(e.intent_for("list"), ScopeIntent::Read);
+        assert_eq!(e.intent_for("update"), ScopeIntent::ReadWrite);
+        assert_eq!(e.intent_for("custom"), ScopeIntent::ReadWrite);
+    }
+
+    #[test]
+    fn with_action_intent_overrides_mapping() {
+        let e = enforcer(AllowAllMock)
+            .with_action_intent("export", ScopeIntent::Read)
+            .with_action_intent("get", ScopeIntent::ReadWrite);
+        assert_eq!(e.intent_for("export"), ScopeIntent::Read);
+        assert_eq!(e.intent_for("get"), ScopeIntent::ReadWrite);
+    }
+
+    #[tokio::test]
+    async fn access_scope_carries_action_intent() {
+        let e = enforcer(AllowAllMock);
+        let ctx = test_ctx();
+        let request = AccessRequest::new().context_tenant_id(uuid(TENANT));
+
+        let read = e
+            .access_scope_with(&ctx, &TEST_RESOURCE, "get", Some(uuid(RESOURCE)), &request)
+            .await
+            .expect("should succeed");
+        assert!(read.is_read_only());
+
+        let write = e
+            .access_scope_with(
+                &ctx,
+                &TEST_RESOURCE,
+                "update",
+                Some(uuid(RESOURCE)),
+                &request,
+            )
+            .await
+            .expect("should succeed");
+        assert_eq!(write.intent(), ScopeIntent::ReadWrite);
+    }
+
+    /// Enricher that pins the context tenant and tags the resource with a classification.
+    struct ClassificationEnricher;
+
+    #[async_trait]
+    impl ContextEnricher for ClassificationEnricher {
+        async fn enrich(&self, ctx: &SecurityContext, request: &mut EvaluationRequest) {
+            request
+                .context
+                .tenant_context
+                .get_or_insert_default()
+                .root_id = Some(ctx.subject_tenant_id());
+            request
+                .resource
+                .properties
+                .insert("classification".to_owned(), serde_json::json!("secret"));
+        }
+    }
+
+    /// Mock that only allows requests carrying the `classification` property.
+    struct RequiresClassificationMock;
+
+    #[async_trait]
+    impl AuthZResolverClient for RequiresClassificationMock {
+        async fn evaluate(
+            &self,
+            req: EvaluationRequest,
+        ) -> Result<EvaluationResponse, AuthZResolverError> {
+            if req.resource.properties.get("classification") == Some(&serde_json::json!("secret")) {
+                AllowAllMock.evaluate(req).await
+            } else {
+                DenyMock::new().evaluate(req).await
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn context_enricher_runs_before_evaluation() {
+        let e = enforcer(RequiresClassificationMock)
+            .with_context_enricher(Arc::new(ClassificationEnricher));
+        let ctx = test_ctx();
+
+        // Tenant constraint comes from the tenant context set by the enricher.
+        let scope = e
+            .access_scope(&ctx, &TEST_RESOURCE, "get", Some(uuid(RESOURCE)))
+            .await
+            .expect("enriched request should be allowed");
+
+        assert_eq!(
+            scope.all_uuid_values_for(pep_properties::OWNER_TENANT_ID),
+            &[uuid(TENANT)]
+        );
+    }
+
+    #[tokio::test]
+    async fn without_context_enricher_request_is_unchanged() {
+        let e = enforcer(RequiresClassificationMock);
+        let ctx = test_ctx();
+
+        let result = e.access_scope(&ctx, &TEST_RESOURCE, "get", None).await;
+
+        assert!(matches!(result, Err(EnforcerError::Denied { .. })));
+    }
+
+    #[test]
+    fn context_enricher_not_applied_to_build_request() {
+        let e = enforcer(AllowAllMock).with_context_enricher(Arc::new(ClassificationEnricher));
+        let req = e.build_request(&test_ctx(), &TEST_RESOURCE, "get", None, true);
+
+        assert!(req.resource.properties.is_empty());
+        assert!(req.context.tenant_context.is_none());
+    }
+
     #[test]
     fn debug_impl() {
         let e = enforcer(AllowAllMock);
@@ -755,6 +1556,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_request_with_applies_context_attributes() {
+        use crate::ctx_attrs;
+
+        let e = enforcer(AllowAllMock);
+        let ctx = test_ctx();
+        let gateway_attrs = ContextAttributes::new()
+            .with(ctx_attrs::REQUEST_IP, "203.0.113.7")
+            .with(ctx_attrs::MFA_PRESENT, false);
+
+        let req = e.build_request_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "delete",
+            None,
+            true,
+            &AccessRequest::new()
+                .context_attributes(&gateway_attrs)
+                .context_attribute(ctx_attrs::MFA_PRESENT, true),
+        );
+
+        assert_eq!(
+            req.context.attributes.get(ctx_attrs::REQUEST_IP),
+            Some(&serde_json::json!("203.0.113.7")),
+        );
+        assert_eq!(
+            req.context.attributes.get(ctx_attrs::MFA_PRESENT),
+            Some(&serde_json::json!(true)),
+        );
+
+        let json = serde_json::to_value(&req).unwrap();
+        assert_eq!(
+            json.pointer("/context/attributes/request_ip"),
+            Some(&serde_json::json!("203.0.113.7")),
+        );
+    }
+
+    #[test]
+    fn build_request_omits_empty_context_attributes() {
+        let e = enforcer(AllowAllMock);
+        let req = e.build_request(&test_ctx(), &TEST_RESOURCE, "get", None, true);
+
+        assert!(req.context.attributes.is_empty());
+        let json = serde_json::to_value(&req).unwrap();
+        assert!(json.pointer("/context/attributes").is_none());
+    }
+
     #[test]
     fn build_request_with_applies_tenant_mode_and_barrier() {
         let e = enforcer(AllowAllMock);
@@ -868,7 +1716,7 @@ mod tests {
             .build()
             .unwrap();
 
-        let e = PolicyEnforcer::new(Arc::new(AllowAllMock))
+        let e = PolicyEnforcer::from_client(AllowAllMock)
             .with_capabilities(vec![Capability::TenantHierarchy]);
 
         let access_req = AccessRequest::new().tenant_context(TenantContext {
@@ -1011,6 +1859,47 @@ mod tests {
         assert!(request.context.tenant_context.is_none());
     }
 
+    #[test]
+    fn positional_resource_id_wins_over_request() {
+        let e = enforcer(AllowAllMock);
+        let ctx = test_ctx();
+        let other = uuid("44444444-4444-4444-4444-444444444444");
+        let access_req = AccessRequest::new().resource_id(Some(other));
+
+        let request = e.build_request_with(&ctx, &TEST_RESOURCE, "get", None, true, &access_req);
+        assert_eq!(request.resource.id, Some(other));
+
+        let request = e.build_request_with(
+            &ctx,
+            &TEST_RESOURCE,
+            "get",
+            Some(uuid(RESOURCE)),
+            true,
+            &access_req,
+        );
+        assert_eq!(request.resource.id, Some(uuid(RESOURCE)));
+    }
+
+    #[tokio::test]
+    async fn access_scope_with_request_sends_request_resource_id() {
+        let mock = DefaultsMock::new(EvaluationDefaults::default());
+        let e = PolicyEnforcer::new(mock.clone());
+
+        e.access_scope_with_request(
+            &test_ctx(),
+            &TEST_RESOURCE,
+            "get",
+            &AccessRequest::new()
+                .resource_id(Some(uuid(RESOURCE)))
+                .require_constraints(false),
+        )
+        .await
+        .unwrap();
+
+        let sent = mock.last_request.lock().unwrap().clone().unwrap();
+        assert_eq!(sent.resource.id, Some(uuid(RESOURCE)));
+    }
+
     #[test]
     fn explicit_root_id_overrides_subject_tenant() {
         let subject_tenant = Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap();
@@ -1,6 +1,7 @@
 //! PEP (Policy Enforcement Point) helpers.
 //!
 //! - [`PolicyEnforcer`] — PEP object (build → evaluate → compile)
+//! - [`ContextEnricher`] — Hook to enrich evaluation requests before they reach the PDP
 //! - [`ResourceType`] — Static descriptor for a resource type + its supported properties
 //! - [`compile_to_access_scope`] — Low-level: compile evaluation response into `AccessScope`
 //! - [`IntoPropertyValue`] — Convert typed values into `serde_json::Value` for PDP requests
@@ -12,7 +13,10 @@ pub mod compiler;
 pub mod enforcer;
 
 pub use compiler::{ConstraintCompileError, compile_to_access_scope};
-pub use enforcer::{AccessRequest, EnforcerError, PolicyEnforcer, ResourceType};
+pub use enforcer::{
+    AccessRequest, ContextEnricher, DEFAULT_READ_ACTIONS, DEFAULT_RESOLVER_DEFAULTS_TTL,
+    EnforcerError, PolicyEnforcer, ResourceType,
+};
 
 /// Trait for types that can be converted into `serde_json::Value` for PDP
 /// evaluation requests and predicate construction.
@@ -20,4 +20,17 @@ pub trait AuthZResolverPluginClient: Send + Sync {
         &self,
         request: EvaluationRequest,
     ) -> Result<EvaluationResponse, AuthZResolverError>;
+
+    /// Hint that authorization policies may have changed.
+    ///
+    /// Called by the resolver when it is asked to reload policies (e.g. after a
+    /// configuration change). PDP-backed plugins should flush cached policies and
+    /// reload rules; plugins without external policy state can keep the default no-op.
+    ///
+    /// # Errors
+    ///
+    /// - `Internal` if the plugin fails to reload its policies
+    async fn on_policy_reload_hint(&self) -> Result<(), AuthZResolverError> {
+        Ok(())
+    }
 }
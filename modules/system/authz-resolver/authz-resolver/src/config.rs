@@ -1,5 +1,6 @@
 //! Configuration for the `AuthZ` resolver.
 
+use authz_resolver_sdk::{BarrierMode, TenantMode};
 use serde::Deserialize;
 
 /// Configuration.
@@ -8,12 +9,62 @@ use serde::Deserialize;
 pub struct AuthZResolverConfig {
     /// Vendor selector used to pick a plugin implementation.
     pub vendor: String,
+
+    /// Fail startup when any registered plugin instance is invalid.
+    ///
+    /// When `false`, invalid instances are only logged and reported through
+    /// the `authz.plugins.health` admin command.
+    pub strict_plugins: bool,
+
+    /// Default tenant context values served to PEPs through
+    /// `AuthZResolverClient::evaluation_defaults`.
+    pub evaluation_defaults: Vec<EvaluationDefaultsRule>,
 }
 
 impl Default for AuthZResolverConfig {
     fn default() -> Self {
         Self {
             vendor: "hyperspot".to_owned(),
+            strict_plugins: false,
+            evaluation_defaults: Vec::new(),
         }
     }
 }
+
+/// Default tenant context values for resource types matching a prefix.
+///
+/// When several rules match, each field is taken from the most specific rule
+/// that sets it: a longer prefix wins, then a rule listing the action over one
+/// that applies to all actions, then the later rule.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvaluationDefaultsRule {
+    /// Resource type prefix (e.g. `"gts.x.core.users."`); empty matches every type.
+    #[serde(default)]
+    pub resource_type_prefix: String,
+
+    /// Actions the rule applies to; empty applies to every action.
+    #[serde(default)]
+    pub actions: Vec<String>,
+
+    /// Default tenant hierarchy mode.
+    #[serde(default)]
+    pub tenant_mode: Option<TenantMode>,
+
+    /// Default barrier enforcement mode.
+    #[serde(default)]
+    pub barrier_mode: Option<BarrierMode>,
+
+    /// Default tenant status filter.
+    #[serde(default)]
+    pub tenant_status: Option<Vec<String>>,
+}
+
+impl EvaluationDefaultsRule {
+    /// Whether the rule applies to `resource_type` and `action`.
+    #[must_use]
+    pub fn matches(&self, resource_type: &str, action: &str) -> bool {
+        resource_type.starts_with(&self.resource_type_prefix)
+            && (self.actions.is_empty() || self.actions.iter().any(|a| a == action))
+    }
+}
@@ -4,7 +4,8 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use authz_resolver_sdk::{
-    AuthZResolverClient, AuthZResolverError, EvaluationRequest, EvaluationResponse,
+    AuthZResolverClient, AuthZResolverError, EvaluationDefaults, EvaluationRequest,
+    EvaluationResponse,
 };
 use modkit_macros::domain_model;
 
@@ -39,4 +40,207 @@ impl AuthZResolverClient for AuthZResolverLocalClient {
             .await
             .map_err(|e| log_and_convert("evaluate", e))
     }
+
+    async fn reload_policies(&self) -> Result<(), AuthZResolverError> {
+        self.svc
+            .reload_policies()
+            .await
+            .map_err(|e| log_and_convert("reload_policies", e))
+    }
+
+    async fn evaluation_defaults(
+        &self,
+        resource_type: &str,
+        action: &str,
+    ) -> Result<EvaluationDefaults, AuthZResolverError> {
+        Ok(self.svc.evaluation_defaults(resource_type, action))
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::sync::Mutex;
+
+    use authz_resolver_sdk::pep::{AccessRequest, PolicyEnforcer, ResourceType};
+    use authz_resolver_sdk::{
+        AuthZResolverPluginClient, AuthZResolverPluginSpecV1, BarrierMode,
+        EvaluationResponseContext, TenantContext, TenantMode,
+    };
+    use modkit::client_hub::{ClientHub, ClientScope};
+    use modkit::gts::BaseModkitPluginV1;
+    use modkit_security::{SecurityContext, pep_properties};
+    use types_registry_sdk::{
+        GtsEntity, ListQuery, RegisterResult, TypesRegistryClient, TypesRegistryError,
+    };
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::config::EvaluationDefaultsRule;
+
+    /// Same resource type and call shape as the users-info example.
+    const USER: ResourceType = ResourceType {
+        name: "users_info.user",
+        supported_properties: &[pep_properties::OWNER_TENANT_ID],
+    };
+
+    struct SinglePluginRegistry {
+        instance: GtsEntity,
+    }
+
+    #[async_trait]
+    impl TypesRegistryClient for SinglePluginRegistry {
+        async fn register(
+            &self,
+            _entities: Vec<serde_json::Value>,
+        ) -> Result<Vec<RegisterResult>, TypesRegistryError> {
+            unimplemented!()
+        }
+
+        async fn list(&self, _query: ListQuery) -> Result<Vec<GtsEntity>, TypesRegistryError> {
+            Ok(vec![self.instance.clone()])
+        }
+
+        async fn get(&self, _gts_id: &str) -> Result<GtsEntity, TypesRegistryError> {
+            unimplemented!()
+        }
+    }
+
+    /// Allows everything and records the tenant context it was asked about.
+    #[derive(Default)]
+    struct RecordingPlugin {
+        tenant_context: Mutex<Option<TenantContext>>,
+    }
+
+    #[async_trait]
+    impl AuthZResolverPluginClient for RecordingPlugin {
+        async fn evaluate(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<EvaluationResponse, AuthZResolverError> {
+            *self.tenant_context.lock().unwrap() = request.context.tenant_context;
+            Ok(EvaluationResponse {
+                decision: true,
+                context: EvaluationResponseContext::default(),
+            })
+        }
+    }
+
+    fn list_rule(tenant_mode: TenantMode) -> EvaluationDefaultsRule {
+        EvaluationDefaultsRule {
+            resource_type_prefix: "users_info.".to_owned(),
+            actions: vec!["list".to_owned()],
+            tenant_mode: Some(tenant_mode),
+            barrier_mode: None,
+            tenant_status: Some(vec!["active".to_owned()]),
+        }
+    }
+
+    /// Resolver configured with `rules`, backed by a recording plugin.
+    fn resolver(rules: Vec<EvaluationDefaultsRule>) -> (PolicyEnforcer, Arc<RecordingPlugin>) {
+        let instance_id =
+            AuthZResolverPluginSpecV1::gts_make_instance_id("test.recording_authz.plugin.v1");
+        let instance = BaseModkitPluginV1::<AuthZResolverPluginSpecV1> {
+            id: instance_id.clone(),
+            vendor: "test".to_owned(),
+            priority: 0,
+            properties: AuthZResolverPluginSpecV1,
+        };
+
+        let hub = Arc::new(ClientHub::new());
+        let registry: Arc<dyn TypesRegistryClient> = Arc::new(SinglePluginRegistry {
+            instance: GtsEntity::new(
+                Uuid::new_v4(),
+                &instance_id.to_string(),
+                vec![],
+                false,
+                serde_json::to_value(&instance).unwrap(),
+                None,
+            ),
+        });
+        hub.register::<dyn TypesRegistryClient>(registry);
+
+        let plugin = Arc::new(RecordingPlugin::default());
+        let api: Arc<dyn AuthZResolverPluginClient> = plugin.clone();
+        hub.register_scoped::<dyn AuthZResolverPluginClient>(
+            ClientScope::gts_id(&instance_id),
+            api,
+        );
+
+        let svc = Arc::new(Service::new(hub, "test".to_owned()).with_evaluation_defaults(rules));
+        let enforcer = PolicyEnforcer::from_client(AuthZResolverLocalClient::new(svc))
+            .with_resolver_defaults();
+        (enforcer, plugin)
+    }
+
+    /// A PEP call site that sets no tenant context fields of its own.
+    async fn list_users(enforcer: &PolicyEnforcer, request: &AccessRequest) {
+        let ctx = SecurityContext::builder()
+            .subject_id(Uuid::new_v4())
+            .subject_tenant_id(Uuid::new_v4())
+            .build()
+            .unwrap();
+        enforcer
+            .access_scope_with(&ctx, &USER, "list", None, request)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn configured_list_default_reaches_the_plugin() {
+        let request = AccessRequest::new().require_constraints(false);
+
+        for mode in [TenantMode::RootOnly, TenantMode::Subtree] {
+            let (enforcer, plugin) = resolver(vec![list_rule(mode.clone())]);
+            list_users(&enforcer, &request).await;
+
+            let tc = plugin.tenant_context.lock().unwrap().clone().unwrap();
+            assert_eq!(tc.mode, mode);
+            assert_eq!(tc.tenant_status, Some(vec!["active".to_owned()]));
+        }
+    }
+
+    #[tokio::test]
+    async fn explicit_override_beats_configured_default() {
+        let (enforcer, plugin) = resolver(vec![list_rule(TenantMode::RootOnly)]);
+
+        list_users(
+            &enforcer,
+            &AccessRequest::new()
+                .require_constraints(false)
+                .tenant_mode(TenantMode::Subtree),
+        )
+        .await;
+
+        let tc = plugin.tenant_context.lock().unwrap().clone().unwrap();
+        assert_eq!(tc.mode, TenantMode::Subtree);
+        assert_eq!(tc.tenant_status, Some(vec!["active".to_owned()]));
+    }
+
+    #[test]
+    fn most_specific_rule_wins_per_field() {
+        let catch_all = EvaluationDefaultsRule {
+            resource_type_prefix: String::new(),
+            actions: vec![],
+            tenant_mode: Some(TenantMode::Subtree),
+            barrier_mode: Some(BarrierMode::Respect),
+            tenant_status: None,
+        };
+        let svc = Service::new(Arc::new(ClientHub::new()), "test".to_owned())
+            .with_evaluation_defaults(vec![list_rule(TenantMode::RootOnly), catch_all]);
+
+        let list = svc.evaluation_defaults(USER.name, "list");
+        assert_eq!(list.tenant_mode, Some(TenantMode::RootOnly));
+        assert_eq!(list.barrier_mode, Some(BarrierMode::Respect));
+
+        let create = svc.evaluation_defaults(USER.name, "create");
+        assert_eq!(create.tenant_mode, Some(TenantMode::Subtree));
+        assert!(create.tenant_status.is_none());
+
+        assert!(
+            svc.evaluation_defaults("billing.invoice", "list")
+                .tenant_status
+                .is_none()
+        );
+    }
 }
@@ -4,16 +4,20 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use authz_resolver_sdk::{
-    AuthZResolverPluginClient, AuthZResolverPluginSpecV1, EvaluationRequest, EvaluationResponse,
+    AuthZResolverPluginClient, AuthZResolverPluginSpecV1, EvaluationDefaults, EvaluationRequest,
+    EvaluationResponse,
 };
 use modkit::client_hub::{ClientHub, ClientScope};
-use modkit::plugins::{GtsPluginSelector, choose_plugin_instance};
+use modkit::gts::{PluginHealth, validate_plugin_instances};
+use modkit::plugins::{GtsPluginSelector, SelectorStatus, choose_plugin_instance};
 use modkit::telemetry::ThrottledLog;
 use modkit_macros::domain_model;
+use tokio::sync::RwLock;
 use tracing::info;
-use types_registry_sdk::{ListQuery, TypesRegistryClient};
+use types_registry_sdk::{GtsEntity, ListQuery, TypesRegistryClient};
 
 use super::error::DomainError;
+use crate::config::EvaluationDefaultsRule;
 
 /// Throttle interval for unavailable plugin warnings.
 const UNAVAILABLE_LOG_THROTTLE: Duration = Duration::from_secs(10);
@@ -25,6 +29,8 @@ pub struct Service {
     vendor: String,
     selector: GtsPluginSelector,
     unavailable_log_throttle: ThrottledLog,
+    plugin_health: RwLock<PluginHealth>,
+    evaluation_defaults: Vec<EvaluationDefaultsRule>,
 }
 
 impl Service {
@@ -35,9 +41,48 @@ impl Service {
             vendor,
             selector: GtsPluginSelector::new(),
             unavailable_log_throttle: ThrottledLog::new(UNAVAILABLE_LOG_THROTTLE),
+            plugin_health: RwLock::new(PluginHealth::default()),
+            evaluation_defaults: Vec::new(),
         }
     }
 
+    /// Serve `rules` through [`evaluation_defaults`](Self::evaluation_defaults).
+    #[must_use]
+    pub fn with_evaluation_defaults(mut self, rules: Vec<EvaluationDefaultsRule>) -> Self {
+        self.evaluation_defaults = rules;
+        self
+    }
+
+    /// Default tenant context values configured for `resource_type` and `action`.
+    ///
+    /// Each field comes from the most specific matching rule that sets it
+    /// (see [`EvaluationDefaultsRule`]).
+    #[must_use]
+    pub fn evaluation_defaults(&self, resource_type: &str, action: &str) -> EvaluationDefaults {
+        let mut rules: Vec<&EvaluationDefaultsRule> = self
+            .evaluation_defaults
+            .iter()
+            .filter(|rule| rule.matches(resource_type, action))
+            .collect();
+        // Least specific first, so more specific rules overwrite (stable for ties)
+        rules.sort_by_key(|rule| (rule.resource_type_prefix.len(), !rule.actions.is_empty()));
+
+        rules
+            .into_iter()
+            .fold(EvaluationDefaults::default(), |mut defaults, rule| {
+                if let Some(mode) = &rule.tenant_mode {
+                    defaults.tenant_mode = Some(mode.clone());
+                }
+                if let Some(mode) = rule.barrier_mode {
+                    defaults.barrier_mode = Some(mode);
+                }
+                if let Some(statuses) = &rule.tenant_status {
+                    defaults.tenant_status = Some(statuses.clone());
+                }
+                defaults
+            })
+    }
+
     async fn get_plugin(&self) -> Result<Arc<dyn AuthZResolverPluginClient>, DomainError> {
         let instance_id = self.selector.get_or_init(|| self.resolve_plugin()).await?;
         let scope = ClientScope::gts_id(instance_id.as_ref());
@@ -62,10 +107,29 @@ impl Service {
         }
     }
 
-    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
-    async fn resolve_plugin(&self) -> Result<String, DomainError> {
-        info!("Resolving authz_resolver plugin");
+    /// Drop the selected plugin so the next call resolves it again.
+    ///
+    /// Returns `true` if a plugin was selected.
+    pub async fn reset_selector(&self) -> bool {
+        let reset = self.selector.reset().await;
+        info!(reset, vendor = %self.vendor, "Plugin selector reset");
+        reset
+    }
+
+    /// Number of selector resets since startup.
+    #[must_use]
+    pub fn selector_reset_count(&self) -> u64 {
+        self.selector.reset_count()
+    }
 
+    /// Plugin selection state, including the last resolution error.
+    #[must_use]
+    pub fn selector_status(&self) -> SelectorStatus {
+        self.selector.status()
+    }
+
+    /// List all registered plugin instances from types-registry.
+    async fn list_plugin_instances(&self) -> Result<Vec<GtsEntity>, DomainError> {
         let registry = self
             .hub
             .get::<dyn TypesRegistryClient>()
@@ -73,13 +137,20 @@ impl Service {
 
         let plugin_type_id = AuthZResolverPluginSpecV1::gts_schema_id().clone();
 
-        let instances = registry
+        Ok(registry
             .list(
                 ListQuery::new()
                     .with_pattern(format!("{plugin_type_id}*"))
                     .with_is_type(false),
             )
-            .await?;
+            .await?)
+    }
+
+    #[tracing::instrument(skip_all, fields(vendor = %self.vendor))]
+    async fn resolve_plugin(&self) -> Result<String, DomainError> {
+        info!("Resolving authz_resolver plugin");
+
+        let instances = self.list_plugin_instances().await?;
 
         let gts_id = choose_plugin_instance::<AuthZResolverPluginSpecV1>(
             &self.vendor,
@@ -90,6 +161,29 @@ impl Service {
         Ok(gts_id)
     }
 
+    /// Validates every registered plugin instance and stores the result for
+    /// [`plugin_health`](Self::plugin_health).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if types-registry cannot be queried.
+    pub async fn validate_plugins(&self) -> Result<PluginHealth, DomainError> {
+        let instances = self.list_plugin_instances().await?;
+        let health = PluginHealth {
+            checked: instances.len(),
+            issues: validate_plugin_instances::<AuthZResolverPluginSpecV1>(
+                instances.iter().map(|e| (e.gts_id.as_str(), &e.content)),
+            ),
+        };
+        *self.plugin_health.write().await = health.clone();
+        Ok(health)
+    }
+
+    /// Result of the last [`validate_plugins`](Self::validate_plugins) run.
+    pub async fn plugin_health(&self) -> PluginHealth {
+        self.plugin_health.read().await.clone()
+    }
+
     /// Evaluate an authorization request via the selected plugin.
     ///
     /// # Errors
@@ -104,4 +198,186 @@ impl Service {
         let plugin = self.get_plugin().await?;
         plugin.evaluate(request).await.map_err(DomainError::from)
     }
+
+    /// Forward a policy reload hint to every registered plugin instance.
+    ///
+    /// All instances are notified (not only the selected one) so that a vendor
+    /// switch never picks up a stale policy cache. Instances whose client is not
+    /// registered yet are skipped. A failing plugin does not prevent the others
+    /// from being notified; the first error is returned.
+    ///
+    /// # Errors
+    ///
+    /// - Types registry errors while listing plugin instances
+    /// - The first plugin reload error
+    #[tracing::instrument(skip_all)]
+    pub async fn reload_policies(&self) -> Result<(), DomainError> {
+        let instances = self.list_plugin_instances().await?;
+
+        let mut first_error = None;
+        for instance in &instances {
+            let scope = ClientScope::gts_id(&instance.gts_id);
+            let Some(plugin) = self
+                .hub
+                .try_get_scoped::<dyn AuthZResolverPluginClient>(&scope)
+            else {
+                tracing::debug!(
+                    plugin_gts_id = %instance.gts_id,
+                    "Plugin client not registered, skipping reload hint"
+                );
+                continue;
+            };
+
+            if let Err(e) = plugin.on_policy_reload_hint().await {
+                tracing::warn!(
+                    plugin_gts_id = %instance.gts_id,
+                    error = %e,
+                    "Plugin failed to reload policies"
+                );
+                first_error.get_or_insert(DomainError::from(e));
+            }
+        }
+
+        info!(plugins = instances.len(), "Policy reload hint dispatched");
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+#[cfg_attr(coverage_nightly, coverage(off))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use async_trait::async_trait;
+    use authz_resolver_sdk::AuthZResolverError;
+    use types_registry_sdk::{RegisterResult, TypesRegistryError};
+    use uuid::Uuid;
+
+    use super::*;
+
+    struct PluginsRegistry {
+        instances: Vec<GtsEntity>,
+    }
+
+    #[async_trait]
+    impl TypesRegistryClient for PluginsRegistry {
+        async fn register(
+            &self,
+            _entities: Vec<serde_json::Value>,
+        ) -> Result<Vec<RegisterResult>, TypesRegistryError> {
+            unimplemented!()
+        }
+
+        async fn list(&self, _query: ListQuery) -> Result<Vec<GtsEntity>, TypesRegistryError> {
+            Ok(self.instances.clone())
+        }
+
+        async fn get(&self, _gts_id: &str) -> Result<GtsEntity, TypesRegistryError> {
+            unimplemented!()
+        }
+    }
+
+    /// Counts reload hints and optionally fails them.
+    struct ReloadingPlugin {
+        reloads: AtomicUsize,
+        error: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl AuthZResolverPluginClient for ReloadingPlugin {
+        async fn evaluate(
+            &self,
+            _request: EvaluationRequest,
+        ) -> Result<EvaluationResponse, AuthZResolverError> {
+            unimplemented!()
+        }
+
+        async fn on_policy_reload_hint(&self) -> Result<(), AuthZResolverError> {
+            self.reloads.fetch_add(1, Ordering::Relaxed);
+            match self.error {
+                Some(msg) => Err(AuthZResolverError::Internal(msg.to_owned())),
+                None => Ok(()),
+            }
+        }
+    }
+
+    fn entity(gts_id: &str) -> GtsEntity {
+        GtsEntity::new(
+            Uuid::new_v4(),
+            gts_id,
+            vec![],
+            false,
+            serde_json::json!({}),
+            None,
+        )
+    }
+
+    fn register(
+        hub: &ClientHub,
+        gts_id: &str,
+        error: Option<&'static str>,
+    ) -> Arc<ReloadingPlugin> {
+        let plugin = Arc::new(ReloadingPlugin {
+            reloads: AtomicUsize::new(0),
+            error,
+        });
+        let api: Arc<dyn AuthZResolverPluginClient> = plugin.clone();
+        hub.register_scoped::<dyn AuthZResolverPluginClient>(ClientScope::gts_id(gts_id), api);
+        plugin
+    }
+
+    #[tokio::test]
+    async fn reload_reaches_every_plugin_and_returns_the_first_error() {
+        let failing_id =
+            AuthZResolverPluginSpecV1::gts_make_instance_id("test.failing_authz.plugin.v1")
+                .to_string();
+        let pending_id =
+            AuthZResolverPluginSpecV1::gts_make_instance_id("test.pending_authz.plugin.v1")
+                .to_string();
+        let healthy_id =
+            AuthZResolverPluginSpecV1::gts_make_instance_id("test.healthy_authz.plugin.v1")
+                .to_string();
+
+        let hub = Arc::new(ClientHub::new());
+        let registry: Arc<dyn TypesRegistryClient> = Arc::new(PluginsRegistry {
+            instances: vec![
+                entity(&failing_id),
+                entity(&pending_id),
+                entity(&healthy_id),
+            ],
+        });
+        hub.register::<dyn TypesRegistryClient>(registry);
+        let failing = register(&hub, &failing_id, Some("policy store unreachable"));
+        let healthy = register(&hub, &healthy_id, None);
+        let svc = Service::new(hub, "test".to_owned());
+
+        let err = svc.reload_policies().await.unwrap_err();
+
+        assert!(
+            matches!(&err, DomainError::Internal(msg) if msg == "policy store unreachable"),
+            "{err:?}"
+        );
+        // The failure does not stop the fan-out; the unregistered instance is skipped
+        assert_eq!(failing.reloads.load(Ordering::Relaxed), 1);
+        assert_eq!(healthy.reloads.load(Ordering::Relaxed), 1);
+    }
+
+    #[tokio::test]
+    async fn reload_succeeds_when_every_plugin_does() {
+        let id = AuthZResolverPluginSpecV1::gts_make_instance_id("test.healthy_authz.plugin.v1")
+            .to_string();
+        let hub = Arc::new(ClientHub::new());
+        let registry: Arc<dyn TypesRegistryClient> = Arc::new(PluginsRegistry {
+            instances: vec![entity(&id)],
+        });
+        hub.register::<dyn TypesRegistryClient>(registry);
+        let plugin = register(&hub, &id, None);
+
+        Service::new(hub, "test".to_owned())
+            .reload_policies()
+            .await
+            .unwrap();
+
+        assert_eq!(plugin.reloads.load(Ordering::Relaxed), 1);
+    }
 }
@@ -1,5 +1,6 @@
 //! `AuthZ` resolver module.
 
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, OnceLock};
 
 use async_trait::async_trait;
@@ -7,7 +8,9 @@ use authz_resolver_sdk::{AuthZResolverClient, AuthZResolverPluginSpecV1};
 use modkit::Module;
 use modkit::context::ModuleCtx;
 use modkit::contracts::SystemCapability;
-use tracing::info;
+use modkit::gts::format_plugin_issues;
+use modkit::runtime::SystemContext;
+use tracing::{info, warn};
 use types_registry_sdk::{RegisterResult, TypesRegistryClient};
 
 use crate::config::AuthZResolverConfig;
@@ -21,7 +24,8 @@ use crate::domain::{AuthZResolverLocalClient, Service};
 /// 3. Routes requests to the selected plugin based on vendor configuration
 ///
 /// Plugin discovery is lazy: happens on first API call after types-registry
-/// is ready.
+/// is ready. All registered instances are validated once in `post_init`, after
+/// plugins have registered them.
 #[modkit::module(
     name = "authz-resolver",
     deps = ["types-registry"],
@@ -29,12 +33,14 @@ use crate::domain::{AuthZResolverLocalClient, Service};
 )]
 pub(crate) struct AuthZResolver {
     service: OnceLock<Arc<Service>>,
+    strict_plugins: AtomicBool,
 }
 
 impl Default for AuthZResolver {
     fn default() -> Self {
         Self {
             service: OnceLock::new(),
+            strict_plugins: AtomicBool::new(false),
         }
     }
 }
@@ -42,7 +48,49 @@ impl Default for AuthZResolver {
 // Marked as `system` so that init() runs in the system-module phase.
 // This ensures the AuthZResolver client is available in ClientHub before
 // other system modules that depend on it.
-impl SystemCapability for AuthZResolver {}
+#[async_trait]
+impl SystemCapability for AuthZResolver {
+    /// Validates plugin instances once every module has registered its own.
+    ///
+    /// Plugins register their instances in `init()`, which runs after this
+    /// system module's `init()`, so the check has to wait for this phase.
+    async fn post_init(&self, _sys: &SystemContext) -> anyhow::Result<()> {
+        let svc = self
+            .service
+            .get()
+            .ok_or_else(|| anyhow::anyhow!("{} module not initialized", Self::MODULE_NAME))?;
+
+        let health = svc.validate_plugins().await?;
+        if health.is_healthy() {
+            info!(
+                checked = health.checked,
+                "All authz_resolver plugin instances are valid"
+            );
+            return Ok(());
+        }
+
+        warn!(
+            checked = health.checked,
+            invalid = health.invalid_instances(),
+            "{}:\n{}",
+            health.summary(),
+            format_plugin_issues(&health.issues)
+        );
+        if self.strict_plugins.load(Ordering::Relaxed) {
+            anyhow::bail!(
+                "{} (strict_plugins is enabled): {}",
+                health.summary(),
+                health
+                    .issues
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            );
+        }
+        Ok(())
+    }
+}
 
 #[async_trait]
 impl Module for AuthZResolver {
@@ -73,17 +121,53 @@ impl Module for AuthZResolver {
             "Registered plugin schema in types-registry"
         );
 
+        self.strict_plugins
+            .store(cfg.strict_plugins, Ordering::Relaxed);
+
         // Create service
         let hub = ctx.client_hub();
-        let svc = Arc::new(Service::new(hub, cfg.vendor));
+        if !cfg.evaluation_defaults.is_empty() {
+            info!(
+                rules = cfg.evaluation_defaults.len(),
+                "Serving configured evaluation defaults"
+            );
+        }
+        let svc = Arc::new(
+            Service::new(hub, cfg.vendor).with_evaluation_defaults(cfg.evaluation_defaults),
+        );
         self.service
             .set(svc.clone())
             .map_err(|_| anyhow::anyhow!("{} module already initialized", Self::MODULE_NAME))?;
 
         // Register client in ClientHub
-        let api: Arc<dyn AuthZResolverClient> = Arc::new(AuthZResolverLocalClient::new(svc));
+        let api: Arc<dyn AuthZResolverClient> =
+            Arc::new(AuthZResolverLocalClient::new(svc.clone()));
         ctx.client_hub().register::<dyn AuthZResolverClient>(api);
 
+        // Health probe: result of the plugin instance validation in post_init
+        // and the current plugin selection state
+        let health_svc = Arc::clone(&svc);
+        ctx.register_admin_command("authz.plugins.health", move |_args| {
+            let svc = Arc::clone(&health_svc);
+            async move {
+                let mut health = svc.plugin_health().await.to_json();
+                health["selector"] = svc.selector_status().to_json();
+                Ok(health)
+            }
+        })?;
+
+        // Operator command: force plugin reselection without a restart
+        ctx.register_admin_command("authz.reset-selector", move |_args| {
+            let svc = Arc::clone(&svc);
+            async move {
+                let reset = svc.reset_selector().await;
+                Ok(serde_json::json!({
+                    "reset": reset,
+                    "reset_count": svc.selector_reset_count(),
+                }))
+            }
+        })?;
+
         info!("{} module initialized successfully", Self::MODULE_NAME);
 
         Ok(())
@@ -11,6 +11,13 @@ pub struct StaticAuthZPluginConfig {
 
     /// Plugin priority (lower = higher priority).
     pub priority: i16,
+
+    /// Context attributes (`context.attributes` keys) that must be present
+    /// on every evaluation request; requests missing any of them are denied.
+    ///
+    /// Useful for end-to-end testing of attribute propagation
+    /// (e.g. `["request_ip", "request_time"]`).
+    pub required_context_attributes: Vec<String>,
 }
 
 impl Default for StaticAuthZPluginConfig {
@@ -18,6 +25,7 @@ impl Default for StaticAuthZPluginConfig {
         Self {
             vendor: "hyperspot".to_owned(),
             priority: 100,
+            required_context_attributes: Vec::new(),
         }
     }
 }
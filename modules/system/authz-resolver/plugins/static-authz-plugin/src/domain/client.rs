@@ -15,16 +15,34 @@ impl AuthZResolverPluginClient for Service {
     ) -> Result<EvaluationResponse, AuthZResolverError> {
         Ok(self.evaluate(&request))
     }
+
+    async fn on_policy_reload_hint(&self) -> Result<(), AuthZResolverError> {
+        // Static policies are compiled in; there is nothing to reload.
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
     use super::*;
+    use authz_resolver_sdk::conformance::{ConformanceFixture, run_conformance_suite};
+    use authz_resolver_sdk::ctx_attrs::{self, ContextAttributes};
+    use authz_resolver_sdk::pep::{AccessRequest, EnforcerError, PolicyEnforcer, ResourceType};
     use authz_resolver_sdk::{Action, EvaluationRequestContext, Resource, Subject, TenantContext};
+    use modkit_security::{SecurityContext, pep_properties};
     use std::collections::HashMap;
+    use std::sync::Arc;
     use uuid::Uuid;
 
+    #[tokio::test]
+    async fn policy_reload_hint_is_noop() {
+        let service = Service::new();
+        let plugin: &dyn AuthZResolverPluginClient = &service;
+
+        plugin.on_policy_reload_hint().await.unwrap();
+    }
+
     #[tokio::test]
     async fn plugin_trait_evaluates_successfully() {
         let service = Service::new();
@@ -50,9 +68,11 @@ mod tests {
                     ..TenantContext::default()
                 }),
                 token_scopes: vec![],
+                token_scopes_narrowed: false,
                 require_constraints: false,
                 capabilities: vec![],
                 supported_properties: vec![],
+                attributes: HashMap::new(),
                 bearer_token: None,
             },
         };
@@ -61,4 +81,120 @@ mod tests {
         assert!(result.is_ok());
         assert!(result.unwrap().decision);
     }
+
+    fn conformance_request(tenant_id: Uuid) -> EvaluationRequest {
+        EvaluationRequest {
+            subject: Subject {
+                id: Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap(),
+                subject_type: None,
+                properties: HashMap::new(),
+            },
+            action: Action {
+                name: "list".to_owned(),
+            },
+            resource: Resource {
+                resource_type: RESOURCE.name.to_owned(),
+                id: None,
+                properties: HashMap::new(),
+            },
+            context: EvaluationRequestContext {
+                tenant_context: Some(TenantContext {
+                    root_id: Some(tenant_id),
+                    ..TenantContext::default()
+                }),
+                token_scopes: vec![],
+                token_scopes_narrowed: false,
+                require_constraints: true,
+                capabilities: vec![],
+                supported_properties: vec![pep_properties::OWNER_TENANT_ID.to_owned()],
+                attributes: HashMap::new(),
+                bearer_token: None,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn passes_plugin_conformance_suite() {
+        let tenant_id = Uuid::parse_str("33333333-3333-3333-3333-333333333333").unwrap();
+        // A nil tenant is the one request the static policy denies
+        let fixture = ConformanceFixture::new(conformance_request(tenant_id))
+            .with_denied(conformance_request(Uuid::nil()));
+
+        run_conformance_suite(Arc::new(Service::new()), fixture)
+            .await
+            .assert_passed();
+    }
+
+    /// Routes `PolicyEnforcer` calls straight into the plugin, standing in for
+    /// the `authz-resolver` gateway module.
+    struct PluginAsResolver(Service);
+
+    #[async_trait]
+    impl authz_resolver_sdk::AuthZResolverClient for PluginAsResolver {
+        async fn evaluate(
+            &self,
+            request: EvaluationRequest,
+        ) -> Result<EvaluationResponse, AuthZResolverError> {
+            AuthZResolverPluginClient::evaluate(&self.0, request).await
+        }
+    }
+
+    const RESOURCE: ResourceType = ResourceType {
+        name: "gts.x.core.users.user.v1~",
+        supported_properties: &[pep_properties::OWNER_TENANT_ID],
+    };
+
+    fn enforcer_requiring(keys: &[&str]) -> PolicyEnforcer {
+        let service = Service::new()
+            .with_required_context_attributes(keys.iter().map(|k| (*k).to_owned()).collect());
+        PolicyEnforcer::from_client(PluginAsResolver(service))
+    }
+
+    fn security_ctx() -> SecurityContext {
+        SecurityContext::builder()
+            .subject_id(Uuid::parse_str("11111111-1111-1111-1111-111111111111").unwrap())
+            .subject_tenant_id(Uuid::parse_str("33333333-3333-3333-3333-333333333333").unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn context_attributes_propagate_through_enforcer() {
+        let enforcer = enforcer_requiring(&[ctx_attrs::REQUEST_IP, ctx_attrs::REQUEST_TIME]);
+
+        // As stashed in request extensions by the API gateway
+        let gateway_attrs = ContextAttributes::new()
+            .with(ctx_attrs::REQUEST_IP, "198.51.100.4")
+            .with(ctx_attrs::REQUEST_TIME, "2026-01-01T12:00:00+00:00");
+
+        let scope = enforcer
+            .access_scope_with(
+                &security_ctx(),
+                &RESOURCE,
+                "list",
+                None,
+                &AccessRequest::new().context_attributes(&gateway_attrs),
+            )
+            .await
+            .unwrap();
+
+        assert!(!scope.is_unconstrained());
+    }
+
+    #[tokio::test]
+    async fn missing_context_attribute_is_denied_through_enforcer() {
+        let enforcer = enforcer_requiring(&[ctx_attrs::REQUEST_IP]);
+
+        let err = enforcer
+            .access_scope(&security_ctx(), &RESOURCE, "list", None)
+            .await
+            .unwrap_err();
+
+        match err {
+            EnforcerError::Denied {
+                deny_reason: Some(reason),
+            } => assert_eq!(reason.error_code, "missing_context_attribute"),
+            other => panic!("Expected Denied with reason, got: {other:?}"),
+        }
+    }
 }
@@ -1,11 +1,7 @@
 //! Service implementation for the static `AuthZ` resolver plugin.
 
-use authz_resolver_sdk::{
-    Constraint, EvaluationRequest, EvaluationResponse, EvaluationResponseContext, InPredicate,
-    Predicate,
-};
+use authz_resolver_sdk::{ConstraintSet, EvaluationRequest, EvaluationResponse, respond_allow};
 use modkit_macros::domain_model;
-use modkit_security::pep_properties;
 use uuid::Uuid;
 
 /// Static `AuthZ` resolver service.
@@ -13,20 +9,40 @@ use uuid::Uuid;
 /// - Returns `decision: true` with an `in` predicate on `pep_properties::OWNER_TENANT_ID`
 ///   scoped to the context tenant from the request (for all operations including CREATE).
 /// - Denies access (`decision: false`) when no valid tenant can be resolved.
+/// - Denies access when any configured required context attribute is missing.
 #[domain_model]
 #[derive(Default)]
-pub struct Service;
+pub struct Service {
+    required_context_attributes: Vec<String>,
+}
 
 impl Service {
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Deny requests that lack any of the given `context.attributes` keys.
+    #[must_use]
+    pub fn with_required_context_attributes(mut self, keys: Vec<String>) -> Self {
+        self.required_context_attributes = keys;
+        self
     }
 
     /// Evaluate an authorization request.
     #[must_use]
-    #[allow(clippy::unused_self)] // &self reserved for future config/state
     pub fn evaluate(&self, request: &EvaluationRequest) -> EvaluationResponse {
+        if let Some(missing) = self
+            .required_context_attributes
+            .iter()
+            .find(|key| !request.context.attributes.contains_key(key.as_str()))
+        {
+            return ConstraintSet::deny(
+                "missing_context_attribute",
+                Some(format!("required context attribute '{missing}' is missing")),
+            );
+        }
+
         // Always scope to context tenant (all CRUD operations get constraints)
         let tenant_id = request
             .context
@@ -45,31 +61,17 @@ impl Service {
 
         let Some(tid) = tenant_id else {
             // No tenant resolvable from context or subject — deny access.
-            return EvaluationResponse {
-                decision: false,
-                context: EvaluationResponseContext::default(),
-            };
+            return ConstraintSet::deny("tenant_unresolved", None);
         };
 
         if tid == Uuid::default() {
             // Nil UUID tenant — deny rather than grant unrestricted access.
-            return EvaluationResponse {
-                decision: false,
-                context: EvaluationResponseContext::default(),
-            };
+            return ConstraintSet::deny("tenant_unresolved", Some("nil tenant id".to_owned()));
         }
 
-        EvaluationResponse {
-            decision: true,
-            context: EvaluationResponseContext {
-                constraints: vec![Constraint {
-                    predicates: vec![Predicate::In(InPredicate::new(
-                        pep_properties::OWNER_TENANT_ID,
-                        [tid],
-                    ))],
-                }],
-                ..Default::default()
-            },
+        match ConstraintSet::allow().tenant_in([tid]).build() {
+            Ok(constraints) => respond_allow(constraints),
+            Err(e) => ConstraintSet::deny("invalid_constraints", Some(e.to_string())),
         }
     }
 }
@@ -78,8 +80,12 @@ impl Service {
 #[cfg_attr(coverage_nightly, coverage(off))]
 mod tests {
     use super::*;
+    use authz_resolver_sdk::ctx_attrs;
     use authz_resolver_sdk::pep::IntoPropertyValue;
-    use authz_resolver_sdk::{Action, EvaluationRequestContext, Resource, Subject, TenantContext};
+    use authz_resolver_sdk::{
+        Action, EvaluationRequestContext, Predicate, Resource, Subject, TenantContext,
+    };
+    use modkit_security::pep_properties;
     use std::collections::HashMap;
 
     fn make_request(require_constraints: bool, tenant_id: Option<Uuid>) -> EvaluationRequest {
@@ -109,9 +115,11 @@ mod tests {
                     ..TenantContext::default()
                 }),
                 token_scopes: vec!["*".to_owned()],
+                token_scopes_narrowed: false,
                 require_constraints,
                 capabilities: vec![],
                 supported_properties: vec![],
+                attributes: HashMap::new(),
                 bearer_token: None,
             },
         }
@@ -134,7 +142,9 @@ mod tests {
                 assert_eq!(in_pred.property, pep_properties::OWNER_TENANT_ID);
                 assert_eq!(in_pred.values, vec![tenant_id.into_filter_value()]);
             }
-            other @ Predicate::Eq(_) => panic!("Expected In predicate, got: {other:?}"),
+            other @ (Predicate::Eq(_) | Predicate::NotIn(_)) => {
+                panic!("Expected In predicate, got: {other:?}")
+            }
         }
     }
 
@@ -158,7 +168,9 @@ mod tests {
                     ]
                 );
             }
-            other @ Predicate::Eq(_) => panic!("Expected In predicate, got: {other:?}"),
+            other @ (Predicate::Eq(_) | Predicate::NotIn(_)) => {
+                panic!("Expected In predicate, got: {other:?}")
+            }
         }
     }
 
@@ -190,9 +202,11 @@ mod tests {
             context: EvaluationRequestContext {
                 tenant_context: None,
                 token_scopes: vec!["*".to_owned()],
+                token_scopes_narrowed: false,
                 require_constraints: true,
                 capabilities: vec![],
                 supported_properties: vec![],
+                attributes: HashMap::new(),
                 bearer_token: None,
             },
         };
@@ -203,4 +217,36 @@ mod tests {
         assert!(!response.decision);
         assert!(response.context.constraints.is_empty());
     }
+
+    #[test]
+    fn missing_required_context_attribute_is_denied() {
+        let tenant_id = Uuid::parse_str("33333333-3333-3333-3333-333333333333").unwrap();
+        let service =
+            Service::new().with_required_context_attributes(vec![ctx_attrs::REQUEST_IP.to_owned()]);
+
+        let response = service.evaluate(&make_request(true, Some(tenant_id)));
+
+        assert!(!response.decision);
+        assert!(response.context.constraints.is_empty());
+        let reason = response.context.deny_reason.expect("deny reason");
+        assert_eq!(reason.error_code, "missing_context_attribute");
+        assert!(reason.details.unwrap().contains(ctx_attrs::REQUEST_IP));
+    }
+
+    #[test]
+    fn present_required_context_attribute_is_allowed() {
+        let tenant_id = Uuid::parse_str("33333333-3333-3333-3333-333333333333").unwrap();
+        let service =
+            Service::new().with_required_context_attributes(vec![ctx_attrs::REQUEST_IP.to_owned()]);
+
+        let mut request = make_request(true, Some(tenant_id));
+        request.context.attributes.insert(
+            ctx_attrs::REQUEST_IP.to_owned(),
+            serde_json::Value::String("10.0.0.1".to_owned()),
+        );
+        let response = service.evaluate(&request);
+
+        assert!(response.decision);
+        assert_eq!(response.context.constraints.len(), 1);
+    }
 }
@@ -40,6 +40,7 @@ impl Module for StaticAuthZPlugin {
         info!(
             vendor = %cfg.vendor,
             priority = cfg.priority,
+            required_context_attributes = ?cfg.required_context_attributes,
             "Loaded plugin configuration"
         );
 
@@ -62,7 +63,9 @@ impl Module for StaticAuthZPlugin {
         RegisterResult::ensure_all_ok(&results)?;
 
         // Create service
-        let service = Arc::new(Service::new());
+        let service = Arc::new(
+            Service::new().with_required_context_attributes(cfg.required_context_attributes),
+        );
         self.service
             .set(service.clone())
             .map_err(|_| anyhow::anyhow!("{} module already initialized", Self::MODULE_NAME))?;
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use modkit::admin::{ModuleGraph, ModuleNode, ModuleState};
 use modkit::runtime::InstanceState;
 
 use crate::domain::model::{DeploymentMode, InstanceInfo, ModuleInfo};
@@ -104,3 +105,69 @@ impl From<&InstanceInfo> for ModuleInstanceDto {
         }
     }
 }
+
+/// Lifecycle state of a module in this process
+#[derive(Debug, Clone)]
+#[modkit_macros::api_dto(response)]
+pub enum ModuleStateDto {
+    Registered,
+    Initialized,
+    Started,
+    Stopped,
+    Failed,
+}
+
+/// A module in the dependency graph
+#[modkit_macros::api_dto(response)]
+pub struct ModuleNodeDto {
+    /// Module name
+    pub name: String,
+    /// Declared dependencies (other module names)
+    pub deps: Vec<String>,
+    /// Declared capabilities (e.g., "rest", "system", "db")
+    pub capabilities: Vec<String>,
+    /// Current lifecycle state
+    pub state: ModuleStateDto,
+    /// Time spent in `init()` in milliseconds (absent until init has run)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub init_duration_ms: Option<u64>,
+}
+
+/// Resolved module dependency graph, in init order
+#[modkit_macros::api_dto(response)]
+pub struct ModuleGraphDto {
+    /// Modules in init order
+    pub modules: Vec<ModuleNodeDto>,
+}
+
+impl From<ModuleState> for ModuleStateDto {
+    fn from(state: ModuleState) -> Self {
+        match state {
+            ModuleState::Registered => Self::Registered,
+            ModuleState::Initialized => Self::Initialized,
+            ModuleState::Started => Self::Started,
+            ModuleState::Stopped => Self::Stopped,
+            ModuleState::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<ModuleNode> for ModuleNodeDto {
+    fn from(node: ModuleNode) -> Self {
+        Self {
+            name: node.name,
+            deps: node.deps,
+            capabilities: node.capabilities,
+            state: node.state.into(),
+            init_duration_ms: node.init_duration_ms,
+        }
+    }
+}
+
+impl From<ModuleGraph> for ModuleGraphDto {
+    fn from(graph: ModuleGraph) -> Self {
+        Self {
+            modules: graph.modules.into_iter().map(Into::into).collect(),
+        }
+    }
+}
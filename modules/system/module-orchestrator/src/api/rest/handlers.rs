@@ -1,8 +1,9 @@
 use axum::Extension;
+use modkit::admin::RuntimeIntrospection;
 use modkit::api::prelude::*;
 use std::sync::Arc;
 
-use super::dto::ModuleDto;
+use super::dto::{ModuleDto, ModuleGraphDto};
 use crate::domain::service::ModulesService;
 
 /// List all registered modules with their capabilities, instances, and deployment mode.
@@ -16,3 +17,14 @@ pub async fn list_modules(
     let modules: Vec<ModuleDto> = svc.list_modules().iter().map(ModuleDto::from).collect();
     Ok(Json(modules))
 }
+
+/// Dependency graph of this process with lifecycle states and init durations.
+///
+/// # Errors
+///
+/// Returns `ApiError` if the response cannot be constructed.
+pub async fn get_module_graph(
+    Extension(introspection): Extension<Arc<RuntimeIntrospection>>,
+) -> ApiResult<Json<ModuleGraphDto>> {
+    Ok(Json(introspection.graph().into()))
+}
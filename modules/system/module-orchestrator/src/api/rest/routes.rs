@@ -1,9 +1,10 @@
 use axum::http;
 use axum::{Extension, Router};
+use modkit::admin::RuntimeIntrospection;
 use modkit::api::{OpenApiRegistry, OperationBuilder};
 use std::sync::Arc;
 
-use super::dto::ModuleDto;
+use super::dto::{ModuleDto, ModuleGraphDto};
 use super::handlers;
 use crate::domain::service::ModulesService;
 
@@ -38,3 +39,33 @@ pub fn register_routes(
 
     router
 }
+
+/// Register the process-local system info routes
+#[allow(clippy::needless_pass_by_value)]
+pub fn register_system_routes(
+    mut router: Router,
+    openapi: &dyn OpenApiRegistry,
+    introspection: Arc<RuntimeIntrospection>,
+) -> Router {
+    // GET /system/v1/modules - Module dependency graph of this process
+    router = OperationBuilder::get("/system/v1/modules")
+        .operation_id("system.get_module_graph")
+        .summary("Get the module dependency graph")
+        .description(
+            "Returns the modules compiled into this process in init order, with their \
+         declared dependencies, lifecycle state, and init duration.",
+        )
+        .tag("system")
+        .authenticated()
+        .no_license_required()
+        .handler(handlers::get_module_graph)
+        .json_response_with_schema::<ModuleGraphDto>(
+            openapi,
+            http::StatusCode::OK,
+            "Module dependency graph",
+        )
+        .standard_errors(openapi)
+        .register(router, openapi);
+
+    router.layer(Extension(introspection))
+}
@@ -6,6 +6,7 @@ use std::sync::{Arc, OnceLock};
 use tokio::sync::RwLock;
 
 use modkit::DirectoryClient;
+use modkit::admin::RuntimeIntrospection;
 use modkit::context::ModuleCtx;
 use modkit::contracts::{
     GrpcServiceCapability, OpenApiRegistry, RegisterGrpcServiceFn, RestApiCapability,
@@ -31,6 +32,7 @@ pub struct ModuleOrchestratorConfig;
 /// - Exposes `DirectoryService` gRPC service via `grpc-hub`
 /// - Tracks module instances and provides service resolution
 /// - Exposes REST API to list all registered modules
+/// - Serves the module dependency graph of this process at `/system/v1/modules`
 #[modkit::module(
     name = "module-orchestrator",
     capabilities = [grpc, system, rest],
@@ -40,6 +42,7 @@ pub struct ModuleOrchestrator {
     config: RwLock<ModuleOrchestratorConfig>,
     directory_api: OnceLock<Arc<dyn DirectoryClient>>,
     module_manager: OnceLock<Arc<ModuleManager>>,
+    introspection: OnceLock<Arc<RuntimeIntrospection>>,
     modules_service: OnceLock<Arc<ModulesService>>,
 }
 
@@ -49,6 +52,7 @@ impl Default for ModuleOrchestrator {
             config: RwLock::new(ModuleOrchestratorConfig),
             directory_api: OnceLock::new(),
             module_manager: OnceLock::new(),
+            introspection: OnceLock::new(),
             modules_service: OnceLock::new(),
         }
     }
@@ -60,6 +64,11 @@ impl SystemCapability for ModuleOrchestrator {
         self.module_manager
             .set(Arc::clone(&sys.module_manager))
             .map_err(|_| anyhow::anyhow!("ModuleManager already set (pre_init called twice?)"))?;
+        self.introspection
+            .set(Arc::clone(&sys.introspection))
+            .map_err(|_| {
+                anyhow::anyhow!("RuntimeIntrospection already set (pre_init called twice?)")
+            })?;
         Ok(())
     }
 }
@@ -115,7 +124,15 @@ impl RestApiCapability for ModuleOrchestrator {
                 .ok_or_else(|| anyhow::anyhow!("ModulesService not initialized"))?,
         );
 
+        let introspection = Arc::clone(
+            self.introspection
+                .get()
+                .ok_or_else(|| anyhow::anyhow!("RuntimeIntrospection not wired"))?,
+        );
+
         let router = crate::api::rest::routes::register_routes(router, openapi, service);
+        let router =
+            crate::api::rest::routes::register_system_routes(router, openapi, introspection);
 
         tracing::info!("ModuleOrchestrator REST routes registered");
         Ok(router)
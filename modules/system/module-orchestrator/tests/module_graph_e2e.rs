@@ -0,0 +1,96 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+//! End-to-end tests for the `GET /system/v1/modules` REST endpoint.
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use modkit::admin::{ModuleState, RuntimeIntrospection};
+use modkit::registry::RegistryBuilder;
+use module_orchestrator::api::rest;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceExt;
+
+#[derive(Default)]
+struct DummyCore;
+#[async_trait::async_trait]
+impl modkit::Module for DummyCore {
+    async fn init(&self, _ctx: &modkit::context::ModuleCtx) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// `storage` <- `users` <- `reports`, with `reports` failing its init.
+fn introspection() -> Arc<RuntimeIntrospection> {
+    let mut b = RegistryBuilder::default();
+    b.register_core_with_meta("reports", &["users", "storage"], Arc::new(DummyCore));
+    b.register_core_with_meta("users", &["storage"], Arc::new(DummyCore));
+    b.register_core_with_meta("storage", &[], Arc::new(DummyCore));
+    let registry = b.build_topo_sorted().unwrap();
+
+    let introspection = Arc::new(RuntimeIntrospection::from_registry(&registry));
+    introspection.record_init_duration("storage", Duration::from_millis(40));
+    introspection.set_state("storage", ModuleState::Started);
+    introspection.record_init_duration("users", Duration::from_millis(5));
+    introspection.set_state("users", ModuleState::Initialized);
+    introspection.record_init_duration("reports", Duration::from_millis(1));
+    introspection.set_state("reports", ModuleState::Failed);
+    introspection
+}
+
+async fn get_graph(router: Router) -> (StatusCode, serde_json::Value) {
+    let response = router
+        .oneshot(
+            Request::builder()
+                .method(Method::GET)
+                .uri("/system/v1/modules")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn reports_modules_in_init_order_with_states() {
+    let openapi = api_gateway::ApiGateway::default();
+    let router = rest::routes::register_system_routes(Router::new(), &openapi, introspection());
+
+    let (status, json) = get_graph(router).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        json,
+        json!({"modules": [
+            {"name": "storage", "deps": [], "capabilities": [], "state": "started", "init_duration_ms": 40},
+            {"name": "users", "deps": ["storage"], "capabilities": [], "state": "initialized", "init_duration_ms": 5},
+            {"name": "reports", "deps": ["users", "storage"], "capabilities": [], "state": "failed", "init_duration_ms": 1},
+        ]})
+    );
+}
+
+#[tokio::test]
+async fn modules_not_yet_initialized_have_no_duration() {
+    let mut b = RegistryBuilder::default();
+    b.register_core_with_meta("pending", &[], Arc::new(DummyCore));
+    let introspection = Arc::new(RuntimeIntrospection::from_registry(
+        &b.build_topo_sorted().unwrap(),
+    ));
+    let openapi = api_gateway::ApiGateway::default();
+    let router = rest::routes::register_system_routes(Router::new(), &openapi, introspection);
+
+    let (status, json) = get_graph(router).await;
+
+    assert_eq!(status, StatusCode::OK);
+    let module = &json["modules"][0];
+    assert_eq!(module["state"], "registered");
+    assert!(module.get("init_duration_ms").is_none());
+}